@@ -0,0 +1,291 @@
+use crate::generators::utils::*;
+use anyhow::Result;
+use stormforge_generator::ir::{Property, ValueObject};
+
+/// Field of a generated Dart class
+pub struct DartField {
+    /// Dart field name
+    pub name: String,
+    /// JSON key, the name of the Rust field
+    pub json_key: String,
+    pub ir_type: IrType,
+    pub nullable: bool,
+    pub description: Option<String>,
+    /// Overrides a getter of the superclass
+    pub overrides: bool,
+}
+
+impl DartField {
+    pub fn from_property(prop: &Property) -> Self {
+        Self::new(
+            &prop.name,
+            &prop.prop_type,
+            !prop.required,
+            prop.description.as_deref(),
+        )
+    }
+
+    pub fn new(name: &str, ir_type: &str, nullable: bool, description: Option<&str>) -> Self {
+        Self {
+            name: dart_name(name),
+            json_key: json_key(name),
+            ir_type: IrType::parse(ir_type),
+            nullable,
+            description: description.map(str::to_string),
+            overrides: false,
+        }
+    }
+}
+
+/// Immutable Dart class with JSON conversion
+pub struct DartClass {
+    pub name: String,
+    pub description: Option<String>,
+    pub fields: Vec<DartField>,
+    /// Sealed superclass, for domain events
+    pub extends: Option<String>,
+    /// Value of the `type` tag written by `toJson`
+    pub type_tag: Option<String>,
+}
+
+pub struct ClassGenerator;
+
+impl ClassGenerator {
+    /// Generate an immutable class with `fromJson`, `toJson`, equality and `toString`
+    pub fn generate_class(types: &DartTypes, class: &DartClass) -> String {
+        let name = &class.name;
+        let mut out = doc_comment(class.description.as_deref(), "");
+        match &class.extends {
+            Some(superclass) => {
+                out.push_str(&format!("final class {} extends {} {{\n", name, superclass))
+            }
+            None => out.push_str(&format!("@immutable\nclass {} {{\n", name)),
+        }
+
+        if class.fields.is_empty() {
+            out.push_str(&format!("  const {}();\n\n", name));
+            out.push_str(&format!(
+                "  factory {}.fromJson(Map<String, dynamic> json) => const {}();\n\n",
+                name, name
+            ));
+        } else {
+            out.push_str(&format!("  const {}({{\n", name));
+            for field in &class.fields {
+                let required = if field.nullable { "" } else { "required " };
+                out.push_str(&format!("    {}this.{},\n", required, field.name));
+            }
+            out.push_str("  });\n\n");
+
+            out.push_str(&format!(
+                "  factory {}.fromJson(Map<String, dynamic> json) {{\n    return {}(\n",
+                name, name
+            ));
+            for field in &class.fields {
+                let json = format!("json['{}']", field.json_key);
+                let decoded = if field.nullable {
+                    types.decode_nullable(&field.ir_type, &json)
+                } else {
+                    types.decode(&field.ir_type, &json)
+                };
+                out.push_str(&format!("      {}: {},\n", field.name, decoded));
+            }
+            out.push_str("    );\n  }\n\n");
+
+            for field in &class.fields {
+                out.push_str(&doc_comment(field.description.as_deref(), "  "));
+                if field.overrides {
+                    out.push_str("  @override\n");
+                }
+                out.push_str(&format!(
+                    "  final {} {};\n\n",
+                    types.field_type(&field.ir_type, field.nullable),
+                    field.name
+                ));
+            }
+        }
+
+        if class.extends.is_some() {
+            out.push_str("  @override\n");
+        }
+        out.push_str("  Map<String, dynamic> toJson() {\n    return {\n");
+        if let Some(tag) = &class.type_tag {
+            out.push_str(&format!("      'type': '{}',\n", tag));
+        }
+        for field in &class.fields {
+            let encoded = if field.nullable {
+                types.encode_nullable(&field.ir_type, &field.name)
+            } else {
+                types.encode(&field.ir_type, &field.name)
+            };
+            out.push_str(&format!("      '{}': {},\n", field.json_key, encoded));
+        }
+        out.push_str("    };\n  }\n\n");
+
+        out.push_str(&Self::generate_equality(types, name, &class.fields));
+        out.push_str("}\n");
+        out
+    }
+
+    fn generate_equality(types: &DartTypes, name: &str, fields: &[DartField]) -> String {
+        let mut out = String::from("  @override\n  bool operator ==(Object other) =>\n");
+        if fields.is_empty() {
+            out.push_str(&format!("      other is {};\n\n", name));
+            out.push_str("  @override\n  int get hashCode => runtimeType.hashCode;\n\n");
+            out.push_str(&format!(
+                "  @override\n  String toString() => '{}()';\n",
+                name
+            ));
+            return out;
+        }
+
+        let comparisons = fields
+            .iter()
+            .map(|field| {
+                if types.needs_deep_equality(&field.ir_type) {
+                    format!("_equality.equals({}, other.{})", field.name, field.name)
+                } else {
+                    format!("{} == other.{}", field.name, field.name)
+                }
+            })
+            .collect::<Vec<_>>();
+        out.push_str(&format!(
+            "      identical(this, other) ||\n      other is {} &&\n          {};\n\n",
+            name,
+            comparisons.join(" &&\n          ")
+        ));
+
+        let hashes = fields
+            .iter()
+            .map(|field| {
+                if types.needs_deep_equality(&field.ir_type) {
+                    format!("_equality.hash({})", field.name)
+                } else {
+                    field.name.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+        out.push_str("  @override\n  int get hashCode => Object.hashAll([\n");
+        for hash in hashes {
+            out.push_str(&format!("        {},\n", hash));
+        }
+        out.push_str("      ]);\n\n");
+
+        let fields = fields
+            .iter()
+            .map(|field| format!("{}: ${}", field.name, field.name))
+            .collect::<Vec<_>>();
+        out.push_str(&format!(
+            "  @override\n  String toString() => '{}({})';\n",
+            name,
+            fields.join(", ")
+        ));
+        out
+    }
+
+    /// Generate an enum serialized as the Rust variant name
+    pub fn generate_enum(name: &str, vo: &ValueObject) -> Result<String> {
+        if vo.values.is_empty() {
+            anyhow::bail!("Enum '{}' has no values", name);
+        }
+
+        let mut out = doc_comment(vo.description.as_deref(), "");
+        out.push_str(&format!("enum {} {{\n", name));
+        let values = vo
+            .values
+            .iter()
+            .map(|value| {
+                let wire = heck::ToPascalCase::to_pascal_case(value.name.as_str());
+                format!(
+                    "{}  {}('{}')",
+                    doc_comment(value.description.as_deref(), "  "),
+                    dart_name(&value.name),
+                    wire
+                )
+            })
+            .collect::<Vec<_>>();
+        out.push_str(&values.join(",\n"));
+        out.push_str(";\n\n");
+        out.push_str(&format!("  const {}(this.value);\n\n", name));
+        out.push_str("  /// Serialized name, the variant of the Rust enum\n");
+        out.push_str("  final String value;\n\n");
+        out.push_str(&format!(
+            "  static {} fromJson(Object? json) {{\n    return values.firstWhere(\n      (value) => value.value == json,\n      orElse: () => throw ArgumentError.value(json, 'json', 'Unknown {}'),\n    );\n  }}\n\n",
+            name, name
+        ));
+        out.push_str("  String toJson() => value;\n}\n");
+        Ok(out)
+    }
+
+    /// Generate a wrapper class for an identifier, serialized as its underlying value
+    pub fn generate_identifier(types: &DartTypes, name: &str, vo: &ValueObject) -> String {
+        let underlying = IrType::parse(vo.underlying_type.as_deref().unwrap_or("String"));
+        let dart_type = types.dart_type(&underlying);
+
+        let mut out = doc_comment(vo.description.as_deref(), "");
+        out.push_str(&format!("@immutable\nclass {} {{\n", name));
+        out.push_str(&format!("  const {}(this.value);\n\n", name));
+        out.push_str(&format!(
+            "  factory {}.fromJson(Object? json) => {}({});\n\n",
+            name,
+            name,
+            types.decode(&underlying, "json")
+        ));
+        out.push_str(&format!("  final {} value;\n\n", dart_type));
+        out.push_str(&format!(
+            "  {} toJson() => {};\n\n",
+            if dart_type == "DateTime" {
+                "String"
+            } else {
+                dart_type.as_str()
+            },
+            types.encode(&underlying, "value")
+        ));
+        out.push_str(&format!(
+            "  @override\n  bool operator ==(Object other) => other is {} && value == other.value;\n\n",
+            name
+        ));
+        out.push_str("  @override\n  int get hashCode => value.hashCode;\n\n");
+        out.push_str("  @override\n  String toString() => '$value';\n}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stormforge_generator::ir::IRParser;
+
+    #[test]
+    fn test_fields_use_rust_json_keys() {
+        let model = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "test.order"
+value_objects:
+  OrderId:
+    name: "OrderId"
+    type: "identifier"
+"#,
+        )
+        .unwrap();
+        let types = DartTypes::new(&model);
+        let class = DartClass {
+            name: "CancelOrder".to_string(),
+            description: None,
+            fields: vec![
+                DartField::new("orderId", "OrderId", false, None),
+                DartField::new("cancelledAt", "DateTime", true, None),
+            ],
+            extends: None,
+            type_tag: None,
+        };
+
+        let code = ClassGenerator::generate_class(&types, &class);
+
+        assert!(code.contains("orderId: OrderId.fromJson(json['order_id']),"));
+        assert!(code.contains("'cancelled_at': cancelledAt?.toUtc().toIso8601String(),"));
+        assert!(code.contains("  final DateTime? cancelledAt;"));
+    }
+}
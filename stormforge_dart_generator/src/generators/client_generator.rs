@@ -0,0 +1,411 @@
+use crate::generators::utils::*;
+use heck::{ToKebabCase, ToSnakeCase};
+use stormforge_generator::ir::{IRModel, Query};
+
+/// Prefix under which the Rust service nests its API router
+const API_PREFIX: &str = "/api";
+
+pub struct ClientGenerator;
+
+impl ClientGenerator {
+    /// Generate the HTTP client with a method per command, query and read model endpoint
+    pub fn generate(model: &IRModel, has_queries: bool) -> String {
+        let context = &model.bounded_context.name;
+        let file = context.to_snake_case();
+        let types = DartTypes::new(model);
+
+        let mut out =
+            String::from("import 'dart:convert';\n\nimport 'package:http/http.dart' as http;\n\n");
+        out.push_str(&format!("import '../commands/{}_commands.dart';\n", file));
+        if has_queries {
+            out.push_str(&format!("import '../queries/{}_queries.dart';\n", file));
+        }
+        out.push_str(&format!("import '../types/{}.dart';\n\n", file));
+
+        out.push_str(&format!(
+            r#"/// Error response of the {context} service
+class ApiException implements Exception {{
+  const ApiException(this.statusCode, this.code, this.message);
+
+  final int statusCode;
+
+  /// Error code, such as `VALIDATION_ERROR`
+  final String code;
+
+  final String message;
+
+  @override
+  String toString() => 'ApiException($statusCode, $code: $message)';
+}}
+
+"#
+        ));
+
+        out.push_str(&Self::generate_service(model));
+        out.push('\n');
+        out.push_str(&Self::generate_commands(model));
+        out.push('\n');
+        out.push_str(&Self::generate_queries(model, &types));
+        if !model.read_models.is_empty() {
+            out.push('\n');
+            out.push_str(&Self::generate_read_models(model));
+        }
+        out.push('\n');
+        out.push_str(TRANSPORT);
+        out
+    }
+
+    /// Generate a test calling each query against the response body of the
+    /// generated Rust service, skipping queries whose required parameters are
+    /// classes
+    pub fn generate_test(model: &IRModel, package: &str) -> Option<String> {
+        let context = &model.bounded_context.name;
+        let types = DartTypes::new(model);
+        let tests = model
+            .queries
+            .iter()
+            .filter_map(|(name, query)| {
+                let arguments = query
+                    .parameters
+                    .iter()
+                    .filter(|param| param.required)
+                    .map(|param| {
+                        let sample = types.sample(&IrType::parse(&param.prop_type))?;
+                        Some(format!("{}: {}", dart_name(&param.name), sample))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!(
+                    "    test('{method} returns the response data', () async {{\n      expect(await service.queries.{method}({arguments}), 'Query result');\n    }});\n",
+                    method = dart_name(name),
+                    arguments = arguments.join(", ")
+                ))
+            })
+            .collect::<Vec<_>>();
+        if tests.is_empty() {
+            return None;
+        }
+
+        Some(format!(
+            r#"import 'dart:convert';
+
+import 'package:http/http.dart' as http;
+import 'package:http/testing.dart';
+import 'package:{package}/{package}.dart';
+import 'package:test/test.dart';
+
+/// Body the generated Rust service answers its query routes with
+const queryResponse = {{'data': 'Query result'}};
+
+void main() {{
+  late {context}Service service;
+
+  setUp(() {{
+    service = {context}Service(
+      baseUrl: 'http://localhost:3000',
+      httpClient: MockClient((request) async => http.Response(jsonEncode(queryResponse), 200)),
+    );
+  }});
+
+  tearDown(() => service.close());
+
+  group('queries', () {{
+{tests}  }});
+}}
+"#,
+            tests = tests.join("\n")
+        ))
+    }
+
+    fn generate_service(model: &IRModel) -> String {
+        let context = &model.bounded_context.name;
+        let mut out = format!(
+            r#"/// HTTP client for the {context} service
+class {context}Service {{
+  {context}Service({{required String baseUrl, http.Client? httpClient}})
+      : _transport = _Transport(Uri.parse(baseUrl), httpClient ?? http.Client());
+
+  final _Transport _transport;
+
+  /// Command endpoints
+  late final {context}Commands commands = {context}Commands._(_transport);
+
+  /// Query endpoints
+  late final {context}Queries queries = {context}Queries._(_transport);
+"#
+        );
+        if !model.read_models.is_empty() {
+            out.push_str(&format!(
+                "\n  /// Read model endpoints\n  late final {context}ReadModels readModels = {context}ReadModels._(_transport);\n"
+            ));
+        }
+        out.push_str("\n  /// Close the underlying HTTP client\n  void close() => _transport.client.close();\n}\n");
+        out
+    }
+
+    fn generate_commands(model: &IRModel) -> String {
+        let context = &model.bounded_context.name;
+        let methods = model.commands.iter().map(|(name, command)| {
+            format!(
+                "{}  Future<String> {}({} command) async {{\n    final body = await _transport.post('{}/{}', command.toJson());\n    return _data(body) as String;\n  }}\n",
+                doc_comment(command.description.as_deref(), "  "),
+                dart_name(name),
+                name,
+                API_PREFIX,
+                name.to_kebab_case()
+            )
+        });
+        Self::endpoint_class(
+            &format!("Command endpoints of the {} service", context),
+            &format!("{}Commands", context),
+            methods.collect(),
+        )
+    }
+
+    fn generate_queries(model: &IRModel, types: &DartTypes) -> String {
+        let context = &model.bounded_context.name;
+        let methods = model
+            .queries
+            .iter()
+            .map(|(name, query)| Self::generate_query(types, name, query))
+            .collect();
+        Self::endpoint_class(
+            &format!("Query endpoints of the {} service", context),
+            &format!("{}Queries", context),
+            methods,
+        )
+    }
+
+    /// A query answers with the `data` string of the service's
+    /// `ApiResponseString` response, until the service returns the type the
+    /// IR declares
+    fn generate_query(types: &DartTypes, name: &str, query: &Query) -> String {
+        let mut description = query
+            .description
+            .as_deref()
+            .map(|description| format!("{}\n\n", description))
+            .unwrap_or_default();
+        description
+            .push_str("Returns the `data` string of the service's `ApiResponseString` response.");
+        if let Some(returns) = &query.returns {
+            description.push_str(&format!(
+                "\nThe IR declares `{}`, which the generated service does not return yet.",
+                types.field_type(&IrType::parse(&returns.return_type), returns.nullable)
+            ));
+        }
+
+        let parameters = query
+            .parameters
+            .iter()
+            .map(|param| {
+                let ir_type = IrType::parse(&param.prop_type);
+                let name = dart_name(&param.name);
+                if param.required {
+                    format!("required {} {}", types.dart_type(&ir_type), name)
+                } else {
+                    format!("{} {}", types.field_type(&ir_type, true), name)
+                }
+            })
+            .collect::<Vec<_>>();
+        let signature = if parameters.is_empty() {
+            String::new()
+        } else {
+            format!("{{{}}}", parameters.join(", "))
+        };
+
+        let mut query_string = String::new();
+        if !query.parameters.is_empty() {
+            query_string.push_str(", {\n");
+            for param in &query.parameters {
+                let ir_type = IrType::parse(&param.prop_type);
+                let name = dart_name(&param.name);
+                let encoded = if param.required {
+                    types.encode(&ir_type, &name)
+                } else {
+                    types.encode_nullable(&ir_type, &name)
+                };
+                query_string.push_str(&format!(
+                    "      '{}': {},\n",
+                    json_key(&param.name),
+                    encoded
+                ));
+            }
+            query_string.push_str("    }");
+        }
+
+        format!(
+            "{}  Future<String> {}({}) async {{\n    final body = await _transport.get('{}/{}'{});\n    return _data(body) as String;\n  }}\n",
+            doc_comment(Some(&description), "  "),
+            dart_name(name),
+            signature,
+            API_PREFIX,
+            name.to_kebab_case(),
+            query_string
+        )
+    }
+
+    /// Endpoints serving the projected read model rows directly
+    fn generate_read_models(model: &IRModel) -> String {
+        let context = &model.bounded_context.name;
+        let methods = model
+            .read_models
+            .values()
+            .map(|read_model| {
+                let name = &read_model.name;
+                let path = format!("{}/read-models/{}", API_PREFIX, name.to_kebab_case());
+                format!(
+                    r#"  /// List all `{name}` rows
+  Future<List<{name}>> list{name}() async {{
+    final body = await _transport.get('{path}');
+    return (body as List<dynamic>)
+        .map((row) => {name}.fromJson(row as Map<String, dynamic>))
+        .toList();
+  }}
+
+  /// Get one `{name}` row by aggregate ID, `null` if there is none
+  Future<{name}?> {method}(String id) async {{
+    try {{
+      final body = await _transport.get('{path}/${{Uri.encodeComponent(id)}}');
+      return {name}.fromJson(body as Map<String, dynamic>);
+    }} on ApiException catch (e) {{
+      if (e.statusCode == 404) {{
+        return null;
+      }}
+      rethrow;
+    }}
+  }}
+"#,
+                    method = dart_name(name),
+                )
+            })
+            .collect();
+        Self::endpoint_class(
+            &format!("Read model endpoints of the {} service", context),
+            &format!("{}ReadModels", context),
+            methods,
+        )
+    }
+
+    fn endpoint_class(description: &str, name: &str, methods: Vec<String>) -> String {
+        let mut out = format!(
+            "/// {}\nclass {} {{\n  {}._(this._transport);\n\n  final _Transport _transport;\n",
+            description, name, name
+        );
+        for method in methods {
+            out.push('\n');
+            out.push_str(&method);
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// JSON transport shared by the endpoint classes
+const TRANSPORT: &str = r#"/// Payload of the `{"data": ...}` envelope of command and query responses
+Object? _data(Object? body) => (body as Map<String, dynamic>)['data'];
+
+class _Transport {
+  _Transport(this.baseUrl, this.client);
+
+  final Uri baseUrl;
+
+  final http.Client client;
+
+  Future<Object?> get(String path, [Map<String, Object?> query = const {}]) async {
+    final parameters = {
+      for (final entry in query.entries)
+        if (entry.value != null) entry.key: '${entry.value}',
+    };
+    final uri = parameters.isEmpty ? _uri(path) : _uri(path).replace(queryParameters: parameters);
+    return _decode(await client.get(uri));
+  }
+
+  Future<Object?> post(String path, Map<String, dynamic> body) async {
+    final response = await client.post(
+      _uri(path),
+      headers: {'content-type': 'application/json'},
+      body: jsonEncode(body),
+    );
+    return _decode(response);
+  }
+
+  Uri _uri(String path) {
+    final base = baseUrl.path.endsWith('/')
+        ? baseUrl.path.substring(0, baseUrl.path.length - 1)
+        : baseUrl.path;
+    return baseUrl.replace(path: '$base$path');
+  }
+
+  Object? _decode(http.Response response) {
+    final body = response.body.isEmpty ? null : jsonDecode(response.body);
+    if (response.statusCode >= 400) {
+      final error = body is Map<String, dynamic> ? body : const <String, dynamic>{};
+      throw ApiException(
+        response.statusCode,
+        error['code'] as String? ?? 'HTTP_${response.statusCode}',
+        error['message'] as String? ?? response.reasonPhrase ?? '',
+      );
+    }
+    return body;
+  }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stormforge_generator::ir::IRParser;
+
+    fn model() -> IRModel {
+        IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "test.order"
+value_objects:
+  OrderId:
+    name: "OrderId"
+    type: "identifier"
+  Address:
+    name: "Address"
+    properties:
+      - name: "street"
+        type: "String"
+        required: true
+queries:
+  GetOrder:
+    name: "GetOrder"
+    parameters:
+      - name: "orderId"
+        type: "OrderId"
+        required: true
+    returns:
+      type: "Order"
+      nullable: true
+  FindByAddress:
+    name: "FindByAddress"
+    parameters:
+      - name: "address"
+        type: "Address"
+        required: true
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_queries_return_the_service_response_data() {
+        let client = ClientGenerator::generate(&model(), false);
+
+        assert!(client.contains("  Future<String> getOrder({required OrderId orderId}) async {"));
+        assert!(client.contains("    return _data(body) as String;\n"));
+        assert!(client.contains("The IR declares `Object?`"));
+
+        let test = ClientGenerator::generate_test(&model(), "order_service").unwrap();
+        assert!(test.contains("const queryResponse = {'data': 'Query result'};"));
+        assert!(test.contains(
+            "expect(await service.queries.getOrder(orderId: OrderId('sample')), 'Query result');"
+        ));
+        assert!(!test.contains("findByAddress"));
+    }
+}
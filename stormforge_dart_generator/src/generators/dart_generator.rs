@@ -0,0 +1,391 @@
+use crate::generators::class_generator::*;
+use crate::generators::client_generator::ClientGenerator;
+use crate::generators::utils::*;
+use anyhow::{Context, Result};
+use heck::ToSnakeCase;
+use std::fs;
+use std::path::Path;
+use stormforge_generator::ir::{Event, IRModel, ReadModel};
+
+pub struct DartGenerator {
+    output_dir: String,
+    package_name: Option<String>,
+}
+
+impl DartGenerator {
+    pub fn new(output_dir: String) -> Self {
+        Self {
+            output_dir,
+            package_name: None,
+        }
+    }
+
+    /// Use a package name other than `<context>_service`
+    pub fn with_package_name(mut self, package_name: String) -> Self {
+        self.package_name = Some(package_name);
+        self
+    }
+
+    /// Generate a Dart client package from an IR model
+    pub fn generate(&self, model: &IRModel) -> Result<()> {
+        println!(
+            "🚀 Generating Dart package for '{}'...",
+            model.bounded_context.name
+        );
+
+        let package = self.package_name(model);
+        let context = model.bounded_context.name.to_snake_case();
+
+        let mut files = vec![
+            (
+                "pubspec.yaml".to_string(),
+                Self::generate_pubspec(model, &package),
+            ),
+            (
+                "README.md".to_string(),
+                Self::generate_readme(model, &package),
+            ),
+            (
+                format!("lib/src/types/{}.dart", context),
+                Self::generate_types(model)?,
+            ),
+            (
+                format!("lib/src/commands/{}_commands.dart", context),
+                Self::generate_commands(model),
+            ),
+            (
+                format!("lib/src/events/{}_events.dart", context),
+                Self::generate_events(model),
+            ),
+        ];
+        let queries = Self::generate_queries(model);
+        if let Some(queries) = &queries {
+            files.push((
+                format!("lib/src/queries/{}_queries.dart", context),
+                queries.clone(),
+            ));
+        }
+        files.push((
+            format!("lib/src/client/{}_client.dart", context),
+            ClientGenerator::generate(model, queries.is_some()),
+        ));
+        files.push((
+            format!("lib/{}.dart", package),
+            Self::generate_library(model, queries.is_some()),
+        ));
+        if let Some(test) = ClientGenerator::generate_test(model, &package) {
+            files.push((format!("test/{}_test.dart", package), test));
+        }
+
+        for (name, content) in files {
+            let path = Path::new(&self.output_dir).join(&name);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+            }
+            fs::write(&path, content)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+
+        println!("✅ Generation complete! Output at: {}", self.output_dir);
+
+        Ok(())
+    }
+
+    fn package_name(&self, model: &IRModel) -> String {
+        self.package_name
+            .clone()
+            .unwrap_or_else(|| format!("{}_service", model.bounded_context.name.to_snake_case()))
+    }
+
+    fn generate_pubspec(model: &IRModel, package: &str) -> String {
+        format!(
+            r#"name: {}
+description: Typed client for the {} bounded context
+version: 0.1.0
+publish_to: none
+
+environment:
+  sdk: ^3.0.0
+
+dependencies:
+  collection: ^1.18.0
+  http: ^1.2.0
+  meta: ^1.9.0
+
+dev_dependencies:
+  test: ^1.24.0
+"#,
+            package, model.bounded_context.name
+        )
+    }
+
+    fn generate_readme(model: &IRModel, package: &str) -> String {
+        let context = &model.bounded_context.name;
+        let description = model
+            .bounded_context
+            .description
+            .as_deref()
+            .map(|description| format!("{}\n\n", description))
+            .unwrap_or_default();
+        format!(
+            r#"# {package}
+
+Typed Dart client for the {context} bounded context, generated by StormForge.
+
+{description}## Usage
+
+```dart
+import 'package:{package}/{package}.dart';
+
+final service = {context}Service(baseUrl: 'http://localhost:3000');
+```
+
+`service.commands` has a method per command endpoint and `service.queries` a
+method per query endpoint, returning the `data` string of the service's
+`ApiResponseString` response. Classes convert to and from the JSON of the
+generated Rust service with `fromJson`/`toJson`; `DomainEvent.fromJson`
+decodes any event of this context by its `type` tag.
+"#
+        )
+    }
+
+    /// Library file exporting the public API
+    fn generate_library(model: &IRModel, has_queries: bool) -> String {
+        let context = model.bounded_context.name.to_snake_case();
+        let mut out = format!(
+            "/// Typed client for the {} bounded context\nlibrary;\n\n",
+            model.bounded_context.name
+        );
+        out.push_str(&format!("export 'src/client/{}_client.dart';\n", context));
+        out.push_str(&format!(
+            "export 'src/commands/{}_commands.dart';\n",
+            context
+        ));
+        out.push_str(&format!("export 'src/events/{}_events.dart';\n", context));
+        if has_queries {
+            out.push_str(&format!("export 'src/queries/{}_queries.dart';\n", context));
+        }
+        out.push_str(&format!("export 'src/types/{}.dart';\n", context));
+        out
+    }
+
+    /// Value objects and aggregates
+    fn generate_types(model: &IRModel) -> Result<String> {
+        let types = DartTypes::new(model);
+        let mut items = Vec::new();
+
+        for (name, vo) in &model.value_objects {
+            items.push(match vo.vo_type.as_deref() {
+                Some("enum") => ClassGenerator::generate_enum(name, vo)?,
+                Some("identifier") => ClassGenerator::generate_identifier(&types, name, vo),
+                _ => ClassGenerator::generate_class(
+                    &types,
+                    &DartClass {
+                        name: name.clone(),
+                        description: vo.description.clone(),
+                        fields: vo.properties.iter().map(DartField::from_property).collect(),
+                        extends: None,
+                        type_tag: None,
+                    },
+                ),
+            });
+        }
+
+        for (name, aggregate) in &model.aggregates {
+            items.push(ClassGenerator::generate_class(
+                &types,
+                &DartClass {
+                    name: name.clone(),
+                    description: aggregate.description.clone(),
+                    fields: aggregate
+                        .root_entity
+                        .properties
+                        .iter()
+                        .map(DartField::from_property)
+                        .collect(),
+                    extends: None,
+                    type_tag: None,
+                },
+            ));
+        }
+
+        Ok(Self::dart_file(&[], &items))
+    }
+
+    fn generate_commands(model: &IRModel) -> String {
+        let types = DartTypes::new(model);
+        let items = model
+            .commands
+            .iter()
+            .map(|(name, command)| {
+                ClassGenerator::generate_class(
+                    &types,
+                    &DartClass {
+                        name: name.clone(),
+                        description: command.description.clone(),
+                        fields: command
+                            .payload
+                            .iter()
+                            .map(DartField::from_property)
+                            .collect(),
+                        extends: None,
+                        type_tag: None,
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Self::dart_file(&[Self::types_import(model)], &items)
+    }
+
+    fn generate_events(model: &IRModel) -> String {
+        let types = DartTypes::new(model);
+        let mut items = vec![Self::generate_domain_event(model)];
+        items.extend(model.events.iter().map(|(name, event)| {
+            ClassGenerator::generate_class(
+                &types,
+                &DartClass {
+                    name: name.clone(),
+                    description: event.description.clone(),
+                    fields: Self::event_fields(event),
+                    extends: Some("DomainEvent".to_string()),
+                    type_tag: Some(name.clone()),
+                },
+            )
+        }));
+
+        Self::dart_file(&[Self::types_import(model)], &items)
+    }
+
+    /// Event metadata followed by the payload, as in the Rust event structs
+    fn event_fields(event: &Event) -> Vec<DartField> {
+        let mut fields = vec![
+            DartField {
+                overrides: true,
+                ..DartField::new("eventId", "Uuid", false, Some("Unique event identifier"))
+            },
+            DartField {
+                overrides: true,
+                ..DartField::new(
+                    "occurredAt",
+                    "DateTime",
+                    false,
+                    Some("Timestamp when the event occurred"),
+                )
+            },
+        ];
+        if event.aggregate.is_some() {
+            fields.push(DartField::new(
+                "aggregateId",
+                "String",
+                false,
+                Some("ID of the aggregate that produced this event"),
+            ));
+        }
+        fields.extend(event.payload.iter().map(DartField::from_property));
+        fields
+    }
+
+    /// Sealed superclass of all events, decoding them by their `type` tag
+    fn generate_domain_event(model: &IRModel) -> String {
+        let mut out = format!(
+            "/// Domain event of the {} bounded context, tagged by `type`\n",
+            model.bounded_context.name
+        );
+        out.push_str("@immutable\nsealed class DomainEvent {\n  const DomainEvent();\n\n");
+        out.push_str("  factory DomainEvent.fromJson(Map<String, dynamic> json) {\n");
+        out.push_str("    return switch (json['type']) {\n");
+        for name in model.events.keys() {
+            out.push_str(&format!("      '{}' => {}.fromJson(json),\n", name, name));
+        }
+        out.push_str(
+            "      final type => throw ArgumentError.value(type, 'type', 'Unknown domain event'),\n",
+        );
+        out.push_str("    };\n  }\n\n");
+        out.push_str("  /// Unique event identifier\n  String get eventId;\n\n");
+        out.push_str("  /// Timestamp when the event occurred\n  DateTime get occurredAt;\n\n");
+        out.push_str("  Map<String, dynamic> toJson();\n}\n");
+        out
+    }
+
+    /// Read model rows, which are not defined with the domain types
+    fn generate_queries(model: &IRModel) -> Option<String> {
+        let types = DartTypes::new(model);
+        let items = model
+            .read_models
+            .values()
+            .map(|read_model| Self::generate_read_model(&types, read_model))
+            .collect::<Vec<_>>();
+
+        if items.is_empty() {
+            None
+        } else {
+            Some(Self::dart_file(&[Self::types_import(model)], &items))
+        }
+    }
+
+    /// Read model rows have only optional fields, like the Rust projection rows
+    fn generate_read_model(types: &DartTypes, read_model: &ReadModel) -> String {
+        let fields = read_model
+            .fields
+            .iter()
+            .map(|field| {
+                DartField::new(
+                    &field.name,
+                    field.field_type.as_deref().unwrap_or("Object"),
+                    true,
+                    field.description.as_deref(),
+                )
+            })
+            .collect();
+        ClassGenerator::generate_class(
+            types,
+            &DartClass {
+                name: read_model.name.clone(),
+                description: read_model.description.clone(),
+                fields,
+                extends: None,
+                type_tag: None,
+            },
+        )
+    }
+
+    fn types_import(model: &IRModel) -> String {
+        format!(
+            "../types/{}.dart",
+            model.bounded_context.name.to_snake_case()
+        )
+    }
+
+    /// Join items into a Dart file, importing the packages they use
+    fn dart_file(imports: &[String], items: &[String]) -> String {
+        let body = items.join("\n");
+        let mut packages = Vec::new();
+        if body.contains("_equality.") {
+            packages.push("package:collection/collection.dart");
+        }
+        if body.contains("@immutable") {
+            packages.push("package:meta/meta.dart");
+        }
+
+        let mut out = String::new();
+        for package in &packages {
+            out.push_str(&format!("import '{}';\n", package));
+        }
+        if !packages.is_empty() && !imports.is_empty() {
+            out.push('\n');
+        }
+        for import in imports {
+            out.push_str(&format!("import '{}';\n", import));
+        }
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        if body.contains("_equality.") {
+            out.push_str("const _equality = DeepCollectionEquality();\n\n");
+        }
+        out.push_str(&body);
+        out
+    }
+}
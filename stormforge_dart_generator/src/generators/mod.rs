@@ -0,0 +1,6 @@
+pub mod class_generator;
+pub mod client_generator;
+pub mod dart_generator;
+pub mod utils;
+
+pub use dart_generator::DartGenerator;
@@ -0,0 +1,310 @@
+use heck::{ToLowerCamelCase, ToSnakeCase};
+use stormforge_generator::generators::utils::{to_rust_type, PRIMITIVE_TYPES};
+use stormforge_generator::ir::IRModel;
+
+/// Dart reserved words that cannot be used as identifiers
+const DART_KEYWORDS: &[&str] = &[
+    "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else",
+    "enum", "extends", "false", "final", "finally", "for", "if", "in", "is", "new", "null",
+    "rethrow", "return", "super", "switch", "this", "throw", "true", "try", "var", "void", "when",
+    "while", "with",
+];
+
+/// Dart field or parameter name, escaping reserved words with a trailing `_`
+pub fn dart_name(name: &str) -> String {
+    let name = name.to_lower_camel_case();
+    if DART_KEYWORDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+/// JSON key of a field, matching the snake_case field of the Rust service
+pub fn json_key(name: &str) -> String {
+    name.to_snake_case()
+}
+
+/// `///` doc comment lines at the given indentation
+pub fn doc_comment(text: Option<&str>, indent: &str) -> String {
+    let Some(text) = text else {
+        return String::new();
+    };
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                format!("{}///\n", indent)
+            } else {
+                format!("{}/// {}\n", indent, line)
+            }
+        })
+        .collect()
+}
+
+/// IR type parsed into the shapes the Dart mapping distinguishes
+#[derive(Debug, Clone, PartialEq)]
+pub enum IrType {
+    Primitive(String),
+    Named(String),
+    List(Box<IrType>),
+    Option(Box<IrType>),
+    Paged(Box<IrType>),
+}
+
+impl IrType {
+    pub fn parse(ir_type: &str) -> Self {
+        let ir_type = ir_type.trim();
+        for prefix in ["List<", "Vec<", "Option<", "PagedResult<"] {
+            if let Some(rest) = ir_type.strip_prefix(prefix) {
+                let inner = Box::new(Self::parse(rest.strip_suffix('>').unwrap_or(rest)));
+                return match prefix {
+                    "Option<" => IrType::Option(inner),
+                    "PagedResult<" => IrType::Paged(inner),
+                    _ => IrType::List(inner),
+                };
+            }
+        }
+        if PRIMITIVE_TYPES.contains(&ir_type) {
+            IrType::Primitive(ir_type.to_string())
+        } else {
+            // Same resolution as the Rust generator, e.g. `CreateOrderItem` is `OrderItem`
+            IrType::Named(to_rust_type(ir_type))
+        }
+    }
+}
+
+/// How a named type is represented in Dart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NamedKind {
+    /// Identifier value object, serialized as its underlying value
+    Identifier,
+    /// Enum value object, serialized as the variant name
+    Enum,
+    /// Class serialized as a JSON object
+    Class,
+    /// Type the IR does not define, kept as raw JSON
+    Unknown,
+}
+
+/// Maps IR types to Dart types and JSON conversions
+pub struct DartTypes<'a> {
+    model: &'a IRModel,
+}
+
+impl<'a> DartTypes<'a> {
+    pub fn new(model: &'a IRModel) -> Self {
+        Self { model }
+    }
+
+    pub fn kind(&self, name: &str) -> NamedKind {
+        if let Some(vo) = self.model.value_objects.get(name) {
+            return match vo.vo_type.as_deref() {
+                Some("identifier") => NamedKind::Identifier,
+                Some("enum") => NamedKind::Enum,
+                _ => NamedKind::Class,
+            };
+        }
+        if self.model.aggregates.contains_key(name) || self.model.read_models.contains_key(name) {
+            NamedKind::Class
+        } else {
+            NamedKind::Unknown
+        }
+    }
+
+    /// Dart type of an IR type
+    pub fn dart_type(&self, ir_type: &IrType) -> String {
+        match ir_type {
+            IrType::Primitive(name) => match name.as_str() {
+                "Integer" => "int",
+                "Boolean" => "bool",
+                "DateTime" | "Date" => "DateTime",
+                // Decimals are serialized as strings to keep their precision
+                _ => "String",
+            }
+            .to_string(),
+            IrType::Named(name) => match self.kind(name) {
+                NamedKind::Unknown => "Object?".to_string(),
+                _ => name.clone(),
+            },
+            IrType::List(item) => format!("List<{}>", self.dart_type(item)),
+            IrType::Option(inner) => Self::nullable(self.dart_type(inner)),
+            IrType::Paged(item) => format!("PagedResult<{}>", self.dart_type(item)),
+        }
+    }
+
+    /// Dart type of a field, nullable when the field is optional
+    pub fn field_type(&self, ir_type: &IrType, nullable: bool) -> String {
+        let dart_type = self.dart_type(ir_type);
+        if nullable {
+            Self::nullable(dart_type)
+        } else {
+            dart_type
+        }
+    }
+
+    fn nullable(dart_type: String) -> String {
+        if dart_type.ends_with('?') {
+            dart_type
+        } else {
+            format!("{}?", dart_type)
+        }
+    }
+
+    /// Expression decoding the JSON value `json` into the Dart type
+    pub fn decode(&self, ir_type: &IrType, json: &str) -> String {
+        match ir_type {
+            IrType::Primitive(name) => match name.as_str() {
+                "Integer" => format!("({} as num).toInt()", json),
+                "Boolean" => format!("{} as bool", json),
+                "DateTime" | "Date" => format!("DateTime.parse({} as String)", json),
+                "Decimal" => format!("{}.toString()", json),
+                _ => format!("{} as String", json),
+            },
+            IrType::Named(name) => match self.kind(name) {
+                NamedKind::Identifier | NamedKind::Enum => format!("{}.fromJson({})", name, json),
+                NamedKind::Class => format!("{}.fromJson({} as Map<String, dynamic>)", name, json),
+                NamedKind::Unknown => json.to_string(),
+            },
+            IrType::List(item) => format!(
+                "({} as List<dynamic>).map((item) => {}).toList()",
+                json,
+                self.decode(item, "item")
+            ),
+            IrType::Option(inner) => self.decode_nullable(inner, json),
+            IrType::Paged(item) => format!(
+                "PagedResult.fromJson({} as Map<String, dynamic>, (item) => {})",
+                json,
+                self.decode(item, "item")
+            ),
+        }
+    }
+
+    /// Dart expression of a sample value, `None` for types built from JSON objects
+    pub fn sample(&self, ir_type: &IrType) -> Option<String> {
+        match ir_type {
+            IrType::Primitive(name) => Some(
+                match name.as_str() {
+                    "Integer" => "1",
+                    "Boolean" => "true",
+                    "DateTime" | "Date" => "DateTime.utc(2024, 1, 1)",
+                    "Decimal" => "'1'",
+                    _ => "'sample'",
+                }
+                .to_string(),
+            ),
+            IrType::Named(name) => match self.kind(name) {
+                NamedKind::Identifier => {
+                    let vo = &self.model.value_objects[name];
+                    let underlying =
+                        IrType::parse(vo.underlying_type.as_deref().unwrap_or("String"));
+                    Some(format!("{}({})", name, self.sample(&underlying)?))
+                }
+                NamedKind::Enum => Some(format!("{}.values.first", name)),
+                NamedKind::Class | NamedKind::Unknown => None,
+            },
+            IrType::List(item) => Some(format!("<{}>[]", self.dart_type(item))),
+            IrType::Option(_) => Some("null".to_string()),
+            IrType::Paged(_) => None,
+        }
+    }
+
+    /// Like `decode`, mapping JSON `null` to `null`
+    pub fn decode_nullable(&self, ir_type: &IrType, json: &str) -> String {
+        let decoded = self.decode(ir_type, json);
+        if decoded == json {
+            decoded
+        } else {
+            format!("{} == null ? null : {}", json, decoded)
+        }
+    }
+
+    /// Expression encoding the Dart value `value` as JSON
+    pub fn encode(&self, ir_type: &IrType, value: &str) -> String {
+        match ir_type {
+            IrType::Primitive(name) => match name.as_str() {
+                "DateTime" => format!("{}.toUtc().toIso8601String()", value),
+                "Date" => format!("{}.toIso8601String().substring(0, 10)", value),
+                _ => value.to_string(),
+            },
+            IrType::Named(name) => match self.kind(name) {
+                NamedKind::Unknown => value.to_string(),
+                _ => format!("{}.toJson()", value),
+            },
+            IrType::List(item) => {
+                let encoded = self.encode(item, "item");
+                if encoded == "item" {
+                    value.to_string()
+                } else {
+                    format!("{}.map((item) => {}).toList()", value, encoded)
+                }
+            }
+            IrType::Option(inner) => self.encode_nullable(inner, value),
+            IrType::Paged(item) => {
+                format!("{}.toJson((item) => {})", value, self.encode(item, "item"))
+            }
+        }
+    }
+
+    /// Like `encode` for a nullable value, relying on null-aware short-circuiting
+    pub fn encode_nullable(&self, ir_type: &IrType, value: &str) -> String {
+        let encoded = self.encode(ir_type, value);
+        match encoded.strip_prefix(&format!("{}.", value)) {
+            Some(rest) => format!("{}?.{}", value, rest),
+            None => encoded,
+        }
+    }
+
+    /// Whether values of the type need deep equality (lists and raw JSON)
+    pub fn needs_deep_equality(&self, ir_type: &IrType) -> bool {
+        match ir_type {
+            IrType::List(_) => true,
+            IrType::Option(inner) => self.needs_deep_equality(inner),
+            IrType::Named(name) => self.kind(name) == NamedKind::Unknown,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stormforge_generator::ir::IRParser;
+
+    #[test]
+    fn test_json_conversions_follow_serde_layout() {
+        let model = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "test.order"
+value_objects:
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "Paid"
+"#,
+        )
+        .unwrap();
+        let types = DartTypes::new(&model);
+        let statuses = IrType::parse("List<OrderStatus>");
+
+        assert_eq!(types.dart_type(&statuses), "List<OrderStatus>");
+        assert_eq!(
+            types.decode(&statuses, "json"),
+            "(json as List<dynamic>).map((item) => OrderStatus.fromJson(item)).toList()"
+        );
+        assert_eq!(
+            types.encode_nullable(&statuses, "statuses"),
+            "statuses?.map((item) => item.toJson()).toList()"
+        );
+        assert_eq!(types.dart_type(&IrType::parse("CustomerInfo")), "Object?");
+        assert_eq!(
+            types.sample(&IrType::parse("OrderStatus")).as_deref(),
+            Some("OrderStatus.values.first")
+        );
+        assert_eq!(types.sample(&IrType::parse("CustomerInfo")), None);
+        assert_eq!(dart_name("default"), "default_");
+    }
+}
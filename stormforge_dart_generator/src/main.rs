@@ -0,0 +1,61 @@
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use stormforge_generator::ir::IRParser;
+
+mod generators;
+
+use generators::DartGenerator;
+
+#[derive(Parser)]
+#[command(name = "stormforge-dart-generator")]
+#[command(about = "StormForge code generator for Dart client packages", long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Generate a Dart package from an IR file
+    Generate {
+        /// Input IR YAML file
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// Output directory for the generated package
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Package name (defaults to `<context>_service`)
+        #[arg(short, long)]
+        package: Option<String>,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Commands::Generate {
+            input,
+            output,
+            package,
+        } => {
+            println!("📄 Reading IR file: {}", input.display());
+
+            let model = IRParser::parse_file(&input)?;
+
+            println!("✅ IR file parsed successfully");
+            println!("   Bounded Context: {}", model.bounded_context.name);
+
+            let mut generator = DartGenerator::new(output.to_str().unwrap().to_string());
+            if let Some(package) = package {
+                generator = generator.with_package_name(package);
+            }
+            generator.generate(&model)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+/// Typed client for the Inventory bounded context
+library;
+
+export 'src/client/inventory_client.dart';
+export 'src/commands/inventory_commands.dart';
+export 'src/events/inventory_events.dart';
+export 'src/types/inventory.dart';
@@ -0,0 +1,168 @@
+import 'dart:convert';
+
+import 'package:http/http.dart' as http;
+
+import '../commands/inventory_commands.dart';
+import '../types/inventory.dart';
+
+/// Error response of the Inventory service
+class ApiException implements Exception {
+  const ApiException(this.statusCode, this.code, this.message);
+
+  final int statusCode;
+
+  /// Error code, such as `VALIDATION_ERROR`
+  final String code;
+
+  final String message;
+
+  @override
+  String toString() => 'ApiException($statusCode, $code: $message)';
+}
+
+/// HTTP client for the Inventory service
+class InventoryService {
+  InventoryService({required String baseUrl, http.Client? httpClient})
+      : _transport = _Transport(Uri.parse(baseUrl), httpClient ?? http.Client());
+
+  final _Transport _transport;
+
+  /// Command endpoints
+  late final InventoryCommands commands = InventoryCommands._(_transport);
+
+  /// Query endpoints
+  late final InventoryQueries queries = InventoryQueries._(_transport);
+
+  /// Close the underlying HTTP client
+  void close() => _transport.client.close();
+}
+
+/// Command endpoints of the Inventory service
+class InventoryCommands {
+  InventoryCommands._(this._transport);
+
+  final _Transport _transport;
+
+  /// Add stock to inventory
+  Future<String> addStock(AddStock command) async {
+    final body = await _transport.post('/api/add-stock', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Reserve stock for an order
+  Future<String> reserveStock(ReserveStock command) async {
+    final body = await _transport.post('/api/reserve-stock', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Release reserved stock
+  Future<String> releaseStock(ReleaseStock command) async {
+    final body = await _transport.post('/api/release-stock', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Deduct reserved stock (when order ships)
+  Future<String> deductStock(DeductStock command) async {
+    final body = await _transport.post('/api/deduct-stock', command.toJson());
+    return _data(body) as String;
+  }
+}
+
+/// Query endpoints of the Inventory service
+class InventoryQueries {
+  InventoryQueries._(this._transport);
+
+  final _Transport _transport;
+
+  /// Get product by ID
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `Product?`, which the generated service does not return yet.
+  Future<String> getProduct({required ProductId productId}) async {
+    final body = await _transport.get('/api/get-product', {
+      'product_id': productId.toJson(),
+    });
+    return _data(body) as String;
+  }
+
+  /// Get product by SKU
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `Product?`, which the generated service does not return yet.
+  Future<String> getProductBySku({required String sku}) async {
+    final body = await _transport.get('/api/get-product-by-sku', {
+      'sku': sku,
+    });
+    return _data(body) as String;
+  }
+
+  /// Check if quantity is available
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `bool`, which the generated service does not return yet.
+  Future<String> checkAvailability({required ProductId productId, required int quantity}) async {
+    final body = await _transport.get('/api/check-availability', {
+      'product_id': productId.toJson(),
+      'quantity': quantity,
+    });
+    return _data(body) as String;
+  }
+
+  /// Get products below reorder level
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `List<Product>`, which the generated service does not return yet.
+  Future<String> getLowStockProducts() async {
+    final body = await _transport.get('/api/get-low-stock-products');
+    return _data(body) as String;
+  }
+}
+
+/// Payload of the `{"data": ...}` envelope of command and query responses
+Object? _data(Object? body) => (body as Map<String, dynamic>)['data'];
+
+class _Transport {
+  _Transport(this.baseUrl, this.client);
+
+  final Uri baseUrl;
+
+  final http.Client client;
+
+  Future<Object?> get(String path, [Map<String, Object?> query = const {}]) async {
+    final parameters = {
+      for (final entry in query.entries)
+        if (entry.value != null) entry.key: '${entry.value}',
+    };
+    final uri = parameters.isEmpty ? _uri(path) : _uri(path).replace(queryParameters: parameters);
+    return _decode(await client.get(uri));
+  }
+
+  Future<Object?> post(String path, Map<String, dynamic> body) async {
+    final response = await client.post(
+      _uri(path),
+      headers: {'content-type': 'application/json'},
+      body: jsonEncode(body),
+    );
+    return _decode(response);
+  }
+
+  Uri _uri(String path) {
+    final base = baseUrl.path.endsWith('/')
+        ? baseUrl.path.substring(0, baseUrl.path.length - 1)
+        : baseUrl.path;
+    return baseUrl.replace(path: '$base$path');
+  }
+
+  Object? _decode(http.Response response) {
+    final body = response.body.isEmpty ? null : jsonDecode(response.body);
+    if (response.statusCode >= 400) {
+      final error = body is Map<String, dynamic> ? body : const <String, dynamic>{};
+      throw ApiException(
+        response.statusCode,
+        error['code'] as String? ?? 'HTTP_${response.statusCode}',
+        error['message'] as String? ?? response.reasonPhrase ?? '',
+      );
+    }
+    return body;
+  }
+}
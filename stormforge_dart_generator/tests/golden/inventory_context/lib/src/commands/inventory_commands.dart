@@ -0,0 +1,168 @@
+import 'package:meta/meta.dart';
+
+import '../types/inventory.dart';
+
+/// Add stock to inventory
+@immutable
+class AddStock {
+  const AddStock({
+    required this.productId,
+    required this.quantity,
+  });
+
+  factory AddStock.fromJson(Map<String, dynamic> json) {
+    return AddStock(
+      productId: ProductId.fromJson(json['product_id']),
+      quantity: (json['quantity'] as num).toInt(),
+    );
+  }
+
+  final ProductId productId;
+
+  final int quantity;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'product_id': productId.toJson(),
+      'quantity': quantity,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AddStock &&
+          productId == other.productId &&
+          quantity == other.quantity;
+
+  @override
+  int get hashCode => Object.hashAll([
+        productId,
+        quantity,
+      ]);
+
+  @override
+  String toString() => 'AddStock(productId: $productId, quantity: $quantity)';
+}
+
+/// Reserve stock for an order
+@immutable
+class ReserveStock {
+  const ReserveStock({
+    required this.productId,
+    required this.orderId,
+    required this.quantity,
+  });
+
+  factory ReserveStock.fromJson(Map<String, dynamic> json) {
+    return ReserveStock(
+      productId: ProductId.fromJson(json['product_id']),
+      orderId: OrderId.fromJson(json['order_id']),
+      quantity: (json['quantity'] as num).toInt(),
+    );
+  }
+
+  final ProductId productId;
+
+  final OrderId orderId;
+
+  final int quantity;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'product_id': productId.toJson(),
+      'order_id': orderId.toJson(),
+      'quantity': quantity,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReserveStock &&
+          productId == other.productId &&
+          orderId == other.orderId &&
+          quantity == other.quantity;
+
+  @override
+  int get hashCode => Object.hashAll([
+        productId,
+        orderId,
+        quantity,
+      ]);
+
+  @override
+  String toString() => 'ReserveStock(productId: $productId, orderId: $orderId, quantity: $quantity)';
+}
+
+/// Release reserved stock
+@immutable
+class ReleaseStock {
+  const ReleaseStock({
+    required this.reservationId,
+  });
+
+  factory ReleaseStock.fromJson(Map<String, dynamic> json) {
+    return ReleaseStock(
+      reservationId: ReservationId.fromJson(json['reservation_id']),
+    );
+  }
+
+  final ReservationId reservationId;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'reservation_id': reservationId.toJson(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReleaseStock &&
+          reservationId == other.reservationId;
+
+  @override
+  int get hashCode => Object.hashAll([
+        reservationId,
+      ]);
+
+  @override
+  String toString() => 'ReleaseStock(reservationId: $reservationId)';
+}
+
+/// Deduct reserved stock (when order ships)
+@immutable
+class DeductStock {
+  const DeductStock({
+    required this.reservationId,
+  });
+
+  factory DeductStock.fromJson(Map<String, dynamic> json) {
+    return DeductStock(
+      reservationId: ReservationId.fromJson(json['reservation_id']),
+    );
+  }
+
+  final ReservationId reservationId;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'reservation_id': reservationId.toJson(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DeductStock &&
+          reservationId == other.reservationId;
+
+  @override
+  int get hashCode => Object.hashAll([
+        reservationId,
+      ]);
+
+  @override
+  String toString() => 'DeductStock(reservationId: $reservationId)';
+}
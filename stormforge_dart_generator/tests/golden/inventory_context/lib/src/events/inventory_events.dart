@@ -0,0 +1,462 @@
+import 'package:meta/meta.dart';
+
+import '../types/inventory.dart';
+
+/// Domain event of the Inventory bounded context, tagged by `type`
+@immutable
+sealed class DomainEvent {
+  const DomainEvent();
+
+  factory DomainEvent.fromJson(Map<String, dynamic> json) {
+    return switch (json['type']) {
+      'StockAdded' => StockAdded.fromJson(json),
+      'StockReserved' => StockReserved.fromJson(json),
+      'StockReleased' => StockReleased.fromJson(json),
+      'StockDeducted' => StockDeducted.fromJson(json),
+      'LowStockAlert' => LowStockAlert.fromJson(json),
+      final type => throw ArgumentError.value(type, 'type', 'Unknown domain event'),
+    };
+  }
+
+  /// Unique event identifier
+  String get eventId;
+
+  /// Timestamp when the event occurred
+  DateTime get occurredAt;
+
+  Map<String, dynamic> toJson();
+}
+
+/// Stock has been added to inventory
+final class StockAdded extends DomainEvent {
+  const StockAdded({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.productId,
+    required this.quantity,
+    required this.newAvailableQuantity,
+    required this.addedAt,
+  });
+
+  factory StockAdded.fromJson(Map<String, dynamic> json) {
+    return StockAdded(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      productId: ProductId.fromJson(json['product_id']),
+      quantity: (json['quantity'] as num).toInt(),
+      newAvailableQuantity: (json['new_available_quantity'] as num).toInt(),
+      addedAt: DateTime.parse(json['added_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final ProductId productId;
+
+  final int quantity;
+
+  final int newAvailableQuantity;
+
+  final DateTime addedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'StockAdded',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'product_id': productId.toJson(),
+      'quantity': quantity,
+      'new_available_quantity': newAvailableQuantity,
+      'added_at': addedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StockAdded &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          productId == other.productId &&
+          quantity == other.quantity &&
+          newAvailableQuantity == other.newAvailableQuantity &&
+          addedAt == other.addedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        productId,
+        quantity,
+        newAvailableQuantity,
+        addedAt,
+      ]);
+
+  @override
+  String toString() => 'StockAdded(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, productId: $productId, quantity: $quantity, newAvailableQuantity: $newAvailableQuantity, addedAt: $addedAt)';
+}
+
+/// Stock has been reserved for an order
+final class StockReserved extends DomainEvent {
+  const StockReserved({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.productId,
+    required this.orderId,
+    required this.reservationId,
+    required this.quantity,
+    required this.reservedAt,
+  });
+
+  factory StockReserved.fromJson(Map<String, dynamic> json) {
+    return StockReserved(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      productId: ProductId.fromJson(json['product_id']),
+      orderId: OrderId.fromJson(json['order_id']),
+      reservationId: ReservationId.fromJson(json['reservation_id']),
+      quantity: (json['quantity'] as num).toInt(),
+      reservedAt: DateTime.parse(json['reserved_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final ProductId productId;
+
+  final OrderId orderId;
+
+  final ReservationId reservationId;
+
+  final int quantity;
+
+  final DateTime reservedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'StockReserved',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'product_id': productId.toJson(),
+      'order_id': orderId.toJson(),
+      'reservation_id': reservationId.toJson(),
+      'quantity': quantity,
+      'reserved_at': reservedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StockReserved &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          productId == other.productId &&
+          orderId == other.orderId &&
+          reservationId == other.reservationId &&
+          quantity == other.quantity &&
+          reservedAt == other.reservedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        productId,
+        orderId,
+        reservationId,
+        quantity,
+        reservedAt,
+      ]);
+
+  @override
+  String toString() => 'StockReserved(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, productId: $productId, orderId: $orderId, reservationId: $reservationId, quantity: $quantity, reservedAt: $reservedAt)';
+}
+
+/// Reserved stock has been released
+final class StockReleased extends DomainEvent {
+  const StockReleased({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.productId,
+    required this.reservationId,
+    required this.quantity,
+    required this.releasedAt,
+  });
+
+  factory StockReleased.fromJson(Map<String, dynamic> json) {
+    return StockReleased(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      productId: ProductId.fromJson(json['product_id']),
+      reservationId: ReservationId.fromJson(json['reservation_id']),
+      quantity: (json['quantity'] as num).toInt(),
+      releasedAt: DateTime.parse(json['released_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final ProductId productId;
+
+  final ReservationId reservationId;
+
+  final int quantity;
+
+  final DateTime releasedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'StockReleased',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'product_id': productId.toJson(),
+      'reservation_id': reservationId.toJson(),
+      'quantity': quantity,
+      'released_at': releasedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StockReleased &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          productId == other.productId &&
+          reservationId == other.reservationId &&
+          quantity == other.quantity &&
+          releasedAt == other.releasedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        productId,
+        reservationId,
+        quantity,
+        releasedAt,
+      ]);
+
+  @override
+  String toString() => 'StockReleased(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, productId: $productId, reservationId: $reservationId, quantity: $quantity, releasedAt: $releasedAt)';
+}
+
+/// Stock has been deducted (shipped)
+final class StockDeducted extends DomainEvent {
+  const StockDeducted({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.productId,
+    required this.reservationId,
+    required this.quantity,
+    required this.deductedAt,
+  });
+
+  factory StockDeducted.fromJson(Map<String, dynamic> json) {
+    return StockDeducted(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      productId: ProductId.fromJson(json['product_id']),
+      reservationId: ReservationId.fromJson(json['reservation_id']),
+      quantity: (json['quantity'] as num).toInt(),
+      deductedAt: DateTime.parse(json['deducted_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final ProductId productId;
+
+  final ReservationId reservationId;
+
+  final int quantity;
+
+  final DateTime deductedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'StockDeducted',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'product_id': productId.toJson(),
+      'reservation_id': reservationId.toJson(),
+      'quantity': quantity,
+      'deducted_at': deductedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StockDeducted &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          productId == other.productId &&
+          reservationId == other.reservationId &&
+          quantity == other.quantity &&
+          deductedAt == other.deductedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        productId,
+        reservationId,
+        quantity,
+        deductedAt,
+      ]);
+
+  @override
+  String toString() => 'StockDeducted(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, productId: $productId, reservationId: $reservationId, quantity: $quantity, deductedAt: $deductedAt)';
+}
+
+/// Stock has fallen below reorder level
+final class LowStockAlert extends DomainEvent {
+  const LowStockAlert({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.productId,
+    required this.sku,
+    required this.currentQuantity,
+    required this.reorderLevel,
+    required this.alertedAt,
+  });
+
+  factory LowStockAlert.fromJson(Map<String, dynamic> json) {
+    return LowStockAlert(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      productId: ProductId.fromJson(json['product_id']),
+      sku: json['sku'] as String,
+      currentQuantity: (json['current_quantity'] as num).toInt(),
+      reorderLevel: (json['reorder_level'] as num).toInt(),
+      alertedAt: DateTime.parse(json['alerted_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final ProductId productId;
+
+  final String sku;
+
+  final int currentQuantity;
+
+  final int reorderLevel;
+
+  final DateTime alertedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'LowStockAlert',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'product_id': productId.toJson(),
+      'sku': sku,
+      'current_quantity': currentQuantity,
+      'reorder_level': reorderLevel,
+      'alerted_at': alertedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LowStockAlert &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          productId == other.productId &&
+          sku == other.sku &&
+          currentQuantity == other.currentQuantity &&
+          reorderLevel == other.reorderLevel &&
+          alertedAt == other.alertedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        productId,
+        sku,
+        currentQuantity,
+        reorderLevel,
+        alertedAt,
+      ]);
+
+  @override
+  String toString() => 'LowStockAlert(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, productId: $productId, sku: $sku, currentQuantity: $currentQuantity, reorderLevel: $reorderLevel, alertedAt: $alertedAt)';
+}
@@ -0,0 +1,139 @@
+import 'package:meta/meta.dart';
+
+@immutable
+class ProductId {
+  const ProductId(this.value);
+
+  factory ProductId.fromJson(Object? json) => ProductId(json as String);
+
+  final String value;
+
+  String toJson() => value;
+
+  @override
+  bool operator ==(Object other) => other is ProductId && value == other.value;
+
+  @override
+  int get hashCode => value.hashCode;
+
+  @override
+  String toString() => '$value';
+}
+
+@immutable
+class OrderId {
+  const OrderId(this.value);
+
+  factory OrderId.fromJson(Object? json) => OrderId(json as String);
+
+  final String value;
+
+  String toJson() => value;
+
+  @override
+  bool operator ==(Object other) => other is OrderId && value == other.value;
+
+  @override
+  int get hashCode => value.hashCode;
+
+  @override
+  String toString() => '$value';
+}
+
+@immutable
+class ReservationId {
+  const ReservationId(this.value);
+
+  factory ReservationId.fromJson(Object? json) => ReservationId(json as String);
+
+  final String value;
+
+  String toJson() => value;
+
+  @override
+  bool operator ==(Object other) => other is ReservationId && value == other.value;
+
+  @override
+  int get hashCode => value.hashCode;
+
+  @override
+  String toString() => '$value';
+}
+
+/// Product inventory aggregate
+@immutable
+class Product {
+  const Product({
+    required this.id,
+    required this.sku,
+    required this.name,
+    required this.availableQuantity,
+    required this.reservedQuantity,
+    required this.reorderLevel,
+    required this.updatedAt,
+  });
+
+  factory Product.fromJson(Map<String, dynamic> json) {
+    return Product(
+      id: ProductId.fromJson(json['id']),
+      sku: json['sku'] as String,
+      name: json['name'] as String,
+      availableQuantity: (json['available_quantity'] as num).toInt(),
+      reservedQuantity: (json['reserved_quantity'] as num).toInt(),
+      reorderLevel: (json['reorder_level'] as num).toInt(),
+      updatedAt: DateTime.parse(json['updated_at'] as String),
+    );
+  }
+
+  final ProductId id;
+
+  final String sku;
+
+  final String name;
+
+  final int availableQuantity;
+
+  final int reservedQuantity;
+
+  final int reorderLevel;
+
+  final DateTime updatedAt;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'id': id.toJson(),
+      'sku': sku,
+      'name': name,
+      'available_quantity': availableQuantity,
+      'reserved_quantity': reservedQuantity,
+      'reorder_level': reorderLevel,
+      'updated_at': updatedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Product &&
+          id == other.id &&
+          sku == other.sku &&
+          name == other.name &&
+          availableQuantity == other.availableQuantity &&
+          reservedQuantity == other.reservedQuantity &&
+          reorderLevel == other.reorderLevel &&
+          updatedAt == other.updatedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        id,
+        sku,
+        name,
+        availableQuantity,
+        reservedQuantity,
+        reorderLevel,
+        updatedAt,
+      ]);
+
+  @override
+  String toString() => 'Product(id: $id, sku: $sku, name: $name, availableQuantity: $availableQuantity, reservedQuantity: $reservedQuantity, reorderLevel: $reorderLevel, updatedAt: $updatedAt)';
+}
@@ -0,0 +1,40 @@
+import 'dart:convert';
+
+import 'package:http/http.dart' as http;
+import 'package:http/testing.dart';
+import 'package:inventory_service/inventory_service.dart';
+import 'package:test/test.dart';
+
+/// Body the generated Rust service answers its query routes with
+const queryResponse = {'data': 'Query result'};
+
+void main() {
+  late InventoryService service;
+
+  setUp(() {
+    service = InventoryService(
+      baseUrl: 'http://localhost:3000',
+      httpClient: MockClient((request) async => http.Response(jsonEncode(queryResponse), 200)),
+    );
+  });
+
+  tearDown(() => service.close());
+
+  group('queries', () {
+    test('getProduct returns the response data', () async {
+      expect(await service.queries.getProduct(productId: ProductId('sample')), 'Query result');
+    });
+
+    test('getProductBySku returns the response data', () async {
+      expect(await service.queries.getProductBySku(sku: 'sample'), 'Query result');
+    });
+
+    test('checkAvailability returns the response data', () async {
+      expect(await service.queries.checkAvailability(productId: ProductId('sample'), quantity: 1), 'Query result');
+    });
+
+    test('getLowStockProducts returns the response data', () async {
+      expect(await service.queries.getLowStockProducts(), 'Query result');
+    });
+  });
+}
@@ -0,0 +1,7 @@
+/// Typed client for the Leave bounded context
+library;
+
+export 'src/client/leave_client.dart';
+export 'src/commands/leave_commands.dart';
+export 'src/events/leave_events.dart';
+export 'src/types/leave.dart';
@@ -0,0 +1,173 @@
+import 'dart:convert';
+
+import 'package:http/http.dart' as http;
+
+import '../commands/leave_commands.dart';
+import '../types/leave.dart';
+
+/// Error response of the Leave service
+class ApiException implements Exception {
+  const ApiException(this.statusCode, this.code, this.message);
+
+  final int statusCode;
+
+  /// Error code, such as `VALIDATION_ERROR`
+  final String code;
+
+  final String message;
+
+  @override
+  String toString() => 'ApiException($statusCode, $code: $message)';
+}
+
+/// HTTP client for the Leave service
+class LeaveService {
+  LeaveService({required String baseUrl, http.Client? httpClient})
+      : _transport = _Transport(Uri.parse(baseUrl), httpClient ?? http.Client());
+
+  final _Transport _transport;
+
+  /// Command endpoints
+  late final LeaveCommands commands = LeaveCommands._(_transport);
+
+  /// Query endpoints
+  late final LeaveQueries queries = LeaveQueries._(_transport);
+
+  /// Close the underlying HTTP client
+  void close() => _transport.client.close();
+}
+
+/// Command endpoints of the Leave service
+class LeaveCommands {
+  LeaveCommands._(this._transport);
+
+  final _Transport _transport;
+
+  /// Submit a leave request
+  Future<String> requestLeave(RequestLeave command) async {
+    final body = await _transport.post('/api/request-leave', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Approve a leave request
+  Future<String> approveLeave(ApproveLeave command) async {
+    final body = await _transport.post('/api/approve-leave', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Reject a leave request
+  Future<String> rejectLeave(RejectLeave command) async {
+    final body = await _transport.post('/api/reject-leave', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Cancel a leave request
+  Future<String> cancelLeave(CancelLeave command) async {
+    final body = await _transport.post('/api/cancel-leave', command.toJson());
+    return _data(body) as String;
+  }
+}
+
+/// Query endpoints of the Leave service
+class LeaveQueries {
+  LeaveQueries._(this._transport);
+
+  final _Transport _transport;
+
+  /// Get leave request by ID
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `LeaveRequest?`, which the generated service does not return yet.
+  Future<String> getLeaveRequest({required LeaveRequestId leaveRequestId}) async {
+    final body = await _transport.get('/api/get-leave-request', {
+      'leave_request_id': leaveRequestId.toJson(),
+    });
+    return _data(body) as String;
+  }
+
+  /// Get all leave requests for an employee
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `List<LeaveRequest>`, which the generated service does not return yet.
+  Future<String> getEmployeeLeaves({required EmployeeId employeeId, int? year, LeaveStatus? status}) async {
+    final body = await _transport.get('/api/get-employee-leaves', {
+      'employee_id': employeeId.toJson(),
+      'year': year,
+      'status': status?.toJson(),
+    });
+    return _data(body) as String;
+  }
+
+  /// Get pending leave requests for approval
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `List<LeaveRequest>`, which the generated service does not return yet.
+  Future<String> getPendingApprovals({required EmployeeId approverId}) async {
+    final body = await _transport.get('/api/get-pending-approvals', {
+      'approver_id': approverId.toJson(),
+    });
+    return _data(body) as String;
+  }
+
+  /// Get remaining leave balance for an employee
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `Object?`, which the generated service does not return yet.
+  Future<String> getLeaveBalance({required EmployeeId employeeId, required LeaveType leaveType, int? year}) async {
+    final body = await _transport.get('/api/get-leave-balance', {
+      'employee_id': employeeId.toJson(),
+      'leave_type': leaveType.toJson(),
+      'year': year,
+    });
+    return _data(body) as String;
+  }
+}
+
+/// Payload of the `{"data": ...}` envelope of command and query responses
+Object? _data(Object? body) => (body as Map<String, dynamic>)['data'];
+
+class _Transport {
+  _Transport(this.baseUrl, this.client);
+
+  final Uri baseUrl;
+
+  final http.Client client;
+
+  Future<Object?> get(String path, [Map<String, Object?> query = const {}]) async {
+    final parameters = {
+      for (final entry in query.entries)
+        if (entry.value != null) entry.key: '${entry.value}',
+    };
+    final uri = parameters.isEmpty ? _uri(path) : _uri(path).replace(queryParameters: parameters);
+    return _decode(await client.get(uri));
+  }
+
+  Future<Object?> post(String path, Map<String, dynamic> body) async {
+    final response = await client.post(
+      _uri(path),
+      headers: {'content-type': 'application/json'},
+      body: jsonEncode(body),
+    );
+    return _decode(response);
+  }
+
+  Uri _uri(String path) {
+    final base = baseUrl.path.endsWith('/')
+        ? baseUrl.path.substring(0, baseUrl.path.length - 1)
+        : baseUrl.path;
+    return baseUrl.replace(path: '$base$path');
+  }
+
+  Object? _decode(http.Response response) {
+    final body = response.body.isEmpty ? null : jsonDecode(response.body);
+    if (response.statusCode >= 400) {
+      final error = body is Map<String, dynamic> ? body : const <String, dynamic>{};
+      throw ApiException(
+        response.statusCode,
+        error['code'] as String? ?? 'HTTP_${response.statusCode}',
+        error['message'] as String? ?? response.reasonPhrase ?? '',
+      );
+    }
+    return body;
+  }
+}
@@ -0,0 +1,210 @@
+import 'package:meta/meta.dart';
+
+import '../types/leave.dart';
+
+/// Submit a leave request
+@immutable
+class RequestLeave {
+  const RequestLeave({
+    required this.employeeId,
+    required this.leaveType,
+    required this.startDate,
+    required this.endDate,
+    this.reason,
+  });
+
+  factory RequestLeave.fromJson(Map<String, dynamic> json) {
+    return RequestLeave(
+      employeeId: EmployeeId.fromJson(json['employee_id']),
+      leaveType: LeaveType.fromJson(json['leave_type']),
+      startDate: DateTime.parse(json['start_date'] as String),
+      endDate: DateTime.parse(json['end_date'] as String),
+      reason: json['reason'] == null ? null : json['reason'] as String,
+    );
+  }
+
+  final EmployeeId employeeId;
+
+  final LeaveType leaveType;
+
+  final DateTime startDate;
+
+  final DateTime endDate;
+
+  final String? reason;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'employee_id': employeeId.toJson(),
+      'leave_type': leaveType.toJson(),
+      'start_date': startDate.toIso8601String().substring(0, 10),
+      'end_date': endDate.toIso8601String().substring(0, 10),
+      'reason': reason,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RequestLeave &&
+          employeeId == other.employeeId &&
+          leaveType == other.leaveType &&
+          startDate == other.startDate &&
+          endDate == other.endDate &&
+          reason == other.reason;
+
+  @override
+  int get hashCode => Object.hashAll([
+        employeeId,
+        leaveType,
+        startDate,
+        endDate,
+        reason,
+      ]);
+
+  @override
+  String toString() => 'RequestLeave(employeeId: $employeeId, leaveType: $leaveType, startDate: $startDate, endDate: $endDate, reason: $reason)';
+}
+
+/// Approve a leave request
+@immutable
+class ApproveLeave {
+  const ApproveLeave({
+    required this.leaveRequestId,
+    required this.approverId,
+    this.comment,
+  });
+
+  factory ApproveLeave.fromJson(Map<String, dynamic> json) {
+    return ApproveLeave(
+      leaveRequestId: LeaveRequestId.fromJson(json['leave_request_id']),
+      approverId: EmployeeId.fromJson(json['approver_id']),
+      comment: json['comment'] == null ? null : json['comment'] as String,
+    );
+  }
+
+  final LeaveRequestId leaveRequestId;
+
+  final EmployeeId approverId;
+
+  final String? comment;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'leave_request_id': leaveRequestId.toJson(),
+      'approver_id': approverId.toJson(),
+      'comment': comment,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApproveLeave &&
+          leaveRequestId == other.leaveRequestId &&
+          approverId == other.approverId &&
+          comment == other.comment;
+
+  @override
+  int get hashCode => Object.hashAll([
+        leaveRequestId,
+        approverId,
+        comment,
+      ]);
+
+  @override
+  String toString() => 'ApproveLeave(leaveRequestId: $leaveRequestId, approverId: $approverId, comment: $comment)';
+}
+
+/// Reject a leave request
+@immutable
+class RejectLeave {
+  const RejectLeave({
+    required this.leaveRequestId,
+    required this.approverId,
+    required this.reason,
+  });
+
+  factory RejectLeave.fromJson(Map<String, dynamic> json) {
+    return RejectLeave(
+      leaveRequestId: LeaveRequestId.fromJson(json['leave_request_id']),
+      approverId: EmployeeId.fromJson(json['approver_id']),
+      reason: json['reason'] as String,
+    );
+  }
+
+  final LeaveRequestId leaveRequestId;
+
+  final EmployeeId approverId;
+
+  final String reason;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'leave_request_id': leaveRequestId.toJson(),
+      'approver_id': approverId.toJson(),
+      'reason': reason,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RejectLeave &&
+          leaveRequestId == other.leaveRequestId &&
+          approverId == other.approverId &&
+          reason == other.reason;
+
+  @override
+  int get hashCode => Object.hashAll([
+        leaveRequestId,
+        approverId,
+        reason,
+      ]);
+
+  @override
+  String toString() => 'RejectLeave(leaveRequestId: $leaveRequestId, approverId: $approverId, reason: $reason)';
+}
+
+/// Cancel a leave request
+@immutable
+class CancelLeave {
+  const CancelLeave({
+    required this.leaveRequestId,
+    required this.employeeId,
+  });
+
+  factory CancelLeave.fromJson(Map<String, dynamic> json) {
+    return CancelLeave(
+      leaveRequestId: LeaveRequestId.fromJson(json['leave_request_id']),
+      employeeId: EmployeeId.fromJson(json['employee_id']),
+    );
+  }
+
+  final LeaveRequestId leaveRequestId;
+
+  final EmployeeId employeeId;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'leave_request_id': leaveRequestId.toJson(),
+      'employee_id': employeeId.toJson(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CancelLeave &&
+          leaveRequestId == other.leaveRequestId &&
+          employeeId == other.employeeId;
+
+  @override
+  int get hashCode => Object.hashAll([
+        leaveRequestId,
+        employeeId,
+      ]);
+
+  @override
+  String toString() => 'CancelLeave(leaveRequestId: $leaveRequestId, employeeId: $employeeId)';
+}
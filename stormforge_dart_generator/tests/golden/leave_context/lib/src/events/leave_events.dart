@@ -0,0 +1,391 @@
+import 'package:meta/meta.dart';
+
+import '../types/leave.dart';
+
+/// Domain event of the Leave bounded context, tagged by `type`
+@immutable
+sealed class DomainEvent {
+  const DomainEvent();
+
+  factory DomainEvent.fromJson(Map<String, dynamic> json) {
+    return switch (json['type']) {
+      'LeaveRequested' => LeaveRequested.fromJson(json),
+      'LeaveApproved' => LeaveApproved.fromJson(json),
+      'LeaveRejected' => LeaveRejected.fromJson(json),
+      'LeaveCancelled' => LeaveCancelled.fromJson(json),
+      final type => throw ArgumentError.value(type, 'type', 'Unknown domain event'),
+    };
+  }
+
+  /// Unique event identifier
+  String get eventId;
+
+  /// Timestamp when the event occurred
+  DateTime get occurredAt;
+
+  Map<String, dynamic> toJson();
+}
+
+/// A leave request has been submitted
+final class LeaveRequested extends DomainEvent {
+  const LeaveRequested({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.leaveRequestId,
+    required this.employeeId,
+    required this.leaveType,
+    required this.startDate,
+    required this.endDate,
+    required this.reason,
+    required this.requestedAt,
+  });
+
+  factory LeaveRequested.fromJson(Map<String, dynamic> json) {
+    return LeaveRequested(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      leaveRequestId: LeaveRequestId.fromJson(json['leave_request_id']),
+      employeeId: EmployeeId.fromJson(json['employee_id']),
+      leaveType: LeaveType.fromJson(json['leave_type']),
+      startDate: DateTime.parse(json['start_date'] as String),
+      endDate: DateTime.parse(json['end_date'] as String),
+      reason: json['reason'] as String,
+      requestedAt: DateTime.parse(json['requested_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final LeaveRequestId leaveRequestId;
+
+  final EmployeeId employeeId;
+
+  final LeaveType leaveType;
+
+  final DateTime startDate;
+
+  final DateTime endDate;
+
+  final String reason;
+
+  final DateTime requestedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'LeaveRequested',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'leave_request_id': leaveRequestId.toJson(),
+      'employee_id': employeeId.toJson(),
+      'leave_type': leaveType.toJson(),
+      'start_date': startDate.toIso8601String().substring(0, 10),
+      'end_date': endDate.toIso8601String().substring(0, 10),
+      'reason': reason,
+      'requested_at': requestedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LeaveRequested &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          leaveRequestId == other.leaveRequestId &&
+          employeeId == other.employeeId &&
+          leaveType == other.leaveType &&
+          startDate == other.startDate &&
+          endDate == other.endDate &&
+          reason == other.reason &&
+          requestedAt == other.requestedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        leaveRequestId,
+        employeeId,
+        leaveType,
+        startDate,
+        endDate,
+        reason,
+        requestedAt,
+      ]);
+
+  @override
+  String toString() => 'LeaveRequested(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, leaveRequestId: $leaveRequestId, employeeId: $employeeId, leaveType: $leaveType, startDate: $startDate, endDate: $endDate, reason: $reason, requestedAt: $requestedAt)';
+}
+
+/// Leave request has been approved
+final class LeaveApproved extends DomainEvent {
+  const LeaveApproved({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.leaveRequestId,
+    required this.employeeId,
+    required this.approverId,
+    required this.comment,
+    required this.approvedAt,
+  });
+
+  factory LeaveApproved.fromJson(Map<String, dynamic> json) {
+    return LeaveApproved(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      leaveRequestId: LeaveRequestId.fromJson(json['leave_request_id']),
+      employeeId: EmployeeId.fromJson(json['employee_id']),
+      approverId: EmployeeId.fromJson(json['approver_id']),
+      comment: json['comment'] as String,
+      approvedAt: DateTime.parse(json['approved_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final LeaveRequestId leaveRequestId;
+
+  final EmployeeId employeeId;
+
+  final EmployeeId approverId;
+
+  final String comment;
+
+  final DateTime approvedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'LeaveApproved',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'leave_request_id': leaveRequestId.toJson(),
+      'employee_id': employeeId.toJson(),
+      'approver_id': approverId.toJson(),
+      'comment': comment,
+      'approved_at': approvedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LeaveApproved &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          leaveRequestId == other.leaveRequestId &&
+          employeeId == other.employeeId &&
+          approverId == other.approverId &&
+          comment == other.comment &&
+          approvedAt == other.approvedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        leaveRequestId,
+        employeeId,
+        approverId,
+        comment,
+        approvedAt,
+      ]);
+
+  @override
+  String toString() => 'LeaveApproved(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, leaveRequestId: $leaveRequestId, employeeId: $employeeId, approverId: $approverId, comment: $comment, approvedAt: $approvedAt)';
+}
+
+/// Leave request has been rejected
+final class LeaveRejected extends DomainEvent {
+  const LeaveRejected({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.leaveRequestId,
+    required this.employeeId,
+    required this.approverId,
+    required this.reason,
+    required this.rejectedAt,
+  });
+
+  factory LeaveRejected.fromJson(Map<String, dynamic> json) {
+    return LeaveRejected(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      leaveRequestId: LeaveRequestId.fromJson(json['leave_request_id']),
+      employeeId: EmployeeId.fromJson(json['employee_id']),
+      approverId: EmployeeId.fromJson(json['approver_id']),
+      reason: json['reason'] as String,
+      rejectedAt: DateTime.parse(json['rejected_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final LeaveRequestId leaveRequestId;
+
+  final EmployeeId employeeId;
+
+  final EmployeeId approverId;
+
+  final String reason;
+
+  final DateTime rejectedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'LeaveRejected',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'leave_request_id': leaveRequestId.toJson(),
+      'employee_id': employeeId.toJson(),
+      'approver_id': approverId.toJson(),
+      'reason': reason,
+      'rejected_at': rejectedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LeaveRejected &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          leaveRequestId == other.leaveRequestId &&
+          employeeId == other.employeeId &&
+          approverId == other.approverId &&
+          reason == other.reason &&
+          rejectedAt == other.rejectedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        leaveRequestId,
+        employeeId,
+        approverId,
+        reason,
+        rejectedAt,
+      ]);
+
+  @override
+  String toString() => 'LeaveRejected(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, leaveRequestId: $leaveRequestId, employeeId: $employeeId, approverId: $approverId, reason: $reason, rejectedAt: $rejectedAt)';
+}
+
+/// Leave request has been cancelled
+final class LeaveCancelled extends DomainEvent {
+  const LeaveCancelled({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.leaveRequestId,
+    required this.employeeId,
+    required this.cancelledAt,
+  });
+
+  factory LeaveCancelled.fromJson(Map<String, dynamic> json) {
+    return LeaveCancelled(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      leaveRequestId: LeaveRequestId.fromJson(json['leave_request_id']),
+      employeeId: EmployeeId.fromJson(json['employee_id']),
+      cancelledAt: DateTime.parse(json['cancelled_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final LeaveRequestId leaveRequestId;
+
+  final EmployeeId employeeId;
+
+  final DateTime cancelledAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'LeaveCancelled',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'leave_request_id': leaveRequestId.toJson(),
+      'employee_id': employeeId.toJson(),
+      'cancelled_at': cancelledAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LeaveCancelled &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          leaveRequestId == other.leaveRequestId &&
+          employeeId == other.employeeId &&
+          cancelledAt == other.cancelledAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        leaveRequestId,
+        employeeId,
+        cancelledAt,
+      ]);
+
+  @override
+  String toString() => 'LeaveCancelled(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, leaveRequestId: $leaveRequestId, employeeId: $employeeId, cancelledAt: $cancelledAt)';
+}
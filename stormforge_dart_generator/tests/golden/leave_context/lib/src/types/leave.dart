@@ -0,0 +1,203 @@
+import 'package:meta/meta.dart';
+
+@immutable
+class LeaveRequestId {
+  const LeaveRequestId(this.value);
+
+  factory LeaveRequestId.fromJson(Object? json) => LeaveRequestId(json as String);
+
+  final String value;
+
+  String toJson() => value;
+
+  @override
+  bool operator ==(Object other) => other is LeaveRequestId && value == other.value;
+
+  @override
+  int get hashCode => value.hashCode;
+
+  @override
+  String toString() => '$value';
+}
+
+@immutable
+class EmployeeId {
+  const EmployeeId(this.value);
+
+  factory EmployeeId.fromJson(Object? json) => EmployeeId(json as String);
+
+  final String value;
+
+  String toJson() => value;
+
+  @override
+  bool operator ==(Object other) => other is EmployeeId && value == other.value;
+
+  @override
+  int get hashCode => value.hashCode;
+
+  @override
+  String toString() => '$value';
+}
+
+enum LeaveType {
+  /// Annual leave
+  annual('Annual'),
+  /// Sick leave
+  sick('Sick'),
+  /// Personal leave
+  personal('Personal'),
+  /// Maternity leave
+  maternity('Maternity'),
+  /// Paternity leave
+  paternity('Paternity'),
+  /// Bereavement leave
+  bereavement('Bereavement'),
+  /// Unpaid leave
+  unpaid('Unpaid');
+
+  const LeaveType(this.value);
+
+  /// Serialized name, the variant of the Rust enum
+  final String value;
+
+  static LeaveType fromJson(Object? json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw ArgumentError.value(json, 'json', 'Unknown LeaveType'),
+    );
+  }
+
+  String toJson() => value;
+}
+
+enum LeaveStatus {
+  /// Awaiting approval
+  pending('Pending'),
+  /// Leave approved
+  approved('Approved'),
+  /// Leave rejected
+  rejected('Rejected'),
+  /// Leave cancelled by employee
+  cancelled('Cancelled');
+
+  const LeaveStatus(this.value);
+
+  /// Serialized name, the variant of the Rust enum
+  final String value;
+
+  static LeaveStatus fromJson(Object? json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw ArgumentError.value(json, 'json', 'Unknown LeaveStatus'),
+    );
+  }
+
+  String toJson() => value;
+}
+
+/// Leave request aggregate
+@immutable
+class LeaveRequest {
+  const LeaveRequest({
+    required this.id,
+    required this.employeeId,
+    required this.leaveType,
+    required this.startDate,
+    required this.endDate,
+    this.reason,
+    required this.status,
+    this.approverId,
+    this.approvalComment,
+    required this.createdAt,
+    this.updatedAt,
+  });
+
+  factory LeaveRequest.fromJson(Map<String, dynamic> json) {
+    return LeaveRequest(
+      id: LeaveRequestId.fromJson(json['id']),
+      employeeId: EmployeeId.fromJson(json['employee_id']),
+      leaveType: LeaveType.fromJson(json['leave_type']),
+      startDate: DateTime.parse(json['start_date'] as String),
+      endDate: DateTime.parse(json['end_date'] as String),
+      reason: json['reason'] == null ? null : json['reason'] as String,
+      status: LeaveStatus.fromJson(json['status']),
+      approverId: json['approver_id'] == null ? null : EmployeeId.fromJson(json['approver_id']),
+      approvalComment: json['approval_comment'] == null ? null : json['approval_comment'] as String,
+      createdAt: DateTime.parse(json['created_at'] as String),
+      updatedAt: json['updated_at'] == null ? null : DateTime.parse(json['updated_at'] as String),
+    );
+  }
+
+  final LeaveRequestId id;
+
+  final EmployeeId employeeId;
+
+  final LeaveType leaveType;
+
+  final DateTime startDate;
+
+  final DateTime endDate;
+
+  final String? reason;
+
+  final LeaveStatus status;
+
+  final EmployeeId? approverId;
+
+  final String? approvalComment;
+
+  final DateTime createdAt;
+
+  final DateTime? updatedAt;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'id': id.toJson(),
+      'employee_id': employeeId.toJson(),
+      'leave_type': leaveType.toJson(),
+      'start_date': startDate.toIso8601String().substring(0, 10),
+      'end_date': endDate.toIso8601String().substring(0, 10),
+      'reason': reason,
+      'status': status.toJson(),
+      'approver_id': approverId?.toJson(),
+      'approval_comment': approvalComment,
+      'created_at': createdAt.toUtc().toIso8601String(),
+      'updated_at': updatedAt?.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LeaveRequest &&
+          id == other.id &&
+          employeeId == other.employeeId &&
+          leaveType == other.leaveType &&
+          startDate == other.startDate &&
+          endDate == other.endDate &&
+          reason == other.reason &&
+          status == other.status &&
+          approverId == other.approverId &&
+          approvalComment == other.approvalComment &&
+          createdAt == other.createdAt &&
+          updatedAt == other.updatedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        id,
+        employeeId,
+        leaveType,
+        startDate,
+        endDate,
+        reason,
+        status,
+        approverId,
+        approvalComment,
+        createdAt,
+        updatedAt,
+      ]);
+
+  @override
+  String toString() => 'LeaveRequest(id: $id, employeeId: $employeeId, leaveType: $leaveType, startDate: $startDate, endDate: $endDate, reason: $reason, status: $status, approverId: $approverId, approvalComment: $approvalComment, createdAt: $createdAt, updatedAt: $updatedAt)';
+}
@@ -0,0 +1,40 @@
+import 'dart:convert';
+
+import 'package:http/http.dart' as http;
+import 'package:http/testing.dart';
+import 'package:leave_service/leave_service.dart';
+import 'package:test/test.dart';
+
+/// Body the generated Rust service answers its query routes with
+const queryResponse = {'data': 'Query result'};
+
+void main() {
+  late LeaveService service;
+
+  setUp(() {
+    service = LeaveService(
+      baseUrl: 'http://localhost:3000',
+      httpClient: MockClient((request) async => http.Response(jsonEncode(queryResponse), 200)),
+    );
+  });
+
+  tearDown(() => service.close());
+
+  group('queries', () {
+    test('getLeaveRequest returns the response data', () async {
+      expect(await service.queries.getLeaveRequest(leaveRequestId: LeaveRequestId('sample')), 'Query result');
+    });
+
+    test('getEmployeeLeaves returns the response data', () async {
+      expect(await service.queries.getEmployeeLeaves(employeeId: EmployeeId('sample')), 'Query result');
+    });
+
+    test('getPendingApprovals returns the response data', () async {
+      expect(await service.queries.getPendingApprovals(approverId: EmployeeId('sample')), 'Query result');
+    });
+
+    test('getLeaveBalance returns the response data', () async {
+      expect(await service.queries.getLeaveBalance(employeeId: EmployeeId('sample'), leaveType: LeaveType.values.first), 'Query result');
+    });
+  });
+}
@@ -0,0 +1,169 @@
+import 'dart:convert';
+
+import 'package:http/http.dart' as http;
+
+import '../commands/order_commands.dart';
+import '../types/order.dart';
+
+/// Error response of the Order service
+class ApiException implements Exception {
+  const ApiException(this.statusCode, this.code, this.message);
+
+  final int statusCode;
+
+  /// Error code, such as `VALIDATION_ERROR`
+  final String code;
+
+  final String message;
+
+  @override
+  String toString() => 'ApiException($statusCode, $code: $message)';
+}
+
+/// HTTP client for the Order service
+class OrderService {
+  OrderService({required String baseUrl, http.Client? httpClient})
+      : _transport = _Transport(Uri.parse(baseUrl), httpClient ?? http.Client());
+
+  final _Transport _transport;
+
+  /// Command endpoints
+  late final OrderCommands commands = OrderCommands._(_transport);
+
+  /// Query endpoints
+  late final OrderQueries queries = OrderQueries._(_transport);
+
+  /// Close the underlying HTTP client
+  void close() => _transport.client.close();
+}
+
+/// Command endpoints of the Order service
+class OrderCommands {
+  OrderCommands._(this._transport);
+
+  final _Transport _transport;
+
+  /// Create a new order
+  Future<String> createOrder(CreateOrder command) async {
+    final body = await _transport.post('/api/create-order', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Confirm order payment
+  Future<String> confirmPayment(ConfirmPayment command) async {
+    final body = await _transport.post('/api/confirm-payment', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Ship the order
+  Future<String> shipOrder(ShipOrder command) async {
+    final body = await _transport.post('/api/ship-order', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Mark order as delivered
+  Future<String> deliverOrder(DeliverOrder command) async {
+    final body = await _transport.post('/api/deliver-order', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Cancel the order
+  Future<String> cancelOrder(CancelOrder command) async {
+    final body = await _transport.post('/api/cancel-order', command.toJson());
+    return _data(body) as String;
+  }
+}
+
+/// Query endpoints of the Order service
+class OrderQueries {
+  OrderQueries._(this._transport);
+
+  final _Transport _transport;
+
+  /// Get order by ID
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `Order?`, which the generated service does not return yet.
+  Future<String> getOrder({required OrderId orderId}) async {
+    final body = await _transport.get('/api/get-order', {
+      'order_id': orderId.toJson(),
+    });
+    return _data(body) as String;
+  }
+
+  /// List orders with filters
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `PagedResult<Order>`, which the generated service does not return yet.
+  Future<String> listOrders({CustomerId? customerId, OrderStatus? status, DateTime? fromDate, DateTime? toDate, int? page, int? pageSize}) async {
+    final body = await _transport.get('/api/list-orders', {
+      'customer_id': customerId?.toJson(),
+      'status': status?.toJson(),
+      'from_date': fromDate?.toUtc().toIso8601String(),
+      'to_date': toDate?.toUtc().toIso8601String(),
+      'page': page,
+      'page_size': pageSize,
+    });
+    return _data(body) as String;
+  }
+
+  /// Get all orders with a specific status
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `List<Order>`, which the generated service does not return yet.
+  Future<String> getOrdersByStatus({required OrderStatus status}) async {
+    final body = await _transport.get('/api/get-orders-by-status', {
+      'status': status.toJson(),
+    });
+    return _data(body) as String;
+  }
+}
+
+/// Payload of the `{"data": ...}` envelope of command and query responses
+Object? _data(Object? body) => (body as Map<String, dynamic>)['data'];
+
+class _Transport {
+  _Transport(this.baseUrl, this.client);
+
+  final Uri baseUrl;
+
+  final http.Client client;
+
+  Future<Object?> get(String path, [Map<String, Object?> query = const {}]) async {
+    final parameters = {
+      for (final entry in query.entries)
+        if (entry.value != null) entry.key: '${entry.value}',
+    };
+    final uri = parameters.isEmpty ? _uri(path) : _uri(path).replace(queryParameters: parameters);
+    return _decode(await client.get(uri));
+  }
+
+  Future<Object?> post(String path, Map<String, dynamic> body) async {
+    final response = await client.post(
+      _uri(path),
+      headers: {'content-type': 'application/json'},
+      body: jsonEncode(body),
+    );
+    return _decode(response);
+  }
+
+  Uri _uri(String path) {
+    final base = baseUrl.path.endsWith('/')
+        ? baseUrl.path.substring(0, baseUrl.path.length - 1)
+        : baseUrl.path;
+    return baseUrl.replace(path: '$base$path');
+  }
+
+  Object? _decode(http.Response response) {
+    final body = response.body.isEmpty ? null : jsonDecode(response.body);
+    if (response.statusCode >= 400) {
+      final error = body is Map<String, dynamic> ? body : const <String, dynamic>{};
+      throw ApiException(
+        response.statusCode,
+        error['code'] as String? ?? 'HTTP_${response.statusCode}',
+        error['message'] as String? ?? response.reasonPhrase ?? '',
+      );
+    }
+    return body;
+  }
+}
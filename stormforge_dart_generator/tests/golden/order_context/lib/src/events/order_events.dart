@@ -0,0 +1,451 @@
+import 'package:collection/collection.dart';
+import 'package:meta/meta.dart';
+
+import '../types/order.dart';
+
+const _equality = DeepCollectionEquality();
+
+/// Domain event of the Order bounded context, tagged by `type`
+@immutable
+sealed class DomainEvent {
+  const DomainEvent();
+
+  factory DomainEvent.fromJson(Map<String, dynamic> json) {
+    return switch (json['type']) {
+      'OrderCreated' => OrderCreated.fromJson(json),
+      'OrderPaid' => OrderPaid.fromJson(json),
+      'OrderShipped' => OrderShipped.fromJson(json),
+      'OrderDelivered' => OrderDelivered.fromJson(json),
+      'OrderCancelled' => OrderCancelled.fromJson(json),
+      final type => throw ArgumentError.value(type, 'type', 'Unknown domain event'),
+    };
+  }
+
+  /// Unique event identifier
+  String get eventId;
+
+  /// Timestamp when the event occurred
+  DateTime get occurredAt;
+
+  Map<String, dynamic> toJson();
+}
+
+/// Emitted when a new order is created
+final class OrderCreated extends DomainEvent {
+  const OrderCreated({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.orderId,
+    required this.customerId,
+    required this.items,
+    required this.shippingAddress,
+    required this.totalAmount,
+    required this.createdAt,
+  });
+
+  factory OrderCreated.fromJson(Map<String, dynamic> json) {
+    return OrderCreated(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      orderId: OrderId.fromJson(json['order_id']),
+      customerId: CustomerId.fromJson(json['customer_id']),
+      items: (json['items'] as List<dynamic>).map((item) => OrderItem.fromJson(item as Map<String, dynamic>)).toList(),
+      shippingAddress: Address.fromJson(json['shipping_address'] as Map<String, dynamic>),
+      totalAmount: Money.fromJson(json['total_amount'] as Map<String, dynamic>),
+      createdAt: DateTime.parse(json['created_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final OrderId orderId;
+
+  final CustomerId customerId;
+
+  final List<OrderItem> items;
+
+  final Address shippingAddress;
+
+  final Money totalAmount;
+
+  final DateTime createdAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'OrderCreated',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'order_id': orderId.toJson(),
+      'customer_id': customerId.toJson(),
+      'items': items.map((item) => item.toJson()).toList(),
+      'shipping_address': shippingAddress.toJson(),
+      'total_amount': totalAmount.toJson(),
+      'created_at': createdAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OrderCreated &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          orderId == other.orderId &&
+          customerId == other.customerId &&
+          _equality.equals(items, other.items) &&
+          shippingAddress == other.shippingAddress &&
+          totalAmount == other.totalAmount &&
+          createdAt == other.createdAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        orderId,
+        customerId,
+        _equality.hash(items),
+        shippingAddress,
+        totalAmount,
+        createdAt,
+      ]);
+
+  @override
+  String toString() => 'OrderCreated(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, orderId: $orderId, customerId: $customerId, items: $items, shippingAddress: $shippingAddress, totalAmount: $totalAmount, createdAt: $createdAt)';
+}
+
+/// Emitted when an order payment is confirmed
+final class OrderPaid extends DomainEvent {
+  const OrderPaid({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.orderId,
+    required this.paymentId,
+    required this.paidAmount,
+    required this.paidAt,
+  });
+
+  factory OrderPaid.fromJson(Map<String, dynamic> json) {
+    return OrderPaid(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      orderId: OrderId.fromJson(json['order_id']),
+      paymentId: json['payment_id'] as String,
+      paidAmount: Money.fromJson(json['paid_amount'] as Map<String, dynamic>),
+      paidAt: DateTime.parse(json['paid_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final OrderId orderId;
+
+  final String paymentId;
+
+  final Money paidAmount;
+
+  final DateTime paidAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'OrderPaid',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'order_id': orderId.toJson(),
+      'payment_id': paymentId,
+      'paid_amount': paidAmount.toJson(),
+      'paid_at': paidAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OrderPaid &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          orderId == other.orderId &&
+          paymentId == other.paymentId &&
+          paidAmount == other.paidAmount &&
+          paidAt == other.paidAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        orderId,
+        paymentId,
+        paidAmount,
+        paidAt,
+      ]);
+
+  @override
+  String toString() => 'OrderPaid(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, orderId: $orderId, paymentId: $paymentId, paidAmount: $paidAmount, paidAt: $paidAt)';
+}
+
+/// Emitted when an order is shipped
+final class OrderShipped extends DomainEvent {
+  const OrderShipped({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.orderId,
+    required this.trackingNumber,
+    required this.carrier,
+    required this.shippedAt,
+  });
+
+  factory OrderShipped.fromJson(Map<String, dynamic> json) {
+    return OrderShipped(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      orderId: OrderId.fromJson(json['order_id']),
+      trackingNumber: json['tracking_number'] as String,
+      carrier: json['carrier'] as String,
+      shippedAt: DateTime.parse(json['shipped_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final OrderId orderId;
+
+  final String trackingNumber;
+
+  final String carrier;
+
+  final DateTime shippedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'OrderShipped',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'order_id': orderId.toJson(),
+      'tracking_number': trackingNumber,
+      'carrier': carrier,
+      'shipped_at': shippedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OrderShipped &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          orderId == other.orderId &&
+          trackingNumber == other.trackingNumber &&
+          carrier == other.carrier &&
+          shippedAt == other.shippedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        orderId,
+        trackingNumber,
+        carrier,
+        shippedAt,
+      ]);
+
+  @override
+  String toString() => 'OrderShipped(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, orderId: $orderId, trackingNumber: $trackingNumber, carrier: $carrier, shippedAt: $shippedAt)';
+}
+
+/// Emitted when an order is delivered
+final class OrderDelivered extends DomainEvent {
+  const OrderDelivered({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.orderId,
+    required this.deliveredAt,
+    required this.signedBy,
+  });
+
+  factory OrderDelivered.fromJson(Map<String, dynamic> json) {
+    return OrderDelivered(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      orderId: OrderId.fromJson(json['order_id']),
+      deliveredAt: DateTime.parse(json['delivered_at'] as String),
+      signedBy: json['signed_by'] as String,
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final OrderId orderId;
+
+  final DateTime deliveredAt;
+
+  final String signedBy;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'OrderDelivered',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'order_id': orderId.toJson(),
+      'delivered_at': deliveredAt.toUtc().toIso8601String(),
+      'signed_by': signedBy,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OrderDelivered &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          orderId == other.orderId &&
+          deliveredAt == other.deliveredAt &&
+          signedBy == other.signedBy;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        orderId,
+        deliveredAt,
+        signedBy,
+      ]);
+
+  @override
+  String toString() => 'OrderDelivered(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, orderId: $orderId, deliveredAt: $deliveredAt, signedBy: $signedBy)';
+}
+
+/// Emitted when an order is cancelled
+final class OrderCancelled extends DomainEvent {
+  const OrderCancelled({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.orderId,
+    required this.reason,
+    required this.cancelledAt,
+  });
+
+  factory OrderCancelled.fromJson(Map<String, dynamic> json) {
+    return OrderCancelled(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      orderId: OrderId.fromJson(json['order_id']),
+      reason: json['reason'] as String,
+      cancelledAt: DateTime.parse(json['cancelled_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final OrderId orderId;
+
+  final String reason;
+
+  final DateTime cancelledAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'OrderCancelled',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'order_id': orderId.toJson(),
+      'reason': reason,
+      'cancelled_at': cancelledAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OrderCancelled &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          orderId == other.orderId &&
+          reason == other.reason &&
+          cancelledAt == other.cancelledAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        orderId,
+        reason,
+        cancelledAt,
+      ]);
+
+  @override
+  String toString() => 'OrderCancelled(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, orderId: $orderId, reason: $reason, cancelledAt: $cancelledAt)';
+}
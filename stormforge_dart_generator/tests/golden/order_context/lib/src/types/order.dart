@@ -0,0 +1,357 @@
+import 'package:collection/collection.dart';
+import 'package:meta/meta.dart';
+
+const _equality = DeepCollectionEquality();
+
+@immutable
+class OrderId {
+  const OrderId(this.value);
+
+  factory OrderId.fromJson(Object? json) => OrderId(json as String);
+
+  final String value;
+
+  String toJson() => value;
+
+  @override
+  bool operator ==(Object other) => other is OrderId && value == other.value;
+
+  @override
+  int get hashCode => value.hashCode;
+
+  @override
+  String toString() => '$value';
+}
+
+@immutable
+class CustomerId {
+  const CustomerId(this.value);
+
+  factory CustomerId.fromJson(Object? json) => CustomerId(json as String);
+
+  final String value;
+
+  String toJson() => value;
+
+  @override
+  bool operator ==(Object other) => other is CustomerId && value == other.value;
+
+  @override
+  int get hashCode => value.hashCode;
+
+  @override
+  String toString() => '$value';
+}
+
+@immutable
+class ProductId {
+  const ProductId(this.value);
+
+  factory ProductId.fromJson(Object? json) => ProductId(json as String);
+
+  final String value;
+
+  String toJson() => value;
+
+  @override
+  bool operator ==(Object other) => other is ProductId && value == other.value;
+
+  @override
+  int get hashCode => value.hashCode;
+
+  @override
+  String toString() => '$value';
+}
+
+/// A line item in an order
+@immutable
+class OrderItem {
+  const OrderItem({
+    required this.productId,
+    required this.productName,
+    required this.quantity,
+    required this.unitPrice,
+    required this.subtotal,
+  });
+
+  factory OrderItem.fromJson(Map<String, dynamic> json) {
+    return OrderItem(
+      productId: ProductId.fromJson(json['product_id']),
+      productName: json['product_name'] as String,
+      quantity: (json['quantity'] as num).toInt(),
+      unitPrice: Money.fromJson(json['unit_price'] as Map<String, dynamic>),
+      subtotal: Money.fromJson(json['subtotal'] as Map<String, dynamic>),
+    );
+  }
+
+  final ProductId productId;
+
+  final String productName;
+
+  final int quantity;
+
+  final Money unitPrice;
+
+  final Money subtotal;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'product_id': productId.toJson(),
+      'product_name': productName,
+      'quantity': quantity,
+      'unit_price': unitPrice.toJson(),
+      'subtotal': subtotal.toJson(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OrderItem &&
+          productId == other.productId &&
+          productName == other.productName &&
+          quantity == other.quantity &&
+          unitPrice == other.unitPrice &&
+          subtotal == other.subtotal;
+
+  @override
+  int get hashCode => Object.hashAll([
+        productId,
+        productName,
+        quantity,
+        unitPrice,
+        subtotal,
+      ]);
+
+  @override
+  String toString() => 'OrderItem(productId: $productId, productName: $productName, quantity: $quantity, unitPrice: $unitPrice, subtotal: $subtotal)';
+}
+
+/// Monetary value with currency
+@immutable
+class Money {
+  const Money({
+    required this.amount,
+    required this.currency,
+  });
+
+  factory Money.fromJson(Map<String, dynamic> json) {
+    return Money(
+      amount: json['amount'].toString(),
+      currency: json['currency'] as String,
+    );
+  }
+
+  final String amount;
+
+  final String currency;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'amount': amount,
+      'currency': currency,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Money &&
+          amount == other.amount &&
+          currency == other.currency;
+
+  @override
+  int get hashCode => Object.hashAll([
+        amount,
+        currency,
+      ]);
+
+  @override
+  String toString() => 'Money(amount: $amount, currency: $currency)';
+}
+
+/// Physical address
+@immutable
+class Address {
+  const Address({
+    required this.street,
+    required this.city,
+    required this.province,
+    required this.postalCode,
+    required this.country,
+  });
+
+  factory Address.fromJson(Map<String, dynamic> json) {
+    return Address(
+      street: json['street'] as String,
+      city: json['city'] as String,
+      province: json['province'] as String,
+      postalCode: json['postal_code'] as String,
+      country: json['country'] as String,
+    );
+  }
+
+  final String street;
+
+  final String city;
+
+  final String province;
+
+  final String postalCode;
+
+  final String country;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'street': street,
+      'city': city,
+      'province': province,
+      'postal_code': postalCode,
+      'country': country,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Address &&
+          street == other.street &&
+          city == other.city &&
+          province == other.province &&
+          postalCode == other.postalCode &&
+          country == other.country;
+
+  @override
+  int get hashCode => Object.hashAll([
+        street,
+        city,
+        province,
+        postalCode,
+        country,
+      ]);
+
+  @override
+  String toString() => 'Address(street: $street, city: $city, province: $province, postalCode: $postalCode, country: $country)';
+}
+
+enum OrderStatus {
+  /// Order has been created, awaiting payment
+  created('Created'),
+  /// Payment has been received
+  paid('Paid'),
+  /// Order is being prepared
+  processing('Processing'),
+  /// Order has been shipped
+  shipped('Shipped'),
+  /// Order has been delivered
+  delivered('Delivered'),
+  /// Order has been cancelled
+  cancelled('Cancelled'),
+  /// Order has been refunded
+  refunded('Refunded');
+
+  const OrderStatus(this.value);
+
+  /// Serialized name, the variant of the Rust enum
+  final String value;
+
+  static OrderStatus fromJson(Object? json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw ArgumentError.value(json, 'json', 'Unknown OrderStatus'),
+    );
+  }
+
+  String toJson() => value;
+}
+
+/// Order aggregate root managing order lifecycle
+@immutable
+class Order {
+  const Order({
+    required this.id,
+    required this.customerId,
+    required this.items,
+    required this.status,
+    required this.shippingAddress,
+    required this.totalAmount,
+    required this.createdAt,
+    this.updatedAt,
+  });
+
+  factory Order.fromJson(Map<String, dynamic> json) {
+    return Order(
+      id: OrderId.fromJson(json['id']),
+      customerId: CustomerId.fromJson(json['customer_id']),
+      items: (json['items'] as List<dynamic>).map((item) => OrderItem.fromJson(item as Map<String, dynamic>)).toList(),
+      status: OrderStatus.fromJson(json['status']),
+      shippingAddress: Address.fromJson(json['shipping_address'] as Map<String, dynamic>),
+      totalAmount: Money.fromJson(json['total_amount'] as Map<String, dynamic>),
+      createdAt: DateTime.parse(json['created_at'] as String),
+      updatedAt: json['updated_at'] == null ? null : DateTime.parse(json['updated_at'] as String),
+    );
+  }
+
+  /// Unique order identifier
+  final OrderId id;
+
+  /// Customer who placed the order
+  final CustomerId customerId;
+
+  /// Order line items
+  final List<OrderItem> items;
+
+  /// Current order status
+  final OrderStatus status;
+
+  /// Delivery address
+  final Address shippingAddress;
+
+  /// Order total
+  final Money totalAmount;
+
+  final DateTime createdAt;
+
+  final DateTime? updatedAt;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'id': id.toJson(),
+      'customer_id': customerId.toJson(),
+      'items': items.map((item) => item.toJson()).toList(),
+      'status': status.toJson(),
+      'shipping_address': shippingAddress.toJson(),
+      'total_amount': totalAmount.toJson(),
+      'created_at': createdAt.toUtc().toIso8601String(),
+      'updated_at': updatedAt?.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Order &&
+          id == other.id &&
+          customerId == other.customerId &&
+          _equality.equals(items, other.items) &&
+          status == other.status &&
+          shippingAddress == other.shippingAddress &&
+          totalAmount == other.totalAmount &&
+          createdAt == other.createdAt &&
+          updatedAt == other.updatedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        id,
+        customerId,
+        _equality.hash(items),
+        status,
+        shippingAddress,
+        totalAmount,
+        createdAt,
+        updatedAt,
+      ]);
+
+  @override
+  String toString() => 'Order(id: $id, customerId: $customerId, items: $items, status: $status, shippingAddress: $shippingAddress, totalAmount: $totalAmount, createdAt: $createdAt, updatedAt: $updatedAt)';
+}
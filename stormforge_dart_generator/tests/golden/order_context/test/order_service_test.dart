@@ -0,0 +1,36 @@
+import 'dart:convert';
+
+import 'package:http/http.dart' as http;
+import 'package:http/testing.dart';
+import 'package:order_service/order_service.dart';
+import 'package:test/test.dart';
+
+/// Body the generated Rust service answers its query routes with
+const queryResponse = {'data': 'Query result'};
+
+void main() {
+  late OrderService service;
+
+  setUp(() {
+    service = OrderService(
+      baseUrl: 'http://localhost:3000',
+      httpClient: MockClient((request) async => http.Response(jsonEncode(queryResponse), 200)),
+    );
+  });
+
+  tearDown(() => service.close());
+
+  group('queries', () {
+    test('getOrder returns the response data', () async {
+      expect(await service.queries.getOrder(orderId: OrderId('sample')), 'Query result');
+    });
+
+    test('listOrders returns the response data', () async {
+      expect(await service.queries.listOrders(), 'Query result');
+    });
+
+    test('getOrdersByStatus returns the response data', () async {
+      expect(await service.queries.getOrdersByStatus(status: OrderStatus.values.first), 'Query result');
+    });
+  });
+}
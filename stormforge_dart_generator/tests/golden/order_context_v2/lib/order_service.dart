@@ -0,0 +1,8 @@
+/// Typed client for the Order bounded context
+library;
+
+export 'src/client/order_client.dart';
+export 'src/commands/order_commands.dart';
+export 'src/events/order_events.dart';
+export 'src/queries/order_queries.dart';
+export 'src/types/order.dart';
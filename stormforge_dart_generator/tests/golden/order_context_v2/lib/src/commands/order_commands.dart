@@ -0,0 +1,195 @@
+import 'package:collection/collection.dart';
+import 'package:meta/meta.dart';
+
+import '../types/order.dart';
+
+const _equality = DeepCollectionEquality();
+
+/// Create a new order
+@immutable
+class CreateOrder {
+  const CreateOrder({
+    required this.customerId,
+    required this.items,
+    required this.shippingAddress,
+  });
+
+  factory CreateOrder.fromJson(Map<String, dynamic> json) {
+    return CreateOrder(
+      customerId: CustomerId.fromJson(json['customer_id']),
+      items: (json['items'] as List<dynamic>).map((item) => OrderItem.fromJson(item as Map<String, dynamic>)).toList(),
+      shippingAddress: Address.fromJson(json['shipping_address'] as Map<String, dynamic>),
+    );
+  }
+
+  /// Customer placing the order
+  final CustomerId customerId;
+
+  /// Items to order
+  final List<OrderItem> items;
+
+  /// Delivery address
+  final Address shippingAddress;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'customer_id': customerId.toJson(),
+      'items': items.map((item) => item.toJson()).toList(),
+      'shipping_address': shippingAddress.toJson(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CreateOrder &&
+          customerId == other.customerId &&
+          _equality.equals(items, other.items) &&
+          shippingAddress == other.shippingAddress;
+
+  @override
+  int get hashCode => Object.hashAll([
+        customerId,
+        _equality.hash(items),
+        shippingAddress,
+      ]);
+
+  @override
+  String toString() => 'CreateOrder(customerId: $customerId, items: $items, shippingAddress: $shippingAddress)';
+}
+
+/// Confirm order payment
+@immutable
+class ConfirmPayment {
+  const ConfirmPayment({
+    required this.orderId,
+    required this.paymentId,
+  });
+
+  factory ConfirmPayment.fromJson(Map<String, dynamic> json) {
+    return ConfirmPayment(
+      orderId: OrderId.fromJson(json['order_id']),
+      paymentId: json['payment_id'] as String,
+    );
+  }
+
+  final OrderId orderId;
+
+  final String paymentId;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'order_id': orderId.toJson(),
+      'payment_id': paymentId,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConfirmPayment &&
+          orderId == other.orderId &&
+          paymentId == other.paymentId;
+
+  @override
+  int get hashCode => Object.hashAll([
+        orderId,
+        paymentId,
+      ]);
+
+  @override
+  String toString() => 'ConfirmPayment(orderId: $orderId, paymentId: $paymentId)';
+}
+
+/// Ship the order
+@immutable
+class ShipOrder {
+  const ShipOrder({
+    required this.orderId,
+    required this.trackingNumber,
+    required this.carrier,
+  });
+
+  factory ShipOrder.fromJson(Map<String, dynamic> json) {
+    return ShipOrder(
+      orderId: OrderId.fromJson(json['order_id']),
+      trackingNumber: json['tracking_number'] as String,
+      carrier: json['carrier'] as String,
+    );
+  }
+
+  final OrderId orderId;
+
+  final String trackingNumber;
+
+  final String carrier;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'order_id': orderId.toJson(),
+      'tracking_number': trackingNumber,
+      'carrier': carrier,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ShipOrder &&
+          orderId == other.orderId &&
+          trackingNumber == other.trackingNumber &&
+          carrier == other.carrier;
+
+  @override
+  int get hashCode => Object.hashAll([
+        orderId,
+        trackingNumber,
+        carrier,
+      ]);
+
+  @override
+  String toString() => 'ShipOrder(orderId: $orderId, trackingNumber: $trackingNumber, carrier: $carrier)';
+}
+
+/// Cancel the order
+@immutable
+class CancelOrder {
+  const CancelOrder({
+    required this.orderId,
+    required this.reason,
+  });
+
+  factory CancelOrder.fromJson(Map<String, dynamic> json) {
+    return CancelOrder(
+      orderId: OrderId.fromJson(json['order_id']),
+      reason: json['reason'] as String,
+    );
+  }
+
+  final OrderId orderId;
+
+  final String reason;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'order_id': orderId.toJson(),
+      'reason': reason,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CancelOrder &&
+          orderId == other.orderId &&
+          reason == other.reason;
+
+  @override
+  int get hashCode => Object.hashAll([
+        orderId,
+        reason,
+      ]);
+
+  @override
+  String toString() => 'CancelOrder(orderId: $orderId, reason: $reason)';
+}
@@ -0,0 +1,169 @@
+import 'package:collection/collection.dart';
+import 'package:meta/meta.dart';
+
+import '../types/order.dart';
+
+const _equality = DeepCollectionEquality();
+
+/// Summary view of orders for listing
+@immutable
+class OrderSummary {
+  const OrderSummary({
+    this.orderId,
+    this.orderNumber,
+    this.customerName,
+    this.customerEmail,
+    this.itemCount,
+    this.totalAmount,
+    this.status,
+    this.createdAt,
+  });
+
+  factory OrderSummary.fromJson(Map<String, dynamic> json) {
+    return OrderSummary(
+      orderId: json['order_id'] == null ? null : OrderId.fromJson(json['order_id']),
+      orderNumber: json['order_number'] == null ? null : json['order_number'] as String,
+      customerName: json['customer_name'] == null ? null : json['customer_name'] as String,
+      customerEmail: json['customer_email'] == null ? null : json['customer_email'] as String,
+      itemCount: json['item_count'] == null ? null : (json['item_count'] as num).toInt(),
+      totalAmount: json['total_amount'] == null ? null : Money.fromJson(json['total_amount'] as Map<String, dynamic>),
+      status: json['status'] == null ? null : OrderStatus.fromJson(json['status']),
+      createdAt: json['created_at'] == null ? null : DateTime.parse(json['created_at'] as String),
+    );
+  }
+
+  final OrderId? orderId;
+
+  final String? orderNumber;
+
+  final String? customerName;
+
+  final String? customerEmail;
+
+  final int? itemCount;
+
+  final Money? totalAmount;
+
+  final OrderStatus? status;
+
+  final DateTime? createdAt;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'order_id': orderId?.toJson(),
+      'order_number': orderNumber,
+      'customer_name': customerName,
+      'customer_email': customerEmail,
+      'item_count': itemCount,
+      'total_amount': totalAmount?.toJson(),
+      'status': status?.toJson(),
+      'created_at': createdAt?.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OrderSummary &&
+          orderId == other.orderId &&
+          orderNumber == other.orderNumber &&
+          customerName == other.customerName &&
+          customerEmail == other.customerEmail &&
+          itemCount == other.itemCount &&
+          totalAmount == other.totalAmount &&
+          status == other.status &&
+          createdAt == other.createdAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        orderId,
+        orderNumber,
+        customerName,
+        customerEmail,
+        itemCount,
+        totalAmount,
+        status,
+        createdAt,
+      ]);
+
+  @override
+  String toString() => 'OrderSummary(orderId: $orderId, orderNumber: $orderNumber, customerName: $customerName, customerEmail: $customerEmail, itemCount: $itemCount, totalAmount: $totalAmount, status: $status, createdAt: $createdAt)';
+}
+
+/// Detailed view of a single order
+@immutable
+class OrderDetails {
+  const OrderDetails({
+    this.orderId,
+    this.customer,
+    this.items,
+    this.shippingAddress,
+    this.totalAmount,
+    this.status,
+    this.timeline,
+  });
+
+  factory OrderDetails.fromJson(Map<String, dynamic> json) {
+    return OrderDetails(
+      orderId: json['order_id'] == null ? null : OrderId.fromJson(json['order_id']),
+      customer: json['customer'],
+      items: json['items'] == null ? null : (json['items'] as List<dynamic>).map((item) => item).toList(),
+      shippingAddress: json['shipping_address'] == null ? null : Address.fromJson(json['shipping_address'] as Map<String, dynamic>),
+      totalAmount: json['total_amount'] == null ? null : Money.fromJson(json['total_amount'] as Map<String, dynamic>),
+      status: json['status'] == null ? null : OrderStatus.fromJson(json['status']),
+      timeline: json['timeline'] == null ? null : (json['timeline'] as List<dynamic>).map((item) => item).toList(),
+    );
+  }
+
+  final OrderId? orderId;
+
+  final Object? customer;
+
+  final List<Object?>? items;
+
+  final Address? shippingAddress;
+
+  final Money? totalAmount;
+
+  final OrderStatus? status;
+
+  final List<Object?>? timeline;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'order_id': orderId?.toJson(),
+      'customer': customer,
+      'items': items,
+      'shipping_address': shippingAddress?.toJson(),
+      'total_amount': totalAmount?.toJson(),
+      'status': status?.toJson(),
+      'timeline': timeline,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OrderDetails &&
+          orderId == other.orderId &&
+          _equality.equals(customer, other.customer) &&
+          _equality.equals(items, other.items) &&
+          shippingAddress == other.shippingAddress &&
+          totalAmount == other.totalAmount &&
+          status == other.status &&
+          _equality.equals(timeline, other.timeline);
+
+  @override
+  int get hashCode => Object.hashAll([
+        orderId,
+        _equality.hash(customer),
+        _equality.hash(items),
+        shippingAddress,
+        totalAmount,
+        status,
+        _equality.hash(timeline),
+      ]);
+
+  @override
+  String toString() => 'OrderDetails(orderId: $orderId, customer: $customer, items: $items, shippingAddress: $shippingAddress, totalAmount: $totalAmount, status: $status, timeline: $timeline)';
+}
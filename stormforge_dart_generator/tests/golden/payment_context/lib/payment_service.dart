@@ -0,0 +1,7 @@
+/// Typed client for the Payment bounded context
+library;
+
+export 'src/client/payment_client.dart';
+export 'src/commands/payment_commands.dart';
+export 'src/events/payment_events.dart';
+export 'src/types/payment.dart';
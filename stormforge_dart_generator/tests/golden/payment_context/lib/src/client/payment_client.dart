@@ -0,0 +1,147 @@
+import 'dart:convert';
+
+import 'package:http/http.dart' as http;
+
+import '../commands/payment_commands.dart';
+import '../types/payment.dart';
+
+/// Error response of the Payment service
+class ApiException implements Exception {
+  const ApiException(this.statusCode, this.code, this.message);
+
+  final int statusCode;
+
+  /// Error code, such as `VALIDATION_ERROR`
+  final String code;
+
+  final String message;
+
+  @override
+  String toString() => 'ApiException($statusCode, $code: $message)';
+}
+
+/// HTTP client for the Payment service
+class PaymentService {
+  PaymentService({required String baseUrl, http.Client? httpClient})
+      : _transport = _Transport(Uri.parse(baseUrl), httpClient ?? http.Client());
+
+  final _Transport _transport;
+
+  /// Command endpoints
+  late final PaymentCommands commands = PaymentCommands._(_transport);
+
+  /// Query endpoints
+  late final PaymentQueries queries = PaymentQueries._(_transport);
+
+  /// Close the underlying HTTP client
+  void close() => _transport.client.close();
+}
+
+/// Command endpoints of the Payment service
+class PaymentCommands {
+  PaymentCommands._(this._transport);
+
+  final _Transport _transport;
+
+  /// Initiate a payment for an order
+  Future<String> initiatePayment(InitiatePayment command) async {
+    final body = await _transport.post('/api/initiate-payment', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Mark payment as completed
+  Future<String> completePayment(CompletePayment command) async {
+    final body = await _transport.post('/api/complete-payment', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Mark payment as failed
+  Future<String> failPayment(FailPayment command) async {
+    final body = await _transport.post('/api/fail-payment', command.toJson());
+    return _data(body) as String;
+  }
+
+  /// Refund a completed payment
+  Future<String> refundPayment(RefundPayment command) async {
+    final body = await _transport.post('/api/refund-payment', command.toJson());
+    return _data(body) as String;
+  }
+}
+
+/// Query endpoints of the Payment service
+class PaymentQueries {
+  PaymentQueries._(this._transport);
+
+  final _Transport _transport;
+
+  /// Get payment by ID
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `Payment?`, which the generated service does not return yet.
+  Future<String> getPayment({required PaymentId paymentId}) async {
+    final body = await _transport.get('/api/get-payment', {
+      'payment_id': paymentId.toJson(),
+    });
+    return _data(body) as String;
+  }
+
+  /// Get payment for an order
+  ///
+  /// Returns the `data` string of the service's `ApiResponseString` response.
+  /// The IR declares `Payment?`, which the generated service does not return yet.
+  Future<String> getPaymentByOrder({required OrderId orderId}) async {
+    final body = await _transport.get('/api/get-payment-by-order', {
+      'order_id': orderId.toJson(),
+    });
+    return _data(body) as String;
+  }
+}
+
+/// Payload of the `{"data": ...}` envelope of command and query responses
+Object? _data(Object? body) => (body as Map<String, dynamic>)['data'];
+
+class _Transport {
+  _Transport(this.baseUrl, this.client);
+
+  final Uri baseUrl;
+
+  final http.Client client;
+
+  Future<Object?> get(String path, [Map<String, Object?> query = const {}]) async {
+    final parameters = {
+      for (final entry in query.entries)
+        if (entry.value != null) entry.key: '${entry.value}',
+    };
+    final uri = parameters.isEmpty ? _uri(path) : _uri(path).replace(queryParameters: parameters);
+    return _decode(await client.get(uri));
+  }
+
+  Future<Object?> post(String path, Map<String, dynamic> body) async {
+    final response = await client.post(
+      _uri(path),
+      headers: {'content-type': 'application/json'},
+      body: jsonEncode(body),
+    );
+    return _decode(response);
+  }
+
+  Uri _uri(String path) {
+    final base = baseUrl.path.endsWith('/')
+        ? baseUrl.path.substring(0, baseUrl.path.length - 1)
+        : baseUrl.path;
+    return baseUrl.replace(path: '$base$path');
+  }
+
+  Object? _decode(http.Response response) {
+    final body = response.body.isEmpty ? null : jsonDecode(response.body);
+    if (response.statusCode >= 400) {
+      final error = body is Map<String, dynamic> ? body : const <String, dynamic>{};
+      throw ApiException(
+        response.statusCode,
+        error['code'] as String? ?? 'HTTP_${response.statusCode}',
+        error['message'] as String? ?? response.reasonPhrase ?? '',
+      );
+    }
+    return body;
+  }
+}
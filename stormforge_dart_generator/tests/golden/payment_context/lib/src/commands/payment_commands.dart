@@ -0,0 +1,183 @@
+import 'package:meta/meta.dart';
+
+import '../types/payment.dart';
+
+/// Initiate a payment for an order
+@immutable
+class InitiatePayment {
+  const InitiatePayment({
+    required this.orderId,
+    required this.amount,
+    required this.method,
+  });
+
+  factory InitiatePayment.fromJson(Map<String, dynamic> json) {
+    return InitiatePayment(
+      orderId: OrderId.fromJson(json['order_id']),
+      amount: Money.fromJson(json['amount'] as Map<String, dynamic>),
+      method: PaymentMethod.fromJson(json['method']),
+    );
+  }
+
+  final OrderId orderId;
+
+  final Money amount;
+
+  final PaymentMethod method;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'order_id': orderId.toJson(),
+      'amount': amount.toJson(),
+      'method': method.toJson(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InitiatePayment &&
+          orderId == other.orderId &&
+          amount == other.amount &&
+          method == other.method;
+
+  @override
+  int get hashCode => Object.hashAll([
+        orderId,
+        amount,
+        method,
+      ]);
+
+  @override
+  String toString() => 'InitiatePayment(orderId: $orderId, amount: $amount, method: $method)';
+}
+
+/// Mark payment as completed
+@immutable
+class CompletePayment {
+  const CompletePayment({
+    required this.paymentId,
+    required this.transactionId,
+  });
+
+  factory CompletePayment.fromJson(Map<String, dynamic> json) {
+    return CompletePayment(
+      paymentId: PaymentId.fromJson(json['payment_id']),
+      transactionId: json['transaction_id'] as String,
+    );
+  }
+
+  final PaymentId paymentId;
+
+  final String transactionId;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'payment_id': paymentId.toJson(),
+      'transaction_id': transactionId,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CompletePayment &&
+          paymentId == other.paymentId &&
+          transactionId == other.transactionId;
+
+  @override
+  int get hashCode => Object.hashAll([
+        paymentId,
+        transactionId,
+      ]);
+
+  @override
+  String toString() => 'CompletePayment(paymentId: $paymentId, transactionId: $transactionId)';
+}
+
+/// Mark payment as failed
+@immutable
+class FailPayment {
+  const FailPayment({
+    required this.paymentId,
+    required this.reason,
+  });
+
+  factory FailPayment.fromJson(Map<String, dynamic> json) {
+    return FailPayment(
+      paymentId: PaymentId.fromJson(json['payment_id']),
+      reason: json['reason'] as String,
+    );
+  }
+
+  final PaymentId paymentId;
+
+  final String reason;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'payment_id': paymentId.toJson(),
+      'reason': reason,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FailPayment &&
+          paymentId == other.paymentId &&
+          reason == other.reason;
+
+  @override
+  int get hashCode => Object.hashAll([
+        paymentId,
+        reason,
+      ]);
+
+  @override
+  String toString() => 'FailPayment(paymentId: $paymentId, reason: $reason)';
+}
+
+/// Refund a completed payment
+@immutable
+class RefundPayment {
+  const RefundPayment({
+    required this.paymentId,
+    this.refundAmount,
+  });
+
+  factory RefundPayment.fromJson(Map<String, dynamic> json) {
+    return RefundPayment(
+      paymentId: PaymentId.fromJson(json['payment_id']),
+      refundAmount: json['refund_amount'] == null ? null : Money.fromJson(json['refund_amount'] as Map<String, dynamic>),
+    );
+  }
+
+  final PaymentId paymentId;
+
+  /// If not provided, full refund
+  final Money? refundAmount;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'payment_id': paymentId.toJson(),
+      'refund_amount': refundAmount?.toJson(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RefundPayment &&
+          paymentId == other.paymentId &&
+          refundAmount == other.refundAmount;
+
+  @override
+  int get hashCode => Object.hashAll([
+        paymentId,
+        refundAmount,
+      ]);
+
+  @override
+  String toString() => 'RefundPayment(paymentId: $paymentId, refundAmount: $refundAmount)';
+}
@@ -0,0 +1,370 @@
+import 'package:meta/meta.dart';
+
+import '../types/payment.dart';
+
+/// Domain event of the Payment bounded context, tagged by `type`
+@immutable
+sealed class DomainEvent {
+  const DomainEvent();
+
+  factory DomainEvent.fromJson(Map<String, dynamic> json) {
+    return switch (json['type']) {
+      'PaymentInitiated' => PaymentInitiated.fromJson(json),
+      'PaymentCompleted' => PaymentCompleted.fromJson(json),
+      'PaymentFailed' => PaymentFailed.fromJson(json),
+      'PaymentRefunded' => PaymentRefunded.fromJson(json),
+      final type => throw ArgumentError.value(type, 'type', 'Unknown domain event'),
+    };
+  }
+
+  /// Unique event identifier
+  String get eventId;
+
+  /// Timestamp when the event occurred
+  DateTime get occurredAt;
+
+  Map<String, dynamic> toJson();
+}
+
+/// Payment has been initiated
+final class PaymentInitiated extends DomainEvent {
+  const PaymentInitiated({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.paymentId,
+    required this.orderId,
+    required this.amount,
+    required this.method,
+    required this.createdAt,
+  });
+
+  factory PaymentInitiated.fromJson(Map<String, dynamic> json) {
+    return PaymentInitiated(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      paymentId: PaymentId.fromJson(json['payment_id']),
+      orderId: OrderId.fromJson(json['order_id']),
+      amount: Money.fromJson(json['amount'] as Map<String, dynamic>),
+      method: PaymentMethod.fromJson(json['method']),
+      createdAt: DateTime.parse(json['created_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final PaymentId paymentId;
+
+  final OrderId orderId;
+
+  final Money amount;
+
+  final PaymentMethod method;
+
+  final DateTime createdAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'PaymentInitiated',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'payment_id': paymentId.toJson(),
+      'order_id': orderId.toJson(),
+      'amount': amount.toJson(),
+      'method': method.toJson(),
+      'created_at': createdAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentInitiated &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          paymentId == other.paymentId &&
+          orderId == other.orderId &&
+          amount == other.amount &&
+          method == other.method &&
+          createdAt == other.createdAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        paymentId,
+        orderId,
+        amount,
+        method,
+        createdAt,
+      ]);
+
+  @override
+  String toString() => 'PaymentInitiated(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, paymentId: $paymentId, orderId: $orderId, amount: $amount, method: $method, createdAt: $createdAt)';
+}
+
+/// Payment completed successfully
+final class PaymentCompleted extends DomainEvent {
+  const PaymentCompleted({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.paymentId,
+    required this.orderId,
+    required this.transactionId,
+    required this.completedAt,
+  });
+
+  factory PaymentCompleted.fromJson(Map<String, dynamic> json) {
+    return PaymentCompleted(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      paymentId: PaymentId.fromJson(json['payment_id']),
+      orderId: OrderId.fromJson(json['order_id']),
+      transactionId: json['transaction_id'] as String,
+      completedAt: DateTime.parse(json['completed_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final PaymentId paymentId;
+
+  final OrderId orderId;
+
+  final String transactionId;
+
+  final DateTime completedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'PaymentCompleted',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'payment_id': paymentId.toJson(),
+      'order_id': orderId.toJson(),
+      'transaction_id': transactionId,
+      'completed_at': completedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentCompleted &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          paymentId == other.paymentId &&
+          orderId == other.orderId &&
+          transactionId == other.transactionId &&
+          completedAt == other.completedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        paymentId,
+        orderId,
+        transactionId,
+        completedAt,
+      ]);
+
+  @override
+  String toString() => 'PaymentCompleted(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, paymentId: $paymentId, orderId: $orderId, transactionId: $transactionId, completedAt: $completedAt)';
+}
+
+/// Payment has failed
+final class PaymentFailed extends DomainEvent {
+  const PaymentFailed({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.paymentId,
+    required this.orderId,
+    required this.reason,
+    required this.failedAt,
+  });
+
+  factory PaymentFailed.fromJson(Map<String, dynamic> json) {
+    return PaymentFailed(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      paymentId: PaymentId.fromJson(json['payment_id']),
+      orderId: OrderId.fromJson(json['order_id']),
+      reason: json['reason'] as String,
+      failedAt: DateTime.parse(json['failed_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final PaymentId paymentId;
+
+  final OrderId orderId;
+
+  final String reason;
+
+  final DateTime failedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'PaymentFailed',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'payment_id': paymentId.toJson(),
+      'order_id': orderId.toJson(),
+      'reason': reason,
+      'failed_at': failedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentFailed &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          paymentId == other.paymentId &&
+          orderId == other.orderId &&
+          reason == other.reason &&
+          failedAt == other.failedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        paymentId,
+        orderId,
+        reason,
+        failedAt,
+      ]);
+
+  @override
+  String toString() => 'PaymentFailed(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, paymentId: $paymentId, orderId: $orderId, reason: $reason, failedAt: $failedAt)';
+}
+
+/// Payment has been refunded
+final class PaymentRefunded extends DomainEvent {
+  const PaymentRefunded({
+    required this.eventId,
+    required this.occurredAt,
+    required this.aggregateId,
+    required this.paymentId,
+    required this.orderId,
+    required this.refundAmount,
+    required this.refundedAt,
+  });
+
+  factory PaymentRefunded.fromJson(Map<String, dynamic> json) {
+    return PaymentRefunded(
+      eventId: json['event_id'] as String,
+      occurredAt: DateTime.parse(json['occurred_at'] as String),
+      aggregateId: json['aggregate_id'] as String,
+      paymentId: PaymentId.fromJson(json['payment_id']),
+      orderId: OrderId.fromJson(json['order_id']),
+      refundAmount: Money.fromJson(json['refund_amount'] as Map<String, dynamic>),
+      refundedAt: DateTime.parse(json['refunded_at'] as String),
+    );
+  }
+
+  /// Unique event identifier
+  @override
+  final String eventId;
+
+  /// Timestamp when the event occurred
+  @override
+  final DateTime occurredAt;
+
+  /// ID of the aggregate that produced this event
+  final String aggregateId;
+
+  final PaymentId paymentId;
+
+  final OrderId orderId;
+
+  final Money refundAmount;
+
+  final DateTime refundedAt;
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      'type': 'PaymentRefunded',
+      'event_id': eventId,
+      'occurred_at': occurredAt.toUtc().toIso8601String(),
+      'aggregate_id': aggregateId,
+      'payment_id': paymentId.toJson(),
+      'order_id': orderId.toJson(),
+      'refund_amount': refundAmount.toJson(),
+      'refunded_at': refundedAt.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentRefunded &&
+          eventId == other.eventId &&
+          occurredAt == other.occurredAt &&
+          aggregateId == other.aggregateId &&
+          paymentId == other.paymentId &&
+          orderId == other.orderId &&
+          refundAmount == other.refundAmount &&
+          refundedAt == other.refundedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        eventId,
+        occurredAt,
+        aggregateId,
+        paymentId,
+        orderId,
+        refundAmount,
+        refundedAt,
+      ]);
+
+  @override
+  String toString() => 'PaymentRefunded(eventId: $eventId, occurredAt: $occurredAt, aggregateId: $aggregateId, paymentId: $paymentId, orderId: $orderId, refundAmount: $refundAmount, refundedAt: $refundedAt)';
+}
@@ -0,0 +1,220 @@
+import 'package:meta/meta.dart';
+
+@immutable
+class PaymentId {
+  const PaymentId(this.value);
+
+  factory PaymentId.fromJson(Object? json) => PaymentId(json as String);
+
+  final String value;
+
+  String toJson() => value;
+
+  @override
+  bool operator ==(Object other) => other is PaymentId && value == other.value;
+
+  @override
+  int get hashCode => value.hashCode;
+
+  @override
+  String toString() => '$value';
+}
+
+@immutable
+class OrderId {
+  const OrderId(this.value);
+
+  factory OrderId.fromJson(Object? json) => OrderId(json as String);
+
+  final String value;
+
+  String toJson() => value;
+
+  @override
+  bool operator ==(Object other) => other is OrderId && value == other.value;
+
+  @override
+  int get hashCode => value.hashCode;
+
+  @override
+  String toString() => '$value';
+}
+
+@immutable
+class Money {
+  const Money({
+    required this.amount,
+    required this.currency,
+  });
+
+  factory Money.fromJson(Map<String, dynamic> json) {
+    return Money(
+      amount: json['amount'].toString(),
+      currency: json['currency'] as String,
+    );
+  }
+
+  final String amount;
+
+  final String currency;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'amount': amount,
+      'currency': currency,
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Money &&
+          amount == other.amount &&
+          currency == other.currency;
+
+  @override
+  int get hashCode => Object.hashAll([
+        amount,
+        currency,
+      ]);
+
+  @override
+  String toString() => 'Money(amount: $amount, currency: $currency)';
+}
+
+enum PaymentMethod {
+  /// Alipay payment
+  alipay('Alipay'),
+  /// WeChat Pay
+  wechatPay('WechatPay'),
+  /// Credit card payment
+  creditCard('CreditCard'),
+  /// Bank transfer
+  bankTransfer('BankTransfer');
+
+  const PaymentMethod(this.value);
+
+  /// Serialized name, the variant of the Rust enum
+  final String value;
+
+  static PaymentMethod fromJson(Object? json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw ArgumentError.value(json, 'json', 'Unknown PaymentMethod'),
+    );
+  }
+
+  String toJson() => value;
+}
+
+enum PaymentStatus {
+  /// Payment is pending
+  pending('Pending'),
+  /// Payment is being processed
+  processing('Processing'),
+  /// Payment completed successfully
+  completed('Completed'),
+  /// Payment failed
+  failed('Failed'),
+  /// Payment has been refunded
+  refunded('Refunded');
+
+  const PaymentStatus(this.value);
+
+  /// Serialized name, the variant of the Rust enum
+  final String value;
+
+  static PaymentStatus fromJson(Object? json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw ArgumentError.value(json, 'json', 'Unknown PaymentStatus'),
+    );
+  }
+
+  String toJson() => value;
+}
+
+/// Payment aggregate handling payment lifecycle
+@immutable
+class Payment {
+  const Payment({
+    required this.id,
+    required this.orderId,
+    required this.amount,
+    required this.method,
+    required this.status,
+    this.transactionId,
+    required this.createdAt,
+    this.completedAt,
+  });
+
+  factory Payment.fromJson(Map<String, dynamic> json) {
+    return Payment(
+      id: PaymentId.fromJson(json['id']),
+      orderId: OrderId.fromJson(json['order_id']),
+      amount: Money.fromJson(json['amount'] as Map<String, dynamic>),
+      method: PaymentMethod.fromJson(json['method']),
+      status: PaymentStatus.fromJson(json['status']),
+      transactionId: json['transaction_id'] == null ? null : json['transaction_id'] as String,
+      createdAt: DateTime.parse(json['created_at'] as String),
+      completedAt: json['completed_at'] == null ? null : DateTime.parse(json['completed_at'] as String),
+    );
+  }
+
+  final PaymentId id;
+
+  final OrderId orderId;
+
+  final Money amount;
+
+  final PaymentMethod method;
+
+  final PaymentStatus status;
+
+  final String? transactionId;
+
+  final DateTime createdAt;
+
+  final DateTime? completedAt;
+
+  Map<String, dynamic> toJson() {
+    return {
+      'id': id.toJson(),
+      'order_id': orderId.toJson(),
+      'amount': amount.toJson(),
+      'method': method.toJson(),
+      'status': status.toJson(),
+      'transaction_id': transactionId,
+      'created_at': createdAt.toUtc().toIso8601String(),
+      'completed_at': completedAt?.toUtc().toIso8601String(),
+    };
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Payment &&
+          id == other.id &&
+          orderId == other.orderId &&
+          amount == other.amount &&
+          method == other.method &&
+          status == other.status &&
+          transactionId == other.transactionId &&
+          createdAt == other.createdAt &&
+          completedAt == other.completedAt;
+
+  @override
+  int get hashCode => Object.hashAll([
+        id,
+        orderId,
+        amount,
+        method,
+        status,
+        transactionId,
+        createdAt,
+        completedAt,
+      ]);
+
+  @override
+  String toString() => 'Payment(id: $id, orderId: $orderId, amount: $amount, method: $method, status: $status, transactionId: $transactionId, createdAt: $createdAt, completedAt: $completedAt)';
+}
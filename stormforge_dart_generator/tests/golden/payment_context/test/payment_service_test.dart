@@ -0,0 +1,32 @@
+import 'dart:convert';
+
+import 'package:http/http.dart' as http;
+import 'package:http/testing.dart';
+import 'package:payment_service/payment_service.dart';
+import 'package:test/test.dart';
+
+/// Body the generated Rust service answers its query routes with
+const queryResponse = {'data': 'Query result'};
+
+void main() {
+  late PaymentService service;
+
+  setUp(() {
+    service = PaymentService(
+      baseUrl: 'http://localhost:3000',
+      httpClient: MockClient((request) async => http.Response(jsonEncode(queryResponse), 200)),
+    );
+  });
+
+  tearDown(() => service.close());
+
+  group('queries', () {
+    test('getPayment returns the response data', () async {
+      expect(await service.queries.getPayment(paymentId: PaymentId('sample')), 'Query result');
+    });
+
+    test('getPaymentByOrder returns the response data', () async {
+      expect(await service.queries.getPaymentByOrder(orderId: OrderId('sample')), 'Query result');
+    });
+  });
+}
@@ -0,0 +1,105 @@
+//! Golden-file tests for the generated Dart package
+//!
+//! Every example in `ir_schema/examples` is generated and compared file by
+//! file against `tests/golden/<example>/`. Set `UPDATE_GOLDEN=1` to rewrite
+//! the golden files after an intentional change to the generator output.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+use walkdir::WalkDir;
+
+const EXAMPLES_DIR: &str = "../ir_schema/examples";
+const GOLDEN_DIR: &str = "tests/golden";
+
+/// Relative paths of all files below `root`, sorted
+fn list_files(root: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = WalkDir::new(root)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.path().strip_prefix(root).unwrap().to_path_buf())
+        .collect();
+    files.sort();
+    files
+}
+
+fn example_files() -> Vec<PathBuf> {
+    let mut examples: Vec<PathBuf> = WalkDir::new(EXAMPLES_DIR)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "yaml"))
+        .map(|entry| entry.path().to_path_buf())
+        .collect();
+    examples.sort();
+    examples
+}
+
+#[test]
+fn test_generated_packages_match_golden_files() {
+    let examples = example_files();
+    if examples.is_empty() {
+        println!("Skipping test: no example files found");
+        return;
+    }
+
+    let build_status = Command::new("cargo")
+        .args(["build"])
+        .status()
+        .expect("Failed to build generator");
+
+    assert!(build_status.success(), "Generator build failed");
+
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+
+    for example in examples {
+        let name = example.file_stem().unwrap().to_str().unwrap();
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        let generate_status = Command::new("./target/debug/stormforge-dart-generator")
+            .args([
+                "generate",
+                "--input",
+                example.to_str().unwrap(),
+                "--output",
+                temp_dir.path().to_str().unwrap(),
+            ])
+            .stdout(std::process::Stdio::null())
+            .status()
+            .expect("Failed to run generator");
+
+        assert!(generate_status.success(), "Generator failed for {}", name);
+
+        let golden_root = Path::new(GOLDEN_DIR).join(name);
+        let generated = list_files(temp_dir.path());
+
+        if update {
+            let _ = fs::remove_dir_all(&golden_root);
+            for file in &generated {
+                let target = golden_root.join(file);
+                fs::create_dir_all(target.parent().unwrap()).unwrap();
+                fs::copy(temp_dir.path().join(file), target).unwrap();
+            }
+            continue;
+        }
+
+        assert_eq!(
+            generated,
+            list_files(&golden_root),
+            "Generated file set differs from golden files for {}",
+            name
+        );
+
+        for file in &generated {
+            let actual = fs::read_to_string(temp_dir.path().join(file)).unwrap();
+            let expected = fs::read_to_string(golden_root.join(file)).unwrap();
+            assert!(
+                actual == expected,
+                "{}/{} differs from golden file (rerun with UPDATE_GOLDEN=1 to accept)",
+                name,
+                file.display()
+            );
+        }
+    }
+}
@@ -0,0 +1,4 @@
+#[tokio::main]
+async fn main() {
+    stormforge_generator::lsp::run_stdio().await;
+}
@@ -0,0 +1,964 @@
+use crate::generators::expression_generator::ExpressionGenerator;
+use crate::generators::rust_ast::*;
+use crate::generators::utils::*;
+use crate::ir::{Aggregate, Command, IRModel, Persistence, Property, ValueObject};
+use anyhow::{bail, Context, Result};
+use heck::ToShoutySnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+pub struct AggregateGenerator;
+
+impl AggregateGenerator {
+    /// Generate the aggregate state folded from events
+    pub fn generate_state(model: &IRModel) -> Result<String> {
+        let aggregates = model
+            .aggregates
+            .values()
+            .map(|aggregate| Self::generate_aggregate_state(model, aggregate))
+            .collect::<Result<Vec<_>>>()?;
+        let names = model.aggregates.keys();
+        let applies = model
+            .aggregates
+            .keys()
+            .map(|name| Ok(format_ident!("apply_{}", snake_ident(name)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        render(quote! {
+            //! Aggregate state folded from events
+            //!
+            //! The state of an aggregate is a JSON object of its root entity
+            //! properties, starting from their defaults. Each event copies its
+            //! payload fields into the state, and an event whose name ends with a
+            //! value of the aggregate's status enum, such as `OrderPaid` for `PAID`,
+            //! sets the status. The identifier is the aggregate ID of the first
+            //! event, unless its payload carries the identifier field.
+
+            use serde_json::Value;
+
+            use crate::domain::expressions;
+
+            /// Event fields that describe the event rather than the aggregate
+            const ENVELOPE_FIELDS: [&str; 4] = ["type", "event_id", "occurred_at", "aggregate_id"];
+
+            /// Fold events with the apply function of an aggregate
+            pub fn fold(events: &[Value], apply: fn(&mut Value, &Value)) -> Value {
+                let mut state = Value::Null;
+                for event in events {
+                    apply(&mut state, event);
+                }
+                state
+            }
+
+            /// State of an aggregate of this context, `None` for other aggregate types
+            pub fn load(aggregate_type: &str, events: &[Value]) -> Option<Value> {
+                match aggregate_type {
+                    #(#names => Some(fold(events, #applies)),)*
+                    _ => None,
+                }
+            }
+
+            /// Copy the payload fields of an event into a state
+            fn copy_payload(state: &mut Value, event: &Value) {
+                let (Some(state), Some(fields)) = (state.as_object_mut(), event.as_object()) else {
+                    return;
+                };
+                for (key, value) in fields {
+                    if !ENVELOPE_FIELDS.contains(&key.as_str()) {
+                        state.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+
+            #(#aggregates)*
+        })
+    }
+
+    fn generate_aggregate_state(model: &IRModel, aggregate: &Aggregate) -> Result<TokenStream> {
+        let snake = to_snake_case(&aggregate.name);
+        let new = format_ident!("new_{}", snake);
+        let apply = format_ident!("apply_{}", snake);
+        let new_doc = doc_lines(&format!(
+            "Initial state of a `{}`: the defaults of its root entity properties",
+            aggregate.name
+        ));
+        let apply_doc = doc_lines(&format!(
+            "Fold an event into the state of a `{}`",
+            aggregate.name
+        ));
+
+        let defaults = aggregate
+            .root_entity
+            .properties
+            .iter()
+            .filter_map(|prop| {
+                let default = prop.default.as_ref()?;
+                let value = match (enum_type(model, &prop.prop_type), default) {
+                    (Some(_), serde_json::Value::String(name)) => {
+                        serde_json::Value::String(to_pascal_case(name))
+                    }
+                    _ => default.clone(),
+                };
+                Some((
+                    to_snake_case(&prop.name),
+                    ExpressionGenerator::constant(&value),
+                ))
+            })
+            .collect::<Vec<_>>();
+        let default_keys = defaults.iter().map(|(key, _)| key);
+        let default_values = defaults.iter().map(|(_, value)| value);
+
+        let identifier = aggregate
+            .root_entity
+            .properties
+            .iter()
+            .find(|prop| prop.identifier)
+            .map(|prop| {
+                let key = to_snake_case(&prop.name);
+                quote! {
+                    if let (Some(state), Some(id)) = (state.as_object_mut(), event.get("aggregate_id")) {
+                        state.entry(#key).or_insert_with(|| id.clone());
+                    }
+                }
+            });
+
+        let statuses = Self::status_events(model, aggregate);
+        let status = if statuses.is_empty() {
+            quote! {}
+        } else {
+            let events = statuses.iter().map(|(event, _)| event);
+            let values = statuses.iter().map(|(_, value)| value);
+            quote! {
+                let status = match event.get("type").and_then(Value::as_str) {
+                    #(Some(#events) => Some(#values),)*
+                    _ => None,
+                };
+                if let (Some(state), Some(status)) = (state.as_object_mut(), status) {
+                    state.insert("status".to_string(), Value::from(status));
+                }
+            }
+        };
+
+        Ok(quote! {
+            #new_doc
+            pub fn #new() -> Value {
+                expressions::object([#((#default_keys, #default_values)),*])
+            }
+
+            #apply_doc
+            pub fn #apply(state: &mut Value, event: &Value) {
+                if !state.is_object() {
+                    *state = #new();
+                }
+                #status
+                copy_payload(state, event);
+                #identifier
+            }
+        })
+    }
+
+    /// Generate the command handler appending the events of each command
+    pub fn generate_handlers(model: &IRModel) -> Result<String> {
+        let handlers = model
+            .commands
+            .iter()
+            .map(|(name, command)| Self::generate_handler(model, name, command, false))
+            .collect::<Result<Vec<_>>>()?;
+        let stored = !Self::stored_aggregates(model).is_empty();
+        let targets = model.commands.values().filter_map(|command| {
+            let aggregate = model.aggregates.get(command.aggregate.as_deref()?)?;
+            Some((aggregate, command))
+        });
+        let checks = model
+            .aggregates
+            .values()
+            .filter(|aggregate| !aggregate.invariants.is_empty())
+            .filter(|aggregate| {
+                targets
+                    .clone()
+                    .any(|(target, _)| target.name == aggregate.name)
+            })
+            .map(Self::generate_check)
+            .collect::<Result<Vec<_>>>()?;
+        let id = targets
+            .clone()
+            .any(|(aggregate, command)| Self::id_field(aggregate, command).is_some())
+            .then(|| {
+                quote! {
+                    /// Aggregate ID held by a command field
+                    fn id(value: &Value) -> String {
+                        match value {
+                            Value::String(id) => id.clone(),
+                            other => other.to_string(),
+                        }
+                    }
+                }
+            });
+        let apply_events = (stored
+            || targets
+                .clone()
+                .any(|(aggregate, _)| !aggregate.invariants.is_empty()))
+        .then(|| {
+                quote! {
+                    /// Fold events into the state of an aggregate
+                    fn apply_events(state: &mut Value, events: &[DomainEvent], apply: fn(&mut Value, &Value)) -> CommandResult<()> {
+                        for event in events {
+                            apply(state, &serde_json::to_value(event).map_err(internal)?);
+                        }
+                        Ok(())
+                    }
+                }
+            });
+
+        let (stored_doc, (serde_imports, stored_imports), version, stored_handler) = if stored {
+            (
+                quote! {
+                    //!
+                    //! `StateStoredHandler` handles the commands of state-stored
+                    //! aggregates instead: it loads the aggregate from its repository and
+                    //! saves it with the events applied, after appending the events at the
+                    //! version of the aggregate's stream. Other commands go to the
+                    //! event-sourced handler.
+                },
+                (
+                    quote! {
+                        use serde::de::DeserializeOwned;
+                        use serde::Serialize;
+                    },
+                    quote! {
+                        use crate::domain::entities::*;
+                        use crate::repository::{Repositories, Repository};
+                    },
+                ),
+                quote! {
+                    /// Number of events stored for an aggregate
+                    async fn version(&self, aggregate_type: &str, aggregate_id: &str) -> CommandResult<i64> {
+                        self.event_store
+                            .version(aggregate_id, aggregate_type)
+                            .await
+                            .map_err(internal)
+                    }
+                },
+                Self::generate_stored_handler(model)?,
+            )
+        } else {
+            (
+                TokenStream::new(),
+                (TokenStream::new(), TokenStream::new()),
+                TokenStream::new(),
+                TokenStream::new(),
+            )
+        };
+
+        render(quote! {
+            //! Command handler backed by the event store
+            //!
+            //! A command is validated, then checked against the state of its
+            //! aggregate folded from the stored events. A command carrying the
+            //! aggregate's identifier acts on that aggregate, any other command
+            //! creates one. Each event takes a field from the `computed` expression
+            //! of the event property, else from the command field, the aggregate ID
+            //! or the aggregate state of the same name; `DateTime` fields the
+            //! command does not give are the time it is handled. The events must
+            //! keep the aggregate's invariants, and are appended with their outbox
+            //! entries at the version the state was loaded at, so a concurrent
+            //! command on the same aggregate fails rather than being lost.
+            #stored_doc
+
+            use async_trait::async_trait;
+            #serde_imports
+            use serde_json::Value;
+            use std::sync::Arc;
+            use uuid::Uuid;
+
+            use crate::domain::commands::*;
+            #stored_imports
+            use crate::domain::events::DomainEvent;
+            use crate::domain::{expressions, state};
+            use crate::infrastructure::event_store::EventStore;
+
+            /// Command handler appending the events of commands to an event store
+            pub struct EventSourcedHandler {
+                event_store: Arc<dyn EventStore>,
+            }
+
+            impl EventSourcedHandler {
+                pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+                    Self { event_store }
+                }
+
+                /// State of an aggregate folded from its events, and its version
+                pub async fn load(&self, aggregate_type: &str, aggregate_id: &str) -> CommandResult<(Value, i64)> {
+                    let events = self
+                        .event_store
+                        .load_events(aggregate_id, aggregate_type)
+                        .await
+                        .map_err(internal)?
+                        .iter()
+                        .map(serde_json::to_value)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(internal)?;
+                    let version = i64::try_from(events.len()).map_err(internal)?;
+                    let state = state::load(aggregate_type, &events).unwrap_or(Value::Null);
+                    Ok((state, version))
+                }
+
+                #version
+
+                /// Append the events of a command at the version its aggregate was loaded at
+                async fn append(
+                    &self,
+                    aggregate_type: &str,
+                    aggregate_id: &str,
+                    events: Vec<DomainEvent>,
+                    version: i64,
+                ) -> CommandResult<Vec<DomainEvent>> {
+                    self.event_store
+                        .append_events(aggregate_id, aggregate_type, events.clone(), Some(version))
+                        .await
+                        .map_err(internal)?;
+                    Ok(events)
+                }
+            }
+
+            fn internal(error: impl std::fmt::Display) -> CommandError {
+                CommandError::InternalError(error.to_string())
+            }
+
+            #id
+
+            /// Event of a type built from its payload fields
+            fn event(event_type: &str, aggregate_id: &str, mut payload: Value) -> CommandResult<DomainEvent> {
+                if let Some(fields) = payload.as_object_mut() {
+                    fields.insert("type".to_string(), Value::from(event_type));
+                    fields.insert("event_id".to_string(), Value::from(Uuid::new_v4().to_string()));
+                    fields.insert("occurred_at".to_string(), expressions::now());
+                    fields.insert("aggregate_id".to_string(), Value::from(aggregate_id));
+                }
+                serde_json::from_value(payload).map_err(|e| {
+                    CommandError::InternalError(format!("Cannot build {}: {}", event_type, e))
+                })
+            }
+
+            #apply_events
+
+            #(#checks)*
+
+            #[async_trait]
+            impl CommandHandler for EventSourcedHandler {
+                #(#handlers)*
+            }
+
+            #stored_handler
+        })
+    }
+
+    /// State-stored aggregates that commands act on
+    pub fn stored_aggregates(model: &IRModel) -> Vec<&Aggregate> {
+        model
+            .aggregates
+            .iter()
+            .filter(|(name, aggregate)| {
+                aggregate.persistence == Persistence::State
+                    && model
+                        .commands
+                        .values()
+                        .any(|command| command.aggregate.as_deref() == Some(name.as_str()))
+            })
+            .map(|(_, aggregate)| aggregate)
+            .collect()
+    }
+
+    /// Command handler keeping state-stored aggregates in their repositories
+    fn generate_stored_handler(model: &IRModel) -> Result<TokenStream> {
+        let handlers = model
+            .commands
+            .iter()
+            .map(|(name, command)| {
+                let stored = command
+                    .aggregate
+                    .as_deref()
+                    .and_then(|aggregate| model.aggregates.get(aggregate))
+                    .is_some_and(|aggregate| aggregate.persistence == Persistence::State);
+                if stored {
+                    return Self::generate_handler(model, name, command, true);
+                }
+                let handler = format_ident!("handle_{}", to_snake_case(name));
+                let command_type = ident(name)?;
+                Ok(quote! {
+                    async fn #handler(&self, command: #command_type) -> CommandResult<Vec<DomainEvent>> {
+                        self.events.#handler(command).await
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            /// Command handler saving state-stored aggregates to their repositories
+            pub struct StateStoredHandler {
+                events: EventSourcedHandler,
+                repositories: Repositories,
+            }
+
+            impl StateStoredHandler {
+                pub fn new(event_store: Arc<dyn EventStore>, repositories: Repositories) -> Self {
+                    Self {
+                        events: EventSourcedHandler::new(event_store),
+                        repositories,
+                    }
+                }
+            }
+
+            /// Stored state of an aggregate, `null` if it is not stored
+            async fn stored_state<T: Serialize>(repository: &dyn Repository<T>, aggregate_id: &str) -> CommandResult<Value> {
+                match repository.find_by_id(aggregate_id).await.map_err(internal)? {
+                    Some(aggregate) => serde_json::to_value(aggregate).map_err(internal),
+                    None => Ok(Value::Null),
+                }
+            }
+
+            /// Aggregate of a state, to be saved to its repository
+            fn stored_aggregate<T: DeserializeOwned>(aggregate_type: &str, state: Value) -> CommandResult<T> {
+                serde_json::from_value(state).map_err(|e| {
+                    CommandError::InternalError(format!("Cannot store {}: {}", aggregate_type, e))
+                })
+            }
+
+            #[async_trait]
+            impl CommandHandler for StateStoredHandler {
+                #(#handlers)*
+            }
+        })
+    }
+
+    /// Handler of a command, loading and saving a state-stored aggregate
+    /// through its repository if `stored`
+    fn generate_handler(
+        model: &IRModel,
+        name: &str,
+        command: &Command,
+        stored: bool,
+    ) -> Result<TokenStream> {
+        let handler = format_ident!("handle_{}", to_snake_case(name));
+        let command_type = ident(name)?;
+        let Some(aggregate) = command
+            .aggregate
+            .as_deref()
+            .and_then(|aggregate| model.aggregates.get(aggregate))
+        else {
+            let message = format!("{} has no aggregate", name);
+            return Ok(quote! {
+                async fn #handler(&self, _command: #command_type) -> CommandResult<Vec<DomainEvent>> {
+                    Err(CommandError::InternalError(#message.to_string()))
+                }
+            });
+        };
+        let aggregate_type = &aggregate.name;
+        let alias = to_snake_case(&aggregate.name);
+
+        let mut sources = Vec::new();
+        let mut events = Vec::new();
+        for event_name in &command.produces {
+            let Some(event) = model.events.get(event_name) else {
+                bail!("Command {} produces unknown event {}", name, event_name);
+            };
+            let mut fields = Vec::new();
+            for prop in &event.payload {
+                let source = FieldSource::of(aggregate, command, prop);
+                let key = to_snake_case(&prop.name);
+                let value = source.value(&key).with_context(|| {
+                    format!("Invalid computed field {}.{}", event_name, prop.name)
+                })?;
+                fields.push(quote! { (#key, #value) });
+                sources.push(source);
+            }
+            events.push(quote! {
+                event(#event_name, &aggregate_id, expressions::object([#(#fields),*]))?
+            });
+        }
+
+        let preconditions: Vec<_> = command
+            .preconditions
+            .iter()
+            .map(|precondition| {
+                let message = &precondition.message;
+                ExpressionGenerator::guard(
+                    &precondition.expression,
+                    &format!("Precondition of command {}", name),
+                    quote! { return Err(CommandError::PreconditionFailed(#message.to_string())); },
+                )
+            })
+            .collect();
+        let enforced = preconditions
+            .iter()
+            .any(|precondition| precondition.enforced);
+        let preconditions: Vec<_> = preconditions
+            .into_iter()
+            .map(|precondition| precondition.tokens)
+            .collect();
+
+        // Preconditions and computed fields read the state through the scope,
+        // other fields may read it directly, and invariants check the new state
+        let scoped = enforced
+            || sources
+                .iter()
+                .any(|source| matches!(source, FieldSource::Computed(_)));
+        let checked = !aggregate.invariants.is_empty();
+        let applied = checked || stored;
+        let reads_state = scoped
+            || applied
+            || sources
+                .iter()
+                .any(|source| matches!(source, FieldSource::State));
+
+        let repository = snake_ident(&aggregate.name)?;
+        let state = if applied {
+            quote! { mut state }
+        } else {
+            quote! { state }
+        };
+        let load = match (Self::id_field(aggregate, command), reads_state) {
+            (Some(field), true) if stored => {
+                let key = to_snake_case(&field.name);
+                quote! {
+                    let aggregate_id = id(&expressions::get(&command, #key));
+                    let mut state = stored_state(self.repositories.#repository.as_ref(), &aggregate_id).await?;
+                    let version = self.events.version(#aggregate_type, &aggregate_id).await?;
+                }
+            }
+            (Some(field), true) => {
+                let key = to_snake_case(&field.name);
+                quote! {
+                    let aggregate_id = id(&expressions::get(&command, #key));
+                    let (#state, version) = self.load(#aggregate_type, &aggregate_id).await?;
+                }
+            }
+            (Some(field), false) => {
+                let key = to_snake_case(&field.name);
+                quote! {
+                    let aggregate_id = id(&expressions::get(&command, #key));
+                    let version = self
+                        .event_store
+                        .version(&aggregate_id, #aggregate_type)
+                        .await
+                        .map_err(internal)?;
+                }
+            }
+            (None, true) => quote! {
+                let aggregate_id = Uuid::new_v4().to_string();
+                let (#state, version) = (Value::Null, 0);
+            },
+            (None, false) => quote! {
+                let aggregate_id = Uuid::new_v4().to_string();
+                let version = 0;
+            },
+        };
+        let missing = if stored {
+            quote! { state.is_null() }
+        } else {
+            quote! { version == 0 }
+        };
+        let exists = (Self::id_field(aggregate, command).is_some() && !preconditions.is_empty())
+            .then(|| {
+                quote! {
+                    if #missing {
+                        return Err(CommandError::AggregateNotFound(aggregate_id));
+                    }
+                }
+            });
+        let scope =
+            scoped.then(|| quote! { let scope = &expressions::with(&command, #alias, &state); });
+        let check = applied.then(|| {
+            let apply = format_ident!("apply_{}", to_snake_case(&aggregate.name));
+            let check = format_ident!("check_{}", to_snake_case(&aggregate.name));
+            let check = checked.then(|| quote! { #check(&state)?; });
+            quote! {
+                apply_events(&mut state, &events, state::#apply)?;
+                #check
+            }
+        });
+        let append = if stored {
+            let entity = ident(&aggregate.name)?;
+            quote! {
+                let aggregate: #entity = stored_aggregate(#aggregate_type, state)?;
+                let events = self.events.append(#aggregate_type, &aggregate_id, events, version).await?;
+                self.repositories.#repository.save(&aggregate).await.map_err(internal)?;
+                Ok(events)
+            }
+        } else {
+            quote! { self.append(#aggregate_type, &aggregate_id, events, version).await }
+        };
+
+        Ok(quote! {
+            async fn #handler(&self, command: #command_type) -> CommandResult<Vec<DomainEvent>> {
+                command.validate()?;
+                let command = serde_json::to_value(&command).map_err(internal)?;
+                #load
+                #exists
+                #scope
+                #(#preconditions)*
+                let events: Vec<DomainEvent> = vec![#(#events),*];
+                #check
+                #append
+            }
+        })
+    }
+
+    /// Invariant check of an aggregate's state
+    fn generate_check(aggregate: &Aggregate) -> Result<TokenStream> {
+        let mut text = format!("Invariants of a `{}`\n", aggregate.name);
+        for invariant in &aggregate.invariants {
+            text.push_str(&format!(
+                "\n- `{}`: {}",
+                invariant.expression,
+                invariant.description.as_deref().unwrap_or(&invariant.name)
+            ));
+        }
+        let doc = doc_lines(&text);
+        let check = format_ident!("check_{}", to_snake_case(&aggregate.name));
+        let invariants: Vec<_> = aggregate
+            .invariants
+            .iter()
+            .map(|invariant| {
+                let message = invariant.description.as_deref().unwrap_or(&invariant.name);
+                ExpressionGenerator::guard(
+                    &invariant.expression,
+                    &format!("Invariant {} of {}", invariant.name, aggregate.name),
+                    quote! { return Err(CommandError::ValidationError(#message.to_string())); },
+                )
+            })
+            .collect();
+        // The state is unused when every invariant is left to a hook
+        let scope = if invariants.iter().any(|invariant| invariant.enforced) {
+            quote! { scope }
+        } else {
+            quote! { _scope }
+        };
+        let invariants = invariants.into_iter().map(|invariant| invariant.tokens);
+
+        Ok(quote! {
+            #doc
+            fn #check(#scope: &Value) -> CommandResult<()> {
+                #(#invariants)*
+                Ok(())
+            }
+        })
+    }
+
+    /// Command field holding the ID of the aggregate the command acts on
+    ///
+    /// That is a field of the aggregate's identifier type, or else one named
+    /// after the aggregate, as `orderId` for `Order`.
+    pub fn id_field<'a>(aggregate: &Aggregate, command: &'a Command) -> Option<&'a Property> {
+        let id_type = Self::identifier(aggregate).map(|prop| prop.prop_type.as_str());
+        let id_name = format!("{}Id", to_camel_case(&aggregate.name));
+        command
+            .payload
+            .iter()
+            .find(|field| Some(field.prop_type.as_str()) == id_type)
+            .or_else(|| command.payload.iter().find(|field| field.name == id_name))
+    }
+
+    /// Whether a command creates its aggregate: it names no aggregate to act
+    /// on, and the events it produces need no field from the aggregate state
+    pub fn creates(model: &IRModel, aggregate: &Aggregate, command: &Command) -> bool {
+        Self::id_field(aggregate, command).is_none()
+            && command
+                .produces
+                .iter()
+                .filter_map(|event| model.events.get(event))
+                .flat_map(|event| &event.payload)
+                .all(|prop| {
+                    !prop.required
+                        || !matches!(
+                            FieldSource::of(aggregate, command, prop),
+                            FieldSource::State
+                        )
+                })
+    }
+
+    pub fn identifier(aggregate: &Aggregate) -> Option<&Property> {
+        aggregate
+            .root_entity
+            .properties
+            .iter()
+            .find(|prop| prop.identifier)
+    }
+
+    /// Events of an aggregate that set its status, with the serialized status value
+    ///
+    /// The status is the root entity's `status` property, or else the
+    /// `<Aggregate>Status` enum. An event sets the value its name ends with,
+    /// the longest one when several match.
+    pub fn status_events(model: &IRModel, aggregate: &Aggregate) -> Vec<(String, String)> {
+        let status_type = aggregate
+            .root_entity
+            .properties
+            .iter()
+            .find(|prop| prop.name == "status")
+            .map(|prop| prop.prop_type.clone())
+            .unwrap_or_else(|| format!("{}Status", aggregate.name));
+        let Some(status) = enum_type(model, &status_type) else {
+            return Vec::new();
+        };
+
+        model
+            .events
+            .values()
+            .filter(|event| event.aggregate.as_deref() == Some(aggregate.name.as_str()))
+            .filter_map(|event| {
+                let name = format!("_{}", event.name.to_shouty_snake_case());
+                let value = status
+                    .values
+                    .iter()
+                    .filter(|value| {
+                        name.ends_with(&format!("_{}", value.name.to_shouty_snake_case()))
+                    })
+                    .max_by_key(|value| value.name.len())?;
+                Some((event.name.clone(), to_pascal_case(&value.name)))
+            })
+            .collect()
+    }
+}
+
+/// Where an event field takes its value from when a command produces the event
+pub enum FieldSource {
+    /// The `computed` expression of the event property
+    Computed(String),
+    /// The command field of the same name
+    Command,
+    /// The ID of the aggregate, for a field of its identifier type
+    AggregateId,
+    /// The time the command is handled, for a `DateTime` field
+    Now,
+    /// The aggregate state property of the same name
+    State,
+}
+
+impl FieldSource {
+    pub fn of(aggregate: &Aggregate, command: &Command, prop: &Property) -> Self {
+        let id_type = AggregateGenerator::identifier(aggregate).map(|id| id.prop_type.as_str());
+        if let Some(computed) = &prop.computed {
+            FieldSource::Computed(computed.clone())
+        } else if command.payload.iter().any(|field| field.name == prop.name) {
+            FieldSource::Command
+        } else if Some(prop.prop_type.as_str()) == id_type
+            || prop.name == format!("{}Id", to_camel_case(&aggregate.name))
+        {
+            FieldSource::AggregateId
+        } else if prop.prop_type == "DateTime" {
+            FieldSource::Now
+        } else {
+            FieldSource::State
+        }
+    }
+
+    /// `serde_json::Value` expression of the field named `key` in the event
+    fn value(&self, key: &str) -> Result<TokenStream> {
+        Ok(match self {
+            FieldSource::Computed(expression) => ExpressionGenerator::value(expression)?,
+            FieldSource::Command => quote! { expressions::get(&command, #key) },
+            FieldSource::AggregateId => quote! { Value::from(aggregate_id.as_str()) },
+            FieldSource::Now => quote! { expressions::now() },
+            FieldSource::State => quote! { expressions::get(&state, #key) },
+        })
+    }
+}
+
+/// Enum value object of an IR type, if it is one
+fn enum_type<'a>(model: &'a IRModel, ir_type: &str) -> Option<&'a ValueObject> {
+    model
+        .value_objects
+        .get(ir_type)
+        .filter(|vo| vo.vo_type.as_deref() == Some("enum"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    #[test]
+    fn test_status_events_match_enum_values() {
+        let model = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Leave"
+  namespace: "test.leave"
+aggregates:
+  LeaveRequest:
+    name: "LeaveRequest"
+    root_entity:
+      name: "LeaveRequest"
+      properties:
+        - name: "status"
+          type: "LeaveStatus"
+value_objects:
+  LeaveStatus:
+    name: "LeaveStatus"
+    type: "enum"
+    values:
+      - name: "PENDING"
+      - name: "APPROVED"
+      - name: "PARTLY_APPROVED"
+events:
+  LeaveRequested:
+    name: "LeaveRequested"
+    aggregate: "LeaveRequest"
+  LeaveApproved:
+    name: "LeaveApproved"
+    aggregate: "LeaveRequest"
+  LeavePartlyApproved:
+    name: "LeavePartlyApproved"
+    aggregate: "LeaveRequest"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            AggregateGenerator::status_events(&model, &model.aggregates["LeaveRequest"]),
+            vec![
+                ("LeaveApproved".to_string(), "Approved".to_string()),
+                (
+                    "LeavePartlyApproved".to_string(),
+                    "PartlyApproved".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_event_fields_take_their_source() {
+        let model = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Orders"
+  namespace: "test.orders"
+aggregates:
+  Order:
+    name: "Order"
+    root_entity:
+      name: "Order"
+      properties:
+        - name: "id"
+          type: "OrderId"
+          identifier: true
+commands:
+  PlaceOrder:
+    name: "PlaceOrder"
+    aggregate: "Order"
+    payload:
+      - name: "customerId"
+        type: "String"
+    produces: ["OrderPlaced"]
+  PayOrder:
+    name: "PayOrder"
+    aggregate: "Order"
+    payload:
+      - name: "orderId"
+        type: "OrderId"
+    produces: ["OrderPaid"]
+    preconditions:
+      - expression: "order.status == PLACED"
+        message: "Order must be placed"
+events:
+  OrderPlaced:
+    name: "OrderPlaced"
+    aggregate: "Order"
+    payload:
+      - name: "orderId"
+        type: "OrderId"
+      - name: "customerId"
+        type: "String"
+  OrderPaid:
+    name: "OrderPaid"
+    aggregate: "Order"
+    payload:
+      - name: "customerId"
+        type: "String"
+      - name: "amount"
+        type: "Decimal"
+        computed: "order.total * 2"
+      - name: "paidAt"
+        type: "DateTime"
+"#,
+        )
+        .unwrap();
+
+        let code = AggregateGenerator::generate_handlers(&model).unwrap();
+        for expected in [
+            "let aggregate_id = Uuid::new_v4().to_string();",
+            "(\"order_id\", Value::from(aggregate_id.as_str()))",
+            "(\"customer_id\", expressions::get(&command, \"customer_id\"))",
+            "let aggregate_id = id(&expressions::get(&command, \"order_id\"));",
+            "return Err(CommandError::AggregateNotFound(aggregate_id));",
+            "(\"customer_id\", expressions::get(&state, \"customer_id\"))",
+            "expressions::mul(",
+            "(\"paid_at\", expressions::now())",
+        ] {
+            assert!(code.contains(expected), "missing {}", expected);
+        }
+    }
+
+    #[test]
+    fn test_untranslatable_rules_leave_todo_hooks() {
+        let model = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Orders"
+  namespace: "test.orders"
+aggregates:
+  Order:
+    name: "Order"
+    root_entity:
+      name: "Order"
+      properties:
+        - name: "id"
+          type: "OrderId"
+          identifier: true
+        - name: "items"
+          type: "List<String>"
+    invariants:
+      - name: "ValidBasket"
+        expression: "isValidBasket(items)"
+commands:
+  CreateOrder:
+    name: "CreateOrder"
+    aggregate: "Order"
+    payload:
+      - name: "items"
+        type: "List<String>"
+    produces: ["OrderCreated"]
+    validation:
+      - expression: "isValidBasket(items)"
+        message: "Basket is invalid"
+    preconditions:
+      - expression: "isValidBasket(order.items)"
+        message: "Basket is invalid"
+events:
+  OrderCreated:
+    name: "OrderCreated"
+    aggregate: "Order"
+    payload:
+      - name: "items"
+        type: "List<String>"
+"#,
+        )
+        .unwrap();
+
+        let commands =
+            crate::generators::command_generator::CommandGenerator::generate(&model).unwrap();
+        assert!(commands.contains("// TODO: enforce `isValidBasket(items)`\n        Ok(())"));
+        assert!(!commands.contains("let scope"));
+
+        let handlers = AggregateGenerator::generate_handlers(&model).unwrap();
+        assert!(handlers.contains("// TODO: enforce `isValidBasket(order.items)`"));
+        assert!(handlers.contains(
+            "fn check_order(_scope: &Value) -> CommandResult<()> {\n    // TODO: enforce `isValidBasket(items)`\n"
+        ));
+        assert!(!handlers.contains("let scope"));
+    }
+}
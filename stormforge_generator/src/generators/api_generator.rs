@@ -1,206 +1,343 @@
+use crate::generators::aggregate_generator::AggregateGenerator;
+use crate::generators::rust_ast::*;
 use crate::generators::utils::*;
 use crate::ir::{Command, IRModel, Query};
 use anyhow::Result;
+use proc_macro2::TokenStream;
+use quote::quote;
 
 pub struct ApiGenerator;
 
 impl ApiGenerator {
     /// Generate Rust code for API endpoints
     pub fn generate(model: &IRModel) -> Result<String> {
-        let mut code = String::new();
-
-        // Add imports
-        code.push_str(&Self::generate_imports(&model.bounded_context.name));
-        code.push_str("\n\n");
-
         // Generate API router
-        code.push_str(&Self::generate_router(model)?);
-        code.push_str("\n\n");
+        let router = Self::generate_router(model)?;
 
         // Generate command endpoints
-        for (name, command) in &model.commands {
-            code.push_str(&Self::generate_command_handler(name, command)?);
-            code.push_str("\n\n");
-        }
+        let command_handlers = model
+            .commands
+            .iter()
+            .map(|(name, command)| Self::generate_command_handler(name, command))
+            .collect::<Result<Vec<_>>>()?;
 
         // Generate query endpoints
-        for (name, query) in &model.queries {
-            code.push_str(&Self::generate_query_handler(name, query)?);
-            code.push_str("\n\n");
-        }
-
-        Ok(code)
+        let query_handlers = model
+            .queries
+            .iter()
+            .map(|(name, query)| Self::generate_query_handler(name, query))
+            .collect::<Result<Vec<_>>>()?;
+
+        let code = quote! {
+            #router
+            #(#command_handlers)*
+            #(#query_handlers)*
+        };
+        let preamble = Self::generate_imports(model, TokenStream::new());
+        let domain = Self::generate_domain_imports(model, &quote! { #preamble #code });
+        let imports = Self::generate_imports(model, domain);
+
+        render(quote! {
+            #imports
+            #code
+        })
     }
 
-    fn generate_imports(context_name: &str) -> String {
-        format!(
-            r#"use axum::{{
-    http::StatusCode,
-    response::{{IntoResponse, Json}},
-    routing::{{get, post}},
-    Router,
-}};
-use serde::{{Deserialize, Serialize}};
-use utoipa::{{ToSchema, OpenApi}};
-
-use crate::domain::{{entities::*, commands::*, events::*}};
-
-/// API documentation
-#[derive(OpenApi)]
-#[openapi(
-    paths(
-        // Command endpoints will be listed here
-    ),
-    components(
-        schemas(ApiError)
-    ),
-    tags(
-        (name = "{}", description = "{} API endpoints")
-    )
-)]
-pub struct ApiDoc;
-
-/// Standard API error response
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
-pub struct ApiError {{
-    pub code: String,
-    pub message: String,
-}}
-
-/// Standard API success response (String variant)
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
-pub struct ApiResponseString {{
-    pub data: String,
-}}"#,
-            context_name, context_name
-        )
+    /// Glob imports of the domain modules whose types `code` mentions
+    fn generate_domain_imports(model: &IRModel, code: &TokenStream) -> TokenStream {
+        let commands: Vec<String> = model
+            .commands
+            .keys()
+            .cloned()
+            .chain(["CommandHandler", "CommandError", "CommandResult"].map(String::from))
+            .collect();
+        let modules: Vec<TokenStream> = [
+            (quote! { entities }, mentions(code, &entity_names(model))),
+            (quote! { commands }, mentions(code, &commands)),
+            (quote! { events }, mentions(code, &event_names(model))),
+        ]
+        .into_iter()
+        .filter(|(_, used)| *used)
+        .map(|(module, _)| quote! { #module::* })
+        .collect();
+        match modules.as_slice() {
+            [] => TokenStream::new(),
+            [module] => quote! { use crate::domain::#module; },
+            modules => quote! { use crate::domain::{#(#modules),*}; },
+        }
     }
 
-    fn generate_router(model: &IRModel) -> Result<String> {
-        let mut code = String::new();
-
-        code.push_str("/// Create the API router\n");
-        code.push_str("pub fn create_router() -> Router {\n");
-        code.push_str("    Router::new()\n");
+    fn generate_imports(model: &IRModel, domain: TokenStream) -> TokenStream {
+        let context_name = &model.bounded_context.name;
+        let tag_description = format!("{} API endpoints", context_name);
+        let (handler_import, constructors) = if AggregateGenerator::stored_aggregates(model)
+            .is_empty()
+        {
+            (
+                quote! { use crate::domain::handlers::EventSourcedHandler; },
+                quote! {
+                    /// State handling commands with the event-sourced handler over `event_store`
+                    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+                        Self {
+                            commands: Arc::new(EventSourcedHandler::new(event_store.clone())),
+                            event_store,
+                        }
+                    }
+                },
+            )
+        } else {
+            (
+                quote! {
+                    use crate::domain::handlers::StateStoredHandler;
+                    use crate::repository::Repositories;
+                },
+                quote! {
+                    /// State keeping the state-stored aggregates in memory
+                    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+                        Self::with_repositories(event_store, Repositories::in_memory())
+                    }
+
+                    /// State handling the commands of state-stored aggregates through
+                    /// `repositories`, and the others with the event-sourced handler over
+                    /// `event_store`
+                    pub fn with_repositories(event_store: Arc<dyn EventStore>, repositories: Repositories) -> Self {
+                        Self {
+                            commands: Arc::new(StateStoredHandler::new(event_store.clone(), repositories)),
+                            event_store,
+                        }
+                    }
+                },
+            )
+        };
+
+        quote! {
+            use axum::{
+                extract::State,
+                http::{header, StatusCode},
+                response::{IntoResponse, Json},
+                routing::{get, post},
+                Router,
+            };
+            use serde::{Deserialize, Serialize};
+            use std::sync::Arc;
+            use utoipa::{ToSchema, OpenApi};
+
+            #domain
+            #handler_import
+            use crate::infrastructure::event_store::EventStore;
+
+            /// API documentation
+            #[derive(OpenApi)]
+            #[openapi(
+                paths(),
+                components(schemas(ApiError, ApiResponseString)),
+                tags((name = #context_name, description = #tag_description))
+            )]
+            pub struct ApiDoc;
+
+            /// AsyncAPI document of the events published and consumed by this service
+            pub const ASYNCAPI: &str = include_str!("../../asyncapi.json");
+
+            /// OpenAPI document of the HTTP API
+            ///
+            /// The component schemas of the AsyncAPI document are merged in, so
+            /// both documents describe payloads with the same schemas.
+            pub fn openapi() -> serde_json::Value {
+                let mut openapi = serde_json::to_value(ApiDoc::openapi())
+                    .expect("OpenAPI document serializes to JSON");
+                let asyncapi: serde_json::Value =
+                    serde_json::from_str(ASYNCAPI).expect("AsyncAPI document is valid JSON");
+
+                if let (Some(schemas), Some(shared)) = (
+                    openapi
+                        .pointer_mut("/components/schemas")
+                        .and_then(|schemas| schemas.as_object_mut()),
+                    asyncapi
+                        .pointer("/components/schemas")
+                        .and_then(|schemas| schemas.as_object()),
+                ) {
+                    for (name, schema) in shared {
+                        schemas.entry(name.clone()).or_insert_with(|| schema.clone());
+                    }
+                }
+                openapi
+            }
+
+            /// Serve the AsyncAPI document
+            pub async fn asyncapi() -> impl IntoResponse {
+                ([(header::CONTENT_TYPE, "application/json")], ASYNCAPI)
+            }
+
+            /// Standard API error response
+            #[derive(Debug, Serialize, Deserialize, ToSchema)]
+            pub struct ApiError {
+                pub code: String,
+                pub message: String,
+            }
+
+            impl ApiError {
+                /// HTTP status of the error code
+                pub fn status_code(&self) -> StatusCode {
+                    match self.code.as_str() {
+                        "VALIDATION_ERROR" => StatusCode::BAD_REQUEST,
+                        "NOT_FOUND" => StatusCode::NOT_FOUND,
+                        "PRECONDITION_FAILED" => StatusCode::CONFLICT,
+                        _ => StatusCode::INTERNAL_SERVER_ERROR,
+                    }
+                }
+            }
+
+            impl From<CommandError> for ApiError {
+                fn from(error: CommandError) -> Self {
+                    let code = match &error {
+                        CommandError::ValidationError(_) => "VALIDATION_ERROR",
+                        CommandError::PreconditionFailed(_) => "PRECONDITION_FAILED",
+                        CommandError::AggregateNotFound(_) => "NOT_FOUND",
+                        CommandError::InternalError(_) => "INTERNAL_ERROR",
+                    };
+                    ApiError {
+                        code: code.to_string(),
+                        message: error.to_string(),
+                    }
+                }
+            }
+
+            /// Standard API success response (String variant)
+            #[derive(Debug, Serialize, Deserialize, ToSchema)]
+            pub struct ApiResponseString {
+                pub data: String,
+            }
+
+            /// State shared by the HTTP, GraphQL and gRPC APIs
+            #[derive(Clone)]
+            pub struct AppState {
+                /// Event store the commands append to
+                pub event_store: Arc<dyn EventStore>,
+                /// Handler of the commands
+                pub commands: Arc<dyn CommandHandler>,
+            }
+
+            impl AppState {
+                #constructors
+            }
+        }
+    }
 
+    fn generate_router(model: &IRModel) -> Result<TokenStream> {
         // Add command routes
-        for (name, _) in &model.commands {
-            let endpoint = format!("/{}", to_kebab_case(name));
-            let handler = to_snake_case(name);
-            code.push_str(&format!(
-                "        .route(\"{}\", post({}))\n",
-                endpoint, handler
-            ));
-        }
+        let command_paths = model
+            .commands
+            .keys()
+            .map(|name| format!("/{}", to_kebab_case(name)));
+        let command_handlers = model
+            .commands
+            .keys()
+            .map(|name| snake_ident(name))
+            .collect::<Result<Vec<_>>>()?;
 
         // Add query routes
-        for (name, _) in &model.queries {
-            let endpoint = format!("/{}", to_kebab_case(name));
-            let handler = to_snake_case(name);
-            code.push_str(&format!(
-                "        .route(\"{}\", get({}))\n",
-                endpoint, handler
-            ));
-        }
-
-        code.push_str("}");
-
-        Ok(code)
+        let query_paths = model
+            .queries
+            .keys()
+            .map(|name| format!("/{}", to_kebab_case(name)));
+        let query_handlers = model
+            .queries
+            .keys()
+            .map(|name| snake_ident(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            /// Create the API router
+            pub fn create_router(state: AppState) -> Router {
+                Router::new()
+                    #(.route(#command_paths, post(#command_handlers)))*
+                    #(.route(#query_paths, get(#query_handlers)))*
+                    .with_state(state)
+            }
+        })
     }
 
-    fn generate_command_handler(name: &str, command: &Command) -> Result<String> {
-        let mut code = String::new();
-        let handler_name = to_snake_case(name);
-
-        // Add documentation
-        if let Some(desc) = &command.description {
-            code.push_str(&format!("/// {}\n", desc));
-        }
-
-        code.push_str(&format!("#[utoipa::path(\n"));
-        code.push_str(&format!("    post,\n"));
-        code.push_str(&format!("    path = \"/{}\",\n", to_kebab_case(name)));
-        code.push_str(&format!("    request_body = {},\n", name));
-        code.push_str(&format!("    responses(\n"));
-        code.push_str(&format!(
-            "        (status = 200, description = \"Command executed successfully\"),\n"
-        ));
-        code.push_str(&format!(
-            "        (status = 400, description = \"Invalid request\", body = ApiError),\n"
-        ));
-        code.push_str(&format!(
-            "        (status = 500, description = \"Internal server error\", body = ApiError)\n"
+    fn generate_command_handler(name: &str, command: &Command) -> Result<TokenStream> {
+        let doc = doc(command.description.as_deref());
+        let handler_name = snake_ident(name)?;
+        let execute_name = ident(&format!("execute_{}", to_snake_case(name)))?;
+        let execute_doc = doc_lines(&format!(
+            "Execute the `{}` command, shared by the HTTP and gRPC APIs",
+            name
         ));
-        code.push_str(&format!("    )\n"));
-        code.push_str(&format!(")]\n"));
-
-        code.push_str(&format!("pub async fn {}(\n", handler_name));
-        code.push_str(&format!("    Json(payload): Json<{}>,\n", name));
-        code.push_str(") -> impl IntoResponse {\n");
-
-        code.push_str("    // Validate command\n");
-        code.push_str("    if let Err(e) = payload.validate() {\n");
-        code.push_str("        return (StatusCode::BAD_REQUEST, Json(ApiError {\n");
-        code.push_str("            code: \"VALIDATION_ERROR\".to_string(),\n");
-        code.push_str("            message: e.to_string(),\n");
-        code.push_str("        })).into_response();\n");
-        code.push_str("    }\n\n");
-
-        code.push_str("    // TODO: Implement command handler logic\n");
-        code.push_str("    // 1. Load aggregate from repository\n");
-        code.push_str("    // 2. Execute command on aggregate\n");
-        code.push_str("    // 3. Store events in event store\n");
-        code.push_str("    // 4. Publish events to event bus\n");
-        code.push_str("    // 5. Return response\n\n");
-
-        code.push_str("    (StatusCode::OK, Json(ApiResponseString {\n");
-        code.push_str("        data: \"Command executed successfully\".to_string(),\n");
-        code.push_str("    })).into_response()\n");
-        code.push_str("}");
-
-        Ok(code)
+        let path = format!("/{}", to_kebab_case(name));
+        let handle = ident(&format!("handle_{}", to_snake_case(name)))?;
+        let name = ident(name)?;
+
+        // Appended events are published by the OutboxRelay started in main.rs
+        Ok(quote! {
+            #doc
+            #[utoipa::path(
+                post,
+                path = #path,
+                request_body = #name,
+                responses(
+                    (status = 200, description = "Command executed successfully", body = ApiResponseString),
+                    (status = 400, description = "Invalid request", body = ApiError),
+                    (status = 404, description = "Aggregate not found", body = ApiError),
+                    (status = 409, description = "Precondition failed", body = ApiError),
+                    (status = 500, description = "Internal server error", body = ApiError)
+                )
+            )]
+            pub async fn #handler_name(
+                State(state): State<AppState>,
+                Json(payload): Json<#name>,
+            ) -> impl IntoResponse {
+                match #execute_name(&state, payload).await {
+                    Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+                    Err(error) => (error.status_code(), Json(error)).into_response(),
+                }
+            }
+
+            #execute_doc
+            pub async fn #execute_name(state: &AppState, command: #name) -> Result<String, ApiError> {
+                state.commands.#handle(command).await?;
+                Ok("Command executed successfully".to_string())
+            }
+        })
     }
 
-    fn generate_query_handler(name: &str, query: &Query) -> Result<String> {
-        let mut code = String::new();
-        let handler_name = to_snake_case(name);
-
-        // Add documentation
-        if let Some(desc) = &query.description {
-            code.push_str(&format!("/// {}\n", desc));
-        }
-
-        code.push_str(&format!("#[utoipa::path(\n"));
-        code.push_str(&format!("    get,\n"));
-        code.push_str(&format!("    path = \"/{}\",\n", to_kebab_case(name)));
-        code.push_str(&format!("    responses(\n"));
-        code.push_str(&format!(
-            "        (status = 200, description = \"Query executed successfully\"),\n"
+    fn generate_query_handler(name: &str, query: &Query) -> Result<TokenStream> {
+        let doc = doc(query.description.as_deref());
+        let handler_name = snake_ident(name)?;
+        let execute_name = ident(&format!("execute_{}", to_snake_case(name)))?;
+        let execute_doc = doc_lines(&format!(
+            "Execute the `{}` query, shared by the HTTP and gRPC APIs",
+            name
         ));
-        code.push_str(&format!(
-            "        (status = 404, description = \"Not found\", body = ApiError),\n"
-        ));
-        code.push_str(&format!(
-            "        (status = 500, description = \"Internal server error\", body = ApiError)\n"
-        ));
-        code.push_str(&format!("    )\n"));
-        code.push_str(&format!(")]\n"));
-
-        code.push_str(&format!(
-            "pub async fn {}() -> impl IntoResponse {{\n",
-            handler_name
-        ));
-
-        code.push_str("    // TODO: Implement query handler logic\n");
-        code.push_str("    // 1. Fetch data from read model/repository\n");
-        code.push_str("    // 2. Transform to response format\n");
-        code.push_str("    // 3. Return response\n\n");
-
-        code.push_str("    (StatusCode::OK, Json(ApiResponseString {\n");
-        code.push_str("        data: \"Query result\".to_string(),\n");
-        code.push_str("    })).into_response()\n");
-        code.push_str("}");
-
-        Ok(code)
+        let path = format!("/{}", to_kebab_case(name));
+
+        // TODO: Implement query handler logic
+        // 1. Fetch data from read model/repository
+        // 2. Transform to response format
+        // 3. Return response
+        Ok(quote! {
+            #doc
+            #[utoipa::path(
+                get,
+                path = #path,
+                responses(
+                    (status = 200, description = "Query executed successfully", body = ApiResponseString),
+                    (status = 404, description = "Not found", body = ApiError),
+                    (status = 500, description = "Internal server error", body = ApiError)
+                )
+            )]
+            pub async fn #handler_name() -> impl IntoResponse {
+                match #execute_name().await {
+                    Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+                    Err(error) => (error.status_code(), Json(error)).into_response(),
+                }
+            }
+
+            #execute_doc
+            pub async fn #execute_name() -> Result<String, ApiError> {
+                Ok("Query result".to_string())
+            }
+        })
     }
 }
@@ -0,0 +1,212 @@
+use crate::generators::schema_generator::*;
+use crate::ir::{ExternalEventSubscription, IRModel};
+use anyhow::Result;
+use serde_json::{json, Map, Value};
+
+/// Path of the AsyncAPI document, relative to the crate root
+pub const ASYNCAPI_PATH: &str = "asyncapi.json";
+
+pub struct AsyncApiGenerator;
+
+impl AsyncApiGenerator {
+    /// Generate the AsyncAPI 3.0 document of the bounded context
+    ///
+    /// Each published event and each `external_events` subscription gets a
+    /// channel addressed `<Context>.<Event>`, the `context` and `event_type`
+    /// of the integration message on the event bus. The component schemas
+    /// are the ones merged into the OpenAPI document of the HTTP API.
+    pub fn generate(model: &IRModel) -> Result<String> {
+        let context = &model.bounded_context.name;
+
+        let mut channels = Map::new();
+        let mut operations = Map::new();
+        let mut messages = Map::new();
+
+        for (name, event) in &model.events {
+            let address = format!("{}.{}", context, name);
+            let message = Self::message(
+                context,
+                name,
+                event.description.as_deref(),
+                SchemaGenerator::reference(name),
+            );
+            messages.insert(address.clone(), message);
+            channels.insert(
+                address.clone(),
+                Self::channel(&address, event.description.as_deref()),
+            );
+            operations.insert(
+                format!("publish{}", name),
+                Self::operation("send", &address, &format!("Publish `{}`", name)),
+            );
+        }
+
+        for subscription in &model.external_events {
+            let address = format!("{}.{}", subscription.context, subscription.event);
+            if !messages.contains_key(&address) {
+                messages.insert(address.clone(), Self::external_message(subscription));
+                channels.insert(address.clone(), Self::channel(&address, None));
+            }
+            let summary = subscription
+                .description
+                .clone()
+                .unwrap_or_else(|| format!("Handle {}", subscription.event));
+            operations.insert(
+                subscription.handler.clone(),
+                Self::operation("receive", &address, &summary),
+            );
+        }
+
+        let mut info = json!({
+            "title": format!("{} Events", context),
+            "version": model.version,
+        });
+        if let Some(description) = &model.bounded_context.description {
+            info["description"] = json!(description);
+        }
+
+        let document = json!({
+            "asyncapi": "3.0.0",
+            "info": info,
+            "defaultContentType": "application/json",
+            "channels": channels,
+            "operations": operations,
+            "components": {
+                "messages": messages,
+                "schemas": SchemaGenerator::component_schemas(model),
+            },
+        });
+
+        let mut content = serde_json::to_string_pretty(&document)?;
+        content.push('\n');
+        Ok(content)
+    }
+
+    fn channel(address: &str, description: Option<&str>) -> Value {
+        let mut channel = Map::new();
+        channel.insert("address".to_string(), json!(address));
+        if let Some(description) = description {
+            channel.insert("description".to_string(), json!(description));
+        }
+        channel.insert(
+            "messages".to_string(),
+            json!({
+                address: { "$ref": format!("#/components/messages/{}", address) },
+            }),
+        );
+        Value::Object(channel)
+    }
+
+    fn operation(action: &str, address: &str, summary: &str) -> Value {
+        json!({
+            "action": action,
+            "summary": summary,
+            "channel": { "$ref": format!("#/channels/{}", address) },
+            "messages": [
+                { "$ref": format!("#/channels/{}/messages/{}", address, address) },
+            ],
+        })
+    }
+
+    /// Message wrapping an event in the integration message envelope
+    fn message(context: &str, event: &str, description: Option<&str>, payload: Value) -> Value {
+        let mut message = json!({ "name": event });
+        if let Some(description) = description {
+            message["description"] = json!(description);
+        }
+        message["payload"] = json!({
+            "schemaFormat": SCHEMA_FORMAT,
+            "schema": {
+                "type": "object",
+                "required": ["message_id", "context", "event_type", "payload"],
+                "properties": {
+                    "message_id": {
+                        "type": "string",
+                        "format": "uuid",
+                        "description": "Unique message identifier, used by consumers for deduplication",
+                    },
+                    "context": {
+                        "type": "string",
+                        "enum": [context],
+                        "description": "Bounded context that published the event",
+                    },
+                    "event_type": {
+                        "type": "string",
+                        "enum": [event],
+                        "description": "Name of the event type",
+                    },
+                    "payload": payload,
+                },
+            },
+        });
+        message
+    }
+
+    /// Message of another context, whose event schema is not part of this model
+    fn external_message(subscription: &ExternalEventSubscription) -> Value {
+        Self::message(
+            &subscription.context,
+            &subscription.event,
+            None,
+            json!({
+                "type": "object",
+                "description": format!("Event published by the {} context", subscription.context),
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    #[test]
+    fn test_generate_channels_and_operations() {
+        let model = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Payment"
+  namespace: "acme.payment"
+events:
+  PaymentCompleted:
+    name: "PaymentCompleted"
+    payload:
+      - name: "amount"
+        type: "Decimal"
+external_events:
+  - context: "Order"
+    event: "OrderCreated"
+    handler: "handleOrderCreated"
+"#,
+        )
+        .unwrap();
+
+        let document: Value =
+            serde_json::from_str(&AsyncApiGenerator::generate(&model).unwrap()).unwrap();
+
+        assert_eq!(document["asyncapi"], "3.0.0");
+        let channels = document["channels"].as_object().unwrap();
+        assert_eq!(
+            channels.keys().collect::<Vec<_>>(),
+            ["Payment.PaymentCompleted", "Order.OrderCreated"]
+        );
+        assert_eq!(
+            document["operations"]["publishPaymentCompleted"]["action"],
+            "send"
+        );
+        assert_eq!(
+            document["operations"]["handleOrderCreated"]["channel"]["$ref"],
+            "#/channels/Order.OrderCreated"
+        );
+
+        let payload = &document["components"]["messages"]["Payment.PaymentCompleted"]["payload"];
+        assert_eq!(payload["schemaFormat"], SCHEMA_FORMAT);
+        assert_eq!(
+            payload["schema"]["properties"]["payload"]["$ref"],
+            "#/components/schemas/PaymentCompleted"
+        );
+        assert!(document["components"]["schemas"]["PaymentCompleted"].is_object());
+    }
+}
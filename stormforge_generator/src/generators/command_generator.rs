@@ -1,135 +1,167 @@
+use crate::generators::expression_generator::ExpressionGenerator;
+use crate::generators::rust_ast::*;
 use crate::generators::utils::*;
 use crate::ir::{Command, IRModel};
 use anyhow::Result;
+use proc_macro2::TokenStream;
+use quote::quote;
 
 pub struct CommandGenerator;
 
 impl CommandGenerator {
     /// Generate Rust code for all commands in the model
     pub fn generate(model: &IRModel) -> Result<String> {
-        let mut code = String::new();
-
-        // Add imports
-        code.push_str(&Self::generate_imports());
-        code.push_str("\n\n");
-
         // Generate command structs
-        for (name, command) in &model.commands {
-            code.push_str(&Self::generate_command(name, command)?);
-            code.push_str("\n\n");
-        }
+        let commands = model
+            .commands
+            .iter()
+            .map(|(name, command)| Self::generate_command(name, command))
+            .collect::<Result<Vec<_>>>()?;
 
         // Generate command handler trait
-        code.push_str(&Self::generate_command_handler_trait(model)?);
-
-        Ok(code)
+        let handler_trait = Self::generate_command_handler_trait(model)?;
+
+        let code = quote! {
+            #(#commands)*
+            #handler_trait
+        };
+        let imports = Self::generate_imports(model, &code);
+
+        render(quote! {
+            #imports
+            #code
+        })
     }
 
-    fn generate_imports() -> String {
-        r#"use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
-use uuid::Uuid;
-use rust_decimal::Decimal;
-use async_trait::async_trait;
-use crate::domain::entities::*;
-use crate::domain::events::*;
-
-pub type CommandResult<T> = Result<T, CommandError>;
-
-#[derive(Debug, thiserror::Error)]
-pub enum CommandError {
-    #[error("Validation error: {0}")]
-    ValidationError(String),
-    
-    #[error("Precondition failed: {0}")]
-    PreconditionFailed(String),
-    
-    #[error("Aggregate not found: {0}")]
-    AggregateNotFound(String),
-    
-    #[error("Internal error: {0}")]
-    InternalError(String),
-}"#
-        .to_string()
-    }
-
-    fn generate_command(name: &str, command: &Command) -> Result<String> {
-        let mut code = String::new();
-
-        // Add documentation
-        if let Some(desc) = &command.description {
-            code.push_str(&format!("/// {}\n", desc));
-        }
-
-        code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
-        code.push_str(&format!("pub struct {} {{\n", name));
-
-        for prop in &command.payload {
-            if let Some(desc) = &prop.description {
-                code.push_str(&format!("    /// {}\n", desc));
+    /// Imports of the types `code` mentions, with the command error type
+    fn generate_imports(model: &IRModel, code: &TokenStream) -> TokenStream {
+        let expressions = model
+            .commands
+            .values()
+            .any(|command| !command.validation.is_empty())
+            .then(|| quote! { use crate::domain::expressions; });
+
+        let chrono = use_mentioned(quote! { chrono }, &["DateTime", "Utc"], code);
+        let uuid = use_mentioned(quote! { uuid }, &["Uuid"], code);
+        let decimal = use_mentioned(quote! { rust_decimal }, &["Decimal"], code);
+        let entities = mentions(code, &entity_names(model))
+            .then(|| quote! { use crate::domain::entities::*; });
+        let events =
+            mentions(code, &event_names(model)).then(|| quote! { use crate::domain::events::*; });
+
+        quote! {
+            use serde::{Deserialize, Serialize};
+            #chrono
+            #uuid
+            #decimal
+            use async_trait::async_trait;
+            #entities
+            #events
+            #expressions
+
+            pub type CommandResult<T> = Result<T, CommandError>;
+
+            #[derive(Debug, thiserror::Error)]
+            pub enum CommandError {
+                #[error("Validation error: {0}")]
+                ValidationError(String),
+
+                #[error("Precondition failed: {0}")]
+                PreconditionFailed(String),
+
+                #[error("Aggregate not found: {0}")]
+                AggregateNotFound(String),
+
+                #[error("Internal error: {0}")]
+                InternalError(String),
             }
-
-            let rust_type = to_rust_type(&prop.prop_type);
-            let field_type = if !prop.required {
-                format!("Option<{}>", rust_type)
-            } else {
-                rust_type
-            };
-
-            code.push_str(&format!(
-                "    pub {}: {},\n",
-                to_snake_case(&prop.name),
-                field_type
-            ));
-        }
-
-        code.push_str("}\n\n");
-
-        // Generate command implementation with validation
-        code.push_str(&format!("impl {} {{\n", name));
-        code.push_str("    /// Validate the command\n");
-        code.push_str("    pub fn validate(&self) -> CommandResult<()> {\n");
-
-        // Add validation logic
-        for validation in &command.validation {
-            // Simple validation - in production this would be more sophisticated
-            code.push_str(&format!("        // Validation: {}\n", validation.message));
         }
-
-        code.push_str("        Ok(())\n");
-        code.push_str("    }\n");
-        code.push_str("}");
-
-        Ok(code)
     }
 
-    fn generate_command_handler_trait(model: &IRModel) -> Result<String> {
-        let mut code = String::new();
-
-        code.push_str("/// Command handler trait for processing commands\n");
-        code.push_str("#[async_trait]\n");
-        code.push_str("pub trait CommandHandler: Send + Sync {\n");
-
-        for (name, command) in &model.commands {
-            let handler_name = format!("handle_{}", to_snake_case(name));
-            let events: Vec<String> = command.produces.iter().map(|e| e.clone()).collect();
-            let event_types = if events.is_empty() {
-                "Vec<DomainEvent>".to_string()
-            } else {
-                "Vec<DomainEvent>".to_string()
-            };
-
-            if let Some(desc) = &command.description {
-                code.push_str(&format!("    /// {}\n", desc));
+    fn generate_command(name: &str, command: &Command) -> Result<TokenStream> {
+        let doc = doc(command.description.as_deref());
+        let graphql = graphql_derives(name, &command.payload, false, true);
+        let name = ident(name)?;
+        let fields = struct_fields(&command.payload)?;
+
+        let mut validate_doc = String::from("Validate the command");
+        if !command.validation.is_empty() {
+            validate_doc.push_str("\n\nRules:");
+            for validation in &command.validation {
+                validate_doc.push_str(&format!(
+                    "\n- `{}`: {}",
+                    validation.expression, validation.message
+                ));
             }
-            code.push_str(&format!(
-                "    async fn {}(&self, command: {}) -> CommandResult<{}>;\n\n",
-                handler_name, name, event_types
-            ));
         }
+        let validate_doc = doc_lines(&validate_doc);
+        let rules: Vec<_> = command
+            .validation
+            .iter()
+            .map(|validation| {
+                let message = &validation.message;
+                ExpressionGenerator::guard(
+                    &validation.expression,
+                    &format!("Validation rule of command {}", name),
+                    quote! { return Err(CommandError::ValidationError(#message.to_string())); },
+                )
+            })
+            .collect();
+        let enforced = rules.iter().any(|rule| rule.enforced);
+        let rules = rules.into_iter().map(|rule| rule.tokens);
+        let body = if !enforced {
+            quote! {
+                #(#rules)*
+                Ok(())
+            }
+        } else {
+            quote! {
+                let command = serde_json::to_value(self)
+                    .map_err(|e| CommandError::InternalError(e.to_string()))?;
+                let scope = &command;
+                #(#rules)*
+                Ok(())
+            }
+        };
+
+        Ok(quote! {
+            #doc
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            #graphql
+            pub struct #name {
+                #(#fields,)*
+            }
 
-        code.push_str("}");
+            impl #name {
+                #validate_doc
+                pub fn validate(&self) -> CommandResult<()> {
+                    #body
+                }
+            }
+        })
+    }
 
-        Ok(code)
+    fn generate_command_handler_trait(model: &IRModel) -> Result<TokenStream> {
+        let methods = model
+            .commands
+            .iter()
+            .map(|(name, command)| {
+                let doc = doc(command.description.as_deref());
+                let handler_name = ident(&format!("handle_{}", to_snake_case(name)))?;
+                let name = ident(name)?;
+                Ok(quote! {
+                    #doc
+                    async fn #handler_name(&self, command: #name) -> CommandResult<Vec<DomainEvent>>;
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            /// Command handler trait for processing commands
+            #[async_trait]
+            pub trait CommandHandler: Send + Sync {
+                #(#methods)*
+            }
+        })
     }
 }
@@ -0,0 +1,630 @@
+use crate::ir::{IRModel, Property};
+use indexmap::IndexMap;
+
+/// Diagram language to render
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagramFormat {
+    Mermaid,
+    PlantUml,
+    Dot,
+}
+
+impl DiagramFormat {
+    /// Conventional file extension of the format
+    pub fn extension(self) -> &'static str {
+        match self {
+            DiagramFormat::Mermaid => "mmd",
+            DiagramFormat::PlantUml => "puml",
+            DiagramFormat::Dot => "dot",
+        }
+    }
+}
+
+pub struct DiagramGenerator;
+
+/// Event storming sticky note a node stands for
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    Aggregate,
+    Command,
+    Event,
+    Policy,
+    /// `external_events` handler of the subscribing context
+    Handler,
+    /// Event of a context that is not part of the diagram
+    ExternalEvent,
+}
+
+impl NodeKind {
+    fn class(self) -> &'static str {
+        match self {
+            NodeKind::Aggregate => "aggregate",
+            NodeKind::Command => "command",
+            NodeKind::Event => "event",
+            NodeKind::Policy => "policy",
+            NodeKind::Handler => "handler",
+            NodeKind::ExternalEvent => "externalEvent",
+        }
+    }
+
+    fn stereotype(self) -> &'static str {
+        match self {
+            NodeKind::Aggregate => "Aggregate",
+            NodeKind::Command => "Command",
+            NodeKind::Event => "Event",
+            NodeKind::Policy => "Policy",
+            NodeKind::Handler => "Handler",
+            NodeKind::ExternalEvent => "ExternalEvent",
+        }
+    }
+
+    /// Event storming colors
+    fn color(self) -> &'static str {
+        match self {
+            NodeKind::Aggregate => "#FFF59D",
+            NodeKind::Command => "#90CAF9",
+            NodeKind::Event => "#FFB74D",
+            NodeKind::Policy => "#CE93D8",
+            NodeKind::Handler => "#F48FB1",
+            NodeKind::ExternalEvent => "#FFE0B2",
+        }
+    }
+
+    const ALL: [NodeKind; 6] = [
+        NodeKind::Aggregate,
+        NodeKind::Command,
+        NodeKind::Event,
+        NodeKind::Policy,
+        NodeKind::Handler,
+        NodeKind::ExternalEvent,
+    ];
+}
+
+struct Node {
+    id: String,
+    kind: NodeKind,
+    title: String,
+    /// Property lines below the title
+    lines: Vec<String>,
+}
+
+/// Bounded context, drawn as a subgraph
+struct Cluster {
+    id: String,
+    label: String,
+    nodes: Vec<Node>,
+}
+
+struct Edge {
+    from: String,
+    to: String,
+    label: Option<String>,
+    dashed: bool,
+}
+
+/// Format independent diagram of one or more bounded contexts
+#[derive(Default)]
+struct Diagram {
+    clusters: IndexMap<String, Cluster>,
+    edges: Vec<Edge>,
+}
+
+impl Diagram {
+    fn new(contexts: &[IRModel]) -> Self {
+        let mut diagram = Self::default();
+        for model in contexts {
+            diagram.add_context(model);
+        }
+        for model in contexts {
+            diagram.add_flows(model, contexts);
+        }
+        diagram
+    }
+
+    fn add_context(&mut self, model: &IRModel) {
+        let context = &model.bounded_context.name;
+        let mut nodes = Vec::new();
+
+        for (name, aggregate) in &model.aggregates {
+            nodes.push(Node {
+                id: node_id(context, NodeKind::Aggregate, name),
+                kind: NodeKind::Aggregate,
+                title: name.clone(),
+                lines: aggregate
+                    .root_entity
+                    .properties
+                    .iter()
+                    .map(property_line)
+                    .collect(),
+            });
+        }
+        for name in model.commands.keys() {
+            nodes.push(Node {
+                id: node_id(context, NodeKind::Command, name),
+                kind: NodeKind::Command,
+                title: name.clone(),
+                lines: Vec::new(),
+            });
+        }
+        for name in model.events.keys() {
+            nodes.push(Node {
+                id: node_id(context, NodeKind::Event, name),
+                kind: NodeKind::Event,
+                title: name.clone(),
+                lines: Vec::new(),
+            });
+        }
+        for policy in &model.policies {
+            let lines = match &policy.saga {
+                Some(saga) => match saga.timeout_seconds {
+                    Some(timeout) => vec![format!("saga, times out after {}s", timeout)],
+                    None => vec!["saga".to_string()],
+                },
+                None => Vec::new(),
+            };
+            nodes.push(Node {
+                id: node_id(context, NodeKind::Policy, &policy.name),
+                kind: NodeKind::Policy,
+                title: policy.name.clone(),
+                lines,
+            });
+        }
+        for subscription in &model.external_events {
+            nodes.push(Node {
+                id: node_id(context, NodeKind::Handler, &subscription.handler),
+                kind: NodeKind::Handler,
+                title: subscription.handler.clone(),
+                lines: Vec::new(),
+            });
+        }
+
+        self.clusters.insert(
+            context.clone(),
+            Cluster {
+                id: sanitize(context),
+                label: context.clone(),
+                nodes,
+            },
+        );
+    }
+
+    fn add_flows(&mut self, model: &IRModel, contexts: &[IRModel]) {
+        let context = &model.bounded_context.name;
+        let event = |name: &str| {
+            model
+                .events
+                .contains_key(name)
+                .then(|| node_id(context, NodeKind::Event, name))
+        };
+        let command = |name: &str| {
+            model
+                .commands
+                .contains_key(name)
+                .then(|| node_id(context, NodeKind::Command, name))
+        };
+
+        // Command -> aggregate -> event, labelled with the command where the
+        // aggregate hides which command produced the event
+        for (name, cmd) in &model.commands {
+            let command_id = node_id(context, NodeKind::Command, name);
+            let aggregate_id = cmd
+                .aggregate
+                .as_deref()
+                .filter(|aggregate| model.aggregates.contains_key(*aggregate))
+                .map(|aggregate| node_id(context, NodeKind::Aggregate, aggregate));
+            if let Some(aggregate_id) = &aggregate_id {
+                self.edge(&command_id, aggregate_id, None, false);
+            }
+            for produced in &cmd.produces {
+                let Some(event_id) = event(produced) else {
+                    continue;
+                };
+                match &aggregate_id {
+                    Some(aggregate_id) => self.edge(aggregate_id, &event_id, Some(name), false),
+                    None => self.edge(&command_id, &event_id, None, false),
+                }
+            }
+        }
+
+        for policy in &model.policies {
+            let policy_id = node_id(context, NodeKind::Policy, &policy.name);
+            for trigger in &policy.triggers {
+                if let Some(event_id) = event(trigger) {
+                    self.edge(&event_id, &policy_id, None, false);
+                }
+            }
+            for action in &policy.actions {
+                if let Some(command_id) = command(action) {
+                    self.edge(&policy_id, &command_id, None, false);
+                }
+            }
+            if let Some(saga) = &policy.saga {
+                for completed_by in &saga.completed_by {
+                    if let Some(event_id) = event(completed_by) {
+                        self.edge(&event_id, &policy_id, Some("completes"), true);
+                    }
+                }
+                for compensation in &saga.compensation {
+                    if let Some(command_id) = command(compensation) {
+                        self.edge(&policy_id, &command_id, Some("compensates"), true);
+                    }
+                }
+            }
+        }
+
+        // Events of contexts outside the diagram get a node of their own
+        for subscription in &model.external_events {
+            let published = contexts.iter().any(|other| {
+                other.bounded_context.name == subscription.context
+                    && other.events.contains_key(&subscription.event)
+            });
+            let event_id = if published {
+                node_id(&subscription.context, NodeKind::Event, &subscription.event)
+            } else {
+                self.external_event(&subscription.context, &subscription.event)
+            };
+            let handler_id = node_id(context, NodeKind::Handler, &subscription.handler);
+            self.edge(&event_id, &handler_id, None, true);
+        }
+    }
+
+    fn external_event(&mut self, context: &str, event: &str) -> String {
+        let id = node_id(context, NodeKind::ExternalEvent, event);
+        let cluster = self
+            .clusters
+            .entry(context.to_string())
+            .or_insert_with(|| Cluster {
+                id: sanitize(context),
+                label: format!("{} (external)", context),
+                nodes: Vec::new(),
+            });
+        if !cluster.nodes.iter().any(|node| node.id == id) {
+            cluster.nodes.push(Node {
+                id: id.clone(),
+                kind: NodeKind::ExternalEvent,
+                title: event.to_string(),
+                lines: Vec::new(),
+            });
+        }
+        id
+    }
+
+    fn edge(&mut self, from: &str, to: &str, label: Option<&str>, dashed: bool) {
+        self.edges.push(Edge {
+            from: from.to_string(),
+            to: to.to_string(),
+            label: label.map(str::to_string),
+            dashed,
+        });
+    }
+}
+
+impl DiagramGenerator {
+    /// Render bounded contexts as a diagram
+    ///
+    /// Each context is a subgraph of event storming notes: aggregates with
+    /// their properties, commands, events, policies and `external_events`
+    /// handlers. Edges follow commands through their aggregate to the events
+    /// in `produces`, events through policies to commands, and subscribed
+    /// events to their handlers in other contexts.
+    pub fn generate(contexts: &[IRModel], format: DiagramFormat) -> String {
+        let diagram = Diagram::new(contexts);
+        match format {
+            DiagramFormat::Mermaid => Self::mermaid(&diagram),
+            DiagramFormat::PlantUml => Self::plantuml(&diagram),
+            DiagramFormat::Dot => Self::dot(&diagram),
+        }
+    }
+
+    fn mermaid(diagram: &Diagram) -> String {
+        let escape = |text: &str| {
+            text.replace('"', "#quot;")
+                .replace('<', "#lt;")
+                .replace('>', "#gt;")
+        };
+
+        let mut out = String::from("flowchart LR\n");
+        for cluster in diagram.clusters.values() {
+            out.push_str(&format!(
+                "    subgraph {}[\"{}\"]\n",
+                cluster.id,
+                escape(&cluster.label)
+            ));
+            for node in &cluster.nodes {
+                let mut label = escape(&node.title);
+                if !node.lines.is_empty() {
+                    label = format!("<b>{}</b>", label);
+                    for line in &node.lines {
+                        label.push_str("<br/>");
+                        label.push_str(&escape(line));
+                    }
+                }
+                let (open, close) = match node.kind {
+                    NodeKind::Policy => ("{{", "}}"),
+                    NodeKind::Handler => ("([", "])"),
+                    _ => ("[", "]"),
+                };
+                out.push_str(&format!(
+                    "        {}{}\"{}\"{}:::{}\n",
+                    node.id,
+                    open,
+                    label,
+                    close,
+                    node.kind.class()
+                ));
+            }
+            out.push_str("    end\n");
+        }
+        for edge in &diagram.edges {
+            let arrow = if edge.dashed { "-.->" } else { "-->" };
+            match &edge.label {
+                Some(label) => out.push_str(&format!(
+                    "    {} {}|{}| {}\n",
+                    edge.from, arrow, label, edge.to
+                )),
+                None => out.push_str(&format!("    {} {} {}\n", edge.from, arrow, edge.to)),
+            }
+        }
+        for kind in NodeKind::ALL {
+            out.push_str(&format!(
+                "    classDef {} fill:{},stroke:#555,color:#000\n",
+                kind.class(),
+                kind.color()
+            ));
+        }
+        out
+    }
+
+    fn plantuml(diagram: &Diagram) -> String {
+        let escape = |text: &str| text.replace('"', "'");
+
+        let mut out = String::from("@startuml\nleft to right direction\n");
+        for kind in NodeKind::ALL {
+            out.push_str(&format!(
+                "skinparam rectangle<<{}>> {{\n  BackgroundColor {}\n}}\n",
+                kind.stereotype(),
+                kind.color()
+            ));
+        }
+        for cluster in diagram.clusters.values() {
+            out.push_str(&format!("\npackage \"{}\" {{\n", escape(&cluster.label)));
+            for node in &cluster.nodes {
+                let mut label = escape(&node.title);
+                if !node.lines.is_empty() {
+                    label = format!("**{}**\\n----", label);
+                    for line in &node.lines {
+                        label.push_str("\\n");
+                        label.push_str(&escape(line));
+                    }
+                }
+                out.push_str(&format!(
+                    "  rectangle \"{}\" as {} <<{}>>\n",
+                    label,
+                    node.id,
+                    node.kind.stereotype()
+                ));
+            }
+            out.push_str("}\n");
+        }
+        out.push('\n');
+        for edge in &diagram.edges {
+            let arrow = if edge.dashed { "..>" } else { "-->" };
+            match &edge.label {
+                Some(label) => out.push_str(&format!(
+                    "{} {} {} : {}\n",
+                    edge.from, arrow, edge.to, label
+                )),
+                None => out.push_str(&format!("{} {} {}\n", edge.from, arrow, edge.to)),
+            }
+        }
+        out.push_str("@enduml\n");
+        out
+    }
+
+    fn dot(diagram: &Diagram) -> String {
+        let escape = |text: &str| text.replace('\\', "\\\\").replace('"', "\\\"");
+        // Characters with a meaning in record labels
+        let escape_record = |text: &str| {
+            let mut escaped = String::new();
+            for c in escape(text).chars() {
+                if matches!(c, '{' | '}' | '|' | '<' | '>') {
+                    escaped.push('\\');
+                }
+                escaped.push(c);
+            }
+            escaped
+        };
+
+        let mut out = String::from(
+            "digraph StormForge {\n    rankdir=LR;\n    node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n    edge [fontname=\"Helvetica\", fontsize=10];\n",
+        );
+        for cluster in diagram.clusters.values() {
+            out.push_str(&format!(
+                "\n    subgraph cluster_{} {{\n        label=\"{}\";\n        style=dashed;\n",
+                cluster.id,
+                escape(&cluster.label)
+            ));
+            for node in &cluster.nodes {
+                let (shape, label) = if node.lines.is_empty() {
+                    let shape = match node.kind {
+                        NodeKind::Policy => "hexagon",
+                        NodeKind::Handler => "ellipse",
+                        _ => "box",
+                    };
+                    (shape, escape(&node.title))
+                } else {
+                    let lines: String = node
+                        .lines
+                        .iter()
+                        .map(|line| format!("{}\\l", escape_record(line)))
+                        .collect();
+                    (
+                        "record",
+                        format!("{{{}|{}}}", escape_record(&node.title), lines),
+                    )
+                };
+                out.push_str(&format!(
+                    "        {} [label=\"{}\", shape={}, fillcolor=\"{}\"];\n",
+                    node.id,
+                    label,
+                    shape,
+                    node.kind.color()
+                ));
+            }
+            out.push_str("    }\n");
+        }
+        out.push('\n');
+        for edge in &diagram.edges {
+            let mut attributes = Vec::new();
+            if let Some(label) = &edge.label {
+                attributes.push(format!("label=\"{}\"", escape(label)));
+            }
+            if edge.dashed {
+                attributes.push("style=dashed".to_string());
+            }
+            if attributes.is_empty() {
+                out.push_str(&format!("    {} -> {};\n", edge.from, edge.to));
+            } else {
+                out.push_str(&format!(
+                    "    {} -> {} [{}];\n",
+                    edge.from,
+                    edge.to,
+                    attributes.join(", ")
+                ));
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Identifier of a node, unique across contexts and note kinds
+fn node_id(context: &str, kind: NodeKind, name: &str) -> String {
+    format!("{}_{}_{}", sanitize(context), kind.class(), sanitize(name))
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// `name: Type` line of a property, with `?` marking optional ones
+fn property_line(prop: &Property) -> String {
+    let optional = if prop.required { "" } else { "?" };
+    format!("{}{}: {}", prop.name, optional, prop.prop_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn contexts() -> Vec<IRModel> {
+        let order = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+aggregates:
+  Order:
+    name: "Order"
+    root_entity:
+      name: "Order"
+      properties:
+        - name: "lines"
+          type: "List<OrderLine>"
+        - name: "note"
+          type: "String"
+          required: false
+commands:
+  PlaceOrder:
+    name: "PlaceOrder"
+    aggregate: "Order"
+    produces: ["OrderPlaced"]
+  CancelOrder:
+    name: "CancelOrder"
+    aggregate: "Order"
+    produces: ["OrderCancelled"]
+events:
+  OrderPlaced:
+    name: "OrderPlaced"
+    aggregate: "Order"
+  OrderCancelled:
+    name: "OrderCancelled"
+    aggregate: "Order"
+policies:
+  - name: "CancelUnpaid"
+    triggers: ["OrderPlaced"]
+    saga:
+      completed_by: ["OrderCancelled"]
+      compensation: ["CancelOrder"]
+external_events:
+  - context: "Payment"
+    event: "PaymentCompleted"
+    handler: "handlePaymentCompleted"
+"#,
+        )
+        .unwrap();
+        let shipping = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Shipping"
+  namespace: "acme.shipping"
+external_events:
+  - context: "Order"
+    event: "OrderPlaced"
+    handler: "handleOrderPlaced"
+"#,
+        )
+        .unwrap();
+        vec![order, shipping]
+    }
+
+    #[test]
+    fn test_mermaid_flows() {
+        let diagram = DiagramGenerator::generate(&contexts(), DiagramFormat::Mermaid);
+
+        assert!(diagram.contains(
+            "Order_aggregate_Order[\"<b>Order</b><br/>lines: List#lt;OrderLine#gt;<br/>note?: String\"]:::aggregate"
+        ));
+        assert!(diagram.contains("    Order_command_PlaceOrder --> Order_aggregate_Order\n"));
+        assert!(
+            diagram.contains("    Order_aggregate_Order -->|PlaceOrder| Order_event_OrderPlaced\n")
+        );
+        assert!(diagram.contains("    Order_event_OrderPlaced --> Order_policy_CancelUnpaid\n"));
+        assert!(diagram.contains(
+            "    Order_policy_CancelUnpaid -.->|compensates| Order_command_CancelOrder\n"
+        ));
+        // Subscriptions link to the publishing context when it is part of the diagram
+        assert!(diagram
+            .contains("    Order_event_OrderPlaced -.-> Shipping_handler_handleOrderPlaced\n"));
+        assert!(diagram.contains("    subgraph Payment[\"Payment (external)\"]\n"));
+        assert!(diagram.contains(
+            "    Payment_externalEvent_PaymentCompleted -.-> Order_handler_handlePaymentCompleted\n"
+        ));
+    }
+
+    #[test]
+    fn test_plantuml_and_dot_escaping() {
+        let plantuml = DiagramGenerator::generate(&contexts(), DiagramFormat::PlantUml);
+        assert!(plantuml.starts_with("@startuml\n"));
+        assert!(plantuml.contains(
+            "  rectangle \"**Order**\\n----\\nlines: List<OrderLine>\\nnote?: String\" as Order_aggregate_Order <<Aggregate>>\n"
+        ));
+        assert!(
+            plantuml.contains("Order_aggregate_Order --> Order_event_OrderPlaced : PlaceOrder\n")
+        );
+
+        let dot = DiagramGenerator::generate(&contexts(), DiagramFormat::Dot);
+        assert!(dot.contains(
+            "label=\"{Order|lines: List\\<OrderLine\\>\\lnote?: String\\l}\", shape=record"
+        ));
+        assert!(dot.contains("    subgraph cluster_Shipping {\n"));
+        assert!(dot.contains(
+            "    Order_event_OrderCancelled -> Order_policy_CancelUnpaid [label=\"completes\", style=dashed];\n"
+        ));
+    }
+}
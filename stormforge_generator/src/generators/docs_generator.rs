@@ -0,0 +1,1358 @@
+use crate::generators::utils::*;
+use crate::ir::{Condition, IRModel, IRProject, Property, ValueObject};
+use anyhow::{Context, Result};
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, TagEnd};
+use std::fs;
+
+pub struct DocsGenerator {
+    output_dir: String,
+}
+
+/// Markdown page of the documentation site, rendered to HTML as well
+struct Page {
+    /// Path relative to the site root, without extension
+    path: String,
+    /// Title of the HTML page
+    title: String,
+    markdown: String,
+}
+
+impl DocsGenerator {
+    pub fn new(output_dir: String) -> Self {
+        Self { output_dir }
+    }
+
+    /// Generate a static documentation site for the bounded contexts of a
+    /// project
+    ///
+    /// Each context gets a directory of pages, linked from the index page of
+    /// the site. Every page is written as Markdown and as HTML, so the site
+    /// can be browsed from a repository or served as is.
+    pub fn generate(&self, project: &IRProject) -> Result<()> {
+        println!(
+            "🚀 Generating documentation for {} bounded context(s)...",
+            project.contexts.len()
+        );
+
+        for model in &project.contexts {
+            for dir in ["aggregates", "commands", "events", "queries"] {
+                let dir = format!(
+                    "{}/{}/{}",
+                    self.output_dir,
+                    context_dir(&model.bounded_context.name),
+                    dir
+                );
+                fs::create_dir_all(&dir)
+                    .with_context(|| format!("Failed to create directory: {}", dir))?;
+            }
+        }
+
+        for page in Self::pages(project) {
+            let html = Self::render_html(&page);
+            for (extension, content) in [("md", &page.markdown), ("html", &html)] {
+                let path = format!("{}/{}.{}", self.output_dir, page.path, extension);
+                fs::write(&path, content).with_context(|| format!("Failed to write {}", path))?;
+            }
+        }
+
+        let path = format!("{}/style.css", self.output_dir);
+        fs::write(&path, STYLE).with_context(|| format!("Failed to write {}", path))?;
+
+        println!("✅ Generation complete! Output at: {}", self.output_dir);
+
+        Ok(())
+    }
+
+    fn pages(project: &IRProject) -> Vec<Page> {
+        let mut pages = vec![Page {
+            path: "index".to_string(),
+            title: "Bounded Contexts".to_string(),
+            markdown: Self::site_index(project),
+        }];
+        for model in &project.contexts {
+            let context = &model.bounded_context.name;
+            for page in Self::context_pages(project, model) {
+                pages.push(Page {
+                    path: format!("{}/{}", context_dir(context), page.path),
+                    title: format!("{} · {}", page.title, context),
+                    markdown: page.markdown,
+                });
+            }
+        }
+        pages
+    }
+
+    /// Pages of a bounded context, with paths relative to its directory
+    fn context_pages(project: &IRProject, model: &IRModel) -> Vec<Page> {
+        let mut pages = vec![Page {
+            path: "index".to_string(),
+            title: format!("{} Context", model.bounded_context.name),
+            markdown: Self::context_page(project, model),
+        }];
+        for name in model.aggregates.keys() {
+            pages.push(Page {
+                path: format!("aggregates/{}", name),
+                title: name.clone(),
+                markdown: Self::aggregate_page(model, name),
+            });
+        }
+        for name in model.commands.keys() {
+            pages.push(Page {
+                path: format!("commands/{}", name),
+                title: name.clone(),
+                markdown: Self::command_page(model, name),
+            });
+        }
+        for name in model.events.keys() {
+            pages.push(Page {
+                path: format!("events/{}", name),
+                title: name.clone(),
+                markdown: Self::event_page(project, model, name),
+            });
+        }
+        for name in model.queries.keys() {
+            pages.push(Page {
+                path: format!("queries/{}", name),
+                title: name.clone(),
+                markdown: Self::query_page(model, name),
+            });
+        }
+        pages.push(Page {
+            path: "glossary".to_string(),
+            title: "Glossary".to_string(),
+            markdown: Self::glossary_page(model),
+        });
+        pages
+    }
+
+    /// Index of the site, linking the bounded contexts and the events they
+    /// exchange
+    fn site_index(project: &IRProject) -> String {
+        let mut out = String::from(
+            "# Bounded Contexts\n\nEach context documents its aggregates, commands, events and queries, with a glossary of its ubiquitous language.\n\n| Context | Namespace | Description |\n| --- | --- | --- |\n",
+        );
+        for model in &project.contexts {
+            let context = &model.bounded_context;
+            out.push_str(&format!(
+                "| {} | `{}` | {} |\n",
+                context_link(project, &context.name, ""),
+                context.namespace,
+                cell(context.description.as_deref())
+            ));
+        }
+
+        let subscriptions: Vec<String> = project
+            .contexts
+            .iter()
+            .flat_map(|model| {
+                model.external_events.iter().map(|subscription| {
+                    format!(
+                        "| {} | {} | {} | {} |\n",
+                        external_event_link(
+                            project,
+                            &subscription.context,
+                            &subscription.event,
+                            ""
+                        ),
+                        context_link(project, &subscription.context, ""),
+                        context_link(project, &model.bounded_context.name, ""),
+                        cell(subscription.description.as_deref())
+                    )
+                })
+            })
+            .collect();
+        if !subscriptions.is_empty() {
+            out.push_str(
+                "\n## Integration\n\nEvents one context publishes and another reacts to.\n\n| Event | Published by | Subscribed by | Description |\n| --- | --- | --- | --- |\n",
+            );
+            out.push_str(&subscriptions.concat());
+        }
+
+        out
+    }
+
+    fn context_page(project: &IRProject, model: &IRModel) -> String {
+        let context = &model.bounded_context;
+        let mut out = format!(
+            "# {} Context\n\n[Bounded Contexts](../index.md) › {}\n\n",
+            context.name, context.name
+        );
+        if let Some(description) = &context.description {
+            out.push_str(&format!("{}\n\n", description.trim()));
+        }
+        out.push_str(&format!(
+            "Namespace `{}`, IR version {}. The [glossary](glossary.md) lists the ubiquitous language of the context.\n",
+            context.namespace, model.version
+        ));
+
+        if !model.aggregates.is_empty() {
+            out.push_str("\n## Aggregates\n\n| Aggregate | Description |\n| --- | --- |\n");
+            for (name, aggregate) in &model.aggregates {
+                out.push_str(&format!(
+                    "| {} | {} |\n",
+                    link(name, &format!("aggregates/{}.md", name)),
+                    cell(aggregate.description.as_deref())
+                ));
+            }
+        }
+
+        if !model.commands.is_empty() {
+            out.push_str(
+                "\n## Commands\n\n| Command | Aggregate | Produces | Description |\n| --- | --- | --- | --- |\n",
+            );
+            for (name, command) in &model.commands {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    link(name, &format!("commands/{}.md", name)),
+                    command
+                        .aggregate
+                        .as_deref()
+                        .map(|aggregate| aggregate_link(model, aggregate, ""))
+                        .unwrap_or_default(),
+                    event_links(model, &command.produces, ""),
+                    cell(command.description.as_deref())
+                ));
+            }
+        }
+
+        if !model.events.is_empty() {
+            out.push_str(
+                "\n## Events\n\n| Event | Aggregate | Description |\n| --- | --- | --- |\n",
+            );
+            for (name, event) in &model.events {
+                out.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    link(name, &format!("events/{}.md", name)),
+                    event
+                        .aggregate
+                        .as_deref()
+                        .map(|aggregate| aggregate_link(model, aggregate, ""))
+                        .unwrap_or_default(),
+                    cell(event.description.as_deref())
+                ));
+            }
+        }
+
+        if !model.queries.is_empty() {
+            out.push_str(
+                "\n## Queries\n\n| Query | Returns | Description |\n| --- | --- | --- |\n",
+            );
+            for (name, query) in &model.queries {
+                out.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    link(name, &format!("queries/{}.md", name)),
+                    query
+                        .returns
+                        .as_ref()
+                        .map(|returns| type_text(model, &returns.return_type, ""))
+                        .unwrap_or_default(),
+                    cell(query.description.as_deref())
+                ));
+            }
+        }
+
+        if !model.policies.is_empty() {
+            out.push_str(
+                "\n## Policies\n\n| Policy | When | Then | Description |\n| --- | --- | --- | --- |\n",
+            );
+            for policy in &model.policies {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    link(
+                        &policy.name,
+                        &format!("glossary.md#{}", anchor(&policy.name))
+                    ),
+                    event_links(model, &policy.triggers, ""),
+                    command_links(model, &policy.actions, ""),
+                    cell(policy.description.as_deref())
+                ));
+            }
+        }
+
+        if !model.external_events.is_empty() {
+            out.push_str(
+                "\n## Subscribed Events\n\nEvents of other contexts this context reacts to.\n\n| Event | Published by | Handler | Description |\n| --- | --- | --- | --- |\n",
+            );
+            for subscription in &model.external_events {
+                out.push_str(&format!(
+                    "| {} | {} | `{}` | {} |\n",
+                    external_event_link(project, &subscription.context, &subscription.event, "../"),
+                    context_link(project, &subscription.context, "../"),
+                    subscription.handler,
+                    cell(subscription.description.as_deref())
+                ));
+            }
+        }
+
+        out
+    }
+
+    fn aggregate_page(model: &IRModel, name: &str) -> String {
+        let aggregate = &model.aggregates[name];
+        let mut out = page_header(model, name, "Aggregate", aggregate.description.as_deref());
+
+        out.push_str("\n## Properties\n\n");
+        out.push_str(&property_table(
+            model,
+            &aggregate.root_entity.properties,
+            "../",
+        ));
+
+        if !aggregate.invariants.is_empty() {
+            out.push_str("\n## Invariants\n\nRules that always hold for every instance.\n\n");
+            for invariant in &aggregate.invariants {
+                out.push_str(&format!("- **{}**", invariant.name));
+                if let Some(description) = &invariant.description {
+                    out.push_str(&format!(": {}", description.trim()));
+                }
+                out.push_str(&format!(
+                    "\n  - Reads as: {}\n  - Expression: `{}`\n",
+                    readable(&invariant.expression),
+                    invariant.expression
+                ));
+            }
+        }
+
+        let commands: Vec<_> = model
+            .commands
+            .iter()
+            .filter(|(_, command)| command.aggregate.as_deref() == Some(name))
+            .collect();
+        if !commands.is_empty() {
+            out.push_str("\n## Commands\n\n");
+            for (command_name, command) in commands {
+                out.push_str(&format!(
+                    "- {}",
+                    link(command_name, &format!("../commands/{}.md", command_name))
+                ));
+                if !command.produces.is_empty() {
+                    out.push_str(&format!(
+                        " produces {}",
+                        event_links(model, &command.produces, "../")
+                    ));
+                }
+                out.push('\n');
+            }
+        }
+
+        let events: Vec<&String> = model
+            .events
+            .iter()
+            .filter(|(_, event)| event.aggregate.as_deref() == Some(name))
+            .map(|(event_name, _)| event_name)
+            .collect();
+        if !events.is_empty() {
+            out.push_str("\n## Events\n\n");
+            for event_name in events {
+                out.push_str(&format!(
+                    "- {}\n",
+                    link(event_name, &format!("../events/{}.md", event_name))
+                ));
+            }
+        }
+
+        out
+    }
+
+    fn command_page(model: &IRModel, name: &str) -> String {
+        let command = &model.commands[name];
+        let mut out = page_header(model, name, "Command", command.description.as_deref());
+
+        if let Some(aggregate) = &command.aggregate {
+            out.push_str(&format!(
+                "\nHandled by the {} aggregate.\n",
+                aggregate_link(model, aggregate, "../")
+            ));
+        }
+
+        out.push_str("\n## Payload\n\n");
+        out.push_str(&property_table(model, &command.payload, "../"));
+
+        if !command.validation.is_empty() {
+            out.push_str(
+                "\n## Validation\n\nChecked on the payload before the command is handled.\n\n",
+            );
+            out.push_str(&condition_list(&command.validation));
+        }
+        if !command.preconditions.is_empty() {
+            out.push_str(
+                "\n## Preconditions\n\nChecked against the current state of the aggregate.\n\n",
+            );
+            out.push_str(&condition_list(&command.preconditions));
+        }
+
+        if !command.produces.is_empty() {
+            out.push_str("\n## Produces\n\n");
+            for event in &command.produces {
+                out.push_str(&format!("- {}\n", event_link(model, event, "../")));
+            }
+        }
+
+        let mut dispatched_by = Vec::new();
+        for policy in &model.policies {
+            if policy.actions.iter().any(|action| action == name) {
+                dispatched_by.push(format!("- {}\n", policy_link(&policy.name, "../")));
+            }
+            let compensates = policy
+                .saga
+                .as_ref()
+                .is_some_and(|saga| saga.compensation.iter().any(|command| command == name));
+            if compensates {
+                dispatched_by.push(format!(
+                    "- {} (compensation)\n",
+                    policy_link(&policy.name, "../")
+                ));
+            }
+        }
+        if !dispatched_by.is_empty() {
+            out.push_str("\n## Dispatched By\n\n");
+            out.push_str(&dispatched_by.concat());
+        }
+
+        out
+    }
+
+    fn event_page(project: &IRProject, model: &IRModel, name: &str) -> String {
+        let event = &model.events[name];
+        let mut out = page_header(model, name, "Event", event.description.as_deref());
+
+        if let Some(aggregate) = &event.aggregate {
+            out.push_str(&format!(
+                "\nEmitted by the {} aggregate.\n",
+                aggregate_link(model, aggregate, "../")
+            ));
+        }
+
+        out.push_str("\n## Payload\n\n");
+        out.push_str(&property_table(model, &event.payload, "../"));
+
+        let produced_by: Vec<&String> = model
+            .commands
+            .iter()
+            .filter(|(_, command)| command.produces.iter().any(|event| event == name))
+            .map(|(command_name, _)| command_name)
+            .collect();
+        if !produced_by.is_empty() {
+            out.push_str("\n## Produced By\n\n");
+            for command in produced_by {
+                out.push_str(&format!(
+                    "- {}\n",
+                    link(command, &format!("../commands/{}.md", command))
+                ));
+            }
+        }
+
+        let triggers: Vec<String> = model
+            .policies
+            .iter()
+            .filter(|policy| policy.triggers.iter().any(|trigger| trigger == name))
+            .map(|policy| {
+                let then = if policy.actions.is_empty() {
+                    String::new()
+                } else {
+                    format!(
+                        ", which dispatches {}",
+                        command_links(model, &policy.actions, "../")
+                    )
+                };
+                format!("- {}{}\n", policy_link(&policy.name, "../"), then)
+            })
+            .collect();
+        if !triggers.is_empty() {
+            out.push_str("\n## Triggers\n\n");
+            out.push_str(&triggers.concat());
+        }
+
+        let read_models: Vec<&String> = model
+            .read_models
+            .iter()
+            .filter(|(_, read_model)| {
+                read_model
+                    .updated_by_events
+                    .iter()
+                    .any(|event| event == name)
+            })
+            .map(|(read_model_name, _)| read_model_name)
+            .collect();
+        if !read_models.is_empty() {
+            out.push_str("\n## Updates Read Models\n\n");
+            for read_model in read_models {
+                out.push_str(&format!(
+                    "- {}\n",
+                    link(
+                        read_model,
+                        &format!("../glossary.md#{}", anchor(read_model))
+                    )
+                ));
+            }
+        }
+
+        let subscribers: Vec<String> = project
+            .contexts
+            .iter()
+            .flat_map(|subscriber| {
+                subscriber
+                    .external_events
+                    .iter()
+                    .filter(|subscription| {
+                        subscription.context == model.bounded_context.name
+                            && subscription.event == name
+                    })
+                    .map(|subscription| {
+                        let mut line = format!(
+                            "- {}",
+                            context_link(project, &subscriber.bounded_context.name, "../../")
+                        );
+                        if let Some(description) = &subscription.description {
+                            line.push_str(&format!(": {}", cell(Some(description))));
+                        }
+                        line.push('\n');
+                        line
+                    })
+            })
+            .collect();
+        if !subscribers.is_empty() {
+            out.push_str("\n## Subscribed By\n\n");
+            out.push_str(&subscribers.concat());
+        }
+
+        out
+    }
+
+    fn query_page(model: &IRModel, name: &str) -> String {
+        let query = &model.queries[name];
+        let mut out = page_header(model, name, "Query", query.description.as_deref());
+
+        out.push_str("\n## Parameters\n\n");
+        out.push_str(&property_table(model, &query.parameters, "../"));
+
+        if let Some(returns) = &query.returns {
+            out.push_str(&format!(
+                "\n## Returns\n\n{}{}\n",
+                type_text(model, &returns.return_type, "../"),
+                if returns.nullable {
+                    ", or nothing when there is no match"
+                } else {
+                    ""
+                }
+            ));
+        }
+
+        out
+    }
+
+    fn glossary_page(model: &IRModel) -> String {
+        // (term, kind, details)
+        let mut terms: Vec<(&str, &str, String)> = Vec::new();
+        for (name, aggregate) in &model.aggregates {
+            terms.push((
+                name,
+                "Aggregate",
+                entry(
+                    aggregate.description.as_deref(),
+                    &format!("aggregates/{}.md", name),
+                ),
+            ));
+        }
+        for (name, vo) in &model.value_objects {
+            terms.push((name, value_object_kind(vo), value_object_entry(model, vo)));
+        }
+        for (name, command) in &model.commands {
+            terms.push((
+                name,
+                "Command",
+                entry(
+                    command.description.as_deref(),
+                    &format!("commands/{}.md", name),
+                ),
+            ));
+        }
+        for (name, event) in &model.events {
+            terms.push((
+                name,
+                "Event",
+                entry(event.description.as_deref(), &format!("events/{}.md", name)),
+            ));
+        }
+        for (name, query) in &model.queries {
+            terms.push((
+                name,
+                "Query",
+                entry(
+                    query.description.as_deref(),
+                    &format!("queries/{}.md", name),
+                ),
+            ));
+        }
+        for policy in &model.policies {
+            let mut details = description_paragraph(policy.description.as_deref());
+            details.push_str(&format!(
+                "When {} happens",
+                event_links(model, &policy.triggers, "")
+            ));
+            if !policy.actions.is_empty() {
+                details.push_str(&format!(
+                    ", dispatch {}",
+                    command_links(model, &policy.actions, "")
+                ));
+            }
+            details.push_str(".\n");
+            if !policy.conditions.is_empty() {
+                let conditions: Vec<String> = policy
+                    .conditions
+                    .iter()
+                    .map(|condition| condition.message.trim().to_string())
+                    .collect();
+                details.push_str(&format!("\nOnly when: {}.\n", conditions.join("; ")));
+            }
+            if let Some(saga) = &policy.saga {
+                details.push_str("\nRuns as a saga");
+                if !saga.completed_by.is_empty() {
+                    let events: Vec<String> = saga
+                        .completed_by
+                        .iter()
+                        .map(|event| event_link(model, event, ""))
+                        .collect();
+                    details.push_str(&format!(" until {}", events.join(" or ")));
+                }
+                if let Some(timeout) = saga.timeout_seconds {
+                    details.push_str(&format!(", timing out after {} seconds", timeout));
+                }
+                if !saga.compensation.is_empty() {
+                    details.push_str(&format!(
+                        "; compensated by {}",
+                        command_links(model, &saga.compensation, "")
+                    ));
+                }
+                details.push_str(".\n");
+            }
+            terms.push((&policy.name, "Policy", details));
+        }
+        for (name, read_model) in &model.read_models {
+            let mut details = description_paragraph(read_model.description.as_deref());
+            if !read_model.updated_by_events.is_empty() {
+                details.push_str(&format!(
+                    "Updated by {}.\n",
+                    event_links(model, &read_model.updated_by_events, "")
+                ));
+            }
+            terms.push((name, "Read model", details));
+        }
+        terms.sort_by_key(|(term, _, _)| term.to_lowercase());
+
+        let mut out = format!(
+            "# Glossary\n\n[{} Context](index.md) › Glossary\n\nThe ubiquitous language of the {} context.\n",
+            model.bounded_context.name, model.bounded_context.name
+        );
+        for (term, kind, details) in terms {
+            out.push_str(&format!("\n## {}\n\n*{}*\n\n{}", term, kind, details));
+        }
+        out
+    }
+
+    /// Wrap the HTML of a page in the site layout
+    fn render_html(page: &Page) -> String {
+        let root = "../".repeat(page.path.matches('/').count());
+
+        let events: Vec<Event> = Parser::new_ext(&page.markdown, Options::ENABLE_TABLES).collect();
+        let mut rewritten = Vec::with_capacity(events.len());
+        for (index, event) in events.iter().enumerate() {
+            let event = match event {
+                // Links between pages point to the HTML pages
+                Event::Start(Tag::Link {
+                    link_type,
+                    dest_url,
+                    title,
+                    id,
+                }) => Event::Start(Tag::Link {
+                    link_type: *link_type,
+                    dest_url: html_link(dest_url),
+                    title: title.clone(),
+                    id: id.clone(),
+                }),
+                // Headings get the ids glossary links point to
+                Event::Start(Tag::Heading {
+                    level,
+                    id: None,
+                    classes,
+                    attrs,
+                }) => {
+                    let text: String = events[index + 1..]
+                        .iter()
+                        .take_while(|event| !matches!(event, Event::End(TagEnd::Heading(_))))
+                        .filter_map(|event| match event {
+                            Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                            _ => None,
+                        })
+                        .collect();
+                    Event::Start(Tag::Heading {
+                        level: *level,
+                        id: Some(anchor(&text).into()),
+                        classes: classes.clone(),
+                        attrs: attrs.clone(),
+                    })
+                }
+                event => event.clone(),
+            };
+            rewritten.push(event);
+        }
+        let mut body = String::new();
+        pulldown_cmark::html::push_html(&mut body, rewritten.into_iter());
+
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+<link rel="stylesheet" href="{root}style.css">
+</head>
+<body>
+<main>
+{body}</main>
+</body>
+</html>
+"#,
+            title = escape_html(&page.title),
+            root = root,
+            body = body
+        )
+    }
+}
+
+/// Title, breadcrumb and description of an element page
+fn page_header(model: &IRModel, name: &str, kind: &str, description: Option<&str>) -> String {
+    let mut out = format!(
+        "# {}\n\n[{} Context](../index.md) › {}\n",
+        name, model.bounded_context.name, kind
+    );
+    if let Some(description) = description {
+        out.push_str(&format!("\n{}\n", description.trim()));
+    }
+    out
+}
+
+fn description_paragraph(description: Option<&str>) -> String {
+    match description {
+        Some(description) => format!("{}\n\n", description.trim()),
+        None => String::new(),
+    }
+}
+
+/// Glossary details of an element with its own page
+fn entry(description: Option<&str>, page: &str) -> String {
+    format!(
+        "{}See {}.\n",
+        description_paragraph(description),
+        link("details", page)
+    )
+}
+
+fn value_object_kind(vo: &ValueObject) -> &'static str {
+    match vo.vo_type.as_deref() {
+        Some("enum") => "Enumeration",
+        Some("identifier") => "Identifier",
+        _ => "Value object",
+    }
+}
+
+fn value_object_entry(model: &IRModel, vo: &ValueObject) -> String {
+    let mut out = description_paragraph(vo.description.as_deref());
+    match vo.vo_type.as_deref() {
+        Some("enum") => {
+            out.push_str("| Value | Description |\n| --- | --- |\n");
+            for value in &vo.values {
+                out.push_str(&format!(
+                    "| `{}` | {} |\n",
+                    value.name,
+                    cell(value.description.as_deref())
+                ));
+            }
+        }
+        Some("identifier") => {
+            out.push_str(&format!(
+                "Identifies an instance by a {}",
+                type_text(model, vo.underlying_type.as_deref().unwrap_or("String"), "")
+            ));
+            if let Some(format) = &vo.format {
+                out.push_str(&format!(" in `{}` format", format));
+            }
+            if let Some(prefix) = &vo.prefix {
+                out.push_str(&format!(", prefixed with `{}`", prefix));
+            }
+            out.push_str(".\n");
+        }
+        _ => out.push_str(&property_table(model, &vo.properties, "")),
+    }
+    out
+}
+
+/// Table of properties with their types and validation rules
+fn property_table(model: &IRModel, properties: &[Property], root: &str) -> String {
+    if properties.is_empty() {
+        return "None.\n".to_string();
+    }
+    let mut out = String::from(
+        "| Property | Type | Required | Rules | Description |\n| --- | --- | --- | --- | --- |\n",
+    );
+    for prop in properties {
+        let required = match (prop.identifier, prop.required) {
+            (true, _) => "Yes (identifier)",
+            (false, true) => "Yes",
+            (false, false) => "No",
+        };
+        out.push_str(&format!(
+            "| `{}` | {} | {} | {} | {} |\n",
+            prop.name,
+            type_text(model, &prop.prop_type, root),
+            required,
+            rules(prop).join("; "),
+            cell(prop.description.as_deref())
+        ));
+    }
+    out
+}
+
+/// Readable validation rules, defaults and computations of a property
+fn rules(prop: &Property) -> Vec<String> {
+    let mut rules = Vec::new();
+    if let Some(validation) = &prop.validation {
+        match (validation.min, validation.max) {
+            (Some(min), Some(max)) => rules.push(format!("between {} and {}", min, max)),
+            (Some(min), None) => rules.push(format!("at least {}", min)),
+            (None, Some(max)) => rules.push(format!("at most {}", max)),
+            (None, None) => {}
+        }
+        // Length rules of a list bound its number of items
+        let unit = if prop.prop_type.trim().starts_with("List<") {
+            "items"
+        } else {
+            "characters"
+        };
+        match (validation.min_length, validation.max_length) {
+            (Some(min), Some(max)) => rules.push(format!("{} to {} {}", min, max, unit)),
+            (Some(min), None) => rules.push(format!("at least {} {}", min, unit)),
+            (None, Some(max)) => rules.push(format!("at most {} {}", max, unit)),
+            (None, None) => {}
+        }
+        if let Some(pattern) = &validation.pattern {
+            rules.push(format!("matches `{}`", pattern.replace('|', "\\|")));
+        }
+        if let Some(precision) = validation.precision {
+            rules.push(format!("{} decimal places", precision));
+        }
+    }
+    if let Some(default) = &prop.default {
+        rules.push(format!("defaults to `{}`", default));
+    }
+    if let Some(computed) = &prop.computed {
+        rules.push(format!("computed as {}", readable(computed)));
+    }
+    rules
+}
+
+/// Validation rules or preconditions as a list of messages
+fn condition_list(conditions: &[Condition]) -> String {
+    conditions
+        .iter()
+        .map(|condition| {
+            format!(
+                "- {}: {} (`{}`)\n",
+                condition.message.trim(),
+                readable(&condition.expression),
+                condition.expression
+            )
+        })
+        .collect()
+}
+
+/// Readable form of an IR type, linking to the types the IR defines
+fn type_text(model: &IRModel, ir_type: &str, root: &str) -> String {
+    let ir_type = ir_type.trim();
+    let generic = |prefix: &str| {
+        ir_type
+            .strip_prefix(prefix)
+            .map(|rest| type_text(model, rest.strip_suffix('>').unwrap_or(rest), root))
+    };
+    if let Some(item) = generic("List<").or_else(|| generic("Vec<")) {
+        return format!("list of {}", item);
+    }
+    if let Some(inner) = generic("Option<") {
+        return format!("{} (optional)", inner);
+    }
+    if let Some(item) = generic("PagedResult<") {
+        return format!("page of {}", item);
+    }
+
+    let name = to_rust_type(ir_type);
+    if model.aggregates.contains_key(&name) {
+        aggregate_link(model, &name, root)
+    } else if model.value_objects.contains_key(&name) || model.read_models.contains_key(&name) {
+        link(&name, &format!("{}glossary.md#{}", root, anchor(&name)))
+    } else {
+        ir_type.to_string()
+    }
+}
+
+/// Directory of the pages of a bounded context
+fn context_dir(context: &str) -> String {
+    to_kebab_case(context)
+}
+
+/// Link to the index page of a context, if the project documents it
+fn context_link(project: &IRProject, context: &str, root: &str) -> String {
+    if project.context(context).is_some() {
+        link(
+            context,
+            &format!("{}{}/index.md", root, context_dir(context)),
+        )
+    } else {
+        context.to_string()
+    }
+}
+
+/// Link to the page of an event another context publishes, if the project
+/// documents it
+fn external_event_link(project: &IRProject, context: &str, event: &str, root: &str) -> String {
+    if project.find_event(context, event).is_some() {
+        link(
+            event,
+            &format!("{}{}/events/{}.md", root, context_dir(context), event),
+        )
+    } else {
+        event.to_string()
+    }
+}
+
+fn link(text: &str, target: &str) -> String {
+    format!("[{}]({})", text, target)
+}
+
+fn aggregate_link(model: &IRModel, name: &str, root: &str) -> String {
+    if model.aggregates.contains_key(name) {
+        link(name, &format!("{}aggregates/{}.md", root, name))
+    } else {
+        name.to_string()
+    }
+}
+
+fn event_link(model: &IRModel, name: &str, root: &str) -> String {
+    if model.events.contains_key(name) {
+        link(name, &format!("{}events/{}.md", root, name))
+    } else {
+        name.to_string()
+    }
+}
+
+fn event_links(model: &IRModel, names: &[String], root: &str) -> String {
+    names
+        .iter()
+        .map(|name| event_link(model, name, root))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn command_links(model: &IRModel, names: &[String], root: &str) -> String {
+    names
+        .iter()
+        .map(|name| {
+            if model.commands.contains_key(name) {
+                link(name, &format!("{}commands/{}.md", root, name))
+            } else {
+                name.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn policy_link(name: &str, root: &str) -> String {
+    link(name, &format!("{}glossary.md#{}", root, anchor(name)))
+}
+
+/// Id of the heading of a glossary term
+fn anchor(text: &str) -> String {
+    text.trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            ' ' => Some('-'),
+            _ => None,
+        })
+        .collect()
+}
+
+/// First paragraph of a description, fit for a table cell
+fn cell(description: Option<&str>) -> String {
+    description
+        .and_then(|description| description.trim().split("\n\n").next())
+        .unwrap_or_default()
+        .replace('\n', " ")
+        .replace('|', "\\|")
+}
+
+fn html_link(dest: &CowStr) -> CowStr<'static> {
+    let (path, fragment) = match dest.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment)),
+        None => (dest.as_ref(), None),
+    };
+    let path = match path.strip_suffix(".md") {
+        Some(stem) if !path.contains("://") => format!("{}.html", stem),
+        _ => path.to_string(),
+    };
+    match fragment {
+        Some(fragment) => format!("{}#{}", path, fragment).into(),
+        None => path.into(),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Plain English reading of an IR expression
+///
+/// Operators become words, `a.b` paths become "a b" and `.length` the
+/// number of items. The result is a reading aid next to the expression,
+/// not a translation of every construct.
+fn readable(expression: &str) -> String {
+    let tokens = tokenize(expression);
+    let mut index = 0;
+    let words = phrase(&tokens, &mut index, None);
+    let text = words.join(" ");
+    text.replace(" ,", ",")
+        .replace("( ", "(")
+        .replace(" )", ")")
+}
+
+fn tokenize(expression: &str) -> Vec<String> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        let start = index;
+        if c.is_whitespace() {
+            index += 1;
+            continue;
+        }
+        if c.is_alphanumeric() || c == '_' {
+            while index < chars.len()
+                && (chars[index].is_alphanumeric() || matches!(chars[index], '_' | '.'))
+            {
+                index += 1;
+            }
+        } else if c == '\'' || c == '"' {
+            index += 1;
+            while index < chars.len() && chars[index] != c {
+                index += 1;
+            }
+            index = (index + 1).min(chars.len());
+        } else if index + 1 < chars.len()
+            && matches!(
+                (c, chars[index + 1]),
+                ('=', '=') | ('!', '=') | ('>', '=') | ('<', '=') | ('&', '&') | ('|', '|')
+            )
+        {
+            index += 2;
+        } else {
+            index += 1;
+        }
+        tokens.push(chars[start..index].iter().collect());
+    }
+    tokens
+}
+
+/// Words of the tokens up to the closing token, which is consumed
+fn phrase(tokens: &[String], index: &mut usize, until: Option<&str>) -> Vec<String> {
+    let mut words = Vec::new();
+    while *index < tokens.len() {
+        let token = tokens[*index].as_str();
+        *index += 1;
+        if Some(token) == until {
+            break;
+        }
+        let word = match token {
+            "==" | "=" => "is".to_string(),
+            "!=" => "is not".to_string(),
+            ">=" => "is at least".to_string(),
+            "<=" => "is at most".to_string(),
+            ">" => "is greater than".to_string(),
+            "<" => "is less than".to_string(),
+            "&&" | "and" => "and".to_string(),
+            "||" | "or" => "or".to_string(),
+            "!" | "not" => "not".to_string(),
+            "in" => "is one of".to_string(),
+            "[" | "]" => continue,
+            "(" => format!("({})", phrase(tokens, index, Some(")")).join(" ")),
+            _ if tokens.get(*index).map(String::as_str) == Some("(") => {
+                *index += 1;
+                let arguments = phrase(tokens, index, Some(")"));
+                call_words(token, &arguments)
+            }
+            _ => path_words(token),
+        };
+        words.push(word);
+    }
+    words
+}
+
+fn call_words(function: &str, arguments: &[String]) -> String {
+    let arguments = arguments
+        .split(|word| word == ",")
+        .map(|argument| argument.join(" "))
+        .filter(|argument| !argument.is_empty())
+        .collect::<Vec<_>>()
+        .join(" and ");
+    let prefix = match function {
+        "sum" => "the sum of".to_string(),
+        "count" | "len" | "length" => "the number of".to_string(),
+        "min" => "the smallest of".to_string(),
+        "max" => "the largest of".to_string(),
+        "avg" | "average" => "the average of".to_string(),
+        _ => path_words(function),
+    };
+    if arguments.is_empty() {
+        prefix
+    } else {
+        format!("{} {}", prefix, arguments)
+    }
+}
+
+fn path_words(token: &str) -> String {
+    let starts_like_name = token
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_');
+    // Literals, enum values and constants are kept as written
+    if !starts_like_name
+        || token == "true"
+        || token == "false"
+        || token.chars().all(|c| !c.is_lowercase())
+    {
+        return token.to_string();
+    }
+
+    let segments: Vec<&str> = token.split('.').collect();
+    let words = |segments: &[&str]| {
+        segments
+            .iter()
+            .map(|segment| to_snake_case(segment).replace('_', " "))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    match segments.split_last() {
+        Some((last, rest)) if !rest.is_empty() && matches!(*last, "length" | "size" | "count") => {
+            format!("the number of {}", words(rest))
+        }
+        _ => words(&segments),
+    }
+}
+
+const STYLE: &str = r#"body {
+  font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Helvetica, Arial, sans-serif;
+  line-height: 1.5;
+  color: #24292f;
+  margin: 0;
+}
+
+main {
+  max-width: 960px;
+  margin: 0 auto;
+  padding: 2rem;
+}
+
+a {
+  color: #0969da;
+}
+
+table {
+  border-collapse: collapse;
+  margin: 1rem 0;
+}
+
+th,
+td {
+  border: 1px solid #d0d7de;
+  padding: 0.4rem 0.8rem;
+  text-align: left;
+  vertical-align: top;
+}
+
+th {
+  background: #f6f8fa;
+}
+
+code {
+  background: #f6f8fa;
+  padding: 0.1rem 0.3rem;
+  border-radius: 4px;
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    #[test]
+    fn test_readable_expressions() {
+        assert_eq!(
+            readable("items.length > 0"),
+            "the number of items is greater than 0"
+        );
+        assert_eq!(
+            readable("totalAmount == sum(items.subtotal)"),
+            "total amount is the sum of items subtotal"
+        );
+        assert_eq!(
+            readable("leaveRequest.status in [PENDING, APPROVED]"),
+            "leave request status is one of PENDING, APPROVED"
+        );
+        assert_eq!(
+            readable("daysBetween(startDate, endDate) <= 30"),
+            "days between start date and end date is at most 30"
+        );
+        assert_eq!(
+            readable("startDate >= today()"),
+            "start date is at least today"
+        );
+    }
+
+    #[test]
+    fn test_pages_cross_link_commands_and_events() {
+        let model = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+aggregates:
+  Order:
+    name: "Order"
+    root_entity:
+      name: "Order"
+      properties:
+        - name: "id"
+          type: "OrderId"
+          identifier: true
+        - name: "items"
+          type: "List<String>"
+          validation:
+            minLength: 1
+            maxLength: 50
+    invariants:
+      - name: "HasItems"
+        description: "An order has items"
+        expression: "items.length > 0"
+value_objects:
+  OrderId:
+    name: "OrderId"
+    type: "identifier"
+    underlying_type: "Uuid"
+commands:
+  PlaceOrder:
+    name: "PlaceOrder"
+    description: "Place a new order"
+    aggregate: "Order"
+    produces: ["OrderPlaced"]
+events:
+  OrderPlaced:
+    name: "OrderPlaced"
+    aggregate: "Order"
+"#,
+        )
+        .unwrap();
+
+        let pages = DocsGenerator::pages(&IRProject {
+            contexts: vec![model],
+        });
+        let page = |path: &str| {
+            &pages
+                .iter()
+                .find(|page| page.path == path)
+                .unwrap()
+                .markdown
+        };
+
+        let aggregate = page("order/aggregates/Order");
+        assert!(aggregate
+            .contains("| `id` | [OrderId](../glossary.md#orderid) | Yes (identifier) |  |  |\n"));
+        assert!(aggregate.contains("| `items` | list of String | Yes | 1 to 50 items |  |\n"));
+        assert!(aggregate.contains(
+            "- **HasItems**: An order has items\n  - Reads as: the number of items is greater than 0\n"
+        ));
+        assert!(page("order/commands/PlaceOrder")
+            .contains("## Produces\n\n- [OrderPlaced](../events/OrderPlaced.md)\n"));
+        assert!(page("order/events/OrderPlaced")
+            .contains("## Produced By\n\n- [PlaceOrder](../commands/PlaceOrder.md)\n"));
+        assert!(page("order/glossary").contains("## OrderId\n\n*Identifier*\n\n"));
+
+        let html = DocsGenerator::render_html(
+            pages
+                .iter()
+                .find(|page| page.path == "order/commands/PlaceOrder")
+                .unwrap(),
+        );
+        assert!(html.contains("<title>PlaceOrder · Order</title>"));
+        assert!(html.contains("<a href=\"../events/OrderPlaced.html\">OrderPlaced</a>"));
+        assert!(html.contains("<link rel=\"stylesheet\" href=\"../../style.css\">"));
+    }
+
+    #[test]
+    fn test_site_links_every_context() {
+        let order = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+events:
+  OrderPlaced:
+    name: "OrderPlaced"
+"#,
+        )
+        .unwrap();
+        let stock_keeping = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "StockKeeping"
+  namespace: "acme.stock"
+external_events:
+  - context: "Order"
+    event: "OrderPlaced"
+    handler: "reserveStock"
+    description: "Reserve the ordered items"
+"#,
+        )
+        .unwrap();
+
+        let pages = DocsGenerator::pages(&IRProject {
+            contexts: vec![order, stock_keeping],
+        });
+        let page = |path: &str| {
+            &pages
+                .iter()
+                .find(|page| page.path == path)
+                .unwrap()
+                .markdown
+        };
+
+        let index = page("index");
+        assert!(index.contains("| [Order](order/index.md) | `acme.order` |  |\n"));
+        assert!(index.contains(
+            "| [OrderPlaced](order/events/OrderPlaced.md) | [Order](order/index.md) | [StockKeeping](stock-keeping/index.md) | Reserve the ordered items |\n"
+        ));
+        assert!(page("stock-keeping/index").contains(
+            "| [OrderPlaced](../order/events/OrderPlaced.md) | [Order](../order/index.md) | `reserveStock` |"
+        ));
+        assert!(page("order/events/OrderPlaced").contains(
+            "## Subscribed By\n\n- [StockKeeping](../../stock-keeping/index.md): Reserve the ordered items\n"
+        ));
+    }
+}
@@ -1,52 +1,72 @@
+use crate::generators::rust_ast::*;
 use crate::generators::utils::*;
 use crate::ir::{Aggregate, IRModel, ValueObject};
 use anyhow::Result;
+use proc_macro2::TokenStream;
+use quote::quote;
 
 pub struct EntityGenerator;
 
 impl EntityGenerator {
     /// Generate Rust code for all entities in the model
-    pub fn generate(model: &IRModel) -> Result<String> {
-        let mut code = String::new();
-
-        // Add imports
-        code.push_str(&Self::generate_imports());
-        code.push_str("\n\n");
+    ///
+    /// Items of the given crates are re-exported, which lets workspace members
+    /// use value objects that live in a shared crate.
+    pub fn generate(model: &IRModel, reexports: &[String]) -> Result<String> {
+        let reexports = reexports
+            .iter()
+            .map(|name| ident(name))
+            .collect::<Result<Vec<_>>>()?;
 
         // Generate value objects first (as they may be used by entities)
-        for (name, vo) in &model.value_objects {
-            code.push_str(&Self::generate_value_object(name, vo)?);
-            code.push_str("\n\n");
-        }
+        let value_objects = model
+            .value_objects
+            .iter()
+            .map(|(name, vo)| Self::generate_value_object(name, vo))
+            .collect::<Result<Vec<_>>>()?;
 
         // Generate aggregate root entities
-        for (name, aggregate) in &model.aggregates {
-            code.push_str(&Self::generate_aggregate(name, aggregate)?);
-            code.push_str("\n\n");
-        }
+        let aggregates = model
+            .aggregates
+            .iter()
+            .map(|(name, aggregate)| Self::generate_aggregate(name, aggregate))
+            .collect::<Result<Vec<_>>>()?;
+
+        let code = quote! {
+            #(#value_objects)*
+            #(#aggregates)*
+        };
+        let imports = Self::generate_imports(&code);
 
-        Ok(code)
+        render(quote! {
+            #imports
+            #(pub use #reexports::*;)*
+            #code
+        })
     }
 
-    fn generate_imports() -> String {
-        r#"use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc, NaiveDate, NaiveTime};
-use uuid::Uuid;
-use rust_decimal::Decimal;
-
-#[cfg(feature = "sqlx")]
-use sqlx::FromRow;"#
-            .to_string()
-    }
+    /// Imports of the types `code` mentions
+    pub fn generate_imports(code: &TokenStream) -> TokenStream {
+        let chrono = use_mentioned(
+            quote! { chrono },
+            &["DateTime", "Utc", "NaiveDate", "NaiveTime"],
+            code,
+        );
+        let uuid = use_mentioned(quote! { uuid }, &["Uuid"], code);
+        let decimal = use_mentioned(quote! { rust_decimal }, &["Decimal"], code);
 
-    fn generate_value_object(name: &str, vo: &ValueObject) -> Result<String> {
-        let mut code = String::new();
+        quote! {
+            use serde::{Deserialize, Serialize};
+            #chrono
+            #uuid
+            #decimal
 
-        // Add documentation
-        if let Some(desc) = &vo.description {
-            code.push_str(&format!("/// {}\n", desc));
+            #[cfg(feature = "sqlx")]
+            use sqlx::FromRow;
         }
+    }
 
+    pub fn generate_value_object(name: &str, vo: &ValueObject) -> Result<TokenStream> {
         // Check if it's an enum
         if vo.vo_type.as_deref() == Some("enum") {
             return Self::generate_enum(name, vo);
@@ -58,111 +78,90 @@ use sqlx::FromRow;"#
         }
 
         // Generate complex value object (struct with properties)
-        code.push_str("#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]\n");
-        code.push_str(&format!("pub struct {} {{\n", name));
-
-        for prop in &vo.properties {
-            if let Some(desc) = &prop.description {
-                code.push_str(&format!("    /// {}\n", desc));
+        let doc = doc(vo.description.as_deref());
+        let graphql = graphql_derives(name, &vo.properties, true, true);
+        let name = ident(name)?;
+        let fields = struct_fields(&vo.properties)?;
+
+        Ok(quote! {
+            #doc
+            #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+            #graphql
+            pub struct #name {
+                #(#fields,)*
             }
-
-            let rust_type = to_rust_type(&prop.prop_type);
-            let field_type = if !prop.required {
-                format!("Option<{}>", rust_type)
-            } else {
-                rust_type
-            };
-
-            code.push_str(&format!(
-                "    pub {}: {},\n",
-                to_snake_case(&prop.name),
-                field_type
-            ));
-        }
-
-        code.push_str("}");
-
-        Ok(code)
+        })
     }
 
-    fn generate_enum(name: &str, vo: &ValueObject) -> Result<String> {
-        let mut code = String::new();
-
-        code.push_str(
-            "#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]\n",
-        );
-        code.push_str(&format!("pub enum {} {{\n", name));
-
-        for value in &vo.values {
-            if let Some(desc) = &value.description {
-                code.push_str(&format!("    /// {}\n", desc));
+    fn generate_enum(name: &str, vo: &ValueObject) -> Result<TokenStream> {
+        let enum_doc = doc(vo.description.as_deref());
+        let name = ident(name)?;
+        let variants = vo
+            .values
+            .iter()
+            .map(|value| {
+                let doc = doc(value.description.as_deref());
+                let variant = ident(&to_pascal_case(&value.name))?;
+                Ok(quote! {
+                    #doc
+                    #variant
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            #enum_doc
+            #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+            #[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+            pub enum #name {
+                #(#variants,)*
             }
-            code.push_str(&format!("    {},\n", to_pascal_case(&value.name)));
-        }
-
-        code.push_str("}");
-
-        Ok(code)
+        })
     }
 
-    fn generate_identifier(name: &str, vo: &ValueObject) -> Result<String> {
+    fn generate_identifier(name: &str, vo: &ValueObject) -> Result<TokenStream> {
         let underlying = vo.underlying_type.as_deref().unwrap_or("String");
-        let rust_type = to_rust_type(underlying);
-
-        let mut code = String::new();
-        code.push_str("#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]\n");
-        code.push_str(&format!("pub struct {}(pub {});\n\n", name, rust_type));
-
-        // Add From/Into implementations
-        code.push_str(&format!("impl From<{}> for {} {{\n", rust_type, name));
-        code.push_str(&format!("    fn from(value: {}) -> Self {{\n", rust_type));
-        code.push_str("        Self(value)\n");
-        code.push_str("    }\n");
-        code.push_str("}\n\n");
-
-        code.push_str(&format!("impl From<{}> for {} {{\n", name, rust_type));
-        code.push_str(&format!("    fn from(id: {}) -> Self {{\n", name));
-        code.push_str("        id.0\n");
-        code.push_str("    }\n");
-        code.push_str("}");
-
-        Ok(code)
-    }
-
-    fn generate_aggregate(name: &str, aggregate: &Aggregate) -> Result<String> {
-        let mut code = String::new();
-
-        // Add documentation
-        if let Some(desc) = &aggregate.description {
-            code.push_str(&format!("/// {}\n", desc));
-        }
-
-        code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
-        code.push_str("#[cfg_attr(feature = \"sqlx\", derive(FromRow))]\n");
-        code.push_str(&format!("pub struct {} {{\n", name));
-
-        for prop in &aggregate.root_entity.properties {
-            if let Some(desc) = &prop.description {
-                code.push_str(&format!("    /// {}\n", desc));
+        let rust_type = rust_type(underlying)?;
+        let doc = doc(vo.description.as_deref());
+        let name = ident(name)?;
+
+        Ok(quote! {
+            #doc
+            #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+            pub struct #name(pub #rust_type);
+
+            impl From<#rust_type> for #name {
+                fn from(value: #rust_type) -> Self {
+                    Self(value)
+                }
             }
 
-            let rust_type = to_rust_type(&prop.prop_type);
-            let field_type = if !prop.required {
-                format!("Option<{}>", rust_type)
-            } else {
-                rust_type
-            };
-
-            code.push_str(&format!(
-                "    pub {}: {},\n",
-                to_snake_case(&prop.name),
-                field_type
-            ));
-        }
+            impl From<#name> for #rust_type {
+                fn from(id: #name) -> Self {
+                    id.0
+                }
+            }
 
-        code.push_str("}");
+            #[cfg(feature = "graphql")]
+            async_graphql::scalar!(#name);
+        })
+    }
 
-        Ok(code)
+    fn generate_aggregate(name: &str, aggregate: &Aggregate) -> Result<TokenStream> {
+        let doc = doc(aggregate.description.as_deref());
+        let graphql = graphql_derives(name, &aggregate.root_entity.properties, true, true);
+        let name = ident(name)?;
+        let fields = struct_fields(&aggregate.root_entity.properties)?;
+
+        Ok(quote! {
+            #doc
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            #[cfg_attr(feature = "sqlx", derive(FromRow))]
+            #graphql
+            pub struct #name {
+                #(#fields,)*
+            }
+        })
     }
 }
 
@@ -188,8 +187,9 @@ mod tests {
 
         let result = EntityGenerator::generate_value_object("Status", &vo);
         assert!(result.is_ok());
-        let code = result.unwrap();
+        let code = render(result.unwrap()).unwrap();
+        assert!(code.contains("/// Status enum\n"));
         assert!(code.contains("pub enum Status"));
-        assert!(code.contains("Active"));
+        assert!(code.contains("    /// Active status\n    Active,\n"));
     }
 }
@@ -1,174 +1,153 @@
-use crate::generators::utils::*;
+use crate::generators::rust_ast::*;
 use crate::ir::{Event, IRModel};
 use anyhow::Result;
+use proc_macro2::TokenStream;
+use quote::quote;
 
 pub struct EventGenerator;
 
 impl EventGenerator {
     /// Generate Rust code for all events in the model
     pub fn generate(model: &IRModel) -> Result<String> {
-        let mut code = String::new();
-
-        // Add imports
-        code.push_str(&Self::generate_imports());
-        code.push_str("\n\n");
-
         // Generate base event envelope
-        code.push_str(&Self::generate_domain_event_enum(model)?);
-        code.push_str("\n\n");
+        let domain_event = Self::generate_domain_event_enum(model)?;
 
         // Generate individual event structs
-        for (name, event) in &model.events {
-            code.push_str(&Self::generate_event(name, event)?);
-            code.push_str("\n\n");
-        }
+        let events = model
+            .events
+            .iter()
+            .map(|(name, event)| Self::generate_event(name, event))
+            .collect::<Result<Vec<_>>>()?;
 
         // Generate event metadata
-        code.push_str(&Self::generate_event_metadata());
+        let metadata = Self::generate_event_metadata();
 
-        Ok(code)
-    }
+        let code = quote! {
+            #domain_event
+            #(#events)*
+            #metadata
+        };
+        let imports = Self::generate_imports(model, &code);
 
-    fn generate_imports() -> String {
-        r#"use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
-use uuid::Uuid;
-use rust_decimal::Decimal;
-use crate::domain::entities::*;"#
-            .to_string()
+        render(quote! {
+            #imports
+            #code
+        })
     }
 
-    fn generate_domain_event_enum(model: &IRModel) -> Result<String> {
-        let mut code = String::new();
-
-        code.push_str("/// Domain event enum containing all events in this bounded context\n");
-        code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
-        code.push_str("#[serde(tag = \"type\")]\n");
-        code.push_str("pub enum DomainEvent {\n");
-
-        for (name, _) in &model.events {
-            code.push_str(&format!("    {}({}),\n", name, name));
+    /// Imports of the types `code` mentions
+    fn generate_imports(model: &IRModel, code: &TokenStream) -> TokenStream {
+        let chrono = use_mentioned(quote! { chrono }, &["DateTime", "Utc"], code);
+        let uuid = use_mentioned(quote! { uuid }, &["Uuid"], code);
+        let decimal = use_mentioned(quote! { rust_decimal }, &["Decimal"], code);
+        let entities = mentions(code, &entity_names(model))
+            .then(|| quote! { use crate::domain::entities::*; });
+
+        quote! {
+            use serde::{Deserialize, Serialize};
+            #chrono
+            #uuid
+            #decimal
+            #entities
         }
-
-        code.push_str("}");
-
-        Ok(code)
     }
 
-    fn generate_event(name: &str, event: &Event) -> Result<String> {
-        let mut code = String::new();
+    fn generate_domain_event_enum(model: &IRModel) -> Result<TokenStream> {
+        let variants = model
+            .events
+            .keys()
+            .map(|name| ident(name))
+            .collect::<Result<Vec<_>>>()?;
 
-        // Add documentation
-        if let Some(desc) = &event.description {
-            code.push_str(&format!("/// {}\n", desc));
-        }
-
-        code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
-        code.push_str(&format!("pub struct {} {{\n", name));
-
-        // Add event metadata fields
-        code.push_str("    /// Unique event identifier\n");
-        code.push_str("    pub event_id: Uuid,\n");
-        code.push_str("    /// Timestamp when the event occurred\n");
-        code.push_str("    pub occurred_at: DateTime<Utc>,\n");
-
-        // Add aggregate ID if specified
-        if event.aggregate.is_some() {
-            code.push_str("    /// ID of the aggregate that produced this event\n");
-            code.push_str("    pub aggregate_id: String,\n");
-        }
+        let graphql = if variants.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! { #[cfg_attr(feature = "graphql", derive(async_graphql::Union))] }
+        };
 
-        // Add event payload fields
-        for prop in &event.payload {
-            if let Some(desc) = &prop.description {
-                code.push_str(&format!("    /// {}\n", desc));
+        Ok(quote! {
+            /// Domain event enum containing all events in this bounded context
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            #graphql
+            #[serde(tag = "type")]
+            pub enum DomainEvent {
+                #(#variants(#variants),)*
             }
+        })
+    }
 
-            let rust_type = to_rust_type(&prop.prop_type);
-            let field_type = if !prop.required {
-                format!("Option<{}>", rust_type)
-            } else {
-                rust_type
-            };
-
-            code.push_str(&format!(
-                "    pub {}: {},\n",
-                to_snake_case(&prop.name),
-                field_type
-            ));
-        }
-
-        code.push_str("}\n\n");
-
-        // Generate constructor
-        code.push_str(&format!("impl {} {{\n", name));
-        code.push_str("    /// Create a new event instance\n");
-
-        let params: Vec<String> = event
-            .payload
-            .iter()
-            .map(|p| {
-                let rust_type = to_rust_type(&p.prop_type);
-                let param_type = if !p.required {
-                    format!("Option<{}>", rust_type)
-                } else {
-                    rust_type
-                };
-                format!("{}: {}", to_snake_case(&p.name), param_type)
-            })
-            .collect();
-
-        let aggregate_param = if event.aggregate.is_some() {
-            "aggregate_id: String, "
-        } else {
-            ""
-        };
+    fn generate_event(name: &str, event: &Event) -> Result<TokenStream> {
+        let doc = doc(event.description.as_deref());
+        let name = ident(name)?;
+        let fields = struct_fields(&event.payload)?;
 
-        let params_str = if params.is_empty() {
-            String::new()
-        } else if aggregate_param.is_empty() {
-            params.join(", ")
+        // Add aggregate ID if specified
+        let (aggregate_field, aggregate_param, aggregate_init) = if event.aggregate.is_some() {
+            (
+                quote! {
+                    /// ID of the aggregate that produced this event
+                    pub aggregate_id: String,
+                },
+                quote! { aggregate_id: String, },
+                quote! { aggregate_id, },
+            )
         } else {
-            format!("{}", params.join(", "))
+            (TokenStream::new(), TokenStream::new(), TokenStream::new())
         };
 
-        code.push_str(&format!(
-            "    pub fn new({}{}) -> Self {{\n",
-            aggregate_param, params_str
-        ));
-        code.push_str("        Self {\n");
-        code.push_str("            event_id: Uuid::new_v4(),\n");
-        code.push_str("            occurred_at: Utc::now(),\n");
-
-        if event.aggregate.is_some() {
-            code.push_str("            aggregate_id,\n");
-        }
-
-        for prop in &event.payload {
-            code.push_str(&format!("            {},\n", to_snake_case(&prop.name)));
-        }
-
-        code.push_str("        }\n");
-        code.push_str("    }\n");
-        code.push_str("}");
+        let param_names = event
+            .payload
+            .iter()
+            .map(|p| snake_ident(&p.name))
+            .collect::<Result<Vec<_>>>()?;
+        let param_types = event
+            .payload
+            .iter()
+            .map(property_type)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            #doc
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+            pub struct #name {
+                /// Unique event identifier
+                pub event_id: Uuid,
+                /// Timestamp when the event occurred
+                pub occurred_at: DateTime<Utc>,
+                #aggregate_field
+                #(#fields,)*
+            }
 
-        Ok(code)
+            impl #name {
+                /// Create a new event instance
+                pub fn new(#aggregate_param #(#param_names: #param_types),*) -> Self {
+                    Self {
+                        event_id: Uuid::new_v4(),
+                        occurred_at: Utc::now(),
+                        #aggregate_init
+                        #(#param_names,)*
+                    }
+                }
+            }
+        })
     }
 
-    fn generate_event_metadata() -> String {
-        r#"/// Event store metadata
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EventMetadata {
-    pub event_id: Uuid,
-    pub event_type: String,
-    pub aggregate_id: String,
-    pub aggregate_type: String,
-    pub sequence_number: i64,
-    pub occurred_at: DateTime<Utc>,
-    pub stored_at: DateTime<Utc>,
-    pub correlation_id: Option<Uuid>,
-    pub causation_id: Option<Uuid>,
-}"#
-        .to_string()
+    fn generate_event_metadata() -> TokenStream {
+        quote! {
+            /// Event store metadata
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            pub struct EventMetadata {
+                pub event_id: Uuid,
+                pub event_type: String,
+                pub aggregate_id: String,
+                pub aggregate_type: String,
+                pub sequence_number: i64,
+                pub occurred_at: DateTime<Utc>,
+                pub stored_at: DateTime<Utc>,
+                pub correlation_id: Option<Uuid>,
+                pub causation_id: Option<Uuid>,
+            }
+        }
     }
 }
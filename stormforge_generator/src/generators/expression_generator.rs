@@ -0,0 +1,844 @@
+use crate::generators::rust_ast::*;
+use crate::generators::utils::*;
+use crate::ir::{BinaryOp, Expr, UnaryOp};
+use anyhow::{bail, Result};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// Translates IR expressions to Rust over JSON values
+///
+/// A translated expression reads its variables from a `scope:
+/// &serde_json::Value` object in scope at the point of use, and calls the
+/// `crate::domain::expressions` runtime, which must be imported as
+/// `expressions`. Property names are snake-cased to match the serialized
+/// fields of the generated types, and enum values such as `PAID` become the
+/// serialized variant names.
+pub struct ExpressionGenerator;
+
+/// Check of an IR rule in generated code
+pub struct Guard {
+    /// `if` statement rejecting what breaks the rule, or a `// TODO` hook
+    pub tokens: TokenStream,
+    /// Whether the rule is checked rather than left to the hook
+    pub enforced: bool,
+}
+
+impl ExpressionGenerator {
+    /// Rust `bool` expression checking a condition
+    pub fn condition(expression: &str) -> Result<TokenStream> {
+        let expr = Expr::parse(expression)?;
+        Translation { expression }.condition(&expr)
+    }
+
+    /// Statement running `reject` unless a condition holds
+    ///
+    /// A condition the translator cannot handle, such as a call to a domain
+    /// function it does not know, is reported as a warning about `rule` and
+    /// left as an unenforced `// TODO` hook, so the rest of the crate is still
+    /// generated.
+    pub fn guard(expression: &str, rule: &str, reject: TokenStream) -> Guard {
+        match Self::condition(expression) {
+            Ok(check) => Guard {
+                tokens: quote! {
+                    if !#check {
+                        #reject
+                    }
+                },
+                enforced: true,
+            },
+            Err(error) => {
+                eprintln!("⚠️  {} is not enforced: {:#}", rule, error);
+                Guard {
+                    tokens: comment(&format!("TODO: enforce `{}`", expression)),
+                    enforced: false,
+                }
+            }
+        }
+    }
+
+    /// Rust `serde_json::Value` expression computing a value
+    pub fn value(expression: &str) -> Result<TokenStream> {
+        let expr = Expr::parse(expression)?;
+        Translation { expression }.value(&expr)
+    }
+
+    /// Rust `serde_json::Value` expression of a constant, such as an IR default
+    pub fn constant(value: &serde_json::Value) -> TokenStream {
+        match value {
+            serde_json::Value::Null => quote! { serde_json::Value::Null },
+            serde_json::Value::Bool(value) => quote! { serde_json::Value::Bool(#value) },
+            serde_json::Value::Number(number) => {
+                let number = match (number.as_i64(), number.as_f64()) {
+                    (Some(integer), _) => Literal::i64_unsuffixed(integer),
+                    (None, Some(float)) => Literal::f64_unsuffixed(float),
+                    _ => Literal::string(&number.to_string()),
+                };
+                quote! { serde_json::Value::from(#number) }
+            }
+            serde_json::Value::String(text) => quote! { serde_json::Value::from(#text) },
+            serde_json::Value::Array(items) => {
+                let items = items.iter().map(Self::constant);
+                quote! { serde_json::Value::Array(vec![#(#items),*]) }
+            }
+            serde_json::Value::Object(fields) => {
+                let keys = fields.keys();
+                let values = fields.values().map(Self::constant);
+                quote! { expressions::object([#((#keys, #values)),*]) }
+            }
+        }
+    }
+
+    /// Generate the expression runtime, independent of the model
+    pub fn generate_runtime() -> Result<String> {
+        render(quote! {
+            //! Runtime of the translated IR expressions
+            //!
+            //! Invariants, preconditions, validation rules, policy conditions and
+            //! computed read model fields are translated to Rust over JSON values, so
+            //! they read commands, events and aggregate state alike. A missing
+            //! property reads as `null`, which is false in conditions and neither
+            //! equal to, less nor greater than another value. Numbers, decimals
+            //! (serialized as strings) and dates compare by value.
+
+            use chrono::{DateTime, NaiveDate, Utc};
+            use rust_decimal::prelude::ToPrimitive;
+            use rust_decimal::Decimal;
+            use serde_json::{Map, Value};
+            use std::cmp::Ordering;
+            use std::str::FromStr;
+
+            /// Property of an object; on a list, the property of each element
+            pub fn get(value: &Value, key: &str) -> Value {
+                match value {
+                    Value::Object(object) => object.get(key).cloned().unwrap_or(Value::Null),
+                    Value::Array(items) => Value::Array(items.iter().map(|item| get(item, key)).collect()),
+                    _ => Value::Null,
+                }
+            }
+
+            /// Element of a list or property of an object
+            pub fn index(value: &Value, index: &Value) -> Value {
+                let found = match (value, index) {
+                    (Value::Array(items), Value::Number(position)) => position
+                        .as_u64()
+                        .and_then(|position| items.get(usize::try_from(position).ok()?)),
+                    (Value::Object(object), Value::String(key)) => object.get(key),
+                    _ => None,
+                };
+                found.cloned().unwrap_or(Value::Null)
+            }
+
+            /// Scope with one more variable, such as a lambda parameter
+            pub fn with(scope: &Value, name: &str, value: &Value) -> Value {
+                let mut scope = match scope {
+                    Value::Object(object) => object.clone(),
+                    _ => Map::new(),
+                };
+                scope.insert(name.to_string(), value.clone());
+                Value::Object(scope)
+            }
+
+            /// Object literal
+            pub fn object<const N: usize>(fields: [(&str, Value); N]) -> Value {
+                Value::Object(
+                    fields
+                        .into_iter()
+                        .map(|(key, value)| (key.to_string(), value))
+                        .collect(),
+                )
+            }
+
+            /// Number of elements of a list, characters of a string or properties of an object
+            pub fn len(value: &Value) -> Value {
+                match value {
+                    Value::Array(items) => Value::from(items.len()),
+                    Value::String(text) => Value::from(text.chars().count()),
+                    Value::Object(object) => Value::from(object.len()),
+                    _ => Value::Null,
+                }
+            }
+
+            /// Whether a value holds in a condition
+            pub fn truthy(value: &Value) -> bool {
+                match value {
+                    Value::Null => false,
+                    Value::Bool(value) => *value,
+                    Value::Number(number) => number.as_f64().is_some_and(|number| number != 0.0),
+                    Value::String(text) => !text.is_empty(),
+                    Value::Array(items) => !items.is_empty(),
+                    Value::Object(_) => true,
+                }
+            }
+
+            fn decimal(value: &Value) -> Option<Decimal> {
+                match value {
+                    Value::Number(number) => {
+                        let text = number.to_string();
+                        Decimal::from_str(&text)
+                            .or_else(|_| Decimal::from_scientific(&text))
+                            .ok()
+                    }
+                    Value::String(text) => Decimal::from_str(text).ok(),
+                    _ => None,
+                }
+            }
+
+            fn datetime(value: &Value) -> Option<DateTime<Utc>> {
+                let text = value.as_str()?;
+                if let Ok(datetime) = DateTime::parse_from_rfc3339(text) {
+                    return Some(datetime.with_timezone(&Utc));
+                }
+                let date = NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()?;
+                Some(date.and_hms_opt(0, 0, 0)?.and_utc())
+            }
+
+            /// Number with the representation of its operands: decimals are
+            /// serialized as strings, integers and floats as numbers
+            fn number(value: Decimal, operands: [&Value; 2]) -> Value {
+                if operands.iter().any(|operand| operand.is_string()) {
+                    return Value::String(value.normalize().to_string());
+                }
+                if value.fract().is_zero() {
+                    if let Some(value) = value.to_i64() {
+                        return Value::from(value);
+                    }
+                }
+                value.to_f64().map(Value::from).unwrap_or(Value::Null)
+            }
+
+            /// Applies an operator to numbers, and to the numeric properties of
+            /// objects such as amounts of money
+            fn arithmetic(left: &Value, right: &Value, op: fn(Decimal, Decimal) -> Option<Decimal>) -> Value {
+                match (left, right) {
+                    (Value::Object(object), Value::Object(other)) => fieldwise(object, |key, value| {
+                        match other.get(key) {
+                            Some(other) => arithmetic(value, other, op),
+                            None => value.clone(),
+                        }
+                    }),
+                    (Value::Object(object), _) => fieldwise(object, |_, value| arithmetic(value, right, op)),
+                    (_, Value::Object(object)) => fieldwise(object, |_, value| arithmetic(left, value, op)),
+                    _ => match (decimal(left), decimal(right)) {
+                        (Some(l), Some(r)) => op(l, r)
+                            .map(|result| number(result, [left, right]))
+                            .unwrap_or(Value::Null),
+                        _ => Value::Null,
+                    },
+                }
+            }
+
+            /// Object with its numeric properties transformed
+            fn fieldwise(object: &Map<String, Value>, apply: impl Fn(&str, &Value) -> Value) -> Value {
+                Value::Object(
+                    object
+                        .iter()
+                        .map(|(key, value)| {
+                            let value = match decimal(value) {
+                                Some(_) => apply(key, value),
+                                None => value.clone(),
+                            };
+                            (key.clone(), value)
+                        })
+                        .collect(),
+                )
+            }
+
+            /// Sum of numbers, or concatenation when a string is not a number
+            pub fn add(left: &Value, right: &Value) -> Value {
+                if let (Value::String(_), _) | (_, Value::String(_)) = (left, right) {
+                    if decimal(left).is_none() || decimal(right).is_none() {
+                        let text = |value: &Value| match value {
+                            Value::String(text) => text.clone(),
+                            Value::Null => String::new(),
+                            other => other.to_string(),
+                        };
+                        return Value::String(text(left) + &text(right));
+                    }
+                }
+                arithmetic(left, right, Decimal::checked_add)
+            }
+
+            pub fn sub(left: &Value, right: &Value) -> Value {
+                arithmetic(left, right, Decimal::checked_sub)
+            }
+
+            pub fn mul(left: &Value, right: &Value) -> Value {
+                arithmetic(left, right, Decimal::checked_mul)
+            }
+
+            pub fn div(left: &Value, right: &Value) -> Value {
+                arithmetic(left, right, Decimal::checked_div)
+            }
+
+            pub fn rem(left: &Value, right: &Value) -> Value {
+                arithmetic(left, right, Decimal::checked_rem)
+            }
+
+            pub fn neg(value: &Value) -> Value {
+                sub(&Value::from(0), value)
+            }
+
+            /// Equality of values, comparing numbers and decimals by value
+            pub fn eq(left: &Value, right: &Value) -> bool {
+                match (left, right) {
+                    (Value::Array(left), Value::Array(right)) => {
+                        left.len() == right.len() && left.iter().zip(right).all(|(l, r)| eq(l, r))
+                    }
+                    (Value::Object(left), Value::Object(right)) => {
+                        left.len() == right.len()
+                            && left
+                                .iter()
+                                .all(|(key, l)| right.get(key).is_some_and(|r| eq(l, r)))
+                    }
+                    _ => match (decimal(left), decimal(right)) {
+                        (Some(l), Some(r)) => l == r,
+                        _ => left == right,
+                    },
+                }
+            }
+
+            /// Order of numbers, dates and strings, and of objects differing in
+            /// one numeric property such as amounts of money in one currency
+            pub fn compare(left: &Value, right: &Value) -> Option<Ordering> {
+                if let (Some(l), Some(r)) = (decimal(left), decimal(right)) {
+                    return Some(l.cmp(&r));
+                }
+                if let (Some(l), Some(r)) = (datetime(left), datetime(right)) {
+                    return Some(l.cmp(&r));
+                }
+                match (left, right) {
+                    (Value::String(l), Value::String(r)) => Some(l.cmp(r)),
+                    (Value::Object(l), Value::Object(r)) if l.len() == r.len() => {
+                        let mut order = None;
+                        for (key, value) in l {
+                            let other = r.get(key)?;
+                            match (decimal(value), decimal(other)) {
+                                (Some(value), Some(other)) if order.is_none() => {
+                                    order = Some(value.cmp(&other));
+                                }
+                                _ if eq(value, other) => {}
+                                _ => return None,
+                            }
+                        }
+                        order
+                    }
+                    _ => None,
+                }
+            }
+
+            pub fn lt(left: &Value, right: &Value) -> bool {
+                compare(left, right) == Some(Ordering::Less)
+            }
+
+            pub fn le(left: &Value, right: &Value) -> bool {
+                matches!(compare(left, right), Some(Ordering::Less | Ordering::Equal))
+            }
+
+            pub fn gt(left: &Value, right: &Value) -> bool {
+                compare(left, right) == Some(Ordering::Greater)
+            }
+
+            pub fn ge(left: &Value, right: &Value) -> bool {
+                matches!(compare(left, right), Some(Ordering::Greater | Ordering::Equal))
+            }
+
+            /// Whether a list holds an element, a string a substring or an object a property
+            pub fn contains(collection: &Value, item: &Value) -> bool {
+                match (collection, item) {
+                    (Value::Array(items), _) => items.iter().any(|element| eq(element, item)),
+                    (Value::String(text), Value::String(part)) => text.contains(part.as_str()),
+                    (Value::Object(object), Value::String(key)) => object.contains_key(key),
+                    _ => false,
+                }
+            }
+
+            /// Whether every element of a list matches; false when the value is not a list
+            pub fn all(list: &Value, predicate: impl Fn(&Value) -> bool) -> bool {
+                list.as_array().is_some_and(|items| items.iter().all(predicate))
+            }
+
+            /// Whether some element of a list matches
+            pub fn any(list: &Value, predicate: impl Fn(&Value) -> bool) -> bool {
+                list.as_array().is_some_and(|items| items.iter().any(predicate))
+            }
+
+            /// Elements of a list that match
+            pub fn filter(list: &Value, predicate: impl Fn(&Value) -> bool) -> Value {
+                match list {
+                    Value::Array(items) => Value::Array(
+                        items.iter().filter(|item| predicate(item)).cloned().collect(),
+                    ),
+                    _ => Value::Null,
+                }
+            }
+
+            /// Elements of a list transformed one by one
+            pub fn map(list: &Value, transform: impl Fn(&Value) -> Value) -> Value {
+                match list {
+                    Value::Array(items) => Value::Array(items.iter().map(transform).collect()),
+                    _ => Value::Null,
+                }
+            }
+
+            /// Sum of the elements of a list, property by property for objects
+            pub fn sum(list: &Value) -> Value {
+                match list.as_array().map(Vec::as_slice) {
+                    Some([first, rest @ ..]) => rest.iter().fold(first.clone(), |sum, item| add(&sum, item)),
+                    Some([]) => Value::from(0),
+                    None => Value::Null,
+                }
+            }
+
+            /// Smallest element of a list
+            pub fn min(list: &Value) -> Value {
+                extreme(list, Ordering::Less)
+            }
+
+            /// Largest element of a list
+            pub fn max(list: &Value) -> Value {
+                extreme(list, Ordering::Greater)
+            }
+
+            fn extreme(list: &Value, wanted: Ordering) -> Value {
+                let Some(items) = list.as_array() else {
+                    return Value::Null;
+                };
+                let mut best: Option<&Value> = None;
+                for item in items {
+                    match best {
+                        Some(current) if compare(item, current) != Some(wanted) => {}
+                        _ => best = Some(item),
+                    }
+                }
+                best.cloned().unwrap_or(Value::Null)
+            }
+
+            /// Current date
+            pub fn today() -> Value {
+                Value::String(Utc::now().date_naive().to_string())
+            }
+
+            /// Current time
+            pub fn now() -> Value {
+                serde_json::to_value(Utc::now()).unwrap_or(Value::Null)
+            }
+
+            /// Whole days from one date to another
+            pub fn days_between(from: &Value, to: &Value) -> Value {
+                match (datetime(from), datetime(to)) {
+                    (Some(from), Some(to)) => Value::from((to - from).num_days()),
+                    _ => Value::Null,
+                }
+            }
+
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+                use serde_json::json;
+
+                #[test]
+                fn test_paths_read_missing_properties_as_null() {
+                    let order = json!({ "items": [{ "qty": 1 }, { "qty": 2 }] });
+                    assert_eq!(get(&order, "items").as_array().map(Vec::len), Some(2));
+                    assert_eq!(get(&get(&order, "items"), "qty"), json!([1, 2]));
+                    assert_eq!(get(&order, "status"), Value::Null);
+                    assert!(!truthy(&get(&order, "status")));
+                    assert!(!all(&get(&order, "lines"), truthy));
+                }
+
+                #[test]
+                fn test_numbers_compare_by_value() {
+                    assert!(eq(&json!("12.50"), &json!(12.5)));
+                    assert!(ge(&json!(3), &json!("2.99")));
+                    assert!(!lt(&Value::Null, &json!(1)));
+                    assert!(!ge(&Value::Null, &json!(1)));
+                    assert!(lt(
+                        &json!({ "amount": "5", "currency": "EUR" }),
+                        &json!({ "amount": "7.5", "currency": "EUR" })
+                    ));
+                    assert_eq!(
+                        compare(
+                            &json!({ "amount": "5", "currency": "EUR" }),
+                            &json!({ "amount": "5", "currency": "USD" })
+                        ),
+                        None
+                    );
+                }
+
+                #[test]
+                fn test_sums_keep_the_shape_of_their_operands() {
+                    let subtotals = json!([
+                        { "amount": "10.25", "currency": "EUR" },
+                        { "amount": "4.75", "currency": "EUR" }
+                    ]);
+                    assert!(eq(&sum(&subtotals), &json!({ "amount": "15", "currency": "EUR" })));
+                    assert_eq!(sum(&json!([1, 2, 3])), json!(6));
+                    assert_eq!(mul(&json!(3), &json!({ "amount": "2.5" })), json!({ "amount": "7.5" }));
+                    assert_eq!(add(&json!("ORD-"), &json!(7)), json!("ORD-7"));
+                }
+
+                #[test]
+                fn test_dates() {
+                    assert_eq!(days_between(&json!("2024-01-01"), &json!("2024-01-31")), json!(30));
+                    assert!(gt(&json!("2024-01-02T00:00:00Z"), &json!("2024-01-01")));
+                    assert!(le(&today(), &now()));
+                }
+            }
+        })
+    }
+}
+
+/// Translation of one parsed expression, kept for error messages
+struct Translation<'a> {
+    expression: &'a str,
+}
+
+impl Translation<'_> {
+    /// `bool` expression, without going through a JSON value where possible
+    fn condition(&self, expr: &Expr) -> Result<TokenStream> {
+        Ok(match expr {
+            Expr::Literal(serde_json::Value::Bool(value)) => quote! { #value },
+            Expr::Unary(UnaryOp::Not, value) => {
+                let value = self.condition(value)?;
+                quote! { !#value }
+            }
+            Expr::Binary(op @ (BinaryOp::And | BinaryOp::Or), left, right) => {
+                let left = self.condition(left)?;
+                let right = self.condition(right)?;
+                if *op == BinaryOp::And {
+                    quote! { (#left && #right) }
+                } else {
+                    quote! { (#left || #right) }
+                }
+            }
+            Expr::Binary(op, left, right) if Self::comparison(*op).is_some() => {
+                let function = Self::comparison(*op).unwrap_or_default();
+                let function = ident(function)?;
+                let left = self.value(left)?;
+                let right = self.value(right)?;
+                quote! { expressions::#function(&#left, &#right) }
+            }
+            Expr::Binary(BinaryOp::Ne, left, right) => {
+                let left = self.value(left)?;
+                let right = self.value(right)?;
+                quote! { !expressions::eq(&#left, &#right) }
+            }
+            Expr::Binary(BinaryOp::In, item, collection) => {
+                let item = self.value(item)?;
+                let collection = self.value(collection)?;
+                quote! { expressions::contains(&#collection, &#item) }
+            }
+            Expr::Method(receiver, method, args)
+                if matches!(
+                    method.as_str(),
+                    "all" | "any" | "none" | "contains" | "includes"
+                ) =>
+            {
+                let receiver = self.value(receiver)?;
+                match (method.as_str(), args.as_slice()) {
+                    ("contains" | "includes", [item]) => {
+                        let item = self.value(item)?;
+                        quote! { expressions::contains(&#receiver, &#item) }
+                    }
+                    ("all", [lambda]) => {
+                        let predicate = self.predicate(lambda)?;
+                        quote! { expressions::all(&#receiver, #predicate) }
+                    }
+                    ("any", [lambda]) => {
+                        let predicate = self.predicate(lambda)?;
+                        quote! { expressions::any(&#receiver, #predicate) }
+                    }
+                    ("none", [lambda]) => {
+                        let predicate = self.predicate(lambda)?;
+                        quote! { !expressions::any(&#receiver, #predicate) }
+                    }
+                    _ => return self.arity(method),
+                }
+            }
+            _ => {
+                let value = self.value(expr)?;
+                quote! { expressions::truthy(&#value) }
+            }
+        })
+    }
+
+    fn comparison(op: BinaryOp) -> Option<&'static str> {
+        match op {
+            BinaryOp::Eq => Some("eq"),
+            BinaryOp::Lt => Some("lt"),
+            BinaryOp::Le => Some("le"),
+            BinaryOp::Gt => Some("gt"),
+            BinaryOp::Ge => Some("ge"),
+            _ => None,
+        }
+    }
+
+    /// `serde_json::Value` expression
+    fn value(&self, expr: &Expr) -> Result<TokenStream> {
+        Ok(match expr {
+            Expr::Literal(serde_json::Value::Bool(_)) => {
+                let value = self.condition(expr)?;
+                quote! { serde_json::Value::Bool(#value) }
+            }
+            Expr::Literal(value) => ExpressionGenerator::constant(value),
+            Expr::Constant(name) => {
+                let variant = to_pascal_case(name);
+                quote! { serde_json::Value::from(#variant) }
+            }
+            Expr::Name(name) => {
+                let name = to_snake_case(name);
+                quote! { expressions::get(scope, #name) }
+            }
+            Expr::Member(value, property) => {
+                let value = self.value(value)?;
+                if property == "length" {
+                    quote! { expressions::len(&#value) }
+                } else {
+                    let property = to_snake_case(property);
+                    quote! { expressions::get(&#value, #property) }
+                }
+            }
+            Expr::Index(value, index) => {
+                let value = self.value(value)?;
+                let index = self.value(index)?;
+                quote! { expressions::index(&#value, &#index) }
+            }
+            Expr::Call(function, args) => self.call(function, args)?,
+            Expr::Method(receiver, method, args) => self.method(receiver, method, args)?,
+            Expr::Lambda(..) => bail!(
+                "A lambda is only allowed as a method argument in expression `{}`",
+                self.expression
+            ),
+            Expr::Unary(UnaryOp::Neg, value) => {
+                let value = self.value(value)?;
+                quote! { expressions::neg(&#value) }
+            }
+            Expr::Binary(op, left, right) => {
+                let function = match op {
+                    BinaryOp::Add => "add",
+                    BinaryOp::Sub => "sub",
+                    BinaryOp::Mul => "mul",
+                    BinaryOp::Div => "div",
+                    BinaryOp::Rem => "rem",
+                    _ => {
+                        let value = self.condition(expr)?;
+                        return Ok(quote! { serde_json::Value::Bool(#value) });
+                    }
+                };
+                let function = ident(function)?;
+                let left = self.value(left)?;
+                let right = self.value(right)?;
+                quote! { expressions::#function(&#left, &#right) }
+            }
+            Expr::Unary(UnaryOp::Not, _) => {
+                let value = self.condition(expr)?;
+                quote! { serde_json::Value::Bool(#value) }
+            }
+            Expr::List(items) => {
+                let items = items
+                    .iter()
+                    .map(|item| self.value(item))
+                    .collect::<Result<Vec<_>>>()?;
+                quote! { serde_json::Value::Array(vec![#(#items),*]) }
+            }
+            Expr::Object(fields) => {
+                let keys = fields.iter().map(|(key, _)| to_snake_case(key));
+                let values = fields
+                    .iter()
+                    .map(|(_, value)| self.value(value))
+                    .collect::<Result<Vec<_>>>()?;
+                quote! { expressions::object([#((#keys, #values)),*]) }
+            }
+        })
+    }
+
+    fn call(&self, function: &str, args: &[Expr]) -> Result<TokenStream> {
+        let values = args
+            .iter()
+            .map(|arg| self.value(arg))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(match (function, values.as_slice()) {
+            ("sum", [list]) => quote! { expressions::sum(&#list) },
+            ("count", [list]) => quote! { expressions::len(&#list) },
+            ("min", [list]) => quote! { expressions::min(&#list) },
+            ("max", [list]) => quote! { expressions::max(&#list) },
+            ("min" | "max", values) if values.len() > 1 => {
+                let function = ident(function)?;
+                quote! { expressions::#function(&serde_json::Value::Array(vec![#(#values),*])) }
+            }
+            ("today", []) => quote! { expressions::today() },
+            ("now", []) => quote! { expressions::now() },
+            ("daysBetween", [from, to]) => quote! { expressions::days_between(&#from, &#to) },
+            ("sum" | "count" | "min" | "max" | "today" | "now" | "daysBetween", _) => {
+                return self.arity(function)
+            }
+            _ => bail!(
+                "Unsupported function `{}` in expression `{}`",
+                function,
+                self.expression
+            ),
+        })
+    }
+
+    fn method(&self, receiver: &Expr, method: &str, args: &[Expr]) -> Result<TokenStream> {
+        let list = self.value(receiver)?;
+        Ok(match (method, args) {
+            ("all" | "any" | "none" | "contains" | "includes", _) => {
+                let value = self.condition(&Expr::Method(
+                    Box::new(receiver.clone()),
+                    method.to_string(),
+                    args.to_vec(),
+                ))?;
+                quote! { serde_json::Value::Bool(#value) }
+            }
+            ("filter", [lambda]) => {
+                let predicate = self.predicate(lambda)?;
+                quote! { expressions::filter(&#list, #predicate) }
+            }
+            ("map", [lambda]) => {
+                let transform = self.lambda(lambda, |body| self.value(body))?;
+                quote! { expressions::map(&#list, #transform) }
+            }
+            ("count", []) => quote! { expressions::len(&#list) },
+            ("count", [lambda]) => {
+                let predicate = self.predicate(lambda)?;
+                quote! { expressions::len(&expressions::filter(&#list, #predicate)) }
+            }
+            ("sum", []) => quote! { expressions::sum(&#list) },
+            ("sum", [lambda]) => {
+                let transform = self.lambda(lambda, |body| self.value(body))?;
+                quote! { expressions::sum(&expressions::map(&#list, #transform)) }
+            }
+            ("isEmpty", []) => {
+                quote! { serde_json::Value::Bool(!expressions::truthy(&#list)) }
+            }
+            ("filter" | "map" | "count" | "sum" | "isEmpty", _) => return self.arity(method),
+            _ => bail!(
+                "Unsupported method `{}` in expression `{}`",
+                method,
+                self.expression
+            ),
+        })
+    }
+
+    fn predicate(&self, lambda: &Expr) -> Result<TokenStream> {
+        self.lambda(lambda, |body| self.condition(body))
+    }
+
+    /// Closure binding the lambda parameter in a nested scope
+    fn lambda(
+        &self,
+        lambda: &Expr,
+        body: impl Fn(&Expr) -> Result<TokenStream>,
+    ) -> Result<TokenStream> {
+        let Expr::Lambda(param, value) = lambda else {
+            bail!(
+                "Expected a lambda such as `item => item.price` in expression `{}`",
+                self.expression
+            );
+        };
+        let name = to_snake_case(param);
+        let param = ident(&name)?;
+        let value = body(value)?;
+        Ok(quote! {
+            |#param: &serde_json::Value| {
+                let scope = &expressions::with(scope, #name, #param);
+                #value
+            }
+        })
+    }
+
+    fn arity<T>(&self, function: &str) -> Result<T> {
+        bail!(
+            "Wrong number of arguments to `{}` in expression `{}`",
+            function,
+            self.expression
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translated(expression: &str) -> String {
+        ExpressionGenerator::condition(expression)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_translate_conditions() {
+        assert_eq!(
+            translated("order.status == CREATED"),
+            quote! {
+                expressions::eq(
+                    &expressions::get(&expressions::get(scope, "order"), "status"),
+                    &serde_json::Value::from("Created")
+                )
+            }
+            .to_string()
+        );
+        assert_eq!(
+            translated("order.items.all(item => item.inStock)"),
+            quote! {
+                expressions::all(
+                    &expressions::get(&expressions::get(scope, "order"), "items"),
+                    |item: &serde_json::Value| {
+                        let scope = &expressions::with(scope, "item", item);
+                        expressions::truthy(&expressions::get(&expressions::get(scope, "item"), "in_stock"))
+                    }
+                )
+            }
+            .to_string()
+        );
+        assert_eq!(
+            translated("items.length > 0"),
+            quote! {
+                expressions::gt(
+                    &expressions::len(&expressions::get(scope, "items")),
+                    &serde_json::Value::from(0)
+                )
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_example_expressions_translate() {
+        for expression in [
+            "totalAmount == sum(items.subtotal)",
+            "daysBetween(startDate, endDate) <= 30",
+            "leaveRequest.startDate > today()",
+            "payment.status in [PENDING, PROCESSING]",
+            "approverId != leaveRequest.employeeId",
+            "!(a && b) || not c",
+            "order.items.all(inStock)",
+        ] {
+            let tokens = ExpressionGenerator::condition(expression).unwrap();
+            syn::parse2::<syn::Expr>(tokens).unwrap();
+        }
+        let tokens = ExpressionGenerator::value(
+            "{ id: customer.id, lines: order.items.map(item => item.quantity * 2) }",
+        )
+        .unwrap();
+        syn::parse2::<syn::Expr>(tokens).unwrap();
+    }
+
+    #[test]
+    fn test_untranslatable_expressions_are_errors() {
+        for expression in [
+            "getOrderTimeline(order.id)",
+            "items.sortBy(item => item.price)",
+            "items.all(true)",
+            "daysBetween(startDate)",
+            "order.status ==",
+        ] {
+            assert!(
+                ExpressionGenerator::condition(expression).is_err(),
+                "{}",
+                expression
+            );
+        }
+    }
+}
@@ -0,0 +1,671 @@
+use crate::generators::rust_ast::*;
+use crate::generators::utils::*;
+use crate::ir::{IRModel, Property};
+use anyhow::Result;
+use heck::ToShoutySnakeCase;
+use indexmap::IndexSet;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Path of the GraphQL schema, relative to the crate root
+pub const SCHEMA_PATH: &str = "schema.graphql";
+
+pub struct GraphQLGenerator;
+
+/// Domain types the GraphQL schema exposes
+///
+/// Only types reachable from the root fields appear in the schema, as
+/// `async-graphql` registers types when they are used.
+#[derive(Default)]
+struct Reachable {
+    /// Built-in scalars of `async-graphql` beyond the GraphQL ones
+    scalars: IndexSet<&'static str>,
+    /// Enums and identifiers, which are used as inputs and outputs alike
+    leaves: IndexSet<String>,
+    /// Value objects and aggregates used as input objects
+    inputs: IndexSet<String>,
+    /// Value objects and aggregates used as output objects
+    outputs: IndexSet<String>,
+}
+
+impl Reachable {
+    fn new(model: &IRModel) -> Self {
+        let mut reachable = Self::default();
+        for command in model.commands.values() {
+            reachable.visit_properties(model, &command.payload, true);
+        }
+        for query in model.queries.values() {
+            reachable.visit_properties(model, &query.parameters, true);
+        }
+        if !model.events.is_empty() {
+            reachable.scalars.insert("UUID");
+            reachable.scalars.insert("DateTime");
+        }
+        for event in model.events.values() {
+            reachable.visit_properties(model, &event.payload, false);
+        }
+        reachable
+    }
+
+    fn visit_properties(&mut self, model: &IRModel, properties: &[Property], input: bool) {
+        for prop in properties {
+            self.visit(model, &prop.prop_type, input);
+        }
+    }
+
+    fn visit(&mut self, model: &IRModel, ir_type: &str, input: bool) {
+        let ir_type = ir_type.trim();
+        for prefix in ["List<", "Vec<", "Option<"] {
+            if let Some(rest) = ir_type.strip_prefix(prefix) {
+                return self.visit(model, rest.strip_suffix('>').unwrap_or(rest), input);
+            }
+        }
+        if let Some(scalar) = builtin_scalar(ir_type) {
+            self.scalars.insert(scalar);
+            return;
+        }
+
+        let name = to_rust_type(ir_type);
+        let properties = match model.value_objects.get(&name) {
+            Some(vo) if matches!(vo.vo_type.as_deref(), Some("enum" | "identifier")) => {
+                self.leaves.insert(name);
+                return;
+            }
+            Some(vo) => &vo.properties,
+            None => match model.aggregates.get(&name) {
+                Some(aggregate) => &aggregate.root_entity.properties,
+                None => return,
+            },
+        };
+        let objects = if input {
+            &mut self.inputs
+        } else {
+            &mut self.outputs
+        };
+        if objects.insert(name) {
+            self.visit_properties(model, properties, input);
+        }
+    }
+}
+
+impl GraphQLGenerator {
+    /// Whether the model has a GraphQL API
+    ///
+    /// GraphQL requires the query type to have at least one field.
+    pub fn supported(model: &IRModel) -> bool {
+        !model.queries.is_empty()
+    }
+
+    /// Generate the GraphQL schema in SDL
+    ///
+    /// The schema mirrors what the `async-graphql` derives of the domain types
+    /// register: camelCase fields, SCREAMING_SNAKE_CASE enum values, `Input`
+    /// suffixed input objects and a scalar per identifier.
+    pub fn generate_sdl(model: &IRModel) -> Result<String> {
+        let reachable = Reachable::new(model);
+        let mut blocks = Vec::new();
+
+        for scalar in &reachable.scalars {
+            blocks.push(format!("scalar {}\n", scalar));
+        }
+        for (name, vo) in &model.value_objects {
+            let description = sdl_description(vo.description.as_deref(), "");
+            if reachable.leaves.contains(name) {
+                if vo.vo_type.as_deref() == Some("enum") {
+                    let values: String = vo
+                        .values
+                        .iter()
+                        .map(|value| {
+                            format!(
+                                "{}  {}\n",
+                                sdl_description(value.description.as_deref(), "  "),
+                                to_pascal_case(&value.name).to_shouty_snake_case()
+                            )
+                        })
+                        .collect();
+                    blocks.push(format!("{}enum {} {{\n{}}}\n", description, name, values));
+                } else {
+                    blocks.push(format!("{}scalar {}\n", description, name));
+                }
+                continue;
+            }
+            if reachable.outputs.contains(name) {
+                blocks.push(object_block(
+                    model,
+                    "type",
+                    name,
+                    &description,
+                    &vo.properties,
+                    false,
+                ));
+            }
+            if reachable.inputs.contains(name) {
+                blocks.push(object_block(
+                    model,
+                    "input",
+                    &format!("{}Input", name),
+                    &description,
+                    &vo.properties,
+                    true,
+                ));
+            }
+        }
+        for (name, aggregate) in &model.aggregates {
+            let description = sdl_description(aggregate.description.as_deref(), "");
+            let properties = &aggregate.root_entity.properties;
+            if reachable.outputs.contains(name) {
+                blocks.push(object_block(
+                    model,
+                    "type",
+                    name,
+                    &description,
+                    properties,
+                    false,
+                ));
+            }
+            if reachable.inputs.contains(name) {
+                blocks.push(object_block(
+                    model,
+                    "input",
+                    &format!("{}Input", name),
+                    &description,
+                    properties,
+                    true,
+                ));
+            }
+        }
+        // Commands without payload have no input object
+        for (name, command) in model.commands.iter().filter(|(_, c)| !c.payload.is_empty()) {
+            blocks.push(object_block(
+                model,
+                "input",
+                &format!("{}Input", name),
+                &sdl_description(command.description.as_deref(), ""),
+                &command.payload,
+                true,
+            ));
+        }
+        for (name, event) in &model.events {
+            let mut fields = String::from(
+                "  \"\"\"Unique event identifier\"\"\"\n  eventId: UUID!\n  \"\"\"Timestamp when the event occurred\"\"\"\n  occurredAt: DateTime!\n",
+            );
+            if event.aggregate.is_some() {
+                fields.push_str(
+                    "  \"\"\"ID of the aggregate that produced this event\"\"\"\n  aggregateId: String!\n",
+                );
+            }
+            fields.push_str(&object_fields(model, &event.payload, false));
+            blocks.push(format!(
+                "{}type {} {{\n{}}}\n",
+                sdl_description(event.description.as_deref(), ""),
+                name,
+                fields
+            ));
+        }
+        if !model.events.is_empty() {
+            let events: Vec<&str> = model.events.keys().map(String::as_str).collect();
+            blocks.push(format!(
+                "\"\"\"Domain event of the {} context\"\"\"\nunion DomainEvent = {}\n",
+                model.bounded_context.name,
+                events.join(" | ")
+            ));
+        }
+
+        let queries: String = model
+            .queries
+            .iter()
+            .map(|(name, query)| {
+                let arguments = query
+                    .parameters
+                    .iter()
+                    .map(|prop| {
+                        format!(
+                            "{}: {}",
+                            field_name(&prop.name),
+                            graphql_type(model, prop, true)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let arguments = if arguments.is_empty() {
+                    String::new()
+                } else {
+                    format!("({})", arguments.join(", "))
+                };
+                format!(
+                    "{}  {}{}: String!\n",
+                    sdl_description(query.description.as_deref(), "  "),
+                    to_camel_case(name),
+                    arguments
+                )
+            })
+            .collect();
+        blocks.push(format!("type Query {{\n{}}}\n", queries));
+
+        if !model.commands.is_empty() {
+            let mutations: String = model
+                .commands
+                .iter()
+                .map(|(name, command)| {
+                    let arguments = if command.payload.is_empty() {
+                        String::new()
+                    } else {
+                        format!("(input: {}Input!)", name)
+                    };
+                    format!(
+                        "{}  {}{}: String!\n",
+                        sdl_description(command.description.as_deref(), "  "),
+                        to_camel_case(name),
+                        arguments
+                    )
+                })
+                .collect();
+            blocks.push(format!("type Mutation {{\n{}}}\n", mutations));
+        }
+
+        if !model.events.is_empty() {
+            blocks.push(
+                "type Subscription {\n  \"\"\"Domain events appended to the event store, skipping the first `fromPosition`\"\"\"\n  events(fromPosition: Int! = 0): DomainEvent!\n}\n"
+                    .to_string(),
+            );
+        }
+
+        Ok(format!(
+            "# GraphQL schema of the {} context, served at /graphql\n\n{}",
+            model.bounded_context.name,
+            blocks.join("\n")
+        ))
+    }
+
+    /// Generate the `async-graphql` resolvers and router
+    pub fn generate_resolvers(model: &IRModel) -> Result<String> {
+        let module_doc = format!(
+            " GraphQL API of the {} context\n\n \
+             Queries and mutations delegate to the same handlers as the HTTP routes,\n \
+             and the `events` subscription streams the domain events appended to the\n \
+             event store. `{}` holds the schema in SDL.",
+            model.bounded_context.name, SCHEMA_PATH
+        );
+        let module_doc = module_doc.lines().map(str::to_string);
+
+        let queries = model
+            .queries
+            .iter()
+            .map(|(name, query)| {
+                let doc = doc(query.description.as_deref());
+                let method = snake_ident(name)?;
+                let execute = format_ident!("execute_{}", to_snake_case(name));
+                let arguments = query
+                    .parameters
+                    .iter()
+                    .map(|prop| {
+                        let argument = format_ident!("_{}", to_snake_case(&prop.name));
+                        let name = field_name(&prop.name);
+                        let argument_type = property_type(prop)?;
+                        Ok(quote! { #[graphql(name = #name)] #argument: #argument_type })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                // Like the HTTP handler, the query handler does not take its parameters yet
+                Ok(quote! {
+                    #doc
+                    async fn #method(&self, #(#arguments),*) -> async_graphql::Result<String> {
+                        #execute().await.map_err(graphql_error)
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mutations = model
+            .commands
+            .iter()
+            .map(|(name, command)| {
+                let doc = doc(command.description.as_deref());
+                let method = snake_ident(name)?;
+                let execute = format_ident!("execute_{}", to_snake_case(name));
+                let command_type = ident(name)?;
+                if command.payload.is_empty() {
+                    return Ok(quote! {
+                        #doc
+                        async fn #method(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<String> {
+                            #execute(ctx.data_unchecked::<AppState>(), #command_type {})
+                                .await
+                                .map_err(graphql_error)
+                        }
+                    });
+                }
+                Ok(quote! {
+                    #doc
+                    async fn #method(
+                        &self,
+                        ctx: &async_graphql::Context<'_>,
+                        input: #command_type,
+                    ) -> async_graphql::Result<String> {
+                        #execute(ctx.data_unchecked::<AppState>(), input)
+                            .await
+                            .map_err(graphql_error)
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let (mutation_root, mutation) = if model.commands.is_empty() {
+            (quote! { async_graphql::EmptyMutation }, TokenStream::new())
+        } else {
+            (
+                quote! { MutationRoot },
+                quote! {
+                    /// Commands as mutations
+                    pub struct MutationRoot;
+
+                    #[Object(name = "Mutation")]
+                    impl MutationRoot {
+                        #(#mutations)*
+                    }
+                },
+            )
+        };
+
+        let (subscription_root, subscription) = if model.events.is_empty() {
+            (
+                quote! { async_graphql::EmptySubscription },
+                TokenStream::new(),
+            )
+        } else {
+            (
+                quote! { SubscriptionRoot },
+                quote! {
+                    /// Interval at which subscriptions poll the event store
+                    const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+                    /// Domain events as a subscription
+                    pub struct SubscriptionRoot;
+
+                    #[async_graphql::Subscription(name = "Subscription")]
+                    impl SubscriptionRoot {
+                        /// Domain events appended to the event store, skipping the first `fromPosition`
+                        async fn events(
+                            &self,
+                            ctx: &async_graphql::Context<'_>,
+                            #[graphql(default)] from_position: i64,
+                        ) -> impl async_graphql::futures_util::Stream<Item = async_graphql::Result<DomainEvent>> {
+                            let event_store = ctx.data_unchecked::<AppState>().event_store.clone();
+                            async_stream::try_stream! {
+                                let mut position = from_position;
+                                loop {
+                                    for payload in event_store.read_all_from(position).await? {
+                                        position += 1;
+                                        yield serde_json::from_value::<DomainEvent>(payload)?;
+                                    }
+                                    tokio::time::sleep(POLL_INTERVAL).await;
+                                }
+                            }
+                        }
+                    }
+                },
+            )
+        };
+
+        render(quote! {
+            #(#![doc = #module_doc])*
+
+            use async_graphql::http::GraphiQLSource;
+            use async_graphql::{ErrorExtensions, Object, Schema};
+            use async_graphql_axum::{GraphQL, GraphQLSubscription};
+            use axum::{
+                response::{Html, IntoResponse},
+                routing::get,
+                Router,
+            };
+
+            use crate::api::routes::*;
+            use crate::domain::{commands::*, entities::*, events::*};
+
+            /// Schema of the GraphQL API
+            pub type ApiSchema = Schema<QueryRoot, #mutation_root, #subscription_root>;
+
+            /// Build the schema over the state shared with the HTTP API
+            pub fn schema(state: AppState) -> ApiSchema {
+                Schema::build(QueryRoot, #mutation_root, #subscription_root)
+                    .data(state)
+                    .finish()
+            }
+
+            /// Router serving GraphQL and GraphiQL at `/graphql`, and subscriptions at `/graphql/ws`
+            pub fn router(state: AppState) -> Router {
+                let schema = schema(state);
+                Router::new()
+                    .route(
+                        "/graphql",
+                        get(graphiql).post_service(GraphQL::new(schema.clone())),
+                    )
+                    .route_service("/graphql/ws", GraphQLSubscription::new(schema))
+            }
+
+            async fn graphiql() -> impl IntoResponse {
+                Html(
+                    GraphiQLSource::build()
+                        .endpoint("/graphql")
+                        .subscription_endpoint("/graphql/ws")
+                        .finish(),
+                )
+            }
+
+            /// GraphQL error with the API error code as `code` extension
+            fn graphql_error(error: ApiError) -> async_graphql::Error {
+                let ApiError { code, message } = error;
+                async_graphql::Error::new(message)
+                    .extend_with(|_, extensions| extensions.set("code", code))
+            }
+
+            /// Queries as query fields
+            pub struct QueryRoot;
+
+            #[Object(name = "Query")]
+            impl QueryRoot {
+                #(#queries)*
+            }
+
+            #mutation
+
+            #subscription
+        })
+    }
+}
+
+/// `async-graphql` scalar of a primitive IR type, other than the GraphQL ones
+fn builtin_scalar(ir_type: &str) -> Option<&'static str> {
+    match ir_type {
+        "Decimal" => Some("Decimal"),
+        "DateTime" => Some("DateTime"),
+        "Date" => Some("NaiveDate"),
+        "Time" => Some("NaiveTime"),
+        "Uuid" => Some("UUID"),
+        _ => None,
+    }
+}
+
+/// GraphQL name of a field or argument, as `async-graphql` renames them
+fn field_name(name: &str) -> String {
+    to_camel_case(&to_snake_case(name))
+}
+
+fn object_block(
+    model: &IRModel,
+    keyword: &str,
+    name: &str,
+    description: &str,
+    properties: &[Property],
+    input: bool,
+) -> String {
+    format!(
+        "{}{} {} {{\n{}}}\n",
+        description,
+        keyword,
+        name,
+        object_fields(model, properties, input)
+    )
+}
+
+fn object_fields(model: &IRModel, properties: &[Property], input: bool) -> String {
+    properties
+        .iter()
+        .map(|prop| {
+            format!(
+                "{}  {}: {}\n",
+                sdl_description(prop.description.as_deref(), "  "),
+                field_name(&prop.name),
+                graphql_type(model, prop, input)
+            )
+        })
+        .collect()
+}
+
+/// GraphQL type of a property, non-null unless its Rust field is an `Option`
+fn graphql_type(model: &IRModel, prop: &Property, input: bool) -> String {
+    let ir_type = prop.prop_type.trim();
+    let ir_type = ir_type
+        .strip_prefix("Option<")
+        .map(|rest| rest.strip_suffix('>').unwrap_or(rest))
+        .unwrap_or(ir_type);
+    let graphql_type = type_name(model, ir_type, input);
+    if prop.required && !prop.prop_type.trim().starts_with("Option<") {
+        format!("{}!", graphql_type)
+    } else {
+        graphql_type
+    }
+}
+
+fn type_name(model: &IRModel, ir_type: &str, input: bool) -> String {
+    for prefix in ["List<", "Vec<"] {
+        if let Some(rest) = ir_type.strip_prefix(prefix) {
+            let item = rest.strip_suffix('>').unwrap_or(rest).trim();
+            return format!("[{}!]", type_name(model, item, input));
+        }
+    }
+    match ir_type {
+        "String" => "String".to_string(),
+        "Integer" => "Int".to_string(),
+        "Boolean" => "Boolean".to_string(),
+        _ => match builtin_scalar(ir_type) {
+            Some(scalar) => scalar.to_string(),
+            None => {
+                let name = to_rust_type(ir_type);
+                let is_object = match model.value_objects.get(&name) {
+                    Some(vo) => !matches!(vo.vo_type.as_deref(), Some("enum" | "identifier")),
+                    None => model.aggregates.contains_key(&name),
+                };
+                if is_object && input {
+                    format!("{}Input", name)
+                } else {
+                    name
+                }
+            }
+        },
+    }
+}
+
+/// SDL description at the given indentation
+fn sdl_description(text: Option<&str>, indent: &str) -> String {
+    let Some(text) = text else {
+        return String::new();
+    };
+    let text = text.trim().replace("\"\"\"", "\\\"\"\"");
+    if !text.contains('\n') {
+        return format!("{}\"\"\"{}\"\"\"\n", indent, text);
+    }
+    let mut out = format!("{}\"\"\"\n", indent);
+    for line in text.lines() {
+        if line.is_empty() {
+            out.push('\n');
+        } else {
+            out.push_str(&format!("{}{}\n", indent, line));
+        }
+    }
+    out.push_str(&format!("{}\"\"\"\n", indent));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn model() -> IRModel {
+        IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+value_objects:
+  OrderId:
+    name: "OrderId"
+    type: "identifier"
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "IN_TRANSIT"
+  Money:
+    name: "Money"
+    properties:
+      - name: "amount"
+        type: "Decimal"
+  Unused:
+    name: "Unused"
+    properties:
+      - name: "note"
+        type: "String"
+commands:
+  PlaceOrder:
+    name: "PlaceOrder"
+    payload:
+      - name: "orderId"
+        type: "OrderId"
+      - name: "total"
+        type: "Money"
+      - name: "notes"
+        type: "List<String>"
+        required: false
+events:
+  OrderPlaced:
+    name: "OrderPlaced"
+    aggregate: "Order"
+    payload:
+      - name: "total"
+        type: "Money"
+      - name: "status"
+        type: "OrderStatus"
+queries:
+  GetOrder:
+    name: "GetOrder"
+    parameters:
+      - name: "orderId"
+        type: "OrderId"
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_generate_sdl_mirrors_derives() {
+        let sdl = GraphQLGenerator::generate_sdl(&model()).unwrap();
+
+        assert!(sdl.contains("scalar Decimal\n"));
+        assert!(sdl.contains("scalar OrderId\n"));
+        assert!(sdl.contains("enum OrderStatus {\n  IN_TRANSIT\n}\n"));
+        assert!(sdl.contains("type Money {\n  amount: Decimal!\n}\n"));
+        assert!(sdl.contains("input MoneyInput {\n  amount: Decimal!\n}\n"));
+        assert!(!sdl.contains("Unused"));
+        assert!(sdl.contains(
+            "input PlaceOrderInput {\n  orderId: OrderId!\n  total: MoneyInput!\n  notes: [String!]\n}\n"
+        ));
+        assert!(
+            sdl.contains("  aggregateId: String!\n  total: Money!\n  status: OrderStatus!\n}\n")
+        );
+        assert!(sdl.contains("union DomainEvent = OrderPlaced\n"));
+        assert!(sdl.contains("type Query {\n  getOrder(orderId: OrderId!): String!\n}\n"));
+        assert!(sdl.contains("  placeOrder(input: PlaceOrderInput!): String!\n"));
+        assert!(sdl.contains("  events(fromPosition: Int! = 0): DomainEvent!\n"));
+    }
+}
@@ -0,0 +1,601 @@
+use crate::generators::rust_ast::*;
+use crate::generators::utils::*;
+use crate::ir::{ExternalEventSubscription, IRModel};
+use anyhow::Result;
+use indexmap::{IndexMap, IndexSet};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub struct IntegrationGenerator;
+
+impl IntegrationGenerator {
+    /// Generate the event bus abstraction with in-process and JSONL implementations
+    pub fn generate_event_bus(model: &IRModel) -> Result<String> {
+        let context_name = &model.bounded_context.name;
+
+        render(quote! {
+            //! Event bus for exchanging integration events between bounded contexts
+            //!
+            //! `InMemoryEventBus` serves a single process, while `JsonlEventBus`
+            //! appends to a JSON Lines file so that several local services can
+            //! exchange events without a message broker.
+
+            use anyhow::Result;
+            use async_trait::async_trait;
+            use serde::{Deserialize, Serialize};
+            use std::path::PathBuf;
+            use std::sync::Arc;
+            use tokio::io::AsyncWriteExt;
+            use tokio::sync::RwLock;
+            use uuid::Uuid;
+
+            use crate::domain::events::DomainEvent;
+
+            /// Name of the bounded context publishing from this service
+            pub const BOUNDED_CONTEXT: &str = #context_name;
+
+            /// Integration event exchanged over the event bus
+            #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+            pub struct IntegrationMessage {
+                /// Unique message identifier, used by consumers for deduplication
+                pub message_id: Uuid,
+                /// Bounded context that published the event
+                pub context: String,
+                /// Name of the event type
+                pub event_type: String,
+                /// Serialized event
+                pub payload: serde_json::Value,
+            }
+
+            impl IntegrationMessage {
+                /// Wrap a domain event published by this bounded context
+                ///
+                /// The event ID doubles as message ID, so republishing the same
+                /// event yields a message consumers recognise as a duplicate.
+                pub fn from_domain_event(event: &DomainEvent) -> Result<Self> {
+                    let payload = serde_json::to_value(event)?;
+                    let event_type = payload
+                        .get("type")
+                        .and_then(|value| value.as_str())
+                        .unwrap_or_default()
+                        .to_string();
+                    let message_id = payload
+                        .get("event_id")
+                        .and_then(|value| serde_json::from_value(value.clone()).ok())
+                        .unwrap_or_else(Uuid::new_v4);
+
+                    Ok(Self {
+                        message_id,
+                        context: BOUNDED_CONTEXT.to_string(),
+                        event_type,
+                        payload,
+                    })
+                }
+            }
+
+            /// Event bus trait
+            #[async_trait]
+            pub trait EventBus: Send + Sync {
+                /// Publish a message to all subscribers
+                async fn publish(&self, message: IntegrationMessage) -> Result<()>;
+
+                /// Read the messages published at or after `offset`, in order
+                async fn read_from(&self, offset: usize) -> Result<Vec<IntegrationMessage>>;
+            }
+
+            /// In-process event bus (for a single service and tests)
+            #[derive(Clone, Default)]
+            pub struct InMemoryEventBus {
+                messages: Arc<RwLock<Vec<IntegrationMessage>>>,
+            }
+
+            impl InMemoryEventBus {
+                pub fn new() -> Self {
+                    Self::default()
+                }
+            }
+
+            #[async_trait]
+            impl EventBus for InMemoryEventBus {
+                async fn publish(&self, message: IntegrationMessage) -> Result<()> {
+                    self.messages.write().await.push(message);
+                    Ok(())
+                }
+
+                async fn read_from(&self, offset: usize) -> Result<Vec<IntegrationMessage>> {
+                    Ok(self.messages.read().await.iter().skip(offset).cloned().collect())
+                }
+            }
+
+            /// Event bus backed by a JSON Lines file shared between local services
+            #[derive(Clone)]
+            pub struct JsonlEventBus {
+                path: PathBuf,
+            }
+
+            impl JsonlEventBus {
+                pub fn new(path: impl Into<PathBuf>) -> Self {
+                    Self { path: path.into() }
+                }
+            }
+
+            #[async_trait]
+            impl EventBus for JsonlEventBus {
+                async fn publish(&self, message: IntegrationMessage) -> Result<()> {
+                    let mut line = serde_json::to_string(&message)?;
+                    line.push('\n');
+
+                    let mut file = tokio::fs::OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(&self.path)
+                        .await?;
+                    file.write_all(line.as_bytes()).await?;
+                    file.flush().await?;
+                    Ok(())
+                }
+
+                async fn read_from(&self, offset: usize) -> Result<Vec<IntegrationMessage>> {
+                    let content = match tokio::fs::read_to_string(&self.path).await {
+                        Ok(content) => content,
+                        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+                        Err(e) => return Err(e.into()),
+                    };
+
+                    // Skip a trailing line that is still being written
+                    content
+                        .split_inclusive('\n')
+                        .filter(|line| line.ends_with('\n') && !line.trim().is_empty())
+                        .skip(offset)
+                        .map(|line| Ok(serde_json::from_str(line)?))
+                        .collect()
+                }
+            }
+
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+
+                fn message(event_type: &str) -> IntegrationMessage {
+                    IntegrationMessage {
+                        message_id: Uuid::new_v4(),
+                        context: "Test".to_string(),
+                        event_type: event_type.to_string(),
+                        payload: serde_json::json!({ "type": event_type }),
+                    }
+                }
+
+                #[tokio::test]
+                async fn in_memory_bus_delivers_messages_in_order() {
+                    let bus = InMemoryEventBus::new();
+                    let subscriber = bus.clone();
+                    bus.publish(message("First")).await.unwrap();
+                    bus.publish(message("Second")).await.unwrap();
+
+                    let messages = subscriber.read_from(0).await.unwrap();
+                    assert_eq!(messages.len(), 2);
+                    assert_eq!(messages[0].event_type, "First");
+                    assert_eq!(subscriber.read_from(1).await.unwrap()[0].event_type, "Second");
+                }
+
+                #[tokio::test]
+                async fn jsonl_bus_is_shared_between_services() {
+                    let path = std::env::temp_dir()
+                        .join(format!("event-bus-{}.jsonl", Uuid::new_v4()));
+                    let publisher = JsonlEventBus::new(&path);
+                    let subscriber = JsonlEventBus::new(&path);
+
+                    assert!(subscriber.read_from(0).await.unwrap().is_empty());
+
+                    let first = message("First");
+                    publisher.publish(first.clone()).await.unwrap();
+                    publisher.publish(message("Second")).await.unwrap();
+
+                    let messages = subscriber.read_from(0).await.unwrap();
+                    assert_eq!(messages.len(), 2);
+                    assert_eq!(messages[0], first);
+                    assert_eq!(subscriber.read_from(2).await.unwrap().len(), 0);
+
+                    let _ = std::fs::remove_file(&path);
+                }
+            }
+        })
+    }
+
+    /// Generate contracts for the subscribed events
+    ///
+    /// With `contracts_crate`, the typed contracts of a workspace are
+    /// re-exported; otherwise payloads are kept as untyped JSON objects.
+    pub fn generate_contracts(model: &IRModel, contracts_crate: Option<&str>) -> Result<String> {
+        let subscriptions = Self::subscriptions_by_context(model);
+
+        if let Some(contracts_crate) = contracts_crate {
+            let contracts_crate = snake_ident(contracts_crate)?;
+            let modules = subscriptions
+                .keys()
+                .map(|context| snake_ident(context))
+                .collect::<Result<Vec<_>>>()?;
+
+            return render(quote! {
+                //! Contracts of the integration events this context subscribes to
+
+                #(pub use #contracts_crate::#modules;)*
+            });
+        }
+
+        let modules = subscriptions
+            .iter()
+            .map(|(context, events)| {
+                let module = snake_ident(context)?;
+                let contracts = events
+                    .iter()
+                    .map(|event| Self::generate_opaque_contract(context, event))
+                    .collect::<Result<Vec<_>>>()?;
+                let module_doc = format!(" Events published by the {} context", context);
+
+                Ok(quote! {
+                    #[doc = #module_doc]
+                    pub mod #module {
+                        use serde::{Deserialize, Serialize};
+
+                        #(#contracts)*
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        render(quote! {
+            //! Contracts of the integration events this context subscribes to
+            //!
+            //! The publishing contexts are not part of this build, so payloads
+            //! are kept as untyped JSON objects.
+
+            #(#modules)*
+        })
+    }
+
+    /// Contract for an event whose producer schema is unknown
+    pub fn generate_opaque_contract(context: &str, name: &str) -> Result<TokenStream> {
+        let doc = format!(
+            " Published by the {} context, which is not part of this build",
+            context
+        );
+        let name = ident(name)?;
+
+        Ok(quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            pub struct #name {
+                #[serde(flatten)]
+                pub data: serde_json::Map<String, serde_json::Value>,
+            }
+        })
+    }
+
+    /// Generate a typed consumer per subscription and the polling listener
+    pub fn generate_consumers(model: &IRModel) -> Result<String> {
+        Self::check_handlers(model)?;
+
+        let consumers = model
+            .external_events
+            .iter()
+            .map(Self::generate_consumer)
+            .collect::<Result<Vec<_>>>()?;
+        let consumer_names = model
+            .external_events
+            .iter()
+            .map(|subscription| ident(&Self::consumer_name(subscription)))
+            .collect::<Result<Vec<_>>>()?;
+
+        render(quote! {
+            //! Consumers for integration events from other bounded contexts
+
+            use anyhow::Result;
+            use async_trait::async_trait;
+            use std::collections::HashSet;
+            use std::sync::Arc;
+            use uuid::Uuid;
+
+            use crate::infrastructure::event_bus::{EventBus, IntegrationMessage};
+            use crate::integration::{acl, contracts};
+
+            /// Typed consumer for one subscribed integration event
+            #[async_trait]
+            pub trait IntegrationEventConsumer: Send + Sync {
+                /// Bounded context publishing the event
+                fn context(&self) -> &'static str;
+
+                /// Subscribed event type
+                fn event_type(&self) -> &'static str;
+
+                /// Handle a message matching this subscription
+                async fn consume(&self, message: &IntegrationMessage) -> Result<()>;
+            }
+
+            #(#consumers)*
+
+            /// All consumers of this bounded context's subscriptions
+            pub fn consumers() -> Vec<Box<dyn IntegrationEventConsumer>> {
+                vec![#(Box::new(#consumer_names)),*]
+            }
+
+            /// Polls an event bus and dispatches subscribed messages to their consumers
+            ///
+            /// Delivery is at-least-once: a failing message is retried on the
+            /// next poll, and already processed message IDs are skipped.
+            pub struct IntegrationEventListener {
+                bus: Arc<dyn EventBus>,
+                consumers: Vec<Box<dyn IntegrationEventConsumer>>,
+                offset: usize,
+                processed: HashSet<Uuid>,
+            }
+
+            impl IntegrationEventListener {
+                pub fn new(bus: Arc<dyn EventBus>) -> Self {
+                    Self::with_consumers(bus, consumers())
+                }
+
+                /// Create a listener dispatching to the given consumers
+                pub fn with_consumers(
+                    bus: Arc<dyn EventBus>,
+                    consumers: Vec<Box<dyn IntegrationEventConsumer>>,
+                ) -> Self {
+                    Self {
+                        bus,
+                        consumers,
+                        offset: 0,
+                        processed: HashSet::new(),
+                    }
+                }
+
+                /// Process new messages, returning how many were consumed
+                pub async fn poll(&mut self) -> Result<usize> {
+                    let messages = self.bus.read_from(self.offset).await?;
+                    let mut consumed = 0;
+
+                    for message in messages {
+                        if !self.processed.contains(&message.message_id) {
+                            for consumer in &self.consumers {
+                                if consumer.context() == message.context
+                                    && consumer.event_type() == message.event_type
+                                {
+                                    consumer.consume(&message).await?;
+                                    consumed += 1;
+                                }
+                            }
+                            self.processed.insert(message.message_id);
+                        }
+                        self.offset += 1;
+                    }
+
+                    Ok(consumed)
+                }
+            }
+
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+                use crate::infrastructure::event_bus::InMemoryEventBus;
+                use std::sync::atomic::{AtomicUsize, Ordering};
+
+                struct CountingConsumer(Arc<AtomicUsize>);
+
+                #[async_trait]
+                impl IntegrationEventConsumer for CountingConsumer {
+                    fn context(&self) -> &'static str {
+                        "Upstream"
+                    }
+
+                    fn event_type(&self) -> &'static str {
+                        "SomethingHappened"
+                    }
+
+                    async fn consume(&self, _message: &IntegrationMessage) -> Result<()> {
+                        self.0.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    }
+                }
+
+                fn message(event_type: &str) -> IntegrationMessage {
+                    IntegrationMessage {
+                        message_id: Uuid::new_v4(),
+                        context: "Upstream".to_string(),
+                        event_type: event_type.to_string(),
+                        payload: serde_json::json!({ "type": event_type }),
+                    }
+                }
+
+                #[tokio::test]
+                async fn listener_dispatches_subscribed_messages_once() {
+                    let bus = InMemoryEventBus::new();
+                    let count = Arc::new(AtomicUsize::new(0));
+                    let mut listener = IntegrationEventListener::with_consumers(
+                        Arc::new(bus.clone()),
+                        vec![Box::new(CountingConsumer(count.clone()))],
+                    );
+
+                    let subscribed = message("SomethingHappened");
+                    bus.publish(subscribed.clone()).await.unwrap();
+                    bus.publish(message("SomethingElse")).await.unwrap();
+                    // Redelivery of the same message is skipped
+                    bus.publish(subscribed).await.unwrap();
+
+                    assert_eq!(listener.poll().await.unwrap(), 1);
+                    assert_eq!(count.load(Ordering::SeqCst), 1);
+                    assert_eq!(listener.poll().await.unwrap(), 0);
+                }
+            }
+        })
+    }
+
+    fn generate_consumer(subscription: &ExternalEventSubscription) -> Result<TokenStream> {
+        let doc = doc(subscription.description.as_deref());
+        let consumer_name = ident(&Self::consumer_name(subscription))?;
+        let module = snake_ident(&subscription.context)?;
+        let event = ident(&subscription.event)?;
+        let handler = snake_ident(&subscription.handler)?;
+        let context = &subscription.context;
+        let event_type = &subscription.event;
+
+        Ok(quote! {
+            #doc
+            pub struct #consumer_name;
+
+            #[async_trait]
+            impl IntegrationEventConsumer for #consumer_name {
+                fn context(&self) -> &'static str {
+                    #context
+                }
+
+                fn event_type(&self) -> &'static str {
+                    #event_type
+                }
+
+                async fn consume(&self, message: &IntegrationMessage) -> Result<()> {
+                    let event: contracts::#module::#event =
+                        serde_json::from_value(message.payload.clone())?;
+                    acl::#handler(event).await
+                }
+            }
+        })
+    }
+
+    /// Generate an anti-corruption-layer translation stub per handler
+    pub fn generate_acl(model: &IRModel) -> Result<String> {
+        Self::check_handlers(model)?;
+
+        // TODO: Translate into local commands once the IR maps handlers to commands
+        let handlers = model
+            .external_events
+            .iter()
+            .map(|subscription| {
+                let mut text = subscription
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| format!("Handle {}", subscription.event));
+                text.push_str(&format!(
+                    "\n\nTranslates `{}` from the {} context into this context's domain model.",
+                    subscription.event, subscription.context
+                ));
+                let doc = doc_lines(&text);
+                let handler = snake_ident(&subscription.handler)?;
+                let module = snake_ident(&subscription.context)?;
+                let event = ident(&subscription.event)?;
+                let log_format = format!(
+                    "Received {} from {}: {{:?}}",
+                    subscription.event, subscription.context
+                );
+
+                Ok(quote! {
+                    #doc
+                    pub async fn #handler(event: contracts::#module::#event) -> Result<()> {
+                        tracing::info!(#log_format, event);
+                        Ok(())
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        render(quote! {
+            //! Anti-corruption layer
+            //!
+            //! Translates integration events from other bounded contexts into
+            //! this context's domain model.
+
+            use anyhow::Result;
+
+            use crate::integration::contracts;
+
+            #(#handlers)*
+        })
+    }
+
+    /// Generate the integration module root
+    pub fn generate_mod() -> Result<String> {
+        render(quote! {
+            pub mod acl;
+            pub mod consumers;
+            pub mod contracts;
+
+            pub use consumers::*;
+        })
+    }
+
+    /// Subscribed event names grouped by publishing context
+    fn subscriptions_by_context(model: &IRModel) -> IndexMap<&str, IndexSet<&str>> {
+        let mut subscriptions: IndexMap<&str, IndexSet<&str>> = IndexMap::new();
+        for subscription in &model.external_events {
+            subscriptions
+                .entry(subscription.context.as_str())
+                .or_default()
+                .insert(subscription.event.as_str());
+        }
+        subscriptions
+    }
+
+    fn consumer_name(subscription: &ExternalEventSubscription) -> String {
+        let handler = to_pascal_case(&subscription.handler);
+        format!(
+            "{}Consumer",
+            handler.strip_prefix("Handle").unwrap_or(&handler)
+        )
+    }
+
+    fn check_handlers(model: &IRModel) -> Result<()> {
+        let mut handlers = IndexSet::new();
+        for subscription in &model.external_events {
+            if !handlers.insert(to_snake_case(&subscription.handler)) {
+                anyhow::bail!(
+                    "Duplicate external event handler '{}'",
+                    subscription.handler
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn subscription(handler: &str) -> ExternalEventSubscription {
+        ExternalEventSubscription {
+            context: "Payment".to_string(),
+            event: "PaymentCompleted".to_string(),
+            handler: handler.to_string(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_consumer_name_strips_handle_prefix() {
+        assert_eq!(
+            IntegrationGenerator::consumer_name(&subscription("handlePaymentCompleted")),
+            "PaymentCompletedConsumer"
+        );
+        assert_eq!(
+            IntegrationGenerator::consumer_name(&subscription("onPaid")),
+            "OnPaidConsumer"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_handlers_are_rejected() {
+        let mut model = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "test.order"
+"#,
+        )
+        .unwrap();
+        model.external_events = vec![
+            subscription("handlePaymentCompleted"),
+            subscription("HandlePaymentCompleted"),
+        ];
+        assert!(IntegrationGenerator::check_handlers(&model).is_err());
+    }
+}
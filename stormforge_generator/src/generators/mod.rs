@@ -1,8 +1,35 @@
+pub mod aggregate_generator;
 pub mod api_generator;
+pub mod asyncapi_generator;
 pub mod command_generator;
+pub mod diagram_generator;
+pub mod docs_generator;
 pub mod entity_generator;
 pub mod event_generator;
+pub mod expression_generator;
+pub mod graphql_generator;
+pub mod integration_generator;
+pub mod nestjs_generator;
+pub mod outbox_generator;
+pub mod persistence_generator;
+pub mod policy_generator;
+pub mod projection_generator;
+pub mod proto_generator;
+pub mod python_expression_generator;
+pub mod python_generator;
+pub mod rust_ast;
 pub mod rust_generator;
+pub mod schema_generator;
+pub mod strategy_generator;
+pub mod test_generator;
+pub mod typescript_generator;
 pub mod utils;
+pub mod workspace_generator;
 
+pub use diagram_generator::{DiagramFormat, DiagramGenerator};
+pub use docs_generator::DocsGenerator;
+pub use nestjs_generator::NestJsGenerator;
+pub use python_generator::PythonGenerator;
 pub use rust_generator::RustGenerator;
+pub use typescript_generator::TypeScriptGenerator;
+pub use workspace_generator::WorkspaceGenerator;
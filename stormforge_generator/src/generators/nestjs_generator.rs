@@ -0,0 +1,2047 @@
+use crate::generators::aggregate_generator::{AggregateGenerator, FieldSource};
+use crate::generators::utils::*;
+use crate::ir::{
+    Aggregate, Command, Event, IRModel, IRProject, Property, Query, Validation, ValueObject,
+};
+use anyhow::{Context, Result};
+use std::collections::BTreeSet;
+use std::fs;
+
+/// Print width of the generated code, as formatted by prettier
+const PRINT_WIDTH: usize = 80;
+
+/// Decorators of the `class-transformer` package; the others come from
+/// `class-validator` or, for decimals, the generated `common/validation`
+const TRANSFORMER_DECORATORS: &[&str] = &["Transform", "Type"];
+const DECIMAL_DECORATORS: &[&str] = &["MaxDecimal", "MinDecimal"];
+
+const DATE: &str = r"/^\d{4}-\d{2}-\d{2}$/";
+const TIME: &str = r"/^\d{2}:\d{2}(:\d{2}(\.\d+)?)?$/";
+
+pub struct NestJsGenerator {
+    output_dir: String,
+}
+
+/// Property of a generated class
+struct TsField {
+    /// JSON key, the snake_case name of the Rust field
+    name: String,
+    ts_type: String,
+    /// The key may be omitted, as serde accepts a missing `Option` field
+    optional: bool,
+    default: Option<String>,
+    decorators: Vec<String>,
+    description: Option<String>,
+}
+
+impl NestJsGenerator {
+    pub fn new(output_dir: String) -> Self {
+        Self { output_dir }
+    }
+
+    /// Generate a NestJS service with a module per bounded context of a
+    /// project
+    pub fn generate(&self, project: &IRProject) -> Result<()> {
+        println!(
+            "🚀 Generating NestJS service for {} bounded context(s)...",
+            project.contexts.len()
+        );
+
+        let mut dirs = Vec::from(["src/common".to_string()]);
+        let mut files = Vec::from([
+            (
+                "package.json".to_string(),
+                Self::generate_package_json(project),
+            ),
+            ("tsconfig.json".to_string(), TSCONFIG.to_string()),
+            ("README.md".to_string(), Self::generate_readme(project)),
+            ("src/main.ts".to_string(), MAIN.to_string()),
+            (
+                "src/app.module.ts".to_string(),
+                Self::generate_app_module(project),
+            ),
+            ("src/common/api-error.ts".to_string(), API_ERROR.to_string()),
+            (
+                "src/common/api-response.ts".to_string(),
+                API_RESPONSE.to_string(),
+            ),
+            (
+                "src/common/validation.ts".to_string(),
+                VALIDATION.to_string(),
+            ),
+        ]);
+
+        let mut contexts = Vec::new();
+        for model in &project.contexts {
+            let context = Self::context_dir(model);
+            if context == "common" || contexts.contains(&context) {
+                anyhow::bail!(
+                    "Bounded context '{}' clashes with generated directory 'src/{}'",
+                    model.bounded_context.name,
+                    context
+                );
+            }
+            for dir in [
+                "commands",
+                "controllers",
+                "domain",
+                "dto",
+                "infrastructure",
+                "queries",
+            ] {
+                dirs.push(format!("src/{}/{}", context, dir));
+            }
+            files.extend(Self::context_files(model)?);
+            contexts.push(context);
+        }
+
+        for dir in dirs {
+            let path = format!("{}/{}", self.output_dir, dir);
+            fs::create_dir_all(&path)
+                .with_context(|| format!("Failed to create directory: {}", path))?;
+        }
+        for (file, content) in files {
+            let path = format!("{}/{}", self.output_dir, file);
+            fs::write(&path, content).with_context(|| format!("Failed to write {}", path))?;
+        }
+
+        println!("✅ Generation complete! Output at: {}", self.output_dir);
+
+        Ok(())
+    }
+
+    /// Files of the module of a bounded context
+    fn context_files(model: &IRModel) -> Result<Vec<(String, String)>> {
+        let context = Self::context_dir(model);
+        let mut files = Vec::from([
+            (
+                format!("src/{0}/{0}.module.ts", context),
+                Self::generate_module(model),
+            ),
+            (
+                format!("src/{}/domain/value-objects.ts", context),
+                Self::generate_value_objects(model)?,
+            ),
+            (
+                format!("src/{}/domain/events.ts", context),
+                Self::generate_events(model),
+            ),
+            (
+                format!("src/{}/dto/commands.dto.ts", context),
+                Self::generate_command_dtos(model),
+            ),
+            (
+                format!("src/{}/dto/queries.dto.ts", context),
+                Self::generate_query_dtos(model),
+            ),
+            (
+                format!("src/{}/commands/commands.ts", context),
+                Self::generate_commands(model),
+            ),
+            (
+                format!("src/{}/commands/handlers.ts", context),
+                Self::generate_command_handlers(model),
+            ),
+            (
+                format!("src/{}/queries/queries.ts", context),
+                Self::generate_queries(model),
+            ),
+            (
+                format!("src/{}/queries/handlers.ts", context),
+                Self::generate_query_handlers(model),
+            ),
+            (
+                format!("src/{}/controllers/commands.controller.ts", context),
+                Self::generate_commands_controller(model),
+            ),
+            (
+                format!("src/{}/controllers/queries.controller.ts", context),
+                Self::generate_queries_controller(model),
+            ),
+            (
+                format!("src/{}/infrastructure/event-store.ts", context),
+                EVENT_STORE.to_string(),
+            ),
+        ]);
+        for (name, aggregate) in &model.aggregates {
+            files.push((
+                format!("src/{}/domain/{}", context, aggregate_file(name)),
+                Self::generate_aggregate(model, name, aggregate),
+            ));
+        }
+        Ok(files)
+    }
+
+    fn context_dir(model: &IRModel) -> String {
+        to_kebab_case(&model.bounded_context.name)
+    }
+
+    fn module_name(model: &IRModel) -> String {
+        format!("{}Module", to_pascal_case(&model.bounded_context.name))
+    }
+
+    /// Names of the bounded contexts of a project, as in "A, B and C"
+    fn context_names(project: &IRProject) -> String {
+        let names: Vec<&str> = project
+            .contexts
+            .iter()
+            .map(|model| model.bounded_context.name.as_str())
+            .collect();
+        match names.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+            _ => names.concat(),
+        }
+    }
+
+    fn generate_package_json(project: &IRProject) -> String {
+        let contexts: Vec<String> = project.contexts.iter().map(Self::context_dir).collect();
+        format!(
+            r#"{{
+  "name": "{}-service",
+  "version": "0.1.0",
+  "description": "NestJS service for the {} bounded {}",
+  "private": true,
+  "scripts": {{
+    "build": "tsc",
+    "start": "node dist/main.js",
+    "start:dev": "ts-node src/main.ts"
+  }},
+  "dependencies": {{
+    "@nestjs/common": "^10.3.0",
+    "@nestjs/core": "^10.3.0",
+    "@nestjs/cqrs": "^10.2.0",
+    "@nestjs/platform-express": "^10.3.0",
+    "class-transformer": "^0.5.1",
+    "class-validator": "^0.14.1",
+    "reflect-metadata": "^0.2.0",
+    "rxjs": "^7.8.0"
+  }},
+  "devDependencies": {{
+    "@types/node": "^20.0.0",
+    "ts-node": "^10.9.0",
+    "typescript": "^5.4.0"
+  }}
+}}
+"#,
+            contexts.join("-"),
+            Self::context_names(project),
+            if contexts.len() == 1 {
+                "context"
+            } else {
+                "contexts"
+            }
+        )
+    }
+
+    fn generate_readme(project: &IRProject) -> String {
+        let contexts: Vec<String> = project.contexts.iter().map(Self::context_dir).collect();
+        let (first, others) = contexts
+            .split_first()
+            .expect("a project has at least one bounded context");
+        let routes = if others.is_empty() {
+            "The API is served under `/api` on port 3000, with the same routes and JSON as the Rust service.".to_string()
+        } else {
+            format!(
+                "The API is served on port 3000, each context under `/api/<context>`, such as `/api/{}`, with the routes and JSON of its Rust service below that path.",
+                first
+            )
+        };
+        let others: String = others
+            .iter()
+            .map(|context| {
+                let entry = format!("├── {}/", context);
+                format!("{:<27}# Laid out as {}/\n", entry, first)
+            })
+            .collect();
+
+        format!(
+            r#"# {package}-service
+
+NestJS service for the {names} bounded {noun}, generated by StormForge Generator
+
+## Running
+
+```bash
+npm install
+npm run start:dev
+```
+
+{routes}
+
+## Structure
+
+```
+src/
+├── {first}/
+│   ├── domain/            # Value objects, events and aggregates
+│   ├── dto/               # Request bodies and query strings, with their validation rules
+│   ├── commands/          # Commands and their handlers
+│   ├── queries/           # Queries and their handlers
+│   ├── controllers/       # POST /api/<command> and GET /api/<query>
+│   ├── infrastructure/    # Event store
+│   └── {first}.module.ts
+{others}├── common/                # Error responses, response envelope and validation pipe
+├── app.module.ts
+└── main.ts
+```
+
+Command handlers are stubs listing the events they produce and the conditions to check; aggregates change state in their `on<Event>` methods, which `apply` calls.
+"#,
+            package = contexts.join("-"),
+            names = Self::context_names(project),
+            noun = if contexts.len() == 1 {
+                "context"
+            } else {
+                "contexts"
+            },
+        )
+    }
+
+    /// Generate the root module, importing the module of every bounded
+    /// context
+    ///
+    /// Several contexts are each routed under their directory, as their
+    /// command and query names may clash.
+    fn generate_app_module(project: &IRProject) -> String {
+        let modules: Vec<(String, String)> = project
+            .contexts
+            .iter()
+            .map(|model| (Self::module_name(model), Self::context_dir(model)))
+            .collect();
+        let mut imports = Vec::from([r#"import { Module } from "@nestjs/common";"#.to_string()]);
+        if modules.len() > 1 {
+            imports.push(r#"import { RouterModule } from "@nestjs/core";"#.to_string());
+        }
+        for (module, context) in &modules {
+            imports.push(format!(
+                "import {{ {} }} from \"./{}/{}.module\";",
+                module, context, context
+            ));
+        }
+
+        let module_imports = if modules.len() == 1 {
+            format!("[{}]", modules[0].0)
+        } else {
+            let routes: String = modules
+                .iter()
+                .map(|(module, context)| {
+                    format!("      {{ path: \"{}\", module: {} }},\n", context, module)
+                })
+                .collect();
+            let modules: String = modules
+                .iter()
+                .map(|(module, _)| format!("    {},\n", module))
+                .collect();
+            format!(
+                "[\n{}    RouterModule.register([\n{}    ]),\n  ]",
+                modules, routes
+            )
+        };
+
+        format!(
+            "{}\n\n@Module({{\n  imports: {},\n}})\nexport class AppModule {{}}\n",
+            imports.join("\n"),
+            module_imports
+        )
+    }
+
+    /// Generate the module of the bounded context, wiring its handlers
+    pub fn generate_module(model: &IRModel) -> String {
+        let context = Self::context_dir(model);
+        format!(
+            r#"import {{ Module }} from "@nestjs/common";
+import {{ CqrsModule }} from "@nestjs/cqrs";
+import {{ CommandHandlers }} from "./commands/handlers";
+import {{ CommandsController }} from "./controllers/commands.controller";
+import {{ QueriesController }} from "./controllers/queries.controller";
+import {{ EventStore, InMemoryEventStore }} from "./infrastructure/event-store";
+import {{ QueryHandlers }} from "./queries/handlers";
+
+/** {context_name} bounded context */
+@Module({{
+  imports: [CqrsModule],
+  controllers: [CommandsController, QueriesController],
+  providers: [
+    ...CommandHandlers,
+    ...QueryHandlers,
+    {{ provide: EventStore, useClass: InMemoryEventStore }},
+  ],
+}})
+export class {module} {{}}
+"#,
+            context_name = model.bounded_context.name,
+            module = Self::module_name(model),
+        )
+        .replace("{context}", &context)
+    }
+
+    /// Generate value objects, each declared after the types it uses
+    /// since decorator metadata references them when the class is defined
+    pub fn generate_value_objects(model: &IRModel) -> Result<String> {
+        let mut items = Vec::new();
+        for name in value_object_order(model) {
+            let vo = &model.value_objects[name];
+            items.push(Self::generate_value_object(model, name, vo)?);
+        }
+        let decorators = items.concat();
+        let imports = decorator_imports(&decorators, "../../common/validation");
+        Ok(module(imports, items))
+    }
+
+    fn generate_value_object(model: &IRModel, name: &str, vo: &ValueObject) -> Result<String> {
+        let mut out = doc_comment(vo.description.as_deref(), "");
+        match vo.vo_type.as_deref() {
+            // Enums are serialized as the variant name, like the Rust service
+            Some("enum") => {
+                if vo.values.is_empty() {
+                    anyhow::bail!("Enum '{}' has no values", name);
+                }
+                out.push_str(&format!("export enum {} {{\n", name));
+                for value in &vo.values {
+                    let variant = to_pascal_case(&value.name);
+                    out.push_str(&doc_comment(value.description.as_deref(), "  "));
+                    out.push_str(&format!("  {0} = \"{0}\",\n", variant));
+                }
+                out.push_str("}\n");
+            }
+            // Identifiers serialize as their underlying value
+            Some("identifier") => {
+                let underlying =
+                    nest_type(model, vo.underlying_type.as_deref().unwrap_or("String"));
+                out.push_str(&format!("export type {} = {};\n", name, underlying));
+            }
+            _ => {
+                let fields = dto_fields(model, &vo.properties, false);
+                out.push_str(&class(&format!("export class {}", name), &fields));
+            }
+        }
+        Ok(out)
+    }
+
+    /// Generate the classes of domain events, matching their payloads
+    pub fn generate_events(model: &IRModel) -> String {
+        let mut items = Vec::from([EVENT_PAYLOAD.to_string()]);
+        for (name, event) in &model.events {
+            items.push(Self::generate_event(model, name, event));
+        }
+
+        let mut union = String::from(
+            "/** Domain event of this bounded context, tagged by `type` */\nexport type DomainEvent =",
+        );
+        if model.events.is_empty() {
+            union.push_str(" never;\n");
+        } else {
+            for name in model.events.keys() {
+                union.push_str(&format!("\n  | {}", name));
+            }
+            union.push_str(";\n");
+        }
+        items.push(union);
+
+        let mut imports = Vec::from([
+            r#"import { IEvent } from "@nestjs/cqrs";"#.to_string(),
+            r#"import { randomUUID } from "crypto";"#.to_string(),
+        ]);
+        imports.extend(domain_imports(
+            model,
+            "./",
+            model.events.values().flat_map(|event| &event.payload),
+        ));
+        module(imports, items)
+    }
+
+    fn generate_event(model: &IRModel, name: &str, event: &Event) -> String {
+        let mut out = doc_comment(event.description.as_deref(), "");
+        out.push_str(&format!("export class {} implements IEvent {{\n", name));
+        out.push_str(&format!("  readonly type = \"{}\" as const;\n", name));
+        out.push_str(
+            "  /** Unique event identifier */\n  readonly event_id: string = randomUUID();\n",
+        );
+        out.push_str("  /** Timestamp when the event occurred, in RFC 3339 */\n  readonly occurred_at: string = new Date().toISOString();\n");
+        if event.aggregate.is_some() {
+            out.push_str(
+                "  /** ID of the aggregate that produced this event */\n  readonly aggregate_id!: string;\n",
+            );
+        }
+        for field in plain_fields(model, &event.payload) {
+            out.push_str(&doc_comment(field.description.as_deref(), "  "));
+            out.push_str(&format!("  readonly {};\n", declaration(&field)));
+        }
+        out.push_str(&format!(
+            "\n  constructor(payload: EventPayload<{}>) {{\n    Object.assign(this, payload);\n  }}\n}}\n",
+            name
+        ));
+        out
+    }
+
+    /// Generate an aggregate root, changing state in an `on<Event>` method
+    /// per event, which `AggregateRoot.apply` calls by event class name
+    pub fn generate_aggregate(model: &IRModel, name: &str, aggregate: &Aggregate) -> String {
+        let mut description = aggregate.description.clone().unwrap_or_default();
+        if !aggregate.invariants.is_empty() {
+            if !description.is_empty() {
+                description.push_str("\n\n");
+            }
+            description.push_str("Invariants:");
+            for invariant in &aggregate.invariants {
+                description.push_str(&format!(
+                    "\n- {}: `{}`",
+                    invariant.description.as_deref().unwrap_or(&invariant.name),
+                    invariant.expression
+                ));
+            }
+        }
+
+        let fields = plain_fields(model, &aggregate.root_entity.properties);
+        let mut out = doc_comment((!description.is_empty()).then_some(&description), "");
+        out.push_str(&format!("export class {} extends AggregateRoot {{\n", name));
+        for (index, field) in fields.iter().enumerate() {
+            if index > 0 && (spaced(field) || spaced(&fields[index - 1])) {
+                out.push('\n');
+            }
+            out.push_str(&doc_comment(field.description.as_deref(), "  "));
+            out.push_str(&format!("  {};\n", declaration(field)));
+        }
+
+        let events: Vec<(&String, &Event)> = model
+            .events
+            .iter()
+            .filter(|(_, event)| event.aggregate.as_deref() == Some(name))
+            .collect();
+        for (event_name, event) in &events {
+            out.push_str(&format!("\n  on{0}(event: {0}): void {{\n", event_name));
+            let statements = Self::state_changes(model, name, aggregate, event_name, event);
+            if statements.is_empty() {
+                out.push_str("    // TODO: Change the state from the event\n");
+            }
+            for statement in statements {
+                out.push_str(&format!("    {}\n", statement));
+            }
+            out.push_str("  }\n");
+        }
+        out.push_str("}\n");
+
+        let mut imports =
+            Vec::from([r#"import { AggregateRoot } from "@nestjs/cqrs";"#.to_string()]);
+        if !events.is_empty() {
+            imports.push(named_import(
+                events.iter().map(|(event_name, _)| event_name.to_string()),
+                "./events",
+            ));
+        }
+        imports.extend(domain_imports(
+            model,
+            "./",
+            aggregate.root_entity.properties.iter(),
+        ));
+        module(imports, Vec::from([out]))
+    }
+
+    /// The identifier takes the event's aggregate ID, fields named like
+    /// aggregate fields are copied, and an enum field moves to the value
+    /// named by the event, as in the mock server, so `OrderPaid` sets an
+    /// `OrderStatus` to `Paid`
+    fn state_changes(
+        model: &IRModel,
+        name: &str,
+        aggregate: &Aggregate,
+        event_name: &str,
+        event: &Event,
+    ) -> Vec<String> {
+        let transition = event_name.strip_prefix(name).unwrap_or(event_name);
+        let mut statements = Vec::new();
+        for prop in &aggregate.root_entity.properties {
+            let field = to_snake_case(&prop.name);
+            if prop.identifier {
+                if let Some(id) = id_value(model, &prop.prop_type, "event.aggregate_id") {
+                    statements.push(format!("this.{} = {};", field, id));
+                }
+                continue;
+            }
+            let target = plain_field(model, prop);
+            if let Some(source) = event
+                .payload
+                .iter()
+                .find(|source| to_snake_case(&source.name) == field)
+            {
+                // Only values the field can hold are copied
+                if assignable(&plain_field(model, source), &target) {
+                    statements.push(format!("this.{0} = event.{0};", field));
+                }
+            } else if let Some(vo) = model
+                .value_objects
+                .get(prop.prop_type.trim())
+                .filter(|vo| vo.vo_type.as_deref() == Some("enum"))
+            {
+                if vo
+                    .values
+                    .iter()
+                    .any(|value| to_pascal_case(&value.name) == transition)
+                {
+                    statements.push(format!("this.{} = {}.{};", field, vo.name, transition));
+                }
+            }
+        }
+        statements
+    }
+
+    /// Generate request body classes of the commands, validated on arrival
+    pub fn generate_command_dtos(model: &IRModel) -> String {
+        let items: Vec<String> = model
+            .commands
+            .iter()
+            .map(|(name, command)| {
+                let mut out = doc_comment(command.description.as_deref(), "");
+                out.push_str(&class(
+                    &format!("export class {}Dto", name),
+                    &dto_fields(model, &command.payload, false),
+                ));
+                out
+            })
+            .collect();
+        let mut imports = decorator_imports(&items.concat(), "../../common/validation");
+        imports.extend(domain_imports(
+            model,
+            "../domain/",
+            model.commands.values().flat_map(|command| &command.payload),
+        ));
+        module(imports, items)
+    }
+
+    /// Generate query string classes of the queries, converting the
+    /// parameters from text before validating them
+    pub fn generate_query_dtos(model: &IRModel) -> String {
+        let items: Vec<String> = model
+            .queries
+            .iter()
+            .map(|(name, query)| {
+                let mut out = doc_comment(Some(&format!("Query string of `{}`", name)), "");
+                out.push_str(&class(
+                    &format!("export class {}Dto", name),
+                    &dto_fields(model, &query.parameters, true),
+                ));
+                out
+            })
+            .collect();
+        let mut imports = decorator_imports(&items.concat(), "../../common/validation");
+        imports.extend(domain_imports(
+            model,
+            "../domain/",
+            model.queries.values().flat_map(|query| &query.parameters),
+        ));
+        module(imports, items)
+    }
+
+    /// Generate a CQRS command per IR command, carrying its request body
+    pub fn generate_commands(model: &IRModel) -> String {
+        let items: Vec<String> = model
+            .commands
+            .iter()
+            .map(|(name, command)| {
+                let mut out = doc_comment(command.description.as_deref(), "");
+                out.push_str(&format!(
+                    "export class {0}Command implements ICommand {{\n  constructor(readonly payload: {0}Dto) {{}}\n}}\n",
+                    name
+                ));
+                out
+            })
+            .collect();
+        let mut imports = Vec::from([r#"import { ICommand } from "@nestjs/cqrs";"#.to_string()]);
+        if !model.commands.is_empty() {
+            imports.push(named_import(
+                model.commands.keys().map(|name| format!("{}Dto", name)),
+                "../dto/commands.dto",
+            ));
+        }
+        module(imports, items)
+    }
+
+    /// Generate a handler per command, loading its aggregate from the event
+    /// store, applying the events it produces and appending them
+    pub fn generate_command_handlers(model: &IRModel) -> String {
+        let mut items: Vec<String> = model
+            .commands
+            .iter()
+            .map(|(name, command)| Self::generate_command_handler(model, name, command))
+            .collect();
+        items.push(handler_list("CommandHandlers", model.commands.keys()));
+
+        let aggregates: BTreeSet<&str> = model
+            .commands
+            .values()
+            .filter_map(|command| command.aggregate.as_deref())
+            .filter(|aggregate| model.aggregates.contains_key(*aggregate))
+            .collect();
+        let code = items.concat();
+        let mut imports = Vec::from([
+            r#"import { CommandHandler, ICommandHandler } from "@nestjs/cqrs";"#.to_string(),
+        ]);
+        if code.contains("randomUUID()") {
+            imports.push(r#"import { randomUUID } from "crypto";"#.to_string());
+        }
+        if code.contains("new ApiError(") {
+            imports.push(r#"import { ApiError } from "../../common/api-error";"#.to_string());
+        }
+        imports.extend(aggregates.iter().map(|aggregate| {
+            named_import(
+                [aggregate.to_string()],
+                &format!(
+                    "../domain/{}",
+                    aggregate_file(aggregate).trim_end_matches(".ts")
+                ),
+            )
+        }));
+        if !aggregates.is_empty() {
+            let events = model
+                .commands
+                .values()
+                .filter(|command| command.aggregate.is_some())
+                .flat_map(|command| command.produces.iter().cloned())
+                .filter(|event| model.events.contains_key(event));
+            imports.push(named_import(
+                std::iter::once("DomainEvent".to_string()).chain(events),
+                "../domain/events",
+            ));
+            imports
+                .push(r#"import { EventStore } from "../infrastructure/event-store";"#.to_string());
+        }
+        if !model.commands.is_empty() {
+            imports.push(named_import(
+                model.commands.keys().map(|name| format!("{}Command", name)),
+                "./commands",
+            ));
+        }
+        module(imports, items)
+    }
+
+    fn generate_command_handler(model: &IRModel, name: &str, command: &Command) -> String {
+        let mut doc = command
+            .description
+            .clone()
+            .unwrap_or_else(|| format!("Handle `{}`", name));
+        if !command.produces.is_empty() {
+            let events: Vec<String> = command
+                .produces
+                .iter()
+                .map(|event| format!("`{}`", event))
+                .collect();
+            doc.push_str(&format!("\n\nProduces {}.", events.join(", ")));
+        }
+        for (title, conditions) in [
+            ("Validation", &command.validation),
+            ("Preconditions", &command.preconditions),
+        ] {
+            if !conditions.is_empty() {
+                doc.push_str(&format!("\n\n{}:", title));
+                for condition in conditions {
+                    doc.push_str(&format!("\n- {}", condition.message));
+                }
+            }
+        }
+        let header = class_header(
+            &format!("{}Handler", name),
+            &format!("ICommandHandler<{}Command>", name),
+        );
+        let doc = doc_comment(Some(&doc), "  ");
+
+        let Some(aggregate) = command
+            .aggregate
+            .as_deref()
+            .and_then(|aggregate| model.aggregates.get(aggregate))
+        else {
+            return format!(
+                r#"@CommandHandler({name}Command)
+{header}
+{doc}  async execute(command: {name}Command): Promise<string> {{
+    throw new ApiError("INTERNAL_ERROR", "{name} has no aggregate");
+  }}
+}}
+"#
+            );
+        };
+        let aggregate_type = &aggregate.name;
+        let state = to_camel_case(aggregate_type);
+
+        let mut body = String::from("    const { payload } = command;\n");
+        let id_field = AggregateGenerator::id_field(aggregate, command);
+        match id_field {
+            Some(field) => body.push_str(&format!(
+                "    const aggregateId = String(payload.{});\n",
+                to_snake_case(&field.name)
+            )),
+            None => body.push_str("    const aggregateId = randomUUID();\n"),
+        }
+        let aggregate_type_literal = format!("\"{}\"", aggregate_type);
+        body.push_str(&call(
+            "    const history = await this.eventStore.loadEvents",
+            &["aggregateId", &aggregate_type_literal],
+        ));
+        if id_field.is_some() && !command.preconditions.is_empty() {
+            body.push_str(&format!(
+                "    if (history.length === 0) {{\n      throw new ApiError(\"NOT_FOUND\", `{} ${{aggregateId}} not found`);\n    }}\n",
+                aggregate_type
+            ));
+        }
+        body.push_str(&format!(
+            "    const {0} = new {1}();\n    {0}.loadFromHistory(history);\n",
+            state, aggregate_type
+        ));
+        for condition in command.validation.iter().chain(&command.preconditions) {
+            body.push_str(&format!(
+                "    // TODO: enforce `{}`\n",
+                condition.expression
+            ));
+        }
+
+        body.push_str("    const events: DomainEvent[] = [\n");
+        for event_name in &command.produces {
+            let Some(event) = model.events.get(event_name) else {
+                continue;
+            };
+            body.push_str(&format!(
+                "      new {}({{\n        aggregate_id: aggregateId,\n",
+                event_name
+            ));
+            for prop in &event.payload {
+                let target = plain_field(model, prop);
+                match Self::event_value(model, aggregate, command, prop, &state) {
+                    Ok(value) => body.push_str(&format!("        {}: {},\n", target.name, value)),
+                    Err(todo) => {
+                        // Left to the developer, with a placeholder the field accepts
+                        body.push_str(&format!("        // TODO: {}\n", todo));
+                        let placeholder = if target.optional {
+                            "null"
+                        } else {
+                            "undefined!"
+                        };
+                        body.push_str(&format!("        {}: {},\n", target.name, placeholder));
+                    }
+                }
+            }
+            body.push_str("      }),\n");
+        }
+        body.push_str("    ];\n");
+        body.push_str(&format!(
+            "    events.forEach((event) => {}.apply(event));\n",
+            state
+        ));
+        for invariant in &aggregate.invariants {
+            body.push_str(&format!(
+                "    // TODO: enforce `{}`\n",
+                invariant.expression
+            ));
+        }
+        body.push_str(&call(
+            "    await this.eventStore.appendEvents",
+            &[
+                "aggregateId",
+                &aggregate_type_literal,
+                "events",
+                "history.length",
+            ],
+        ));
+        body.push_str("    return \"Command executed successfully\";\n");
+
+        format!(
+            r#"@CommandHandler({name}Command)
+{header}
+  constructor(private readonly eventStore: EventStore) {{}}
+
+{doc}  async execute(command: {name}Command): Promise<string> {{
+{body}  }}
+}}
+"#
+        )
+    }
+
+    /// TypeScript value of an event field, taken from the same source as in
+    /// the Rust handler, or what is left to do when it cannot be typed
+    fn event_value(
+        model: &IRModel,
+        aggregate: &Aggregate,
+        command: &Command,
+        prop: &Property,
+        state: &str,
+    ) -> std::result::Result<String, String> {
+        let target = plain_field(model, prop);
+        match FieldSource::of(aggregate, command, prop) {
+            FieldSource::Computed(expression) => Err(format!("compute `{}`", expression)),
+            FieldSource::Command => command
+                .payload
+                .iter()
+                .find(|field| field.name == prop.name)
+                .filter(|field| assignable(&plain_field(model, field), &target))
+                .map(|_| format!("payload.{}", target.name))
+                .ok_or_else(|| format!("convert `payload.{}`", target.name)),
+            FieldSource::AggregateId => id_value(model, &prop.prop_type, "aggregateId")
+                .ok_or_else(|| format!("convert `aggregateId` to `{}`", target.ts_type)),
+            FieldSource::Now => Ok("new Date().toISOString()".to_string()),
+            FieldSource::State => aggregate
+                .root_entity
+                .properties
+                .iter()
+                .find(|field| to_snake_case(&field.name) == target.name)
+                .filter(|field| assignable(&plain_field(model, field), &target))
+                .map(|_| format!("{}.{}", state, target.name))
+                .ok_or_else(|| format!("set `{}` from the `{}` state", target.name, state)),
+        }
+    }
+
+    /// Generate a CQRS query per IR query, carrying its query string
+    pub fn generate_queries(model: &IRModel) -> String {
+        let items: Vec<String> = model
+            .queries
+            .iter()
+            .map(|(name, query)| {
+                let mut out = doc_comment(query.description.as_deref(), "");
+                out.push_str(&format!(
+                    "export class {0}Query implements IQuery {{\n  constructor(readonly params: {0}Dto) {{}}\n}}\n",
+                    name
+                ));
+                out
+            })
+            .collect();
+        let mut imports = Vec::from([r#"import { IQuery } from "@nestjs/cqrs";"#.to_string()]);
+        if !model.queries.is_empty() {
+            imports.push(named_import(
+                model.queries.keys().map(|name| format!("{}Dto", name)),
+                "../dto/queries.dto",
+            ));
+        }
+        module(imports, items)
+    }
+
+    /// Generate a handler per query, to be completed with a read model
+    pub fn generate_query_handlers(model: &IRModel) -> String {
+        let mut items: Vec<String> = model
+            .queries
+            .iter()
+            .map(|(name, query)| Self::generate_query_handler(model, name, query))
+            .collect();
+        items.push(handler_list("QueryHandlers", model.queries.keys()));
+
+        let mut imports = Vec::from([
+            r#"import { IQueryHandler, QueryHandler } from "@nestjs/cqrs";"#.to_string(),
+        ]);
+        if model.queries.values().any(query_returns_error) {
+            imports.push(r#"import { ApiError } from "../../common/api-error";"#.to_string());
+        }
+        if model.queries.values().any(returns_page) {
+            imports.push(r#"import { PagedResult } from "../../common/api-response";"#.to_string());
+        }
+        imports.extend(result_imports(model));
+        if !model.queries.is_empty() {
+            imports.push(named_import(
+                model.queries.keys().map(|name| format!("{}Query", name)),
+                "./queries",
+            ));
+        }
+        module(imports, items)
+    }
+
+    fn generate_query_handler(model: &IRModel, name: &str, query: &Query) -> String {
+        let result = query_result(model, query);
+        let body = match &query.returns {
+            None => "return null;".to_string(),
+            Some(returns) if returns.nullable => "return null;".to_string(),
+            Some(returns) => {
+                let return_type = returns.return_type.trim();
+                if return_type.starts_with("List<") || return_type.starts_with("Vec<") {
+                    "return [];".to_string()
+                } else if return_type.starts_with("PagedResult<") {
+                    "return { items: [], total: 0, page: 1, page_size: 20 };".to_string()
+                } else {
+                    format!(
+                        "throw new ApiError(\"NOT_FOUND\", \"{} not found\");",
+                        to_rust_type(return_type)
+                    )
+                }
+            }
+        };
+        format!(
+            r#"@QueryHandler({name}Query)
+{header}
+{doc}  async execute(query: {name}Query): Promise<{result}> {{
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    {body}
+  }}
+}}
+"#,
+            doc = doc_comment(
+                Some(
+                    query
+                        .description
+                        .as_deref()
+                        .unwrap_or(&format!("Handle `{}`", name))
+                ),
+                "  "
+            ),
+            header = class_header(
+                &format!("{}Handler", name),
+                &format!("IQueryHandler<{}Query, {}>", name, result)
+            ),
+        )
+    }
+
+    /// Generate `POST /api/<command>` routes, sending commands on the bus
+    pub fn generate_commands_controller(model: &IRModel) -> String {
+        let mut out = String::from(
+            "@Controller()\nexport class CommandsController {\n  constructor(private readonly commandBus: CommandBus) {}\n",
+        );
+        for (name, command) in &model.commands {
+            out.push('\n');
+            out.push_str(&doc_comment(command.description.as_deref(), "  "));
+            out.push_str(&format!(
+                r#"  @Post("{path}")
+  @HttpCode(HttpStatus.OK)
+{signature}
+    return {{
+      data: await this.commandBus.execute(new {name}Command(body)),
+    }};
+  }}
+"#,
+                path = to_kebab_case(name),
+                signature = route_signature(
+                    &to_camel_case(name),
+                    &format!("@Body() body: {}Dto", name),
+                    "string"
+                ),
+            ));
+        }
+        out.push_str("}\n");
+
+        let mut imports = Vec::from([
+            r#"import { Body, Controller, HttpCode, HttpStatus, Post } from "@nestjs/common";"#
+                .to_string(),
+            r#"import { CommandBus } from "@nestjs/cqrs";"#.to_string(),
+            r#"import { ApiResponse } from "../../common/api-response";"#.to_string(),
+        ]);
+        if !model.commands.is_empty() {
+            imports.push(named_import(
+                model.commands.keys().map(|name| format!("{}Command", name)),
+                "../commands/commands",
+            ));
+            imports.push(named_import(
+                model.commands.keys().map(|name| format!("{}Dto", name)),
+                "../dto/commands.dto",
+            ));
+        }
+        module(imports, Vec::from([out]))
+    }
+
+    /// Generate `GET /api/<query>` routes, sending queries on the bus
+    pub fn generate_queries_controller(model: &IRModel) -> String {
+        let mut out = String::from(
+            "@Controller()\nexport class QueriesController {\n  constructor(private readonly queryBus: QueryBus) {}\n",
+        );
+        for (name, query) in &model.queries {
+            let signature = route_signature(
+                &to_camel_case(name),
+                &format!("@Query() params: {}Dto", name),
+                &query_result(model, query),
+            );
+            out.push('\n');
+            out.push_str(&doc_comment(query.description.as_deref(), "  "));
+            out.push_str(&format!(
+                r#"  @Get("{path}")
+{signature}
+    return {{
+      data: await this.queryBus.execute(new {name}Query(params)),
+    }};
+  }}
+"#,
+                path = to_kebab_case(name),
+            ));
+        }
+        out.push_str("}\n");
+
+        let mut imports = Vec::from([
+            r#"import { Controller, Get, Query } from "@nestjs/common";"#.to_string(),
+            r#"import { QueryBus } from "@nestjs/cqrs";"#.to_string(),
+        ]);
+        if model.queries.values().any(returns_page) {
+            imports.push(
+                r#"import { ApiResponse, PagedResult } from "../../common/api-response";"#
+                    .to_string(),
+            );
+        } else {
+            imports.push(r#"import { ApiResponse } from "../../common/api-response";"#.to_string());
+        }
+        imports.extend(result_imports(model));
+        if !model.queries.is_empty() {
+            imports.push(named_import(
+                model.queries.keys().map(|name| format!("{}Dto", name)),
+                "../dto/queries.dto",
+            ));
+            imports.push(named_import(
+                model.queries.keys().map(|name| format!("{}Query", name)),
+                "../queries/queries",
+            ));
+        }
+        module(imports, Vec::from([out]))
+    }
+}
+
+/// File of an aggregate class, such as `order-line.aggregate.ts`
+fn aggregate_file(name: &str) -> String {
+    format!("{}.aggregate.ts", to_kebab_case(name))
+}
+
+/// TypeScript type of the JSON the Rust service exchanges for an IR type
+pub fn nest_type(model: &IRModel, ir_type: &str) -> String {
+    let ir_type = ir_type.trim();
+    let generic = |prefix: &str| {
+        ir_type
+            .strip_prefix(prefix)
+            .map(|rest| nest_type(model, rest.strip_suffix('>').unwrap_or(rest)))
+    };
+    if let Some(item) = generic("List<").or_else(|| generic("Vec<")) {
+        return if item.contains(' ') {
+            format!("({})[]", item)
+        } else {
+            format!("{}[]", item)
+        };
+    }
+    if let Some(inner) = generic("Option<") {
+        return nullable(&inner);
+    }
+    if let Some(item) = generic("PagedResult<") {
+        return format!("PagedResult<{}>", item);
+    }
+
+    match ir_type {
+        "Integer" => "number".to_string(),
+        "Boolean" => "boolean".to_string(),
+        // Decimals are serialized as strings to keep their precision, and
+        // dates and times as ISO 8601 strings
+        "String" | "Decimal" | "DateTime" | "Date" | "Time" | "Uuid" => "string".to_string(),
+        _ => {
+            let name = to_rust_type(ir_type);
+            if model.value_objects.contains_key(&name) || model.aggregates.contains_key(&name) {
+                name
+            } else {
+                // Types the IR does not define are kept as raw JSON
+                "unknown".to_string()
+            }
+        }
+    }
+}
+
+/// Call statement, with an argument per line when too long
+fn call(callee: &str, arguments: &[&str]) -> String {
+    let inline = format!("{}({});", callee, arguments.join(", "));
+    if inline.len() <= PRINT_WIDTH {
+        return format!("{}\n", inline);
+    }
+    let indent = &callee[..callee.len() - callee.trim_start().len()];
+    let arguments: String = arguments
+        .iter()
+        .map(|argument| format!("{}  {},\n", indent, argument))
+        .collect();
+    format!("{}(\n{}{});\n", callee, arguments, indent)
+}
+
+/// Whether a field of type `source` can be assigned to `target`
+fn assignable(source: &TsField, target: &TsField) -> bool {
+    source.ts_type == target.ts_type
+        || (target.optional && nullable(&source.ts_type) == target.ts_type)
+}
+
+/// `id`, a string aggregate ID, as a value of an identifier type
+fn id_value(model: &IRModel, ir_type: &str, id: &str) -> Option<String> {
+    let underlying = model
+        .value_objects
+        .get(ir_type.trim())
+        .filter(|vo| vo.vo_type.as_deref() == Some("identifier"))
+        .map_or(ir_type, |vo| {
+            vo.underlying_type.as_deref().unwrap_or("String")
+        });
+    match nest_type(model, underlying).as_str() {
+        "string" => Some(id.to_string()),
+        "number" => Some(format!("Number({})", id)),
+        _ => None,
+    }
+}
+
+/// Nullable TypeScript type, as an `Option` is serialized as `null`
+fn nullable(ts_type: &str) -> String {
+    if ts_type == "unknown" || ts_type.ends_with(" | null") {
+        ts_type.to_string()
+    } else {
+        format!("{} | null", ts_type)
+    }
+}
+
+/// Result type of a query handler
+fn query_result(model: &IRModel, query: &Query) -> String {
+    match &query.returns {
+        Some(returns) if returns.nullable => nullable(&nest_type(model, &returns.return_type)),
+        Some(returns) => nest_type(model, &returns.return_type),
+        None => "null".to_string(),
+    }
+}
+
+fn returns_page(query: &Query) -> bool {
+    query
+        .returns
+        .as_ref()
+        .is_some_and(|returns| returns.return_type.trim().starts_with("PagedResult<"))
+}
+
+/// Whether the handler stub of a query answers `NOT_FOUND`
+fn query_returns_error(query: &Query) -> bool {
+    query.returns.as_ref().is_some_and(|returns| {
+        let return_type = returns.return_type.trim();
+        !returns.nullable
+            && !["List<", "Vec<", "PagedResult<"]
+                .iter()
+                .any(|prefix| return_type.starts_with(prefix))
+    })
+}
+
+/// Imports of the domain types that query results reference
+fn result_imports(model: &IRModel) -> Vec<String> {
+    let results: Vec<Property> = model
+        .queries
+        .values()
+        .filter_map(|query| query.returns.as_ref())
+        .map(|returns| Property {
+            name: "result".to_string(),
+            prop_type: returns.return_type.clone(),
+            identifier: false,
+            required: true,
+            default: None,
+            description: None,
+            validation: None,
+            computed: None,
+        })
+        .collect();
+    domain_imports(model, "../domain/", results.iter())
+}
+
+/// Imports of the value objects and aggregates that properties reference,
+/// from the domain directory at `domain`
+fn domain_imports<'a>(
+    model: &IRModel,
+    domain: &str,
+    properties: impl Iterator<Item = &'a Property>,
+) -> Vec<String> {
+    let mut value_objects = BTreeSet::new();
+    let mut aggregates = BTreeSet::new();
+    for prop in properties {
+        if let Some(name) = referenced_type(&prop.prop_type) {
+            if model.value_objects.contains_key(&name) {
+                value_objects.insert(name);
+            } else if model.aggregates.contains_key(&name) {
+                aggregates.insert(name);
+            }
+        }
+    }
+    let mut imports: Vec<String> = aggregates
+        .into_iter()
+        .map(|name| {
+            named_import(
+                [name.clone()],
+                &format!(
+                    "{}{}",
+                    domain,
+                    aggregate_file(&name).trim_end_matches(".ts")
+                ),
+            )
+        })
+        .collect();
+    if !value_objects.is_empty() {
+        imports.push(named_import(
+            value_objects,
+            &format!("{}value-objects", domain),
+        ));
+    }
+    imports
+}
+
+/// Imports of the decorators used in `code`, by package
+fn decorator_imports(code: &str, decimal_module: &str) -> Vec<String> {
+    let used: BTreeSet<&str> = code
+        .split('@')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('(').map(|(name, _)| name))
+        .filter(|name| name.chars().all(|c| c.is_ascii_alphanumeric()))
+        .collect();
+    let pick = |names: &[&str]| -> Vec<String> {
+        used.iter()
+            .filter(|name| names.contains(name))
+            .map(|name| name.to_string())
+            .collect()
+    };
+    let validators: Vec<String> = used
+        .iter()
+        .filter(|name| !TRANSFORMER_DECORATORS.contains(name) && !DECIMAL_DECORATORS.contains(name))
+        .map(|name| name.to_string())
+        .collect();
+
+    let mut imports = Vec::new();
+    for (names, package) in [
+        (pick(TRANSFORMER_DECORATORS), "class-transformer"),
+        (validators, "class-validator"),
+        (pick(DECIMAL_DECORATORS), decimal_module),
+    ] {
+        if !names.is_empty() {
+            imports.push(named_import(names, package));
+        }
+    }
+    imports
+}
+
+/// `import { A, B } from "module";`, one name per line when too long
+fn named_import(names: impl IntoIterator<Item = String>, from: &str) -> String {
+    let names: BTreeSet<String> = names.into_iter().collect();
+    let names: Vec<String> = names.into_iter().collect();
+    let inline = format!("import {{ {} }} from \"{}\";", names.join(", "), from);
+    if inline.len() <= PRINT_WIDTH {
+        return inline;
+    }
+    let names: String = names.iter().map(|name| format!("  {},\n", name)).collect();
+    format!("import {{\n{}}} from \"{}\";", names, from)
+}
+
+/// `export const Handlers = [...]` of the handlers of some commands or queries
+fn handler_list<'a>(list: &str, names: impl Iterator<Item = &'a String>) -> String {
+    let handlers: Vec<String> = names.map(|name| format!("{}Handler", name)).collect();
+    let inline = format!("export const {} = [{}];\n", list, handlers.join(", "));
+    if inline.len() <= PRINT_WIDTH + 1 {
+        return inline;
+    }
+    let handlers: String = handlers
+        .iter()
+        .map(|handler| format!("  {},\n", handler))
+        .collect();
+    format!("export const {} = [\n{}];\n", list, handlers)
+}
+
+/// Fields without decorators, for events and aggregates
+fn plain_fields(model: &IRModel, properties: &[Property]) -> Vec<TsField> {
+    properties
+        .iter()
+        .map(|prop| plain_field(model, prop))
+        .collect()
+}
+
+fn plain_field(model: &IRModel, prop: &Property) -> TsField {
+    let ts_type = nest_type(model, &prop.prop_type);
+    TsField {
+        name: to_snake_case(&prop.name),
+        ts_type: if prop.required {
+            ts_type
+        } else {
+            nullable(&ts_type)
+        },
+        optional: !prop.required,
+        default: None,
+        decorators: Vec::new(),
+        description: prop.description.clone(),
+    }
+}
+
+/// Fields of a request class, with the validation rules as decorators
+fn dto_fields(model: &IRModel, properties: &[Property], query: bool) -> Vec<TsField> {
+    properties
+        .iter()
+        .map(|prop| {
+            let mut field = plain_field(model, prop);
+            field.optional = field.optional || prop.computed.is_some();
+            field.default = prop
+                .default
+                .as_ref()
+                .map(|default| default_literal(model, prop, default));
+            // Query strings carry no `null`
+            if query && field.optional {
+                field.ts_type = nest_type(model, &prop.prop_type)
+                    .trim_end_matches(" | null")
+                    .to_string();
+            }
+            field.decorators = decorators(model, prop, query);
+            field
+        })
+        .collect()
+}
+
+/// TypeScript expression of an IR default value
+fn default_literal(model: &IRModel, prop: &Property, default: &serde_json::Value) -> String {
+    let ir_type = prop.prop_type.trim();
+    match (model.value_objects.get(ir_type), default) {
+        (Some(vo), serde_json::Value::String(value)) if vo.vo_type.as_deref() == Some("enum") => {
+            format!("{}.{}", ir_type, to_pascal_case(value))
+        }
+        // Decimals are strings on the wire
+        (_, serde_json::Value::Number(number)) if ir_type == "Decimal" => {
+            format!("\"{}\"", number)
+        }
+        _ => default.to_string(),
+    }
+}
+
+/// `class-validator` decorators checking a property as the Rust service
+/// deserializes and validates it
+fn decorators(model: &IRModel, prop: &Property, query: bool) -> Vec<String> {
+    let mut ir_type = prop.prop_type.trim();
+    let mut optional = !prop.required || prop.computed.is_some() || prop.default.is_some();
+    if let Some(inner) = generic(ir_type, "Option<") {
+        ir_type = inner;
+        optional = true;
+    }
+
+    let mut out = Vec::new();
+    if optional {
+        out.push("@IsOptional()".to_string());
+    }
+    let validation = prop.validation.as_ref();
+    if let Some(item) = generic(ir_type, "List<").or_else(|| generic(ir_type, "Vec<")) {
+        out.push("@IsArray()".to_string());
+        // Length rules of a list bound its number of items
+        if let Some(min) = validation.and_then(|rule| rule.min_length) {
+            out.push(format!("@ArrayMinSize({})", min));
+        }
+        if let Some(max) = validation.and_then(|rule| rule.max_length) {
+            out.push(format!("@ArrayMaxSize({})", max));
+        }
+        out.extend(type_decorators(model, item, None, true, query));
+    } else {
+        out.extend(type_decorators(model, ir_type, validation, false, query));
+    }
+    if out.is_empty() {
+        out.push("@IsDefined()".to_string());
+    }
+    out
+}
+
+fn type_decorators(
+    model: &IRModel,
+    ir_type: &str,
+    validation: Option<&Validation>,
+    each: bool,
+    query: bool,
+) -> Vec<String> {
+    // Decorator with its arguments and, for list items, `{ each: true }`
+    let decorator = |name: &str, args: &[String]| {
+        let mut args = args.to_vec();
+        if each {
+            args.push("{ each: true }".to_string());
+        }
+        format!("@{}({})", name, args.join(", "))
+    };
+    let range = |out: &mut Vec<String>, min: &str, max: &str| {
+        if let Some(value) = validation.and_then(|rule| rule.min) {
+            out.push(decorator(min, &[number(value)]));
+        }
+        if let Some(value) = validation.and_then(|rule| rule.max) {
+            out.push(decorator(max, &[number(value)]));
+        }
+    };
+
+    let mut out = Vec::new();
+    match ir_type.trim() {
+        "String" => {
+            out.push(decorator("IsString", &[]));
+            if let Some(min) = validation.and_then(|rule| rule.min_length) {
+                out.push(decorator("MinLength", &[min.to_string()]));
+            }
+            if let Some(max) = validation.and_then(|rule| rule.max_length) {
+                out.push(decorator("MaxLength", &[max.to_string()]));
+            }
+            if let Some(pattern) = validation.and_then(|rule| rule.pattern.as_deref()) {
+                out.push(decorator("Matches", &[regex_literal(pattern)]));
+            }
+        }
+        "Integer" => {
+            if query && !each {
+                out.push("@Type(() => Number)".to_string());
+            }
+            out.push(decorator("IsInt", &[]));
+            range(&mut out, "Min", "Max");
+        }
+        "Decimal" => {
+            let digits = validation
+                .and_then(|rule| rule.precision)
+                .map(|precision| format!("{{ decimal_digits: \"0,{}\" }}", precision));
+            match (digits, each) {
+                (Some(digits), _) => out.push(decorator("IsDecimal", &[digits])),
+                (None, true) => out.push(decorator("IsDecimal", &["{}".to_string()])),
+                (None, false) => out.push(decorator("IsDecimal", &[])),
+            }
+            range(&mut out, "MinDecimal", "MaxDecimal");
+        }
+        "Boolean" => {
+            if query && !each {
+                out.push(
+                    "@Transform(({ value }) => value === true || value === \"true\")".to_string(),
+                );
+            }
+            out.push(decorator("IsBoolean", &[]));
+        }
+        "DateTime" => out.push(decorator("IsRFC3339", &[])),
+        "Date" => out.push(decorator("Matches", &[DATE.to_string()])),
+        "Time" => out.push(decorator("Matches", &[TIME.to_string()])),
+        "Uuid" if each => out.push(decorator("IsUUID", &["\"all\"".to_string()])),
+        "Uuid" => out.push(decorator("IsUUID", &[])),
+        other => {
+            let name = to_rust_type(other);
+            if let Some(vo) = model.value_objects.get(&name) {
+                match vo.vo_type.as_deref() {
+                    Some("enum") => out.push(decorator("IsEnum", std::slice::from_ref(&name))),
+                    Some("identifier") => out.extend(type_decorators(
+                        model,
+                        vo.underlying_type.as_deref().unwrap_or("String"),
+                        validation,
+                        each,
+                        query,
+                    )),
+                    _ => {
+                        out.push(decorator("ValidateNested", &[]));
+                        out.push(format!("@Type(() => {})", name));
+                    }
+                }
+            } else if model.aggregates.contains_key(&name) {
+                out.push(decorator("IsObject", &[]));
+            }
+            // Types the IR does not define accept any JSON
+        }
+    }
+    out
+}
+
+/// Argument of a generic IR type such as `List<T>`
+fn generic<'t>(ir_type: &'t str, prefix: &str) -> Option<&'t str> {
+    ir_type
+        .strip_prefix(prefix)
+        .map(|rest| rest.strip_suffix('>').unwrap_or(rest).trim())
+}
+
+fn number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+/// JavaScript regular expression literal of an IR pattern
+fn regex_literal(pattern: &str) -> String {
+    let mut out = String::from("/");
+    let mut escaped = false;
+    for c in pattern.chars() {
+        if c == '/' && !escaped {
+            out.push('\\');
+        }
+        escaped = c == '\\' && !escaped;
+        out.push(c);
+    }
+    out.push('/');
+    out
+}
+
+/// `name!: type`, `name?: type` or `name: type = default`
+fn declaration(field: &TsField) -> String {
+    match (&field.default, field.optional) {
+        (Some(default), _) => format!("{}: {} = {}", field.name, field.ts_type, default),
+        (None, true) => format!("{}?: {}", field.name, field.ts_type),
+        (None, false) => format!("{}!: {}", field.name, field.ts_type),
+    }
+}
+
+/// `async method(parameter): Promise<ApiResponse<T>> {` of a route, with
+/// the parameter on its own line when too long
+fn route_signature(method: &str, parameter: &str, result: &str) -> String {
+    let inline = format!(
+        "  async {}({}): Promise<ApiResponse<{}>> {{",
+        method, parameter, result
+    );
+    if inline.len() <= PRINT_WIDTH {
+        inline
+    } else {
+        format!(
+            "  async {}(\n    {},\n  ): Promise<ApiResponse<{}>> {{",
+            method, parameter, result
+        )
+    }
+}
+
+/// Whether a field is set apart by blank lines, having a comment or decorators
+fn spaced(field: &TsField) -> bool {
+    field.description.is_some() || !field.decorators.is_empty()
+}
+
+/// `export class X implements Y {`, with `implements` on its own line when
+/// too long
+fn class_header(class: &str, implements: &str) -> String {
+    let inline = format!("export class {} implements {} {{", class, implements);
+    if inline.len() <= PRINT_WIDTH {
+        inline
+    } else {
+        format!("export class {}\n  implements {}\n{{", class, implements)
+    }
+}
+
+/// Class with decorated fields, separated by a blank line when decorated
+fn class(header: &str, fields: &[TsField]) -> String {
+    if fields.is_empty() {
+        return format!("{} {{}}\n", header);
+    }
+    let mut out = format!("{} {{\n", header);
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 && (spaced(field) || spaced(&fields[index - 1])) {
+            out.push('\n');
+        }
+        out.push_str(&doc_comment(field.description.as_deref(), "  "));
+        for decorator in &field.decorators {
+            out.push_str(&format!("  {}\n", decorator));
+        }
+        out.push_str(&format!("  {};\n", declaration(field)));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn module(imports: Vec<String>, items: Vec<String>) -> String {
+    let mut out = String::new();
+    if !imports.is_empty() {
+        out.push_str(&imports.join("\n"));
+        out.push_str("\n\n");
+    }
+    if items.is_empty() {
+        out.push_str("export {};\n");
+    }
+    out.push_str(&items.join("\n"));
+    out
+}
+
+/// TSDoc comment at the given indentation
+fn doc_comment(text: Option<&str>, indent: &str) -> String {
+    let Some(text) = text else {
+        return String::new();
+    };
+    if !text.contains('\n') {
+        return format!("{}/** {} */\n", indent, text);
+    }
+    let mut out = format!("{}/**\n", indent);
+    for line in text.lines() {
+        if line.is_empty() {
+            out.push_str(&format!("{} *\n", indent));
+        } else {
+            out.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    out.push_str(&format!("{} */\n", indent));
+    out
+}
+
+const TSCONFIG: &str = r#"{
+  "compilerOptions": {
+    "module": "commonjs",
+    "target": "ES2021",
+    "declaration": true,
+    "emitDecoratorMetadata": true,
+    "experimentalDecorators": true,
+    "strict": true,
+    "skipLibCheck": true,
+    "outDir": "dist",
+    "rootDir": "src"
+  },
+  "include": ["src"]
+}
+"#;
+
+const MAIN: &str = r#"import "reflect-metadata";
+import { NestFactory } from "@nestjs/core";
+import { AppModule } from "./app.module";
+import { validationPipe } from "./common/validation";
+
+async function bootstrap(): Promise<void> {
+  const app = await NestFactory.create(AppModule);
+  app.setGlobalPrefix("api");
+  app.useGlobalPipes(validationPipe);
+  await app.listen(3000);
+}
+
+void bootstrap();
+"#;
+
+const API_ERROR: &str = r#"import { HttpException, HttpStatus } from "@nestjs/common";
+
+const STATUS_CODES: Record<string, HttpStatus> = {
+  VALIDATION_ERROR: HttpStatus.BAD_REQUEST,
+  NOT_FOUND: HttpStatus.NOT_FOUND,
+};
+
+/** Error answered with `{ code, message }`, such as `NOT_FOUND` */
+export class ApiError extends HttpException {
+  constructor(
+    readonly code: string,
+    message: string,
+  ) {
+    super(
+      { code, message },
+      STATUS_CODES[code] ?? HttpStatus.INTERNAL_SERVER_ERROR,
+    );
+  }
+}
+"#;
+
+const API_RESPONSE: &str = r#"/** `{ data }` envelope of command and query responses */
+export interface ApiResponse<T> {
+  data: T;
+}
+
+/** Page of query results */
+export interface PagedResult<T> {
+  items: T[];
+  total: number;
+  page: number;
+  page_size: number;
+}
+"#;
+
+const VALIDATION: &str = r#"import { ValidationPipe } from "@nestjs/common";
+import {
+  ValidateBy,
+  ValidationError,
+  ValidationOptions,
+  buildMessage,
+} from "class-validator";
+import { ApiError } from "./api-error";
+
+/** Pipe validating request bodies and query strings against their DTO */
+export const validationPipe = new ValidationPipe({
+  transform: true,
+  exceptionFactory: (errors: ValidationError[]) =>
+    new ApiError(
+      "VALIDATION_ERROR",
+      `Validation error: ${problems(errors).join("; ")}`,
+    ),
+});
+
+/** Messages of failed constraints, with the path of nested fields */
+function problems(errors: ValidationError[], path = ""): string[] {
+  return errors.flatMap((error) => {
+    const field = path ? `${path}.${error.property}` : error.property;
+    const messages = Object.values(error.constraints ?? {}).map((message) =>
+      message.startsWith(error.property)
+        ? field + message.slice(error.property.length)
+        : `${field}: ${message}`,
+    );
+    return [...messages, ...problems(error.children ?? [], field)];
+  });
+}
+
+/** Checks that a decimal string is at least `min` */
+export function MinDecimal(
+  min: number,
+  options?: ValidationOptions,
+): PropertyDecorator {
+  return ValidateBy(
+    {
+      name: "minDecimal",
+      constraints: [min],
+      validator: {
+        validate: (value: unknown) =>
+          typeof value === "string" && Number(value) >= min,
+        defaultMessage: buildMessage(
+          (each) => `${each}$property must not be less than $constraint1`,
+          options,
+        ),
+      },
+    },
+    options,
+  );
+}
+
+/** Checks that a decimal string is at most `max` */
+export function MaxDecimal(
+  max: number,
+  options?: ValidationOptions,
+): PropertyDecorator {
+  return ValidateBy(
+    {
+      name: "maxDecimal",
+      constraints: [max],
+      validator: {
+        validate: (value: unknown) =>
+          typeof value === "string" && Number(value) <= max,
+        defaultMessage: buildMessage(
+          (each) => `${each}$property must not be greater than $constraint1`,
+          options,
+        ),
+      },
+    },
+    options,
+  );
+}
+"#;
+
+const EVENT_PAYLOAD: &str = r#"/** Fields of an event given when raising it */
+export type EventPayload<E> = Omit<E, "type" | "event_id" | "occurred_at">;
+"#;
+
+const EVENT_STORE: &str = r#"import { Injectable } from "@nestjs/common";
+import { EventBus } from "@nestjs/cqrs";
+import { DomainEvent } from "../domain/events";
+
+/** The aggregate is not at the version the writer expected */
+export class ConcurrencyError extends Error {}
+
+/** Event store, injected into command and query handlers */
+export abstract class EventStore {
+  /**
+   * Append events to an aggregate
+   *
+   * Nothing is written when `expectedVersion` does not match the number of
+   * events already stored for the aggregate.
+   */
+  abstract appendEvents(
+    aggregateId: string,
+    aggregateType: string,
+    events: DomainEvent[],
+    expectedVersion?: number,
+  ): Promise<void>;
+
+  /** Number of events stored for an aggregate */
+  abstract version(aggregateId: string, aggregateType: string): Promise<number>;
+
+  /** Events of an aggregate in append order */
+  abstract loadEvents(
+    aggregateId: string,
+    aggregateType: string,
+  ): Promise<DomainEvent[]>;
+
+  /** Events of all aggregates in append order */
+  abstract getAllEvents(): Promise<DomainEvent[]>;
+}
+
+/** Event store keeping events in memory, publishing them once stored */
+@Injectable()
+export class InMemoryEventStore extends EventStore {
+  private readonly streams = new Map<string, DomainEvent[]>();
+  private readonly log: DomainEvent[] = [];
+
+  constructor(private readonly eventBus: EventBus) {
+    super();
+  }
+
+  async appendEvents(
+    aggregateId: string,
+    aggregateType: string,
+    events: DomainEvent[],
+    expectedVersion?: number,
+  ): Promise<void> {
+    const stream = this.stream(aggregateId, aggregateType);
+    if (expectedVersion !== undefined && expectedVersion !== stream.length) {
+      throw new ConcurrencyError(
+        `Concurrency conflict: expected version ${expectedVersion}, found ${stream.length}`,
+      );
+    }
+    stream.push(...events);
+    this.log.push(...events);
+    this.eventBus.publishAll(events);
+  }
+
+  async version(aggregateId: string, aggregateType: string): Promise<number> {
+    return this.stream(aggregateId, aggregateType).length;
+  }
+
+  async loadEvents(
+    aggregateId: string,
+    aggregateType: string,
+  ): Promise<DomainEvent[]> {
+    return [...this.stream(aggregateId, aggregateType)];
+  }
+
+  async getAllEvents(): Promise<DomainEvent[]> {
+    return [...this.log];
+  }
+
+  private stream(aggregateId: string, aggregateType: string): DomainEvent[] {
+    const key = `${aggregateType}/${aggregateId}`;
+    let stream = this.streams.get(key);
+    if (!stream) {
+      stream = [];
+      this.streams.set(key, stream);
+    }
+    return stream;
+  }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn model() -> IRModel {
+        IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "test.order"
+aggregates:
+  Order:
+    name: "Order"
+    root_entity:
+      name: "Order"
+      properties:
+        - name: "id"
+          type: "OrderId"
+          identifier: true
+        - name: "status"
+          type: "OrderStatus"
+        - name: "reason"
+          type: "String"
+          required: false
+value_objects:
+  OrderId:
+    name: "OrderId"
+    type: "identifier"
+    underlying_type: "String"
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "CREATED"
+      - name: "CANCELLED"
+  Money:
+    name: "Money"
+    properties:
+      - name: "amount"
+        type: "Decimal"
+        validation:
+          min: 0
+          precision: 2
+commands:
+  CancelOrder:
+    name: "CancelOrder"
+    aggregate: "Order"
+    produces: ["OrderCancelled"]
+    preconditions:
+      - expression: "order.status == CREATED"
+        message: "Only created orders can be cancelled"
+    payload:
+      - name: "orderId"
+        type: "OrderId"
+        required: true
+        validation:
+          pattern: "^ord/[0-9]+$"
+      - name: "reason"
+        type: "String"
+        required: false
+        validation:
+          maxLength: 200
+      - name: "refunds"
+        type: "List<Money>"
+        validation:
+          maxLength: 3
+events:
+  OrderCancelled:
+    name: "OrderCancelled"
+    aggregate: "Order"
+    payload:
+      - name: "orderId"
+        type: "OrderId"
+      - name: "reason"
+        type: "String"
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_dtos_carry_validation_rules() {
+        let model = model();
+        let dtos = NestJsGenerator::generate_command_dtos(&model);
+        assert!(
+            dtos.contains("  @IsString()\n  @Matches(/^ord\\/[0-9]+$/)\n  order_id!: OrderId;\n")
+        );
+        assert!(dtos.contains(
+            "  @IsOptional()\n  @IsString()\n  @MaxLength(200)\n  reason?: string | null;\n"
+        ));
+        assert!(dtos.contains(
+            "  @IsArray()\n  @ArrayMaxSize(3)\n  @ValidateNested({ each: true })\n  @Type(() => Money)\n  refunds!: Money[];\n"
+        ));
+        assert!(dtos.starts_with("import { Type } from \"class-transformer\";\n"));
+
+        let value_objects = NestJsGenerator::generate_value_objects(&model).unwrap();
+        assert!(value_objects.contains(
+            "  @IsDecimal({ decimal_digits: \"0,2\" })\n  @MinDecimal(0)\n  amount!: string;\n"
+        ));
+        assert!(value_objects.contains("import { MinDecimal } from \"../../common/validation\";\n"));
+    }
+
+    #[test]
+    fn test_aggregate_applies_its_events() {
+        let model = model();
+        let aggregate =
+            NestJsGenerator::generate_aggregate(&model, "Order", &model.aggregates["Order"]);
+        assert!(aggregate.contains("export class Order extends AggregateRoot {\n"));
+        assert!(aggregate.contains(
+            "  onOrderCancelled(event: OrderCancelled): void {\n    this.id = event.aggregate_id;\n    this.status = OrderStatus.Cancelled;\n    this.reason = event.reason;\n  }\n"
+        ));
+
+        let events = NestJsGenerator::generate_events(&model);
+        assert!(events.contains("export class OrderCancelled implements IEvent {\n  readonly type = \"OrderCancelled\" as const;\n"));
+        assert!(events.contains("  readonly order_id!: OrderId;\n"));
+        assert!(events.contains("export type DomainEvent =\n  | OrderCancelled;\n"));
+    }
+
+    #[test]
+    fn test_command_handler_appends_its_events() {
+        let handlers = NestJsGenerator::generate_command_handlers(&model());
+        assert!(handlers.contains(
+            r#"    const aggregateId = String(payload.order_id);
+    const history = await this.eventStore.loadEvents(aggregateId, "Order");
+    if (history.length === 0) {
+      throw new ApiError("NOT_FOUND", `Order ${aggregateId} not found`);
+    }
+    const order = new Order();
+    order.loadFromHistory(history);
+    // TODO: enforce `order.status == CREATED`
+    const events: DomainEvent[] = [
+      new OrderCancelled({
+        aggregate_id: aggregateId,
+        order_id: payload.order_id,
+        // TODO: convert `payload.reason`
+        reason: undefined!,
+      }),
+    ];
+    events.forEach((event) => order.apply(event));
+    await this.eventStore.appendEvents(
+"#
+        ));
+        assert!(handlers
+            .contains("import { DomainEvent, OrderCancelled } from \"../domain/events\";\n"));
+
+        let queries = NestJsGenerator::generate_query_handlers(&model());
+        assert!(!queries.contains("EventStore)"));
+    }
+
+    #[test]
+    fn test_root_module_routes_each_context() {
+        let mut shipping = model();
+        shipping.bounded_context.name = "Shipping".to_string();
+
+        let single = NestJsGenerator::generate_app_module(&IRProject {
+            contexts: vec![model()],
+        });
+        assert!(single.contains("  imports: [OrderModule],\n"));
+        assert!(!single.contains("RouterModule"));
+
+        let project = IRProject {
+            contexts: vec![model(), shipping],
+        };
+        let root = NestJsGenerator::generate_app_module(&project);
+        assert!(root.contains("import { ShippingModule } from \"./shipping/shipping.module\";\n"));
+        assert!(root.contains(
+            "    RouterModule.register([\n      { path: \"order\", module: OrderModule },\n      { path: \"shipping\", module: ShippingModule },\n    ]),\n"
+        ));
+        assert!(NestJsGenerator::generate_package_json(&project).contains(
+            "\"description\": \"NestJS service for the Order and Shipping bounded contexts\""
+        ));
+    }
+}
@@ -0,0 +1,967 @@
+use crate::generators::rust_ast::*;
+use anyhow::Result;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Event log table, indented to line up inside the generated `SCHEMA` array
+const EVENTS_TABLE: &str = "CREATE TABLE IF NOT EXISTS events (
+        position INTEGER PRIMARY KEY AUTOINCREMENT,
+        event_id TEXT NOT NULL UNIQUE,
+        aggregate_id TEXT NOT NULL,
+        aggregate_type TEXT NOT NULL,
+        sequence_number INTEGER NOT NULL,
+        event_type TEXT NOT NULL,
+        payload TEXT NOT NULL,
+        UNIQUE (aggregate_id, aggregate_type, sequence_number)
+    )";
+
+/// Outbox table
+const OUTBOX_TABLE: &str = "CREATE TABLE IF NOT EXISTS outbox (
+        position INTEGER PRIMARY KEY AUTOINCREMENT,
+        message_id TEXT NOT NULL UNIQUE,
+        context TEXT NOT NULL,
+        event_type TEXT NOT NULL,
+        payload TEXT NOT NULL,
+        attempts INTEGER NOT NULL DEFAULT 0,
+        last_error TEXT,
+        created_at TEXT NOT NULL,
+        published_at TEXT,
+        dead_lettered_at TEXT
+    )";
+
+pub struct OutboxGenerator;
+
+impl OutboxGenerator {
+    /// Generate the event store trait and its in-memory implementation
+    ///
+    /// Appending events records them in the outbox within the same write.
+    pub fn generate_event_store() -> Result<String> {
+        let tests = Self::generate_conformance_tests(quote! { InMemoryEventStore::new() })?;
+
+        render(quote! {
+            //! Event sourcing infrastructure
+            //!
+            //! Events are appended together with their outbox entries in one
+            //! atomic write, so every stored event is eventually published by
+            //! the outbox relay.
+
+            use async_trait::async_trait;
+            use anyhow::Result;
+            use chrono::Utc;
+            use tokio::sync::RwLock;
+            use uuid::Uuid;
+
+            use crate::domain::events::*;
+            use crate::infrastructure::event_bus::IntegrationMessage;
+            use crate::infrastructure::outbox::{OutboxEntry, OutboxStore};
+
+            /// Event store trait
+            #[async_trait]
+            pub trait EventStore: Send + Sync {
+                /// Append events to the store and record them in the outbox
+                async fn append_events(
+                    &self,
+                    aggregate_id: &str,
+                    aggregate_type: &str,
+                    events: Vec<DomainEvent>,
+                    expected_version: Option<i64>,
+                ) -> Result<()> {
+                    let messages = events
+                        .iter()
+                        .map(IntegrationMessage::from_domain_event)
+                        .collect::<Result<Vec<_>>>()?;
+                    self.append_messages(aggregate_id, aggregate_type, messages, expected_version)
+                        .await
+                }
+
+                /// Append serialized events and their outbox entries in one atomic write
+                ///
+                /// Nothing is written when `expected_version` does not match the
+                /// number of events already stored for the aggregate.
+                async fn append_messages(
+                    &self,
+                    aggregate_id: &str,
+                    aggregate_type: &str,
+                    messages: Vec<IntegrationMessage>,
+                    expected_version: Option<i64>,
+                ) -> Result<()>;
+
+                /// Number of events stored for an aggregate
+                async fn version(&self, aggregate_id: &str, aggregate_type: &str) -> Result<i64>;
+
+                /// Load events for an aggregate
+                async fn load_events(
+                    &self,
+                    aggregate_id: &str,
+                    aggregate_type: &str,
+                ) -> Result<Vec<DomainEvent>>;
+
+                /// Get all events in order
+                async fn get_all_events(&self) -> Result<Vec<DomainEvent>>;
+
+                /// Serialized events of all aggregates in append order, skipping the first `position`
+                async fn read_all_from(&self, position: i64) -> Result<Vec<serde_json::Value>>;
+            }
+
+            /// Reject an append when the aggregate is not at the expected version
+            pub fn check_version(expected_version: Option<i64>, current_version: i64) -> Result<()> {
+                match expected_version {
+                    Some(expected) if expected != current_version => anyhow::bail!(
+                        "Concurrency conflict: expected version {}, found {}",
+                        expected,
+                        current_version
+                    ),
+                    _ => Ok(()),
+                }
+            }
+
+            /// Event stored for an aggregate
+            #[derive(Debug, Clone)]
+            struct StoredEvent {
+                aggregate_id: String,
+                aggregate_type: String,
+                payload: serde_json::Value,
+            }
+
+            #[derive(Default)]
+            struct InMemoryState {
+                events: Vec<StoredEvent>,
+                outbox: Vec<OutboxEntry>,
+            }
+
+            impl InMemoryState {
+                fn aggregate_events<'a>(
+                    &'a self,
+                    aggregate_id: &'a str,
+                    aggregate_type: &'a str,
+                ) -> impl Iterator<Item = &'a StoredEvent> + 'a {
+                    self.events.iter().filter(move |event| {
+                        event.aggregate_id == aggregate_id && event.aggregate_type == aggregate_type
+                    })
+                }
+
+                fn outbox_entry(&mut self, message_id: Uuid) -> Option<&mut OutboxEntry> {
+                    self.outbox
+                        .iter_mut()
+                        .find(|entry| entry.message.message_id == message_id)
+                }
+            }
+
+            /// In-memory event store and outbox (for development/testing)
+            #[derive(Default)]
+            pub struct InMemoryEventStore {
+                state: RwLock<InMemoryState>,
+            }
+
+            impl InMemoryEventStore {
+                pub fn new() -> Self {
+                    Self::default()
+                }
+            }
+
+            #[async_trait]
+            impl EventStore for InMemoryEventStore {
+                async fn append_messages(
+                    &self,
+                    aggregate_id: &str,
+                    aggregate_type: &str,
+                    messages: Vec<IntegrationMessage>,
+                    expected_version: Option<i64>,
+                ) -> Result<()> {
+                    // A single write lock covers both the event log and the outbox
+                    let mut state = self.state.write().await;
+                    let current_version =
+                        state.aggregate_events(aggregate_id, aggregate_type).count() as i64;
+                    check_version(expected_version, current_version)?;
+
+                    for message in messages {
+                        state.events.push(StoredEvent {
+                            aggregate_id: aggregate_id.to_string(),
+                            aggregate_type: aggregate_type.to_string(),
+                            payload: message.payload.clone(),
+                        });
+                        state.outbox.push(OutboxEntry::new(message));
+                    }
+                    Ok(())
+                }
+
+                async fn version(&self, aggregate_id: &str, aggregate_type: &str) -> Result<i64> {
+                    let state = self.state.read().await;
+                    Ok(state.aggregate_events(aggregate_id, aggregate_type).count() as i64)
+                }
+
+                async fn load_events(
+                    &self,
+                    aggregate_id: &str,
+                    aggregate_type: &str,
+                ) -> Result<Vec<DomainEvent>> {
+                    let state = self.state.read().await;
+                    state
+                        .aggregate_events(aggregate_id, aggregate_type)
+                        .map(|event| Ok(serde_json::from_value(event.payload.clone())?))
+                        .collect()
+                }
+
+                async fn get_all_events(&self) -> Result<Vec<DomainEvent>> {
+                    let state = self.state.read().await;
+                    state
+                        .events
+                        .iter()
+                        .map(|event| Ok(serde_json::from_value(event.payload.clone())?))
+                        .collect()
+                }
+
+                async fn read_all_from(&self, position: i64) -> Result<Vec<serde_json::Value>> {
+                    let state = self.state.read().await;
+                    Ok(state
+                        .events
+                        .iter()
+                        .skip(usize::try_from(position)?)
+                        .map(|event| event.payload.clone())
+                        .collect())
+                }
+            }
+
+            #[async_trait]
+            impl OutboxStore for InMemoryEventStore {
+                async fn pending(&self, limit: usize) -> Result<Vec<OutboxEntry>> {
+                    let state = self.state.read().await;
+                    Ok(state
+                        .outbox
+                        .iter()
+                        .filter(|entry| entry.is_pending())
+                        .take(limit)
+                        .cloned()
+                        .collect())
+                }
+
+                async fn mark_published(&self, message_id: Uuid) -> Result<()> {
+                    if let Some(entry) = self.state.write().await.outbox_entry(message_id) {
+                        entry.published_at = Some(Utc::now());
+                    }
+                    Ok(())
+                }
+
+                async fn mark_failed(&self, message_id: Uuid, error: &str) -> Result<()> {
+                    if let Some(entry) = self.state.write().await.outbox_entry(message_id) {
+                        entry.attempts += 1;
+                        entry.last_error = Some(error.to_string());
+                    }
+                    Ok(())
+                }
+
+                async fn mark_dead_lettered(&self, message_id: Uuid) -> Result<()> {
+                    if let Some(entry) = self.state.write().await.outbox_entry(message_id) {
+                        entry.dead_lettered_at = Some(Utc::now());
+                    }
+                    Ok(())
+                }
+
+                async fn dead_letters(&self, limit: usize) -> Result<Vec<OutboxEntry>> {
+                    let state = self.state.read().await;
+                    Ok(state
+                        .outbox
+                        .iter()
+                        .filter(|entry| entry.published_at.is_none() && entry.dead_lettered_at.is_some())
+                        .take(limit)
+                        .cloned()
+                        .collect())
+                }
+
+                async fn replay(&self, message_id: Uuid) -> Result<()> {
+                    if let Some(entry) = self.state.write().await.outbox_entry(message_id) {
+                        entry.attempts = 0;
+                        entry.dead_lettered_at = None;
+                    }
+                    Ok(())
+                }
+            }
+
+            #tests
+        })
+    }
+
+    /// Generate the SQLite event store, compiled with the `sqlx` feature
+    pub fn generate_sqlite_event_store() -> Result<String> {
+        let tests = Self::generate_conformance_tests(quote! {
+            SqliteEventStore::in_memory().await.unwrap()
+        })?;
+        let events_table = Self::sql_literal(EVENTS_TABLE)?;
+        let outbox_table = Self::sql_literal(OUTBOX_TABLE)?;
+
+        // The in-memory pool is limited to one connection because every
+        // connection to `sqlite::memory:` opens a separate database
+        render(quote! {
+            //! SQLite event store
+            //!
+            //! Events and their outbox entries are inserted in one transaction.
+
+            use anyhow::Result;
+            use async_trait::async_trait;
+            use chrono::{DateTime, Utc};
+            use sqlx::sqlite::{SqlitePool, SqlitePoolOptions, SqliteRow};
+            use sqlx::Row;
+            use uuid::Uuid;
+
+            use crate::domain::events::DomainEvent;
+            use crate::infrastructure::event_bus::IntegrationMessage;
+            use crate::infrastructure::event_store::{check_version, EventStore};
+            use crate::infrastructure::outbox::{OutboxEntry, OutboxStore};
+
+            /// Tables for the event log and the outbox
+            const SCHEMA: &[&str] = &[#events_table, #outbox_table];
+
+            /// Event store and outbox persisted in SQLite
+            #[derive(Clone)]
+            pub struct SqliteEventStore {
+                pool: SqlitePool,
+            }
+
+            impl SqliteEventStore {
+                /// Connect to a database such as `sqlite://events.db?mode=rwc`
+                pub async fn connect(url: &str) -> Result<Self> {
+                    let pool = SqlitePoolOptions::new().connect(url).await?;
+                    Self::with_pool(pool).await
+                }
+
+                /// Open a private in-memory database (for tests)
+                pub async fn in_memory() -> Result<Self> {
+                    let pool = SqlitePoolOptions::new()
+                        .max_connections(1)
+                        .connect("sqlite::memory:")
+                        .await?;
+                    Self::with_pool(pool).await
+                }
+
+                /// Use an existing pool, creating the tables if needed
+                pub async fn with_pool(pool: SqlitePool) -> Result<Self> {
+                    for statement in SCHEMA {
+                        sqlx::query(statement).execute(&pool).await?;
+                    }
+                    // Outbox tables created before dead letters lack their column
+                    let columns: Vec<String> =
+                        sqlx::query_scalar("SELECT name FROM pragma_table_info('outbox')")
+                            .fetch_all(&pool)
+                            .await?;
+                    if !columns.iter().any(|column| column == "dead_lettered_at") {
+                        sqlx::query("ALTER TABLE outbox ADD COLUMN dead_lettered_at TEXT")
+                            .execute(&pool)
+                            .await?;
+                    }
+                    Ok(Self { pool })
+                }
+
+                /// Connection pool, for sharing the database with other stores
+                pub fn pool(&self) -> &SqlitePool {
+                    &self.pool
+                }
+            }
+
+            fn decode_events(payloads: Vec<String>) -> Result<Vec<DomainEvent>> {
+                payloads
+                    .iter()
+                    .map(|payload| Ok(serde_json::from_str(payload)?))
+                    .collect()
+            }
+
+            fn decode_outbox_entry(row: SqliteRow) -> Result<OutboxEntry> {
+                let message_id: String = row.try_get("message_id")?;
+                let payload: String = row.try_get("payload")?;
+                let attempts: i64 = row.try_get("attempts")?;
+                let created_at: String = row.try_get("created_at")?;
+                let dead_lettered_at: Option<String> = row.try_get("dead_lettered_at")?;
+
+                Ok(OutboxEntry {
+                    message: IntegrationMessage {
+                        message_id: message_id.parse()?,
+                        context: row.try_get("context")?,
+                        event_type: row.try_get("event_type")?,
+                        payload: serde_json::from_str(&payload)?,
+                    },
+                    attempts: u32::try_from(attempts)?,
+                    last_error: row.try_get("last_error")?,
+                    created_at: DateTime::parse_from_rfc3339(&created_at)?.with_timezone(&Utc),
+                    published_at: None,
+                    dead_lettered_at: dead_lettered_at
+                        .map(|at| Ok::<_, anyhow::Error>(DateTime::parse_from_rfc3339(&at)?.with_timezone(&Utc)))
+                        .transpose()?,
+                })
+            }
+
+            #[async_trait]
+            impl EventStore for SqliteEventStore {
+                async fn append_messages(
+                    &self,
+                    aggregate_id: &str,
+                    aggregate_type: &str,
+                    messages: Vec<IntegrationMessage>,
+                    expected_version: Option<i64>,
+                ) -> Result<()> {
+                    let mut tx = self.pool.begin().await?;
+
+                    let current_version: i64 = sqlx::query_scalar(
+                        "SELECT COUNT(*) FROM events WHERE aggregate_id = ? AND aggregate_type = ?",
+                    )
+                    .bind(aggregate_id)
+                    .bind(aggregate_type)
+                    .fetch_one(&mut *tx)
+                    .await?;
+                    check_version(expected_version, current_version)?;
+
+                    let created_at = Utc::now().to_rfc3339();
+                    for (sequence_number, message) in (current_version + 1..).zip(messages) {
+                        let message_id = message.message_id.to_string();
+                        let payload = serde_json::to_string(&message.payload)?;
+
+                        sqlx::query(
+                            "INSERT INTO events (event_id, aggregate_id, aggregate_type, sequence_number, event_type, payload) VALUES (?, ?, ?, ?, ?, ?)",
+                        )
+                        .bind(&message_id)
+                        .bind(aggregate_id)
+                        .bind(aggregate_type)
+                        .bind(sequence_number)
+                        .bind(&message.event_type)
+                        .bind(&payload)
+                        .execute(&mut *tx)
+                        .await?;
+
+                        sqlx::query(
+                            "INSERT INTO outbox (message_id, context, event_type, payload, created_at) VALUES (?, ?, ?, ?, ?)",
+                        )
+                        .bind(&message_id)
+                        .bind(&message.context)
+                        .bind(&message.event_type)
+                        .bind(&payload)
+                        .bind(&created_at)
+                        .execute(&mut *tx)
+                        .await?;
+                    }
+
+                    tx.commit().await?;
+                    Ok(())
+                }
+
+                async fn version(&self, aggregate_id: &str, aggregate_type: &str) -> Result<i64> {
+                    Ok(sqlx::query_scalar(
+                        "SELECT COUNT(*) FROM events WHERE aggregate_id = ? AND aggregate_type = ?",
+                    )
+                    .bind(aggregate_id)
+                    .bind(aggregate_type)
+                    .fetch_one(&self.pool)
+                    .await?)
+                }
+
+                async fn load_events(
+                    &self,
+                    aggregate_id: &str,
+                    aggregate_type: &str,
+                ) -> Result<Vec<DomainEvent>> {
+                    let payloads = sqlx::query_scalar(
+                        "SELECT payload FROM events WHERE aggregate_id = ? AND aggregate_type = ? ORDER BY sequence_number",
+                    )
+                    .bind(aggregate_id)
+                    .bind(aggregate_type)
+                    .fetch_all(&self.pool)
+                    .await?;
+                    decode_events(payloads)
+                }
+
+                async fn get_all_events(&self) -> Result<Vec<DomainEvent>> {
+                    let payloads = sqlx::query_scalar("SELECT payload FROM events ORDER BY position")
+                        .fetch_all(&self.pool)
+                        .await?;
+                    decode_events(payloads)
+                }
+
+                async fn read_all_from(&self, position: i64) -> Result<Vec<serde_json::Value>> {
+                    let payloads: Vec<String> = sqlx::query_scalar(
+                        "SELECT payload FROM events ORDER BY position LIMIT -1 OFFSET ?",
+                    )
+                    .bind(position)
+                    .fetch_all(&self.pool)
+                    .await?;
+                    payloads
+                        .iter()
+                        .map(|payload| Ok(serde_json::from_str(payload)?))
+                        .collect()
+                }
+            }
+
+            #[async_trait]
+            impl OutboxStore for SqliteEventStore {
+                async fn pending(&self, limit: usize) -> Result<Vec<OutboxEntry>> {
+                    sqlx::query(
+                        "SELECT message_id, context, event_type, payload, attempts, last_error, created_at, dead_lettered_at FROM outbox WHERE published_at IS NULL AND dead_lettered_at IS NULL ORDER BY position LIMIT ?",
+                    )
+                    .bind(i64::try_from(limit)?)
+                    .fetch_all(&self.pool)
+                    .await?
+                    .into_iter()
+                    .map(decode_outbox_entry)
+                    .collect()
+                }
+
+                async fn mark_published(&self, message_id: Uuid) -> Result<()> {
+                    sqlx::query("UPDATE outbox SET published_at = ? WHERE message_id = ?")
+                        .bind(Utc::now().to_rfc3339())
+                        .bind(message_id.to_string())
+                        .execute(&self.pool)
+                        .await?;
+                    Ok(())
+                }
+
+                async fn mark_failed(&self, message_id: Uuid, error: &str) -> Result<()> {
+                    sqlx::query(
+                        "UPDATE outbox SET attempts = attempts + 1, last_error = ? WHERE message_id = ?",
+                    )
+                    .bind(error)
+                    .bind(message_id.to_string())
+                    .execute(&self.pool)
+                    .await?;
+                    Ok(())
+                }
+
+                async fn mark_dead_lettered(&self, message_id: Uuid) -> Result<()> {
+                    sqlx::query("UPDATE outbox SET dead_lettered_at = ? WHERE message_id = ?")
+                        .bind(Utc::now().to_rfc3339())
+                        .bind(message_id.to_string())
+                        .execute(&self.pool)
+                        .await?;
+                    Ok(())
+                }
+
+                async fn dead_letters(&self, limit: usize) -> Result<Vec<OutboxEntry>> {
+                    sqlx::query(
+                        "SELECT message_id, context, event_type, payload, attempts, last_error, created_at, dead_lettered_at FROM outbox WHERE published_at IS NULL AND dead_lettered_at IS NOT NULL ORDER BY position LIMIT ?",
+                    )
+                    .bind(i64::try_from(limit)?)
+                    .fetch_all(&self.pool)
+                    .await?
+                    .into_iter()
+                    .map(decode_outbox_entry)
+                    .collect()
+                }
+
+                async fn replay(&self, message_id: Uuid) -> Result<()> {
+                    sqlx::query(
+                        "UPDATE outbox SET attempts = 0, dead_lettered_at = NULL WHERE message_id = ?",
+                    )
+                    .bind(message_id.to_string())
+                    .execute(&self.pool)
+                    .await?;
+                    Ok(())
+                }
+            }
+
+            #tests
+        })
+    }
+
+    /// String literal keeping the SQL line breaks instead of `\n` escapes
+    fn sql_literal(sql: &str) -> Result<TokenStream> {
+        format!("\"{}\"", sql)
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid SQL literal: {}", e))
+    }
+
+    /// Generate the outbox entry, its store trait and the relay task
+    pub fn generate_outbox() -> Result<String> {
+        let conformance = Self::generate_conformance_suite();
+
+        render(quote! {
+            //! Transactional outbox
+            //!
+            //! Event stores record every appended event as an outbox entry in the
+            //! same write. `OutboxRelay` publishes pending entries to the event bus
+            //! and only then marks them as published, so delivery is
+            //! at-least-once: an entry published right before a crash is published
+            //! again with the same message ID, which consumers use to deduplicate.
+            //! An entry that keeps failing is moved to the dead letters, where it
+            //! stays, unpublished, until it is replayed.
+
+            use anyhow::Result;
+            use async_trait::async_trait;
+            use chrono::{DateTime, Utc};
+            use std::sync::Arc;
+            use std::time::Duration;
+            use uuid::Uuid;
+
+            use crate::infrastructure::event_bus::{EventBus, IntegrationMessage};
+
+            /// Stored event waiting to be published
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct OutboxEntry {
+                /// Message to publish; its ID is the deduplication ID
+                pub message: IntegrationMessage,
+                /// Number of failed publish attempts
+                pub attempts: u32,
+                /// Error of the last failed attempt
+                pub last_error: Option<String>,
+                pub created_at: DateTime<Utc>,
+                pub published_at: Option<DateTime<Utc>>,
+                /// When the relay stopped retrying the entry
+                pub dead_lettered_at: Option<DateTime<Utc>>,
+            }
+
+            impl OutboxEntry {
+                pub fn new(message: IntegrationMessage) -> Self {
+                    Self {
+                        message,
+                        attempts: 0,
+                        last_error: None,
+                        created_at: Utc::now(),
+                        published_at: None,
+                        dead_lettered_at: None,
+                    }
+                }
+
+                /// Whether the relay should still try to publish this entry
+                pub fn is_pending(&self) -> bool {
+                    self.published_at.is_none() && self.dead_lettered_at.is_none()
+                }
+            }
+
+            /// Storage for outbox entries
+            #[async_trait]
+            pub trait OutboxStore: Send + Sync {
+                /// Entries neither published nor dead-lettered, oldest first
+                async fn pending(&self, limit: usize) -> Result<Vec<OutboxEntry>>;
+
+                /// Mark an entry as published
+                async fn mark_published(&self, message_id: Uuid) -> Result<()>;
+
+                /// Record a failed publish attempt
+                async fn mark_failed(&self, message_id: Uuid, error: &str) -> Result<()>;
+
+                /// Stop retrying an entry until it is replayed
+                async fn mark_dead_lettered(&self, message_id: Uuid) -> Result<()>;
+
+                /// Dead-lettered entries, oldest first
+                async fn dead_letters(&self, limit: usize) -> Result<Vec<OutboxEntry>>;
+
+                /// Return a dead-lettered entry to the pending entries, with no failed attempts
+                async fn replay(&self, message_id: Uuid) -> Result<()>;
+            }
+
+            /// Background task publishing outbox entries to the event bus
+            pub struct OutboxRelay {
+                store: Arc<dyn OutboxStore>,
+                bus: Arc<dyn EventBus>,
+                max_attempts: u32,
+                batch_size: usize,
+            }
+
+            impl OutboxRelay {
+                pub fn new(store: Arc<dyn OutboxStore>, bus: Arc<dyn EventBus>) -> Self {
+                    Self {
+                        store,
+                        bus,
+                        max_attempts: 10,
+                        batch_size: 100,
+                    }
+                }
+
+                /// Dead-letter an entry after this many failed attempts
+                pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+                    self.max_attempts = max_attempts;
+                    self
+                }
+
+                /// Publish pending entries, returning how many were published
+                ///
+                /// Stops at the first failure so that entries are published in
+                /// order; the failed entry is retried on the next call until it
+                /// reaches the maximum number of attempts, when it is moved to the
+                /// dead letters and the entries after it go ahead.
+                pub async fn relay_pending(&self) -> Result<usize> {
+                    let mut published = 0;
+
+                    for entry in self.store.pending(self.batch_size).await? {
+                        let message_id = entry.message.message_id;
+
+                        if let Err(e) = self.bus.publish(entry.message).await {
+                            self.store.mark_failed(message_id, &e.to_string()).await?;
+                            if entry.attempts + 1 < self.max_attempts {
+                                tracing::warn!("Failed to publish outbox entry {}: {}", message_id, e);
+                                break;
+                            }
+                            self.store.mark_dead_lettered(message_id).await?;
+                            tracing::error!(
+                                "Dead-lettered outbox entry {} after {} attempts: {}",
+                                message_id,
+                                self.max_attempts,
+                                e
+                            );
+                            continue;
+                        }
+
+                        self.store.mark_published(message_id).await?;
+                        published += 1;
+                    }
+
+                    Ok(published)
+                }
+
+                /// Relay pending entries every `interval`
+                pub async fn run(self, interval: Duration) {
+                    loop {
+                        if let Err(e) = self.relay_pending().await {
+                            tracing::error!("Failed to relay outbox entries: {}", e);
+                        }
+                        tokio::time::sleep(interval).await;
+                    }
+                }
+            }
+
+            #conformance
+        })
+    }
+
+    /// Behaviour every event store with an outbox must have, shared by the
+    /// in-memory and SQLite store tests
+    fn generate_conformance_suite() -> TokenStream {
+        quote! {
+            #[cfg(test)]
+            pub mod conformance {
+                use super::*;
+                use crate::infrastructure::event_bus::InMemoryEventBus;
+                use crate::infrastructure::event_store::EventStore;
+                use std::sync::atomic::{AtomicU32, Ordering};
+
+                pub fn message(event_type: &str) -> IntegrationMessage {
+                    IntegrationMessage {
+                        message_id: Uuid::new_v4(),
+                        context: "Test".to_string(),
+                        event_type: event_type.to_string(),
+                        payload: serde_json::json!({ "type": event_type }),
+                    }
+                }
+
+                /// Event bus failing the first `failures` publishes
+                struct FlakyBus {
+                    inner: InMemoryEventBus,
+                    failures: AtomicU32,
+                }
+
+                impl FlakyBus {
+                    fn new(failures: u32) -> Self {
+                        Self {
+                            inner: InMemoryEventBus::new(),
+                            failures: AtomicU32::new(failures),
+                        }
+                    }
+                }
+
+                #[async_trait]
+                impl EventBus for FlakyBus {
+                    async fn publish(&self, message: IntegrationMessage) -> Result<()> {
+                        let failing = self
+                            .failures
+                            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                            .is_ok();
+                        if failing {
+                            anyhow::bail!("Event bus unavailable");
+                        }
+                        self.inner.publish(message).await
+                    }
+
+                    async fn read_from(&self, offset: usize) -> Result<Vec<IntegrationMessage>> {
+                        self.inner.read_from(offset).await
+                    }
+                }
+
+                fn message_ids(messages: &[IntegrationMessage]) -> Vec<Uuid> {
+                    messages.iter().map(|message| message.message_id).collect()
+                }
+
+                pub async fn append_records_events_in_outbox<S: EventStore + OutboxStore>(store: S) {
+                    let messages = vec![message("First"), message("Second")];
+                    store
+                        .append_messages("agg-1", "Test", messages.clone(), Some(0))
+                        .await
+                        .unwrap();
+
+                    assert_eq!(store.version("agg-1", "Test").await.unwrap(), 2);
+                    let pending = store.pending(100).await.unwrap();
+                    let pending: Vec<_> = pending.into_iter().map(|entry| entry.message).collect();
+                    assert_eq!(pending, messages);
+                }
+
+                pub async fn conflicting_append_writes_nothing<S: EventStore + OutboxStore>(store: S) {
+                    store
+                        .append_messages("agg-1", "Test", vec![message("First")], None)
+                        .await
+                        .unwrap();
+
+                    let result = store
+                        .append_messages("agg-1", "Test", vec![message("Second")], Some(0))
+                        .await;
+
+                    assert!(result.is_err());
+                    assert_eq!(store.version("agg-1", "Test").await.unwrap(), 1);
+                    assert_eq!(store.pending(100).await.unwrap().len(), 1);
+                }
+
+                pub async fn reads_events_from_position<S: EventStore + OutboxStore>(store: S) {
+                    store
+                        .append_messages("agg-1", "Test", vec![message("First")], None)
+                        .await
+                        .unwrap();
+                    store
+                        .append_messages("agg-2", "Test", vec![message("Second")], None)
+                        .await
+                        .unwrap();
+
+                    let events = store.read_all_from(1).await.unwrap();
+                    assert_eq!(events, vec![serde_json::json!({ "type": "Second" })]);
+                    assert!(store.read_all_from(2).await.unwrap().is_empty());
+                }
+
+                pub async fn relay_publishes_each_entry_once<S: EventStore + OutboxStore + 'static>(
+                    store: S,
+                ) {
+                    let store = Arc::new(store);
+                    let bus = InMemoryEventBus::new();
+                    let messages = vec![message("First"), message("Second")];
+                    store
+                        .append_messages("agg-1", "Test", messages.clone(), None)
+                        .await
+                        .unwrap();
+
+                    let relay = OutboxRelay::new(store.clone(), Arc::new(bus.clone()));
+                    assert_eq!(relay.relay_pending().await.unwrap(), 2);
+                    assert_eq!(relay.relay_pending().await.unwrap(), 0);
+
+                    let published = bus.read_from(0).await.unwrap();
+                    assert_eq!(message_ids(&published), message_ids(&messages));
+                }
+
+                pub async fn relay_retries_failed_publishes<S: EventStore + OutboxStore + 'static>(
+                    store: S,
+                ) {
+                    let store = Arc::new(store);
+                    let bus = Arc::new(FlakyBus::new(2));
+                    store
+                        .append_messages("agg-1", "Test", vec![message("First"), message("Second")], None)
+                        .await
+                        .unwrap();
+
+                    let relay = OutboxRelay::new(store.clone(), bus.clone()).with_max_attempts(5);
+                    assert_eq!(relay.relay_pending().await.unwrap(), 0);
+                    assert_eq!(relay.relay_pending().await.unwrap(), 0);
+
+                    let pending = store.pending(100).await.unwrap();
+                    assert_eq!(pending.len(), 2);
+                    assert_eq!(pending[0].attempts, 2);
+                    assert_eq!(pending[0].last_error.as_deref(), Some("Event bus unavailable"));
+                    assert_eq!(pending[1].attempts, 0);
+
+                    assert_eq!(relay.relay_pending().await.unwrap(), 2);
+                    assert!(store.pending(100).await.unwrap().is_empty());
+                }
+
+                pub async fn relay_dead_letters_after_max_attempts<S: EventStore + OutboxStore + 'static>(
+                    store: S,
+                ) {
+                    let store = Arc::new(store);
+                    let bus = Arc::new(FlakyBus::new(2));
+                    let messages = vec![message("First"), message("Second")];
+                    store
+                        .append_messages("agg-1", "Test", messages.clone(), None)
+                        .await
+                        .unwrap();
+
+                    let relay = OutboxRelay::new(store.clone(), bus.clone()).with_max_attempts(2);
+                    assert_eq!(relay.relay_pending().await.unwrap(), 0);
+                    assert_eq!(relay.relay_pending().await.unwrap(), 1);
+
+                    let dead_letters = store.dead_letters(100).await.unwrap();
+                    assert_eq!(dead_letters.len(), 1);
+                    assert_eq!(dead_letters[0].message, messages[0]);
+                    assert_eq!(dead_letters[0].attempts, 2);
+                    assert_eq!(dead_letters[0].last_error.as_deref(), Some("Event bus unavailable"));
+                    assert!(dead_letters[0].dead_lettered_at.is_some());
+                    assert!(store.pending(100).await.unwrap().is_empty());
+                    assert_eq!(message_ids(&bus.read_from(0).await.unwrap()), message_ids(&messages[1..]));
+                }
+
+                pub async fn replayed_dead_letter_is_published<S: EventStore + OutboxStore + 'static>(
+                    store: S,
+                ) {
+                    let store = Arc::new(store);
+                    let bus = Arc::new(FlakyBus::new(1));
+                    let messages = vec![message("First")];
+                    store
+                        .append_messages("agg-1", "Test", messages.clone(), None)
+                        .await
+                        .unwrap();
+
+                    let relay = OutboxRelay::new(store.clone(), bus.clone()).with_max_attempts(1);
+                    assert_eq!(relay.relay_pending().await.unwrap(), 0);
+                    assert_eq!(relay.relay_pending().await.unwrap(), 0);
+
+                    store.replay(messages[0].message_id).await.unwrap();
+                    assert!(store.dead_letters(100).await.unwrap().is_empty());
+                    assert_eq!(store.pending(100).await.unwrap()[0].attempts, 0);
+
+                    assert_eq!(relay.relay_pending().await.unwrap(), 1);
+                    assert_eq!(message_ids(&bus.read_from(0).await.unwrap()), message_ids(&messages));
+                    assert!(store.pending(100).await.unwrap().is_empty());
+                }
+
+                pub async fn redelivery_keeps_message_id<S: EventStore + OutboxStore + 'static>(
+                    store: S,
+                ) {
+                    let store = Arc::new(store);
+                    let bus = InMemoryEventBus::new();
+                    store
+                        .append_messages("agg-1", "Test", vec![message("First")], None)
+                        .await
+                        .unwrap();
+
+                    // Publish without marking, as if the relay crashed in between
+                    let entry = store.pending(100).await.unwrap().remove(0);
+                    bus.publish(entry.message.clone()).await.unwrap();
+
+                    let relay = OutboxRelay::new(store.clone(), Arc::new(bus.clone()));
+                    assert_eq!(relay.relay_pending().await.unwrap(), 1);
+
+                    let published = bus.read_from(0).await.unwrap();
+                    assert_eq!(message_ids(&published), vec![entry.message.message_id; 2]);
+                }
+            }
+        }
+    }
+
+    /// Run the conformance suite against the store built by `store`
+    fn generate_conformance_tests(store: TokenStream) -> Result<TokenStream> {
+        let cases = [
+            "append_records_events_in_outbox",
+            "conflicting_append_writes_nothing",
+            "reads_events_from_position",
+            "relay_publishes_each_entry_once",
+            "relay_retries_failed_publishes",
+            "relay_dead_letters_after_max_attempts",
+            "replayed_dead_letter_is_published",
+            "redelivery_keeps_message_id",
+        ]
+        .iter()
+        .map(|case| ident(case))
+        .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+                use crate::infrastructure::outbox::conformance;
+
+                #(
+                    #[tokio::test]
+                    async fn #cases() {
+                        conformance::#cases(#store).await;
+                    }
+                )*
+            }
+        })
+    }
+}
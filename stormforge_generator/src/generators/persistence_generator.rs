@@ -0,0 +1,1540 @@
+use crate::generators::rust_ast::*;
+use crate::generators::utils::*;
+use crate::ir::{Aggregate, IRModel, Persistence, Property, ValueObject};
+use anyhow::Result;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use std::collections::BTreeSet;
+
+/// Digits of the version that prefixes migration file names
+const VERSION_WIDTH: usize = 4;
+
+/// First line of the migrations written by the generator
+///
+/// Only these are replayed to find the current schema, so hand-written
+/// migrations can sit next to them.
+const MIGRATION_HEADER: &str = "-- Generated by StormForge Generator";
+
+/// SQLite keywords, quoted when used as table or column names
+const SQL_KEYWORDS: &str = "abort action add after all alter always analyze and as asc attach \
+    autoincrement before begin between by cascade case cast check collate column commit conflict \
+    constraint create cross current current_date current_time current_timestamp database default \
+    deferrable deferred delete desc detach distinct do drop each else end escape except exclude \
+    exclusive exists explain fail filter first following for foreign from full generated glob \
+    group groups having if ignore immediate in index indexed initially inner insert instead \
+    intersect into is isnull join key last left like limit match materialized natural no not \
+    nothing notnull null nulls of offset on or order others outer over partition plan pragma \
+    preceding primary query raise range recursive references regexp reindex release rename \
+    replace restrict returning right rollback row rows savepoint select set table temp temporary \
+    then ties to transaction trigger unbounded union unique update using vacuum values view \
+    virtual when where window with without";
+
+pub struct PersistenceGenerator;
+
+/// How a column stores a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Text,
+    Integer,
+    Boolean,
+    /// Text, as decimals lose precision in SQLite's numbers
+    Decimal,
+    DateTime,
+    Date,
+    Time,
+    Uuid,
+    /// Name of the variant, as serde writes it
+    Enum,
+    /// JSON text, for values that have no columns of their own
+    Json,
+}
+
+impl Kind {
+    fn primitive(ir_type: &str) -> Option<Self> {
+        match ir_type {
+            "String" => Some(Self::Text),
+            "Integer" => Some(Self::Integer),
+            "Boolean" => Some(Self::Boolean),
+            "Decimal" => Some(Self::Decimal),
+            "DateTime" => Some(Self::DateTime),
+            "Date" => Some(Self::Date),
+            "Time" => Some(Self::Time),
+            "Uuid" => Some(Self::Uuid),
+            _ => None,
+        }
+    }
+
+    fn sql_type(self) -> &'static str {
+        match self {
+            Self::Integer => "INTEGER",
+            Self::Boolean => "BOOLEAN",
+            Self::DateTime => "TIMESTAMP",
+            Self::Date => "DATE",
+            Self::Time => "TIME",
+            Self::Text | Self::Decimal | Self::Uuid | Self::Enum | Self::Json => "TEXT",
+        }
+    }
+
+    /// Rust type read from and written to the column
+    fn storage(self) -> TokenStream {
+        match self {
+            Self::Integer => quote! { i64 },
+            Self::Boolean => quote! { bool },
+            Self::DateTime => quote! { chrono::DateTime<chrono::Utc> },
+            Self::Date => quote! { chrono::NaiveDate },
+            Self::Time => quote! { chrono::NaiveTime },
+            Self::Text | Self::Decimal | Self::Uuid | Self::Enum | Self::Json => {
+                quote! { String }
+            }
+        }
+    }
+
+    /// SQL literal of an IR default value
+    fn literal(self, value: &serde_json::Value) -> Option<String> {
+        match (self, value) {
+            (Self::Text | Self::Decimal, serde_json::Value::String(text)) => Some(sql_string(text)),
+            (Self::Decimal, serde_json::Value::Number(number)) => {
+                Some(sql_string(&number.to_string()))
+            }
+            (Self::Integer, serde_json::Value::Number(number)) if number.is_i64() => {
+                Some(number.to_string())
+            }
+            (Self::Boolean, serde_json::Value::Bool(value)) => {
+                Some(if *value { "TRUE" } else { "FALSE" }.to_string())
+            }
+            (Self::Enum, serde_json::Value::String(name)) => {
+                Some(sql_string(&to_pascal_case(name)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Column holding a single value
+#[derive(Debug, Clone)]
+struct Column {
+    name: String,
+    kind: Kind,
+    /// Identifier type wrapping the value
+    newtype: Option<Ident>,
+    nullable: bool,
+    default: Option<String>,
+    unique: bool,
+}
+
+/// How a field is spread over columns
+#[derive(Debug)]
+enum Value {
+    Column(Column),
+    /// Value object with a column per field
+    Struct(Ident, Vec<(Ident, Value)>),
+    /// List stored in the child table at this index
+    List(usize),
+}
+
+/// Child table holding the items of a list
+struct ListTable {
+    name: String,
+    field: Ident,
+    item_type: syn::Type,
+    /// Column referencing the aggregate's row
+    parent: String,
+    /// Column keeping the items in order
+    position: String,
+    item: Value,
+}
+
+/// Tables storing a state-stored aggregate
+struct Layout {
+    name: Ident,
+    table: String,
+    id: Column,
+    root: Value,
+    lists: Vec<ListTable>,
+}
+
+/// Table as declared in a migration
+#[derive(Debug, Clone, PartialEq)]
+struct TableDef {
+    name: String,
+    /// Column names and definitions
+    columns: Vec<(String, String)>,
+    constraints: Vec<String>,
+}
+
+impl TableDef {
+    fn column(&self, name: &str) -> Option<&str> {
+        self.columns
+            .iter()
+            .find(|(column, _)| column == name)
+            .map(|(_, definition)| definition.as_str())
+    }
+
+    fn create(&self, name: &str) -> String {
+        let lines = self
+            .columns
+            .iter()
+            .map(|(column, definition)| format!("    {} {}", quote_name(column), definition))
+            .chain(
+                self.constraints
+                    .iter()
+                    .map(|constraint| format!("    {}", constraint)),
+            )
+            .collect::<Vec<_>>();
+        format!(
+            "CREATE TABLE {} (\n{}\n);\n",
+            quote_name(name),
+            lines.join(",\n")
+        )
+    }
+}
+
+impl PersistenceGenerator {
+    /// State-stored aggregates of the model
+    pub fn state_aggregates(model: &IRModel) -> impl Iterator<Item = &Aggregate> {
+        model
+            .aggregates
+            .values()
+            .filter(|aggregate| aggregate.persistence == Persistence::State)
+    }
+
+    /// Generate the next migration, if the tables of the state-stored
+    /// aggregates differ from those the existing migrations create
+    ///
+    /// `existing` holds the file names and contents of the migrations
+    /// directory. Only migrations written by the generator are replayed;
+    /// the result is the file name and SQL of the new migration.
+    pub fn generate_migration(
+        model: &IRModel,
+        existing: &[(String, String)],
+    ) -> Result<Option<(String, String)>> {
+        let desired = Self::state_aggregates(model)
+            .map(|aggregate| Self::layout(model, aggregate))
+            .collect::<Result<Vec<_>>>()?
+            .iter()
+            .flat_map(Self::tables)
+            .collect::<Vec<_>>();
+        let current = existing
+            .iter()
+            .filter(|(_, sql)| sql.starts_with(MIGRATION_HEADER))
+            .fold(Vec::new(), |tables, (_, sql)| replay(tables, sql));
+
+        let (statements, changes) = migration_statements(&current, &desired);
+        if statements.is_empty() {
+            return Ok(None);
+        }
+
+        let description = match changes.as_slice() {
+            [change] => change.clone(),
+            _ if current.is_empty() => "create_schema".to_string(),
+            _ => "update_schema".to_string(),
+        };
+        let version = existing
+            .iter()
+            .filter_map(|(file, _)| {
+                let digits = file.chars().take_while(char::is_ascii_digit).count();
+                file[..digits].parse::<u64>().ok()
+            })
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let file = format!(
+            "{:0width$}_{}.sql",
+            version,
+            description,
+            width = VERSION_WIDTH
+        );
+        let sql = format!(
+            "{} for the {} context\n\
+             --\n\
+             -- Applied in order by `repository::migrate`. Regenerating after an IR\n\
+             -- change adds a migration for the difference, so this file is never\n\
+             -- rewritten.\n\n{}",
+            MIGRATION_HEADER,
+            model.bounded_context.name,
+            statements.join("\n")
+        );
+
+        Ok(Some((file, sql)))
+    }
+
+    /// Generate `src/repository/sqlite_<aggregate>_repository.rs`
+    ///
+    /// The repository loads and saves the aggregate's current state in the
+    /// tables created by the migrations, writing list items to their child
+    /// tables in the same transaction.
+    pub fn generate_repository(model: &IRModel, aggregate: &Aggregate) -> Result<String> {
+        let layout = Self::layout(model, aggregate)?;
+        let name = &layout.name;
+        let value = snake_ident(&aggregate.name)?;
+        let repository = ident(&format!("Sqlite{}Repository", aggregate.name))?;
+        let table = quote_name(&layout.table);
+        let id = quote_name(&layout.id.name);
+
+        let mut columns = Vec::new();
+        flatten(&layout.root, quote! { aggregate }, &mut columns);
+        let names = columns
+            .iter()
+            .map(|(column, _)| quote_name(&column.name))
+            .collect::<Vec<_>>();
+        let select = format!(
+            "SELECT {} FROM {} WHERE {} = ?",
+            names.join(", "),
+            table,
+            id
+        );
+        let updates = names
+            .iter()
+            .filter(|name| **name != id)
+            .map(|name| format!("{} = excluded.{}", name, name))
+            .collect::<Vec<_>>();
+        let upsert = format!(
+            "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT ({}) DO {}",
+            table,
+            names.join(", "),
+            vec!["?"; names.len()].join(", "),
+            id,
+            if updates.is_empty() {
+                "NOTHING".to_string()
+            } else {
+                format!("UPDATE SET {}", updates.join(", "))
+            }
+        );
+        let binds = columns
+            .iter()
+            .map(|(column, place)| encode(column, place, false))
+            .collect::<Vec<_>>();
+        let id_bind = columns
+            .iter()
+            .find(|(column, _)| column.name == layout.id.name)
+            .map(|(column, place)| encode(column, place, false))
+            .unwrap_or_default();
+
+        let mut loads = Vec::new();
+        let mut saves = Vec::new();
+        let mut deletes = Vec::new();
+        for list in &layout.lists {
+            let child = quote_name(&list.name);
+            let parent = quote_name(&list.parent);
+            let position = quote_name(&list.position);
+            let field = &list.field;
+            let item_type = &list.item_type;
+
+            let mut item_columns = Vec::new();
+            flatten(&list.item, quote! { item }, &mut item_columns);
+            let item_names = item_columns
+                .iter()
+                .map(|(column, _)| quote_name(&column.name))
+                .collect::<Vec<_>>();
+            let select = format!(
+                "SELECT {} FROM {} WHERE {} = ? ORDER BY {}",
+                item_names.join(", "),
+                child,
+                parent,
+                position
+            );
+            let item = decode(&list.item, &[]);
+            loads.push(quote! {
+                let #field = sqlx::query(#select)
+                    .bind(id)
+                    .fetch_all(&self.pool)
+                    .await?
+                    .iter()
+                    .map(|row| -> Result<#item_type> { Ok(#item) })
+                    .collect::<Result<Vec<_>>>()?;
+            });
+
+            let delete = format!("DELETE FROM {} WHERE {} = ?", child, parent);
+            let insert = format!(
+                "INSERT INTO {} ({}, {}, {}) VALUES (?, ?, {})",
+                child,
+                parent,
+                position,
+                item_names.join(", "),
+                vec!["?"; item_names.len()].join(", ")
+            );
+            let scalar = matches!(list.item, Value::Column(_));
+            let item_binds = item_columns
+                .iter()
+                .map(|(column, place)| encode(column, place, scalar))
+                .collect::<Vec<_>>();
+            saves.push(quote! {
+                sqlx::query(#delete)
+                    .bind(#id_bind)
+                    .execute(&mut *tx)
+                    .await?;
+                for (position, item) in aggregate.#field.iter().enumerate() {
+                    sqlx::query(#insert)
+                        .bind(#id_bind)
+                        .bind(i64::try_from(position)?)
+                        #(.bind(#item_binds))*
+                        .execute(&mut *tx)
+                        .await?;
+                }
+            });
+            deletes.push(quote! {
+                sqlx::query(#delete)
+                    .bind(id)
+                    .execute(&mut *tx)
+                    .await?;
+            });
+        }
+
+        let lists = layout
+            .lists
+            .iter()
+            .map(|list| list.field.clone())
+            .collect::<Vec<_>>();
+        let aggregate_value = decode(&layout.root, &lists);
+        let delete = format!("DELETE FROM {} WHERE {} = ?", table, id);
+        let (save, delete) = if layout.lists.is_empty() {
+            (
+                quote! {
+                    sqlx::query(#upsert)
+                        #(.bind(#binds))*
+                        .execute(&self.pool)
+                        .await?;
+                },
+                quote! {
+                    sqlx::query(#delete)
+                        .bind(id)
+                        .execute(&self.pool)
+                        .await?;
+                },
+            )
+        } else {
+            (
+                quote! {
+                    let mut tx = self.pool.begin().await?;
+                    sqlx::query(#upsert)
+                        #(.bind(#binds))*
+                        .execute(&mut *tx)
+                        .await?;
+                    #(#saves)*
+                    tx.commit().await?;
+                },
+                quote! {
+                    let mut tx = self.pool.begin().await?;
+                    #(#deletes)*
+                    sqlx::query(#delete)
+                        .bind(id)
+                        .execute(&mut *tx)
+                        .await?;
+                    tx.commit().await?;
+                },
+            )
+        };
+
+        let mut all_columns = columns
+            .iter()
+            .map(|(column, _)| *column)
+            .collect::<Vec<_>>();
+        for list in &layout.lists {
+            let mut item_columns = Vec::new();
+            flatten(&list.item, quote! { item }, &mut item_columns);
+            all_columns.extend(item_columns.into_iter().map(|(column, _)| column));
+        }
+        let (enum_imports, enum_helpers) =
+            if all_columns.iter().any(|column| column.kind == Kind::Enum) {
+                (
+                    quote! {
+                        use serde::de::DeserializeOwned;
+                        use serde::Serialize;
+                    },
+                    quote! {
+                        /// Name of an enum variant, as stored in its column
+                        fn encode_enum<T: Serialize>(value: &T) -> Result<String> {
+                            match serde_json::to_value(value)? {
+                                serde_json::Value::String(name) => Ok(name),
+                                other => anyhow::bail!("Expected an enum variant, found {}", other),
+                            }
+                        }
+
+                        /// Enum variant stored in a column
+                        fn decode_enum<T: DeserializeOwned>(name: String) -> Result<T> {
+                            Ok(serde_json::from_value(serde_json::Value::String(name))?)
+                        }
+                    },
+                )
+            } else {
+                (TokenStream::new(), TokenStream::new())
+            };
+
+        let tables = std::iter::once(&layout.table)
+            .chain(layout.lists.iter().map(|list| &list.name))
+            .map(|table| format!("`{}`", table))
+            .collect::<Vec<_>>();
+        let struct_doc = doc_lines(&format!(
+            "`{}` aggregates stored as their current state\n\nTables: {}",
+            aggregate.name,
+            tables.join(", ")
+        ));
+        let module_doc = format!(
+            " SQLite repository of the state-stored `{}` aggregate",
+            aggregate.name
+        );
+
+        render(quote! {
+            #![doc = #module_doc]
+            //!
+            //! The tables are created by the migrations in `migrations/`.
+
+            use anyhow::Result;
+            use async_trait::async_trait;
+            #enum_imports
+            use sqlx::{Row, SqlitePool};
+
+            use super::Repository;
+            use crate::domain::entities::*;
+
+            #struct_doc
+            #[derive(Clone)]
+            pub struct #repository {
+                pool: SqlitePool,
+            }
+
+            impl #repository {
+                pub fn new(pool: SqlitePool) -> Self {
+                    Self { pool }
+                }
+            }
+
+            #[async_trait]
+            impl Repository<#name> for #repository {
+                async fn find_by_id(&self, id: &str) -> Result<Option<#name>> {
+                    let row = sqlx::query(#select)
+                        .bind(id)
+                        .fetch_optional(&self.pool)
+                        .await?;
+                    let Some(row) = row else {
+                        return Ok(None);
+                    };
+                    #(#loads)*
+
+                    let #value = #aggregate_value;
+                    Ok(Some(#value))
+                }
+
+                async fn save(&self, aggregate: &#name) -> Result<()> {
+                    #save
+                    Ok(())
+                }
+
+                async fn delete(&self, id: &str) -> Result<()> {
+                    #delete
+                    Ok(())
+                }
+            }
+
+            #enum_helpers
+        })
+    }
+
+    /// Expression of the ID of the aggregate `value`, as the string its
+    /// repository finds it by
+    pub fn aggregate_id(
+        model: &IRModel,
+        aggregate: &Aggregate,
+        value: TokenStream,
+    ) -> Result<TokenStream> {
+        Ok(Self::id_string(&Self::layout(model, aggregate)?, value))
+    }
+
+    fn id_string(layout: &Layout, value: TokenStream) -> TokenStream {
+        let mut columns = Vec::new();
+        flatten(&layout.root, value, &mut columns);
+        columns
+            .iter()
+            .find(|(column, _)| column.name == layout.id.name)
+            .map(
+                |(column, place)| match (column.kind, column.newtype.is_some()) {
+                    (Kind::Text, false) => quote! { #place.clone() },
+                    (Kind::Text, true) => quote! { #place.0.clone() },
+                    (_, false) => quote! { #place.to_string() },
+                    (_, true) => quote! { #place.0.to_string() },
+                },
+            )
+            .unwrap_or_default()
+    }
+
+    /// Generate `tests/<aggregate>_repository_test.rs`
+    ///
+    /// Aggregates from the proptest strategy are saved twice, loaded back
+    /// unchanged and deleted, against an in-memory database migrated with
+    /// the generated migrations.
+    pub fn generate_repository_tests(model: &IRModel, aggregate: &Aggregate) -> Result<String> {
+        let layout = Self::layout(model, aggregate)?;
+        let krate = crate_ident(model)?;
+        let name = &layout.name;
+        let snake = to_snake_case(&aggregate.name);
+        let value = ident(&snake)?;
+        let repository = ident(&format!("Sqlite{}Repository", aggregate.name))?;
+        let strategy = snake_ident(&aggregate.name)?;
+        let test = ident(&format!("{}_round_trips", snake))?;
+
+        let id = Self::id_string(&layout, quote! { #value });
+
+        // Items with identifiers are unique within their list
+        let mut filters = Vec::new();
+        for list in &layout.lists {
+            let field = &list.field;
+            if let Value::Struct(_, fields) = &list.item {
+                for (item_field, item_value) in fields {
+                    if matches!(item_value, Value::Column(column) if column.unique) {
+                        filters.push(quote! {
+                            unique(&#value.#field, |item| &item.#item_field)
+                        });
+                    }
+                }
+            }
+        }
+        let (strategy, unique_helper, unique_imports) = if filters.is_empty() {
+            (
+                quote! { strategies::#strategy() },
+                TokenStream::new(),
+                TokenStream::new(),
+            )
+        } else {
+            (
+                quote! {
+                    strategies::#strategy().prop_filter("list items have unique identifiers", |#value| {
+                        #(#filters)&&*
+                    })
+                },
+                quote! {
+                    /// Whether no two items have the same key
+                    fn unique<'a, T, K: Eq + Hash>(items: &'a [T], key: impl Fn(&'a T) -> K) -> bool {
+                        let mut keys = HashSet::new();
+                        items.iter().all(|item| keys.insert(key(item)))
+                    }
+                },
+                quote! {
+                    use std::collections::HashSet;
+                    use std::hash::Hash;
+                },
+            )
+        };
+        let doc = format!(
+            " Round trips of `{}` through `{}`",
+            aggregate.name, repository
+        );
+
+        render(quote! {
+            #![doc = #doc]
+            #![cfg(feature = "sqlx")]
+
+            mod support;
+
+            use proptest::prelude::*;
+            use sqlx::sqlite::SqlitePoolOptions;
+            #unique_imports
+            use support::strategies::{self, check};
+            use ::#krate::domain::entities::*;
+            use ::#krate::repository::{self, Repository, #repository};
+
+            /// Save an aggregate twice, load it back and delete it
+            async fn round_trip(repository: &#repository, #value: #name) -> Result<(), TestCaseError> {
+                let fail = |error: anyhow::Error| TestCaseError::fail(error.to_string());
+                let id = #id;
+                let expected = serde_json::to_value(&#value).unwrap();
+
+                repository.save(&#value).await.map_err(fail)?;
+                repository.save(&#value).await.map_err(fail)?;
+                let loaded = repository.find_by_id(&id).await.map_err(fail)?;
+                let loaded = loaded.map(|loaded| serde_json::to_value(loaded).unwrap());
+                prop_assert_eq!(loaded, Some(expected));
+
+                repository.delete(&id).await.map_err(fail)?;
+                let deleted = repository.find_by_id(&id).await.map_err(fail)?;
+                prop_assert!(deleted.is_none());
+                Ok(())
+            }
+
+            #unique_helper
+
+            #[test]
+            fn #test() {
+                let runtime = tokio::runtime::Runtime::new().unwrap();
+                let repository = runtime.block_on(async {
+                    let pool = SqlitePoolOptions::new()
+                        .max_connections(1)
+                        .connect("sqlite::memory:")
+                        .await
+                        .unwrap();
+                    repository::migrate(&pool).await.unwrap();
+                    #repository::new(pool)
+                });
+
+                check(#strategy, |#value| runtime.block_on(round_trip(&repository, #value)));
+            }
+        })
+    }
+
+    /// Work out the tables of a state-stored aggregate
+    ///
+    /// Required value objects are spread over a column per field, and
+    /// required lists of the root entity get a child table. Anything else
+    /// without a column type of its own is stored as JSON.
+    fn layout(model: &IRModel, aggregate: &Aggregate) -> Result<Layout> {
+        let snake = to_snake_case(&aggregate.name);
+        let mut visiting = vec![aggregate.name.clone()];
+        let mut fields = Vec::new();
+        let mut lists = Vec::new();
+        let mut id = None;
+
+        for prop in &aggregate.root_entity.properties {
+            let field = snake_ident(&prop.name)?;
+            let column = to_snake_case(&prop.name);
+            if let (true, Some(item_type)) = (prop.required, list_item(&prop.prop_type)) {
+                lists.push(Self::list_table(
+                    model,
+                    &snake,
+                    &column,
+                    field.clone(),
+                    item_type,
+                    &mut visiting,
+                )?);
+                fields.push((field, Value::List(lists.len() - 1)));
+                continue;
+            }
+
+            let mut value = Self::property(model, prop, column, &mut visiting)?;
+            if let (true, Value::Column(column)) = (prop.identifier, &mut value) {
+                if id.is_none() {
+                    if column.nullable
+                        || !matches!(column.kind, Kind::Text | Kind::Integer | Kind::Uuid)
+                    {
+                        anyhow::bail!(
+                            "Identifier '{}' of state-stored aggregate '{}' must be a required String, Integer or Uuid",
+                            prop.name,
+                            aggregate.name
+                        );
+                    }
+                    id = Some(column.clone());
+                } else if column.kind != Kind::Json {
+                    column.unique = true;
+                }
+            }
+            fields.push((field, value));
+        }
+
+        let Some(id) = id else {
+            anyhow::bail!(
+                "Aggregate '{}' is stored as state but has no identifier column",
+                aggregate.name
+            );
+        };
+        let layout = Layout {
+            name: ident(&aggregate.name)?,
+            table: pluralize(&snake),
+            id,
+            root: Value::Struct(ident(&aggregate.name)?, fields),
+            lists,
+        };
+        for table in Self::tables(&layout) {
+            let mut names = BTreeSet::new();
+            for (column, _) in &table.columns {
+                if !names.insert(column.as_str()) {
+                    anyhow::bail!(
+                        "State-stored aggregate '{}' has two '{}' columns in table '{}'",
+                        aggregate.name,
+                        column,
+                        table.name
+                    );
+                }
+            }
+        }
+        Ok(layout)
+    }
+
+    fn list_table(
+        model: &IRModel,
+        parent: &str,
+        column: &str,
+        field: Ident,
+        item_type: &str,
+        visiting: &mut Vec<String>,
+    ) -> Result<ListTable> {
+        let item = match composite(model, item_type) {
+            Some((name, vo)) if !visiting.contains(&name) => {
+                visiting.push(name.clone());
+                let fields = Self::fields(model, vo, None, true, visiting)?;
+                visiting.pop();
+                Value::Struct(ident(&name)?, fields)
+            }
+            _ => Self::value(model, item_type, false, "value".to_string(), visiting)?,
+        };
+
+        let mut item_columns = Vec::new();
+        flatten(&item, TokenStream::new(), &mut item_columns);
+        let taken = |name: &str| item_columns.iter().any(|(column, _)| column.name == name);
+        let parent_column = [format!("{}_id", parent), "parent_id".to_string()]
+            .into_iter()
+            .find(|name| !taken(name))
+            .unwrap_or_else(|| format!("{}_parent_id", parent));
+        let position = ["position", "list_position"]
+            .into_iter()
+            .find(|name| !taken(name))
+            .unwrap_or("item_position")
+            .to_string();
+
+        Ok(ListTable {
+            name: format!("{}_{}", parent, column),
+            field,
+            item_type: rust_type(item_type)?,
+            parent: parent_column,
+            position,
+            item,
+        })
+    }
+
+    /// Values of a value object's fields, with columns named after them
+    fn fields(
+        model: &IRModel,
+        vo: &ValueObject,
+        prefix: Option<&str>,
+        identifiers: bool,
+        visiting: &mut Vec<String>,
+    ) -> Result<Vec<(Ident, Value)>> {
+        vo.properties
+            .iter()
+            .map(|prop| {
+                let column = match prefix {
+                    Some(prefix) => format!("{}_{}", prefix, to_snake_case(&prop.name)),
+                    None => to_snake_case(&prop.name),
+                };
+                let mut value = Self::property(model, prop, column, visiting)?;
+                if let (true, true, Value::Column(column)) =
+                    (identifiers, prop.identifier, &mut value)
+                {
+                    column.unique = column.kind != Kind::Json;
+                }
+                Ok((snake_ident(&prop.name)?, value))
+            })
+            .collect()
+    }
+
+    fn property(
+        model: &IRModel,
+        prop: &Property,
+        column: String,
+        visiting: &mut Vec<String>,
+    ) -> Result<Value> {
+        let mut value = Self::value(model, &prop.prop_type, !prop.required, column, visiting)?;
+        if let (Some(default), Value::Column(column)) = (&prop.default, &mut value) {
+            column.default = column.kind.literal(default);
+        }
+        Ok(value)
+    }
+
+    fn value(
+        model: &IRModel,
+        ir_type: &str,
+        nullable: bool,
+        name: String,
+        visiting: &mut Vec<String>,
+    ) -> Result<Value> {
+        let ir_type = ir_type.trim();
+        let column = |kind, newtype| {
+            Value::Column(Column {
+                name: name.clone(),
+                kind,
+                newtype,
+                nullable,
+                default: None,
+                unique: false,
+            })
+        };
+
+        if let Some(inner) = ir_type
+            .strip_prefix("Option<")
+            .and_then(|rest| rest.strip_suffix('>'))
+        {
+            return if nullable {
+                Ok(column(Kind::Json, None))
+            } else {
+                Self::value(model, inner, true, name, visiting)
+            };
+        }
+        if let Some(kind) = Kind::primitive(ir_type) {
+            return Ok(column(kind, None));
+        }
+
+        let type_name = to_rust_type(ir_type);
+        let Some(vo) = model.value_objects.get(&type_name) else {
+            // Lists, aggregates and types the model does not declare
+            return Ok(column(Kind::Json, None));
+        };
+        match vo.vo_type.as_deref() {
+            Some("enum") => Ok(column(Kind::Enum, None)),
+            Some("identifier") => {
+                match Kind::primitive(vo.underlying_type.as_deref().unwrap_or("String")) {
+                    Some(kind) => Ok(column(kind, Some(ident(&type_name)?))),
+                    None => Ok(column(Kind::Json, None)),
+                }
+            }
+            _ if nullable || visiting.contains(&type_name) => Ok(column(Kind::Json, None)),
+            _ => {
+                visiting.push(type_name.clone());
+                let fields = Self::fields(model, vo, Some(&name), false, visiting)?;
+                visiting.pop();
+                Ok(Value::Struct(ident(&type_name)?, fields))
+            }
+        }
+    }
+
+    /// Tables of a layout, the aggregate's before its lists
+    fn tables(layout: &Layout) -> Vec<TableDef> {
+        let mut columns = Vec::new();
+        flatten(&layout.root, TokenStream::new(), &mut columns);
+        let root = TableDef {
+            name: layout.table.clone(),
+            columns: columns
+                .iter()
+                .map(|(column, _)| {
+                    let primary_key = column.name == layout.id.name;
+                    (column.name.clone(), definition(column, primary_key, true))
+                })
+                .collect(),
+            constraints: Vec::new(),
+        };
+
+        let mut tables = vec![root];
+        for list in &layout.lists {
+            let parent = Column {
+                name: list.parent.clone(),
+                nullable: false,
+                default: None,
+                unique: false,
+                ..layout.id.clone()
+            };
+            let mut definitions = vec![
+                (
+                    list.parent.clone(),
+                    format!(
+                        "{} REFERENCES {} ({})",
+                        definition(&parent, false, true),
+                        quote_name(&layout.table),
+                        quote_name(&layout.id.name)
+                    ),
+                ),
+                (list.position.clone(), "INTEGER NOT NULL".to_string()),
+            ];
+            let mut constraints = vec![format!(
+                "PRIMARY KEY ({}, {})",
+                quote_name(&list.parent),
+                quote_name(&list.position)
+            )];
+
+            let mut item_columns = Vec::new();
+            flatten(&list.item, TokenStream::new(), &mut item_columns);
+            for (column, _) in item_columns {
+                definitions.push((column.name.clone(), definition(column, false, false)));
+                if column.unique {
+                    constraints.push(format!(
+                        "UNIQUE ({}, {})",
+                        quote_name(&list.parent),
+                        quote_name(&column.name)
+                    ));
+                }
+            }
+            tables.push(TableDef {
+                name: list.name.clone(),
+                columns: definitions,
+                constraints,
+            });
+        }
+        tables
+    }
+}
+
+/// Columns of a value, with the expressions of their values
+fn flatten<'a>(value: &'a Value, place: TokenStream, columns: &mut Vec<(&'a Column, TokenStream)>) {
+    match value {
+        Value::Column(column) => columns.push((column, place)),
+        Value::Struct(_, fields) => {
+            for (field, value) in fields {
+                let place = if place.is_empty() {
+                    quote! { #field }
+                } else {
+                    quote! { #place.#field }
+                };
+                flatten(value, place, columns);
+            }
+        }
+        Value::List(_) => {}
+    }
+}
+
+/// Expression building a value from the columns of `row`
+///
+/// Lists are read beforehand into variables named after their fields.
+fn decode(value: &Value, lists: &[Ident]) -> TokenStream {
+    match value {
+        Value::Column(column) => decode_column(column),
+        Value::Struct(name, fields) => {
+            let fields = fields.iter().map(|(field, value)| match value {
+                Value::List(index) if lists[*index] == *field => quote! { #field },
+                _ => {
+                    let value = decode(value, lists);
+                    quote! { #field: #value }
+                }
+            });
+            quote! { #name { #(#fields),* } }
+        }
+        Value::List(index) => {
+            let list = &lists[*index];
+            quote! { #list }
+        }
+    }
+}
+
+fn decode_column(column: &Column) -> TokenStream {
+    let name = &column.name;
+    let storage = column.kind.storage();
+    let newtype = column.newtype.as_ref();
+    if !column.nullable {
+        let get = quote! { row.try_get::<#storage, _>(#name)? };
+        let value = match column.kind {
+            Kind::Decimal | Kind::Uuid => quote! { #get.parse()? },
+            Kind::Enum => quote! { decode_enum(#get)? },
+            Kind::Json => quote! { serde_json::from_str(&#get)? },
+            _ => get,
+        };
+        return match newtype {
+            Some(newtype) => quote! { #newtype(#value) },
+            None => value,
+        };
+    }
+
+    let get = quote! { row.try_get::<Option<#storage>, _>(#name)? };
+    match (column.kind, newtype) {
+        (Kind::Decimal | Kind::Uuid, None) => {
+            quote! { #get.map(|value| value.parse()).transpose()? }
+        }
+        (Kind::Decimal | Kind::Uuid, Some(newtype)) => {
+            quote! { #get.map(|value| value.parse().map(#newtype)).transpose()? }
+        }
+        (Kind::Enum, _) => quote! { #get.map(decode_enum).transpose()? },
+        (Kind::Json, _) => {
+            quote! { #get.map(|value| serde_json::from_str(&value)).transpose()? }
+        }
+        (_, Some(newtype)) => quote! { #get.map(#newtype) },
+        (_, None) => get,
+    }
+}
+
+/// Expression binding the value at `place` to its column
+///
+/// `place` is either a field or, for the items of scalar lists, a reference.
+fn encode(column: &Column, place: &TokenStream, by_ref: bool) -> TokenStream {
+    let newtype = column.newtype.is_some();
+    let value = if by_ref {
+        place.clone()
+    } else {
+        quote! { &#place }
+    };
+    match (column.kind, column.nullable, newtype) {
+        (Kind::Enum, false, _) => quote! { encode_enum(#value)? },
+        (Kind::Enum, true, _) => quote! { #place.as_ref().map(encode_enum).transpose()? },
+        (Kind::Json, false, _) => quote! { serde_json::to_string(#value)? },
+        (Kind::Json, true, _) => {
+            quote! { #place.as_ref().map(serde_json::to_string).transpose()? }
+        }
+        (Kind::Decimal | Kind::Uuid, false, false) => quote! { #place.to_string() },
+        (Kind::Decimal | Kind::Uuid, false, true) => quote! { #place.0.to_string() },
+        (Kind::Decimal | Kind::Uuid, true, false) => {
+            quote! { #place.as_ref().map(|value| value.to_string()) }
+        }
+        (Kind::Decimal | Kind::Uuid, true, true) => {
+            quote! { #place.as_ref().map(|value| value.0.to_string()) }
+        }
+        (Kind::Text, false, false) => value,
+        (Kind::Text, false, true) => quote! { &#place.0 },
+        (Kind::Text, true, false) => quote! { #place.as_deref() },
+        (Kind::Text, true, true) => quote! { #place.as_ref().map(|value| &value.0) },
+        (_, _, false) if by_ref => quote! { *#place },
+        (_, _, false) => quote! { #place },
+        (_, false, true) => quote! { #place.0 },
+        (_, true, true) => quote! { #place.as_ref().map(|value| value.0) },
+    }
+}
+
+/// SQL definition of a column, without its name
+fn definition(column: &Column, primary_key: bool, inline_unique: bool) -> String {
+    let mut definition = column.kind.sql_type().to_string();
+    if !column.nullable {
+        definition.push_str(" NOT NULL");
+    }
+    if primary_key {
+        definition.push_str(" PRIMARY KEY");
+    } else if column.unique && inline_unique {
+        definition.push_str(" UNIQUE");
+    }
+    if let Some(default) = &column.default {
+        definition.push_str(&format!(" DEFAULT {}", default));
+    }
+    definition
+}
+
+/// Item type of a `List<>` or `Vec<>` type
+fn list_item(ir_type: &str) -> Option<&str> {
+    let ir_type = ir_type.trim();
+    ["List<", "Vec<"]
+        .into_iter()
+        .find_map(|wrapper| ir_type.strip_prefix(wrapper))
+        .and_then(|rest| rest.strip_suffix('>'))
+        .map(str::trim)
+}
+
+/// Value object with fields that an IR type names
+fn composite<'a>(model: &'a IRModel, ir_type: &str) -> Option<(String, &'a ValueObject)> {
+    let name = to_rust_type(ir_type.trim());
+    let vo = model.value_objects.get(&name)?;
+    match vo.vo_type.as_deref() {
+        Some("enum") | Some("identifier") => None,
+        _ => Some((name, vo)),
+    }
+}
+
+/// Plural of a snake_case name, for table names
+fn pluralize(name: &str) -> String {
+    let consonant_y =
+        name.ends_with('y') && !name[..name.len() - 1].ends_with(['a', 'e', 'i', 'o', 'u']);
+    if consonant_y {
+        format!("{}ies", &name[..name.len() - 1])
+    } else if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+    {
+        format!("{}es", name)
+    } else {
+        format!("{}s", name)
+    }
+}
+
+/// Table or column name, quoted if it is a keyword
+fn quote_name(name: &str) -> String {
+    if SQL_KEYWORDS
+        .split_whitespace()
+        .any(|keyword| keyword == name)
+    {
+        format!("\"{}\"", name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn unquote_name(name: &str) -> String {
+    name.trim_matches('"').to_string()
+}
+
+fn sql_string(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+/// Statements of a migration, split on semicolons outside string literals
+fn statements(sql: &str) -> Vec<String> {
+    let sql = sql
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("--"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in sql.chars() {
+        match c {
+            '\'' => quoted = !quoted,
+            ';' if !quoted => {
+                statements.push(std::mem::take(&mut current).trim().to_string());
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    statements.push(current.trim().to_string());
+    statements.retain(|statement| !statement.is_empty());
+    statements
+}
+
+/// First name of a statement and the rest of it
+fn split_name(text: &str) -> (String, &str) {
+    let text = text.trim_start();
+    let end = if let Some(rest) = text.strip_prefix('"') {
+        rest.find('"').map_or(text.len(), |end| end + 2)
+    } else {
+        text.find(char::is_whitespace).unwrap_or(text.len())
+    };
+    (unquote_name(&text[..end]), text[end..].trim())
+}
+
+/// Apply the statements of a generated migration to a schema
+///
+/// Only the statements the generator writes are understood; anything else
+/// leaves the schema as it is.
+fn replay(mut tables: Vec<TableDef>, sql: &str) -> Vec<TableDef> {
+    for statement in statements(sql) {
+        if let Some(rest) = statement.strip_prefix("CREATE TABLE ") {
+            let (name, body) = split_name(rest);
+            let body = body
+                .strip_prefix('(')
+                .and_then(|body| body.strip_suffix(')'))
+                .unwrap_or(body);
+            let mut table = TableDef {
+                name,
+                columns: Vec::new(),
+                constraints: Vec::new(),
+            };
+            for line in body.lines() {
+                let line = line.trim().trim_end_matches(',');
+                if line.is_empty() {
+                    continue;
+                }
+                if ["PRIMARY KEY ", "UNIQUE ", "FOREIGN KEY ", "CHECK "]
+                    .iter()
+                    .any(|keyword| line.starts_with(keyword))
+                {
+                    table.constraints.push(line.to_string());
+                } else {
+                    let (column, definition) = split_name(line);
+                    table.columns.push((column, definition.to_string()));
+                }
+            }
+            tables.retain(|existing| existing.name != table.name);
+            tables.push(table);
+        } else if let Some(rest) = statement.strip_prefix("DROP TABLE ") {
+            let (name, _) = split_name(rest);
+            tables.retain(|table| table.name != name);
+        } else if let Some(rest) = statement.strip_prefix("ALTER TABLE ") {
+            let (name, action) = split_name(rest);
+            let Some(table) = tables.iter_mut().find(|table| table.name == name) else {
+                continue;
+            };
+            if let Some(column) = action.strip_prefix("ADD COLUMN ") {
+                let (column, definition) = split_name(column);
+                table.columns.push((column, definition.to_string()));
+            } else if let Some(column) = action.strip_prefix("DROP COLUMN ") {
+                let (column, _) = split_name(column);
+                table.columns.retain(|(existing, _)| *existing != column);
+            } else if let Some(new_name) = action.strip_prefix("RENAME TO ") {
+                // References to the table follow it, as they do in SQLite
+                let (new_name, _) = split_name(new_name);
+                table.name = new_name.clone();
+                let from = references(&name);
+                let to = references(&new_name);
+                for table in &mut tables {
+                    for (_, definition) in &mut table.columns {
+                        *definition = definition.replace(&from, &to);
+                    }
+                }
+            }
+        }
+    }
+    tables
+}
+
+/// Start of a column's reference to a table
+fn references(table: &str) -> String {
+    format!("REFERENCES {} (", quote_name(table))
+}
+
+/// Statements turning the current schema into the desired one, and the
+/// changes they make for the migration's name
+fn migration_statements(current: &[TableDef], desired: &[TableDef]) -> (Vec<String>, Vec<String>) {
+    let mut statements = Vec::new();
+    let mut changes = Vec::new();
+
+    // Child tables reference their parents, so they are dropped first
+    let children = |table: &&TableDef| {
+        table
+            .columns
+            .iter()
+            .any(|(_, definition)| definition.contains("REFERENCES"))
+    };
+    let dropped = current
+        .iter()
+        .filter(children)
+        .chain(current.iter().filter(|table| !children(table)));
+    for table in dropped {
+        if !desired.iter().any(|desired| desired.name == table.name) {
+            statements.push(format!("DROP TABLE {};\n", quote_name(&table.name)));
+            changes.push(format!("drop_{}", table.name));
+        }
+    }
+
+    let mut rebuilt = Vec::new();
+    for table in desired {
+        let Some(existing) = current.iter().find(|current| current.name == table.name) else {
+            continue;
+        };
+
+        let added = table
+            .columns
+            .iter()
+            .filter(|(name, _)| existing.column(name).is_none())
+            .collect::<Vec<_>>();
+        let dropped = existing
+            .columns
+            .iter()
+            .filter(|(name, _)| table.column(name).is_none())
+            .collect::<Vec<_>>();
+        let changed = table.columns.iter().any(|(name, definition)| {
+            existing
+                .column(name)
+                .is_some_and(|existing| existing != definition)
+        });
+        let constraints_changed = table.constraints.iter().collect::<BTreeSet<_>>()
+            != existing.constraints.iter().collect::<BTreeSet<_>>();
+        if added.is_empty() && dropped.is_empty() && !changed && !constraints_changed {
+            continue;
+        }
+        changes.push(format!("alter_{}", table.name));
+
+        let addable = added.iter().all(|(_, definition)| {
+            !definition.contains("PRIMARY KEY")
+                && !definition.contains("UNIQUE")
+                && (!definition.contains("NOT NULL") || definition.contains("DEFAULT"))
+        });
+        let droppable = dropped.iter().all(|(name, definition)| {
+            !definition.contains("PRIMARY KEY")
+                && !definition.contains("UNIQUE")
+                && !definition.contains("REFERENCES")
+                && !existing
+                    .constraints
+                    .iter()
+                    .any(|constraint| constraint.contains(&quote_name(name)))
+        });
+        if changed || constraints_changed || !addable || !droppable {
+            rebuilt.push(table.name.as_str());
+            continue;
+        }
+
+        let name = quote_name(&table.name);
+        for (column, _) in dropped {
+            statements.push(format!(
+                "ALTER TABLE {} DROP COLUMN {};\n",
+                name,
+                quote_name(column)
+            ));
+        }
+        for (column, definition) in added {
+            statements.push(format!(
+                "ALTER TABLE {} ADD COLUMN {} {};\n",
+                name,
+                quote_name(column),
+                definition
+            ));
+        }
+    }
+
+    // SQLite cannot change columns or constraints in place, so tables are
+    // rebuilt as `new_<table>` with the columns they keep. Dropping a table
+    // with rows that other tables reference fails, so the children of a
+    // rebuilt table are rebuilt with it, referencing its new version.
+    for table in desired {
+        let parent = table.columns.iter().any(|(_, definition)| {
+            rebuilt
+                .iter()
+                .any(|parent| definition.contains(&references(parent)))
+        });
+        let exists = current.iter().any(|current| current.name == table.name);
+        if parent && exists && !rebuilt.contains(&table.name.as_str()) {
+            rebuilt.push(table.name.as_str());
+        }
+    }
+    let rebuilt = desired
+        .iter()
+        .filter(|table| rebuilt.contains(&table.name.as_str()))
+        .collect::<Vec<_>>();
+    for table in &rebuilt {
+        let existing = current
+            .iter()
+            .find(|current| current.name == table.name)
+            .expect("rebuilt tables exist");
+        let mut new_table = (*table).clone();
+        for parent in &rebuilt {
+            let from = references(&parent.name);
+            let to = references(&format!("new_{}", parent.name));
+            for (_, definition) in &mut new_table.columns {
+                *definition = definition.replace(&from, &to);
+            }
+        }
+        let new_name = format!("new_{}", table.name);
+        statements.push(new_table.create(&new_name));
+
+        let kept = table
+            .columns
+            .iter()
+            .filter(|(column, _)| existing.column(column).is_some())
+            .map(|(column, _)| quote_name(column))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if !kept.is_empty() {
+            statements.push(format!(
+                "INSERT INTO {} ({}) SELECT {} FROM {};\n",
+                new_name,
+                kept,
+                kept,
+                quote_name(&table.name)
+            ));
+        }
+    }
+    for table in rebuilt.iter().rev() {
+        statements.push(format!("DROP TABLE {};\n", quote_name(&table.name)));
+    }
+    for table in &rebuilt {
+        statements.push(format!(
+            "ALTER TABLE new_{} RENAME TO {};\n",
+            table.name,
+            quote_name(&table.name)
+        ));
+    }
+
+    for table in desired {
+        if !current.iter().any(|current| current.name == table.name) {
+            statements.push(table.create(&table.name));
+            changes.push(format!("create_{}", table.name));
+        }
+    }
+
+    (statements, changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn model(product: &str) -> IRModel {
+        IRParser::parse_yaml(&format!(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Catalog"
+  namespace: "test.catalog"
+aggregates:
+  Product:
+    name: "Product"
+    persistence: "state"
+    root_entity:
+      name: "Product"
+      properties:
+{}
+value_objects:
+  ProductId:
+    name: "ProductId"
+    type: "identifier"
+    underlying_type: "String"
+  Money:
+    name: "Money"
+    properties:
+      - name: "amount"
+        type: "Decimal"
+      - name: "currency"
+        type: "String"
+        default: "EUR"
+  Variant:
+    name: "Variant"
+    properties:
+      - name: "sku"
+        type: "String"
+        identifier: true
+      - name: "price"
+        type: "Money"
+"#,
+            product
+        ))
+        .unwrap()
+    }
+
+    const PRODUCT: &str = r#"        - name: "id"
+          type: "ProductId"
+          identifier: true
+        - name: "order"
+          type: "Integer"
+        - name: "price"
+          type: "Money"
+        - name: "variants"
+          type: "List<Variant>"
+        - name: "tags"
+          type: "List<String>"
+          required: false"#;
+
+    #[test]
+    fn test_first_migration_creates_normalized_tables() {
+        let model = model(PRODUCT);
+        let (file, sql) = PersistenceGenerator::generate_migration(&model, &[])
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(file, "0001_create_schema.sql");
+        assert!(sql.starts_with(MIGRATION_HEADER));
+        assert!(sql.contains(
+            "CREATE TABLE products (\n    id TEXT NOT NULL PRIMARY KEY,\n    \"order\" INTEGER NOT NULL,\n    price_amount TEXT NOT NULL,\n    price_currency TEXT NOT NULL DEFAULT 'EUR',\n    tags TEXT\n);\n"
+        ));
+        assert!(sql.contains(
+            "CREATE TABLE product_variants (\n    product_id TEXT NOT NULL REFERENCES products (id),\n    position INTEGER NOT NULL,\n    sku TEXT NOT NULL,\n    price_amount TEXT NOT NULL,\n    price_currency TEXT NOT NULL DEFAULT 'EUR',\n    PRIMARY KEY (product_id, position),\n    UNIQUE (product_id, sku)\n);\n"
+        ));
+    }
+
+    #[test]
+    fn test_later_migrations_hold_only_the_difference() {
+        let first = PersistenceGenerator::generate_migration(&model(PRODUCT), &[])
+            .unwrap()
+            .unwrap();
+        let existing = vec![first];
+        assert!(
+            PersistenceGenerator::generate_migration(&model(PRODUCT), &existing)
+                .unwrap()
+                .is_none()
+        );
+
+        let added = format!(
+            "{}\n        - name: \"name\"\n          type: \"String\"\n          required: false",
+            PRODUCT
+        );
+        let (file, sql) = PersistenceGenerator::generate_migration(&model(&added), &existing)
+            .unwrap()
+            .unwrap();
+        assert_eq!(file, "0002_alter_products.sql");
+        assert!(sql.ends_with("\n\nALTER TABLE products ADD COLUMN name TEXT;\n"));
+
+        let required = PRODUCT.replace("List<String>\"\n          required: false", "String\"");
+        let (_, sql) = PersistenceGenerator::generate_migration(&model(&required), &existing)
+            .unwrap()
+            .unwrap();
+        assert!(sql.contains("REFERENCES new_products (id)"));
+        assert!(sql.contains("DROP TABLE product_variants;\n\nDROP TABLE products;\n"));
+        assert!(sql.contains(
+            "INSERT INTO new_products (id, \"order\", price_amount, price_currency, tags) SELECT id, \"order\", price_amount, price_currency, tags FROM products;\n"
+        ));
+        assert!(sql.contains("ALTER TABLE new_products RENAME TO products;\n"));
+
+        let mut existing = existing;
+        existing.push(("0002_update_schema.sql".to_string(), sql));
+        assert!(
+            PersistenceGenerator::generate_migration(&model(&required), &existing)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_repository_binds_every_column() {
+        let model = model(PRODUCT);
+        let code = PersistenceGenerator::generate_repository(&model, &model.aggregates["Product"])
+            .unwrap();
+
+        assert!(code.contains("pub struct SqliteProductRepository"));
+        assert!(code.contains(
+            "\"INSERT INTO products (id, \\\"order\\\", price_amount, price_currency, tags) VALUES (?, ?, ?, ?, ?) ON CONFLICT (id) DO UPDATE SET"
+        ));
+        assert!(code.contains(".bind(aggregate.price.amount.to_string())"));
+        assert!(
+            code.contains(".bind(aggregate.tags.as_ref().map(serde_json::to_string).transpose()?)")
+        );
+        assert!(code.contains("price: Money {"));
+    }
+}
@@ -0,0 +1,1080 @@
+use crate::generators::expression_generator::ExpressionGenerator;
+use crate::generators::rust_ast::*;
+use crate::generators::utils::*;
+use crate::ir::{IRModel, Policy};
+use anyhow::Result;
+use indexmap::IndexSet;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub struct PolicyGenerator;
+
+impl PolicyGenerator {
+    /// Generate the policies module root with the command enum and policy definitions
+    pub fn generate_mod(model: &IRModel) -> Result<String> {
+        Self::check_policies(model)?;
+
+        let variants = Self::dispatched_commands(model)
+            .iter()
+            .map(|name| ident(name))
+            .collect::<Result<Vec<_>>>()?;
+        let definitions = model
+            .policies
+            .iter()
+            .map(Self::generate_definition)
+            .collect::<Result<Vec<_>>>()?;
+
+        render(quote! {
+            //! Policies reacting to this bounded context's events
+
+            pub mod actions;
+            pub mod conditions;
+            pub mod engine;
+            pub mod saga_store;
+            #[cfg(feature = "sqlx")]
+            pub mod sqlite_saga_store;
+
+            pub use engine::*;
+            pub use saga_store::*;
+            #[cfg(feature = "sqlx")]
+            pub use sqlite_saga_store::*;
+
+            use anyhow::Result;
+            use async_trait::async_trait;
+
+            use crate::domain::commands::*;
+
+            /// Commands dispatched by policies
+            #[derive(Debug, Clone)]
+            pub enum PolicyCommand {
+                #(#variants(#variants),)*
+            }
+
+            /// All policies of this bounded context
+            pub fn policies() -> Vec<PolicyDefinition<PolicyCommand>> {
+                Vec::from([#(#definitions),*])
+            }
+
+            /// Dispatcher logging commands until they are routed to command handlers
+            pub struct LoggingDispatcher;
+
+            #[async_trait]
+            impl CommandDispatcher<PolicyCommand> for LoggingDispatcher {
+                async fn dispatch(&self, command: PolicyCommand) -> Result<()> {
+                    tracing::info!("Policy dispatched command: {:?}", command);
+                    Ok(())
+                }
+            }
+        })
+    }
+
+    fn generate_definition(policy: &Policy) -> Result<TokenStream> {
+        let name = &policy.name;
+        let triggers = &policy.triggers;
+        let conditions = if policy.conditions.is_empty() {
+            quote! { None }
+        } else {
+            let check = snake_ident(&policy.name)?;
+            quote! { Some(conditions::#check) }
+        };
+        let actions = Self::builders(&policy.actions)?;
+
+        let saga = match &policy.saga {
+            None => quote! { None },
+            Some(saga) => {
+                let completed_by = &saga.completed_by;
+                let timeout = match saga.timeout_seconds {
+                    Some(seconds) => {
+                        let seconds = proc_macro2::Literal::u64_unsuffixed(seconds);
+                        quote! { Some(chrono::Duration::seconds(#seconds)) }
+                    }
+                    None => quote! { None },
+                };
+                let compensation = Self::builders(&saga.compensation)?;
+                quote! {
+                    Some(SagaDefinition {
+                        completed_by: &[#(#completed_by),*],
+                        timeout: #timeout,
+                        compensation: &[#(#compensation),*],
+                    })
+                }
+            }
+        };
+
+        Ok(quote! {
+            PolicyDefinition {
+                name: #name,
+                triggers: &[#(#triggers),*],
+                conditions: #conditions,
+                actions: &[#(#actions),*],
+                saga: #saga,
+            }
+        })
+    }
+
+    fn builders(commands: &[String]) -> Result<Vec<TokenStream>> {
+        commands
+            .iter()
+            .map(|command| {
+                let builder = snake_ident(command)?;
+                Ok(quote! { actions::#builder })
+            })
+            .collect()
+    }
+
+    /// Generate one builder per command dispatched by a policy
+    pub fn generate_actions(model: &IRModel) -> Result<String> {
+        let builders = Self::dispatched_commands(model)
+            .iter()
+            .map(|command| {
+                let doc = doc_lines(&format!("Build a `{}` command", command));
+                let builder = snake_ident(command)?;
+                let command = ident(command)?;
+                Ok(quote! {
+                    #doc
+                    pub fn #builder(message: &IntegrationMessage) -> Result<PolicyCommand> {
+                        Ok(PolicyCommand::#command(serde_json::from_value(message.payload.clone())?))
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        render(quote! {
+            //! Commands built from the events that trigger policies
+            //!
+            //! Each builder fills the command from the event fields with the same
+            //! name. Adapt a builder when its command needs data the event does
+            //! not carry.
+
+            use anyhow::Result;
+
+            use crate::infrastructure::event_bus::IntegrationMessage;
+            use crate::policies::PolicyCommand;
+
+            #(#builders)*
+        })
+    }
+
+    /// Generate a condition check per policy with conditions
+    ///
+    /// Conditions read the payload of the triggering event, under its own
+    /// field names and under the name of the event's aggregate, so that
+    /// `order.items` reads the `items` of an `Order` event.
+    pub fn generate_conditions(model: &IRModel) -> Result<String> {
+        let checks = model
+            .policies
+            .iter()
+            .filter(|policy| !policy.conditions.is_empty())
+            .map(|policy| {
+                let mut text = format!("Conditions of the `{}` policy\n", policy.name);
+                for condition in &policy.conditions {
+                    text.push_str(&format!(
+                        "\n- `{}`: {}",
+                        condition.expression, condition.message
+                    ));
+                }
+                let doc = doc_lines(&text);
+                let check = snake_ident(&policy.name)?;
+                let aliases = policy
+                    .triggers
+                    .iter()
+                    .filter_map(|trigger| model.events.get(trigger)?.aggregate.as_deref())
+                    .map(to_snake_case)
+                    .collect::<IndexSet<_>>()
+                    .into_iter();
+                let log_format = format!("{} skipped {{}}: {{}}", policy.name);
+                let conditions: Vec<_> = policy
+                    .conditions
+                    .iter()
+                    .map(|condition| {
+                        let message = &condition.message;
+                        ExpressionGenerator::guard(
+                            &condition.expression,
+                            &format!("Condition of policy {}", policy.name),
+                            quote! {
+                                tracing::debug!(#log_format, message.event_type, #message);
+                                return Ok(false);
+                            },
+                        )
+                    })
+                    .collect();
+                // The message is unused when every condition is left to a hook
+                let (param, scope) = if conditions.iter().any(|condition| condition.enforced) {
+                    let scope = quote! {
+                        let scope = &message.payload;
+                        #(let scope = &expressions::with(scope, #aliases, &message.payload);)*
+                    };
+                    (quote! { message }, scope)
+                } else {
+                    (quote! { _message }, TokenStream::new())
+                };
+                let conditions = conditions.into_iter().map(|condition| condition.tokens);
+
+                Ok(quote! {
+                    #doc
+                    pub fn #check(#param: &IntegrationMessage) -> Result<bool> {
+                        #scope
+                        #(#conditions)*
+                        Ok(true)
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        render(quote! {
+            //! Policy conditions
+            //!
+            //! Each check decides whether its policy reacts to an event. A property
+            //! the event does not carry reads as `null`, which fails the condition.
+
+            use anyhow::Result;
+
+            use crate::domain::expressions;
+            use crate::infrastructure::event_bus::IntegrationMessage;
+
+            #(#checks)*
+        })
+    }
+
+    /// Generate the policy engine, independent of the model
+    pub fn generate_engine() -> Result<String> {
+        render(quote! {
+            //! Policy engine
+            //!
+            //! Reacts to this bounded context's events on the event bus. Plain
+            //! policies dispatch their actions for every matching event. Sagas also
+            //! keep their state in a `SagaStore` until a completion event arrives,
+            //! and dispatch their compensation commands when an action fails or the
+            //! deadline passes first.
+
+            use anyhow::{Context, Result};
+            use async_trait::async_trait;
+            use chrono::{DateTime, Utc};
+            use std::collections::HashSet;
+            use std::sync::Arc;
+            use uuid::Uuid;
+
+            use crate::infrastructure::event_bus::{EventBus, IntegrationMessage, BOUNDED_CONTEXT};
+            use crate::policies::saga_store::{SagaState, SagaStatus, SagaStore};
+
+            /// Builds a command from the event that triggered a policy
+            pub type CommandBuilder<C> = fn(&IntegrationMessage) -> Result<C>;
+
+            /// Decides whether a policy reacts to an event
+            pub type ConditionCheck = fn(&IntegrationMessage) -> Result<bool>;
+
+            /// Policy reacting to events, optionally as a long-running saga
+            pub struct PolicyDefinition<C: 'static> {
+                pub name: &'static str,
+                /// Events that trigger the policy
+                pub triggers: &'static [&'static str],
+                pub conditions: Option<ConditionCheck>,
+                /// Commands dispatched when the policy is triggered
+                pub actions: &'static [CommandBuilder<C>],
+                pub saga: Option<SagaDefinition<C>>,
+            }
+
+            /// Saga settings of a policy
+            pub struct SagaDefinition<C: 'static> {
+                /// Events that complete the saga
+                pub completed_by: &'static [&'static str],
+                /// Time after which a running saga is compensated
+                pub timeout: Option<chrono::Duration>,
+                /// Commands dispatched when an action fails or the saga times out
+                pub compensation: &'static [CommandBuilder<C>],
+            }
+
+            /// Executes commands issued by policies
+            #[async_trait]
+            pub trait CommandDispatcher<C>: Send + Sync {
+                async fn dispatch(&self, command: C) -> Result<()>;
+            }
+
+            /// Polls the event bus and runs the policies matching each event
+            ///
+            /// Delivery is at-least-once: a failing event is retried on the next
+            /// poll. Saga state makes a redelivered trigger start a saga only once.
+            pub struct PolicyEngine<C: 'static> {
+                bus: Arc<dyn EventBus>,
+                dispatcher: Arc<dyn CommandDispatcher<C>>,
+                sagas: Arc<dyn SagaStore>,
+                policies: Vec<PolicyDefinition<C>>,
+                offset: usize,
+                processed: HashSet<Uuid>,
+            }
+
+            impl<C: Send + 'static> PolicyEngine<C> {
+                pub fn new(
+                    bus: Arc<dyn EventBus>,
+                    dispatcher: Arc<dyn CommandDispatcher<C>>,
+                    sagas: Arc<dyn SagaStore>,
+                    policies: Vec<PolicyDefinition<C>>,
+                ) -> Self {
+                    Self {
+                        bus,
+                        dispatcher,
+                        sagas,
+                        policies,
+                        offset: 0,
+                        processed: HashSet::new(),
+                    }
+                }
+
+                /// Process new events, returning how many policy reactions ran
+                pub async fn poll(&mut self) -> Result<usize> {
+                    let messages = self.bus.read_from(self.offset).await?;
+                    let mut reactions = 0;
+
+                    for message in messages {
+                        if message.context == BOUNDED_CONTEXT
+                            && !self.processed.contains(&message.message_id)
+                        {
+                            for policy in &self.policies {
+                                reactions += self.react(policy, &message).await?;
+                            }
+                            self.processed.insert(message.message_id);
+                        }
+                        self.offset += 1;
+                    }
+
+                    Ok(reactions)
+                }
+
+                /// Compensate running sagas whose deadline has passed
+                pub async fn check_timeouts(&self, now: DateTime<Utc>) -> Result<usize> {
+                    let mut compensated = 0;
+
+                    for mut state in self.sagas.expired(now).await? {
+                        let saga = self
+                            .policies
+                            .iter()
+                            .find(|policy| policy.name == state.saga_type)
+                            .and_then(|policy| policy.saga.as_ref());
+                        if let Some(saga) = saga {
+                            tracing::warn!(
+                                "Saga {} for {} timed out, compensating",
+                                state.saga_type,
+                                state.correlation_id
+                            );
+                            self.compensate(saga, &mut state).await?;
+                            compensated += 1;
+                        }
+                    }
+
+                    Ok(compensated)
+                }
+
+                async fn react(
+                    &self,
+                    policy: &PolicyDefinition<C>,
+                    message: &IntegrationMessage,
+                ) -> Result<usize> {
+                    let event_type = message.event_type.as_str();
+                    let mut reactions = 0;
+
+                    if let Some(saga) = &policy.saga {
+                        if saga.completed_by.contains(&event_type) {
+                            self.complete_saga(policy, message).await?;
+                            reactions += 1;
+                        }
+                    }
+
+                    if !policy.triggers.contains(&event_type) {
+                        return Ok(reactions);
+                    }
+                    if let Some(conditions) = policy.conditions {
+                        if !conditions(message)? {
+                            return Ok(reactions);
+                        }
+                    }
+
+                    match &policy.saga {
+                        None => {
+                            for action in policy.actions {
+                                self.dispatcher.dispatch(action(message)?).await?;
+                            }
+                        }
+                        Some(saga) => self.start_saga(policy, saga, message).await?,
+                    }
+
+                    Ok(reactions + 1)
+                }
+
+                async fn start_saga(
+                    &self,
+                    policy: &PolicyDefinition<C>,
+                    saga: &SagaDefinition<C>,
+                    message: &IntegrationMessage,
+                ) -> Result<()> {
+                    let correlation_id = correlation_id(message)?;
+                    if self.sagas.load(policy.name, &correlation_id).await?.is_some() {
+                        return Ok(());
+                    }
+
+                    let deadline = saga.timeout.map(|timeout| Utc::now() + timeout);
+                    let mut state =
+                        SagaState::new(policy.name, correlation_id, message.clone(), deadline);
+
+                    for action in policy.actions {
+                        let result = match action(message) {
+                            Ok(command) => self.dispatcher.dispatch(command).await,
+                            Err(e) => Err(e),
+                        };
+                        if let Err(e) = result {
+                            tracing::warn!(
+                                "Saga {} for {} failed, compensating: {}",
+                                policy.name,
+                                state.correlation_id,
+                                e
+                            );
+                            return self.compensate(saga, &mut state).await;
+                        }
+                    }
+
+                    self.sagas.save(&state).await
+                }
+
+                async fn complete_saga(
+                    &self,
+                    policy: &PolicyDefinition<C>,
+                    message: &IntegrationMessage,
+                ) -> Result<()> {
+                    let correlation_id = correlation_id(message)?;
+                    if let Some(mut state) = self.sagas.load(policy.name, &correlation_id).await? {
+                        if state.status == SagaStatus::Running {
+                            state.status = SagaStatus::Completed;
+                            self.sagas.save(&state).await?;
+                        }
+                    }
+                    Ok(())
+                }
+
+                async fn compensate(&self, saga: &SagaDefinition<C>, state: &mut SagaState) -> Result<()> {
+                    for compensation in saga.compensation {
+                        self.dispatcher.dispatch(compensation(&state.trigger)?).await?;
+                    }
+                    state.status = SagaStatus::Compensated;
+                    self.sagas.save(state).await
+                }
+            }
+
+            /// Aggregate ID correlating the events of one saga instance
+            fn correlation_id(message: &IntegrationMessage) -> Result<String> {
+                message
+                    .payload
+                    .get("aggregate_id")
+                    .and_then(|value| value.as_str())
+                    .map(str::to_string)
+                    .with_context(|| {
+                        format!("Event {} has no aggregate_id to correlate a saga", message.event_type)
+                    })
+            }
+
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+                use crate::infrastructure::event_bus::InMemoryEventBus;
+                use crate::infrastructure::event_store::{EventStore, InMemoryEventStore};
+                use crate::infrastructure::outbox::OutboxRelay;
+                use crate::policies::saga_store::InMemorySagaStore;
+                use tokio::sync::Mutex;
+
+                /// Dispatcher recording commands, optionally failing actions
+                #[derive(Default)]
+                struct RecordingDispatcher {
+                    commands: Mutex<Vec<String>>,
+                    fail_actions: bool,
+                }
+
+                #[async_trait]
+                impl CommandDispatcher<String> for RecordingDispatcher {
+                    async fn dispatch(&self, command: String) -> Result<()> {
+                        if self.fail_actions && command.starts_with("Action") {
+                            anyhow::bail!("Command rejected");
+                        }
+                        self.commands.lock().await.push(command);
+                        Ok(())
+                    }
+                }
+
+                fn aggregate_id(message: &IntegrationMessage) -> &str {
+                    message.payload["aggregate_id"].as_str().unwrap_or_default()
+                }
+
+                fn action(message: &IntegrationMessage) -> Result<String> {
+                    Ok(format!("Action:{}", aggregate_id(message)))
+                }
+
+                fn compensation(message: &IntegrationMessage) -> Result<String> {
+                    Ok(format!("Compensation:{}", aggregate_id(message)))
+                }
+
+                fn only_vip(message: &IntegrationMessage) -> Result<bool> {
+                    Ok(aggregate_id(message) == "vip")
+                }
+
+                fn policy() -> PolicyDefinition<String> {
+                    PolicyDefinition {
+                        name: "NotifyVip",
+                        triggers: &["Started"],
+                        conditions: Some(only_vip),
+                        actions: &[action],
+                        saga: None,
+                    }
+                }
+
+                fn saga() -> PolicyDefinition<String> {
+                    PolicyDefinition {
+                        name: "Fulfilment",
+                        triggers: &["Started"],
+                        conditions: None,
+                        actions: &[action],
+                        saga: Some(SagaDefinition {
+                            completed_by: &["Finished"],
+                            timeout: Some(chrono::Duration::minutes(30)),
+                            compensation: &[compensation],
+                        }),
+                    }
+                }
+
+                /// Events flow from the in-memory event store through the outbox
+                /// relay and the event bus to the policy engine
+                struct Harness {
+                    store: Arc<InMemoryEventStore>,
+                    relay: OutboxRelay,
+                    bus: InMemoryEventBus,
+                    sagas: Arc<InMemorySagaStore>,
+                    dispatcher: Arc<RecordingDispatcher>,
+                    engine: PolicyEngine<String>,
+                }
+
+                impl Harness {
+                    fn new(policy: PolicyDefinition<String>, fail_actions: bool) -> Self {
+                        let store = Arc::new(InMemoryEventStore::new());
+                        let bus = InMemoryEventBus::new();
+                        let relay = OutboxRelay::new(store.clone(), Arc::new(bus.clone()));
+                        let sagas = Arc::new(InMemorySagaStore::new());
+                        let dispatcher = Arc::new(RecordingDispatcher {
+                            fail_actions,
+                            ..Default::default()
+                        });
+                        let engine = PolicyEngine::new(
+                            Arc::new(bus.clone()),
+                            dispatcher.clone(),
+                            sagas.clone(),
+                            vec![policy],
+                        );
+                        Self {
+                            store,
+                            relay,
+                            bus,
+                            sagas,
+                            dispatcher,
+                            engine,
+                        }
+                    }
+
+                    async fn append(&self, event_type: &str, aggregate_id: &str) {
+                        let message = IntegrationMessage {
+                            message_id: Uuid::new_v4(),
+                            context: BOUNDED_CONTEXT.to_string(),
+                            event_type: event_type.to_string(),
+                            payload: serde_json::json!({
+                                "type": event_type,
+                                "aggregate_id": aggregate_id,
+                            }),
+                        };
+                        self.store
+                            .append_messages(aggregate_id, "Test", vec![message], None)
+                            .await
+                            .unwrap();
+                        self.relay.relay_pending().await.unwrap();
+                    }
+
+                    async fn commands(&self) -> Vec<String> {
+                        self.dispatcher.commands.lock().await.clone()
+                    }
+
+                    async fn status(&self, correlation_id: &str) -> Option<SagaStatus> {
+                        let state = self.sagas.load("Fulfilment", correlation_id).await.unwrap();
+                        state.map(|state| state.status)
+                    }
+                }
+
+                #[tokio::test]
+                async fn policy_dispatches_actions_when_conditions_hold() {
+                    let mut harness = Harness::new(policy(), false);
+                    harness.append("Started", "vip").await;
+                    harness.append("Started", "regular").await;
+                    harness.append("Other", "vip").await;
+
+                    assert_eq!(harness.engine.poll().await.unwrap(), 1);
+                    assert_eq!(harness.commands().await, vec!["Action:vip"]);
+                }
+
+                #[tokio::test]
+                async fn saga_completes_on_completion_event() {
+                    let mut harness = Harness::new(saga(), false);
+                    harness.append("Started", "order-1").await;
+                    harness.engine.poll().await.unwrap();
+                    assert_eq!(harness.status("order-1").await, Some(SagaStatus::Running));
+
+                    harness.append("Finished", "order-1").await;
+                    harness.engine.poll().await.unwrap();
+                    assert_eq!(harness.status("order-1").await, Some(SagaStatus::Completed));
+
+                    let later = Utc::now() + chrono::Duration::hours(1);
+                    assert_eq!(harness.engine.check_timeouts(later).await.unwrap(), 0);
+                    assert_eq!(harness.commands().await, vec!["Action:order-1"]);
+                }
+
+                #[tokio::test]
+                async fn saga_compensates_after_timeout() {
+                    let mut harness = Harness::new(saga(), false);
+                    harness.append("Started", "order-1").await;
+                    harness.engine.poll().await.unwrap();
+
+                    assert_eq!(harness.engine.check_timeouts(Utc::now()).await.unwrap(), 0);
+                    let later = Utc::now() + chrono::Duration::minutes(31);
+                    assert_eq!(harness.engine.check_timeouts(later).await.unwrap(), 1);
+
+                    assert_eq!(harness.status("order-1").await, Some(SagaStatus::Compensated));
+                    assert_eq!(
+                        harness.commands().await,
+                        vec!["Action:order-1", "Compensation:order-1"]
+                    );
+                }
+
+                #[tokio::test]
+                async fn saga_compensates_failed_actions() {
+                    let mut harness = Harness::new(saga(), true);
+                    harness.append("Started", "order-1").await;
+                    harness.engine.poll().await.unwrap();
+
+                    assert_eq!(harness.status("order-1").await, Some(SagaStatus::Compensated));
+                    assert_eq!(harness.commands().await, vec!["Compensation:order-1"]);
+                }
+
+                #[tokio::test]
+                async fn restarted_engine_does_not_restart_sagas() {
+                    let mut harness = Harness::new(saga(), false);
+                    harness.append("Started", "order-1").await;
+                    harness.engine.poll().await.unwrap();
+
+                    // A new engine reads the bus from the start with the same saga store
+                    let mut restarted = PolicyEngine::new(
+                        Arc::new(harness.bus.clone()),
+                        harness.dispatcher.clone(),
+                        harness.sagas.clone(),
+                        vec![saga()],
+                    );
+                    restarted.poll().await.unwrap();
+
+                    assert_eq!(harness.commands().await, vec!["Action:order-1"]);
+                }
+            }
+        })
+    }
+
+    /// Generate the saga state, its store trait and the in-memory store
+    pub fn generate_saga_store() -> Result<String> {
+        let tests = Self::generate_saga_store_tests(quote! { InMemorySagaStore::new() })?;
+
+        render(quote! {
+            //! Persisted saga state
+
+            use anyhow::Result;
+            use async_trait::async_trait;
+            use chrono::{DateTime, Utc};
+            use serde::{Deserialize, Serialize};
+            use tokio::sync::RwLock;
+
+            use crate::infrastructure::event_bus::IntegrationMessage;
+
+            /// Lifecycle of a saga instance
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+            pub enum SagaStatus {
+                Running,
+                Completed,
+                Compensated,
+            }
+
+            /// State of one saga instance
+            #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+            pub struct SagaState {
+                pub saga_type: String,
+                /// Aggregate ID shared by the events of this saga instance
+                pub correlation_id: String,
+                pub status: SagaStatus,
+                /// Event that started the saga, used to build compensation commands
+                pub trigger: IntegrationMessage,
+                pub started_at: DateTime<Utc>,
+                /// Deadline after which a running saga is compensated
+                pub deadline: Option<DateTime<Utc>>,
+            }
+
+            impl SagaState {
+                pub fn new(
+                    saga_type: &str,
+                    correlation_id: String,
+                    trigger: IntegrationMessage,
+                    deadline: Option<DateTime<Utc>>,
+                ) -> Self {
+                    Self {
+                        saga_type: saga_type.to_string(),
+                        correlation_id,
+                        status: SagaStatus::Running,
+                        trigger,
+                        started_at: Utc::now(),
+                        deadline,
+                    }
+                }
+
+                /// Whether the saga is running past its deadline
+                pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+                    self.status == SagaStatus::Running
+                        && self.deadline.is_some_and(|deadline| deadline <= now)
+                }
+            }
+
+            /// Storage for saga state
+            #[async_trait]
+            pub trait SagaStore: Send + Sync {
+                /// Load a saga instance
+                async fn load(&self, saga_type: &str, correlation_id: &str) -> Result<Option<SagaState>>;
+
+                /// Insert or replace a saga instance
+                async fn save(&self, state: &SagaState) -> Result<()>;
+
+                /// Running sagas whose deadline has passed, oldest first
+                async fn expired(&self, now: DateTime<Utc>) -> Result<Vec<SagaState>>;
+            }
+
+            /// In-memory saga store (for development/testing)
+            #[derive(Default)]
+            pub struct InMemorySagaStore {
+                states: RwLock<Vec<SagaState>>,
+            }
+
+            impl InMemorySagaStore {
+                pub fn new() -> Self {
+                    Self::default()
+                }
+            }
+
+            #[async_trait]
+            impl SagaStore for InMemorySagaStore {
+                async fn load(&self, saga_type: &str, correlation_id: &str) -> Result<Option<SagaState>> {
+                    let states = self.states.read().await;
+                    Ok(states
+                        .iter()
+                        .find(|state| {
+                            state.saga_type == saga_type && state.correlation_id == correlation_id
+                        })
+                        .cloned())
+                }
+
+                async fn save(&self, state: &SagaState) -> Result<()> {
+                    let mut states = self.states.write().await;
+                    let existing = states.iter_mut().find(|existing| {
+                        existing.saga_type == state.saga_type
+                            && existing.correlation_id == state.correlation_id
+                    });
+                    match existing {
+                        Some(existing) => *existing = state.clone(),
+                        None => states.push(state.clone()),
+                    }
+                    Ok(())
+                }
+
+                async fn expired(&self, now: DateTime<Utc>) -> Result<Vec<SagaState>> {
+                    let states = self.states.read().await;
+                    Ok(states
+                        .iter()
+                        .filter(|state| state.is_expired(now))
+                        .cloned()
+                        .collect())
+                }
+            }
+
+            #[cfg(test)]
+            pub mod conformance {
+                use super::*;
+                use uuid::Uuid;
+
+                fn state(correlation_id: &str, deadline: Option<DateTime<Utc>>) -> SagaState {
+                    let trigger = IntegrationMessage {
+                        message_id: Uuid::new_v4(),
+                        context: "Test".to_string(),
+                        event_type: "Started".to_string(),
+                        payload: serde_json::json!({ "aggregate_id": correlation_id }),
+                    };
+                    SagaState::new("Fulfilment", correlation_id.to_string(), trigger, deadline)
+                }
+
+                pub async fn saves_and_loads_state<S: SagaStore>(store: S) {
+                    let mut saved = state("order-1", None);
+                    store.save(&saved).await.unwrap();
+                    saved.status = SagaStatus::Completed;
+                    store.save(&saved).await.unwrap();
+
+                    let loaded = store.load("Fulfilment", "order-1").await.unwrap();
+                    assert_eq!(loaded, Some(saved));
+                    assert_eq!(store.load("Fulfilment", "order-2").await.unwrap(), None);
+                }
+
+                pub async fn lists_expired_running_sagas<S: SagaStore>(store: S) {
+                    let now = Utc::now();
+                    let expired = state("order-1", Some(now - chrono::Duration::minutes(1)));
+                    let mut completed = state("order-2", Some(now - chrono::Duration::minutes(1)));
+                    completed.status = SagaStatus::Completed;
+                    store.save(&expired).await.unwrap();
+                    store.save(&completed).await.unwrap();
+                    store
+                        .save(&state("order-3", Some(now + chrono::Duration::minutes(1))))
+                        .await
+                        .unwrap();
+                    store.save(&state("order-4", None)).await.unwrap();
+
+                    assert_eq!(store.expired(now).await.unwrap(), vec![expired]);
+                }
+            }
+
+            #tests
+        })
+    }
+
+    /// Generate the SQLite saga store, compiled with the `sqlx` feature
+    pub fn generate_sqlite_saga_store() -> Result<String> {
+        let tests = Self::generate_saga_store_tests(quote! {
+            SqliteSagaStore::in_memory().await.unwrap()
+        })?;
+
+        // Deadlines are stored as milliseconds so that SQLite compares them
+        // numerically; the full state is kept as JSON
+        render(quote! {
+            //! SQLite saga store
+
+            use anyhow::Result;
+            use async_trait::async_trait;
+            use chrono::{DateTime, Utc};
+            use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+
+            use crate::policies::saga_store::{SagaState, SagaStatus, SagaStore};
+
+            const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS sagas (saga_type TEXT NOT NULL, correlation_id TEXT NOT NULL, running INTEGER NOT NULL, deadline INTEGER, state TEXT NOT NULL, PRIMARY KEY (saga_type, correlation_id))";
+
+            /// Saga store persisted in SQLite
+            #[derive(Clone)]
+            pub struct SqliteSagaStore {
+                pool: SqlitePool,
+            }
+
+            impl SqliteSagaStore {
+                /// Open a private in-memory database (for tests)
+                pub async fn in_memory() -> Result<Self> {
+                    let pool = SqlitePoolOptions::new()
+                        .max_connections(1)
+                        .connect("sqlite::memory:")
+                        .await?;
+                    Self::with_pool(pool).await
+                }
+
+                /// Use an existing pool, such as the event store's, creating the table if needed
+                pub async fn with_pool(pool: SqlitePool) -> Result<Self> {
+                    sqlx::query(SCHEMA).execute(&pool).await?;
+                    Ok(Self { pool })
+                }
+            }
+
+            fn decode_states(states: Vec<String>) -> Result<Vec<SagaState>> {
+                states
+                    .iter()
+                    .map(|state| Ok(serde_json::from_str(state)?))
+                    .collect()
+            }
+
+            #[async_trait]
+            impl SagaStore for SqliteSagaStore {
+                async fn load(&self, saga_type: &str, correlation_id: &str) -> Result<Option<SagaState>> {
+                    let state: Option<String> = sqlx::query_scalar(
+                        "SELECT state FROM sagas WHERE saga_type = ? AND correlation_id = ?",
+                    )
+                    .bind(saga_type)
+                    .bind(correlation_id)
+                    .fetch_optional(&self.pool)
+                    .await?;
+                    Ok(state.map(|state| serde_json::from_str(&state)).transpose()?)
+                }
+
+                async fn save(&self, state: &SagaState) -> Result<()> {
+                    sqlx::query(
+                        "INSERT INTO sagas (saga_type, correlation_id, running, deadline, state) VALUES (?, ?, ?, ?, ?) ON CONFLICT (saga_type, correlation_id) DO UPDATE SET running = excluded.running, deadline = excluded.deadline, state = excluded.state",
+                    )
+                    .bind(&state.saga_type)
+                    .bind(&state.correlation_id)
+                    .bind(state.status == SagaStatus::Running)
+                    .bind(state.deadline.map(|deadline| deadline.timestamp_millis()))
+                    .bind(serde_json::to_string(state)?)
+                    .execute(&self.pool)
+                    .await?;
+                    Ok(())
+                }
+
+                async fn expired(&self, now: DateTime<Utc>) -> Result<Vec<SagaState>> {
+                    let states = sqlx::query_scalar(
+                        "SELECT state FROM sagas WHERE running = 1 AND deadline <= ? ORDER BY deadline",
+                    )
+                    .bind(now.timestamp_millis())
+                    .fetch_all(&self.pool)
+                    .await?;
+                    decode_states(states)
+                }
+            }
+
+            #tests
+        })
+    }
+
+    /// Run the saga store conformance suite against the store built by `store`
+    fn generate_saga_store_tests(store: TokenStream) -> Result<TokenStream> {
+        let cases = ["saves_and_loads_state", "lists_expired_running_sagas"]
+            .iter()
+            .map(|case| ident(case))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+                use crate::policies::saga_store::conformance;
+
+                #(
+                    #[tokio::test]
+                    async fn #cases() {
+                        conformance::#cases(#store).await;
+                    }
+                )*
+            }
+        })
+    }
+
+    /// Commands dispatched as actions or compensations, in declaration order
+    fn dispatched_commands(model: &IRModel) -> IndexSet<&str> {
+        model
+            .policies
+            .iter()
+            .flat_map(|policy| {
+                let compensation = policy.saga.iter().flat_map(|saga| &saga.compensation);
+                policy.actions.iter().chain(compensation)
+            })
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Reject policies referring to unknown events or commands
+    fn check_policies(model: &IRModel) -> Result<()> {
+        let mut names = IndexSet::new();
+
+        for policy in &model.policies {
+            if !names.insert(to_snake_case(&policy.name)) {
+                anyhow::bail!("Duplicate policy '{}'", policy.name);
+            }
+
+            let completed_by = policy.saga.iter().flat_map(|saga| &saga.completed_by);
+            for event_name in policy.triggers.iter().chain(completed_by) {
+                let Some(event) = model.events.get(event_name) else {
+                    anyhow::bail!(
+                        "Policy '{}' refers to unknown event '{}'",
+                        policy.name,
+                        event_name
+                    );
+                };
+                if policy.saga.is_some() && event.aggregate.is_none() {
+                    anyhow::bail!(
+                        "Saga '{}' needs an aggregate on event '{}' to correlate its instances",
+                        policy.name,
+                        event_name
+                    );
+                }
+            }
+
+            let compensation = policy.saga.iter().flat_map(|saga| &saga.compensation);
+            for command in policy.actions.iter().chain(compensation) {
+                if !model.commands.contains_key(command) {
+                    anyhow::bail!(
+                        "Policy '{}' refers to unknown command '{}'",
+                        policy.name,
+                        command
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn model(policies: &str) -> IRModel {
+        IRParser::parse_yaml(&format!(
+            r#"
+version: "2.0"
+bounded_context:
+  name: "Order"
+  namespace: "test.order"
+events:
+  OrderPaid:
+    name: "OrderPaid"
+    aggregate: "Order"
+  ReportPublished:
+    name: "ReportPublished"
+commands:
+  ShipOrder:
+    name: "ShipOrder"
+policies:
+{}
+"#,
+            policies
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_policies_must_refer_to_known_events_and_commands() {
+        let valid = model(
+            r#"
+  - name: "AutoShip"
+    triggers: ["OrderPaid"]
+    actions: ["ShipOrder"]
+"#,
+        );
+        assert!(PolicyGenerator::check_policies(&valid).is_ok());
+
+        let unknown_command = model(
+            r#"
+  - name: "AutoShip"
+    triggers: ["OrderPaid"]
+    actions: ["PrepareShipment"]
+"#,
+        );
+        assert!(PolicyGenerator::check_policies(&unknown_command).is_err());
+    }
+
+    #[test]
+    fn test_saga_events_need_an_aggregate() {
+        let saga = model(
+            r#"
+  - name: "Reporting"
+    triggers: ["ReportPublished"]
+    saga:
+      completed_by: ["OrderPaid"]
+"#,
+        );
+        assert!(PolicyGenerator::check_policies(&saga).is_err());
+    }
+}
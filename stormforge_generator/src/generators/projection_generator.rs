@@ -0,0 +1,1431 @@
+use crate::generators::aggregate_generator::AggregateGenerator;
+use crate::generators::expression_generator::ExpressionGenerator;
+use crate::generators::rust_ast::*;
+use crate::generators::utils::*;
+use crate::ir::{DataSource, Expr, FieldSourceType, IRModel, JoinType, ReadModel, ReadModelField};
+use anyhow::{Context, Result};
+use indexmap::IndexSet;
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+pub struct ProjectionGenerator;
+
+/// Variable holding the events applied to a row, readable by field expressions
+const EVENTS: &str = "events";
+
+/// Source joined into the rows of a read model
+struct Join {
+    alias: String,
+    /// Aggregate type the joined entity is loaded as
+    entity: String,
+    /// Expression over the primary source giving the joined entity's ID
+    key: String,
+    inner: bool,
+}
+
+/// How a read model field is computed
+enum FieldValue {
+    Expression(String),
+    Constant(serde_json::Value),
+    /// Looked up under this entity name, for sources events cannot fill
+    Lookup(String),
+}
+
+impl ProjectionGenerator {
+    /// Generate the projections module root with the read model registry and query routes
+    pub fn generate_mod(model: &IRModel) -> Result<String> {
+        Self::check_read_models(model)?;
+
+        let modules = model
+            .read_models
+            .values()
+            .map(|read_model| snake_ident(&read_model.name))
+            .collect::<Result<Vec<_>>>()?;
+        let projections = model
+            .read_models
+            .values()
+            .map(|read_model| format_ident!("{}Projection", read_model.name))
+            .collect::<Vec<_>>();
+        let rows = model
+            .read_models
+            .values()
+            .map(|read_model| ident(&read_model.name))
+            .collect::<Result<Vec<_>>>()?;
+        let paths = model
+            .read_models
+            .values()
+            .map(|read_model| format!("/read-models/{}", to_kebab_case(&read_model.name)))
+            .collect::<Vec<_>>();
+        let row_paths = paths.iter().map(|path| format!("{}/:id", path));
+        let list_handlers = modules
+            .iter()
+            .map(|module| format_ident!("list_{}", module))
+            .collect::<Vec<_>>();
+        let get_handlers = modules
+            .iter()
+            .map(|module| format_ident!("get_{}", module))
+            .collect::<Vec<_>>();
+        let list_docs = model
+            .read_models
+            .values()
+            .map(|read_model| doc_lines(&format!("List all `{}` rows", read_model.name)));
+        let get_docs = model.read_models.values().map(|read_model| {
+            doc_lines(&format!(
+                "Get one `{}` row by aggregate ID",
+                read_model.name
+            ))
+        });
+        let not_found = model
+            .read_models
+            .values()
+            .map(|read_model| format!("{} {{}} not found", read_model.name));
+
+        render(quote! {
+            //! Read model projections
+
+            #(pub mod #modules;)*
+            pub mod projector;
+            #[cfg(feature = "sqlx")]
+            pub mod sqlite_projection_store;
+
+            #(pub use #modules::*;)*
+            pub use projector::*;
+            #[cfg(feature = "sqlx")]
+            pub use sqlite_projection_store::*;
+
+            use anyhow::Result;
+            use axum::{
+                extract::{Path, State},
+                http::StatusCode,
+                response::Json,
+                routing::get,
+                Router,
+            };
+            use std::sync::Arc;
+
+            use crate::api::ApiError;
+            use crate::infrastructure::event_store::EventStore;
+
+            /// Projections of this bounded context
+            #[derive(Clone)]
+            pub struct ReadModels {
+                #(pub #modules: Arc<Projector<#projections>>,)*
+            }
+
+            impl ReadModels {
+                /// Keep rows in memory; they are rebuilt from the event store on every start
+                pub fn in_memory(events: Arc<dyn EventStore>) -> Self {
+                    Self {
+                        #(
+                            #modules: Arc::new(Projector::new(
+                                events.clone(),
+                                Arc::new(InMemoryProjectionStore::new()),
+                            )),
+                        )*
+                    }
+                }
+
+                /// Keep rows and checkpoints in SQLite, next to the events
+                #[cfg(feature = "sqlx")]
+                pub async fn sqlite(events: Arc<dyn EventStore>, pool: sqlx::SqlitePool) -> Result<Self> {
+                    Ok(Self {
+                        #(
+                            #modules: Arc::new(Projector::new(
+                                events.clone(),
+                                Arc::new(
+                                    SqliteProjectionStore::with_pool(pool.clone(), #projections::NAME)
+                                        .await?,
+                                ),
+                            )),
+                        )*
+                    })
+                }
+
+                /// Apply events appended since the last checkpoint to every projection
+                pub async fn catch_up(&self) -> Result<usize> {
+                    let mut applied = 0;
+                    #(applied += self.#modules.catch_up().await?;)*
+                    Ok(applied)
+                }
+
+                /// Drop all rows and replay every event into every projection
+                pub async fn rebuild(&self) -> Result<usize> {
+                    let mut applied = 0;
+                    #(applied += self.#modules.rebuild().await?;)*
+                    Ok(applied)
+                }
+            }
+
+            /// Query endpoints over the projections
+            pub fn router(read_models: ReadModels) -> Router {
+                Router::new()
+                    #(
+                        .route(#paths, get(#list_handlers))
+                        .route(#row_paths, get(#get_handlers))
+                    )*
+                    .with_state(read_models)
+            }
+
+            fn internal_error(error: anyhow::Error) -> (StatusCode, Json<ApiError>) {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError {
+                        code: "INTERNAL_ERROR".to_string(),
+                        message: error.to_string(),
+                    }),
+                )
+            }
+
+            #(
+                #list_docs
+                async fn #list_handlers(
+                    State(read_models): State<ReadModels>,
+                ) -> Result<Json<Vec<#rows>>, (StatusCode, Json<ApiError>)> {
+                    read_models.#modules.list().await.map(Json).map_err(internal_error)
+                }
+
+                #get_docs
+                async fn #get_handlers(
+                    State(read_models): State<ReadModels>,
+                    Path(id): Path<String>,
+                ) -> Result<Json<#rows>, (StatusCode, Json<ApiError>)> {
+                    match read_models.#modules.get(&id).await.map_err(internal_error)? {
+                        Some(row) => Ok(Json(row)),
+                        None => Err((
+                            StatusCode::NOT_FOUND,
+                            Json(ApiError {
+                                code: "NOT_FOUND".to_string(),
+                                message: format!(#not_found, id),
+                            }),
+                        )),
+                    }
+                }
+            )*
+        })
+    }
+
+    /// Generate the row struct and projection of one read model
+    ///
+    /// The projection folds the events of the primary source's aggregate into
+    /// its state with `domain::state`, and keeps the applied events when a
+    /// field reads `events`. Rows are computed from the state and the joined
+    /// entities when they are read.
+    pub fn generate_read_model(model: &IRModel, read_model: &ReadModel) -> Result<String> {
+        let mut module_doc = format!("{} projection", read_model.name);
+        if let Some(description) = &read_model.description {
+            module_doc.push_str("\n\n");
+            module_doc.push_str(description);
+        }
+        let module_doc = module_doc.lines().map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!(" {}", line)
+            }
+        });
+        let row = ident(&read_model.name)?;
+        let row_doc = doc(read_model.description.as_deref());
+        let projection = format_ident!("{}Projection", read_model.name);
+        let projection_doc = doc_lines(&format!(
+            "Folds domain events into `{}` rows keyed by aggregate ID",
+            read_model.name
+        ));
+        let name = &read_model.name;
+
+        let aggregate = Self::source_aggregate(model, read_model)?;
+        let primary = Self::primary_source(read_model)?;
+        let alias = &primary.alias;
+        let new_state = format_ident!("new_{}", snake_ident(&aggregate)?);
+        let apply_state = format_ident!("apply_{}", snake_ident(&aggregate)?);
+        let updated_by = &read_model.updated_by_events;
+        let updated_by_len = Literal::usize_unsuffixed(updated_by.len());
+        let updated_by_doc = doc_lines(&format!(
+            "Events of the `{}` aggregate that update `{}`",
+            aggregate, read_model.name
+        ));
+
+        let mut reads_events = false;
+        let mut fields = Vec::new();
+        let mut values = Vec::new();
+        let mut lookups = Vec::new();
+        for field in &read_model.fields {
+            let field_name = snake_ident(&field.name)?;
+            let field_type = Self::field_type(model, field)?;
+            let field_value = Self::field_expression(read_model, field)?;
+            let doc = match (&field_value, field.description.as_deref()) {
+                (FieldValue::Lookup(entity), description) => {
+                    let note = format!("Looked up as `{}`", entity);
+                    doc_lines(&match description {
+                        Some(description) => format!("{}\n\n{}", description, note),
+                        None => note,
+                    })
+                }
+                (_, description) => doc(description),
+            };
+            fields.push(quote! {
+                #doc
+                pub #field_name: Option<#field_type>
+            });
+
+            let value = match field_value {
+                FieldValue::Expression(expression) => {
+                    reads_events |= Expr::parse(&expression)?.names().contains(&EVENTS);
+                    ExpressionGenerator::value(&expression).with_context(|| {
+                        format!("Field '{}' of read model '{}'", field.name, read_model.name)
+                    })?
+                }
+                FieldValue::Constant(value) => ExpressionGenerator::constant(&value),
+                FieldValue::Lookup(entity) => {
+                    let found = format_ident!("{}_found", field_name.to_string());
+                    lookups.push((entity, found.clone()));
+                    quote! { #found }
+                }
+            };
+            values.push(quote! { #field_name: field(#value) });
+        }
+
+        let initial = if reads_events {
+            quote! { expressions::object([(#alias, state::#new_state()), (#EVENTS, Value::Array(Vec::new()))]) }
+        } else {
+            quote! { expressions::object([(#alias, state::#new_state())]) }
+        };
+        let track_events = reads_events.then(|| {
+            quote! {
+                if let Some(Value::Array(events)) = state.get_mut(#EVENTS) {
+                    events.push(event.clone());
+                }
+            }
+        });
+
+        let joins = Self::joins(model, read_model)?;
+        let (joins_fn, joined, joined_param) = if joins.is_empty() && lookups.is_empty() {
+            (quote! {}, Vec::new(), quote! { _joined })
+        } else {
+            // Looked up fields follow the joins, keyed by the row's aggregate ID
+            let identifier = model
+                .aggregates
+                .get(&aggregate)
+                .and_then(AggregateGenerator::identifier)
+                .map_or("id", |prop| prop.name.as_str());
+            let row_id = ExpressionGenerator::value(&format!("{}.{}", alias, identifier))?;
+            let entities = joins
+                .iter()
+                .map(|join| join.entity.as_str())
+                .chain(lookups.iter().map(|(entity, _)| entity.as_str()));
+            let keys = joins
+                .iter()
+                .map(|join| ExpressionGenerator::value(&join.key))
+                .chain(lookups.iter().map(|_| Ok(row_id.clone())))
+                .collect::<Result<Vec<_>>>()?;
+            let joins_fn = quote! {
+                fn joins(state: &Value) -> Vec<(&'static str, Option<String>)> {
+                    let scope = state;
+                    vec![#((#entities, #keys.as_str().map(str::to_string))),*]
+                }
+            };
+            let joined = std::iter::once(quote! { let mut joined = joined.iter(); })
+                .chain(joins.iter().map(|join| {
+                    let alias = &join.alias;
+                    let entity = if join.inner {
+                        quote! { joined.next()?.as_ref()? }
+                    } else {
+                        quote! { joined.next().and_then(Option::as_ref).unwrap_or(&Value::Null) }
+                    };
+                    quote! { let scope = &expressions::with(scope, #alias, #entity); }
+                }))
+                .chain(lookups.iter().map(|(_, found)| {
+                    quote! { let #found = joined.next().cloned().flatten().unwrap_or(Value::Null); }
+                }))
+                .collect();
+            (joins_fn, joined, quote! { joined })
+        };
+
+        render(quote! {
+            #(#![doc = #module_doc])*
+
+            use serde::{Deserialize, Serialize};
+            use serde_json::Value;
+
+            use crate::domain::entities::*;
+            use crate::domain::{expressions, state};
+            use crate::projections::projector::{field, Projection};
+
+            #row_doc
+            #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+            pub struct #row {
+                #(#fields,)*
+            }
+
+            #updated_by_doc
+            const UPDATED_BY: [&str; #updated_by_len] = [#(#updated_by),*];
+
+            #projection_doc
+            pub struct #projection;
+
+            impl Projection for #projection {
+                type Event = Value;
+                type State = Value;
+                type Row = #row;
+
+                const NAME: &'static str = #name;
+
+                fn row_id(event: &Value) -> Option<String> {
+                    let event_type = event.get("type")?.as_str()?;
+                    if !UPDATED_BY.contains(&event_type) {
+                        return None;
+                    }
+                    Some(event.get("aggregate_id")?.as_str()?.to_string())
+                }
+
+                fn apply(state: &mut Value, event: &Value) {
+                    if !state.is_object() {
+                        *state = #initial;
+                    }
+                    state::#apply_state(&mut state[#alias], event);
+                    #track_events
+                }
+
+                #joins_fn
+
+                fn row(state: &Value, #joined_param: &[Option<Value>]) -> Option<#row> {
+                    let scope = state;
+                    #(#joined)*
+                    Some(#row {
+                        #(#values,)*
+                    })
+                }
+            }
+        })
+    }
+
+    /// Generate the projection runtime shared by all read models
+    pub fn generate_projector() -> Result<String> {
+        let tests = Self::generate_store_tests(quote! { InMemoryProjectionStore::new() })?;
+
+        render(quote! {
+            //! Projection runtime
+            //!
+            //! A `Projector` reads the events appended since its checkpoint, folds them
+            //! into the state of their rows, and commits the changed states together
+            //! with the new checkpoint. Rows are computed from the state when read,
+            //! looking up joined entities at that time. Rebuilding drops all rows and
+            //! replays the event store from the start.
+
+            use anyhow::Result;
+            use async_trait::async_trait;
+            use serde::{de::DeserializeOwned, Serialize};
+            use std::collections::btree_map::Entry;
+            use std::collections::BTreeMap;
+            use std::marker::PhantomData;
+            use serde_json::Value;
+            use std::sync::Arc;
+            use tokio::sync::RwLock;
+
+            use crate::domain::state;
+            use crate::infrastructure::event_store::EventStore;
+
+            /// Read model built by folding events into a state per row
+            ///
+            /// The state is what the store persists; rows are computed from it,
+            /// together with the joined entities, when they are read.
+            pub trait Projection: Send + Sync + 'static {
+                type Event: DeserializeOwned + Send + Sync;
+                type State: Clone + Default + Serialize + DeserializeOwned + Send + Sync + 'static;
+                type Row: Send;
+
+                /// Read model name, used as the storage key of rows and checkpoints
+                const NAME: &'static str;
+
+                /// ID of the row an event updates, `None` when the event does not concern the read model
+                fn row_id(event: &Self::Event) -> Option<String>;
+
+                /// Apply an event to the state of its row
+                fn apply(state: &mut Self::State, event: &Self::Event);
+
+                /// Entities joined into a row, as aggregate type and ID, in join order,
+                /// then the looked up fields
+                fn joins(_state: &Self::State) -> Vec<(&'static str, Option<String>)> {
+                    Vec::new()
+                }
+
+                /// Row of a state and its joined entities, `None` when an inner join found nothing
+                fn row(state: &Self::State, joined: &[Option<Value>]) -> Option<Self::Row>;
+            }
+
+            /// Finds the entities joined into read model rows
+            ///
+            /// Fields with `custom` or `read_model` sources, which events cannot
+            /// fill, are looked up too: as entity `ReadModel.field`, with the ID of
+            /// their row. `EventStoreLookup` finds none, so wrap it to fill them.
+            #[async_trait]
+            pub trait EntityLookup: Send + Sync {
+                /// State of an entity, `None` when it does not exist
+                async fn find(&self, entity: &str, id: &str) -> Result<Option<Value>>;
+            }
+
+            /// Folds the events of joined aggregates of this context
+            pub struct EventStoreLookup {
+                events: Arc<dyn EventStore>,
+            }
+
+            impl EventStoreLookup {
+                pub fn new(events: Arc<dyn EventStore>) -> Self {
+                    Self { events }
+                }
+            }
+
+            #[async_trait]
+            impl EntityLookup for EventStoreLookup {
+                async fn find(&self, entity: &str, id: &str) -> Result<Option<Value>> {
+                    let events = self
+                        .events
+                        .load_events(id, entity)
+                        .await?
+                        .iter()
+                        .map(serde_json::to_value)
+                        .collect::<serde_json::Result<Vec<_>>>()?;
+                    if events.is_empty() {
+                        return Ok(None);
+                    }
+                    Ok(state::load(entity, &events))
+                }
+            }
+
+            /// Typed row field from a computed value, `None` for `null` or a value of another shape
+            pub fn field<T: DeserializeOwned>(value: Value) -> Option<T> {
+                if value.is_null() {
+                    return None;
+                }
+                serde_json::from_value(value).ok()
+            }
+
+            /// Storage for the rows and checkpoint of one projection
+            #[async_trait]
+            pub trait ProjectionStore<R>: Send + Sync {
+                /// Load a row
+                async fn get(&self, id: &str) -> Result<Option<R>>;
+
+                /// All rows ordered by ID
+                async fn list(&self) -> Result<Vec<R>>;
+
+                /// Number of events applied so far
+                async fn checkpoint(&self) -> Result<i64>;
+
+                /// Save changed rows and the new checkpoint in one write
+                async fn commit(&self, rows: Vec<(String, R)>, checkpoint: i64) -> Result<()>;
+
+                /// Remove all rows and reset the checkpoint
+                async fn reset(&self) -> Result<()>;
+            }
+
+            struct InMemoryRows<R> {
+                rows: BTreeMap<String, R>,
+                checkpoint: i64,
+            }
+
+            /// In-memory projection store (for development/testing)
+            pub struct InMemoryProjectionStore<R> {
+                state: RwLock<InMemoryRows<R>>,
+            }
+
+            impl<R> InMemoryProjectionStore<R> {
+                pub fn new() -> Self {
+                    Self {
+                        state: RwLock::new(InMemoryRows {
+                            rows: BTreeMap::new(),
+                            checkpoint: 0,
+                        }),
+                    }
+                }
+            }
+
+            impl<R> Default for InMemoryProjectionStore<R> {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            #[async_trait]
+            impl<R: Clone + Send + Sync> ProjectionStore<R> for InMemoryProjectionStore<R> {
+                async fn get(&self, id: &str) -> Result<Option<R>> {
+                    let state = self.state.read().await;
+                    Ok(state.rows.get(id).cloned())
+                }
+
+                async fn list(&self) -> Result<Vec<R>> {
+                    let state = self.state.read().await;
+                    Ok(state.rows.values().cloned().collect())
+                }
+
+                async fn checkpoint(&self) -> Result<i64> {
+                    Ok(self.state.read().await.checkpoint)
+                }
+
+                async fn commit(&self, rows: Vec<(String, R)>, checkpoint: i64) -> Result<()> {
+                    let mut state = self.state.write().await;
+                    state.rows.extend(rows);
+                    state.checkpoint = checkpoint;
+                    Ok(())
+                }
+
+                async fn reset(&self) -> Result<()> {
+                    let mut state = self.state.write().await;
+                    state.rows.clear();
+                    state.checkpoint = 0;
+                    Ok(())
+                }
+            }
+
+            /// Keeps a projection up to date with the event store
+            pub struct Projector<P: Projection> {
+                events: Arc<dyn EventStore>,
+                store: Arc<dyn ProjectionStore<P::State>>,
+                lookup: Arc<dyn EntityLookup>,
+                projection: PhantomData<P>,
+            }
+
+            impl<P: Projection> Projector<P> {
+                pub fn new(events: Arc<dyn EventStore>, store: Arc<dyn ProjectionStore<P::State>>) -> Self {
+                    Self {
+                        lookup: Arc::new(EventStoreLookup::new(events.clone())),
+                        events,
+                        store,
+                        projection: PhantomData,
+                    }
+                }
+
+                /// Find joined entities with `lookup` instead of this context's event store
+                pub fn with_lookup(mut self, lookup: Arc<dyn EntityLookup>) -> Self {
+                    self.lookup = lookup;
+                    self
+                }
+
+                /// Load a row
+                pub async fn get(&self, id: &str) -> Result<Option<P::Row>> {
+                    match self.store.get(id).await? {
+                        Some(state) => self.row(&state).await,
+                        None => Ok(None),
+                    }
+                }
+
+                /// All rows ordered by ID
+                pub async fn list(&self) -> Result<Vec<P::Row>> {
+                    let mut rows = Vec::new();
+                    for state in self.store.list().await? {
+                        rows.extend(self.row(&state).await?);
+                    }
+                    Ok(rows)
+                }
+
+                async fn row(&self, state: &P::State) -> Result<Option<P::Row>> {
+                    let mut joined = Vec::new();
+                    for (entity, id) in P::joins(state) {
+                        joined.push(match id {
+                            Some(id) => self.lookup.find(entity, &id).await?,
+                            None => None,
+                        });
+                    }
+                    Ok(P::row(state, &joined))
+                }
+
+                /// Apply the events appended since the checkpoint, returning how many were read
+                pub async fn catch_up(&self) -> Result<usize> {
+                    let checkpoint = self.store.checkpoint().await?;
+                    let payloads = self.events.read_all_from(checkpoint).await?;
+                    if payloads.is_empty() {
+                        return Ok(0);
+                    }
+
+                    let mut states: BTreeMap<String, P::State> = BTreeMap::new();
+                    for payload in &payloads {
+                        let event: P::Event = serde_json::from_value(payload.clone())?;
+                        let Some(id) = P::row_id(&event) else {
+                            continue;
+                        };
+                        let state = match states.entry(id) {
+                            Entry::Occupied(entry) => entry.into_mut(),
+                            Entry::Vacant(entry) => {
+                                let state = self.store.get(entry.key()).await?.unwrap_or_default();
+                                entry.insert(state)
+                            }
+                        };
+                        P::apply(state, &event);
+                    }
+
+                    let checkpoint = checkpoint + i64::try_from(payloads.len())?;
+                    self.store.commit(states.into_iter().collect(), checkpoint).await?;
+                    Ok(payloads.len())
+                }
+
+                /// Drop all rows and replay every event, returning how many were read
+                pub async fn rebuild(&self) -> Result<usize> {
+                    self.store.reset().await?;
+                    self.catch_up().await
+                }
+            }
+
+            #[cfg(test)]
+            pub mod conformance {
+                use super::*;
+                use crate::infrastructure::event_bus::IntegrationMessage;
+                use crate::infrastructure::event_store::InMemoryEventStore;
+                use serde::Deserialize;
+                use uuid::Uuid;
+
+                #[derive(Deserialize)]
+                #[serde(tag = "type")]
+                pub enum AccountEvent {
+                    Opened { aggregate_id: String, owner: String },
+                    Renamed { aggregate_id: String, owner: String },
+                    Audited,
+                }
+
+                #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+                pub struct Account {
+                    pub owner: Option<String>,
+                    pub changes: i64,
+                }
+
+                pub struct Accounts;
+
+                impl Projection for Accounts {
+                    type Event = AccountEvent;
+                    type State = Account;
+                    type Row = Account;
+
+                    const NAME: &'static str = "Accounts";
+
+                    fn row_id(event: &AccountEvent) -> Option<String> {
+                        match event {
+                            AccountEvent::Opened { aggregate_id, .. }
+                            | AccountEvent::Renamed { aggregate_id, .. } => Some(aggregate_id.clone()),
+                            AccountEvent::Audited => None,
+                        }
+                    }
+
+                    fn apply(account: &mut Account, event: &AccountEvent) {
+                        if let AccountEvent::Opened { owner, .. } | AccountEvent::Renamed { owner, .. } =
+                            event
+                        {
+                            account.owner = Some(owner.clone());
+                            account.changes += 1;
+                        }
+                    }
+
+                    fn row(account: &Account, _joined: &[Option<Value>]) -> Option<Account> {
+                        Some(account.clone())
+                    }
+                }
+
+                async fn append(events: &InMemoryEventStore, payload: serde_json::Value) {
+                    let message = IntegrationMessage {
+                        message_id: Uuid::new_v4(),
+                        context: "Test".to_string(),
+                        event_type: payload["type"].as_str().unwrap().to_string(),
+                        payload,
+                    };
+                    let aggregate_id = message.payload["aggregate_id"].as_str().unwrap_or("audit").to_string();
+                    events
+                        .append_messages(&aggregate_id, "Account", vec![message], None)
+                        .await
+                        .unwrap();
+                }
+
+                fn opened(id: &str, owner: &str) -> serde_json::Value {
+                    serde_json::json!({ "type": "Opened", "aggregate_id": id, "owner": owner })
+                }
+
+                fn renamed(id: &str, owner: &str) -> serde_json::Value {
+                    serde_json::json!({ "type": "Renamed", "aggregate_id": id, "owner": owner })
+                }
+
+                fn account(owner: &str, changes: i64) -> Account {
+                    Account {
+                        owner: Some(owner.to_string()),
+                        changes,
+                    }
+                }
+
+                pub async fn catch_up_applies_each_event_once<S: ProjectionStore<Account> + 'static>(
+                    store: S,
+                ) {
+                    let events = Arc::new(InMemoryEventStore::new());
+                    let projector = Projector::<Accounts>::new(events.clone(), Arc::new(store));
+                    append(&events, opened("acc-1", "Ada")).await;
+                    append(&events, serde_json::json!({ "type": "Audited" })).await;
+
+                    assert_eq!(projector.catch_up().await.unwrap(), 2);
+                    assert_eq!(projector.catch_up().await.unwrap(), 0);
+
+                    append(&events, renamed("acc-1", "Grace")).await;
+                    append(&events, opened("acc-2", "Alan")).await;
+                    assert_eq!(projector.catch_up().await.unwrap(), 2);
+
+                    assert_eq!(projector.get("acc-1").await.unwrap(), Some(account("Grace", 2)));
+                    assert_eq!(
+                        projector.list().await.unwrap(),
+                        vec![account("Grace", 2), account("Alan", 1)]
+                    );
+                    assert_eq!(projector.get("acc-3").await.unwrap(), None);
+                }
+
+                pub async fn checkpoint_survives_restart<S: ProjectionStore<Account> + 'static>(
+                    store: S,
+                ) {
+                    let events = Arc::new(InMemoryEventStore::new());
+                    let store: Arc<dyn ProjectionStore<Account>> = Arc::new(store);
+                    append(&events, opened("acc-1", "Ada")).await;
+                    Projector::<Accounts>::new(events.clone(), store.clone())
+                        .catch_up()
+                        .await
+                        .unwrap();
+
+                    append(&events, renamed("acc-1", "Grace")).await;
+                    let restarted = Projector::<Accounts>::new(events.clone(), store.clone());
+                    assert_eq!(restarted.catch_up().await.unwrap(), 1);
+                    assert_eq!(store.checkpoint().await.unwrap(), 2);
+                    assert_eq!(restarted.get("acc-1").await.unwrap(), Some(account("Grace", 2)));
+                }
+
+                pub async fn rebuild_replays_from_scratch<S: ProjectionStore<Account> + 'static>(
+                    store: S,
+                ) {
+                    let events = Arc::new(InMemoryEventStore::new());
+                    let store: Arc<dyn ProjectionStore<Account>> = Arc::new(store);
+                    append(&events, opened("acc-1", "Ada")).await;
+                    append(&events, renamed("acc-1", "Grace")).await;
+                    let projector = Projector::<Accounts>::new(events.clone(), store.clone());
+                    projector.catch_up().await.unwrap();
+
+                    // A row the events do not produce, as left by an older projection
+                    store
+                        .commit(vec![("stale".to_string(), account("Nobody", 9))], 2)
+                        .await
+                        .unwrap();
+
+                    assert_eq!(projector.rebuild().await.unwrap(), 2);
+                    assert_eq!(projector.list().await.unwrap(), vec![account("Grace", 2)]);
+                }
+            }
+
+            #tests
+        })
+    }
+
+    /// Generate the SQLite projection store, compiled with the `sqlx` feature
+    pub fn generate_sqlite_projection_store() -> Result<String> {
+        let tests = Self::generate_store_tests(quote! {
+            SqliteProjectionStore::in_memory("Accounts").await.unwrap()
+        })?;
+
+        // Rows of all projections share one table, keyed by projection name,
+        // and are stored as JSON so that the schema does not follow the IR
+        render(quote! {
+            //! SQLite projection store
+
+            use anyhow::Result;
+            use async_trait::async_trait;
+            use serde::{de::DeserializeOwned, Serialize};
+            use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+            use std::marker::PhantomData;
+
+            use crate::projections::projector::ProjectionStore;
+
+            const SCHEMA: [&str; 2] = [
+                "CREATE TABLE IF NOT EXISTS read_models (projection TEXT NOT NULL, id TEXT NOT NULL, row TEXT NOT NULL, PRIMARY KEY (projection, id))",
+                "CREATE TABLE IF NOT EXISTS projection_checkpoints (projection TEXT PRIMARY KEY, position INTEGER NOT NULL)",
+            ];
+
+            /// Projection store persisted in SQLite
+            pub struct SqliteProjectionStore<R> {
+                pool: SqlitePool,
+                projection: String,
+                rows: PhantomData<fn() -> R>,
+            }
+
+            impl<R> SqliteProjectionStore<R> {
+                /// Open a private in-memory database (for tests)
+                pub async fn in_memory(projection: &str) -> Result<Self> {
+                    let pool = SqlitePoolOptions::new()
+                        .max_connections(1)
+                        .connect("sqlite::memory:")
+                        .await?;
+                    Self::with_pool(pool, projection).await
+                }
+
+                /// Use an existing pool, such as the event store's, creating the tables if needed
+                pub async fn with_pool(pool: SqlitePool, projection: &str) -> Result<Self> {
+                    for statement in SCHEMA {
+                        sqlx::query(statement).execute(&pool).await?;
+                    }
+                    Ok(Self {
+                        pool,
+                        projection: projection.to_string(),
+                        rows: PhantomData,
+                    })
+                }
+            }
+
+            #[async_trait]
+            impl<R: Serialize + DeserializeOwned + Send + Sync> ProjectionStore<R>
+                for SqliteProjectionStore<R>
+            {
+                async fn get(&self, id: &str) -> Result<Option<R>> {
+                    let row: Option<String> = sqlx::query_scalar(
+                        "SELECT row FROM read_models WHERE projection = ? AND id = ?",
+                    )
+                    .bind(&self.projection)
+                    .bind(id)
+                    .fetch_optional(&self.pool)
+                    .await?;
+                    Ok(row.map(|row| serde_json::from_str(&row)).transpose()?)
+                }
+
+                async fn list(&self) -> Result<Vec<R>> {
+                    let rows: Vec<String> = sqlx::query_scalar(
+                        "SELECT row FROM read_models WHERE projection = ? ORDER BY id",
+                    )
+                    .bind(&self.projection)
+                    .fetch_all(&self.pool)
+                    .await?;
+                    rows.iter()
+                        .map(|row| Ok(serde_json::from_str(row)?))
+                        .collect()
+                }
+
+                async fn checkpoint(&self) -> Result<i64> {
+                    let position: Option<i64> = sqlx::query_scalar(
+                        "SELECT position FROM projection_checkpoints WHERE projection = ?",
+                    )
+                    .bind(&self.projection)
+                    .fetch_optional(&self.pool)
+                    .await?;
+                    Ok(position.unwrap_or(0))
+                }
+
+                async fn commit(&self, rows: Vec<(String, R)>, checkpoint: i64) -> Result<()> {
+                    let mut tx = self.pool.begin().await?;
+                    for (id, row) in rows {
+                        sqlx::query(
+                            "INSERT INTO read_models (projection, id, row) VALUES (?, ?, ?) ON CONFLICT (projection, id) DO UPDATE SET row = excluded.row",
+                        )
+                        .bind(&self.projection)
+                        .bind(id)
+                        .bind(serde_json::to_string(&row)?)
+                        .execute(&mut *tx)
+                        .await?;
+                    }
+                    sqlx::query(
+                        "INSERT INTO projection_checkpoints (projection, position) VALUES (?, ?) ON CONFLICT (projection) DO UPDATE SET position = excluded.position",
+                    )
+                    .bind(&self.projection)
+                    .bind(checkpoint)
+                    .execute(&mut *tx)
+                    .await?;
+                    tx.commit().await?;
+                    Ok(())
+                }
+
+                async fn reset(&self) -> Result<()> {
+                    let mut tx = self.pool.begin().await?;
+                    sqlx::query("DELETE FROM read_models WHERE projection = ?")
+                        .bind(&self.projection)
+                        .execute(&mut *tx)
+                        .await?;
+                    sqlx::query("DELETE FROM projection_checkpoints WHERE projection = ?")
+                        .bind(&self.projection)
+                        .execute(&mut *tx)
+                        .await?;
+                    tx.commit().await?;
+                    Ok(())
+                }
+            }
+
+            #tests
+        })
+    }
+
+    /// Run the projection conformance suite against the store built by `store`
+    fn generate_store_tests(store: TokenStream) -> Result<TokenStream> {
+        let cases = [
+            "catch_up_applies_each_event_once",
+            "checkpoint_survives_restart",
+            "rebuild_replays_from_scratch",
+        ]
+        .iter()
+        .map(|case| ident(case))
+        .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+                use crate::projections::projector::conformance;
+
+                #(
+                    #[tokio::test]
+                    async fn #cases() {
+                        conformance::#cases(#store).await;
+                    }
+                )*
+            }
+        })
+    }
+
+    /// Rust type of a read model field, `serde_json::Value` for types the IR does not define
+    fn field_type(model: &IRModel, field: &ReadModelField) -> Result<syn::Type> {
+        let Some(field_type) = &field.field_type else {
+            return Ok(syn::parse_quote!(serde_json::Value));
+        };
+        let known = referenced_type(field_type).is_none_or(|name| {
+            model.value_objects.contains_key(&name) || model.aggregates.contains_key(&name)
+        });
+        if known {
+            rust_type(field_type)
+        } else {
+            Ok(syn::parse_quote!(serde_json::Value))
+        }
+    }
+
+    /// Aggregate whose events update a read model, which keys its rows
+    fn source_aggregate(model: &IRModel, read_model: &ReadModel) -> Result<String> {
+        let aggregates: IndexSet<&str> = read_model
+            .updated_by_events
+            .iter()
+            .filter_map(|event| model.events.get(event)?.aggregate.as_deref())
+            .collect();
+        let aggregate = match aggregates.into_iter().collect::<Vec<_>>().as_slice() {
+            [aggregate] => aggregate.to_string(),
+            [] => anyhow::bail!("Read model '{}' is not updated by any event", read_model.name),
+            aggregates => anyhow::bail!(
+                "Read model '{}' is updated by events of several aggregates ({}); join the others as sources",
+                read_model.name,
+                aggregates.join(", ")
+            ),
+        };
+        if !model.aggregates.contains_key(&aggregate) {
+            anyhow::bail!(
+                "Read model '{}' is updated by events of unknown aggregate '{}'",
+                read_model.name,
+                aggregate
+            );
+        }
+        Ok(aggregate)
+    }
+
+    /// Source the rows are folded from: the one without a join
+    fn primary_source(read_model: &ReadModel) -> Result<&DataSource> {
+        let primary = read_model
+            .sources
+            .iter()
+            .filter(|source| source.join_type.is_none())
+            .collect::<Vec<_>>();
+        match primary.as_slice() {
+            [source] => Ok(source),
+            _ => anyhow::bail!(
+                "Read model '{}' needs exactly one source without a join",
+                read_model.name
+            ),
+        }
+    }
+
+    /// Joined sources, looked up by the value of their left property in the primary source
+    fn joins(model: &IRModel, read_model: &ReadModel) -> Result<Vec<Join>> {
+        let primary = Self::primary_source(read_model)?;
+        read_model
+            .sources
+            .iter()
+            .filter_map(|source| Some((source, source.join_type?)))
+            .map(|(source, join_type)| {
+                let context = || {
+                    format!(
+                        "Join of '{}' in read model '{}'",
+                        source.alias, read_model.name
+                    )
+                };
+                if join_type == JoinType::Right {
+                    anyhow::bail!("{}: right joins are not supported", context());
+                }
+                let Some(condition) = &source.join_condition else {
+                    anyhow::bail!("{}: missing join condition", context());
+                };
+                if condition
+                    .operator
+                    .as_deref()
+                    .is_some_and(|operator| operator != "equals")
+                {
+                    anyhow::bail!(
+                        "{}: only the `equals` operator is supported, not `{}`",
+                        context(),
+                        condition.operator.as_deref().unwrap_or_default()
+                    );
+                }
+
+                if Expr::parse(&condition.left_property)?.names() != [primary.alias.as_str()] {
+                    anyhow::bail!(
+                        "{}: the left property `{}` must read the primary source `{}`",
+                        context(),
+                        condition.left_property,
+                        primary.alias
+                    );
+                }
+                let entity = to_pascal_case(&source.alias);
+                let identifier = model
+                    .aggregates
+                    .get(&entity)
+                    .and_then(|aggregate| {
+                        aggregate
+                            .root_entity
+                            .properties
+                            .iter()
+                            .find(|prop| prop.identifier)
+                    })
+                    .map_or("id", |prop| prop.name.as_str());
+                if condition.right_property != format!("{}.{}", source.alias, identifier) {
+                    anyhow::bail!(
+                        "{}: the right property `{}` must be the identifier `{}.{}`",
+                        context(),
+                        condition.right_property,
+                        source.alias,
+                        identifier
+                    );
+                }
+
+                Ok(Join {
+                    alias: source.alias.clone(),
+                    entity,
+                    key: condition.left_property.clone(),
+                    inner: join_type == JoinType::Inner,
+                })
+            })
+            .collect()
+    }
+
+    /// Expression or constant computing a field from the sources
+    fn field_expression(read_model: &ReadModel, field: &ReadModelField) -> Result<FieldValue> {
+        let context = || format!("Field '{}' of read model '{}'", field.name, read_model.name);
+        let source = &field.source;
+        let expression = match source.source_type {
+            FieldSourceType::EntityProperty => field
+                .transformation
+                .clone()
+                .or_else(|| source.property_path.clone()),
+            FieldSourceType::Computed => field
+                .transformation
+                .clone()
+                .or_else(|| source.expression.clone()),
+            FieldSourceType::Constant => {
+                return match &source.value {
+                    Some(value) => Ok(FieldValue::Constant(value.clone())),
+                    None => anyhow::bail!("{}: constant without a value", context()),
+                };
+            }
+            FieldSourceType::ReadModel | FieldSourceType::Custom => {
+                let entity = format!("{}.{}", read_model.name, field.name);
+                eprintln!(
+                    "⚠️  {}: {} sources cannot be projected from events, so the field is looked up as `{}`",
+                    context(),
+                    if source.source_type == FieldSourceType::Custom {
+                        "custom"
+                    } else {
+                        "read model"
+                    },
+                    entity
+                );
+                return Ok(FieldValue::Lookup(entity));
+            }
+        };
+        let Some(expression) = expression else {
+            anyhow::bail!("{}: missing property path or expression", context());
+        };
+
+        let aliases = read_model
+            .sources
+            .iter()
+            .map(|source| source.alias.as_str())
+            .collect::<Vec<_>>();
+        for name in Expr::parse(&expression).with_context(context)?.names() {
+            if name != EVENTS && !aliases.contains(&name) {
+                anyhow::bail!(
+                    "{}: `{}` is neither a source alias nor `{}`",
+                    context(),
+                    name,
+                    EVENTS
+                );
+            }
+        }
+        Ok(FieldValue::Expression(expression))
+    }
+
+    /// Reject read models updated by unknown events or events without an aggregate
+    fn check_read_models(model: &IRModel) -> Result<()> {
+        let mut names = IndexSet::new();
+
+        for read_model in model.read_models.values() {
+            if !names.insert(to_snake_case(&read_model.name)) {
+                anyhow::bail!("Duplicate read model '{}'", read_model.name);
+            }
+
+            for event_name in &read_model.updated_by_events {
+                let Some(event) = model.events.get(event_name) else {
+                    anyhow::bail!(
+                        "Read model '{}' is updated by unknown event '{}'",
+                        read_model.name,
+                        event_name
+                    );
+                };
+                if event.aggregate.is_none() {
+                    anyhow::bail!(
+                        "Read model '{}' needs an aggregate on event '{}' to key its rows",
+                        read_model.name,
+                        event_name
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn model(read_models: &str) -> IRModel {
+        IRParser::parse_yaml(&format!(
+            r#"
+version: "2.0"
+bounded_context:
+  name: "Order"
+  namespace: "test.order"
+aggregates:
+  Order:
+    name: "Order"
+    root_entity:
+      name: "Order"
+      properties:
+        - name: "id"
+          type: "String"
+          identifier: true
+        - name: "status"
+          type: "OrderStatus"
+value_objects:
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "Paid"
+events:
+  OrderPaid:
+    name: "OrderPaid"
+    aggregate: "Order"
+    payload:
+      - name: "status"
+        type: "OrderStatus"
+      - name: "paidAt"
+        type: "DateTime"
+  ReportPublished:
+    name: "ReportPublished"
+read_models:
+{}
+"#,
+            read_models
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_read_models_must_be_updated_by_aggregate_events() {
+        let valid = model(
+            r#"
+  OrderSummary:
+    name: "OrderSummary"
+    updated_by_events: ["OrderPaid"]
+"#,
+        );
+        assert!(ProjectionGenerator::check_read_models(&valid).is_ok());
+
+        let unknown = model(
+            r#"
+  OrderSummary:
+    name: "OrderSummary"
+    updated_by_events: ["OrderShipped"]
+"#,
+        );
+        assert!(ProjectionGenerator::check_read_models(&unknown).is_err());
+
+        let without_aggregate = model(
+            r#"
+  Reports:
+    name: "Reports"
+    updated_by_events: ["ReportPublished"]
+"#,
+        );
+        assert!(ProjectionGenerator::check_read_models(&without_aggregate).is_err());
+    }
+
+    fn read_model(fields: &str, sources: &str) -> String {
+        format!(
+            r#"
+  OrderSummary:
+    name: "OrderSummary"
+    sources:
+      - entity_id: "entity-order"
+        alias: "order"
+{}
+    fields:
+{}
+    updated_by_events: ["OrderPaid"]
+"#,
+            sources, fields
+        )
+    }
+
+    fn generate(read_model: &str) -> Result<String> {
+        let model = model(read_model);
+        ProjectionGenerator::generate_read_model(&model, &model.read_models["OrderSummary"])
+    }
+
+    #[test]
+    fn test_fields_read_the_folded_sources() {
+        let code = generate(&read_model(
+            r#"
+      - name: "status"
+        type: "OrderStatus"
+        source:
+          type: "entity_property"
+          property_path: "order.status"
+      - name: "label"
+        type: "String"
+        source:
+          type: "entity_property"
+          property_path: "order.id"
+        transformation: "'ORD-' + order.id"
+      - name: "changes"
+        type: "Integer"
+        source:
+          type: "computed"
+          expression: "events.length"
+      - name: "customerName"
+        type: "String"
+        source:
+          type: "entity_property"
+          property_path: "customer.name"
+"#,
+            r#"
+      - entity_id: "entity-customer"
+        alias: "customer"
+        join_type: "inner"
+        join_condition:
+          left_property: "order.customerId"
+          right_property: "customer.id"
+          operator: "equals"
+"#,
+        ))
+        .unwrap();
+
+        assert!(code.contains(r#"state::apply_order(&mut state["order"], event);"#));
+        assert!(code.contains("events.push(event.clone());"));
+        assert!(code.contains(r#""Customer","#));
+        assert!(code.contains("joined.next()?.as_ref()?"));
+        assert!(code.contains(
+            r#"status: field(expressions::get(&expressions::get(scope, "order"), "status"))"#
+        ));
+    }
+
+    #[test]
+    fn test_rejects_fields_that_cannot_be_projected() {
+        let field = |source: &str| {
+            generate(&read_model(
+                &format!(
+                    r#"
+      - name: "value"
+        source:
+{}"#,
+                    source
+                ),
+                "",
+            ))
+        };
+
+        assert!(field(
+            r#"
+          type: "entity_property"
+          property_path: "order.status""#
+        )
+        .is_ok());
+        assert!(field(
+            r#"
+          type: "computed"
+          expression: "formatMoney(order.total)""#
+        )
+        .is_err());
+        assert!(field(
+            r#"
+          type: "entity_property"
+          property_path: "invoice.total""#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_looks_up_fields_events_cannot_fill() {
+        let code = generate(&read_model(
+            r#"
+      - name: "note"
+        type: "String"
+        source:
+          type: "custom"
+      - name: "address"
+        description: "Saved address"
+        source:
+          type: "read_model"
+          read_model_id: "rm-customer-addresses""#,
+            "",
+        ))
+        .unwrap();
+
+        assert!(code.contains(
+            r#"            (
+                "OrderSummary.note",
+                expressions::get(&expressions::get(scope, "order"), "id")"#
+        ));
+        assert!(code.contains(r#""OrderSummary.address","#));
+        assert!(code
+            .contains("let note_found = joined.next().cloned().flatten().unwrap_or(Value::Null);"));
+        assert!(code.contains("note: field(note_found)"));
+        assert!(code.contains(
+            "    /// Saved address\n    ///\n    /// Looked up as `OrderSummary.address`\n"
+        ));
+    }
+
+    #[test]
+    fn test_rejects_unsupported_joins() {
+        let join = |join_type: &str, right_property: &str| {
+            generate(&read_model(
+                r#"
+      - name: "customerName"
+        source:
+          type: "entity_property"
+          property_path: "customer.name"
+"#,
+                &format!(
+                    r#"
+      - entity_id: "entity-customer"
+        alias: "customer"
+        join_type: "{}"
+        join_condition:
+          left_property: "order.customerId"
+          right_property: "{}""#,
+                    join_type, right_property
+                ),
+            ))
+        };
+
+        assert!(join("left", "customer.id").is_ok());
+        assert!(join("right", "customer.id").is_err());
+        assert!(join("left", "customer.email").is_err());
+    }
+}
@@ -0,0 +1,951 @@
+use crate::generators::rust_ast::*;
+use crate::generators::utils::*;
+use crate::ir::{IRModel, Property, ValueObject};
+use anyhow::Result;
+use heck::ToShoutySnakeCase;
+use indexmap::IndexMap;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+pub struct ProtoGenerator;
+
+/// Proto representation of an IR type
+struct ProtoType {
+    /// Proto type name, e.g. `string` or `OrderId`
+    name: String,
+    repeated: bool,
+    /// Message fields always track presence and decode to `Option`
+    message: bool,
+}
+
+/// Field of a message before numbering
+struct ProtoField {
+    name: String,
+    /// Type with its label, e.g. `repeated OrderItem`
+    declaration: String,
+    description: Option<String>,
+}
+
+/// Message or enum of the proto file before numbering
+struct ProtoBlock {
+    keyword: &'static str,
+    name: String,
+    description: Option<String>,
+    fields: Vec<ProtoField>,
+    /// Wrap the fields in a `oneof` of this name
+    oneof: Option<&'static str>,
+}
+
+/// Field of a previously generated message or enum
+struct NumberedField {
+    number: u32,
+    declaration: String,
+}
+
+/// Field numbers of a previously generated block
+#[derive(Default)]
+struct BlockNumbers {
+    fields: IndexMap<String, NumberedField>,
+    /// Reserved numbers, with the name of the removed field when known
+    reserved: Vec<(u32, Option<String>)>,
+}
+
+/// Field numbers of the previously generated proto file
+///
+/// Fields keep their number across regenerations. The numbers of removed
+/// fields, or of fields whose type changed, are reserved so they are never
+/// reused with a different meaning.
+#[derive(Default)]
+struct FieldNumbers {
+    blocks: IndexMap<String, BlockNumbers>,
+}
+
+impl FieldNumbers {
+    /// Read the numbers from a proto file in the format written by `render_block`
+    fn parse(proto: &str) -> Self {
+        let mut numbers = Self::default();
+        let mut current: Option<(String, bool)> = None;
+        let mut depth = 0;
+
+        for line in proto.lines().map(str::trim) {
+            if line.starts_with("//") || line.is_empty() {
+                continue;
+            }
+            if line.ends_with('{') {
+                if depth == 0 {
+                    let mut words = line.split_whitespace();
+                    current = match (words.next(), words.next()) {
+                        (Some(keyword @ ("message" | "enum")), Some(name)) => {
+                            Some((name.to_string(), keyword == "enum"))
+                        }
+                        _ => None,
+                    };
+                }
+                depth += 1;
+                continue;
+            }
+            if line == "}" {
+                depth -= 1;
+                if depth == 0 {
+                    current = None;
+                }
+                continue;
+            }
+            let Some((block, is_enum)) = &current else {
+                continue;
+            };
+            let block = numbers.blocks.entry(block.clone()).or_default();
+            let line = line.trim_end_matches(';');
+
+            if let Some(reserved) = line.strip_prefix("reserved ") {
+                if let Some(name) = reserved.strip_prefix('"') {
+                    // A reserved name follows the number of the removed field
+                    if let Some(last) = block.reserved.last_mut() {
+                        last.1 = Some(name.trim_end_matches('"').to_string());
+                    }
+                } else if let Ok(number) = reserved.parse() {
+                    block.reserved.push((number, None));
+                }
+                continue;
+            }
+
+            let Some((declaration, number)) = line.split_once(" = ") else {
+                continue;
+            };
+            let Ok(number) = number.trim().parse::<u32>() else {
+                continue;
+            };
+            // The zero value of an enum is always the unspecified value
+            if *is_enum && number == 0 {
+                continue;
+            }
+            let declaration = declaration.trim_start_matches("optional ");
+            let (declaration, name) = match declaration.rsplit_once(' ') {
+                Some((declaration, name)) => (declaration.to_string(), name),
+                None => (String::new(), declaration),
+            };
+            block.fields.insert(
+                name.to_string(),
+                NumberedField {
+                    number,
+                    declaration,
+                },
+            );
+        }
+
+        numbers
+    }
+
+    /// Number the fields of a block, returning the numbers and reserved entries
+    fn assign(&self, block: &ProtoBlock) -> (Vec<u32>, Vec<(u32, Option<String>)>) {
+        let empty = BlockNumbers::default();
+        let previous = self.blocks.get(&block.name).unwrap_or(&empty);
+        let mut reserved = previous.reserved.clone();
+        let mut next = previous
+            .fields
+            .values()
+            .map(|field| field.number)
+            .chain(reserved.iter().map(|(number, _)| *number))
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        let declaration = |field: &ProtoField| {
+            field
+                .declaration
+                .trim_start_matches("optional ")
+                .to_string()
+        };
+
+        let mut numbers = Vec::new();
+        for field in &block.fields {
+            // A reserved name cannot be used again, only its number stays reserved
+            for entry in reserved.iter_mut() {
+                if entry.1.as_deref() == Some(field.name.as_str()) {
+                    entry.1 = None;
+                }
+            }
+            match previous.fields.get(&field.name) {
+                Some(old) if old.declaration == declaration(field) => numbers.push(old.number),
+                _ => {
+                    numbers.push(next);
+                    next += 1;
+                }
+            }
+        }
+
+        for (name, old) in &previous.fields {
+            match block.fields.iter().find(|field| &field.name == name) {
+                None => reserved.push((old.number, Some(name.clone()))),
+                Some(field) if old.declaration != declaration(field) => {
+                    reserved.push((old.number, None))
+                }
+                Some(_) => {}
+            }
+        }
+        reserved.sort_by_key(|(number, _)| *number);
+
+        (numbers, reserved)
+    }
+}
+
+impl ProtoGenerator {
+    /// Path of the proto file, relative to the crate root
+    pub fn proto_path(model: &IRModel) -> String {
+        format!("proto/{}.proto", to_snake_case(&model.bounded_context.name))
+    }
+
+    /// Proto package of the bounded context, derived from its namespace
+    pub fn package(model: &IRModel) -> String {
+        model
+            .bounded_context
+            .namespace
+            .split('.')
+            .map(to_snake_case)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    fn service_name(model: &IRModel) -> String {
+        format!("{}Service", to_pascal_case(&model.bounded_context.name))
+    }
+
+    /// Generate the proto file of the bounded context
+    ///
+    /// `previous` is the content of the proto file generated before, whose
+    /// field numbers are kept.
+    pub fn generate_proto(model: &IRModel, previous: Option<&str>) -> Result<String> {
+        let numbers = previous.map(FieldNumbers::parse).unwrap_or_default();
+        let context = &model.bounded_context.name;
+
+        let mut blocks = Vec::new();
+        for (name, vo) in &model.value_objects {
+            blocks.push(Self::value_object_block(model, name, vo)?);
+        }
+        for (name, aggregate) in &model.aggregates {
+            blocks.push(ProtoBlock {
+                keyword: "message",
+                name: name.clone(),
+                description: aggregate.description.clone(),
+                fields: Self::fields(model, &aggregate.root_entity.properties)?,
+                oneof: None,
+            });
+        }
+        for (name, command) in &model.commands {
+            blocks.push(ProtoBlock {
+                keyword: "message",
+                name: format!("{}Request", name),
+                description: command.description.clone(),
+                fields: Self::fields(model, &command.payload)?,
+                oneof: None,
+            });
+            blocks.push(Self::response_block(name));
+        }
+        for (name, query) in &model.queries {
+            blocks.push(ProtoBlock {
+                keyword: "message",
+                name: format!("{}Request", name),
+                description: query.description.clone(),
+                fields: Self::fields(model, &query.parameters)?,
+                oneof: None,
+            });
+            blocks.push(Self::response_block(name));
+        }
+        for (name, event) in &model.events {
+            let mut fields = Vec::from([
+                ProtoField {
+                    name: "event_id".to_string(),
+                    declaration: "string".to_string(),
+                    description: Some("Unique event identifier".to_string()),
+                },
+                ProtoField {
+                    name: "occurred_at".to_string(),
+                    declaration: "google.protobuf.Timestamp".to_string(),
+                    description: Some("Timestamp when the event occurred".to_string()),
+                },
+            ]);
+            if event.aggregate.is_some() {
+                fields.push(ProtoField {
+                    name: "aggregate_id".to_string(),
+                    declaration: "string".to_string(),
+                    description: Some("ID of the aggregate that produced this event".to_string()),
+                });
+            }
+            fields.extend(Self::fields(model, &event.payload)?);
+            blocks.push(ProtoBlock {
+                keyword: "message",
+                name: name.clone(),
+                description: event.description.clone(),
+                fields,
+                oneof: None,
+            });
+        }
+        if !model.events.is_empty() {
+            blocks.push(ProtoBlock {
+                keyword: "message",
+                name: "DomainEvent".to_string(),
+                description: Some(
+                    "Domain event enum containing all events in this bounded context".to_string(),
+                ),
+                fields: model
+                    .events
+                    .keys()
+                    .map(|name| ProtoField {
+                        name: to_snake_case(name),
+                        declaration: name.clone(),
+                        description: None,
+                    })
+                    .collect(),
+                oneof: Some("event"),
+            });
+        }
+
+        let mut out = format!(
+            "// Generated by StormForge Generator from the {} bounded context.\n// Field numbers are kept across regenerations and the numbers of removed\n// fields are reserved.\nsyntax = \"proto3\";\n\npackage {};\n",
+            context,
+            Self::package(model)
+        );
+        let uses_timestamp = blocks.iter().any(|block| {
+            block
+                .fields
+                .iter()
+                .any(|field| field.declaration.ends_with("google.protobuf.Timestamp"))
+        });
+        if uses_timestamp {
+            out.push_str("\nimport \"google/protobuf/timestamp.proto\";\n");
+        }
+
+        out.push('\n');
+        out.push_str(&comment(Some(&format!("{} API", context)), ""));
+        out.push_str(&format!("service {} {{\n", Self::service_name(model)));
+        for (name, description) in model
+            .commands
+            .iter()
+            .map(|(name, command)| (name, &command.description))
+            .chain(
+                model
+                    .queries
+                    .iter()
+                    .map(|(name, query)| (name, &query.description)),
+            )
+        {
+            out.push_str(&comment(description.as_deref(), "  "));
+            out.push_str(&format!(
+                "  rpc {}({}Request) returns ({}Response);\n",
+                name, name, name
+            ));
+        }
+        out.push_str("}\n");
+
+        for block in &blocks {
+            out.push('\n');
+            out.push_str(&Self::render_block(block, &numbers));
+        }
+
+        Ok(out)
+    }
+
+    fn value_object_block(model: &IRModel, name: &str, vo: &ValueObject) -> Result<ProtoBlock> {
+        let fields = match vo.vo_type.as_deref() {
+            Some("enum") => vo
+                .values
+                .iter()
+                .map(|value| ProtoField {
+                    name: enum_value_name(name, &value.name),
+                    declaration: String::new(),
+                    description: value.description.clone(),
+                })
+                .collect(),
+            // Identifiers are messages wrapping the underlying value
+            Some("identifier") => {
+                let underlying = vo.underlying_type.as_deref().unwrap_or("String");
+                Vec::from([ProtoField {
+                    name: "value".to_string(),
+                    declaration: proto_type(model, underlying)?.name,
+                    description: None,
+                }])
+            }
+            _ => Self::fields(model, &vo.properties)?,
+        };
+        Ok(ProtoBlock {
+            keyword: if vo.vo_type.as_deref() == Some("enum") {
+                "enum"
+            } else {
+                "message"
+            },
+            name: name.to_string(),
+            description: vo.description.clone(),
+            fields,
+            oneof: None,
+        })
+    }
+
+    /// Response carrying the `data` of the HTTP API's `{"data": ...}` envelope
+    fn response_block(name: &str) -> ProtoBlock {
+        ProtoBlock {
+            keyword: "message",
+            name: format!("{}Response", name),
+            description: None,
+            fields: Vec::from([ProtoField {
+                name: "data".to_string(),
+                declaration: "string".to_string(),
+                description: None,
+            }]),
+            oneof: None,
+        }
+    }
+
+    fn fields(model: &IRModel, properties: &[Property]) -> Result<Vec<ProtoField>> {
+        properties
+            .iter()
+            .map(|prop| {
+                let proto_type = proto_type(model, &prop.prop_type)?;
+                let label = if proto_type.repeated {
+                    "repeated "
+                } else if is_nullable(prop) && !proto_type.message {
+                    "optional "
+                } else {
+                    ""
+                };
+                Ok(ProtoField {
+                    name: to_snake_case(&prop.name),
+                    declaration: format!("{}{}", label, proto_type.name),
+                    description: prop.description.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn render_block(block: &ProtoBlock, numbers: &FieldNumbers) -> String {
+        let (field_numbers, reserved) = numbers.assign(block);
+        let is_enum = block.keyword == "enum";
+        let indent = if block.oneof.is_some() { "    " } else { "  " };
+
+        let mut out = comment(block.description.as_deref(), "");
+        out.push_str(&format!("{} {} {{\n", block.keyword, block.name));
+        if is_enum {
+            out.push_str(&format!(
+                "  {} = 0;\n",
+                enum_value_name(&block.name, "Unspecified")
+            ));
+        }
+        if let Some(oneof) = block.oneof {
+            out.push_str(&format!("  oneof {} {{\n", oneof));
+        }
+        for (field, number) in block.fields.iter().zip(field_numbers) {
+            out.push_str(&comment(field.description.as_deref(), indent));
+            if is_enum {
+                out.push_str(&format!("{}{} = {};\n", indent, field.name, number));
+            } else {
+                out.push_str(&format!(
+                    "{}{} {} = {};\n",
+                    indent, field.declaration, field.name, number
+                ));
+            }
+        }
+        if block.oneof.is_some() {
+            out.push_str("  }\n");
+        }
+        for (number, name) in reserved {
+            out.push_str(&format!("  reserved {};\n", number));
+            if let Some(name) = name {
+                out.push_str(&format!("  reserved \"{}\";\n", name));
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Generate the build script compiling the proto file with the `grpc` feature
+    pub fn generate_build_script(model: &IRModel) -> Result<String> {
+        let proto_path = Self::proto_path(model);
+
+        render(quote! {
+            //! Compiles the gRPC API definition when the `grpc` feature is enabled
+
+            fn main() -> Result<(), Box<dyn std::error::Error>> {
+                #[cfg(feature = "grpc")]
+                {
+                    // Use the bundled protoc unless one is configured
+                    if std::env::var_os("PROTOC").is_none() {
+                        std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+                    }
+                    tonic_build::compile_protos(#proto_path)?;
+                }
+
+                Ok(())
+            }
+        })
+    }
+
+    /// Generate the tonic server routing RPCs into the API's command and query handlers
+    pub fn generate_service(model: &IRModel) -> Result<String> {
+        let module_doc = format!(
+            " gRPC API serving the command and query handlers of the HTTP API\n\n Implements `{}` from `{}`.",
+            Self::service_name(model),
+            Self::proto_path(model)
+        );
+        let module_doc = module_doc.lines().map(str::to_string);
+        let proto_doc = doc_lines(&format!(
+            "Types and service generated from `{}`",
+            Self::proto_path(model)
+        ));
+        let package = Self::package(model);
+        let service = format_ident!("{}", Self::service_name(model));
+        let server_module = format_ident!("{}_server", to_snake_case(&service.to_string()));
+        let server = format_ident!("{}Server", service);
+
+        let mut methods = Vec::new();
+        for (name, command) in &model.commands {
+            let doc = doc(command.description.as_deref());
+            let method = snake_ident(name)?;
+            let execute = format_ident!("execute_{}", to_snake_case(name));
+            let command_type = ident(name)?;
+            let request = format_ident!("{}Request", to_pascal_case(name));
+            let response = format_ident!("{}Response", to_pascal_case(name));
+            methods.push(quote! {
+                #doc
+                async fn #method(
+                    &self,
+                    request: Request<proto::#request>,
+                ) -> Result<Response<proto::#response>, Status> {
+                    let command = #command_type::from_proto(request.into_inner())?;
+                    let data = #execute(&self.state, command).await?;
+                    Ok(Response::new(proto::#response { data }))
+                }
+            });
+        }
+        for (name, query) in &model.queries {
+            let doc = doc(query.description.as_deref());
+            let method = snake_ident(name)?;
+            let execute = format_ident!("execute_{}", to_snake_case(name));
+            let request = format_ident!("{}Request", to_pascal_case(name));
+            let response = format_ident!("{}Response", to_pascal_case(name));
+            // Like the HTTP handler, the query handler does not take its parameters yet
+            methods.push(quote! {
+                #doc
+                async fn #method(
+                    &self,
+                    _request: Request<proto::#request>,
+                ) -> Result<Response<proto::#response>, Status> {
+                    let data = #execute().await?;
+                    Ok(Response::new(proto::#response { data }))
+                }
+            });
+        }
+
+        let mut conversions = Vec::new();
+        for (name, vo) in &model.value_objects {
+            conversions.push(Self::value_object_conversion(model, name, vo)?);
+        }
+        for (name, aggregate) in &model.aggregates {
+            conversions.push(Self::struct_conversion(
+                model,
+                &ident(name)?,
+                &format_ident!("{}", to_pascal_case(name)),
+                &aggregate.root_entity.properties,
+            )?);
+        }
+        for (name, command) in &model.commands {
+            conversions.push(Self::struct_conversion(
+                model,
+                &ident(name)?,
+                &format_ident!("{}Request", to_pascal_case(name)),
+                &command.payload,
+            )?);
+        }
+
+        render(quote! {
+            #(#![doc = #module_doc])*
+
+            use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+            use rust_decimal::Decimal;
+            use std::net::SocketAddr;
+            use tonic::{Request, Response, Status};
+            use uuid::Uuid;
+
+            use crate::api::routes::*;
+            use crate::domain::{commands::*, entities::*};
+
+            #proto_doc
+            pub mod proto {
+                tonic::include_proto!(#package);
+            }
+
+            use proto::#server_module::{#service, #server};
+
+            /// Serve the gRPC API on the given address, over the state shared with the HTTP API
+            pub async fn serve(addr: SocketAddr, state: AppState) -> Result<(), tonic::transport::Error> {
+                tonic::transport::Server::builder()
+                    .add_service(#server::new(GrpcApi { state }))
+                    .serve(addr)
+                    .await
+            }
+
+            /// gRPC service delegating to the API's command and query handlers
+            pub struct GrpcApi {
+                pub state: AppState,
+            }
+
+            #[tonic::async_trait]
+            impl #service for GrpcApi {
+                #(#methods)*
+            }
+
+            impl From<ApiError> for Status {
+                fn from(error: ApiError) -> Self {
+                    let code = match error.code.as_str() {
+                        "VALIDATION_ERROR" => tonic::Code::InvalidArgument,
+                        "NOT_FOUND" => tonic::Code::NotFound,
+                        "PRECONDITION_FAILED" => tonic::Code::FailedPrecondition,
+                        _ => tonic::Code::Internal,
+                    };
+                    Status::new(code, error.message)
+                }
+            }
+
+            /// Conversion of a decoded protobuf value into a domain value
+            pub trait FromProto<T>: Sized {
+                fn from_proto(value: T) -> Result<Self, Status>;
+            }
+
+            /// Value of a message field the domain type requires
+            fn required<T>(value: Option<T>, field: &str) -> Result<T, Status> {
+                value.ok_or_else(|| Status::invalid_argument(format!("Missing field: {}", field)))
+            }
+
+            fn invalid(field: &str, error: impl std::fmt::Display) -> Status {
+                Status::invalid_argument(format!("Invalid {}: {}", field, error))
+            }
+
+            impl FromProto<String> for String {
+                fn from_proto(value: String) -> Result<Self, Status> {
+                    Ok(value)
+                }
+            }
+
+            impl FromProto<i64> for i64 {
+                fn from_proto(value: i64) -> Result<Self, Status> {
+                    Ok(value)
+                }
+            }
+
+            impl FromProto<bool> for bool {
+                fn from_proto(value: bool) -> Result<Self, Status> {
+                    Ok(value)
+                }
+            }
+
+            /// Decimals are strings to keep their precision
+            impl FromProto<String> for Decimal {
+                fn from_proto(value: String) -> Result<Self, Status> {
+                    value.parse().map_err(|e| invalid("decimal", e))
+                }
+            }
+
+            impl FromProto<String> for Uuid {
+                fn from_proto(value: String) -> Result<Self, Status> {
+                    value.parse().map_err(|e| invalid("UUID", e))
+                }
+            }
+
+            /// Dates are `YYYY-MM-DD` strings
+            impl FromProto<String> for NaiveDate {
+                fn from_proto(value: String) -> Result<Self, Status> {
+                    value.parse().map_err(|e| invalid("date", e))
+                }
+            }
+
+            impl FromProto<String> for NaiveTime {
+                fn from_proto(value: String) -> Result<Self, Status> {
+                    value.parse().map_err(|e| invalid("time", e))
+                }
+            }
+
+            impl FromProto<prost_types::Timestamp> for DateTime<Utc> {
+                fn from_proto(value: prost_types::Timestamp) -> Result<Self, Status> {
+                    u32::try_from(value.nanos)
+                        .ok()
+                        .and_then(|nanos| DateTime::from_timestamp(value.seconds, nanos))
+                        .ok_or_else(|| invalid("timestamp", format!("{:?}", value)))
+                }
+            }
+
+            impl<T: FromProto<P>, P> FromProto<Vec<P>> for Vec<T> {
+                fn from_proto(value: Vec<P>) -> Result<Self, Status> {
+                    value.into_iter().map(T::from_proto).collect()
+                }
+            }
+
+            impl<T: FromProto<P>, P> FromProto<Option<P>> for Option<T> {
+                fn from_proto(value: Option<P>) -> Result<Self, Status> {
+                    value.map(T::from_proto).transpose()
+                }
+            }
+
+            #(#conversions)*
+        })
+    }
+
+    fn value_object_conversion(
+        model: &IRModel,
+        name: &str,
+        vo: &ValueObject,
+    ) -> Result<TokenStream> {
+        let domain = ident(name)?;
+        let message = format_ident!("{}", to_pascal_case(name));
+        match vo.vo_type.as_deref() {
+            Some("enum") => {
+                let variants = vo
+                    .values
+                    .iter()
+                    .map(|value| ident(&to_pascal_case(&value.name)))
+                    .collect::<Result<Vec<_>>>()?;
+                let invalid = format!("Invalid {}: {{}}", name);
+                Ok(quote! {
+                    impl FromProto<i32> for #domain {
+                        fn from_proto(value: i32) -> Result<Self, Status> {
+                            match proto::#message::try_from(value) {
+                                #(Ok(proto::#message::#variants) => Ok(Self::#variants),)*
+                                _ => Err(Status::invalid_argument(format!(#invalid, value))),
+                            }
+                        }
+                    }
+                })
+            }
+            Some("identifier") => Ok(quote! {
+                impl FromProto<proto::#message> for #domain {
+                    fn from_proto(value: proto::#message) -> Result<Self, Status> {
+                        Ok(Self(FromProto::from_proto(value.value)?))
+                    }
+                }
+            }),
+            _ => Self::struct_conversion(model, &domain, &message, &vo.properties),
+        }
+    }
+
+    fn struct_conversion(
+        model: &IRModel,
+        domain: &proc_macro2::Ident,
+        message: &proc_macro2::Ident,
+        properties: &[Property],
+    ) -> Result<TokenStream> {
+        let value = if properties.is_empty() {
+            format_ident!("_value")
+        } else {
+            format_ident!("value")
+        };
+        let fields = properties
+            .iter()
+            .map(|prop| {
+                let field = snake_ident(&prop.name)?;
+                let field_name = to_snake_case(&prop.name);
+                let proto_type = proto_type(model, &prop.prop_type)?;
+                let nullable = is_nullable(prop);
+                Ok(if proto_type.repeated && nullable {
+                    // An empty repeated field decodes as an empty list
+                    quote! { #field: Some(FromProto::from_proto(value.#field)?) }
+                } else if proto_type.message && !proto_type.repeated && !nullable {
+                    quote! { #field: FromProto::from_proto(required(value.#field, #field_name)?)? }
+                } else {
+                    quote! { #field: FromProto::from_proto(value.#field)? }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            impl FromProto<proto::#message> for #domain {
+                fn from_proto(#value: proto::#message) -> Result<Self, Status> {
+                    Ok(Self {
+                        #(#fields,)*
+                    })
+                }
+            }
+        })
+    }
+}
+
+/// Whether the Rust field of a property is an `Option`
+fn is_nullable(prop: &Property) -> bool {
+    !prop.required || prop.prop_type.trim().starts_with("Option<")
+}
+
+/// Enum value name prefixed with the enum name, as proto enum values share
+/// the package scope
+fn enum_value_name(enum_name: &str, value: &str) -> String {
+    format!(
+        "{}_{}",
+        enum_name.to_shouty_snake_case(),
+        value.to_shouty_snake_case()
+    )
+}
+
+fn proto_type(model: &IRModel, ir_type: &str) -> Result<ProtoType> {
+    let ir_type = ir_type.trim();
+    for prefix in ["List<", "Vec<"] {
+        if let Some(rest) = ir_type.strip_prefix(prefix) {
+            let item = proto_type(model, rest.strip_suffix('>').unwrap_or(rest))?;
+            if item.repeated {
+                anyhow::bail!("Nested lists cannot be mapped to protobuf: {}", ir_type);
+            }
+            return Ok(ProtoType {
+                repeated: true,
+                ..item
+            });
+        }
+    }
+    if let Some(rest) = ir_type.strip_prefix("Option<") {
+        return proto_type(model, rest.strip_suffix('>').unwrap_or(rest));
+    }
+    if ir_type.starts_with("PagedResult<") {
+        anyhow::bail!(
+            "Paged results cannot be mapped to protobuf fields: {}",
+            ir_type
+        );
+    }
+
+    let scalar = |name: &str| ProtoType {
+        name: name.to_string(),
+        repeated: false,
+        message: false,
+    };
+    Ok(match ir_type {
+        "Integer" => scalar("int64"),
+        "Boolean" => scalar("bool"),
+        // Decimals, dates and times are strings like in the JSON API
+        "String" | "Decimal" | "Date" | "Time" | "Uuid" => scalar("string"),
+        "DateTime" => ProtoType {
+            name: "google.protobuf.Timestamp".to_string(),
+            repeated: false,
+            message: true,
+        },
+        _ => {
+            let name = to_rust_type(ir_type);
+            match model.value_objects.get(&name) {
+                Some(vo) if vo.vo_type.as_deref() == Some("enum") => scalar(&name),
+                Some(_) => ProtoType {
+                    name,
+                    repeated: false,
+                    message: true,
+                },
+                None if model.aggregates.contains_key(&name) => ProtoType {
+                    name,
+                    repeated: false,
+                    message: true,
+                },
+                // Types the IR does not define are not part of the API contract
+                None => scalar("string"),
+            }
+        }
+    })
+}
+
+/// `//` comment lines at the given indentation
+fn comment(text: Option<&str>, indent: &str) -> String {
+    let Some(text) = text else {
+        return String::new();
+    };
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                format!("{}//\n", indent)
+            } else {
+                format!("{}// {}\n", indent, line)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn model(order_fields: &str) -> IRModel {
+        IRParser::parse_yaml(&format!(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+value_objects:
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "Paid"
+commands:
+  PlaceOrder:
+    name: "PlaceOrder"
+    payload:
+{}
+"#,
+            order_fields
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_generate_proto_maps_types() {
+        let proto = ProtoGenerator::generate_proto(
+            &model(
+                r#"      - name: "status"
+        type: "OrderStatus"
+        required: false
+      - name: "placedAt"
+        type: "DateTime""#,
+            ),
+            None,
+        )
+        .unwrap();
+
+        assert!(proto.contains("package acme.order;"));
+        assert!(proto.contains("  rpc PlaceOrder(PlaceOrderRequest) returns (PlaceOrderResponse);"));
+        assert!(proto.contains("  ORDER_STATUS_UNSPECIFIED = 0;\n  ORDER_STATUS_PAID = 1;\n"));
+        assert!(proto.contains(
+            "message PlaceOrderRequest {\n  optional OrderStatus status = 1;\n  google.protobuf.Timestamp placed_at = 2;\n}"
+        ));
+    }
+
+    #[test]
+    fn test_field_numbers_are_stable_across_regenerations() {
+        let first = ProtoGenerator::generate_proto(
+            &model(
+                r#"      - name: "note"
+        type: "String"
+      - name: "quantity"
+        type: "Integer""#,
+            ),
+            None,
+        )
+        .unwrap();
+
+        // `note` is removed, `quantity` changes type and `customer` is added
+        let second = ProtoGenerator::generate_proto(
+            &model(
+                r#"      - name: "customer"
+        type: "String"
+      - name: "quantity"
+        type: "String""#,
+            ),
+            Some(&first),
+        )
+        .unwrap();
+        assert!(second.contains(
+            "message PlaceOrderRequest {\n  string customer = 3;\n  string quantity = 4;\n  reserved 1;\n  reserved \"note\";\n  reserved 2;\n}"
+        ));
+
+        // Regenerating without changes keeps the file as it is
+        let third = ProtoGenerator::generate_proto(
+            &model(
+                r#"      - name: "customer"
+        type: "String"
+      - name: "quantity"
+        type: "String""#,
+            ),
+            Some(&second),
+        )
+        .unwrap();
+        assert_eq!(second, third);
+    }
+}
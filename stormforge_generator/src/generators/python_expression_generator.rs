@@ -0,0 +1,843 @@
+use crate::generators::python_generator::{py_inline, py_str, KEYWORDS, LINE_LENGTH};
+use crate::generators::utils::*;
+use crate::ir::{BinaryOp, Expr, UnaryOp};
+use anyhow::{bail, Result};
+
+/// Translates IR expressions to Python over JSON values
+///
+/// A translated expression reads its variables from a `scope` dict in scope
+/// at the point of use, and calls the `app.domain.expressions` runtime,
+/// which must be imported as `expressions`. Property names are snake-cased
+/// to match the JSON keys of the generated models, and enum values such as
+/// `PAID` become the serialized member values, as in the Rust service.
+pub struct PythonExpressionGenerator;
+
+/// Check of an IR rule in generated code
+pub struct Guard {
+    /// `if` statement rejecting what breaks the rule, or a `# TODO` hook
+    pub code: String,
+    /// Whether the rule is checked rather than left to the hook
+    pub enforced: bool,
+}
+
+/// Python expression, wrapped like black when longer than a line
+pub enum PyExpr {
+    /// Name or literal, never wrapped
+    Atom(String),
+    /// Text before an expression, as in `not x` or `lambda item: x`
+    Prefix(String, Box<PyExpr>),
+    /// Items between brackets, as in a call or a list
+    Group(String, Vec<PyExpr>, &'static str),
+    /// Operands of `and` or `or`, parenthesized
+    Chain(&'static str, Vec<PyExpr>),
+    /// Item of a group with a comment line above it, such as a `# TODO`
+    /// hook, which keeps the group from fitting on one line
+    Commented(String, Box<PyExpr>),
+}
+
+impl PyExpr {
+    pub fn call(function: &str, args: Vec<PyExpr>) -> Self {
+        PyExpr::Group(format!("expressions.{}(", function), args, ")")
+    }
+
+    fn not(self) -> Self {
+        PyExpr::Prefix("not ".to_string(), Box::new(self))
+    }
+
+    /// Expression on one line
+    pub fn flat(&self) -> String {
+        match self {
+            PyExpr::Atom(text) => text.clone(),
+            PyExpr::Prefix(prefix, value) => format!("{}{}", prefix, value.flat()),
+            PyExpr::Group(open, items, close) => format!(
+                "{}{}{}",
+                open,
+                items
+                    .iter()
+                    .map(PyExpr::flat)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                close
+            ),
+            PyExpr::Chain(op, operands) => format!(
+                "({})",
+                operands
+                    .iter()
+                    .map(PyExpr::flat)
+                    .collect::<Vec<_>>()
+                    .join(&format!(" {} ", op))
+            ),
+            PyExpr::Commented(comment, value) => format!("# {}\n{}", comment, value.flat()),
+        }
+    }
+
+    /// Expression starting at `column` of a line indented by `indent` and
+    /// followed by `suffix` more characters, with the lines it wraps onto
+    /// indented from `indent`
+    pub fn render(&self, indent: usize, column: usize, suffix: usize) -> String {
+        let flat = self.flat();
+        if !flat.contains('\n') && column + flat.len() + suffix <= LINE_LENGTH {
+            return flat;
+        }
+        let inner = indent + 4;
+        let pad = " ".repeat(inner);
+        let close_pad = " ".repeat(indent);
+        match self {
+            PyExpr::Atom(_) => flat,
+            PyExpr::Prefix(prefix, value) => format!(
+                "{}{}",
+                prefix,
+                value.render(indent, column + prefix.len(), suffix)
+            ),
+            PyExpr::Group(_, items, _) if items.is_empty() => flat,
+            PyExpr::Group(open, items, close) => {
+                let joined = items
+                    .iter()
+                    .map(PyExpr::flat)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                if !joined.contains('\n') && inner + joined.len() <= LINE_LENGTH {
+                    return format!("{open}\n{pad}{joined}\n{close_pad}{close}");
+                }
+                let mut out = format!("{}\n", open);
+                for item in items {
+                    out.push_str(&format!("{}{},\n", pad, item.render(inner, inner, 1)));
+                }
+                out.push_str(&format!("{}{}", close_pad, close));
+                out
+            }
+            PyExpr::Chain(op, operands) => {
+                let mut out = "(\n".to_string();
+                for (index, operand) in operands.iter().enumerate() {
+                    let op = if index == 0 {
+                        String::new()
+                    } else {
+                        format!("{} ", op)
+                    };
+                    out.push_str(&format!(
+                        "{}{}{}\n",
+                        pad,
+                        op,
+                        operand.render(inner, inner + op.len(), 0)
+                    ));
+                }
+                out.push_str(&format!("{})", close_pad));
+                out
+            }
+            PyExpr::Commented(comment, value) => format!(
+                "# {}\n{}{}",
+                comment,
+                close_pad,
+                value.render(indent, indent, suffix)
+            ),
+        }
+    }
+}
+
+impl PythonExpressionGenerator {
+    /// Python `bool` expression checking a condition
+    pub fn condition(expression: &str) -> Result<PyExpr> {
+        let expr = Expr::parse(expression)?;
+        Translation::new(expression).condition(&expr)
+    }
+
+    /// Python expression computing a JSON value
+    pub fn value(expression: &str) -> Result<PyExpr> {
+        let expr = Expr::parse(expression)?;
+        Translation::new(expression).value(&expr)
+    }
+
+    /// Statement at `indent` running `reject`, a statement indented one
+    /// level further, unless a condition holds
+    ///
+    /// A condition the translator cannot handle is reported as a warning about
+    /// `rule` and left as an unenforced `# TODO` hook, as in the Rust service.
+    pub fn guard(expression: &str, rule: &str, reject: &str, indent: usize) -> Guard {
+        let pad = " ".repeat(indent);
+        match Self::condition(expression) {
+            Ok(check) => {
+                let check = match check {
+                    PyExpr::Prefix(prefix, value) if prefix == "not " => *value,
+                    check => check.not(),
+                };
+                Guard {
+                    code: format!(
+                        "{pad}if {}:\n{reject}\n",
+                        check.render(indent, indent + 3, 1)
+                    ),
+                    enforced: true,
+                }
+            }
+            Err(error) => {
+                eprintln!("⚠️  {} is not enforced: {:#}", rule, error);
+                Guard {
+                    code: format!("{}# TODO: enforce `{}`\n", pad, expression),
+                    enforced: false,
+                }
+            }
+        }
+    }
+
+    /// Generate the expression runtime, independent of the model
+    pub fn generate_runtime() -> String {
+        RUNTIME.to_string()
+    }
+
+    /// Generate the tests of the expression runtime
+    pub fn generate_runtime_tests() -> String {
+        RUNTIME_TESTS.to_string()
+    }
+}
+
+/// Translation of one parsed expression, kept for error messages, with the
+/// lambda parameters bound at the current point
+struct Translation<'a> {
+    expression: &'a str,
+    bound: Vec<String>,
+}
+
+impl<'a> Translation<'a> {
+    fn new(expression: &'a str) -> Self {
+        Self {
+            expression,
+            bound: Vec::new(),
+        }
+    }
+
+    /// `bool` expression, without going through `truthy` where possible
+    fn condition(&mut self, expr: &Expr) -> Result<PyExpr> {
+        Ok(match expr {
+            Expr::Literal(serde_json::Value::Bool(value)) => {
+                PyExpr::Atom(if *value { "True" } else { "False" }.to_string())
+            }
+            Expr::Unary(UnaryOp::Not, value) => self.condition(value)?.not(),
+            Expr::Binary(op @ (BinaryOp::And | BinaryOp::Or), left, right) => {
+                let op = if *op == BinaryOp::And { "and" } else { "or" };
+                let mut operands = Vec::new();
+                for side in [left, right] {
+                    match self.condition(side)? {
+                        PyExpr::Chain(inner, nested) if inner == op => operands.extend(nested),
+                        operand => operands.push(operand),
+                    }
+                }
+                PyExpr::Chain(op, operands)
+            }
+            Expr::Binary(op, left, right) if Self::comparison(*op).is_some() => {
+                let function = Self::comparison(*op).unwrap_or_default();
+                PyExpr::call(function, vec![self.value(left)?, self.value(right)?])
+            }
+            Expr::Binary(BinaryOp::Ne, left, right) => {
+                PyExpr::call("eq", vec![self.value(left)?, self.value(right)?]).not()
+            }
+            Expr::Binary(BinaryOp::In, item, collection) => {
+                PyExpr::call("contains", vec![self.value(collection)?, self.value(item)?])
+            }
+            Expr::Method(receiver, method, args)
+                if matches!(
+                    method.as_str(),
+                    "all" | "any" | "none" | "contains" | "includes"
+                ) =>
+            {
+                let receiver = self.value(receiver)?;
+                match (method.as_str(), args.as_slice()) {
+                    ("contains" | "includes", [item]) => {
+                        PyExpr::call("contains", vec![receiver, self.value(item)?])
+                    }
+                    ("all", [lambda]) => {
+                        PyExpr::call("all_", vec![receiver, self.predicate(lambda)?])
+                    }
+                    ("any", [lambda]) => {
+                        PyExpr::call("any_", vec![receiver, self.predicate(lambda)?])
+                    }
+                    ("none", [lambda]) => {
+                        PyExpr::call("any_", vec![receiver, self.predicate(lambda)?]).not()
+                    }
+                    _ => return self.arity(method),
+                }
+            }
+            _ => PyExpr::call("truthy", vec![self.value(expr)?]),
+        })
+    }
+
+    fn comparison(op: BinaryOp) -> Option<&'static str> {
+        match op {
+            BinaryOp::Eq => Some("eq"),
+            BinaryOp::Lt => Some("lt"),
+            BinaryOp::Le => Some("le"),
+            BinaryOp::Gt => Some("gt"),
+            BinaryOp::Ge => Some("ge"),
+            _ => None,
+        }
+    }
+
+    /// JSON value, which a condition already is in Python
+    fn value(&mut self, expr: &Expr) -> Result<PyExpr> {
+        Ok(match expr {
+            Expr::Literal(serde_json::Value::Bool(_)) => self.condition(expr)?,
+            Expr::Literal(value) => PyExpr::Atom(py_inline(value)),
+            Expr::Constant(name) => PyExpr::Atom(py_str(&to_pascal_case(name))),
+            Expr::Name(name) => match self.bound.iter().rev().find(|param| *param == name) {
+                Some(param) => PyExpr::Atom(parameter(param)),
+                None => PyExpr::call(
+                    "get",
+                    vec![
+                        PyExpr::Atom("scope".to_string()),
+                        PyExpr::Atom(py_str(&to_snake_case(name))),
+                    ],
+                ),
+            },
+            Expr::Member(value, property) => {
+                let value = self.value(value)?;
+                if property == "length" {
+                    PyExpr::call("len_", vec![value])
+                } else {
+                    let property = PyExpr::Atom(py_str(&to_snake_case(property)));
+                    PyExpr::call("get", vec![value, property])
+                }
+            }
+            Expr::Index(value, index) => {
+                PyExpr::call("index", vec![self.value(value)?, self.value(index)?])
+            }
+            Expr::Call(function, args) => self.call(function, args)?,
+            Expr::Method(receiver, method, args) => self.method(receiver, method, args)?,
+            Expr::Lambda(..) => bail!(
+                "A lambda is only allowed as a method argument in expression `{}`",
+                self.expression
+            ),
+            Expr::Unary(UnaryOp::Neg, value) => PyExpr::call("neg", vec![self.value(value)?]),
+            Expr::Binary(op, left, right) => {
+                let function = match op {
+                    BinaryOp::Add => "add",
+                    BinaryOp::Sub => "sub",
+                    BinaryOp::Mul => "mul",
+                    BinaryOp::Div => "div",
+                    BinaryOp::Rem => "rem",
+                    _ => return self.condition(expr),
+                };
+                PyExpr::call(function, vec![self.value(left)?, self.value(right)?])
+            }
+            Expr::Unary(UnaryOp::Not, _) => self.condition(expr)?,
+            Expr::List(items) => PyExpr::Group("[".to_string(), self.values(items)?, "]"),
+            Expr::Object(fields) => {
+                let mut items = Vec::new();
+                for (key, value) in fields {
+                    let key = format!("{}: ", py_str(&to_snake_case(key)));
+                    items.push(PyExpr::Prefix(key, Box::new(self.value(value)?)));
+                }
+                PyExpr::Group("{".to_string(), items, "}")
+            }
+        })
+    }
+
+    fn values(&mut self, exprs: &[Expr]) -> Result<Vec<PyExpr>> {
+        exprs.iter().map(|expr| self.value(expr)).collect()
+    }
+
+    fn call(&mut self, function: &str, args: &[Expr]) -> Result<PyExpr> {
+        let values = self.values(args)?;
+        Ok(match (function, values.len()) {
+            ("sum", 1) => PyExpr::call("sum_", values),
+            ("count", 1) => PyExpr::call("len_", values),
+            ("min", 1) => PyExpr::call("min_", values),
+            ("max", 1) => PyExpr::call("max_", values),
+            ("min" | "max", count) if count > 1 => {
+                let list = PyExpr::Group("[".to_string(), values, "]");
+                PyExpr::call(&format!("{}_", function), vec![list])
+            }
+            ("today", 0) => PyExpr::call("today", values),
+            ("now", 0) => PyExpr::call("now", values),
+            ("daysBetween", 2) => PyExpr::call("days_between", values),
+            ("sum" | "count" | "min" | "max" | "today" | "now" | "daysBetween", _) => {
+                return self.arity(function)
+            }
+            _ => bail!(
+                "Unsupported function `{}` in expression `{}`",
+                function,
+                self.expression
+            ),
+        })
+    }
+
+    fn method(&mut self, receiver: &Expr, method: &str, args: &[Expr]) -> Result<PyExpr> {
+        if matches!(method, "all" | "any" | "none" | "contains" | "includes") {
+            return self.condition(&Expr::Method(
+                Box::new(receiver.clone()),
+                method.to_string(),
+                args.to_vec(),
+            ));
+        }
+        let list = self.value(receiver)?;
+        Ok(match (method, args) {
+            ("filter", [lambda]) => PyExpr::call("filter_", vec![list, self.predicate(lambda)?]),
+            ("map", [lambda]) => PyExpr::call("map_", vec![list, self.transform(lambda)?]),
+            ("count", []) => PyExpr::call("len_", vec![list]),
+            ("count", [lambda]) => {
+                let matching = PyExpr::call("filter_", vec![list, self.predicate(lambda)?]);
+                PyExpr::call("len_", vec![matching])
+            }
+            ("sum", []) => PyExpr::call("sum_", vec![list]),
+            ("sum", [lambda]) => {
+                let values = PyExpr::call("map_", vec![list, self.transform(lambda)?]);
+                PyExpr::call("sum_", vec![values])
+            }
+            ("isEmpty", []) => PyExpr::call("truthy", vec![list]).not(),
+            ("filter" | "map" | "count" | "sum" | "isEmpty", _) => return self.arity(method),
+            _ => bail!(
+                "Unsupported method `{}` in expression `{}`",
+                method,
+                self.expression
+            ),
+        })
+    }
+
+    fn predicate(&mut self, lambda: &Expr) -> Result<PyExpr> {
+        self.lambda(lambda, Self::condition)
+    }
+
+    fn transform(&mut self, lambda: &Expr) -> Result<PyExpr> {
+        self.lambda(lambda, Self::value)
+    }
+
+    /// Python lambda whose parameter the body reads directly
+    fn lambda(
+        &mut self,
+        lambda: &Expr,
+        body: fn(&mut Self, &Expr) -> Result<PyExpr>,
+    ) -> Result<PyExpr> {
+        let Expr::Lambda(param, value) = lambda else {
+            bail!(
+                "Expected a lambda such as `item => item.price` in expression `{}`",
+                self.expression
+            );
+        };
+        self.bound.push(param.clone());
+        let value = body(self, value);
+        self.bound.pop();
+        Ok(PyExpr::Prefix(
+            format!("lambda {}: ", parameter(param)),
+            Box::new(value?),
+        ))
+    }
+
+    fn arity<T>(&self, function: &str) -> Result<T> {
+        bail!(
+            "Wrong number of arguments to `{}` in expression `{}`",
+            function,
+            self.expression
+        )
+    }
+}
+
+/// Python name of a lambda parameter, clear of keywords and the names the
+/// translated expression uses
+fn parameter(name: &str) -> String {
+    let name = to_snake_case(name);
+    if KEYWORDS.contains(&name.as_str()) || name == "scope" || name == "expressions" {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+const RUNTIME: &str = r#""""Runtime of the translated IR expressions
+
+Invariants, preconditions, validation rules and computed event fields are
+translated to Python over JSON values, so they read commands, events and
+aggregate state alike. A missing property reads as `None`, which is false in
+conditions and neither equal to, less nor greater than another value.
+Numbers, decimals (serialized as strings) and dates compare by value.
+Functions named like a builtin end with `_`.
+"""
+
+import json
+import operator
+from collections.abc import Callable
+from datetime import datetime, timedelta, timezone
+from decimal import Decimal, InvalidOperation
+from typing import Any
+
+
+def get(value: Any, key: str) -> Any:
+    """Property of an object; on a list, the property of each element"""
+    if isinstance(value, dict):
+        return value.get(key)
+    if isinstance(value, list):
+        return [get(item, key) for item in value]
+    return None
+
+
+def index(value: Any, position: Any) -> Any:
+    """Element of a list or property of an object"""
+    if isinstance(value, list) and _integer(position):
+        return value[position] if 0 <= position < len(value) else None
+    if isinstance(value, dict) and isinstance(position, str):
+        return value.get(position)
+    return None
+
+
+def len_(value: Any) -> Any:
+    """Number of elements of a list, characters of a string or properties of an object"""
+    if isinstance(value, (list, str, dict)):
+        return len(value)
+    return None
+
+
+def truthy(value: Any) -> bool:
+    """Whether a value holds in a condition"""
+    if isinstance(value, dict):
+        return True
+    return bool(value)
+
+
+def _integer(value: Any) -> bool:
+    return isinstance(value, int) and not isinstance(value, bool)
+
+
+def _decimal(value: Any) -> Decimal | None:
+    if isinstance(value, bool) or not isinstance(value, (int, float, str)):
+        return None
+    try:
+        number = Decimal(str(value))
+    except InvalidOperation:
+        return None
+    return number if number.is_finite() else None
+
+
+def _datetime(value: Any) -> datetime | None:
+    if not isinstance(value, str):
+        return None
+    try:
+        parsed = datetime.fromisoformat(value.replace("Z", "+00:00"))
+    except ValueError:
+        return None
+    if parsed.tzinfo is None:
+        return parsed.replace(tzinfo=timezone.utc)
+    return parsed.astimezone(timezone.utc)
+
+
+def _number(value: Decimal, operands: tuple[Any, Any]) -> Any:
+    """Number with the representation of its operands: decimals are
+    serialized as strings, integers and floats as numbers"""
+    if any(isinstance(operand, str) for operand in operands):
+        return format(value.normalize(), "f")
+    if value == value.to_integral_value():
+        return int(value)
+    return float(value)
+
+
+def _arithmetic(
+    left: Any, right: Any, op: Callable[[Decimal, Decimal], Decimal]
+) -> Any:
+    """Applies an operator to numbers, and to the numeric properties of
+    objects such as amounts of money"""
+    if isinstance(left, dict) and isinstance(right, dict):
+        return _fieldwise(
+            left,
+            lambda key, value: (
+                _arithmetic(value, right[key], op) if key in right else value
+            ),
+        )
+    if isinstance(left, dict):
+        return _fieldwise(left, lambda _, value: _arithmetic(value, right, op))
+    if isinstance(right, dict):
+        return _fieldwise(right, lambda _, value: _arithmetic(left, value, op))
+    lhs, rhs = _decimal(left), _decimal(right)
+    if lhs is None or rhs is None:
+        return None
+    try:
+        return _number(op(lhs, rhs), (left, right))
+    except ArithmeticError:
+        return None
+
+
+def _fieldwise(object_: dict, apply: Callable[[str, Any], Any]) -> dict:
+    """Object with its numeric properties transformed"""
+    return {
+        key: apply(key, value) if _decimal(value) is not None else value
+        for key, value in object_.items()
+    }
+
+
+def _text(value: Any) -> str:
+    if isinstance(value, str):
+        return value
+    if value is None:
+        return ""
+    return json.dumps(value)
+
+
+def add(left: Any, right: Any) -> Any:
+    """Sum of numbers, or concatenation when a string is not a number"""
+    if isinstance(left, str) or isinstance(right, str):
+        if _decimal(left) is None or _decimal(right) is None:
+            return _text(left) + _text(right)
+    return _arithmetic(left, right, operator.add)
+
+
+def sub(left: Any, right: Any) -> Any:
+    return _arithmetic(left, right, operator.sub)
+
+
+def mul(left: Any, right: Any) -> Any:
+    return _arithmetic(left, right, operator.mul)
+
+
+def div(left: Any, right: Any) -> Any:
+    return _arithmetic(left, right, operator.truediv)
+
+
+def rem(left: Any, right: Any) -> Any:
+    return _arithmetic(left, right, operator.mod)
+
+
+def neg(value: Any) -> Any:
+    return sub(0, value)
+
+
+def eq(left: Any, right: Any) -> bool:
+    """Equality of values, comparing numbers and decimals by value"""
+    if isinstance(left, list) and isinstance(right, list):
+        return len(left) == len(right) and all(map(eq, left, right))
+    if isinstance(left, dict) and isinstance(right, dict):
+        return len(left) == len(right) and all(
+            key in right and eq(value, right[key]) for key, value in left.items()
+        )
+    lhs, rhs = _decimal(left), _decimal(right)
+    if lhs is not None and rhs is not None:
+        return lhs == rhs
+    if isinstance(left, bool) != isinstance(right, bool):
+        return False
+    return left == right
+
+
+def _order(left: Any, right: Any) -> int:
+    return (left > right) - (left < right)
+
+
+def compare(left: Any, right: Any) -> int | None:
+    """Order of numbers, dates and strings, and of objects differing in one
+    numeric property such as amounts of money in one currency: -1, 0 or 1,
+    or `None` when the values do not compare"""
+    lhs, rhs = _decimal(left), _decimal(right)
+    if lhs is not None and rhs is not None:
+        return _order(lhs, rhs)
+    start, end = _datetime(left), _datetime(right)
+    if start is not None and end is not None:
+        return _order(start, end)
+    if isinstance(left, str) and isinstance(right, str):
+        return _order(left, right)
+    if isinstance(left, dict) and isinstance(right, dict) and len(left) == len(right):
+        order = None
+        for key, value in left.items():
+            if key not in right:
+                return None
+            other = right[key]
+            lhs, rhs = _decimal(value), _decimal(other)
+            if lhs is not None and rhs is not None and order is None:
+                order = _order(lhs, rhs)
+            elif not eq(value, other):
+                return None
+        return order
+    return None
+
+
+def lt(left: Any, right: Any) -> bool:
+    return compare(left, right) == -1
+
+
+def le(left: Any, right: Any) -> bool:
+    return compare(left, right) in (-1, 0)
+
+
+def gt(left: Any, right: Any) -> bool:
+    return compare(left, right) == 1
+
+
+def ge(left: Any, right: Any) -> bool:
+    return compare(left, right) in (1, 0)
+
+
+def contains(collection: Any, item: Any) -> bool:
+    """Whether a list holds an element, a string a substring or an object a property"""
+    if isinstance(collection, list):
+        return any(eq(element, item) for element in collection)
+    if isinstance(collection, (str, dict)) and isinstance(item, str):
+        return item in collection
+    return False
+
+
+def all_(items: Any, predicate: Callable[[Any], bool]) -> bool:
+    """Whether every element of a list matches; false when the value is not a list"""
+    return isinstance(items, list) and all(predicate(item) for item in items)
+
+
+def any_(items: Any, predicate: Callable[[Any], bool]) -> bool:
+    """Whether some element of a list matches"""
+    return isinstance(items, list) and any(predicate(item) for item in items)
+
+
+def filter_(items: Any, predicate: Callable[[Any], bool]) -> Any:
+    """Elements of a list that match"""
+    if not isinstance(items, list):
+        return None
+    return [item for item in items if predicate(item)]
+
+
+def map_(items: Any, transform: Callable[[Any], Any]) -> Any:
+    """Elements of a list transformed one by one"""
+    if not isinstance(items, list):
+        return None
+    return [transform(item) for item in items]
+
+
+def sum_(items: Any) -> Any:
+    """Sum of the elements of a list, property by property for objects"""
+    if not isinstance(items, list):
+        return None
+    if not items:
+        return 0
+    total = items[0]
+    for item in items[1:]:
+        total = add(total, item)
+    return total
+
+
+def min_(items: Any) -> Any:
+    """Smallest element of a list"""
+    return _extreme(items, -1)
+
+
+def max_(items: Any) -> Any:
+    """Largest element of a list"""
+    return _extreme(items, 1)
+
+
+def _extreme(items: Any, wanted: int) -> Any:
+    if not isinstance(items, list) or not items:
+        return None
+    best = items[0]
+    for item in items[1:]:
+        if compare(item, best) == wanted:
+            best = item
+    return best
+
+
+def today() -> str:
+    """Current date"""
+    return datetime.now(timezone.utc).date().isoformat()
+
+
+def now() -> str:
+    """Current time"""
+    return datetime.now(timezone.utc).isoformat().replace("+00:00", "Z")
+
+
+def days_between(start: Any, end: Any) -> Any:
+    """Whole days from one date to another"""
+    first, last = _datetime(start), _datetime(end)
+    if first is None or last is None:
+        return None
+    return int((last - first) / timedelta(days=1))
+"#;
+
+const RUNTIME_TESTS: &str = r#""""Tests of the expression runtime"""
+
+from app.domain import expressions
+
+
+def test_paths_read_missing_properties_as_none() -> None:
+    order = {"items": [{"qty": 1}, {"qty": 2}]}
+    assert len(expressions.get(order, "items")) == 2
+    assert expressions.get(expressions.get(order, "items"), "qty") == [1, 2]
+    assert expressions.get(order, "status") is None
+    assert not expressions.truthy(expressions.get(order, "status"))
+    assert not expressions.all_(expressions.get(order, "lines"), expressions.truthy)
+
+
+def test_numbers_compare_by_value() -> None:
+    assert expressions.eq("12.50", 12.5)
+    assert expressions.ge(3, "2.99")
+    assert not expressions.lt(None, 1)
+    assert not expressions.ge(None, 1)
+    assert not expressions.eq(True, 1)
+    assert expressions.lt(
+        {"amount": "5", "currency": "EUR"}, {"amount": "7.5", "currency": "EUR"}
+    )
+    assert (
+        expressions.compare(
+            {"amount": "5", "currency": "EUR"}, {"amount": "5", "currency": "USD"}
+        )
+        is None
+    )
+
+
+def test_sums_keep_the_shape_of_their_operands() -> None:
+    subtotals = [
+        {"amount": "10.25", "currency": "EUR"},
+        {"amount": "4.75", "currency": "EUR"},
+    ]
+    assert expressions.sum_(subtotals) == {"amount": "15", "currency": "EUR"}
+    assert expressions.sum_([1, 2, 3]) == 6
+    assert expressions.mul(3, {"amount": "2.5"}) == {"amount": "7.5"}
+    assert expressions.add("ORD-", 7) == "ORD-7"
+    assert expressions.div(1, 0) is None
+
+
+def test_dates() -> None:
+    assert expressions.days_between("2024-01-01", "2024-01-31") == 30
+    assert expressions.gt("2024-01-02T00:00:00Z", "2024-01-01")
+    assert expressions.le(expressions.today(), expressions.now())
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translated(expression: &str) -> String {
+        PythonExpressionGenerator::condition(expression)
+            .unwrap()
+            .flat()
+    }
+
+    #[test]
+    fn test_translate_conditions() {
+        assert_eq!(
+            translated("order.status == CREATED"),
+            r#"expressions.eq(expressions.get(expressions.get(scope, "order"), "status"), "Created")"#
+        );
+        assert_eq!(
+            translated("order.items.all(item => item.inStock)"),
+            r#"expressions.all_(expressions.get(expressions.get(scope, "order"), "items"), lambda item: expressions.truthy(expressions.get(item, "in_stock")))"#
+        );
+        assert_eq!(
+            translated("!(a && b) || not c"),
+            r#"(not (expressions.truthy(expressions.get(scope, "a")) and expressions.truthy(expressions.get(scope, "b"))) or not expressions.truthy(expressions.get(scope, "c")))"#
+        );
+        assert!(PythonExpressionGenerator::condition("getOrderTimeline(order.id)").is_err());
+    }
+
+    #[test]
+    fn test_guards_wrap_long_conditions() {
+        let guard = PythonExpressionGenerator::guard(
+            "leaveRequest.status in [PENDING, APPROVED]",
+            "Precondition of command CancelLeave",
+            "            raise ApiError(\"PRECONDITION_FAILED\", \"Leave request cannot be cancelled\")",
+            8,
+        );
+        assert!(guard.enforced);
+        assert_eq!(
+            guard.code,
+            r#"        if not expressions.contains(
+            ["Pending", "Approved"],
+            expressions.get(expressions.get(scope, "leave_request"), "status"),
+        ):
+            raise ApiError("PRECONDITION_FAILED", "Leave request cannot be cancelled")
+"#
+        );
+        assert!(guard.code.lines().all(|line| line.len() <= LINE_LENGTH));
+
+        let hook = PythonExpressionGenerator::guard("isValid(items)", "Rule", "        pass", 4);
+        assert!(!hook.enforced);
+        assert_eq!(hook.code, "    # TODO: enforce `isValid(items)`\n");
+    }
+}
@@ -0,0 +1,2250 @@
+use crate::generators::aggregate_generator::{AggregateGenerator, FieldSource};
+use crate::generators::python_expression_generator::{Guard, PyExpr, PythonExpressionGenerator};
+use crate::generators::utils::*;
+use crate::ir::{Aggregate, Command, Event, Expr, IRModel, Property, Query, ValueObject};
+use crate::mock::Examples;
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs;
+use std::sync::LazyLock;
+
+/// Line length of the generated code, as formatted by black
+pub const LINE_LENGTH: usize = 88;
+
+/// Python keywords, which fields are renamed from with an alias
+pub const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Names the standard library provides to the generated modules, by module
+const STD_NAMES: &[(&str, &[&str])] = &[
+    ("collections.abc", &["Callable", "Iterable"]),
+    ("datetime", &["date", "datetime", "time", "timezone"]),
+    ("decimal", &["Decimal"]),
+    ("enum", &["Enum"]),
+    (
+        "typing",
+        &["Annotated", "Any", "Literal", "NewType", "TypeVar", "Union"],
+    ),
+    ("uuid", &["UUID", "uuid4"]),
+];
+
+static STRINGS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#""""[\s\S]*?"""|"(?:[^"\\\n]|\\.)*""#).unwrap());
+static WORDS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[A-Za-z_][A-Za-z0-9_]*").unwrap());
+
+pub struct PythonGenerator {
+    output_dir: String,
+}
+
+impl PythonGenerator {
+    pub fn new(output_dir: String) -> Self {
+        Self { output_dir }
+    }
+
+    /// Generate a FastAPI service from an IR model
+    pub fn generate(&self, model: &IRModel) -> Result<()> {
+        println!(
+            "🚀 Generating Python service for '{}'...",
+            model.bounded_context.name
+        );
+
+        for dir in [
+            "app/api",
+            "app/application",
+            "app/domain",
+            "app/infrastructure",
+            "tests",
+        ] {
+            let path = format!("{}/{}", self.output_dir, dir);
+            fs::create_dir_all(&path)
+                .with_context(|| format!("Failed to create directory: {}", path))?;
+        }
+
+        let context = &model.bounded_context.name;
+        let mut files = Vec::from([
+            ("pyproject.toml", Self::generate_pyproject(model)),
+            ("README.md", Self::generate_readme(model)),
+            (
+                "app/__init__.py",
+                format!(
+                    "\"\"\"{} service, generated by StormForge Generator\"\"\"\n",
+                    context
+                ),
+            ),
+            ("app/main.py", Self::generate_main(model)),
+            ("app/errors.py", ERRORS.to_string()),
+            ("app/schemas.py", SCHEMAS.to_string()),
+            ("app/dependencies.py", DEPENDENCIES.to_string()),
+            (
+                "app/domain/__init__.py",
+                "\"\"\"Domain model: value objects, aggregates, commands and events\"\"\"\n"
+                    .to_string(),
+            ),
+            (
+                "app/domain/value_objects.py",
+                Self::generate_value_objects(model)?,
+            ),
+            ("app/domain/aggregates.py", Self::generate_aggregates(model)),
+            ("app/domain/commands.py", Self::generate_commands(model)),
+            ("app/domain/events.py", Self::generate_events(model)),
+            (
+                "app/domain/expressions.py",
+                PythonExpressionGenerator::generate_runtime(),
+            ),
+            ("app/domain/state.py", Self::generate_state(model)),
+            (
+                "app/application/__init__.py",
+                "\"\"\"Command and query handlers\"\"\"\n".to_string(),
+            ),
+            (
+                "app/application/command_handlers.py",
+                Self::generate_command_handlers(model),
+            ),
+            (
+                "app/application/query_handlers.py",
+                Self::generate_query_handlers(model),
+            ),
+            (
+                "app/infrastructure/__init__.py",
+                "\"\"\"Event store\"\"\"\n".to_string(),
+            ),
+            ("app/infrastructure/event_store.py", EVENT_STORE.to_string()),
+            (
+                "app/api/__init__.py",
+                "\"\"\"HTTP routes, nested under `/api`\"\"\"\n".to_string(),
+            ),
+            ("app/api/commands.py", Self::generate_command_routes(model)),
+            ("app/api/queries.py", Self::generate_query_routes(model)),
+            ("tests/__init__.py", String::new()),
+            ("tests/conftest.py", CONFTEST.to_string()),
+            (
+                "tests/test_commands.py",
+                Self::generate_command_tests(model),
+            ),
+            ("tests/test_queries.py", Self::generate_query_tests(model)),
+            (
+                "tests/test_expressions.py",
+                PythonExpressionGenerator::generate_runtime_tests(),
+            ),
+        ]);
+        if let Some(tests) = Self::generate_event_store_tests(model) {
+            files.push(("tests/test_event_store.py", tests));
+        }
+        for (file, content) in files {
+            let path = format!("{}/{}", self.output_dir, file);
+            fs::write(&path, content).with_context(|| format!("Failed to write {}", path))?;
+        }
+
+        println!("✅ Generation complete! Output at: {}", self.output_dir);
+
+        Ok(())
+    }
+
+    fn project_name(model: &IRModel) -> String {
+        format!("{}-service", to_kebab_case(&model.bounded_context.name))
+    }
+
+    fn generate_pyproject(model: &IRModel) -> String {
+        format!(
+            r#"[build-system]
+requires = ["setuptools>=68"]
+build-backend = "setuptools.build_meta"
+
+[project]
+name = "{}"
+version = "0.1.0"
+description = "{} bounded context service, generated by StormForge Generator"
+requires-python = ">=3.10"
+dependencies = [
+    "fastapi>=0.110",
+    "pydantic>=2.6",
+    "uvicorn[standard]>=0.29",
+]
+
+[project.optional-dependencies]
+test = [
+    "httpx>=0.27",
+    "pytest>=8",
+    "pytest-asyncio>=0.23",
+]
+
+[tool.setuptools.packages.find]
+include = ["app*"]
+
+[tool.pytest.ini_options]
+asyncio_mode = "auto"
+pythonpath = ["."]
+"#,
+            Self::project_name(model),
+            model.bounded_context.name
+        )
+    }
+
+    fn generate_readme(model: &IRModel) -> String {
+        format!(
+            r#"# {project}
+
+{description}
+
+FastAPI service for the {context} bounded context, generated by StormForge Generator
+
+## Running
+
+```bash
+pip install -e ".[test]"
+uvicorn app.main:app --reload --port 3000
+```
+
+The API is served under `/api`, with interactive documentation at http://localhost:3000/docs.
+
+## Testing
+
+```bash
+pytest
+```
+
+`tests/test_commands.py` and `tests/test_queries.py` send example requests built from the IR to every route, `tests/test_event_store.py` covers the in-memory event store and `tests/test_expressions.py` the runtime of the IR rules.
+
+## Structure
+
+```
+app/
+├── domain/
+│   ├── value_objects.py  # Value objects, enums and identifiers
+│   ├── aggregates.py     # Aggregate state
+│   ├── commands.py       # Command payloads with their validation rules
+│   ├── events.py         # Domain events and the DomainEvent union
+│   ├── expressions.py    # Runtime of the IR rules translated to Python
+│   └── state.py          # Aggregate state folded from events
+├── application/
+│   ├── command_handlers.py
+│   └── query_handlers.py
+├── infrastructure/
+│   └── event_store.py    # In-memory event store
+├── api/
+│   ├── commands.py       # POST /api/<command>
+│   └── queries.py        # GET /api/<query>
+├── dependencies.py       # Event store and handler wiring
+├── errors.py             # {{"code", "message"}} error responses
+├── schemas.py            # Response envelope and PagedResult
+└── main.py               # FastAPI application
+tests/
+```
+"#,
+            project = Self::project_name(model),
+            description = model
+                .bounded_context
+                .description
+                .as_deref()
+                .unwrap_or(&model.bounded_context.name),
+            context = model.bounded_context.name,
+        )
+    }
+
+    fn generate_main(model: &IRModel) -> String {
+        let context = &model.bounded_context.name;
+        let description = model
+            .bounded_context
+            .description
+            .as_deref()
+            .map(|description| format!(",\n    description={}", py_str(description)))
+            .unwrap_or_default();
+        format!(
+            r#""""FastAPI application of the {context} service"""
+
+from fastapi import FastAPI
+
+from app.api import commands, queries
+from app.errors import install_error_handlers
+
+app = FastAPI(
+    title="{context} Service"{description},
+    version="0.1.0",
+)
+install_error_handlers(app)
+app.include_router(commands.router, prefix="/api")
+app.include_router(queries.router, prefix="/api")
+"#
+        )
+    }
+
+    /// Generate value objects, ordered so that each follows the types it uses
+    pub fn generate_value_objects(model: &IRModel) -> Result<String> {
+        let mut items = Vec::new();
+        for name in value_object_order(model) {
+            let vo = &model.value_objects[name];
+            items.push(Self::generate_value_object(model, name, vo)?);
+        }
+        Ok(module(
+            "Value objects",
+            &[("pydantic", &["BaseModel", "ConfigDict", "Field"])],
+            Vec::new(),
+            items,
+        ))
+    }
+
+    fn generate_value_object(model: &IRModel, name: &str, vo: &ValueObject) -> Result<String> {
+        match vo.vo_type.as_deref() {
+            // Enums are serialized as the variant name, like the Rust service
+            Some("enum") => {
+                if vo.values.is_empty() {
+                    anyhow::bail!("Enum '{}' has no values", name);
+                }
+                let mut out = format!("class {}(str, Enum):\n", name);
+                out.push_str(&docstring(vo.description.as_deref(), "    "));
+                if vo.description.is_some() {
+                    out.push('\n');
+                }
+                for value in &vo.values {
+                    out.push_str(&format!(
+                        "    {} = {}\n",
+                        enum_member(&value.name),
+                        py_str(&to_pascal_case(&value.name))
+                    ));
+                    out.push_str(&docstring(value.description.as_deref(), "    "));
+                }
+                Ok(out)
+            }
+            // Identifiers are distinct types over their underlying value
+            Some("identifier") => {
+                let underlying = py_type(model, vo.underlying_type.as_deref().unwrap_or("String"));
+                let mut out = format!("{} = NewType({}, {})\n", name, py_str(name), underlying);
+                out.push_str(&docstring(vo.description.as_deref(), ""));
+                Ok(out)
+            }
+            _ => {
+                let mut body = Vec::from(["model_config = ConfigDict(frozen=True)".to_string()]);
+                body.extend(fields(model, &vo.properties));
+                Ok(class(name, "BaseModel", vo.description.as_deref(), body))
+            }
+        }
+    }
+
+    /// Generate the state of each aggregate
+    pub fn generate_aggregates(model: &IRModel) -> String {
+        let items = model
+            .aggregates
+            .iter()
+            .map(|(name, aggregate)| {
+                let mut description = aggregate.description.clone().unwrap_or_default();
+                if !aggregate.invariants.is_empty() {
+                    if !description.is_empty() {
+                        description.push_str("\n\n");
+                    }
+                    description.push_str("Invariants:");
+                    for invariant in &aggregate.invariants {
+                        description.push_str(&format!(
+                            "\n- {}: `{}`",
+                            invariant.description.as_deref().unwrap_or(&invariant.name),
+                            invariant.expression
+                        ));
+                    }
+                }
+                class(
+                    name,
+                    "BaseModel",
+                    (!description.is_empty()).then_some(description.as_str()),
+                    fields(model, &aggregate.root_entity.properties),
+                )
+            })
+            .collect();
+        let imports = domain_imports(
+            model,
+            "aggregates",
+            model
+                .aggregates
+                .values()
+                .flat_map(|aggregate| &aggregate.root_entity.properties),
+        );
+        module(
+            "Aggregate state",
+            &[("pydantic", &["BaseModel", "Field"])],
+            imports,
+            items,
+        )
+    }
+
+    /// Generate command payloads, validated by pydantic on arrival
+    pub fn generate_commands(model: &IRModel) -> String {
+        let mut checked = false;
+        let items = model
+            .commands
+            .iter()
+            .map(|(name, command)| {
+                let mut body = fields(model, &command.payload);
+                if !command.validation.is_empty() {
+                    let (validator, enforced) = Self::generate_validator(name, command);
+                    body.push(validator);
+                    checked |= enforced;
+                }
+                class(name, "BaseModel", command.description.as_deref(), body)
+            })
+            .collect();
+        let mut imports = domain_imports(
+            model,
+            "commands",
+            model.commands.values().flat_map(|command| &command.payload),
+        );
+        if checked {
+            imports.push("from app.domain import expressions".to_string());
+        }
+        module(
+            "Commands",
+            &[("pydantic", &["BaseModel", "Field", "model_validator"])],
+            imports,
+            items,
+        )
+    }
+
+    /// Validator checking the validation rules of a command, which pydantic
+    /// reports like an invalid field, and whether it enforces any rule
+    fn generate_validator(name: &str, command: &Command) -> (String, bool) {
+        let guards: Vec<Guard> = command
+            .validation
+            .iter()
+            .map(|rule| {
+                PythonExpressionGenerator::guard(
+                    &rule.expression,
+                    &format!("Validation rule of command {}", name),
+                    &call(
+                        "raise ValueError",
+                        &[py_str(&rule.message)],
+                        "",
+                        "            ",
+                    ),
+                    8,
+                )
+            })
+            .collect();
+        let enforced = guards.iter().any(|guard| guard.enforced);
+        let mut out = format!(
+            "    @model_validator(mode=\"after\")\n    def check_rules(self) -> {}:\n        \"\"\"Validation rules of the command\"\"\"\n",
+            py_str(name)
+        );
+        if enforced {
+            out.push_str("        scope = self.model_dump(mode=\"json\", by_alias=True)\n");
+        }
+        for guard in guards {
+            out.push_str(&guard.code);
+        }
+        out.push_str("        return self\n");
+        (dedent(&out), enforced)
+    }
+
+    /// Generate events and the `DomainEvent` union tagged by `type`
+    pub fn generate_events(model: &IRModel) -> String {
+        let mut items = Vec::from([r#"def utc_now() -> datetime:
+    """Current time in UTC"""
+    return datetime.now(timezone.utc)
+"#
+        .to_string()]);
+        for (name, event) in &model.events {
+            items.push(Self::generate_event(model, name, event));
+        }
+
+        let names: Vec<String> = model.events.keys().cloned().collect();
+        let union = match names.len() {
+            0 => "DomainEvent = Any\n".to_string(),
+            1 => format!("DomainEvent = {}\n", names[0]),
+            _ => format!(
+                "{}\n",
+                call(
+                    "DomainEvent = Annotated[",
+                    &[
+                        format!("Union[{}]", names.join(", ")),
+                        "Field(discriminator=\"type\")".to_string(),
+                    ],
+                    "",
+                    ""
+                )
+            ),
+        };
+        items.push(format!(
+            "{}{}",
+            union,
+            docstring(
+                Some(&format!(
+                    "Domain event of the {} context, tagged by `type`",
+                    model.bounded_context.name
+                )),
+                ""
+            )
+        ));
+
+        let imports = domain_imports(
+            model,
+            "events",
+            model.events.values().flat_map(|event| &event.payload),
+        );
+        module(
+            "Domain events",
+            &[("pydantic", &["BaseModel", "Field"])],
+            imports,
+            items,
+        )
+    }
+
+    fn generate_event(model: &IRModel, name: &str, event: &Event) -> String {
+        let mut body = Vec::from([
+            format!("type: Literal[{0}] = {0}", py_str(name)),
+            field_line(
+                "event_id",
+                "UUID",
+                &[
+                    "default_factory=uuid4".to_string(),
+                    "description=\"Unique event identifier\"".to_string(),
+                ],
+            ),
+            field_line(
+                "occurred_at",
+                "datetime",
+                &[
+                    "default_factory=utc_now".to_string(),
+                    "description=\"Timestamp when the event occurred\"".to_string(),
+                ],
+            ),
+        ]);
+        if event.aggregate.is_some() {
+            body.push(field_line(
+                "aggregate_id",
+                "str",
+                &["description=\"ID of the aggregate that produced this event\"".to_string()],
+            ));
+        }
+        body.extend(fields(model, &event.payload));
+        class(name, "BaseModel", event.description.as_deref(), body)
+    }
+
+    /// Generate the fold of events into aggregate state, as in the Rust service
+    pub fn generate_state(model: &IRModel) -> String {
+        let mut items = Vec::from([
+            "State = dict[str, Any]\n\"\"\"JSON object of the root entity properties of an aggregate\"\"\"\n\nENVELOPE_FIELDS = (\"type\", \"event_id\", \"occurred_at\", \"aggregate_id\")\n\"\"\"Event fields that describe the event rather than the aggregate\"\"\"\n".to_string(),
+            r#"def fold(
+    events: Iterable[BaseModel],
+    apply: Callable[[State | None, BaseModel], State],
+    state: State | None = None,
+) -> State | None:
+    """Fold events into a state, if any, with the apply function of an aggregate"""
+    for event in events:
+        state = apply(state, event)
+    return state
+"#
+            .to_string(),
+            r#"def copy_payload(state: State, event: dict[str, Any]) -> None:
+    """Copy the payload fields of an event into a state"""
+    for key, value in event.items():
+        if key not in ENVELOPE_FIELDS:
+            state[key] = value
+"#
+            .to_string(),
+        ]);
+        for aggregate in model.aggregates.values() {
+            items.extend(Self::generate_aggregate_state(model, aggregate));
+        }
+        module(
+            "Aggregate state folded from events\n\nThe state of an aggregate is a JSON object of its root entity properties,\nstarting from their defaults. Each event copies its payload fields into the\nstate, and an event whose name ends with a value of the aggregate's status\nenum, such as `OrderPaid` for `PAID`, sets the status. The identifier is the\naggregate ID of the first event, unless its payload carries the identifier\nfield.\n",
+            &[("pydantic", &["BaseModel"])],
+            Vec::new(),
+            items,
+        )
+    }
+
+    fn generate_aggregate_state(model: &IRModel, aggregate: &Aggregate) -> [String; 2] {
+        let snake = to_snake_case(&aggregate.name);
+        let defaults: serde_json::Map<String, Value> = aggregate
+            .root_entity
+            .properties
+            .iter()
+            .filter_map(|prop| {
+                let default = prop.default.as_ref()?;
+                let is_enum = model
+                    .value_objects
+                    .get(prop.prop_type.trim())
+                    .is_some_and(|vo| vo.vo_type.as_deref() == Some("enum"));
+                let value = match default {
+                    Value::String(name) if is_enum => Value::String(to_pascal_case(name)),
+                    _ => default.clone(),
+                };
+                Some((to_snake_case(&prop.name), value))
+            })
+            .collect();
+        let new = format!(
+            "def new_{}() -> State:\n{}    return {}\n",
+            snake,
+            docstring(
+                Some(&format!(
+                    "Initial state of a `{}`: the defaults of its root entity properties",
+                    aggregate.name
+                )),
+                "    "
+            ),
+            py_literal(&Value::Object(defaults), 4, 11)
+        );
+
+        let mut apply = format!(
+            "def apply_{0}(state: State | None, event: BaseModel) -> State:\n{1}    if state is None:\n        state = new_{0}()\n    fields = event.model_dump(mode=\"json\", by_alias=True)\n",
+            snake,
+            docstring(
+                Some(&format!("Fold an event into the state of a `{}`", aggregate.name)),
+                "    "
+            ),
+        );
+        let statuses: serde_json::Map<String, Value> =
+            AggregateGenerator::status_events(model, aggregate)
+                .into_iter()
+                .map(|(event, status)| (event, Value::String(status)))
+                .collect();
+        if !statuses.is_empty() {
+            let statuses = Value::Object(statuses);
+            let lookup = ".get(fields[\"type\"])";
+            let inline = py_inline(&statuses);
+            let statuses = if 13 + inline.len() + lookup.len() <= LINE_LENGTH {
+                inline
+            } else {
+                py_literal(&statuses, 4, LINE_LENGTH)
+            };
+            apply.push_str(&format!(
+                "    status = {}{}\n    if status is not None:\n        state[\"status\"] = status\n",
+                statuses, lookup
+            ));
+        }
+        apply.push_str("    copy_payload(state, fields)\n");
+        if let Some(identifier) = AggregateGenerator::identifier(aggregate) {
+            apply.push_str(&format!(
+                "    if \"aggregate_id\" in fields:\n        state.setdefault({}, fields[\"aggregate_id\"])\n",
+                py_str(&to_snake_case(&identifier.name))
+            ));
+        }
+        apply.push_str("    return state\n");
+        [new, apply]
+    }
+
+    /// Generate the handlers appending the events of each command to the
+    /// event store, as in the Rust service
+    pub fn generate_command_handlers(model: &IRModel) -> String {
+        let context = &model.bounded_context.name;
+        let mut body = Vec::from([r#"def __init__(self, event_store: EventStore) -> None:
+    self.event_store = event_store
+"#
+        .to_string()]);
+        for (name, command) in &model.commands {
+            body.push(Self::generate_command_handler(model, name, command));
+        }
+        let class = class(
+            "CommandHandlers",
+            "",
+            Some(&format!(
+                "Handlers of the {} commands\n\nEach handler loads its aggregate from the event store, executes the\ncommand and appends the events it produces.",
+                context
+            )),
+            body,
+        );
+
+        let mut items = Vec::new();
+        let handled: Vec<&Command> = model
+            .commands
+            .values()
+            .filter(|command| {
+                command
+                    .aggregate
+                    .as_ref()
+                    .is_some_and(|aggregate| model.aggregates.contains_key(aggregate))
+            })
+            .collect();
+        let builds_events = handled.iter().any(|command| !command.produces.is_empty());
+        if builds_events {
+            items.push(
+                r#"E = TypeVar("E", bound=BaseModel)
+
+
+def event(event_type: type[E], aggregate_id: str, fields: dict[str, Any]) -> E:
+    """Event of a type built from its payload fields"""
+    try:
+        return event_type.model_validate({**fields, "aggregate_id": aggregate_id})
+    except ValidationError as error:
+        raise ApiError(
+            "INTERNAL_ERROR", f"Cannot build {event_type.__name__}: {error}"
+        ) from error
+"#
+                .to_string(),
+            );
+        }
+        let checked: Vec<&Aggregate> = model
+            .aggregates
+            .values()
+            .filter(|aggregate| {
+                !aggregate.invariants.is_empty()
+                    && handled
+                        .iter()
+                        .any(|command| command.aggregate.as_ref() == Some(&aggregate.name))
+            })
+            .collect();
+        items.extend(
+            checked
+                .iter()
+                .map(|aggregate| Self::generate_check(aggregate)),
+        );
+        items.push(class);
+
+        let code = items.concat();
+        let mut imports =
+            Vec::from(["from app.infrastructure.event_store import EventStore".to_string()]);
+        imports.extend(names_import("app.domain.commands", model.commands.keys()));
+        let events: BTreeSet<String> = handled
+            .iter()
+            .flat_map(|command| &command.produces)
+            .filter(|event| model.events.contains_key(*event))
+            .cloned()
+            .chain((!handled.is_empty()).then(|| "DomainEvent".to_string()))
+            .collect();
+        imports.extend(names_import("app.domain.events", events.iter()));
+        let state: BTreeSet<String> = WORDS
+            .find_iter(&code)
+            .map(|word| word.as_str())
+            .filter(|word| *word == "State" || *word == "fold" || word.starts_with("apply_"))
+            .map(str::to_string)
+            .collect();
+        imports.extend(names_import("app.domain.state", state.iter()));
+        if code.contains("expressions.") {
+            imports.push("from app.domain import expressions".to_string());
+        }
+        if code.contains("ApiError(") {
+            imports.push("from app.errors import ApiError".to_string());
+        }
+        module(
+            "Command handlers backed by the event store\n\nA command is validated by its model, then checked against the state of its\naggregate folded from the stored events. A command carrying the aggregate's\nidentifier acts on that aggregate, any other command creates one. Each event\ntakes a field from the `computed` expression of the event property, else from\nthe command field, the aggregate ID or the aggregate state of the same name;\n`DateTime` fields the command does not give are the time it is handled. The\nevents must keep the aggregate's invariants, and are appended at the version\nthe state was loaded at, so a concurrent command on the same aggregate fails\nrather than being lost.\n",
+            &[("pydantic", &["BaseModel", "ValidationError"])],
+            imports,
+            items,
+        )
+    }
+
+    /// Invariant check of an aggregate's state
+    fn generate_check(aggregate: &Aggregate) -> String {
+        let mut doc = format!("Invariants of a `{}`\n", aggregate.name);
+        let mut body = String::new();
+        for invariant in &aggregate.invariants {
+            let message = invariant.description.as_deref().unwrap_or(&invariant.name);
+            doc.push_str(&format!("\n- `{}`: {}", invariant.expression, message));
+            body.push_str(
+                &PythonExpressionGenerator::guard(
+                    &invariant.expression,
+                    &format!("Invariant {} of {}", invariant.name, aggregate.name),
+                    &call(
+                        "raise ApiError",
+                        &["\"VALIDATION_ERROR\"".to_string(), py_str(message)],
+                        "",
+                        "        ",
+                    ),
+                    4,
+                )
+                .code,
+            );
+        }
+        format!(
+            "def check_{}(scope: State) -> None:\n{}{}",
+            to_snake_case(&aggregate.name),
+            docstring(Some(&doc), "    "),
+            body
+        )
+    }
+
+    fn generate_command_handler(model: &IRModel, name: &str, command: &Command) -> String {
+        let mut doc = command
+            .description
+            .clone()
+            .unwrap_or_else(|| format!("Handle `{}`", name));
+        if !command.produces.is_empty() {
+            let events: Vec<String> = command
+                .produces
+                .iter()
+                .map(|event| format!("`{}`", event))
+                .collect();
+            doc.push_str(&format!("\n\nProduces {}.", events.join(", ")));
+        }
+        for (title, conditions) in [
+            ("Validation", &command.validation),
+            ("Preconditions", &command.preconditions),
+        ] {
+            if !conditions.is_empty() {
+                doc.push_str(&format!("\n\n{}:", title));
+                for condition in conditions {
+                    doc.push_str(&format!("\n- {}", condition.message));
+                }
+            }
+        }
+        let signature = call(
+            &format!("async def {}", to_snake_case(name)),
+            &["self".to_string(), format!("command: {}", name)],
+            " -> str:",
+            "    ",
+        );
+        let doc = docstring(Some(&doc), "        ");
+        let body = match command
+            .aggregate
+            .as_deref()
+            .and_then(|aggregate| model.aggregates.get(aggregate))
+        {
+            Some(aggregate) => Self::generate_command_body(model, aggregate, command),
+            None => format!(
+                "        raise ApiError(\"INTERNAL_ERROR\", {})\n",
+                py_str(&format!("{} has no aggregate", name))
+            ),
+        };
+        dedent(&format!("{}\n{}{}", signature, doc, body))
+    }
+
+    /// Load, decide and append steps of a handler, at the method body indentation
+    fn generate_command_body(model: &IRModel, aggregate: &Aggregate, command: &Command) -> String {
+        let aggregate_type = py_str(&aggregate.name);
+        let snake = to_snake_case(&aggregate.name);
+        let name = &command.name;
+
+        let mut sources = Vec::new();
+        let mut events = Vec::new();
+        for event_name in &command.produces {
+            let Some(event) = model.events.get(event_name) else {
+                continue;
+            };
+            let mut fields = Vec::new();
+            for prop in &event.payload {
+                let key = to_snake_case(&prop.name);
+                let source = FieldSource::of(aggregate, command, prop);
+                let value = match &source {
+                    FieldSource::Computed(expression) => {
+                        match PythonExpressionGenerator::value(expression) {
+                            Ok(value) => Ok(value),
+                            Err(error) => {
+                                eprintln!(
+                                    "⚠️  Computed field {}.{} is not set: {:#}",
+                                    event_name, prop.name, error
+                                );
+                                Err(format!("TODO: compute `{}`", expression))
+                            }
+                        }
+                    }
+                    FieldSource::Command => Ok(PyExpr::Atom(format!("values[{}]", py_str(&key)))),
+                    FieldSource::AggregateId => Ok(PyExpr::Atom("aggregate_id".to_string())),
+                    FieldSource::Now => Ok(PyExpr::Atom("expressions.now()".to_string())),
+                    FieldSource::State => Ok(PyExpr::Atom(format!(
+                        "expressions.get(state, {})",
+                        py_str(&key)
+                    ))),
+                };
+                let key = format!("{}: ", py_str(&key));
+                fields.push(match value {
+                    Ok(value) => {
+                        sources.push(source);
+                        PyExpr::Prefix(key, Box::new(value))
+                    }
+                    Err(todo) => PyExpr::Commented(
+                        todo,
+                        Box::new(PyExpr::Prefix(key, Box::new(PyExpr::Atom("None".into())))),
+                    ),
+                });
+            }
+            events.push(PyExpr::Group(
+                "event(".to_string(),
+                vec![
+                    PyExpr::Atom(event_name.clone()),
+                    PyExpr::Atom("aggregate_id".to_string()),
+                    PyExpr::Group("{".to_string(), fields, "}"),
+                ],
+                ")",
+            ));
+        }
+
+        let preconditions: Vec<Guard> = command
+            .preconditions
+            .iter()
+            .map(|precondition| {
+                PythonExpressionGenerator::guard(
+                    &precondition.expression,
+                    &format!("Precondition of command {}", name),
+                    &call(
+                        "raise ApiError",
+                        &[
+                            "\"PRECONDITION_FAILED\"".to_string(),
+                            py_str(&precondition.message),
+                        ],
+                        "",
+                        "            ",
+                    ),
+                    8,
+                )
+            })
+            .collect();
+
+        // Preconditions and computed fields read the state through the scope,
+        // other fields may read it directly, and invariants check the new state
+        let scoped = preconditions.iter().any(|guard| guard.enforced)
+            || sources
+                .iter()
+                .any(|source| matches!(source, FieldSource::Computed(_)));
+        let checked = !aggregate.invariants.is_empty();
+        let reads_state = scoped
+            || checked
+            || sources
+                .iter()
+                .any(|source| matches!(source, FieldSource::State));
+        let reads_command = scoped
+            || sources
+                .iter()
+                .any(|source| matches!(source, FieldSource::Command));
+
+        let mut body = String::new();
+        if reads_command {
+            body.push_str("        values = command.model_dump(mode=\"json\", by_alias=True)\n");
+        }
+        let id_field = AggregateGenerator::id_field(aggregate, command);
+        match (id_field, reads_state) {
+            (Some(field), reads_state) => {
+                body.push_str(&format!(
+                    "        aggregate_id = str(command.{})\n",
+                    field_name(&field.name).0
+                ));
+                if reads_state {
+                    body.push_str(&format!(
+                        "{}\n        state = fold(history, apply_{})\n        version = len(history)\n",
+                        call(
+                            "history = await self.event_store.load_events",
+                            &["aggregate_id".to_string(), aggregate_type.clone()],
+                            "",
+                            "        ",
+                        ),
+                        snake
+                    ));
+                } else {
+                    body.push_str(&format!(
+                        "{}\n",
+                        call(
+                            "version = await self.event_store.version",
+                            &["aggregate_id".to_string(), aggregate_type.clone()],
+                            "",
+                            "        ",
+                        )
+                    ));
+                }
+            }
+            (None, true) => body.push_str(
+                "        aggregate_id = str(uuid4())\n        state, version = None, 0\n",
+            ),
+            (None, false) => {
+                body.push_str("        aggregate_id = str(uuid4())\n        version = 0\n")
+            }
+        }
+        if id_field.is_some() && !preconditions.is_empty() {
+            body.push_str(&format!(
+                "        if version == 0:\n            raise ApiError(\"NOT_FOUND\", f\"{} {{aggregate_id}} not found\")\n",
+                aggregate.name
+            ));
+        }
+        if scoped {
+            body.push_str(&format!(
+                "        scope = {{**values, {}: state}}\n",
+                py_str(&snake)
+            ));
+        }
+        for guard in preconditions {
+            body.push_str(&guard.code);
+        }
+        let events = PyExpr::Group("[".to_string(), events, "]");
+        body.push_str(&format!(
+            "        events: list[DomainEvent] = {}\n",
+            events.render(8, 36, 0)
+        ));
+        if checked {
+            body.push_str(&format!(
+                "        state = fold(events, apply_{0}, state)\n        check_{0}(state)\n",
+                snake
+            ));
+        }
+        body.push_str(&format!(
+            "{}\n        return \"Command executed successfully\"\n",
+            call(
+                "await self.event_store.append_events",
+                &[
+                    "aggregate_id".to_string(),
+                    aggregate_type,
+                    "events".to_string(),
+                    "version".to_string(),
+                ],
+                "",
+                "        ",
+            )
+        ));
+        body
+    }
+
+    /// Generate a handler per query, to be completed with a read model
+    pub fn generate_query_handlers(model: &IRModel) -> String {
+        let context = &model.bounded_context.name;
+        let mut body = Vec::from([r#"def __init__(self, event_store: EventStore) -> None:
+    self.event_store = event_store
+"#
+        .to_string()]);
+        for (name, query) in &model.queries {
+            body.push(Self::generate_query_handler(model, name, query));
+        }
+        let class = class(
+            "QueryHandlers",
+            "",
+            Some(&format!("Handlers of the {} queries", context)),
+            body,
+        );
+
+        let mut imports = Vec::new();
+        if model.queries.values().any(query_returns_error) {
+            imports.push("from app.errors import ApiError".to_string());
+        }
+        imports.push("from app.infrastructure.event_store import EventStore".to_string());
+        if model.queries.values().any(|query| {
+            query
+                .returns
+                .as_ref()
+                .is_some_and(|returns| returns.return_type.trim().starts_with("PagedResult<"))
+        }) {
+            imports.push("from app.schemas import PagedResult".to_string());
+        }
+        imports.extend(domain_imports(model, "", query_properties(model).iter()));
+        module("Query handlers", &[], imports, Vec::from([class]))
+    }
+
+    fn generate_query_handler(model: &IRModel, name: &str, query: &Query) -> String {
+        let mut args = Vec::from(["self".to_string()]);
+        if !query.parameters.is_empty() {
+            args.push("*".to_string());
+        }
+        for param in &query.parameters {
+            let ty = py_type(model, &param.prop_type);
+            args.push(format!(
+                "{}: {}",
+                field_name(&param.name).0,
+                if param.required || param.default.is_some() {
+                    ty
+                } else {
+                    nullable(&ty)
+                }
+            ));
+        }
+        let signature = dedent(&call(
+            &format!("async def {}", to_snake_case(name)),
+            &args,
+            &format!(" -> {}:", query_result(model, query)),
+            "    ",
+        ));
+
+        let result = match &query.returns {
+            None => "return None".to_string(),
+            Some(returns) if returns.nullable => "return None".to_string(),
+            Some(returns) => {
+                let return_type = returns.return_type.trim();
+                if return_type.starts_with("List<") || return_type.starts_with("Vec<") {
+                    "return []".to_string()
+                } else if return_type.starts_with("PagedResult<") {
+                    "return PagedResult(items=[], total=0, page=1, page_size=20)".to_string()
+                } else {
+                    format!(
+                        "raise ApiError(\"NOT_FOUND\", {})",
+                        py_str(&format!("{} not found", to_rust_type(return_type)))
+                    )
+                }
+            }
+        };
+        format!(
+            "{}\n{}    # TODO: Read the result from a read model or by replaying events\n    {}\n",
+            signature,
+            docstring(
+                Some(
+                    query
+                        .description
+                        .as_deref()
+                        .unwrap_or(&format!("Handle `{}`", name))
+                ),
+                "    "
+            ),
+            result
+        )
+    }
+
+    /// Generate `POST /api/<command>` routes
+    pub fn generate_command_routes(model: &IRModel) -> String {
+        let mut items = Vec::from(["router = APIRouter(tags=[\"commands\"])\n".to_string()]);
+        for (name, command) in &model.commands {
+            let mut decorator_args = Vec::from([
+                py_str(&format!("/{}", to_kebab_case(name))),
+                "response_model=ApiResponse[str]".to_string(),
+            ]);
+            if let Some(description) = &command.description {
+                decorator_args.push(format!("summary={}", py_str(description)));
+            }
+            items.push(format!(
+                "{}\n{}\n    return ApiResponse(data=await handlers.{}(command))\n",
+                call("@router.post", &decorator_args, "", ""),
+                call(
+                    &format!("async def {}", to_snake_case(name)),
+                    &[
+                        format!("command: {}", name),
+                        "handlers: CommandHandlers = Depends(get_command_handlers)".to_string(),
+                    ],
+                    " -> ApiResponse[str]:",
+                    ""
+                ),
+                to_snake_case(name)
+            ));
+        }
+
+        let mut imports = Vec::from([
+            "from app.application.command_handlers import CommandHandlers".to_string(),
+            "from app.dependencies import get_command_handlers".to_string(),
+        ]);
+        imports.extend(names_import("app.domain.commands", model.commands.keys()));
+        imports.push("from app.schemas import ApiResponse".to_string());
+        module(
+            "Command routes",
+            &[("fastapi", &["APIRouter", "Depends"])],
+            imports,
+            items,
+        )
+    }
+
+    /// Generate `GET /api/<query>` routes, taking parameters from the query string
+    pub fn generate_query_routes(model: &IRModel) -> String {
+        let mut items = Vec::from(["router = APIRouter(tags=[\"queries\"])\n".to_string()]);
+        for (name, query) in &model.queries {
+            let result = query_result(model, query);
+            let mut decorator_args = Vec::from([
+                py_str(&format!("/{}", to_kebab_case(name))),
+                format!("response_model=ApiResponse[{}]", result),
+            ]);
+            if let Some(description) = &query.description {
+                decorator_args.push(format!("summary={}", py_str(description)));
+            }
+
+            let mut args = Vec::new();
+            if !query.parameters.is_empty() {
+                args.push("*".to_string());
+            }
+            let mut forwarded = Vec::new();
+            for param in &query.parameters {
+                let (field, alias) = field_name(&param.name);
+                let mut ty = py_type(model, &param.prop_type);
+                let mut query_args = constraints(model, param);
+                if let Some(alias) = alias {
+                    query_args.insert(0, format!("alias={}", py_str(&alias)));
+                }
+                if let Some(description) = &param.description {
+                    query_args.push(format!("description={}", py_str(description)));
+                }
+                if !query_args.is_empty() {
+                    ty = call(
+                        "Annotated[",
+                        &[ty, call("Query", &query_args, "", "")],
+                        "",
+                        "",
+                    );
+                }
+                let default = match &param.default {
+                    Some(default) => format!(" = {}", default_literal(model, param, default)),
+                    None if !param.required => " = None".to_string(),
+                    None => String::new(),
+                };
+                if !param.required && param.default.is_none() {
+                    ty = nullable(&ty);
+                }
+                args.push(format!("{}: {}{}", field, ty, default));
+                forwarded.push(format!("{0}={0}", field));
+            }
+            args.push("handlers: QueryHandlers = Depends(get_query_handlers)".to_string());
+            let handler = call(
+                &format!("data=await handlers.{}", to_snake_case(name)),
+                &forwarded,
+                "",
+                "",
+            );
+            items.push(format!(
+                "{}\n{}\n{}\n",
+                call("@router.get", &decorator_args, "", ""),
+                call(
+                    &format!("async def {}", to_snake_case(name)),
+                    &args,
+                    &format!(" -> ApiResponse[{}]:", result),
+                    ""
+                ),
+                call("return ApiResponse", &[handler], "", "    ")
+            ));
+        }
+
+        let mut fastapi = Vec::from(["APIRouter", "Depends"]);
+        if query_properties(model)
+            .iter()
+            .any(|param| !constraints(model, param).is_empty() || param.description.is_some())
+        {
+            fastapi.push("Query");
+        }
+        let mut imports = Vec::from([
+            "from app.application.query_handlers import QueryHandlers".to_string(),
+            "from app.dependencies import get_query_handlers".to_string(),
+        ]);
+        if model.queries.values().any(|query| {
+            query
+                .returns
+                .as_ref()
+                .is_some_and(|returns| returns.return_type.trim().starts_with("PagedResult<"))
+        }) {
+            imports.push("from app.schemas import ApiResponse, PagedResult".to_string());
+        } else {
+            imports.push("from app.schemas import ApiResponse".to_string());
+        }
+        imports.extend(domain_imports(model, "", query_properties(model).iter()));
+        module("Query routes", &[("fastapi", &fastapi)], imports, items)
+    }
+
+    /// Generate tests sending an example of every command to an empty event
+    /// store: a command acting on an aggregate that must exist is answered
+    /// `NOT_FOUND`, and one whose events need no stored state is accepted
+    pub fn generate_command_tests(model: &IRModel) -> String {
+        let examples = Examples::new(model);
+        let mut items = Vec::new();
+        for (name, command) in &model.commands {
+            let snake = to_snake_case(name);
+            let path = format!("/api/{}", to_kebab_case(name));
+            let payload = future_dates(command, examples.object(&command.payload));
+            let aggregate = command
+                .aggregate
+                .as_deref()
+                .and_then(|aggregate| model.aggregates.get(aggregate));
+            match aggregate {
+                Some(aggregate)
+                    if AggregateGenerator::id_field(aggregate, command).is_some()
+                        && !command.preconditions.is_empty() =>
+                {
+                    items.push(format!(
+                        "def test_{snake}_needs_an_existing_{}(client: TestClient) -> None:\n{}\n\n    assert response.status_code == 404\n    assert response.json()[\"code\"] == \"NOT_FOUND\"\n",
+                        to_snake_case(&aggregate.name),
+                        request("post", &path, Some(("json", &payload))),
+                    ));
+                }
+                Some(aggregate) if builds_events(model, aggregate, command) => {
+                    items.push(format!(
+                        "def test_{snake}_is_accepted(client: TestClient) -> None:\n{}\n\n    assert response.status_code == 200\n    assert response.json() == {{\"data\": \"Command executed successfully\"}}\n",
+                        request("post", &path, Some(("json", &payload))),
+                    ));
+                }
+                _ => {}
+            }
+
+            let required = command.payload.iter().find(|prop| {
+                prop.required
+                    && prop.default.is_none()
+                    && prop.computed.is_none()
+                    && !prop.prop_type.trim().starts_with("Option<")
+            });
+            if let Some(prop) = required {
+                let field = to_snake_case(&prop.name);
+                let mut payload = payload.clone();
+                if let Some(object) = payload.as_object_mut() {
+                    object.shift_remove(&field);
+                }
+                items.push(format!(
+                    "def test_{snake}_requires_{field}(client: TestClient) -> None:\n{}\n\n    assert response.status_code == 400\n    assert response.json()[\"code\"] == \"VALIDATION_ERROR\"\n",
+                    request("post", &path, Some(("json", &payload))),
+                ));
+            }
+        }
+        test_module(
+            &format!(
+                "Example requests to the {} commands",
+                model.bounded_context.name
+            ),
+            items,
+        )
+    }
+
+    /// Generate tests sending the required parameters of every query
+    pub fn generate_query_tests(model: &IRModel) -> String {
+        let examples = Examples::new(model);
+        let mut items = Vec::new();
+        for (name, query) in &model.queries {
+            let path = format!("/api/{}", to_kebab_case(name));
+            let required: Vec<Property> = query
+                .parameters
+                .iter()
+                .filter(|param| param.required && param.default.is_none())
+                .cloned()
+                .collect();
+            let params = examples.object(&required);
+            let request = request(
+                "get",
+                &path,
+                (!required.is_empty()).then_some(("params", &params)),
+            );
+            items.push(format!(
+                "def test_{}_accepts_parameters(client: TestClient) -> None:\n{}\n\n    assert response.status_code != 400\n",
+                to_snake_case(name),
+                request
+            ));
+        }
+        test_module(
+            &format!(
+                "Example requests to the {} queries",
+                model.bounded_context.name
+            ),
+            items,
+        )
+    }
+
+    /// Generate tests of the event store, with the first event as example
+    pub fn generate_event_store_tests(model: &IRModel) -> Option<String> {
+        let (name, event) = model.events.iter().next()?;
+        let aggregate_type = event
+            .aggregate
+            .clone()
+            .unwrap_or_else(|| model.bounded_context.name.clone());
+        let aggregate_id = format!("{}-1", to_kebab_case(&aggregate_type));
+        let payload = Examples::new(model).object(&event.payload);
+        let mut arguments = Vec::new();
+        if event.aggregate.is_some() {
+            arguments.push("aggregate_id=AGGREGATE_ID".to_string());
+        }
+        arguments.push(format!("**{}", py_literal(&payload, 0, 10)));
+        let constructor = dedent(&call(name, &arguments, "", "    "));
+        Some(format!(
+            r#""""Tests of the in-memory event store"""
+
+import pytest
+
+from app.domain.events import {name}
+from app.infrastructure.event_store import ConcurrencyError, InMemoryEventStore
+
+AGGREGATE_TYPE = {aggregate_type}
+AGGREGATE_ID = {aggregate_id}
+
+
+def example_event() -> {name}:
+    return {constructor}
+
+
+async def test_events_load_in_append_order(event_store: InMemoryEventStore) -> None:
+    first, second = example_event(), example_event()
+    await event_store.append_events(AGGREGATE_ID, AGGREGATE_TYPE, [first])
+    await event_store.append_events(
+        AGGREGATE_ID, AGGREGATE_TYPE, [second], expected_version=1
+    )
+
+    events = await event_store.load_events(AGGREGATE_ID, AGGREGATE_TYPE)
+    assert events == [first, second]
+    assert await event_store.version(AGGREGATE_ID, AGGREGATE_TYPE) == 2
+    assert await event_store.load_events("other", AGGREGATE_TYPE) == []
+
+
+async def test_append_rejects_stale_version(event_store: InMemoryEventStore) -> None:
+    await event_store.append_events(AGGREGATE_ID, AGGREGATE_TYPE, [example_event()])
+
+    with pytest.raises(ConcurrencyError):
+        await event_store.append_events(
+            AGGREGATE_ID, AGGREGATE_TYPE, [example_event()], expected_version=0
+        )
+    assert await event_store.version(AGGREGATE_ID, AGGREGATE_TYPE) == 1
+"#,
+            aggregate_type = py_str(&aggregate_type),
+            aggregate_id = py_str(&aggregate_id),
+            constructor = indent_continuation(&constructor, "    "),
+        ))
+    }
+}
+
+/// Example payload of a command with its dates in the future, as in the Rust
+/// starter tests, so that rules such as `startDate >= today()` hold
+fn future_dates(command: &Command, mut payload: Value) -> Value {
+    let Some(object) = payload.as_object_mut() else {
+        return payload;
+    };
+    for prop in command.payload.iter().filter(|prop| prop.default.is_none()) {
+        let ir_type = prop.prop_type.trim();
+        let ir_type = ir_type
+            .strip_prefix("Option<")
+            .and_then(|inner| inner.strip_suffix('>'))
+            .unwrap_or(ir_type);
+        let future = match ir_type {
+            "Date" => "2099-01-01",
+            "DateTime" => "2099-01-01T09:00:00Z",
+            _ => continue,
+        };
+        if let Some(value) = object.get_mut(&to_snake_case(&prop.name)) {
+            *value = Value::from(future);
+        }
+    }
+    payload
+}
+
+/// Whether the handler of a command builds its events without a stored
+/// aggregate: no required event field reads the state or is left to a hook
+fn builds_events(model: &IRModel, aggregate: &Aggregate, command: &Command) -> bool {
+    let alias = to_snake_case(&aggregate.name);
+    let reads_state = |expression: &str| {
+        Expr::parse(expression).map_or(true, |expr| {
+            expr.names().iter().any(|name| to_snake_case(name) == alias)
+        })
+    };
+    command.produces.iter().all(|event| {
+        model.events.get(event).is_some_and(|event| {
+            event.payload.iter().all(|prop| {
+                !prop.required
+                    || match FieldSource::of(aggregate, command, prop) {
+                        FieldSource::State => false,
+                        FieldSource::Computed(expression) => {
+                            !reads_state(&expression)
+                                && PythonExpressionGenerator::value(&expression).is_ok()
+                        }
+                        _ => true,
+                    }
+            })
+        })
+    })
+}
+
+/// `response = client.<method>(path, <keyword>=value)` in a test
+fn request(method: &str, path: &str, argument: Option<(&str, &Value)>) -> String {
+    let mut args = Vec::from([py_str(path)]);
+    if let Some((keyword, value)) = argument {
+        let inline = format!("{}={}", keyword, py_inline(value));
+        let column = 8 + keyword.len() + 1;
+        args.push(
+            if 4 + "response = client.(, )".len() + method.len() + args[0].len() + inline.len()
+                <= LINE_LENGTH
+            {
+                inline
+            } else {
+                format!("{}={}", keyword, py_literal(value, 0, column))
+            },
+        );
+    }
+    call(&format!("response = client.{}", method), &args, "", "    ")
+}
+
+/// Python type of the JSON the Rust service exchanges for an IR type
+pub fn py_type(model: &IRModel, ir_type: &str) -> String {
+    let ir_type = ir_type.trim();
+    let generic = |prefix: &str| {
+        ir_type
+            .strip_prefix(prefix)
+            .map(|rest| py_type(model, rest.strip_suffix('>').unwrap_or(rest)))
+    };
+    if let Some(item) = generic("List<").or_else(|| generic("Vec<")) {
+        return format!("list[{}]", item);
+    }
+    if let Some(inner) = generic("Option<") {
+        return nullable(&inner);
+    }
+    if let Some(item) = generic("PagedResult<") {
+        return format!("PagedResult[{}]", item);
+    }
+
+    match ir_type {
+        "String" => "str".to_string(),
+        "Integer" => "int".to_string(),
+        "Decimal" => "Decimal".to_string(),
+        "Boolean" => "bool".to_string(),
+        "DateTime" => "datetime".to_string(),
+        "Date" => "date".to_string(),
+        "Time" => "time".to_string(),
+        "Uuid" => "UUID".to_string(),
+        _ => {
+            let name = to_rust_type(ir_type);
+            if model.value_objects.contains_key(&name) || model.aggregates.contains_key(&name) {
+                name
+            } else {
+                // Types the IR does not define are kept as raw JSON
+                "Any".to_string()
+            }
+        }
+    }
+}
+
+/// Nullable Python type, as an `Option` is serialized as `null`
+fn nullable(py_type: &str) -> String {
+    if py_type == "Any" || py_type.ends_with(" | None") {
+        py_type.to_string()
+    } else {
+        format!("{} | None", py_type)
+    }
+}
+
+/// Result type of a query handler
+fn query_result(model: &IRModel, query: &Query) -> String {
+    match &query.returns {
+        Some(returns) if returns.nullable => nullable(&py_type(model, &returns.return_type)),
+        Some(returns) => py_type(model, &returns.return_type),
+        None => "None".to_string(),
+    }
+}
+
+/// Whether the handler stub of a query answers `NOT_FOUND`
+fn query_returns_error(query: &Query) -> bool {
+    query.returns.as_ref().is_some_and(|returns| {
+        let return_type = returns.return_type.trim();
+        !returns.nullable
+            && !["List<", "Vec<", "PagedResult<"]
+                .iter()
+                .any(|prefix| return_type.starts_with(prefix))
+    })
+}
+
+/// Parameters and result types of all queries, as properties for imports
+fn query_properties(model: &IRModel) -> Vec<Property> {
+    model
+        .queries
+        .values()
+        .flat_map(|query| {
+            let returns = query.returns.iter().map(|returns| Property {
+                name: "result".to_string(),
+                prop_type: returns.return_type.clone(),
+                identifier: false,
+                required: true,
+                default: None,
+                description: None,
+                validation: None,
+                computed: None,
+            });
+            query.parameters.iter().cloned().chain(returns)
+        })
+        .collect()
+}
+
+/// Field name of a property, with the JSON key as alias if it is a keyword
+fn field_name(name: &str) -> (String, Option<String>) {
+    let snake = to_snake_case(name);
+    if KEYWORDS.contains(&snake.as_str()) {
+        (format!("{}_", snake), Some(snake))
+    } else {
+        (snake, None)
+    }
+}
+
+fn enum_member(name: &str) -> String {
+    let member = to_snake_case(name).to_uppercase();
+    if member.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", member)
+    } else {
+        member
+    }
+}
+
+/// Field declarations of properties, with their validation rules as constraints
+fn fields(model: &IRModel, properties: &[Property]) -> Vec<String> {
+    properties
+        .iter()
+        .map(|prop| {
+            let (name, alias) = field_name(&prop.name);
+            let mut ty = py_type(model, &prop.prop_type);
+            let mut args = Vec::new();
+            match &prop.default {
+                Some(default) => {
+                    args.push(format!("default={}", default_literal(model, prop, default)))
+                }
+                None if !prop.required => {
+                    ty = nullable(&ty);
+                    args.push("default=None".to_string());
+                }
+                None => {}
+            }
+            if let Some(alias) = alias {
+                args.push(format!("alias={}", py_str(&alias)));
+            }
+            args.extend(constraints(model, prop));
+            if let Some(description) = &prop.description {
+                args.push(format!("description={}", py_str(description)));
+            }
+            match args.as_slice() {
+                [] => format!("{}: {}", name, ty),
+                [default] if default.starts_with("default=") => {
+                    format!("{}: {} = {}", name, ty, &default["default=".len()..])
+                }
+                _ => field_line(&name, &ty, &args),
+            }
+        })
+        .collect()
+}
+
+/// pydantic constraints of the validation rules that apply to a property's type
+fn constraints(model: &IRModel, prop: &Property) -> Vec<String> {
+    let Some(validation) = &prop.validation else {
+        return Vec::new();
+    };
+    let mut base = prop.prop_type.trim();
+    if let Some(inner) = base.strip_prefix("Option<") {
+        base = inner.strip_suffix('>').unwrap_or(inner).trim();
+    }
+    let underlying = model
+        .value_objects
+        .get(base)
+        .filter(|vo| vo.vo_type.as_deref() == Some("identifier"))
+        .map(|vo| vo.underlying_type.as_deref().unwrap_or("String"))
+        .unwrap_or(base);
+    let numeric = matches!(underlying, "Integer" | "Decimal");
+    let text = underlying == "String";
+    let list = underlying.starts_with("List<") || underlying.starts_with("Vec<");
+
+    let mut args = Vec::new();
+    if numeric {
+        if let Some(min) = validation.min {
+            args.push(format!("ge={}", number(min)));
+        }
+        if let Some(max) = validation.max {
+            args.push(format!("le={}", number(max)));
+        }
+    }
+    if text || list {
+        if let Some(min) = validation.min_length {
+            args.push(format!("min_length={}", min));
+        }
+        if let Some(max) = validation.max_length {
+            args.push(format!("max_length={}", max));
+        }
+    }
+    if text {
+        if let Some(pattern) = &validation.pattern {
+            args.push(format!("pattern={}", py_str(pattern)));
+        }
+    }
+    if underlying == "Decimal" {
+        if let Some(precision) = validation.precision {
+            args.push(format!("decimal_places={}", precision));
+        }
+    }
+    args
+}
+
+fn number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+/// Python expression of an IR default value
+fn default_literal(model: &IRModel, prop: &Property, default: &Value) -> String {
+    let ir_type = prop.prop_type.trim();
+    let is_enum = model
+        .value_objects
+        .get(ir_type)
+        .is_some_and(|vo| vo.vo_type.as_deref() == Some("enum"));
+    match default {
+        Value::String(name) if is_enum => format!("{}.{}", ir_type, enum_member(name)),
+        Value::String(text) if ir_type == "Decimal" => format!("Decimal({})", py_str(text)),
+        Value::Number(number) if ir_type == "Decimal" => {
+            format!("Decimal({})", py_str(&number.to_string()))
+        }
+        _ => py_literal(default, 4, 4),
+    }
+}
+
+/// `name: type = Field(args)`, wrapped like black
+fn field_line(name: &str, ty: &str, args: &[String]) -> String {
+    dedent(&call(
+        &format!("{}: {} = Field", name, ty),
+        args,
+        "",
+        "    ",
+    ))
+}
+
+/// Text wrapped at one indentation level, with that indentation removed
+/// again for callers that indent it themselves
+fn dedent(text: &str) -> String {
+    text.lines()
+        .map(|line| line.strip_prefix("    ").unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Call or signature `prefix(args)suffix` at `indent`, wrapped like black
+/// when longer than a line: first the arguments on their own line, then
+/// one argument per line with a trailing comma
+fn call(prefix: &str, args: &[String], suffix: &str, indent: &str) -> String {
+    let (open, close) = if prefix.ends_with('[') {
+        ("", "]")
+    } else {
+        ("(", ")")
+    };
+    let inline = format!(
+        "{}{}{}{}{}{}",
+        indent,
+        prefix,
+        open,
+        args.join(", "),
+        close,
+        suffix
+    );
+    if (inline.len() <= LINE_LENGTH && !inline.contains('\n')) || args.is_empty() {
+        return inline;
+    }
+    let joined = args.join(", ");
+    if !joined.contains('\n') && indent.len() + 4 + joined.len() <= LINE_LENGTH {
+        return format!("{indent}{prefix}{open}\n{indent}    {joined}\n{indent}{close}{suffix}");
+    }
+    let mut out = format!("{}{}{}\n", indent, prefix, open);
+    for arg in args {
+        out.push_str(&format!(
+            "{}    {},\n",
+            indent,
+            indent_continuation(arg, &format!("{}    ", indent))
+        ));
+    }
+    out.push_str(&format!("{}{}{}", indent, close, suffix));
+    out
+}
+
+/// Indent every line after the first
+fn indent_continuation(text: &str, indent: &str) -> String {
+    text.replace('\n', &format!("\n{}", indent))
+}
+
+/// Python literal of a JSON value, on one line when it fits after `column`
+fn py_literal(value: &Value, indent: usize, column: usize) -> String {
+    let inline = py_inline(value);
+    if column + inline.len() <= LINE_LENGTH {
+        return inline;
+    }
+    let pad = " ".repeat(indent + 4);
+    let items: Vec<String> = match value {
+        Value::Array(items) if !items.is_empty() => items
+            .iter()
+            .map(|item| format!("{}{},", pad, py_literal(item, indent + 4, indent + 4)))
+            .collect(),
+        Value::Object(object) if !object.is_empty() => object
+            .iter()
+            .map(|(key, item)| {
+                let key = py_str(key);
+                format!(
+                    "{}{}: {},",
+                    pad,
+                    key,
+                    py_literal(item, indent + 4, indent + 4 + key.len() + 2)
+                )
+            })
+            .collect(),
+        _ => return inline,
+    };
+    let (open, close) = if value.is_array() {
+        ("[", "]")
+    } else {
+        ("{", "}")
+    };
+    format!(
+        "{}\n{}\n{}{}",
+        open,
+        items.join("\n"),
+        " ".repeat(indent),
+        close
+    )
+}
+
+pub fn py_inline(value: &Value) -> String {
+    match value {
+        Value::Null => "None".to_string(),
+        Value::Bool(true) => "True".to_string(),
+        Value::Bool(false) => "False".to_string(),
+        Value::Number(number) => number.to_string(),
+        Value::String(text) => py_str(text),
+        Value::Array(items) => format!(
+            "[{}]",
+            items.iter().map(py_inline).collect::<Vec<_>>().join(", ")
+        ),
+        Value::Object(object) => format!(
+            "{{{}}}",
+            object
+                .iter()
+                .map(|(key, item)| format!("{}: {}", py_str(key), py_inline(item)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Double-quoted Python string literal
+pub fn py_str(text: &str) -> String {
+    // JSON string escapes are valid Python escapes
+    serde_json::to_string(text).unwrap_or_else(|_| format!("{:?}", text))
+}
+
+/// Docstring at the given indentation
+fn docstring(text: Option<&str>, indent: &str) -> String {
+    let Some(text) = text else {
+        return String::new();
+    };
+    let text = text.trim().replace("\"\"\"", "\\\"\\\"\\\"");
+    if !text.contains('\n') {
+        return format!("{}\"\"\"{}\"\"\"\n", indent, text);
+    }
+    let mut out = format!("{}\"\"\"", indent);
+    for (index, line) in text.lines().enumerate() {
+        if index > 0 && !line.is_empty() {
+            out.push_str(indent);
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    out.push_str(&format!("{}\"\"\"\n", indent));
+    out
+}
+
+/// Class with a docstring and body statements
+fn class(name: &str, base: &str, description: Option<&str>, body: Vec<String>) -> String {
+    let mut out = if base.is_empty() {
+        format!("class {}:\n", name)
+    } else {
+        format!("class {}({}):\n", name, base)
+    };
+    out.push_str(&docstring(description, "    "));
+    if body.is_empty() && description.is_none() {
+        out.push_str("    pass\n");
+    }
+    // Fields are grouped, methods and nested blocks are separated by a blank line
+    let mut previous_block = description.is_some();
+    for statement in body {
+        let block = statement.contains(":\n");
+        if previous_block || block {
+            out.push('\n');
+        }
+        for line in statement.trim_end().lines() {
+            if line.is_empty() {
+                out.push('\n');
+            } else {
+                out.push_str(&format!("    {}\n", line));
+            }
+        }
+        previous_block = block;
+    }
+    out
+}
+
+/// `from module import A, B` of the given names, if any, parenthesized
+/// one per line when longer than a line
+fn names_import<'a>(module: &str, names: impl Iterator<Item = &'a String>) -> Option<String> {
+    let names: BTreeSet<&str> = names.map(String::as_str).collect();
+    if names.is_empty() {
+        return None;
+    }
+    let names: Vec<&str> = names.into_iter().collect();
+    let inline = format!("from {} import {}", module, names.join(", "));
+    if inline.len() <= LINE_LENGTH {
+        return Some(inline);
+    }
+    let names: String = names
+        .iter()
+        .map(|name| format!("    {},\n", name))
+        .collect();
+    Some(format!("from {} import (\n{})", module, names))
+}
+
+/// Imports of the value objects and aggregates used by the properties
+/// of a domain module other than `current`
+fn domain_imports<'a>(
+    model: &IRModel,
+    current: &str,
+    properties: impl Iterator<Item = &'a Property>,
+) -> Vec<String> {
+    let mut value_objects = BTreeSet::new();
+    let mut aggregates = BTreeSet::new();
+    for prop in properties {
+        if let Some(name) = referenced_type(&prop.prop_type) {
+            if model.value_objects.contains_key(&name) {
+                value_objects.insert(name);
+            } else if model.aggregates.contains_key(&name) {
+                aggregates.insert(name);
+            }
+        }
+    }
+    let mut imports = Vec::new();
+    if current != "aggregates" {
+        imports.extend(names_import("app.domain.aggregates", aggregates.iter()));
+    }
+    imports.extend(names_import(
+        "app.domain.value_objects",
+        value_objects.iter(),
+    ));
+    imports
+}
+
+/// Module with a docstring and its imports in isort order: the standard
+/// library names the items use, third party packages, then the app
+fn module(
+    doc: &str,
+    third_party: &[(&str, &[&str])],
+    mut local: Vec<String>,
+    items: Vec<String>,
+) -> String {
+    let code = STRINGS.replace_all(&items.concat(), "\"\"").into_owned();
+    let used: BTreeSet<&str> = WORDS.find_iter(&code).map(|word| word.as_str()).collect();
+
+    let group = |modules: &[(&str, &[&str])]| -> Vec<String> {
+        modules
+            .iter()
+            .filter_map(|(module, names)| {
+                let names: Vec<String> = names
+                    .iter()
+                    .filter(|name| used.contains(*name))
+                    .map(|name| name.to_string())
+                    .collect();
+                names_import(module, names.iter())
+            })
+            .collect()
+    };
+    local.sort();
+    let mut sections = Vec::new();
+    for section in [group(STD_NAMES), group(third_party), local] {
+        if !section.is_empty() {
+            sections.push(section.join("\n"));
+        }
+    }
+
+    let mut out = format!("\"\"\"{}\"\"\"\n", doc);
+    if !sections.is_empty() {
+        out.push('\n');
+        out.push_str(&sections.join("\n\n"));
+        out.push('\n');
+    }
+    for item in items {
+        out.push_str("\n\n");
+        out.push_str(&item);
+    }
+    out
+}
+
+/// Test module importing the client fixture type
+fn test_module(doc: &str, items: Vec<String>) -> String {
+    let mut out = format!(
+        "\"\"\"{}\"\"\"\n\nfrom fastapi.testclient import TestClient\n",
+        doc
+    );
+    for item in items {
+        out.push_str("\n\n");
+        out.push_str(&item);
+    }
+    out
+}
+
+const ERRORS: &str = r#""""Error responses in the `{"code", "message"}` layout of the service API"""
+
+from typing import Any
+
+from fastapi import FastAPI, Request
+from fastapi.exceptions import RequestValidationError
+from fastapi.responses import JSONResponse
+
+STATUS_CODES = {
+    "VALIDATION_ERROR": 400,
+    "NOT_FOUND": 404,
+    "PRECONDITION_FAILED": 409,
+}
+
+
+class ApiError(Exception):
+    """Error answered with its code, such as `NOT_FOUND`, and message"""
+
+    def __init__(self, code: str, message: str) -> None:
+        super().__init__(message)
+        self.code = code
+        self.message = message
+
+    @property
+    def status_code(self) -> int:
+        return STATUS_CODES.get(self.code, 500)
+
+
+def describe(problem: dict[str, Any]) -> str:
+    """Problem of a request, prefixed with the field it is in, if any"""
+    field = ".".join(map(str, problem["loc"][1:]))
+    return "{}: {}".format(field, problem["msg"]) if field else problem["msg"]
+
+
+def install_error_handlers(app: FastAPI) -> None:
+    """Answer `ApiError`s and invalid requests with an error body"""
+
+    @app.exception_handler(ApiError)
+    async def api_error(request: Request, error: ApiError) -> JSONResponse:
+        return JSONResponse(
+            status_code=error.status_code,
+            content={"code": error.code, "message": error.message},
+        )
+
+    @app.exception_handler(RequestValidationError)
+    async def validation_error(
+        request: Request, error: RequestValidationError
+    ) -> JSONResponse:
+        problems = [describe(problem) for problem in error.errors()]
+        return JSONResponse(
+            status_code=400,
+            content={
+                "code": "VALIDATION_ERROR",
+                "message": "Validation error: {}".format("; ".join(problems)),
+            },
+        )
+"#;
+
+const SCHEMAS: &str = r#""""Response bodies shared by the routes"""
+
+from typing import Generic, TypeVar
+
+from pydantic import BaseModel
+
+T = TypeVar("T")
+
+
+class ApiResponse(BaseModel, Generic[T]):
+    """`{"data": ...}` envelope of command and query responses"""
+
+    data: T
+
+
+class PagedResult(BaseModel, Generic[T]):
+    """Page of query results"""
+
+    items: list[T]
+    total: int
+    page: int
+    page_size: int
+"#;
+
+const DEPENDENCIES: &str = r#""""Wiring of the event store and handlers, overridable in tests"""
+
+from fastapi import Depends
+
+from app.application.command_handlers import CommandHandlers
+from app.application.query_handlers import QueryHandlers
+from app.infrastructure.event_store import EventStore, InMemoryEventStore
+
+event_store = InMemoryEventStore()
+
+
+def get_event_store() -> EventStore:
+    return event_store
+
+
+def get_command_handlers(
+    store: EventStore = Depends(get_event_store),
+) -> CommandHandlers:
+    return CommandHandlers(store)
+
+
+def get_query_handlers(store: EventStore = Depends(get_event_store)) -> QueryHandlers:
+    return QueryHandlers(store)
+"#;
+
+const EVENT_STORE: &str = r#""""Event store
+
+Events are kept per aggregate in append order. Appends check the version
+the writer expects, so concurrent writers to one aggregate are detected.
+"""
+
+import asyncio
+from abc import ABC, abstractmethod
+
+from app.domain.events import DomainEvent
+
+
+class ConcurrencyError(Exception):
+    """The aggregate is not at the version the writer expected"""
+
+
+class EventStore(ABC):
+    """Event store"""
+
+    @abstractmethod
+    async def append_events(
+        self,
+        aggregate_id: str,
+        aggregate_type: str,
+        events: list[DomainEvent],
+        expected_version: int | None = None,
+    ) -> None:
+        """Append events to an aggregate
+
+        Nothing is written when `expected_version` does not match the
+        number of events already stored for the aggregate.
+        """
+
+    @abstractmethod
+    async def version(self, aggregate_id: str, aggregate_type: str) -> int:
+        """Number of events stored for an aggregate"""
+
+    @abstractmethod
+    async def load_events(
+        self, aggregate_id: str, aggregate_type: str
+    ) -> list[DomainEvent]:
+        """Events of an aggregate in append order"""
+
+    @abstractmethod
+    async def get_all_events(self) -> list[DomainEvent]:
+        """Events of all aggregates in append order"""
+
+
+class InMemoryEventStore(EventStore):
+    """Event store keeping events in memory, for development and tests"""
+
+    def __init__(self) -> None:
+        self._streams: dict[tuple[str, str], list[DomainEvent]] = {}
+        self._log: list[DomainEvent] = []
+        self._lock = asyncio.Lock()
+
+    async def append_events(
+        self,
+        aggregate_id: str,
+        aggregate_type: str,
+        events: list[DomainEvent],
+        expected_version: int | None = None,
+    ) -> None:
+        async with self._lock:
+            stream = self._streams.setdefault((aggregate_type, aggregate_id), [])
+            if expected_version is not None and expected_version != len(stream):
+                raise ConcurrencyError(
+                    f"Concurrency conflict: expected version {expected_version}, "
+                    f"found {len(stream)}"
+                )
+            stream.extend(events)
+            self._log.extend(events)
+
+    async def version(self, aggregate_id: str, aggregate_type: str) -> int:
+        return len(self._streams.get((aggregate_type, aggregate_id), []))
+
+    async def load_events(
+        self, aggregate_id: str, aggregate_type: str
+    ) -> list[DomainEvent]:
+        return list(self._streams.get((aggregate_type, aggregate_id), []))
+
+    async def get_all_events(self) -> list[DomainEvent]:
+        return list(self._log)
+"#;
+
+const CONFTEST: &str = r#""""Fixtures giving each test its own event store"""
+
+from collections.abc import Iterator
+
+import pytest
+from fastapi.testclient import TestClient
+
+from app.dependencies import get_event_store
+from app.infrastructure.event_store import InMemoryEventStore
+from app.main import app
+
+
+@pytest.fixture
+def event_store() -> InMemoryEventStore:
+    return InMemoryEventStore()
+
+
+@pytest.fixture
+def client(event_store: InMemoryEventStore) -> Iterator[TestClient]:
+    app.dependency_overrides[get_event_store] = lambda: event_store
+    with TestClient(app) as client:
+        yield client
+    app.dependency_overrides.clear()
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn model() -> IRModel {
+        IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "test.order"
+value_objects:
+  OrderId:
+    name: "OrderId"
+    type: "identifier"
+    underlying_type: "Uuid"
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "PAID"
+  OrderLine:
+    name: "OrderLine"
+    properties:
+      - name: "price"
+        type: "Money"
+  Money:
+    name: "Money"
+    properties:
+      - name: "amount"
+        type: "Decimal"
+        validation:
+          min: 0
+          precision: 2
+commands:
+  CancelOrder:
+    name: "CancelOrder"
+    payload:
+      - name: "orderId"
+        type: "OrderId"
+        required: true
+      - name: "reason"
+        type: "String"
+        required: false
+        validation:
+          maxLength: 200
+      - name: "from"
+        type: "String"
+events:
+  OrderCancelled:
+    name: "OrderCancelled"
+    aggregate: "Order"
+    payload:
+      - name: "status"
+        type: "OrderStatus"
+        default: "PAID"
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_models_carry_validation_rules() {
+        let model = model();
+        let value_objects = PythonGenerator::generate_value_objects(&model).unwrap();
+        assert!(value_objects.contains("OrderId = NewType(\"OrderId\", UUID)\n"));
+        assert!(value_objects.contains("class OrderStatus(str, Enum):\n    PAID = \"Paid\"\n"));
+        assert!(
+            value_objects.find("class Money").unwrap()
+                < value_objects.find("class OrderLine").unwrap()
+        );
+        assert!(value_objects.contains("    amount: Decimal = Field(ge=0, decimal_places=2)\n"));
+        assert!(value_objects.starts_with(
+            "\"\"\"Value objects\"\"\"\n\nfrom decimal import Decimal\nfrom enum import Enum\nfrom typing import NewType\nfrom uuid import UUID\n\nfrom pydantic import BaseModel, ConfigDict, Field\n"
+        ));
+
+        let commands = PythonGenerator::generate_commands(&model);
+        assert!(commands.contains("    order_id: OrderId\n"));
+        assert!(commands.contains("    reason: str | None = Field(default=None, max_length=200)\n"));
+        assert!(commands.contains("    from_: str = Field(alias=\"from\")\n"));
+        assert!(commands.contains("from app.domain.value_objects import OrderId\n"));
+    }
+
+    #[test]
+    fn test_events_are_tagged_by_type() {
+        let model = model();
+        let events = PythonGenerator::generate_events(&model);
+        assert!(events.contains("    type: Literal[\"OrderCancelled\"] = \"OrderCancelled\"\n"));
+        assert!(events.contains("    status: OrderStatus = OrderStatus.PAID\n"));
+        assert!(events.contains("DomainEvent = OrderCancelled\n"));
+
+        let routes = PythonGenerator::generate_command_routes(&model);
+        assert!(
+            routes.contains("@router.post(\"/cancel-order\", response_model=ApiResponse[str])\n")
+        );
+    }
+}
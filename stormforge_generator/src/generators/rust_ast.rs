@@ -0,0 +1,594 @@
+use crate::generators::utils::*;
+use crate::ir::{IRModel, Property};
+use anyhow::{Context, Result};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::parse::{ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::visit_mut::VisitMut;
+
+/// Rust keywords that must be emitted as raw identifiers
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try", "typeof",
+    "unsized", "virtual", "yield", "gen",
+];
+
+/// Parse a token stream as a Rust file and pretty-print it
+///
+/// Items are separated by blank lines, also inside inline modules, except
+/// for runs of `use` and `mod` declarations which are kept together.
+pub fn render(tokens: TokenStream) -> Result<String> {
+    let file: syn::File = syn::parse2(tokens).context("Generated code is not valid Rust")?;
+
+    let mut output = String::new();
+    if !file.attrs.is_empty() {
+        output.push_str(&unparse(file.attrs.clone(), vec![]));
+    }
+    render_items(&file.items, 0, &mut output);
+
+    Ok(output)
+}
+
+/// Render the items of a module `depth` levels deep
+fn render_items(items: &[syn::Item], depth: usize, output: &mut String) {
+    let declaration = |item: &syn::Item| match item {
+        syn::Item::Mod(module) => module.content.is_none(),
+        _ => false,
+    };
+    let mut previous: Option<&syn::Item> = None;
+    for item in items {
+        let grouped = match (previous, item) {
+            (Some(syn::Item::Use(_)), syn::Item::Use(_)) => true,
+            (Some(previous), item) => declaration(previous) && declaration(item),
+            // The first item of a module opens it, that of a file follows its docs
+            (None, _) => depth > 0,
+        };
+        if !output.is_empty() && !grouped {
+            output.push('\n');
+        }
+        match item {
+            syn::Item::Mod(module) if module.content.is_some() => {
+                let mut header = module.clone();
+                let items = header
+                    .content
+                    .as_mut()
+                    .map(|(_, items)| std::mem::take(items))
+                    .unwrap_or_default();
+                // `mod name {}`, or `mod name {\n #![..]\n}` with inner attributes
+                let header = unparse_at(syn::Item::Mod(header), depth);
+                output.push_str(header.trim_end().trim_end_matches('}').trim_end());
+                output.push('\n');
+                render_items(&items, depth + 1, output);
+                output.push_str(&"    ".repeat(depth));
+                output.push_str("}\n");
+            }
+            _ => output.push_str(&unparse_at(item.clone(), depth)),
+        }
+        previous = Some(item);
+    }
+}
+
+/// Pretty-print an item as if `depth` modules deep, so that it is indented
+/// and wrapped as it would be there
+fn unparse_at(item: syn::Item, depth: usize) -> String {
+    let mut item = item;
+    for _ in 0..depth {
+        item = syn::Item::Mod(syn::parse_quote! { mod nested { #item } });
+    }
+    let code = unparse(vec![], vec![item]);
+    let lines: Vec<&str> = code.lines().collect();
+    let mut output = String::new();
+    for line in &lines[depth..lines.len() - depth] {
+        output.push_str(line);
+        output.push('\n');
+    }
+    output
+}
+
+fn unparse(attrs: Vec<syn::Attribute>, items: Vec<syn::Item>) -> String {
+    let mut file = syn::File {
+        shebang: None,
+        attrs,
+        items,
+    };
+    let mut bodies = MacroBodies::default();
+    bodies.visit_file_mut(&mut file);
+    bodies.restore(prettyplease::unparse(&file))
+}
+
+/// Macros that prettyplease formats itself
+const STANDARD_MACROS: &[&str] = &[
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "format",
+    "panic",
+    "print",
+    "println",
+    "eprint",
+    "eprintln",
+    "todo",
+    "unimplemented",
+    "unreachable",
+    "vec",
+    "write",
+    "writeln",
+    "matches",
+];
+
+/// Placeholder macro of a line comment, see [`comment`]
+const COMMENT_MACRO: &str = "__comment__";
+
+/// Formats the bodies of macros prettyplease prints as raw tokens
+///
+/// prettyplease only understands the standard library macros, so any other
+/// macro would come out as `x. await` or `json!({ "a" : b })`. Macros whose
+/// arguments are expressions become calls to a placeholder function and
+/// macros whose body is a block become labelled blocks, which are formatted
+/// as code and turned back into macros by [`MacroBodies::restore`]. `json!`
+/// bodies are printed on one line with their values formatted, and comment
+/// placeholders become `//` comments.
+#[derive(Default)]
+struct MacroBodies {
+    /// Text replacing each placeholder, by index
+    replacements: Vec<(String, String)>,
+}
+
+impl MacroBodies {
+    fn placeholder(&mut self, replacement: String) -> Ident {
+        let name = format!("__macro_{}__", self.replacements.len());
+        self.replacements.push((name.clone(), replacement));
+        Ident::new(&name, Span::call_site())
+    }
+
+    /// Placeholder expression for a macro, if its body can be formatted
+    fn replace(&mut self, mac: &syn::Macro) -> Option<syn::Expr> {
+        let name = mac.path.segments.last()?.ident.to_string();
+        if name == COMMENT_MACRO {
+            let text: syn::LitStr = syn::parse2(mac.tokens.clone()).ok()?;
+            let placeholder = self.placeholder(format!("// {}", text.value()));
+            return Some(syn::parse_quote!(#placeholder));
+        }
+        if STANDARD_MACROS.contains(&name.as_str()) {
+            return None;
+        }
+        let path = macro_path(&mac.path);
+
+        if name == "json" {
+            let body = json_value.parse2(mac.tokens.clone()).ok()?;
+            let placeholder = self.placeholder(format!("{}!({})", path, body));
+            return Some(syn::parse_quote!(#placeholder));
+        }
+
+        match mac.delimiter {
+            syn::MacroDelimiter::Paren(_) => {
+                let args = Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated
+                    .parse2(mac.tokens.clone())
+                    .ok()?;
+                let placeholder = self.placeholder(format!("{}!", path));
+                Some(syn::parse_quote!(#placeholder(#args)))
+            }
+            syn::MacroDelimiter::Brace(_) => {
+                let stmts = syn::Block::parse_within.parse2(mac.tokens.clone()).ok()?;
+                let label = syn::Lifetime::new(
+                    &format!("'{}", self.placeholder(format!("{}!", path))),
+                    Span::call_site(),
+                );
+                Some(syn::parse_quote!(#label: { #(#stmts)* }))
+            }
+            syn::MacroDelimiter::Bracket(_) => None,
+        }
+    }
+
+    /// Turn the placeholders in formatted code back into macros
+    fn restore(&self, mut code: String) -> String {
+        for (name, replacement) in self.replacements.iter().rev() {
+            if replacement.starts_with("//") {
+                code = code.replace(&format!("{};", name), replacement);
+            }
+            code = code
+                .replace(&format!("'{}: {{", name), &format!("{} {{", replacement))
+                .replace(name, replacement);
+        }
+        code
+    }
+}
+
+impl VisitMut for MacroBodies {
+    fn visit_expr_mut(&mut self, expr: &mut syn::Expr) {
+        if let syn::Expr::Macro(mac) = expr {
+            if let Some(replacement) = self.replace(&mac.mac) {
+                *expr = replacement;
+            }
+        }
+        syn::visit_mut::visit_expr_mut(self, expr);
+    }
+
+    fn visit_stmt_mut(&mut self, stmt: &mut syn::Stmt) {
+        if let syn::Stmt::Macro(mac) = stmt {
+            if let Some(replacement) = self.replace(&mac.mac) {
+                *stmt = syn::Stmt::Expr(replacement, mac.semi_token);
+            }
+        }
+        syn::visit_mut::visit_stmt_mut(self, stmt);
+    }
+
+    /// Standard macros keep their arguments as tokens, so macros nested in
+    /// them are reached by parsing the arguments as expressions
+    fn visit_macro_mut(&mut self, mac: &mut syn::Macro) {
+        let Ok(mut args) =
+            Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated.parse2(mac.tokens.clone())
+        else {
+            return;
+        };
+        for arg in args.iter_mut() {
+            self.visit_expr_mut(arg);
+        }
+        mac.tokens = args.to_token_stream();
+    }
+}
+
+fn macro_path(path: &syn::Path) -> String {
+    let segments: Vec<String> = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    let prefix = if path.leading_colon.is_some() {
+        "::"
+    } else {
+        ""
+    };
+    format!("{}{}", prefix, segments.join("::"))
+}
+
+/// One `json!` value: an object, an array or a Rust expression
+fn json_value(input: ParseStream) -> syn::Result<String> {
+    if input.peek(syn::token::Brace) {
+        let content;
+        syn::braced!(content in input);
+        let mut entries = Vec::new();
+        while !content.is_empty() {
+            let key: syn::LitStr = content.parse()?;
+            content.parse::<syn::Token![:]>()?;
+            entries.push(format!(
+                "{}: {}",
+                key.to_token_stream(),
+                json_value(&content)?
+            ));
+            if !content.is_empty() {
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+        if entries.is_empty() {
+            return Ok("{}".to_string());
+        }
+        return Ok(format!("{{ {} }}", entries.join(", ")));
+    }
+
+    if input.peek(syn::token::Bracket) {
+        let content;
+        syn::bracketed!(content in input);
+        let mut items = Vec::new();
+        while !content.is_empty() {
+            items.push(json_value(&content)?);
+            if !content.is_empty() {
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+        return Ok(format!("[{}]", items.join(", ")));
+    }
+
+    let expr: syn::Expr = input.parse()?;
+    let code = unparse(vec![], vec![syn::parse_quote!(const _: () = #expr;)]);
+    code.strip_prefix("const _: () = ")
+        .and_then(|code| code.strip_suffix(";\n"))
+        .filter(|code| !code.contains('\n'))
+        .map(str::to_string)
+        .ok_or_else(|| input.error("json! value does not fit on one line"))
+}
+
+/// Create an identifier, escaping keywords as raw identifiers
+pub fn ident(name: &str) -> Result<Ident> {
+    if KEYWORDS.contains(&name) {
+        return Ok(Ident::new_raw(name, Span::call_site()));
+    }
+    syn::parse_str::<Ident>(name).with_context(|| format!("Invalid Rust identifier: {}", name))
+}
+
+/// Name of the generated crate as used in paths
+pub fn crate_ident(model: &IRModel) -> Result<Ident> {
+    ident(&to_kebab_case(&model.bounded_context.name).replace('-', "_"))
+}
+
+/// Create a snake_case field or function identifier
+pub fn snake_ident(name: &str) -> Result<Ident> {
+    ident(&to_snake_case(name))
+}
+
+/// Parse an IR type into a Rust type
+pub fn rust_type(ir_type: &str) -> Result<syn::Type> {
+    let rust_type = to_rust_type(ir_type);
+    syn::parse_str(&rust_type).with_context(|| format!("Invalid type: {}", ir_type))
+}
+
+/// Rust type of a property, wrapped in `Option` when it is not required
+pub fn property_type(prop: &Property) -> Result<syn::Type> {
+    let rust_type = rust_type(&prop.prop_type)?;
+    if prop.required {
+        Ok(rust_type)
+    } else {
+        Ok(syn::parse_quote!(Option<#rust_type>))
+    }
+}
+
+/// Build `pub` struct fields for a list of properties
+pub fn struct_fields(properties: &[Property]) -> Result<Vec<TokenStream>> {
+    properties
+        .iter()
+        .map(|prop| {
+            let doc = doc(prop.description.as_deref());
+            let name = snake_ident(&prop.name)?;
+            let field_type = property_type(prop)?;
+            Ok(quote! {
+                #doc
+                pub #name: #field_type
+            })
+        })
+        .collect()
+}
+
+/// `async-graphql` derives of a struct, enabled by the `graphql` feature
+///
+/// Input objects get an `Input` suffix so that a struct can be both. Structs
+/// without fields get none, as GraphQL objects need at least one field.
+pub fn graphql_derives(
+    name: &str,
+    properties: &[Property],
+    output: bool,
+    input: bool,
+) -> TokenStream {
+    if properties.is_empty() {
+        return TokenStream::new();
+    }
+    let input_name = format!("{}Input", name);
+    match (output, input) {
+        (true, true) => quote! {
+            #[cfg_attr(
+                feature = "graphql",
+                derive(async_graphql::SimpleObject, async_graphql::InputObject),
+                graphql(input_name = #input_name)
+            )]
+        },
+        (true, false) => quote! {
+            #[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+        },
+        (false, true) => quote! {
+            #[cfg_attr(
+                feature = "graphql",
+                derive(async_graphql::InputObject),
+                graphql(name = #input_name)
+            )]
+        },
+        (false, false) => TokenStream::new(),
+    }
+}
+
+/// Line comment statement, such as a `TODO` left in a function body
+///
+/// quote drops comments, so the comment travels as a placeholder macro that
+/// [`render`] prints as `// text`.
+pub fn comment(text: &str) -> TokenStream {
+    let name = Ident::new(COMMENT_MACRO, Span::call_site());
+    quote! { #name!(#text); }
+}
+
+/// Build doc attributes from an optional description
+pub fn doc(text: Option<&str>) -> TokenStream {
+    match text {
+        Some(text) => doc_lines(text),
+        None => TokenStream::new(),
+    }
+}
+
+/// Build one doc attribute per line of text
+pub fn doc_lines(text: &str) -> TokenStream {
+    let lines = text.lines().map(|line| {
+        if line.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", line)
+        }
+    });
+    quote! { #(#[doc = #lines])* }
+}
+
+/// Whether `code` mentions one of `names` as an identifier, other than at
+/// the end of a path such as `chrono::Utc`
+pub fn mentions<S: AsRef<str>>(code: &TokenStream, names: &[S]) -> bool {
+    let mut colons = 0;
+    code.clone().into_iter().any(|token| {
+        let found = match &token {
+            proc_macro2::TokenTree::Ident(ident) => {
+                colons < 2 && names.iter().any(|name| ident == name.as_ref())
+            }
+            proc_macro2::TokenTree::Group(group) => mentions(&group.stream(), names),
+            _ => false,
+        };
+        colons = match &token {
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ':' => colons + 1,
+            _ => 0,
+        };
+        found
+    })
+}
+
+/// Names of the types `crate::domain::entities` provides, including the
+/// shared value objects a workspace member re-exports
+pub fn entity_names(model: &IRModel) -> Vec<String> {
+    let referenced = model
+        .commands
+        .values()
+        .flat_map(|command| &command.payload)
+        .chain(model.events.values().flat_map(|event| &event.payload))
+        .filter_map(|prop| referenced_type(&prop.prop_type));
+    model
+        .value_objects
+        .keys()
+        .chain(model.aggregates.keys())
+        .cloned()
+        .chain(referenced)
+        .collect()
+}
+
+/// Names of the types `crate::domain::events` defines
+pub fn event_names(model: &IRModel) -> Vec<String> {
+    model
+        .events
+        .keys()
+        .cloned()
+        .chain(["DomainEvent".to_string(), "EventMetadata".to_string()])
+        .collect()
+}
+
+/// `use` of those of `names` under `path` that `code` mentions, or nothing
+pub fn use_mentioned(path: TokenStream, names: &[&str], code: &TokenStream) -> TokenStream {
+    let names: Vec<Ident> = names
+        .iter()
+        .filter(|name| mentions(code, &[name]))
+        .map(|name| Ident::new(name, Span::call_site()))
+        .collect();
+    match names.as_slice() {
+        [] => TokenStream::new(),
+        [name] => quote! { use #path::#name; },
+        names => quote! { use #path::{#(#names),*}; },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keywords_become_raw_identifiers() {
+        assert_eq!(ident("type").unwrap().to_string(), "r#type");
+        assert_eq!(ident("order_id").unwrap().to_string(), "order_id");
+        assert!(ident("not valid").is_err());
+    }
+
+    #[test]
+    fn test_render_formats_doc_comments() {
+        let doc = doc(Some("An order"));
+        let code = render(quote! {
+            #doc
+            pub struct Order { pub id: String }
+        })
+        .unwrap();
+        assert_eq!(
+            code,
+            "/// An order\npub struct Order {\n    pub id: String,\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_render_separates_items_of_inline_modules() {
+        let code = render(quote! {
+            mod a;
+            mod b;
+            pub mod contracts {
+                use serde::Serialize;
+                use uuid::Uuid;
+                pub struct Placed { pub id: Uuid }
+                pub struct Shipped;
+            }
+        })
+        .unwrap();
+        assert_eq!(
+            code,
+            concat!(
+                "mod a;\n",
+                "mod b;\n",
+                "\n",
+                "pub mod contracts {\n",
+                "    use serde::Serialize;\n",
+                "    use uuid::Uuid;\n",
+                "\n",
+                "    pub struct Placed {\n",
+                "        pub id: Uuid,\n",
+                "    }\n",
+                "\n",
+                "    pub struct Shipped;\n",
+                "}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_imports_only_mentioned_names() {
+        let code = quote! {
+            pub struct Paid {
+                pub at: DateTime<Utc>,
+                pub on: chrono::NaiveDate,
+                pub amounts: Vec<Option<Decimal>>,
+            }
+        };
+        assert_eq!(
+            use_mentioned(quote! { chrono }, &["DateTime", "Utc", "NaiveDate"], &code).to_string(),
+            quote! { use chrono::{DateTime, Utc}; }.to_string()
+        );
+        assert_eq!(
+            use_mentioned(quote! { rust_decimal }, &["Decimal"], &code).to_string(),
+            quote! { use rust_decimal::Decimal; }.to_string()
+        );
+        assert!(use_mentioned(quote! { uuid }, &["Uuid"], &code).is_empty());
+    }
+
+    #[test]
+    fn test_render_formats_macro_bodies() {
+        let code = render(quote! {
+            async fn check(bus: &Bus, id: &str) {
+                assert_eq!(bus.read_from(1).await.unwrap()[0].event_type, "Second");
+                tracing::info!("Published {}", bus.last().await.id);
+                bus.send(serde_json::json!({ "type": "Opened", "ids": [id] }));
+            }
+        })
+        .unwrap();
+        assert_eq!(
+            code,
+            concat!(
+                "async fn check(bus: &Bus, id: &str) {\n",
+                "    assert_eq!(bus.read_from(1).await.unwrap()[0].event_type, \"Second\");\n",
+                "    tracing::info!(\"Published {}\", bus.last().await.id);\n",
+                "    bus.send(serde_json::json!({ \"type\": \"Opened\", \"ids\": [id] }));\n",
+                "}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_formats_comments() {
+        let todo = comment("TODO: check the stock");
+        let code = render(quote! {
+            fn check() -> bool {
+                #todo
+                true
+            }
+        })
+        .unwrap();
+        assert_eq!(
+            code,
+            "fn check() -> bool {\n    // TODO: check the stock\n    true\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_render_rejects_invalid_code() {
+        assert!(render(quote! { pub struct { } }).is_err());
+    }
+}
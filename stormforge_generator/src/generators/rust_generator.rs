@@ -1,17 +1,56 @@
 use crate::generators::{
-    api_generator::*, command_generator::*, entity_generator::*, event_generator::*, utils::*,
+    aggregate_generator::*, api_generator::*, asyncapi_generator::*, command_generator::*,
+    entity_generator::*, event_generator::*, expression_generator::*, graphql_generator::*,
+    integration_generator::*, outbox_generator::*, persistence_generator::*, policy_generator::*,
+    projection_generator::*, proto_generator::*, rust_ast::*, strategy_generator::*,
+    test_generator::*, utils::*, workspace_generator::INTEGRATION_EVENTS_CRATE,
 };
-use crate::ir::IRModel;
+use crate::ir::{IRModel, ValueObject};
 use anyhow::{Context, Result};
+use indexmap::IndexMap;
+use quote::quote;
 use std::fs;
+use std::path::Path;
 
 pub struct RustGenerator {
     output_dir: String,
+    workspace_crates: Option<Vec<WorkspaceCrate>>,
+    shared_value_objects: IndexMap<String, ValueObject>,
+}
+
+/// Sibling crate a workspace member depends on
+#[derive(Debug, Clone)]
+pub struct WorkspaceCrate {
+    pub name: String,
+    /// Re-export the crate's items from the domain entities module
+    pub reexport: bool,
 }
 
 impl RustGenerator {
     pub fn new(output_dir: String) -> Self {
-        Self { output_dir }
+        Self {
+            output_dir,
+            workspace_crates: None,
+            shared_value_objects: IndexMap::new(),
+        }
+    }
+
+    /// Generate the crate as a workspace member depending on sibling crates
+    pub fn with_workspace_crates(mut self, crates: Vec<WorkspaceCrate>) -> Self {
+        self.workspace_crates = Some(crates);
+        self
+    }
+
+    /// Value objects of the shared kernel the context uses
+    ///
+    /// They are not generated in the crate, but its proto file and AsyncAPI
+    /// document still declare them.
+    pub fn with_shared_value_objects(
+        mut self,
+        value_objects: IndexMap<String, ValueObject>,
+    ) -> Self {
+        self.shared_value_objects = value_objects;
+        self
     }
 
     /// Generate a complete Rust microservice from an IR model
@@ -22,7 +61,7 @@ impl RustGenerator {
         );
 
         // Create output directory structure
-        self.create_directory_structure()?;
+        self.create_directory_structure(model)?;
 
         // Generate Cargo.toml
         self.generate_cargo_toml(model)?;
@@ -31,16 +70,35 @@ impl RustGenerator {
         self.generate_domain_entities(model)?;
         self.generate_domain_commands(model)?;
         self.generate_domain_events(model)?;
+        self.generate_domain_expressions()?;
+        self.generate_domain_state(model)?;
+        self.generate_domain_handlers(model)?;
         self.generate_api_routes(model)?;
+        self.generate_grpc(model)?;
+        self.generate_graphql(model)?;
+        self.generate_asyncapi(model)?;
         self.generate_main(model)?;
         self.generate_lib(model)?;
 
         // Generate repository layer
         self.generate_repository(model)?;
 
-        // Generate event store
+        // Generate event store and transactional outbox
         self.generate_event_store(model)?;
 
+        // Generate event bus and integration event consumers
+        self.generate_event_bus(model)?;
+        self.generate_integration(model)?;
+
+        // Generate policies and sagas
+        self.generate_policies(model)?;
+
+        // Generate read model projections
+        self.generate_projections(model)?;
+
+        // Generate aggregate test harness and starter tests
+        self.generate_tests(model)?;
+
         // Generate README
         self.generate_readme(model)?;
 
@@ -49,16 +107,29 @@ impl RustGenerator {
         Ok(())
     }
 
-    fn create_directory_structure(&self) -> Result<()> {
-        let dirs = vec![
+    fn create_directory_structure(&self, model: &IRModel) -> Result<()> {
+        let mut dirs = vec![
             format!("{}/src", self.output_dir),
             format!("{}/src/domain", self.output_dir),
             format!("{}/src/api", self.output_dir),
+            format!("{}/proto", self.output_dir),
             format!("{}/src/infrastructure", self.output_dir),
             format!("{}/src/repository", self.output_dir),
-            format!("{}/tests", self.output_dir),
+            format!("{}/tests/support", self.output_dir),
         ];
 
+        if !model.external_events.is_empty() {
+            dirs.push(format!("{}/src/integration", self.output_dir));
+        }
+
+        if !model.policies.is_empty() {
+            dirs.push(format!("{}/src/policies", self.output_dir));
+        }
+
+        if !model.read_models.is_empty() {
+            dirs.push(format!("{}/src/projections", self.output_dir));
+        }
+
         for dir in dirs {
             fs::create_dir_all(&dir)
                 .with_context(|| format!("Failed to create directory: {}", dir))?;
@@ -70,7 +141,7 @@ impl RustGenerator {
     fn generate_cargo_toml(&self, model: &IRModel) -> Result<()> {
         let package_name = to_kebab_case(&model.bounded_context.name);
 
-        let content = format!(
+        let mut content = format!(
             r#"[package]
 name = "{}"
 version = "0.1.0"
@@ -98,7 +169,7 @@ uuid = {{ version = "1.10", features = ["v4", "serde"] }}
 rust_decimal = {{ version = "1.35", features = ["serde"] }}
 
 # Database (optional)
-sqlx = {{ version = "0.7", features = ["runtime-tokio-native-tls", "postgres", "uuid", "chrono"], optional = true }}
+sqlx = {{ version = "0.7", features = ["runtime-tokio-native-tls", "postgres", "sqlite", "uuid", "chrono"], optional = true }}
 
 # Async
 async-trait = "0.1"
@@ -115,17 +186,72 @@ tracing-subscriber = {{ version = "0.3", features = ["env-filter"] }}
 utoipa = {{ version = "4", features = ["axum_extras"] }}
 utoipa-swagger-ui = {{ version = "7", features = ["axum"] }}
 
+# gRPC (optional)
+tonic = {{ version = "0.12", optional = true }}
+prost = {{ version = "0.13", optional = true }}
+prost-types = {{ version = "0.13", optional = true }}
+
+# GraphQL (optional)
+async-graphql = {{ version = "7", features = ["chrono", "decimal", "uuid"], optional = true }}
+# Later releases of async-graphql-axum require axum 0.8
+async-graphql-axum = {{ version = ">=7.0, <7.0.14", optional = true }}
+async-stream = {{ version = "0.3", optional = true }}
+"#,
+            package_name
+        );
+
+        let workspace_crates = self.workspace_crates.as_deref().unwrap_or_default();
+        if !workspace_crates.is_empty() {
+            content.push_str("\n# Workspace crates\n");
+            for dependency in workspace_crates {
+                content.push_str(&format!(
+                    "{} = {{ path = \"../{}\" }}\n",
+                    dependency.name, dependency.name
+                ));
+            }
+        }
+
+        content.push_str(
+            r#"
+[build-dependencies]
+tonic-build = { version = "0.12", optional = true }
+protoc-bin-vendored = { version = "3", optional = true }
+
+[dev-dependencies]
+proptest = "1"
+
 [features]
 default = []
 sqlx = ["dep:sqlx"]
+grpc = ["dep:tonic", "dep:prost", "dep:prost-types", "dep:tonic-build", "dep:protoc-bin-vendored"]
+"#,
+        );
+
+        // Shared value objects need their GraphQL derives as well
+        let mut graphql_features = vec![
+            "\"dep:async-graphql\"".to_string(),
+            "\"dep:async-graphql-axum\"".to_string(),
+            "\"dep:async-stream\"".to_string(),
+        ];
+        for dependency in workspace_crates
+            .iter()
+            .filter(|dependency| dependency.reexport)
+        {
+            graphql_features.push(format!("\"{}/graphql\"", dependency.name));
+        }
+        content.push_str(&format!("graphql = [{}]\n", graphql_features.join(", ")));
 
+        // Profiles are only honoured in the workspace root
+        if self.workspace_crates.is_none() {
+            content.push_str(
+                r#"
 [profile.release]
 opt-level = 3
 lto = true
 codegen-units = 1
 "#,
-            package_name
-        );
+            );
+        }
 
         let path = format!("{}/Cargo.toml", self.output_dir);
         fs::write(&path, content)
@@ -135,7 +261,14 @@ codegen-units = 1
     }
 
     fn generate_domain_entities(&self, model: &IRModel) -> Result<()> {
-        let code = EntityGenerator::generate(model)?;
+        let reexports: Vec<String> = self
+            .workspace_crates
+            .iter()
+            .flatten()
+            .filter(|dependency| dependency.reexport)
+            .map(|dependency| to_snake_case(&dependency.name))
+            .collect();
+        let code = EntityGenerator::generate(model, &reexports)?;
 
         let path = format!("{}/src/domain/entities.rs", self.output_dir);
         fs::write(&path, code).with_context(|| format!("Failed to write entities to {}", path))?;
@@ -161,6 +294,36 @@ codegen-units = 1
         Ok(())
     }
 
+    fn generate_domain_expressions(&self) -> Result<()> {
+        let code = ExpressionGenerator::generate_runtime()?;
+
+        let path = format!("{}/src/domain/expressions.rs", self.output_dir);
+        fs::write(&path, code)
+            .with_context(|| format!("Failed to write expressions to {}", path))?;
+
+        Ok(())
+    }
+
+    fn generate_domain_state(&self, model: &IRModel) -> Result<()> {
+        let code = AggregateGenerator::generate_state(model)?;
+
+        let path = format!("{}/src/domain/state.rs", self.output_dir);
+        fs::write(&path, code)
+            .with_context(|| format!("Failed to write aggregate state to {}", path))?;
+
+        Ok(())
+    }
+
+    fn generate_domain_handlers(&self, model: &IRModel) -> Result<()> {
+        let code = AggregateGenerator::generate_handlers(model)?;
+
+        let path = format!("{}/src/domain/handlers.rs", self.output_dir);
+        fs::write(&path, code)
+            .with_context(|| format!("Failed to write command handler to {}", path))?;
+
+        Ok(())
+    }
+
     fn generate_api_routes(&self, model: &IRModel) -> Result<()> {
         let code = ApiGenerator::generate(model)?;
 
@@ -169,70 +332,351 @@ codegen-units = 1
             .with_context(|| format!("Failed to write API routes to {}", path))?;
 
         // Generate api mod.rs
-        let mod_content = "pub mod routes;\n\npub use routes::*;\n";
+        let graphql_mod = if GraphQLGenerator::supported(model) {
+            quote! {
+                #[cfg(feature = "graphql")]
+                pub mod graphql;
+            }
+        } else {
+            quote! {}
+        };
+        let mod_content = render(quote! {
+            #graphql_mod
+            #[cfg(feature = "grpc")]
+            pub mod grpc;
+            pub mod routes;
+
+            pub use routes::*;
+        })?;
         let mod_path = format!("{}/src/api/mod.rs", self.output_dir);
         fs::write(&mod_path, mod_content)?;
 
         Ok(())
     }
 
+    /// The model with the shared kernel value objects it uses
+    fn with_shared_kernel(&self, model: &IRModel) -> IRModel {
+        let mut model_with_kernel = model.clone();
+        model_with_kernel.value_objects = self.shared_value_objects.clone();
+        model_with_kernel
+            .value_objects
+            .extend(model.value_objects.clone());
+        model_with_kernel
+    }
+
+    fn generate_grpc(&self, model: &IRModel) -> Result<()> {
+        let proto_model = self.with_shared_kernel(model);
+
+        // Field numbers of the previously generated proto file are kept
+        let proto_path = format!(
+            "{}/{}",
+            self.output_dir,
+            ProtoGenerator::proto_path(&proto_model)
+        );
+        let previous = fs::read_to_string(&proto_path).ok();
+        let proto = ProtoGenerator::generate_proto(&proto_model, previous.as_deref())?;
+        fs::write(&proto_path, proto)
+            .with_context(|| format!("Failed to write proto file to {}", proto_path))?;
+
+        let files = [
+            (
+                "build.rs",
+                ProtoGenerator::generate_build_script(&proto_model)?,
+            ),
+            (
+                "src/api/grpc.rs",
+                ProtoGenerator::generate_service(&proto_model)?,
+            ),
+        ];
+        for (name, code) in files {
+            let path = format!("{}/{}", self.output_dir, name);
+            fs::write(&path, code)
+                .with_context(|| format!("Failed to write gRPC API to {}", path))?;
+        }
+
+        Ok(())
+    }
+
+    fn generate_graphql(&self, model: &IRModel) -> Result<()> {
+        if !GraphQLGenerator::supported(model) {
+            return Ok(());
+        }
+        let graphql_model = self.with_shared_kernel(model);
+
+        let files = [
+            (SCHEMA_PATH, GraphQLGenerator::generate_sdl(&graphql_model)?),
+            (
+                "src/api/graphql.rs",
+                GraphQLGenerator::generate_resolvers(&graphql_model)?,
+            ),
+        ];
+        for (name, code) in files {
+            let path = format!("{}/{}", self.output_dir, name);
+            fs::write(&path, code)
+                .with_context(|| format!("Failed to write GraphQL API to {}", path))?;
+        }
+
+        Ok(())
+    }
+
+    fn generate_asyncapi(&self, model: &IRModel) -> Result<()> {
+        let document = AsyncApiGenerator::generate(&self.with_shared_kernel(model))?;
+
+        let path = format!("{}/{}", self.output_dir, ASYNCAPI_PATH);
+        fs::write(&path, document)
+            .with_context(|| format!("Failed to write AsyncAPI document to {}", path))?;
+
+        Ok(())
+    }
+
     fn generate_main(&self, model: &IRModel) -> Result<()> {
         let context_name = &model.bounded_context.name;
 
-        let content = format!(
-            r#"use axum::Router;
-use std::net::SocketAddr;
-use tower_http::cors::CorsLayer;
-use tracing_subscriber::{{layer::SubscriberExt, util::SubscriberInitExt}};
-use utoipa::OpenApi;
-use utoipa_swagger_ui::SwaggerUi;
-
-mod api;
-mod domain;
-mod infrastructure;
-mod repository;
-
-use crate::api::{{create_router, ApiDoc}};
-
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {{
-    // Initialize tracing
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "info".into()),
-        )
-        .with(tracing_subscriber::fmt::layer())
-        .init();
-
-    tracing::info!("Starting {{}} service...", "{}");
-
-    // Create API router
-    let api_router = create_router();
-    
-    // Create Swagger UI
-    let swagger = SwaggerUi::new("/swagger-ui")
-        .url("/api-docs/openapi.json", ApiDoc::openapi());
-
-    // Build application with middleware
-    let app = Router::new()
-        .merge(swagger)
-        .nest("/api", api_router)
-        .layer(CorsLayer::permissive());
-
-    // Start server
-    let addr = SocketAddr::from(([0, 0, 0, 0], 3000));
-    tracing::info!("Listening on http://{{}}", addr);
-    tracing::info!("Swagger UI available at http://{{}}/swagger-ui", addr);
-
-    let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
-
-    Ok(())
-}}
-"#,
-            context_name
-        );
+        // Poll the event bus for subscribed integration events
+        let (integration_mod, integration_setup) = if model.external_events.is_empty() {
+            (quote! {}, quote! {})
+        } else {
+            (
+                quote! { integration, },
+                quote! {
+                    let mut event_listener =
+                        integration::IntegrationEventListener::new(event_bus.clone());
+                    tokio::spawn(async move {
+                        loop {
+                            if let Err(e) = event_listener.poll().await {
+                                tracing::error!("Failed to process integration events: {}", e);
+                            }
+                            tokio::time::sleep(Duration::from_secs(1)).await;
+                        }
+                    });
+                },
+            )
+        };
+
+        // Run policies against this context's events, checking saga deadlines
+        let (policies_mod, policies_setup) = if model.policies.is_empty() {
+            (quote! {}, quote! {})
+        } else {
+            (
+                quote! { policies, },
+                quote! {
+                    #[cfg(feature = "sqlx")]
+                    let saga_store = Arc::new(
+                        policies::SqliteSagaStore::with_pool(event_store.pool().clone()).await?,
+                    );
+                    #[cfg(not(feature = "sqlx"))]
+                    let saga_store = Arc::new(policies::InMemorySagaStore::new());
+
+                    let mut policy_engine = policies::PolicyEngine::new(
+                        event_bus.clone(),
+                        Arc::new(policies::LoggingDispatcher),
+                        saga_store,
+                        policies::policies(),
+                    );
+                    tokio::spawn(async move {
+                        loop {
+                            if let Err(e) = policy_engine.poll().await {
+                                tracing::error!("Failed to run policies: {}", e);
+                            }
+                            if let Err(e) = policy_engine.check_timeouts(chrono::Utc::now()).await {
+                                tracing::error!("Failed to check saga timeouts: {}", e);
+                            }
+                            tokio::time::sleep(Duration::from_secs(1)).await;
+                        }
+                    });
+                },
+            )
+        };
+
+        // Keep read models up to date and serve them next to the API; the
+        // `--rebuild-projections` argument replays all events first
+        let (projections_mod, projections_setup, api_router) = if model.read_models.is_empty() {
+            (
+                quote! {},
+                quote! {},
+                quote! { create_router(app_state.clone()) },
+            )
+        } else {
+            (
+                quote! { projections, },
+                quote! {
+                    #[cfg(feature = "sqlx")]
+                    let read_models = projections::ReadModels::sqlite(
+                        event_store.clone(),
+                        event_store.pool().clone(),
+                    )
+                    .await?;
+                    #[cfg(not(feature = "sqlx"))]
+                    let read_models = projections::ReadModels::in_memory(event_store.clone());
+
+                    if std::env::args().any(|arg| arg == "--rebuild-projections") {
+                        let events = read_models.rebuild().await?;
+                        tracing::info!("Rebuilt projections from {} events", events);
+                    }
+
+                    let projection_updates = read_models.clone();
+                    tokio::spawn(async move {
+                        loop {
+                            if let Err(e) = projection_updates.catch_up().await {
+                                tracing::error!("Failed to update projections: {}", e);
+                            }
+                            tokio::time::sleep(Duration::from_secs(1)).await;
+                        }
+                    });
+                },
+                quote! { create_router(app_state.clone()).merge(projections::router(read_models)) },
+            )
+        };
+
+        // Serve the GraphQL API with the `graphql` feature, its subscription
+        // reading from the event store
+        let (graphql_setup, graphql_merge) = if GraphQLGenerator::supported(model) {
+            (
+                quote! {
+                    #[cfg(feature = "graphql")]
+                    let graphql_router = api::graphql::router(app_state.clone());
+                },
+                quote! {
+                    #[cfg(feature = "graphql")]
+                    let app = app.merge(graphql_router);
+                },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+
+        // Bring the tables of state-stored aggregates up to date
+        let migrate = if self.has_migrations(model)? {
+            quote! {
+                #[cfg(feature = "sqlx")]
+                {
+                    repository::migrate(event_store.pool()).await?;
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let app_state = if AggregateGenerator::stored_aggregates(model).is_empty() {
+            quote! {
+                let app_state = api::AppState::new(event_store.clone());
+            }
+        } else {
+            quote! {
+                #[cfg(feature = "sqlx")]
+                let app_state = api::AppState::with_repositories(
+                    event_store.clone(),
+                    repository::Repositories::sqlite(event_store.pool().clone()),
+                );
+                #[cfg(not(feature = "sqlx"))]
+                let app_state = api::AppState::new(event_store.clone());
+            }
+        };
+
+        // The binary uses the modules of the library rather than compiling
+        // them again, where everything it leaves unused would be dead code.
+        // Repositories are only set up over SQLite.
+        let krate = crate_ident(model)?;
+        let repository_mod = if self.has_migrations(model)?
+            || !AggregateGenerator::stored_aggregates(model).is_empty()
+        {
+            quote! {
+                #[cfg(feature = "sqlx")]
+                use ::#krate::repository;
+            }
+        } else {
+            quote! {}
+        };
+
+        let content = render(quote! {
+            use axum::{routing::get, Router};
+            use std::net::SocketAddr;
+            use std::sync::Arc;
+            use std::time::Duration;
+            use tower_http::cors::CorsLayer;
+            use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+            use utoipa_swagger_ui::SwaggerUi;
+
+            use ::#krate::{api, infrastructure, #integration_mod #policies_mod #projections_mod};
+            use ::#krate::api::create_router;
+            #repository_mod
+
+            #[tokio::main]
+            async fn main() -> Result<(), Box<dyn std::error::Error>> {
+                tracing_subscriber::registry()
+                    .with(
+                        tracing_subscriber::EnvFilter::try_from_default_env()
+                            .unwrap_or_else(|_| "info".into()),
+                    )
+                    .with(tracing_subscriber::fmt::layer())
+                    .init();
+
+                tracing::info!("Starting {} service...", #context_name);
+
+                let event_bus: Arc<dyn infrastructure::EventBus> = match std::env::var("EVENT_BUS_PATH") {
+                    Ok(path) => Arc::new(infrastructure::JsonlEventBus::new(path)),
+                    Err(_) => Arc::new(infrastructure::InMemoryEventBus::new()),
+                };
+
+                #[cfg(feature = "sqlx")]
+                let event_store = Arc::new(
+                    infrastructure::SqliteEventStore::connect(
+                        &std::env::var("DATABASE_URL")
+                            .unwrap_or_else(|_| "sqlite://events.db?mode=rwc".to_string()),
+                    )
+                    .await?,
+                );
+                #[cfg(not(feature = "sqlx"))]
+                let event_store = Arc::new(infrastructure::InMemoryEventStore::new());
+
+                #migrate
+
+                #app_state
+
+                #policies_setup
+
+                #projections_setup
+
+                #graphql_setup
+
+                let outbox_relay = infrastructure::OutboxRelay::new(event_store, event_bus.clone());
+                tokio::spawn(outbox_relay.run(Duration::from_secs(1)));
+
+                #integration_setup
+
+                let api_router = #api_router;
+
+                let swagger = SwaggerUi::new("/swagger-ui")
+                    .external_url_unchecked("/api-docs/openapi.json", api::openapi());
+
+                let app = Router::new()
+                    .merge(swagger)
+                    .route("/api-docs/asyncapi.json", get(api::asyncapi))
+                    .nest("/api", api_router)
+                    .layer(CorsLayer::permissive());
+                #graphql_merge
+
+                let addr = SocketAddr::from(([0, 0, 0, 0], 3000));
+                tracing::info!("Listening on http://{}", addr);
+                tracing::info!("Swagger UI available at http://{}/swagger-ui", addr);
+
+                #[cfg(feature = "grpc")]
+                {
+                    let grpc_addr = SocketAddr::from(([0, 0, 0, 0], 50051));
+                    tracing::info!("gRPC API listening on {}", grpc_addr);
+                    tokio::spawn(async move {
+                        if let Err(e) = api::grpc::serve(grpc_addr, app_state).await {
+                            tracing::error!("gRPC server failed: {}", e);
+                        }
+                    });
+                }
+
+                let listener = tokio::net::TcpListener::bind(addr).await?;
+                axum::serve(listener, app).await?;
+
+                Ok(())
+            }
+        })?;
 
         let path = format!("{}/src/main.rs", self.output_dir);
         fs::write(&path, content)
@@ -241,12 +685,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {{
         Ok(())
     }
 
-    fn generate_lib(&self, _model: &IRModel) -> Result<()> {
-        let content = r#"pub mod api;
-pub mod domain;
-pub mod infrastructure;
-pub mod repository;
-"#;
+    fn generate_lib(&self, model: &IRModel) -> Result<()> {
+        let integration_mod = if model.external_events.is_empty() {
+            quote! {}
+        } else {
+            quote! { pub mod integration; }
+        };
+
+        let policies_mod = if model.policies.is_empty() {
+            quote! {}
+        } else {
+            quote! { pub mod policies; }
+        };
+
+        let projections_mod = if model.read_models.is_empty() {
+            quote! {}
+        } else {
+            quote! { pub mod projections; }
+        };
+
+        let content = render(quote! {
+            pub mod api;
+            pub mod domain;
+            pub mod infrastructure;
+            #integration_mod
+            #policies_mod
+            #projections_mod
+            pub mod repository;
+        })?;
 
         let path = format!("{}/src/lib.rs", self.output_dir);
         fs::write(&path, content)?;
@@ -254,60 +720,173 @@ pub mod repository;
         Ok(())
     }
 
-    fn generate_repository(&self, _model: &IRModel) -> Result<()> {
-        let content = r#"//! Repository layer for data persistence
-//!
-//! This module provides repository implementations for aggregates.
-
-use async_trait::async_trait;
-use anyhow::Result;
-
-use crate::domain::entities::*;
-
-/// Generic repository trait for aggregates
-#[async_trait]
-pub trait Repository<T>: Send + Sync {
-    /// Find an aggregate by ID
-    async fn find_by_id(&self, id: &str) -> Result<Option<T>>;
-    
-    /// Save an aggregate
-    async fn save(&self, aggregate: &T) -> Result<()>;
-    
-    /// Delete an aggregate
-    async fn delete(&self, id: &str) -> Result<()>;
-}
-
-/// In-memory repository implementation (for development/testing)
-pub struct InMemoryRepository<T> {
-    _phantom: std::marker::PhantomData<T>,
-}
+    fn generate_repository(&self, model: &IRModel) -> Result<()> {
+        let persistence_model = self.with_shared_kernel(model);
 
-impl<T> InMemoryRepository<T> {
-    pub fn new() -> Self {
-        Self {
-            _phantom: std::marker::PhantomData,
+        // Migrations are only ever added, with the difference to the
+        // tables the existing ones create
+        let existing = self.existing_migrations()?;
+        if let Some((name, sql)) =
+            PersistenceGenerator::generate_migration(&persistence_model, &existing)?
+        {
+            let dir = format!("{}/migrations", self.output_dir);
+            fs::create_dir_all(&dir)
+                .with_context(|| format!("Failed to create directory: {}", dir))?;
+            let path = format!("{}/{}", dir, name);
+            fs::write(&path, sql)
+                .with_context(|| format!("Failed to write migration to {}", path))?;
         }
-    }
-}
 
-#[async_trait]
-impl<T: Send + Sync> Repository<T> for InMemoryRepository<T> {
-    async fn find_by_id(&self, _id: &str) -> Result<Option<T>> {
-        // TODO: Implement in-memory storage
-        Ok(None)
-    }
-    
-    async fn save(&self, _aggregate: &T) -> Result<()> {
-        // TODO: Implement in-memory storage
-        Ok(())
-    }
-    
-    async fn delete(&self, _id: &str) -> Result<()> {
-        // TODO: Implement in-memory storage
-        Ok(())
-    }
-}
-"#;
+        let mut repositories = Vec::new();
+        let mut stored = Vec::new();
+        for aggregate in AggregateGenerator::stored_aggregates(model) {
+            let field = snake_ident(&aggregate.name)?;
+            let entity = ident(&aggregate.name)?;
+            let sqlite = ident(&format!("Sqlite{}Repository", aggregate.name))?;
+            let id = PersistenceGenerator::aggregate_id(
+                &persistence_model,
+                aggregate,
+                quote! { aggregate },
+            )?;
+            stored.push((field, entity, sqlite, id));
+        }
+        let (arc_import, stored_repositories) = if stored.is_empty() {
+            (quote! {}, quote! {})
+        } else {
+            let fields = stored.iter().map(|(field, ..)| field).collect::<Vec<_>>();
+            let entities = stored.iter().map(|(_, entity, ..)| entity);
+            let sqlite = stored.iter().map(|(_, _, sqlite, _)| sqlite);
+            let ids = stored.iter().map(|(_, entity, _, id)| {
+                quote! { |aggregate: &#entity| #id }
+            });
+            let repositories = quote! {
+                /// Repositories of the state-stored aggregates that commands act on
+                #[derive(Clone)]
+                pub struct Repositories {
+                    #(pub #fields: Arc<dyn Repository<#entities>>,)*
+                }
+
+                impl Repositories {
+                    /// Repositories keeping the aggregates in memory
+                    pub fn in_memory() -> Self {
+                        Self {
+                            #(#fields: Arc::new(InMemoryRepository::new(#ids)),)*
+                        }
+                    }
+
+                    /// SQLite repositories over the tables created by `migrate`
+                    #[cfg(feature = "sqlx")]
+                    pub fn sqlite(pool: sqlx::SqlitePool) -> Self {
+                        Self {
+                            #(#fields: Arc::new(#sqlite::new(pool.clone())),)*
+                        }
+                    }
+                }
+            };
+            (quote! { use std::sync::Arc; }, repositories)
+        };
+        for aggregate in PersistenceGenerator::state_aggregates(model) {
+            let module = format!("sqlite_{}_repository", to_snake_case(&aggregate.name));
+            let path = format!("{}/src/repository/{}.rs", self.output_dir, module);
+            let code = PersistenceGenerator::generate_repository(&persistence_model, aggregate)?;
+            fs::write(&path, code)
+                .with_context(|| format!("Failed to write repository to {}", path))?;
+            repositories.push(ident(&module)?);
+        }
+        let migrate = if self.has_migrations(model)? {
+            quote! {
+                /// Migrations of the state-stored aggregates' tables, from `migrations/`
+                #[cfg(feature = "sqlx")]
+                pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!();
+
+                /// Apply the migrations the database has not seen yet
+                #[cfg(feature = "sqlx")]
+                pub async fn migrate(pool: &sqlx::SqlitePool) -> Result<()> {
+                    MIGRATOR.run(pool).await?;
+                    Ok(())
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let entities = mentions(&stored_repositories, &entity_names(model))
+            .then(|| quote! { use crate::domain::entities::*; });
+
+        let content = render(quote! {
+            //! Repository layer for data persistence
+            //!
+            //! This module provides repository implementations for aggregates.
+
+            use async_trait::async_trait;
+            use anyhow::Result;
+            use std::collections::HashMap;
+            #arc_import
+            use tokio::sync::RwLock;
+
+            #entities
+
+            #(
+                #[cfg(feature = "sqlx")]
+                pub mod #repositories;
+            )*
+
+            #(
+                #[cfg(feature = "sqlx")]
+                pub use #repositories::*;
+            )*
+
+            /// Generic repository trait for aggregates
+            #[async_trait]
+            pub trait Repository<T>: Send + Sync {
+                /// Find an aggregate by ID
+                async fn find_by_id(&self, id: &str) -> Result<Option<T>>;
+
+                /// Save an aggregate
+                async fn save(&self, aggregate: &T) -> Result<()>;
+
+                /// Delete an aggregate
+                async fn delete(&self, id: &str) -> Result<()>;
+            }
+
+            /// In-memory repository implementation (for development/testing)
+            pub struct InMemoryRepository<T> {
+                aggregates: RwLock<HashMap<String, T>>,
+                /// ID of an aggregate
+                id: fn(&T) -> String,
+            }
+
+            impl<T> InMemoryRepository<T> {
+                pub fn new(id: fn(&T) -> String) -> Self {
+                    Self {
+                        aggregates: RwLock::new(HashMap::new()),
+                        id,
+                    }
+                }
+            }
+
+            #[async_trait]
+            impl<T: Clone + Send + Sync> Repository<T> for InMemoryRepository<T> {
+                async fn find_by_id(&self, id: &str) -> Result<Option<T>> {
+                    Ok(self.aggregates.read().await.get(id).cloned())
+                }
+
+                async fn save(&self, aggregate: &T) -> Result<()> {
+                    let id = (self.id)(aggregate);
+                    self.aggregates.write().await.insert(id, aggregate.clone());
+                    Ok(())
+                }
+
+                async fn delete(&self, id: &str) -> Result<()> {
+                    self.aggregates.write().await.remove(id);
+                    Ok(())
+                }
+            }
+
+            #stored_repositories
+
+            #migrate
+        })?;
 
         let path = format!("{}/src/repository/mod.rs", self.output_dir);
         fs::write(&path, content)?;
@@ -315,108 +894,276 @@ impl<T: Send + Sync> Repository<T> for InMemoryRepository<T> {
         Ok(())
     }
 
-    fn generate_event_store(&self, _model: &IRModel) -> Result<()> {
-        let content = r#"//! Event sourcing infrastructure
-//!
-//! This module provides event store implementation for event sourcing.
-
-use async_trait::async_trait;
-use anyhow::Result;
-use chrono::{DateTime, Utc};
-use uuid::Uuid;
-
-use crate::domain::events::*;
-
-/// Event store trait
-#[async_trait]
-pub trait EventStore: Send + Sync {
-    /// Append events to the store
-    async fn append_events(
-        &self,
-        aggregate_id: &str,
-        aggregate_type: &str,
-        events: Vec<DomainEvent>,
-        expected_version: Option<i64>,
-    ) -> Result<()>;
-    
-    /// Load events for an aggregate
-    async fn load_events(
-        &self,
-        aggregate_id: &str,
-        aggregate_type: &str,
-    ) -> Result<Vec<DomainEvent>>;
-    
-    /// Get all events in order
-    async fn get_all_events(&self) -> Result<Vec<DomainEvent>>;
-}
-
-/// In-memory event store (for development/testing)
-pub struct InMemoryEventStore {
-    // TODO: Implement storage
-}
+    /// File names and contents of the migrations in the output directory
+    fn existing_migrations(&self) -> Result<Vec<(String, String)>> {
+        let dir = Path::new(&self.output_dir).join("migrations");
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
 
-impl InMemoryEventStore {
-    pub fn new() -> Self {
-        Self {}
+        let mut migrations = Vec::new();
+        for entry in fs::read_dir(&dir)
+            .with_context(|| format!("Failed to read migrations from {}", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "sql") {
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                let sql = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read migration {}", path.display()))?;
+                migrations.push((name.into_owned(), sql));
+            }
+        }
+        migrations.sort();
+        Ok(migrations)
     }
-}
 
-#[async_trait]
-impl EventStore for InMemoryEventStore {
-    async fn append_events(
-        &self,
-        _aggregate_id: &str,
-        _aggregate_type: &str,
-        _events: Vec<DomainEvent>,
-        _expected_version: Option<i64>,
-    ) -> Result<()> {
-        // TODO: Implement event storage
-        Ok(())
+    /// Whether the crate has migrations, either written earlier or for its
+    /// state-stored aggregates
+    fn has_migrations(&self, model: &IRModel) -> Result<bool> {
+        Ok(PersistenceGenerator::state_aggregates(model)
+            .next()
+            .is_some()
+            || !self.existing_migrations()?.is_empty())
     }
-    
-    async fn load_events(
-        &self,
-        _aggregate_id: &str,
-        _aggregate_type: &str,
-    ) -> Result<Vec<DomainEvent>> {
-        // TODO: Implement event loading
-        Ok(vec![])
-    }
-    
-    async fn get_all_events(&self) -> Result<Vec<DomainEvent>> {
-        // TODO: Implement event retrieval
-        Ok(vec![])
-    }
-}
-"#;
 
-        let path = format!("{}/src/infrastructure/event_store.rs", self.output_dir);
-        fs::write(&path, content)?;
+    fn generate_event_store(&self, _model: &IRModel) -> Result<()> {
+        let files = [
+            ("event_store.rs", OutboxGenerator::generate_event_store()?),
+            (
+                "sqlite_event_store.rs",
+                OutboxGenerator::generate_sqlite_event_store()?,
+            ),
+            ("outbox.rs", OutboxGenerator::generate_outbox()?),
+        ];
+
+        for (name, code) in files {
+            let path = format!("{}/src/infrastructure/{}", self.output_dir, name);
+            fs::write(&path, code)
+                .with_context(|| format!("Failed to write event store to {}", path))?;
+        }
 
         // Generate infrastructure mod.rs
-        let mod_content = "pub mod event_store;\n\npub use event_store::*;\n";
+        let mod_content = render(quote! {
+            pub mod event_bus;
+            pub mod event_store;
+            pub mod outbox;
+            #[cfg(feature = "sqlx")]
+            pub mod sqlite_event_store;
+
+            pub use event_bus::*;
+            pub use event_store::*;
+            pub use outbox::*;
+            #[cfg(feature = "sqlx")]
+            pub use sqlite_event_store::*;
+        })?;
         let mod_path = format!("{}/src/infrastructure/mod.rs", self.output_dir);
         fs::write(&mod_path, mod_content)?;
 
         // Generate domain mod.rs
-        let domain_mod = r#"pub mod entities;
-pub mod commands;
-pub mod events;
-
-pub use entities::*;
-pub use commands::*;
-pub use events::*;
-"#;
+        let domain_mod = render(quote! {
+            pub mod entities;
+            pub mod commands;
+            pub mod events;
+            pub mod expressions;
+            pub mod handlers;
+            pub mod state;
+
+            pub use entities::*;
+            pub use commands::*;
+            pub use events::*;
+        })?;
         let domain_mod_path = format!("{}/src/domain/mod.rs", self.output_dir);
         fs::write(&domain_mod_path, domain_mod)?;
 
         Ok(())
     }
 
+    fn generate_event_bus(&self, model: &IRModel) -> Result<()> {
+        let code = IntegrationGenerator::generate_event_bus(model)?;
+
+        let path = format!("{}/src/infrastructure/event_bus.rs", self.output_dir);
+        fs::write(&path, code).with_context(|| format!("Failed to write event bus to {}", path))?;
+
+        Ok(())
+    }
+
+    fn generate_integration(&self, model: &IRModel) -> Result<()> {
+        if model.external_events.is_empty() {
+            return Ok(());
+        }
+
+        // Typed contracts come from the workspace crate when available
+        let contracts_crate = self
+            .workspace_crates
+            .iter()
+            .flatten()
+            .find(|dependency| dependency.name == INTEGRATION_EVENTS_CRATE)
+            .map(|dependency| dependency.name.as_str());
+
+        let files = [
+            ("mod.rs", IntegrationGenerator::generate_mod()?),
+            (
+                "contracts.rs",
+                IntegrationGenerator::generate_contracts(model, contracts_crate)?,
+            ),
+            (
+                "consumers.rs",
+                IntegrationGenerator::generate_consumers(model)?,
+            ),
+            ("acl.rs", IntegrationGenerator::generate_acl(model)?),
+        ];
+
+        for (name, code) in files {
+            let path = format!("{}/src/integration/{}", self.output_dir, name);
+            fs::write(&path, code)
+                .with_context(|| format!("Failed to write integration module to {}", path))?;
+        }
+
+        Ok(())
+    }
+
+    fn generate_policies(&self, model: &IRModel) -> Result<()> {
+        if model.policies.is_empty() {
+            return Ok(());
+        }
+
+        let files = [
+            ("mod.rs", PolicyGenerator::generate_mod(model)?),
+            ("actions.rs", PolicyGenerator::generate_actions(model)?),
+            (
+                "conditions.rs",
+                PolicyGenerator::generate_conditions(model)?,
+            ),
+            ("engine.rs", PolicyGenerator::generate_engine()?),
+            ("saga_store.rs", PolicyGenerator::generate_saga_store()?),
+            (
+                "sqlite_saga_store.rs",
+                PolicyGenerator::generate_sqlite_saga_store()?,
+            ),
+        ];
+
+        for (name, code) in files {
+            let path = format!("{}/src/policies/{}", self.output_dir, name);
+            fs::write(&path, code)
+                .with_context(|| format!("Failed to write policies module to {}", path))?;
+        }
+
+        Ok(())
+    }
+
+    fn generate_projections(&self, model: &IRModel) -> Result<()> {
+        if model.read_models.is_empty() {
+            return Ok(());
+        }
+
+        let mut files = vec![
+            (
+                "mod.rs".to_string(),
+                ProjectionGenerator::generate_mod(model)?,
+            ),
+            (
+                "projector.rs".to_string(),
+                ProjectionGenerator::generate_projector()?,
+            ),
+            (
+                "sqlite_projection_store.rs".to_string(),
+                ProjectionGenerator::generate_sqlite_projection_store()?,
+            ),
+        ];
+        for read_model in model.read_models.values() {
+            files.push((
+                format!("{}.rs", to_snake_case(&read_model.name)),
+                ProjectionGenerator::generate_read_model(model, read_model)?,
+            ));
+        }
+
+        for (name, code) in files {
+            let path = format!("{}/src/projections/{}", self.output_dir, name);
+            fs::write(&path, code)
+                .with_context(|| format!("Failed to write projections module to {}", path))?;
+        }
+
+        Ok(())
+    }
+
+    fn generate_tests(&self, model: &IRModel) -> Result<()> {
+        let sample_model = self.with_shared_kernel(model);
+
+        let mut files = vec![
+            (
+                "support/mod.rs".to_string(),
+                TestGenerator::generate_support(model)?,
+            ),
+            (
+                "support/strategies.rs".to_string(),
+                StrategyGenerator::generate_strategies(&sample_model)?,
+            ),
+            (
+                "serde_round_trip_test.rs".to_string(),
+                StrategyGenerator::generate_round_trip_tests(model)?,
+            ),
+        ];
+        if let Some(tests) = StrategyGenerator::generate_fuzz_tests(model)? {
+            files.push(("command_fuzz_test.rs".to_string(), tests));
+        }
+        for aggregate in model.aggregates.values() {
+            let name = to_snake_case(&aggregate.name);
+            files.push((
+                format!("support/{}.rs", name),
+                TestGenerator::generate_aggregate_support(&sample_model, aggregate)?,
+            ));
+            if let Some(tests) = TestGenerator::generate_aggregate_tests(model, aggregate)? {
+                files.push((format!("{}_test.rs", name), tests));
+            }
+        }
+        for aggregate in PersistenceGenerator::state_aggregates(model) {
+            files.push((
+                format!("{}_repository_test.rs", to_snake_case(&aggregate.name)),
+                PersistenceGenerator::generate_repository_tests(&sample_model, aggregate)?,
+            ));
+        }
+
+        for (name, code) in files {
+            let path = format!("{}/tests/{}", self.output_dir, name);
+            fs::write(&path, code)
+                .with_context(|| format!("Failed to write aggregate tests to {}", path))?;
+        }
+
+        Ok(())
+    }
+
     fn generate_readme(&self, model: &IRModel) -> Result<()> {
         let context_name = &model.bounded_context.name;
         let package_name = to_kebab_case(context_name);
 
+        let state_aggregates = PersistenceGenerator::state_aggregates(model)
+            .map(|aggregate| format!("`{}`", aggregate.name))
+            .collect::<Vec<_>>();
+        let persistence = match state_aggregates.split_last() {
+            None => String::new(),
+            Some((last, init)) => format!(
+                r#"
+### State-Stored Aggregates
+
+{} stored as current state rather than as events. With the `sqlx`
+feature, `repository::Sqlite<Aggregate>Repository` loads and saves them in
+tables with a column per field, list items in child tables, and the service
+applies the migrations in `migrations/` on startup. Commands on them go through
+`domain::handlers::StateStoredHandler`, which loads the aggregate from its
+repository and saves it with the command's events applied.
+
+Regenerating after an IR change adds a migration for the difference and never
+rewrites existing ones. A required column without a default can only be added
+to an empty table, so give new required properties a `default` in the IR.
+"#,
+                if init.is_empty() {
+                    format!("{} is", last)
+                } else {
+                    format!("{} and {} are", init.join(", "), last)
+                }
+            ),
+        };
+
         let content = format!(
             r#"# {} Service
 
@@ -453,7 +1200,38 @@ Once the service is running, visit:
 
 - Swagger UI: `http://localhost:3000/swagger-ui`
 - OpenAPI JSON: `http://localhost:3000/api-docs/openapi.json`
+- AsyncAPI JSON: `http://localhost:3000/api-docs/asyncapi.json`
 
+`asyncapi.json` describes a channel per published event and per subscribed
+external event. Its component schemas are merged into the OpenAPI document,
+so both documents describe payloads the same way.
+
+### gRPC API
+
+The `grpc` feature serves the same commands and queries over gRPC on port 50051,
+as defined in `proto/`. Both APIs share one `AppState`, so a command sent over
+either one appends to the same event store:
+
+```bash
+cargo run --features grpc
+```
+
+Field numbers in the proto file are kept when the service is regenerated, and
+the numbers of removed fields are reserved.
+
+### GraphQL API
+
+When the context has queries, the `graphql` feature serves a GraphQL API at
+`http://localhost:3000/graphql`, with GraphiQL on `GET` and subscriptions over
+WebSocket at `/graphql/ws`:
+
+```bash
+cargo run --features graphql
+```
+
+Queries are query fields, commands are mutations and the `events` subscription
+streams domain events from the event store. `schema.graphql` holds the schema.
+{}
 ## Project Structure
 
 ```
@@ -462,15 +1240,26 @@ Once the service is running, visit:
 │   ├── main.rs              # Application entry point
 │   ├── lib.rs               # Library root
 │   ├── api/                 # API layer
-│   │   └── routes.rs        # HTTP routes and handlers
+│   │   ├── routes.rs        # HTTP routes and handlers
+│   │   ├── grpc.rs          # gRPC service (`grpc` feature)
+│   │   └── graphql.rs       # GraphQL resolvers (`graphql` feature)
 │   ├── domain/              # Domain layer
 │   │   ├── entities.rs      # Domain entities and value objects
 │   │   ├── commands.rs      # CQRS commands
-│   │   └── events.rs        # Domain events
+│   │   ├── events.rs        # Domain events
+│   │   ├── expressions.rs   # Runtime of the translated IR expressions
+│   │   ├── handlers.rs      # Command handler appending events to the event store
+│   │   └── state.rs         # Aggregate state folded from events
 │   ├── infrastructure/      # Infrastructure layer
 │   │   └── event_store.rs   # Event sourcing infrastructure
 │   └── repository/          # Repository layer
 │       └── mod.rs           # Data persistence
+├── tests/                   # Aggregate tests
+│   └── support/             # Given/when/then harness, samples and strategies
+├── proto/                   # gRPC API definition
+├── build.rs                 # Compiles the proto file
+├── asyncapi.json            # Event channels and payload schemas
+├── schema.graphql           # GraphQL schema
 └── Cargo.toml
 ```
 
@@ -491,6 +1280,18 @@ This microservice follows:
 cargo test
 ```
 
+Each aggregate has starter tests in `tests/<aggregate>_test.rs`, written as
+given(events)/when(command)/then(events or error) scenarios with the samples
+in `tests/support/<aggregate>.rs`, run against the service's command handler.
+Each scenario gives the events that bring the aggregate to the status its
+command needs; the module doc lists the tests whose setup it cannot derive.
+
+`tests/support/strategies.rs` has proptest strategies for the domain types
+that follow the IR's validation rules. `tests/serde_round_trip_test.rs` runs
+them through JSON round trips and `tests/command_fuzz_test.rs` checks that
+command validation never panics. Set `PROPTEST_CASES` to change the number of
+generated cases.
+
 ### Running with Watch Mode
 
 ```bash
@@ -508,6 +1309,7 @@ MIT
                 .description
                 .as_deref()
                 .unwrap_or("Microservice generated from IR model"),
+            persistence,
             package_name
         );
 
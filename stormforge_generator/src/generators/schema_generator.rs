@@ -0,0 +1,317 @@
+use crate::generators::utils::*;
+use crate::ir::{IRModel, Property, ValueObject};
+use serde_json::{json, Map, Value};
+
+/// Format of the generated schemas, for AsyncAPI multi-format schema objects
+pub const SCHEMA_FORMAT: &str = "application/vnd.oai.openapi+json;version=3.0.0";
+
+pub struct SchemaGenerator;
+
+impl SchemaGenerator {
+    /// OpenAPI 3.0 schemas of the JSON the generated service exchanges
+    ///
+    /// Value objects, aggregates, commands and events each get a component
+    /// schema. The schemas follow the serde layout of the generated Rust
+    /// types, so they describe both the HTTP API and the integration events.
+    pub fn component_schemas(model: &IRModel) -> Map<String, Value> {
+        let mut schemas = Map::new();
+        for (name, vo) in &model.value_objects {
+            schemas.insert(name.clone(), Self::value_object_schema(model, vo));
+        }
+        for (name, aggregate) in &model.aggregates {
+            schemas.insert(
+                name.clone(),
+                Self::object_schema(
+                    model,
+                    aggregate.description.as_deref(),
+                    Map::new(),
+                    &aggregate.root_entity.properties,
+                ),
+            );
+        }
+        for (name, command) in &model.commands {
+            schemas.insert(
+                name.clone(),
+                Self::object_schema(
+                    model,
+                    command.description.as_deref(),
+                    Map::new(),
+                    &command.payload,
+                ),
+            );
+        }
+        for (name, event) in &model.events {
+            let mut metadata = Map::new();
+            metadata.insert(
+                "type".to_string(),
+                json!({ "type": "string", "enum": [name] }),
+            );
+            metadata.insert(
+                "event_id".to_string(),
+                json!({
+                    "type": "string",
+                    "format": "uuid",
+                    "description": "Unique event identifier",
+                }),
+            );
+            metadata.insert(
+                "occurred_at".to_string(),
+                json!({
+                    "type": "string",
+                    "format": "date-time",
+                    "description": "Timestamp when the event occurred",
+                }),
+            );
+            if event.aggregate.is_some() {
+                metadata.insert(
+                    "aggregate_id".to_string(),
+                    json!({
+                        "type": "string",
+                        "description": "ID of the aggregate that produced this event",
+                    }),
+                );
+            }
+            schemas.insert(
+                name.clone(),
+                Self::object_schema(
+                    model,
+                    event.description.as_deref(),
+                    metadata,
+                    &event.payload,
+                ),
+            );
+        }
+        schemas
+    }
+
+    /// Reference to a component schema
+    pub fn reference(name: &str) -> Value {
+        json!({ "$ref": format!("#/components/schemas/{}", name) })
+    }
+
+    fn value_object_schema(model: &IRModel, vo: &ValueObject) -> Value {
+        let mut schema = match vo.vo_type.as_deref() {
+            Some("enum") => json!({
+                "type": "string",
+                "enum": vo
+                    .values
+                    .iter()
+                    .map(|value| to_pascal_case(&value.name))
+                    .collect::<Vec<_>>(),
+            }),
+            // Identifiers serialize as their underlying value
+            Some("identifier") => {
+                Self::type_schema(model, vo.underlying_type.as_deref().unwrap_or("String"))
+            }
+            _ => Self::object_schema(model, None, Map::new(), &vo.properties),
+        };
+        describe(&mut schema, vo.description.as_deref());
+        schema
+    }
+
+    /// Object schema with the given leading properties followed by the IR ones
+    fn object_schema(
+        model: &IRModel,
+        description: Option<&str>,
+        mut properties: Map<String, Value>,
+        fields: &[Property],
+    ) -> Value {
+        let mut required: Vec<String> = properties.keys().cloned().collect();
+        for prop in fields {
+            let name = to_snake_case(&prop.name);
+            if !is_nullable(prop) {
+                required.push(name.clone());
+            }
+            properties.insert(name, Self::property_schema(model, prop));
+        }
+
+        let mut schema = json!({ "type": "object" });
+        describe(&mut schema, description);
+        if !required.is_empty() {
+            schema["required"] = json!(required);
+        }
+        schema["properties"] = Value::Object(properties);
+        schema
+    }
+
+    fn property_schema(model: &IRModel, prop: &Property) -> Value {
+        let mut schema = Self::type_schema(model, &prop.prop_type);
+
+        // Sibling keywords of a reference are ignored, so wrap it
+        if schema.get("$ref").is_some()
+            && (is_nullable(prop) || prop.description.is_some() || prop.default.is_some())
+        {
+            schema = json!({ "allOf": [schema] });
+        }
+        describe(&mut schema, prop.description.as_deref());
+        if is_nullable(prop) {
+            schema["nullable"] = json!(true);
+        }
+        if let Some(default) = &prop.default {
+            schema["default"] = default.clone();
+        }
+        if let Some(validation) = &prop.validation {
+            // Length rules of a list bound its number of items
+            let (min_length, max_length) = if schema["type"] == "array" {
+                ("minItems", "maxItems")
+            } else {
+                ("minLength", "maxLength")
+            };
+            let keywords = [
+                ("minimum", validation.min.map(|value| json!(value))),
+                ("maximum", validation.max.map(|value| json!(value))),
+                (min_length, validation.min_length.map(|value| json!(value))),
+                (max_length, validation.max_length.map(|value| json!(value))),
+                (
+                    "pattern",
+                    validation.pattern.as_ref().map(|value| json!(value)),
+                ),
+            ];
+            for (keyword, value) in keywords {
+                if let Some(value) = value {
+                    schema[keyword] = value;
+                }
+            }
+        }
+        schema
+    }
+
+    /// Schema of the JSON the Rust service produces for an IR type
+    pub fn type_schema(model: &IRModel, ir_type: &str) -> Value {
+        let ir_type = ir_type.trim();
+        let generic = |prefix: &str| {
+            ir_type
+                .strip_prefix(prefix)
+                .map(|rest| Self::type_schema(model, rest.strip_suffix('>').unwrap_or(rest)))
+        };
+        if let Some(item) = generic("List<").or_else(|| generic("Vec<")) {
+            return json!({ "type": "array", "items": item });
+        }
+        if let Some(inner) = generic("Option<") {
+            return inner;
+        }
+        if let Some(item) = generic("PagedResult<") {
+            return json!({
+                "type": "object",
+                "required": ["items", "total", "page", "page_size"],
+                "properties": {
+                    "items": { "type": "array", "items": item },
+                    "total": { "type": "integer", "format": "int64" },
+                    "page": { "type": "integer", "format": "int64" },
+                    "page_size": { "type": "integer", "format": "int64" },
+                },
+            });
+        }
+
+        match ir_type {
+            "String" => json!({ "type": "string" }),
+            "Integer" => json!({ "type": "integer", "format": "int64" }),
+            "Boolean" => json!({ "type": "boolean" }),
+            // Decimals are serialized as strings to keep their precision
+            "Decimal" => json!({ "type": "string", "format": "decimal" }),
+            "DateTime" => json!({ "type": "string", "format": "date-time" }),
+            "Date" => json!({ "type": "string", "format": "date" }),
+            "Time" => json!({ "type": "string", "format": "time" }),
+            "Uuid" => json!({ "type": "string", "format": "uuid" }),
+            _ => {
+                let name = to_rust_type(ir_type);
+                if model.value_objects.contains_key(&name) || model.aggregates.contains_key(&name) {
+                    Self::reference(&name)
+                } else {
+                    // Types the IR does not define are kept as raw JSON
+                    json!({})
+                }
+            }
+        }
+    }
+}
+
+/// Whether the Rust field of a property is an `Option`
+fn is_nullable(prop: &Property) -> bool {
+    !prop.required || prop.prop_type.trim().starts_with("Option<")
+}
+
+fn describe(schema: &mut Value, description: Option<&str>) {
+    if let (Some(description), Some(object)) = (description, schema.as_object_mut()) {
+        object.insert("description".to_string(), json!(description));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    #[test]
+    fn test_component_schemas_follow_serde_layout() {
+        let model = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+value_objects:
+  OrderId:
+    name: "OrderId"
+    type: "identifier"
+    underlying_type: "Uuid"
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "PAID"
+events:
+  OrderPaid:
+    name: "OrderPaid"
+    aggregate: "Order"
+    payload:
+      - name: "orderId"
+        type: "OrderId"
+      - name: "status"
+        type: "OrderStatus"
+        required: false
+      - name: "lines"
+        type: "List<String>"
+        validation:
+          maxLength: 3
+"#,
+        )
+        .unwrap();
+
+        let schemas = SchemaGenerator::component_schemas(&model);
+        assert_eq!(
+            schemas["OrderId"],
+            json!({ "type": "string", "format": "uuid" })
+        );
+        assert_eq!(
+            schemas["OrderStatus"],
+            json!({ "type": "string", "enum": ["Paid"] })
+        );
+
+        let event = &schemas["OrderPaid"];
+        assert_eq!(
+            event["required"],
+            json!([
+                "type",
+                "event_id",
+                "occurred_at",
+                "aggregate_id",
+                "order_id",
+                "lines"
+            ])
+        );
+        assert_eq!(event["properties"]["type"]["enum"], json!(["OrderPaid"]));
+        assert_eq!(
+            event["properties"]["order_id"],
+            json!({ "$ref": "#/components/schemas/OrderId" })
+        );
+        assert_eq!(
+            event["properties"]["status"],
+            json!({ "allOf": [{ "$ref": "#/components/schemas/OrderStatus" }], "nullable": true })
+        );
+        assert_eq!(
+            event["properties"]["lines"],
+            json!({ "type": "array", "items": { "type": "string" }, "maxItems": 3 })
+        );
+    }
+}
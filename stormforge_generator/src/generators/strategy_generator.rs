@@ -0,0 +1,525 @@
+use crate::generators::rust_ast::*;
+use crate::generators::utils::*;
+use crate::ir::{IRModel, Property, Validation, ValueObject};
+use anyhow::Result;
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+
+/// Largest tuple of field strategies before they are nested
+const MAX_TUPLE: usize = 12;
+
+/// Range of generated decimals without `min` or `max`, in whole units
+const DECIMAL_BOUND: i64 = 1_000_000;
+
+/// Characters generated for strings without a `pattern`
+const STRING_CHARS: &str = "\\PC";
+
+/// Length range of strings and lists without `maxLength`
+const DEFAULT_EXTRA_LENGTH: usize = 32;
+const DEFAULT_EXTRA_ITEMS: usize = 3;
+
+pub struct StrategyGenerator;
+
+impl StrategyGenerator {
+    /// Generate `tests/support/strategies.rs` with a proptest strategy per type
+    ///
+    /// Value objects, aggregates, commands and events each get a function
+    /// returning a `BoxedStrategy`. Generated values respect the `validation`
+    /// rules of their properties and the values of enums.
+    pub fn generate_strategies(model: &IRModel) -> Result<String> {
+        let krate = crate_ident(model)?;
+        let value_objects = model
+            .value_objects
+            .iter()
+            .map(|(name, vo)| Self::generate_value_object(name, vo))
+            .collect::<Result<Vec<_>>>()?;
+        let aggregates = model
+            .aggregates
+            .iter()
+            .map(|(name, aggregate)| {
+                Self::generate_struct(name, &aggregate.root_entity.properties, None)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let commands = model
+            .commands
+            .iter()
+            .map(|(name, command)| Self::generate_struct(name, &command.payload, None))
+            .collect::<Result<Vec<_>>>()?;
+        let events = model
+            .events
+            .iter()
+            .map(|(name, event)| {
+                let mut metadata = vec![
+                    (ident("event_id")?, Self::primitive("Uuid", None)?),
+                    (ident("occurred_at")?, Self::primitive("DateTime", None)?),
+                ];
+                if event.aggregate.is_some() {
+                    metadata.push((ident("aggregate_id")?, Self::primitive("String", None)?));
+                }
+                Self::generate_struct(name, &event.payload, Some(metadata))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut imports = Vec::new();
+        if !commands.is_empty() {
+            imports.push(quote! { use ::#krate::domain::commands::*; });
+        }
+        if !value_objects.is_empty() || !aggregates.is_empty() {
+            imports.push(quote! { use ::#krate::domain::entities::*; });
+        }
+        if !events.is_empty() {
+            imports.push(quote! { use ::#krate::domain::events::*; });
+        }
+
+        render(quote! {
+            //! Proptest strategies for the domain types
+            //!
+            //! Values respect the IR's validation rules: string lengths and
+            //! patterns, numeric ranges and decimal places.
+
+            use proptest::prelude::*;
+            use proptest::test_runner::TestRunner;
+            #(#imports)*
+
+            /// Run a property against values of a strategy
+            ///
+            /// A failure panics with the smallest failing value proptest finds.
+            pub fn check<S: Strategy>(
+                strategy: S,
+                test: impl Fn(S::Value) -> Result<(), TestCaseError>,
+            ) {
+                let mut runner = TestRunner::default();
+                if let Err(error) = runner.run(&strategy, test) {
+                    panic!("{}", error);
+                }
+            }
+
+            #(#value_objects)*
+            #(#aggregates)*
+            #(#commands)*
+            #(#events)*
+        })
+    }
+
+    /// Generate `tests/serde_round_trip_test.rs`
+    ///
+    /// Every value object, aggregate, command and event must deserialize
+    /// from its own JSON into a value with the same JSON.
+    pub fn generate_round_trip_tests(model: &IRModel) -> Result<String> {
+        let krate = crate_ident(model)?;
+        let mut tests = Vec::new();
+        let types = model
+            .value_objects
+            .keys()
+            .chain(model.aggregates.keys())
+            .chain(model.commands.keys());
+        for name in types {
+            let test = ident(&format!("{}_round_trips", to_snake_case(name)))?;
+            let strategy = snake_ident(name)?;
+            tests.push(quote! {
+                #[test]
+                fn #test() {
+                    check(strategies::#strategy(), |value| round_trip(&value));
+                }
+            });
+        }
+        for name in model.events.keys() {
+            let test = ident(&format!("{}_round_trips", to_snake_case(name)))?;
+            let strategy = snake_ident(name)?;
+            let variant = ident(name)?;
+            tests.push(quote! {
+                #[test]
+                fn #test() {
+                    check(strategies::#strategy(), |event| {
+                        round_trip(&DomainEvent::#variant(event))
+                    });
+                }
+            });
+        }
+        let events_import = if model.events.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! { use ::#krate::domain::events::DomainEvent; }
+        };
+
+        render(quote! {
+            //! Serde round trips of the domain types with generated values
+
+            mod support;
+
+            use proptest::prelude::*;
+            use serde::de::DeserializeOwned;
+            use serde::Serialize;
+            use support::strategies::{self, check};
+            #events_import
+
+            /// Decode a value's JSON and expect the result to encode to the same JSON
+            fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> Result<(), TestCaseError> {
+                let json = serde_json::to_value(value).map_err(|error| TestCaseError::fail(error.to_string()))?;
+                let decoded: T = serde_json::from_value(json.clone())
+                    .map_err(|error| TestCaseError::fail(error.to_string()))?;
+                let encoded = serde_json::to_value(&decoded).map_err(|error| TestCaseError::fail(error.to_string()))?;
+                prop_assert_eq!(encoded, json);
+                Ok(())
+            }
+
+            #(#tests)*
+        })
+    }
+
+    /// Generate `tests/command_fuzz_test.rs`, if the IR declares commands
+    ///
+    /// Payload fields ignore the validation rules, so `validate` sees values
+    /// it must reject, and must do so without panicking.
+    pub fn generate_fuzz_tests(model: &IRModel) -> Result<Option<String>> {
+        if model.commands.is_empty() {
+            return Ok(None);
+        }
+        let krate = crate_ident(model)?;
+
+        let mut strategies = Vec::new();
+        let mut tests = Vec::new();
+        for (name, command) in &model.commands {
+            let function = ident(&format!("any_{}", to_snake_case(name)))?;
+            let type_name = ident(name)?;
+            let fields = command
+                .payload
+                .iter()
+                .map(|prop| {
+                    let unchecked = Property {
+                        validation: None,
+                        ..prop.clone()
+                    };
+                    Ok((
+                        snake_ident(&prop.name)?,
+                        Self::property(&unchecked, &quote! { strategies:: })?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let strategy = Self::struct_strategy(&type_name, fields);
+            let doc = format!(" `{}` commands ignoring the validation rules", name);
+            strategies.push(quote! {
+                #[doc = #doc]
+                fn #function() -> BoxedStrategy<#type_name> {
+                    #strategy.boxed()
+                }
+            });
+
+            let test = ident(&format!("{}_validation_never_panics", to_snake_case(name)))?;
+            tests.push(quote! {
+                #[test]
+                fn #test() {
+                    check(#function(), |command| {
+                        let _ = command.validate();
+                        Ok(())
+                    });
+                }
+            });
+        }
+
+        render(quote! {
+            //! Command validation fuzzed with payloads outside the validation rules
+
+            mod support;
+
+            use proptest::prelude::*;
+            use support::strategies::{self, check};
+            use ::#krate::domain::commands::*;
+
+            #(#strategies)*
+            #(#tests)*
+        })
+        .map(Some)
+    }
+
+    fn generate_value_object(name: &str, vo: &ValueObject) -> Result<TokenStream> {
+        let function = snake_ident(name)?;
+        let type_name = ident(name)?;
+        let doc = format!(" `{}` values", name);
+
+        let strategy = match vo.vo_type.as_deref() {
+            Some("enum") => {
+                let variants = vo
+                    .values
+                    .iter()
+                    .map(|value| ident(&to_pascal_case(&value.name)))
+                    .collect::<Result<Vec<_>>>()?;
+                quote! {
+                    proptest::sample::select(Vec::from([#(#type_name::#variants),*]))
+                }
+            }
+            Some("identifier") => {
+                let underlying = match vo.underlying_type.as_deref() {
+                    None | Some("String") => {
+                        quote! { proptest::string::string_regex("[a-z0-9-]{1,16}").unwrap() }
+                    }
+                    Some(underlying) => Self::ir_type(underlying, None, &TokenStream::new())?,
+                };
+                quote! { #underlying.prop_map(#type_name) }
+            }
+            _ => {
+                let fields = Self::fields(&vo.properties, &TokenStream::new())?;
+                Self::struct_strategy(&type_name, fields)
+            }
+        };
+
+        Ok(quote! {
+            #[doc = #doc]
+            pub fn #function() -> BoxedStrategy<#type_name> {
+                #strategy.boxed()
+            }
+        })
+    }
+
+    /// Strategy function of a struct, with fields ahead of its properties
+    fn generate_struct(
+        name: &str,
+        properties: &[Property],
+        metadata: Option<Vec<(Ident, TokenStream)>>,
+    ) -> Result<TokenStream> {
+        let function = snake_ident(name)?;
+        let type_name = ident(name)?;
+        let doc = format!(" `{}` values", name);
+        let mut fields = metadata.unwrap_or_default();
+        fields.extend(Self::fields(properties, &TokenStream::new())?);
+        let strategy = Self::struct_strategy(&type_name, fields);
+
+        Ok(quote! {
+            #[doc = #doc]
+            pub fn #function() -> BoxedStrategy<#type_name> {
+                #strategy.boxed()
+            }
+        })
+    }
+
+    fn fields(properties: &[Property], path: &TokenStream) -> Result<Vec<(Ident, TokenStream)>> {
+        properties
+            .iter()
+            .map(|prop| Ok((snake_ident(&prop.name)?, Self::property(prop, path)?)))
+            .collect()
+    }
+
+    /// Strategy building a struct from a tuple of field strategies
+    ///
+    /// Proptest implements `Strategy` for tuples of up to twelve elements,
+    /// so longer field lists are split into nested tuples.
+    fn struct_strategy(type_name: &Ident, fields: Vec<(Ident, TokenStream)>) -> TokenStream {
+        if fields.is_empty() {
+            return quote! { Just(#type_name {}) };
+        }
+        let names: Vec<&Ident> = fields.iter().map(|(name, _)| name).collect();
+        let (strategies, pattern) = if fields.len() <= MAX_TUPLE {
+            let strategies = fields.iter().map(|(_, strategy)| strategy);
+            (quote! { (#(#strategies,)*) }, quote! { (#(#names,)*) })
+        } else {
+            let groups: Vec<_> = fields.chunks(MAX_TUPLE - 2).collect();
+            let strategies = groups.iter().map(|group| {
+                let strategies = group.iter().map(|(_, strategy)| strategy);
+                quote! { (#(#strategies,)*) }
+            });
+            let patterns = groups.iter().map(|group| {
+                let names = group.iter().map(|(name, _)| name);
+                quote! { (#(#names,)*) }
+            });
+            (quote! { (#(#strategies,)*) }, quote! { (#(#patterns,)*) })
+        };
+
+        quote! {
+            #strategies.prop_map(|#pattern| #type_name { #(#names),* })
+        }
+    }
+
+    fn property(prop: &Property, path: &TokenStream) -> Result<TokenStream> {
+        let strategy = Self::ir_type(&prop.prop_type, prop.validation.as_ref(), path)?;
+        if prop.required {
+            Ok(strategy)
+        } else {
+            Ok(quote! { proptest::option::of(#strategy) })
+        }
+    }
+
+    /// Strategy for an IR type; `path` prefixes calls to other strategies
+    fn ir_type(
+        ir_type: &str,
+        validation: Option<&Validation>,
+        path: &TokenStream,
+    ) -> Result<TokenStream> {
+        let ir_type = ir_type.trim();
+        for wrapper in ["List<", "Vec<"] {
+            if let Some(inner) = ir_type.strip_prefix(wrapper) {
+                let inner = inner.strip_suffix('>').unwrap_or(inner);
+                let items = Self::ir_type(inner, None, path)?;
+                let min = validation.and_then(|v| v.min_length).unwrap_or(0);
+                let max = validation
+                    .and_then(|v| v.max_length)
+                    .unwrap_or(min + DEFAULT_EXTRA_ITEMS);
+                let min = Literal::usize_unsuffixed(min);
+                let max = Literal::usize_unsuffixed(max);
+                return Ok(quote! { proptest::collection::vec(#items, #min..=#max) });
+            }
+        }
+        if let Some(inner) = ir_type.strip_prefix("Option<") {
+            let inner = inner.strip_suffix('>').unwrap_or(inner);
+            let strategy = Self::ir_type(inner, validation, path)?;
+            return Ok(quote! { proptest::option::of(#strategy) });
+        }
+        if PRIMITIVE_TYPES.contains(&ir_type) {
+            return Self::primitive(ir_type, validation);
+        }
+
+        let function = snake_ident(&to_rust_type(ir_type))?;
+        Ok(quote! { #path #function() })
+    }
+
+    fn primitive(ir_type: &str, validation: Option<&Validation>) -> Result<TokenStream> {
+        Ok(match ir_type {
+            "String" => Self::string(validation),
+            "Integer" => Self::integer(validation),
+            "Decimal" => Self::decimal(validation),
+            "Boolean" => quote! { any::<bool>() },
+            "DateTime" => quote! {
+                (0i64..4_102_444_800)
+                    .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap())
+            },
+            "Date" => quote! {
+                (719_163i32..=767_009)
+                    .prop_map(|days| chrono::NaiveDate::from_num_days_from_ce_opt(days).unwrap())
+            },
+            "Time" => quote! {
+                (0u32..86_400).prop_map(|seconds| {
+                    chrono::NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0).unwrap()
+                })
+            },
+            "Uuid" => quote! { any::<u128>().prop_map(uuid::Uuid::from_u128) },
+            _ => anyhow::bail!("Not a primitive type: {}", ir_type),
+        })
+    }
+
+    /// Strings matching `pattern`, or printable strings, within the length limits
+    fn string(validation: Option<&Validation>) -> TokenStream {
+        let min = validation.and_then(|v| v.min_length);
+        let max = validation.and_then(|v| v.max_length);
+        match validation.and_then(|v| v.pattern.as_deref()) {
+            Some(pattern) => {
+                // Generated strings are whole matches, so anchors are implied
+                let pattern = pattern.strip_prefix('^').unwrap_or(pattern);
+                let pattern = match pattern.strip_suffix('$') {
+                    Some(rest) if !rest.ends_with('\\') => rest,
+                    _ => pattern,
+                };
+                let strategy = quote! { proptest::string::string_regex(#pattern).unwrap() };
+                if min.is_none() && max.is_none() {
+                    return strategy;
+                }
+                let min = Literal::usize_unsuffixed(min.unwrap_or(0));
+                let max = Literal::usize_unsuffixed(max.unwrap_or(usize::MAX));
+                quote! {
+                    #strategy.prop_filter("length outside minLength..=maxLength", |value| {
+                        (#min..=#max).contains(&value.chars().count())
+                    })
+                }
+            }
+            None => {
+                let min = min.unwrap_or(0);
+                let max = max.unwrap_or(min + DEFAULT_EXTRA_LENGTH);
+                let regex = format!("{}{{{},{}}}", STRING_CHARS, min, max);
+                quote! { proptest::string::string_regex(#regex).unwrap() }
+            }
+        }
+    }
+
+    fn integer(validation: Option<&Validation>) -> TokenStream {
+        let min = validation.and_then(|v| v.min).map(|min| min.ceil() as i64);
+        let max = validation.and_then(|v| v.max).map(|max| max.floor() as i64);
+        match (min, max) {
+            (None, None) => quote! { any::<i64>() },
+            (min, max) => {
+                let min = Literal::i64_suffixed(min.unwrap_or(i64::MIN));
+                let max = Literal::i64_unsuffixed(max.unwrap_or(i64::MAX));
+                quote! { #min..=#max }
+            }
+        }
+    }
+
+    /// Decimals with `precision` decimal places (two by default)
+    fn decimal(validation: Option<&Validation>) -> TokenStream {
+        let scale = validation.and_then(|v| v.precision).unwrap_or(2) as u32;
+        let factor = 10f64.powi(scale as i32);
+        let min = validation
+            .and_then(|v| v.min)
+            .map(|min| (min * factor).ceil() as i64)
+            .unwrap_or(-DECIMAL_BOUND * factor as i64);
+        let max = validation
+            .and_then(|v| v.max)
+            .map(|max| (max * factor).floor() as i64)
+            .unwrap_or(DECIMAL_BOUND * factor as i64);
+        let min = Literal::i64_suffixed(min);
+        let max = Literal::i64_unsuffixed(max);
+        let scale = Literal::u32_unsuffixed(scale);
+        quote! {
+            (#min..=#max).prop_map(|units| rust_decimal::Decimal::new(units, #scale))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> IRModel {
+        serde_yaml::from_str(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Shop"
+  namespace: "shop"
+value_objects:
+  Money:
+    name: "Money"
+    properties:
+      - name: "amount"
+        type: "Decimal"
+        validation:
+          min: 0
+          precision: 2
+      - name: "currency"
+        type: "String"
+        validation:
+          pattern: "^[A-Z]{3}$"
+commands:
+  AddItem:
+    name: "AddItem"
+    payload:
+      - name: "quantity"
+        type: "Integer"
+        validation:
+          min: 1
+          max: 99
+      - name: "price"
+        type: "Money"
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_strategies_respect_validation() {
+        let strategies = StrategyGenerator::generate_strategies(&model()).unwrap();
+
+        assert!(strategies.contains("pub fn money() -> BoxedStrategy<Money>"));
+        assert!(strategies
+            .contains("(0i64..=100000000).prop_map(|units| rust_decimal::Decimal::new(units, 2))"));
+        assert!(strategies.contains("string_regex(\"[A-Z]{3}\")"));
+        assert!(strategies.contains("(1i64..=99, money())"));
+    }
+
+    #[test]
+    fn test_fuzz_tests_ignore_validation() {
+        let fuzz = StrategyGenerator::generate_fuzz_tests(&model())
+            .unwrap()
+            .unwrap();
+
+        assert!(fuzz.contains("(any::<i64>(), strategies::money())"));
+        assert!(fuzz.contains("fn add_item_validation_never_panics()"));
+    }
+}
@@ -0,0 +1,1147 @@
+use crate::generators::aggregate_generator::AggregateGenerator;
+use crate::generators::expression_generator::ExpressionGenerator;
+use crate::generators::rust_ast::*;
+use crate::generators::utils::*;
+use crate::ir::{
+    Aggregate, BinaryOp, Command, Event, Expr, IRModel, Property, UnaryOp, Validation,
+};
+use crate::mock::{bounded, string};
+use anyhow::{bail, Context, Result};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// UUID the samples give every `Uuid` value
+const SAMPLE_UUID: &str = "00000000-0000-4000-8000-000000000001";
+
+pub struct TestGenerator;
+
+impl TestGenerator {
+    /// Generate `tests/support/mod.rs` with the given/when/then harness
+    pub fn generate_support(model: &IRModel) -> Result<String> {
+        let krate = crate_ident(model)?;
+        let modules = model
+            .aggregates
+            .keys()
+            .map(|name| snake_ident(name))
+            .collect::<Result<Vec<_>>>()?;
+        let senders = model
+            .commands
+            .keys()
+            .map(|name| {
+                let handler = ident(&format!("handle_{}", to_snake_case(name)))?;
+                let name = ident(name)?;
+                Ok(quote! {
+                    #[async_trait]
+                    impl SendCommand for #name {
+                        async fn send(self, handler: &dyn CommandHandler) -> CommandResult<Vec<DomainEvent>> {
+                            handler.#handler(self).await
+                        }
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        render(quote! {
+            //! Given/when/then harness for aggregate tests
+            //!
+            //! A scenario appends its given events to an in-memory event store,
+            //! sends one command to the service's command handler and checks the
+            //! events or the error that come back.
+            #![allow(dead_code)]
+
+            #(pub mod #modules;)*
+            pub mod strategies;
+
+            use async_trait::async_trait;
+            use std::sync::Arc;
+            use ::#krate::domain::commands::*;
+            use ::#krate::domain::events::DomainEvent;
+            use ::#krate::domain::handlers::EventSourcedHandler;
+            use ::#krate::infrastructure::event_store::{EventStore, InMemoryEventStore};
+
+            /// Command handler the scenarios run against
+            pub fn handler(store: Arc<dyn EventStore>) -> Arc<dyn CommandHandler> {
+                Arc::new(EventSourcedHandler::new(store))
+            }
+
+            /// Command a scenario can send to the handler
+            #[async_trait]
+            pub trait SendCommand: Send + Sized {
+                async fn send(self, handler: &dyn CommandHandler) -> CommandResult<Vec<DomainEvent>>;
+            }
+
+            #(#senders)*
+
+            /// Type name of a domain event, as in its serialized `type` field
+            pub fn event_type(event: &DomainEvent) -> String {
+                serde_json::to_value(event)
+                    .ok()
+                    .and_then(|value| value["type"].as_str().map(str::to_string))
+                    .unwrap_or_default()
+            }
+
+            /// One aggregate instance with its recorded events
+            pub struct Scenario {
+                aggregate_type: &'static str,
+                aggregate_id: String,
+                store: Arc<InMemoryEventStore>,
+            }
+
+            impl Scenario {
+                pub fn new(aggregate_type: &'static str, aggregate_id: impl Into<String>) -> Self {
+                    Self {
+                        aggregate_type,
+                        aggregate_id: aggregate_id.into(),
+                        store: Arc::new(InMemoryEventStore::new()),
+                    }
+                }
+
+                /// Events the aggregate recorded before the command
+                pub async fn given(self, events: Vec<DomainEvent>) -> Self {
+                    if !events.is_empty() {
+                        self.store
+                            .append_events(&self.aggregate_id, self.aggregate_type, events, None)
+                            .await
+                            .expect("Failed to append the given events");
+                    }
+                    self
+                }
+
+                /// Send a command to the handler
+                pub async fn when(self, command: impl SendCommand) -> Outcome {
+                    let handler = handler(self.store.clone());
+                    Outcome {
+                        result: command.send(handler.as_ref()).await,
+                    }
+                }
+            }
+
+            /// Result of the command sent by a scenario
+            pub struct Outcome {
+                result: CommandResult<Vec<DomainEvent>>,
+            }
+
+            impl Outcome {
+                /// Assert that the command produced events of these types, in order
+                pub fn then(self, expected: &[&str]) -> Vec<DomainEvent> {
+                    match self.result {
+                        Ok(events) => {
+                            let types: Vec<String> = events.iter().map(event_type).collect();
+                            assert_eq!(types, expected, "Unexpected events");
+                            events
+                        }
+                        Err(error) => {
+                            panic!("Expected events {:?}, but the command failed: {}", expected, error)
+                        }
+                    }
+                }
+
+                /// Assert that the command failed with an error mentioning `message`
+                pub fn then_error(self, message: &str) -> CommandError {
+                    let error = self.then_rejected();
+                    assert!(
+                        error.to_string().contains(message),
+                        "Expected an error mentioning {:?}, got: {}",
+                        message,
+                        error
+                    );
+                    error
+                }
+
+                /// Assert that the command failed
+                pub fn then_rejected(self) -> CommandError {
+                    match self.result {
+                        Ok(events) => panic!(
+                            "Expected the command to fail, but it produced {:?}",
+                            events.iter().map(event_type).collect::<Vec<_>>()
+                        ),
+                        Err(error) => error,
+                    }
+                }
+            }
+        })
+    }
+
+    /// Generate `tests/support/<aggregate>.rs` with its scenario and samples
+    pub fn generate_aggregate_support(model: &IRModel, aggregate: &Aggregate) -> Result<String> {
+        let krate = crate_ident(model)?;
+        let commands = Self::commands(model, aggregate);
+        let events = Self::events(model, aggregate);
+
+        let module_doc = format!(
+            " Scenario and samples of the `{}` aggregate",
+            aggregate.name
+        );
+        let aggregate_type = &aggregate.name;
+        let aggregate_id = Self::aggregate_id(model, aggregate);
+        let scenario_doc = format!(" Scenario of the sample `{}`", aggregate.name);
+
+        let mut uses_entities = false;
+        let command_samples = commands
+            .iter()
+            .map(|(name, command)| {
+                let doc = format!(" Sample `{}` command", name);
+                let function = snake_ident(name)?;
+                let id_field = AggregateGenerator::id_field(aggregate, command);
+                let fields = command
+                    .payload
+                    .iter()
+                    .map(|prop| {
+                        let field = snake_ident(&prop.name)?;
+                        let value = match id_field {
+                            Some(id) if id.name == prop.name && id.prop_type == "String" => {
+                                let id = quote! { AGGREGATE_ID.to_string() };
+                                if prop.required {
+                                    id
+                                } else {
+                                    quote! { Some(#id) }
+                                }
+                            }
+                            _ => Self::sample_property(
+                                model,
+                                prop,
+                                &mut Vec::new(),
+                                &mut uses_entities,
+                            )?,
+                        };
+                        Ok(quote! { #field: #value })
+                    })
+                    .collect::<Result<Vec<_>>>()
+                    .with_context(|| format!("Failed to sample command {}", name))?;
+                let name = ident(name)?;
+                Ok(quote! {
+                    #[doc = #doc]
+                    pub fn #function() -> #name {
+                        #name {
+                            #(#fields,)*
+                        }
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let event_samples = events
+            .iter()
+            .map(|(name, event)| {
+                let doc = format!(" Sample `{}` event", name);
+                let function = snake_ident(name)?;
+                let args = event
+                    .payload
+                    .iter()
+                    .map(|prop| {
+                        Self::sample_property(model, prop, &mut Vec::new(), &mut uses_entities)
+                    })
+                    .collect::<Result<Vec<_>>>()
+                    .with_context(|| format!("Failed to sample event {}", name))?;
+                let name = ident(name)?;
+                Ok(quote! {
+                    #[doc = #doc]
+                    pub fn #function() -> DomainEvent {
+                        DomainEvent::#name(#name::new(AGGREGATE_ID.to_string(), #(#args),*))
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut imports = vec![quote! { use super::Scenario; }];
+        if !commands.is_empty() {
+            imports.push(quote! { use ::#krate::domain::commands::*; });
+        }
+        if uses_entities {
+            imports.push(quote! { use ::#krate::domain::entities::*; });
+        }
+        if !events.is_empty() {
+            imports.push(quote! { use ::#krate::domain::events::*; });
+        }
+
+        render(quote! {
+            #![doc = #module_doc]
+
+            #(#imports)*
+
+            pub const AGGREGATE_TYPE: &str = #aggregate_type;
+
+            /// ID of the aggregate in scenarios and sample events
+            pub const AGGREGATE_ID: &str = #aggregate_id;
+
+            #[doc = #scenario_doc]
+            pub fn scenario() -> Scenario {
+                Scenario::new(AGGREGATE_TYPE, AGGREGATE_ID)
+            }
+
+            #(#command_samples)*
+            #(#event_samples)*
+        })
+    }
+
+    /// Generate `tests/<aggregate>_test.rs` with starter tests, if the IR gives any
+    ///
+    /// Each command gets a test that it produces its events, each precondition
+    /// one that its message is reported and each invariant one that a command
+    /// breaking it is rejected. A scenario gives the sample events that bring
+    /// the aggregate to the status its command needs. Tests whose setup the
+    /// generator cannot derive are left out and listed in the module doc.
+    pub fn generate_aggregate_tests(
+        model: &IRModel,
+        aggregate: &Aggregate,
+    ) -> Result<Option<String>> {
+        let commands = Self::commands(model, aggregate);
+        let histories = Self::histories(model, aggregate);
+
+        let mut tests = Vec::new();
+        let mut left_out = Vec::new();
+        let mut uses_commands = false;
+        for (name, command) in &commands {
+            let sample = snake_ident(name)?;
+            let candidates = Self::candidates(model, aggregate, command, &histories);
+            let produces = &command.produces;
+            let doc = if produces.is_empty() {
+                format!(" `{}` produces no events", name)
+            } else {
+                format!(" `{}` produces `{}`", name, produces.join("`, `"))
+            };
+            let test = if produces.is_empty() {
+                format!("{}_produces_no_events", to_snake_case(name))
+            } else {
+                let events: Vec<String> = produces.iter().map(|e| to_snake_case(e)).collect();
+                format!("{}_produces_{}", to_snake_case(name), events.join("_and_"))
+            };
+            match Self::accepting_history(aggregate, command, &candidates) {
+                Some(given) => tests.push(Self::starter_test(
+                    &test,
+                    &doc,
+                    given,
+                    quote! { .when(#sample()).await.then(&[#(#produces),*]); },
+                )?),
+                None => left_out.push(test),
+            }
+
+            for (index, precondition) in command.preconditions.iter().enumerate() {
+                let test = if command.preconditions.len() == 1 {
+                    format!("{}_fails_precondition", to_snake_case(name))
+                } else {
+                    format!("{}_fails_precondition_{}", to_snake_case(name), index + 1)
+                };
+                let doc = format!(
+                    " `{}` is rejected unless `{}`",
+                    name, precondition.expression
+                );
+                let given = candidates.iter().find(|history| {
+                    command.preconditions[..index]
+                        .iter()
+                        .all(|earlier| history.holds(aggregate, &earlier.expression) == Some(true))
+                        && history.holds(aggregate, &precondition.expression) == Some(false)
+                });
+                let message = &precondition.message;
+                match given {
+                    Some(given) => tests.push(Self::starter_test(
+                        &test,
+                        &doc,
+                        given,
+                        quote! { .when(#sample()).await.then_error(#message); },
+                    )?),
+                    None => left_out.push(test),
+                }
+            }
+        }
+
+        for invariant in &aggregate.invariants {
+            let test = format!("{}_is_enforced", to_snake_case(&invariant.name));
+            let mut doc = format!(" Commands cannot break `{}`", invariant.name);
+            if let Some(description) = &invariant.description {
+                doc.push_str(&format!(": {}", description));
+            }
+            doc.push_str(&format!("\n\n `{}`", invariant.expression));
+            let breaking = commands.iter().find_map(|(name, command)| {
+                let candidates = Self::candidates(model, aggregate, command, &histories);
+                let given = Self::accepting_history(aggregate, command, &candidates)?;
+                let field = Self::breaking_field(model, command, &invariant.expression)?;
+                Some((name, command, given.clone(), field))
+            });
+            match breaking {
+                Some((name, command, given, field)) => {
+                    uses_commands = true;
+                    let sample = snake_ident(name)?;
+                    let name = ident(name)?;
+                    let command = if command.payload.len() == 1 {
+                        quote! { #name { #field } }
+                    } else {
+                        quote! { #name { #field, ..#sample() } }
+                    };
+                    tests.push(Self::starter_test(
+                        &test,
+                        &doc,
+                        &given,
+                        quote! { .when(#command).await.then_rejected(); },
+                    )?);
+                }
+                None => left_out.push(test),
+            }
+        }
+
+        if tests.is_empty() {
+            return Ok(None);
+        }
+
+        let mut module_doc = format!(
+            " Starter tests of the `{}` aggregate derived from the IR",
+            aggregate.name
+        );
+        if !left_out.is_empty() {
+            module_doc.push_str("\n\n Left out, as the IR does not tell how to set them up:\n");
+            for test in &left_out {
+                module_doc.push_str(&format!("\n - `{}`", test));
+            }
+        }
+        let doc_lines = module_doc.lines();
+        let module = snake_ident(&aggregate.name)?;
+        let commands_import = if uses_commands {
+            let krate = crate_ident(model)?;
+            quote! { use ::#krate::domain::commands::*; }
+        } else {
+            quote! {}
+        };
+
+        render(quote! {
+            #(#![doc = #doc_lines])*
+
+            mod support;
+
+            #commands_import
+            use support::#module::*;
+
+            #(#tests)*
+        })
+        .map(Some)
+    }
+
+    fn starter_test(
+        name: &str,
+        doc: &str,
+        given: &History,
+        when: TokenStream,
+    ) -> Result<TokenStream> {
+        let name = ident(name)?;
+        let doc = doc.lines();
+        let events = given
+            .events
+            .iter()
+            .map(|event| snake_ident(event))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(quote! {
+            #(#[doc = #doc])*
+            #[tokio::test]
+            async fn #name() {
+                scenario().given(vec![#(#events()),*]).await #when
+            }
+        })
+    }
+
+    /// Histories of sample events a scenario can give, shortest first
+    ///
+    /// Each starts with an event of a command creating the aggregate, alone or
+    /// followed by one of the events that set its status.
+    fn histories(model: &IRModel, aggregate: &Aggregate) -> Vec<History> {
+        let statuses = AggregateGenerator::status_events(model, aggregate);
+        let initial = aggregate
+            .root_entity
+            .properties
+            .iter()
+            .find(|prop| prop.name == "status")
+            .and_then(|prop| prop.default.as_ref())
+            .and_then(serde_json::Value::as_str)
+            .map(to_pascal_case);
+        let status_of = |event: &str| {
+            statuses
+                .iter()
+                .find(|(name, _)| name == event)
+                .map(|(_, status)| status.clone())
+        };
+
+        let mut created: Vec<&String> = Vec::new();
+        for (_, command) in Self::commands(model, aggregate) {
+            if AggregateGenerator::creates(model, aggregate, command) {
+                for event in &command.produces {
+                    if !created.contains(&event) {
+                        created.push(event);
+                    }
+                }
+            }
+        }
+
+        let mut histories: Vec<History> = created
+            .iter()
+            .map(|event| History {
+                events: vec![event.to_string()],
+                status: status_of(event).or_else(|| initial.clone()),
+            })
+            .collect();
+        for event in &created {
+            for (next, status) in &statuses {
+                if next != *event {
+                    histories.push(History {
+                        events: vec![event.to_string(), next.clone()],
+                        status: Some(status.clone()),
+                    });
+                }
+            }
+        }
+        histories
+    }
+
+    /// Histories a command can be sent after: none for a command creating its
+    /// aggregate, one in which the aggregate exists for a command acting on it
+    fn candidates(
+        model: &IRModel,
+        aggregate: &Aggregate,
+        command: &Command,
+        histories: &[History],
+    ) -> Vec<History> {
+        if AggregateGenerator::creates(model, aggregate, command) {
+            vec![History::default()]
+        } else if AggregateGenerator::id_field(aggregate, command).is_some() {
+            histories.to_vec()
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn accepting_history<'a>(
+        aggregate: &Aggregate,
+        command: &Command,
+        candidates: &'a [History],
+    ) -> Option<&'a History> {
+        candidates.iter().find(|history| {
+            command.preconditions.iter().all(|precondition| {
+                history.holds(aggregate, &precondition.expression) == Some(true)
+            })
+        })
+    }
+
+    /// Command field set so that the command breaks an invariant, as in
+    /// `items: Vec::new()` for `items.length > 0`
+    ///
+    /// The invariant must compare a field the command copies into its events,
+    /// or its length, with a number or another field of the command, and be
+    /// enforced rather than left to a `TODO` hook.
+    fn breaking_field(model: &IRModel, command: &Command, invariant: &str) -> Option<TokenStream> {
+        ExpressionGenerator::condition(invariant).ok()?;
+        let Ok(Expr::Binary(op, lhs, rhs)) = Expr::parse(invariant) else {
+            return None;
+        };
+        let flipped = match op {
+            BinaryOp::Lt => BinaryOp::Gt,
+            BinaryOp::Le => BinaryOp::Ge,
+            BinaryOp::Gt => BinaryOp::Lt,
+            BinaryOp::Ge => BinaryOp::Le,
+            BinaryOp::Eq => BinaryOp::Eq,
+            _ => return None,
+        };
+        Self::breaking_value(model, command, &lhs, op, &rhs)
+            .or_else(|| Self::breaking_value(model, command, &rhs, flipped, &lhs))
+    }
+
+    fn breaking_value(
+        model: &IRModel,
+        command: &Command,
+        field: &Expr,
+        op: BinaryOp,
+        other: &Expr,
+    ) -> Option<TokenStream> {
+        let (name, length) = match field {
+            Expr::Name(name) => (name, false),
+            Expr::Member(value, property) if property == "length" => match value.as_ref() {
+                Expr::Name(name) => (name, true),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let prop = command.payload.iter().find(|prop| &prop.name == name)?;
+        let copied = command
+            .produces
+            .iter()
+            .filter_map(|event| model.events.get(event))
+            .any(|event| {
+                event
+                    .payload
+                    .iter()
+                    .any(|field| &field.name == name && field.computed.is_none())
+            });
+        if !copied {
+            return None;
+        }
+
+        // A value above the other side breaks `<`, `<=` and `==`, one below it
+        // `>` and `>=`
+        let above = matches!(op, BinaryOp::Lt | BinaryOp::Le | BinaryOp::Eq);
+        let value = if length {
+            let Expr::Literal(serde_json::Value::Number(bound)) = other else {
+                return None;
+            };
+            let bound = bound.as_f64()?;
+            let list = prop.prop_type.starts_with("List<") || prop.prop_type.starts_with("Vec<");
+            let empty_breaks = match op {
+                BinaryOp::Gt => bound >= 0.0,
+                BinaryOp::Ge | BinaryOp::Eq => bound > 0.0,
+                _ => false,
+            };
+            if !list || !empty_breaks {
+                return None;
+            }
+            quote! { Vec::new() }
+        } else {
+            let bound = match other {
+                Expr::Literal(serde_json::Value::Number(bound)) => Some(bound.as_f64()?),
+                Expr::Name(other) if other != name => {
+                    command
+                        .payload
+                        .iter()
+                        .find(|prop| &prop.name == other)
+                        .filter(|other| other.prop_type == prop.prop_type)?;
+                    None
+                }
+                _ => return None,
+            };
+            Self::beyond(&prop.prop_type, bound, above)?
+        };
+        let field = snake_ident(name).ok()?;
+        Some(if prop.required {
+            quote! { #field: #value }
+        } else {
+            quote! { #field: Some(#value) }
+        })
+    }
+
+    /// Value of a primitive type one step above or below a number, or below
+    /// the sample of the type when no number is given
+    fn beyond(ir_type: &str, bound: Option<f64>, above: bool) -> Option<TokenStream> {
+        let step = if above { 1.0 } else { -1.0 };
+        let parsed = |text: &str| quote! { #text.parse().unwrap() };
+        match (ir_type, bound) {
+            ("Integer", bound) => {
+                let value = bound.unwrap_or(1.0) + step;
+                if value.fract() != 0.0 {
+                    return None;
+                }
+                let value = Literal::i64_unsuffixed(value as i64);
+                Some(quote! { #value })
+            }
+            ("Decimal", bound) => Some(parsed(&format!("{:.2}", bound.unwrap_or(10.0) + step))),
+            ("Date", None) => Some(parsed(if above { "2099-01-02" } else { "2098-12-31" })),
+            ("DateTime", None) => Some(parsed(if above {
+                "2099-01-02T09:00:00Z"
+            } else {
+                "2098-12-31T09:00:00Z"
+            })),
+            _ => None,
+        }
+    }
+
+    /// ID the samples give the aggregate, as its identifier sample serializes
+    fn aggregate_id(model: &IRModel, aggregate: &Aggregate) -> String {
+        let identifier = AggregateGenerator::identifier(aggregate)
+            .and_then(|prop| model.value_objects.get(&prop.prop_type))
+            .filter(|vo| vo.vo_type.as_deref() == Some("identifier"));
+        match identifier {
+            Some(vo) => match vo.underlying_type.as_deref() {
+                None | Some("String") => Self::identifier_sample(&vo.name),
+                Some("Uuid") => SAMPLE_UUID.to_string(),
+                Some("Integer") => "1".to_string(),
+                Some(_) => Self::identifier_sample(&aggregate.name),
+            },
+            None => Self::identifier_sample(&aggregate.name),
+        }
+    }
+
+    /// Sample of a string identifier, named after its type as `order-1` for `OrderId`
+    fn identifier_sample(name: &str) -> String {
+        let entity = name.strip_suffix("Id").unwrap_or(name);
+        format!("{}-1", to_kebab_case(entity))
+    }
+
+    fn commands<'a>(model: &'a IRModel, aggregate: &Aggregate) -> Vec<(&'a String, &'a Command)> {
+        model
+            .commands
+            .iter()
+            .filter(|(_, command)| command.aggregate.as_deref() == Some(aggregate.name.as_str()))
+            .collect()
+    }
+
+    fn events<'a>(model: &'a IRModel, aggregate: &Aggregate) -> Vec<(&'a String, &'a Event)> {
+        model
+            .events
+            .iter()
+            .filter(|(_, event)| event.aggregate.as_deref() == Some(aggregate.name.as_str()))
+            .collect()
+    }
+
+    fn sample_fields(
+        model: &IRModel,
+        properties: &[Property],
+        path: &mut Vec<String>,
+        uses_entities: &mut bool,
+    ) -> Result<Vec<TokenStream>> {
+        properties
+            .iter()
+            .map(|prop| {
+                let name = snake_ident(&prop.name)?;
+                let value = Self::sample_property(model, prop, path, uses_entities)?;
+                Ok(quote! { #name: #value })
+            })
+            .collect()
+    }
+
+    fn sample_property(
+        model: &IRModel,
+        prop: &Property,
+        path: &mut Vec<String>,
+        uses_entities: &mut bool,
+    ) -> Result<TokenStream> {
+        let validation = prop.validation.as_ref();
+        if prop.required {
+            Self::sample(model, &prop.prop_type, validation, path, uses_entities)
+        } else {
+            Self::sample(
+                model,
+                &format!("Option<{}>", prop.prop_type),
+                validation,
+                path,
+                uses_entities,
+            )
+        }
+    }
+
+    /// Sample value of an IR type, fixed so that generated tests are stable
+    ///
+    /// Enums take their first value and string identifiers are named after
+    /// their type, as in `order-1` for `OrderId`. Values satisfy `validation`,
+    /// as the mock server's examples do, so that commands built from them
+    /// pass validation. `path` holds the types being sampled: a list or
+    /// option of one of them stays empty, and a type that requires a value of
+    /// itself has no sample.
+    fn sample(
+        model: &IRModel,
+        ir_type: &str,
+        validation: Option<&Validation>,
+        path: &mut Vec<String>,
+        uses_entities: &mut bool,
+    ) -> Result<TokenStream> {
+        let ir_type = ir_type.trim();
+        for wrapper in ["List<", "Vec<", "Option<"] {
+            if let Some(inner) = ir_type.strip_prefix(wrapper) {
+                let inner = inner.strip_suffix('>').unwrap_or(inner);
+                let optional = wrapper == "Option<";
+                if referenced_type(inner).is_some_and(|name| path.contains(&name)) {
+                    return Ok(if optional {
+                        quote! { None }
+                    } else {
+                        quote! { Vec::new() }
+                    });
+                }
+                if optional {
+                    let value = Self::sample(model, inner, validation, path, uses_entities)?;
+                    return Ok(quote! { Some(#value) });
+                }
+                // Length rules of a list bound its number of items
+                let count = validation
+                    .and_then(|rule| rule.min_length)
+                    .unwrap_or(1)
+                    .max(1)
+                    .min(validation.and_then(|rule| rule.max_length).unwrap_or(1));
+                let value = Self::sample(model, inner, None, path, uses_entities)?;
+                let values = std::iter::repeat_n(value, count);
+                return Ok(quote! { Vec::from([#(#values),*]) });
+            }
+        }
+
+        let parsed = |text: &str| quote! { #text.parse().unwrap() };
+        match ir_type {
+            "String" => {
+                let text = match validation {
+                    Some(validation) => string("sample", Some(validation)),
+                    None => "sample".to_string(),
+                };
+                return Ok(quote! { #text.to_string() });
+            }
+            "Integer" => {
+                let value = Literal::i64_unsuffixed(bounded(1.0, validation) as i64);
+                return Ok(quote! { #value });
+            }
+            "Boolean" => return Ok(quote! { true }),
+            "Decimal" => {
+                let places = validation.and_then(|rule| rule.precision).unwrap_or(2);
+                return Ok(parsed(&format!("{:.*}", places, bounded(10.0, validation))));
+            }
+            "DateTime" => return Ok(parsed("2099-01-01T09:00:00Z")),
+            "Date" => return Ok(parsed("2099-01-01")),
+            "Time" => return Ok(parsed("09:00:00")),
+            "Uuid" => return Ok(parsed(SAMPLE_UUID)),
+            _ => {}
+        }
+
+        let name = to_rust_type(ir_type);
+        if path.contains(&name) {
+            bail!(
+                "Cannot sample {}, which requires a value of its own type",
+                name
+            );
+        }
+        let type_name = ident(&name)?;
+
+        if let Some(vo) = model.value_objects.get(&name) {
+            *uses_entities = true;
+            return match vo.vo_type.as_deref() {
+                Some("enum") => match vo.values.first() {
+                    Some(value) => {
+                        let variant = ident(&to_pascal_case(&value.name))?;
+                        Ok(quote! { #type_name::#variant })
+                    }
+                    None => bail!("Cannot sample enum {}, which has no values", name),
+                },
+                Some("identifier") => {
+                    let value = match vo.underlying_type.as_deref() {
+                        None | Some("String") => {
+                            let id = Self::identifier_sample(&name);
+                            quote! { #id.to_string() }
+                        }
+                        Some(underlying) => {
+                            Self::sample(model, underlying, None, path, uses_entities)?
+                        }
+                    };
+                    Ok(quote! { #type_name(#value) })
+                }
+                _ => {
+                    path.push(name.clone());
+                    let fields = Self::sample_fields(model, &vo.properties, path, uses_entities);
+                    path.pop();
+                    let fields = fields?;
+                    Ok(quote! { #type_name { #(#fields,)* } })
+                }
+            };
+        }
+        if let Some(aggregate) = model.aggregates.get(&name) {
+            *uses_entities = true;
+            path.push(name.clone());
+            let fields = Self::sample_fields(
+                model,
+                &aggregate.root_entity.properties,
+                path,
+                uses_entities,
+            );
+            path.pop();
+            let fields = fields?;
+            return Ok(quote! { #type_name { #(#fields,)* } });
+        }
+        bail!("Cannot sample {}, which the IR does not define", name)
+    }
+}
+
+/// Sample events a scenario gives, with the aggregate status they leave
+#[derive(Debug, Clone, Default)]
+struct History {
+    events: Vec<String>,
+    status: Option<String>,
+}
+
+impl History {
+    /// Whether a precondition holds after the history, if it depends on the
+    /// aggregate status alone and is enforced rather than left to a `TODO` hook
+    fn holds(&self, aggregate: &Aggregate, precondition: &str) -> Option<bool> {
+        ExpressionGenerator::condition(precondition).ok()?;
+        let expr = Expr::parse(precondition).ok()?;
+        self.status_holds(aggregate, &expr)
+    }
+
+    fn status_holds(&self, aggregate: &Aggregate, expr: &Expr) -> Option<bool> {
+        let is_status = |expr: &Expr| match expr {
+            Expr::Member(value, property) if property == "status" => matches!(
+                value.as_ref(),
+                Expr::Name(name) if to_snake_case(name) == to_snake_case(&aggregate.name)
+            ),
+            _ => false,
+        };
+        let constant = |expr: &Expr| match expr {
+            Expr::Constant(name) => Some(to_pascal_case(name)),
+            _ => None,
+        };
+        let status = self.status.as_deref();
+        match expr {
+            Expr::Unary(UnaryOp::Not, value) => self.status_holds(aggregate, value).map(|h| !h),
+            Expr::Binary(BinaryOp::And, lhs, rhs) => {
+                match (
+                    self.status_holds(aggregate, lhs),
+                    self.status_holds(aggregate, rhs),
+                ) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                }
+            }
+            Expr::Binary(BinaryOp::Or, lhs, rhs) => {
+                match (
+                    self.status_holds(aggregate, lhs),
+                    self.status_holds(aggregate, rhs),
+                ) {
+                    (Some(true), _) | (_, Some(true)) => Some(true),
+                    (Some(false), Some(false)) => Some(false),
+                    _ => None,
+                }
+            }
+            Expr::Binary(op @ (BinaryOp::Eq | BinaryOp::Ne), lhs, rhs) => {
+                let value = if is_status(lhs) {
+                    constant(rhs)?
+                } else if is_status(rhs) {
+                    constant(lhs)?
+                } else {
+                    return None;
+                };
+                let equal = status == Some(value.as_str());
+                Some(if *op == BinaryOp::Eq { equal } else { !equal })
+            }
+            Expr::Binary(BinaryOp::In, value, list) if is_status(value) => {
+                let Expr::List(items) = list.as_ref() else {
+                    return None;
+                };
+                let values = items.iter().map(constant).collect::<Option<Vec<_>>>()?;
+                Some(values.iter().any(|value| status == Some(value.as_str())))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> IRModel {
+        serde_yaml::from_str(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "shop.order"
+aggregates:
+  Order:
+    name: "Order"
+    root_entity:
+      name: "Order"
+      properties:
+        - name: "id"
+          type: "OrderId"
+          identifier: true
+        - name: "status"
+          type: "OrderStatus"
+          default: "DRAFT"
+        - name: "items"
+          type: "List<String>"
+        - name: "total"
+          type: "Decimal"
+    invariants:
+      - name: "OrderMustHaveItems"
+        expression: "items.length > 0"
+      - name: "TotalMatchesItems"
+        expression: "total == sum(items)"
+value_objects:
+  OrderId:
+    name: "OrderId"
+    type: "identifier"
+    underlying_type: "String"
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "DRAFT"
+      - name: "PLACED"
+events:
+  OrderDrafted:
+    name: "OrderDrafted"
+    aggregate: "Order"
+    payload:
+      - name: "items"
+        type: "List<String>"
+  OrderPlaced:
+    name: "OrderPlaced"
+    aggregate: "Order"
+    payload:
+      - name: "itemCount"
+        type: "Integer"
+commands:
+  DraftOrder:
+    name: "DraftOrder"
+    aggregate: "Order"
+    payload:
+      - name: "items"
+        type: "List<String>"
+    produces: ["OrderDrafted"]
+  PlaceOrder:
+    name: "PlaceOrder"
+    aggregate: "Order"
+    payload:
+      - name: "orderId"
+        type: "OrderId"
+      - name: "itemCount"
+        type: "Integer"
+    produces: ["OrderPlaced"]
+    preconditions:
+      - expression: "order.status == DRAFT"
+        message: "Only draft orders can be placed"
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_starter_tests_follow_the_ir() {
+        let model = model();
+        let tests = TestGenerator::generate_aggregate_tests(&model, &model.aggregates["Order"])
+            .unwrap()
+            .unwrap();
+
+        assert!(tests.contains("async fn place_order_produces_order_placed()"));
+        assert!(tests.contains(".then(&[\"OrderPlaced\"])"));
+        assert!(tests.contains("async fn place_order_fails_precondition()"));
+        assert!(tests.contains(".then_error(\"Only draft orders can be placed\")"));
+        assert!(tests.contains("async fn order_must_have_items_is_enforced()"));
+        assert!(!tests.contains("#[ignore"));
+    }
+
+    #[test]
+    fn test_scenarios_give_the_status_a_command_needs() {
+        let model = model();
+        let tests = TestGenerator::generate_aggregate_tests(&model, &model.aggregates["Order"])
+            .unwrap()
+            .unwrap();
+        let test = |name: &str| {
+            let start = tests.find(&format!("async fn {}()", name)).unwrap();
+            let end = tests[start..].find("\n}").unwrap();
+            tests[start..start + end].to_string()
+        };
+
+        assert!(test("draft_order_produces_order_drafted").contains(".given(vec![])"));
+        assert!(test("place_order_produces_order_placed").contains(".given(vec![order_drafted()])"));
+        assert!(test("place_order_fails_precondition")
+            .contains(".given(vec![order_drafted(), order_placed()])"));
+        assert!(test("order_must_have_items_is_enforced")
+            .contains(".when(DraftOrder { items: Vec::new() })"));
+        assert!(!tests.contains("async fn total_matches_items_is_enforced()"));
+        assert!(tests.contains("//! - `total_matches_items_is_enforced`"));
+    }
+
+    #[test]
+    fn test_samples_use_the_aggregate_id() {
+        let model = model();
+        let support =
+            TestGenerator::generate_aggregate_support(&model, &model.aggregates["Order"]).unwrap();
+
+        assert!(support.contains("pub const AGGREGATE_ID: &str = \"order-1\";"));
+        assert!(support.contains("pub fn place_order() -> PlaceOrder"));
+        assert!(support.contains("pub fn order_placed() -> DomainEvent"));
+        assert!(support.contains("order_id: OrderId(\"order-1\".to_string())"));
+        assert!(support
+            .contains("DomainEvent::OrderPlaced(OrderPlaced::new(AGGREGATE_ID.to_string(), 1))"));
+    }
+
+    #[test]
+    fn test_types_without_a_sample_fail_generation() {
+        let mut model = model();
+        model
+            .value_objects
+            .get_mut("OrderStatus")
+            .unwrap()
+            .values
+            .clear();
+        model.events.get_mut("OrderPlaced").unwrap().payload[0].prop_type =
+            "OrderStatus".to_string();
+        let error = TestGenerator::generate_aggregate_support(&model, &model.aggregates["Order"])
+            .unwrap_err();
+        assert!(format!("{:#}", error).contains("Cannot sample enum OrderStatus"));
+
+        let mut model = self::model();
+        let event = model.events.get_mut("OrderPlaced").unwrap();
+        event.payload[0].prop_type = "Coupon".to_string();
+        let error = TestGenerator::generate_aggregate_support(&model, &model.aggregates["Order"])
+            .unwrap_err();
+        assert!(format!("{:#}", error).contains("Cannot sample Coupon"));
+    }
+
+    #[test]
+    fn test_recursive_samples_stop_at_optional_fields() {
+        let model: IRModel = serde_yaml::from_str(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Catalog"
+  namespace: "shop.catalog"
+value_objects:
+  Category:
+    name: "Category"
+    properties:
+      - name: "name"
+        type: "String"
+      - name: "parent"
+        type: "Category"
+        required: false
+      - name: "children"
+        type: "List<Category>"
+"#,
+        )
+        .unwrap();
+        let sample = TestGenerator::sample(&model, "Category", None, &mut Vec::new(), &mut false)
+            .unwrap()
+            .to_string();
+        assert!(sample.contains("parent : None"));
+        assert!(sample.contains("children : Vec :: new ()"));
+
+        let mut model = model;
+        model.value_objects.get_mut("Category").unwrap().properties[1].required = true;
+        let error = TestGenerator::sample(&model, "Category", None, &mut Vec::new(), &mut false)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("requires a value of its own type"));
+    }
+
+    #[test]
+    fn test_samples_satisfy_validation_rules() {
+        let model: IRModel = serde_yaml::from_str(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Billing"
+  namespace: "shop.billing"
+value_objects:
+  Money:
+    name: "Money"
+    properties:
+      - name: "amount"
+        type: "Decimal"
+        validation:
+          min: 100
+          precision: 1
+      - name: "currency"
+        type: "String"
+        validation:
+          pattern: "^[A-Z]{3}$"
+      - name: "quantity"
+        type: "Integer"
+        validation:
+          max: 0
+      - name: "tags"
+        type: "List<String>"
+        validation:
+          minLength: 2
+          maxLength: 5
+"#,
+        )
+        .unwrap();
+        let sample = TestGenerator::sample(&model, "Money", None, &mut Vec::new(), &mut false)
+            .unwrap()
+            .to_string();
+        assert!(sample.contains("amount : \"100.0\" . parse ()"));
+        assert!(sample.contains("currency : \"AAA\" . to_string ()"));
+        assert!(sample.contains("quantity : 0"));
+        assert!(sample.contains(
+            "tags : Vec :: from ([\"sample\" . to_string () , \"sample\" . to_string ()])"
+        ));
+    }
+}
@@ -0,0 +1,841 @@
+use crate::generators::utils::*;
+use crate::ir::{Event, IRModel, Property, Query, ReadModel, ReadModelField, ValueObject};
+use anyhow::{Context, Result};
+use std::collections::BTreeSet;
+use std::fs;
+
+/// Prefix under which the Rust service nests its API router
+const API_PREFIX: &str = "/api";
+
+pub struct TypeScriptGenerator {
+    output_dir: String,
+}
+
+/// Property of a generated TypeScript interface
+struct TsField {
+    /// JSON key, the snake_case name of the Rust field
+    name: String,
+    ts_type: String,
+    /// The key may be omitted, as serde accepts a missing `Option` field
+    optional: bool,
+    description: Option<String>,
+}
+
+impl TypeScriptGenerator {
+    pub fn new(output_dir: String) -> Self {
+        Self { output_dir }
+    }
+
+    /// Generate a TypeScript SDK for the service generated from an IR model
+    pub fn generate(&self, model: &IRModel) -> Result<()> {
+        println!(
+            "🚀 Generating TypeScript SDK for '{}'...",
+            model.bounded_context.name
+        );
+
+        let src_dir = format!("{}/src", self.output_dir);
+        fs::create_dir_all(&src_dir)
+            .with_context(|| format!("Failed to create directory: {}", src_dir))?;
+
+        let files = [
+            ("package.json", Self::generate_package_json(model)),
+            ("tsconfig.json", TSCONFIG.to_string()),
+            ("README.md", Self::generate_readme(model)),
+            ("src/types.ts", Self::generate_types(model)?),
+            ("src/commands.ts", Self::generate_commands(model)),
+            ("src/events.ts", Self::generate_events(model)),
+            ("src/queries.ts", Self::generate_queries(model)),
+            ("src/client.ts", Self::generate_client(model)),
+            ("src/index.ts", INDEX.to_string()),
+        ];
+        for (file, content) in files {
+            let path = format!("{}/{}", self.output_dir, file);
+            fs::write(&path, content).with_context(|| format!("Failed to write {}", path))?;
+        }
+
+        println!("✅ Generation complete! Output at: {}", self.output_dir);
+
+        Ok(())
+    }
+
+    fn package_name(model: &IRModel) -> String {
+        format!("{}-client", to_kebab_case(&model.bounded_context.name))
+    }
+
+    fn generate_package_json(model: &IRModel) -> String {
+        format!(
+            r#"{{
+  "name": "{}",
+  "version": "0.1.0",
+  "description": "TypeScript client for the {} service",
+  "type": "module",
+  "main": "dist/index.js",
+  "types": "dist/index.d.ts",
+  "files": [
+    "dist"
+  ],
+  "scripts": {{
+    "build": "tsc"
+  }},
+  "devDependencies": {{
+    "typescript": "^5.4.0"
+  }}
+}}
+"#,
+            Self::package_name(model),
+            model.bounded_context.name
+        )
+    }
+
+    fn generate_readme(model: &IRModel) -> String {
+        let context = &model.bounded_context.name;
+        let example = match model.commands.iter().next() {
+            Some((name, _)) => format!(
+                "\n// Commands return the `data` of the response\nconst result = await client.{}(command);\n",
+                to_camel_case(name)
+            ),
+            None => String::new(),
+        };
+
+        format!(
+            r#"# {package}
+
+TypeScript client for the {context} service, generated by StormForge Generator
+
+## Building
+
+```bash
+npm install
+npm run build
+```
+
+## Usage
+
+```typescript
+import {{ {context}Client }} from "{package}";
+
+const client = new {context}Client({{ baseUrl: "http://localhost:3000" }});
+{example}```
+
+Failed requests throw an `ApiError` carrying the HTTP status and the error code of the service.
+
+## Structure
+
+```
+src/
+├── types.ts      # Value objects, aggregates and read models
+├── commands.ts   # Command payloads
+├── events.ts     # Domain events and the DomainEvent union
+├── queries.ts    # Query parameters and results
+├── client.ts     # HTTP client
+└── index.ts      # Exports
+```
+"#,
+            package = Self::package_name(model),
+        )
+    }
+
+    /// Generate types for value objects, aggregates and read model rows
+    pub fn generate_types(model: &IRModel) -> Result<String> {
+        let mut items = Vec::new();
+
+        for (name, vo) in &model.value_objects {
+            items.push(Self::generate_value_object(model, name, vo)?);
+        }
+
+        for (name, aggregate) in &model.aggregates {
+            let fields = Self::fields(model, &aggregate.root_entity.properties, false);
+            items.push(Self::interface(
+                name,
+                aggregate.description.as_deref(),
+                &fields,
+            ));
+        }
+
+        for read_model in model.read_models.values() {
+            items.push(Self::generate_read_model(model, read_model));
+        }
+
+        Ok(Self::module(Vec::new(), items))
+    }
+
+    fn generate_value_object(model: &IRModel, name: &str, vo: &ValueObject) -> Result<String> {
+        let mut out = doc_comment(vo.description.as_deref(), "");
+        match vo.vo_type.as_deref() {
+            // Enums are unit variants, serialized as the variant name
+            Some("enum") => {
+                if vo.values.is_empty() {
+                    anyhow::bail!("Enum '{}' has no values", name);
+                }
+                let values_const = format!("{}_VALUES", to_snake_case(name).to_uppercase());
+                out = format!(
+                    "/** Variants of `{}` */\nexport const {} = [\n",
+                    name, values_const
+                );
+                for value in &vo.values {
+                    out.push_str(&doc_comment(value.description.as_deref(), "  "));
+                    out.push_str(&format!("  \"{}\",\n", to_pascal_case(&value.name)));
+                }
+                out.push_str("] as const;\n\n");
+                out.push_str(&doc_comment(vo.description.as_deref(), ""));
+                out.push_str(&format!(
+                    "export type {} = (typeof {})[number];\n",
+                    name, values_const
+                ));
+            }
+            // Identifiers are newtypes, serialized as the underlying value
+            Some("identifier") => {
+                let underlying = vo.underlying_type.as_deref().unwrap_or("String");
+                out.push_str(&format!(
+                    "export type {} = {};\n",
+                    name,
+                    ts_type(model, underlying)
+                ));
+            }
+            _ => {
+                let fields = Self::fields(model, &vo.properties, false);
+                return Ok(Self::interface(name, vo.description.as_deref(), &fields));
+            }
+        }
+        Ok(out)
+    }
+
+    /// Read model rows have every field optional, and untyped fields are raw JSON
+    fn generate_read_model(model: &IRModel, read_model: &ReadModel) -> String {
+        let fields = read_model
+            .fields
+            .iter()
+            .map(|field| TsField {
+                name: to_snake_case(&field.name),
+                ts_type: nullable(&Self::read_model_field_type(model, field)),
+                optional: false,
+                description: field.description.clone(),
+            })
+            .collect::<Vec<_>>();
+        Self::interface(&read_model.name, read_model.description.as_deref(), &fields)
+    }
+
+    /// Same resolution as the projection row, where fields of a type that is
+    /// not a value object or aggregate are raw JSON
+    fn read_model_field_type(model: &IRModel, field: &ReadModelField) -> String {
+        let Some(field_type) = &field.field_type else {
+            return "unknown".to_string();
+        };
+        let known = referenced_type(field_type).is_none_or(|name| {
+            model.value_objects.contains_key(&name) || model.aggregates.contains_key(&name)
+        });
+        if known {
+            ts_type(model, field_type)
+        } else {
+            "unknown".to_string()
+        }
+    }
+
+    /// Generate command payload types
+    pub fn generate_commands(model: &IRModel) -> String {
+        let items = model
+            .commands
+            .iter()
+            .map(|(name, command)| {
+                let fields = Self::fields(model, &command.payload, true);
+                Self::interface(name, command.description.as_deref(), &fields)
+            })
+            .collect();
+        let imports = Self::type_imports(
+            model,
+            model
+                .commands
+                .values()
+                .flat_map(|command| &command.payload)
+                .map(|prop| prop.prop_type.as_str()),
+        );
+        Self::module(imports, items)
+    }
+
+    /// Generate event types and the `DomainEvent` union tagged by `type`
+    pub fn generate_events(model: &IRModel) -> String {
+        let mut items = Vec::new();
+
+        let mut union = String::from(
+            "/** Domain event enum containing all events in this bounded context, tagged by `type` */\nexport type DomainEvent =",
+        );
+        if model.events.is_empty() {
+            union.push_str(" never;\n");
+        } else {
+            for name in model.events.keys() {
+                union.push_str(&format!("\n  | ({{ type: \"{}\" }} & {})", name, name));
+            }
+            union.push_str(";\n");
+        }
+        union.push_str("\n/** Name of a domain event */\nexport type DomainEventType = DomainEvent[\"type\"];\n");
+        items.push(union);
+
+        for (name, event) in &model.events {
+            items.push(Self::generate_event(model, name, event));
+        }
+
+        let imports = Self::type_imports(
+            model,
+            model
+                .events
+                .values()
+                .flat_map(|event| &event.payload)
+                .map(|prop| prop.prop_type.as_str()),
+        );
+        Self::module(imports, items)
+    }
+
+    fn generate_event(model: &IRModel, name: &str, event: &Event) -> String {
+        let mut fields = Vec::from([
+            TsField {
+                name: "event_id".to_string(),
+                ts_type: "string".to_string(),
+                optional: false,
+                description: Some("Unique event identifier".to_string()),
+            },
+            TsField {
+                name: "occurred_at".to_string(),
+                ts_type: "string".to_string(),
+                optional: false,
+                description: Some("Timestamp when the event occurred, in RFC 3339".to_string()),
+            },
+        ]);
+        if event.aggregate.is_some() {
+            fields.push(TsField {
+                name: "aggregate_id".to_string(),
+                ts_type: "string".to_string(),
+                optional: false,
+                description: Some("ID of the aggregate that produced this event".to_string()),
+            });
+        }
+        fields.extend(Self::fields(model, &event.payload, false));
+        Self::interface(name, event.description.as_deref(), &fields)
+    }
+
+    /// Generate query parameter and result types
+    ///
+    /// A result is the `data` of the `ApiResponseString` the service answers
+    /// its query routes with, so it stays a string until the service returns
+    /// the type the IR declares.
+    pub fn generate_queries(model: &IRModel) -> String {
+        let mut items = Vec::new();
+
+        for (name, query) in &model.queries {
+            if !query.parameters.is_empty() {
+                let fields = query
+                    .parameters
+                    .iter()
+                    .map(|param| TsField {
+                        name: to_snake_case(&param.name),
+                        ts_type: ts_type(model, &param.prop_type),
+                        optional: !param.required,
+                        description: param.description.clone(),
+                    })
+                    .collect::<Vec<_>>();
+                items.push(Self::interface(
+                    &format!("{}Params", name),
+                    Some(&format!("Parameters of the `{}` query", name)),
+                    &fields,
+                ));
+            }
+            let mut description = format!(
+                "Result of the `{}` query, the `data` of its `ApiResponseString` response",
+                name
+            );
+            if let Some(declared) = Self::query_result(model, query) {
+                description.push_str(&format!(
+                    "\n\nThe IR declares `{}`, which the generated service does not return yet.",
+                    declared
+                ));
+            }
+            items.push(format!(
+                "{}export type {}Result = string;\n",
+                doc_comment(Some(&description), ""),
+                name
+            ));
+        }
+
+        let imports = Self::type_imports(
+            model,
+            model.queries.values().flat_map(|query| {
+                query
+                    .parameters
+                    .iter()
+                    .map(|param| param.prop_type.as_str())
+            }),
+        );
+        Self::module(imports, items)
+    }
+
+    /// TypeScript type of the result the IR declares for a query
+    fn query_result(model: &IRModel, query: &Query) -> Option<String> {
+        query.returns.as_ref().map(|returns| {
+            let ts_type = ts_type(model, &returns.return_type);
+            if returns.nullable {
+                nullable(&ts_type)
+            } else {
+                ts_type
+            }
+        })
+    }
+
+    /// Generate the HTTP client with a method per route of the API router
+    pub fn generate_client(model: &IRModel) -> String {
+        let context = &model.bounded_context.name;
+
+        let mut imports = Vec::new();
+        if !model.commands.is_empty() {
+            imports.push(format!(
+                "import type {{ {} }} from \"./commands\";",
+                model
+                    .commands
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        let query_types = model
+            .queries
+            .iter()
+            .flat_map(|(name, query)| {
+                let params = (!query.parameters.is_empty()).then(|| format!("{}Params", name));
+                params.into_iter().chain([format!("{}Result", name)])
+            })
+            .collect::<Vec<_>>();
+        if !query_types.is_empty() {
+            imports.push(format!(
+                "import type {{ {} }} from \"./queries\";",
+                query_types.join(", ")
+            ));
+        }
+        if !model.read_models.is_empty() {
+            imports.push(format!(
+                "import type {{ {} }} from \"./types\";",
+                model
+                    .read_models
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        let mut methods = Vec::new();
+        for (name, command) in &model.commands {
+            methods.push(format!(
+                "{}  async {}(command: {}): Promise<string> {{\n    return this.post<string>(\"{}/{}\", command);\n  }}\n",
+                doc_comment(command.description.as_deref(), "  "),
+                to_camel_case(name),
+                name,
+                API_PREFIX,
+                to_kebab_case(name)
+            ));
+        }
+        for (name, query) in &model.queries {
+            let (params, args) = if query.parameters.is_empty() {
+                (String::new(), String::new())
+            } else {
+                (format!("params: {}Params", name), ", params".to_string())
+            };
+            methods.push(format!(
+                "{}  async {}({}): Promise<{}Result> {{\n    return this.get<{}Result>(\"{}/{}\"{});\n  }}\n",
+                doc_comment(query.description.as_deref(), "  "),
+                to_camel_case(name),
+                params,
+                name,
+                name,
+                API_PREFIX,
+                to_kebab_case(name),
+                args
+            ));
+        }
+        for name in model.read_models.keys() {
+            let path = format!("{}/read-models/{}", API_PREFIX, to_kebab_case(name));
+            methods.push(format!(
+                r#"  /** List all `{name}` rows */
+  async list{name}(): Promise<{name}[]> {{
+    return this.request<{name}[]>("GET", "{path}");
+  }}
+
+  /** Get one `{name}` row by aggregate ID, `null` if there is none */
+  async get{name}(id: string): Promise<{name} | null> {{
+    try {{
+      return await this.request<{name}>("GET", `{path}/${{encodeURIComponent(id)}}`);
+    }} catch (error) {{
+      if (error instanceof ApiError && error.status === 404) {{
+        return null;
+      }}
+      throw error;
+    }}
+  }}
+"#
+            ));
+        }
+
+        let mut out = String::new();
+        if !imports.is_empty() {
+            out.push_str(&imports.join("\n"));
+            out.push_str("\n\n");
+        }
+        out.push_str(&CLIENT_PRELUDE.replace("{context}", context));
+        out.push_str(&format!(
+            "\n/** HTTP client for the {context} service */\nexport class {context}Client {{\n"
+        ));
+        out.push_str(CLIENT_CONSTRUCTOR);
+        for method in methods {
+            out.push('\n');
+            out.push_str(&method);
+        }
+        out.push('\n');
+        out.push_str(CLIENT_TRANSPORT);
+        out.push_str("}\n");
+        out
+    }
+
+    fn fields(model: &IRModel, properties: &[Property], input: bool) -> Vec<TsField> {
+        properties
+            .iter()
+            .map(|prop| {
+                let ts_type = ts_type(model, &prop.prop_type);
+                TsField {
+                    name: to_snake_case(&prop.name),
+                    ts_type: if prop.required {
+                        ts_type
+                    } else {
+                        nullable(&ts_type)
+                    },
+                    optional: input && !prop.required,
+                    description: prop.description.clone(),
+                }
+            })
+            .collect()
+    }
+
+    fn interface(name: &str, description: Option<&str>, fields: &[TsField]) -> String {
+        let mut out = doc_comment(description, "");
+        if fields.is_empty() {
+            out.push_str(&format!("export type {} = Record<string, never>;\n", name));
+            return out;
+        }
+        out.push_str(&format!("export interface {} {{\n", name));
+        for field in fields {
+            out.push_str(&doc_comment(field.description.as_deref(), "  "));
+            out.push_str(&format!(
+                "  {}{}: {};\n",
+                field.name,
+                if field.optional { "?" } else { "" },
+                field.ts_type
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// `import type` of the types module for the domain types referenced by IR types
+    fn type_imports<'a>(model: &IRModel, ir_types: impl Iterator<Item = &'a str>) -> Vec<String> {
+        let names = ir_types
+            .filter_map(referenced_type)
+            .filter(|name| is_known_type(model, name))
+            .collect::<BTreeSet<_>>();
+        if names.is_empty() {
+            return Vec::new();
+        }
+        Vec::from([format!(
+            "import type {{ {} }} from \"./types\";",
+            names.into_iter().collect::<Vec<_>>().join(", ")
+        )])
+    }
+
+    fn module(imports: Vec<String>, items: Vec<String>) -> String {
+        if items.is_empty() {
+            return "export {};\n".to_string();
+        }
+        let mut out = String::new();
+        if !imports.is_empty() {
+            out.push_str(&imports.join("\n"));
+            out.push_str("\n\n");
+        }
+        out.push_str(&items.join("\n"));
+        out
+    }
+}
+
+/// Whether a named type is generated in the types module
+fn is_known_type(model: &IRModel, name: &str) -> bool {
+    model.value_objects.contains_key(name)
+        || model.aggregates.contains_key(name)
+        || model.read_models.contains_key(name)
+}
+
+/// TypeScript type of the JSON the Rust service produces for an IR type
+pub fn ts_type(model: &IRModel, ir_type: &str) -> String {
+    let ir_type = ir_type.trim();
+    let generic = |prefix: &str| {
+        ir_type
+            .strip_prefix(prefix)
+            .map(|rest| ts_type(model, rest.strip_suffix('>').unwrap_or(rest)))
+    };
+    if let Some(item) = generic("List<").or_else(|| generic("Vec<")) {
+        return if item.contains(' ') {
+            format!("({})[]", item)
+        } else {
+            format!("{}[]", item)
+        };
+    }
+    if let Some(inner) = generic("Option<") {
+        return nullable(&inner);
+    }
+    if let Some(item) = generic("PagedResult<") {
+        return format!("PagedResult<{}>", item);
+    }
+
+    match ir_type {
+        "Integer" => "number".to_string(),
+        "Boolean" => "boolean".to_string(),
+        // Decimals are serialized as strings to keep their precision, and
+        // dates and times as ISO 8601 strings
+        "String" | "Decimal" | "DateTime" | "Date" | "Time" | "Uuid" => "string".to_string(),
+        _ => {
+            let name = to_rust_type(ir_type);
+            if is_known_type(model, &name) {
+                name
+            } else {
+                // Types the IR does not define are kept as raw JSON
+                "unknown".to_string()
+            }
+        }
+    }
+}
+
+/// Nullable TypeScript type, as an `Option` is serialized as `null`
+fn nullable(ts_type: &str) -> String {
+    if ts_type == "unknown" || ts_type.ends_with(" | null") {
+        ts_type.to_string()
+    } else {
+        format!("{} | null", ts_type)
+    }
+}
+
+/// TSDoc comment at the given indentation
+fn doc_comment(text: Option<&str>, indent: &str) -> String {
+    let Some(text) = text else {
+        return String::new();
+    };
+    if !text.contains('\n') {
+        return format!("{}/** {} */\n", indent, text);
+    }
+    let mut out = format!("{}/**\n", indent);
+    for line in text.lines() {
+        if line.is_empty() {
+            out.push_str(&format!("{} *\n", indent));
+        } else {
+            out.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    out.push_str(&format!("{} */\n", indent));
+    out
+}
+
+const TSCONFIG: &str = r#"{
+  "compilerOptions": {
+    "target": "ES2020",
+    "module": "ESNext",
+    "moduleResolution": "Bundler",
+    "lib": ["ES2020", "DOM"],
+    "declaration": true,
+    "strict": true,
+    "outDir": "dist",
+    "rootDir": "src"
+  },
+  "include": ["src"]
+}
+"#;
+
+const INDEX: &str = r#"export * from "./types";
+export * from "./commands";
+export * from "./events";
+export * from "./queries";
+export * from "./client";
+"#;
+
+const CLIENT_PRELUDE: &str = r#"/** Error response of the {context} service */
+export class ApiError extends Error {
+  constructor(
+    /** HTTP status code */
+    readonly status: number,
+    /** Error code, such as `VALIDATION_ERROR` */
+    readonly code: string,
+    message: string,
+  ) {
+    super(message);
+    this.name = "ApiError";
+  }
+}
+
+/** Options of the {context} client */
+export interface ClientOptions {
+  /** Base URL of the service, without the `/api` prefix */
+  baseUrl: string;
+  /** Headers sent with every request */
+  headers?: Record<string, string>;
+  /** Fetch implementation, the global `fetch` by default */
+  fetch?: typeof fetch;
+}
+
+/** `{"data": ...}` envelope of command and query responses */
+interface ApiResponse<T> {
+  data: T;
+}
+"#;
+
+const CLIENT_CONSTRUCTOR: &str = r#"  private readonly baseUrl: string;
+  private readonly headers: Record<string, string>;
+  private readonly fetchImpl: typeof fetch;
+
+  constructor(options: ClientOptions) {
+    this.baseUrl = options.baseUrl.replace(/\/+$/, "");
+    this.headers = options.headers ?? {};
+    this.fetchImpl = options.fetch ?? globalThis.fetch.bind(globalThis);
+  }
+"#;
+
+const CLIENT_TRANSPORT: &str = r#"  private async post<T>(path: string, body: unknown): Promise<T> {
+    const response = await this.request<ApiResponse<T>>("POST", path, body);
+    return response.data;
+  }
+
+  private async get<T>(path: string, params: object = {}): Promise<T> {
+    const query = new URLSearchParams();
+    for (const [key, value] of Object.entries(params)) {
+      if (value !== undefined && value !== null) {
+        query.append(key, String(value));
+      }
+    }
+    const search = query.toString();
+    const response = await this.request<ApiResponse<T>>("GET", search ? `${path}?${search}` : path);
+    return response.data;
+  }
+
+  private async request<T>(method: string, path: string, body?: unknown): Promise<T> {
+    const response = await this.fetchImpl(`${this.baseUrl}${path}`, {
+      method,
+      headers:
+        body === undefined ? this.headers : { ...this.headers, "content-type": "application/json" },
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    const text = await response.text();
+    const payload: unknown = text ? JSON.parse(text) : null;
+    if (!response.ok) {
+      const error = (payload ?? {}) as { code?: string; message?: string };
+      throw new ApiError(
+        response.status,
+        error.code ?? `HTTP_${response.status}`,
+        error.message ?? response.statusText,
+      );
+    }
+    return payload as T;
+  }
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn model() -> IRModel {
+        IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "test.order"
+value_objects:
+  OrderId:
+    name: "OrderId"
+    type: "identifier"
+    underlying_type: "Uuid"
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "PAID"
+queries:
+  GetOrder:
+    name: "GetOrder"
+    parameters:
+      - name: "orderId"
+        type: "OrderId"
+        required: true
+    returns:
+      type: "OrderStatus"
+      nullable: true
+commands:
+  CancelOrder:
+    name: "CancelOrder"
+    payload:
+      - name: "orderId"
+        type: "OrderId"
+        required: true
+      - name: "reason"
+        type: "String"
+        required: false
+events:
+  OrderCancelled:
+    name: "OrderCancelled"
+    aggregate: "Order"
+    payload:
+      - name: "statuses"
+        type: "List<OrderStatus>"
+        required: true
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_types_follow_serde_layout() {
+        let model = model();
+        let types = TypeScriptGenerator::generate_types(&model).unwrap();
+        assert!(types.contains("export type OrderId = string;"));
+        assert!(types.contains("export const ORDER_STATUS_VALUES = [\n  \"Paid\",\n] as const;"));
+
+        let commands = TypeScriptGenerator::generate_commands(&model);
+        assert!(commands.starts_with("import type { OrderId } from \"./types\";"));
+        assert!(commands.contains("  order_id: OrderId;\n  reason?: string | null;\n"));
+
+        assert_eq!(
+            ts_type(&model, "Option<List<Option<Money>>>"),
+            "unknown[] | null"
+        );
+        assert_eq!(
+            ts_type(&model, "List<Option<OrderId>>"),
+            "(OrderId | null)[]"
+        );
+    }
+
+    #[test]
+    fn test_domain_event_is_discriminated_union() {
+        let model = model();
+        let events = TypeScriptGenerator::generate_events(&model);
+        assert!(events.contains(
+            "export type DomainEvent =\n  | ({ type: \"OrderCancelled\" } & OrderCancelled);"
+        ));
+        assert!(events.contains("  aggregate_id: string;\n  statuses: OrderStatus[];\n"));
+
+        let client = TypeScriptGenerator::generate_client(&model);
+        assert!(client.contains("return this.post<string>(\"/api/cancel-order\", command);"));
+    }
+
+    #[test]
+    fn test_query_result_is_the_service_response() {
+        let model = model();
+        let queries = TypeScriptGenerator::generate_queries(&model);
+        assert!(queries.starts_with("import type { OrderId } from \"./types\";"));
+        assert!(queries.contains("The IR declares `OrderStatus | null`"));
+        assert!(queries.contains("export type GetOrderResult = string;"));
+
+        let client = TypeScriptGenerator::generate_client(&model);
+        assert!(client.contains("return this.get<GetOrderResult>(\"/api/get-order\", params);"));
+    }
+}
@@ -1,4 +1,6 @@
-use heck::{ToKebabCase, ToPascalCase, ToSnakeCase};
+use crate::ir::IRModel;
+use heck::{ToKebabCase, ToLowerCamelCase, ToPascalCase, ToSnakeCase};
+use std::collections::BTreeSet;
 
 /// Convert a type name to Rust type
 pub fn to_rust_type(ir_type: &str) -> String {
@@ -48,6 +50,59 @@ pub fn to_rust_type(ir_type: &str) -> String {
     }
 }
 
+/// IR primitive type names
+pub const PRIMITIVE_TYPES: &[&str] = &[
+    "String", "Integer", "Decimal", "Boolean", "DateTime", "Date", "Time", "Uuid",
+];
+
+/// Name of the domain type referenced by an IR type, if any
+///
+/// Generic wrappers (`List<>`, `Option<>`, ...) are unwrapped and primitives
+/// yield `None`, so `List<CreateOrderItem>` references `OrderItem`.
+pub fn referenced_type(ir_type: &str) -> Option<String> {
+    let ir_type = ir_type.trim();
+    for wrapper in ["List<", "Vec<", "Option<", "PagedResult<"] {
+        if let Some(rest) = ir_type.strip_prefix(wrapper) {
+            return referenced_type(rest.strip_suffix('>').unwrap_or(rest));
+        }
+    }
+    if PRIMITIVE_TYPES.contains(&ir_type) {
+        None
+    } else {
+        Some(to_rust_type(ir_type))
+    }
+}
+
+/// Names of the value objects with each one after the value objects its
+/// properties use, for targets where a type must be declared before use
+pub fn value_object_order(model: &IRModel) -> Vec<&str> {
+    fn visit<'a>(
+        model: &'a IRModel,
+        name: &'a str,
+        visiting: &mut BTreeSet<&'a str>,
+        order: &mut Vec<&'a str>,
+    ) {
+        if order.contains(&name) || !visiting.insert(name) {
+            return;
+        }
+        for prop in &model.value_objects[name].properties {
+            if let Some((used, _)) = referenced_type(&prop.prop_type)
+                .and_then(|used| model.value_objects.get_key_value(&used))
+            {
+                visit(model, used, visiting, order);
+            }
+        }
+        order.push(name);
+    }
+
+    let mut order = Vec::new();
+    let mut visiting = BTreeSet::new();
+    for name in model.value_objects.keys() {
+        visit(model, name, &mut visiting, &mut order);
+    }
+    order
+}
+
 /// Convert to snake_case
 pub fn to_snake_case(s: &str) -> String {
     s.to_snake_case()
@@ -58,21 +113,16 @@ pub fn to_pascal_case(s: &str) -> String {
     s.to_pascal_case()
 }
 
+/// Convert to camelCase
+pub fn to_camel_case(s: &str) -> String {
+    s.to_lower_camel_case()
+}
+
 /// Convert to kebab-case
 pub fn to_kebab_case(s: &str) -> String {
     s.to_kebab_case()
 }
 
-/// Convert namespace to Rust module path
-pub fn namespace_to_module(namespace: &str) -> String {
-    namespace.replace('.', "::")
-}
-
-/// Generate file path from namespace and name
-pub fn generate_file_path(base: &str, name: &str) -> String {
-    format!("{}/{}.rs", base, to_snake_case(name))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,10 +135,26 @@ mod tests {
         assert_eq!(to_rust_type("DateTime"), "chrono::DateTime<chrono::Utc>");
     }
 
+    #[test]
+    fn test_referenced_type() {
+        assert_eq!(referenced_type("String"), None);
+        assert_eq!(referenced_type("List<DateTime>"), None);
+        assert_eq!(referenced_type("OrderId"), Some("OrderId".to_string()));
+        assert_eq!(
+            referenced_type("List<CreateOrderItem>"),
+            Some("OrderItem".to_string())
+        );
+        assert_eq!(
+            referenced_type("Option<List<Money>>"),
+            Some("Money".to_string())
+        );
+    }
+
     #[test]
     fn test_case_conversions() {
         assert_eq!(to_snake_case("OrderId"), "order_id");
         assert_eq!(to_pascal_case("order_id"), "OrderId");
+        assert_eq!(to_camel_case("GetOrder"), "getOrder");
         assert_eq!(to_kebab_case("OrderId"), "order-id");
     }
 }
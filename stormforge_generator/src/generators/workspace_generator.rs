@@ -0,0 +1,525 @@
+use crate::generators::{
+    entity_generator::*, integration_generator::*, rust_ast::*, rust_generator::*, utils::*,
+};
+use crate::ir::{Event, IRModel, IRProject, ValueObject};
+use anyhow::{Context, Result};
+use indexmap::{IndexMap, IndexSet};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::fs;
+
+/// Crate holding value objects shared by several bounded contexts
+pub const SHARED_KERNEL_CRATE: &str = "shared-kernel";
+
+/// Crate holding typed contracts for cross-context integration events
+pub const INTEGRATION_EVENTS_CRATE: &str = "integration-events";
+
+pub struct WorkspaceGenerator {
+    output_dir: String,
+}
+
+impl WorkspaceGenerator {
+    pub fn new(output_dir: String) -> Self {
+        Self { output_dir }
+    }
+
+    /// Generate a Cargo workspace with one crate per bounded context
+    pub fn generate(&self, project: &IRProject) -> Result<()> {
+        println!(
+            "🚀 Generating Cargo workspace for {} bounded contexts...",
+            project.contexts.len()
+        );
+
+        fs::create_dir_all(&self.output_dir)
+            .with_context(|| format!("Failed to create directory: {}", self.output_dir))?;
+
+        let shared = Self::shared_value_objects(project);
+        let mut members = Vec::new();
+
+        if !shared.is_empty() {
+            self.generate_shared_kernel(&shared)?;
+            members.push(SHARED_KERNEL_CRATE.to_string());
+        }
+
+        let has_subscriptions = project
+            .contexts
+            .iter()
+            .any(|model| !model.external_events.is_empty());
+        if has_subscriptions {
+            self.generate_integration_events(project, &shared)?;
+            members.push(INTEGRATION_EVENTS_CRATE.to_string());
+        }
+
+        for model in &project.contexts {
+            let crate_name = to_kebab_case(&model.bounded_context.name);
+            if members.contains(&crate_name) {
+                anyhow::bail!(
+                    "Bounded context '{}' clashes with generated crate '{}'",
+                    model.bounded_context.name,
+                    crate_name
+                );
+            }
+
+            let mut dependencies = Vec::new();
+            if !shared.is_empty() {
+                dependencies.push(WorkspaceCrate {
+                    name: SHARED_KERNEL_CRATE.to_string(),
+                    reexport: true,
+                });
+            }
+            if !model.external_events.is_empty() {
+                dependencies.push(WorkspaceCrate {
+                    name: INTEGRATION_EVENTS_CRATE.to_string(),
+                    reexport: false,
+                });
+            }
+
+            // Shared value objects are provided by the kernel crate
+            let mut local = model.clone();
+            local
+                .value_objects
+                .retain(|name, _| !shared.contains_key(name));
+
+            let kernel = model
+                .value_objects
+                .iter()
+                .filter(|(name, _)| shared.contains_key(*name))
+                .map(|(name, vo)| (name.clone(), vo.clone()))
+                .collect();
+
+            RustGenerator::new(format!("{}/{}", self.output_dir, crate_name))
+                .with_workspace_crates(dependencies)
+                .with_shared_value_objects(kernel)
+                .generate(&local)?;
+            members.push(crate_name);
+        }
+
+        self.generate_workspace_manifest(&members)?;
+        self.generate_readme(project, &shared, &members)?;
+
+        println!("✅ Workspace generated at: {}", self.output_dir);
+
+        Ok(())
+    }
+
+    /// Value objects that can be moved into the shared kernel
+    ///
+    /// A value object is shared when at least two contexts define it
+    /// identically and every domain type it references is shared as well.
+    pub fn shared_value_objects(project: &IRProject) -> IndexMap<String, ValueObject> {
+        let mut candidates: IndexMap<String, (Option<ValueObject>, usize)> = IndexMap::new();
+
+        for model in &project.contexts {
+            for (name, vo) in &model.value_objects {
+                match candidates.get_mut(name) {
+                    Some((existing, count)) => {
+                        let identical = existing.as_ref().is_some_and(|existing| {
+                            serde_json::to_value(existing).ok() == serde_json::to_value(vo).ok()
+                        });
+                        if identical {
+                            *count += 1;
+                        } else {
+                            // Conflicting definitions stay local to each context
+                            *existing = None;
+                        }
+                    }
+                    None => {
+                        candidates.insert(name.clone(), (Some(vo.clone()), 1));
+                    }
+                }
+            }
+        }
+
+        let mut shared: IndexMap<String, ValueObject> = candidates
+            .into_iter()
+            .filter_map(|(name, (vo, count))| vo.filter(|_| count > 1).map(|vo| (name, vo)))
+            .collect();
+
+        // Drop value objects that reference types outside the kernel
+        loop {
+            let unresolved: Vec<String> = shared
+                .iter()
+                .filter(|(_, vo)| {
+                    Self::value_object_references(vo)
+                        .iter()
+                        .any(|reference| !shared.contains_key(reference))
+                })
+                .map(|(name, _)| name.clone())
+                .collect();
+
+            if unresolved.is_empty() {
+                break;
+            }
+            shared.retain(|name, _| !unresolved.contains(name));
+        }
+
+        shared
+    }
+
+    /// Domain types referenced by a value object's properties
+    fn value_object_references(vo: &ValueObject) -> Vec<String> {
+        vo.properties
+            .iter()
+            .filter_map(|prop| referenced_type(&prop.prop_type))
+            .chain(vo.underlying_type.as_deref().and_then(referenced_type))
+            .collect()
+    }
+
+    fn generate_shared_kernel(&self, shared: &IndexMap<String, ValueObject>) -> Result<()> {
+        let crate_dir = format!("{}/{}", self.output_dir, SHARED_KERNEL_CRATE);
+        fs::create_dir_all(format!("{}/src", crate_dir))
+            .with_context(|| format!("Failed to create directory: {}", crate_dir))?;
+
+        let manifest = Self::library_manifest(SHARED_KERNEL_CRATE, false);
+        fs::write(format!("{}/Cargo.toml", crate_dir), manifest)?;
+
+        let value_objects = shared
+            .iter()
+            .map(|(name, vo)| EntityGenerator::generate_value_object(name, vo))
+            .collect::<Result<Vec<_>>>()?;
+
+        let code = render(quote! {
+            //! Shared kernel
+            //!
+            //! Value objects defined identically by several bounded contexts.
+
+            use serde::{Deserialize, Serialize};
+
+            #(#value_objects)*
+        })?;
+        fs::write(format!("{}/src/lib.rs", crate_dir), code)?;
+
+        Ok(())
+    }
+
+    fn generate_integration_events(
+        &self,
+        project: &IRProject,
+        shared: &IndexMap<String, ValueObject>,
+    ) -> Result<()> {
+        let crate_dir = format!("{}/{}", self.output_dir, INTEGRATION_EVENTS_CRATE);
+        fs::create_dir_all(format!("{}/src", crate_dir))
+            .with_context(|| format!("Failed to create directory: {}", crate_dir))?;
+
+        let manifest = Self::library_manifest(INTEGRATION_EVENTS_CRATE, !shared.is_empty());
+        fs::write(format!("{}/Cargo.toml", crate_dir), manifest)?;
+
+        // Group subscribed events by the publishing context
+        let mut subscriptions: IndexMap<&str, IndexSet<&str>> = IndexMap::new();
+        for model in &project.contexts {
+            for subscription in &model.external_events {
+                subscriptions
+                    .entry(subscription.context.as_str())
+                    .or_default()
+                    .insert(subscription.event.as_str());
+            }
+        }
+
+        let modules = subscriptions
+            .iter()
+            .map(|(context, events)| {
+                Self::generate_contract_module(project, shared, context, events)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let code = render(quote! {
+            //! Integration event contracts
+            //!
+            //! Typed payloads of the events bounded contexts subscribe to from
+            //! each other, derived from their `external_events` subscriptions.
+
+            #(#modules)*
+        })?;
+        fs::write(format!("{}/src/lib.rs", crate_dir), code)?;
+
+        Ok(())
+    }
+
+    fn generate_contract_module(
+        project: &IRProject,
+        shared: &IndexMap<String, ValueObject>,
+        context: &str,
+        events: &IndexSet<&str>,
+    ) -> Result<TokenStream> {
+        let module_doc = format!(" Events published by the {} context", context);
+        let module = snake_ident(context)?;
+        let producer = project.context(context);
+
+        let kernel_import = if shared.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! { pub use shared_kernel::*; }
+        };
+
+        let mut contracts = Vec::new();
+        let mut value_objects: IndexSet<String> = IndexSet::new();
+        for event_name in events {
+            match (producer, project.find_event(context, event_name)) {
+                (Some(model), Some(event)) => {
+                    contracts.push(Self::generate_contract(event_name, event)?);
+                    for prop in &event.payload {
+                        Self::collect_local_types(
+                            model,
+                            shared,
+                            &prop.prop_type,
+                            &mut value_objects,
+                        );
+                    }
+                }
+                _ => contracts.push(IntegrationGenerator::generate_opaque_contract(
+                    context, event_name,
+                )?),
+            }
+        }
+
+        // Copies of the producer's own value objects used by the contracts
+        let local_types = match producer {
+            Some(model) => value_objects
+                .iter()
+                .map(|name| {
+                    EntityGenerator::generate_value_object(name, &model.value_objects[name])
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+
+        Ok(quote! {
+            #[doc = #module_doc]
+            pub mod #module {
+                use serde::{Deserialize, Serialize};
+                use chrono::{DateTime, Utc};
+                use uuid::Uuid;
+                #kernel_import
+
+                #(#contracts)*
+                #(#local_types)*
+            }
+        })
+    }
+
+    /// Contract mirroring the serialized domain event of the producer
+    fn generate_contract(name: &str, event: &Event) -> Result<TokenStream> {
+        let doc = doc(event.description.as_deref());
+        let name = ident(name)?;
+        let fields = struct_fields(&event.payload)?;
+
+        let aggregate_field = if event.aggregate.is_some() {
+            quote! {
+                /// ID of the aggregate that produced this event
+                pub aggregate_id: String,
+            }
+        } else {
+            TokenStream::new()
+        };
+
+        Ok(quote! {
+            #doc
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            pub struct #name {
+                /// Unique event identifier
+                pub event_id: Uuid,
+                /// Timestamp when the event occurred
+                pub occurred_at: DateTime<Utc>,
+                #aggregate_field
+                #(#fields,)*
+            }
+        })
+    }
+
+    /// Collect the non-shared value objects of `model` reachable from a type
+    fn collect_local_types(
+        model: &IRModel,
+        shared: &IndexMap<String, ValueObject>,
+        ir_type: &str,
+        found: &mut IndexSet<String>,
+    ) {
+        let Some(name) = referenced_type(ir_type) else {
+            return;
+        };
+        if shared.contains_key(&name) || found.contains(&name) {
+            return;
+        }
+        if let Some(vo) = model.value_objects.get(&name) {
+            found.insert(name);
+            for reference in Self::value_object_references(vo) {
+                Self::collect_local_types(model, shared, &reference, found);
+            }
+        }
+    }
+
+    fn library_manifest(name: &str, depends_on_kernel: bool) -> String {
+        let mut content = format!(
+            r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+authors = ["StormForge Generator"]
+
+[dependencies]
+serde = {{ version = "1.0", features = ["derive"] }}
+serde_json = "1.0"
+chrono = {{ version = "0.4", features = ["serde"] }}
+uuid = {{ version = "1.10", features = ["v4", "serde"] }}
+rust_decimal = {{ version = "1.35", features = ["serde"] }}
+async-graphql = {{ version = "7", features = ["chrono", "decimal", "uuid"], optional = true }}
+"#,
+            name
+        );
+
+        let mut graphql_features = vec!["\"dep:async-graphql\"".to_string()];
+        if depends_on_kernel {
+            content.push_str(&format!(
+                "{} = {{ path = \"../{}\" }}\n",
+                SHARED_KERNEL_CRATE, SHARED_KERNEL_CRATE
+            ));
+            graphql_features.push(format!("\"{}/graphql\"", SHARED_KERNEL_CRATE));
+        }
+
+        // Services enable the GraphQL derives of the value objects
+        content.push_str(&format!(
+            "\n[features]\ngraphql = [{}]\n",
+            graphql_features.join(", ")
+        ));
+
+        content
+    }
+
+    fn generate_workspace_manifest(&self, members: &[String]) -> Result<()> {
+        let members = members
+            .iter()
+            .map(|member| format!("    \"{}\",\n", member))
+            .collect::<String>();
+
+        let content = format!(
+            r#"[workspace]
+resolver = "2"
+members = [
+{}]
+
+[profile.release]
+opt-level = 3
+lto = true
+codegen-units = 1
+"#,
+            members
+        );
+
+        let path = format!("{}/Cargo.toml", self.output_dir);
+        fs::write(&path, content)
+            .with_context(|| format!("Failed to write Cargo.toml to {}", path))?;
+
+        Ok(())
+    }
+
+    fn generate_readme(
+        &self,
+        project: &IRProject,
+        shared: &IndexMap<String, ValueObject>,
+        members: &[String],
+    ) -> Result<()> {
+        let mut content =
+            String::from("# Workspace\n\nGenerated by StormForge Generator\n\n## Crates\n\n");
+
+        for member in members {
+            let description = match member.as_str() {
+                SHARED_KERNEL_CRATE => {
+                    "Value objects shared by several bounded contexts".to_string()
+                }
+                INTEGRATION_EVENTS_CRATE => {
+                    "Typed contracts for cross-context integration events".to_string()
+                }
+                _ => project
+                    .contexts
+                    .iter()
+                    .find(|model| to_kebab_case(&model.bounded_context.name) == *member)
+                    .and_then(|model| model.bounded_context.description.clone())
+                    .unwrap_or_else(|| "Bounded context service".to_string()),
+            };
+            content.push_str(&format!("- `{}`: {}\n", member, description));
+        }
+
+        if !shared.is_empty() {
+            content.push_str("\n## Shared Kernel\n\n");
+            for name in shared.keys() {
+                content.push_str(&format!("- `{}`\n", name));
+            }
+        }
+
+        content.push_str("\n## Building\n\n```bash\ncargo build --workspace\n```\n");
+
+        let path = format!("{}/README.md", self.output_dir);
+        fs::write(&path, content)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn context(name: &str, value_objects: &str) -> IRModel {
+        IRParser::parse_yaml(&format!(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "{}"
+  namespace: "test.{}"
+value_objects:
+{}
+"#,
+            name,
+            name.to_lowercase(),
+            value_objects
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_shared_value_objects_require_identical_definitions() {
+        let order = context(
+            "Order",
+            r#"
+  OrderId:
+    name: "OrderId"
+    type: "identifier"
+  Money:
+    name: "Money"
+    properties:
+      - name: "amount"
+        type: "Decimal"
+  Line:
+    name: "Line"
+    properties:
+      - name: "price"
+        type: "Money"
+"#,
+        );
+        let payment = context(
+            "Payment",
+            r#"
+  OrderId:
+    name: "OrderId"
+    type: "identifier"
+  Money:
+    name: "Money"
+    properties:
+      - name: "amount"
+        type: "Integer"
+  Line:
+    name: "Line"
+    properties:
+      - name: "price"
+        type: "Money"
+"#,
+        );
+        let project = IRProject {
+            contexts: vec![order, payment],
+        };
+
+        let shared = WorkspaceGenerator::shared_value_objects(&project);
+        // Money differs between contexts, so Line cannot be shared either
+        assert_eq!(shared.keys().collect::<Vec<_>>(), vec!["OrderId"]);
+    }
+}
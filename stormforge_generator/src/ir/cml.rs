@@ -0,0 +1,2529 @@
+use super::format::IRFormatter;
+use super::types::{
+    Aggregate, BoundedContext, Command, Entity, EnumValue, Event, ExternalEventSubscription,
+    IRModel, Persistence, Property, Validation, ValueObject,
+};
+use crate::generators::utils::{referenced_type, to_snake_case};
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Constructs that start a top-level CML declaration
+const TOP_LEVEL: &[&str] = &[
+    "ContextMap",
+    "BoundedContext",
+    "Domain",
+    "UseCase",
+    "UserStory",
+    "Stakeholders",
+    "ValueRegister",
+    "import",
+];
+
+/// Flags that may follow the name of an attribute
+const ATTRIBUTE_FLAGS: &[&str] = &[
+    "key",
+    "nullable",
+    "required",
+    "notEmpty",
+    "notBlank",
+    "email",
+    "past",
+    "future",
+    "transient",
+    "cache",
+    "inverse",
+    "index",
+    "assertTrue",
+    "assertFalse",
+    "creditCardNumber",
+    "changeable",
+];
+
+/// Flags of a domain object
+const OBJECT_FLAGS: &[&str] = &[
+    "aggregateLifecycle",
+    "cache",
+    "gap",
+    "nogap",
+    "scaffold",
+    "persistent",
+    "optimisticLocking",
+    "auditable",
+    "immutable",
+];
+
+/// Words of the CML grammar that are escaped with `^` when used as names
+const KEYWORDS: &str = "abstract aggregateRoot Aggregate BoundedContext cache changeable \
+    CommandEvent contains ContextMap def DomainEvent domainVisionStatement email Entity enum \
+    extends gap hint implements index inverse key length Module nullable owner past pattern \
+    required Repository Service size state transient type ValueObject with";
+
+/// Name of the module that holds elements belonging to no aggregate
+const SHARED_MODULE: &str = "shared";
+
+/// A construct that has no counterpart on the other side of a translation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Untranslated {
+    /// Bounded context, or `ContextMap`
+    pub context: String,
+    /// Dotted path of the construct, e.g. `Customers.AddressService`
+    pub path: String,
+    pub reason: String,
+}
+
+impl fmt::Display for Untranslated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.context.is_empty(), self.path.is_empty()) {
+            (true, _) => write!(f, "{}: {}", self.path, self.reason),
+            (false, true) => write!(f, "{}: {}", self.context, self.reason),
+            (false, false) => write!(f, "{}: {}: {}", self.context, self.path, self.reason),
+        }
+    }
+}
+
+/// Bounded contexts read from a CML model
+#[derive(Debug, Clone)]
+pub struct CmlImport {
+    pub contexts: Vec<IRModel>,
+    pub untranslated: Vec<Untranslated>,
+}
+
+impl CmlImport {
+    /// File name and canonically formatted IR of each bounded context
+    pub fn files(&self) -> Result<Vec<(String, String)>> {
+        self.contexts
+            .iter()
+            .map(|model| {
+                let name = &model.bounded_context.name;
+                let mut value = serde_yaml::to_value(model)?;
+                prune(&mut value);
+                let yaml = format!(
+                    "# {} Context\n# Imported from Context Mapper (CML)\n\n{}",
+                    name,
+                    serde_yaml::to_string(&value)?
+                );
+                let mut file = to_snake_case(name);
+                if !file.ends_with("_context") {
+                    file.push_str("_context");
+                }
+                Ok((format!("{}.yaml", file), IRFormatter::format(&yaml)?))
+            })
+            .collect()
+    }
+}
+
+/// CML model written from bounded contexts
+#[derive(Debug, Clone)]
+pub struct CmlExport {
+    pub cml: String,
+    pub untranslated: Vec<Untranslated>,
+}
+
+/// Translator between the IR and Context Mapper DSL (CML)
+///
+/// Bounded contexts, aggregates, entities, value objects, domain events and
+/// command events translate both ways. Non-root entities become value
+/// objects with `key` properties, and a value object with a single `value`
+/// attribute is an identifier. Upstream-downstream and partnership
+/// relationships of the context map become subscriptions to every event of
+/// the upstream context, or of its `exposedAggregates`, and subscriptions
+/// are exported the same way. Everything else is listed as untranslated.
+pub struct Cml;
+
+impl Cml {
+    pub fn import(text: &str) -> Result<CmlImport> {
+        let mut parser = Parser::new(text)?;
+        let model = parser.model()?;
+        let mut import = Importer {
+            untranslated: parser.untranslated,
+            roots: HashMap::new(),
+        };
+        let contexts = import.translate(model);
+        Ok(CmlImport {
+            contexts,
+            untranslated: import.untranslated,
+        })
+    }
+
+    pub fn export(contexts: &[IRModel]) -> CmlExport {
+        let mut export = Exporter {
+            contexts,
+            out: String::from("// Generated by StormForge Generator\n"),
+            untranslated: Vec::new(),
+        };
+        export.context_map();
+        for model in contexts {
+            export.bounded_context(model);
+        }
+        CmlExport {
+            cml: export.out,
+            untranslated: export.untranslated,
+        }
+    }
+}
+
+/// Namespace given to imported bounded contexts
+fn default_namespace(context: &str) -> String {
+    to_snake_case(context)
+}
+
+/// Handler name given to imported subscriptions
+fn default_handler(event: &str) -> String {
+    format!("handle{}", event)
+}
+
+fn join(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+/// Drop the values the IR defaults to, so imported files read like written ones
+fn prune(value: &mut serde_yaml::Value) {
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            for (_, child) in map.iter_mut() {
+                prune(child);
+            }
+            // Entities must list their properties, even when there are none
+            let keep_properties = !map.contains_key("values");
+            map.retain(|key, child| {
+                let key = key.as_str().unwrap_or_default();
+                match child {
+                    serde_yaml::Value::Null => false,
+                    serde_yaml::Value::Sequence(items) => {
+                        !items.is_empty() || (key == "properties" && keep_properties)
+                    }
+                    serde_yaml::Value::Mapping(entries) => !entries.is_empty(),
+                    serde_yaml::Value::Bool(flag) => match key {
+                        "identifier" | "nullable" => *flag,
+                        "required" => !*flag,
+                        _ => true,
+                    },
+                    serde_yaml::Value::String(text) => {
+                        key != "persistence" || text != Persistence::EventSourced.as_str()
+                    }
+                    _ => true,
+                }
+            });
+        }
+        serde_yaml::Value::Sequence(items) => items.iter_mut().for_each(prune),
+        _ => {}
+    }
+}
+
+/// IR type of a CML basic type
+fn ir_basic_type(cml_type: &str) -> Option<&'static str> {
+    Some(match cml_type {
+        "String" | "char" | "Character" => "String",
+        "int" | "Integer" | "long" | "Long" | "short" | "Short" | "byte" | "Byte"
+        | "BigInteger" => "Integer",
+        "double" | "Double" | "float" | "Float" | "BigDecimal" => "Decimal",
+        "boolean" | "Boolean" => "Boolean",
+        "Date" | "LocalDate" => "Date",
+        "DateTime" | "Timestamp" | "LocalDateTime" | "ZonedDateTime" | "Instant" => "DateTime",
+        "Time" | "LocalTime" => "Time",
+        "UUID" => "Uuid",
+        _ => return None,
+    })
+}
+
+/// CML type of an IR primitive
+fn cml_basic_type(ir_type: &str) -> Option<&'static str> {
+    Some(match ir_type {
+        "String" => "String",
+        "Integer" => "long",
+        "Decimal" => "BigDecimal",
+        "Boolean" => "boolean",
+        "DateTime" => "DateTime",
+        "Date" => "Date",
+        "Time" => "Time",
+        "Uuid" => "UUID",
+        _ => return None,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Name or keyword, with the `^` of escaped names
+    Ident(String),
+    Str(String),
+    Number(String),
+    Symbol(&'static str),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Ident(text) | Token::Number(text) => write!(f, "`{}`", text),
+            Token::Symbol(text) => write!(f, "`{}`", text),
+            Token::Str(text) => write!(f, "\"{}\"", text),
+        }
+    }
+}
+
+/// Longest symbols first, so that arrows are not split
+const SYMBOLS: &[&str] = &[
+    "<->", "<-", "->", "{", "}", "[", "]", "<", ">", "(", ")", ",", "=", ":", ";", "-", "@", ".",
+    "!", "*", "+", "/", "|", "&", "?", "#",
+];
+
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let (mut i, mut line) = (0, 1);
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if c == '\n' {
+            line += 1;
+            i += 1;
+        } else if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            let start = line;
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                if chars[i] == '\n' {
+                    line += 1;
+                }
+                i += 1;
+            }
+            if i >= chars.len() {
+                bail!("Unterminated comment at line {}", start);
+            }
+            i += 2;
+        } else if c == '"' || c == '\'' {
+            let start = line;
+            let mut value = String::new();
+            i += 1;
+            loop {
+                let Some(&ch) = chars.get(i) else {
+                    bail!("Unterminated string at line {}", start);
+                };
+                i += 1;
+                match ch {
+                    '\\' => {
+                        let escaped = chars.get(i).copied().unwrap_or('\\');
+                        i += 1;
+                        value.push(match escaped {
+                            'n' => '\n',
+                            't' => '\t',
+                            other => other,
+                        });
+                    }
+                    quote if quote == c => break,
+                    other => {
+                        if other == '\n' {
+                            line += 1;
+                        }
+                        value.push(other);
+                    }
+                }
+            }
+            tokens.push((Token::Str(value), start));
+        } else if c.is_ascii_alphabetic() || c == '_' || c == '^' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            if c == '^' && i == start + 1 {
+                bail!("Expected a name after `^` at line {}", line);
+            }
+            tokens.push((Token::Ident(chars[start..i].iter().collect()), line));
+        } else if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            tokens.push((Token::Number(chars[start..i].iter().collect()), line));
+        } else if let Some(symbol) = SYMBOLS.iter().find(|symbol| {
+            symbol
+                .chars()
+                .enumerate()
+                .all(|(offset, ch)| chars.get(i + offset) == Some(&ch))
+        }) {
+            tokens.push((Token::Symbol(symbol), line));
+            i += symbol.len();
+        } else {
+            bail!("Unexpected character '{}' at line {}", c, line);
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Default)]
+struct CmlModel {
+    contains: Vec<String>,
+    relationships: Vec<RelationshipDef>,
+    contexts: Vec<ContextDef>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Relation {
+    UpstreamDownstream,
+    Partnership,
+    SharedKernel,
+}
+
+struct RelationshipDef {
+    /// Left-hand context of symmetric relationships
+    upstream: String,
+    downstream: String,
+    relation: Relation,
+    exposed: Vec<String>,
+}
+
+impl RelationshipDef {
+    fn label(&self) -> String {
+        let arrow = match self.relation {
+            Relation::UpstreamDownstream => "->",
+            _ => "<->",
+        };
+        format!("{} {} {}", self.upstream, arrow, self.downstream)
+    }
+}
+
+/// Members of a bounded context, module or aggregate
+#[derive(Default)]
+struct Members {
+    description: Option<String>,
+    aggregates: Vec<AggregateDef>,
+    objects: Vec<ObjectDef>,
+}
+
+struct ContextDef {
+    name: String,
+    members: Members,
+}
+
+struct AggregateDef {
+    name: String,
+    doc: Option<String>,
+    objects: Vec<ObjectDef>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ObjectKind {
+    Entity,
+    ValueObject,
+    DomainEvent,
+    CommandEvent,
+    Enum,
+}
+
+struct ObjectDef {
+    kind: ObjectKind,
+    name: String,
+    doc: Option<String>,
+    root: bool,
+    attributes: Vec<AttributeDef>,
+    values: Vec<String>,
+}
+
+struct AttributeDef {
+    name: String,
+    doc: Option<String>,
+    collection: Option<String>,
+    type_name: String,
+    /// Flags and `option = value` pairs as written
+    options: Vec<(String, Option<String>)>,
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    untranslated: Vec<Untranslated>,
+}
+
+impl Parser {
+    fn new(text: &str) -> Result<Self> {
+        Ok(Self {
+            tokens: tokenize(text)?,
+            pos: 0,
+            untranslated: Vec::new(),
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset).map(|(token, _)| token)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map_or(1, |(_, line)| *line)
+    }
+
+    fn found(&self) -> String {
+        match self.peek() {
+            Some(token) => format!("{} at line {}", token, self.line()),
+            None => "end of file".to_string(),
+        }
+    }
+
+    fn at_ident(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(text)) if text == keyword)
+    }
+
+    fn at_symbol(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(Token::Symbol(text)) if *text == symbol)
+    }
+
+    fn eat_ident(&mut self, keyword: &str) -> bool {
+        let found = self.at_ident(keyword);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn eat_symbol(&mut self, symbol: &str) -> bool {
+        let found = self.at_symbol(symbol);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, symbol: &str) -> Result<()> {
+        if !self.eat_symbol(symbol) {
+            bail!("Expected `{}`, found {}", symbol, self.found());
+        }
+        Ok(())
+    }
+
+    /// A name, without the `^` that escapes keywords
+    fn ident(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(Token::Ident(text)) => {
+                let name = text.trim_start_matches('^').to_string();
+                self.pos += 1;
+                Ok(name)
+            }
+            _ => bail!("Expected a name, found {}", self.found()),
+        }
+    }
+
+    /// Doc strings in front of an element, of which the last one counts
+    fn docs(&mut self) -> Option<String> {
+        let mut doc = None;
+        while let Some(Token::Str(text)) = self.peek() {
+            doc = Some(text.clone());
+            self.pos += 1;
+        }
+        doc
+    }
+
+    /// Comma-separated values of an `option = value` pair
+    fn values(&mut self) -> Result<Vec<String>> {
+        let mut values = Vec::new();
+        loop {
+            let sign = if self.eat_symbol("-") { "-" } else { "" };
+            match self.peek() {
+                Some(Token::Ident(text) | Token::Str(text) | Token::Number(text)) => {
+                    values.push(format!("{}{}", sign, text.trim_start_matches('^')));
+                    self.pos += 1;
+                }
+                _ => bail!("Expected a value, found {}", self.found()),
+            }
+            if !self.eat_symbol(",") {
+                return Ok(values);
+            }
+        }
+    }
+
+    /// Skip a bracketed group, including nested ones
+    fn skip_group(&mut self, open: &str, close: &str) -> Result<()> {
+        self.expect(open)?;
+        let mut depth = 1;
+        while depth > 0 {
+            match self.peek() {
+                None => bail!("Missing `{}` at end of file", close),
+                Some(Token::Symbol(symbol)) if *symbol == open => depth += 1,
+                Some(Token::Symbol(symbol)) if *symbol == close => depth -= 1,
+                _ => {}
+            }
+            self.pos += 1;
+        }
+        Ok(())
+    }
+
+    /// Skip a construct up to the end of its block, returning its name
+    fn skip_construct(&mut self) -> Result<String> {
+        self.pos += 1;
+        let name = match self.peek() {
+            Some(Token::Ident(text)) => text.trim_start_matches('^').to_string(),
+            _ => String::new(),
+        };
+        while let Some(token) = self.peek() {
+            match token {
+                Token::Symbol("{") => {
+                    self.skip_group("{", "}")?;
+                    break;
+                }
+                Token::Ident(text) if TOP_LEVEL.contains(&text.as_str()) => break,
+                _ => self.pos += 1,
+            }
+        }
+        Ok(name)
+    }
+
+    fn report(&mut self, context: &str, path: &str, reason: impl Into<String>) {
+        self.untranslated.push(Untranslated {
+            context: context.to_string(),
+            path: path.to_string(),
+            reason: reason.into(),
+        });
+    }
+
+    fn model(&mut self) -> Result<CmlModel> {
+        let mut model = CmlModel::default();
+        while let Some(token) = self.peek().cloned() {
+            match token {
+                Token::Ident(keyword) if keyword == "ContextMap" => self.context_map(&mut model)?,
+                Token::Ident(keyword) if keyword == "BoundedContext" => {
+                    let context = self.bounded_context()?;
+                    model.contexts.push(context);
+                }
+                Token::Ident(keyword) if keyword == "import" => {
+                    self.pos += 1;
+                    let file = self.docs().unwrap_or_default();
+                    self.report(
+                        "",
+                        &format!("import \"{}\"", file),
+                        "imported files are not read",
+                    );
+                }
+                Token::Ident(keyword) if TOP_LEVEL.contains(&keyword.as_str()) => {
+                    let name = self.skip_construct()?;
+                    self.report(&name, "", format!("{} has no IR counterpart", keyword));
+                }
+                // Doc strings of top-level constructs
+                Token::Str(_) => self.pos += 1,
+                _ => bail!("Unexpected {}", self.found()),
+            }
+        }
+        Ok(model)
+    }
+
+    fn context_map(&mut self, model: &mut CmlModel) -> Result<()> {
+        self.pos += 1;
+        if matches!(self.peek(), Some(Token::Ident(_))) {
+            self.pos += 1;
+        }
+        self.expect("{")?;
+
+        while !self.eat_symbol("}") {
+            if self.peek().is_none() {
+                bail!("Missing `}}` of ContextMap at end of file");
+            }
+            if self.eat_ident("contains") {
+                loop {
+                    model.contains.push(self.ident()?);
+                    if !self.eat_symbol(",") {
+                        break;
+                    }
+                }
+            } else if self.peek_at(1) == Some(&Token::Symbol("=")) {
+                let key = self.ident()?;
+                self.pos += 1;
+                self.values()?;
+                self.report("ContextMap", &key, "attribute has no IR counterpart");
+            } else {
+                let relationship = self.relationship()?;
+                model.relationships.push(relationship);
+            }
+        }
+        Ok(())
+    }
+
+    /// Roles written in brackets next to a context, e.g. `[U,OHS,PL]`
+    fn roles(&mut self) -> Result<Vec<String>> {
+        let mut roles = Vec::new();
+        if self.eat_symbol("[") {
+            while !self.eat_symbol("]") {
+                if !self.eat_symbol(",") {
+                    roles.push(self.ident()?);
+                }
+            }
+        }
+        Ok(roles)
+    }
+
+    fn relationship(&mut self) -> Result<RelationshipDef> {
+        let mut roles = self.roles()?;
+        let left = self.ident()?;
+        roles.extend(self.roles()?);
+
+        // Which side is upstream, for asymmetric relationships
+        let (relation, left_upstream) = if self.eat_symbol("->") {
+            (Relation::UpstreamDownstream, true)
+        } else if self.eat_symbol("<-") {
+            (Relation::UpstreamDownstream, false)
+        } else if self.eat_symbol("<->") {
+            (Relation::Partnership, true)
+        } else {
+            let line = self.line();
+            let mut keyword = self.ident()?;
+            while self.eat_symbol("-") {
+                keyword = format!("{}-{}", keyword, self.ident()?);
+            }
+            match keyword.as_str() {
+                "Partnership" => (Relation::Partnership, true),
+                "Shared-Kernel" => (Relation::SharedKernel, true),
+                "Upstream-Downstream" | "Supplier-Customer" => (Relation::UpstreamDownstream, true),
+                "Downstream-Upstream" | "Customer-Supplier" => {
+                    (Relation::UpstreamDownstream, false)
+                }
+                _ => bail!("Unknown relationship `{}` at line {}", keyword, line),
+            }
+        };
+        roles.extend(self.roles()?);
+        let right = self.ident()?;
+
+        let relation = if roles.iter().any(|role| role == "SK") {
+            Relation::SharedKernel
+        } else {
+            relation
+        };
+        let (upstream, downstream) = if left_upstream {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        let mut relationship = RelationshipDef {
+            upstream,
+            downstream,
+            relation,
+            exposed: Vec::new(),
+        };
+        let label = relationship.label();
+
+        let known: &[&str] = match relation {
+            Relation::UpstreamDownstream => &["U", "D", "C", "S"],
+            _ => &["P", "SK"],
+        };
+        let extra: Vec<String> = roles
+            .into_iter()
+            .filter(|role| !known.contains(&role.as_str()))
+            .collect();
+        if !extra.is_empty() {
+            self.report(
+                "ContextMap",
+                &label,
+                format!("roles {} have no IR counterpart", extra.join(", ")),
+            );
+        }
+
+        // Optional name and attributes
+        if self.eat_symbol(":") {
+            self.ident()?;
+        }
+        if self.eat_symbol("{") {
+            while !self.eat_symbol("}") {
+                let key = self.ident()?;
+                self.expect("=")?;
+                let values = self.values()?;
+                if key == "exposedAggregates" {
+                    relationship.exposed = values;
+                } else {
+                    self.report(
+                        "ContextMap",
+                        &join(&label, &key),
+                        "attribute has no IR counterpart",
+                    );
+                }
+            }
+        }
+        Ok(relationship)
+    }
+
+    fn bounded_context(&mut self) -> Result<ContextDef> {
+        self.pos += 1;
+        let name = self.ident()?;
+        for keyword in ["implements", "realizes", "refines"] {
+            if self.eat_ident(keyword) {
+                let mut targets = vec![self.ident()?];
+                while self.eat_symbol(",") {
+                    targets.push(self.ident()?);
+                }
+                self.report(
+                    &name,
+                    "",
+                    format!("`{} {}` has no IR counterpart", keyword, targets.join(", ")),
+                );
+            }
+        }
+
+        let mut members = Members::default();
+        if self.eat_symbol("{") {
+            self.members(&name, "", &mut members)?;
+        }
+        Ok(ContextDef { name, members })
+    }
+
+    /// Members of a bounded context, module or aggregate up to the closing brace
+    fn members(&mut self, context: &str, path: &str, members: &mut Members) -> Result<()> {
+        loop {
+            let doc = self.docs();
+            let Some(token) = self.peek().cloned() else {
+                bail!("Missing `}}` of `{}` at end of file", join(context, path));
+            };
+            match token {
+                Token::Symbol("}") => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                Token::Ident(key) if self.peek_at(1) == Some(&Token::Symbol("=")) => {
+                    self.pos += 2;
+                    let values = self.values()?;
+                    if key == "domainVisionStatement" && path.is_empty() {
+                        members.description = values.into_iter().next();
+                    } else {
+                        self.report(
+                            context,
+                            &join(path, &key),
+                            "attribute has no IR counterpart",
+                        );
+                    }
+                }
+                Token::Ident(keyword) if keyword == "Aggregate" => {
+                    self.pos += 1;
+                    let name = self.ident()?;
+                    self.expect("{")?;
+                    let mut inner = Members::default();
+                    self.members(context, &join(path, &name), &mut inner)?;
+                    members.aggregates.push(AggregateDef {
+                        name,
+                        doc,
+                        objects: inner.objects,
+                    });
+                    members.aggregates.extend(inner.aggregates);
+                }
+                Token::Ident(keyword) if keyword == "Module" => {
+                    self.pos += 1;
+                    let name = self.ident()?;
+                    let path = join(path, &name);
+                    self.report(
+                        context,
+                        &path,
+                        "Module is flattened into its bounded context",
+                    );
+                    self.expect("{")?;
+                    self.members(context, &path, members)?;
+                }
+                Token::Ident(keyword)
+                    if ["Service", "Repository", "Application"].contains(&keyword.as_str()) =>
+                {
+                    let name = self.skip_construct()?;
+                    self.report(
+                        context,
+                        &join(path, &name),
+                        format!("{} has no IR counterpart", keyword),
+                    );
+                }
+                Token::Ident(keyword) if keyword == "external" => {
+                    self.pos += 1;
+                    self.report(context, path, "`external` has no IR counterpart");
+                }
+                Token::Ident(_) => {
+                    if let Some(object) = self.domain_object(context, path, doc)? {
+                        members.objects.push(object);
+                    }
+                }
+                _ => bail!("Unexpected {}", self.found()),
+            }
+        }
+    }
+
+    fn domain_object(
+        &mut self,
+        context: &str,
+        path: &str,
+        doc: Option<String>,
+    ) -> Result<Option<ObjectDef>> {
+        let is_abstract = self.eat_ident("abstract");
+        let line = self.line();
+        let keyword = self.ident()?;
+        let kind = match keyword.as_str() {
+            "Entity" => Some(ObjectKind::Entity),
+            "ValueObject" => Some(ObjectKind::ValueObject),
+            "DomainEvent" => Some(ObjectKind::DomainEvent),
+            "CommandEvent" => Some(ObjectKind::CommandEvent),
+            "enum" => Some(ObjectKind::Enum),
+            "DataTransferObject" | "BasicType" | "Trait" => None,
+            _ => bail!("Unexpected `{}` at line {}", keyword, line),
+        };
+        let name = self.ident()?;
+        let path = join(path, &name);
+
+        if is_abstract {
+            self.report(context, &path, "`abstract` has no IR counterpart");
+        }
+        loop {
+            if self.eat_ident("extends") {
+                self.eat_symbol("@");
+                let base = self.ident()?;
+                self.report(
+                    context,
+                    &path,
+                    format!("`extends {}` has no IR counterpart", base),
+                );
+            } else if self.eat_ident("with") {
+                self.eat_symbol("@");
+                let base = self.ident()?;
+                self.report(
+                    context,
+                    &path,
+                    format!("`with {}` has no IR counterpart", base),
+                );
+            } else {
+                break;
+            }
+        }
+
+        let Some(kind) = kind else {
+            if self.at_symbol("{") {
+                self.skip_group("{", "}")?;
+            }
+            self.report(context, &path, format!("{} has no IR counterpart", keyword));
+            return Ok(None);
+        };
+        let mut object = ObjectDef {
+            kind,
+            name,
+            doc,
+            root: false,
+            attributes: Vec::new(),
+            values: Vec::new(),
+        };
+        if !self.eat_symbol("{") {
+            return Ok(Some(object));
+        }
+
+        loop {
+            let doc = self.docs();
+            let Some(token) = self.peek().cloned() else {
+                bail!("Missing `}}` of `{}` at end of file", path);
+            };
+            match token {
+                Token::Symbol("}") => {
+                    self.pos += 1;
+                    break;
+                }
+                Token::Symbol(";") => self.pos += 1,
+                Token::Symbol("!") => {
+                    self.pos += 1;
+                    let flag = self.ident()?;
+                    self.report(context, &path, format!("`!{}` has no IR counterpart", flag));
+                }
+                Token::Ident(flag) if flag == "aggregateRoot" => {
+                    self.pos += 1;
+                    object.root = true;
+                }
+                Token::Ident(keyword) if keyword == "def" => self.operation(context, &path)?,
+                Token::Ident(keyword) if keyword == "Repository" => {
+                    let name = self.skip_construct()?;
+                    self.report(
+                        context,
+                        &join(&path, &name),
+                        "Repository has no IR counterpart",
+                    );
+                }
+                Token::Ident(keyword) if keyword == "belongsTo" => {
+                    self.pos += 1;
+                    self.eat_symbol("@");
+                    let owner = self.ident()?;
+                    self.report(
+                        context,
+                        &path,
+                        format!("`belongsTo {}` has no IR counterpart", owner),
+                    );
+                }
+                Token::Ident(flag) if OBJECT_FLAGS.contains(&flag.as_str()) => {
+                    self.pos += 1;
+                    self.report(context, &path, format!("`{}` has no IR counterpart", flag));
+                }
+                Token::Ident(key) if self.peek_at(1) == Some(&Token::Symbol("=")) => {
+                    self.pos += 2;
+                    self.values()?;
+                    self.report(context, &path, format!("`{}` has no IR counterpart", key));
+                }
+                Token::Ident(_)
+                    if kind == ObjectKind::Enum
+                        && matches!(
+                            self.peek_at(1),
+                            Some(Token::Symbol("," | "(" | "}" | ";"))
+                        ) =>
+                {
+                    loop {
+                        let value = self.ident()?;
+                        if self.at_symbol("(") {
+                            self.skip_group("(", ")")?;
+                            self.report(
+                                context,
+                                &join(&path, &value),
+                                "enum value arguments have no IR counterpart",
+                            );
+                        }
+                        object.values.push(value);
+                        if !self.eat_symbol(",") {
+                            break;
+                        }
+                    }
+                }
+                _ => {
+                    let attribute = self.attribute(doc)?;
+                    object.attributes.push(attribute);
+                }
+            }
+        }
+        Ok(Some(object))
+    }
+
+    /// Skip an operation, e.g. `def @Customer update(@Address address) : write;`
+    fn operation(&mut self, context: &str, path: &str) -> Result<()> {
+        self.pos += 1;
+        let mut name = String::new();
+        while !self.at_symbol("(") {
+            match self.peek() {
+                Some(Token::Ident(text)) => name = text.trim_start_matches('^').to_string(),
+                None => bail!("Missing parameters of operation at end of file"),
+                _ => {}
+            }
+            self.pos += 1;
+        }
+        self.skip_group("(", ")")?;
+        loop {
+            if self.eat_symbol(":") {
+                self.ident()?;
+                while self.eat_symbol("-") {
+                    self.ident()?;
+                }
+                if self.at_symbol("[") {
+                    self.skip_group("[", "]")?;
+                }
+            } else if self.eat_ident("throws") {
+                self.ident()?;
+                while self.eat_symbol(",") {
+                    self.ident()?;
+                }
+            } else {
+                self.eat_symbol(";");
+                break;
+            }
+        }
+        self.report(
+            context,
+            &join(path, &name),
+            "operation has no IR counterpart",
+        );
+        Ok(())
+    }
+
+    /// An attribute or reference, e.g. `- List<Address> addresses nullable`
+    fn attribute(&mut self, doc: Option<String>) -> Result<AttributeDef> {
+        self.eat_symbol("-");
+        for visibility in ["private", "protected", "public", "package"] {
+            self.eat_ident(visibility);
+        }
+        self.eat_symbol("@");
+        let first = self.ident()?;
+        let (collection, type_name) = if self.eat_symbol("<") {
+            self.eat_symbol("@");
+            let mut inner = self.ident()?;
+            while self.eat_symbol(",") {
+                self.eat_symbol("@");
+                inner = format!("{},{}", inner, self.ident()?);
+            }
+            self.expect(">")?;
+            (Some(first), inner)
+        } else {
+            (None, first)
+        };
+        let name = self.ident()?;
+
+        let mut options = Vec::new();
+        loop {
+            match self.peek() {
+                Some(Token::Ident(flag)) if ATTRIBUTE_FLAGS.contains(&flag.as_str()) => {
+                    options.push((flag.clone(), None));
+                    self.pos += 1;
+                }
+                Some(Token::Symbol("!")) => {
+                    self.pos += 1;
+                    options.push((format!("!{}", self.ident()?), None));
+                }
+                Some(Token::Symbol("<->")) => {
+                    self.pos += 1;
+                    options.push(("<->".to_string(), Some(self.ident()?)));
+                }
+                Some(Token::Ident(key)) if self.peek_at(1) == Some(&Token::Symbol("=")) => {
+                    let key = key.clone();
+                    self.pos += 2;
+                    options.push((key, Some(self.values()?.join(","))));
+                }
+                _ => break,
+            }
+        }
+
+        Ok(AttributeDef {
+            name,
+            doc,
+            collection,
+            type_name,
+            options,
+        })
+    }
+}
+
+struct Importer {
+    untranslated: Vec<Untranslated>,
+    /// Root entity of each CML aggregate, by context and aggregate name
+    roots: HashMap<(String, String), String>,
+}
+
+impl Importer {
+    fn report(&mut self, context: &str, path: &str, reason: impl Into<String>) {
+        self.untranslated.push(Untranslated {
+            context: context.to_string(),
+            path: path.to_string(),
+            reason: reason.into(),
+        });
+    }
+
+    fn translate(&mut self, cml: CmlModel) -> Vec<IRModel> {
+        let mut contexts: Vec<IRModel> = cml
+            .contexts
+            .iter()
+            .map(|context| self.context(context))
+            .collect();
+
+        for name in &cml.contains {
+            if !contexts
+                .iter()
+                .any(|model| &model.bounded_context.name == name)
+            {
+                self.report("ContextMap", name, "bounded context is not defined");
+            }
+        }
+        for relationship in &cml.relationships {
+            let label = relationship.label();
+            match relationship.relation {
+                Relation::SharedKernel => {
+                    self.report("ContextMap", &label, "Shared Kernel has no IR counterpart");
+                }
+                Relation::Partnership => {
+                    self.subscribe(&mut contexts, relationship, false);
+                    self.subscribe(&mut contexts, relationship, true);
+                }
+                Relation::UpstreamDownstream => {
+                    self.subscribe(&mut contexts, relationship, false);
+                }
+            }
+        }
+        contexts
+    }
+
+    /// Subscribe the downstream context to the events the upstream one exposes
+    fn subscribe(
+        &mut self,
+        contexts: &mut [IRModel],
+        relationship: &RelationshipDef,
+        reverse: bool,
+    ) {
+        let label = relationship.label();
+        let (upstream, downstream) = if reverse {
+            (&relationship.downstream, &relationship.upstream)
+        } else {
+            (&relationship.upstream, &relationship.downstream)
+        };
+        let find = |name: &str| {
+            contexts
+                .iter()
+                .position(|model| model.bounded_context.name == name)
+        };
+        let (Some(up), Some(down)) = (find(upstream), find(downstream)) else {
+            for name in [upstream, downstream] {
+                if find(name).is_none() {
+                    self.report(
+                        "ContextMap",
+                        &label,
+                        format!("bounded context `{}` is not defined", name),
+                    );
+                }
+            }
+            return;
+        };
+
+        let mut exposed = HashSet::new();
+        for aggregate in &relationship.exposed {
+            match self.roots.get(&(upstream.clone(), aggregate.clone())) {
+                Some(root) => {
+                    exposed.insert(root.clone());
+                }
+                None => self.report(
+                    "ContextMap",
+                    &label,
+                    format!("aggregate `{}` is not defined in `{}`", aggregate, upstream),
+                ),
+            }
+        }
+        let events: Vec<String> = contexts[up]
+            .events
+            .values()
+            .filter(|event| {
+                relationship.exposed.is_empty()
+                    || event
+                        .aggregate
+                        .as_ref()
+                        .is_some_and(|aggregate| exposed.contains(aggregate))
+            })
+            .map(|event| event.name.clone())
+            .collect();
+        if events.is_empty() {
+            self.report(
+                "ContextMap",
+                &label,
+                format!(
+                    "`{}` exposes no domain events, so `{}` subscribes to none",
+                    upstream, downstream
+                ),
+            );
+        }
+
+        let subscriptions = &mut contexts[down].external_events;
+        for event in events {
+            if subscriptions
+                .iter()
+                .any(|sub| &sub.context == upstream && sub.event == event)
+            {
+                continue;
+            }
+            subscriptions.push(ExternalEventSubscription {
+                context: upstream.clone(),
+                handler: default_handler(&event),
+                event,
+                description: None,
+            });
+        }
+    }
+
+    fn context(&mut self, def: &ContextDef) -> IRModel {
+        let context = def.name.as_str();
+        let mut model = IRModel {
+            version: "1.0".to_string(),
+            bounded_context: BoundedContext {
+                name: def.name.clone(),
+                namespace: default_namespace(context),
+                description: def.members.description.clone(),
+            },
+            aggregates: IndexMap::new(),
+            value_objects: IndexMap::new(),
+            events: IndexMap::new(),
+            commands: IndexMap::new(),
+            queries: IndexMap::new(),
+            policies: Vec::new(),
+            read_models: IndexMap::new(),
+            external_events: Vec::new(),
+        };
+
+        let declared: HashSet<&str> = def
+            .members
+            .aggregates
+            .iter()
+            .flat_map(|aggregate| &aggregate.objects)
+            .chain(&def.members.objects)
+            .map(|object| object.name.as_str())
+            .collect();
+
+        for aggregate in &def.members.aggregates {
+            let entities: Vec<&ObjectDef> = aggregate
+                .objects
+                .iter()
+                .filter(|object| object.kind == ObjectKind::Entity)
+                .collect();
+            let root = match entities.iter().find(|entity| entity.root) {
+                Some(root) => Some(*root),
+                None => {
+                    match entities.first() {
+                        Some(first) => self.report(
+                            context,
+                            &aggregate.name,
+                            format!(
+                                "no entity is marked `aggregateRoot`, so `{}` is taken as the root",
+                                first.name
+                            ),
+                        ),
+                        None => self.report(
+                            context,
+                            &aggregate.name,
+                            "aggregate has no entity to be its root",
+                        ),
+                    }
+                    entities.first().copied()
+                }
+            };
+
+            let owner = root.map(|root| root.name.as_str());
+            if let Some(root) = root {
+                self.roots.insert(
+                    (context.to_string(), aggregate.name.clone()),
+                    root.name.clone(),
+                );
+                if aggregate.name != root.name {
+                    self.report(
+                        context,
+                        &aggregate.name,
+                        format!("aggregate is named after its root entity `{}`", root.name),
+                    );
+                }
+                if aggregate.doc.is_some() && root.doc.is_some() {
+                    self.report(
+                        context,
+                        &join(&aggregate.name, &root.name),
+                        "description of the root entity is dropped for the aggregate's",
+                    );
+                }
+                if self.unique_name(&model, context, &aggregate.name, &root.name) {
+                    let path = join(&aggregate.name, &root.name);
+                    let properties = self.properties(context, &path, &root.attributes, &declared);
+                    model.aggregates.insert(
+                        root.name.clone(),
+                        Aggregate {
+                            name: root.name.clone(),
+                            description: aggregate.doc.clone().or_else(|| root.doc.clone()),
+                            persistence: Persistence::default(),
+                            root_entity: Entity {
+                                name: root.name.clone(),
+                                properties,
+                            },
+                            invariants: Vec::new(),
+                        },
+                    );
+                }
+            }
+
+            for object in &aggregate.objects {
+                if root.is_some_and(|root| std::ptr::eq(root, object)) {
+                    continue;
+                }
+                self.object(&mut model, &aggregate.name, owner, object, &declared);
+            }
+        }
+        for object in &def.members.objects {
+            self.object(&mut model, "", None, object, &declared);
+        }
+
+        model
+    }
+
+    /// Whether a name is still free in the IR, reporting it otherwise
+    fn unique_name(&mut self, model: &IRModel, context: &str, path: &str, name: &str) -> bool {
+        let taken = model.aggregates.contains_key(name)
+            || model.value_objects.contains_key(name)
+            || model.events.contains_key(name)
+            || model.commands.contains_key(name);
+        if taken {
+            self.report(
+                context,
+                &join(path, name),
+                "name is already used in the bounded context",
+            );
+        }
+        !taken
+    }
+
+    fn object(
+        &mut self,
+        model: &mut IRModel,
+        path: &str,
+        owner: Option<&str>,
+        object: &ObjectDef,
+        declared: &HashSet<&str>,
+    ) {
+        let context = model.bounded_context.name.clone();
+        let context = context.as_str();
+        if !self.unique_name(model, context, path, &object.name) {
+            return;
+        }
+        let path = join(path, &object.name);
+        let name = object.name.clone();
+
+        match object.kind {
+            ObjectKind::Entity | ObjectKind::ValueObject => {
+                let identifier = object.kind == ObjectKind::ValueObject
+                    && object.attributes.len() == 1
+                    && object.attributes[0].name == "value"
+                    && object.attributes[0].collection.is_none()
+                    && object.attributes[0].options.is_empty()
+                    && ir_basic_type(&object.attributes[0].type_name).is_some();
+                let value_object = if identifier {
+                    let cml_type = object.attributes[0].type_name.as_str();
+                    let (underlying, format) = match cml_type {
+                        "UUID" => ("String", Some("uuid".to_string())),
+                        other => (ir_basic_type(other).unwrap_or("String"), None),
+                    };
+                    ValueObject {
+                        name: name.clone(),
+                        description: object.doc.clone(),
+                        vo_type: Some("identifier".to_string()),
+                        underlying_type: Some(underlying.to_string()),
+                        format,
+                        prefix: None,
+                        properties: Vec::new(),
+                        values: Vec::new(),
+                    }
+                } else {
+                    let properties = self.properties(context, &path, &object.attributes, declared);
+                    if object.kind == ObjectKind::Entity
+                        && !properties.iter().any(|prop| prop.identifier)
+                    {
+                        self.report(
+                            context,
+                            &path,
+                            "entity without a `key` attribute is imported as a value object",
+                        );
+                    }
+                    ValueObject {
+                        name: name.clone(),
+                        description: object.doc.clone(),
+                        vo_type: None,
+                        underlying_type: None,
+                        format: None,
+                        prefix: None,
+                        properties,
+                        values: Vec::new(),
+                    }
+                };
+                model.value_objects.insert(name, value_object);
+            }
+            ObjectKind::Enum => {
+                for attribute in &object.attributes {
+                    self.report(
+                        context,
+                        &join(&path, &attribute.name),
+                        "enum attribute has no IR counterpart",
+                    );
+                }
+                model.value_objects.insert(
+                    name.clone(),
+                    ValueObject {
+                        name,
+                        description: object.doc.clone(),
+                        vo_type: Some("enum".to_string()),
+                        underlying_type: None,
+                        format: None,
+                        prefix: None,
+                        properties: Vec::new(),
+                        values: object
+                            .values
+                            .iter()
+                            .map(|value| EnumValue {
+                                name: value.clone(),
+                                description: None,
+                            })
+                            .collect(),
+                    },
+                );
+            }
+            ObjectKind::DomainEvent => {
+                let payload = self.properties(context, &path, &object.attributes, declared);
+                model.events.insert(
+                    name.clone(),
+                    Event {
+                        name,
+                        description: object.doc.clone(),
+                        aggregate: owner.map(str::to_string),
+                        payload,
+                    },
+                );
+            }
+            ObjectKind::CommandEvent => {
+                let payload = self.properties(context, &path, &object.attributes, declared);
+                model.commands.insert(
+                    name.clone(),
+                    Command {
+                        name,
+                        description: object.doc.clone(),
+                        aggregate: owner.map(str::to_string),
+                        payload,
+                        produces: Vec::new(),
+                        preconditions: Vec::new(),
+                        validation: Vec::new(),
+                    },
+                );
+            }
+        }
+    }
+
+    fn properties(
+        &mut self,
+        context: &str,
+        path: &str,
+        attributes: &[AttributeDef],
+        declared: &HashSet<&str>,
+    ) -> Vec<Property> {
+        attributes
+            .iter()
+            .filter_map(|attribute| self.property(context, path, attribute, declared))
+            .collect()
+    }
+
+    fn property(
+        &mut self,
+        context: &str,
+        path: &str,
+        attribute: &AttributeDef,
+        declared: &HashSet<&str>,
+    ) -> Option<Property> {
+        let path = join(path, &attribute.name);
+        let type_name = attribute.type_name.as_str();
+        match attribute.collection.as_deref() {
+            None | Some("List") => {}
+            Some(kind @ ("Set" | "Bag" | "Collection")) => {
+                self.report(context, &path, format!("`{}` is imported as `List`", kind));
+            }
+            Some(kind) => {
+                self.report(
+                    context,
+                    &path,
+                    format!("`{}<{}>` has no IR counterpart", kind, type_name),
+                );
+                return None;
+            }
+        }
+        let base = match ir_basic_type(type_name) {
+            Some(base) => base.to_string(),
+            None => {
+                if !declared.contains(type_name) {
+                    self.report(
+                        context,
+                        &path,
+                        format!(
+                            "type `{}` is not declared in the bounded context",
+                            type_name
+                        ),
+                    );
+                }
+                type_name.to_string()
+            }
+        };
+
+        let mut property = Property {
+            name: attribute.name.clone(),
+            prop_type: match attribute.collection {
+                Some(_) => format!("List<{}>", base),
+                None => base,
+            },
+            identifier: false,
+            required: true,
+            default: None,
+            description: attribute.doc.clone(),
+            validation: None,
+            computed: None,
+        };
+        let mut validation = Validation {
+            min: None,
+            max: None,
+            min_length: None,
+            max_length: None,
+            pattern: None,
+            precision: None,
+        };
+        for (option, value) in &attribute.options {
+            let number = value.as_deref().and_then(|value| value.parse::<f64>().ok());
+            match (option.as_str(), value) {
+                ("key", None) => property.identifier = true,
+                ("nullable", None) => property.required = false,
+                ("required", None) => {}
+                ("min", Some(_)) if number.is_some() => validation.min = number,
+                ("max", Some(_)) if number.is_some() => validation.max = number,
+                ("length", Some(value)) if value.contains(',') => {
+                    let (min, max) = value.split_once(',').unwrap_or_default();
+                    validation.min_length = min.trim().parse().ok();
+                    validation.max_length = max.trim().parse().ok();
+                }
+                ("pattern", Some(value)) => validation.pattern = Some(value.clone()),
+                (option, value) => {
+                    let written = match value {
+                        Some(value) => format!("{} = \"{}\"", option, value),
+                        None => option.to_string(),
+                    };
+                    self.report(
+                        context,
+                        &path,
+                        format!("`{}` has no IR counterpart", written),
+                    );
+                }
+            }
+        }
+        let validated = validation.min.is_some()
+            || validation.max.is_some()
+            || validation.min_length.is_some()
+            || validation.max_length.is_some()
+            || validation.pattern.is_some();
+        if validated {
+            property.validation = Some(validation);
+        }
+        Some(property)
+    }
+}
+
+struct Exporter<'a> {
+    contexts: &'a [IRModel],
+    out: String,
+    untranslated: Vec<Untranslated>,
+}
+
+impl Exporter<'_> {
+    fn report(&mut self, context: &str, path: &str, reason: impl Into<String>) {
+        self.untranslated.push(Untranslated {
+            context: context.to_string(),
+            path: path.to_string(),
+            reason: reason.into(),
+        });
+    }
+
+    fn line(&mut self, depth: usize, text: &str) {
+        if !text.is_empty() {
+            self.out.push_str(&"    ".repeat(depth));
+            self.out.push_str(text);
+        }
+        self.out.push('\n');
+    }
+
+    fn doc(&mut self, depth: usize, description: Option<&String>) {
+        if let Some(description) = description {
+            let doc = quote(description);
+            self.line(depth, &doc);
+        }
+    }
+
+    fn context_map(&mut self) {
+        let contexts = self.contexts;
+        let subscribing = contexts
+            .iter()
+            .any(|model| !model.external_events.is_empty());
+        if contexts.len() < 2 && !subscribing {
+            return;
+        }
+
+        self.line(0, "");
+        self.line(0, "ContextMap {");
+        let names: Vec<String> = contexts
+            .iter()
+            .map(|model| escape(&model.bounded_context.name))
+            .collect();
+        self.line(1, &format!("contains {}", names.join(", ")));
+
+        for model in contexts {
+            let downstream = model.bounded_context.name.as_str();
+            let mut by_upstream: IndexMap<&str, Vec<&ExternalEventSubscription>> = IndexMap::new();
+            for sub in &model.external_events {
+                by_upstream.entry(&sub.context).or_default().push(sub);
+            }
+            for (upstream, subs) in by_upstream {
+                if let Some(exposed) = self.subscriptions(downstream, upstream, &subs) {
+                    self.line(0, "");
+                    let relationship =
+                        format!("{} [U]->[D] {}", escape(upstream), escape(downstream));
+                    if exposed.is_empty() {
+                        self.line(1, &relationship);
+                    } else {
+                        self.line(1, &format!("{} {{", relationship));
+                        self.line(2, &format!("exposedAggregates = {}", exposed.join(", ")));
+                        self.line(1, "}");
+                    }
+                }
+            }
+        }
+        self.line(0, "}");
+    }
+
+    /// Aggregates the upstream context exposes to a downstream one, or `None`
+    /// when the subscriptions cannot be written as a relationship
+    fn subscriptions(
+        &mut self,
+        downstream: &str,
+        upstream: &str,
+        subs: &[&ExternalEventSubscription],
+    ) -> Option<Vec<String>> {
+        let Some(publisher) = self
+            .contexts
+            .iter()
+            .find(|model| model.bounded_context.name == upstream)
+        else {
+            for sub in subs {
+                self.report(
+                    downstream,
+                    &format!("external_events.{}", sub.event),
+                    format!("bounded context `{}` is not exported", upstream),
+                );
+            }
+            return None;
+        };
+
+        let mut subscribed: Vec<&Event> = Vec::new();
+        for sub in subs {
+            let path = format!("external_events.{}", sub.event);
+            match publisher.events.get(&sub.event) {
+                Some(event) => subscribed.push(event),
+                None => self.report(
+                    downstream,
+                    &path,
+                    format!("`{}` does not publish `{}`", upstream, sub.event),
+                ),
+            }
+            if sub.handler != default_handler(&sub.event) {
+                self.report(
+                    downstream,
+                    &path,
+                    format!("handler `{}` has no CML counterpart", sub.handler),
+                );
+            }
+            if sub.description.is_some() {
+                self.report(downstream, &path, "description has no CML counterpart");
+            }
+        }
+        if subscribed.is_empty() {
+            return None;
+        }
+
+        // CML exposes whole aggregates, or everything when none are listed
+        let every = publisher.events.len() == subscribed.len();
+        let mut exposed: Vec<String> = Vec::new();
+        if !every && subscribed.iter().all(|event| event.aggregate.is_some()) {
+            for event in &subscribed {
+                let aggregate = event.aggregate.clone().unwrap_or_default();
+                if !exposed.contains(&aggregate) {
+                    exposed.push(aggregate);
+                }
+            }
+        }
+        let extra: Vec<&str> = publisher
+            .events
+            .values()
+            .filter(|event| {
+                exposed.is_empty()
+                    || event
+                        .aggregate
+                        .as_ref()
+                        .is_some_and(|aggregate| exposed.contains(aggregate))
+            })
+            .filter(|event| !subscribed.iter().any(|sub| sub.name == event.name))
+            .map(|event| event.name.as_str())
+            .collect();
+        if !extra.is_empty() {
+            self.report(
+                downstream,
+                "external_events",
+                format!(
+                    "relationships expose whole aggregates, so importing the one with `{}` also subscribes to {}",
+                    upstream,
+                    extra.join(", ")
+                ),
+            );
+        }
+        Some(exposed.iter().map(|name| escape(name)).collect())
+    }
+
+    fn bounded_context(&mut self, model: &IRModel) {
+        let context = model.bounded_context.name.as_str();
+        if model.bounded_context.namespace != default_namespace(context) {
+            self.report(
+                context,
+                "bounded_context.namespace",
+                format!(
+                    "namespace `{}` has no CML counterpart",
+                    model.bounded_context.namespace
+                ),
+            );
+        }
+        for name in model.queries.keys() {
+            self.report(
+                context,
+                &format!("queries.{}", name),
+                "query has no CML counterpart",
+            );
+        }
+        for policy in &model.policies {
+            self.report(
+                context,
+                &format!("policies.{}", policy.name),
+                "policy has no CML counterpart",
+            );
+        }
+        for name in model.read_models.keys() {
+            self.report(
+                context,
+                &format!("read_models.{}", name),
+                "read model has no CML counterpart",
+            );
+        }
+
+        self.line(0, "");
+        self.line(0, &format!("BoundedContext {} {{", escape(context)));
+        let mut first = true;
+        if let Some(description) = &model.bounded_context.description {
+            self.line(
+                1,
+                &format!("domainVisionStatement = {}", quote(description)),
+            );
+            first = false;
+        }
+
+        // Each value object goes into the first aggregate that uses it
+        let mut owners: HashMap<&str, &str> = HashMap::new();
+        for (name, aggregate) in &model.aggregates {
+            let mut queue: Vec<&str> = aggregate
+                .root_entity
+                .properties
+                .iter()
+                .chain(
+                    model
+                        .events
+                        .values()
+                        .filter(|event| event.aggregate.as_deref() == Some(name.as_str()))
+                        .flat_map(|event| &event.payload),
+                )
+                .chain(
+                    model
+                        .commands
+                        .values()
+                        .filter(|command| command.aggregate.as_deref() == Some(name.as_str()))
+                        .flat_map(|command| &command.payload),
+                )
+                .map(|prop| prop.prop_type.as_str())
+                .collect();
+            while let Some(ir_type) = queue.pop() {
+                let Some(used) = referenced_type(ir_type) else {
+                    continue;
+                };
+                if let Some((used, vo)) = model.value_objects.get_key_value(&used) {
+                    if !owners.contains_key(used.as_str()) {
+                        owners.insert(used.as_str(), name.as_str());
+                        queue.extend(vo.properties.iter().map(|prop| prop.prop_type.as_str()));
+                    }
+                }
+            }
+        }
+
+        for (name, aggregate) in &model.aggregates {
+            if !first {
+                self.line(0, "");
+            }
+            first = false;
+            self.aggregate(model, name, aggregate, &owners);
+        }
+
+        let shared = |owner: Option<&String>| {
+            owner.is_none_or(|owner| !model.aggregates.contains_key(owner))
+        };
+        let loose_value_objects: Vec<&str> = model
+            .value_objects
+            .keys()
+            .filter(|name| !owners.contains_key(name.as_str()))
+            .map(String::as_str)
+            .collect();
+        let loose_events: Vec<&Event> = model
+            .events
+            .values()
+            .filter(|event| shared(event.aggregate.as_ref()))
+            .collect();
+        let loose_commands: Vec<&Command> = model
+            .commands
+            .values()
+            .filter(|command| shared(command.aggregate.as_ref()))
+            .collect();
+        if !loose_value_objects.is_empty() || !loose_events.is_empty() || !loose_commands.is_empty()
+        {
+            if !first {
+                self.line(0, "");
+            }
+            self.line(1, &format!("Module {} {{", SHARED_MODULE));
+            self.members(
+                model,
+                2,
+                &loose_value_objects,
+                &loose_events,
+                &loose_commands,
+                true,
+            );
+            self.line(1, "}");
+        }
+        self.line(0, "}");
+    }
+
+    fn aggregate(
+        &mut self,
+        model: &IRModel,
+        name: &str,
+        aggregate: &Aggregate,
+        owners: &HashMap<&str, &str>,
+    ) {
+        let context = model.bounded_context.name.as_str();
+        let path = format!("aggregates.{}", name);
+        if aggregate.persistence == Persistence::State {
+            self.report(
+                context,
+                &format!("{}.persistence", path),
+                "state persistence has no CML counterpart",
+            );
+        }
+        for invariant in &aggregate.invariants {
+            self.report(
+                context,
+                &format!("{}.invariants.{}", path, invariant.name),
+                "invariant has no CML counterpart",
+            );
+        }
+
+        self.doc(1, aggregate.description.as_ref());
+        self.line(1, &format!("Aggregate {} {{", escape(name)));
+        self.line(2, &format!("Entity {} {{", escape(name)));
+        self.line(3, "aggregateRoot");
+        self.properties(model, &path, &aggregate.root_entity.properties, 3);
+        self.line(2, "}");
+
+        let value_objects: Vec<&str> = model
+            .value_objects
+            .keys()
+            .filter(|vo| owners.get(vo.as_str()) == Some(&name))
+            .map(String::as_str)
+            .collect();
+        let events: Vec<&Event> = model
+            .events
+            .values()
+            .filter(|event| event.aggregate.as_deref() == Some(name))
+            .collect();
+        let commands: Vec<&Command> = model
+            .commands
+            .values()
+            .filter(|command| command.aggregate.as_deref() == Some(name))
+            .collect();
+        self.members(model, 2, &value_objects, &events, &commands, false);
+        self.line(1, "}");
+    }
+
+    /// Value objects, events and commands of an aggregate or module
+    fn members(
+        &mut self,
+        model: &IRModel,
+        depth: usize,
+        value_objects: &[&str],
+        events: &[&Event],
+        commands: &[&Command],
+        mut first: bool,
+    ) {
+        let mut separate = |exporter: &mut Self| {
+            if !first {
+                exporter.line(0, "");
+            }
+            first = false;
+        };
+        for name in value_objects {
+            separate(self);
+            self.value_object(model, name, &model.value_objects[*name], depth);
+        }
+        for event in events {
+            separate(self);
+            let path = format!("events.{}", event.name);
+            self.doc(depth, event.description.as_ref());
+            self.line(depth, &format!("DomainEvent {} {{", escape(&event.name)));
+            self.properties(model, &path, &event.payload, depth + 1);
+            self.line(depth, "}");
+        }
+        for command in commands {
+            separate(self);
+            let context = model.bounded_context.name.as_str();
+            let path = format!("commands.{}", command.name);
+            for (field, unused) in [
+                ("produces", command.produces.is_empty()),
+                ("preconditions", command.preconditions.is_empty()),
+                ("validation", command.validation.is_empty()),
+            ] {
+                if !unused {
+                    self.report(
+                        context,
+                        &format!("{}.{}", path, field),
+                        format!("`{}` has no CML counterpart", field),
+                    );
+                }
+            }
+            self.doc(depth, command.description.as_ref());
+            self.line(depth, &format!("CommandEvent {} {{", escape(&command.name)));
+            self.properties(model, &path, &command.payload, depth + 1);
+            self.line(depth, "}");
+        }
+    }
+
+    fn value_object(&mut self, model: &IRModel, name: &str, vo: &ValueObject, depth: usize) {
+        let context = model.bounded_context.name.as_str();
+        let path = format!("value_objects.{}", name);
+        self.doc(depth, vo.description.as_ref());
+
+        match vo.vo_type.as_deref() {
+            Some("enum") => {
+                if vo.values.iter().any(|value| value.description.is_some()) {
+                    self.report(
+                        context,
+                        &format!("{}.values", path),
+                        "enum value descriptions have no CML counterpart",
+                    );
+                }
+                let values: Vec<String> =
+                    vo.values.iter().map(|value| escape(&value.name)).collect();
+                self.line(depth, &format!("enum {} {{", escape(name)));
+                self.line(depth + 1, &values.join(", "));
+                self.line(depth, "}");
+            }
+            Some("identifier") => {
+                let underlying = vo.underlying_type.as_deref().unwrap_or("String");
+                let cml_type = match vo.format.as_deref() {
+                    Some("uuid") if underlying == "String" => "UUID",
+                    format => {
+                        if let Some(format) = format {
+                            self.report(
+                                context,
+                                &format!("{}.format", path),
+                                format!("format `{}` has no CML counterpart", format),
+                            );
+                        }
+                        cml_basic_type(underlying).unwrap_or("String")
+                    }
+                };
+                if let Some(prefix) = &vo.prefix {
+                    self.report(
+                        context,
+                        &format!("{}.prefix", path),
+                        format!("prefix `{}` has no CML counterpart", prefix),
+                    );
+                }
+                self.line(depth, &format!("ValueObject {} {{", escape(name)));
+                self.line(depth + 1, &format!("{} value", cml_type));
+                self.line(depth, "}");
+            }
+            vo_type => {
+                if let Some(vo_type) = vo_type {
+                    self.report(
+                        context,
+                        &format!("{}.type", path),
+                        format!("value object type `{}` has no CML counterpart", vo_type),
+                    );
+                }
+                // Value objects with an identity are the non-root entities
+                let keyword = if vo.properties.iter().any(|prop| prop.identifier) {
+                    "Entity"
+                } else {
+                    "ValueObject"
+                };
+                self.line(depth, &format!("{} {} {{", keyword, escape(name)));
+                self.properties(model, &path, &vo.properties, depth + 1);
+                self.line(depth, "}");
+            }
+        }
+    }
+
+    fn properties(&mut self, model: &IRModel, path: &str, properties: &[Property], depth: usize) {
+        for prop in properties {
+            self.property(model, &format!("{}.{}", path, prop.name), prop, depth);
+        }
+    }
+
+    fn property(&mut self, model: &IRModel, path: &str, prop: &Property, depth: usize) {
+        let context = model.bounded_context.name.as_str();
+
+        // `Option<>` and `List<>` may each wrap the type once
+        let mut ir_type = prop.prop_type.trim();
+        let mut nullable = !prop.required;
+        if let Some(inner) = ir_type
+            .strip_prefix("Option<")
+            .and_then(|rest| rest.strip_suffix('>'))
+        {
+            ir_type = inner;
+            nullable = true;
+        }
+        let mut list = false;
+        for wrapper in ["List<", "Vec<"] {
+            if let Some(inner) = ir_type
+                .strip_prefix(wrapper)
+                .and_then(|rest| rest.strip_suffix('>'))
+            {
+                ir_type = inner;
+                list = true;
+                break;
+            }
+        }
+        if ir_type.contains('<') {
+            self.report(
+                context,
+                path,
+                format!("type `{}` has no CML counterpart", prop.prop_type),
+            );
+            return;
+        }
+
+        let (cml_type, reference) = match cml_basic_type(ir_type) {
+            Some(basic) => (basic.to_string(), false),
+            None => (escape(ir_type), true),
+        };
+        let mut text = String::new();
+        if reference {
+            text.push_str("- ");
+        }
+        if list {
+            text.push_str(&format!("List<{}>", cml_type));
+        } else {
+            text.push_str(&cml_type);
+        }
+        text.push(' ');
+        text.push_str(&escape(&prop.name));
+        if prop.identifier {
+            text.push_str(" key");
+        }
+        if nullable {
+            text.push_str(" nullable");
+        }
+        if let Some(validation) = &prop.validation {
+            if let Some(min) = validation.min {
+                text.push_str(&format!(" min = \"{}\"", min));
+            }
+            if let Some(max) = validation.max {
+                text.push_str(&format!(" max = \"{}\"", max));
+            }
+            if validation.min_length.is_some() || validation.max_length.is_some() {
+                let bound =
+                    |length: Option<usize>| length.map(|n| n.to_string()).unwrap_or_default();
+                text.push_str(&format!(
+                    " length = \"{},{}\"",
+                    bound(validation.min_length),
+                    bound(validation.max_length)
+                ));
+            }
+            if let Some(pattern) = &validation.pattern {
+                text.push_str(&format!(" pattern = {}", quote(pattern)));
+            }
+            if validation.precision.is_some() {
+                self.report(context, path, "`precision` has no CML counterpart");
+            }
+        }
+        if prop.default.is_some() {
+            self.report(context, path, "default value has no CML counterpart");
+        }
+        if prop.computed.is_some() {
+            self.report(context, path, "computed value has no CML counterpart");
+        }
+
+        self.doc(depth, prop.description.as_ref());
+        self.line(depth, &text);
+    }
+}
+
+/// A name, escaped when it is a CML keyword
+fn escape(name: &str) -> String {
+    if KEYWORDS.split_whitespace().any(|keyword| keyword == name) {
+        format!("^{}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// A CML string literal
+fn quote(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            other => quoted.push(other),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    const CML: &str = r#"
+Domain Insurance {
+    Subdomain CustomerDomain
+}
+
+ContextMap InsuranceMap {
+    type = SYSTEM_LANDSCAPE
+    contains Customers, Policies, Risks
+
+    Customers [U,OHS]->[D] Policies {
+        exposedAggregates = Accounts
+        implementationTechnology = "RESTful HTTP"
+    }
+    Risks [SK]<->[SK] Policies
+}
+
+BoundedContext Customers implements Insurance {
+    domainVisionStatement = "Knows who our customers are"
+    responsibilities = "Customers", "Addresses"
+
+    "Customer accounts"
+    Aggregate Accounts {
+        owner = CustomerTeam
+
+        Entity Customer {
+            aggregateRoot
+            - CustomerId id key
+            "Given name"
+            String firstName length = "1,50"
+            int age nullable min = "18"
+            - List<Address> addresses
+            - CustomerStatus status
+            def void rename(String firstName);
+        }
+
+        ValueObject CustomerId {
+            UUID value
+        }
+
+        Entity Address {
+            String ^key key
+            String street
+            Set<String> tags
+        }
+
+        enum CustomerStatus {
+            aggregateLifecycle
+            ACTIVE, SUSPENDED
+        }
+
+        DomainEvent CustomerRegistered {
+            - CustomerId customerId
+            DateTime registeredAt
+        }
+
+        CommandEvent RegisterCustomer {
+            String firstName
+        }
+
+        Service AccountService {
+            void open(@Customer customer);
+        }
+    }
+}
+
+BoundedContext Policies {
+    Module contracts {
+        Aggregate Policy {
+            Entity Policy {
+                aggregateRoot
+                long policyNumber key
+            }
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn test_import_translates_tactic_and_strategic_constructs() {
+        let import = Cml::import(CML).unwrap();
+        let names: Vec<&str> = import
+            .contexts
+            .iter()
+            .map(|model| model.bounded_context.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Customers", "Policies"]);
+
+        let customers = &import.contexts[0];
+        assert_eq!(
+            customers.bounded_context.description.as_deref(),
+            Some("Knows who our customers are")
+        );
+        let customer = &customers.aggregates["Customer"];
+        assert_eq!(customer.description.as_deref(), Some("Customer accounts"));
+        let props: Vec<(&str, &str, bool, bool)> = customer
+            .root_entity
+            .properties
+            .iter()
+            .map(|prop| {
+                (
+                    prop.name.as_str(),
+                    prop.prop_type.as_str(),
+                    prop.required,
+                    prop.identifier,
+                )
+            })
+            .collect();
+        assert_eq!(
+            props,
+            vec![
+                ("id", "CustomerId", true, true),
+                ("firstName", "String", true, false),
+                ("age", "Integer", false, false),
+                ("addresses", "List<Address>", true, false),
+                ("status", "CustomerStatus", true, false),
+            ]
+        );
+        let first_name = &customer.root_entity.properties[1];
+        assert_eq!(first_name.description.as_deref(), Some("Given name"));
+        let validation = first_name.validation.as_ref().unwrap();
+        assert_eq!(
+            (validation.min_length, validation.max_length),
+            (Some(1), Some(50))
+        );
+        let age = customer.root_entity.properties[2]
+            .validation
+            .as_ref()
+            .unwrap();
+        assert_eq!(age.min, Some(18.0));
+
+        let id = &customers.value_objects["CustomerId"];
+        assert_eq!(id.vo_type.as_deref(), Some("identifier"));
+        assert_eq!(id.format.as_deref(), Some("uuid"));
+        let address = &customers.value_objects["Address"];
+        assert!(address.properties[0].identifier);
+        assert_eq!(address.properties[0].name, "key");
+        assert_eq!(address.properties[2].prop_type, "List<String>");
+        let status = &customers.value_objects["CustomerStatus"];
+        let values: Vec<&str> = status
+            .values
+            .iter()
+            .map(|value| value.name.as_str())
+            .collect();
+        assert_eq!(values, vec!["ACTIVE", "SUSPENDED"]);
+        assert_eq!(
+            customers.events["CustomerRegistered"].aggregate.as_deref(),
+            Some("Customer")
+        );
+        assert_eq!(
+            customers.commands["RegisterCustomer"].aggregate.as_deref(),
+            Some("Customer")
+        );
+
+        // The relationship subscribes Policies to the events of `Accounts`
+        let policies = &import.contexts[1];
+        assert!(policies.aggregates.contains_key("Policy"));
+        let subscriptions: Vec<(&str, &str, &str)> = policies
+            .external_events
+            .iter()
+            .map(|sub| {
+                (
+                    sub.context.as_str(),
+                    sub.event.as_str(),
+                    sub.handler.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            subscriptions,
+            vec![(
+                "Customers",
+                "CustomerRegistered",
+                "handleCustomerRegistered"
+            )]
+        );
+
+        let report: Vec<String> = import
+            .untranslated
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        for expected in [
+            "Insurance: Domain has no IR counterpart",
+            "ContextMap: type: attribute has no IR counterpart",
+            "ContextMap: Risks: bounded context is not defined",
+            "ContextMap: Customers -> Policies: roles OHS have no IR counterpart",
+            "ContextMap: Customers -> Policies.implementationTechnology: attribute has no IR counterpart",
+            "ContextMap: Risks <-> Policies: Shared Kernel has no IR counterpart",
+            "Customers: `implements Insurance` has no IR counterpart",
+            "Customers: responsibilities: attribute has no IR counterpart",
+            "Customers: Accounts.owner: attribute has no IR counterpart",
+            "Customers: Accounts: aggregate is named after its root entity `Customer`",
+            "Customers: Accounts.Customer.rename: operation has no IR counterpart",
+            "Customers: Accounts.Address.tags: `Set` is imported as `List`",
+            "Customers: Accounts.CustomerStatus: `aggregateLifecycle` has no IR counterpart",
+            "Customers: Accounts.AccountService: Service has no IR counterpart",
+            "Policies: contracts: Module is flattened into its bounded context",
+        ] {
+            assert!(
+                report.iter().any(|line| line == expected),
+                "missing `{}` in {:#?}",
+                expected,
+                report
+            );
+        }
+        assert_eq!(report.len(), 15, "{:#?}", report);
+
+        for (_, yaml) in import.files().unwrap() {
+            IRParser::parse_yaml(&yaml).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_imported_files_are_canonical_ir() {
+        let import = Cml::import(CML).unwrap();
+        let files = import.files().unwrap();
+        assert_eq!(files[0].0, "customers_context.yaml");
+        assert_eq!(files[1].0, "policies_context.yaml");
+
+        let policies = &files[1].1;
+        assert_eq!(IRFormatter::format(policies).unwrap(), *policies);
+        assert!(policies.starts_with("# Policies Context\n# Imported from Context Mapper (CML)\n"));
+        assert!(policies.contains("namespace: \"policies\""));
+        assert!(policies.contains("context: \"Customers\"\n    event: \"CustomerRegistered\""));
+        assert!(!policies.contains("null"));
+        assert!(!policies.contains("required: true"));
+        assert!(!policies.contains("persistence"));
+    }
+
+    #[test]
+    fn test_export_round_trips_through_import() {
+        let project = IRParser::parse_project(&[
+            "../ir_schema/examples/ecommerce/order_context.yaml",
+            "../ir_schema/examples/ecommerce/inventory_context.yaml",
+            "../ir_schema/examples/ecommerce/payment_context.yaml",
+        ])
+        .unwrap();
+        let export = Cml::export(&project.contexts);
+        assert!(export
+            .cml
+            .contains("Order [U]->[D] Inventory {\n        exposedAggregates = Order\n    }"));
+        assert!(export.cml.contains("    Aggregate Order {\n        Entity Order {\n            aggregateRoot\n            \"Unique order identifier\"\n            - OrderId id key\n"));
+        assert!(export
+            .cml
+            .contains("        ValueObject OrderId {\n            UUID value\n        }"));
+
+        let report: Vec<String> = export
+            .untranslated
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        for expected in [
+            "Order: queries.GetOrder: query has no CML counterpart",
+            "Order: aggregates.Order.invariants.OrderMustHaveItems: invariant has no CML counterpart",
+            "Order: commands.CreateOrder.produces: `produces` has no CML counterpart",
+            "Inventory: aggregates.Product.persistence: state persistence has no CML counterpart",
+        ] {
+            assert!(
+                report.iter().any(|line| line == expected),
+                "missing `{}` in {:#?}",
+                expected,
+                report
+            );
+        }
+        assert!(report.iter().any(|line| line.starts_with(
+            "Inventory: external_events: relationships expose whole aggregates, so importing the one with `Order` also subscribes to "
+        )));
+
+        let import = Cml::import(&export.cml).unwrap();
+        assert_eq!(import.contexts.len(), project.contexts.len());
+        for (original, imported) in project.contexts.iter().zip(&import.contexts) {
+            let keys = |model: &IRModel| {
+                (
+                    model.aggregates.keys().cloned().collect::<Vec<_>>(),
+                    model.value_objects.keys().cloned().collect::<HashSet<_>>(),
+                    model.events.keys().cloned().collect::<Vec<_>>(),
+                    model.commands.keys().cloned().collect::<Vec<_>>(),
+                )
+            };
+            assert_eq!(keys(original), keys(imported));
+            assert_eq!(
+                original.bounded_context.description,
+                imported.bounded_context.description
+            );
+
+            for (name, aggregate) in &original.aggregates {
+                let properties = |aggregate: &Aggregate| {
+                    aggregate
+                        .root_entity
+                        .properties
+                        .iter()
+                        .map(|prop| {
+                            (
+                                prop.name.clone(),
+                                prop.prop_type.clone(),
+                                prop.required,
+                                prop.identifier,
+                                prop.description.clone(),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                };
+                assert_eq!(
+                    properties(aggregate),
+                    properties(&imported.aggregates[name])
+                );
+            }
+            for (name, vo) in &original.value_objects {
+                let imported = &imported.value_objects[name];
+                assert_eq!(vo.vo_type, imported.vo_type, "{}", name);
+                assert_eq!(vo.values.len(), imported.values.len(), "{}", name);
+            }
+            for (name, event) in &original.events {
+                assert_eq!(event.aggregate, imported.events[name].aggregate, "{}", name);
+            }
+            for sub in &original.external_events {
+                assert!(
+                    imported
+                        .external_events
+                        .iter()
+                        .any(|imported| imported.context == sub.context
+                            && imported.event == sub.event)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_keywords_are_escaped() {
+        let yaml = r#"
+version: "1.0"
+bounded_context:
+  name: "Catalog"
+  namespace: "catalog"
+aggregates:
+  Item:
+    name: "Item"
+    root_entity:
+      name: "Item"
+      properties:
+        - name: "key"
+          type: "String"
+          identifier: true
+        - name: "type"
+          type: "Option<String>"
+"#;
+        let model = IRParser::parse_yaml(yaml).unwrap();
+        let export = Cml::export(std::slice::from_ref(&model));
+        assert!(export.cml.contains("String ^key key\n"));
+        assert!(export.cml.contains("String ^type nullable\n"));
+        assert!(export.untranslated.is_empty());
+
+        let import = Cml::import(&export.cml).unwrap();
+        let properties = &import.contexts[0].aggregates["Item"].root_entity.properties;
+        assert_eq!(properties[0].name, "key");
+        assert!(properties[0].identifier);
+        assert_eq!(properties[1].name, "type");
+        assert!(!properties[1].required);
+    }
+}
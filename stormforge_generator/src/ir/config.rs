@@ -0,0 +1,51 @@
+use super::lint::LintConfig;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name of the project configuration
+pub const CONFIG_FILE: &str = "stormforge.yaml";
+
+/// Project configuration (`stormforge.yaml`)
+///
+/// Only the settings used by the generator are read; other sections of the
+/// file, such as the context list, are ignored.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProjectConfig {
+    #[serde(default)]
+    pub lint: LintConfig,
+}
+
+impl ProjectConfig {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse config file: {}", path.display()))
+    }
+
+    /// Load the configuration of the project containing a path
+    ///
+    /// The directory of the path and its ancestors are searched for
+    /// `stormforge.yaml`.
+    pub fn discover<P: AsRef<Path>>(start: P) -> Result<Option<(PathBuf, Self)>> {
+        let start = start.as_ref();
+        let start = if start.is_dir() {
+            start
+        } else {
+            start.parent().unwrap_or(Path::new("."))
+        };
+        let start = fs::canonicalize(start).unwrap_or_else(|_| start.to_path_buf());
+
+        for dir in start.ancestors() {
+            let path = dir.join(CONFIG_FILE);
+            if path.is_file() {
+                let config = Self::load(&path)?;
+                return Ok(Some((path, config)));
+            }
+        }
+        Ok(None)
+    }
+}
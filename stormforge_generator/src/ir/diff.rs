@@ -0,0 +1,641 @@
+use super::types::{IRModel, Property, Validation};
+use indexmap::IndexMap;
+use serde::Serialize;
+
+/// Impact of a change on the consumers of a bounded context
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Existing clients, consumers or stored data may stop working
+    Breaking,
+    /// Existing clients, consumers and stored data keep working
+    Compatible,
+}
+
+/// One semantic change between two versions of a model
+#[derive(Debug, Clone, Serialize)]
+pub struct Change {
+    pub severity: Severity,
+    /// Dotted path of the changed element, e.g. `commands.PlaceOrder.payload.note`
+    pub path: String,
+    pub description: String,
+}
+
+/// Semantic comparison of two versions of a bounded context
+#[derive(Debug, Clone, Serialize)]
+pub struct IRDiff {
+    pub context: String,
+    pub old_version: String,
+    pub new_version: String,
+    pub breaking: usize,
+    pub compatible: usize,
+    pub changes: Vec<Change>,
+}
+
+/// Which side of the context reads or writes the properties being compared
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Role {
+    /// Sent by clients: command payloads and query parameters
+    Input,
+    /// Read by consumers or stored: events, aggregates and read models
+    Output,
+    /// Value objects, which appear in both
+    Shared,
+}
+
+impl Role {
+    fn reads(self) -> bool {
+        matches!(self, Role::Output | Role::Shared)
+    }
+
+    fn writes(self) -> bool {
+        matches!(self, Role::Input | Role::Shared)
+    }
+}
+
+#[derive(Default)]
+struct Differ {
+    changes: Vec<Change>,
+}
+
+impl IRDiff {
+    /// Compare two versions of a model element by element
+    ///
+    /// Elements and properties are matched by name, so text-only edits such
+    /// as reordering or rewording descriptions produce no changes. A removed
+    /// and an added property of the same type are reported as a rename.
+    pub fn compare(old: &IRModel, new: &IRModel) -> Self {
+        let mut differ = Differ::default();
+
+        if old.bounded_context.name != new.bounded_context.name {
+            differ.breaking(
+                "bounded_context.name",
+                format!(
+                    "bounded context renamed from `{}` to `{}`",
+                    old.bounded_context.name, new.bounded_context.name
+                ),
+            );
+        }
+
+        differ.elements(
+            "aggregates",
+            "aggregate",
+            &old.aggregates,
+            &new.aggregates,
+            |differ, path, old, new| {
+                if old.persistence != new.persistence {
+                    differ.breaking(
+                        &format!("{}.persistence", path),
+                        format!(
+                            "persistence changed from {} to {}",
+                            old.persistence.as_str(),
+                            new.persistence.as_str()
+                        ),
+                    );
+                }
+                differ.properties(
+                    path,
+                    &old.root_entity.properties,
+                    &new.root_entity.properties,
+                    Role::Output,
+                )
+            },
+        );
+
+        differ.elements(
+            "value_objects",
+            "value object",
+            &old.value_objects,
+            &new.value_objects,
+            |differ, path, old, new| {
+                if old.vo_type != new.vo_type {
+                    differ.breaking(
+                        path,
+                        format!(
+                            "kind changed from {} to {}",
+                            old.vo_type.as_deref().unwrap_or("value object"),
+                            new.vo_type.as_deref().unwrap_or("value object")
+                        ),
+                    );
+                    return;
+                }
+                if normalize(old.underlying_type.as_deref().unwrap_or("String"))
+                    != normalize(new.underlying_type.as_deref().unwrap_or("String"))
+                {
+                    differ.breaking(
+                        &format!("{}.underlying_type", path),
+                        format!(
+                            "underlying type changed from `{}` to `{}`",
+                            old.underlying_type.as_deref().unwrap_or("String"),
+                            new.underlying_type.as_deref().unwrap_or("String")
+                        ),
+                    );
+                }
+                for value in &old.values {
+                    if !new.values.iter().any(|new| new.name == value.name) {
+                        differ.breaking(
+                            &format!("{}.values.{}", path, value.name),
+                            "enum value removed".to_string(),
+                        );
+                    }
+                }
+                for value in &new.values {
+                    if !old.values.iter().any(|old| old.name == value.name) {
+                        differ.compatible(
+                            &format!("{}.values.{}", path, value.name),
+                            "enum value added".to_string(),
+                        );
+                    }
+                }
+                differ.properties(path, &old.properties, &new.properties, Role::Shared);
+            },
+        );
+
+        differ.elements(
+            "commands",
+            "command",
+            &old.commands,
+            &new.commands,
+            |differ, path, old, new| {
+                differ.properties(
+                    &format!("{}.payload", path),
+                    &old.payload,
+                    &new.payload,
+                    Role::Input,
+                )
+            },
+        );
+
+        differ.elements(
+            "events",
+            "event",
+            &old.events,
+            &new.events,
+            |differ, path, old, new| {
+                differ.properties(
+                    &format!("{}.payload", path),
+                    &old.payload,
+                    &new.payload,
+                    Role::Output,
+                )
+            },
+        );
+
+        differ.elements(
+            "queries",
+            "query",
+            &old.queries,
+            &new.queries,
+            |differ, path, old, new| {
+                differ.properties(
+                    &format!("{}.parameters", path),
+                    &old.parameters,
+                    &new.parameters,
+                    Role::Input,
+                );
+                let path = format!("{}.returns", path);
+                match (&old.returns, &new.returns) {
+                    (Some(old), Some(new)) => {
+                        if normalize(&old.return_type) != normalize(&new.return_type) {
+                            differ.breaking(
+                                &path,
+                                format!(
+                                    "return type changed from `{}` to `{}`",
+                                    old.return_type, new.return_type
+                                ),
+                            );
+                        } else if !old.nullable && new.nullable {
+                            differ.breaking(&path, "result became nullable".to_string());
+                        } else if old.nullable && !new.nullable {
+                            differ.compatible(&path, "result is no longer nullable".to_string());
+                        }
+                    }
+                    (Some(_), None) => differ.breaking(&path, "return type removed".to_string()),
+                    (None, Some(_)) => differ.compatible(&path, "return type added".to_string()),
+                    (None, None) => {}
+                }
+            },
+        );
+
+        differ.elements(
+            "read_models",
+            "read model",
+            &old.read_models,
+            &new.read_models,
+            |differ, path, old, new| {
+                for field in &old.fields {
+                    let field_path = format!("{}.fields.{}", path, field.name);
+                    match new.fields.iter().find(|new| new.name == field.name) {
+                        None => differ.breaking(&field_path, "field removed".to_string()),
+                        Some(new_field) => {
+                            let old_type = field.field_type.as_deref().unwrap_or("String");
+                            let new_type = new_field.field_type.as_deref().unwrap_or("String");
+                            if normalize(old_type) != normalize(new_type) {
+                                differ.breaking(
+                                    &field_path,
+                                    format!("type changed from `{}` to `{}`", old_type, new_type),
+                                );
+                            }
+                        }
+                    }
+                }
+                for field in &new.fields {
+                    if !old.fields.iter().any(|old| old.name == field.name) {
+                        differ.compatible(
+                            &format!("{}.fields.{}", path, field.name),
+                            "field added".to_string(),
+                        );
+                    }
+                }
+            },
+        );
+
+        let changes = differ.changes;
+        let breaking = changes
+            .iter()
+            .filter(|change| change.severity == Severity::Breaking)
+            .count();
+        Self {
+            context: new.bounded_context.name.clone(),
+            old_version: old.version.clone(),
+            new_version: new.version.clone(),
+            breaking,
+            compatible: changes.len() - breaking,
+            changes,
+        }
+    }
+
+    pub fn has_breaking_changes(&self) -> bool {
+        self.breaking > 0
+    }
+
+    /// Human readable report, breaking changes first
+    pub fn to_text(&self) -> String {
+        let mut out = format!(
+            "{}: {} → {}\n",
+            self.context, self.old_version, self.new_version
+        );
+        if self.changes.is_empty() {
+            out.push_str("\nNo semantic changes\n");
+            return out;
+        }
+        for (severity, heading, marker) in [
+            (Severity::Breaking, "Breaking changes", "✗"),
+            (Severity::Compatible, "Compatible changes", "✓"),
+        ] {
+            let changes: Vec<&Change> = self
+                .changes
+                .iter()
+                .filter(|change| change.severity == severity)
+                .collect();
+            if changes.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("\n{}:\n", heading));
+            for change in changes {
+                out.push_str(&format!(
+                    "  {} {}: {}\n",
+                    marker, change.path, change.description
+                ));
+            }
+        }
+        out.push_str(&format!(
+            "\n{} breaking, {} compatible\n",
+            self.breaking, self.compatible
+        ));
+        out
+    }
+}
+
+impl Differ {
+    fn breaking(&mut self, path: &str, description: String) {
+        self.push(Severity::Breaking, path, description);
+    }
+
+    fn compatible(&mut self, path: &str, description: String) {
+        self.push(Severity::Compatible, path, description);
+    }
+
+    fn push(&mut self, severity: Severity, path: &str, description: String) {
+        self.changes.push(Change {
+            severity,
+            path: path.to_string(),
+            description,
+        });
+    }
+
+    /// Compare the elements of one section, removals being breaking
+    fn elements<T>(
+        &mut self,
+        section: &str,
+        kind: &str,
+        old: &IndexMap<String, T>,
+        new: &IndexMap<String, T>,
+        mut compare: impl FnMut(&mut Self, &str, &T, &T),
+    ) {
+        for (name, old_element) in old {
+            let path = format!("{}.{}", section, name);
+            match new.get(name) {
+                Some(new_element) => compare(self, &path, old_element, new_element),
+                None => self.breaking(&path, format!("{} removed", kind)),
+            }
+        }
+        for name in new.keys() {
+            if !old.contains_key(name) {
+                self.compatible(&format!("{}.{}", section, name), format!("{} added", kind));
+            }
+        }
+    }
+
+    fn properties(&mut self, path: &str, old: &[Property], new: &[Property], role: Role) {
+        let mut removed: Vec<&Property> = Vec::new();
+        for old_prop in old {
+            match new.iter().find(|new_prop| new_prop.name == old_prop.name) {
+                Some(new_prop) => self.property(
+                    &format!("{}.{}", path, old_prop.name),
+                    old_prop,
+                    new_prop,
+                    role,
+                ),
+                None => removed.push(old_prop),
+            }
+        }
+        let mut added: Vec<&Property> = new
+            .iter()
+            .filter(|new_prop| !old.iter().any(|old_prop| old_prop.name == new_prop.name))
+            .collect();
+
+        for old_prop in removed {
+            // A property of the same shape added in its place is a rename
+            let renamed = added.iter().position(|new_prop| {
+                normalize(&new_prop.prop_type) == normalize(&old_prop.prop_type)
+                    && new_prop.required == old_prop.required
+            });
+            match renamed {
+                Some(index) => {
+                    let new_prop = added.remove(index);
+                    self.breaking(
+                        &format!("{}.{}", path, old_prop.name),
+                        format!("property renamed to `{}`", new_prop.name),
+                    );
+                }
+                None => self.breaking(
+                    &format!("{}.{}", path, old_prop.name),
+                    "property removed".to_string(),
+                ),
+            }
+        }
+        for new_prop in added {
+            let path = format!("{}.{}", path, new_prop.name);
+            if !new_prop.required {
+                self.compatible(&path, "optional property added".to_string());
+            } else if role.writes() {
+                self.breaking(&path, "required property added".to_string());
+            } else {
+                self.breaking(
+                    &path,
+                    "required property added, which stored data lacks".to_string(),
+                );
+            }
+        }
+    }
+
+    fn property(&mut self, path: &str, old: &Property, new: &Property, role: Role) {
+        if normalize(&old.prop_type) != normalize(&new.prop_type) {
+            self.breaking(
+                path,
+                format!(
+                    "type changed from `{}` to `{}`",
+                    old.prop_type, new.prop_type
+                ),
+            );
+        }
+
+        match (old.required, new.required) {
+            (true, false) if role.reads() => {
+                self.breaking(path, "became optional, so readers may get null".to_string())
+            }
+            (true, false) => self.compatible(path, "became optional".to_string()),
+            (false, true) if role.writes() => self.breaking(path, "became required".to_string()),
+            (false, true) => self.breaking(
+                path,
+                "became required, which stored data may lack".to_string(),
+            ),
+            _ => {}
+        }
+
+        // Stricter rules reject input that was valid before
+        if role.writes() {
+            self.validation(path, old.validation.as_ref(), new.validation.as_ref());
+        }
+    }
+
+    fn validation(&mut self, path: &str, old: Option<&Validation>, new: Option<&Validation>) {
+        let rule = |validation: Option<&Validation>, get: fn(&Validation) -> Option<f64>| {
+            validation.and_then(get)
+        };
+        // (name, old, new, whether a higher value is stricter)
+        let bounds: [(&str, Option<f64>, Option<f64>, bool); 4] = [
+            ("min", rule(old, |v| v.min), rule(new, |v| v.min), true),
+            ("max", rule(old, |v| v.max), rule(new, |v| v.max), false),
+            (
+                "minLength",
+                rule(old, |v| v.min_length.map(|n| n as f64)),
+                rule(new, |v| v.min_length.map(|n| n as f64)),
+                true,
+            ),
+            (
+                "maxLength",
+                rule(old, |v| v.max_length.map(|n| n as f64)),
+                rule(new, |v| v.max_length.map(|n| n as f64)),
+                false,
+            ),
+        ];
+        let path = format!("{}.validation", path);
+        for (name, old_bound, new_bound, higher_is_stricter) in bounds {
+            match (old_bound, new_bound) {
+                (None, Some(bound)) => {
+                    self.breaking(&path, format!("`{}` of {} added", name, bound))
+                }
+                (Some(bound), None) => {
+                    self.compatible(&path, format!("`{}` of {} removed", name, bound))
+                }
+                (Some(old_bound), Some(new_bound)) if old_bound != new_bound => {
+                    let description =
+                        format!("`{}` changed from {} to {}", name, old_bound, new_bound);
+                    if (new_bound > old_bound) == higher_is_stricter {
+                        self.breaking(&path, description);
+                    } else {
+                        self.compatible(&path, description);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let old_pattern = old.and_then(|v| v.pattern.as_deref());
+        let new_pattern = new.and_then(|v| v.pattern.as_deref());
+        match (old_pattern, new_pattern) {
+            (_, Some(pattern)) if old_pattern != new_pattern => {
+                self.breaking(&path, format!("`pattern` changed to `{}`", pattern))
+            }
+            (Some(_), None) => self.compatible(&path, "`pattern` removed".to_string()),
+            _ => {}
+        }
+    }
+}
+
+/// Type spelling that ignores whitespace and the `Vec`/`List` aliases
+fn normalize(ir_type: &str) -> String {
+    ir_type
+        .split_whitespace()
+        .collect::<String>()
+        .replace("Vec<", "List<")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    const OLD: &str = r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+value_objects:
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "CREATED"
+      - name: "PAID"
+commands:
+  PlaceOrder:
+    name: "PlaceOrder"
+    description: "Place an order"
+    payload:
+      - name: "customerId"
+        type: "String"
+      - name: "items"
+        type: "List<String>"
+        validation:
+          maxLength: 50
+events:
+  OrderPlaced:
+    name: "OrderPlaced"
+    payload:
+      - name: "total"
+        type: "Integer"
+  OrderPaid:
+    name: "OrderPaid"
+"#;
+
+    #[test]
+    fn test_description_edits_are_not_changes() {
+        let old = IRParser::parse_yaml(OLD).unwrap();
+        let new = IRParser::parse_yaml(&OLD.replace("Place an order", "Places an order")).unwrap();
+
+        let diff = IRDiff::compare(&old, &new);
+        assert!(diff.changes.is_empty());
+        assert!(diff.to_text().contains("No semantic changes"));
+    }
+
+    #[test]
+    fn test_classifies_changes() {
+        let old = IRParser::parse_yaml(OLD).unwrap();
+        let new = IRParser::parse_yaml(
+            r#"
+version: "1.1"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+value_objects:
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "CREATED"
+      - name: "SHIPPED"
+commands:
+  PlaceOrder:
+    name: "PlaceOrder"
+    payload:
+      - name: "buyerId"
+        type: "String"
+      - name: "items"
+        type: "Vec<String>"
+        validation:
+          maxLength: 100
+      - name: "couponCode"
+        type: "String"
+      - name: "note"
+        type: "String"
+        required: false
+events:
+  OrderPlaced:
+    name: "OrderPlaced"
+    payload:
+      - name: "total"
+        type: "Decimal"
+"#,
+        )
+        .unwrap();
+
+        let diff = IRDiff::compare(&old, &new);
+        let changes: Vec<(Severity, &str, &str)> = diff
+            .changes
+            .iter()
+            .map(|change| {
+                (
+                    change.severity,
+                    change.path.as_str(),
+                    change.description.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                (
+                    Severity::Breaking,
+                    "value_objects.OrderStatus.values.PAID",
+                    "enum value removed"
+                ),
+                (
+                    Severity::Compatible,
+                    "value_objects.OrderStatus.values.SHIPPED",
+                    "enum value added"
+                ),
+                (
+                    Severity::Compatible,
+                    "commands.PlaceOrder.payload.items.validation",
+                    "`maxLength` changed from 50 to 100"
+                ),
+                (
+                    Severity::Breaking,
+                    "commands.PlaceOrder.payload.customerId",
+                    "property renamed to `buyerId`"
+                ),
+                (
+                    Severity::Breaking,
+                    "commands.PlaceOrder.payload.couponCode",
+                    "required property added"
+                ),
+                (
+                    Severity::Compatible,
+                    "commands.PlaceOrder.payload.note",
+                    "optional property added"
+                ),
+                (
+                    Severity::Breaking,
+                    "events.OrderPlaced.payload.total",
+                    "type changed from `Integer` to `Decimal`"
+                ),
+                (Severity::Breaking, "events.OrderPaid", "event removed"),
+            ]
+        );
+        assert_eq!((diff.breaking, diff.compatible), (5, 3));
+        assert!(diff.has_breaking_changes());
+    }
+}
@@ -0,0 +1,647 @@
+use anyhow::{bail, Result};
+use std::fmt;
+
+/// List methods whose argument is a lambda over the items
+const LAMBDA_METHODS: &[&str] = &["all", "any", "none", "filter", "map", "count", "sum"];
+
+/// Lambda parameter of the shorthand `items.all(inStock)`
+const SHORTHAND_PARAM: &str = "item";
+
+/// Parsed IR expression, as written in invariants, preconditions, validation
+/// rules, policy conditions and computed read model fields
+///
+/// The grammar is a small JavaScript-like language: boolean operators
+/// (`&&`/`and`, `||`/`or`, `!`/`not`), comparisons, `in [..]`, arithmetic,
+/// member paths such as `order.items.length`, function calls such as
+/// `sum(items.subtotal)`, methods taking lambdas such as
+/// `items.all(item => item.inStock)` or the shorthand `items.all(inStock)`,
+/// and list and object literals.
+/// Upper-case names such as `PAID` are enum values.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// Number, string, boolean or `null`
+    Literal(serde_json::Value),
+    /// Enum value, such as `PAID`
+    Constant(String),
+    /// Variable, such as `order` or `quantity`
+    Name(String),
+    /// Property of a value, such as `order.status`
+    Member(Box<Expr>, String),
+    /// Element of a list or property of an object, such as `items[0]`
+    Index(Box<Expr>, Box<Expr>),
+    /// Function call, such as `today()`
+    Call(String, Vec<Expr>),
+    /// Method call, such as `items.all(item => item.inStock)`
+    Method(Box<Expr>, String, Vec<Expr>),
+    /// Lambda argument of a method, such as `item => item.inStock`
+    Lambda(String, Box<Expr>),
+    Unary(UnaryOp, Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    List(Vec<Expr>),
+    Object(Vec<(String, Expr)>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Not,
+    Neg,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    In,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl Expr {
+    /// Parse an expression
+    pub fn parse(expression: &str) -> Result<Expr> {
+        let tokens = tokenize(expression)?;
+        let mut parser = Parser {
+            expression,
+            tokens,
+            position: 0,
+        };
+        let expr = parser.or()?;
+        if let Some(token) = parser.peek() {
+            bail!(
+                "Unexpected `{}` in expression `{}`",
+                token,
+                parser.expression
+            );
+        }
+        Ok(expr)
+    }
+
+    /// Root names the expression reads, outside of lambda parameters
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        self.collect_names(&mut Vec::new(), &mut names);
+        names
+    }
+
+    fn collect_names<'a>(&'a self, bound: &mut Vec<&'a str>, names: &mut Vec<&'a str>) {
+        match self {
+            Expr::Literal(_) | Expr::Constant(_) => {}
+            Expr::Name(name) => {
+                if !bound.contains(&name.as_str()) && !names.contains(&name.as_str()) {
+                    names.push(name);
+                }
+            }
+            Expr::Member(value, _) => value.collect_names(bound, names),
+            Expr::Index(value, index) => {
+                value.collect_names(bound, names);
+                index.collect_names(bound, names);
+            }
+            Expr::Call(_, args) | Expr::List(args) => {
+                for arg in args {
+                    arg.collect_names(bound, names);
+                }
+            }
+            Expr::Method(receiver, _, args) => {
+                receiver.collect_names(bound, names);
+                for arg in args {
+                    arg.collect_names(bound, names);
+                }
+            }
+            Expr::Lambda(param, body) => {
+                bound.push(param);
+                body.collect_names(bound, names);
+                bound.pop();
+            }
+            Expr::Unary(_, value) => value.collect_names(bound, names),
+            Expr::Binary(_, left, right) => {
+                left.collect_names(bound, names);
+                right.collect_names(bound, names);
+            }
+            Expr::Object(fields) => {
+                for (_, value) in fields {
+                    value.collect_names(bound, names);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(serde_json::Number),
+    String(String),
+    Name(String),
+    Symbol(&'static str),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(number) => write!(f, "{}", number),
+            Token::String(text) => write!(f, "{:?}", text),
+            Token::Name(name) => f.write_str(name),
+            Token::Symbol(symbol) => f.write_str(symbol),
+        }
+    }
+}
+
+/// Symbols, longest first so that `<=` is not read as `<`
+const SYMBOLS: &[&str] = &[
+    "=>", "==", "!=", "<=", ">=", "&&", "||", "<", ">", "!", "+", "-", "*", "/", "%", ".", ",",
+    ":", "(", ")", "[", "]", "{", "}",
+];
+
+fn tokenize(expression: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                // A dot not followed by a digit is member access, as in `1.max`
+                if chars[i] == '.' && !chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()) {
+                    break;
+                }
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            match serde_json::from_str::<serde_json::Number>(&text) {
+                Ok(number) => tokens.push(Token::Number(number)),
+                Err(_) => bail!("Invalid number `{}` in expression `{}`", text, expression),
+            }
+        } else if c == '"' || c == '\'' {
+            let mut text = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    None => bail!("Unterminated string in expression `{}`", expression),
+                    Some(&end) if end == c => break,
+                    Some('\\') if i + 1 < chars.len() => {
+                        text.push(chars[i + 1]);
+                        i += 2;
+                    }
+                    Some(&other) => {
+                        text.push(other);
+                        i += 1;
+                    }
+                }
+            }
+            i += 1;
+            tokens.push(Token::String(text));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Name(chars[start..i].iter().collect()));
+        } else {
+            let rest: String = chars[i..chars.len().min(i + 2)].iter().collect();
+            match SYMBOLS.iter().find(|symbol| rest.starts_with(**symbol)) {
+                Some(symbol) => {
+                    tokens.push(Token::Symbol(symbol));
+                    i += symbol.len();
+                }
+                None => bail!("Unexpected `{}` in expression `{}`", c, expression),
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser, one method per precedence level
+struct Parser<'a> {
+    expression: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.position + offset)
+    }
+
+    fn next(&mut self) -> Result<Token> {
+        match self.tokens.get(self.position) {
+            Some(token) => {
+                self.position += 1;
+                Ok(token.clone())
+            }
+            None => bail!("Unexpected end of expression `{}`", self.expression),
+        }
+    }
+
+    /// Consume the symbol or keyword if it comes next
+    fn eat(&mut self, text: &str) -> bool {
+        let found = match self.peek() {
+            Some(Token::Symbol(symbol)) => *symbol == text,
+            Some(Token::Name(name)) => name == text,
+            _ => false,
+        };
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, symbol: &str) -> Result<()> {
+        if self.eat(symbol) {
+            return Ok(());
+        }
+        match self.peek() {
+            Some(token) => bail!(
+                "Expected `{}` but found `{}` in expression `{}`",
+                symbol,
+                token,
+                self.expression
+            ),
+            None => bail!(
+                "Expected `{}` at the end of expression `{}`",
+                symbol,
+                self.expression
+            ),
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr> {
+        let mut left = self.and()?;
+        while self.eat("||") || self.eat("or") {
+            let right = self.and()?;
+            left = Expr::Binary(BinaryOp::Or, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut left = self.not()?;
+        while self.eat("&&") || self.eat("and") {
+            let right = self.not()?;
+            left = Expr::Binary(BinaryOp::And, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn not(&mut self) -> Result<Expr> {
+        if self.eat("not") {
+            return Ok(Expr::Unary(UnaryOp::Not, Box::new(self.not()?)));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Expr> {
+        let left = self.additive()?;
+        let op = match self.peek() {
+            Some(Token::Symbol("==")) => BinaryOp::Eq,
+            Some(Token::Symbol("!=")) => BinaryOp::Ne,
+            Some(Token::Symbol("<")) => BinaryOp::Lt,
+            Some(Token::Symbol("<=")) => BinaryOp::Le,
+            Some(Token::Symbol(">")) => BinaryOp::Gt,
+            Some(Token::Symbol(">=")) => BinaryOp::Ge,
+            Some(Token::Name(name)) if name == "in" => BinaryOp::In,
+            Some(Token::Name(name))
+                if name == "not" && self.peek_at(1) == Some(&Token::Name("in".to_string())) =>
+            {
+                self.position += 2;
+                let right = self.additive()?;
+                let contained = Expr::Binary(BinaryOp::In, Box::new(left), Box::new(right));
+                return Ok(Expr::Unary(UnaryOp::Not, Box::new(contained)));
+            }
+            _ => return Ok(left),
+        };
+        self.position += 1;
+        let right = self.additive()?;
+        Ok(Expr::Binary(op, Box::new(left), Box::new(right)))
+    }
+
+    fn additive(&mut self) -> Result<Expr> {
+        let mut left = self.multiplicative()?;
+        loop {
+            let op = if self.eat("+") {
+                BinaryOp::Add
+            } else if self.eat("-") {
+                BinaryOp::Sub
+            } else {
+                return Ok(left);
+            };
+            let right = self.multiplicative()?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn multiplicative(&mut self) -> Result<Expr> {
+        let mut left = self.unary()?;
+        loop {
+            let op = if self.eat("*") {
+                BinaryOp::Mul
+            } else if self.eat("/") {
+                BinaryOp::Div
+            } else if self.eat("%") {
+                BinaryOp::Rem
+            } else {
+                return Ok(left);
+            };
+            let right = self.unary()?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        if self.eat("-") {
+            return Ok(Expr::Unary(UnaryOp::Neg, Box::new(self.unary()?)));
+        }
+        if self.eat("!") {
+            return Ok(Expr::Unary(UnaryOp::Not, Box::new(self.unary()?)));
+        }
+        self.postfix()
+    }
+
+    fn postfix(&mut self) -> Result<Expr> {
+        let mut value = self.primary()?;
+        loop {
+            if self.eat(".") {
+                let name = match self.next()? {
+                    Token::Name(name) => name,
+                    token => bail!(
+                        "Expected a property name after `.` but found `{}` in expression `{}`",
+                        token,
+                        self.expression
+                    ),
+                };
+                if self.eat("(") {
+                    let mut args = self.arguments()?;
+                    // `items.all(inStock)` is short for `items.all(item => item.inStock)`
+                    if let ([Expr::Name(property)], true) =
+                        (args.as_slice(), LAMBDA_METHODS.contains(&name.as_str()))
+                    {
+                        let item = Box::new(Expr::Name(SHORTHAND_PARAM.to_string()));
+                        let body = Expr::Member(item, property.clone());
+                        args = vec![Expr::Lambda(SHORTHAND_PARAM.to_string(), Box::new(body))];
+                    }
+                    value = Expr::Method(Box::new(value), name, args);
+                } else {
+                    value = Expr::Member(Box::new(value), name);
+                }
+            } else if self.eat("[") {
+                let index = self.or()?;
+                self.expect("]")?;
+                value = Expr::Index(Box::new(value), Box::new(index));
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Arguments of a call, after its opening parenthesis
+    fn arguments(&mut self) -> Result<Vec<Expr>> {
+        let mut args = Vec::new();
+        while !self.eat(")") {
+            if !args.is_empty() {
+                self.expect(",")?;
+            }
+            args.push(self.argument()?);
+        }
+        Ok(args)
+    }
+
+    fn argument(&mut self) -> Result<Expr> {
+        let param = match (
+            self.peek(),
+            self.peek_at(1),
+            self.peek_at(2),
+            self.peek_at(3),
+        ) {
+            (Some(Token::Name(param)), Some(Token::Symbol("=>")), _, _) => {
+                let param = param.clone();
+                self.position += 2;
+                Some(param)
+            }
+            (
+                Some(Token::Symbol("(")),
+                Some(Token::Name(param)),
+                Some(Token::Symbol(")")),
+                Some(Token::Symbol("=>")),
+            ) => {
+                let param = param.clone();
+                self.position += 4;
+                Some(param)
+            }
+            _ => None,
+        };
+        let value = self.or()?;
+        Ok(match param {
+            Some(param) => Expr::Lambda(param, Box::new(value)),
+            None => value,
+        })
+    }
+
+    fn primary(&mut self) -> Result<Expr> {
+        match self.next()? {
+            Token::Number(number) => Ok(Expr::Literal(serde_json::Value::Number(number))),
+            Token::String(text) => Ok(Expr::Literal(serde_json::Value::String(text))),
+            Token::Name(name) => match name.as_str() {
+                "true" => Ok(Expr::Literal(serde_json::Value::Bool(true))),
+                "false" => Ok(Expr::Literal(serde_json::Value::Bool(false))),
+                "null" => Ok(Expr::Literal(serde_json::Value::Null)),
+                _ if self.eat("(") => Ok(Expr::Call(name, self.arguments()?)),
+                _ if is_constant(&name) => Ok(Expr::Constant(name)),
+                _ => Ok(Expr::Name(name)),
+            },
+            Token::Symbol("(") => {
+                let value = self.or()?;
+                self.expect(")")?;
+                Ok(value)
+            }
+            Token::Symbol("[") => {
+                let mut items = Vec::new();
+                while !self.eat("]") {
+                    if !items.is_empty() {
+                        self.expect(",")?;
+                    }
+                    items.push(self.or()?);
+                }
+                Ok(Expr::List(items))
+            }
+            Token::Symbol("{") => {
+                let mut fields = Vec::new();
+                while !self.eat("}") {
+                    if !fields.is_empty() {
+                        self.expect(",")?;
+                    }
+                    let key = match self.next()? {
+                        Token::Name(key) | Token::String(key) => key,
+                        token => bail!(
+                            "Expected a property name but found `{}` in expression `{}`",
+                            token,
+                            self.expression
+                        ),
+                    };
+                    self.expect(":")?;
+                    fields.push((key, self.or()?));
+                }
+                Ok(Expr::Object(fields))
+            }
+            token => bail!("Unexpected `{}` in expression `{}`", token, self.expression),
+        }
+    }
+}
+
+/// Enum values are written in upper case, such as `PAID` or `IN_TRANSIT`
+fn is_constant(name: &str) -> bool {
+    name.chars().any(|c| c.is_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name(name: &str) -> Box<Expr> {
+        Box::new(Expr::Name(name.to_string()))
+    }
+
+    fn member(value: Box<Expr>, name: &str) -> Box<Expr> {
+        Box::new(Expr::Member(value, name.to_string()))
+    }
+
+    #[test]
+    fn test_parse_comparisons_and_enum_values() {
+        assert_eq!(
+            Expr::parse("order.status == CREATED").unwrap(),
+            Expr::Binary(
+                BinaryOp::Eq,
+                member(name("order"), "status"),
+                Box::new(Expr::Constant("CREATED".to_string()))
+            )
+        );
+        assert_eq!(
+            Expr::parse("payment.status in [PENDING, PROCESSING]").unwrap(),
+            Expr::Binary(
+                BinaryOp::In,
+                member(name("payment"), "status"),
+                Box::new(Expr::List(vec![
+                    Expr::Constant("PENDING".to_string()),
+                    Expr::Constant("PROCESSING".to_string())
+                ]))
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        assert_eq!(
+            Expr::parse("a || b && !c").unwrap(),
+            Expr::Binary(
+                BinaryOp::Or,
+                name("a"),
+                Box::new(Expr::Binary(
+                    BinaryOp::And,
+                    name("b"),
+                    Box::new(Expr::Unary(UnaryOp::Not, name("c")))
+                ))
+            )
+        );
+        assert_eq!(
+            Expr::parse("1 + 2 * 3 >= 7").unwrap(),
+            Expr::Binary(
+                BinaryOp::Ge,
+                Box::new(Expr::Binary(
+                    BinaryOp::Add,
+                    Box::new(Expr::Literal(1.into())),
+                    Box::new(Expr::Binary(
+                        BinaryOp::Mul,
+                        Box::new(Expr::Literal(2.into())),
+                        Box::new(Expr::Literal(3.into()))
+                    ))
+                )),
+                Box::new(Expr::Literal(7.into()))
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_calls_methods_and_lambdas() {
+        assert_eq!(
+            Expr::parse("order.items.all(item => item.inStock)").unwrap(),
+            Expr::Method(
+                member(name("order"), "items"),
+                "all".to_string(),
+                vec![Expr::Lambda(
+                    "item".to_string(),
+                    member(name("item"), "inStock")
+                )]
+            )
+        );
+        assert_eq!(
+            Expr::parse("daysBetween(startDate, endDate) <= 30").unwrap(),
+            Expr::Binary(
+                BinaryOp::Le,
+                Box::new(Expr::Call(
+                    "daysBetween".to_string(),
+                    vec![
+                        Expr::Name("startDate".to_string()),
+                        Expr::Name("endDate".to_string())
+                    ]
+                )),
+                Box::new(Expr::Literal(30.into()))
+            )
+        );
+        assert_eq!(
+            Expr::parse("{ id: customer.id, 'full name': 'x' }").unwrap(),
+            Expr::Object(vec![
+                ("id".to_string(), *member(name("customer"), "id")),
+                ("full name".to_string(), Expr::Literal("x".into())),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_shorthand_predicates_read_item_properties() {
+        assert_eq!(
+            Expr::parse("order.items.all(inStock)").unwrap(),
+            Expr::parse("order.items.all(item => item.inStock)").unwrap()
+        );
+        assert_eq!(
+            Expr::parse("items.contains(sku)").unwrap(),
+            Expr::Method(
+                name("items"),
+                "contains".to_string(),
+                vec![Expr::Name("sku".to_string())]
+            )
+        );
+        assert_eq!(
+            Expr::parse("order.items.all(inStock)").unwrap().names(),
+            vec!["order"]
+        );
+    }
+
+    #[test]
+    fn test_names_skip_lambda_parameters() {
+        let expr = Expr::parse("order.items.all(item => item.quantity <= limit)").unwrap();
+        assert_eq!(expr.names(), vec!["order", "limit"]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for expression in ["order.status ==", "a b", "(a", "items.", "a # b", "'open"] {
+            assert!(Expr::parse(expression).is_err(), "{}", expression);
+        }
+    }
+}
@@ -0,0 +1,679 @@
+use anyhow::{bail, Result};
+
+/// Order of the top-level sections
+const TOP_LEVEL: &[&str] = &[
+    "version",
+    "project",
+    "bounded_context",
+    "entities",
+    "aggregates",
+    "value_objects",
+    "events",
+    "commands",
+    "queries",
+    "read_models",
+    "policies",
+    "external_events",
+];
+
+/// Sections whose keys are element names
+const DEFINITION_SECTIONS: &[&str] = &[
+    "entities",
+    "aggregates",
+    "value_objects",
+    "events",
+    "commands",
+    "queries",
+    "read_models",
+];
+
+const BOUNDED_CONTEXT: &[&str] = &["name", "namespace", "description"];
+
+const ELEMENT: &[&str] = &[
+    "id",
+    "name",
+    "description",
+    "entity_type",
+    "aggregate_id",
+    "entity_id",
+    "library_reference",
+    "canvas_element_id",
+    "aggregate",
+    "type",
+    "underlying_type",
+    "format",
+    "prefix",
+    "persistence",
+    "root_entity",
+    "properties",
+    "methods",
+    "values",
+    "value_objects",
+    "invariants",
+    "payload",
+    "produces",
+    "validation",
+    "preconditions",
+    "parameters",
+    "returns",
+    "sources",
+    "fields",
+    "updated_by_events",
+];
+
+const PROPERTY: &[&str] = &[
+    "id",
+    "name",
+    "type",
+    "identifier",
+    "required",
+    "read_only",
+    "default",
+    "computed",
+    "description",
+    "source",
+    "transformation",
+    "validation",
+];
+
+const VALIDATION: &[&str] = &[
+    "min",
+    "max",
+    "minLength",
+    "maxLength",
+    "pattern",
+    "precision",
+];
+
+const RETURNS: &[&str] = &["type", "nullable"];
+
+const SAGA: &[&str] = &["completed_by", "timeout_seconds", "compensation"];
+
+/// Policies, conditions, enum values and subscriptions
+const ITEM: &[&str] = &[
+    "id",
+    "name",
+    "description",
+    "method_type",
+    "return_type",
+    "canvas_element_id",
+    "context",
+    "event",
+    "handler",
+    "expression",
+    "message",
+    "error_message",
+    "enabled",
+    "triggers",
+    "actions",
+    "conditions",
+    "saga",
+    "parameters",
+    "visibility",
+];
+
+/// Canonical formatter for IR files
+///
+/// Keys are sorted into the order of the IR specification, strings are
+/// double-quoted, indentation is two spaces and blank lines separate the
+/// top-level sections and element definitions. Comments stay attached to
+/// the entry below them. Only block-style YAML is restructured; flow
+/// collections and block scalars are kept as written.
+pub struct IRFormatter;
+
+impl IRFormatter {
+    pub fn format(text: &str) -> Result<String> {
+        let mut parser = Parser::new(text)?;
+        let header = parser.take_comments(parser.next_content(0).unwrap_or(parser.lines.len()));
+        let root = parser.mapping(0)?;
+        if let Some(index) = parser.next_content(parser.pos) {
+            bail!("Unexpected indentation at line {}", index + 1);
+        }
+        let footer = parser.take_comments(parser.lines.len());
+
+        let mut out = String::new();
+        for comment in &header {
+            out.push_str(comment);
+            out.push('\n');
+        }
+        if !header.is_empty() {
+            out.push('\n');
+        }
+        if let Value::Mapping(entries) = root {
+            write_entries(&mut out, entries, 0, &mut Vec::new(), false);
+        }
+        if !footer.is_empty() {
+            out.push('\n');
+            for comment in &footer {
+                out.push_str(comment);
+                out.push('\n');
+            }
+        }
+
+        // Reordering keys and requoting must not change what the file says
+        let before: serde_yaml::Value = serde_yaml::from_str(text)?;
+        let after: serde_yaml::Value = serde_yaml::from_str(&out)?;
+        if before != after {
+            bail!("Formatting would change the meaning of the file");
+        }
+        Ok(out)
+    }
+}
+
+struct Line {
+    indent: usize,
+    /// Content without indentation or trailing comment
+    text: String,
+    comment: Option<String>,
+    raw: String,
+}
+
+enum Value {
+    Null,
+    Scalar(String),
+    /// Header such as `>` or `|-` and the lines without their common indent
+    Block(String, Vec<String>),
+    Mapping(Vec<Entry>),
+    Sequence(Vec<Item>),
+}
+
+struct Entry {
+    comments: Vec<String>,
+    key: String,
+    value: Value,
+    comment: Option<String>,
+}
+
+struct Item {
+    comments: Vec<String>,
+    value: Value,
+    comment: Option<String>,
+}
+
+struct Parser {
+    lines: Vec<Line>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(text: &str) -> Result<Self> {
+        let mut lines = Vec::new();
+        for (number, raw) in text.lines().enumerate() {
+            let trimmed = raw.trim_start_matches(' ');
+            if trimmed.starts_with('\t') {
+                bail!("Tab indentation at line {}", number + 1);
+            }
+            let (text, comment) = split_comment(trimmed);
+            lines.push(Line {
+                indent: raw.len() - trimmed.len(),
+                text: text.to_string(),
+                comment: comment.map(str::to_string),
+                raw: raw.to_string(),
+            });
+        }
+        if lines
+            .iter()
+            .any(|line| line.text == "---" || line.text == "...")
+        {
+            bail!("Multi-document files are not supported");
+        }
+        Ok(Self { lines, pos: 0 })
+    }
+
+    fn next_content(&self, from: usize) -> Option<usize> {
+        (from..self.lines.len()).find(|&index| !self.lines[index].text.is_empty())
+    }
+
+    /// Comment lines up to a line, dropping blank lines
+    fn take_comments(&mut self, until: usize) -> Vec<String> {
+        let comments = self.lines[self.pos..until]
+            .iter()
+            .filter_map(|line| line.comment.clone())
+            .collect();
+        self.pos = until;
+        comments
+    }
+
+    /// Value of a key whose own line is at `indent`
+    fn nested(&mut self, indent: usize) -> Result<Value> {
+        let Some(index) = self.next_content(self.pos) else {
+            return Ok(Value::Null);
+        };
+        let line = &self.lines[index];
+        if line.indent == indent && is_item(&line.text) {
+            // Sequence written at the same indentation as its key
+            return self.sequence(indent);
+        }
+        if line.indent <= indent {
+            return Ok(Value::Null);
+        }
+        if is_item(&line.text) {
+            self.sequence(line.indent)
+        } else {
+            self.mapping(line.indent)
+        }
+    }
+
+    fn mapping(&mut self, indent: usize) -> Result<Value> {
+        let mut entries = Vec::new();
+        while let Some(index) = self.next_content(self.pos) {
+            let line = &self.lines[index];
+            if line.indent != indent || is_item(&line.text) {
+                if line.indent > indent {
+                    bail!("Unexpected indentation at line {}", index + 1);
+                }
+                break;
+            }
+            let Some((key, rest)) = split_key(&line.text) else {
+                bail!("Expected `key: value` at line {}", index + 1);
+            };
+            let (key, rest) = (key.to_string(), rest.to_string());
+            let comment = line.comment.clone();
+            let comments = self.take_comments(index);
+            self.pos = index + 1;
+
+            let value = if rest.is_empty() {
+                self.nested(indent)?
+            } else if rest.starts_with(['|', '>']) {
+                self.block(indent, rest)
+            } else {
+                scalar(&rest, index)?
+            };
+            entries.push(Entry {
+                comments,
+                key: normalize_key(&key),
+                value,
+                comment,
+            });
+        }
+        Ok(Value::Mapping(entries))
+    }
+
+    fn sequence(&mut self, indent: usize) -> Result<Value> {
+        let mut items = Vec::new();
+        while let Some(index) = self.next_content(self.pos) {
+            let line = &self.lines[index];
+            if line.indent != indent || !is_item(&line.text) {
+                if line.indent > indent {
+                    bail!("Unexpected indentation at line {}", index + 1);
+                }
+                break;
+            }
+            let comments = self.take_comments(index);
+            let text = self.lines[index].text.clone();
+            let rest = text[1..].trim_start();
+
+            let (value, comment) = if rest.is_empty() {
+                self.pos = index + 1;
+                (self.nested(indent)?, self.lines[index].comment.clone())
+            } else if is_item(rest) || split_key(rest).is_some() {
+                // The item's content continues as if the dash were indentation
+                let line = &mut self.lines[index];
+                line.indent += text.len() - rest.len();
+                line.text = rest.to_string();
+                let inner = line.indent;
+                let value = if is_item(rest) {
+                    self.sequence(inner)?
+                } else {
+                    self.mapping(inner)?
+                };
+                (value, None)
+            } else if rest.starts_with(['|', '>']) {
+                self.pos = index + 1;
+                (
+                    self.block(indent, rest.to_string()),
+                    self.lines[index].comment.clone(),
+                )
+            } else {
+                self.pos = index + 1;
+                (scalar(rest, index)?, self.lines[index].comment.clone())
+            };
+            items.push(Item {
+                comments,
+                value,
+                comment,
+            });
+        }
+        Ok(Value::Sequence(items))
+    }
+
+    fn block(&mut self, indent: usize, header: String) -> Value {
+        let start = self.pos;
+        while self.pos < self.lines.len() {
+            let line = &self.lines[self.pos];
+            if !line.raw.trim().is_empty() && line.indent <= indent {
+                break;
+            }
+            self.pos += 1;
+        }
+        // Trailing blank lines separate what follows
+        while self.pos > start && self.lines[self.pos - 1].raw.trim().is_empty() {
+            self.pos -= 1;
+        }
+        let lines = &self.lines[start..self.pos];
+        let base = lines
+            .iter()
+            .filter(|line| !line.raw.trim().is_empty())
+            .map(|line| line.indent)
+            .min()
+            .unwrap_or(0);
+        let body = lines
+            .iter()
+            .map(|line| line.raw.get(base..).unwrap_or("").trim_end().to_string())
+            .collect();
+        Value::Block(header, body)
+    }
+}
+
+fn write_entries(
+    out: &mut String,
+    mut entries: Vec<Entry>,
+    indent: usize,
+    path: &mut Vec<String>,
+    dash: bool,
+) {
+    if let Some(order) = key_order(path) {
+        // Stable, so unknown keys keep their relative order after known ones
+        entries.sort_by_key(|entry| {
+            order
+                .iter()
+                .position(|key| *key == entry.key)
+                .unwrap_or(order.len())
+        });
+    }
+    let spaced =
+        path.is_empty() || (path.len() == 1 && DEFINITION_SECTIONS.contains(&path[0].as_str()));
+    let pad = " ".repeat(indent);
+
+    for (index, entry) in entries.into_iter().enumerate() {
+        if spaced && index > 0 {
+            out.push('\n');
+        }
+        for comment in &entry.comments {
+            out.push_str(&pad);
+            out.push_str(comment);
+            out.push('\n');
+        }
+        if dash && index == 0 {
+            out.push_str(&" ".repeat(indent - 2));
+            out.push_str("- ");
+        } else {
+            out.push_str(&pad);
+        }
+        out.push_str(&entry.key);
+        out.push(':');
+        path.push(entry.key);
+        write_value(out, entry.value, entry.comment, indent, path);
+        path.pop();
+    }
+}
+
+fn write_items(out: &mut String, items: Vec<Item>, indent: usize, path: &mut Vec<String>) {
+    let pad = " ".repeat(indent);
+    path.push("-".to_string());
+    for item in items {
+        for comment in &item.comments {
+            out.push_str(&pad);
+            out.push_str(comment);
+            out.push('\n');
+        }
+        match item.value {
+            Value::Mapping(entries) if !entries.is_empty() => {
+                write_entries(out, entries, indent + 2, path, true);
+            }
+            value => {
+                out.push_str(&pad);
+                out.push('-');
+                write_value(out, value, item.comment, indent, path);
+            }
+        }
+    }
+    path.pop();
+}
+
+/// Value after `key:` or `-`, then the lines it spans
+fn write_value(
+    out: &mut String,
+    value: Value,
+    comment: Option<String>,
+    indent: usize,
+    path: &mut Vec<String>,
+) {
+    let end_line = |out: &mut String| {
+        if let Some(comment) = &comment {
+            out.push_str("  ");
+            out.push_str(comment);
+        }
+        out.push('\n');
+    };
+    match value {
+        Value::Null => end_line(out),
+        Value::Scalar(scalar) => {
+            out.push(' ');
+            out.push_str(&scalar);
+            end_line(out);
+        }
+        Value::Block(header, lines) => {
+            out.push(' ');
+            out.push_str(&header);
+            end_line(out);
+            let pad = " ".repeat(indent + 2);
+            for line in lines {
+                if !line.is_empty() {
+                    out.push_str(&pad);
+                    out.push_str(&line);
+                }
+                out.push('\n');
+            }
+        }
+        Value::Mapping(entries) => {
+            end_line(out);
+            write_entries(out, entries, indent + 2, path, false);
+        }
+        Value::Sequence(items) => {
+            end_line(out);
+            write_items(out, items, indent + 2, path);
+        }
+    }
+}
+
+/// Canonical key order of the mapping at a path, if it has one
+fn key_order(path: &[String]) -> Option<&'static [&'static str]> {
+    let path: Vec<&str> = path.iter().map(String::as_str).collect();
+    match path.as_slice() {
+        [] => Some(TOP_LEVEL),
+        ["bounded_context"] => Some(BOUNDED_CONTEXT),
+        [section, _] if DEFINITION_SECTIONS.contains(section) => Some(ELEMENT),
+        [.., "properties" | "payload" | "parameters" | "fields", "-"] => Some(PROPERTY),
+        [.., "root_entity"] => Some(ELEMENT),
+        [.., "validation"] => Some(VALIDATION),
+        [.., "returns"] => Some(RETURNS),
+        [.., "saga"] => Some(SAGA),
+        [.., "-"] => Some(ITEM),
+        _ => None,
+    }
+}
+
+fn is_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// Split `key: value`, where the value may be empty
+fn split_key(text: &str) -> Option<(&str, &str)> {
+    let end = if text.starts_with(['"', '\'']) {
+        let quote = text.as_bytes()[0] as char;
+        let close = text[1..].find(quote)? + 1;
+        text[close + 1..].starts_with(':').then_some(close + 1)?
+    } else {
+        if text.starts_with(['[', '{']) {
+            return None;
+        }
+        text.match_indices(':')
+            .map(|(index, _)| index)
+            .find(|&index| text[index + 1..].is_empty() || text[index + 1..].starts_with(' '))?
+    };
+    Some((&text[..end], text[end + 1..].trim()))
+}
+
+/// Content and `#` comment of a line, ignoring `#` inside quotes
+fn split_comment(text: &str) -> (&str, Option<&str>) {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (index, ch) in text.char_indices() {
+        match (quote, ch) {
+            (None, '"' | '\'') if previous == ' ' || previous == ':' || index == 0 => {
+                quote = Some(ch)
+            }
+            (Some('"'), '\\') => {}
+            (Some(open), _) if ch == open && previous != '\\' => quote = None,
+            (None, '#') if previous == ' ' => {
+                return (text[..index].trim_end(), Some(&text[index..]));
+            }
+            _ => {}
+        }
+        previous = ch;
+    }
+    (text.trim_end(), None)
+}
+
+fn normalize_key(key: &str) -> String {
+    let unquoted = key.trim_matches(['"', '\'']);
+    if unquoted != key
+        && !unquoted.is_empty()
+        && unquoted
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$')
+        && plain_scalar(unquoted)
+    {
+        unquoted.to_string()
+    } else {
+        key.to_string()
+    }
+}
+
+/// Scalar spelled the way IR files spell them: strings in double quotes
+fn scalar(text: &str, index: usize) -> Result<Value> {
+    if let Some(inner) = text.strip_prefix('\'') {
+        let Some(inner) = inner.strip_suffix('\'') else {
+            bail!("Multi-line string at line {}", index + 1);
+        };
+        return Ok(Value::Scalar(quote(&inner.replace("''", "'"))));
+    }
+    if text.starts_with('"') {
+        if text.len() < 2 || !text.ends_with('"') || text.ends_with("\\\"") {
+            bail!("Multi-line string at line {}", index + 1);
+        }
+        return Ok(Value::Scalar(text.to_string()));
+    }
+    if text.starts_with(['[', '{']) {
+        let opened = text.matches(['[', '{']).count();
+        let closed = text.matches([']', '}']).count();
+        if opened != closed {
+            bail!("Multi-line flow collection at line {}", index + 1);
+        }
+        return Ok(Value::Scalar(text.to_string()));
+    }
+    if text.starts_with(['&', '*', '!']) || !plain_scalar(text) {
+        return Ok(Value::Scalar(text.to_string()));
+    }
+    Ok(Value::Scalar(quote(text)))
+}
+
+/// Whether a plain scalar is a string rather than a number, bool or null
+fn plain_scalar(text: &str) -> bool {
+    let special = matches!(
+        text,
+        "true"
+            | "True"
+            | "TRUE"
+            | "false"
+            | "False"
+            | "FALSE"
+            | "null"
+            | "Null"
+            | "NULL"
+            | "~"
+            | ".inf"
+            | "-.inf"
+            | ".nan"
+    );
+    let numeric = text
+        .trim_start_matches(['+', '-'])
+        .starts_with(|ch: char| ch.is_ascii_digit() || ch == '.')
+        && (text.parse::<f64>().is_ok() || text.starts_with("0x") || text.starts_with("0o"));
+    !(special || numeric)
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_orders_keys_and_keeps_comments() {
+        let input = r#"# Order context
+bounded_context:
+    namespace: acme.order
+    name: 'Order'
+version: "1.0"
+events:
+  # Raised on checkout
+  OrderPlaced:
+      payload:
+      - type: OrderId   # identifier
+        name: orderId
+      name: OrderPlaced
+      description: >
+        Raised once an order
+        has been placed
+  OrderPaid: {name: "OrderPaid"}
+"#;
+        let expected = r#"# Order context
+
+version: "1.0"
+
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+
+events:
+  # Raised on checkout
+  OrderPlaced:
+    name: "OrderPlaced"
+    description: >
+      Raised once an order
+      has been placed
+    payload:
+      - name: "orderId"
+        type: "OrderId"  # identifier
+
+  OrderPaid: {name: "OrderPaid"}
+"#;
+        let formatted = IRFormatter::format(input).unwrap();
+        assert_eq!(formatted, expected);
+        assert_eq!(IRFormatter::format(&formatted).unwrap(), formatted);
+    }
+
+    #[test]
+    fn test_format_examples_is_stable() {
+        let examples = concat!(env!("CARGO_MANIFEST_DIR"), "/../ir_schema/examples");
+        for entry in walkdir::WalkDir::new(examples) {
+            let path = entry.unwrap().into_path();
+            if path.extension().is_none_or(|ext| ext != "yaml") {
+                continue;
+            }
+            let text = std::fs::read_to_string(&path).unwrap();
+            let formatted = IRFormatter::format(&text)
+                .unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+            assert_eq!(
+                IRFormatter::format(&formatted).unwrap(),
+                formatted,
+                "{}",
+                path.display()
+            );
+        }
+    }
+}
@@ -0,0 +1,524 @@
+use super::project::IRProject;
+use super::types::{IRModel, Property};
+use crate::generators::utils::referenced_type;
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Names of the lint rules
+pub const RULES: &[&str] = &[
+    "pascal-case",
+    "past-tense-events",
+    "imperative-commands",
+    "property-case",
+    "descriptions",
+    "unused-value-objects",
+];
+
+/// Irregular past participles that do not end in `ed`
+const IRREGULAR_PAST: &[&str] = &[
+    "Begun",
+    "Bought",
+    "Brought",
+    "Built",
+    "Chosen",
+    "Cut",
+    "Done",
+    "Drawn",
+    "Found",
+    "Frozen",
+    "Given",
+    "Gone",
+    "Held",
+    "Hidden",
+    "Kept",
+    "Known",
+    "Laid",
+    "Left",
+    "Lost",
+    "Made",
+    "Met",
+    "Overridden",
+    "Paid",
+    "Put",
+    "Read",
+    "Rebuilt",
+    "Reset",
+    "Run",
+    "Seen",
+    "Sent",
+    "Set",
+    "Shown",
+    "Shut",
+    "Sold",
+    "Spent",
+    "Split",
+    "Taken",
+    "Told",
+    "Undone",
+    "Upheld",
+    "Withdrawn",
+    "Won",
+    "Written",
+];
+
+/// What a rule finding amounts to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Off,
+    #[serde(alias = "warn")]
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Level::Off => "off",
+            Level::Warning => "warning",
+            Level::Error => "error",
+        })
+    }
+}
+
+/// Naming style of property names
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum PropertyCase {
+    /// `customerId`, as in the IR specification
+    #[default]
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    /// `customer_id`
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+}
+
+/// `lint` section of `stormforge.yaml`
+///
+/// ```yaml
+/// lint:
+///   property_case: snake_case
+///   rules:
+///     descriptions: error
+///     unused-value-objects: off
+/// ```
+///
+/// Rules that are not listed are warnings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LintConfig {
+    pub property_case: PropertyCase,
+    pub rules: IndexMap<String, Level>,
+}
+
+impl LintConfig {
+    fn level(&self, rule: &str) -> Level {
+        self.rules.get(rule).copied().unwrap_or(Level::Warning)
+    }
+}
+
+/// One rule violation
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub rule: &'static str,
+    pub level: Level,
+    pub context: String,
+    /// Dotted path of the element, e.g. `events.OrderCreate`
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}] {}: {}: {}",
+            self.level, self.rule, self.context, self.path, self.message
+        )
+    }
+}
+
+/// Style linter for the contexts of a project
+pub struct Linter {
+    config: LintConfig,
+}
+
+impl Linter {
+    pub fn new(config: LintConfig) -> Result<Self> {
+        for rule in config.rules.keys() {
+            if !RULES.contains(&rule.as_str()) {
+                bail!(
+                    "Unknown lint rule '{}' (expected one of: {})",
+                    rule,
+                    RULES.join(", ")
+                );
+            }
+        }
+        Ok(Self { config })
+    }
+
+    pub fn lint(&self, project: &IRProject) -> Vec<Finding> {
+        let used = used_types(project);
+        let mut findings = Vec::new();
+        for model in &project.contexts {
+            let mut lint = ContextLint {
+                config: &self.config,
+                context: &model.bounded_context.name,
+                findings: &mut findings,
+            };
+            lint.names(model);
+            lint.properties(model);
+            lint.descriptions(model);
+            for name in model.value_objects.keys() {
+                if !used.contains(name.as_str()) {
+                    lint.report(
+                        "unused-value-objects",
+                        format!("value_objects.{}", name),
+                        format!("value object `{}` is never used", name),
+                    );
+                }
+            }
+        }
+        findings
+    }
+}
+
+struct ContextLint<'a> {
+    config: &'a LintConfig,
+    context: &'a str,
+    findings: &'a mut Vec<Finding>,
+}
+
+impl ContextLint<'_> {
+    fn report(&mut self, rule: &'static str, path: String, message: String) {
+        let level = self.config.level(rule);
+        if level != Level::Off {
+            self.findings.push(Finding {
+                rule,
+                level,
+                context: self.context.to_string(),
+                path,
+                message,
+            });
+        }
+    }
+
+    fn names(&mut self, model: &IRModel) {
+        let sections = [
+            ("aggregates", "aggregate", keys(&model.aggregates)),
+            ("value_objects", "value object", keys(&model.value_objects)),
+            ("commands", "command", keys(&model.commands)),
+            ("events", "event", keys(&model.events)),
+            ("queries", "query", keys(&model.queries)),
+            ("read_models", "read model", keys(&model.read_models)),
+            (
+                "policies",
+                "policy",
+                model.policies.iter().map(|p| p.name.as_str()).collect(),
+            ),
+        ];
+        for (section, kind, names) in sections {
+            for name in names {
+                if !is_pascal_case(name) {
+                    self.report(
+                        "pascal-case",
+                        format!("{}.{}", section, name),
+                        format!("{} `{}` is not PascalCase", kind, name),
+                    );
+                }
+            }
+        }
+
+        for name in model.events.keys() {
+            let last = words(name).pop().unwrap_or_default();
+            if !is_past_tense(last) {
+                self.report(
+                    "past-tense-events",
+                    format!("events.{}", name),
+                    format!("event `{}` should be named in the past tense", name),
+                );
+            }
+        }
+
+        for (name, command) in &model.commands {
+            let first = words(name).first().copied().unwrap_or_default();
+            let noun_first = command.aggregate.as_deref() == Some(first);
+            if noun_first || is_past_tense(first) || first.ends_with("ing") {
+                self.report(
+                    "imperative-commands",
+                    format!("commands.{}", name),
+                    format!("command `{}` should start with an imperative verb", name),
+                );
+            }
+        }
+    }
+
+    fn properties(&mut self, model: &IRModel) {
+        let mut groups: Vec<(String, &[Property])> = Vec::new();
+        for (name, aggregate) in &model.aggregates {
+            groups.push((
+                format!("aggregates.{}", name),
+                &aggregate.root_entity.properties,
+            ));
+        }
+        for (name, vo) in &model.value_objects {
+            groups.push((format!("value_objects.{}", name), &vo.properties));
+        }
+        for (name, command) in &model.commands {
+            groups.push((format!("commands.{}.payload", name), &command.payload));
+        }
+        for (name, event) in &model.events {
+            groups.push((format!("events.{}.payload", name), &event.payload));
+        }
+        for (name, query) in &model.queries {
+            groups.push((format!("queries.{}.parameters", name), &query.parameters));
+        }
+
+        let mut names: Vec<(String, &str)> = groups
+            .into_iter()
+            .flat_map(|(path, properties)| {
+                properties
+                    .iter()
+                    .map(move |property| (path.clone(), property.name.as_str()))
+            })
+            .collect();
+        for (name, read_model) in &model.read_models {
+            for field in &read_model.fields {
+                names.push((format!("read_models.{}.fields", name), &field.name));
+            }
+        }
+
+        let case = self.config.property_case;
+        for (path, name) in names {
+            let (valid, style) = match case {
+                PropertyCase::CamelCase => (is_camel_case(name), "camelCase"),
+                PropertyCase::SnakeCase => (is_snake_case(name), "snake_case"),
+            };
+            if !valid {
+                self.report(
+                    "property-case",
+                    format!("{}.{}", path, name),
+                    format!("property `{}` is not {}", name, style),
+                );
+            }
+        }
+    }
+
+    fn descriptions(&mut self, model: &IRModel) {
+        let mut elements: Vec<(String, &Option<String>)> = Vec::new();
+        elements.extend(
+            model
+                .aggregates
+                .iter()
+                .map(|(name, e)| (format!("aggregates.{}", name), &e.description)),
+        );
+        elements.extend(
+            model
+                .value_objects
+                .iter()
+                .map(|(name, e)| (format!("value_objects.{}", name), &e.description)),
+        );
+        elements.extend(
+            model
+                .commands
+                .iter()
+                .map(|(name, e)| (format!("commands.{}", name), &e.description)),
+        );
+        elements.extend(
+            model
+                .events
+                .iter()
+                .map(|(name, e)| (format!("events.{}", name), &e.description)),
+        );
+        elements.extend(
+            model
+                .queries
+                .iter()
+                .map(|(name, e)| (format!("queries.{}", name), &e.description)),
+        );
+        elements.extend(
+            model
+                .read_models
+                .iter()
+                .map(|(name, e)| (format!("read_models.{}", name), &e.description)),
+        );
+        elements.extend(
+            model
+                .policies
+                .iter()
+                .map(|e| (format!("policies.{}", e.name), &e.description)),
+        );
+
+        for (path, description) in elements {
+            if description
+                .as_deref()
+                .is_none_or(|text| text.trim().is_empty())
+            {
+                self.report("descriptions", path, "missing description".to_string());
+            }
+        }
+    }
+}
+
+/// Domain types referenced anywhere in the project
+fn used_types(project: &IRProject) -> HashSet<String> {
+    let mut types: Vec<&str> = Vec::new();
+    for model in &project.contexts {
+        let properties = model
+            .aggregates
+            .values()
+            .flat_map(|a| &a.root_entity.properties)
+            .chain(model.value_objects.values().flat_map(|v| &v.properties))
+            .chain(model.commands.values().flat_map(|c| &c.payload))
+            .chain(model.events.values().flat_map(|e| &e.payload))
+            .chain(model.queries.values().flat_map(|q| &q.parameters));
+        types.extend(properties.map(|property| property.prop_type.as_str()));
+        types.extend(
+            model
+                .queries
+                .values()
+                .filter_map(|q| q.returns.as_ref())
+                .map(|returns| returns.return_type.as_str()),
+        );
+        types.extend(
+            model
+                .read_models
+                .values()
+                .flat_map(|r| &r.fields)
+                .filter_map(|field| field.field_type.as_deref()),
+        );
+    }
+    types.into_iter().filter_map(referenced_type).collect()
+}
+
+fn keys<T>(map: &IndexMap<String, T>) -> Vec<&str> {
+    map.keys().map(String::as_str).collect()
+}
+
+/// Words of a PascalCase name
+fn words(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    for (index, ch) in name.char_indices().skip(1) {
+        if ch.is_ascii_uppercase() {
+            words.push(&name[start..index]);
+            start = index;
+        }
+    }
+    words.push(&name[start..]);
+    words
+}
+
+fn is_past_tense(word: &str) -> bool {
+    word.len() > 2 && word.ends_with("ed") || IRREGULAR_PAST.contains(&word)
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|ch: char| ch.is_ascii_uppercase())
+        && name.chars().all(|ch| ch.is_ascii_alphanumeric())
+}
+
+fn is_camel_case(name: &str) -> bool {
+    name.starts_with(|ch: char| ch.is_ascii_lowercase())
+        && name.chars().all(|ch| ch.is_ascii_alphanumeric())
+}
+
+fn is_snake_case(name: &str) -> bool {
+    name.starts_with(|ch: char| ch.is_ascii_lowercase())
+        && !name.contains("__")
+        && !name.ends_with('_')
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    const MODEL: &str = r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+value_objects:
+  OrderId:
+    name: "OrderId"
+    description: "Order identifier"
+    type: "identifier"
+  Coupon:
+    name: "Coupon"
+    description: "Unused"
+aggregates:
+  Order:
+    name: "Order"
+    description: "Order aggregate"
+    root_entity:
+      name: "Order"
+      properties:
+        - name: "id"
+          type: "OrderId"
+        - name: "total_amount"
+          type: "Decimal"
+commands:
+  OrderCreate:
+    name: "OrderCreate"
+    description: "Create an order"
+    aggregate: "Order"
+  ShipOrder:
+    name: "ShipOrder"
+    aggregate: "Order"
+events:
+  OrderCreate:
+    name: "OrderCreate"
+    description: "Order created"
+  OrderPaid:
+    name: "OrderPaid"
+    description: "Order paid"
+"#;
+
+    fn lint(config: &str) -> Vec<String> {
+        let config: LintConfig = serde_yaml::from_str(config).unwrap();
+        let project = IRProject {
+            contexts: vec![IRParser::parse_yaml(MODEL).unwrap()],
+        };
+        Linter::new(config)
+            .unwrap()
+            .lint(&project)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_default_rules() {
+        assert_eq!(
+            lint("{}"),
+            [
+                "warning[past-tense-events] Order: events.OrderCreate: event `OrderCreate` should be named in the past tense",
+                "warning[imperative-commands] Order: commands.OrderCreate: command `OrderCreate` should start with an imperative verb",
+                "warning[property-case] Order: aggregates.Order.total_amount: property `total_amount` is not camelCase",
+                "warning[descriptions] Order: commands.ShipOrder: missing description",
+                "warning[unused-value-objects] Order: value_objects.Coupon: value object `Coupon` is never used",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_configured_rules() {
+        let findings = lint(
+            "property_case: snake_case\nrules:\n  descriptions: error\n  past-tense-events: off\n  imperative-commands: off\n  unused-value-objects: off\n",
+        );
+        assert_eq!(
+            findings,
+            ["error[descriptions] Order: commands.ShipOrder: missing description",]
+        );
+
+        let config: LintConfig = serde_yaml::from_str("rules:\n  no-such-rule: error\n").unwrap();
+        assert!(Linter::new(config).is_err());
+    }
+}
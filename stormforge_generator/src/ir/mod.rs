@@ -1,5 +1,19 @@
+pub mod cml;
+pub mod config;
+pub mod diff;
+pub mod expression;
+pub mod format;
+pub mod lint;
 pub mod parser;
+pub mod project;
 pub mod types;
 
+pub use cml::{Cml, CmlExport, CmlImport, Untranslated};
+pub use config::ProjectConfig;
+pub use diff::{Change, IRDiff, Severity};
+pub use expression::{BinaryOp, Expr, UnaryOp};
+pub use format::IRFormatter;
+pub use lint::{LintConfig, Linter};
 pub use parser::IRParser;
+pub use project::IRProject;
 pub use types::*;
@@ -1,7 +1,10 @@
-use super::types::IRModel;
+use super::config::CONFIG_FILE;
+use super::project::IRProject;
+use super::types::{IRModel, Persistence};
 use anyhow::{Context, Result};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
 
 /// IR Parser for loading and validating IR YAML files
 pub struct IRParser;
@@ -17,6 +20,62 @@ impl IRParser {
             .with_context(|| format!("Failed to parse IR file: {}", path.display()))
     }
 
+    /// Parse several IR files into a multi-context project
+    ///
+    /// Directories are searched recursively for `.yaml`/`.yml` files.
+    pub fn parse_project<P: AsRef<Path>>(inputs: &[P]) -> Result<IRProject> {
+        let mut project = IRProject::default();
+
+        for path in Self::collect_files(inputs)? {
+            let model = Self::parse_file(&path)?;
+            if project.context(&model.bounded_context.name).is_some() {
+                anyhow::bail!(
+                    "Duplicate bounded context '{}' in {}",
+                    model.bounded_context.name,
+                    path.display()
+                );
+            }
+            project.contexts.push(model);
+        }
+
+        if project.contexts.is_empty() {
+            anyhow::bail!("No IR files found");
+        }
+
+        Ok(project)
+    }
+
+    /// IR files among files and directories
+    ///
+    /// Directories are searched recursively for `.yaml`/`.yml` files, other
+    /// than the project's `stormforge.yaml`.
+    pub fn collect_files<P: AsRef<Path>>(inputs: &[P]) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+
+        for input in inputs {
+            let input = input.as_ref();
+            if !input.is_dir() {
+                files.push(input.to_path_buf());
+                continue;
+            }
+
+            let mut found: Vec<PathBuf> = WalkDir::new(input)
+                .into_iter()
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.into_path())
+                .filter(|path| {
+                    path.extension()
+                        .is_some_and(|ext| ext == "yaml" || ext == "yml")
+                        && path.file_name().is_some_and(|name| name != CONFIG_FILE)
+                })
+                .collect();
+            found.sort();
+            files.extend(found);
+        }
+
+        Ok(files)
+    }
+
     /// Parse an IR model from YAML string
     pub fn parse_yaml(yaml: &str) -> Result<IRModel> {
         let model: IRModel =
@@ -44,6 +103,22 @@ impl IRParser {
             anyhow::bail!("Bounded context namespace cannot be empty");
         }
 
+        // State-stored aggregates are keyed by their identifier
+        for (name, aggregate) in &model.aggregates {
+            if aggregate.persistence == Persistence::State
+                && !aggregate
+                    .root_entity
+                    .properties
+                    .iter()
+                    .any(|prop| prop.identifier)
+            {
+                anyhow::bail!(
+                    "Aggregate '{}' is stored as state but has no identifier property",
+                    name
+                );
+            }
+        }
+
         Ok(())
     }
 }
@@ -70,4 +145,60 @@ bounded_context:
         assert_eq!(model.bounded_context.name, "Test");
         assert_eq!(model.bounded_context.namespace, "test.context");
     }
+
+    #[test]
+    fn test_parse_preserves_declaration_order() {
+        let yaml = r#"
+version: "1.0"
+bounded_context:
+  name: "Test"
+  namespace: "test.context"
+events:
+  Zeta:
+    name: "Zeta"
+  Alpha:
+    name: "Alpha"
+  Mid:
+    name: "Mid"
+commands:
+  Second:
+    name: "Second"
+  First:
+    name: "First"
+"#;
+
+        let model = IRParser::parse_yaml(yaml).unwrap();
+        let events: Vec<&str> = model.events.keys().map(String::as_str).collect();
+        assert_eq!(events, vec!["Zeta", "Alpha", "Mid"]);
+        let commands: Vec<&str> = model.commands.keys().map(String::as_str).collect();
+        assert_eq!(commands, vec!["Second", "First"]);
+    }
+
+    #[test]
+    fn test_state_stored_aggregate_needs_identifier() {
+        let yaml = r#"
+version: "1.0"
+bounded_context:
+  name: "Test"
+  namespace: "test.context"
+aggregates:
+  Note:
+    name: "Note"
+    persistence: "state"
+    root_entity:
+      name: "Note"
+      properties:
+        - name: "text"
+          type: "String"
+"#;
+
+        let err = IRParser::parse_yaml(yaml).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("is stored as state but has no identifier property"));
+
+        let event_sourced =
+            yaml.replace("persistence: \"state\"", "persistence: \"event_sourced\"");
+        assert!(IRParser::parse_yaml(&event_sourced).is_ok());
+    }
 }
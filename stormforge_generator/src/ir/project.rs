@@ -0,0 +1,22 @@
+use super::types::{Event, IRModel};
+
+/// A set of bounded contexts that are modeled and generated together
+#[derive(Debug, Clone, Default)]
+pub struct IRProject {
+    pub contexts: Vec<IRModel>,
+}
+
+impl IRProject {
+    /// Find a bounded context by name
+    pub fn context(&self, name: &str) -> Option<&IRModel> {
+        self.contexts
+            .iter()
+            .find(|model| model.bounded_context.name == name)
+    }
+
+    /// Find the event published by another context
+    pub fn find_event(&self, context: &str, event: &str) -> Option<&Event> {
+        self.context(context)
+            .and_then(|model| model.events.get(event))
+    }
+}
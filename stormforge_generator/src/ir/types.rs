@@ -1,21 +1,28 @@
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 /// Root IR model structure
+///
+/// Named elements are kept in `IndexMap`s so that iteration follows the
+/// declaration order of the source YAML, which keeps generated output stable.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IRModel {
     pub version: String,
     pub bounded_context: BoundedContext,
     #[serde(default)]
-    pub aggregates: HashMap<String, Aggregate>,
+    pub aggregates: IndexMap<String, Aggregate>,
     #[serde(default)]
-    pub value_objects: HashMap<String, ValueObject>,
+    pub value_objects: IndexMap<String, ValueObject>,
     #[serde(default)]
-    pub events: HashMap<String, Event>,
+    pub events: IndexMap<String, Event>,
     #[serde(default)]
-    pub commands: HashMap<String, Command>,
+    pub commands: IndexMap<String, Command>,
     #[serde(default)]
-    pub queries: HashMap<String, Query>,
+    pub queries: IndexMap<String, Query>,
+    #[serde(default)]
+    pub policies: Vec<Policy>,
+    #[serde(default)]
+    pub read_models: IndexMap<String, ReadModel>,
     #[serde(default)]
     pub external_events: Vec<ExternalEventSubscription>,
 }
@@ -35,11 +42,34 @@ pub struct Aggregate {
     pub name: String,
     #[serde(default)]
     pub description: Option<String>,
+    #[serde(default)]
+    pub persistence: Persistence,
     pub root_entity: Entity,
     #[serde(default)]
     pub invariants: Vec<Invariant>,
 }
 
+/// How an aggregate is stored
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Persistence {
+    /// Rebuilt from its event stream
+    #[default]
+    EventSourced,
+    /// Stored as its current state, in tables
+    State,
+}
+
+impl Persistence {
+    /// Name of the option in the IR
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::EventSourced => "event_sourced",
+            Self::State => "state",
+        }
+    }
+}
+
 /// Entity definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Entity {
@@ -186,6 +216,117 @@ pub struct Condition {
     pub message: String,
 }
 
+/// Policy reacting to events by dispatching commands (v2)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Policy {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub triggers: Vec<String>,
+    #[serde(default)]
+    pub actions: Vec<String>,
+    #[serde(default)]
+    pub conditions: Vec<Condition>,
+    #[serde(default)]
+    pub saga: Option<Saga>,
+}
+
+/// Long-running saga settings of a policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Saga {
+    #[serde(default)]
+    pub completed_by: Vec<String>,
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+    #[serde(default)]
+    pub compensation: Vec<String>,
+}
+
+/// Denormalized read model projected from events (v2)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadModel {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub sources: Vec<DataSource>,
+    #[serde(default)]
+    pub fields: Vec<ReadModelField>,
+    #[serde(default)]
+    pub updated_by_events: Vec<String>,
+}
+
+/// Entity a read model pulls data from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataSource {
+    pub entity_id: String,
+    pub alias: String,
+    #[serde(default)]
+    pub join_type: Option<JoinType>,
+    #[serde(default)]
+    pub join_condition: Option<JoinCondition>,
+}
+
+/// Join type between read model sources
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JoinType {
+    Inner,
+    Left,
+    Right,
+}
+
+/// Join condition between read model sources
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JoinCondition {
+    pub left_property: String,
+    pub right_property: String,
+    #[serde(default)]
+    pub operator: Option<String>,
+}
+
+/// Read model field
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadModelField {
+    pub name: String,
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub field_type: Option<String>,
+    pub source: FieldSource,
+    #[serde(default)]
+    pub transformation: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Where a read model field takes its value from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldSource {
+    #[serde(rename = "type")]
+    pub source_type: FieldSourceType,
+    #[serde(default)]
+    pub entity_id: Option<String>,
+    #[serde(default)]
+    pub property_path: Option<String>,
+    #[serde(default)]
+    pub expression: Option<String>,
+    #[serde(default)]
+    pub value: Option<serde_json::Value>,
+    #[serde(default)]
+    pub read_model_id: Option<String>,
+}
+
+/// Kind of read model field source
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldSourceType {
+    EntityProperty,
+    Computed,
+    Constant,
+    ReadModel,
+    Custom,
+}
+
 /// External event subscription
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExternalEventSubscription {
@@ -0,0 +1,10 @@
+//! StormForge IR model, parser and code generators
+//!
+//! Used by the `stormforge-generator` and `stormforge-lsp` binaries and by
+//! generators for other languages that work from the same IR.
+
+pub mod generators;
+pub mod ir;
+pub mod lsp;
+pub mod mock;
+pub mod watch;
@@ -0,0 +1,342 @@
+use tower_lsp::lsp_types::{Position, Range};
+
+/// Kind of a named IR element that can be referenced from other elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Aggregate,
+    Entity,
+    ValueObject,
+    Command,
+    Event,
+    Query,
+    ReadModel,
+}
+
+impl SymbolKind {
+    fn from_section(section: &str) -> Option<Self> {
+        match section {
+            "aggregates" => Some(Self::Aggregate),
+            "entities" => Some(Self::Entity),
+            "value_objects" => Some(Self::ValueObject),
+            "commands" => Some(Self::Command),
+            "events" => Some(Self::Event),
+            "queries" => Some(Self::Query),
+            "read_models" => Some(Self::ReadModel),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Aggregate => "aggregate",
+            Self::Entity => "entity",
+            Self::ValueObject => "value object",
+            Self::Command => "command",
+            Self::Event => "event",
+            Self::Query => "query",
+            Self::ReadModel => "read model",
+        }
+    }
+
+    /// Whether the element can be used as the type of a property
+    pub fn is_type(self) -> bool {
+        matches!(
+            self,
+            Self::Aggregate | Self::Entity | Self::ValueObject | Self::ReadModel
+        )
+    }
+}
+
+/// Element declared as a key of a top-level section
+#[derive(Debug, Clone)]
+pub struct Definition {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub range: Range,
+    pub description: Option<String>,
+}
+
+/// Identifier in a YAML value, which may reference a definition
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub name: String,
+    pub range: Range,
+    /// Key whose value holds the identifier; the parent key for list items
+    pub key: Option<String>,
+}
+
+/// Keys whose values are prose, patterns or names outside the IR namespace
+const IGNORED_KEYS: &[&str] = &[
+    "description",
+    "message",
+    "expression",
+    "pattern",
+    "format",
+    "prefix",
+    "default",
+    "example",
+    "version",
+    "namespace",
+    "context",
+];
+
+/// An IR file scanned line by line
+///
+/// `serde_yaml` drops positions, so definitions and references are located
+/// in the text itself. The scanner follows block-style YAML as written in IR
+/// files: top-level sections whose keys are the element names.
+#[derive(Debug, Clone, Default)]
+pub struct Document {
+    pub text: String,
+    pub context: Option<String>,
+    pub definitions: Vec<Definition>,
+    pub tokens: Vec<Token>,
+    /// Key each line provides a value for, used to pick completions
+    line_keys: Vec<Option<String>>,
+}
+
+impl Document {
+    pub fn parse(text: &str) -> Self {
+        let mut document = Document {
+            text: text.to_string(),
+            ..Default::default()
+        };
+        // (indent, key) of the enclosing mappings
+        let mut stack: Vec<(usize, String)> = Vec::new();
+        let lines: Vec<&str> = text.lines().collect();
+        let mut block_scalar: Option<usize> = None;
+
+        for (number, line) in lines.iter().enumerate() {
+            let indent = line.len() - line.trim_start().len();
+            let content = strip_comment(line.trim_start());
+
+            if let Some(parent) = block_scalar {
+                if content.is_empty() || indent > parent {
+                    document.line_keys.push(None);
+                    continue;
+                }
+                block_scalar = None;
+            }
+            if content.is_empty() || content.starts_with("---") {
+                document.line_keys.push(None);
+                continue;
+            }
+
+            // A list item starts a mapping two columns further in
+            let (item_indent, entry) = match content.strip_prefix('-') {
+                Some(rest) if rest.is_empty() || rest.starts_with(' ') => {
+                    stack.retain(|(level, _)| *level <= indent);
+                    let rest = rest.trim_start();
+                    (indent + content.len() - rest.len(), rest)
+                }
+                _ => {
+                    stack.retain(|(level, _)| *level < indent);
+                    (indent, content)
+                }
+            };
+            let is_item = item_indent != indent;
+            let value_start = line.len() - line.trim_start().len() + (content.len() - entry.len());
+
+            let (key, value, value_offset) = match split_key(entry) {
+                Some((key, value, offset)) => {
+                    let key = key.to_string();
+                    if is_item {
+                        stack.retain(|(level, _)| *level < item_indent);
+                    }
+                    // Element definitions are the direct keys of a section
+                    if !is_item && stack.len() == 1 && stack[0].0 == 0 && indent > 0 {
+                        if let Some(kind) = SymbolKind::from_section(&stack[0].1) {
+                            let name = unquote(&key);
+                            let start = value_start
+                                + (key.len() - key.trim_start_matches(['"', '\'']).len());
+                            document.definitions.push(Definition {
+                                name: name.to_string(),
+                                kind,
+                                range: range(line, number, start, start + name.len()),
+                                description: None,
+                            });
+                        }
+                    }
+                    stack.push((item_indent, key.clone()));
+                    (Some(key), value, value_start + offset)
+                }
+                None => {
+                    let parent = stack.last().map(|(_, key)| key.clone());
+                    (parent, entry, value_start)
+                }
+            };
+
+            let value = value.trim_end();
+            document.line_keys.push(key.clone());
+            if value == "|" || value == ">" || value.starts_with("|-") || value.starts_with(">-") {
+                block_scalar = Some(indent);
+            }
+
+            let section = stack.first().map(|(_, key)| key.as_str());
+            let key_name = key.as_deref().unwrap_or_default();
+
+            if section == Some("bounded_context") && key_name == "name" && stack.len() == 2 {
+                document.context = Some(unquote(value).to_string());
+            }
+            // Descriptions sit directly under the element they describe
+            if key_name == "description" && stack.len() == 3 {
+                if let Some(definition) = document.definitions.last_mut() {
+                    if definition.name == unquote(&stack[1].1) {
+                        definition.description =
+                            Some(scalar_text(value, &lines[number + 1..], indent));
+                    }
+                }
+            }
+            if section == Some("bounded_context") || IGNORED_KEYS.contains(&key_name) {
+                continue;
+            }
+
+            for (start, end) in identifiers(value) {
+                document.tokens.push(Token {
+                    name: value[start..end].to_string(),
+                    range: range(line, number, value_offset + start, value_offset + end),
+                    key: key.clone(),
+                });
+            }
+        }
+
+        document
+    }
+
+    /// Key the value at a position belongs to
+    pub fn key_at(&self, position: Position) -> Option<&str> {
+        let line = self.text.lines().nth(position.line as usize)?;
+        let offset = byte_offset(line, position.character);
+        let before = line[..offset].trim_start();
+        let before = before.strip_prefix('-').unwrap_or(before).trim_start();
+        // Typing a key rather than a value
+        if !before.contains(':') && !line.trim_start().starts_with('-') {
+            return None;
+        }
+        self.line_keys
+            .get(position.line as usize)
+            .and_then(|key| key.as_deref())
+    }
+
+    /// Definition or reference under a position
+    pub fn name_at(&self, position: Position) -> Option<(&str, Range)> {
+        let contains = |range: &Range| {
+            range.start.line == position.line
+                && range.start.character <= position.character
+                && position.character <= range.end.character
+        };
+        self.definitions
+            .iter()
+            .find(|definition| contains(&definition.range))
+            .map(|definition| (definition.name.as_str(), definition.range))
+            .or_else(|| {
+                self.tokens
+                    .iter()
+                    .find(|token| contains(&token.range))
+                    .map(|token| (token.name.as_str(), token.range))
+            })
+    }
+}
+
+fn split_key(entry: &str) -> Option<(&str, &str, usize)> {
+    let quoted = entry.starts_with(['"', '\'']);
+    let mut end = None;
+    for (index, ch) in entry.char_indices() {
+        if quoted && index > 0 && ch == entry.as_bytes()[0] as char {
+            end = entry[index + 1..].starts_with(':').then_some(index + 1);
+            break;
+        }
+        if !quoted && ch == ':' {
+            end = Some(index);
+            break;
+        }
+        if !quoted && (ch == '{' || ch == '[' || ch == '"' || ch == '\'') {
+            return None;
+        }
+    }
+    let end = end?;
+    let rest = &entry[end + 1..];
+    if !(rest.is_empty() || rest.starts_with(' ')) {
+        return None;
+    }
+    let offset = end + 1 + (rest.len() - rest.trim_start().len());
+    Some((&entry[..end], &entry[offset..], offset))
+}
+
+fn strip_comment(text: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (index, ch) in text.char_indices() {
+        match (quote, ch) {
+            (None, '"' | '\'') => quote = Some(ch),
+            (Some(open), _) if ch == open => quote = None,
+            (None, '#') if previous.is_whitespace() => return text[..index].trim_end(),
+            _ => {}
+        }
+        previous = ch;
+    }
+    text
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches(['"', '\''])
+}
+
+/// Single-line, quoted or block scalar text of a value
+fn scalar_text(value: &str, following: &[&str], indent: usize) -> String {
+    if !matches!(value.chars().next(), Some('|' | '>')) {
+        return unquote(value).to_string();
+    }
+    let folded = value.starts_with('>');
+    let body: Vec<&str> = following
+        .iter()
+        .take_while(|line| line.trim().is_empty() || line.len() - line.trim_start().len() > indent)
+        .map(|line| line.trim())
+        .collect();
+    body.join(if folded { " " } else { "\n" })
+        .trim()
+        .to_string()
+}
+
+/// Byte ranges of identifiers in a value
+fn identifiers(value: &str) -> Vec<(usize, usize)> {
+    let mut found = Vec::new();
+    let mut start = None;
+    for (index, ch) in value.char_indices().chain([(value.len(), ' ')]) {
+        let word = ch.is_ascii_alphanumeric() || ch == '_';
+        match (start, word) {
+            (None, true) => start = Some(index),
+            (Some(begin), false) => {
+                if !value.as_bytes()[begin].is_ascii_digit() {
+                    found.push((begin, index));
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    found
+}
+
+fn range(line: &str, number: usize, start: usize, end: usize) -> Range {
+    Range::new(
+        Position::new(number as u32, utf16_len(&line[..start])),
+        Position::new(number as u32, utf16_len(&line[..end])),
+    )
+}
+
+fn utf16_len(text: &str) -> u32 {
+    text.encode_utf16().count() as u32
+}
+
+/// Byte offset of a UTF-16 column within a line
+fn byte_offset(line: &str, character: u32) -> usize {
+    let mut units = 0;
+    for (index, ch) in line.char_indices() {
+        if units >= character {
+            return index;
+        }
+        units += ch.len_utf16() as u32;
+    }
+    line.len()
+}
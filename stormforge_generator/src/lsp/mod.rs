@@ -0,0 +1,21 @@
+//! Language server for IR files, run by the `stormforge-lsp` binary
+//!
+//! Files are indexed from their text so that navigation keeps working
+//! while a file is being edited and does not parse.
+
+mod document;
+mod server;
+mod workspace;
+
+pub use server::Backend;
+pub use workspace::Workspace;
+
+use tower_lsp::{LspService, Server};
+
+/// Serve the language server protocol over stdin/stdout
+pub async fn run_stdio() {
+    let (service, socket) = LspService::new(Backend::new);
+    Server::new(tokio::io::stdin(), tokio::io::stdout(), socket)
+        .serve(service)
+        .await;
+}
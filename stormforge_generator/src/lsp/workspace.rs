@@ -0,0 +1,503 @@
+use super::document::{Definition, Document, SymbolKind};
+use crate::ir::IRParser;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, Diagnostic, DiagnosticSeverity, Hover, HoverContents,
+    Location, MarkupContent, MarkupKind, Position, Range, TextEdit, Url, WorkspaceEdit,
+};
+use walkdir::WalkDir;
+
+/// Built-in IR types
+const PRIMITIVES: &[&str] = &[
+    "String", "Integer", "Decimal", "Boolean", "DateTime", "Date", "Time", "Uuid",
+];
+
+/// Generic wrappers accepted around IR types
+const WRAPPERS: &[&str] = &["List", "Vec", "Option", "PagedResult"];
+
+/// IR files of a project: the files on disk, overlaid by the open editors
+#[derive(Debug, Default)]
+pub struct Workspace {
+    documents: BTreeMap<Url, Document>,
+}
+
+impl Workspace {
+    /// Index every IR file under a directory
+    ///
+    /// Only YAML files declaring a `bounded_context` are taken, so that
+    /// unrelated configuration files do not contribute names.
+    pub fn load_dir(&mut self, root: &Path) {
+        for entry in WalkDir::new(root)
+            .into_iter()
+            .filter_entry(|entry| {
+                let name = entry.file_name().to_string_lossy();
+                !(name.starts_with('.') || name == "target" || name == "node_modules")
+                    || entry.depth() == 0
+            })
+            .filter_map(|entry| entry.ok())
+        {
+            let path = entry.path();
+            if !path
+                .extension()
+                .is_some_and(|ext| ext == "yaml" || ext == "yml")
+            {
+                continue;
+            }
+            let (Ok(text), Ok(uri)) = (fs::read_to_string(path), Url::from_file_path(path)) else {
+                continue;
+            };
+            if text
+                .lines()
+                .any(|line| line.starts_with("bounded_context:"))
+            {
+                self.documents.insert(uri, Document::parse(&text));
+            }
+        }
+    }
+
+    pub fn update(&mut self, uri: Url, text: &str) {
+        self.documents.insert(uri, Document::parse(text));
+    }
+
+    /// Fall back to the saved file once an editor closes it
+    pub fn close(&mut self, uri: &Url) {
+        match uri
+            .to_file_path()
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+        {
+            Some(text) => self.update(uri.clone(), &text),
+            None => {
+                self.documents.remove(uri);
+            }
+        }
+    }
+
+    /// Parse and validation errors, plus references to unknown types
+    pub fn diagnostics(&self, uri: &Url) -> Vec<Diagnostic> {
+        let Some(document) = self.documents.get(uri) else {
+            return Vec::new();
+        };
+        let mut diagnostics = Vec::new();
+
+        if let Err(error) = IRParser::parse_yaml(&document.text) {
+            let yaml = error
+                .chain()
+                .find_map(|cause| cause.downcast_ref::<serde_yaml::Error>());
+            let (range, message) = match yaml {
+                Some(yaml) => {
+                    let position = yaml
+                        .location()
+                        .map(|location| {
+                            Position::new(
+                                location.line().saturating_sub(1) as u32,
+                                location.column().saturating_sub(1) as u32,
+                            )
+                        })
+                        .unwrap_or_default();
+                    (line_range(document, position), yaml.to_string())
+                }
+                None => (Range::default(), error.to_string()),
+            };
+            diagnostics.push(Diagnostic {
+                range,
+                severity: Some(DiagnosticSeverity::ERROR),
+                source: Some("stormforge".to_string()),
+                message,
+                ..Default::default()
+            });
+        }
+
+        let types = self.type_names();
+        for token in &document.tokens {
+            let is_type = matches!(token.key.as_deref(), Some("type" | "underlying_type"));
+            if is_type
+                && token.name.starts_with(|ch: char| ch.is_ascii_uppercase())
+                && !types.contains_key(token.name.as_str())
+                && !is_dto(&token.name, &types)
+            {
+                diagnostics.push(Diagnostic {
+                    range: token.range,
+                    severity: Some(DiagnosticSeverity::WARNING),
+                    source: Some("stormforge".to_string()),
+                    message: format!("Unknown type `{}`", token.name),
+                    ..Default::default()
+                });
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Names that fit the value being typed
+    pub fn completions(&self, uri: &Url, position: Position) -> Vec<CompletionItem> {
+        let Some(key) = self
+            .documents
+            .get(uri)
+            .and_then(|document| document.key_at(position))
+        else {
+            return Vec::new();
+        };
+
+        match key {
+            "type" | "underlying_type" | "returns" => {
+                let mut items: Vec<CompletionItem> = PRIMITIVES
+                    .iter()
+                    .chain(WRAPPERS)
+                    .map(|name| CompletionItem {
+                        label: name.to_string(),
+                        kind: Some(CompletionItemKind::KEYWORD),
+                        detail: Some("built-in type".to_string()),
+                        ..Default::default()
+                    })
+                    .collect();
+                items.extend(self.symbol_completions(|kind| kind.is_type()));
+                items
+            }
+            "aggregate" => self.symbol_completions(|kind| kind == SymbolKind::Aggregate),
+            "produces" | "triggers" | "updated_by_events" | "completed_by" | "event" => {
+                self.symbol_completions(|kind| kind == SymbolKind::Event)
+            }
+            "actions" | "compensation" => {
+                self.symbol_completions(|kind| kind == SymbolKind::Command)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn definition(&self, uri: &Url, position: Position) -> Vec<Location> {
+        let Some(name) = self.name_at(uri, position) else {
+            return Vec::new();
+        };
+        self.definitions_of(&name)
+            .map(|(uri, definition)| Location::new(uri.clone(), definition.range))
+            .collect()
+    }
+
+    pub fn references(
+        &self,
+        uri: &Url,
+        position: Position,
+        include_declaration: bool,
+    ) -> Vec<Location> {
+        let Some(name) = self.name_at(uri, position) else {
+            return Vec::new();
+        };
+        self.occurrences(&name, include_declaration)
+            .map(|(uri, range)| Location::new(uri.clone(), range))
+            .collect()
+    }
+
+    pub fn hover(&self, uri: &Url, position: Position) -> Option<Hover> {
+        let (name, range) = self.documents.get(uri)?.name_at(position)?;
+        let sections: Vec<String> = self
+            .definitions_of(name)
+            .map(|(uri, definition)| {
+                let context = self.documents[uri]
+                    .context
+                    .as_deref()
+                    .map(|context| format!(" in {}", context))
+                    .unwrap_or_default();
+                let mut text = format!(
+                    "**{}** `{}`{}",
+                    definition.kind.label(),
+                    definition.name,
+                    context
+                );
+                if let Some(description) = &definition.description {
+                    text.push_str("\n\n");
+                    text.push_str(description);
+                }
+                text
+            })
+            .collect();
+        if sections.is_empty() {
+            return None;
+        }
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: sections.join("\n\n---\n\n"),
+            }),
+            range: Some(range),
+        })
+    }
+
+    /// Range of the defined name under a position, if it can be renamed
+    pub fn prepare_rename(&self, uri: &Url, position: Position) -> Option<Range> {
+        let (name, range) = self.documents.get(uri)?.name_at(position)?;
+        self.definitions_of(name).next().map(|_| range)
+    }
+
+    /// Rename a definition and every reference to it
+    pub fn rename(&self, uri: &Url, position: Position, new_name: &str) -> Option<WorkspaceEdit> {
+        let name = self.name_at(uri, position)?;
+        let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+        for (uri, range) in self.occurrences(&name, true) {
+            changes
+                .entry(uri.clone())
+                .or_default()
+                .push(TextEdit::new(range, new_name.to_string()));
+        }
+        Some(WorkspaceEdit::new(changes))
+    }
+
+    /// Defined name under a position
+    fn name_at(&self, uri: &Url, position: Position) -> Option<String> {
+        let (name, _) = self.documents.get(uri)?.name_at(position)?;
+        self.definitions_of(name).next()?;
+        Some(name.to_string())
+    }
+
+    fn definitions_of<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = (&'a Url, &'a Definition)> + 'a {
+        self.documents.iter().flat_map(move |(uri, document)| {
+            document
+                .definitions
+                .iter()
+                .filter(move |definition| definition.name == name)
+                .map(move |definition| (uri, definition))
+        })
+    }
+
+    fn occurrences<'a>(
+        &'a self,
+        name: &'a str,
+        include_declaration: bool,
+    ) -> impl Iterator<Item = (&'a Url, Range)> + 'a {
+        self.documents.iter().flat_map(move |(uri, document)| {
+            let definitions = document
+                .definitions
+                .iter()
+                .filter(move |definition| include_declaration && definition.name == name)
+                .map(|definition| definition.range);
+            let tokens = document
+                .tokens
+                .iter()
+                .filter(move |token| token.name == name)
+                .map(|token| token.range);
+            definitions.chain(tokens).map(move |range| (uri, range))
+        })
+    }
+
+    fn type_names(&self) -> HashMap<&str, SymbolKind> {
+        self.documents
+            .values()
+            .flat_map(|document| &document.definitions)
+            .filter(|definition| definition.kind.is_type())
+            .map(|definition| (definition.name.as_str(), definition.kind))
+            .chain(
+                PRIMITIVES
+                    .iter()
+                    .chain(WRAPPERS)
+                    .map(|name| (*name, SymbolKind::ValueObject)),
+            )
+            .collect()
+    }
+
+    /// One item per name, even when several contexts define it
+    fn symbol_completions(&self, filter: impl Fn(SymbolKind) -> bool) -> Vec<CompletionItem> {
+        let mut items: BTreeMap<&str, CompletionItem> = BTreeMap::new();
+        for (uri, document) in &self.documents {
+            for definition in &document.definitions {
+                if !filter(definition.kind) || items.contains_key(definition.name.as_str()) {
+                    continue;
+                }
+                let context = self.documents[uri].context.as_deref().unwrap_or_default();
+                items.insert(
+                    &definition.name,
+                    CompletionItem {
+                        label: definition.name.clone(),
+                        kind: Some(completion_kind(definition.kind)),
+                        detail: Some(
+                            format!("{} {}", context, definition.kind.label())
+                                .trim()
+                                .to_string(),
+                        ),
+                        documentation: definition
+                            .description
+                            .clone()
+                            .map(tower_lsp::lsp_types::Documentation::String),
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+        items.into_values().collect()
+    }
+}
+
+/// Command DTO names that the generators map to the entity they wrap
+fn is_dto(name: &str, types: &HashMap<&str, SymbolKind>) -> bool {
+    ["Create", "Update"].iter().any(|prefix| {
+        name.strip_prefix(prefix)
+            .is_some_and(|entity| types.contains_key(entity))
+    })
+}
+
+fn completion_kind(kind: SymbolKind) -> CompletionItemKind {
+    match kind {
+        SymbolKind::Aggregate | SymbolKind::Entity | SymbolKind::ReadModel => {
+            CompletionItemKind::CLASS
+        }
+        SymbolKind::ValueObject => CompletionItemKind::STRUCT,
+        SymbolKind::Command | SymbolKind::Query => CompletionItemKind::FUNCTION,
+        SymbolKind::Event => CompletionItemKind::EVENT,
+    }
+}
+
+/// Rest of the line from a position
+fn line_range(document: &Document, start: Position) -> Range {
+    let end = document
+        .text
+        .lines()
+        .nth(start.line as usize)
+        .map(|line| line.encode_utf16().count() as u32)
+        .unwrap_or(start.character);
+    let start = Position::new(start.line, start.character.min(end));
+    Range::new(start, Position::new(start.line, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER: &str = r#"version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+value_objects:
+  OrderId:
+    name: "OrderId"
+    description: "Unique order identifier"
+    type: "identifier"
+aggregates:
+  Order:
+    name: "Order"
+    root_entity:
+      name: "Order"
+      properties:
+        - name: "id"
+          type: "OrderId"
+commands:
+  PlaceOrder:
+    name: "PlaceOrder"
+    aggregate: "Order"
+    produces:
+      - "OrderPlaced"
+events:
+  OrderPlaced:
+    name: "OrderPlaced"
+    description: >
+      Raised once an order
+      has been placed
+    payload:
+      - name: "orderId"
+        type: "OrderId"
+      - name: "note"
+        type: "Note"
+"#;
+
+    const SHIPPING: &str = r#"version: "1.0"
+bounded_context:
+  name: "Shipping"
+  namespace: "acme.shipping"
+external_events:
+  - context: "Order"
+    event: "OrderPlaced"
+    handler: "onOrderPlaced"
+"#;
+
+    fn workspace() -> (Workspace, Url, Url) {
+        let order = Url::parse("file:///project/order.yaml").unwrap();
+        let shipping = Url::parse("file:///project/shipping.yaml").unwrap();
+        let mut workspace = Workspace::default();
+        workspace.update(order.clone(), ORDER);
+        workspace.update(shipping.clone(), SHIPPING);
+        (workspace, order, shipping)
+    }
+
+    fn lines(locations: &[Location]) -> Vec<(String, u32)> {
+        locations
+            .iter()
+            .map(|location| {
+                let file = location.uri.path().rsplit('/').next().unwrap().to_string();
+                (file, location.range.start.line)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_navigation_across_files() {
+        let (workspace, order, shipping) = workspace();
+
+        // `OrderPlaced` in the shipping subscription
+        let position = Position::new(6, 14);
+        assert_eq!(
+            lines(&workspace.definition(&shipping, position)),
+            [("order.yaml".to_string(), 24)]
+        );
+        assert_eq!(
+            lines(&workspace.references(&shipping, position, true)),
+            [
+                ("order.yaml".to_string(), 24),
+                ("order.yaml".to_string(), 22),
+                ("order.yaml".to_string(), 25),
+                ("shipping.yaml".to_string(), 6),
+            ]
+        );
+
+        let hover = workspace.hover(&order, Position::new(22, 10)).unwrap();
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("expected markdown");
+        };
+        assert_eq!(
+            markup.value,
+            "**event** `OrderPlaced` in Order\n\nRaised once an order has been placed"
+        );
+
+        let edit = workspace
+            .rename(&order, Position::new(5, 3), "OrderNumber")
+            .unwrap();
+        let changes = edit.changes.unwrap();
+        assert_eq!(changes[&order].len(), 4);
+        assert!(!changes.contains_key(&shipping));
+    }
+
+    #[test]
+    fn test_completions_and_diagnostics() {
+        let (workspace, order, _) = workspace();
+        let labels = |line, character| -> Vec<String> {
+            workspace
+                .completions(&order, Position::new(line, character))
+                .into_iter()
+                .map(|item| item.label)
+                .filter(|label| !PRIMITIVES.contains(&label.as_str()))
+                .filter(|label| !WRAPPERS.contains(&label.as_str()))
+                .collect()
+        };
+        assert_eq!(labels(16, 16), ["Order", "OrderId"]);
+        assert_eq!(labels(20, 15), ["Order"]);
+        assert_eq!(labels(22, 8), ["OrderPlaced"]);
+        assert!(labels(19, 4).is_empty());
+
+        let diagnostics = workspace.diagnostics(&order);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Unknown type `Note`");
+        assert_eq!(diagnostics[0].range.start, Position::new(33, 15));
+
+        let broken = Url::parse("file:///project/broken.yaml").unwrap();
+        let mut workspace = workspace;
+        workspace.update(
+            broken.clone(),
+            "version: \"1.0\"\nbounded_context:\n  name: \"X\"\n",
+        );
+        let diagnostics = workspace.diagnostics(&broken);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert!(diagnostics[0].message.contains("namespace"));
+    }
+}
@@ -1,12 +1,19 @@
-use anyhow::Result;
-use clap::{Parser, Subcommand};
-use std::path::PathBuf;
-
-mod generators;
-mod ir;
-
-use generators::RustGenerator;
-use ir::IRParser;
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use stormforge_generator::generators::utils::to_kebab_case;
+use stormforge_generator::generators::{
+    DiagramFormat, DiagramGenerator, DocsGenerator, NestJsGenerator, PythonGenerator,
+    RustGenerator, TypeScriptGenerator, WorkspaceGenerator,
+};
+use stormforge_generator::ir::lint::Level;
+use stormforge_generator::ir::{
+    Cml, IRDiff, IRFormatter, IRModel, IRParser, IRProject, Linter, ProjectConfig, Untranslated,
+};
+use stormforge_generator::mock::{self, MockService};
+use stormforge_generator::watch::{self, Hook, OutputSync};
 
 #[derive(Parser)]
 #[command(name = "stormforge-generator")]
@@ -16,21 +23,207 @@ struct Cli {
     command: Commands,
 }
 
+/// Code generation target
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Target {
+    /// Rust microservice
+    Rust,
+    /// TypeScript SDK for the Rust microservice
+    Typescript,
+    /// Static Markdown/HTML documentation site
+    Docs,
+    /// Python FastAPI service
+    Python,
+    /// NestJS service
+    Nestjs,
+}
+
+/// Diagram language
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Mermaid flowchart
+    Mermaid,
+    /// PlantUML diagram
+    Plantuml,
+    /// Graphviz DOT graph
+    Dot,
+}
+
+impl From<Format> for DiagramFormat {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Mermaid => DiagramFormat::Mermaid,
+            Format::Plantuml => DiagramFormat::PlantUml,
+            Format::Dot => DiagramFormat::Dot,
+        }
+    }
+}
+
+/// Diff report format
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum ReportFormat {
+    /// Human readable report
+    Text,
+    /// Machine readable JSON
+    Json,
+}
+
+/// Modeling language that IR is imported from or exported to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum ModelLanguage {
+    /// Context Mapper DSL
+    Cml,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Generate Rust microservice from IR file
     Generate {
+        /// Input IR YAML files or directories containing them
+        ///
+        /// The docs and NestJS targets cover every bounded context of the
+        /// input, the other targets a single one.
+        #[arg(short, long, num_args = 1.., required = true)]
+        input: Vec<PathBuf>,
+
+        /// Output directory for generated code
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Bounded context to generate, required by single-context targets
+        /// when the input holds several
+        #[arg(short, long)]
+        context: Option<String>,
+
+        /// What to generate
+        #[arg(short, long, value_enum, default_value_t = Target::Rust)]
+        target: Target,
+
+        /// Regenerate whenever the input file changes
+        #[arg(short, long)]
+        watch: bool,
+
+        /// Shell command run in the output directory after generation
+        #[arg(long)]
+        hook: Option<String>,
+    },
+
+    /// Generate a Cargo workspace from several bounded context IR files
+    Workspace {
+        /// Input IR YAML files or directories containing them
+        #[arg(short, long, num_args = 1.., required = true)]
+        input: Vec<PathBuf>,
+
+        /// Output directory for the generated workspace
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Regenerate whenever an input file changes
+        #[arg(short, long)]
+        watch: bool,
+
+        /// Shell command run in the output directory after generation
+        #[arg(long)]
+        hook: Option<String>,
+    },
+
+    /// Render bounded contexts as a Mermaid, PlantUML or DOT diagram
+    Diagram {
+        /// Input IR YAML files or directories containing them
+        #[arg(short, long, num_args = 1.., required = true)]
+        input: Vec<PathBuf>,
+
+        /// Diagram language
+        #[arg(short, long, value_enum, default_value_t = Format::Mermaid)]
+        format: Format,
+
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Compare two versions of an IR file and classify the changes
+    Diff {
+        /// Previous IR YAML file
+        old: PathBuf,
+
+        /// New IR YAML file
+        new: PathBuf,
+
+        /// Report format
+        #[arg(short, long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
+
+        /// Exit with status 1 when any change is breaking
+        #[arg(long)]
+        fail_on_breaking: bool,
+    },
+
+    /// Format IR files canonically, in place
+    Fmt {
+        /// Input IR YAML files or directories containing them
+        #[arg(short, long, num_args = 1.., required = true)]
+        input: Vec<PathBuf>,
+
+        /// List unformatted files instead of rewriting them, failing if any
+        #[arg(long)]
+        check: bool,
+    },
+
+    /// Check IR files against the style rules configured in stormforge.yaml
+    Lint {
+        /// Input IR YAML files or directories containing them
+        #[arg(short, long, num_args = 1.., required = true)]
+        input: Vec<PathBuf>,
+
+        /// Configuration file (defaults to the nearest stormforge.yaml)
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+    },
+
+    /// Serve the command and query routes of an IR file with example data
+    Mock {
         /// Input IR YAML file
         #[arg(short, long)]
         input: PathBuf,
 
-        /// Output directory for generated code
+        /// Port to listen on
+        #[arg(short, long, default_value_t = 3000)]
+        port: u16,
+
+        /// Keep aggregates in memory, applying the events commands produce
+        #[arg(long)]
+        stateful: bool,
+    },
+
+    /// Translate a model written in another modeling language into IR files
+    Import {
+        /// Language of the input model
+        #[arg(long, value_enum)]
+        from: ModelLanguage,
+
+        /// Input model file
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// Output directory for the IR files, one per bounded context
         #[arg(short, long)]
         output: PathBuf,
+    },
+
+    /// Translate IR files into another modeling language
+    Export {
+        /// Language of the output model
+        #[arg(long, value_enum)]
+        to: ModelLanguage,
+
+        /// Input IR YAML files or directories containing them
+        #[arg(short, long, num_args = 1.., required = true)]
+        input: Vec<PathBuf>,
 
-        /// Specific bounded context to generate (optional)
+        /// Output file (defaults to stdout)
         #[arg(short, long)]
-        context: Option<String>,
+        output: Option<PathBuf>,
     },
 
     /// Validate IR file without generating code
@@ -48,32 +241,285 @@ fn main() -> Result<()> {
         Commands::Generate {
             input,
             output,
-            context: _,
+            context,
+            target,
+            watch,
+            hook,
         } => {
-            println!("📄 Reading IR file: {}", input.display());
+            let hook = hook.map(Hook::new);
+            if watch {
+                return watch_and_regenerate(&input, &output, hook, |dir| {
+                    let project = IRParser::parse_project(&input)?;
+                    generate_target(target, &project, context.as_deref(), dir)
+                });
+            }
 
-            // Parse IR file
-            let model = IRParser::parse_file(&input)?;
+            for path in &input {
+                println!("📄 Reading IR file: {}", path.display());
+            }
+
+            // Parse IR files
+            let project = IRParser::parse_project(&input)?;
 
             println!("✅ IR file parsed successfully");
-            println!("   Bounded Context: {}", model.bounded_context.name);
-            println!("   Namespace: {}", model.bounded_context.namespace);
-            println!("   Aggregates: {}", model.aggregates.len());
-            println!("   Commands: {}", model.commands.len());
-            println!("   Events: {}", model.events.len());
-            println!("   Queries: {}", model.queries.len());
+            for model in &project.contexts {
+                println!("   Bounded Context: {}", model.bounded_context.name);
+                println!("   Namespace: {}", model.bounded_context.namespace);
+                println!("   Aggregates: {}", model.aggregates.len());
+                println!("   Commands: {}", model.commands.len());
+                println!("   Events: {}", model.events.len());
+                println!("   Queries: {}", model.queries.len());
+            }
 
             // Generate code
+            generate_target(
+                target,
+                &project,
+                context.as_deref(),
+                output.to_str().unwrap().to_string(),
+            )?;
+            if let Some(hook) = &hook {
+                hook.run_and_report(&output);
+            }
+
+            println!("\n🎉 Generation complete!");
+            println!("   Output: {}", output.display());
+            println!("\n📝 Next steps:");
+            println!("   cd {}", output.display());
+            match target {
+                Target::Rust => {
+                    println!("   cargo build");
+                    println!("   cargo run");
+                }
+                Target::Typescript => {
+                    println!("   npm install");
+                    println!("   npm run build");
+                }
+                Target::Docs => {
+                    println!("   open index.html");
+                }
+                Target::Python => {
+                    println!("   pip install -e \".[test]\"");
+                    println!("   uvicorn app.main:app --reload --port 3000");
+                    println!("   pytest");
+                }
+                Target::Nestjs => {
+                    println!("   npm install");
+                    println!("   npm run build");
+                    println!("   npm start");
+                }
+            }
+        }
+
+        Commands::Workspace {
+            input,
+            output,
+            watch,
+            hook,
+        } => {
+            let hook = hook.map(Hook::new);
+            if watch {
+                return watch_and_regenerate(&input, &output, hook, |dir| {
+                    let project = IRParser::parse_project(&input)?;
+                    WorkspaceGenerator::new(dir).generate(&project)
+                });
+            }
+
+            let project = IRParser::parse_project(&input)?;
+
+            println!("✅ IR files parsed successfully");
+            for model in &project.contexts {
+                println!(
+                    "   Bounded Context: {} ({} aggregates, {} events)",
+                    model.bounded_context.name,
+                    model.aggregates.len(),
+                    model.events.len()
+                );
+            }
+
             let output_path = output.to_str().unwrap().to_string();
-            let generator = RustGenerator::new(output_path);
-            generator.generate(&model)?;
+            let generator = WorkspaceGenerator::new(output_path);
+            generator.generate(&project)?;
+            if let Some(hook) = &hook {
+                hook.run_and_report(&output);
+            }
 
             println!("\n🎉 Generation complete!");
             println!("   Output: {}", output.display());
             println!("\n📝 Next steps:");
             println!("   cd {}", output.display());
-            println!("   cargo build");
-            println!("   cargo run");
+            println!("   cargo build --workspace");
+        }
+
+        Commands::Diagram {
+            input,
+            format,
+            output,
+        } => {
+            let project = IRParser::parse_project(&input)?;
+            let diagram = DiagramGenerator::generate(&project.contexts, format.into());
+
+            // Only the diagram goes to stdout, so that it can be piped
+            match output {
+                Some(output) => {
+                    fs::write(&output, diagram)?;
+                    println!(
+                        "✅ Diagram of {} bounded context(s) written to {}",
+                        project.contexts.len(),
+                        output.display()
+                    );
+                }
+                None => print!("{}", diagram),
+            }
+        }
+
+        Commands::Diff {
+            old,
+            new,
+            format,
+            fail_on_breaking,
+        } => {
+            let diff = IRDiff::compare(&IRParser::parse_file(&old)?, &IRParser::parse_file(&new)?);
+
+            match format {
+                ReportFormat::Text => print!("{}", diff.to_text()),
+                ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&diff)?),
+            }
+
+            if fail_on_breaking && diff.has_breaking_changes() {
+                std::process::exit(1);
+            }
+        }
+
+        Commands::Fmt { input, check } => {
+            let mut unformatted = 0;
+            for path in IRParser::collect_files(&input)? {
+                let text = fs::read_to_string(&path)?;
+                let formatted = IRFormatter::format(&text)
+                    .with_context(|| format!("Failed to format {}", path.display()))?;
+                if formatted == text {
+                    continue;
+                }
+                unformatted += 1;
+                if check {
+                    println!("Would reformat: {}", path.display());
+                } else {
+                    fs::write(&path, formatted)?;
+                    println!("Formatted: {}", path.display());
+                }
+            }
+
+            if check && unformatted > 0 {
+                std::process::exit(1);
+            }
+            if unformatted == 0 {
+                println!("✅ All files formatted");
+            }
+        }
+
+        Commands::Lint { input, config } => {
+            let config = match config {
+                Some(path) => ProjectConfig::load(path)?,
+                None => ProjectConfig::discover(&input[0])?
+                    .map(|(_, config)| config)
+                    .unwrap_or_default(),
+            };
+            let project = IRParser::parse_project(&input)?;
+            let findings = Linter::new(config.lint)?.lint(&project);
+
+            for finding in &findings {
+                println!("{}", finding);
+            }
+            let errors = findings
+                .iter()
+                .filter(|finding| finding.level == Level::Error)
+                .count();
+            if findings.is_empty() {
+                println!("✅ No lint findings");
+            } else {
+                println!(
+                    "\n{} error(s), {} warning(s)",
+                    errors,
+                    findings.len() - errors
+                );
+            }
+            if errors > 0 {
+                std::process::exit(1);
+            }
+        }
+
+        Commands::Mock {
+            input,
+            port,
+            stateful,
+        } => {
+            let model = IRParser::parse_file(&input)?;
+            let service = MockService::new(model, stateful);
+
+            println!(
+                "🎭 Mocking {} on http://localhost:{}{}",
+                service.model().bounded_context.name,
+                port,
+                if stateful { " (stateful)" } else { "" }
+            );
+            for name in service.model().commands.keys() {
+                println!("   POST /api/{}", to_kebab_case(name));
+            }
+            for name in service.model().queries.keys() {
+                println!("   GET  /api/{}", to_kebab_case(name));
+            }
+            tokio::runtime::Runtime::new()?.block_on(mock::serve(service, port))?;
+        }
+
+        Commands::Import {
+            from,
+            input,
+            output,
+        } => {
+            let text = fs::read_to_string(&input)
+                .with_context(|| format!("Failed to read {}", input.display()))?;
+            let import = match from {
+                ModelLanguage::Cml => Cml::import(&text),
+            }
+            .with_context(|| format!("Failed to import {}", input.display()))?;
+
+            fs::create_dir_all(&output)?;
+            let files = import.files()?;
+            for (name, yaml) in &files {
+                let path = output.join(name);
+                fs::write(&path, yaml)?;
+                println!("   {}", path.display());
+            }
+            println!(
+                "✅ Imported {} bounded context(s) into {}",
+                files.len(),
+                output.display()
+            );
+            report_untranslated(&import.untranslated, false);
+        }
+
+        Commands::Export { to, input, output } => {
+            let project = IRParser::parse_project(&input)?;
+            let export = match to {
+                ModelLanguage::Cml => Cml::export(&project.contexts),
+            };
+
+            // Only the model goes to stdout, so that it can be piped
+            match output {
+                Some(output) => {
+                    fs::write(&output, &export.cml)?;
+                    println!(
+                        "✅ {} bounded context(s) exported to {}",
+                        project.contexts.len(),
+                        output.display()
+                    );
+                    report_untranslated(&export.untranslated, false);
+                }
+                None => {
+                    print!("{}", export.cml);
+                    report_untranslated(&export.untranslated, true);
+                }
+            }
         }
 
         Commands::Validate { input } => {
@@ -90,3 +536,117 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// List the constructs a translation left out
+fn report_untranslated(untranslated: &[Untranslated], to_stderr: bool) {
+    if untranslated.is_empty() {
+        return;
+    }
+    let mut report = format!(
+        "⚠️  {} construct(s) could not be translated:\n",
+        untranslated.len()
+    );
+    for construct in untranslated {
+        report.push_str(&format!("   - {}\n", construct));
+    }
+    if to_stderr {
+        eprint!("{}", report);
+    } else {
+        print!("{}", report);
+    }
+}
+
+fn generate_target(
+    target: Target,
+    project: &IRProject,
+    context: Option<&str>,
+    output_dir: String,
+) -> Result<()> {
+    match target {
+        Target::Rust => RustGenerator::new(output_dir).generate(select_context(project, context)?),
+        Target::Typescript => {
+            TypeScriptGenerator::new(output_dir).generate(select_context(project, context)?)
+        }
+        Target::Docs => DocsGenerator::new(output_dir).generate(project),
+        Target::Python => {
+            PythonGenerator::new(output_dir).generate(select_context(project, context)?)
+        }
+        Target::Nestjs => NestJsGenerator::new(output_dir).generate(project),
+    }
+}
+
+/// The bounded context a single-context target generates
+///
+/// `--context` may be omitted when the input holds a single context.
+fn select_context<'a>(project: &'a IRProject, context: Option<&str>) -> Result<&'a IRModel> {
+    match (context, project.contexts.as_slice()) {
+        (Some(name), _) => project
+            .context(name)
+            .with_context(|| format!("Bounded context '{}' not found in the input", name)),
+        (None, [model]) => Ok(model),
+        (None, contexts) => anyhow::bail!(
+            "The input holds {} bounded contexts, choose one with --context",
+            contexts.len()
+        ),
+    }
+}
+
+/// Generate once, then again after every change to the inputs
+///
+/// Errors are printed rather than returned so that watching continues
+/// until the model is fixed.
+fn watch_and_regenerate(
+    inputs: &[PathBuf],
+    output: &Path,
+    hook: Option<Hook>,
+    generate: impl Fn(String) -> Result<()>,
+) -> Result<()> {
+    let mut sync = OutputSync::new(output);
+    regenerate(&mut sync, output, hook.as_ref(), &generate);
+
+    println!("\n👀 Watching for changes (Ctrl+C to stop)");
+    watch::watch(inputs, output, |changed| {
+        for path in changed {
+            println!("\n🔄 Changed: {}", path.display());
+        }
+        regenerate(&mut sync, output, hook.as_ref(), &generate);
+    })
+}
+
+fn regenerate(
+    sync: &mut OutputSync,
+    output: &Path,
+    hook: Option<&Hook>,
+    generate: &impl Fn(String) -> Result<()>,
+) {
+    let started = Instant::now();
+    let report = tempfile::tempdir()
+        .map_err(anyhow::Error::from)
+        .and_then(|staging| {
+            sync.seed(staging.path())?;
+            generate(staging.path().to_string_lossy().into_owned())?;
+            sync.sync(staging.path())
+        });
+
+    match report {
+        Ok(report) => {
+            println!(
+                "✅ Regenerated in {:.1}s: {} written, {} removed, {} unchanged",
+                started.elapsed().as_secs_f64(),
+                report.written.len(),
+                report.removed.len(),
+                report.unchanged
+            );
+            for path in &report.written {
+                println!("   ✎ {}", path.display());
+            }
+            for path in &report.removed {
+                println!("   ✗ {}", path.display());
+            }
+            if let (Some(hook), true) = (hook, report.has_changes()) {
+                hook.run_and_report(output);
+            }
+        }
+        Err(error) => println!("❌ {:#}", error),
+    }
+}
@@ -0,0 +1,340 @@
+use super::generic;
+use crate::generators::utils::*;
+use crate::ir::{IRModel, Property, Validation, ValueObject};
+use regex_syntax::hir::{Class, Hir, HirKind};
+use serde_json::{json, Map, Value};
+
+/// Example JSON for IR types, following the serde layout of the generated
+/// service and satisfying the declared validation rules
+pub struct Examples<'a> {
+    model: &'a IRModel,
+}
+
+impl<'a> Examples<'a> {
+    pub fn new(model: &'a IRModel) -> Self {
+        Self { model }
+    }
+
+    /// Example object with a field for every property
+    pub fn object(&self, properties: &[Property]) -> Value {
+        self.object_at(properties, &mut Vec::new())
+    }
+
+    /// Example value of a property, preferring its declared default
+    pub fn property(&self, prop: &Property) -> Value {
+        self.property_at(prop, &mut Vec::new())
+    }
+
+    /// Example value of an IR type
+    pub fn value(&self, ir_type: &str) -> Value {
+        self.value_at(ir_type, "", None, &mut Vec::new())
+    }
+
+    /// The `n`th identifier of a value object, such as `ord_2`
+    pub fn identifier(&self, vo: &ValueObject, n: usize) -> Value {
+        let prefix = vo.prefix.as_deref().unwrap_or_default();
+        match (vo.underlying_type.as_deref(), vo.format.as_deref()) {
+            (Some("Integer"), _) => json!(n),
+            (Some("Uuid"), _) => json!(uuid(n)),
+            (_, Some("uuid")) => json!(format!("{}{}", prefix, uuid(n))),
+            _ if !prefix.is_empty() => json!(format!("{}{}", prefix, n)),
+            _ => json!(format!("{}-{}", to_kebab_case(&vo.name), n)),
+        }
+    }
+
+    /// Example object of `properties`, inside the types being built in
+    /// `visiting`
+    fn object_at(&self, properties: &[Property], visiting: &mut Vec<String>) -> Value {
+        let mut object = Map::new();
+        for prop in properties {
+            object.insert(to_snake_case(&prop.name), self.property_at(prop, visiting));
+        }
+        Value::Object(object)
+    }
+
+    fn property_at(&self, prop: &Property, visiting: &mut Vec<String>) -> Value {
+        match &prop.default {
+            Some(default) => self.default_value(&prop.prop_type, default),
+            // An optional property of a type being built ends the recursion
+            None if !prop.required && recursive(&prop.prop_type, visiting) => Value::Null,
+            None => self.value_at(
+                &prop.prop_type,
+                &prop.name,
+                prop.validation.as_ref(),
+                visiting,
+            ),
+        }
+    }
+
+    /// IR defaults name enum values as declared, the wire uses their variant
+    fn default_value(&self, ir_type: &str, default: &Value) -> Value {
+        match (self.model.value_objects.get(ir_type), default) {
+            (Some(vo), Value::String(name)) if vo.vo_type.as_deref() == Some("enum") => {
+                json!(to_pascal_case(name))
+            }
+            _ => default.clone(),
+        }
+    }
+
+    fn value_at(
+        &self,
+        ir_type: &str,
+        name: &str,
+        validation: Option<&Validation>,
+        visiting: &mut Vec<String>,
+    ) -> Value {
+        let ir_type = ir_type.trim();
+        if let Some(item) = generic(ir_type, "List<").or_else(|| generic(ir_type, "Vec<")) {
+            // Length rules of a list bound its number of items
+            let count = validation
+                .and_then(|rule| rule.min_length)
+                .unwrap_or(1)
+                .max(1)
+                .min(validation.and_then(|rule| rule.max_length).unwrap_or(1));
+            if recursive(item, visiting) {
+                return json!([]);
+            }
+            let item = self.value_at(item, name, None, visiting);
+            return Value::Array(vec![item; count]);
+        }
+        if let Some(inner) = generic(ir_type, "Option<") {
+            if recursive(inner, visiting) {
+                return Value::Null;
+            }
+            return self.value_at(inner, name, validation, visiting);
+        }
+        if let Some(item) = generic(ir_type, "PagedResult<") {
+            return json!({
+                "items": [self.value_at(item, name, None, visiting)],
+                "total": 1,
+                "page": 1,
+                "page_size": 20,
+            });
+        }
+
+        match ir_type {
+            "String" => json!(string(name, validation)),
+            "Integer" => json!(bounded(1.0, validation) as i64),
+            "Decimal" => {
+                let places = validation.and_then(|rule| rule.precision).unwrap_or(2);
+                json!(format!("{:.*}", places, bounded(10.0, validation)))
+            }
+            "Boolean" => json!(true),
+            "DateTime" => json!("2024-01-01T09:00:00Z"),
+            "Date" => json!("2024-01-01"),
+            "Time" => json!("09:00:00"),
+            "Uuid" => json!(uuid(1)),
+            _ => {
+                let name = to_rust_type(ir_type);
+                if let Some(vo) = self.model.value_objects.get(&name) {
+                    match vo.vo_type.as_deref() {
+                        Some("enum") => vo
+                            .values
+                            .first()
+                            .map(|value| json!(to_pascal_case(&value.name)))
+                            .unwrap_or(Value::Null),
+                        Some("identifier") => self.identifier(vo, 1),
+                        _ => self.nested(&name, &vo.properties, visiting),
+                    }
+                } else if let Some(aggregate) = self.model.aggregates.get(&name) {
+                    self.nested(&name, &aggregate.root_entity.properties, visiting)
+                } else {
+                    // Types the IR does not define are kept as raw JSON
+                    json!({})
+                }
+            }
+        }
+    }
+
+    /// Example object of a value object or aggregate named `name`
+    ///
+    /// A type that requires a value of its own type has no finite example
+    /// and is left empty inside itself.
+    fn nested(&self, name: &str, properties: &[Property], visiting: &mut Vec<String>) -> Value {
+        if visiting.iter().any(|visited| visited == name) {
+            return json!({});
+        }
+        visiting.push(name.to_string());
+        let object = self.object_at(properties, visiting);
+        visiting.pop();
+        object
+    }
+}
+
+/// Whether an IR type refers to one of the types being built
+fn recursive(ir_type: &str, visiting: &[String]) -> bool {
+    referenced_type(ir_type).is_some_and(|name| visiting.contains(&name))
+}
+
+/// Deterministic version 4 UUID numbered `n`
+pub(super) fn uuid(n: usize) -> String {
+    format!("00000000-0000-4000-8000-{:012x}", n)
+}
+
+/// `preferred` moved into the `min`/`max` range of a rule
+pub(crate) fn bounded(preferred: f64, validation: Option<&Validation>) -> f64 {
+    let min = validation.and_then(|rule| rule.min);
+    let max = validation.and_then(|rule| rule.max);
+    let value = min.map_or(preferred, |min| preferred.max(min));
+    max.map_or(value, |max| value.min(max))
+}
+
+/// Example string named after its property, or matching its pattern
+pub(crate) fn string(name: &str, validation: Option<&Validation>) -> String {
+    let pattern = validation.and_then(|rule| rule.pattern.as_deref());
+    let mut text = match pattern.and_then(|pattern| regex_syntax::parse(pattern).ok()) {
+        Some(hir) => {
+            let mut text = String::new();
+            matching(&hir, &mut text);
+            text
+        }
+        None => hinted(name),
+    };
+
+    let length = text.chars().count();
+    if let Some(max) = validation.and_then(|rule| rule.max_length) {
+        if length > max {
+            text = text.chars().take(max).collect();
+        }
+    }
+    if let Some(min) = validation.and_then(|rule| rule.min_length) {
+        if length < min {
+            text.extend(std::iter::repeat_n('x', min - length));
+        }
+    }
+    text
+}
+
+/// Sample text suggested by a property name
+fn hinted(name: &str) -> String {
+    let words = to_snake_case(name);
+    let has = |word: &str| words.split('_').any(|part| part == word);
+    if has("email") {
+        "jane.doe@example.com".to_string()
+    } else if has("url") || has("uri") || has("website") {
+        "https://example.com".to_string()
+    } else if has("phone") {
+        "+1 555 0100".to_string()
+    } else {
+        let mut chars = words.chars();
+        match chars.next() {
+            Some(first) => format!(
+                "{}{} 1",
+                first.to_uppercase(),
+                chars.as_str().replace('_', " ")
+            ),
+            None => "example".to_string(),
+        }
+    }
+}
+
+/// Append the shortest text matching a regular expression: the first
+/// alternative, the minimum repetitions and the first character of a class
+fn matching(hir: &Hir, text: &mut String) {
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => {}
+        HirKind::Literal(literal) => text.push_str(&String::from_utf8_lossy(&literal.0)),
+        HirKind::Class(Class::Unicode(class)) => {
+            if let Some(range) = class.ranges().first() {
+                text.push(range.start());
+            }
+        }
+        HirKind::Class(Class::Bytes(class)) => {
+            if let Some(range) = class.ranges().first() {
+                text.push(char::from(range.start()));
+            }
+        }
+        HirKind::Repetition(repetition) => {
+            for _ in 0..repetition.min {
+                matching(&repetition.sub, text);
+            }
+        }
+        HirKind::Capture(capture) => matching(&capture.sub, text),
+        HirKind::Concat(parts) => parts.iter().for_each(|part| matching(part, text)),
+        HirKind::Alternation(alternatives) => {
+            if let Some(first) = alternatives.first() {
+                matching(first, text);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+
+    #[test]
+    fn test_strings_satisfy_their_rules() {
+        for pattern in ["^[A-Z]{3}$", "^[0-9]{6}$", r"^(INV|ORD)-\d{4,}$", "^a+b?$"] {
+            let validation = Validation {
+                min: None,
+                max: None,
+                min_length: None,
+                max_length: None,
+                pattern: Some(pattern.to_string()),
+                precision: None,
+            };
+            let text = string("code", Some(&validation));
+            assert!(Regex::new(pattern).unwrap().is_match(&text), "{}", text);
+        }
+        assert_eq!(string("trackingNumber", None), "Tracking number 1");
+        assert_eq!(string("contactEmail", None), "jane.doe@example.com");
+    }
+
+    #[test]
+    fn test_numbers_stay_in_range() {
+        let validation = Validation {
+            min: Some(5.0),
+            max: Some(8.0),
+            min_length: None,
+            max_length: None,
+            pattern: None,
+            precision: Some(3),
+        };
+        assert_eq!(bounded(1.0, Some(&validation)), 5.0);
+        assert_eq!(bounded(10.0, Some(&validation)), 8.0);
+        assert_eq!(bounded(10.0, None), 10.0);
+    }
+
+    #[test]
+    fn test_recursive_types_end_at_optional_fields() {
+        let model = crate::ir::IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Catalog"
+  namespace: "acme.catalog"
+value_objects:
+  Money:
+    name: "Money"
+    properties:
+      - name: "amount"
+        type: "Decimal"
+  Category:
+    name: "Category"
+    properties:
+      - name: "name"
+        type: "String"
+      - name: "parent"
+        type: "Category"
+        required: false
+      - name: "children"
+        type: "List<Category>"
+      - name: "price"
+        type: "List<Option<Money>>"
+"#,
+        )
+        .unwrap();
+        let example = Examples::new(&model).value("List<Category>");
+        assert_eq!(
+            example,
+            json!([{
+                "name": "Name 1",
+                "parent": null,
+                "children": [],
+                "price": [{ "amount": "10.00" }],
+            }])
+        );
+    }
+}
@@ -0,0 +1,505 @@
+//! Mock service for a bounded context, run by `stormforge-generator mock`
+//!
+//! Serves the command and query routes of the generated service so that
+//! clients can be built before the service exists. Requests are checked
+//! against the validation rules of the IR and answered with example data.
+//! In stateful mode aggregates are kept in memory: commands apply the
+//! events they produce and queries read the resulting aggregates back.
+
+mod examples;
+mod server;
+mod validate;
+
+pub use examples::Examples;
+pub(crate) use examples::{bounded, string};
+pub use server::serve;
+pub use validate::Validator;
+
+use crate::generators::utils::*;
+use crate::ir::{Aggregate, Command, IRModel, Property, Query};
+use indexmap::IndexMap;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+/// Time stamped on mocked events
+const OCCURRED_AT: &str = "2024-01-01T09:00:00Z";
+
+/// HTTP status and JSON body of a mocked response
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockResponse {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl MockResponse {
+    fn ok(body: Value) -> Self {
+        Self { status: 200, body }
+    }
+
+    /// Error in the `ApiError` layout of the generated service
+    fn error(status: u16, code: &str, message: String) -> Self {
+        Self {
+            status,
+            body: json!({ "code": code, "message": message }),
+        }
+    }
+}
+
+/// Aggregates applied so far, by aggregate name and identifier
+#[derive(Default)]
+struct Store {
+    aggregates: IndexMap<String, IndexMap<String, Value>>,
+    created: usize,
+}
+
+/// Answers the commands and queries of one bounded context
+pub struct MockService {
+    model: IRModel,
+    store: Option<Mutex<Store>>,
+    events: AtomicUsize,
+}
+
+impl MockService {
+    /// Mock the model, keeping aggregates in memory when `stateful`
+    pub fn new(model: IRModel, stateful: bool) -> Self {
+        Self {
+            model,
+            store: stateful.then(|| Mutex::new(Store::default())),
+            events: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn model(&self) -> &IRModel {
+        &self.model
+    }
+
+    pub fn is_stateful(&self) -> bool {
+        self.store.is_some()
+    }
+
+    /// Handle `POST /api/<command>` with a JSON body
+    ///
+    /// The response lists the events the command produced, with fields
+    /// copied from the request where the names match.
+    pub fn command(&self, name: &str, body: &Value) -> MockResponse {
+        let Some(command) = self.model.commands.get(name) else {
+            return MockResponse::error(404, "NOT_FOUND", format!("Unknown command {}", name));
+        };
+        let problems = Validator::new(&self.model).check(&command.payload, body);
+        if !problems.is_empty() {
+            return MockResponse::error(
+                400,
+                "VALIDATION_ERROR",
+                format!("Validation error: {}", problems.join("; ")),
+            );
+        }
+
+        let aggregate = command
+            .aggregate
+            .as_ref()
+            .and_then(|name| self.model.aggregates.get(name));
+        let mut store = self.store();
+        let id = match (aggregate, store.as_deref_mut()) {
+            (Some(aggregate), Some(store)) => match self.target(aggregate, command, body) {
+                Some(id) => {
+                    let exists = store
+                        .aggregates
+                        .get(&aggregate.name)
+                        .is_some_and(|instances| instances.contains_key(&key(&id)));
+                    if !exists {
+                        return MockResponse::error(
+                            404,
+                            "NOT_FOUND",
+                            format!("{} {} not found", aggregate.name, key(&id)),
+                        );
+                    }
+                    Some(id)
+                }
+                // Commands without the identifier create a new aggregate
+                None => {
+                    store.created += 1;
+                    Some(self.new_id(aggregate, store.created))
+                }
+            },
+            (Some(aggregate), None) => Some(
+                self.target(aggregate, command, body)
+                    .unwrap_or_else(|| self.new_id(aggregate, 1)),
+            ),
+            (None, _) => None,
+        };
+
+        let events: Vec<Value> = command
+            .produces
+            .iter()
+            .filter_map(|name| self.model.events.get(name))
+            .map(|event| {
+                let number = self.events.fetch_add(1, Ordering::Relaxed) + 1;
+                let mut object = Map::new();
+                object.insert("type".to_string(), json!(event.name));
+                object.insert("event_id".to_string(), json!(examples::uuid(number)));
+                object.insert("occurred_at".to_string(), json!(OCCURRED_AT));
+                if let (Some(_), Some(id)) = (&event.aggregate, &id) {
+                    object.insert("aggregate_id".to_string(), json!(key(id)));
+                }
+                for prop in &event.payload {
+                    let field = to_snake_case(&prop.name);
+                    let value = match (&id, aggregate) {
+                        (Some(id), Some(aggregate))
+                            if Some(prop.prop_type.trim()) == id_type(aggregate) =>
+                        {
+                            id.clone()
+                        }
+                        _ => body
+                            .get(&field)
+                            .filter(|value| !value.is_null())
+                            .cloned()
+                            .unwrap_or_else(|| Examples::new(&self.model).property(prop)),
+                    };
+                    object.insert(field, value);
+                }
+                Value::Object(object)
+            })
+            .collect();
+
+        if let (Some(store), Some(aggregate), Some(id)) = (store.as_deref_mut(), aggregate, &id) {
+            let instance = store
+                .aggregates
+                .entry(aggregate.name.clone())
+                .or_default()
+                .entry(key(id))
+                .or_insert_with(|| self.initial(aggregate, id));
+            for event in &events {
+                apply(&self.model, aggregate, instance, event);
+            }
+        }
+
+        MockResponse::ok(json!({
+            "data": "Command executed successfully",
+            "events": events,
+        }))
+    }
+
+    /// Handle `GET /api/<query>` with its query string parameters
+    ///
+    /// Stateful mode answers queries returning aggregates from the store,
+    /// filtered by the parameters that name aggregate fields.
+    pub fn query(&self, name: &str, params: &HashMap<String, String>) -> MockResponse {
+        let Some(query) = self.model.queries.get(name) else {
+            return MockResponse::error(404, "NOT_FOUND", format!("Unknown query {}", name));
+        };
+        let arguments = self.arguments(query, params);
+        let problems = Validator::new(&self.model).check(&query.parameters, &arguments);
+        if !problems.is_empty() {
+            return MockResponse::error(
+                400,
+                "VALIDATION_ERROR",
+                format!("Validation error: {}", problems.join("; ")),
+            );
+        }
+
+        let Some(returns) = &query.returns else {
+            return MockResponse::ok(json!({ "data": null }));
+        };
+        let return_type = returns.return_type.trim();
+        let stored = self.store().and_then(|store| {
+            let item = referenced_type(return_type)?;
+            let aggregate = self.model.aggregates.get(&item)?;
+            let instances = store.aggregates.get(&item).cloned().unwrap_or_default();
+            Some((aggregate, instances))
+        });
+        let Some((aggregate, instances)) = stored else {
+            return MockResponse::ok(
+                json!({ "data": Examples::new(&self.model).value(return_type) }),
+            );
+        };
+
+        let matches: Vec<Value> = instances
+            .into_values()
+            .filter(|instance| self.matches(aggregate, query, &arguments, instance))
+            .collect();
+        let data = if return_type.starts_with("List<") || return_type.starts_with("Vec<") {
+            json!(matches)
+        } else if return_type.starts_with("PagedResult<") {
+            let number = |name: &str, default: i64| {
+                arguments
+                    .get(name)
+                    .and_then(Value::as_i64)
+                    .unwrap_or(default)
+                    .max(1)
+            };
+            let (page, page_size) = (number("page", 1), number("page_size", 20));
+            let items: Vec<&Value> = matches
+                .iter()
+                .skip(((page - 1) * page_size) as usize)
+                .take(page_size as usize)
+                .collect();
+            json!({
+                "items": items,
+                "total": matches.len(),
+                "page": page,
+                "page_size": page_size,
+            })
+        } else {
+            match matches.into_iter().next() {
+                Some(instance) => instance,
+                None if returns.nullable => Value::Null,
+                None => {
+                    return MockResponse::error(
+                        404,
+                        "NOT_FOUND",
+                        format!("No {} matches the query", aggregate.name),
+                    )
+                }
+            }
+        };
+        MockResponse::ok(json!({ "data": data }))
+    }
+
+    fn store(&self) -> Option<MutexGuard<'_, Store>> {
+        self.store
+            .as_ref()
+            .map(|store| store.lock().unwrap_or_else(|error| error.into_inner()))
+    }
+
+    /// Identifier of the aggregate a command addresses, if it has one
+    fn target(&self, aggregate: &Aggregate, command: &Command, body: &Value) -> Option<Value> {
+        let id_type = id_type(aggregate)?;
+        command
+            .payload
+            .iter()
+            .find(|prop| prop.prop_type.trim() == id_type)
+            .and_then(|prop| body.get(to_snake_case(&prop.name)))
+            .filter(|value| !value.is_null())
+            .cloned()
+    }
+
+    /// The `n`th identifier of an aggregate
+    fn new_id(&self, aggregate: &Aggregate, n: usize) -> Value {
+        let id_type = id_type(aggregate).unwrap_or("String");
+        match self.model.value_objects.get(id_type) {
+            Some(vo) if vo.vo_type.as_deref() == Some("identifier") => {
+                Examples::new(&self.model).identifier(vo, n)
+            }
+            _ => match id_type {
+                "Integer" => json!(n),
+                "Uuid" => json!(examples::uuid(n)),
+                _ => json!(format!("{}-{}", to_kebab_case(&aggregate.name), n)),
+            },
+        }
+    }
+
+    /// New aggregate with declared defaults and example data
+    fn initial(&self, aggregate: &Aggregate, id: &Value) -> Value {
+        let mut instance = Examples::new(&self.model).object(&aggregate.root_entity.properties);
+        if let Some(prop) = identifier(aggregate) {
+            instance[to_snake_case(&prop.name)] = id.clone();
+        }
+        instance
+    }
+
+    /// Query string parameters as JSON of their IR types, with defaults
+    fn arguments(&self, query: &Query, params: &HashMap<String, String>) -> Value {
+        let mut arguments = Map::new();
+        for prop in &query.parameters {
+            let field = to_snake_case(&prop.name);
+            let value = match params.get(&field).or_else(|| params.get(&prop.name)) {
+                Some(text) => self.parse(&prop.prop_type, text),
+                None => match &prop.default {
+                    Some(default) => default.clone(),
+                    None => continue,
+                },
+            };
+            arguments.insert(field, value);
+        }
+        Value::Object(arguments)
+    }
+
+    /// Query string value as JSON, left a string when it does not parse
+    fn parse(&self, ir_type: &str, text: &str) -> Value {
+        let ir_type = ir_type.trim();
+        if let Some(inner) = generic(ir_type, "Option<") {
+            return self.parse(inner, text);
+        }
+        let underlying = self
+            .model
+            .value_objects
+            .get(ir_type)
+            .and_then(|vo| vo.underlying_type.as_deref());
+        match underlying.unwrap_or(ir_type) {
+            "Integer" => text.parse::<i64>().map(Value::from).ok(),
+            "Boolean" => text.parse::<bool>().map(Value::from).ok(),
+            _ => None,
+        }
+        .unwrap_or_else(|| json!(text))
+    }
+
+    /// Whether an aggregate has the field values a query asks for
+    ///
+    /// Parameters of the identifier type select by identifier; parameters
+    /// naming no aggregate field, such as paging, do not filter.
+    fn matches(
+        &self,
+        aggregate: &Aggregate,
+        query: &Query,
+        arguments: &Value,
+        instance: &Value,
+    ) -> bool {
+        query.parameters.iter().all(|prop| {
+            let Some(expected) = arguments.get(to_snake_case(&prop.name)) else {
+                return true;
+            };
+            let field = match identifier(aggregate) {
+                Some(id) if Some(prop.prop_type.trim()) == id_type(aggregate) => {
+                    to_snake_case(&id.name)
+                }
+                _ => to_snake_case(&prop.name),
+            };
+            instance.get(&field).is_none_or(|actual| actual == expected)
+        })
+    }
+}
+
+/// Apply an event: fields named like aggregate fields are copied, and an
+/// enum field moves to the value named by the event, so `OrderPaid` sets
+/// an `OrderStatus` to `Paid`
+fn apply(model: &IRModel, aggregate: &Aggregate, instance: &mut Value, event: &Value) {
+    let name = event["type"].as_str().unwrap_or_default();
+    let transition = name.strip_prefix(aggregate.name.as_str()).unwrap_or(name);
+    for prop in &aggregate.root_entity.properties {
+        let field = to_snake_case(&prop.name);
+        if prop.identifier {
+            continue;
+        }
+        if let Some(value) = event.get(&field) {
+            instance[field] = value.clone();
+        } else if let Some(vo) = model.value_objects.get(prop.prop_type.trim()) {
+            let reached = vo
+                .values
+                .iter()
+                .map(|value| to_pascal_case(&value.name))
+                .find(|variant| variant == transition);
+            if let (Some("enum"), Some(variant)) = (vo.vo_type.as_deref(), reached) {
+                instance[field] = json!(variant);
+            }
+        }
+    }
+}
+
+/// Identifier property of an aggregate root
+fn identifier(aggregate: &Aggregate) -> Option<&Property> {
+    let properties = &aggregate.root_entity.properties;
+    properties
+        .iter()
+        .find(|prop| prop.identifier)
+        .or_else(|| properties.iter().find(|prop| prop.name == "id"))
+}
+
+fn id_type(aggregate: &Aggregate) -> Option<&str> {
+    identifier(aggregate).map(|prop| prop.prop_type.trim())
+}
+
+/// Store key of an identifier
+fn key(id: &Value) -> String {
+    id.as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| id.to_string())
+}
+
+/// Argument of a generic IR type such as `List<T>`
+fn generic<'t>(ir_type: &'t str, prefix: &str) -> Option<&'t str> {
+    ir_type
+        .strip_prefix(prefix)
+        .map(|rest| rest.strip_suffix('>').unwrap_or(rest).trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+
+    fn service(stateful: bool) -> MockService {
+        let model =
+            IRParser::parse_file("../ir_schema/examples/ecommerce/order_context.yaml").unwrap();
+        MockService::new(model, stateful)
+    }
+
+    fn create_order() -> Value {
+        json!({
+            "customer_id": "cust_42",
+            "items": [{
+                "product_id": "prod_1",
+                "product_name": "Tea",
+                "quantity": 2,
+                "unit_price": { "amount": "3.50", "currency": "EUR" },
+                "subtotal": { "amount": "7.00", "currency": "EUR" },
+            }],
+            "shipping_address": {
+                "street": "1 Main St",
+                "city": "Hangzhou",
+                "province": "Zhejiang",
+                "postal_code": "310000",
+                "country": "CN",
+            },
+        })
+    }
+
+    #[test]
+    fn test_commands_are_validated_and_answered_with_events() {
+        let service = service(false);
+        let mut order = create_order();
+        order["items"][0]["quantity"] = json!(0);
+        let response = service.command("CreateOrder", &order);
+        assert_eq!(response.status, 400);
+        assert_eq!(
+            response.body["message"],
+            "Validation error: items[0].quantity must be at least 1"
+        );
+
+        let response = service.command("CreateOrder", &create_order());
+        assert_eq!(response.status, 200);
+        let event = &response.body["events"][0];
+        assert_eq!(event["type"], "OrderCreated");
+        assert_eq!(event["customer_id"], "cust_42");
+        assert_eq!(event["total_amount"]["amount"], "10.00");
+
+        let response = service.query("GetOrdersByStatus", &HashMap::new());
+        assert_eq!(response.status, 400);
+        let params = HashMap::from([("status".to_string(), "Paid".to_string())]);
+        let response = service.query("GetOrdersByStatus", &params);
+        assert_eq!(response.body["data"][0]["status"], "Created");
+        assert_eq!(
+            response.body["data"][0]["shipping_address"]["postal_code"],
+            "000000"
+        );
+    }
+
+    #[test]
+    fn test_stateful_mode_applies_events_to_aggregates() {
+        let service = service(true);
+        let created = service.command("CreateOrder", &create_order());
+        let id = created.body["events"][0]["order_id"].clone();
+        assert_eq!(id, "ord_00000000-0000-4000-8000-000000000001");
+
+        let payment = json!({ "order_id": id, "payment_id": "pay_1" });
+        assert_eq!(service.command("ConfirmPayment", &payment).status, 200);
+        let unknown = json!({ "order_id": "ord_missing", "payment_id": "pay_1" });
+        assert_eq!(service.command("ConfirmPayment", &unknown).status, 404);
+
+        let params = HashMap::from([("order_id".to_string(), key(&id))]);
+        let order = service.query("GetOrder", &params).body["data"].clone();
+        assert_eq!(order["id"], id);
+        assert_eq!(order["status"], "Paid");
+        assert_eq!(order["customer_id"], "cust_42");
+
+        let params = HashMap::from([("status".to_string(), "Created".to_string())]);
+        let page = service.query("ListOrders", &params).body["data"].clone();
+        assert_eq!(page["total"], 0);
+        let missing = HashMap::from([("order_id".to_string(), "ord_missing".to_string())]);
+        assert_eq!(
+            service.query("GetOrder", &missing).body["data"],
+            Value::Null
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use super::{MockResponse, MockService};
+use crate::generators::utils::to_kebab_case;
+use anyhow::{Context, Result};
+use axum::body::Bytes;
+use axum::extract::Query;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tower_http::cors::CorsLayer;
+
+/// Serve the mock on `port` until the process is stopped
+///
+/// Routes are nested under `/api` like those of the generated service, and
+/// any origin may call them so that frontend dev servers can.
+pub async fn serve(service: MockService, port: u16) -> Result<()> {
+    let service = Arc::new(service);
+    let mut api = Router::new();
+    for name in service.model().commands.keys() {
+        let path = format!("/{}", to_kebab_case(name));
+        let route = format!("POST /api{}", path);
+        let (service, name) = (service.clone(), name.clone());
+        api = api.route(
+            &path,
+            post(move |body: Bytes| async move {
+                let response = match serde_json::from_slice(&body) {
+                    Ok(body) => service.command(&name, &body),
+                    Err(error) => MockResponse::error(
+                        400,
+                        "VALIDATION_ERROR",
+                        format!("Validation error: invalid JSON body: {}", error),
+                    ),
+                };
+                respond(&route, response)
+            }),
+        );
+    }
+    for name in service.model().queries.keys() {
+        let path = format!("/{}", to_kebab_case(name));
+        let route = format!("GET /api{}", path);
+        let (service, name) = (service.clone(), name.clone());
+        api = api.route(
+            &path,
+            get(
+                move |Query(params): Query<HashMap<String, String>>| async move {
+                    respond(&route, service.query(&name, &params))
+                },
+            ),
+        );
+    }
+    let app = Router::new()
+        .nest("/api", api)
+        .layer(CorsLayer::permissive());
+
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to listen on {}", addr))?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+/// Log a request and turn its mocked response into an HTTP one
+fn respond(route: &str, response: MockResponse) -> Response {
+    println!("   {} → {}", route, response.status);
+    let status = StatusCode::from_u16(response.status).unwrap_or(StatusCode::OK);
+    (status, Json(response.body)).into_response()
+}
@@ -0,0 +1,363 @@
+use super::generic;
+use crate::generators::utils::*;
+use crate::ir::{IRModel, Property, Validation};
+use regex::Regex;
+use serde_json::Value;
+use std::sync::LazyLock;
+
+static DATE_TIME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\d{4}-\d{2}-\d{2}[Tt ]\d{2}:\d{2}:\d{2}(\.\d+)?([Zz]|[+-]\d{2}:\d{2})$").unwrap()
+});
+static DATE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap());
+static TIME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\d{2}:\d{2}(:\d{2}(\.\d+)?)?$").unwrap());
+static UUID: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[0-9a-fA-F]{8}-?([0-9a-fA-F]{4}-?){3}[0-9a-fA-F]{12}$").unwrap()
+});
+static DECIMAL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^-?\d+(\.\d+)?$").unwrap());
+
+/// Checks JSON against IR properties the way the generated service would
+/// deserialize and validate it
+///
+/// Problems are reported with the path of the offending field, such as
+/// `items[0].quantity must be at least 1`.
+pub struct Validator<'a> {
+    model: &'a IRModel,
+}
+
+impl<'a> Validator<'a> {
+    pub fn new(model: &'a IRModel) -> Self {
+        Self { model }
+    }
+
+    /// Problems of an object with the given properties, empty when valid
+    pub fn check(&self, properties: &[Property], value: &Value) -> Vec<String> {
+        let mut problems = Vec::new();
+        if value.is_object() {
+            self.check_object(properties, value, "", &mut problems);
+        } else {
+            problems.push("request body must be a JSON object".to_string());
+        }
+        problems
+    }
+
+    fn check_object(
+        &self,
+        properties: &[Property],
+        value: &Value,
+        path: &str,
+        problems: &mut Vec<String>,
+    ) {
+        let Some(object) = value.as_object() else {
+            problems.push(format!("{} must be an object", path));
+            return;
+        };
+        for prop in properties {
+            let name = to_snake_case(&prop.name);
+            let field_path = if path.is_empty() {
+                name.clone()
+            } else {
+                format!("{}.{}", path, name)
+            };
+            match object.get(&name) {
+                None | Some(Value::Null) => {
+                    let optional = !prop.required
+                        || prop.prop_type.trim().starts_with("Option<")
+                        || prop.computed.is_some();
+                    if !optional {
+                        problems.push(format!("{} is required", field_path));
+                    }
+                }
+                Some(field) => self.check_value(
+                    &prop.prop_type,
+                    prop.validation.as_ref(),
+                    field,
+                    &field_path,
+                    problems,
+                ),
+            }
+        }
+    }
+
+    fn check_value(
+        &self,
+        ir_type: &str,
+        validation: Option<&Validation>,
+        value: &Value,
+        path: &str,
+        problems: &mut Vec<String>,
+    ) {
+        let ir_type = ir_type.trim();
+        if let Some(item) = generic(ir_type, "List<").or_else(|| generic(ir_type, "Vec<")) {
+            let Some(items) = value.as_array() else {
+                problems.push(format!("{} must be an array", path));
+                return;
+            };
+            if let Some(min) = validation.and_then(|rule| rule.min_length) {
+                if items.len() < min {
+                    problems.push(format!("{} must have at least {} items", path, min));
+                }
+            }
+            if let Some(max) = validation.and_then(|rule| rule.max_length) {
+                if items.len() > max {
+                    problems.push(format!("{} must have at most {} items", path, max));
+                }
+            }
+            for (index, item_value) in items.iter().enumerate() {
+                let item_path = format!("{}[{}]", path, index);
+                self.check_value(item, None, item_value, &item_path, problems);
+            }
+            return;
+        }
+        if let Some(inner) = generic(ir_type, "Option<") {
+            if !value.is_null() {
+                self.check_value(inner, validation, value, path, problems);
+            }
+            return;
+        }
+
+        match ir_type {
+            "String" => match value.as_str() {
+                Some(text) => check_string(text, validation, path, problems),
+                None => problems.push(format!("{} must be a string", path)),
+            },
+            "Integer" => match value.as_i64() {
+                Some(number) => check_range(number as f64, validation, path, problems),
+                None => problems.push(format!("{} must be an integer", path)),
+            },
+            // Decimals are sent as strings to keep their precision, but
+            // the generated service also accepts numbers
+            "Decimal" => {
+                let number = match value {
+                    Value::String(text) if DECIMAL.is_match(text) => text.parse::<f64>().ok(),
+                    Value::Number(number) => number.as_f64(),
+                    _ => None,
+                };
+                match number {
+                    Some(number) => check_range(number, validation, path, problems),
+                    None => problems.push(format!("{} must be a decimal number", path)),
+                }
+            }
+            "Boolean" => {
+                if !value.is_boolean() {
+                    problems.push(format!("{} must be a boolean", path));
+                }
+            }
+            "DateTime" => check_format(value, &DATE_TIME, "an RFC 3339 date-time", path, problems),
+            "Date" => check_format(value, &DATE, "a date (YYYY-MM-DD)", path, problems),
+            "Time" => check_format(value, &TIME, "a time (HH:MM:SS)", path, problems),
+            "Uuid" => check_format(value, &UUID, "a UUID", path, problems),
+            _ => {
+                let name = to_rust_type(ir_type);
+                if let Some(vo) = self.model.value_objects.get(&name) {
+                    match vo.vo_type.as_deref() {
+                        Some("enum") => {
+                            let variants: Vec<String> = vo
+                                .values
+                                .iter()
+                                .map(|value| to_pascal_case(&value.name))
+                                .collect();
+                            if !value
+                                .as_str()
+                                .is_some_and(|text| variants.iter().any(|v| v == text))
+                            {
+                                problems.push(format!(
+                                    "{} must be one of {}",
+                                    path,
+                                    variants.join(", ")
+                                ));
+                            }
+                        }
+                        // Identifiers serialize as their underlying value
+                        Some("identifier") => self.check_value(
+                            vo.underlying_type.as_deref().unwrap_or("String"),
+                            validation,
+                            value,
+                            path,
+                            problems,
+                        ),
+                        _ => self.check_object(&vo.properties, value, path, problems),
+                    }
+                } else if let Some(aggregate) = self.model.aggregates.get(&name) {
+                    self.check_object(&aggregate.root_entity.properties, value, path, problems);
+                }
+                // Types the IR does not define accept any JSON
+            }
+        }
+    }
+}
+
+fn check_string(
+    text: &str,
+    validation: Option<&Validation>,
+    path: &str,
+    problems: &mut Vec<String>,
+) {
+    let Some(validation) = validation else {
+        return;
+    };
+    let length = text.chars().count();
+    if let Some(min) = validation.min_length {
+        if length < min {
+            problems.push(format!("{} must be at least {} characters", path, min));
+        }
+    }
+    if let Some(max) = validation.max_length {
+        if length > max {
+            problems.push(format!("{} must be at most {} characters", path, max));
+        }
+    }
+    if let Some(pattern) = &validation.pattern {
+        // Patterns the regex engine cannot compile are not enforced
+        if Regex::new(pattern).is_ok_and(|regex| !regex.is_match(text)) {
+            problems.push(format!("{} must match {}", path, pattern));
+        }
+    }
+}
+
+fn check_range(
+    number: f64,
+    validation: Option<&Validation>,
+    path: &str,
+    problems: &mut Vec<String>,
+) {
+    if let Some(min) = validation.and_then(|rule| rule.min) {
+        if number < min {
+            problems.push(format!("{} must be at least {}", path, min));
+        }
+    }
+    if let Some(max) = validation.and_then(|rule| rule.max) {
+        if number > max {
+            problems.push(format!("{} must be at most {}", path, max));
+        }
+    }
+}
+
+fn check_format(
+    value: &Value,
+    format: &Regex,
+    expected: &str,
+    path: &str,
+    problems: &mut Vec<String>,
+) {
+    if !value.as_str().is_some_and(|text| format.is_match(text)) {
+        problems.push(format!("{} must be {}", path, expected));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::IRParser;
+    use crate::mock::Examples;
+    use serde_json::json;
+
+    #[test]
+    fn test_reports_each_broken_rule_with_its_path() {
+        let model = IRParser::parse_yaml(
+            r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+value_objects:
+  OrderStatus:
+    name: "OrderStatus"
+    type: "enum"
+    values:
+      - name: "CREATED"
+      - name: "PAID"
+  OrderItem:
+    name: "OrderItem"
+    properties:
+      - name: "quantity"
+        type: "Integer"
+        validation:
+          min: 1
+      - name: "currency"
+        type: "String"
+        validation:
+          pattern: "^[A-Z]{3}$"
+"#,
+        )
+        .unwrap();
+        let properties: Vec<Property> = serde_yaml::from_str(
+            r#"
+- name: "status"
+  type: "OrderStatus"
+- name: "items"
+  type: "List<OrderItem>"
+  validation:
+    maxLength: 2
+- name: "placedAt"
+  type: "DateTime"
+- name: "note"
+  type: "String"
+  required: false
+"#,
+        )
+        .unwrap();
+        let validator = Validator::new(&model);
+
+        let valid = json!({
+            "status": "Paid",
+            "items": [{ "quantity": 2, "currency": "EUR" }],
+            "placed_at": "2024-01-01T09:00:00Z",
+        });
+        assert!(validator.check(&properties, &valid).is_empty());
+
+        let invalid = json!({
+            "status": "PAID",
+            "items": [{ "quantity": 0, "currency": "EUR" }, { "currency": "eur" }, {}],
+        });
+        assert_eq!(
+            validator.check(&properties, &invalid),
+            [
+                "status must be one of Created, Paid",
+                "items must have at most 2 items",
+                "items[0].quantity must be at least 1",
+                "items[1].quantity is required",
+                "items[1].currency must match ^[A-Z]{3}$",
+                "items[2].quantity is required",
+                "items[2].currency is required",
+                "placed_at is required",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_examples_pass_validation() {
+        for file in [
+            "ecommerce/order_context.yaml",
+            "ecommerce/order_context_v2.yaml",
+            "ecommerce/inventory_context.yaml",
+            "ecommerce/payment_context.yaml",
+            "hr/leave_context.yaml",
+        ] {
+            let model = IRParser::parse_file(format!("../ir_schema/examples/{}", file)).unwrap();
+            let examples = Examples::new(&model);
+            let validator = Validator::new(&model);
+
+            for (name, command) in &model.commands {
+                let problems =
+                    validator.check(&command.payload, &examples.object(&command.payload));
+                assert!(problems.is_empty(), "{} {}: {:?}", file, name, problems);
+            }
+            for (name, query) in &model.queries {
+                let Some(returns) = &query.returns else {
+                    continue;
+                };
+                let return_type = returns.return_type.trim();
+                let example = examples.value(return_type);
+                // Pages are checked through their items
+                let (ir_type, value) = match generic(return_type, "PagedResult<") {
+                    Some(item) => (format!("List<{}>", item), example["items"].clone()),
+                    None => (return_type.to_string(), example),
+                };
+                let mut problems = Vec::new();
+                validator.check_value(&ir_type, None, &value, "data", &mut problems);
+                assert!(problems.is_empty(), "{} {}: {:?}", file, name, problems);
+            }
+        }
+    }
+}
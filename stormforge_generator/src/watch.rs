@@ -0,0 +1,402 @@
+//! Continuous regeneration for `generate --watch`
+//!
+//! Generators write a complete output tree, so each run generates into a
+//! staging directory and [`OutputSync`] copies over only the files whose
+//! content changed. Untouched files keep their modification time, which
+//! lets `cargo` and other incremental tools rebuild just what changed.
+
+use anyhow::{Context, Result};
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use walkdir::WalkDir;
+
+/// Quiet period that ends a burst of file system events from one save
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Lines of hook output shown when it fails
+const HOOK_OUTPUT_LINES: usize = 20;
+
+/// Files written, kept and removed by one sync
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    pub written: Vec<PathBuf>,
+    pub unchanged: usize,
+    pub removed: Vec<PathBuf>,
+}
+
+impl SyncReport {
+    pub fn has_changes(&self) -> bool {
+        !self.written.is_empty() || !self.removed.is_empty()
+    }
+}
+
+/// Output directory kept in step with successive generations
+pub struct OutputSync {
+    output: PathBuf,
+    /// Paths generated by the last sync, relative to the output directory
+    generated: BTreeSet<PathBuf>,
+}
+
+impl OutputSync {
+    pub fn new<P: Into<PathBuf>>(output: P) -> Self {
+        Self {
+            output: output.into(),
+            generated: BTreeSet::new(),
+        }
+    }
+
+    /// Copy changed files from a staging directory into the output
+    ///
+    /// Files generated by the previous sync but not by this one are removed;
+    /// files the generator never wrote are left alone.
+    pub fn sync(&mut self, staging: &Path) -> Result<SyncReport> {
+        let mut report = SyncReport::default();
+        let mut generated = BTreeSet::new();
+
+        for entry in WalkDir::new(staging).sort_by_file_name() {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let relative = entry.path().strip_prefix(staging)?.to_path_buf();
+            let target = self.output.join(&relative);
+            let content = fs::read(entry.path())?;
+
+            if fs::read(&target).is_ok_and(|existing| existing == content) {
+                report.unchanged += 1;
+            } else {
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&target, content)
+                    .with_context(|| format!("Failed to write {}", target.display()))?;
+                report.written.push(relative.clone());
+            }
+            generated.insert(relative);
+        }
+
+        for stale in self.generated.difference(&generated) {
+            let target = self.output.join(stale);
+            if target.is_file() {
+                fs::remove_file(&target)
+                    .with_context(|| format!("Failed to remove {}", target.display()))?;
+                report.removed.push(stale.clone());
+            }
+        }
+
+        self.generated = generated;
+        Ok(report)
+    }
+
+    /// Copy the output's migrations and proto files into a staging directory
+    ///
+    /// Generators add a migration for the difference to the existing ones
+    /// rather than rewriting them, and keep the field numbers of the previous
+    /// proto files, so they need to see them.
+    pub fn seed(&self, staging: &Path) -> Result<()> {
+        let entries = WalkDir::new(&self.output)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| {
+                !matches!(
+                    entry.file_name().to_str(),
+                    Some("target" | ".git" | "node_modules")
+                )
+            });
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            let relative = path.strip_prefix(&self.output)?;
+            let is_migration = path.extension().is_some_and(|extension| extension == "sql")
+                && path
+                    .parent()
+                    .and_then(Path::file_name)
+                    .is_some_and(|dir| dir == "migrations");
+            let is_proto = path
+                .extension()
+                .is_some_and(|extension| extension == "proto")
+                && relative.components().any(|dir| dir.as_os_str() == "proto");
+            if !entry.file_type().is_file() || !(is_migration || is_proto) {
+                continue;
+            }
+            let target = staging.join(relative);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(path, &target)
+                .with_context(|| format!("Failed to copy {}", path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Shell command run in the output directory after generation
+pub struct Hook {
+    command: String,
+}
+
+/// Result of a hook run
+#[derive(Debug)]
+pub struct HookOutcome {
+    pub success: bool,
+    pub code: Option<i32>,
+    pub duration: Duration,
+    /// Last lines of stdout and stderr
+    pub output: Vec<String>,
+}
+
+impl Hook {
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+        }
+    }
+
+    pub fn run(&self, dir: &Path) -> Result<HookOutcome> {
+        let started = Instant::now();
+        let mut command = if cfg!(windows) {
+            let mut command = Command::new("cmd");
+            command.arg("/C");
+            command
+        } else {
+            let mut command = Command::new("sh");
+            command.arg("-c");
+            command
+        };
+        let output = command
+            .arg(&self.command)
+            .current_dir(dir)
+            .output()
+            .with_context(|| format!("Failed to run hook `{}`", self.command))?;
+
+        let text = format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let lines: Vec<&str> = text.lines().collect();
+        Ok(HookOutcome {
+            success: output.status.success(),
+            code: output.status.code(),
+            duration: started.elapsed(),
+            output: lines[lines.len().saturating_sub(HOOK_OUTPUT_LINES)..]
+                .iter()
+                .map(|line| line.to_string())
+                .collect(),
+        })
+    }
+
+    /// Run the hook and print a one-line summary, plus its output on failure
+    pub fn run_and_report(&self, dir: &Path) {
+        println!("🪝 Running hook: {}", self.command);
+        match self.run(dir) {
+            Ok(outcome) if outcome.success => {
+                println!("✅ Hook passed in {:.1}s", outcome.duration.as_secs_f64())
+            }
+            Ok(outcome) => {
+                let code = outcome
+                    .code
+                    .map(|code| format!(" with exit code {}", code))
+                    .unwrap_or_default();
+                println!(
+                    "❌ Hook failed{} in {:.1}s",
+                    code,
+                    outcome.duration.as_secs_f64()
+                );
+                for line in &outcome.output {
+                    println!("   │ {}", line);
+                }
+            }
+            Err(error) => println!("❌ {:#}", error),
+        }
+    }
+}
+
+/// Call `on_change` with the changed IR files after every save, forever
+///
+/// Files are watched through their directory so that editors replacing the
+/// file on save are noticed. Changes under `ignore` (the output directory)
+/// are skipped, so generated YAML never triggers another run.
+pub fn watch(
+    inputs: &[PathBuf],
+    ignore: &Path,
+    mut on_change: impl FnMut(&[PathBuf]),
+) -> Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+
+    let inputs: Vec<PathBuf> = inputs
+        .iter()
+        .map(|input| fs::canonicalize(input).unwrap_or_else(|_| input.clone()))
+        .collect();
+    let ignore = fs::canonicalize(ignore).unwrap_or_else(|_| ignore.to_path_buf());
+    for input in &inputs {
+        if input.is_dir() {
+            watcher.watch(input, RecursiveMode::Recursive)?;
+        } else {
+            let dir = input.parent().unwrap_or(Path::new("."));
+            watcher.watch(dir, RecursiveMode::NonRecursive)?;
+        }
+    }
+
+    let relevant = |path: &Path| {
+        !path.starts_with(&ignore)
+            && inputs.iter().any(|input| {
+                if input.is_dir() {
+                    path.starts_with(input)
+                        && path
+                            .extension()
+                            .is_some_and(|ext| ext == "yaml" || ext == "yml")
+                } else {
+                    path == input
+                }
+            })
+    };
+
+    loop {
+        let mut changed = BTreeSet::new();
+        let mut deadline: Option<Instant> = None;
+        loop {
+            let event = match deadline {
+                None => receiver.recv().map_err(anyhow::Error::from),
+                Some(deadline) => {
+                    match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    {
+                        Ok(event) => Ok(event),
+                        Err(mpsc::RecvTimeoutError::Timeout) => break,
+                        Err(error) => Err(error.into()),
+                    }
+                }
+            };
+            let event = event.context("File watcher stopped")??;
+            if matches!(event.kind, EventKind::Access(_)) {
+                continue;
+            }
+            for path in event.paths.into_iter().filter(|path| relevant(path)) {
+                changed.insert(path);
+                deadline = Some(Instant::now() + DEBOUNCE);
+            }
+        }
+        on_change(&changed.into_iter().collect::<Vec<_>>());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_writes_only_changed_files() {
+        let output = tempfile::tempdir().unwrap();
+        fs::write(output.path().join("notes.txt"), "kept").unwrap();
+        let mut sync = OutputSync::new(output.path());
+
+        let first = tempfile::tempdir().unwrap();
+        fs::create_dir(first.path().join("src")).unwrap();
+        fs::write(first.path().join("src/lib.rs"), "// lib").unwrap();
+        fs::write(first.path().join("src/old.rs"), "// old").unwrap();
+        let report = sync.sync(first.path()).unwrap();
+        assert_eq!(report.written.len(), 2);
+
+        let second = tempfile::tempdir().unwrap();
+        fs::create_dir(second.path().join("src")).unwrap();
+        fs::write(second.path().join("src/lib.rs"), "// lib").unwrap();
+        fs::write(second.path().join("src/new.rs"), "// new").unwrap();
+        let report = sync.sync(second.path()).unwrap();
+        assert_eq!(report.written, [PathBuf::from("src/new.rs")]);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(report.removed, [PathBuf::from("src/old.rs")]);
+        assert!(!output.path().join("src/old.rs").exists());
+        assert!(output.path().join("notes.txt").exists());
+    }
+
+    #[test]
+    fn test_seed_copies_migrations_and_protos() {
+        let output = tempfile::tempdir().unwrap();
+        fs::create_dir_all(output.path().join("orders/migrations")).unwrap();
+        fs::create_dir_all(output.path().join("target/migrations")).unwrap();
+        fs::write(
+            output
+                .path()
+                .join("orders/migrations/0001_create_orders.sql"),
+            "--",
+        )
+        .unwrap();
+        fs::write(output.path().join("orders/README.md"), "# Orders").unwrap();
+        fs::write(output.path().join("target/migrations/0001_old.sql"), "--").unwrap();
+        fs::create_dir_all(output.path().join("orders/proto")).unwrap();
+        fs::write(output.path().join("orders/proto/order.proto"), "syntax").unwrap();
+
+        let staging = tempfile::tempdir().unwrap();
+        OutputSync::new(output.path()).seed(staging.path()).unwrap();
+        assert!(staging
+            .path()
+            .join("orders/migrations/0001_create_orders.sql")
+            .exists());
+        assert!(staging.path().join("orders/proto/order.proto").exists());
+        assert!(!staging.path().join("orders/README.md").exists());
+        assert!(!staging.path().join("target").exists());
+    }
+
+    #[test]
+    fn test_regeneration_reserves_removed_proto_fields() {
+        let regenerate = |sync: &mut OutputSync, fields: &str| {
+            let model = crate::ir::IRParser::parse_yaml(&format!(
+                r#"
+version: "1.0"
+bounded_context:
+  name: "Order"
+  namespace: "acme.order"
+commands:
+  PlaceOrder:
+    name: "PlaceOrder"
+    payload:
+{}
+"#,
+                fields
+            ))
+            .unwrap();
+            let staging = tempfile::tempdir().unwrap();
+            sync.seed(staging.path()).unwrap();
+            crate::generators::RustGenerator::new(staging.path().to_string_lossy().into_owned())
+                .generate(&model)
+                .unwrap();
+            sync.sync(staging.path()).unwrap();
+        };
+        let output = tempfile::tempdir().unwrap();
+        let mut sync = OutputSync::new(output.path());
+
+        regenerate(
+            &mut sync,
+            "      - name: \"note\"\n        type: \"String\"\n      - name: \"quantity\"\n        type: \"Integer\"",
+        );
+        // `note` is removed, so its number must stay reserved
+        regenerate(
+            &mut sync,
+            "      - name: \"quantity\"\n        type: \"Integer\"",
+        );
+        let proto = fs::read_to_string(output.path().join("proto/order.proto")).unwrap();
+        assert!(
+            proto.contains("  int64 quantity = 2;\n  reserved 1;\n  reserved \"note\";\n"),
+            "{}",
+            proto
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hook_reports_failure() {
+        let dir = tempfile::tempdir().unwrap();
+        let outcome = Hook::new("echo checking; echo broken >&2; exit 3")
+            .run(dir.path())
+            .unwrap();
+        assert!(!outcome.success);
+        assert_eq!(outcome.code, Some(3));
+        assert_eq!(outcome.output, ["checking", "broken"]);
+    }
+}
@@ -0,0 +1,15 @@
+-- Generated by StormForge Generator for the Inventory context
+--
+-- Applied in order by `repository::migrate`. Regenerating after an IR
+-- change adds a migration for the difference, so this file is never
+-- rewritten.
+
+CREATE TABLE products (
+    id TEXT NOT NULL PRIMARY KEY,
+    sku TEXT NOT NULL,
+    name TEXT NOT NULL,
+    available_quantity INTEGER NOT NULL DEFAULT 0,
+    reserved_quantity INTEGER NOT NULL DEFAULT 0,
+    reorder_level INTEGER NOT NULL DEFAULT 10,
+    updated_at TIMESTAMP NOT NULL
+);
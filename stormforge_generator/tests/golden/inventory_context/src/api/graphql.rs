@@ -0,0 +1,162 @@
+//! GraphQL API of the Inventory context
+//!
+//! Queries and mutations delegate to the same handlers as the HTTP routes,
+//! and the `events` subscription streams the domain events appended to the
+//! event store. `schema.graphql` holds the schema in SDL.
+
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{ErrorExtensions, Object, Schema};
+use async_graphql_axum::{GraphQL, GraphQLSubscription};
+use axum::{
+    response::{Html, IntoResponse},
+    routing::get, Router,
+};
+use crate::api::routes::*;
+use crate::domain::{commands::*, entities::*, events::*};
+
+/// Schema of the GraphQL API
+pub type ApiSchema = Schema<QueryRoot, MutationRoot, SubscriptionRoot>;
+
+/// Build the schema over the state shared with the HTTP API
+pub fn schema(state: AppState) -> ApiSchema {
+    Schema::build(QueryRoot, MutationRoot, SubscriptionRoot).data(state).finish()
+}
+
+/// Router serving GraphQL and GraphiQL at `/graphql`, and subscriptions at `/graphql/ws`
+pub fn router(state: AppState) -> Router {
+    let schema = schema(state);
+    Router::new()
+        .route("/graphql", get(graphiql).post_service(GraphQL::new(schema.clone())))
+        .route_service("/graphql/ws", GraphQLSubscription::new(schema))
+}
+
+async fn graphiql() -> impl IntoResponse {
+    Html(
+        GraphiQLSource::build()
+            .endpoint("/graphql")
+            .subscription_endpoint("/graphql/ws")
+            .finish(),
+    )
+}
+
+/// GraphQL error with the API error code as `code` extension
+fn graphql_error(error: ApiError) -> async_graphql::Error {
+    let ApiError { code, message } = error;
+    async_graphql::Error::new(message)
+        .extend_with(|_, extensions| extensions.set("code", code))
+}
+
+/// Queries as query fields
+pub struct QueryRoot;
+
+#[Object(name = "Query")]
+impl QueryRoot {
+    /// Get product by ID
+    async fn get_product(
+        &self,
+        #[graphql(name = "productId")]
+        _product_id: ProductId,
+    ) -> async_graphql::Result<String> {
+        execute_get_product().await.map_err(graphql_error)
+    }
+    /// Get product by SKU
+    async fn get_product_by_sku(
+        &self,
+        #[graphql(name = "sku")]
+        _sku: String,
+    ) -> async_graphql::Result<String> {
+        execute_get_product_by_sku().await.map_err(graphql_error)
+    }
+    /// Check if quantity is available
+    async fn check_availability(
+        &self,
+        #[graphql(name = "productId")]
+        _product_id: ProductId,
+        #[graphql(name = "quantity")]
+        _quantity: i64,
+    ) -> async_graphql::Result<String> {
+        execute_check_availability().await.map_err(graphql_error)
+    }
+    /// Get products below reorder level
+    async fn get_low_stock_products(&self) -> async_graphql::Result<String> {
+        execute_get_low_stock_products().await.map_err(graphql_error)
+    }
+}
+
+/// Commands as mutations
+pub struct MutationRoot;
+
+#[Object(name = "Mutation")]
+impl MutationRoot {
+    /// Add stock to inventory
+    async fn add_stock(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: AddStock,
+    ) -> async_graphql::Result<String> {
+        execute_add_stock(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+    /// Reserve stock for an order
+    async fn reserve_stock(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: ReserveStock,
+    ) -> async_graphql::Result<String> {
+        execute_reserve_stock(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+    /// Release reserved stock
+    async fn release_stock(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: ReleaseStock,
+    ) -> async_graphql::Result<String> {
+        execute_release_stock(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+    /// Deduct reserved stock (when order ships)
+    async fn deduct_stock(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: DeductStock,
+    ) -> async_graphql::Result<String> {
+        execute_deduct_stock(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+}
+
+/// Interval at which subscriptions poll the event store
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Domain events as a subscription
+pub struct SubscriptionRoot;
+
+#[async_graphql::Subscription(name = "Subscription")]
+impl SubscriptionRoot {
+    /// Domain events appended to the event store, skipping the first `fromPosition`
+    async fn events(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        #[graphql(default)]
+        from_position: i64,
+    ) -> impl async_graphql::futures_util::Stream<
+        Item = async_graphql::Result<DomainEvent>,
+    > {
+        let event_store = ctx.data_unchecked::<AppState>().event_store.clone();
+        async_stream::try_stream! {
+            let mut position = from_position;
+            loop {
+                for payload in event_store.read_all_from(position).await? {
+                    position += 1;
+                    yield serde_json::from_value::<DomainEvent>(payload)?;
+                }
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,292 @@
+//! gRPC API serving the command and query handlers of the HTTP API
+//!
+//! Implements `InventoryService` from `proto/inventory.proto`.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use rust_decimal::Decimal;
+use std::net::SocketAddr;
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+use crate::api::routes::*;
+use crate::domain::{commands::*, entities::*};
+
+/// Types and service generated from `proto/inventory.proto`
+pub mod proto {
+    tonic::include_proto!("acme.inventory");
+}
+
+use proto::inventory_service_server::{InventoryService, InventoryServiceServer};
+
+/// Serve the gRPC API on the given address, over the state shared with the HTTP API
+pub async fn serve(
+    addr: SocketAddr,
+    state: AppState,
+) -> Result<(), tonic::transport::Error> {
+    tonic::transport::Server::builder()
+        .add_service(InventoryServiceServer::new(GrpcApi { state }))
+        .serve(addr)
+        .await
+}
+
+/// gRPC service delegating to the API's command and query handlers
+pub struct GrpcApi {
+    pub state: AppState,
+}
+
+#[tonic::async_trait]
+impl InventoryService for GrpcApi {
+    /// Add stock to inventory
+    async fn add_stock(
+        &self,
+        request: Request<proto::AddStockRequest>,
+    ) -> Result<Response<proto::AddStockResponse>, Status> {
+        let command = AddStock::from_proto(request.into_inner())?;
+        let data = execute_add_stock(&self.state, command).await?;
+        Ok(Response::new(proto::AddStockResponse { data }))
+    }
+    /// Reserve stock for an order
+    async fn reserve_stock(
+        &self,
+        request: Request<proto::ReserveStockRequest>,
+    ) -> Result<Response<proto::ReserveStockResponse>, Status> {
+        let command = ReserveStock::from_proto(request.into_inner())?;
+        let data = execute_reserve_stock(&self.state, command).await?;
+        Ok(
+            Response::new(proto::ReserveStockResponse {
+                data,
+            }),
+        )
+    }
+    /// Release reserved stock
+    async fn release_stock(
+        &self,
+        request: Request<proto::ReleaseStockRequest>,
+    ) -> Result<Response<proto::ReleaseStockResponse>, Status> {
+        let command = ReleaseStock::from_proto(request.into_inner())?;
+        let data = execute_release_stock(&self.state, command).await?;
+        Ok(
+            Response::new(proto::ReleaseStockResponse {
+                data,
+            }),
+        )
+    }
+    /// Deduct reserved stock (when order ships)
+    async fn deduct_stock(
+        &self,
+        request: Request<proto::DeductStockRequest>,
+    ) -> Result<Response<proto::DeductStockResponse>, Status> {
+        let command = DeductStock::from_proto(request.into_inner())?;
+        let data = execute_deduct_stock(&self.state, command).await?;
+        Ok(Response::new(proto::DeductStockResponse { data }))
+    }
+    /// Get product by ID
+    async fn get_product(
+        &self,
+        _request: Request<proto::GetProductRequest>,
+    ) -> Result<Response<proto::GetProductResponse>, Status> {
+        let data = execute_get_product().await?;
+        Ok(Response::new(proto::GetProductResponse { data }))
+    }
+    /// Get product by SKU
+    async fn get_product_by_sku(
+        &self,
+        _request: Request<proto::GetProductBySkuRequest>,
+    ) -> Result<Response<proto::GetProductBySkuResponse>, Status> {
+        let data = execute_get_product_by_sku().await?;
+        Ok(
+            Response::new(proto::GetProductBySkuResponse {
+                data,
+            }),
+        )
+    }
+    /// Check if quantity is available
+    async fn check_availability(
+        &self,
+        _request: Request<proto::CheckAvailabilityRequest>,
+    ) -> Result<Response<proto::CheckAvailabilityResponse>, Status> {
+        let data = execute_check_availability().await?;
+        Ok(
+            Response::new(proto::CheckAvailabilityResponse {
+                data,
+            }),
+        )
+    }
+    /// Get products below reorder level
+    async fn get_low_stock_products(
+        &self,
+        _request: Request<proto::GetLowStockProductsRequest>,
+    ) -> Result<Response<proto::GetLowStockProductsResponse>, Status> {
+        let data = execute_get_low_stock_products().await?;
+        Ok(
+            Response::new(proto::GetLowStockProductsResponse {
+                data,
+            }),
+        )
+    }
+}
+
+impl From<ApiError> for Status {
+    fn from(error: ApiError) -> Self {
+        let code = match error.code.as_str() {
+            "VALIDATION_ERROR" => tonic::Code::InvalidArgument,
+            "NOT_FOUND" => tonic::Code::NotFound,
+            "PRECONDITION_FAILED" => tonic::Code::FailedPrecondition,
+            _ => tonic::Code::Internal,
+        };
+        Status::new(code, error.message)
+    }
+}
+
+/// Conversion of a decoded protobuf value into a domain value
+pub trait FromProto<T>: Sized {
+    fn from_proto(value: T) -> Result<Self, Status>;
+}
+
+/// Value of a message field the domain type requires
+fn required<T>(value: Option<T>, field: &str) -> Result<T, Status> {
+    value.ok_or_else(|| Status::invalid_argument(format!("Missing field: {}", field)))
+}
+
+fn invalid(field: &str, error: impl std::fmt::Display) -> Status {
+    Status::invalid_argument(format!("Invalid {}: {}", field, error))
+}
+
+impl FromProto<String> for String {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+impl FromProto<i64> for i64 {
+    fn from_proto(value: i64) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+impl FromProto<bool> for bool {
+    fn from_proto(value: bool) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+/// Decimals are strings to keep their precision
+impl FromProto<String> for Decimal {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("decimal", e))
+    }
+}
+
+impl FromProto<String> for Uuid {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("UUID", e))
+    }
+}
+
+/// Dates are `YYYY-MM-DD` strings
+impl FromProto<String> for NaiveDate {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("date", e))
+    }
+}
+
+impl FromProto<String> for NaiveTime {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("time", e))
+    }
+}
+
+impl FromProto<prost_types::Timestamp> for DateTime<Utc> {
+    fn from_proto(value: prost_types::Timestamp) -> Result<Self, Status> {
+        u32::try_from(value.nanos)
+            .ok()
+            .and_then(|nanos| DateTime::from_timestamp(value.seconds, nanos))
+            .ok_or_else(|| invalid("timestamp", format!("{:?}", value)))
+    }
+}
+
+impl<T: FromProto<P>, P> FromProto<Vec<P>> for Vec<T> {
+    fn from_proto(value: Vec<P>) -> Result<Self, Status> {
+        value.into_iter().map(T::from_proto).collect()
+    }
+}
+
+impl<T: FromProto<P>, P> FromProto<Option<P>> for Option<T> {
+    fn from_proto(value: Option<P>) -> Result<Self, Status> {
+        value.map(T::from_proto).transpose()
+    }
+}
+
+impl FromProto<proto::ProductId> for ProductId {
+    fn from_proto(value: proto::ProductId) -> Result<Self, Status> {
+        Ok(Self(FromProto::from_proto(value.value)?))
+    }
+}
+
+impl FromProto<proto::OrderId> for OrderId {
+    fn from_proto(value: proto::OrderId) -> Result<Self, Status> {
+        Ok(Self(FromProto::from_proto(value.value)?))
+    }
+}
+
+impl FromProto<proto::ReservationId> for ReservationId {
+    fn from_proto(value: proto::ReservationId) -> Result<Self, Status> {
+        Ok(Self(FromProto::from_proto(value.value)?))
+    }
+}
+
+impl FromProto<proto::Product> for Product {
+    fn from_proto(value: proto::Product) -> Result<Self, Status> {
+        Ok(Self {
+            id: FromProto::from_proto(required(value.id, "id")?)?,
+            sku: FromProto::from_proto(value.sku)?,
+            name: FromProto::from_proto(value.name)?,
+            available_quantity: FromProto::from_proto(value.available_quantity)?,
+            reserved_quantity: FromProto::from_proto(value.reserved_quantity)?,
+            reorder_level: FromProto::from_proto(value.reorder_level)?,
+            updated_at: FromProto::from_proto(required(value.updated_at, "updated_at")?)?,
+        })
+    }
+}
+
+impl FromProto<proto::AddStockRequest> for AddStock {
+    fn from_proto(value: proto::AddStockRequest) -> Result<Self, Status> {
+        Ok(Self {
+            product_id: FromProto::from_proto(
+                required(value.product_id, "product_id")?,
+            )?,
+            quantity: FromProto::from_proto(value.quantity)?,
+        })
+    }
+}
+
+impl FromProto<proto::ReserveStockRequest> for ReserveStock {
+    fn from_proto(value: proto::ReserveStockRequest) -> Result<Self, Status> {
+        Ok(Self {
+            product_id: FromProto::from_proto(
+                required(value.product_id, "product_id")?,
+            )?,
+            order_id: FromProto::from_proto(required(value.order_id, "order_id")?)?,
+            quantity: FromProto::from_proto(value.quantity)?,
+        })
+    }
+}
+
+impl FromProto<proto::ReleaseStockRequest> for ReleaseStock {
+    fn from_proto(value: proto::ReleaseStockRequest) -> Result<Self, Status> {
+        Ok(Self {
+            reservation_id: FromProto::from_proto(
+                required(value.reservation_id, "reservation_id")?,
+            )?,
+        })
+    }
+}
+
+impl FromProto<proto::DeductStockRequest> for DeductStock {
+    fn from_proto(value: proto::DeductStockRequest) -> Result<Self, Status> {
+        Ok(Self {
+            reservation_id: FromProto::from_proto(
+                required(value.reservation_id, "reservation_id")?,
+            )?,
+        })
+    }
+}
@@ -0,0 +1,7 @@
+#[cfg(feature = "graphql")]
+pub mod graphql;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod routes;
+
+pub use routes::*;
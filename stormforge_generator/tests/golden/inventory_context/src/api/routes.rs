@@ -0,0 +1,384 @@
+use axum::{
+    extract::State, http::{header, StatusCode},
+    response::{IntoResponse, Json},
+    routing::{get, post},
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::{ToSchema, OpenApi};
+use crate::domain::commands::*;
+use crate::domain::handlers::StateStoredHandler;
+use crate::repository::Repositories;
+use crate::infrastructure::event_store::EventStore;
+
+/// API documentation
+#[derive(OpenApi)]
+#[openapi(
+    paths(),
+    components(schemas(ApiError, ApiResponseString)),
+    tags((name = "Inventory", description = "Inventory API endpoints"))
+)]
+pub struct ApiDoc;
+
+/// AsyncAPI document of the events published and consumed by this service
+pub const ASYNCAPI: &str = include_str!("../../asyncapi.json");
+
+/// OpenAPI document of the HTTP API
+///
+/// The component schemas of the AsyncAPI document are merged in, so
+/// both documents describe payloads with the same schemas.
+pub fn openapi() -> serde_json::Value {
+    let mut openapi = serde_json::to_value(ApiDoc::openapi())
+        .expect("OpenAPI document serializes to JSON");
+    let asyncapi: serde_json::Value = serde_json::from_str(ASYNCAPI)
+        .expect("AsyncAPI document is valid JSON");
+    if let (Some(schemas), Some(shared)) = (
+        openapi
+            .pointer_mut("/components/schemas")
+            .and_then(|schemas| schemas.as_object_mut()),
+        asyncapi.pointer("/components/schemas").and_then(|schemas| schemas.as_object()),
+    ) {
+        for (name, schema) in shared {
+            schemas.entry(name.clone()).or_insert_with(|| schema.clone());
+        }
+    }
+    openapi
+}
+
+/// Serve the AsyncAPI document
+pub async fn asyncapi() -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "application/json")], ASYNCAPI)
+}
+
+/// Standard API error response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiError {
+    pub code: String,
+    pub message: String,
+}
+
+impl ApiError {
+    /// HTTP status of the error code
+    pub fn status_code(&self) -> StatusCode {
+        match self.code.as_str() {
+            "VALIDATION_ERROR" => StatusCode::BAD_REQUEST,
+            "NOT_FOUND" => StatusCode::NOT_FOUND,
+            "PRECONDITION_FAILED" => StatusCode::CONFLICT,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<CommandError> for ApiError {
+    fn from(error: CommandError) -> Self {
+        let code = match &error {
+            CommandError::ValidationError(_) => "VALIDATION_ERROR",
+            CommandError::PreconditionFailed(_) => "PRECONDITION_FAILED",
+            CommandError::AggregateNotFound(_) => "NOT_FOUND",
+            CommandError::InternalError(_) => "INTERNAL_ERROR",
+        };
+        ApiError {
+            code: code.to_string(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Standard API success response (String variant)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseString {
+    pub data: String,
+}
+
+/// State shared by the HTTP, GraphQL and gRPC APIs
+#[derive(Clone)]
+pub struct AppState {
+    /// Event store the commands append to
+    pub event_store: Arc<dyn EventStore>,
+    /// Handler of the commands
+    pub commands: Arc<dyn CommandHandler>,
+}
+
+impl AppState {
+    /// State keeping the state-stored aggregates in memory
+    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+        Self::with_repositories(event_store, Repositories::in_memory())
+    }
+    /// State handling the commands of state-stored aggregates through
+    /// `repositories`, and the others with the event-sourced handler over
+    /// `event_store`
+    pub fn with_repositories(
+        event_store: Arc<dyn EventStore>,
+        repositories: Repositories,
+    ) -> Self {
+        Self {
+            commands: Arc::new(
+                StateStoredHandler::new(event_store.clone(), repositories),
+            ),
+            event_store,
+        }
+    }
+}
+
+/// Create the API router
+pub fn create_router(state: AppState) -> Router {
+    Router::new()
+        .route("/add-stock", post(add_stock))
+        .route("/reserve-stock", post(reserve_stock))
+        .route("/release-stock", post(release_stock))
+        .route("/deduct-stock", post(deduct_stock))
+        .route("/get-product", get(get_product))
+        .route("/get-product-by-sku", get(get_product_by_sku))
+        .route("/check-availability", get(check_availability))
+        .route("/get-low-stock-products", get(get_low_stock_products))
+        .with_state(state)
+}
+
+/// Add stock to inventory
+#[utoipa::path(
+    post,
+    path = "/add-stock",
+    request_body = AddStock,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn add_stock(
+    State(state): State<AppState>,
+    Json(payload): Json<AddStock>,
+) -> impl IntoResponse {
+    match execute_add_stock(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `AddStock` command, shared by the HTTP and gRPC APIs
+pub async fn execute_add_stock(
+    state: &AppState,
+    command: AddStock,
+) -> Result<String, ApiError> {
+    state.commands.handle_add_stock(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Reserve stock for an order
+#[utoipa::path(
+    post,
+    path = "/reserve-stock",
+    request_body = ReserveStock,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn reserve_stock(
+    State(state): State<AppState>,
+    Json(payload): Json<ReserveStock>,
+) -> impl IntoResponse {
+    match execute_reserve_stock(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `ReserveStock` command, shared by the HTTP and gRPC APIs
+pub async fn execute_reserve_stock(
+    state: &AppState,
+    command: ReserveStock,
+) -> Result<String, ApiError> {
+    state.commands.handle_reserve_stock(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Release reserved stock
+#[utoipa::path(
+    post,
+    path = "/release-stock",
+    request_body = ReleaseStock,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn release_stock(
+    State(state): State<AppState>,
+    Json(payload): Json<ReleaseStock>,
+) -> impl IntoResponse {
+    match execute_release_stock(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `ReleaseStock` command, shared by the HTTP and gRPC APIs
+pub async fn execute_release_stock(
+    state: &AppState,
+    command: ReleaseStock,
+) -> Result<String, ApiError> {
+    state.commands.handle_release_stock(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Deduct reserved stock (when order ships)
+#[utoipa::path(
+    post,
+    path = "/deduct-stock",
+    request_body = DeductStock,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn deduct_stock(
+    State(state): State<AppState>,
+    Json(payload): Json<DeductStock>,
+) -> impl IntoResponse {
+    match execute_deduct_stock(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `DeductStock` command, shared by the HTTP and gRPC APIs
+pub async fn execute_deduct_stock(
+    state: &AppState,
+    command: DeductStock,
+) -> Result<String, ApiError> {
+    state.commands.handle_deduct_stock(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Get product by ID
+#[utoipa::path(
+    get,
+    path = "/get-product",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn get_product() -> impl IntoResponse {
+    match execute_get_product().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `GetProduct` query, shared by the HTTP and gRPC APIs
+pub async fn execute_get_product() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
+
+/// Get product by SKU
+#[utoipa::path(
+    get,
+    path = "/get-product-by-sku",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn get_product_by_sku() -> impl IntoResponse {
+    match execute_get_product_by_sku().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `GetProductBySku` query, shared by the HTTP and gRPC APIs
+pub async fn execute_get_product_by_sku() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
+
+/// Check if quantity is available
+#[utoipa::path(
+    get,
+    path = "/check-availability",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn check_availability() -> impl IntoResponse {
+    match execute_check_availability().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `CheckAvailability` query, shared by the HTTP and gRPC APIs
+pub async fn execute_check_availability() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
+
+/// Get products below reorder level
+#[utoipa::path(
+    get,
+    path = "/get-low-stock-products",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn get_low_stock_products() -> impl IntoResponse {
+    match execute_get_low_stock_products().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `GetLowStockProducts` query, shared by the HTTP and gRPC APIs
+pub async fn execute_get_low_stock_products() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
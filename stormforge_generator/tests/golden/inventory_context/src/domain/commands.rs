@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+use crate::domain::entities::*;
+use crate::domain::events::*;
+
+pub type CommandResult<T> = Result<T, CommandError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CommandError {
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+    #[error("Aggregate not found: {0}")]
+    AggregateNotFound(String),
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+/// Add stock to inventory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "AddStockInput")
+)]
+pub struct AddStock {
+    pub product_id: ProductId,
+    pub quantity: i64,
+}
+
+impl AddStock {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Reserve stock for an order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "ReserveStockInput")
+)]
+pub struct ReserveStock {
+    pub product_id: ProductId,
+    pub order_id: OrderId,
+    pub quantity: i64,
+}
+
+impl ReserveStock {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Release reserved stock
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "ReleaseStockInput")
+)]
+pub struct ReleaseStock {
+    pub reservation_id: ReservationId,
+}
+
+impl ReleaseStock {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Deduct reserved stock (when order ships)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "DeductStockInput")
+)]
+pub struct DeductStock {
+    pub reservation_id: ReservationId,
+}
+
+impl DeductStock {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Command handler trait for processing commands
+#[async_trait]
+pub trait CommandHandler: Send + Sync {
+    /// Add stock to inventory
+    async fn handle_add_stock(
+        &self,
+        command: AddStock,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Reserve stock for an order
+    async fn handle_reserve_stock(
+        &self,
+        command: ReserveStock,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Release reserved stock
+    async fn handle_release_stock(
+        &self,
+        command: ReleaseStock,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Deduct reserved stock (when order ships)
+    async fn handle_deduct_stock(
+        &self,
+        command: DeductStock,
+    ) -> CommandResult<Vec<DomainEvent>>;
+}
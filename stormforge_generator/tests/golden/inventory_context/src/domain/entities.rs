@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "sqlx")]
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ProductId(pub String);
+
+impl From<String> for ProductId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ProductId> for String {
+    fn from(id: ProductId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(ProductId);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct OrderId(pub String);
+
+impl From<String> for OrderId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<OrderId> for String {
+    fn from(id: OrderId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(OrderId);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ReservationId(pub String);
+
+impl From<String> for ReservationId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ReservationId> for String {
+    fn from(id: ReservationId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(ReservationId);
+
+/// Product inventory aggregate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(FromRow))]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::SimpleObject, async_graphql::InputObject),
+    graphql(input_name = "ProductInput")
+)]
+pub struct Product {
+    pub id: ProductId,
+    pub sku: String,
+    pub name: String,
+    pub available_quantity: i64,
+    pub reserved_quantity: i64,
+    pub reorder_level: i64,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,221 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::entities::*;
+
+/// Domain event enum containing all events in this bounded context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Union))]
+#[serde(tag = "type")]
+pub enum DomainEvent {
+    StockAdded(StockAdded),
+    StockReserved(StockReserved),
+    StockReleased(StockReleased),
+    StockDeducted(StockDeducted),
+    LowStockAlert(LowStockAlert),
+}
+
+/// Stock has been added to inventory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct StockAdded {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub product_id: ProductId,
+    pub quantity: i64,
+    pub new_available_quantity: i64,
+    pub added_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl StockAdded {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        product_id: ProductId,
+        quantity: i64,
+        new_available_quantity: i64,
+        added_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            product_id,
+            quantity,
+            new_available_quantity,
+            added_at,
+        }
+    }
+}
+
+/// Stock has been reserved for an order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct StockReserved {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub product_id: ProductId,
+    pub order_id: OrderId,
+    pub reservation_id: ReservationId,
+    pub quantity: i64,
+    pub reserved_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl StockReserved {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        product_id: ProductId,
+        order_id: OrderId,
+        reservation_id: ReservationId,
+        quantity: i64,
+        reserved_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            product_id,
+            order_id,
+            reservation_id,
+            quantity,
+            reserved_at,
+        }
+    }
+}
+
+/// Reserved stock has been released
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct StockReleased {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub product_id: ProductId,
+    pub reservation_id: ReservationId,
+    pub quantity: i64,
+    pub released_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl StockReleased {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        product_id: ProductId,
+        reservation_id: ReservationId,
+        quantity: i64,
+        released_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            product_id,
+            reservation_id,
+            quantity,
+            released_at,
+        }
+    }
+}
+
+/// Stock has been deducted (shipped)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct StockDeducted {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub product_id: ProductId,
+    pub reservation_id: ReservationId,
+    pub quantity: i64,
+    pub deducted_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl StockDeducted {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        product_id: ProductId,
+        reservation_id: ReservationId,
+        quantity: i64,
+        deducted_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            product_id,
+            reservation_id,
+            quantity,
+            deducted_at,
+        }
+    }
+}
+
+/// Stock has fallen below reorder level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct LowStockAlert {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub product_id: ProductId,
+    pub sku: String,
+    pub current_quantity: i64,
+    pub reorder_level: i64,
+    pub alerted_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl LowStockAlert {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        product_id: ProductId,
+        sku: String,
+        current_quantity: i64,
+        reorder_level: i64,
+        alerted_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            product_id,
+            sku,
+            current_quantity,
+            reorder_level,
+            alerted_at,
+        }
+    }
+}
+
+/// Event store metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventMetadata {
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub aggregate_type: String,
+    pub sequence_number: i64,
+    pub occurred_at: DateTime<Utc>,
+    pub stored_at: DateTime<Utc>,
+    pub correlation_id: Option<Uuid>,
+    pub causation_id: Option<Uuid>,
+}
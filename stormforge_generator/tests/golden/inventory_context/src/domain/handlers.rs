@@ -0,0 +1,492 @@
+//! Command handler backed by the event store
+//!
+//! A command is validated, then checked against the state of its
+//! aggregate folded from the stored events. A command carrying the
+//! aggregate's identifier acts on that aggregate, any other command
+//! creates one. Each event takes a field from the `computed` expression
+//! of the event property, else from the command field, the aggregate ID
+//! or the aggregate state of the same name; `DateTime` fields the
+//! command does not give are the time it is handled. The events must
+//! keep the aggregate's invariants, and are appended with their outbox
+//! entries at the version the state was loaded at, so a concurrent
+//! command on the same aggregate fails rather than being lost.
+//!
+//! `StateStoredHandler` handles the commands of state-stored
+//! aggregates instead: it loads the aggregate from its repository and
+//! saves it with the events applied, after appending the events at the
+//! version of the aggregate's stream. Other commands go to the
+//! event-sourced handler.
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+use crate::domain::commands::*;
+use crate::domain::entities::*;
+use crate::repository::{Repositories, Repository};
+use crate::domain::events::DomainEvent;
+use crate::domain::{expressions, state};
+use crate::infrastructure::event_store::EventStore;
+
+/// Command handler appending the events of commands to an event store
+pub struct EventSourcedHandler {
+    event_store: Arc<dyn EventStore>,
+}
+
+impl EventSourcedHandler {
+    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+        Self { event_store }
+    }
+    /// State of an aggregate folded from its events, and its version
+    pub async fn load(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+    ) -> CommandResult<(Value, i64)> {
+        let events = self
+            .event_store
+            .load_events(aggregate_id, aggregate_type)
+            .await
+            .map_err(internal)?
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(internal)?;
+        let version = i64::try_from(events.len()).map_err(internal)?;
+        let state = state::load(aggregate_type, &events).unwrap_or(Value::Null);
+        Ok((state, version))
+    }
+    /// Number of events stored for an aggregate
+    async fn version(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+    ) -> CommandResult<i64> {
+        self.event_store.version(aggregate_id, aggregate_type).await.map_err(internal)
+    }
+    /// Append the events of a command at the version its aggregate was loaded at
+    async fn append(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+        events: Vec<DomainEvent>,
+        version: i64,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        self.event_store
+            .append_events(aggregate_id, aggregate_type, events.clone(), Some(version))
+            .await
+            .map_err(internal)?;
+        Ok(events)
+    }
+}
+
+fn internal(error: impl std::fmt::Display) -> CommandError {
+    CommandError::InternalError(error.to_string())
+}
+
+/// Aggregate ID held by a command field
+fn id(value: &Value) -> String {
+    match value {
+        Value::String(id) => id.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Event of a type built from its payload fields
+fn event(
+    event_type: &str,
+    aggregate_id: &str,
+    mut payload: Value,
+) -> CommandResult<DomainEvent> {
+    if let Some(fields) = payload.as_object_mut() {
+        fields.insert("type".to_string(), Value::from(event_type));
+        fields.insert("event_id".to_string(), Value::from(Uuid::new_v4().to_string()));
+        fields.insert("occurred_at".to_string(), expressions::now());
+        fields.insert("aggregate_id".to_string(), Value::from(aggregate_id));
+    }
+    serde_json::from_value(payload)
+        .map_err(|e| {
+            CommandError::InternalError(format!("Cannot build {}: {}", event_type, e))
+        })
+}
+
+/// Fold events into the state of an aggregate
+fn apply_events(
+    state: &mut Value,
+    events: &[DomainEvent],
+    apply: fn(&mut Value, &Value),
+) -> CommandResult<()> {
+    for event in events {
+        apply(state, &serde_json::to_value(event).map_err(internal)?);
+    }
+    Ok(())
+}
+
+/// Invariants of a `Product`
+///
+/// - `availableQuantity >= 0`: Available quantity cannot be negative
+/// - `reservedQuantity >= 0`: Reserved quantity cannot be negative
+fn check_product(scope: &Value) -> CommandResult<()> {
+    if !expressions::ge(
+        &expressions::get(scope, "available_quantity"),
+        &serde_json::Value::from(0),
+    ) {
+        return Err(
+            CommandError::ValidationError(
+                "Available quantity cannot be negative".to_string(),
+            ),
+        );
+    }
+    if !expressions::ge(
+        &expressions::get(scope, "reserved_quantity"),
+        &serde_json::Value::from(0),
+    ) {
+        return Err(
+            CommandError::ValidationError(
+                "Reserved quantity cannot be negative".to_string(),
+            ),
+        );
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl CommandHandler for EventSourcedHandler {
+    async fn handle_add_stock(
+        &self,
+        command: AddStock,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "product_id"));
+        let (mut state, version) = self.load("Product", &aggregate_id).await?;
+        let scope = &expressions::with(&command, "product", &state);
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "StockAdded",
+                &aggregate_id,
+                expressions::object([
+                    ("product_id", expressions::get(&command, "product_id")),
+                    ("quantity", expressions::get(&command, "quantity")),
+                    (
+                        "new_available_quantity",
+                        expressions::add(
+                            &expressions::get(
+                                &expressions::get(scope, "product"),
+                                "available_quantity",
+                            ),
+                            &expressions::get(scope, "quantity"),
+                        ),
+                    ),
+                    ("added_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_product)?;
+        check_product(&state)?;
+        self.append("Product", &aggregate_id, events, version).await
+    }
+    async fn handle_reserve_stock(
+        &self,
+        command: ReserveStock,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "product_id"));
+        let (mut state, version) = self.load("Product", &aggregate_id).await?;
+        if version == 0 {
+            return Err(CommandError::AggregateNotFound(aggregate_id));
+        }
+        let scope = &expressions::with(&command, "product", &state);
+        if !expressions::ge(
+            &expressions::get(&expressions::get(scope, "product"), "available_quantity"),
+            &expressions::get(scope, "quantity"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Insufficient stock available".to_string(),
+                ),
+            );
+        }
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "StockReserved",
+                &aggregate_id,
+                expressions::object([
+                    ("product_id", expressions::get(&command, "product_id")),
+                    ("order_id", expressions::get(&command, "order_id")),
+                    ("reservation_id", expressions::get(&state, "reservation_id")),
+                    ("quantity", expressions::get(&command, "quantity")),
+                    ("reserved_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_product)?;
+        check_product(&state)?;
+        self.append("Product", &aggregate_id, events, version).await
+    }
+    async fn handle_release_stock(
+        &self,
+        command: ReleaseStock,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = Uuid::new_v4().to_string();
+        let (mut state, version) = (Value::Null, 0);
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "StockReleased",
+                &aggregate_id,
+                expressions::object([
+                    ("product_id", Value::from(aggregate_id.as_str())),
+                    ("reservation_id", expressions::get(&command, "reservation_id")),
+                    ("quantity", expressions::get(&state, "quantity")),
+                    ("released_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_product)?;
+        check_product(&state)?;
+        self.append("Product", &aggregate_id, events, version).await
+    }
+    async fn handle_deduct_stock(
+        &self,
+        command: DeductStock,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = Uuid::new_v4().to_string();
+        let (mut state, version) = (Value::Null, 0);
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "StockDeducted",
+                &aggregate_id,
+                expressions::object([
+                    ("product_id", Value::from(aggregate_id.as_str())),
+                    ("reservation_id", expressions::get(&command, "reservation_id")),
+                    ("quantity", expressions::get(&state, "quantity")),
+                    ("deducted_at", expressions::now()),
+                ]),
+            )?,
+            event(
+                "LowStockAlert",
+                &aggregate_id,
+                expressions::object([
+                    ("product_id", Value::from(aggregate_id.as_str())),
+                    ("sku", expressions::get(&state, "sku")),
+                    ("current_quantity", expressions::get(&state, "current_quantity")),
+                    ("reorder_level", expressions::get(&state, "reorder_level")),
+                    ("alerted_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_product)?;
+        check_product(&state)?;
+        self.append("Product", &aggregate_id, events, version).await
+    }
+}
+
+/// Command handler saving state-stored aggregates to their repositories
+pub struct StateStoredHandler {
+    events: EventSourcedHandler,
+    repositories: Repositories,
+}
+
+impl StateStoredHandler {
+    pub fn new(event_store: Arc<dyn EventStore>, repositories: Repositories) -> Self {
+        Self {
+            events: EventSourcedHandler::new(event_store),
+            repositories,
+        }
+    }
+}
+
+/// Stored state of an aggregate, `null` if it is not stored
+async fn stored_state<T: Serialize>(
+    repository: &dyn Repository<T>,
+    aggregate_id: &str,
+) -> CommandResult<Value> {
+    match repository.find_by_id(aggregate_id).await.map_err(internal)? {
+        Some(aggregate) => serde_json::to_value(aggregate).map_err(internal),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Aggregate of a state, to be saved to its repository
+fn stored_aggregate<T: DeserializeOwned>(
+    aggregate_type: &str,
+    state: Value,
+) -> CommandResult<T> {
+    serde_json::from_value(state)
+        .map_err(|e| {
+            CommandError::InternalError(
+                format!("Cannot store {}: {}", aggregate_type, e),
+            )
+        })
+}
+
+#[async_trait]
+impl CommandHandler for StateStoredHandler {
+    async fn handle_add_stock(
+        &self,
+        command: AddStock,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "product_id"));
+        let mut state = stored_state(self.repositories.product.as_ref(), &aggregate_id)
+            .await?;
+        let version = self.events.version("Product", &aggregate_id).await?;
+        let scope = &expressions::with(&command, "product", &state);
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "StockAdded",
+                &aggregate_id,
+                expressions::object([
+                    ("product_id", expressions::get(&command, "product_id")),
+                    ("quantity", expressions::get(&command, "quantity")),
+                    (
+                        "new_available_quantity",
+                        expressions::add(
+                            &expressions::get(
+                                &expressions::get(scope, "product"),
+                                "available_quantity",
+                            ),
+                            &expressions::get(scope, "quantity"),
+                        ),
+                    ),
+                    ("added_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_product)?;
+        check_product(&state)?;
+        let aggregate: Product = stored_aggregate("Product", state)?;
+        let events = self
+            .events
+            .append("Product", &aggregate_id, events, version)
+            .await?;
+        self.repositories.product.save(&aggregate).await.map_err(internal)?;
+        Ok(events)
+    }
+    async fn handle_reserve_stock(
+        &self,
+        command: ReserveStock,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "product_id"));
+        let mut state = stored_state(self.repositories.product.as_ref(), &aggregate_id)
+            .await?;
+        let version = self.events.version("Product", &aggregate_id).await?;
+        if state.is_null() {
+            return Err(CommandError::AggregateNotFound(aggregate_id));
+        }
+        let scope = &expressions::with(&command, "product", &state);
+        if !expressions::ge(
+            &expressions::get(&expressions::get(scope, "product"), "available_quantity"),
+            &expressions::get(scope, "quantity"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Insufficient stock available".to_string(),
+                ),
+            );
+        }
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "StockReserved",
+                &aggregate_id,
+                expressions::object([
+                    ("product_id", expressions::get(&command, "product_id")),
+                    ("order_id", expressions::get(&command, "order_id")),
+                    ("reservation_id", expressions::get(&state, "reservation_id")),
+                    ("quantity", expressions::get(&command, "quantity")),
+                    ("reserved_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_product)?;
+        check_product(&state)?;
+        let aggregate: Product = stored_aggregate("Product", state)?;
+        let events = self
+            .events
+            .append("Product", &aggregate_id, events, version)
+            .await?;
+        self.repositories.product.save(&aggregate).await.map_err(internal)?;
+        Ok(events)
+    }
+    async fn handle_release_stock(
+        &self,
+        command: ReleaseStock,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = Uuid::new_v4().to_string();
+        let (mut state, version) = (Value::Null, 0);
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "StockReleased",
+                &aggregate_id,
+                expressions::object([
+                    ("product_id", Value::from(aggregate_id.as_str())),
+                    ("reservation_id", expressions::get(&command, "reservation_id")),
+                    ("quantity", expressions::get(&state, "quantity")),
+                    ("released_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_product)?;
+        check_product(&state)?;
+        let aggregate: Product = stored_aggregate("Product", state)?;
+        let events = self
+            .events
+            .append("Product", &aggregate_id, events, version)
+            .await?;
+        self.repositories.product.save(&aggregate).await.map_err(internal)?;
+        Ok(events)
+    }
+    async fn handle_deduct_stock(
+        &self,
+        command: DeductStock,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = Uuid::new_v4().to_string();
+        let (mut state, version) = (Value::Null, 0);
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "StockDeducted",
+                &aggregate_id,
+                expressions::object([
+                    ("product_id", Value::from(aggregate_id.as_str())),
+                    ("reservation_id", expressions::get(&command, "reservation_id")),
+                    ("quantity", expressions::get(&state, "quantity")),
+                    ("deducted_at", expressions::now()),
+                ]),
+            )?,
+            event(
+                "LowStockAlert",
+                &aggregate_id,
+                expressions::object([
+                    ("product_id", Value::from(aggregate_id.as_str())),
+                    ("sku", expressions::get(&state, "sku")),
+                    ("current_quantity", expressions::get(&state, "current_quantity")),
+                    ("reorder_level", expressions::get(&state, "reorder_level")),
+                    ("alerted_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_product)?;
+        check_product(&state)?;
+        let aggregate: Product = stored_aggregate("Product", state)?;
+        let events = self
+            .events
+            .append("Product", &aggregate_id, events, version)
+            .await?;
+        self.repositories.product.save(&aggregate).await.map_err(internal)?;
+        Ok(events)
+    }
+}
@@ -0,0 +1,63 @@
+//! Aggregate state folded from events
+//!
+//! The state of an aggregate is a JSON object of its root entity
+//! properties, starting from their defaults. Each event copies its
+//! payload fields into the state, and an event whose name ends with a
+//! value of the aggregate's status enum, such as `OrderPaid` for `PAID`,
+//! sets the status. The identifier is the aggregate ID of the first
+//! event, unless its payload carries the identifier field.
+
+use serde_json::Value;
+use crate::domain::expressions;
+
+/// Event fields that describe the event rather than the aggregate
+const ENVELOPE_FIELDS: [&str; 4] = ["type", "event_id", "occurred_at", "aggregate_id"];
+
+/// Fold events with the apply function of an aggregate
+pub fn fold(events: &[Value], apply: fn(&mut Value, &Value)) -> Value {
+    let mut state = Value::Null;
+    for event in events {
+        apply(&mut state, event);
+    }
+    state
+}
+
+/// State of an aggregate of this context, `None` for other aggregate types
+pub fn load(aggregate_type: &str, events: &[Value]) -> Option<Value> {
+    match aggregate_type {
+        "Product" => Some(fold(events, apply_product)),
+        _ => None,
+    }
+}
+
+/// Copy the payload fields of an event into a state
+fn copy_payload(state: &mut Value, event: &Value) {
+    let (Some(state), Some(fields)) = (state.as_object_mut(), event.as_object()) else {
+        return;
+    };
+    for (key, value) in fields {
+        if !ENVELOPE_FIELDS.contains(&key.as_str()) {
+            state.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+/// Initial state of a `Product`: the defaults of its root entity properties
+pub fn new_product() -> Value {
+    expressions::object([
+        ("available_quantity", serde_json::Value::from(0)),
+        ("reserved_quantity", serde_json::Value::from(0)),
+        ("reorder_level", serde_json::Value::from(10)),
+    ])
+}
+
+/// Fold an event into the state of a `Product`
+pub fn apply_product(state: &mut Value, event: &Value) {
+    if !state.is_object() {
+        *state = new_product();
+    }
+    copy_payload(state, event);
+    if let (Some(state), Some(id)) = (state.as_object_mut(), event.get("aggregate_id")) {
+        state.entry("id").or_insert_with(|| id.clone());
+    }
+}
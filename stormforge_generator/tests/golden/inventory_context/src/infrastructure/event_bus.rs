@@ -0,0 +1,172 @@
+//! Event bus for exchanging integration events between bounded contexts
+//!
+//! `InMemoryEventBus` serves a single process, while `JsonlEventBus`
+//! appends to a JSON Lines file so that several local services can
+//! exchange events without a message broker.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use crate::domain::events::DomainEvent;
+
+/// Name of the bounded context publishing from this service
+pub const BOUNDED_CONTEXT: &str = "Inventory";
+
+/// Integration event exchanged over the event bus
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntegrationMessage {
+    /// Unique message identifier, used by consumers for deduplication
+    pub message_id: Uuid,
+    /// Bounded context that published the event
+    pub context: String,
+    /// Name of the event type
+    pub event_type: String,
+    /// Serialized event
+    pub payload: serde_json::Value,
+}
+
+impl IntegrationMessage {
+    /// Wrap a domain event published by this bounded context
+    ///
+    /// The event ID doubles as message ID, so republishing the same
+    /// event yields a message consumers recognise as a duplicate.
+    pub fn from_domain_event(event: &DomainEvent) -> Result<Self> {
+        let payload = serde_json::to_value(event)?;
+        let event_type = payload
+            .get("type")
+            .and_then(|value| value.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let message_id = payload
+            .get("event_id")
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_else(Uuid::new_v4);
+        Ok(Self {
+            message_id,
+            context: BOUNDED_CONTEXT.to_string(),
+            event_type,
+            payload,
+        })
+    }
+}
+
+/// Event bus trait
+#[async_trait]
+pub trait EventBus: Send + Sync {
+    /// Publish a message to all subscribers
+    async fn publish(&self, message: IntegrationMessage) -> Result<()>;
+    /// Read the messages published at or after `offset`, in order
+    async fn read_from(&self, offset: usize) -> Result<Vec<IntegrationMessage>>;
+}
+
+/// In-process event bus (for a single service and tests)
+#[derive(Clone, Default)]
+pub struct InMemoryEventBus {
+    messages: Arc<RwLock<Vec<IntegrationMessage>>>,
+}
+
+impl InMemoryEventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl EventBus for InMemoryEventBus {
+    async fn publish(&self, message: IntegrationMessage) -> Result<()> {
+        self.messages.write().await.push(message);
+        Ok(())
+    }
+    async fn read_from(&self, offset: usize) -> Result<Vec<IntegrationMessage>> {
+        Ok(self.messages.read().await.iter().skip(offset).cloned().collect())
+    }
+}
+
+/// Event bus backed by a JSON Lines file shared between local services
+#[derive(Clone)]
+pub struct JsonlEventBus {
+    path: PathBuf,
+}
+
+impl JsonlEventBus {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl EventBus for JsonlEventBus {
+    async fn publish(&self, message: IntegrationMessage) -> Result<()> {
+        let mut line = serde_json::to_string(&message)?;
+        line.push('\n');
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        file.write_all(line.as_bytes()).await?;
+        file.flush().await?;
+        Ok(())
+    }
+    async fn read_from(&self, offset: usize) -> Result<Vec<IntegrationMessage>> {
+        let content = match tokio::fs::read_to_string(&self.path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e.into()),
+        };
+        content
+            .split_inclusive('\n')
+            .filter(|line| line.ends_with('\n') && !line.trim().is_empty())
+            .skip(offset)
+            .map(|line| Ok(serde_json::from_str(line)?))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(event_type: &str) -> IntegrationMessage {
+        IntegrationMessage {
+            message_id: Uuid::new_v4(),
+            context: "Test".to_string(),
+            event_type: event_type.to_string(),
+            payload: serde_json::json!({ "type": event_type }),
+        }
+    }
+
+    #[tokio::test]
+    async fn in_memory_bus_delivers_messages_in_order() {
+        let bus = InMemoryEventBus::new();
+        let subscriber = bus.clone();
+        bus.publish(message("First")).await.unwrap();
+        bus.publish(message("Second")).await.unwrap();
+        let messages = subscriber.read_from(0).await.unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].event_type, "First");
+        assert_eq!(subscriber.read_from(1).await.unwrap()[0].event_type, "Second");
+    }
+
+    #[tokio::test]
+    async fn jsonl_bus_is_shared_between_services() {
+        let path = std::env::temp_dir()
+            .join(format!("event-bus-{}.jsonl", Uuid::new_v4()));
+        let publisher = JsonlEventBus::new(&path);
+        let subscriber = JsonlEventBus::new(&path);
+        assert!(subscriber.read_from(0).await.unwrap().is_empty());
+        let first = message("First");
+        publisher.publish(first.clone()).await.unwrap();
+        publisher.publish(message("Second")).await.unwrap();
+        let messages = subscriber.read_from(0).await.unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0], first);
+        assert_eq!(subscriber.read_from(2).await.unwrap().len(), 0);
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,280 @@
+//! Event sourcing infrastructure
+//!
+//! Events are appended together with their outbox entries in one
+//! atomic write, so every stored event is eventually published by
+//! the outbox relay.
+
+use async_trait::async_trait;
+use anyhow::Result;
+use chrono::Utc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use crate::domain::events::*;
+use crate::infrastructure::event_bus::IntegrationMessage;
+use crate::infrastructure::outbox::{OutboxEntry, OutboxStore};
+
+/// Event store trait
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    /// Append events to the store and record them in the outbox
+    async fn append_events(
+        &self,
+        aggregate_id: &str,
+        aggregate_type: &str,
+        events: Vec<DomainEvent>,
+        expected_version: Option<i64>,
+    ) -> Result<()> {
+        let messages = events
+            .iter()
+            .map(IntegrationMessage::from_domain_event)
+            .collect::<Result<Vec<_>>>()?;
+        self.append_messages(aggregate_id, aggregate_type, messages, expected_version)
+            .await
+    }
+    /// Append serialized events and their outbox entries in one atomic write
+    ///
+    /// Nothing is written when `expected_version` does not match the
+    /// number of events already stored for the aggregate.
+    async fn append_messages(
+        &self,
+        aggregate_id: &str,
+        aggregate_type: &str,
+        messages: Vec<IntegrationMessage>,
+        expected_version: Option<i64>,
+    ) -> Result<()>;
+    /// Number of events stored for an aggregate
+    async fn version(&self, aggregate_id: &str, aggregate_type: &str) -> Result<i64>;
+    /// Load events for an aggregate
+    async fn load_events(
+        &self,
+        aggregate_id: &str,
+        aggregate_type: &str,
+    ) -> Result<Vec<DomainEvent>>;
+    /// Get all events in order
+    async fn get_all_events(&self) -> Result<Vec<DomainEvent>>;
+    /// Serialized events of all aggregates in append order, skipping the first `position`
+    async fn read_all_from(&self, position: i64) -> Result<Vec<serde_json::Value>>;
+}
+
+/// Reject an append when the aggregate is not at the expected version
+pub fn check_version(expected_version: Option<i64>, current_version: i64) -> Result<()> {
+    match expected_version {
+        Some(expected) if expected != current_version => {
+            anyhow::bail!(
+                "Concurrency conflict: expected version {}, found {}",
+                expected,
+                current_version,
+            )
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Event stored for an aggregate
+#[derive(Debug, Clone)]
+struct StoredEvent {
+    aggregate_id: String,
+    aggregate_type: String,
+    payload: serde_json::Value,
+}
+
+#[derive(Default)]
+struct InMemoryState {
+    events: Vec<StoredEvent>,
+    outbox: Vec<OutboxEntry>,
+}
+
+impl InMemoryState {
+    fn aggregate_events<'a>(
+        &'a self,
+        aggregate_id: &'a str,
+        aggregate_type: &'a str,
+    ) -> impl Iterator<Item = &'a StoredEvent> + 'a {
+        self.events
+            .iter()
+            .filter(move |event| {
+                event.aggregate_id == aggregate_id
+                    && event.aggregate_type == aggregate_type
+            })
+    }
+    fn outbox_entry(&mut self, message_id: Uuid) -> Option<&mut OutboxEntry> {
+        self.outbox.iter_mut().find(|entry| entry.message.message_id == message_id)
+    }
+}
+
+/// In-memory event store and outbox (for development/testing)
+#[derive(Default)]
+pub struct InMemoryEventStore {
+    state: RwLock<InMemoryState>,
+}
+
+impl InMemoryEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append_messages(
+        &self,
+        aggregate_id: &str,
+        aggregate_type: &str,
+        messages: Vec<IntegrationMessage>,
+        expected_version: Option<i64>,
+    ) -> Result<()> {
+        let mut state = self.state.write().await;
+        let current_version = state
+            .aggregate_events(aggregate_id, aggregate_type)
+            .count() as i64;
+        check_version(expected_version, current_version)?;
+        for message in messages {
+            state
+                .events
+                .push(StoredEvent {
+                    aggregate_id: aggregate_id.to_string(),
+                    aggregate_type: aggregate_type.to_string(),
+                    payload: message.payload.clone(),
+                });
+            state.outbox.push(OutboxEntry::new(message));
+        }
+        Ok(())
+    }
+    async fn version(&self, aggregate_id: &str, aggregate_type: &str) -> Result<i64> {
+        let state = self.state.read().await;
+        Ok(state.aggregate_events(aggregate_id, aggregate_type).count() as i64)
+    }
+    async fn load_events(
+        &self,
+        aggregate_id: &str,
+        aggregate_type: &str,
+    ) -> Result<Vec<DomainEvent>> {
+        let state = self.state.read().await;
+        state
+            .aggregate_events(aggregate_id, aggregate_type)
+            .map(|event| Ok(serde_json::from_value(event.payload.clone())?))
+            .collect()
+    }
+    async fn get_all_events(&self) -> Result<Vec<DomainEvent>> {
+        let state = self.state.read().await;
+        state
+            .events
+            .iter()
+            .map(|event| Ok(serde_json::from_value(event.payload.clone())?))
+            .collect()
+    }
+    async fn read_all_from(&self, position: i64) -> Result<Vec<serde_json::Value>> {
+        let state = self.state.read().await;
+        Ok(
+            state
+                .events
+                .iter()
+                .skip(usize::try_from(position)?)
+                .map(|event| event.payload.clone())
+                .collect(),
+        )
+    }
+}
+
+#[async_trait]
+impl OutboxStore for InMemoryEventStore {
+    async fn pending(&self, limit: usize) -> Result<Vec<OutboxEntry>> {
+        let state = self.state.read().await;
+        Ok(
+            state
+                .outbox
+                .iter()
+                .filter(|entry| entry.is_pending())
+                .take(limit)
+                .cloned()
+                .collect(),
+        )
+    }
+    async fn mark_published(&self, message_id: Uuid) -> Result<()> {
+        if let Some(entry) = self.state.write().await.outbox_entry(message_id) {
+            entry.published_at = Some(Utc::now());
+        }
+        Ok(())
+    }
+    async fn mark_failed(&self, message_id: Uuid, error: &str) -> Result<()> {
+        if let Some(entry) = self.state.write().await.outbox_entry(message_id) {
+            entry.attempts += 1;
+            entry.last_error = Some(error.to_string());
+        }
+        Ok(())
+    }
+    async fn mark_dead_lettered(&self, message_id: Uuid) -> Result<()> {
+        if let Some(entry) = self.state.write().await.outbox_entry(message_id) {
+            entry.dead_lettered_at = Some(Utc::now());
+        }
+        Ok(())
+    }
+    async fn dead_letters(&self, limit: usize) -> Result<Vec<OutboxEntry>> {
+        let state = self.state.read().await;
+        Ok(
+            state
+                .outbox
+                .iter()
+                .filter(|entry| {
+                    entry.published_at.is_none() && entry.dead_lettered_at.is_some()
+                })
+                .take(limit)
+                .cloned()
+                .collect(),
+        )
+    }
+    async fn replay(&self, message_id: Uuid) -> Result<()> {
+        if let Some(entry) = self.state.write().await.outbox_entry(message_id) {
+            entry.attempts = 0;
+            entry.dead_lettered_at = None;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::outbox::conformance;
+
+    #[tokio::test]
+    async fn append_records_events_in_outbox() {
+        conformance::append_records_events_in_outbox(InMemoryEventStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn conflicting_append_writes_nothing() {
+        conformance::conflicting_append_writes_nothing(InMemoryEventStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn reads_events_from_position() {
+        conformance::reads_events_from_position(InMemoryEventStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn relay_publishes_each_entry_once() {
+        conformance::relay_publishes_each_entry_once(InMemoryEventStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn relay_retries_failed_publishes() {
+        conformance::relay_retries_failed_publishes(InMemoryEventStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn relay_dead_letters_after_max_attempts() {
+        conformance::relay_dead_letters_after_max_attempts(InMemoryEventStore::new())
+            .await;
+    }
+
+    #[tokio::test]
+    async fn replayed_dead_letter_is_published() {
+        conformance::replayed_dead_letter_is_published(InMemoryEventStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn redelivery_keeps_message_id() {
+        conformance::redelivery_keeps_message_id(InMemoryEventStore::new()).await;
+    }
+}
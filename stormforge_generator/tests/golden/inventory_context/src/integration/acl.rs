@@ -0,0 +1,33 @@
+//! Anti-corruption layer
+//!
+//! Translates integration events from other bounded contexts into
+//! this context's domain model.
+
+use anyhow::Result;
+use crate::integration::contracts;
+
+/// Reserve stock when order is created
+///
+/// Translates `OrderCreated` from the Order context into this context's domain model.
+pub async fn handle_order_created(event: contracts::order::OrderCreated) -> Result<()> {
+    tracing::info!("Received OrderCreated from Order: {:?}", event);
+    Ok(())
+}
+
+/// Release reserved stock when order is cancelled
+///
+/// Translates `OrderCancelled` from the Order context into this context's domain model.
+pub async fn handle_order_cancelled(
+    event: contracts::order::OrderCancelled,
+) -> Result<()> {
+    tracing::info!("Received OrderCancelled from Order: {:?}", event);
+    Ok(())
+}
+
+/// Deduct stock when order is shipped
+///
+/// Translates `OrderShipped` from the Order context into this context's domain model.
+pub async fn handle_order_shipped(event: contracts::order::OrderShipped) -> Result<()> {
+    tracing::info!("Received OrderShipped from Order: {:?}", event);
+    Ok(())
+}
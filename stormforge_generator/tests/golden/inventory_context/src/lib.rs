@@ -0,0 +1,5 @@
+pub mod api;
+pub mod domain;
+pub mod infrastructure;
+pub mod integration;
+pub mod repository;
@@ -0,0 +1,91 @@
+use axum::{routing::get, Router};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tower_http::cors::CorsLayer;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use utoipa_swagger_ui::SwaggerUi;
+use ::inventory::{api, infrastructure, integration};
+use ::inventory::api::create_router;
+#[cfg(feature = "sqlx")]
+use ::inventory::repository;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| "info".into()),
+        )
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+    tracing::info!("Starting {} service...", "Inventory");
+    let event_bus: Arc<dyn infrastructure::EventBus> = match std::env::var(
+        "EVENT_BUS_PATH",
+    ) {
+        Ok(path) => Arc::new(infrastructure::JsonlEventBus::new(path)),
+        Err(_) => Arc::new(infrastructure::InMemoryEventBus::new()),
+    };
+    #[cfg(feature = "sqlx")]
+    let event_store = Arc::new(
+        infrastructure::SqliteEventStore::connect(
+                &std::env::var("DATABASE_URL")
+                    .unwrap_or_else(|_| "sqlite://events.db?mode=rwc".to_string()),
+            )
+            .await?,
+    );
+    #[cfg(not(feature = "sqlx"))]
+    let event_store = Arc::new(infrastructure::InMemoryEventStore::new());
+    #[cfg(feature = "sqlx")]
+    {
+        repository::migrate(event_store.pool()).await?;
+    }
+    #[cfg(feature = "sqlx")]
+    let app_state = api::AppState::with_repositories(
+        event_store.clone(),
+        repository::Repositories::sqlite(event_store.pool().clone()),
+    );
+    #[cfg(not(feature = "sqlx"))]
+    let app_state = api::AppState::new(event_store.clone());
+    #[cfg(feature = "graphql")]
+    let graphql_router = api::graphql::router(app_state.clone());
+    let outbox_relay = infrastructure::OutboxRelay::new(event_store, event_bus.clone());
+    tokio::spawn(outbox_relay.run(Duration::from_secs(1)));
+    let mut event_listener = integration::IntegrationEventListener::new(
+        event_bus.clone(),
+    );
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = event_listener.poll().await {
+                tracing::error!("Failed to process integration events: {}", e);
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+    });
+    let api_router = create_router(app_state.clone());
+    let swagger = SwaggerUi::new("/swagger-ui")
+        .external_url_unchecked("/api-docs/openapi.json", api::openapi());
+    let app = Router::new()
+        .merge(swagger)
+        .route("/api-docs/asyncapi.json", get(api::asyncapi))
+        .nest("/api", api_router)
+        .layer(CorsLayer::permissive());
+    #[cfg(feature = "graphql")]
+    let app = app.merge(graphql_router);
+    let addr = SocketAddr::from(([0, 0, 0, 0], 3000));
+    tracing::info!("Listening on http://{}", addr);
+    tracing::info!("Swagger UI available at http://{}/swagger-ui", addr);
+    #[cfg(feature = "grpc")]
+    {
+        let grpc_addr = SocketAddr::from(([0, 0, 0, 0], 50051));
+        tracing::info!("gRPC API listening on {}", grpc_addr);
+        tokio::spawn(async move {
+            if let Err(e) = api::grpc::serve(grpc_addr, app_state).await {
+                tracing::error!("gRPC server failed: {}", e);
+            }
+        });
+    }
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Repository layer for data persistence
+//!
+//! This module provides repository implementations for aggregates.
+
+use async_trait::async_trait;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use crate::domain::entities::*;
+
+#[cfg(feature = "sqlx")]
+pub mod sqlite_product_repository;
+
+#[cfg(feature = "sqlx")]
+pub use sqlite_product_repository::*;
+
+/// Generic repository trait for aggregates
+#[async_trait]
+pub trait Repository<T>: Send + Sync {
+    /// Find an aggregate by ID
+    async fn find_by_id(&self, id: &str) -> Result<Option<T>>;
+    /// Save an aggregate
+    async fn save(&self, aggregate: &T) -> Result<()>;
+    /// Delete an aggregate
+    async fn delete(&self, id: &str) -> Result<()>;
+}
+
+/// In-memory repository implementation (for development/testing)
+pub struct InMemoryRepository<T> {
+    aggregates: RwLock<HashMap<String, T>>,
+    /// ID of an aggregate
+    id: fn(&T) -> String,
+}
+
+impl<T> InMemoryRepository<T> {
+    pub fn new(id: fn(&T) -> String) -> Self {
+        Self {
+            aggregates: RwLock::new(HashMap::new()),
+            id,
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> Repository<T> for InMemoryRepository<T> {
+    async fn find_by_id(&self, id: &str) -> Result<Option<T>> {
+        Ok(self.aggregates.read().await.get(id).cloned())
+    }
+    async fn save(&self, aggregate: &T) -> Result<()> {
+        let id = (self.id)(aggregate);
+        self.aggregates.write().await.insert(id, aggregate.clone());
+        Ok(())
+    }
+    async fn delete(&self, id: &str) -> Result<()> {
+        self.aggregates.write().await.remove(id);
+        Ok(())
+    }
+}
+
+/// Repositories of the state-stored aggregates that commands act on
+#[derive(Clone)]
+pub struct Repositories {
+    pub product: Arc<dyn Repository<Product>>,
+}
+
+impl Repositories {
+    /// Repositories keeping the aggregates in memory
+    pub fn in_memory() -> Self {
+        Self {
+            product: Arc::new(
+                InMemoryRepository::new(|aggregate: &Product| aggregate.id.0.clone()),
+            ),
+        }
+    }
+    /// SQLite repositories over the tables created by `migrate`
+    #[cfg(feature = "sqlx")]
+    pub fn sqlite(pool: sqlx::SqlitePool) -> Self {
+        Self {
+            product: Arc::new(SqliteProductRepository::new(pool.clone())),
+        }
+    }
+}
+
+/// Migrations of the state-stored aggregates' tables, from `migrations/`
+#[cfg(feature = "sqlx")]
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!();
+
+/// Apply the migrations the database has not seen yet
+#[cfg(feature = "sqlx")]
+pub async fn migrate(pool: &sqlx::SqlitePool) -> Result<()> {
+    MIGRATOR.run(pool).await?;
+    Ok(())
+}
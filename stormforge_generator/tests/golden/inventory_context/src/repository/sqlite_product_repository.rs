@@ -0,0 +1,70 @@
+//! SQLite repository of the state-stored `Product` aggregate
+//!
+//! The tables are created by the migrations in `migrations/`.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool};
+use super::Repository;
+use crate::domain::entities::*;
+
+/// `Product` aggregates stored as their current state
+///
+/// Tables: `products`
+#[derive(Clone)]
+pub struct SqliteProductRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteProductRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl Repository<Product> for SqliteProductRepository {
+    async fn find_by_id(&self, id: &str) -> Result<Option<Product>> {
+        let row = sqlx::query(
+                "SELECT id, sku, name, available_quantity, reserved_quantity, reorder_level, updated_at FROM products WHERE id = ?",
+            )
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let product = Product {
+            id: ProductId(row.try_get::<String, _>("id")?),
+            sku: row.try_get::<String, _>("sku")?,
+            name: row.try_get::<String, _>("name")?,
+            available_quantity: row.try_get::<i64, _>("available_quantity")?,
+            reserved_quantity: row.try_get::<i64, _>("reserved_quantity")?,
+            reorder_level: row.try_get::<i64, _>("reorder_level")?,
+            updated_at: row.try_get::<chrono::DateTime<chrono::Utc>, _>("updated_at")?,
+        };
+        Ok(Some(product))
+    }
+    async fn save(&self, aggregate: &Product) -> Result<()> {
+        sqlx::query(
+                "INSERT INTO products (id, sku, name, available_quantity, reserved_quantity, reorder_level, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?) ON CONFLICT (id) DO UPDATE SET sku = excluded.sku, name = excluded.name, available_quantity = excluded.available_quantity, reserved_quantity = excluded.reserved_quantity, reorder_level = excluded.reorder_level, updated_at = excluded.updated_at",
+            )
+            .bind(&aggregate.id.0)
+            .bind(&aggregate.sku)
+            .bind(&aggregate.name)
+            .bind(aggregate.available_quantity)
+            .bind(aggregate.reserved_quantity)
+            .bind(aggregate.reorder_level)
+            .bind(aggregate.updated_at)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+    async fn delete(&self, id: &str) -> Result<()> {
+        sqlx::query("DELETE FROM products WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
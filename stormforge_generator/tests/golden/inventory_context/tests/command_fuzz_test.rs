@@ -0,0 +1,83 @@
+//! Command validation fuzzed with payloads outside the validation rules
+
+mod support;
+
+use proptest::prelude::*;
+use support::strategies::{self, check};
+use ::inventory::domain::commands::*;
+
+/// `AddStock` commands ignoring the validation rules
+fn any_add_stock() -> BoxedStrategy<AddStock> {
+    (strategies::product_id(), any::<i64>())
+        .prop_map(|(product_id, quantity)| AddStock { product_id, quantity })
+        .boxed()
+}
+
+/// `ReserveStock` commands ignoring the validation rules
+fn any_reserve_stock() -> BoxedStrategy<ReserveStock> {
+    (strategies::product_id(), strategies::order_id(), any::<i64>())
+        .prop_map(|(product_id, order_id, quantity)| ReserveStock {
+            product_id,
+            order_id,
+            quantity,
+        })
+        .boxed()
+}
+
+/// `ReleaseStock` commands ignoring the validation rules
+fn any_release_stock() -> BoxedStrategy<ReleaseStock> {
+    (strategies::reservation_id(),)
+        .prop_map(|(reservation_id,)| ReleaseStock { reservation_id })
+        .boxed()
+}
+
+/// `DeductStock` commands ignoring the validation rules
+fn any_deduct_stock() -> BoxedStrategy<DeductStock> {
+    (strategies::reservation_id(),)
+        .prop_map(|(reservation_id,)| DeductStock { reservation_id })
+        .boxed()
+}
+
+#[test]
+fn add_stock_validation_never_panics() {
+    check(
+        any_add_stock(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn reserve_stock_validation_never_panics() {
+    check(
+        any_reserve_stock(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn release_stock_validation_never_panics() {
+    check(
+        any_release_stock(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn deduct_stock_validation_never_panics() {
+    check(
+        any_deduct_stock(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,48 @@
+//! Round trips of `Product` through `SqliteProductRepository`
+#![cfg(feature = "sqlx")]
+
+mod support;
+
+use proptest::prelude::*;
+use sqlx::sqlite::SqlitePoolOptions;
+use support::strategies::{self, check};
+use ::inventory::domain::entities::*;
+use ::inventory::repository::{self, Repository, SqliteProductRepository};
+
+/// Save an aggregate twice, load it back and delete it
+async fn round_trip(
+    repository: &SqliteProductRepository,
+    product: Product,
+) -> Result<(), TestCaseError> {
+    let fail = |error: anyhow::Error| TestCaseError::fail(error.to_string());
+    let id = product.id.0.clone();
+    let expected = serde_json::to_value(&product).unwrap();
+    repository.save(&product).await.map_err(fail)?;
+    repository.save(&product).await.map_err(fail)?;
+    let loaded = repository.find_by_id(&id).await.map_err(fail)?;
+    let loaded = loaded.map(|loaded| serde_json::to_value(loaded).unwrap());
+    prop_assert_eq!(loaded, Some(expected));
+    repository.delete(&id).await.map_err(fail)?;
+    let deleted = repository.find_by_id(&id).await.map_err(fail)?;
+    prop_assert!(deleted.is_none());
+    Ok(())
+}
+
+#[test]
+fn product_round_trips() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let repository = runtime
+        .block_on(async {
+            let pool = SqlitePoolOptions::new()
+                .max_connections(1)
+                .connect("sqlite::memory:")
+                .await
+                .unwrap();
+            repository::migrate(&pool).await.unwrap();
+            SqliteProductRepository::new(pool)
+        });
+    check(
+        strategies::product(),
+        |product| runtime.block_on(round_trip(&repository, product)),
+    );
+}
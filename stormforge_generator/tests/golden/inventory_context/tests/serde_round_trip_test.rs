@@ -0,0 +1,101 @@
+//! Serde round trips of the domain types with generated values
+
+mod support;
+
+use proptest::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use support::strategies::{self, check};
+use ::inventory::domain::events::DomainEvent;
+
+/// Decode a value's JSON and expect the result to encode to the same JSON
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> Result<(), TestCaseError> {
+    let json = serde_json::to_value(value)
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    let decoded: T = serde_json::from_value(json.clone())
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    let encoded = serde_json::to_value(&decoded)
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    prop_assert_eq!(encoded, json);
+    Ok(())
+}
+
+#[test]
+fn product_id_round_trips() {
+    check(strategies::product_id(), |value| round_trip(&value));
+}
+
+#[test]
+fn order_id_round_trips() {
+    check(strategies::order_id(), |value| round_trip(&value));
+}
+
+#[test]
+fn reservation_id_round_trips() {
+    check(strategies::reservation_id(), |value| round_trip(&value));
+}
+
+#[test]
+fn product_round_trips() {
+    check(strategies::product(), |value| round_trip(&value));
+}
+
+#[test]
+fn add_stock_round_trips() {
+    check(strategies::add_stock(), |value| round_trip(&value));
+}
+
+#[test]
+fn reserve_stock_round_trips() {
+    check(strategies::reserve_stock(), |value| round_trip(&value));
+}
+
+#[test]
+fn release_stock_round_trips() {
+    check(strategies::release_stock(), |value| round_trip(&value));
+}
+
+#[test]
+fn deduct_stock_round_trips() {
+    check(strategies::deduct_stock(), |value| round_trip(&value));
+}
+
+#[test]
+fn stock_added_round_trips() {
+    check(
+        strategies::stock_added(),
+        |event| { round_trip(&DomainEvent::StockAdded(event)) },
+    );
+}
+
+#[test]
+fn stock_reserved_round_trips() {
+    check(
+        strategies::stock_reserved(),
+        |event| { round_trip(&DomainEvent::StockReserved(event)) },
+    );
+}
+
+#[test]
+fn stock_released_round_trips() {
+    check(
+        strategies::stock_released(),
+        |event| { round_trip(&DomainEvent::StockReleased(event)) },
+    );
+}
+
+#[test]
+fn stock_deducted_round_trips() {
+    check(
+        strategies::stock_deducted(),
+        |event| { round_trip(&DomainEvent::StockDeducted(event)) },
+    );
+}
+
+#[test]
+fn low_stock_alert_round_trips() {
+    check(
+        strategies::low_stock_alert(),
+        |event| { round_trip(&DomainEvent::LowStockAlert(event)) },
+    );
+}
@@ -0,0 +1,114 @@
+//! Scenario and samples of the `Product` aggregate
+
+use super::Scenario;
+use ::inventory::domain::commands::*;
+use ::inventory::domain::entities::*;
+use ::inventory::domain::events::*;
+
+pub const AGGREGATE_TYPE: &str = "Product";
+
+/// ID of the aggregate in scenarios and sample events
+pub const AGGREGATE_ID: &str = "product-1";
+
+/// Scenario of the sample `Product`
+pub fn scenario() -> Scenario {
+    Scenario::new(AGGREGATE_TYPE, AGGREGATE_ID)
+}
+
+/// Sample `AddStock` command
+pub fn add_stock() -> AddStock {
+    AddStock {
+        product_id: ProductId("product-1".to_string()),
+        quantity: 1,
+    }
+}
+
+/// Sample `ReserveStock` command
+pub fn reserve_stock() -> ReserveStock {
+    ReserveStock {
+        product_id: ProductId("product-1".to_string()),
+        order_id: OrderId("order-1".to_string()),
+        quantity: 1,
+    }
+}
+
+/// Sample `ReleaseStock` command
+pub fn release_stock() -> ReleaseStock {
+    ReleaseStock {
+        reservation_id: ReservationId("reservation-1".to_string()),
+    }
+}
+
+/// Sample `DeductStock` command
+pub fn deduct_stock() -> DeductStock {
+    DeductStock {
+        reservation_id: ReservationId("reservation-1".to_string()),
+    }
+}
+
+/// Sample `StockAdded` event
+pub fn stock_added() -> DomainEvent {
+    DomainEvent::StockAdded(
+        StockAdded::new(
+            AGGREGATE_ID.to_string(),
+            ProductId("product-1".to_string()),
+            1,
+            1,
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `StockReserved` event
+pub fn stock_reserved() -> DomainEvent {
+    DomainEvent::StockReserved(
+        StockReserved::new(
+            AGGREGATE_ID.to_string(),
+            ProductId("product-1".to_string()),
+            OrderId("order-1".to_string()),
+            ReservationId("reservation-1".to_string()),
+            1,
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `StockReleased` event
+pub fn stock_released() -> DomainEvent {
+    DomainEvent::StockReleased(
+        StockReleased::new(
+            AGGREGATE_ID.to_string(),
+            ProductId("product-1".to_string()),
+            ReservationId("reservation-1".to_string()),
+            1,
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `StockDeducted` event
+pub fn stock_deducted() -> DomainEvent {
+    DomainEvent::StockDeducted(
+        StockDeducted::new(
+            AGGREGATE_ID.to_string(),
+            ProductId("product-1".to_string()),
+            ReservationId("reservation-1".to_string()),
+            1,
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `LowStockAlert` event
+pub fn low_stock_alert() -> DomainEvent {
+    DomainEvent::LowStockAlert(
+        LowStockAlert::new(
+            AGGREGATE_ID.to_string(),
+            ProductId("product-1".to_string()),
+            "sample".to_string(),
+            1,
+            1,
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
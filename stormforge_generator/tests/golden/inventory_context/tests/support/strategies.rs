@@ -0,0 +1,291 @@
+//! Proptest strategies for the domain types
+//!
+//! Values respect the IR's validation rules: string lengths and
+//! patterns, numeric ranges and decimal places.
+
+use proptest::prelude::*;
+use proptest::test_runner::TestRunner;
+use ::inventory::domain::commands::*;
+use ::inventory::domain::entities::*;
+use ::inventory::domain::events::*;
+
+/// Run a property against values of a strategy
+///
+/// A failure panics with the smallest failing value proptest finds.
+pub fn check<S: Strategy>(
+    strategy: S,
+    test: impl Fn(S::Value) -> Result<(), TestCaseError>,
+) {
+    let mut runner = TestRunner::default();
+    if let Err(error) = runner.run(&strategy, test) {
+        panic!("{}", error);
+    }
+}
+
+/// `ProductId` values
+pub fn product_id() -> BoxedStrategy<ProductId> {
+    proptest::string::string_regex("[a-z0-9-]{1,16}")
+        .unwrap()
+        .prop_map(ProductId)
+        .boxed()
+}
+
+/// `OrderId` values
+pub fn order_id() -> BoxedStrategy<OrderId> {
+    proptest::string::string_regex("[a-z0-9-]{1,16}").unwrap().prop_map(OrderId).boxed()
+}
+
+/// `ReservationId` values
+pub fn reservation_id() -> BoxedStrategy<ReservationId> {
+    proptest::string::string_regex("[a-z0-9-]{1,16}")
+        .unwrap()
+        .prop_map(ReservationId)
+        .boxed()
+}
+
+/// `Product` values
+pub fn product() -> BoxedStrategy<Product> {
+    (
+        product_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        any::<i64>(),
+        any::<i64>(),
+        any::<i64>(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                id,
+                sku,
+                name,
+                available_quantity,
+                reserved_quantity,
+                reorder_level,
+                updated_at,
+            )|
+        Product {
+            id,
+            sku,
+            name,
+            available_quantity,
+            reserved_quantity,
+            reorder_level,
+            updated_at,
+        })
+        .boxed()
+}
+
+/// `AddStock` values
+pub fn add_stock() -> BoxedStrategy<AddStock> {
+    (product_id(), 1i64..=9223372036854775807)
+        .prop_map(|(product_id, quantity)| AddStock { product_id, quantity })
+        .boxed()
+}
+
+/// `ReserveStock` values
+pub fn reserve_stock() -> BoxedStrategy<ReserveStock> {
+    (product_id(), order_id(), 1i64..=9223372036854775807)
+        .prop_map(|(product_id, order_id, quantity)| ReserveStock {
+            product_id,
+            order_id,
+            quantity,
+        })
+        .boxed()
+}
+
+/// `ReleaseStock` values
+pub fn release_stock() -> BoxedStrategy<ReleaseStock> {
+    (reservation_id(),)
+        .prop_map(|(reservation_id,)| ReleaseStock { reservation_id })
+        .boxed()
+}
+
+/// `DeductStock` values
+pub fn deduct_stock() -> BoxedStrategy<DeductStock> {
+    (reservation_id(),)
+        .prop_map(|(reservation_id,)| DeductStock { reservation_id })
+        .boxed()
+}
+
+/// `StockAdded` values
+pub fn stock_added() -> BoxedStrategy<StockAdded> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        product_id(),
+        any::<i64>(),
+        any::<i64>(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                product_id,
+                quantity,
+                new_available_quantity,
+                added_at,
+            )|
+        StockAdded {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            product_id,
+            quantity,
+            new_available_quantity,
+            added_at,
+        })
+        .boxed()
+}
+
+/// `StockReserved` values
+pub fn stock_reserved() -> BoxedStrategy<StockReserved> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        product_id(),
+        order_id(),
+        reservation_id(),
+        any::<i64>(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                product_id,
+                order_id,
+                reservation_id,
+                quantity,
+                reserved_at,
+            )|
+        StockReserved {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            product_id,
+            order_id,
+            reservation_id,
+            quantity,
+            reserved_at,
+        })
+        .boxed()
+}
+
+/// `StockReleased` values
+pub fn stock_released() -> BoxedStrategy<StockReleased> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        product_id(),
+        reservation_id(),
+        any::<i64>(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                product_id,
+                reservation_id,
+                quantity,
+                released_at,
+            )|
+        StockReleased {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            product_id,
+            reservation_id,
+            quantity,
+            released_at,
+        })
+        .boxed()
+}
+
+/// `StockDeducted` values
+pub fn stock_deducted() -> BoxedStrategy<StockDeducted> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        product_id(),
+        reservation_id(),
+        any::<i64>(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                product_id,
+                reservation_id,
+                quantity,
+                deducted_at,
+            )|
+        StockDeducted {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            product_id,
+            reservation_id,
+            quantity,
+            deducted_at,
+        })
+        .boxed()
+}
+
+/// `LowStockAlert` values
+pub fn low_stock_alert() -> BoxedStrategy<LowStockAlert> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        product_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        any::<i64>(),
+        any::<i64>(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                product_id,
+                sku,
+                current_quantity,
+                reorder_level,
+                alerted_at,
+            )|
+        LowStockAlert {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            product_id,
+            sku,
+            current_quantity,
+            reorder_level,
+            alerted_at,
+        })
+        .boxed()
+}
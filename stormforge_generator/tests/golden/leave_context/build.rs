@@ -0,0 +1,12 @@
+//! Compiles the gRPC API definition when the `grpc` feature is enabled
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    #[cfg(feature = "grpc")]
+    {
+        if std::env::var_os("PROTOC").is_none() {
+            std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+        }
+        tonic_build::compile_protos("proto/leave.proto")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,172 @@
+//! GraphQL API of the Leave context
+//!
+//! Queries and mutations delegate to the same handlers as the HTTP routes,
+//! and the `events` subscription streams the domain events appended to the
+//! event store. `schema.graphql` holds the schema in SDL.
+
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{ErrorExtensions, Object, Schema};
+use async_graphql_axum::{GraphQL, GraphQLSubscription};
+use axum::{
+    response::{Html, IntoResponse},
+    routing::get, Router,
+};
+use crate::api::routes::*;
+use crate::domain::{commands::*, entities::*, events::*};
+
+/// Schema of the GraphQL API
+pub type ApiSchema = Schema<QueryRoot, MutationRoot, SubscriptionRoot>;
+
+/// Build the schema over the state shared with the HTTP API
+pub fn schema(state: AppState) -> ApiSchema {
+    Schema::build(QueryRoot, MutationRoot, SubscriptionRoot).data(state).finish()
+}
+
+/// Router serving GraphQL and GraphiQL at `/graphql`, and subscriptions at `/graphql/ws`
+pub fn router(state: AppState) -> Router {
+    let schema = schema(state);
+    Router::new()
+        .route("/graphql", get(graphiql).post_service(GraphQL::new(schema.clone())))
+        .route_service("/graphql/ws", GraphQLSubscription::new(schema))
+}
+
+async fn graphiql() -> impl IntoResponse {
+    Html(
+        GraphiQLSource::build()
+            .endpoint("/graphql")
+            .subscription_endpoint("/graphql/ws")
+            .finish(),
+    )
+}
+
+/// GraphQL error with the API error code as `code` extension
+fn graphql_error(error: ApiError) -> async_graphql::Error {
+    let ApiError { code, message } = error;
+    async_graphql::Error::new(message)
+        .extend_with(|_, extensions| extensions.set("code", code))
+}
+
+/// Queries as query fields
+pub struct QueryRoot;
+
+#[Object(name = "Query")]
+impl QueryRoot {
+    /// Get leave request by ID
+    async fn get_leave_request(
+        &self,
+        #[graphql(name = "leaveRequestId")]
+        _leave_request_id: LeaveRequestId,
+    ) -> async_graphql::Result<String> {
+        execute_get_leave_request().await.map_err(graphql_error)
+    }
+    /// Get all leave requests for an employee
+    async fn get_employee_leaves(
+        &self,
+        #[graphql(name = "employeeId")]
+        _employee_id: EmployeeId,
+        #[graphql(name = "year")]
+        _year: Option<i64>,
+        #[graphql(name = "status")]
+        _status: Option<LeaveStatus>,
+    ) -> async_graphql::Result<String> {
+        execute_get_employee_leaves().await.map_err(graphql_error)
+    }
+    /// Get pending leave requests for approval
+    async fn get_pending_approvals(
+        &self,
+        #[graphql(name = "approverId")]
+        _approver_id: EmployeeId,
+    ) -> async_graphql::Result<String> {
+        execute_get_pending_approvals().await.map_err(graphql_error)
+    }
+    /// Get remaining leave balance for an employee
+    async fn get_leave_balance(
+        &self,
+        #[graphql(name = "employeeId")]
+        _employee_id: EmployeeId,
+        #[graphql(name = "leaveType")]
+        _leave_type: LeaveType,
+        #[graphql(name = "year")]
+        _year: Option<i64>,
+    ) -> async_graphql::Result<String> {
+        execute_get_leave_balance().await.map_err(graphql_error)
+    }
+}
+
+/// Commands as mutations
+pub struct MutationRoot;
+
+#[Object(name = "Mutation")]
+impl MutationRoot {
+    /// Submit a leave request
+    async fn request_leave(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: RequestLeave,
+    ) -> async_graphql::Result<String> {
+        execute_request_leave(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+    /// Approve a leave request
+    async fn approve_leave(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: ApproveLeave,
+    ) -> async_graphql::Result<String> {
+        execute_approve_leave(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+    /// Reject a leave request
+    async fn reject_leave(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: RejectLeave,
+    ) -> async_graphql::Result<String> {
+        execute_reject_leave(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+    /// Cancel a leave request
+    async fn cancel_leave(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: CancelLeave,
+    ) -> async_graphql::Result<String> {
+        execute_cancel_leave(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+}
+
+/// Interval at which subscriptions poll the event store
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Domain events as a subscription
+pub struct SubscriptionRoot;
+
+#[async_graphql::Subscription(name = "Subscription")]
+impl SubscriptionRoot {
+    /// Domain events appended to the event store, skipping the first `fromPosition`
+    async fn events(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        #[graphql(default)]
+        from_position: i64,
+    ) -> impl async_graphql::futures_util::Stream<
+        Item = async_graphql::Result<DomainEvent>,
+    > {
+        let event_store = ctx.data_unchecked::<AppState>().event_store.clone();
+        async_stream::try_stream! {
+            let mut position = from_position;
+            loop {
+                for payload in event_store.read_all_from(position).await? {
+                    position += 1;
+                    yield serde_json::from_value::<DomainEvent>(payload)?;
+                }
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        }
+    }
+}
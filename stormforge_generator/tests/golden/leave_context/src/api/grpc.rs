@@ -0,0 +1,337 @@
+//! gRPC API serving the command and query handlers of the HTTP API
+//!
+//! Implements `LeaveService` from `proto/leave.proto`.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use rust_decimal::Decimal;
+use std::net::SocketAddr;
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+use crate::api::routes::*;
+use crate::domain::{commands::*, entities::*};
+
+/// Types and service generated from `proto/leave.proto`
+pub mod proto {
+    tonic::include_proto!("hr.leave");
+}
+
+use proto::leave_service_server::{LeaveService, LeaveServiceServer};
+
+/// Serve the gRPC API on the given address, over the state shared with the HTTP API
+pub async fn serve(
+    addr: SocketAddr,
+    state: AppState,
+) -> Result<(), tonic::transport::Error> {
+    tonic::transport::Server::builder()
+        .add_service(LeaveServiceServer::new(GrpcApi { state }))
+        .serve(addr)
+        .await
+}
+
+/// gRPC service delegating to the API's command and query handlers
+pub struct GrpcApi {
+    pub state: AppState,
+}
+
+#[tonic::async_trait]
+impl LeaveService for GrpcApi {
+    /// Submit a leave request
+    async fn request_leave(
+        &self,
+        request: Request<proto::RequestLeaveRequest>,
+    ) -> Result<Response<proto::RequestLeaveResponse>, Status> {
+        let command = RequestLeave::from_proto(request.into_inner())?;
+        let data = execute_request_leave(&self.state, command).await?;
+        Ok(
+            Response::new(proto::RequestLeaveResponse {
+                data,
+            }),
+        )
+    }
+    /// Approve a leave request
+    async fn approve_leave(
+        &self,
+        request: Request<proto::ApproveLeaveRequest>,
+    ) -> Result<Response<proto::ApproveLeaveResponse>, Status> {
+        let command = ApproveLeave::from_proto(request.into_inner())?;
+        let data = execute_approve_leave(&self.state, command).await?;
+        Ok(
+            Response::new(proto::ApproveLeaveResponse {
+                data,
+            }),
+        )
+    }
+    /// Reject a leave request
+    async fn reject_leave(
+        &self,
+        request: Request<proto::RejectLeaveRequest>,
+    ) -> Result<Response<proto::RejectLeaveResponse>, Status> {
+        let command = RejectLeave::from_proto(request.into_inner())?;
+        let data = execute_reject_leave(&self.state, command).await?;
+        Ok(Response::new(proto::RejectLeaveResponse { data }))
+    }
+    /// Cancel a leave request
+    async fn cancel_leave(
+        &self,
+        request: Request<proto::CancelLeaveRequest>,
+    ) -> Result<Response<proto::CancelLeaveResponse>, Status> {
+        let command = CancelLeave::from_proto(request.into_inner())?;
+        let data = execute_cancel_leave(&self.state, command).await?;
+        Ok(Response::new(proto::CancelLeaveResponse { data }))
+    }
+    /// Get leave request by ID
+    async fn get_leave_request(
+        &self,
+        _request: Request<proto::GetLeaveRequestRequest>,
+    ) -> Result<Response<proto::GetLeaveRequestResponse>, Status> {
+        let data = execute_get_leave_request().await?;
+        Ok(
+            Response::new(proto::GetLeaveRequestResponse {
+                data,
+            }),
+        )
+    }
+    /// Get all leave requests for an employee
+    async fn get_employee_leaves(
+        &self,
+        _request: Request<proto::GetEmployeeLeavesRequest>,
+    ) -> Result<Response<proto::GetEmployeeLeavesResponse>, Status> {
+        let data = execute_get_employee_leaves().await?;
+        Ok(
+            Response::new(proto::GetEmployeeLeavesResponse {
+                data,
+            }),
+        )
+    }
+    /// Get pending leave requests for approval
+    async fn get_pending_approvals(
+        &self,
+        _request: Request<proto::GetPendingApprovalsRequest>,
+    ) -> Result<Response<proto::GetPendingApprovalsResponse>, Status> {
+        let data = execute_get_pending_approvals().await?;
+        Ok(
+            Response::new(proto::GetPendingApprovalsResponse {
+                data,
+            }),
+        )
+    }
+    /// Get remaining leave balance for an employee
+    async fn get_leave_balance(
+        &self,
+        _request: Request<proto::GetLeaveBalanceRequest>,
+    ) -> Result<Response<proto::GetLeaveBalanceResponse>, Status> {
+        let data = execute_get_leave_balance().await?;
+        Ok(
+            Response::new(proto::GetLeaveBalanceResponse {
+                data,
+            }),
+        )
+    }
+}
+
+impl From<ApiError> for Status {
+    fn from(error: ApiError) -> Self {
+        let code = match error.code.as_str() {
+            "VALIDATION_ERROR" => tonic::Code::InvalidArgument,
+            "NOT_FOUND" => tonic::Code::NotFound,
+            "PRECONDITION_FAILED" => tonic::Code::FailedPrecondition,
+            _ => tonic::Code::Internal,
+        };
+        Status::new(code, error.message)
+    }
+}
+
+/// Conversion of a decoded protobuf value into a domain value
+pub trait FromProto<T>: Sized {
+    fn from_proto(value: T) -> Result<Self, Status>;
+}
+
+/// Value of a message field the domain type requires
+fn required<T>(value: Option<T>, field: &str) -> Result<T, Status> {
+    value.ok_or_else(|| Status::invalid_argument(format!("Missing field: {}", field)))
+}
+
+fn invalid(field: &str, error: impl std::fmt::Display) -> Status {
+    Status::invalid_argument(format!("Invalid {}: {}", field, error))
+}
+
+impl FromProto<String> for String {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+impl FromProto<i64> for i64 {
+    fn from_proto(value: i64) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+impl FromProto<bool> for bool {
+    fn from_proto(value: bool) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+/// Decimals are strings to keep their precision
+impl FromProto<String> for Decimal {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("decimal", e))
+    }
+}
+
+impl FromProto<String> for Uuid {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("UUID", e))
+    }
+}
+
+/// Dates are `YYYY-MM-DD` strings
+impl FromProto<String> for NaiveDate {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("date", e))
+    }
+}
+
+impl FromProto<String> for NaiveTime {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("time", e))
+    }
+}
+
+impl FromProto<prost_types::Timestamp> for DateTime<Utc> {
+    fn from_proto(value: prost_types::Timestamp) -> Result<Self, Status> {
+        u32::try_from(value.nanos)
+            .ok()
+            .and_then(|nanos| DateTime::from_timestamp(value.seconds, nanos))
+            .ok_or_else(|| invalid("timestamp", format!("{:?}", value)))
+    }
+}
+
+impl<T: FromProto<P>, P> FromProto<Vec<P>> for Vec<T> {
+    fn from_proto(value: Vec<P>) -> Result<Self, Status> {
+        value.into_iter().map(T::from_proto).collect()
+    }
+}
+
+impl<T: FromProto<P>, P> FromProto<Option<P>> for Option<T> {
+    fn from_proto(value: Option<P>) -> Result<Self, Status> {
+        value.map(T::from_proto).transpose()
+    }
+}
+
+impl FromProto<proto::LeaveRequestId> for LeaveRequestId {
+    fn from_proto(value: proto::LeaveRequestId) -> Result<Self, Status> {
+        Ok(Self(FromProto::from_proto(value.value)?))
+    }
+}
+
+impl FromProto<proto::EmployeeId> for EmployeeId {
+    fn from_proto(value: proto::EmployeeId) -> Result<Self, Status> {
+        Ok(Self(FromProto::from_proto(value.value)?))
+    }
+}
+
+impl FromProto<i32> for LeaveType {
+    fn from_proto(value: i32) -> Result<Self, Status> {
+        match proto::LeaveType::try_from(value) {
+            Ok(proto::LeaveType::Annual) => Ok(Self::Annual),
+            Ok(proto::LeaveType::Sick) => Ok(Self::Sick),
+            Ok(proto::LeaveType::Personal) => Ok(Self::Personal),
+            Ok(proto::LeaveType::Maternity) => Ok(Self::Maternity),
+            Ok(proto::LeaveType::Paternity) => Ok(Self::Paternity),
+            Ok(proto::LeaveType::Bereavement) => Ok(Self::Bereavement),
+            Ok(proto::LeaveType::Unpaid) => Ok(Self::Unpaid),
+            _ => Err(Status::invalid_argument(format!("Invalid LeaveType: {}", value))),
+        }
+    }
+}
+
+impl FromProto<i32> for LeaveStatus {
+    fn from_proto(value: i32) -> Result<Self, Status> {
+        match proto::LeaveStatus::try_from(value) {
+            Ok(proto::LeaveStatus::Pending) => Ok(Self::Pending),
+            Ok(proto::LeaveStatus::Approved) => Ok(Self::Approved),
+            Ok(proto::LeaveStatus::Rejected) => Ok(Self::Rejected),
+            Ok(proto::LeaveStatus::Cancelled) => Ok(Self::Cancelled),
+            _ => Err(Status::invalid_argument(format!("Invalid LeaveStatus: {}", value))),
+        }
+    }
+}
+
+impl FromProto<proto::LeaveRequest> for LeaveRequest {
+    fn from_proto(value: proto::LeaveRequest) -> Result<Self, Status> {
+        Ok(Self {
+            id: FromProto::from_proto(required(value.id, "id")?)?,
+            employee_id: FromProto::from_proto(
+                required(value.employee_id, "employee_id")?,
+            )?,
+            leave_type: FromProto::from_proto(value.leave_type)?,
+            start_date: FromProto::from_proto(value.start_date)?,
+            end_date: FromProto::from_proto(value.end_date)?,
+            reason: FromProto::from_proto(value.reason)?,
+            status: FromProto::from_proto(value.status)?,
+            approver_id: FromProto::from_proto(value.approver_id)?,
+            approval_comment: FromProto::from_proto(value.approval_comment)?,
+            created_at: FromProto::from_proto(
+                required(value.created_at, "created_at")?,
+            )?,
+            updated_at: FromProto::from_proto(value.updated_at)?,
+        })
+    }
+}
+
+impl FromProto<proto::RequestLeaveRequest> for RequestLeave {
+    fn from_proto(value: proto::RequestLeaveRequest) -> Result<Self, Status> {
+        Ok(Self {
+            employee_id: FromProto::from_proto(
+                required(value.employee_id, "employee_id")?,
+            )?,
+            leave_type: FromProto::from_proto(value.leave_type)?,
+            start_date: FromProto::from_proto(value.start_date)?,
+            end_date: FromProto::from_proto(value.end_date)?,
+            reason: FromProto::from_proto(value.reason)?,
+        })
+    }
+}
+
+impl FromProto<proto::ApproveLeaveRequest> for ApproveLeave {
+    fn from_proto(value: proto::ApproveLeaveRequest) -> Result<Self, Status> {
+        Ok(Self {
+            leave_request_id: FromProto::from_proto(
+                required(value.leave_request_id, "leave_request_id")?,
+            )?,
+            approver_id: FromProto::from_proto(
+                required(value.approver_id, "approver_id")?,
+            )?,
+            comment: FromProto::from_proto(value.comment)?,
+        })
+    }
+}
+
+impl FromProto<proto::RejectLeaveRequest> for RejectLeave {
+    fn from_proto(value: proto::RejectLeaveRequest) -> Result<Self, Status> {
+        Ok(Self {
+            leave_request_id: FromProto::from_proto(
+                required(value.leave_request_id, "leave_request_id")?,
+            )?,
+            approver_id: FromProto::from_proto(
+                required(value.approver_id, "approver_id")?,
+            )?,
+            reason: FromProto::from_proto(value.reason)?,
+        })
+    }
+}
+
+impl FromProto<proto::CancelLeaveRequest> for CancelLeave {
+    fn from_proto(value: proto::CancelLeaveRequest) -> Result<Self, Status> {
+        Ok(Self {
+            leave_request_id: FromProto::from_proto(
+                required(value.leave_request_id, "leave_request_id")?,
+            )?,
+            employee_id: FromProto::from_proto(
+                required(value.employee_id, "employee_id")?,
+            )?,
+        })
+    }
+}
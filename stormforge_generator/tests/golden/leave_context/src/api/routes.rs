@@ -0,0 +1,372 @@
+use axum::{
+    extract::State, http::{header, StatusCode},
+    response::{IntoResponse, Json},
+    routing::{get, post},
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::{ToSchema, OpenApi};
+use crate::domain::commands::*;
+use crate::domain::handlers::EventSourcedHandler;
+use crate::infrastructure::event_store::EventStore;
+
+/// API documentation
+#[derive(OpenApi)]
+#[openapi(
+    paths(),
+    components(schemas(ApiError, ApiResponseString)),
+    tags((name = "Leave", description = "Leave API endpoints"))
+)]
+pub struct ApiDoc;
+
+/// AsyncAPI document of the events published and consumed by this service
+pub const ASYNCAPI: &str = include_str!("../../asyncapi.json");
+
+/// OpenAPI document of the HTTP API
+///
+/// The component schemas of the AsyncAPI document are merged in, so
+/// both documents describe payloads with the same schemas.
+pub fn openapi() -> serde_json::Value {
+    let mut openapi = serde_json::to_value(ApiDoc::openapi())
+        .expect("OpenAPI document serializes to JSON");
+    let asyncapi: serde_json::Value = serde_json::from_str(ASYNCAPI)
+        .expect("AsyncAPI document is valid JSON");
+    if let (Some(schemas), Some(shared)) = (
+        openapi
+            .pointer_mut("/components/schemas")
+            .and_then(|schemas| schemas.as_object_mut()),
+        asyncapi.pointer("/components/schemas").and_then(|schemas| schemas.as_object()),
+    ) {
+        for (name, schema) in shared {
+            schemas.entry(name.clone()).or_insert_with(|| schema.clone());
+        }
+    }
+    openapi
+}
+
+/// Serve the AsyncAPI document
+pub async fn asyncapi() -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "application/json")], ASYNCAPI)
+}
+
+/// Standard API error response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiError {
+    pub code: String,
+    pub message: String,
+}
+
+impl ApiError {
+    /// HTTP status of the error code
+    pub fn status_code(&self) -> StatusCode {
+        match self.code.as_str() {
+            "VALIDATION_ERROR" => StatusCode::BAD_REQUEST,
+            "NOT_FOUND" => StatusCode::NOT_FOUND,
+            "PRECONDITION_FAILED" => StatusCode::CONFLICT,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<CommandError> for ApiError {
+    fn from(error: CommandError) -> Self {
+        let code = match &error {
+            CommandError::ValidationError(_) => "VALIDATION_ERROR",
+            CommandError::PreconditionFailed(_) => "PRECONDITION_FAILED",
+            CommandError::AggregateNotFound(_) => "NOT_FOUND",
+            CommandError::InternalError(_) => "INTERNAL_ERROR",
+        };
+        ApiError {
+            code: code.to_string(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Standard API success response (String variant)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseString {
+    pub data: String,
+}
+
+/// State shared by the HTTP, GraphQL and gRPC APIs
+#[derive(Clone)]
+pub struct AppState {
+    /// Event store the commands append to
+    pub event_store: Arc<dyn EventStore>,
+    /// Handler of the commands
+    pub commands: Arc<dyn CommandHandler>,
+}
+
+impl AppState {
+    /// State handling commands with the event-sourced handler over `event_store`
+    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+        Self {
+            commands: Arc::new(EventSourcedHandler::new(event_store.clone())),
+            event_store,
+        }
+    }
+}
+
+/// Create the API router
+pub fn create_router(state: AppState) -> Router {
+    Router::new()
+        .route("/request-leave", post(request_leave))
+        .route("/approve-leave", post(approve_leave))
+        .route("/reject-leave", post(reject_leave))
+        .route("/cancel-leave", post(cancel_leave))
+        .route("/get-leave-request", get(get_leave_request))
+        .route("/get-employee-leaves", get(get_employee_leaves))
+        .route("/get-pending-approvals", get(get_pending_approvals))
+        .route("/get-leave-balance", get(get_leave_balance))
+        .with_state(state)
+}
+
+/// Submit a leave request
+#[utoipa::path(
+    post,
+    path = "/request-leave",
+    request_body = RequestLeave,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn request_leave(
+    State(state): State<AppState>,
+    Json(payload): Json<RequestLeave>,
+) -> impl IntoResponse {
+    match execute_request_leave(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `RequestLeave` command, shared by the HTTP and gRPC APIs
+pub async fn execute_request_leave(
+    state: &AppState,
+    command: RequestLeave,
+) -> Result<String, ApiError> {
+    state.commands.handle_request_leave(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Approve a leave request
+#[utoipa::path(
+    post,
+    path = "/approve-leave",
+    request_body = ApproveLeave,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn approve_leave(
+    State(state): State<AppState>,
+    Json(payload): Json<ApproveLeave>,
+) -> impl IntoResponse {
+    match execute_approve_leave(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `ApproveLeave` command, shared by the HTTP and gRPC APIs
+pub async fn execute_approve_leave(
+    state: &AppState,
+    command: ApproveLeave,
+) -> Result<String, ApiError> {
+    state.commands.handle_approve_leave(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Reject a leave request
+#[utoipa::path(
+    post,
+    path = "/reject-leave",
+    request_body = RejectLeave,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn reject_leave(
+    State(state): State<AppState>,
+    Json(payload): Json<RejectLeave>,
+) -> impl IntoResponse {
+    match execute_reject_leave(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `RejectLeave` command, shared by the HTTP and gRPC APIs
+pub async fn execute_reject_leave(
+    state: &AppState,
+    command: RejectLeave,
+) -> Result<String, ApiError> {
+    state.commands.handle_reject_leave(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Cancel a leave request
+#[utoipa::path(
+    post,
+    path = "/cancel-leave",
+    request_body = CancelLeave,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn cancel_leave(
+    State(state): State<AppState>,
+    Json(payload): Json<CancelLeave>,
+) -> impl IntoResponse {
+    match execute_cancel_leave(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `CancelLeave` command, shared by the HTTP and gRPC APIs
+pub async fn execute_cancel_leave(
+    state: &AppState,
+    command: CancelLeave,
+) -> Result<String, ApiError> {
+    state.commands.handle_cancel_leave(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Get leave request by ID
+#[utoipa::path(
+    get,
+    path = "/get-leave-request",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn get_leave_request() -> impl IntoResponse {
+    match execute_get_leave_request().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `GetLeaveRequest` query, shared by the HTTP and gRPC APIs
+pub async fn execute_get_leave_request() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
+
+/// Get all leave requests for an employee
+#[utoipa::path(
+    get,
+    path = "/get-employee-leaves",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn get_employee_leaves() -> impl IntoResponse {
+    match execute_get_employee_leaves().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `GetEmployeeLeaves` query, shared by the HTTP and gRPC APIs
+pub async fn execute_get_employee_leaves() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
+
+/// Get pending leave requests for approval
+#[utoipa::path(
+    get,
+    path = "/get-pending-approvals",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn get_pending_approvals() -> impl IntoResponse {
+    match execute_get_pending_approvals().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `GetPendingApprovals` query, shared by the HTTP and gRPC APIs
+pub async fn execute_get_pending_approvals() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
+
+/// Get remaining leave balance for an employee
+#[utoipa::path(
+    get,
+    path = "/get-leave-balance",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn get_leave_balance() -> impl IntoResponse {
+    match execute_get_leave_balance().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `GetLeaveBalance` query, shared by the HTTP and gRPC APIs
+pub async fn execute_get_leave_balance() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+use crate::domain::entities::*;
+use crate::domain::events::*;
+use crate::domain::expressions;
+
+pub type CommandResult<T> = Result<T, CommandError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CommandError {
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+    #[error("Aggregate not found: {0}")]
+    AggregateNotFound(String),
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+/// Submit a leave request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "RequestLeaveInput")
+)]
+pub struct RequestLeave {
+    pub employee_id: EmployeeId,
+    pub leave_type: LeaveType,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: chrono::NaiveDate,
+    pub reason: Option<String>,
+}
+
+impl RequestLeave {
+    /// Validate the command
+    ///
+    /// Rules:
+    /// - `endDate >= startDate`: End date must be on or after start date
+    /// - `startDate >= today()`: Leave cannot start in the past
+    pub fn validate(&self) -> CommandResult<()> {
+        let command = serde_json::to_value(self)
+            .map_err(|e| CommandError::InternalError(e.to_string()))?;
+        let scope = &command;
+        if !expressions::ge(
+            &expressions::get(scope, "end_date"),
+            &expressions::get(scope, "start_date"),
+        ) {
+            return Err(
+                CommandError::ValidationError(
+                    "End date must be on or after start date".to_string(),
+                ),
+            );
+        }
+        if !expressions::ge(
+            &expressions::get(scope, "start_date"),
+            &expressions::today(),
+        ) {
+            return Err(
+                CommandError::ValidationError(
+                    "Leave cannot start in the past".to_string(),
+                ),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Approve a leave request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "ApproveLeaveInput")
+)]
+pub struct ApproveLeave {
+    pub leave_request_id: LeaveRequestId,
+    pub approver_id: EmployeeId,
+    pub comment: Option<String>,
+}
+
+impl ApproveLeave {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Reject a leave request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "RejectLeaveInput")
+)]
+pub struct RejectLeave {
+    pub leave_request_id: LeaveRequestId,
+    pub approver_id: EmployeeId,
+    pub reason: String,
+}
+
+impl RejectLeave {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Cancel a leave request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "CancelLeaveInput")
+)]
+pub struct CancelLeave {
+    pub leave_request_id: LeaveRequestId,
+    pub employee_id: EmployeeId,
+}
+
+impl CancelLeave {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Command handler trait for processing commands
+#[async_trait]
+pub trait CommandHandler: Send + Sync {
+    /// Submit a leave request
+    async fn handle_request_leave(
+        &self,
+        command: RequestLeave,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Approve a leave request
+    async fn handle_approve_leave(
+        &self,
+        command: ApproveLeave,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Reject a leave request
+    async fn handle_reject_leave(
+        &self,
+        command: RejectLeave,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Cancel a leave request
+    async fn handle_cancel_leave(
+        &self,
+        command: CancelLeave,
+    ) -> CommandResult<Vec<DomainEvent>>;
+}
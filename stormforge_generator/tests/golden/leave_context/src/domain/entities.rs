@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "sqlx")]
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct LeaveRequestId(pub String);
+
+impl From<String> for LeaveRequestId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<LeaveRequestId> for String {
+    fn from(id: LeaveRequestId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(LeaveRequestId);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct EmployeeId(pub String);
+
+impl From<String> for EmployeeId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<EmployeeId> for String {
+    fn from(id: EmployeeId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(EmployeeId);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum LeaveType {
+    /// Annual leave
+    Annual,
+    /// Sick leave
+    Sick,
+    /// Personal leave
+    Personal,
+    /// Maternity leave
+    Maternity,
+    /// Paternity leave
+    Paternity,
+    /// Bereavement leave
+    Bereavement,
+    /// Unpaid leave
+    Unpaid,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum LeaveStatus {
+    /// Awaiting approval
+    Pending,
+    /// Leave approved
+    Approved,
+    /// Leave rejected
+    Rejected,
+    /// Leave cancelled by employee
+    Cancelled,
+}
+
+/// Leave request aggregate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(FromRow))]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::SimpleObject, async_graphql::InputObject),
+    graphql(input_name = "LeaveRequestInput")
+)]
+pub struct LeaveRequest {
+    pub id: LeaveRequestId,
+    pub employee_id: EmployeeId,
+    pub leave_type: LeaveType,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: chrono::NaiveDate,
+    pub reason: Option<String>,
+    pub status: LeaveStatus,
+    pub approver_id: Option<EmployeeId>,
+    pub approval_comment: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -0,0 +1,189 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::entities::*;
+
+/// Domain event enum containing all events in this bounded context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Union))]
+#[serde(tag = "type")]
+pub enum DomainEvent {
+    LeaveRequested(LeaveRequested),
+    LeaveApproved(LeaveApproved),
+    LeaveRejected(LeaveRejected),
+    LeaveCancelled(LeaveCancelled),
+}
+
+/// A leave request has been submitted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct LeaveRequested {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub leave_request_id: LeaveRequestId,
+    pub employee_id: EmployeeId,
+    pub leave_type: LeaveType,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: chrono::NaiveDate,
+    pub reason: String,
+    pub requested_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl LeaveRequested {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        leave_request_id: LeaveRequestId,
+        employee_id: EmployeeId,
+        leave_type: LeaveType,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        reason: String,
+        requested_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            leave_request_id,
+            employee_id,
+            leave_type,
+            start_date,
+            end_date,
+            reason,
+            requested_at,
+        }
+    }
+}
+
+/// Leave request has been approved
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct LeaveApproved {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub leave_request_id: LeaveRequestId,
+    pub employee_id: EmployeeId,
+    pub approver_id: EmployeeId,
+    pub comment: String,
+    pub approved_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl LeaveApproved {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        leave_request_id: LeaveRequestId,
+        employee_id: EmployeeId,
+        approver_id: EmployeeId,
+        comment: String,
+        approved_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            leave_request_id,
+            employee_id,
+            approver_id,
+            comment,
+            approved_at,
+        }
+    }
+}
+
+/// Leave request has been rejected
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct LeaveRejected {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub leave_request_id: LeaveRequestId,
+    pub employee_id: EmployeeId,
+    pub approver_id: EmployeeId,
+    pub reason: String,
+    pub rejected_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl LeaveRejected {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        leave_request_id: LeaveRequestId,
+        employee_id: EmployeeId,
+        approver_id: EmployeeId,
+        reason: String,
+        rejected_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            leave_request_id,
+            employee_id,
+            approver_id,
+            reason,
+            rejected_at,
+        }
+    }
+}
+
+/// Leave request has been cancelled
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct LeaveCancelled {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub leave_request_id: LeaveRequestId,
+    pub employee_id: EmployeeId,
+    pub cancelled_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl LeaveCancelled {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        leave_request_id: LeaveRequestId,
+        employee_id: EmployeeId,
+        cancelled_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            leave_request_id,
+            employee_id,
+            cancelled_at,
+        }
+    }
+}
+
+/// Event store metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventMetadata {
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub aggregate_type: String,
+    pub sequence_number: i64,
+    pub occurred_at: DateTime<Utc>,
+    pub stored_at: DateTime<Utc>,
+    pub correlation_id: Option<Uuid>,
+    pub causation_id: Option<Uuid>,
+}
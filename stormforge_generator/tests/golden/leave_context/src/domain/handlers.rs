@@ -0,0 +1,317 @@
+//! Command handler backed by the event store
+//!
+//! A command is validated, then checked against the state of its
+//! aggregate folded from the stored events. A command carrying the
+//! aggregate's identifier acts on that aggregate, any other command
+//! creates one. Each event takes a field from the `computed` expression
+//! of the event property, else from the command field, the aggregate ID
+//! or the aggregate state of the same name; `DateTime` fields the
+//! command does not give are the time it is handled. The events must
+//! keep the aggregate's invariants, and are appended with their outbox
+//! entries at the version the state was loaded at, so a concurrent
+//! command on the same aggregate fails rather than being lost.
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+use crate::domain::commands::*;
+use crate::domain::events::DomainEvent;
+use crate::domain::{expressions, state};
+use crate::infrastructure::event_store::EventStore;
+
+/// Command handler appending the events of commands to an event store
+pub struct EventSourcedHandler {
+    event_store: Arc<dyn EventStore>,
+}
+
+impl EventSourcedHandler {
+    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+        Self { event_store }
+    }
+    /// State of an aggregate folded from its events, and its version
+    pub async fn load(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+    ) -> CommandResult<(Value, i64)> {
+        let events = self
+            .event_store
+            .load_events(aggregate_id, aggregate_type)
+            .await
+            .map_err(internal)?
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(internal)?;
+        let version = i64::try_from(events.len()).map_err(internal)?;
+        let state = state::load(aggregate_type, &events).unwrap_or(Value::Null);
+        Ok((state, version))
+    }
+    /// Append the events of a command at the version its aggregate was loaded at
+    async fn append(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+        events: Vec<DomainEvent>,
+        version: i64,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        self.event_store
+            .append_events(aggregate_id, aggregate_type, events.clone(), Some(version))
+            .await
+            .map_err(internal)?;
+        Ok(events)
+    }
+}
+
+fn internal(error: impl std::fmt::Display) -> CommandError {
+    CommandError::InternalError(error.to_string())
+}
+
+/// Aggregate ID held by a command field
+fn id(value: &Value) -> String {
+    match value {
+        Value::String(id) => id.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Event of a type built from its payload fields
+fn event(
+    event_type: &str,
+    aggregate_id: &str,
+    mut payload: Value,
+) -> CommandResult<DomainEvent> {
+    if let Some(fields) = payload.as_object_mut() {
+        fields.insert("type".to_string(), Value::from(event_type));
+        fields.insert("event_id".to_string(), Value::from(Uuid::new_v4().to_string()));
+        fields.insert("occurred_at".to_string(), expressions::now());
+        fields.insert("aggregate_id".to_string(), Value::from(aggregate_id));
+    }
+    serde_json::from_value(payload)
+        .map_err(|e| {
+            CommandError::InternalError(format!("Cannot build {}: {}", event_type, e))
+        })
+}
+
+/// Fold events into the state of an aggregate
+fn apply_events(
+    state: &mut Value,
+    events: &[DomainEvent],
+    apply: fn(&mut Value, &Value),
+) -> CommandResult<()> {
+    for event in events {
+        apply(state, &serde_json::to_value(event).map_err(internal)?);
+    }
+    Ok(())
+}
+
+/// Invariants of a `LeaveRequest`
+///
+/// - `endDate >= startDate`: End date must be on or after start date
+/// - `daysBetween(startDate, endDate) <= 30`: Leave cannot exceed 30 days
+fn check_leave_request(scope: &Value) -> CommandResult<()> {
+    if !expressions::ge(
+        &expressions::get(scope, "end_date"),
+        &expressions::get(scope, "start_date"),
+    ) {
+        return Err(
+            CommandError::ValidationError(
+                "End date must be on or after start date".to_string(),
+            ),
+        );
+    }
+    if !expressions::le(
+        &expressions::days_between(
+            &expressions::get(scope, "start_date"),
+            &expressions::get(scope, "end_date"),
+        ),
+        &serde_json::Value::from(30),
+    ) {
+        return Err(
+            CommandError::ValidationError("Leave cannot exceed 30 days".to_string()),
+        );
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl CommandHandler for EventSourcedHandler {
+    async fn handle_request_leave(
+        &self,
+        command: RequestLeave,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = Uuid::new_v4().to_string();
+        let (mut state, version) = (Value::Null, 0);
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "LeaveRequested",
+                &aggregate_id,
+                expressions::object([
+                    ("leave_request_id", Value::from(aggregate_id.as_str())),
+                    ("employee_id", expressions::get(&command, "employee_id")),
+                    ("leave_type", expressions::get(&command, "leave_type")),
+                    ("start_date", expressions::get(&command, "start_date")),
+                    ("end_date", expressions::get(&command, "end_date")),
+                    ("reason", expressions::get(&command, "reason")),
+                    ("requested_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_leave_request)?;
+        check_leave_request(&state)?;
+        self.append("LeaveRequest", &aggregate_id, events, version).await
+    }
+    async fn handle_approve_leave(
+        &self,
+        command: ApproveLeave,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "leave_request_id"));
+        let (mut state, version) = self.load("LeaveRequest", &aggregate_id).await?;
+        if version == 0 {
+            return Err(CommandError::AggregateNotFound(aggregate_id));
+        }
+        let scope = &expressions::with(&command, "leave_request", &state);
+        if !expressions::eq(
+            &expressions::get(&expressions::get(scope, "leave_request"), "status"),
+            &serde_json::Value::from("Pending"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Can only approve pending leave requests".to_string(),
+                ),
+            );
+        }
+        if !!expressions::eq(
+            &expressions::get(scope, "approver_id"),
+            &expressions::get(&expressions::get(scope, "leave_request"), "employee_id"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Cannot approve own leave request".to_string(),
+                ),
+            );
+        }
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "LeaveApproved",
+                &aggregate_id,
+                expressions::object([
+                    ("leave_request_id", expressions::get(&command, "leave_request_id")),
+                    ("employee_id", expressions::get(&state, "employee_id")),
+                    ("approver_id", expressions::get(&command, "approver_id")),
+                    ("comment", expressions::get(&command, "comment")),
+                    ("approved_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_leave_request)?;
+        check_leave_request(&state)?;
+        self.append("LeaveRequest", &aggregate_id, events, version).await
+    }
+    async fn handle_reject_leave(
+        &self,
+        command: RejectLeave,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "leave_request_id"));
+        let (mut state, version) = self.load("LeaveRequest", &aggregate_id).await?;
+        if version == 0 {
+            return Err(CommandError::AggregateNotFound(aggregate_id));
+        }
+        let scope = &expressions::with(&command, "leave_request", &state);
+        if !expressions::eq(
+            &expressions::get(&expressions::get(scope, "leave_request"), "status"),
+            &serde_json::Value::from("Pending"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Can only reject pending leave requests".to_string(),
+                ),
+            );
+        }
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "LeaveRejected",
+                &aggregate_id,
+                expressions::object([
+                    ("leave_request_id", expressions::get(&command, "leave_request_id")),
+                    ("employee_id", expressions::get(&state, "employee_id")),
+                    ("approver_id", expressions::get(&command, "approver_id")),
+                    ("reason", expressions::get(&command, "reason")),
+                    ("rejected_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_leave_request)?;
+        check_leave_request(&state)?;
+        self.append("LeaveRequest", &aggregate_id, events, version).await
+    }
+    async fn handle_cancel_leave(
+        &self,
+        command: CancelLeave,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "leave_request_id"));
+        let (mut state, version) = self.load("LeaveRequest", &aggregate_id).await?;
+        if version == 0 {
+            return Err(CommandError::AggregateNotFound(aggregate_id));
+        }
+        let scope = &expressions::with(&command, "leave_request", &state);
+        if !expressions::contains(
+            &serde_json::Value::Array(
+                vec![
+                    serde_json::Value::from("Pending"),
+                    serde_json::Value::from("Approved"),
+                ],
+            ),
+            &expressions::get(&expressions::get(scope, "leave_request"), "status"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Can only cancel pending or approved leave requests".to_string(),
+                ),
+            );
+        }
+        if !expressions::eq(
+            &expressions::get(&expressions::get(scope, "leave_request"), "employee_id"),
+            &expressions::get(scope, "employee_id"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Only the employee can cancel their own leave".to_string(),
+                ),
+            );
+        }
+        if !expressions::gt(
+            &expressions::get(&expressions::get(scope, "leave_request"), "start_date"),
+            &expressions::today(),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Cannot cancel leave that has already started".to_string(),
+                ),
+            );
+        }
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "LeaveCancelled",
+                &aggregate_id,
+                expressions::object([
+                    ("leave_request_id", expressions::get(&command, "leave_request_id")),
+                    ("employee_id", expressions::get(&command, "employee_id")),
+                    ("cancelled_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_leave_request)?;
+        check_leave_request(&state)?;
+        self.append("LeaveRequest", &aggregate_id, events, version).await
+    }
+}
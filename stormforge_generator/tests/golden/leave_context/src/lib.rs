@@ -0,0 +1,4 @@
+pub mod api;
+pub mod domain;
+pub mod infrastructure;
+pub mod repository;
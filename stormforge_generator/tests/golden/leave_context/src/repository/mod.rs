@@ -0,0 +1,51 @@
+//! Repository layer for data persistence
+//!
+//! This module provides repository implementations for aggregates.
+
+use async_trait::async_trait;
+use anyhow::Result;
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+
+/// Generic repository trait for aggregates
+#[async_trait]
+pub trait Repository<T>: Send + Sync {
+    /// Find an aggregate by ID
+    async fn find_by_id(&self, id: &str) -> Result<Option<T>>;
+    /// Save an aggregate
+    async fn save(&self, aggregate: &T) -> Result<()>;
+    /// Delete an aggregate
+    async fn delete(&self, id: &str) -> Result<()>;
+}
+
+/// In-memory repository implementation (for development/testing)
+pub struct InMemoryRepository<T> {
+    aggregates: RwLock<HashMap<String, T>>,
+    /// ID of an aggregate
+    id: fn(&T) -> String,
+}
+
+impl<T> InMemoryRepository<T> {
+    pub fn new(id: fn(&T) -> String) -> Self {
+        Self {
+            aggregates: RwLock::new(HashMap::new()),
+            id,
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> Repository<T> for InMemoryRepository<T> {
+    async fn find_by_id(&self, id: &str) -> Result<Option<T>> {
+        Ok(self.aggregates.read().await.get(id).cloned())
+    }
+    async fn save(&self, aggregate: &T) -> Result<()> {
+        let id = (self.id)(aggregate);
+        self.aggregates.write().await.insert(id, aggregate.clone());
+        Ok(())
+    }
+    async fn delete(&self, id: &str) -> Result<()> {
+        self.aggregates.write().await.remove(id);
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+//! Command validation fuzzed with payloads outside the validation rules
+
+mod support;
+
+use proptest::prelude::*;
+use support::strategies::{self, check};
+use ::leave::domain::commands::*;
+
+/// `RequestLeave` commands ignoring the validation rules
+fn any_request_leave() -> BoxedStrategy<RequestLeave> {
+    (
+        strategies::employee_id(),
+        strategies::leave_type(),
+        (719_163i32..=767_009)
+            .prop_map(|days| {
+                chrono::NaiveDate::from_num_days_from_ce_opt(days).unwrap()
+            }),
+        (719_163i32..=767_009)
+            .prop_map(|days| {
+                chrono::NaiveDate::from_num_days_from_ce_opt(days).unwrap()
+            }),
+        proptest::option::of(proptest::string::string_regex("\\PC{0,32}").unwrap()),
+    )
+        .prop_map(|(employee_id, leave_type, start_date, end_date, reason)| RequestLeave {
+            employee_id,
+            leave_type,
+            start_date,
+            end_date,
+            reason,
+        })
+        .boxed()
+}
+
+/// `ApproveLeave` commands ignoring the validation rules
+fn any_approve_leave() -> BoxedStrategy<ApproveLeave> {
+    (
+        strategies::leave_request_id(),
+        strategies::employee_id(),
+        proptest::option::of(proptest::string::string_regex("\\PC{0,32}").unwrap()),
+    )
+        .prop_map(|(leave_request_id, approver_id, comment)| ApproveLeave {
+            leave_request_id,
+            approver_id,
+            comment,
+        })
+        .boxed()
+}
+
+/// `RejectLeave` commands ignoring the validation rules
+fn any_reject_leave() -> BoxedStrategy<RejectLeave> {
+    (
+        strategies::leave_request_id(),
+        strategies::employee_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+    )
+        .prop_map(|(leave_request_id, approver_id, reason)| RejectLeave {
+            leave_request_id,
+            approver_id,
+            reason,
+        })
+        .boxed()
+}
+
+/// `CancelLeave` commands ignoring the validation rules
+fn any_cancel_leave() -> BoxedStrategy<CancelLeave> {
+    (strategies::leave_request_id(), strategies::employee_id())
+        .prop_map(|(leave_request_id, employee_id)| CancelLeave {
+            leave_request_id,
+            employee_id,
+        })
+        .boxed()
+}
+
+#[test]
+fn request_leave_validation_never_panics() {
+    check(
+        any_request_leave(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn approve_leave_validation_never_panics() {
+    check(
+        any_approve_leave(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn reject_leave_validation_never_panics() {
+    check(
+        any_reject_leave(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn cancel_leave_validation_never_panics() {
+    check(
+        any_cancel_leave(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,81 @@
+//! Starter tests of the `LeaveRequest` aggregate derived from the IR
+//!
+//! Left out, as the IR does not tell how to set them up:
+//!
+//! - `approve_leave_produces_leave_approved`
+//! - `approve_leave_fails_precondition_2`
+//! - `cancel_leave_produces_leave_cancelled`
+//! - `cancel_leave_fails_precondition_2`
+//! - `cancel_leave_fails_precondition_3`
+//! - `max_leave_duration_is_enforced`
+
+mod support;
+
+use ::leave::domain::commands::*;
+use support::leave_request::*;
+
+/// `RequestLeave` produces `LeaveRequested`
+#[tokio::test]
+async fn request_leave_produces_leave_requested() {
+    scenario().given(vec![]).await.when(request_leave()).await.then(&["LeaveRequested"]);
+}
+
+/// `ApproveLeave` is rejected unless `leaveRequest.status == PENDING`
+#[tokio::test]
+async fn approve_leave_fails_precondition_1() {
+    scenario()
+        .given(vec![leave_requested(), leave_approved()])
+        .await
+        .when(approve_leave())
+        .await
+        .then_error("Can only approve pending leave requests");
+}
+
+/// `RejectLeave` produces `LeaveRejected`
+#[tokio::test]
+async fn reject_leave_produces_leave_rejected() {
+    scenario()
+        .given(vec![leave_requested()])
+        .await
+        .when(reject_leave())
+        .await
+        .then(&["LeaveRejected"]);
+}
+
+/// `RejectLeave` is rejected unless `leaveRequest.status == PENDING`
+#[tokio::test]
+async fn reject_leave_fails_precondition() {
+    scenario()
+        .given(vec![leave_requested(), leave_approved()])
+        .await
+        .when(reject_leave())
+        .await
+        .then_error("Can only reject pending leave requests");
+}
+
+/// `CancelLeave` is rejected unless `leaveRequest.status in [PENDING, APPROVED]`
+#[tokio::test]
+async fn cancel_leave_fails_precondition_1() {
+    scenario()
+        .given(vec![leave_requested(), leave_rejected()])
+        .await
+        .when(cancel_leave())
+        .await
+        .then_error("Can only cancel pending or approved leave requests");
+}
+
+/// Commands cannot break `EndDateAfterStartDate`: End date must be on or after start date
+///
+/// `endDate >= startDate`
+#[tokio::test]
+async fn end_date_after_start_date_is_enforced() {
+    scenario()
+        .given(vec![])
+        .await
+        .when(RequestLeave {
+            end_date: "2098-12-31".parse().unwrap(),
+            ..request_leave()
+        })
+        .await
+        .then_rejected();
+}
@@ -0,0 +1,98 @@
+//! Serde round trips of the domain types with generated values
+
+mod support;
+
+use proptest::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use support::strategies::{self, check};
+use ::leave::domain::events::DomainEvent;
+
+/// Decode a value's JSON and expect the result to encode to the same JSON
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> Result<(), TestCaseError> {
+    let json = serde_json::to_value(value)
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    let decoded: T = serde_json::from_value(json.clone())
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    let encoded = serde_json::to_value(&decoded)
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    prop_assert_eq!(encoded, json);
+    Ok(())
+}
+
+#[test]
+fn leave_request_id_round_trips() {
+    check(strategies::leave_request_id(), |value| round_trip(&value));
+}
+
+#[test]
+fn employee_id_round_trips() {
+    check(strategies::employee_id(), |value| round_trip(&value));
+}
+
+#[test]
+fn leave_type_round_trips() {
+    check(strategies::leave_type(), |value| round_trip(&value));
+}
+
+#[test]
+fn leave_status_round_trips() {
+    check(strategies::leave_status(), |value| round_trip(&value));
+}
+
+#[test]
+fn leave_request_round_trips() {
+    check(strategies::leave_request(), |value| round_trip(&value));
+}
+
+#[test]
+fn request_leave_round_trips() {
+    check(strategies::request_leave(), |value| round_trip(&value));
+}
+
+#[test]
+fn approve_leave_round_trips() {
+    check(strategies::approve_leave(), |value| round_trip(&value));
+}
+
+#[test]
+fn reject_leave_round_trips() {
+    check(strategies::reject_leave(), |value| round_trip(&value));
+}
+
+#[test]
+fn cancel_leave_round_trips() {
+    check(strategies::cancel_leave(), |value| round_trip(&value));
+}
+
+#[test]
+fn leave_requested_round_trips() {
+    check(
+        strategies::leave_requested(),
+        |event| { round_trip(&DomainEvent::LeaveRequested(event)) },
+    );
+}
+
+#[test]
+fn leave_approved_round_trips() {
+    check(
+        strategies::leave_approved(),
+        |event| { round_trip(&DomainEvent::LeaveApproved(event)) },
+    );
+}
+
+#[test]
+fn leave_rejected_round_trips() {
+    check(
+        strategies::leave_rejected(),
+        |event| { round_trip(&DomainEvent::LeaveRejected(event)) },
+    );
+}
+
+#[test]
+fn leave_cancelled_round_trips() {
+    check(
+        strategies::leave_cancelled(),
+        |event| { round_trip(&DomainEvent::LeaveCancelled(event)) },
+    );
+}
@@ -0,0 +1,109 @@
+//! Scenario and samples of the `LeaveRequest` aggregate
+
+use super::Scenario;
+use ::leave::domain::commands::*;
+use ::leave::domain::entities::*;
+use ::leave::domain::events::*;
+
+pub const AGGREGATE_TYPE: &str = "LeaveRequest";
+
+/// ID of the aggregate in scenarios and sample events
+pub const AGGREGATE_ID: &str = "leave-request-1";
+
+/// Scenario of the sample `LeaveRequest`
+pub fn scenario() -> Scenario {
+    Scenario::new(AGGREGATE_TYPE, AGGREGATE_ID)
+}
+
+/// Sample `RequestLeave` command
+pub fn request_leave() -> RequestLeave {
+    RequestLeave {
+        employee_id: EmployeeId("employee-1".to_string()),
+        leave_type: LeaveType::Annual,
+        start_date: "2099-01-01".parse().unwrap(),
+        end_date: "2099-01-01".parse().unwrap(),
+        reason: Some("sample".to_string()),
+    }
+}
+
+/// Sample `ApproveLeave` command
+pub fn approve_leave() -> ApproveLeave {
+    ApproveLeave {
+        leave_request_id: LeaveRequestId("leave-request-1".to_string()),
+        approver_id: EmployeeId("employee-1".to_string()),
+        comment: Some("sample".to_string()),
+    }
+}
+
+/// Sample `RejectLeave` command
+pub fn reject_leave() -> RejectLeave {
+    RejectLeave {
+        leave_request_id: LeaveRequestId("leave-request-1".to_string()),
+        approver_id: EmployeeId("employee-1".to_string()),
+        reason: "sample".to_string(),
+    }
+}
+
+/// Sample `CancelLeave` command
+pub fn cancel_leave() -> CancelLeave {
+    CancelLeave {
+        leave_request_id: LeaveRequestId("leave-request-1".to_string()),
+        employee_id: EmployeeId("employee-1".to_string()),
+    }
+}
+
+/// Sample `LeaveRequested` event
+pub fn leave_requested() -> DomainEvent {
+    DomainEvent::LeaveRequested(
+        LeaveRequested::new(
+            AGGREGATE_ID.to_string(),
+            LeaveRequestId("leave-request-1".to_string()),
+            EmployeeId("employee-1".to_string()),
+            LeaveType::Annual,
+            "2099-01-01".parse().unwrap(),
+            "2099-01-01".parse().unwrap(),
+            "sample".to_string(),
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `LeaveApproved` event
+pub fn leave_approved() -> DomainEvent {
+    DomainEvent::LeaveApproved(
+        LeaveApproved::new(
+            AGGREGATE_ID.to_string(),
+            LeaveRequestId("leave-request-1".to_string()),
+            EmployeeId("employee-1".to_string()),
+            EmployeeId("employee-1".to_string()),
+            "sample".to_string(),
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `LeaveRejected` event
+pub fn leave_rejected() -> DomainEvent {
+    DomainEvent::LeaveRejected(
+        LeaveRejected::new(
+            AGGREGATE_ID.to_string(),
+            LeaveRequestId("leave-request-1".to_string()),
+            EmployeeId("employee-1".to_string()),
+            EmployeeId("employee-1".to_string()),
+            "sample".to_string(),
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `LeaveCancelled` event
+pub fn leave_cancelled() -> DomainEvent {
+    DomainEvent::LeaveCancelled(
+        LeaveCancelled::new(
+            AGGREGATE_ID.to_string(),
+            LeaveRequestId("leave-request-1".to_string()),
+            EmployeeId("employee-1".to_string()),
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
@@ -0,0 +1,157 @@
+//! Given/when/then harness for aggregate tests
+//!
+//! A scenario appends its given events to an in-memory event store,
+//! sends one command to the service's command handler and checks the
+//! events or the error that come back.
+#![allow(dead_code)]
+
+pub mod leave_request;
+pub mod strategies;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use ::leave::domain::commands::*;
+use ::leave::domain::events::DomainEvent;
+use ::leave::domain::handlers::EventSourcedHandler;
+use ::leave::infrastructure::event_store::{EventStore, InMemoryEventStore};
+
+/// Command handler the scenarios run against
+pub fn handler(store: Arc<dyn EventStore>) -> Arc<dyn CommandHandler> {
+    Arc::new(EventSourcedHandler::new(store))
+}
+
+/// Command a scenario can send to the handler
+#[async_trait]
+pub trait SendCommand: Send + Sized {
+    async fn send(self, handler: &dyn CommandHandler) -> CommandResult<Vec<DomainEvent>>;
+}
+
+#[async_trait]
+impl SendCommand for RequestLeave {
+    async fn send(
+        self,
+        handler: &dyn CommandHandler,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        handler.handle_request_leave(self).await
+    }
+}
+
+#[async_trait]
+impl SendCommand for ApproveLeave {
+    async fn send(
+        self,
+        handler: &dyn CommandHandler,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        handler.handle_approve_leave(self).await
+    }
+}
+
+#[async_trait]
+impl SendCommand for RejectLeave {
+    async fn send(
+        self,
+        handler: &dyn CommandHandler,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        handler.handle_reject_leave(self).await
+    }
+}
+
+#[async_trait]
+impl SendCommand for CancelLeave {
+    async fn send(
+        self,
+        handler: &dyn CommandHandler,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        handler.handle_cancel_leave(self).await
+    }
+}
+
+/// Type name of a domain event, as in its serialized `type` field
+pub fn event_type(event: &DomainEvent) -> String {
+    serde_json::to_value(event)
+        .ok()
+        .and_then(|value| value["type"].as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// One aggregate instance with its recorded events
+pub struct Scenario {
+    aggregate_type: &'static str,
+    aggregate_id: String,
+    store: Arc<InMemoryEventStore>,
+}
+
+impl Scenario {
+    pub fn new(aggregate_type: &'static str, aggregate_id: impl Into<String>) -> Self {
+        Self {
+            aggregate_type,
+            aggregate_id: aggregate_id.into(),
+            store: Arc::new(InMemoryEventStore::new()),
+        }
+    }
+    /// Events the aggregate recorded before the command
+    pub async fn given(self, events: Vec<DomainEvent>) -> Self {
+        if !events.is_empty() {
+            self.store
+                .append_events(&self.aggregate_id, self.aggregate_type, events, None)
+                .await
+                .expect("Failed to append the given events");
+        }
+        self
+    }
+    /// Send a command to the handler
+    pub async fn when(self, command: impl SendCommand) -> Outcome {
+        let handler = handler(self.store.clone());
+        Outcome {
+            result: command.send(handler.as_ref()).await,
+        }
+    }
+}
+
+/// Result of the command sent by a scenario
+pub struct Outcome {
+    result: CommandResult<Vec<DomainEvent>>,
+}
+
+impl Outcome {
+    /// Assert that the command produced events of these types, in order
+    pub fn then(self, expected: &[&str]) -> Vec<DomainEvent> {
+        match self.result {
+            Ok(events) => {
+                let types: Vec<String> = events.iter().map(event_type).collect();
+                assert_eq!(types, expected, "Unexpected events");
+                events
+            }
+            Err(error) => {
+                panic!(
+                    "Expected events {:?}, but the command failed: {}",
+                    expected,
+                    error,
+                )
+            }
+        }
+    }
+    /// Assert that the command failed with an error mentioning `message`
+    pub fn then_error(self, message: &str) -> CommandError {
+        let error = self.then_rejected();
+        assert!(
+            error.to_string().contains(message),
+            "Expected an error mentioning {:?}, got: {}",
+            message,
+            error,
+        );
+        error
+    }
+    /// Assert that the command failed
+    pub fn then_rejected(self) -> CommandError {
+        match self.result {
+            Ok(events) => {
+                panic!(
+                    "Expected the command to fail, but it produced {:?}",
+                    events.iter().map(event_type).collect::<Vec<_>>(),
+                )
+            }
+            Err(error) => error,
+        }
+    }
+}
@@ -0,0 +1,348 @@
+//! Proptest strategies for the domain types
+//!
+//! Values respect the IR's validation rules: string lengths and
+//! patterns, numeric ranges and decimal places.
+
+use proptest::prelude::*;
+use proptest::test_runner::TestRunner;
+use ::leave::domain::commands::*;
+use ::leave::domain::entities::*;
+use ::leave::domain::events::*;
+
+/// Run a property against values of a strategy
+///
+/// A failure panics with the smallest failing value proptest finds.
+pub fn check<S: Strategy>(
+    strategy: S,
+    test: impl Fn(S::Value) -> Result<(), TestCaseError>,
+) {
+    let mut runner = TestRunner::default();
+    if let Err(error) = runner.run(&strategy, test) {
+        panic!("{}", error);
+    }
+}
+
+/// `LeaveRequestId` values
+pub fn leave_request_id() -> BoxedStrategy<LeaveRequestId> {
+    proptest::string::string_regex("[a-z0-9-]{1,16}")
+        .unwrap()
+        .prop_map(LeaveRequestId)
+        .boxed()
+}
+
+/// `EmployeeId` values
+pub fn employee_id() -> BoxedStrategy<EmployeeId> {
+    proptest::string::string_regex("[a-z0-9-]{1,16}")
+        .unwrap()
+        .prop_map(EmployeeId)
+        .boxed()
+}
+
+/// `LeaveType` values
+pub fn leave_type() -> BoxedStrategy<LeaveType> {
+    proptest::sample::select(
+            Vec::from([
+                LeaveType::Annual,
+                LeaveType::Sick,
+                LeaveType::Personal,
+                LeaveType::Maternity,
+                LeaveType::Paternity,
+                LeaveType::Bereavement,
+                LeaveType::Unpaid,
+            ]),
+        )
+        .boxed()
+}
+
+/// `LeaveStatus` values
+pub fn leave_status() -> BoxedStrategy<LeaveStatus> {
+    proptest::sample::select(
+            Vec::from([
+                LeaveStatus::Pending,
+                LeaveStatus::Approved,
+                LeaveStatus::Rejected,
+                LeaveStatus::Cancelled,
+            ]),
+        )
+        .boxed()
+}
+
+/// `LeaveRequest` values
+pub fn leave_request() -> BoxedStrategy<LeaveRequest> {
+    (
+        leave_request_id(),
+        employee_id(),
+        leave_type(),
+        (719_163i32..=767_009)
+            .prop_map(|days| {
+                chrono::NaiveDate::from_num_days_from_ce_opt(days).unwrap()
+            }),
+        (719_163i32..=767_009)
+            .prop_map(|days| {
+                chrono::NaiveDate::from_num_days_from_ce_opt(days).unwrap()
+            }),
+        proptest::option::of(proptest::string::string_regex("\\PC{0,32}").unwrap()),
+        leave_status(),
+        proptest::option::of(employee_id()),
+        proptest::option::of(proptest::string::string_regex("\\PC{0,32}").unwrap()),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::option::of(
+            (0i64..4_102_444_800)
+                .prop_map(|seconds| {
+                    chrono::DateTime::from_timestamp(seconds, 0).unwrap()
+                }),
+        ),
+    )
+        .prop_map(|
+            (
+                id,
+                employee_id,
+                leave_type,
+                start_date,
+                end_date,
+                reason,
+                status,
+                approver_id,
+                approval_comment,
+                created_at,
+                updated_at,
+            )|
+        LeaveRequest {
+            id,
+            employee_id,
+            leave_type,
+            start_date,
+            end_date,
+            reason,
+            status,
+            approver_id,
+            approval_comment,
+            created_at,
+            updated_at,
+        })
+        .boxed()
+}
+
+/// `RequestLeave` values
+pub fn request_leave() -> BoxedStrategy<RequestLeave> {
+    (
+        employee_id(),
+        leave_type(),
+        (719_163i32..=767_009)
+            .prop_map(|days| {
+                chrono::NaiveDate::from_num_days_from_ce_opt(days).unwrap()
+            }),
+        (719_163i32..=767_009)
+            .prop_map(|days| {
+                chrono::NaiveDate::from_num_days_from_ce_opt(days).unwrap()
+            }),
+        proptest::option::of(proptest::string::string_regex("\\PC{0,32}").unwrap()),
+    )
+        .prop_map(|(employee_id, leave_type, start_date, end_date, reason)| RequestLeave {
+            employee_id,
+            leave_type,
+            start_date,
+            end_date,
+            reason,
+        })
+        .boxed()
+}
+
+/// `ApproveLeave` values
+pub fn approve_leave() -> BoxedStrategy<ApproveLeave> {
+    (
+        leave_request_id(),
+        employee_id(),
+        proptest::option::of(proptest::string::string_regex("\\PC{0,32}").unwrap()),
+    )
+        .prop_map(|(leave_request_id, approver_id, comment)| ApproveLeave {
+            leave_request_id,
+            approver_id,
+            comment,
+        })
+        .boxed()
+}
+
+/// `RejectLeave` values
+pub fn reject_leave() -> BoxedStrategy<RejectLeave> {
+    (
+        leave_request_id(),
+        employee_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+    )
+        .prop_map(|(leave_request_id, approver_id, reason)| RejectLeave {
+            leave_request_id,
+            approver_id,
+            reason,
+        })
+        .boxed()
+}
+
+/// `CancelLeave` values
+pub fn cancel_leave() -> BoxedStrategy<CancelLeave> {
+    (leave_request_id(), employee_id())
+        .prop_map(|(leave_request_id, employee_id)| CancelLeave {
+            leave_request_id,
+            employee_id,
+        })
+        .boxed()
+}
+
+/// `LeaveRequested` values
+pub fn leave_requested() -> BoxedStrategy<LeaveRequested> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        leave_request_id(),
+        employee_id(),
+        leave_type(),
+        (719_163i32..=767_009)
+            .prop_map(|days| {
+                chrono::NaiveDate::from_num_days_from_ce_opt(days).unwrap()
+            }),
+        (719_163i32..=767_009)
+            .prop_map(|days| {
+                chrono::NaiveDate::from_num_days_from_ce_opt(days).unwrap()
+            }),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                leave_request_id,
+                employee_id,
+                leave_type,
+                start_date,
+                end_date,
+                reason,
+                requested_at,
+            )|
+        LeaveRequested {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            leave_request_id,
+            employee_id,
+            leave_type,
+            start_date,
+            end_date,
+            reason,
+            requested_at,
+        })
+        .boxed()
+}
+
+/// `LeaveApproved` values
+pub fn leave_approved() -> BoxedStrategy<LeaveApproved> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        leave_request_id(),
+        employee_id(),
+        employee_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                leave_request_id,
+                employee_id,
+                approver_id,
+                comment,
+                approved_at,
+            )|
+        LeaveApproved {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            leave_request_id,
+            employee_id,
+            approver_id,
+            comment,
+            approved_at,
+        })
+        .boxed()
+}
+
+/// `LeaveRejected` values
+pub fn leave_rejected() -> BoxedStrategy<LeaveRejected> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        leave_request_id(),
+        employee_id(),
+        employee_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                leave_request_id,
+                employee_id,
+                approver_id,
+                reason,
+                rejected_at,
+            )|
+        LeaveRejected {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            leave_request_id,
+            employee_id,
+            approver_id,
+            reason,
+            rejected_at,
+        })
+        .boxed()
+}
+
+/// `LeaveCancelled` values
+pub fn leave_cancelled() -> BoxedStrategy<LeaveCancelled> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        leave_request_id(),
+        employee_id(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                leave_request_id,
+                employee_id,
+                cancelled_at,
+            )|
+        LeaveCancelled {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            leave_request_id,
+            employee_id,
+            cancelled_at,
+        })
+        .boxed()
+}
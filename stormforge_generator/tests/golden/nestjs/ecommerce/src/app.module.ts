@@ -0,0 +1,19 @@
+import { Module } from "@nestjs/common";
+import { RouterModule } from "@nestjs/core";
+import { OrderModule } from "./order/order.module";
+import { InventoryModule } from "./inventory/inventory.module";
+import { PaymentModule } from "./payment/payment.module";
+
+@Module({
+  imports: [
+    OrderModule,
+    InventoryModule,
+    PaymentModule,
+    RouterModule.register([
+      { path: "order", module: OrderModule },
+      { path: "inventory", module: InventoryModule },
+      { path: "payment", module: PaymentModule },
+    ]),
+  ],
+})
+export class AppModule {}
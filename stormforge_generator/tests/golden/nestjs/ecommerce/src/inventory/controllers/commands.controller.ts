@@ -0,0 +1,62 @@
+import { Body, Controller, HttpCode, HttpStatus, Post } from "@nestjs/common";
+import { CommandBus } from "@nestjs/cqrs";
+import { ApiResponse } from "../../common/api-response";
+import {
+  AddStockCommand,
+  DeductStockCommand,
+  ReleaseStockCommand,
+  ReserveStockCommand,
+} from "../commands/commands";
+import {
+  AddStockDto,
+  DeductStockDto,
+  ReleaseStockDto,
+  ReserveStockDto,
+} from "../dto/commands.dto";
+
+@Controller()
+export class CommandsController {
+  constructor(private readonly commandBus: CommandBus) {}
+
+  /** Add stock to inventory */
+  @Post("add-stock")
+  @HttpCode(HttpStatus.OK)
+  async addStock(@Body() body: AddStockDto): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new AddStockCommand(body)),
+    };
+  }
+
+  /** Reserve stock for an order */
+  @Post("reserve-stock")
+  @HttpCode(HttpStatus.OK)
+  async reserveStock(
+    @Body() body: ReserveStockDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new ReserveStockCommand(body)),
+    };
+  }
+
+  /** Release reserved stock */
+  @Post("release-stock")
+  @HttpCode(HttpStatus.OK)
+  async releaseStock(
+    @Body() body: ReleaseStockDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new ReleaseStockCommand(body)),
+    };
+  }
+
+  /** Deduct reserved stock (when order ships) */
+  @Post("deduct-stock")
+  @HttpCode(HttpStatus.OK)
+  async deductStock(
+    @Body() body: DeductStockDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new DeductStockCommand(body)),
+    };
+  }
+}
@@ -0,0 +1,111 @@
+import { IEvent } from "@nestjs/cqrs";
+import { randomUUID } from "crypto";
+import { OrderId, ProductId, ReservationId } from "./value-objects";
+
+/** Fields of an event given when raising it */
+export type EventPayload<E> = Omit<E, "type" | "event_id" | "occurred_at">;
+
+/** Stock has been added to inventory */
+export class StockAdded implements IEvent {
+  readonly type = "StockAdded" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly product_id!: ProductId;
+  readonly quantity!: number;
+  readonly new_available_quantity!: number;
+  readonly added_at!: string;
+
+  constructor(payload: EventPayload<StockAdded>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Stock has been reserved for an order */
+export class StockReserved implements IEvent {
+  readonly type = "StockReserved" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly product_id!: ProductId;
+  readonly order_id!: OrderId;
+  readonly reservation_id!: ReservationId;
+  readonly quantity!: number;
+  readonly reserved_at!: string;
+
+  constructor(payload: EventPayload<StockReserved>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Reserved stock has been released */
+export class StockReleased implements IEvent {
+  readonly type = "StockReleased" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly product_id!: ProductId;
+  readonly reservation_id!: ReservationId;
+  readonly quantity!: number;
+  readonly released_at!: string;
+
+  constructor(payload: EventPayload<StockReleased>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Stock has been deducted (shipped) */
+export class StockDeducted implements IEvent {
+  readonly type = "StockDeducted" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly product_id!: ProductId;
+  readonly reservation_id!: ReservationId;
+  readonly quantity!: number;
+  readonly deducted_at!: string;
+
+  constructor(payload: EventPayload<StockDeducted>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Stock has fallen below reorder level */
+export class LowStockAlert implements IEvent {
+  readonly type = "LowStockAlert" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly product_id!: ProductId;
+  readonly sku!: string;
+  readonly current_quantity!: number;
+  readonly reorder_level!: number;
+  readonly alerted_at!: string;
+
+  constructor(payload: EventPayload<LowStockAlert>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Domain event of this bounded context, tagged by `type` */
+export type DomainEvent =
+  | StockAdded
+  | StockReserved
+  | StockReleased
+  | StockDeducted
+  | LowStockAlert;
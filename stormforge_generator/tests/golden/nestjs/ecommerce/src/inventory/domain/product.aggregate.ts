@@ -0,0 +1,48 @@
+import { AggregateRoot } from "@nestjs/cqrs";
+import {
+  LowStockAlert,
+  StockAdded,
+  StockDeducted,
+  StockReleased,
+  StockReserved,
+} from "./events";
+import { ProductId } from "./value-objects";
+
+/**
+ * Product inventory aggregate
+ *
+ * Invariants:
+ * - Available quantity cannot be negative: `availableQuantity >= 0`
+ * - Reserved quantity cannot be negative: `reservedQuantity >= 0`
+ */
+export class Product extends AggregateRoot {
+  id!: ProductId;
+  sku!: string;
+  name!: string;
+  available_quantity!: number;
+  reserved_quantity!: number;
+  reorder_level!: number;
+  updated_at!: string;
+
+  onStockAdded(event: StockAdded): void {
+    this.id = event.aggregate_id;
+  }
+
+  onStockReserved(event: StockReserved): void {
+    this.id = event.aggregate_id;
+  }
+
+  onStockReleased(event: StockReleased): void {
+    this.id = event.aggregate_id;
+  }
+
+  onStockDeducted(event: StockDeducted): void {
+    this.id = event.aggregate_id;
+  }
+
+  onLowStockAlert(event: LowStockAlert): void {
+    this.id = event.aggregate_id;
+    this.sku = event.sku;
+    this.reorder_level = event.reorder_level;
+  }
+}
@@ -0,0 +1,5 @@
+export type ProductId = string;
+
+export type OrderId = string;
+
+export type ReservationId = string;
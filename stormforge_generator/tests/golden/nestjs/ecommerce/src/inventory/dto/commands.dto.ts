@@ -0,0 +1,37 @@
+import { IsInt, IsString, Min } from "class-validator";
+import { OrderId, ProductId, ReservationId } from "../domain/value-objects";
+
+/** Add stock to inventory */
+export class AddStockDto {
+  @IsString()
+  product_id!: ProductId;
+
+  @IsInt()
+  @Min(1)
+  quantity!: number;
+}
+
+/** Reserve stock for an order */
+export class ReserveStockDto {
+  @IsString()
+  product_id!: ProductId;
+
+  @IsString()
+  order_id!: OrderId;
+
+  @IsInt()
+  @Min(1)
+  quantity!: number;
+}
+
+/** Release reserved stock */
+export class ReleaseStockDto {
+  @IsString()
+  reservation_id!: ReservationId;
+}
+
+/** Deduct reserved stock (when order ships) */
+export class DeductStockDto {
+  @IsString()
+  reservation_id!: ReservationId;
+}
@@ -0,0 +1,27 @@
+import { IQuery } from "@nestjs/cqrs";
+import {
+  CheckAvailabilityDto,
+  GetLowStockProductsDto,
+  GetProductBySkuDto,
+  GetProductDto,
+} from "../dto/queries.dto";
+
+/** Get product by ID */
+export class GetProductQuery implements IQuery {
+  constructor(readonly params: GetProductDto) {}
+}
+
+/** Get product by SKU */
+export class GetProductBySkuQuery implements IQuery {
+  constructor(readonly params: GetProductBySkuDto) {}
+}
+
+/** Check if quantity is available */
+export class CheckAvailabilityQuery implements IQuery {
+  constructor(readonly params: CheckAvailabilityDto) {}
+}
+
+/** Get products below reorder level */
+export class GetLowStockProductsQuery implements IQuery {
+  constructor(readonly params: GetLowStockProductsDto) {}
+}
@@ -0,0 +1,13 @@
+import "reflect-metadata";
+import { NestFactory } from "@nestjs/core";
+import { AppModule } from "./app.module";
+import { validationPipe } from "./common/validation";
+
+async function bootstrap(): Promise<void> {
+  const app = await NestFactory.create(AppModule);
+  app.setGlobalPrefix("api");
+  app.useGlobalPipes(validationPipe);
+  await app.listen(3000);
+}
+
+void bootstrap();
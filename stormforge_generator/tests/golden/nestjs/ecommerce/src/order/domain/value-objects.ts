@@ -0,0 +1,95 @@
+import { Type } from "class-transformer";
+import {
+  IsDecimal,
+  IsInt,
+  IsOptional,
+  IsString,
+  Matches,
+  Max,
+  MaxLength,
+  Min,
+  ValidateNested,
+} from "class-validator";
+import { MinDecimal } from "../../common/validation";
+
+export type OrderId = string;
+
+export type CustomerId = string;
+
+export type ProductId = string;
+
+/** Monetary value with currency */
+export class Money {
+  @IsDecimal({ decimal_digits: "0,2" })
+  @MinDecimal(0)
+  amount!: string;
+
+  @IsOptional()
+  @IsString()
+  @Matches(/^[A-Z]{3}$/)
+  currency: string = "CNY";
+}
+
+/** A line item in an order */
+export class OrderItem {
+  @IsString()
+  product_id!: ProductId;
+
+  @IsString()
+  @MaxLength(255)
+  product_name!: string;
+
+  @IsInt()
+  @Min(1)
+  @Max(999)
+  quantity!: number;
+
+  @ValidateNested()
+  @Type(() => Money)
+  unit_price!: Money;
+
+  @IsOptional()
+  @ValidateNested()
+  @Type(() => Money)
+  subtotal?: Money;
+}
+
+/** Physical address */
+export class Address {
+  @IsString()
+  @MaxLength(255)
+  street!: string;
+
+  @IsString()
+  @MaxLength(100)
+  city!: string;
+
+  @IsString()
+  @MaxLength(100)
+  province!: string;
+
+  @IsString()
+  @Matches(/^[0-9]{6}$/)
+  postal_code!: string;
+
+  @IsOptional()
+  @IsString()
+  country: string = "CN";
+}
+
+export enum OrderStatus {
+  /** Order has been created, awaiting payment */
+  Created = "Created",
+  /** Payment has been received */
+  Paid = "Paid",
+  /** Order is being prepared */
+  Processing = "Processing",
+  /** Order has been shipped */
+  Shipped = "Shipped",
+  /** Order has been delivered */
+  Delivered = "Delivered",
+  /** Order has been cancelled */
+  Cancelled = "Cancelled",
+  /** Order has been refunded */
+  Refunded = "Refunded",
+}
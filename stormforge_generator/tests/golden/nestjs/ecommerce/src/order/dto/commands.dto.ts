@@ -0,0 +1,63 @@
+import { Type } from "class-transformer";
+import { IsArray, IsOptional, IsString, ValidateNested } from "class-validator";
+import {
+  Address,
+  CustomerId,
+  OrderId,
+  OrderItem,
+} from "../domain/value-objects";
+
+/** Create a new order */
+export class CreateOrderDto {
+  @IsString()
+  customer_id!: CustomerId;
+
+  @IsArray()
+  @ValidateNested({ each: true })
+  @Type(() => OrderItem)
+  items!: OrderItem[];
+
+  @ValidateNested()
+  @Type(() => Address)
+  shipping_address!: Address;
+}
+
+/** Confirm order payment */
+export class ConfirmPaymentDto {
+  @IsString()
+  order_id!: OrderId;
+
+  @IsString()
+  payment_id!: string;
+}
+
+/** Ship the order */
+export class ShipOrderDto {
+  @IsString()
+  order_id!: OrderId;
+
+  @IsString()
+  tracking_number!: string;
+
+  @IsString()
+  carrier!: string;
+}
+
+/** Mark order as delivered */
+export class DeliverOrderDto {
+  @IsString()
+  order_id!: OrderId;
+
+  @IsOptional()
+  @IsString()
+  signed_by?: string | null;
+}
+
+/** Cancel the order */
+export class CancelOrderDto {
+  @IsString()
+  order_id!: OrderId;
+
+  @IsString()
+  reason!: string;
+}
@@ -0,0 +1,21 @@
+import { IQuery } from "@nestjs/cqrs";
+import {
+  GetOrderDto,
+  GetOrdersByStatusDto,
+  ListOrdersDto,
+} from "../dto/queries.dto";
+
+/** Get order by ID */
+export class GetOrderQuery implements IQuery {
+  constructor(readonly params: GetOrderDto) {}
+}
+
+/** List orders with filters */
+export class ListOrdersQuery implements IQuery {
+  constructor(readonly params: ListOrdersDto) {}
+}
+
+/** Get all orders with a specific status */
+export class GetOrdersByStatusQuery implements IQuery {
+  constructor(readonly params: GetOrdersByStatusDto) {}
+}
@@ -0,0 +1,187 @@
+import { CommandHandler, ICommandHandler } from "@nestjs/cqrs";
+import { randomUUID } from "crypto";
+import { ApiError } from "../../common/api-error";
+import { Payment } from "../domain/payment.aggregate";
+import {
+  DomainEvent,
+  PaymentCompleted,
+  PaymentFailed,
+  PaymentInitiated,
+  PaymentRefunded,
+} from "../domain/events";
+import { EventStore } from "../infrastructure/event-store";
+import {
+  CompletePaymentCommand,
+  FailPaymentCommand,
+  InitiatePaymentCommand,
+  RefundPaymentCommand,
+} from "./commands";
+
+@CommandHandler(InitiatePaymentCommand)
+export class InitiatePaymentHandler
+  implements ICommandHandler<InitiatePaymentCommand>
+{
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Initiate a payment for an order
+   *
+   * Produces `PaymentInitiated`.
+   */
+  async execute(command: InitiatePaymentCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = randomUUID();
+    const history = await this.eventStore.loadEvents(aggregateId, "Payment");
+    const payment = new Payment();
+    payment.loadFromHistory(history);
+    const events: DomainEvent[] = [
+      new PaymentInitiated({
+        aggregate_id: aggregateId,
+        payment_id: aggregateId,
+        order_id: payload.order_id,
+        amount: payload.amount,
+        method: payload.method,
+        created_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => payment.apply(event));
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Payment",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(CompletePaymentCommand)
+export class CompletePaymentHandler
+  implements ICommandHandler<CompletePaymentCommand>
+{
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Mark payment as completed
+   *
+   * Produces `PaymentCompleted`.
+   *
+   * Preconditions:
+   * - Can only complete pending or processing payments
+   */
+  async execute(command: CompletePaymentCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = String(payload.payment_id);
+    const history = await this.eventStore.loadEvents(aggregateId, "Payment");
+    if (history.length === 0) {
+      throw new ApiError("NOT_FOUND", `Payment ${aggregateId} not found`);
+    }
+    const payment = new Payment();
+    payment.loadFromHistory(history);
+    // TODO: enforce `payment.status in [PENDING, PROCESSING]`
+    const events: DomainEvent[] = [
+      new PaymentCompleted({
+        aggregate_id: aggregateId,
+        payment_id: payload.payment_id,
+        order_id: payment.order_id,
+        transaction_id: payload.transaction_id,
+        completed_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => payment.apply(event));
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Payment",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(FailPaymentCommand)
+export class FailPaymentHandler implements ICommandHandler<FailPaymentCommand> {
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Mark payment as failed
+   *
+   * Produces `PaymentFailed`.
+   */
+  async execute(command: FailPaymentCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = String(payload.payment_id);
+    const history = await this.eventStore.loadEvents(aggregateId, "Payment");
+    const payment = new Payment();
+    payment.loadFromHistory(history);
+    const events: DomainEvent[] = [
+      new PaymentFailed({
+        aggregate_id: aggregateId,
+        payment_id: payload.payment_id,
+        order_id: payment.order_id,
+        reason: payload.reason,
+        failed_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => payment.apply(event));
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Payment",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(RefundPaymentCommand)
+export class RefundPaymentHandler
+  implements ICommandHandler<RefundPaymentCommand>
+{
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Refund a completed payment
+   *
+   * Produces `PaymentRefunded`.
+   *
+   * Preconditions:
+   * - Can only refund completed payments
+   */
+  async execute(command: RefundPaymentCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = String(payload.payment_id);
+    const history = await this.eventStore.loadEvents(aggregateId, "Payment");
+    if (history.length === 0) {
+      throw new ApiError("NOT_FOUND", `Payment ${aggregateId} not found`);
+    }
+    const payment = new Payment();
+    payment.loadFromHistory(history);
+    // TODO: enforce `payment.status == COMPLETED`
+    const events: DomainEvent[] = [
+      new PaymentRefunded({
+        aggregate_id: aggregateId,
+        payment_id: payload.payment_id,
+        order_id: payment.order_id,
+        // TODO: convert `payload.refund_amount`
+        refund_amount: undefined!,
+        refunded_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => payment.apply(event));
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Payment",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+export const CommandHandlers = [
+  InitiatePaymentHandler,
+  CompletePaymentHandler,
+  FailPaymentHandler,
+  RefundPaymentHandler,
+];
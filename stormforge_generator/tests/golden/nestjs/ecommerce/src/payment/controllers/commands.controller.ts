@@ -0,0 +1,64 @@
+import { Body, Controller, HttpCode, HttpStatus, Post } from "@nestjs/common";
+import { CommandBus } from "@nestjs/cqrs";
+import { ApiResponse } from "../../common/api-response";
+import {
+  CompletePaymentCommand,
+  FailPaymentCommand,
+  InitiatePaymentCommand,
+  RefundPaymentCommand,
+} from "../commands/commands";
+import {
+  CompletePaymentDto,
+  FailPaymentDto,
+  InitiatePaymentDto,
+  RefundPaymentDto,
+} from "../dto/commands.dto";
+
+@Controller()
+export class CommandsController {
+  constructor(private readonly commandBus: CommandBus) {}
+
+  /** Initiate a payment for an order */
+  @Post("initiate-payment")
+  @HttpCode(HttpStatus.OK)
+  async initiatePayment(
+    @Body() body: InitiatePaymentDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new InitiatePaymentCommand(body)),
+    };
+  }
+
+  /** Mark payment as completed */
+  @Post("complete-payment")
+  @HttpCode(HttpStatus.OK)
+  async completePayment(
+    @Body() body: CompletePaymentDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new CompletePaymentCommand(body)),
+    };
+  }
+
+  /** Mark payment as failed */
+  @Post("fail-payment")
+  @HttpCode(HttpStatus.OK)
+  async failPayment(
+    @Body() body: FailPaymentDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new FailPaymentCommand(body)),
+    };
+  }
+
+  /** Refund a completed payment */
+  @Post("refund-payment")
+  @HttpCode(HttpStatus.OK)
+  async refundPayment(
+    @Body() body: RefundPaymentDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new RefundPaymentCommand(body)),
+    };
+  }
+}
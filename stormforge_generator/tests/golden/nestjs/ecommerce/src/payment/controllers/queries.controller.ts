@@ -0,0 +1,31 @@
+import { Controller, Get, Query } from "@nestjs/common";
+import { QueryBus } from "@nestjs/cqrs";
+import { ApiResponse } from "../../common/api-response";
+import { Payment } from "../domain/payment.aggregate";
+import { GetPaymentByOrderDto, GetPaymentDto } from "../dto/queries.dto";
+import { GetPaymentByOrderQuery, GetPaymentQuery } from "../queries/queries";
+
+@Controller()
+export class QueriesController {
+  constructor(private readonly queryBus: QueryBus) {}
+
+  /** Get payment by ID */
+  @Get("get-payment")
+  async getPayment(
+    @Query() params: GetPaymentDto,
+  ): Promise<ApiResponse<Payment | null>> {
+    return {
+      data: await this.queryBus.execute(new GetPaymentQuery(params)),
+    };
+  }
+
+  /** Get payment for an order */
+  @Get("get-payment-by-order")
+  async getPaymentByOrder(
+    @Query() params: GetPaymentByOrderDto,
+  ): Promise<ApiResponse<Payment | null>> {
+    return {
+      data: await this.queryBus.execute(new GetPaymentByOrderQuery(params)),
+    };
+  }
+}
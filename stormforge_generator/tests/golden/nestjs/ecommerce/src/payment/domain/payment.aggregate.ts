@@ -0,0 +1,54 @@
+import { AggregateRoot } from "@nestjs/cqrs";
+import {
+  PaymentCompleted,
+  PaymentFailed,
+  PaymentInitiated,
+  PaymentRefunded,
+} from "./events";
+import {
+  Money,
+  OrderId,
+  PaymentId,
+  PaymentMethod,
+  PaymentStatus,
+} from "./value-objects";
+
+/** Payment aggregate handling payment lifecycle */
+export class Payment extends AggregateRoot {
+  id!: PaymentId;
+  order_id!: OrderId;
+  amount!: Money;
+  method!: PaymentMethod;
+  status!: PaymentStatus;
+  transaction_id?: string | null;
+  created_at!: string;
+  completed_at?: string | null;
+
+  onPaymentInitiated(event: PaymentInitiated): void {
+    this.id = event.aggregate_id;
+    this.order_id = event.order_id;
+    this.amount = event.amount;
+    this.method = event.method;
+    this.created_at = event.created_at;
+  }
+
+  onPaymentCompleted(event: PaymentCompleted): void {
+    this.id = event.aggregate_id;
+    this.order_id = event.order_id;
+    this.status = PaymentStatus.Completed;
+    this.transaction_id = event.transaction_id;
+    this.completed_at = event.completed_at;
+  }
+
+  onPaymentFailed(event: PaymentFailed): void {
+    this.id = event.aggregate_id;
+    this.order_id = event.order_id;
+    this.status = PaymentStatus.Failed;
+  }
+
+  onPaymentRefunded(event: PaymentRefunded): void {
+    this.id = event.aggregate_id;
+    this.order_id = event.order_id;
+    this.status = PaymentStatus.Refunded;
+  }
+}
@@ -0,0 +1,51 @@
+import { Type } from "class-transformer";
+import { IsEnum, IsOptional, IsString, ValidateNested } from "class-validator";
+import {
+  Money,
+  OrderId,
+  PaymentId,
+  PaymentMethod,
+} from "../domain/value-objects";
+
+/** Initiate a payment for an order */
+export class InitiatePaymentDto {
+  @IsString()
+  order_id!: OrderId;
+
+  @ValidateNested()
+  @Type(() => Money)
+  amount!: Money;
+
+  @IsEnum(PaymentMethod)
+  method!: PaymentMethod;
+}
+
+/** Mark payment as completed */
+export class CompletePaymentDto {
+  @IsString()
+  payment_id!: PaymentId;
+
+  @IsString()
+  transaction_id!: string;
+}
+
+/** Mark payment as failed */
+export class FailPaymentDto {
+  @IsString()
+  payment_id!: PaymentId;
+
+  @IsString()
+  reason!: string;
+}
+
+/** Refund a completed payment */
+export class RefundPaymentDto {
+  @IsString()
+  payment_id!: PaymentId;
+
+  /** If not provided, full refund */
+  @IsOptional()
+  @ValidateNested()
+  @Type(() => Money)
+  refund_amount?: Money | null;
+}
@@ -0,0 +1,27 @@
+import { ICommand } from "@nestjs/cqrs";
+import {
+  AddStockDto,
+  DeductStockDto,
+  ReleaseStockDto,
+  ReserveStockDto,
+} from "../dto/commands.dto";
+
+/** Add stock to inventory */
+export class AddStockCommand implements ICommand {
+  constructor(readonly payload: AddStockDto) {}
+}
+
+/** Reserve stock for an order */
+export class ReserveStockCommand implements ICommand {
+  constructor(readonly payload: ReserveStockDto) {}
+}
+
+/** Release reserved stock */
+export class ReleaseStockCommand implements ICommand {
+  constructor(readonly payload: ReleaseStockDto) {}
+}
+
+/** Deduct reserved stock (when order ships) */
+export class DeductStockCommand implements ICommand {
+  constructor(readonly payload: DeductStockDto) {}
+}
@@ -0,0 +1,199 @@
+import { CommandHandler, ICommandHandler } from "@nestjs/cqrs";
+import { randomUUID } from "crypto";
+import { ApiError } from "../../common/api-error";
+import { Product } from "../domain/product.aggregate";
+import {
+  DomainEvent,
+  LowStockAlert,
+  StockAdded,
+  StockDeducted,
+  StockReleased,
+  StockReserved,
+} from "../domain/events";
+import { EventStore } from "../infrastructure/event-store";
+import {
+  AddStockCommand,
+  DeductStockCommand,
+  ReleaseStockCommand,
+  ReserveStockCommand,
+} from "./commands";
+
+@CommandHandler(AddStockCommand)
+export class AddStockHandler implements ICommandHandler<AddStockCommand> {
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Add stock to inventory
+   *
+   * Produces `StockAdded`.
+   */
+  async execute(command: AddStockCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = String(payload.product_id);
+    const history = await this.eventStore.loadEvents(aggregateId, "Product");
+    const product = new Product();
+    product.loadFromHistory(history);
+    const events: DomainEvent[] = [
+      new StockAdded({
+        aggregate_id: aggregateId,
+        product_id: payload.product_id,
+        quantity: payload.quantity,
+        // TODO: compute `product.availableQuantity + quantity`
+        new_available_quantity: undefined!,
+        added_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => product.apply(event));
+    // TODO: enforce `availableQuantity >= 0`
+    // TODO: enforce `reservedQuantity >= 0`
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Product",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(ReserveStockCommand)
+export class ReserveStockHandler
+  implements ICommandHandler<ReserveStockCommand>
+{
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Reserve stock for an order
+   *
+   * Produces `StockReserved`.
+   *
+   * Preconditions:
+   * - Insufficient stock available
+   */
+  async execute(command: ReserveStockCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = String(payload.product_id);
+    const history = await this.eventStore.loadEvents(aggregateId, "Product");
+    if (history.length === 0) {
+      throw new ApiError("NOT_FOUND", `Product ${aggregateId} not found`);
+    }
+    const product = new Product();
+    product.loadFromHistory(history);
+    // TODO: enforce `product.availableQuantity >= quantity`
+    const events: DomainEvent[] = [
+      new StockReserved({
+        aggregate_id: aggregateId,
+        product_id: payload.product_id,
+        order_id: payload.order_id,
+        // TODO: set `reservation_id` from the `product` state
+        reservation_id: undefined!,
+        quantity: payload.quantity,
+        reserved_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => product.apply(event));
+    // TODO: enforce `availableQuantity >= 0`
+    // TODO: enforce `reservedQuantity >= 0`
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Product",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(ReleaseStockCommand)
+export class ReleaseStockHandler
+  implements ICommandHandler<ReleaseStockCommand>
+{
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Release reserved stock
+   *
+   * Produces `StockReleased`.
+   */
+  async execute(command: ReleaseStockCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = randomUUID();
+    const history = await this.eventStore.loadEvents(aggregateId, "Product");
+    const product = new Product();
+    product.loadFromHistory(history);
+    const events: DomainEvent[] = [
+      new StockReleased({
+        aggregate_id: aggregateId,
+        product_id: aggregateId,
+        reservation_id: payload.reservation_id,
+        // TODO: set `quantity` from the `product` state
+        quantity: undefined!,
+        released_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => product.apply(event));
+    // TODO: enforce `availableQuantity >= 0`
+    // TODO: enforce `reservedQuantity >= 0`
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Product",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(DeductStockCommand)
+export class DeductStockHandler implements ICommandHandler<DeductStockCommand> {
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Deduct reserved stock (when order ships)
+   *
+   * Produces `StockDeducted`, `LowStockAlert`.
+   */
+  async execute(command: DeductStockCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = randomUUID();
+    const history = await this.eventStore.loadEvents(aggregateId, "Product");
+    const product = new Product();
+    product.loadFromHistory(history);
+    const events: DomainEvent[] = [
+      new StockDeducted({
+        aggregate_id: aggregateId,
+        product_id: aggregateId,
+        reservation_id: payload.reservation_id,
+        // TODO: set `quantity` from the `product` state
+        quantity: undefined!,
+        deducted_at: new Date().toISOString(),
+      }),
+      new LowStockAlert({
+        aggregate_id: aggregateId,
+        product_id: aggregateId,
+        sku: product.sku,
+        // TODO: set `current_quantity` from the `product` state
+        current_quantity: undefined!,
+        reorder_level: product.reorder_level,
+        alerted_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => product.apply(event));
+    // TODO: enforce `availableQuantity >= 0`
+    // TODO: enforce `reservedQuantity >= 0`
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Product",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+export const CommandHandlers = [
+  AddStockHandler,
+  ReserveStockHandler,
+  ReleaseStockHandler,
+  DeductStockHandler,
+];
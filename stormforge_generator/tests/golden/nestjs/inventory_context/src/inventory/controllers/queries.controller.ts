@@ -0,0 +1,61 @@
+import { Controller, Get, Query } from "@nestjs/common";
+import { QueryBus } from "@nestjs/cqrs";
+import { ApiResponse } from "../../common/api-response";
+import { Product } from "../domain/product.aggregate";
+import {
+  CheckAvailabilityDto,
+  GetLowStockProductsDto,
+  GetProductBySkuDto,
+  GetProductDto,
+} from "../dto/queries.dto";
+import {
+  CheckAvailabilityQuery,
+  GetLowStockProductsQuery,
+  GetProductBySkuQuery,
+  GetProductQuery,
+} from "../queries/queries";
+
+@Controller()
+export class QueriesController {
+  constructor(private readonly queryBus: QueryBus) {}
+
+  /** Get product by ID */
+  @Get("get-product")
+  async getProduct(
+    @Query() params: GetProductDto,
+  ): Promise<ApiResponse<Product | null>> {
+    return {
+      data: await this.queryBus.execute(new GetProductQuery(params)),
+    };
+  }
+
+  /** Get product by SKU */
+  @Get("get-product-by-sku")
+  async getProductBySku(
+    @Query() params: GetProductBySkuDto,
+  ): Promise<ApiResponse<Product | null>> {
+    return {
+      data: await this.queryBus.execute(new GetProductBySkuQuery(params)),
+    };
+  }
+
+  /** Check if quantity is available */
+  @Get("check-availability")
+  async checkAvailability(
+    @Query() params: CheckAvailabilityDto,
+  ): Promise<ApiResponse<boolean>> {
+    return {
+      data: await this.queryBus.execute(new CheckAvailabilityQuery(params)),
+    };
+  }
+
+  /** Get products below reorder level */
+  @Get("get-low-stock-products")
+  async getLowStockProducts(
+    @Query() params: GetLowStockProductsDto,
+  ): Promise<ApiResponse<Product[]>> {
+    return {
+      data: await this.queryBus.execute(new GetLowStockProductsQuery(params)),
+    };
+  }
+}
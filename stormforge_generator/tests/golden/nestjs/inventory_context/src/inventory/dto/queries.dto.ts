@@ -0,0 +1,28 @@
+import { Type } from "class-transformer";
+import { IsInt, IsString } from "class-validator";
+import { ProductId } from "../domain/value-objects";
+
+/** Query string of `GetProduct` */
+export class GetProductDto {
+  @IsString()
+  product_id!: ProductId;
+}
+
+/** Query string of `GetProductBySku` */
+export class GetProductBySkuDto {
+  @IsString()
+  sku!: string;
+}
+
+/** Query string of `CheckAvailability` */
+export class CheckAvailabilityDto {
+  @IsString()
+  product_id!: ProductId;
+
+  @Type(() => Number)
+  @IsInt()
+  quantity!: number;
+}
+
+/** Query string of `GetLowStockProducts` */
+export class GetLowStockProductsDto {}
@@ -0,0 +1,87 @@
+import { Injectable } from "@nestjs/common";
+import { EventBus } from "@nestjs/cqrs";
+import { DomainEvent } from "../domain/events";
+
+/** The aggregate is not at the version the writer expected */
+export class ConcurrencyError extends Error {}
+
+/** Event store, injected into command and query handlers */
+export abstract class EventStore {
+  /**
+   * Append events to an aggregate
+   *
+   * Nothing is written when `expectedVersion` does not match the number of
+   * events already stored for the aggregate.
+   */
+  abstract appendEvents(
+    aggregateId: string,
+    aggregateType: string,
+    events: DomainEvent[],
+    expectedVersion?: number,
+  ): Promise<void>;
+
+  /** Number of events stored for an aggregate */
+  abstract version(aggregateId: string, aggregateType: string): Promise<number>;
+
+  /** Events of an aggregate in append order */
+  abstract loadEvents(
+    aggregateId: string,
+    aggregateType: string,
+  ): Promise<DomainEvent[]>;
+
+  /** Events of all aggregates in append order */
+  abstract getAllEvents(): Promise<DomainEvent[]>;
+}
+
+/** Event store keeping events in memory, publishing them once stored */
+@Injectable()
+export class InMemoryEventStore extends EventStore {
+  private readonly streams = new Map<string, DomainEvent[]>();
+  private readonly log: DomainEvent[] = [];
+
+  constructor(private readonly eventBus: EventBus) {
+    super();
+  }
+
+  async appendEvents(
+    aggregateId: string,
+    aggregateType: string,
+    events: DomainEvent[],
+    expectedVersion?: number,
+  ): Promise<void> {
+    const stream = this.stream(aggregateId, aggregateType);
+    if (expectedVersion !== undefined && expectedVersion !== stream.length) {
+      throw new ConcurrencyError(
+        `Concurrency conflict: expected version ${expectedVersion}, found ${stream.length}`,
+      );
+    }
+    stream.push(...events);
+    this.log.push(...events);
+    this.eventBus.publishAll(events);
+  }
+
+  async version(aggregateId: string, aggregateType: string): Promise<number> {
+    return this.stream(aggregateId, aggregateType).length;
+  }
+
+  async loadEvents(
+    aggregateId: string,
+    aggregateType: string,
+  ): Promise<DomainEvent[]> {
+    return [...this.stream(aggregateId, aggregateType)];
+  }
+
+  async getAllEvents(): Promise<DomainEvent[]> {
+    return [...this.log];
+  }
+
+  private stream(aggregateId: string, aggregateType: string): DomainEvent[] {
+    const key = `${aggregateType}/${aggregateId}`;
+    let stream = this.streams.get(key);
+    if (!stream) {
+      stream = [];
+      this.streams.set(key, stream);
+    }
+    return stream;
+  }
+}
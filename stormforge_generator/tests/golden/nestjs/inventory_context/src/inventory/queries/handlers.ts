@@ -0,0 +1,60 @@
+import { IQueryHandler, QueryHandler } from "@nestjs/cqrs";
+import { ApiError } from "../../common/api-error";
+import { Product } from "../domain/product.aggregate";
+import {
+  CheckAvailabilityQuery,
+  GetLowStockProductsQuery,
+  GetProductBySkuQuery,
+  GetProductQuery,
+} from "./queries";
+
+@QueryHandler(GetProductQuery)
+export class GetProductHandler
+  implements IQueryHandler<GetProductQuery, Product | null>
+{
+  /** Get product by ID */
+  async execute(query: GetProductQuery): Promise<Product | null> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return null;
+  }
+}
+
+@QueryHandler(GetProductBySkuQuery)
+export class GetProductBySkuHandler
+  implements IQueryHandler<GetProductBySkuQuery, Product | null>
+{
+  /** Get product by SKU */
+  async execute(query: GetProductBySkuQuery): Promise<Product | null> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return null;
+  }
+}
+
+@QueryHandler(CheckAvailabilityQuery)
+export class CheckAvailabilityHandler
+  implements IQueryHandler<CheckAvailabilityQuery, boolean>
+{
+  /** Check if quantity is available */
+  async execute(query: CheckAvailabilityQuery): Promise<boolean> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    throw new ApiError("NOT_FOUND", "bool not found");
+  }
+}
+
+@QueryHandler(GetLowStockProductsQuery)
+export class GetLowStockProductsHandler
+  implements IQueryHandler<GetLowStockProductsQuery, Product[]>
+{
+  /** Get products below reorder level */
+  async execute(query: GetLowStockProductsQuery): Promise<Product[]> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return [];
+  }
+}
+
+export const QueryHandlers = [
+  GetProductHandler,
+  GetProductBySkuHandler,
+  CheckAvailabilityHandler,
+  GetLowStockProductsHandler,
+];
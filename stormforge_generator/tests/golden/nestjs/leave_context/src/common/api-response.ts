@@ -0,0 +1,12 @@
+/** `{ data }` envelope of command and query responses */
+export interface ApiResponse<T> {
+  data: T;
+}
+
+/** Page of query results */
+export interface PagedResult<T> {
+  items: T[];
+  total: number;
+  page: number;
+  page_size: number;
+}
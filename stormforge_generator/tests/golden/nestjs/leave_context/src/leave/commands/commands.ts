@@ -0,0 +1,27 @@
+import { ICommand } from "@nestjs/cqrs";
+import {
+  ApproveLeaveDto,
+  CancelLeaveDto,
+  RejectLeaveDto,
+  RequestLeaveDto,
+} from "../dto/commands.dto";
+
+/** Submit a leave request */
+export class RequestLeaveCommand implements ICommand {
+  constructor(readonly payload: RequestLeaveDto) {}
+}
+
+/** Approve a leave request */
+export class ApproveLeaveCommand implements ICommand {
+  constructor(readonly payload: ApproveLeaveDto) {}
+}
+
+/** Reject a leave request */
+export class RejectLeaveCommand implements ICommand {
+  constructor(readonly payload: RejectLeaveDto) {}
+}
+
+/** Cancel a leave request */
+export class CancelLeaveCommand implements ICommand {
+  constructor(readonly payload: CancelLeaveDto) {}
+}
@@ -0,0 +1,228 @@
+import { CommandHandler, ICommandHandler } from "@nestjs/cqrs";
+import { randomUUID } from "crypto";
+import { ApiError } from "../../common/api-error";
+import { LeaveRequest } from "../domain/leave-request.aggregate";
+import {
+  DomainEvent,
+  LeaveApproved,
+  LeaveCancelled,
+  LeaveRejected,
+  LeaveRequested,
+} from "../domain/events";
+import { EventStore } from "../infrastructure/event-store";
+import {
+  ApproveLeaveCommand,
+  CancelLeaveCommand,
+  RejectLeaveCommand,
+  RequestLeaveCommand,
+} from "./commands";
+
+@CommandHandler(RequestLeaveCommand)
+export class RequestLeaveHandler
+  implements ICommandHandler<RequestLeaveCommand>
+{
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Submit a leave request
+   *
+   * Produces `LeaveRequested`.
+   *
+   * Validation:
+   * - End date must be on or after start date
+   * - Leave cannot start in the past
+   */
+  async execute(command: RequestLeaveCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = randomUUID();
+    const history = await this.eventStore.loadEvents(
+      aggregateId,
+      "LeaveRequest",
+    );
+    const leaveRequest = new LeaveRequest();
+    leaveRequest.loadFromHistory(history);
+    // TODO: enforce `endDate >= startDate`
+    // TODO: enforce `startDate >= today()`
+    const events: DomainEvent[] = [
+      new LeaveRequested({
+        aggregate_id: aggregateId,
+        leave_request_id: aggregateId,
+        employee_id: payload.employee_id,
+        leave_type: payload.leave_type,
+        start_date: payload.start_date,
+        end_date: payload.end_date,
+        // TODO: convert `payload.reason`
+        reason: undefined!,
+        requested_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => leaveRequest.apply(event));
+    // TODO: enforce `endDate >= startDate`
+    // TODO: enforce `daysBetween(startDate, endDate) <= 30`
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "LeaveRequest",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(ApproveLeaveCommand)
+export class ApproveLeaveHandler
+  implements ICommandHandler<ApproveLeaveCommand>
+{
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Approve a leave request
+   *
+   * Produces `LeaveApproved`.
+   *
+   * Preconditions:
+   * - Can only approve pending leave requests
+   * - Cannot approve own leave request
+   */
+  async execute(command: ApproveLeaveCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = String(payload.leave_request_id);
+    const history = await this.eventStore.loadEvents(
+      aggregateId,
+      "LeaveRequest",
+    );
+    if (history.length === 0) {
+      throw new ApiError("NOT_FOUND", `LeaveRequest ${aggregateId} not found`);
+    }
+    const leaveRequest = new LeaveRequest();
+    leaveRequest.loadFromHistory(history);
+    // TODO: enforce `leaveRequest.status == PENDING`
+    // TODO: enforce `approverId != leaveRequest.employeeId`
+    const events: DomainEvent[] = [
+      new LeaveApproved({
+        aggregate_id: aggregateId,
+        leave_request_id: payload.leave_request_id,
+        employee_id: leaveRequest.employee_id,
+        approver_id: payload.approver_id,
+        // TODO: convert `payload.comment`
+        comment: undefined!,
+        approved_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => leaveRequest.apply(event));
+    // TODO: enforce `endDate >= startDate`
+    // TODO: enforce `daysBetween(startDate, endDate) <= 30`
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "LeaveRequest",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(RejectLeaveCommand)
+export class RejectLeaveHandler implements ICommandHandler<RejectLeaveCommand> {
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Reject a leave request
+   *
+   * Produces `LeaveRejected`.
+   *
+   * Preconditions:
+   * - Can only reject pending leave requests
+   */
+  async execute(command: RejectLeaveCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = String(payload.leave_request_id);
+    const history = await this.eventStore.loadEvents(
+      aggregateId,
+      "LeaveRequest",
+    );
+    if (history.length === 0) {
+      throw new ApiError("NOT_FOUND", `LeaveRequest ${aggregateId} not found`);
+    }
+    const leaveRequest = new LeaveRequest();
+    leaveRequest.loadFromHistory(history);
+    // TODO: enforce `leaveRequest.status == PENDING`
+    const events: DomainEvent[] = [
+      new LeaveRejected({
+        aggregate_id: aggregateId,
+        leave_request_id: payload.leave_request_id,
+        employee_id: leaveRequest.employee_id,
+        approver_id: payload.approver_id,
+        reason: payload.reason,
+        rejected_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => leaveRequest.apply(event));
+    // TODO: enforce `endDate >= startDate`
+    // TODO: enforce `daysBetween(startDate, endDate) <= 30`
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "LeaveRequest",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(CancelLeaveCommand)
+export class CancelLeaveHandler implements ICommandHandler<CancelLeaveCommand> {
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Cancel a leave request
+   *
+   * Produces `LeaveCancelled`.
+   *
+   * Preconditions:
+   * - Can only cancel pending or approved leave requests
+   * - Only the employee can cancel their own leave
+   * - Cannot cancel leave that has already started
+   */
+  async execute(command: CancelLeaveCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = String(payload.leave_request_id);
+    const history = await this.eventStore.loadEvents(
+      aggregateId,
+      "LeaveRequest",
+    );
+    if (history.length === 0) {
+      throw new ApiError("NOT_FOUND", `LeaveRequest ${aggregateId} not found`);
+    }
+    const leaveRequest = new LeaveRequest();
+    leaveRequest.loadFromHistory(history);
+    // TODO: enforce `leaveRequest.status in [PENDING, APPROVED]`
+    // TODO: enforce `leaveRequest.employeeId == employeeId`
+    // TODO: enforce `leaveRequest.startDate > today()`
+    const events: DomainEvent[] = [
+      new LeaveCancelled({
+        aggregate_id: aggregateId,
+        leave_request_id: payload.leave_request_id,
+        employee_id: payload.employee_id,
+        cancelled_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => leaveRequest.apply(event));
+    // TODO: enforce `endDate >= startDate`
+    // TODO: enforce `daysBetween(startDate, endDate) <= 30`
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "LeaveRequest",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+export const CommandHandlers = [
+  RequestLeaveHandler,
+  ApproveLeaveHandler,
+  RejectLeaveHandler,
+  CancelLeaveHandler,
+];
@@ -0,0 +1,64 @@
+import { Body, Controller, HttpCode, HttpStatus, Post } from "@nestjs/common";
+import { CommandBus } from "@nestjs/cqrs";
+import { ApiResponse } from "../../common/api-response";
+import {
+  ApproveLeaveCommand,
+  CancelLeaveCommand,
+  RejectLeaveCommand,
+  RequestLeaveCommand,
+} from "../commands/commands";
+import {
+  ApproveLeaveDto,
+  CancelLeaveDto,
+  RejectLeaveDto,
+  RequestLeaveDto,
+} from "../dto/commands.dto";
+
+@Controller()
+export class CommandsController {
+  constructor(private readonly commandBus: CommandBus) {}
+
+  /** Submit a leave request */
+  @Post("request-leave")
+  @HttpCode(HttpStatus.OK)
+  async requestLeave(
+    @Body() body: RequestLeaveDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new RequestLeaveCommand(body)),
+    };
+  }
+
+  /** Approve a leave request */
+  @Post("approve-leave")
+  @HttpCode(HttpStatus.OK)
+  async approveLeave(
+    @Body() body: ApproveLeaveDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new ApproveLeaveCommand(body)),
+    };
+  }
+
+  /** Reject a leave request */
+  @Post("reject-leave")
+  @HttpCode(HttpStatus.OK)
+  async rejectLeave(
+    @Body() body: RejectLeaveDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new RejectLeaveCommand(body)),
+    };
+  }
+
+  /** Cancel a leave request */
+  @Post("cancel-leave")
+  @HttpCode(HttpStatus.OK)
+  async cancelLeave(
+    @Body() body: CancelLeaveDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new CancelLeaveCommand(body)),
+    };
+  }
+}
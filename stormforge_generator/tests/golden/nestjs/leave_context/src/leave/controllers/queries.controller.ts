@@ -0,0 +1,61 @@
+import { Controller, Get, Query } from "@nestjs/common";
+import { QueryBus } from "@nestjs/cqrs";
+import { ApiResponse } from "../../common/api-response";
+import { LeaveRequest } from "../domain/leave-request.aggregate";
+import {
+  GetEmployeeLeavesDto,
+  GetLeaveBalanceDto,
+  GetLeaveRequestDto,
+  GetPendingApprovalsDto,
+} from "../dto/queries.dto";
+import {
+  GetEmployeeLeavesQuery,
+  GetLeaveBalanceQuery,
+  GetLeaveRequestQuery,
+  GetPendingApprovalsQuery,
+} from "../queries/queries";
+
+@Controller()
+export class QueriesController {
+  constructor(private readonly queryBus: QueryBus) {}
+
+  /** Get leave request by ID */
+  @Get("get-leave-request")
+  async getLeaveRequest(
+    @Query() params: GetLeaveRequestDto,
+  ): Promise<ApiResponse<LeaveRequest | null>> {
+    return {
+      data: await this.queryBus.execute(new GetLeaveRequestQuery(params)),
+    };
+  }
+
+  /** Get all leave requests for an employee */
+  @Get("get-employee-leaves")
+  async getEmployeeLeaves(
+    @Query() params: GetEmployeeLeavesDto,
+  ): Promise<ApiResponse<LeaveRequest[]>> {
+    return {
+      data: await this.queryBus.execute(new GetEmployeeLeavesQuery(params)),
+    };
+  }
+
+  /** Get pending leave requests for approval */
+  @Get("get-pending-approvals")
+  async getPendingApprovals(
+    @Query() params: GetPendingApprovalsDto,
+  ): Promise<ApiResponse<LeaveRequest[]>> {
+    return {
+      data: await this.queryBus.execute(new GetPendingApprovalsQuery(params)),
+    };
+  }
+
+  /** Get remaining leave balance for an employee */
+  @Get("get-leave-balance")
+  async getLeaveBalance(
+    @Query() params: GetLeaveBalanceDto,
+  ): Promise<ApiResponse<unknown>> {
+    return {
+      data: await this.queryBus.execute(new GetLeaveBalanceQuery(params)),
+    };
+  }
+}
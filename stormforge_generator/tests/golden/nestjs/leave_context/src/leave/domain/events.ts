@@ -0,0 +1,93 @@
+import { IEvent } from "@nestjs/cqrs";
+import { randomUUID } from "crypto";
+import { EmployeeId, LeaveRequestId, LeaveType } from "./value-objects";
+
+/** Fields of an event given when raising it */
+export type EventPayload<E> = Omit<E, "type" | "event_id" | "occurred_at">;
+
+/** A leave request has been submitted */
+export class LeaveRequested implements IEvent {
+  readonly type = "LeaveRequested" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly leave_request_id!: LeaveRequestId;
+  readonly employee_id!: EmployeeId;
+  readonly leave_type!: LeaveType;
+  readonly start_date!: string;
+  readonly end_date!: string;
+  readonly reason!: string;
+  readonly requested_at!: string;
+
+  constructor(payload: EventPayload<LeaveRequested>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Leave request has been approved */
+export class LeaveApproved implements IEvent {
+  readonly type = "LeaveApproved" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly leave_request_id!: LeaveRequestId;
+  readonly employee_id!: EmployeeId;
+  readonly approver_id!: EmployeeId;
+  readonly comment!: string;
+  readonly approved_at!: string;
+
+  constructor(payload: EventPayload<LeaveApproved>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Leave request has been rejected */
+export class LeaveRejected implements IEvent {
+  readonly type = "LeaveRejected" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly leave_request_id!: LeaveRequestId;
+  readonly employee_id!: EmployeeId;
+  readonly approver_id!: EmployeeId;
+  readonly reason!: string;
+  readonly rejected_at!: string;
+
+  constructor(payload: EventPayload<LeaveRejected>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Leave request has been cancelled */
+export class LeaveCancelled implements IEvent {
+  readonly type = "LeaveCancelled" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly leave_request_id!: LeaveRequestId;
+  readonly employee_id!: EmployeeId;
+  readonly cancelled_at!: string;
+
+  constructor(payload: EventPayload<LeaveCancelled>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Domain event of this bounded context, tagged by `type` */
+export type DomainEvent =
+  | LeaveRequested
+  | LeaveApproved
+  | LeaveRejected
+  | LeaveCancelled;
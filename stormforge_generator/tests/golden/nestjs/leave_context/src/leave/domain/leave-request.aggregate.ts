@@ -0,0 +1,61 @@
+import { AggregateRoot } from "@nestjs/cqrs";
+import {
+  LeaveApproved,
+  LeaveCancelled,
+  LeaveRejected,
+  LeaveRequested,
+} from "./events";
+import {
+  EmployeeId,
+  LeaveRequestId,
+  LeaveStatus,
+  LeaveType,
+} from "./value-objects";
+
+/**
+ * Leave request aggregate
+ *
+ * Invariants:
+ * - End date must be on or after start date: `endDate >= startDate`
+ * - Leave cannot exceed 30 days: `daysBetween(startDate, endDate) <= 30`
+ */
+export class LeaveRequest extends AggregateRoot {
+  id!: LeaveRequestId;
+  employee_id!: EmployeeId;
+  leave_type!: LeaveType;
+  start_date!: string;
+  end_date!: string;
+  reason?: string | null;
+  status!: LeaveStatus;
+  approver_id?: EmployeeId | null;
+  approval_comment?: string | null;
+  created_at!: string;
+  updated_at?: string | null;
+
+  onLeaveRequested(event: LeaveRequested): void {
+    this.id = event.aggregate_id;
+    this.employee_id = event.employee_id;
+    this.leave_type = event.leave_type;
+    this.start_date = event.start_date;
+    this.end_date = event.end_date;
+    this.reason = event.reason;
+  }
+
+  onLeaveApproved(event: LeaveApproved): void {
+    this.id = event.aggregate_id;
+    this.employee_id = event.employee_id;
+    this.approver_id = event.approver_id;
+  }
+
+  onLeaveRejected(event: LeaveRejected): void {
+    this.id = event.aggregate_id;
+    this.employee_id = event.employee_id;
+    this.reason = event.reason;
+    this.approver_id = event.approver_id;
+  }
+
+  onLeaveCancelled(event: LeaveCancelled): void {
+    this.id = event.aggregate_id;
+    this.employee_id = event.employee_id;
+  }
+}
@@ -0,0 +1,31 @@
+export type LeaveRequestId = string;
+
+export type EmployeeId = string;
+
+export enum LeaveType {
+  /** Annual leave */
+  Annual = "Annual",
+  /** Sick leave */
+  Sick = "Sick",
+  /** Personal leave */
+  Personal = "Personal",
+  /** Maternity leave */
+  Maternity = "Maternity",
+  /** Paternity leave */
+  Paternity = "Paternity",
+  /** Bereavement leave */
+  Bereavement = "Bereavement",
+  /** Unpaid leave */
+  Unpaid = "Unpaid",
+}
+
+export enum LeaveStatus {
+  /** Awaiting approval */
+  Pending = "Pending",
+  /** Leave approved */
+  Approved = "Approved",
+  /** Leave rejected */
+  Rejected = "Rejected",
+  /** Leave cancelled by employee */
+  Cancelled = "Cancelled",
+}
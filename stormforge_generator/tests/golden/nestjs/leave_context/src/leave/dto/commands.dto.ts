@@ -0,0 +1,55 @@
+import { IsEnum, IsOptional, IsString, Matches } from "class-validator";
+import { EmployeeId, LeaveRequestId, LeaveType } from "../domain/value-objects";
+
+/** Submit a leave request */
+export class RequestLeaveDto {
+  @IsString()
+  employee_id!: EmployeeId;
+
+  @IsEnum(LeaveType)
+  leave_type!: LeaveType;
+
+  @Matches(/^\d{4}-\d{2}-\d{2}$/)
+  start_date!: string;
+
+  @Matches(/^\d{4}-\d{2}-\d{2}$/)
+  end_date!: string;
+
+  @IsOptional()
+  @IsString()
+  reason?: string | null;
+}
+
+/** Approve a leave request */
+export class ApproveLeaveDto {
+  @IsString()
+  leave_request_id!: LeaveRequestId;
+
+  @IsString()
+  approver_id!: EmployeeId;
+
+  @IsOptional()
+  @IsString()
+  comment?: string | null;
+}
+
+/** Reject a leave request */
+export class RejectLeaveDto {
+  @IsString()
+  leave_request_id!: LeaveRequestId;
+
+  @IsString()
+  approver_id!: EmployeeId;
+
+  @IsString()
+  reason!: string;
+}
+
+/** Cancel a leave request */
+export class CancelLeaveDto {
+  @IsString()
+  leave_request_id!: LeaveRequestId;
+
+  @IsString()
+  employee_id!: EmployeeId;
+}
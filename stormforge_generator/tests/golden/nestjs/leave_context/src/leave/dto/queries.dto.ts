@@ -0,0 +1,49 @@
+import { Type } from "class-transformer";
+import { IsEnum, IsInt, IsOptional, IsString } from "class-validator";
+import {
+  EmployeeId,
+  LeaveRequestId,
+  LeaveStatus,
+  LeaveType,
+} from "../domain/value-objects";
+
+/** Query string of `GetLeaveRequest` */
+export class GetLeaveRequestDto {
+  @IsString()
+  leave_request_id!: LeaveRequestId;
+}
+
+/** Query string of `GetEmployeeLeaves` */
+export class GetEmployeeLeavesDto {
+  @IsString()
+  employee_id!: EmployeeId;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsInt()
+  year?: number;
+
+  @IsOptional()
+  @IsEnum(LeaveStatus)
+  status?: LeaveStatus;
+}
+
+/** Query string of `GetPendingApprovals` */
+export class GetPendingApprovalsDto {
+  @IsString()
+  approver_id!: EmployeeId;
+}
+
+/** Query string of `GetLeaveBalance` */
+export class GetLeaveBalanceDto {
+  @IsString()
+  employee_id!: EmployeeId;
+
+  @IsEnum(LeaveType)
+  leave_type!: LeaveType;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsInt()
+  year?: number;
+}
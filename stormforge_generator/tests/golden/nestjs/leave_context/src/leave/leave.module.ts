@@ -0,0 +1,19 @@
+import { Module } from "@nestjs/common";
+import { CqrsModule } from "@nestjs/cqrs";
+import { CommandHandlers } from "./commands/handlers";
+import { CommandsController } from "./controllers/commands.controller";
+import { QueriesController } from "./controllers/queries.controller";
+import { EventStore, InMemoryEventStore } from "./infrastructure/event-store";
+import { QueryHandlers } from "./queries/handlers";
+
+/** Leave bounded context */
+@Module({
+  imports: [CqrsModule],
+  controllers: [CommandsController, QueriesController],
+  providers: [
+    ...CommandHandlers,
+    ...QueryHandlers,
+    { provide: EventStore, useClass: InMemoryEventStore },
+  ],
+})
+export class LeaveModule {}
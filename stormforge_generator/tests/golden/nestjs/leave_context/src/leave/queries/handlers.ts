@@ -0,0 +1,60 @@
+import { IQueryHandler, QueryHandler } from "@nestjs/cqrs";
+import { ApiError } from "../../common/api-error";
+import { LeaveRequest } from "../domain/leave-request.aggregate";
+import {
+  GetEmployeeLeavesQuery,
+  GetLeaveBalanceQuery,
+  GetLeaveRequestQuery,
+  GetPendingApprovalsQuery,
+} from "./queries";
+
+@QueryHandler(GetLeaveRequestQuery)
+export class GetLeaveRequestHandler
+  implements IQueryHandler<GetLeaveRequestQuery, LeaveRequest | null>
+{
+  /** Get leave request by ID */
+  async execute(query: GetLeaveRequestQuery): Promise<LeaveRequest | null> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return null;
+  }
+}
+
+@QueryHandler(GetEmployeeLeavesQuery)
+export class GetEmployeeLeavesHandler
+  implements IQueryHandler<GetEmployeeLeavesQuery, LeaveRequest[]>
+{
+  /** Get all leave requests for an employee */
+  async execute(query: GetEmployeeLeavesQuery): Promise<LeaveRequest[]> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return [];
+  }
+}
+
+@QueryHandler(GetPendingApprovalsQuery)
+export class GetPendingApprovalsHandler
+  implements IQueryHandler<GetPendingApprovalsQuery, LeaveRequest[]>
+{
+  /** Get pending leave requests for approval */
+  async execute(query: GetPendingApprovalsQuery): Promise<LeaveRequest[]> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return [];
+  }
+}
+
+@QueryHandler(GetLeaveBalanceQuery)
+export class GetLeaveBalanceHandler
+  implements IQueryHandler<GetLeaveBalanceQuery, unknown>
+{
+  /** Get remaining leave balance for an employee */
+  async execute(query: GetLeaveBalanceQuery): Promise<unknown> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    throw new ApiError("NOT_FOUND", "LeaveBalance not found");
+  }
+}
+
+export const QueryHandlers = [
+  GetLeaveRequestHandler,
+  GetEmployeeLeavesHandler,
+  GetPendingApprovalsHandler,
+  GetLeaveBalanceHandler,
+];
@@ -0,0 +1,27 @@
+import { IQuery } from "@nestjs/cqrs";
+import {
+  GetEmployeeLeavesDto,
+  GetLeaveBalanceDto,
+  GetLeaveRequestDto,
+  GetPendingApprovalsDto,
+} from "../dto/queries.dto";
+
+/** Get leave request by ID */
+export class GetLeaveRequestQuery implements IQuery {
+  constructor(readonly params: GetLeaveRequestDto) {}
+}
+
+/** Get all leave requests for an employee */
+export class GetEmployeeLeavesQuery implements IQuery {
+  constructor(readonly params: GetEmployeeLeavesDto) {}
+}
+
+/** Get pending leave requests for approval */
+export class GetPendingApprovalsQuery implements IQuery {
+  constructor(readonly params: GetPendingApprovalsDto) {}
+}
+
+/** Get remaining leave balance for an employee */
+export class GetLeaveBalanceQuery implements IQuery {
+  constructor(readonly params: GetLeaveBalanceDto) {}
+}
@@ -0,0 +1,75 @@
+import { ValidationPipe } from "@nestjs/common";
+import {
+  ValidateBy,
+  ValidationError,
+  ValidationOptions,
+  buildMessage,
+} from "class-validator";
+import { ApiError } from "./api-error";
+
+/** Pipe validating request bodies and query strings against their DTO */
+export const validationPipe = new ValidationPipe({
+  transform: true,
+  exceptionFactory: (errors: ValidationError[]) =>
+    new ApiError(
+      "VALIDATION_ERROR",
+      `Validation error: ${problems(errors).join("; ")}`,
+    ),
+});
+
+/** Messages of failed constraints, with the path of nested fields */
+function problems(errors: ValidationError[], path = ""): string[] {
+  return errors.flatMap((error) => {
+    const field = path ? `${path}.${error.property}` : error.property;
+    const messages = Object.values(error.constraints ?? {}).map((message) =>
+      message.startsWith(error.property)
+        ? field + message.slice(error.property.length)
+        : `${field}: ${message}`,
+    );
+    return [...messages, ...problems(error.children ?? [], field)];
+  });
+}
+
+/** Checks that a decimal string is at least `min` */
+export function MinDecimal(
+  min: number,
+  options?: ValidationOptions,
+): PropertyDecorator {
+  return ValidateBy(
+    {
+      name: "minDecimal",
+      constraints: [min],
+      validator: {
+        validate: (value: unknown) =>
+          typeof value === "string" && Number(value) >= min,
+        defaultMessage: buildMessage(
+          (each) => `${each}$property must not be less than $constraint1`,
+          options,
+        ),
+      },
+    },
+    options,
+  );
+}
+
+/** Checks that a decimal string is at most `max` */
+export function MaxDecimal(
+  max: number,
+  options?: ValidationOptions,
+): PropertyDecorator {
+  return ValidateBy(
+    {
+      name: "maxDecimal",
+      constraints: [max],
+      validator: {
+        validate: (value: unknown) =>
+          typeof value === "string" && Number(value) <= max,
+        defaultMessage: buildMessage(
+          (each) => `${each}$property must not be greater than $constraint1`,
+          options,
+        ),
+      },
+    },
+    options,
+  );
+}
@@ -0,0 +1,33 @@
+import { ICommand } from "@nestjs/cqrs";
+import {
+  CancelOrderDto,
+  ConfirmPaymentDto,
+  CreateOrderDto,
+  DeliverOrderDto,
+  ShipOrderDto,
+} from "../dto/commands.dto";
+
+/** Create a new order */
+export class CreateOrderCommand implements ICommand {
+  constructor(readonly payload: CreateOrderDto) {}
+}
+
+/** Confirm order payment */
+export class ConfirmPaymentCommand implements ICommand {
+  constructor(readonly payload: ConfirmPaymentDto) {}
+}
+
+/** Ship the order */
+export class ShipOrderCommand implements ICommand {
+  constructor(readonly payload: ShipOrderDto) {}
+}
+
+/** Mark order as delivered */
+export class DeliverOrderCommand implements ICommand {
+  constructor(readonly payload: DeliverOrderDto) {}
+}
+
+/** Cancel the order */
+export class CancelOrderCommand implements ICommand {
+  constructor(readonly payload: CancelOrderDto) {}
+}
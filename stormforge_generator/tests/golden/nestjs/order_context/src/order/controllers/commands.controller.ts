@@ -0,0 +1,75 @@
+import { Body, Controller, HttpCode, HttpStatus, Post } from "@nestjs/common";
+import { CommandBus } from "@nestjs/cqrs";
+import { ApiResponse } from "../../common/api-response";
+import {
+  CancelOrderCommand,
+  ConfirmPaymentCommand,
+  CreateOrderCommand,
+  DeliverOrderCommand,
+  ShipOrderCommand,
+} from "../commands/commands";
+import {
+  CancelOrderDto,
+  ConfirmPaymentDto,
+  CreateOrderDto,
+  DeliverOrderDto,
+  ShipOrderDto,
+} from "../dto/commands.dto";
+
+@Controller()
+export class CommandsController {
+  constructor(private readonly commandBus: CommandBus) {}
+
+  /** Create a new order */
+  @Post("create-order")
+  @HttpCode(HttpStatus.OK)
+  async createOrder(
+    @Body() body: CreateOrderDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new CreateOrderCommand(body)),
+    };
+  }
+
+  /** Confirm order payment */
+  @Post("confirm-payment")
+  @HttpCode(HttpStatus.OK)
+  async confirmPayment(
+    @Body() body: ConfirmPaymentDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new ConfirmPaymentCommand(body)),
+    };
+  }
+
+  /** Ship the order */
+  @Post("ship-order")
+  @HttpCode(HttpStatus.OK)
+  async shipOrder(@Body() body: ShipOrderDto): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new ShipOrderCommand(body)),
+    };
+  }
+
+  /** Mark order as delivered */
+  @Post("deliver-order")
+  @HttpCode(HttpStatus.OK)
+  async deliverOrder(
+    @Body() body: DeliverOrderDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new DeliverOrderCommand(body)),
+    };
+  }
+
+  /** Cancel the order */
+  @Post("cancel-order")
+  @HttpCode(HttpStatus.OK)
+  async cancelOrder(
+    @Body() body: CancelOrderDto,
+  ): Promise<ApiResponse<string>> {
+    return {
+      data: await this.commandBus.execute(new CancelOrderCommand(body)),
+    };
+  }
+}
@@ -0,0 +1,49 @@
+import { Controller, Get, Query } from "@nestjs/common";
+import { QueryBus } from "@nestjs/cqrs";
+import { ApiResponse, PagedResult } from "../../common/api-response";
+import { Order } from "../domain/order.aggregate";
+import {
+  GetOrderDto,
+  GetOrdersByStatusDto,
+  ListOrdersDto,
+} from "../dto/queries.dto";
+import {
+  GetOrderQuery,
+  GetOrdersByStatusQuery,
+  ListOrdersQuery,
+} from "../queries/queries";
+
+@Controller()
+export class QueriesController {
+  constructor(private readonly queryBus: QueryBus) {}
+
+  /** Get order by ID */
+  @Get("get-order")
+  async getOrder(
+    @Query() params: GetOrderDto,
+  ): Promise<ApiResponse<Order | null>> {
+    return {
+      data: await this.queryBus.execute(new GetOrderQuery(params)),
+    };
+  }
+
+  /** List orders with filters */
+  @Get("list-orders")
+  async listOrders(
+    @Query() params: ListOrdersDto,
+  ): Promise<ApiResponse<PagedResult<Order>>> {
+    return {
+      data: await this.queryBus.execute(new ListOrdersQuery(params)),
+    };
+  }
+
+  /** Get all orders with a specific status */
+  @Get("get-orders-by-status")
+  async getOrdersByStatus(
+    @Query() params: GetOrdersByStatusDto,
+  ): Promise<ApiResponse<Order[]>> {
+    return {
+      data: await this.queryBus.execute(new GetOrdersByStatusQuery(params)),
+    };
+  }
+}
@@ -0,0 +1,76 @@
+import { AggregateRoot } from "@nestjs/cqrs";
+import {
+  OrderCancelled,
+  OrderCreated,
+  OrderDelivered,
+  OrderPaid,
+  OrderShipped,
+} from "./events";
+import {
+  Address,
+  CustomerId,
+  Money,
+  OrderId,
+  OrderItem,
+  OrderStatus,
+} from "./value-objects";
+
+/**
+ * Order aggregate root managing order lifecycle
+ *
+ * Invariants:
+ * - Order must have at least one item: `items.length > 0`
+ * - Total must equal sum of item subtotals: `totalAmount == sum(items.subtotal)`
+ */
+export class Order extends AggregateRoot {
+  /** Unique order identifier */
+  id!: OrderId;
+
+  /** Customer who placed the order */
+  customer_id!: CustomerId;
+
+  /** Order line items */
+  items!: OrderItem[];
+
+  /** Current order status */
+  status!: OrderStatus;
+
+  /** Delivery address */
+  shipping_address!: Address;
+
+  /** Order total */
+  total_amount!: Money;
+
+  created_at!: string;
+  updated_at?: string | null;
+
+  onOrderCreated(event: OrderCreated): void {
+    this.id = event.aggregate_id;
+    this.customer_id = event.customer_id;
+    this.items = event.items;
+    this.status = OrderStatus.Created;
+    this.shipping_address = event.shipping_address;
+    this.total_amount = event.total_amount;
+    this.created_at = event.created_at;
+  }
+
+  onOrderPaid(event: OrderPaid): void {
+    this.id = event.aggregate_id;
+    this.status = OrderStatus.Paid;
+  }
+
+  onOrderShipped(event: OrderShipped): void {
+    this.id = event.aggregate_id;
+    this.status = OrderStatus.Shipped;
+  }
+
+  onOrderDelivered(event: OrderDelivered): void {
+    this.id = event.aggregate_id;
+    this.status = OrderStatus.Delivered;
+  }
+
+  onOrderCancelled(event: OrderCancelled): void {
+    this.id = event.aggregate_id;
+    this.status = OrderStatus.Cancelled;
+  }
+}
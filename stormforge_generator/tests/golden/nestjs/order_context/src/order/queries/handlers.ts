@@ -0,0 +1,47 @@
+import { IQueryHandler, QueryHandler } from "@nestjs/cqrs";
+import { PagedResult } from "../../common/api-response";
+import { Order } from "../domain/order.aggregate";
+import {
+  GetOrderQuery,
+  GetOrdersByStatusQuery,
+  ListOrdersQuery,
+} from "./queries";
+
+@QueryHandler(GetOrderQuery)
+export class GetOrderHandler
+  implements IQueryHandler<GetOrderQuery, Order | null>
+{
+  /** Get order by ID */
+  async execute(query: GetOrderQuery): Promise<Order | null> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return null;
+  }
+}
+
+@QueryHandler(ListOrdersQuery)
+export class ListOrdersHandler
+  implements IQueryHandler<ListOrdersQuery, PagedResult<Order>>
+{
+  /** List orders with filters */
+  async execute(query: ListOrdersQuery): Promise<PagedResult<Order>> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return { items: [], total: 0, page: 1, page_size: 20 };
+  }
+}
+
+@QueryHandler(GetOrdersByStatusQuery)
+export class GetOrdersByStatusHandler
+  implements IQueryHandler<GetOrdersByStatusQuery, Order[]>
+{
+  /** Get all orders with a specific status */
+  async execute(query: GetOrdersByStatusQuery): Promise<Order[]> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return [];
+  }
+}
+
+export const QueryHandlers = [
+  GetOrderHandler,
+  ListOrdersHandler,
+  GetOrdersByStatusHandler,
+];
@@ -0,0 +1,27 @@
+import { ICommand } from "@nestjs/cqrs";
+import {
+  CancelOrderDto,
+  ConfirmPaymentDto,
+  CreateOrderDto,
+  ShipOrderDto,
+} from "../dto/commands.dto";
+
+/** Create a new order */
+export class CreateOrderCommand implements ICommand {
+  constructor(readonly payload: CreateOrderDto) {}
+}
+
+/** Confirm order payment */
+export class ConfirmPaymentCommand implements ICommand {
+  constructor(readonly payload: ConfirmPaymentDto) {}
+}
+
+/** Ship the order */
+export class ShipOrderCommand implements ICommand {
+  constructor(readonly payload: ShipOrderDto) {}
+}
+
+/** Cancel the order */
+export class CancelOrderCommand implements ICommand {
+  constructor(readonly payload: CancelOrderDto) {}
+}
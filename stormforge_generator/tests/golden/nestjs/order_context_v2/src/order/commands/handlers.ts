@@ -0,0 +1,197 @@
+import { CommandHandler, ICommandHandler } from "@nestjs/cqrs";
+import { randomUUID } from "crypto";
+import { ApiError } from "../../common/api-error";
+import { Order } from "../domain/order.aggregate";
+import {
+  DomainEvent,
+  OrderCancelled,
+  OrderCreated,
+  OrderPaid,
+  OrderShipped,
+} from "../domain/events";
+import { EventStore } from "../infrastructure/event-store";
+import {
+  CancelOrderCommand,
+  ConfirmPaymentCommand,
+  CreateOrderCommand,
+  ShipOrderCommand,
+} from "./commands";
+
+@CommandHandler(CreateOrderCommand)
+export class CreateOrderHandler implements ICommandHandler<CreateOrderCommand> {
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Create a new order
+   *
+   * Produces `OrderCreated`.
+   *
+   * Validation:
+   * - Order must have at least one item
+   * - Order cannot have more than 50 items
+   */
+  async execute(command: CreateOrderCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = randomUUID();
+    const history = await this.eventStore.loadEvents(aggregateId, "Order");
+    const order = new Order();
+    order.loadFromHistory(history);
+    // TODO: enforce `items.length > 0`
+    // TODO: enforce `items.length <= 50`
+    const events: DomainEvent[] = [
+      new OrderCreated({
+        aggregate_id: aggregateId,
+        order_id: aggregateId,
+        customer_id: payload.customer_id,
+        items: payload.items,
+        shipping_address: payload.shipping_address,
+        // TODO: compute `sum(items.subtotal)`
+        total_amount: undefined!,
+        created_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => order.apply(event));
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Order",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(ConfirmPaymentCommand)
+export class ConfirmPaymentHandler
+  implements ICommandHandler<ConfirmPaymentCommand>
+{
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Confirm order payment
+   *
+   * Produces `OrderPaid`.
+   *
+   * Preconditions:
+   * - Can only confirm payment for orders in CREATED status
+   */
+  async execute(command: ConfirmPaymentCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = String(payload.order_id);
+    const history = await this.eventStore.loadEvents(aggregateId, "Order");
+    if (history.length === 0) {
+      throw new ApiError("NOT_FOUND", `Order ${aggregateId} not found`);
+    }
+    const order = new Order();
+    order.loadFromHistory(history);
+    // TODO: enforce `order.status == CREATED`
+    const events: DomainEvent[] = [
+      new OrderPaid({
+        aggregate_id: aggregateId,
+        order_id: payload.order_id,
+        payment_id: payload.payment_id,
+        // TODO: compute `order.totalAmount`
+        paid_amount: undefined!,
+        paid_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => order.apply(event));
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Order",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(ShipOrderCommand)
+export class ShipOrderHandler implements ICommandHandler<ShipOrderCommand> {
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Ship the order
+   *
+   * Produces `OrderShipped`.
+   *
+   * Preconditions:
+   * - Can only ship orders that have been paid
+   */
+  async execute(command: ShipOrderCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = String(payload.order_id);
+    const history = await this.eventStore.loadEvents(aggregateId, "Order");
+    if (history.length === 0) {
+      throw new ApiError("NOT_FOUND", `Order ${aggregateId} not found`);
+    }
+    const order = new Order();
+    order.loadFromHistory(history);
+    // TODO: enforce `order.status == PAID`
+    const events: DomainEvent[] = [
+      new OrderShipped({
+        aggregate_id: aggregateId,
+        order_id: payload.order_id,
+        tracking_number: payload.tracking_number,
+        carrier: payload.carrier,
+        shipped_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => order.apply(event));
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Order",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+@CommandHandler(CancelOrderCommand)
+export class CancelOrderHandler implements ICommandHandler<CancelOrderCommand> {
+  constructor(private readonly eventStore: EventStore) {}
+
+  /**
+   * Cancel the order
+   *
+   * Produces `OrderCancelled`.
+   *
+   * Preconditions:
+   * - Can only cancel orders that have not been shipped
+   */
+  async execute(command: CancelOrderCommand): Promise<string> {
+    const { payload } = command;
+    const aggregateId = String(payload.order_id);
+    const history = await this.eventStore.loadEvents(aggregateId, "Order");
+    if (history.length === 0) {
+      throw new ApiError("NOT_FOUND", `Order ${aggregateId} not found`);
+    }
+    const order = new Order();
+    order.loadFromHistory(history);
+    // TODO: enforce `order.status in [CREATED, PAID]`
+    const events: DomainEvent[] = [
+      new OrderCancelled({
+        aggregate_id: aggregateId,
+        order_id: payload.order_id,
+        reason: payload.reason,
+        cancelled_at: new Date().toISOString(),
+      }),
+    ];
+    events.forEach((event) => order.apply(event));
+    await this.eventStore.appendEvents(
+      aggregateId,
+      "Order",
+      events,
+      history.length,
+    );
+    return "Command executed successfully";
+  }
+}
+
+export const CommandHandlers = [
+  CreateOrderHandler,
+  ConfirmPaymentHandler,
+  ShipOrderHandler,
+  CancelOrderHandler,
+];
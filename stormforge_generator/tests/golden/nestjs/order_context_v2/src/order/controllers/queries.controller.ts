@@ -0,0 +1,28 @@
+import { Controller, Get, Query } from "@nestjs/common";
+import { QueryBus } from "@nestjs/cqrs";
+import { ApiResponse, PagedResult } from "../../common/api-response";
+import { GetOrderDto, ListOrdersDto } from "../dto/queries.dto";
+import { GetOrderQuery, ListOrdersQuery } from "../queries/queries";
+
+@Controller()
+export class QueriesController {
+  constructor(private readonly queryBus: QueryBus) {}
+
+  /** Get order by ID */
+  @Get("get-order")
+  async getOrder(@Query() params: GetOrderDto): Promise<ApiResponse<unknown>> {
+    return {
+      data: await this.queryBus.execute(new GetOrderQuery(params)),
+    };
+  }
+
+  /** List orders with filters */
+  @Get("list-orders")
+  async listOrders(
+    @Query() params: ListOrdersDto,
+  ): Promise<ApiResponse<PagedResult<unknown>>> {
+    return {
+      data: await this.queryBus.execute(new ListOrdersQuery(params)),
+    };
+  }
+}
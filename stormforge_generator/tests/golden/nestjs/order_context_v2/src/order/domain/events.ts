@@ -0,0 +1,115 @@
+import { IEvent } from "@nestjs/cqrs";
+import { randomUUID } from "crypto";
+import {
+  Address,
+  CustomerId,
+  Money,
+  OrderId,
+  OrderItem,
+} from "./value-objects";
+
+/** Fields of an event given when raising it */
+export type EventPayload<E> = Omit<E, "type" | "event_id" | "occurred_at">;
+
+/** Emitted when a new order is created */
+export class OrderCreated implements IEvent {
+  readonly type = "OrderCreated" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly order_id!: OrderId;
+  readonly customer_id!: CustomerId;
+  readonly items!: OrderItem[];
+  readonly shipping_address!: Address;
+  readonly total_amount!: Money;
+  readonly created_at!: string;
+
+  constructor(payload: EventPayload<OrderCreated>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Emitted when an order payment is confirmed */
+export class OrderPaid implements IEvent {
+  readonly type = "OrderPaid" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly order_id!: OrderId;
+  readonly payment_id!: string;
+  readonly paid_amount!: Money;
+  readonly paid_at!: string;
+
+  constructor(payload: EventPayload<OrderPaid>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Emitted when an order is shipped */
+export class OrderShipped implements IEvent {
+  readonly type = "OrderShipped" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly order_id!: OrderId;
+  readonly tracking_number!: string;
+  readonly carrier!: string;
+  readonly shipped_at!: string;
+
+  constructor(payload: EventPayload<OrderShipped>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Emitted when an order is delivered */
+export class OrderDelivered implements IEvent {
+  readonly type = "OrderDelivered" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly order_id!: OrderId;
+  readonly delivered_at!: string;
+  readonly signed_by!: string;
+
+  constructor(payload: EventPayload<OrderDelivered>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Emitted when an order is cancelled */
+export class OrderCancelled implements IEvent {
+  readonly type = "OrderCancelled" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly order_id!: OrderId;
+  readonly reason!: string;
+  readonly cancelled_at!: string;
+
+  constructor(payload: EventPayload<OrderCancelled>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Domain event of this bounded context, tagged by `type` */
+export type DomainEvent =
+  | OrderCreated
+  | OrderPaid
+  | OrderShipped
+  | OrderDelivered
+  | OrderCancelled;
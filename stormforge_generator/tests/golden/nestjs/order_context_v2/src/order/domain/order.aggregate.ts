@@ -0,0 +1,34 @@
+import { AggregateRoot } from "@nestjs/cqrs";
+import {
+  OrderCancelled,
+  OrderCreated,
+  OrderDelivered,
+  OrderPaid,
+  OrderShipped,
+} from "./events";
+import { OrderId } from "./value-objects";
+
+/** Order aggregate root managing order lifecycle */
+export class Order extends AggregateRoot {
+  id!: OrderId;
+
+  onOrderCreated(event: OrderCreated): void {
+    this.id = event.aggregate_id;
+  }
+
+  onOrderPaid(event: OrderPaid): void {
+    this.id = event.aggregate_id;
+  }
+
+  onOrderShipped(event: OrderShipped): void {
+    this.id = event.aggregate_id;
+  }
+
+  onOrderDelivered(event: OrderDelivered): void {
+    this.id = event.aggregate_id;
+  }
+
+  onOrderCancelled(event: OrderCancelled): void {
+    this.id = event.aggregate_id;
+  }
+}
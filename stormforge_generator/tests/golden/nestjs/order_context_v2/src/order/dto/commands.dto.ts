@@ -0,0 +1,64 @@
+import { Type } from "class-transformer";
+import {
+  ArrayMaxSize,
+  ArrayMinSize,
+  IsArray,
+  IsString,
+  ValidateNested,
+} from "class-validator";
+import {
+  Address,
+  CustomerId,
+  OrderId,
+  OrderItem,
+} from "../domain/value-objects";
+
+/** Create a new order */
+export class CreateOrderDto {
+  /** Customer placing the order */
+  @IsString()
+  customer_id!: CustomerId;
+
+  /** Items to order */
+  @IsArray()
+  @ArrayMinSize(1)
+  @ArrayMaxSize(50)
+  @ValidateNested({ each: true })
+  @Type(() => OrderItem)
+  items!: OrderItem[];
+
+  /** Delivery address */
+  @ValidateNested()
+  @Type(() => Address)
+  shipping_address!: Address;
+}
+
+/** Confirm order payment */
+export class ConfirmPaymentDto {
+  @IsString()
+  order_id!: OrderId;
+
+  @IsString()
+  payment_id!: string;
+}
+
+/** Ship the order */
+export class ShipOrderDto {
+  @IsString()
+  order_id!: OrderId;
+
+  @IsString()
+  tracking_number!: string;
+
+  @IsString()
+  carrier!: string;
+}
+
+/** Cancel the order */
+export class CancelOrderDto {
+  @IsString()
+  order_id!: OrderId;
+
+  @IsString()
+  reason!: string;
+}
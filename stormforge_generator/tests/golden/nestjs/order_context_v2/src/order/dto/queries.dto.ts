@@ -0,0 +1,49 @@
+import { Type } from "class-transformer";
+import {
+  IsEnum,
+  IsInt,
+  IsOptional,
+  IsRFC3339,
+  IsString,
+  Max,
+  Min,
+} from "class-validator";
+import { CustomerId, OrderId, OrderStatus } from "../domain/value-objects";
+
+/** Query string of `GetOrder` */
+export class GetOrderDto {
+  @IsString()
+  order_id!: OrderId;
+}
+
+/** Query string of `ListOrders` */
+export class ListOrdersDto {
+  @IsOptional()
+  @IsString()
+  customer_id?: CustomerId;
+
+  @IsOptional()
+  @IsEnum(OrderStatus)
+  status?: OrderStatus;
+
+  @IsOptional()
+  @IsRFC3339()
+  from_date?: string;
+
+  @IsOptional()
+  @IsRFC3339()
+  to_date?: string;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsInt()
+  @Min(1)
+  page: number = 1;
+
+  @IsOptional()
+  @Type(() => Number)
+  @IsInt()
+  @Min(1)
+  @Max(100)
+  page_size: number = 20;
+}
@@ -0,0 +1,25 @@
+import { IQueryHandler, QueryHandler } from "@nestjs/cqrs";
+import { PagedResult } from "../../common/api-response";
+import { GetOrderQuery, ListOrdersQuery } from "./queries";
+
+@QueryHandler(GetOrderQuery)
+export class GetOrderHandler implements IQueryHandler<GetOrderQuery, unknown> {
+  /** Get order by ID */
+  async execute(query: GetOrderQuery): Promise<unknown> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return null;
+  }
+}
+
+@QueryHandler(ListOrdersQuery)
+export class ListOrdersHandler
+  implements IQueryHandler<ListOrdersQuery, PagedResult<unknown>>
+{
+  /** List orders with filters */
+  async execute(query: ListOrdersQuery): Promise<PagedResult<unknown>> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return { items: [], total: 0, page: 1, page_size: 20 };
+  }
+}
+
+export const QueryHandlers = [GetOrderHandler, ListOrdersHandler];
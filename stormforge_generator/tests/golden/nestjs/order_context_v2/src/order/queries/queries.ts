@@ -0,0 +1,12 @@
+import { IQuery } from "@nestjs/cqrs";
+import { GetOrderDto, ListOrdersDto } from "../dto/queries.dto";
+
+/** Get order by ID */
+export class GetOrderQuery implements IQuery {
+  constructor(readonly params: GetOrderDto) {}
+}
+
+/** List orders with filters */
+export class ListOrdersQuery implements IQuery {
+  constructor(readonly params: ListOrdersDto) {}
+}
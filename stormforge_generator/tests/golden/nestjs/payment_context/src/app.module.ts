@@ -0,0 +1,7 @@
+import { Module } from "@nestjs/common";
+import { PaymentModule } from "./payment/payment.module";
+
+@Module({
+  imports: [PaymentModule],
+})
+export class AppModule {}
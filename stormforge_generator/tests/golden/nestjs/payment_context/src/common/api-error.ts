@@ -0,0 +1,19 @@
+import { HttpException, HttpStatus } from "@nestjs/common";
+
+const STATUS_CODES: Record<string, HttpStatus> = {
+  VALIDATION_ERROR: HttpStatus.BAD_REQUEST,
+  NOT_FOUND: HttpStatus.NOT_FOUND,
+};
+
+/** Error answered with `{ code, message }`, such as `NOT_FOUND` */
+export class ApiError extends HttpException {
+  constructor(
+    readonly code: string,
+    message: string,
+  ) {
+    super(
+      { code, message },
+      STATUS_CODES[code] ?? HttpStatus.INTERNAL_SERVER_ERROR,
+    );
+  }
+}
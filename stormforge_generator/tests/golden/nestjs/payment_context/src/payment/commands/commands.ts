@@ -0,0 +1,27 @@
+import { ICommand } from "@nestjs/cqrs";
+import {
+  CompletePaymentDto,
+  FailPaymentDto,
+  InitiatePaymentDto,
+  RefundPaymentDto,
+} from "../dto/commands.dto";
+
+/** Initiate a payment for an order */
+export class InitiatePaymentCommand implements ICommand {
+  constructor(readonly payload: InitiatePaymentDto) {}
+}
+
+/** Mark payment as completed */
+export class CompletePaymentCommand implements ICommand {
+  constructor(readonly payload: CompletePaymentDto) {}
+}
+
+/** Mark payment as failed */
+export class FailPaymentCommand implements ICommand {
+  constructor(readonly payload: FailPaymentDto) {}
+}
+
+/** Refund a completed payment */
+export class RefundPaymentCommand implements ICommand {
+  constructor(readonly payload: RefundPaymentDto) {}
+}
@@ -0,0 +1,90 @@
+import { IEvent } from "@nestjs/cqrs";
+import { randomUUID } from "crypto";
+import { Money, OrderId, PaymentId, PaymentMethod } from "./value-objects";
+
+/** Fields of an event given when raising it */
+export type EventPayload<E> = Omit<E, "type" | "event_id" | "occurred_at">;
+
+/** Payment has been initiated */
+export class PaymentInitiated implements IEvent {
+  readonly type = "PaymentInitiated" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly payment_id!: PaymentId;
+  readonly order_id!: OrderId;
+  readonly amount!: Money;
+  readonly method!: PaymentMethod;
+  readonly created_at!: string;
+
+  constructor(payload: EventPayload<PaymentInitiated>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Payment completed successfully */
+export class PaymentCompleted implements IEvent {
+  readonly type = "PaymentCompleted" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly payment_id!: PaymentId;
+  readonly order_id!: OrderId;
+  readonly transaction_id!: string;
+  readonly completed_at!: string;
+
+  constructor(payload: EventPayload<PaymentCompleted>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Payment has failed */
+export class PaymentFailed implements IEvent {
+  readonly type = "PaymentFailed" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly payment_id!: PaymentId;
+  readonly order_id!: OrderId;
+  readonly reason!: string;
+  readonly failed_at!: string;
+
+  constructor(payload: EventPayload<PaymentFailed>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Payment has been refunded */
+export class PaymentRefunded implements IEvent {
+  readonly type = "PaymentRefunded" as const;
+  /** Unique event identifier */
+  readonly event_id: string = randomUUID();
+  /** Timestamp when the event occurred, in RFC 3339 */
+  readonly occurred_at: string = new Date().toISOString();
+  /** ID of the aggregate that produced this event */
+  readonly aggregate_id!: string;
+  readonly payment_id!: PaymentId;
+  readonly order_id!: OrderId;
+  readonly refund_amount!: Money;
+  readonly refunded_at!: string;
+
+  constructor(payload: EventPayload<PaymentRefunded>) {
+    Object.assign(this, payload);
+  }
+}
+
+/** Domain event of this bounded context, tagged by `type` */
+export type DomainEvent =
+  | PaymentInitiated
+  | PaymentCompleted
+  | PaymentFailed
+  | PaymentRefunded;
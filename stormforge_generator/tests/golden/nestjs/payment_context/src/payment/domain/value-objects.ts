@@ -0,0 +1,40 @@
+import { IsDecimal, IsOptional, IsString } from "class-validator";
+import { MinDecimal } from "../../common/validation";
+
+export type PaymentId = string;
+
+export type OrderId = string;
+
+export class Money {
+  @IsDecimal()
+  @MinDecimal(0)
+  amount!: string;
+
+  @IsOptional()
+  @IsString()
+  currency: string = "CNY";
+}
+
+export enum PaymentMethod {
+  /** Alipay payment */
+  Alipay = "Alipay",
+  /** WeChat Pay */
+  WechatPay = "WechatPay",
+  /** Credit card payment */
+  CreditCard = "CreditCard",
+  /** Bank transfer */
+  BankTransfer = "BankTransfer",
+}
+
+export enum PaymentStatus {
+  /** Payment is pending */
+  Pending = "Pending",
+  /** Payment is being processed */
+  Processing = "Processing",
+  /** Payment completed successfully */
+  Completed = "Completed",
+  /** Payment failed */
+  Failed = "Failed",
+  /** Payment has been refunded */
+  Refunded = "Refunded",
+}
@@ -0,0 +1,14 @@
+import { IsString } from "class-validator";
+import { OrderId, PaymentId } from "../domain/value-objects";
+
+/** Query string of `GetPayment` */
+export class GetPaymentDto {
+  @IsString()
+  payment_id!: PaymentId;
+}
+
+/** Query string of `GetPaymentByOrder` */
+export class GetPaymentByOrderDto {
+  @IsString()
+  order_id!: OrderId;
+}
@@ -0,0 +1,27 @@
+import { IQueryHandler, QueryHandler } from "@nestjs/cqrs";
+import { Payment } from "../domain/payment.aggregate";
+import { GetPaymentByOrderQuery, GetPaymentQuery } from "./queries";
+
+@QueryHandler(GetPaymentQuery)
+export class GetPaymentHandler
+  implements IQueryHandler<GetPaymentQuery, Payment | null>
+{
+  /** Get payment by ID */
+  async execute(query: GetPaymentQuery): Promise<Payment | null> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return null;
+  }
+}
+
+@QueryHandler(GetPaymentByOrderQuery)
+export class GetPaymentByOrderHandler
+  implements IQueryHandler<GetPaymentByOrderQuery, Payment | null>
+{
+  /** Get payment for an order */
+  async execute(query: GetPaymentByOrderQuery): Promise<Payment | null> {
+    // TODO: Read the result from a read model, or inject the EventStore to replay events
+    return null;
+  }
+}
+
+export const QueryHandlers = [GetPaymentHandler, GetPaymentByOrderHandler];
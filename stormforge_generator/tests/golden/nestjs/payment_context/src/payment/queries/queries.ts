@@ -0,0 +1,12 @@
+import { IQuery } from "@nestjs/cqrs";
+import { GetPaymentByOrderDto, GetPaymentDto } from "../dto/queries.dto";
+
+/** Get payment by ID */
+export class GetPaymentQuery implements IQuery {
+  constructor(readonly params: GetPaymentDto) {}
+}
+
+/** Get payment for an order */
+export class GetPaymentByOrderQuery implements IQuery {
+  constructor(readonly params: GetPaymentByOrderDto) {}
+}
@@ -0,0 +1,176 @@
+//! GraphQL API of the Order context
+//!
+//! Queries and mutations delegate to the same handlers as the HTTP routes,
+//! and the `events` subscription streams the domain events appended to the
+//! event store. `schema.graphql` holds the schema in SDL.
+
+use async_graphql::http::GraphiQLSource;
+use async_graphql::{ErrorExtensions, Object, Schema};
+use async_graphql_axum::{GraphQL, GraphQLSubscription};
+use axum::{
+    response::{Html, IntoResponse},
+    routing::get, Router,
+};
+use crate::api::routes::*;
+use crate::domain::{commands::*, entities::*, events::*};
+
+/// Schema of the GraphQL API
+pub type ApiSchema = Schema<QueryRoot, MutationRoot, SubscriptionRoot>;
+
+/// Build the schema over the state shared with the HTTP API
+pub fn schema(state: AppState) -> ApiSchema {
+    Schema::build(QueryRoot, MutationRoot, SubscriptionRoot).data(state).finish()
+}
+
+/// Router serving GraphQL and GraphiQL at `/graphql`, and subscriptions at `/graphql/ws`
+pub fn router(state: AppState) -> Router {
+    let schema = schema(state);
+    Router::new()
+        .route("/graphql", get(graphiql).post_service(GraphQL::new(schema.clone())))
+        .route_service("/graphql/ws", GraphQLSubscription::new(schema))
+}
+
+async fn graphiql() -> impl IntoResponse {
+    Html(
+        GraphiQLSource::build()
+            .endpoint("/graphql")
+            .subscription_endpoint("/graphql/ws")
+            .finish(),
+    )
+}
+
+/// GraphQL error with the API error code as `code` extension
+fn graphql_error(error: ApiError) -> async_graphql::Error {
+    let ApiError { code, message } = error;
+    async_graphql::Error::new(message)
+        .extend_with(|_, extensions| extensions.set("code", code))
+}
+
+/// Queries as query fields
+pub struct QueryRoot;
+
+#[Object(name = "Query")]
+impl QueryRoot {
+    /// Get order by ID
+    async fn get_order(
+        &self,
+        #[graphql(name = "orderId")]
+        _order_id: OrderId,
+    ) -> async_graphql::Result<String> {
+        execute_get_order().await.map_err(graphql_error)
+    }
+    /// List orders with filters
+    async fn list_orders(
+        &self,
+        #[graphql(name = "customerId")]
+        _customer_id: Option<CustomerId>,
+        #[graphql(name = "status")]
+        _status: Option<OrderStatus>,
+        #[graphql(name = "fromDate")]
+        _from_date: Option<chrono::DateTime<chrono::Utc>>,
+        #[graphql(name = "toDate")]
+        _to_date: Option<chrono::DateTime<chrono::Utc>>,
+        #[graphql(name = "page")]
+        _page: Option<i64>,
+        #[graphql(name = "pageSize")]
+        _page_size: Option<i64>,
+    ) -> async_graphql::Result<String> {
+        execute_list_orders().await.map_err(graphql_error)
+    }
+    /// Get all orders with a specific status
+    async fn get_orders_by_status(
+        &self,
+        #[graphql(name = "status")]
+        _status: OrderStatus,
+    ) -> async_graphql::Result<String> {
+        execute_get_orders_by_status().await.map_err(graphql_error)
+    }
+}
+
+/// Commands as mutations
+pub struct MutationRoot;
+
+#[Object(name = "Mutation")]
+impl MutationRoot {
+    /// Create a new order
+    async fn create_order(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: CreateOrder,
+    ) -> async_graphql::Result<String> {
+        execute_create_order(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+    /// Confirm order payment
+    async fn confirm_payment(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: ConfirmPayment,
+    ) -> async_graphql::Result<String> {
+        execute_confirm_payment(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+    /// Ship the order
+    async fn ship_order(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: ShipOrder,
+    ) -> async_graphql::Result<String> {
+        execute_ship_order(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+    /// Mark order as delivered
+    async fn deliver_order(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: DeliverOrder,
+    ) -> async_graphql::Result<String> {
+        execute_deliver_order(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+    /// Cancel the order
+    async fn cancel_order(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        input: CancelOrder,
+    ) -> async_graphql::Result<String> {
+        execute_cancel_order(ctx.data_unchecked::<AppState>(), input)
+            .await
+            .map_err(graphql_error)
+    }
+}
+
+/// Interval at which subscriptions poll the event store
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Domain events as a subscription
+pub struct SubscriptionRoot;
+
+#[async_graphql::Subscription(name = "Subscription")]
+impl SubscriptionRoot {
+    /// Domain events appended to the event store, skipping the first `fromPosition`
+    async fn events(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        #[graphql(default)]
+        from_position: i64,
+    ) -> impl async_graphql::futures_util::Stream<
+        Item = async_graphql::Result<DomainEvent>,
+    > {
+        let event_store = ctx.data_unchecked::<AppState>().event_store.clone();
+        async_stream::try_stream! {
+            let mut position = from_position;
+            loop {
+                for payload in event_store.read_all_from(position).await? {
+                    position += 1;
+                    yield serde_json::from_value::<DomainEvent>(payload)?;
+                }
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,354 @@
+//! gRPC API serving the command and query handlers of the HTTP API
+//!
+//! Implements `OrderService` from `proto/order.proto`.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use rust_decimal::Decimal;
+use std::net::SocketAddr;
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+use crate::api::routes::*;
+use crate::domain::{commands::*, entities::*};
+
+/// Types and service generated from `proto/order.proto`
+pub mod proto {
+    tonic::include_proto!("acme.order");
+}
+
+use proto::order_service_server::{OrderService, OrderServiceServer};
+
+/// Serve the gRPC API on the given address, over the state shared with the HTTP API
+pub async fn serve(
+    addr: SocketAddr,
+    state: AppState,
+) -> Result<(), tonic::transport::Error> {
+    tonic::transport::Server::builder()
+        .add_service(OrderServiceServer::new(GrpcApi { state }))
+        .serve(addr)
+        .await
+}
+
+/// gRPC service delegating to the API's command and query handlers
+pub struct GrpcApi {
+    pub state: AppState,
+}
+
+#[tonic::async_trait]
+impl OrderService for GrpcApi {
+    /// Create a new order
+    async fn create_order(
+        &self,
+        request: Request<proto::CreateOrderRequest>,
+    ) -> Result<Response<proto::CreateOrderResponse>, Status> {
+        let command = CreateOrder::from_proto(request.into_inner())?;
+        let data = execute_create_order(&self.state, command).await?;
+        Ok(Response::new(proto::CreateOrderResponse { data }))
+    }
+    /// Confirm order payment
+    async fn confirm_payment(
+        &self,
+        request: Request<proto::ConfirmPaymentRequest>,
+    ) -> Result<Response<proto::ConfirmPaymentResponse>, Status> {
+        let command = ConfirmPayment::from_proto(request.into_inner())?;
+        let data = execute_confirm_payment(&self.state, command).await?;
+        Ok(
+            Response::new(proto::ConfirmPaymentResponse {
+                data,
+            }),
+        )
+    }
+    /// Ship the order
+    async fn ship_order(
+        &self,
+        request: Request<proto::ShipOrderRequest>,
+    ) -> Result<Response<proto::ShipOrderResponse>, Status> {
+        let command = ShipOrder::from_proto(request.into_inner())?;
+        let data = execute_ship_order(&self.state, command).await?;
+        Ok(Response::new(proto::ShipOrderResponse { data }))
+    }
+    /// Mark order as delivered
+    async fn deliver_order(
+        &self,
+        request: Request<proto::DeliverOrderRequest>,
+    ) -> Result<Response<proto::DeliverOrderResponse>, Status> {
+        let command = DeliverOrder::from_proto(request.into_inner())?;
+        let data = execute_deliver_order(&self.state, command).await?;
+        Ok(
+            Response::new(proto::DeliverOrderResponse {
+                data,
+            }),
+        )
+    }
+    /// Cancel the order
+    async fn cancel_order(
+        &self,
+        request: Request<proto::CancelOrderRequest>,
+    ) -> Result<Response<proto::CancelOrderResponse>, Status> {
+        let command = CancelOrder::from_proto(request.into_inner())?;
+        let data = execute_cancel_order(&self.state, command).await?;
+        Ok(Response::new(proto::CancelOrderResponse { data }))
+    }
+    /// Get order by ID
+    async fn get_order(
+        &self,
+        _request: Request<proto::GetOrderRequest>,
+    ) -> Result<Response<proto::GetOrderResponse>, Status> {
+        let data = execute_get_order().await?;
+        Ok(Response::new(proto::GetOrderResponse { data }))
+    }
+    /// List orders with filters
+    async fn list_orders(
+        &self,
+        _request: Request<proto::ListOrdersRequest>,
+    ) -> Result<Response<proto::ListOrdersResponse>, Status> {
+        let data = execute_list_orders().await?;
+        Ok(Response::new(proto::ListOrdersResponse { data }))
+    }
+    /// Get all orders with a specific status
+    async fn get_orders_by_status(
+        &self,
+        _request: Request<proto::GetOrdersByStatusRequest>,
+    ) -> Result<Response<proto::GetOrdersByStatusResponse>, Status> {
+        let data = execute_get_orders_by_status().await?;
+        Ok(
+            Response::new(proto::GetOrdersByStatusResponse {
+                data,
+            }),
+        )
+    }
+}
+
+impl From<ApiError> for Status {
+    fn from(error: ApiError) -> Self {
+        let code = match error.code.as_str() {
+            "VALIDATION_ERROR" => tonic::Code::InvalidArgument,
+            "NOT_FOUND" => tonic::Code::NotFound,
+            "PRECONDITION_FAILED" => tonic::Code::FailedPrecondition,
+            _ => tonic::Code::Internal,
+        };
+        Status::new(code, error.message)
+    }
+}
+
+/// Conversion of a decoded protobuf value into a domain value
+pub trait FromProto<T>: Sized {
+    fn from_proto(value: T) -> Result<Self, Status>;
+}
+
+/// Value of a message field the domain type requires
+fn required<T>(value: Option<T>, field: &str) -> Result<T, Status> {
+    value.ok_or_else(|| Status::invalid_argument(format!("Missing field: {}", field)))
+}
+
+fn invalid(field: &str, error: impl std::fmt::Display) -> Status {
+    Status::invalid_argument(format!("Invalid {}: {}", field, error))
+}
+
+impl FromProto<String> for String {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+impl FromProto<i64> for i64 {
+    fn from_proto(value: i64) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+impl FromProto<bool> for bool {
+    fn from_proto(value: bool) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+/// Decimals are strings to keep their precision
+impl FromProto<String> for Decimal {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("decimal", e))
+    }
+}
+
+impl FromProto<String> for Uuid {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("UUID", e))
+    }
+}
+
+/// Dates are `YYYY-MM-DD` strings
+impl FromProto<String> for NaiveDate {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("date", e))
+    }
+}
+
+impl FromProto<String> for NaiveTime {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("time", e))
+    }
+}
+
+impl FromProto<prost_types::Timestamp> for DateTime<Utc> {
+    fn from_proto(value: prost_types::Timestamp) -> Result<Self, Status> {
+        u32::try_from(value.nanos)
+            .ok()
+            .and_then(|nanos| DateTime::from_timestamp(value.seconds, nanos))
+            .ok_or_else(|| invalid("timestamp", format!("{:?}", value)))
+    }
+}
+
+impl<T: FromProto<P>, P> FromProto<Vec<P>> for Vec<T> {
+    fn from_proto(value: Vec<P>) -> Result<Self, Status> {
+        value.into_iter().map(T::from_proto).collect()
+    }
+}
+
+impl<T: FromProto<P>, P> FromProto<Option<P>> for Option<T> {
+    fn from_proto(value: Option<P>) -> Result<Self, Status> {
+        value.map(T::from_proto).transpose()
+    }
+}
+
+impl FromProto<proto::OrderId> for OrderId {
+    fn from_proto(value: proto::OrderId) -> Result<Self, Status> {
+        Ok(Self(FromProto::from_proto(value.value)?))
+    }
+}
+
+impl FromProto<proto::CustomerId> for CustomerId {
+    fn from_proto(value: proto::CustomerId) -> Result<Self, Status> {
+        Ok(Self(FromProto::from_proto(value.value)?))
+    }
+}
+
+impl FromProto<proto::ProductId> for ProductId {
+    fn from_proto(value: proto::ProductId) -> Result<Self, Status> {
+        Ok(Self(FromProto::from_proto(value.value)?))
+    }
+}
+
+impl FromProto<proto::OrderItem> for OrderItem {
+    fn from_proto(value: proto::OrderItem) -> Result<Self, Status> {
+        Ok(Self {
+            product_id: FromProto::from_proto(
+                required(value.product_id, "product_id")?,
+            )?,
+            product_name: FromProto::from_proto(value.product_name)?,
+            quantity: FromProto::from_proto(value.quantity)?,
+            unit_price: FromProto::from_proto(
+                required(value.unit_price, "unit_price")?,
+            )?,
+            subtotal: FromProto::from_proto(required(value.subtotal, "subtotal")?)?,
+        })
+    }
+}
+
+impl FromProto<proto::Money> for Money {
+    fn from_proto(value: proto::Money) -> Result<Self, Status> {
+        Ok(Self {
+            amount: FromProto::from_proto(value.amount)?,
+            currency: FromProto::from_proto(value.currency)?,
+        })
+    }
+}
+
+impl FromProto<proto::Address> for Address {
+    fn from_proto(value: proto::Address) -> Result<Self, Status> {
+        Ok(Self {
+            street: FromProto::from_proto(value.street)?,
+            city: FromProto::from_proto(value.city)?,
+            province: FromProto::from_proto(value.province)?,
+            postal_code: FromProto::from_proto(value.postal_code)?,
+            country: FromProto::from_proto(value.country)?,
+        })
+    }
+}
+
+impl FromProto<i32> for OrderStatus {
+    fn from_proto(value: i32) -> Result<Self, Status> {
+        match proto::OrderStatus::try_from(value) {
+            Ok(proto::OrderStatus::Created) => Ok(Self::Created),
+            Ok(proto::OrderStatus::Paid) => Ok(Self::Paid),
+            Ok(proto::OrderStatus::Processing) => Ok(Self::Processing),
+            Ok(proto::OrderStatus::Shipped) => Ok(Self::Shipped),
+            Ok(proto::OrderStatus::Delivered) => Ok(Self::Delivered),
+            Ok(proto::OrderStatus::Cancelled) => Ok(Self::Cancelled),
+            Ok(proto::OrderStatus::Refunded) => Ok(Self::Refunded),
+            _ => Err(Status::invalid_argument(format!("Invalid OrderStatus: {}", value))),
+        }
+    }
+}
+
+impl FromProto<proto::Order> for Order {
+    fn from_proto(value: proto::Order) -> Result<Self, Status> {
+        Ok(Self {
+            id: FromProto::from_proto(required(value.id, "id")?)?,
+            customer_id: FromProto::from_proto(
+                required(value.customer_id, "customer_id")?,
+            )?,
+            items: FromProto::from_proto(value.items)?,
+            status: FromProto::from_proto(value.status)?,
+            shipping_address: FromProto::from_proto(
+                required(value.shipping_address, "shipping_address")?,
+            )?,
+            total_amount: FromProto::from_proto(
+                required(value.total_amount, "total_amount")?,
+            )?,
+            created_at: FromProto::from_proto(
+                required(value.created_at, "created_at")?,
+            )?,
+            updated_at: FromProto::from_proto(value.updated_at)?,
+        })
+    }
+}
+
+impl FromProto<proto::CreateOrderRequest> for CreateOrder {
+    fn from_proto(value: proto::CreateOrderRequest) -> Result<Self, Status> {
+        Ok(Self {
+            customer_id: FromProto::from_proto(
+                required(value.customer_id, "customer_id")?,
+            )?,
+            items: FromProto::from_proto(value.items)?,
+            shipping_address: FromProto::from_proto(
+                required(value.shipping_address, "shipping_address")?,
+            )?,
+        })
+    }
+}
+
+impl FromProto<proto::ConfirmPaymentRequest> for ConfirmPayment {
+    fn from_proto(value: proto::ConfirmPaymentRequest) -> Result<Self, Status> {
+        Ok(Self {
+            order_id: FromProto::from_proto(required(value.order_id, "order_id")?)?,
+            payment_id: FromProto::from_proto(value.payment_id)?,
+        })
+    }
+}
+
+impl FromProto<proto::ShipOrderRequest> for ShipOrder {
+    fn from_proto(value: proto::ShipOrderRequest) -> Result<Self, Status> {
+        Ok(Self {
+            order_id: FromProto::from_proto(required(value.order_id, "order_id")?)?,
+            tracking_number: FromProto::from_proto(value.tracking_number)?,
+            carrier: FromProto::from_proto(value.carrier)?,
+        })
+    }
+}
+
+impl FromProto<proto::DeliverOrderRequest> for DeliverOrder {
+    fn from_proto(value: proto::DeliverOrderRequest) -> Result<Self, Status> {
+        Ok(Self {
+            order_id: FromProto::from_proto(required(value.order_id, "order_id")?)?,
+            signed_by: FromProto::from_proto(value.signed_by)?,
+        })
+    }
+}
+
+impl FromProto<proto::CancelOrderRequest> for CancelOrder {
+    fn from_proto(value: proto::CancelOrderRequest) -> Result<Self, Status> {
+        Ok(Self {
+            order_id: FromProto::from_proto(required(value.order_id, "order_id")?)?,
+            reason: FromProto::from_proto(value.reason)?,
+        })
+    }
+}
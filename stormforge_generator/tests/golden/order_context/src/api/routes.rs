@@ -0,0 +1,382 @@
+use axum::{
+    extract::State, http::{header, StatusCode},
+    response::{IntoResponse, Json},
+    routing::{get, post},
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::{ToSchema, OpenApi};
+use crate::domain::commands::*;
+use crate::domain::handlers::EventSourcedHandler;
+use crate::infrastructure::event_store::EventStore;
+
+/// API documentation
+#[derive(OpenApi)]
+#[openapi(
+    paths(),
+    components(schemas(ApiError, ApiResponseString)),
+    tags((name = "Order", description = "Order API endpoints"))
+)]
+pub struct ApiDoc;
+
+/// AsyncAPI document of the events published and consumed by this service
+pub const ASYNCAPI: &str = include_str!("../../asyncapi.json");
+
+/// OpenAPI document of the HTTP API
+///
+/// The component schemas of the AsyncAPI document are merged in, so
+/// both documents describe payloads with the same schemas.
+pub fn openapi() -> serde_json::Value {
+    let mut openapi = serde_json::to_value(ApiDoc::openapi())
+        .expect("OpenAPI document serializes to JSON");
+    let asyncapi: serde_json::Value = serde_json::from_str(ASYNCAPI)
+        .expect("AsyncAPI document is valid JSON");
+    if let (Some(schemas), Some(shared)) = (
+        openapi
+            .pointer_mut("/components/schemas")
+            .and_then(|schemas| schemas.as_object_mut()),
+        asyncapi.pointer("/components/schemas").and_then(|schemas| schemas.as_object()),
+    ) {
+        for (name, schema) in shared {
+            schemas.entry(name.clone()).or_insert_with(|| schema.clone());
+        }
+    }
+    openapi
+}
+
+/// Serve the AsyncAPI document
+pub async fn asyncapi() -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "application/json")], ASYNCAPI)
+}
+
+/// Standard API error response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiError {
+    pub code: String,
+    pub message: String,
+}
+
+impl ApiError {
+    /// HTTP status of the error code
+    pub fn status_code(&self) -> StatusCode {
+        match self.code.as_str() {
+            "VALIDATION_ERROR" => StatusCode::BAD_REQUEST,
+            "NOT_FOUND" => StatusCode::NOT_FOUND,
+            "PRECONDITION_FAILED" => StatusCode::CONFLICT,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<CommandError> for ApiError {
+    fn from(error: CommandError) -> Self {
+        let code = match &error {
+            CommandError::ValidationError(_) => "VALIDATION_ERROR",
+            CommandError::PreconditionFailed(_) => "PRECONDITION_FAILED",
+            CommandError::AggregateNotFound(_) => "NOT_FOUND",
+            CommandError::InternalError(_) => "INTERNAL_ERROR",
+        };
+        ApiError {
+            code: code.to_string(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Standard API success response (String variant)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseString {
+    pub data: String,
+}
+
+/// State shared by the HTTP, GraphQL and gRPC APIs
+#[derive(Clone)]
+pub struct AppState {
+    /// Event store the commands append to
+    pub event_store: Arc<dyn EventStore>,
+    /// Handler of the commands
+    pub commands: Arc<dyn CommandHandler>,
+}
+
+impl AppState {
+    /// State handling commands with the event-sourced handler over `event_store`
+    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+        Self {
+            commands: Arc::new(EventSourcedHandler::new(event_store.clone())),
+            event_store,
+        }
+    }
+}
+
+/// Create the API router
+pub fn create_router(state: AppState) -> Router {
+    Router::new()
+        .route("/create-order", post(create_order))
+        .route("/confirm-payment", post(confirm_payment))
+        .route("/ship-order", post(ship_order))
+        .route("/deliver-order", post(deliver_order))
+        .route("/cancel-order", post(cancel_order))
+        .route("/get-order", get(get_order))
+        .route("/list-orders", get(list_orders))
+        .route("/get-orders-by-status", get(get_orders_by_status))
+        .with_state(state)
+}
+
+/// Create a new order
+#[utoipa::path(
+    post,
+    path = "/create-order",
+    request_body = CreateOrder,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn create_order(
+    State(state): State<AppState>,
+    Json(payload): Json<CreateOrder>,
+) -> impl IntoResponse {
+    match execute_create_order(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `CreateOrder` command, shared by the HTTP and gRPC APIs
+pub async fn execute_create_order(
+    state: &AppState,
+    command: CreateOrder,
+) -> Result<String, ApiError> {
+    state.commands.handle_create_order(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Confirm order payment
+#[utoipa::path(
+    post,
+    path = "/confirm-payment",
+    request_body = ConfirmPayment,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn confirm_payment(
+    State(state): State<AppState>,
+    Json(payload): Json<ConfirmPayment>,
+) -> impl IntoResponse {
+    match execute_confirm_payment(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `ConfirmPayment` command, shared by the HTTP and gRPC APIs
+pub async fn execute_confirm_payment(
+    state: &AppState,
+    command: ConfirmPayment,
+) -> Result<String, ApiError> {
+    state.commands.handle_confirm_payment(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Ship the order
+#[utoipa::path(
+    post,
+    path = "/ship-order",
+    request_body = ShipOrder,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn ship_order(
+    State(state): State<AppState>,
+    Json(payload): Json<ShipOrder>,
+) -> impl IntoResponse {
+    match execute_ship_order(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `ShipOrder` command, shared by the HTTP and gRPC APIs
+pub async fn execute_ship_order(
+    state: &AppState,
+    command: ShipOrder,
+) -> Result<String, ApiError> {
+    state.commands.handle_ship_order(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Mark order as delivered
+#[utoipa::path(
+    post,
+    path = "/deliver-order",
+    request_body = DeliverOrder,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn deliver_order(
+    State(state): State<AppState>,
+    Json(payload): Json<DeliverOrder>,
+) -> impl IntoResponse {
+    match execute_deliver_order(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `DeliverOrder` command, shared by the HTTP and gRPC APIs
+pub async fn execute_deliver_order(
+    state: &AppState,
+    command: DeliverOrder,
+) -> Result<String, ApiError> {
+    state.commands.handle_deliver_order(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Cancel the order
+#[utoipa::path(
+    post,
+    path = "/cancel-order",
+    request_body = CancelOrder,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn cancel_order(
+    State(state): State<AppState>,
+    Json(payload): Json<CancelOrder>,
+) -> impl IntoResponse {
+    match execute_cancel_order(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `CancelOrder` command, shared by the HTTP and gRPC APIs
+pub async fn execute_cancel_order(
+    state: &AppState,
+    command: CancelOrder,
+) -> Result<String, ApiError> {
+    state.commands.handle_cancel_order(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Get order by ID
+#[utoipa::path(
+    get,
+    path = "/get-order",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn get_order() -> impl IntoResponse {
+    match execute_get_order().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `GetOrder` query, shared by the HTTP and gRPC APIs
+pub async fn execute_get_order() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
+
+/// List orders with filters
+#[utoipa::path(
+    get,
+    path = "/list-orders",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn list_orders() -> impl IntoResponse {
+    match execute_list_orders().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `ListOrders` query, shared by the HTTP and gRPC APIs
+pub async fn execute_list_orders() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
+
+/// Get all orders with a specific status
+#[utoipa::path(
+    get,
+    path = "/get-orders-by-status",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn get_orders_by_status() -> impl IntoResponse {
+    match execute_get_orders_by_status().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `GetOrdersByStatus` query, shared by the HTTP and gRPC APIs
+pub async fn execute_get_orders_by_status() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
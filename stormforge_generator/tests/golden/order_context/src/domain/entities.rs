@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "sqlx")]
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct OrderId(pub String);
+
+impl From<String> for OrderId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<OrderId> for String {
+    fn from(id: OrderId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(OrderId);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct CustomerId(pub String);
+
+impl From<String> for CustomerId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CustomerId> for String {
+    fn from(id: CustomerId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(CustomerId);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ProductId(pub String);
+
+impl From<String> for ProductId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ProductId> for String {
+    fn from(id: ProductId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(ProductId);
+
+/// A line item in an order
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::SimpleObject, async_graphql::InputObject),
+    graphql(input_name = "OrderItemInput")
+)]
+pub struct OrderItem {
+    pub product_id: ProductId,
+    pub product_name: String,
+    pub quantity: i64,
+    pub unit_price: Money,
+    pub subtotal: Money,
+}
+
+/// Monetary value with currency
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::SimpleObject, async_graphql::InputObject),
+    graphql(input_name = "MoneyInput")
+)]
+pub struct Money {
+    pub amount: rust_decimal::Decimal,
+    pub currency: String,
+}
+
+/// Physical address
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::SimpleObject, async_graphql::InputObject),
+    graphql(input_name = "AddressInput")
+)]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+    pub province: String,
+    pub postal_code: String,
+    pub country: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum OrderStatus {
+    /// Order has been created, awaiting payment
+    Created,
+    /// Payment has been received
+    Paid,
+    /// Order is being prepared
+    Processing,
+    /// Order has been shipped
+    Shipped,
+    /// Order has been delivered
+    Delivered,
+    /// Order has been cancelled
+    Cancelled,
+    /// Order has been refunded
+    Refunded,
+}
+
+/// Order aggregate root managing order lifecycle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(FromRow))]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::SimpleObject, async_graphql::InputObject),
+    graphql(input_name = "OrderInput")
+)]
+pub struct Order {
+    /// Unique order identifier
+    pub id: OrderId,
+    /// Customer who placed the order
+    pub customer_id: CustomerId,
+    /// Order line items
+    pub items: Vec<OrderItem>,
+    /// Current order status
+    pub status: OrderStatus,
+    /// Delivery address
+    pub shipping_address: Address,
+    /// Order total
+    pub total_amount: Money,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -0,0 +1,215 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::entities::*;
+
+/// Domain event enum containing all events in this bounded context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Union))]
+#[serde(tag = "type")]
+pub enum DomainEvent {
+    OrderCreated(OrderCreated),
+    OrderPaid(OrderPaid),
+    OrderShipped(OrderShipped),
+    OrderDelivered(OrderDelivered),
+    OrderCancelled(OrderCancelled),
+}
+
+/// Emitted when a new order is created
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct OrderCreated {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub order_id: OrderId,
+    pub customer_id: CustomerId,
+    pub items: Vec<OrderItem>,
+    pub shipping_address: Address,
+    pub total_amount: Money,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl OrderCreated {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        order_id: OrderId,
+        customer_id: CustomerId,
+        items: Vec<OrderItem>,
+        shipping_address: Address,
+        total_amount: Money,
+        created_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            order_id,
+            customer_id,
+            items,
+            shipping_address,
+            total_amount,
+            created_at,
+        }
+    }
+}
+
+/// Emitted when an order payment is confirmed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct OrderPaid {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub order_id: OrderId,
+    pub payment_id: String,
+    pub paid_amount: Money,
+    pub paid_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl OrderPaid {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        order_id: OrderId,
+        payment_id: String,
+        paid_amount: Money,
+        paid_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            order_id,
+            payment_id,
+            paid_amount,
+            paid_at,
+        }
+    }
+}
+
+/// Emitted when an order is shipped
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct OrderShipped {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub order_id: OrderId,
+    pub tracking_number: String,
+    pub carrier: String,
+    pub shipped_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl OrderShipped {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        order_id: OrderId,
+        tracking_number: String,
+        carrier: String,
+        shipped_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            order_id,
+            tracking_number,
+            carrier,
+            shipped_at,
+        }
+    }
+}
+
+/// Emitted when an order is delivered
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct OrderDelivered {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub order_id: OrderId,
+    pub delivered_at: chrono::DateTime<chrono::Utc>,
+    pub signed_by: String,
+}
+
+impl OrderDelivered {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        order_id: OrderId,
+        delivered_at: chrono::DateTime<chrono::Utc>,
+        signed_by: String,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            order_id,
+            delivered_at,
+            signed_by,
+        }
+    }
+}
+
+/// Emitted when an order is cancelled
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct OrderCancelled {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub order_id: OrderId,
+    pub reason: String,
+    pub cancelled_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl OrderCancelled {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        order_id: OrderId,
+        reason: String,
+        cancelled_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            order_id,
+            reason,
+            cancelled_at,
+        }
+    }
+}
+
+/// Event store metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventMetadata {
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub aggregate_type: String,
+    pub sequence_number: i64,
+    pub occurred_at: DateTime<Utc>,
+    pub stored_at: DateTime<Utc>,
+    pub correlation_id: Option<Uuid>,
+    pub causation_id: Option<Uuid>,
+}
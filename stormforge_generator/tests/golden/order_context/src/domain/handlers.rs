@@ -0,0 +1,334 @@
+//! Command handler backed by the event store
+//!
+//! A command is validated, then checked against the state of its
+//! aggregate folded from the stored events. A command carrying the
+//! aggregate's identifier acts on that aggregate, any other command
+//! creates one. Each event takes a field from the `computed` expression
+//! of the event property, else from the command field, the aggregate ID
+//! or the aggregate state of the same name; `DateTime` fields the
+//! command does not give are the time it is handled. The events must
+//! keep the aggregate's invariants, and are appended with their outbox
+//! entries at the version the state was loaded at, so a concurrent
+//! command on the same aggregate fails rather than being lost.
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+use crate::domain::commands::*;
+use crate::domain::events::DomainEvent;
+use crate::domain::{expressions, state};
+use crate::infrastructure::event_store::EventStore;
+
+/// Command handler appending the events of commands to an event store
+pub struct EventSourcedHandler {
+    event_store: Arc<dyn EventStore>,
+}
+
+impl EventSourcedHandler {
+    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+        Self { event_store }
+    }
+    /// State of an aggregate folded from its events, and its version
+    pub async fn load(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+    ) -> CommandResult<(Value, i64)> {
+        let events = self
+            .event_store
+            .load_events(aggregate_id, aggregate_type)
+            .await
+            .map_err(internal)?
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(internal)?;
+        let version = i64::try_from(events.len()).map_err(internal)?;
+        let state = state::load(aggregate_type, &events).unwrap_or(Value::Null);
+        Ok((state, version))
+    }
+    /// Append the events of a command at the version its aggregate was loaded at
+    async fn append(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+        events: Vec<DomainEvent>,
+        version: i64,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        self.event_store
+            .append_events(aggregate_id, aggregate_type, events.clone(), Some(version))
+            .await
+            .map_err(internal)?;
+        Ok(events)
+    }
+}
+
+fn internal(error: impl std::fmt::Display) -> CommandError {
+    CommandError::InternalError(error.to_string())
+}
+
+/// Aggregate ID held by a command field
+fn id(value: &Value) -> String {
+    match value {
+        Value::String(id) => id.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Event of a type built from its payload fields
+fn event(
+    event_type: &str,
+    aggregate_id: &str,
+    mut payload: Value,
+) -> CommandResult<DomainEvent> {
+    if let Some(fields) = payload.as_object_mut() {
+        fields.insert("type".to_string(), Value::from(event_type));
+        fields.insert("event_id".to_string(), Value::from(Uuid::new_v4().to_string()));
+        fields.insert("occurred_at".to_string(), expressions::now());
+        fields.insert("aggregate_id".to_string(), Value::from(aggregate_id));
+    }
+    serde_json::from_value(payload)
+        .map_err(|e| {
+            CommandError::InternalError(format!("Cannot build {}: {}", event_type, e))
+        })
+}
+
+/// Fold events into the state of an aggregate
+fn apply_events(
+    state: &mut Value,
+    events: &[DomainEvent],
+    apply: fn(&mut Value, &Value),
+) -> CommandResult<()> {
+    for event in events {
+        apply(state, &serde_json::to_value(event).map_err(internal)?);
+    }
+    Ok(())
+}
+
+/// Invariants of a `Order`
+///
+/// - `items.length > 0`: Order must have at least one item
+/// - `totalAmount == sum(items.subtotal)`: Total must equal sum of item subtotals
+fn check_order(scope: &Value) -> CommandResult<()> {
+    if !expressions::gt(
+        &expressions::len(&expressions::get(scope, "items")),
+        &serde_json::Value::from(0),
+    ) {
+        return Err(
+            CommandError::ValidationError(
+                "Order must have at least one item".to_string(),
+            ),
+        );
+    }
+    if !expressions::eq(
+        &expressions::get(scope, "total_amount"),
+        &expressions::sum(
+            &expressions::get(&expressions::get(scope, "items"), "subtotal"),
+        ),
+    ) {
+        return Err(
+            CommandError::ValidationError(
+                "Total must equal sum of item subtotals".to_string(),
+            ),
+        );
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl CommandHandler for EventSourcedHandler {
+    async fn handle_create_order(
+        &self,
+        command: CreateOrder,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = Uuid::new_v4().to_string();
+        let (mut state, version) = (Value::Null, 0);
+        let scope = &expressions::with(&command, "order", &state);
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "OrderCreated",
+                &aggregate_id,
+                expressions::object([
+                    ("order_id", Value::from(aggregate_id.as_str())),
+                    ("customer_id", expressions::get(&command, "customer_id")),
+                    ("items", expressions::get(&command, "items")),
+                    ("shipping_address", expressions::get(&command, "shipping_address")),
+                    (
+                        "total_amount",
+                        expressions::sum(
+                            &expressions::get(
+                                &expressions::get(scope, "items"),
+                                "subtotal",
+                            ),
+                        ),
+                    ),
+                    ("created_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_order)?;
+        check_order(&state)?;
+        self.append("Order", &aggregate_id, events, version).await
+    }
+    async fn handle_confirm_payment(
+        &self,
+        command: ConfirmPayment,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "order_id"));
+        let (mut state, version) = self.load("Order", &aggregate_id).await?;
+        if version == 0 {
+            return Err(CommandError::AggregateNotFound(aggregate_id));
+        }
+        let scope = &expressions::with(&command, "order", &state);
+        if !expressions::eq(
+            &expressions::get(&expressions::get(scope, "order"), "status"),
+            &serde_json::Value::from("Created"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Can only confirm payment for orders in CREATED status".to_string(),
+                ),
+            );
+        }
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "OrderPaid",
+                &aggregate_id,
+                expressions::object([
+                    ("order_id", expressions::get(&command, "order_id")),
+                    ("payment_id", expressions::get(&command, "payment_id")),
+                    (
+                        "paid_amount",
+                        expressions::get(
+                            &expressions::get(scope, "order"),
+                            "total_amount",
+                        ),
+                    ),
+                    ("paid_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_order)?;
+        check_order(&state)?;
+        self.append("Order", &aggregate_id, events, version).await
+    }
+    async fn handle_ship_order(
+        &self,
+        command: ShipOrder,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "order_id"));
+        let (mut state, version) = self.load("Order", &aggregate_id).await?;
+        if version == 0 {
+            return Err(CommandError::AggregateNotFound(aggregate_id));
+        }
+        let scope = &expressions::with(&command, "order", &state);
+        if !expressions::eq(
+            &expressions::get(&expressions::get(scope, "order"), "status"),
+            &serde_json::Value::from("Paid"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Can only ship orders that have been paid".to_string(),
+                ),
+            );
+        }
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "OrderShipped",
+                &aggregate_id,
+                expressions::object([
+                    ("order_id", expressions::get(&command, "order_id")),
+                    ("tracking_number", expressions::get(&command, "tracking_number")),
+                    ("carrier", expressions::get(&command, "carrier")),
+                    ("shipped_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_order)?;
+        check_order(&state)?;
+        self.append("Order", &aggregate_id, events, version).await
+    }
+    async fn handle_deliver_order(
+        &self,
+        command: DeliverOrder,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "order_id"));
+        let (mut state, version) = self.load("Order", &aggregate_id).await?;
+        if version == 0 {
+            return Err(CommandError::AggregateNotFound(aggregate_id));
+        }
+        let scope = &expressions::with(&command, "order", &state);
+        if !expressions::eq(
+            &expressions::get(&expressions::get(scope, "order"), "status"),
+            &serde_json::Value::from("Shipped"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Can only deliver orders that have been shipped".to_string(),
+                ),
+            );
+        }
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "OrderDelivered",
+                &aggregate_id,
+                expressions::object([
+                    ("order_id", expressions::get(&command, "order_id")),
+                    ("delivered_at", expressions::now()),
+                    ("signed_by", expressions::get(&command, "signed_by")),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_order)?;
+        check_order(&state)?;
+        self.append("Order", &aggregate_id, events, version).await
+    }
+    async fn handle_cancel_order(
+        &self,
+        command: CancelOrder,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "order_id"));
+        let (mut state, version) = self.load("Order", &aggregate_id).await?;
+        if version == 0 {
+            return Err(CommandError::AggregateNotFound(aggregate_id));
+        }
+        let scope = &expressions::with(&command, "order", &state);
+        if !expressions::contains(
+            &serde_json::Value::Array(
+                vec![serde_json::Value::from("Created"), serde_json::Value::from("Paid")],
+            ),
+            &expressions::get(&expressions::get(scope, "order"), "status"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Can only cancel orders that have not been shipped".to_string(),
+                ),
+            );
+        }
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "OrderCancelled",
+                &aggregate_id,
+                expressions::object([
+                    ("order_id", expressions::get(&command, "order_id")),
+                    ("reason", expressions::get(&command, "reason")),
+                    ("cancelled_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        apply_events(&mut state, &events, state::apply_order)?;
+        check_order(&state)?;
+        self.append("Order", &aggregate_id, events, version).await
+    }
+}
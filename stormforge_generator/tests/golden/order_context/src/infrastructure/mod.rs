@@ -0,0 +1,11 @@
+pub mod event_bus;
+pub mod event_store;
+pub mod outbox;
+#[cfg(feature = "sqlx")]
+pub mod sqlite_event_store;
+
+pub use event_bus::*;
+pub use event_store::*;
+pub use outbox::*;
+#[cfg(feature = "sqlx")]
+pub use sqlite_event_store::*;
@@ -0,0 +1,333 @@
+//! SQLite event store
+//!
+//! Events and their outbox entries are inserted in one transaction.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::{SqlitePool, SqlitePoolOptions, SqliteRow};
+use sqlx::Row;
+use uuid::Uuid;
+use crate::domain::events::DomainEvent;
+use crate::infrastructure::event_bus::IntegrationMessage;
+use crate::infrastructure::event_store::{check_version, EventStore};
+use crate::infrastructure::outbox::{OutboxEntry, OutboxStore};
+
+/// Tables for the event log and the outbox
+const SCHEMA: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS events (
+        position INTEGER PRIMARY KEY AUTOINCREMENT,
+        event_id TEXT NOT NULL UNIQUE,
+        aggregate_id TEXT NOT NULL,
+        aggregate_type TEXT NOT NULL,
+        sequence_number INTEGER NOT NULL,
+        event_type TEXT NOT NULL,
+        payload TEXT NOT NULL,
+        UNIQUE (aggregate_id, aggregate_type, sequence_number)
+    )",
+    "CREATE TABLE IF NOT EXISTS outbox (
+        position INTEGER PRIMARY KEY AUTOINCREMENT,
+        message_id TEXT NOT NULL UNIQUE,
+        context TEXT NOT NULL,
+        event_type TEXT NOT NULL,
+        payload TEXT NOT NULL,
+        attempts INTEGER NOT NULL DEFAULT 0,
+        last_error TEXT,
+        created_at TEXT NOT NULL,
+        published_at TEXT,
+        dead_lettered_at TEXT
+    )",
+];
+
+/// Event store and outbox persisted in SQLite
+#[derive(Clone)]
+pub struct SqliteEventStore {
+    pool: SqlitePool,
+}
+
+impl SqliteEventStore {
+    /// Connect to a database such as `sqlite://events.db?mode=rwc`
+    pub async fn connect(url: &str) -> Result<Self> {
+        let pool = SqlitePoolOptions::new().connect(url).await?;
+        Self::with_pool(pool).await
+    }
+    /// Open a private in-memory database (for tests)
+    pub async fn in_memory() -> Result<Self> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await?;
+        Self::with_pool(pool).await
+    }
+    /// Use an existing pool, creating the tables if needed
+    pub async fn with_pool(pool: SqlitePool) -> Result<Self> {
+        for statement in SCHEMA {
+            sqlx::query(statement).execute(&pool).await?;
+        }
+        let columns: Vec<String> = sqlx::query_scalar(
+                "SELECT name FROM pragma_table_info('outbox')",
+            )
+            .fetch_all(&pool)
+            .await?;
+        if !columns.iter().any(|column| column == "dead_lettered_at") {
+            sqlx::query("ALTER TABLE outbox ADD COLUMN dead_lettered_at TEXT")
+                .execute(&pool)
+                .await?;
+        }
+        Ok(Self { pool })
+    }
+    /// Connection pool, for sharing the database with other stores
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+}
+
+fn decode_events(payloads: Vec<String>) -> Result<Vec<DomainEvent>> {
+    payloads.iter().map(|payload| Ok(serde_json::from_str(payload)?)).collect()
+}
+
+fn decode_outbox_entry(row: SqliteRow) -> Result<OutboxEntry> {
+    let message_id: String = row.try_get("message_id")?;
+    let payload: String = row.try_get("payload")?;
+    let attempts: i64 = row.try_get("attempts")?;
+    let created_at: String = row.try_get("created_at")?;
+    let dead_lettered_at: Option<String> = row.try_get("dead_lettered_at")?;
+    Ok(OutboxEntry {
+        message: IntegrationMessage {
+            message_id: message_id.parse()?,
+            context: row.try_get("context")?,
+            event_type: row.try_get("event_type")?,
+            payload: serde_json::from_str(&payload)?,
+        },
+        attempts: u32::try_from(attempts)?,
+        last_error: row.try_get("last_error")?,
+        created_at: DateTime::parse_from_rfc3339(&created_at)?.with_timezone(&Utc),
+        published_at: None,
+        dead_lettered_at: dead_lettered_at
+            .map(|at| Ok::<
+                _,
+                anyhow::Error,
+            >(DateTime::parse_from_rfc3339(&at)?.with_timezone(&Utc)))
+            .transpose()?,
+    })
+}
+
+#[async_trait]
+impl EventStore for SqliteEventStore {
+    async fn append_messages(
+        &self,
+        aggregate_id: &str,
+        aggregate_type: &str,
+        messages: Vec<IntegrationMessage>,
+        expected_version: Option<i64>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        let current_version: i64 = sqlx::query_scalar(
+                "SELECT COUNT(*) FROM events WHERE aggregate_id = ? AND aggregate_type = ?",
+            )
+            .bind(aggregate_id)
+            .bind(aggregate_type)
+            .fetch_one(&mut *tx)
+            .await?;
+        check_version(expected_version, current_version)?;
+        let created_at = Utc::now().to_rfc3339();
+        for (sequence_number, message) in (current_version + 1..).zip(messages) {
+            let message_id = message.message_id.to_string();
+            let payload = serde_json::to_string(&message.payload)?;
+            sqlx::query(
+                    "INSERT INTO events (event_id, aggregate_id, aggregate_type, sequence_number, event_type, payload) VALUES (?, ?, ?, ?, ?, ?)",
+                )
+                .bind(&message_id)
+                .bind(aggregate_id)
+                .bind(aggregate_type)
+                .bind(sequence_number)
+                .bind(&message.event_type)
+                .bind(&payload)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query(
+                    "INSERT INTO outbox (message_id, context, event_type, payload, created_at) VALUES (?, ?, ?, ?, ?)",
+                )
+                .bind(&message_id)
+                .bind(&message.context)
+                .bind(&message.event_type)
+                .bind(&payload)
+                .bind(&created_at)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+    async fn version(&self, aggregate_id: &str, aggregate_type: &str) -> Result<i64> {
+        Ok(
+            sqlx::query_scalar(
+                    "SELECT COUNT(*) FROM events WHERE aggregate_id = ? AND aggregate_type = ?",
+                )
+                .bind(aggregate_id)
+                .bind(aggregate_type)
+                .fetch_one(&self.pool)
+                .await?,
+        )
+    }
+    async fn load_events(
+        &self,
+        aggregate_id: &str,
+        aggregate_type: &str,
+    ) -> Result<Vec<DomainEvent>> {
+        let payloads = sqlx::query_scalar(
+                "SELECT payload FROM events WHERE aggregate_id = ? AND aggregate_type = ? ORDER BY sequence_number",
+            )
+            .bind(aggregate_id)
+            .bind(aggregate_type)
+            .fetch_all(&self.pool)
+            .await?;
+        decode_events(payloads)
+    }
+    async fn get_all_events(&self) -> Result<Vec<DomainEvent>> {
+        let payloads = sqlx::query_scalar("SELECT payload FROM events ORDER BY position")
+            .fetch_all(&self.pool)
+            .await?;
+        decode_events(payloads)
+    }
+    async fn read_all_from(&self, position: i64) -> Result<Vec<serde_json::Value>> {
+        let payloads: Vec<String> = sqlx::query_scalar(
+                "SELECT payload FROM events ORDER BY position LIMIT -1 OFFSET ?",
+            )
+            .bind(position)
+            .fetch_all(&self.pool)
+            .await?;
+        payloads.iter().map(|payload| Ok(serde_json::from_str(payload)?)).collect()
+    }
+}
+
+#[async_trait]
+impl OutboxStore for SqliteEventStore {
+    async fn pending(&self, limit: usize) -> Result<Vec<OutboxEntry>> {
+        sqlx::query(
+                "SELECT message_id, context, event_type, payload, attempts, last_error, created_at, dead_lettered_at FROM outbox WHERE published_at IS NULL AND dead_lettered_at IS NULL ORDER BY position LIMIT ?",
+            )
+            .bind(i64::try_from(limit)?)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(decode_outbox_entry)
+            .collect()
+    }
+    async fn mark_published(&self, message_id: Uuid) -> Result<()> {
+        sqlx::query("UPDATE outbox SET published_at = ? WHERE message_id = ?")
+            .bind(Utc::now().to_rfc3339())
+            .bind(message_id.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+    async fn mark_failed(&self, message_id: Uuid, error: &str) -> Result<()> {
+        sqlx::query(
+                "UPDATE outbox SET attempts = attempts + 1, last_error = ? WHERE message_id = ?",
+            )
+            .bind(error)
+            .bind(message_id.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+    async fn mark_dead_lettered(&self, message_id: Uuid) -> Result<()> {
+        sqlx::query("UPDATE outbox SET dead_lettered_at = ? WHERE message_id = ?")
+            .bind(Utc::now().to_rfc3339())
+            .bind(message_id.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+    async fn dead_letters(&self, limit: usize) -> Result<Vec<OutboxEntry>> {
+        sqlx::query(
+                "SELECT message_id, context, event_type, payload, attempts, last_error, created_at, dead_lettered_at FROM outbox WHERE published_at IS NULL AND dead_lettered_at IS NOT NULL ORDER BY position LIMIT ?",
+            )
+            .bind(i64::try_from(limit)?)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(decode_outbox_entry)
+            .collect()
+    }
+    async fn replay(&self, message_id: Uuid) -> Result<()> {
+        sqlx::query(
+                "UPDATE outbox SET attempts = 0, dead_lettered_at = NULL WHERE message_id = ?",
+            )
+            .bind(message_id.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::outbox::conformance;
+
+    #[tokio::test]
+    async fn append_records_events_in_outbox() {
+        conformance::append_records_events_in_outbox(
+                SqliteEventStore::in_memory().await.unwrap(),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn conflicting_append_writes_nothing() {
+        conformance::conflicting_append_writes_nothing(
+                SqliteEventStore::in_memory().await.unwrap(),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn reads_events_from_position() {
+        conformance::reads_events_from_position(
+                SqliteEventStore::in_memory().await.unwrap(),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn relay_publishes_each_entry_once() {
+        conformance::relay_publishes_each_entry_once(
+                SqliteEventStore::in_memory().await.unwrap(),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn relay_retries_failed_publishes() {
+        conformance::relay_retries_failed_publishes(
+                SqliteEventStore::in_memory().await.unwrap(),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn relay_dead_letters_after_max_attempts() {
+        conformance::relay_dead_letters_after_max_attempts(
+                SqliteEventStore::in_memory().await.unwrap(),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn replayed_dead_letter_is_published() {
+        conformance::replayed_dead_letter_is_published(
+                SqliteEventStore::in_memory().await.unwrap(),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn redelivery_keeps_message_id() {
+        conformance::redelivery_keeps_message_id(
+                SqliteEventStore::in_memory().await.unwrap(),
+            )
+            .await;
+    }
+}
@@ -0,0 +1,161 @@
+//! Consumers for integration events from other bounded contexts
+
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+use crate::infrastructure::event_bus::{EventBus, IntegrationMessage};
+use crate::integration::{acl, contracts};
+
+/// Typed consumer for one subscribed integration event
+#[async_trait]
+pub trait IntegrationEventConsumer: Send + Sync {
+    /// Bounded context publishing the event
+    fn context(&self) -> &'static str;
+    /// Subscribed event type
+    fn event_type(&self) -> &'static str;
+    /// Handle a message matching this subscription
+    async fn consume(&self, message: &IntegrationMessage) -> Result<()>;
+}
+
+/// When payment is completed, confirm the order payment
+pub struct PaymentCompletedConsumer;
+
+#[async_trait]
+impl IntegrationEventConsumer for PaymentCompletedConsumer {
+    fn context(&self) -> &'static str {
+        "Payment"
+    }
+    fn event_type(&self) -> &'static str {
+        "PaymentCompleted"
+    }
+    async fn consume(&self, message: &IntegrationMessage) -> Result<()> {
+        let event: contracts::payment::PaymentCompleted = serde_json::from_value(
+            message.payload.clone(),
+        )?;
+        acl::handle_payment_completed(event).await
+    }
+}
+
+/// When stock is reserved, proceed with order processing
+pub struct StockReservedConsumer;
+
+#[async_trait]
+impl IntegrationEventConsumer for StockReservedConsumer {
+    fn context(&self) -> &'static str {
+        "Inventory"
+    }
+    fn event_type(&self) -> &'static str {
+        "StockReserved"
+    }
+    async fn consume(&self, message: &IntegrationMessage) -> Result<()> {
+        let event: contracts::inventory::StockReserved = serde_json::from_value(
+            message.payload.clone(),
+        )?;
+        acl::handle_stock_reserved(event).await
+    }
+}
+
+/// All consumers of this bounded context's subscriptions
+pub fn consumers() -> Vec<Box<dyn IntegrationEventConsumer>> {
+    vec![Box::new(PaymentCompletedConsumer), Box::new(StockReservedConsumer)]
+}
+
+/// Polls an event bus and dispatches subscribed messages to their consumers
+///
+/// Delivery is at-least-once: a failing message is retried on the
+/// next poll, and already processed message IDs are skipped.
+pub struct IntegrationEventListener {
+    bus: Arc<dyn EventBus>,
+    consumers: Vec<Box<dyn IntegrationEventConsumer>>,
+    offset: usize,
+    processed: HashSet<Uuid>,
+}
+
+impl IntegrationEventListener {
+    pub fn new(bus: Arc<dyn EventBus>) -> Self {
+        Self::with_consumers(bus, consumers())
+    }
+    /// Create a listener dispatching to the given consumers
+    pub fn with_consumers(
+        bus: Arc<dyn EventBus>,
+        consumers: Vec<Box<dyn IntegrationEventConsumer>>,
+    ) -> Self {
+        Self {
+            bus,
+            consumers,
+            offset: 0,
+            processed: HashSet::new(),
+        }
+    }
+    /// Process new messages, returning how many were consumed
+    pub async fn poll(&mut self) -> Result<usize> {
+        let messages = self.bus.read_from(self.offset).await?;
+        let mut consumed = 0;
+        for message in messages {
+            if !self.processed.contains(&message.message_id) {
+                for consumer in &self.consumers {
+                    if consumer.context() == message.context
+                        && consumer.event_type() == message.event_type
+                    {
+                        consumer.consume(&message).await?;
+                        consumed += 1;
+                    }
+                }
+                self.processed.insert(message.message_id);
+            }
+            self.offset += 1;
+        }
+        Ok(consumed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::event_bus::InMemoryEventBus;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingConsumer(Arc<AtomicUsize>);
+
+    #[async_trait]
+    impl IntegrationEventConsumer for CountingConsumer {
+        fn context(&self) -> &'static str {
+            "Upstream"
+        }
+        fn event_type(&self) -> &'static str {
+            "SomethingHappened"
+        }
+        async fn consume(&self, _message: &IntegrationMessage) -> Result<()> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn message(event_type: &str) -> IntegrationMessage {
+        IntegrationMessage {
+            message_id: Uuid::new_v4(),
+            context: "Upstream".to_string(),
+            event_type: event_type.to_string(),
+            payload: serde_json::json!({ "type": event_type }),
+        }
+    }
+
+    #[tokio::test]
+    async fn listener_dispatches_subscribed_messages_once() {
+        let bus = InMemoryEventBus::new();
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut listener = IntegrationEventListener::with_consumers(
+            Arc::new(bus.clone()),
+            vec![Box::new(CountingConsumer(count.clone()))],
+        );
+        let subscribed = message("SomethingHappened");
+        bus.publish(subscribed.clone()).await.unwrap();
+        bus.publish(message("SomethingElse")).await.unwrap();
+        bus.publish(subscribed).await.unwrap();
+        assert_eq!(listener.poll().await.unwrap(), 1);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert_eq!(listener.poll().await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,28 @@
+//! Contracts of the integration events this context subscribes to
+//!
+//! The publishing contexts are not part of this build, so payloads
+//! are kept as untyped JSON objects.
+
+/// Events published by the Payment context
+pub mod payment {
+    use serde::{Deserialize, Serialize};
+
+    /// Published by the Payment context, which is not part of this build
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct PaymentCompleted {
+        #[serde(flatten)]
+        pub data: serde_json::Map<String, serde_json::Value>,
+    }
+}
+
+/// Events published by the Inventory context
+pub mod inventory {
+    use serde::{Deserialize, Serialize};
+
+    /// Published by the Inventory context, which is not part of this build
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct StockReserved {
+        #[serde(flatten)]
+        pub data: serde_json::Map<String, serde_json::Value>,
+    }
+}
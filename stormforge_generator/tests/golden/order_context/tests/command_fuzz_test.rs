@@ -0,0 +1,122 @@
+//! Command validation fuzzed with payloads outside the validation rules
+
+mod support;
+
+use proptest::prelude::*;
+use support::strategies::{self, check};
+use ::order::domain::commands::*;
+
+/// `CreateOrder` commands ignoring the validation rules
+fn any_create_order() -> BoxedStrategy<CreateOrder> {
+    (
+        strategies::customer_id(),
+        proptest::collection::vec(strategies::order_item(), 0..=3),
+        strategies::address(),
+    )
+        .prop_map(|(customer_id, items, shipping_address)| CreateOrder {
+            customer_id,
+            items,
+            shipping_address,
+        })
+        .boxed()
+}
+
+/// `ConfirmPayment` commands ignoring the validation rules
+fn any_confirm_payment() -> BoxedStrategy<ConfirmPayment> {
+    (strategies::order_id(), proptest::string::string_regex("\\PC{0,32}").unwrap())
+        .prop_map(|(order_id, payment_id)| ConfirmPayment {
+            order_id,
+            payment_id,
+        })
+        .boxed()
+}
+
+/// `ShipOrder` commands ignoring the validation rules
+fn any_ship_order() -> BoxedStrategy<ShipOrder> {
+    (
+        strategies::order_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+    )
+        .prop_map(|(order_id, tracking_number, carrier)| ShipOrder {
+            order_id,
+            tracking_number,
+            carrier,
+        })
+        .boxed()
+}
+
+/// `DeliverOrder` commands ignoring the validation rules
+fn any_deliver_order() -> BoxedStrategy<DeliverOrder> {
+    (
+        strategies::order_id(),
+        proptest::option::of(proptest::string::string_regex("\\PC{0,32}").unwrap()),
+    )
+        .prop_map(|(order_id, signed_by)| DeliverOrder {
+            order_id,
+            signed_by,
+        })
+        .boxed()
+}
+
+/// `CancelOrder` commands ignoring the validation rules
+fn any_cancel_order() -> BoxedStrategy<CancelOrder> {
+    (strategies::order_id(), proptest::string::string_regex("\\PC{0,32}").unwrap())
+        .prop_map(|(order_id, reason)| CancelOrder { order_id, reason })
+        .boxed()
+}
+
+#[test]
+fn create_order_validation_never_panics() {
+    check(
+        any_create_order(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn confirm_payment_validation_never_panics() {
+    check(
+        any_confirm_payment(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn ship_order_validation_never_panics() {
+    check(
+        any_ship_order(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn deliver_order_validation_never_panics() {
+    check(
+        any_deliver_order(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn cancel_order_validation_never_panics() {
+    check(
+        any_cancel_order(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,120 @@
+//! Starter tests of the `Order` aggregate derived from the IR
+//!
+//! Left out, as the IR does not tell how to set them up:
+//!
+//! - `total_must_match_items_is_enforced`
+
+mod support;
+
+use ::order::domain::commands::*;
+use support::order::*;
+
+/// `CreateOrder` produces `OrderCreated`
+#[tokio::test]
+async fn create_order_produces_order_created() {
+    scenario().given(vec![]).await.when(create_order()).await.then(&["OrderCreated"]);
+}
+
+/// `ConfirmPayment` produces `OrderPaid`
+#[tokio::test]
+async fn confirm_payment_produces_order_paid() {
+    scenario()
+        .given(vec![order_created()])
+        .await
+        .when(confirm_payment())
+        .await
+        .then(&["OrderPaid"]);
+}
+
+/// `ConfirmPayment` is rejected unless `order.status == CREATED`
+#[tokio::test]
+async fn confirm_payment_fails_precondition() {
+    scenario()
+        .given(vec![order_created(), order_paid()])
+        .await
+        .when(confirm_payment())
+        .await
+        .then_error("Can only confirm payment for orders in CREATED status");
+}
+
+/// `ShipOrder` produces `OrderShipped`
+#[tokio::test]
+async fn ship_order_produces_order_shipped() {
+    scenario()
+        .given(vec![order_created(), order_paid()])
+        .await
+        .when(ship_order())
+        .await
+        .then(&["OrderShipped"]);
+}
+
+/// `ShipOrder` is rejected unless `order.status == PAID`
+#[tokio::test]
+async fn ship_order_fails_precondition() {
+    scenario()
+        .given(vec![order_created()])
+        .await
+        .when(ship_order())
+        .await
+        .then_error("Can only ship orders that have been paid");
+}
+
+/// `DeliverOrder` produces `OrderDelivered`
+#[tokio::test]
+async fn deliver_order_produces_order_delivered() {
+    scenario()
+        .given(vec![order_created(), order_shipped()])
+        .await
+        .when(deliver_order())
+        .await
+        .then(&["OrderDelivered"]);
+}
+
+/// `DeliverOrder` is rejected unless `order.status == SHIPPED`
+#[tokio::test]
+async fn deliver_order_fails_precondition() {
+    scenario()
+        .given(vec![order_created()])
+        .await
+        .when(deliver_order())
+        .await
+        .then_error("Can only deliver orders that have been shipped");
+}
+
+/// `CancelOrder` produces `OrderCancelled`
+#[tokio::test]
+async fn cancel_order_produces_order_cancelled() {
+    scenario()
+        .given(vec![order_created()])
+        .await
+        .when(cancel_order())
+        .await
+        .then(&["OrderCancelled"]);
+}
+
+/// `CancelOrder` is rejected unless `order.status in [CREATED, PAID]`
+#[tokio::test]
+async fn cancel_order_fails_precondition() {
+    scenario()
+        .given(vec![order_created(), order_shipped()])
+        .await
+        .when(cancel_order())
+        .await
+        .then_error("Can only cancel orders that have not been shipped");
+}
+
+/// Commands cannot break `OrderMustHaveItems`: Order must have at least one item
+///
+/// `items.length > 0`
+#[tokio::test]
+async fn order_must_have_items_is_enforced() {
+    scenario()
+        .given(vec![])
+        .await
+        .when(CreateOrder {
+            items: Vec::new(),
+            ..create_order()
+        })
+        .await
+        .then_rejected();
+}
@@ -0,0 +1,367 @@
+//! Proptest strategies for the domain types
+//!
+//! Values respect the IR's validation rules: string lengths and
+//! patterns, numeric ranges and decimal places.
+
+use proptest::prelude::*;
+use proptest::test_runner::TestRunner;
+use ::order::domain::commands::*;
+use ::order::domain::entities::*;
+use ::order::domain::events::*;
+
+/// Run a property against values of a strategy
+///
+/// A failure panics with the smallest failing value proptest finds.
+pub fn check<S: Strategy>(
+    strategy: S,
+    test: impl Fn(S::Value) -> Result<(), TestCaseError>,
+) {
+    let mut runner = TestRunner::default();
+    if let Err(error) = runner.run(&strategy, test) {
+        panic!("{}", error);
+    }
+}
+
+/// `OrderId` values
+pub fn order_id() -> BoxedStrategy<OrderId> {
+    proptest::string::string_regex("[a-z0-9-]{1,16}").unwrap().prop_map(OrderId).boxed()
+}
+
+/// `CustomerId` values
+pub fn customer_id() -> BoxedStrategy<CustomerId> {
+    proptest::string::string_regex("[a-z0-9-]{1,16}")
+        .unwrap()
+        .prop_map(CustomerId)
+        .boxed()
+}
+
+/// `ProductId` values
+pub fn product_id() -> BoxedStrategy<ProductId> {
+    proptest::string::string_regex("[a-z0-9-]{1,16}")
+        .unwrap()
+        .prop_map(ProductId)
+        .boxed()
+}
+
+/// `OrderItem` values
+pub fn order_item() -> BoxedStrategy<OrderItem> {
+    (
+        product_id(),
+        proptest::string::string_regex("\\PC{0,255}").unwrap(),
+        1i64..=999,
+        money(),
+        money(),
+    )
+        .prop_map(|(product_id, product_name, quantity, unit_price, subtotal)| OrderItem {
+            product_id,
+            product_name,
+            quantity,
+            unit_price,
+            subtotal,
+        })
+        .boxed()
+}
+
+/// `Money` values
+pub fn money() -> BoxedStrategy<Money> {
+    (
+        (0i64..=100000000).prop_map(|units| rust_decimal::Decimal::new(units, 2)),
+        proptest::string::string_regex("[A-Z]{3}").unwrap(),
+    )
+        .prop_map(|(amount, currency)| Money { amount, currency })
+        .boxed()
+}
+
+/// `Address` values
+pub fn address() -> BoxedStrategy<Address> {
+    (
+        proptest::string::string_regex("\\PC{0,255}").unwrap(),
+        proptest::string::string_regex("\\PC{0,100}").unwrap(),
+        proptest::string::string_regex("\\PC{0,100}").unwrap(),
+        proptest::string::string_regex("[0-9]{6}").unwrap(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+    )
+        .prop_map(|(street, city, province, postal_code, country)| Address {
+            street,
+            city,
+            province,
+            postal_code,
+            country,
+        })
+        .boxed()
+}
+
+/// `OrderStatus` values
+pub fn order_status() -> BoxedStrategy<OrderStatus> {
+    proptest::sample::select(
+            Vec::from([
+                OrderStatus::Created,
+                OrderStatus::Paid,
+                OrderStatus::Processing,
+                OrderStatus::Shipped,
+                OrderStatus::Delivered,
+                OrderStatus::Cancelled,
+                OrderStatus::Refunded,
+            ]),
+        )
+        .boxed()
+}
+
+/// `Order` values
+pub fn order() -> BoxedStrategy<Order> {
+    (
+        order_id(),
+        customer_id(),
+        proptest::collection::vec(order_item(), 0..=3),
+        order_status(),
+        address(),
+        money(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::option::of(
+            (0i64..4_102_444_800)
+                .prop_map(|seconds| {
+                    chrono::DateTime::from_timestamp(seconds, 0).unwrap()
+                }),
+        ),
+    )
+        .prop_map(|
+            (
+                id,
+                customer_id,
+                items,
+                status,
+                shipping_address,
+                total_amount,
+                created_at,
+                updated_at,
+            )|
+        Order {
+            id,
+            customer_id,
+            items,
+            status,
+            shipping_address,
+            total_amount,
+            created_at,
+            updated_at,
+        })
+        .boxed()
+}
+
+/// `CreateOrder` values
+pub fn create_order() -> BoxedStrategy<CreateOrder> {
+    (customer_id(), proptest::collection::vec(order_item(), 0..=3), address())
+        .prop_map(|(customer_id, items, shipping_address)| CreateOrder {
+            customer_id,
+            items,
+            shipping_address,
+        })
+        .boxed()
+}
+
+/// `ConfirmPayment` values
+pub fn confirm_payment() -> BoxedStrategy<ConfirmPayment> {
+    (order_id(), proptest::string::string_regex("\\PC{0,32}").unwrap())
+        .prop_map(|(order_id, payment_id)| ConfirmPayment {
+            order_id,
+            payment_id,
+        })
+        .boxed()
+}
+
+/// `ShipOrder` values
+pub fn ship_order() -> BoxedStrategy<ShipOrder> {
+    (
+        order_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+    )
+        .prop_map(|(order_id, tracking_number, carrier)| ShipOrder {
+            order_id,
+            tracking_number,
+            carrier,
+        })
+        .boxed()
+}
+
+/// `DeliverOrder` values
+pub fn deliver_order() -> BoxedStrategy<DeliverOrder> {
+    (
+        order_id(),
+        proptest::option::of(proptest::string::string_regex("\\PC{0,32}").unwrap()),
+    )
+        .prop_map(|(order_id, signed_by)| DeliverOrder {
+            order_id,
+            signed_by,
+        })
+        .boxed()
+}
+
+/// `CancelOrder` values
+pub fn cancel_order() -> BoxedStrategy<CancelOrder> {
+    (order_id(), proptest::string::string_regex("\\PC{0,32}").unwrap())
+        .prop_map(|(order_id, reason)| CancelOrder { order_id, reason })
+        .boxed()
+}
+
+/// `OrderCreated` values
+pub fn order_created() -> BoxedStrategy<OrderCreated> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        order_id(),
+        customer_id(),
+        proptest::collection::vec(order_item(), 0..=3),
+        address(),
+        money(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                order_id,
+                customer_id,
+                items,
+                shipping_address,
+                total_amount,
+                created_at,
+            )|
+        OrderCreated {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            order_id,
+            customer_id,
+            items,
+            shipping_address,
+            total_amount,
+            created_at,
+        })
+        .boxed()
+}
+
+/// `OrderPaid` values
+pub fn order_paid() -> BoxedStrategy<OrderPaid> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        order_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        money(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                order_id,
+                payment_id,
+                paid_amount,
+                paid_at,
+            )|
+        OrderPaid {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            order_id,
+            payment_id,
+            paid_amount,
+            paid_at,
+        })
+        .boxed()
+}
+
+/// `OrderShipped` values
+pub fn order_shipped() -> BoxedStrategy<OrderShipped> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        order_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                order_id,
+                tracking_number,
+                carrier,
+                shipped_at,
+            )|
+        OrderShipped {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            order_id,
+            tracking_number,
+            carrier,
+            shipped_at,
+        })
+        .boxed()
+}
+
+/// `OrderDelivered` values
+pub fn order_delivered() -> BoxedStrategy<OrderDelivered> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        order_id(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+    )
+        .prop_map(|
+            (event_id, occurred_at, aggregate_id, order_id, delivered_at, signed_by)|
+        OrderDelivered {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            order_id,
+            delivered_at,
+            signed_by,
+        })
+        .boxed()
+}
+
+/// `OrderCancelled` values
+pub fn order_cancelled() -> BoxedStrategy<OrderCancelled> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        order_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (event_id, occurred_at, aggregate_id, order_id, reason, cancelled_at)|
+        OrderCancelled {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            order_id,
+            reason,
+            cancelled_at,
+        })
+        .boxed()
+}
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+use crate::domain::entities::*;
+use crate::domain::events::*;
+use crate::domain::expressions;
+
+pub type CommandResult<T> = Result<T, CommandError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CommandError {
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+    #[error("Aggregate not found: {0}")]
+    AggregateNotFound(String),
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+/// Create a new order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "CreateOrderInput")
+)]
+pub struct CreateOrder {
+    /// Customer placing the order
+    pub customer_id: CustomerId,
+    /// Items to order
+    pub items: Vec<OrderItem>,
+    /// Delivery address
+    pub shipping_address: Address,
+}
+
+impl CreateOrder {
+    /// Validate the command
+    ///
+    /// Rules:
+    /// - `items.length > 0`: Order must have at least one item
+    /// - `items.length <= 50`: Order cannot have more than 50 items
+    pub fn validate(&self) -> CommandResult<()> {
+        let command = serde_json::to_value(self)
+            .map_err(|e| CommandError::InternalError(e.to_string()))?;
+        let scope = &command;
+        if !expressions::gt(
+            &expressions::len(&expressions::get(scope, "items")),
+            &serde_json::Value::from(0),
+        ) {
+            return Err(
+                CommandError::ValidationError(
+                    "Order must have at least one item".to_string(),
+                ),
+            );
+        }
+        if !expressions::le(
+            &expressions::len(&expressions::get(scope, "items")),
+            &serde_json::Value::from(50),
+        ) {
+            return Err(
+                CommandError::ValidationError(
+                    "Order cannot have more than 50 items".to_string(),
+                ),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Confirm order payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "ConfirmPaymentInput")
+)]
+pub struct ConfirmPayment {
+    pub order_id: OrderId,
+    pub payment_id: String,
+}
+
+impl ConfirmPayment {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Ship the order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "ShipOrderInput")
+)]
+pub struct ShipOrder {
+    pub order_id: OrderId,
+    pub tracking_number: String,
+    pub carrier: String,
+}
+
+impl ShipOrder {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Cancel the order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "CancelOrderInput")
+)]
+pub struct CancelOrder {
+    pub order_id: OrderId,
+    pub reason: String,
+}
+
+impl CancelOrder {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Command handler trait for processing commands
+#[async_trait]
+pub trait CommandHandler: Send + Sync {
+    /// Create a new order
+    async fn handle_create_order(
+        &self,
+        command: CreateOrder,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Confirm order payment
+    async fn handle_confirm_payment(
+        &self,
+        command: ConfirmPayment,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Ship the order
+    async fn handle_ship_order(
+        &self,
+        command: ShipOrder,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Cancel the order
+    async fn handle_cancel_order(
+        &self,
+        command: CancelOrder,
+    ) -> CommandResult<Vec<DomainEvent>>;
+}
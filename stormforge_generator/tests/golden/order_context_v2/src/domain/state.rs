@@ -0,0 +1,70 @@
+//! Aggregate state folded from events
+//!
+//! The state of an aggregate is a JSON object of its root entity
+//! properties, starting from their defaults. Each event copies its
+//! payload fields into the state, and an event whose name ends with a
+//! value of the aggregate's status enum, such as `OrderPaid` for `PAID`,
+//! sets the status. The identifier is the aggregate ID of the first
+//! event, unless its payload carries the identifier field.
+
+use serde_json::Value;
+use crate::domain::expressions;
+
+/// Event fields that describe the event rather than the aggregate
+const ENVELOPE_FIELDS: [&str; 4] = ["type", "event_id", "occurred_at", "aggregate_id"];
+
+/// Fold events with the apply function of an aggregate
+pub fn fold(events: &[Value], apply: fn(&mut Value, &Value)) -> Value {
+    let mut state = Value::Null;
+    for event in events {
+        apply(&mut state, event);
+    }
+    state
+}
+
+/// State of an aggregate of this context, `None` for other aggregate types
+pub fn load(aggregate_type: &str, events: &[Value]) -> Option<Value> {
+    match aggregate_type {
+        "Order" => Some(fold(events, apply_order)),
+        _ => None,
+    }
+}
+
+/// Copy the payload fields of an event into a state
+fn copy_payload(state: &mut Value, event: &Value) {
+    let (Some(state), Some(fields)) = (state.as_object_mut(), event.as_object()) else {
+        return;
+    };
+    for (key, value) in fields {
+        if !ENVELOPE_FIELDS.contains(&key.as_str()) {
+            state.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+/// Initial state of a `Order`: the defaults of its root entity properties
+pub fn new_order() -> Value {
+    expressions::object([])
+}
+
+/// Fold an event into the state of a `Order`
+pub fn apply_order(state: &mut Value, event: &Value) {
+    if !state.is_object() {
+        *state = new_order();
+    }
+    let status = match event.get("type").and_then(Value::as_str) {
+        Some("OrderCreated") => Some("Created"),
+        Some("OrderPaid") => Some("Paid"),
+        Some("OrderShipped") => Some("Shipped"),
+        Some("OrderDelivered") => Some("Delivered"),
+        Some("OrderCancelled") => Some("Cancelled"),
+        _ => None,
+    };
+    if let (Some(state), Some(status)) = (state.as_object_mut(), status) {
+        state.insert("status".to_string(), Value::from(status));
+    }
+    copy_payload(state, event);
+    if let (Some(state), Some(id)) = (state.as_object_mut(), event.get("aggregate_id")) {
+        state.entry("id").or_insert_with(|| id.clone());
+    }
+}
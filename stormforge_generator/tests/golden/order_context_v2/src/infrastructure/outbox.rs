@@ -0,0 +1,321 @@
+//! Transactional outbox
+//!
+//! Event stores record every appended event as an outbox entry in the
+//! same write. `OutboxRelay` publishes pending entries to the event bus
+//! and only then marks them as published, so delivery is
+//! at-least-once: an entry published right before a crash is published
+//! again with the same message ID, which consumers use to deduplicate.
+//! An entry that keeps failing is moved to the dead letters, where it
+//! stays, unpublished, until it is replayed.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+use crate::infrastructure::event_bus::{EventBus, IntegrationMessage};
+
+/// Stored event waiting to be published
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxEntry {
+    /// Message to publish; its ID is the deduplication ID
+    pub message: IntegrationMessage,
+    /// Number of failed publish attempts
+    pub attempts: u32,
+    /// Error of the last failed attempt
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+    /// When the relay stopped retrying the entry
+    pub dead_lettered_at: Option<DateTime<Utc>>,
+}
+
+impl OutboxEntry {
+    pub fn new(message: IntegrationMessage) -> Self {
+        Self {
+            message,
+            attempts: 0,
+            last_error: None,
+            created_at: Utc::now(),
+            published_at: None,
+            dead_lettered_at: None,
+        }
+    }
+    /// Whether the relay should still try to publish this entry
+    pub fn is_pending(&self) -> bool {
+        self.published_at.is_none() && self.dead_lettered_at.is_none()
+    }
+}
+
+/// Storage for outbox entries
+#[async_trait]
+pub trait OutboxStore: Send + Sync {
+    /// Entries neither published nor dead-lettered, oldest first
+    async fn pending(&self, limit: usize) -> Result<Vec<OutboxEntry>>;
+    /// Mark an entry as published
+    async fn mark_published(&self, message_id: Uuid) -> Result<()>;
+    /// Record a failed publish attempt
+    async fn mark_failed(&self, message_id: Uuid, error: &str) -> Result<()>;
+    /// Stop retrying an entry until it is replayed
+    async fn mark_dead_lettered(&self, message_id: Uuid) -> Result<()>;
+    /// Dead-lettered entries, oldest first
+    async fn dead_letters(&self, limit: usize) -> Result<Vec<OutboxEntry>>;
+    /// Return a dead-lettered entry to the pending entries, with no failed attempts
+    async fn replay(&self, message_id: Uuid) -> Result<()>;
+}
+
+/// Background task publishing outbox entries to the event bus
+pub struct OutboxRelay {
+    store: Arc<dyn OutboxStore>,
+    bus: Arc<dyn EventBus>,
+    max_attempts: u32,
+    batch_size: usize,
+}
+
+impl OutboxRelay {
+    pub fn new(store: Arc<dyn OutboxStore>, bus: Arc<dyn EventBus>) -> Self {
+        Self {
+            store,
+            bus,
+            max_attempts: 10,
+            batch_size: 100,
+        }
+    }
+    /// Dead-letter an entry after this many failed attempts
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+    /// Publish pending entries, returning how many were published
+    ///
+    /// Stops at the first failure so that entries are published in
+    /// order; the failed entry is retried on the next call until it
+    /// reaches the maximum number of attempts, when it is moved to the
+    /// dead letters and the entries after it go ahead.
+    pub async fn relay_pending(&self) -> Result<usize> {
+        let mut published = 0;
+        for entry in self.store.pending(self.batch_size).await? {
+            let message_id = entry.message.message_id;
+            if let Err(e) = self.bus.publish(entry.message).await {
+                self.store.mark_failed(message_id, &e.to_string()).await?;
+                if entry.attempts + 1 < self.max_attempts {
+                    tracing::warn!("Failed to publish outbox entry {}: {}", message_id, e);
+                    break;
+                }
+                self.store.mark_dead_lettered(message_id).await?;
+                tracing::error!(
+                    "Dead-lettered outbox entry {} after {} attempts: {}",
+                    message_id,
+                    self.max_attempts,
+                    e,
+                );
+                continue;
+            }
+            self.store.mark_published(message_id).await?;
+            published += 1;
+        }
+        Ok(published)
+    }
+    /// Relay pending entries every `interval`
+    pub async fn run(self, interval: Duration) {
+        loop {
+            if let Err(e) = self.relay_pending().await {
+                tracing::error!("Failed to relay outbox entries: {}", e);
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod conformance {
+    use super::*;
+    use crate::infrastructure::event_bus::InMemoryEventBus;
+    use crate::infrastructure::event_store::EventStore;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    pub fn message(event_type: &str) -> IntegrationMessage {
+        IntegrationMessage {
+            message_id: Uuid::new_v4(),
+            context: "Test".to_string(),
+            event_type: event_type.to_string(),
+            payload: serde_json::json!({ "type": event_type }),
+        }
+    }
+
+    /// Event bus failing the first `failures` publishes
+    struct FlakyBus {
+        inner: InMemoryEventBus,
+        failures: AtomicU32,
+    }
+
+    impl FlakyBus {
+        fn new(failures: u32) -> Self {
+            Self {
+                inner: InMemoryEventBus::new(),
+                failures: AtomicU32::new(failures),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl EventBus for FlakyBus {
+        async fn publish(&self, message: IntegrationMessage) -> Result<()> {
+            let failing = self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            if failing {
+                anyhow::bail!("Event bus unavailable");
+            }
+            self.inner.publish(message).await
+        }
+        async fn read_from(&self, offset: usize) -> Result<Vec<IntegrationMessage>> {
+            self.inner.read_from(offset).await
+        }
+    }
+
+    fn message_ids(messages: &[IntegrationMessage]) -> Vec<Uuid> {
+        messages.iter().map(|message| message.message_id).collect()
+    }
+
+    pub async fn append_records_events_in_outbox<S: EventStore + OutboxStore>(store: S) {
+        let messages = vec![message("First"), message("Second")];
+        store.append_messages("agg-1", "Test", messages.clone(), Some(0)).await.unwrap();
+        assert_eq!(store.version("agg-1", "Test").await.unwrap(), 2);
+        let pending = store.pending(100).await.unwrap();
+        let pending: Vec<_> = pending.into_iter().map(|entry| entry.message).collect();
+        assert_eq!(pending, messages);
+    }
+
+    pub async fn conflicting_append_writes_nothing<S: EventStore + OutboxStore>(
+        store: S,
+    ) {
+        store
+            .append_messages("agg-1", "Test", vec![message("First")], None)
+            .await
+            .unwrap();
+        let result = store
+            .append_messages("agg-1", "Test", vec![message("Second")], Some(0))
+            .await;
+        assert!(result.is_err());
+        assert_eq!(store.version("agg-1", "Test").await.unwrap(), 1);
+        assert_eq!(store.pending(100).await.unwrap().len(), 1);
+    }
+
+    pub async fn reads_events_from_position<S: EventStore + OutboxStore>(store: S) {
+        store
+            .append_messages("agg-1", "Test", vec![message("First")], None)
+            .await
+            .unwrap();
+        store
+            .append_messages("agg-2", "Test", vec![message("Second")], None)
+            .await
+            .unwrap();
+        let events = store.read_all_from(1).await.unwrap();
+        assert_eq!(events, vec![serde_json::json!({ "type": "Second" })]);
+        assert!(store.read_all_from(2).await.unwrap().is_empty());
+    }
+
+    pub async fn relay_publishes_each_entry_once<S: EventStore + OutboxStore + 'static>(
+        store: S,
+    ) {
+        let store = Arc::new(store);
+        let bus = InMemoryEventBus::new();
+        let messages = vec![message("First"), message("Second")];
+        store.append_messages("agg-1", "Test", messages.clone(), None).await.unwrap();
+        let relay = OutboxRelay::new(store.clone(), Arc::new(bus.clone()));
+        assert_eq!(relay.relay_pending().await.unwrap(), 2);
+        assert_eq!(relay.relay_pending().await.unwrap(), 0);
+        let published = bus.read_from(0).await.unwrap();
+        assert_eq!(message_ids(&published), message_ids(&messages));
+    }
+
+    pub async fn relay_retries_failed_publishes<S: EventStore + OutboxStore + 'static>(
+        store: S,
+    ) {
+        let store = Arc::new(store);
+        let bus = Arc::new(FlakyBus::new(2));
+        store
+            .append_messages(
+                "agg-1",
+                "Test",
+                vec![message("First"), message("Second")],
+                None,
+            )
+            .await
+            .unwrap();
+        let relay = OutboxRelay::new(store.clone(), bus.clone()).with_max_attempts(5);
+        assert_eq!(relay.relay_pending().await.unwrap(), 0);
+        assert_eq!(relay.relay_pending().await.unwrap(), 0);
+        let pending = store.pending(100).await.unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].attempts, 2);
+        assert_eq!(pending[0].last_error.as_deref(), Some("Event bus unavailable"));
+        assert_eq!(pending[1].attempts, 0);
+        assert_eq!(relay.relay_pending().await.unwrap(), 2);
+        assert!(store.pending(100).await.unwrap().is_empty());
+    }
+
+    pub async fn relay_dead_letters_after_max_attempts<
+        S: EventStore + OutboxStore + 'static,
+    >(store: S) {
+        let store = Arc::new(store);
+        let bus = Arc::new(FlakyBus::new(2));
+        let messages = vec![message("First"), message("Second")];
+        store.append_messages("agg-1", "Test", messages.clone(), None).await.unwrap();
+        let relay = OutboxRelay::new(store.clone(), bus.clone()).with_max_attempts(2);
+        assert_eq!(relay.relay_pending().await.unwrap(), 0);
+        assert_eq!(relay.relay_pending().await.unwrap(), 1);
+        let dead_letters = store.dead_letters(100).await.unwrap();
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].message, messages[0]);
+        assert_eq!(dead_letters[0].attempts, 2);
+        assert_eq!(dead_letters[0].last_error.as_deref(), Some("Event bus unavailable"));
+        assert!(dead_letters[0].dead_lettered_at.is_some());
+        assert!(store.pending(100).await.unwrap().is_empty());
+        assert_eq!(
+            message_ids(&bus.read_from(0).await.unwrap()),
+            message_ids(&messages[1..]),
+        );
+    }
+
+    pub async fn replayed_dead_letter_is_published<
+        S: EventStore + OutboxStore + 'static,
+    >(store: S) {
+        let store = Arc::new(store);
+        let bus = Arc::new(FlakyBus::new(1));
+        let messages = vec![message("First")];
+        store.append_messages("agg-1", "Test", messages.clone(), None).await.unwrap();
+        let relay = OutboxRelay::new(store.clone(), bus.clone()).with_max_attempts(1);
+        assert_eq!(relay.relay_pending().await.unwrap(), 0);
+        assert_eq!(relay.relay_pending().await.unwrap(), 0);
+        store.replay(messages[0].message_id).await.unwrap();
+        assert!(store.dead_letters(100).await.unwrap().is_empty());
+        assert_eq!(store.pending(100).await.unwrap()[0].attempts, 0);
+        assert_eq!(relay.relay_pending().await.unwrap(), 1);
+        assert_eq!(
+            message_ids(&bus.read_from(0).await.unwrap()),
+            message_ids(&messages),
+        );
+        assert!(store.pending(100).await.unwrap().is_empty());
+    }
+
+    pub async fn redelivery_keeps_message_id<S: EventStore + OutboxStore + 'static>(
+        store: S,
+    ) {
+        let store = Arc::new(store);
+        let bus = InMemoryEventBus::new();
+        store
+            .append_messages("agg-1", "Test", vec![message("First")], None)
+            .await
+            .unwrap();
+        let entry = store.pending(100).await.unwrap().remove(0);
+        bus.publish(entry.message.clone()).await.unwrap();
+        let relay = OutboxRelay::new(store.clone(), Arc::new(bus.clone()));
+        assert_eq!(relay.relay_pending().await.unwrap(), 1);
+        let published = bus.read_from(0).await.unwrap();
+        assert_eq!(message_ids(&published), vec![entry.message.message_id; 2]);
+    }
+}
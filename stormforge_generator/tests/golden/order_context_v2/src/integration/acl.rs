@@ -0,0 +1,27 @@
+//! Anti-corruption layer
+//!
+//! Translates integration events from other bounded contexts into
+//! this context's domain model.
+
+use anyhow::Result;
+use crate::integration::contracts;
+
+/// When payment is completed, confirm the order payment
+///
+/// Translates `PaymentCompleted` from the Payment context into this context's domain model.
+pub async fn handle_payment_completed(
+    event: contracts::payment::PaymentCompleted,
+) -> Result<()> {
+    tracing::info!("Received PaymentCompleted from Payment: {:?}", event);
+    Ok(())
+}
+
+/// When stock is reserved, proceed with order processing
+///
+/// Translates `StockReserved` from the Inventory context into this context's domain model.
+pub async fn handle_stock_reserved(
+    event: contracts::inventory::StockReserved,
+) -> Result<()> {
+    tracing::info!("Received StockReserved from Inventory: {:?}", event);
+    Ok(())
+}
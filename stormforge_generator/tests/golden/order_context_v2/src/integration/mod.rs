@@ -0,0 +1,5 @@
+pub mod acl;
+pub mod consumers;
+pub mod contracts;
+
+pub use consumers::*;
@@ -0,0 +1,7 @@
+pub mod api;
+pub mod domain;
+pub mod infrastructure;
+pub mod integration;
+pub mod policies;
+pub mod projections;
+pub mod repository;
@@ -0,0 +1,124 @@
+use axum::{routing::get, Router};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tower_http::cors::CorsLayer;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use utoipa_swagger_ui::SwaggerUi;
+use ::order::{api, infrastructure, integration, policies, projections};
+use ::order::api::create_router;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| "info".into()),
+        )
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+    tracing::info!("Starting {} service...", "Order");
+    let event_bus: Arc<dyn infrastructure::EventBus> = match std::env::var(
+        "EVENT_BUS_PATH",
+    ) {
+        Ok(path) => Arc::new(infrastructure::JsonlEventBus::new(path)),
+        Err(_) => Arc::new(infrastructure::InMemoryEventBus::new()),
+    };
+    #[cfg(feature = "sqlx")]
+    let event_store = Arc::new(
+        infrastructure::SqliteEventStore::connect(
+                &std::env::var("DATABASE_URL")
+                    .unwrap_or_else(|_| "sqlite://events.db?mode=rwc".to_string()),
+            )
+            .await?,
+    );
+    #[cfg(not(feature = "sqlx"))]
+    let event_store = Arc::new(infrastructure::InMemoryEventStore::new());
+    let app_state = api::AppState::new(event_store.clone());
+    #[cfg(feature = "sqlx")]
+    let saga_store = Arc::new(
+        policies::SqliteSagaStore::with_pool(event_store.pool().clone()).await?,
+    );
+    #[cfg(not(feature = "sqlx"))]
+    let saga_store = Arc::new(policies::InMemorySagaStore::new());
+    let mut policy_engine = policies::PolicyEngine::new(
+        event_bus.clone(),
+        Arc::new(policies::LoggingDispatcher),
+        saga_store,
+        policies::policies(),
+    );
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = policy_engine.poll().await {
+                tracing::error!("Failed to run policies: {}", e);
+            }
+            if let Err(e) = policy_engine.check_timeouts(chrono::Utc::now()).await {
+                tracing::error!("Failed to check saga timeouts: {}", e);
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+    });
+    #[cfg(feature = "sqlx")]
+    let read_models = projections::ReadModels::sqlite(
+            event_store.clone(),
+            event_store.pool().clone(),
+        )
+        .await?;
+    #[cfg(not(feature = "sqlx"))]
+    let read_models = projections::ReadModels::in_memory(event_store.clone());
+    if std::env::args().any(|arg| arg == "--rebuild-projections") {
+        let events = read_models.rebuild().await?;
+        tracing::info!("Rebuilt projections from {} events", events);
+    }
+    let projection_updates = read_models.clone();
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = projection_updates.catch_up().await {
+                tracing::error!("Failed to update projections: {}", e);
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+    });
+    #[cfg(feature = "graphql")]
+    let graphql_router = api::graphql::router(app_state.clone());
+    let outbox_relay = infrastructure::OutboxRelay::new(event_store, event_bus.clone());
+    tokio::spawn(outbox_relay.run(Duration::from_secs(1)));
+    let mut event_listener = integration::IntegrationEventListener::new(
+        event_bus.clone(),
+    );
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = event_listener.poll().await {
+                tracing::error!("Failed to process integration events: {}", e);
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+    });
+    let api_router = create_router(app_state.clone())
+        .merge(projections::router(read_models));
+    let swagger = SwaggerUi::new("/swagger-ui")
+        .external_url_unchecked("/api-docs/openapi.json", api::openapi());
+    let app = Router::new()
+        .merge(swagger)
+        .route("/api-docs/asyncapi.json", get(api::asyncapi))
+        .nest("/api", api_router)
+        .layer(CorsLayer::permissive());
+    #[cfg(feature = "graphql")]
+    let app = app.merge(graphql_router);
+    let addr = SocketAddr::from(([0, 0, 0, 0], 3000));
+    tracing::info!("Listening on http://{}", addr);
+    tracing::info!("Swagger UI available at http://{}/swagger-ui", addr);
+    #[cfg(feature = "grpc")]
+    {
+        let grpc_addr = SocketAddr::from(([0, 0, 0, 0], 50051));
+        tracing::info!("gRPC API listening on {}", grpc_addr);
+        tokio::spawn(async move {
+            if let Err(e) = api::grpc::serve(grpc_addr, app_state).await {
+                tracing::error!("gRPC server failed: {}", e);
+            }
+        });
+    }
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//! Commands built from the events that trigger policies
+//!
+//! Each builder fills the command from the event fields with the same
+//! name. Adapt a builder when its command needs data the event does
+//! not carry.
+
+use anyhow::Result;
+use crate::infrastructure::event_bus::IntegrationMessage;
+use crate::policies::PolicyCommand;
+
+/// Build a `ShipOrder` command
+pub fn ship_order(message: &IntegrationMessage) -> Result<PolicyCommand> {
+    Ok(PolicyCommand::ShipOrder(serde_json::from_value(message.payload.clone())?))
+}
+
+/// Build a `CancelOrder` command
+pub fn cancel_order(message: &IntegrationMessage) -> Result<PolicyCommand> {
+    Ok(PolicyCommand::CancelOrder(serde_json::from_value(message.payload.clone())?))
+}
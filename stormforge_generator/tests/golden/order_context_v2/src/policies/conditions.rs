@@ -0,0 +1,33 @@
+//! Policy conditions
+//!
+//! Each check decides whether its policy reacts to an event. A property
+//! the event does not carry reads as `null`, which fails the condition.
+
+use anyhow::Result;
+use crate::domain::expressions;
+use crate::infrastructure::event_bus::IntegrationMessage;
+
+/// Conditions of the `AutoShipWhenPaid` policy
+///
+/// - `order.items.all(item => item.inStock)`: All items must be in stock
+pub fn auto_ship_when_paid(message: &IntegrationMessage) -> Result<bool> {
+    let scope = &message.payload;
+    let scope = &expressions::with(scope, "order", &message.payload);
+    if !expressions::all(
+        &expressions::get(&expressions::get(scope, "order"), "items"),
+        |item: &serde_json::Value| {
+            let scope = &expressions::with(scope, "item", item);
+            expressions::truthy(
+                &expressions::get(&expressions::get(scope, "item"), "in_stock"),
+            )
+        },
+    ) {
+        tracing::debug!(
+            "AutoShipWhenPaid skipped {}: {}",
+            message.event_type,
+            "All items must be in stock",
+        );
+        return Ok(false);
+    }
+    Ok(true)
+}
@@ -0,0 +1,412 @@
+//! Policy engine
+//!
+//! Reacts to this bounded context's events on the event bus. Plain
+//! policies dispatch their actions for every matching event. Sagas also
+//! keep their state in a `SagaStore` until a completion event arrives,
+//! and dispatch their compensation commands when an action fails or the
+//! deadline passes first.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+use crate::infrastructure::event_bus::{EventBus, IntegrationMessage, BOUNDED_CONTEXT};
+use crate::policies::saga_store::{SagaState, SagaStatus, SagaStore};
+
+/// Builds a command from the event that triggered a policy
+pub type CommandBuilder<C> = fn(&IntegrationMessage) -> Result<C>;
+
+/// Decides whether a policy reacts to an event
+pub type ConditionCheck = fn(&IntegrationMessage) -> Result<bool>;
+
+/// Policy reacting to events, optionally as a long-running saga
+pub struct PolicyDefinition<C: 'static> {
+    pub name: &'static str,
+    /// Events that trigger the policy
+    pub triggers: &'static [&'static str],
+    pub conditions: Option<ConditionCheck>,
+    /// Commands dispatched when the policy is triggered
+    pub actions: &'static [CommandBuilder<C>],
+    pub saga: Option<SagaDefinition<C>>,
+}
+
+/// Saga settings of a policy
+pub struct SagaDefinition<C: 'static> {
+    /// Events that complete the saga
+    pub completed_by: &'static [&'static str],
+    /// Time after which a running saga is compensated
+    pub timeout: Option<chrono::Duration>,
+    /// Commands dispatched when an action fails or the saga times out
+    pub compensation: &'static [CommandBuilder<C>],
+}
+
+/// Executes commands issued by policies
+#[async_trait]
+pub trait CommandDispatcher<C>: Send + Sync {
+    async fn dispatch(&self, command: C) -> Result<()>;
+}
+
+/// Polls the event bus and runs the policies matching each event
+///
+/// Delivery is at-least-once: a failing event is retried on the next
+/// poll. Saga state makes a redelivered trigger start a saga only once.
+pub struct PolicyEngine<C: 'static> {
+    bus: Arc<dyn EventBus>,
+    dispatcher: Arc<dyn CommandDispatcher<C>>,
+    sagas: Arc<dyn SagaStore>,
+    policies: Vec<PolicyDefinition<C>>,
+    offset: usize,
+    processed: HashSet<Uuid>,
+}
+
+impl<C: Send + 'static> PolicyEngine<C> {
+    pub fn new(
+        bus: Arc<dyn EventBus>,
+        dispatcher: Arc<dyn CommandDispatcher<C>>,
+        sagas: Arc<dyn SagaStore>,
+        policies: Vec<PolicyDefinition<C>>,
+    ) -> Self {
+        Self {
+            bus,
+            dispatcher,
+            sagas,
+            policies,
+            offset: 0,
+            processed: HashSet::new(),
+        }
+    }
+    /// Process new events, returning how many policy reactions ran
+    pub async fn poll(&mut self) -> Result<usize> {
+        let messages = self.bus.read_from(self.offset).await?;
+        let mut reactions = 0;
+        for message in messages {
+            if message.context == BOUNDED_CONTEXT
+                && !self.processed.contains(&message.message_id)
+            {
+                for policy in &self.policies {
+                    reactions += self.react(policy, &message).await?;
+                }
+                self.processed.insert(message.message_id);
+            }
+            self.offset += 1;
+        }
+        Ok(reactions)
+    }
+    /// Compensate running sagas whose deadline has passed
+    pub async fn check_timeouts(&self, now: DateTime<Utc>) -> Result<usize> {
+        let mut compensated = 0;
+        for mut state in self.sagas.expired(now).await? {
+            let saga = self
+                .policies
+                .iter()
+                .find(|policy| policy.name == state.saga_type)
+                .and_then(|policy| policy.saga.as_ref());
+            if let Some(saga) = saga {
+                tracing::warn!(
+                    "Saga {} for {} timed out, compensating",
+                    state.saga_type,
+                    state.correlation_id,
+                );
+                self.compensate(saga, &mut state).await?;
+                compensated += 1;
+            }
+        }
+        Ok(compensated)
+    }
+    async fn react(
+        &self,
+        policy: &PolicyDefinition<C>,
+        message: &IntegrationMessage,
+    ) -> Result<usize> {
+        let event_type = message.event_type.as_str();
+        let mut reactions = 0;
+        if let Some(saga) = &policy.saga {
+            if saga.completed_by.contains(&event_type) {
+                self.complete_saga(policy, message).await?;
+                reactions += 1;
+            }
+        }
+        if !policy.triggers.contains(&event_type) {
+            return Ok(reactions);
+        }
+        if let Some(conditions) = policy.conditions {
+            if !conditions(message)? {
+                return Ok(reactions);
+            }
+        }
+        match &policy.saga {
+            None => {
+                for action in policy.actions {
+                    self.dispatcher.dispatch(action(message)?).await?;
+                }
+            }
+            Some(saga) => self.start_saga(policy, saga, message).await?,
+        }
+        Ok(reactions + 1)
+    }
+    async fn start_saga(
+        &self,
+        policy: &PolicyDefinition<C>,
+        saga: &SagaDefinition<C>,
+        message: &IntegrationMessage,
+    ) -> Result<()> {
+        let correlation_id = correlation_id(message)?;
+        if self.sagas.load(policy.name, &correlation_id).await?.is_some() {
+            return Ok(());
+        }
+        let deadline = saga.timeout.map(|timeout| Utc::now() + timeout);
+        let mut state = SagaState::new(
+            policy.name,
+            correlation_id,
+            message.clone(),
+            deadline,
+        );
+        for action in policy.actions {
+            let result = match action(message) {
+                Ok(command) => self.dispatcher.dispatch(command).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                tracing::warn!(
+                    "Saga {} for {} failed, compensating: {}",
+                    policy.name,
+                    state.correlation_id,
+                    e,
+                );
+                return self.compensate(saga, &mut state).await;
+            }
+        }
+        self.sagas.save(&state).await
+    }
+    async fn complete_saga(
+        &self,
+        policy: &PolicyDefinition<C>,
+        message: &IntegrationMessage,
+    ) -> Result<()> {
+        let correlation_id = correlation_id(message)?;
+        if let Some(mut state) = self.sagas.load(policy.name, &correlation_id).await? {
+            if state.status == SagaStatus::Running {
+                state.status = SagaStatus::Completed;
+                self.sagas.save(&state).await?;
+            }
+        }
+        Ok(())
+    }
+    async fn compensate(
+        &self,
+        saga: &SagaDefinition<C>,
+        state: &mut SagaState,
+    ) -> Result<()> {
+        for compensation in saga.compensation {
+            self.dispatcher.dispatch(compensation(&state.trigger)?).await?;
+        }
+        state.status = SagaStatus::Compensated;
+        self.sagas.save(state).await
+    }
+}
+
+/// Aggregate ID correlating the events of one saga instance
+fn correlation_id(message: &IntegrationMessage) -> Result<String> {
+    message
+        .payload
+        .get("aggregate_id")
+        .and_then(|value| value.as_str())
+        .map(str::to_string)
+        .with_context(|| {
+            format!(
+                "Event {} has no aggregate_id to correlate a saga",
+                message.event_type,
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::event_bus::InMemoryEventBus;
+    use crate::infrastructure::event_store::{EventStore, InMemoryEventStore};
+    use crate::infrastructure::outbox::OutboxRelay;
+    use crate::policies::saga_store::InMemorySagaStore;
+    use tokio::sync::Mutex;
+
+    /// Dispatcher recording commands, optionally failing actions
+    #[derive(Default)]
+    struct RecordingDispatcher {
+        commands: Mutex<Vec<String>>,
+        fail_actions: bool,
+    }
+
+    #[async_trait]
+    impl CommandDispatcher<String> for RecordingDispatcher {
+        async fn dispatch(&self, command: String) -> Result<()> {
+            if self.fail_actions && command.starts_with("Action") {
+                anyhow::bail!("Command rejected");
+            }
+            self.commands.lock().await.push(command);
+            Ok(())
+        }
+    }
+
+    fn aggregate_id(message: &IntegrationMessage) -> &str {
+        message.payload["aggregate_id"].as_str().unwrap_or_default()
+    }
+
+    fn action(message: &IntegrationMessage) -> Result<String> {
+        Ok(format!("Action:{}", aggregate_id(message)))
+    }
+
+    fn compensation(message: &IntegrationMessage) -> Result<String> {
+        Ok(format!("Compensation:{}", aggregate_id(message)))
+    }
+
+    fn only_vip(message: &IntegrationMessage) -> Result<bool> {
+        Ok(aggregate_id(message) == "vip")
+    }
+
+    fn policy() -> PolicyDefinition<String> {
+        PolicyDefinition {
+            name: "NotifyVip",
+            triggers: &["Started"],
+            conditions: Some(only_vip),
+            actions: &[action],
+            saga: None,
+        }
+    }
+
+    fn saga() -> PolicyDefinition<String> {
+        PolicyDefinition {
+            name: "Fulfilment",
+            triggers: &["Started"],
+            conditions: None,
+            actions: &[action],
+            saga: Some(SagaDefinition {
+                completed_by: &["Finished"],
+                timeout: Some(chrono::Duration::minutes(30)),
+                compensation: &[compensation],
+            }),
+        }
+    }
+
+    /// Events flow from the in-memory event store through the outbox
+    /// relay and the event bus to the policy engine
+    struct Harness {
+        store: Arc<InMemoryEventStore>,
+        relay: OutboxRelay,
+        bus: InMemoryEventBus,
+        sagas: Arc<InMemorySagaStore>,
+        dispatcher: Arc<RecordingDispatcher>,
+        engine: PolicyEngine<String>,
+    }
+
+    impl Harness {
+        fn new(policy: PolicyDefinition<String>, fail_actions: bool) -> Self {
+            let store = Arc::new(InMemoryEventStore::new());
+            let bus = InMemoryEventBus::new();
+            let relay = OutboxRelay::new(store.clone(), Arc::new(bus.clone()));
+            let sagas = Arc::new(InMemorySagaStore::new());
+            let dispatcher = Arc::new(RecordingDispatcher {
+                fail_actions,
+                ..Default::default()
+            });
+            let engine = PolicyEngine::new(
+                Arc::new(bus.clone()),
+                dispatcher.clone(),
+                sagas.clone(),
+                vec![policy],
+            );
+            Self {
+                store,
+                relay,
+                bus,
+                sagas,
+                dispatcher,
+                engine,
+            }
+        }
+        async fn append(&self, event_type: &str, aggregate_id: &str) {
+            let message = IntegrationMessage {
+                message_id: Uuid::new_v4(),
+                context: BOUNDED_CONTEXT.to_string(),
+                event_type: event_type.to_string(),
+                payload: serde_json::json!({ "type": event_type, "aggregate_id": aggregate_id }),
+            };
+            self.store
+                .append_messages(aggregate_id, "Test", vec![message], None)
+                .await
+                .unwrap();
+            self.relay.relay_pending().await.unwrap();
+        }
+        async fn commands(&self) -> Vec<String> {
+            self.dispatcher.commands.lock().await.clone()
+        }
+        async fn status(&self, correlation_id: &str) -> Option<SagaStatus> {
+            let state = self.sagas.load("Fulfilment", correlation_id).await.unwrap();
+            state.map(|state| state.status)
+        }
+    }
+
+    #[tokio::test]
+    async fn policy_dispatches_actions_when_conditions_hold() {
+        let mut harness = Harness::new(policy(), false);
+        harness.append("Started", "vip").await;
+        harness.append("Started", "regular").await;
+        harness.append("Other", "vip").await;
+        assert_eq!(harness.engine.poll().await.unwrap(), 1);
+        assert_eq!(harness.commands().await, vec!["Action:vip"]);
+    }
+
+    #[tokio::test]
+    async fn saga_completes_on_completion_event() {
+        let mut harness = Harness::new(saga(), false);
+        harness.append("Started", "order-1").await;
+        harness.engine.poll().await.unwrap();
+        assert_eq!(harness.status("order-1").await, Some(SagaStatus::Running));
+        harness.append("Finished", "order-1").await;
+        harness.engine.poll().await.unwrap();
+        assert_eq!(harness.status("order-1").await, Some(SagaStatus::Completed));
+        let later = Utc::now() + chrono::Duration::hours(1);
+        assert_eq!(harness.engine.check_timeouts(later).await.unwrap(), 0);
+        assert_eq!(harness.commands().await, vec!["Action:order-1"]);
+    }
+
+    #[tokio::test]
+    async fn saga_compensates_after_timeout() {
+        let mut harness = Harness::new(saga(), false);
+        harness.append("Started", "order-1").await;
+        harness.engine.poll().await.unwrap();
+        assert_eq!(harness.engine.check_timeouts(Utc::now()).await.unwrap(), 0);
+        let later = Utc::now() + chrono::Duration::minutes(31);
+        assert_eq!(harness.engine.check_timeouts(later).await.unwrap(), 1);
+        assert_eq!(harness.status("order-1").await, Some(SagaStatus::Compensated));
+        assert_eq!(
+            harness.commands().await,
+            vec!["Action:order-1", "Compensation:order-1"],
+        );
+    }
+
+    #[tokio::test]
+    async fn saga_compensates_failed_actions() {
+        let mut harness = Harness::new(saga(), true);
+        harness.append("Started", "order-1").await;
+        harness.engine.poll().await.unwrap();
+        assert_eq!(harness.status("order-1").await, Some(SagaStatus::Compensated));
+        assert_eq!(harness.commands().await, vec!["Compensation:order-1"]);
+    }
+
+    #[tokio::test]
+    async fn restarted_engine_does_not_restart_sagas() {
+        let mut harness = Harness::new(saga(), false);
+        harness.append("Started", "order-1").await;
+        harness.engine.poll().await.unwrap();
+        let mut restarted = PolicyEngine::new(
+            Arc::new(harness.bus.clone()),
+            harness.dispatcher.clone(),
+            harness.sagas.clone(),
+            vec![saga()],
+        );
+        restarted.poll().await.unwrap();
+        assert_eq!(harness.commands().await, vec!["Action:order-1"]);
+    }
+}
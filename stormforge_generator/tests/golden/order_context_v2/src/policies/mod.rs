@@ -0,0 +1,58 @@
+//! Policies reacting to this bounded context's events
+
+pub mod actions;
+pub mod conditions;
+pub mod engine;
+pub mod saga_store;
+#[cfg(feature = "sqlx")]
+pub mod sqlite_saga_store;
+
+pub use engine::*;
+pub use saga_store::*;
+#[cfg(feature = "sqlx")]
+pub use sqlite_saga_store::*;
+use anyhow::Result;
+use async_trait::async_trait;
+use crate::domain::commands::*;
+
+/// Commands dispatched by policies
+#[derive(Debug, Clone)]
+pub enum PolicyCommand {
+    ShipOrder(ShipOrder),
+    CancelOrder(CancelOrder),
+}
+
+/// All policies of this bounded context
+pub fn policies() -> Vec<PolicyDefinition<PolicyCommand>> {
+    Vec::from([
+        PolicyDefinition {
+            name: "AutoShipWhenPaid",
+            triggers: &["OrderPaid"],
+            conditions: Some(conditions::auto_ship_when_paid),
+            actions: &[actions::ship_order],
+            saga: None,
+        },
+        PolicyDefinition {
+            name: "CancelUnpaidOrder",
+            triggers: &["OrderCreated"],
+            conditions: None,
+            actions: &[],
+            saga: Some(SagaDefinition {
+                completed_by: &["OrderPaid", "OrderCancelled"],
+                timeout: Some(chrono::Duration::seconds(1800)),
+                compensation: &[actions::cancel_order],
+            }),
+        },
+    ])
+}
+
+/// Dispatcher logging commands until they are routed to command handlers
+pub struct LoggingDispatcher;
+
+#[async_trait]
+impl CommandDispatcher<PolicyCommand> for LoggingDispatcher {
+    async fn dispatch(&self, command: PolicyCommand) -> Result<()> {
+        tracing::info!("Policy dispatched command: {:?}", command);
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+//! Persisted saga state
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use crate::infrastructure::event_bus::IntegrationMessage;
+
+/// Lifecycle of a saga instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SagaStatus {
+    Running,
+    Completed,
+    Compensated,
+}
+
+/// State of one saga instance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SagaState {
+    pub saga_type: String,
+    /// Aggregate ID shared by the events of this saga instance
+    pub correlation_id: String,
+    pub status: SagaStatus,
+    /// Event that started the saga, used to build compensation commands
+    pub trigger: IntegrationMessage,
+    pub started_at: DateTime<Utc>,
+    /// Deadline after which a running saga is compensated
+    pub deadline: Option<DateTime<Utc>>,
+}
+
+impl SagaState {
+    pub fn new(
+        saga_type: &str,
+        correlation_id: String,
+        trigger: IntegrationMessage,
+        deadline: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            saga_type: saga_type.to_string(),
+            correlation_id,
+            status: SagaStatus::Running,
+            trigger,
+            started_at: Utc::now(),
+            deadline,
+        }
+    }
+    /// Whether the saga is running past its deadline
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.status == SagaStatus::Running
+            && self.deadline.is_some_and(|deadline| deadline <= now)
+    }
+}
+
+/// Storage for saga state
+#[async_trait]
+pub trait SagaStore: Send + Sync {
+    /// Load a saga instance
+    async fn load(
+        &self,
+        saga_type: &str,
+        correlation_id: &str,
+    ) -> Result<Option<SagaState>>;
+    /// Insert or replace a saga instance
+    async fn save(&self, state: &SagaState) -> Result<()>;
+    /// Running sagas whose deadline has passed, oldest first
+    async fn expired(&self, now: DateTime<Utc>) -> Result<Vec<SagaState>>;
+}
+
+/// In-memory saga store (for development/testing)
+#[derive(Default)]
+pub struct InMemorySagaStore {
+    states: RwLock<Vec<SagaState>>,
+}
+
+impl InMemorySagaStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SagaStore for InMemorySagaStore {
+    async fn load(
+        &self,
+        saga_type: &str,
+        correlation_id: &str,
+    ) -> Result<Option<SagaState>> {
+        let states = self.states.read().await;
+        Ok(
+            states
+                .iter()
+                .find(|state| {
+                    state.saga_type == saga_type
+                        && state.correlation_id == correlation_id
+                })
+                .cloned(),
+        )
+    }
+    async fn save(&self, state: &SagaState) -> Result<()> {
+        let mut states = self.states.write().await;
+        let existing = states
+            .iter_mut()
+            .find(|existing| {
+                existing.saga_type == state.saga_type
+                    && existing.correlation_id == state.correlation_id
+            });
+        match existing {
+            Some(existing) => *existing = state.clone(),
+            None => states.push(state.clone()),
+        }
+        Ok(())
+    }
+    async fn expired(&self, now: DateTime<Utc>) -> Result<Vec<SagaState>> {
+        let states = self.states.read().await;
+        Ok(states.iter().filter(|state| state.is_expired(now)).cloned().collect())
+    }
+}
+
+#[cfg(test)]
+pub mod conformance {
+    use super::*;
+    use uuid::Uuid;
+
+    fn state(correlation_id: &str, deadline: Option<DateTime<Utc>>) -> SagaState {
+        let trigger = IntegrationMessage {
+            message_id: Uuid::new_v4(),
+            context: "Test".to_string(),
+            event_type: "Started".to_string(),
+            payload: serde_json::json!({ "aggregate_id": correlation_id }),
+        };
+        SagaState::new("Fulfilment", correlation_id.to_string(), trigger, deadline)
+    }
+
+    pub async fn saves_and_loads_state<S: SagaStore>(store: S) {
+        let mut saved = state("order-1", None);
+        store.save(&saved).await.unwrap();
+        saved.status = SagaStatus::Completed;
+        store.save(&saved).await.unwrap();
+        let loaded = store.load("Fulfilment", "order-1").await.unwrap();
+        assert_eq!(loaded, Some(saved));
+        assert_eq!(store.load("Fulfilment", "order-2").await.unwrap(), None);
+    }
+
+    pub async fn lists_expired_running_sagas<S: SagaStore>(store: S) {
+        let now = Utc::now();
+        let expired = state("order-1", Some(now - chrono::Duration::minutes(1)));
+        let mut completed = state("order-2", Some(now - chrono::Duration::minutes(1)));
+        completed.status = SagaStatus::Completed;
+        store.save(&expired).await.unwrap();
+        store.save(&completed).await.unwrap();
+        store
+            .save(&state("order-3", Some(now + chrono::Duration::minutes(1))))
+            .await
+            .unwrap();
+        store.save(&state("order-4", None)).await.unwrap();
+        assert_eq!(store.expired(now).await.unwrap(), vec![expired]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policies::saga_store::conformance;
+
+    #[tokio::test]
+    async fn saves_and_loads_state() {
+        conformance::saves_and_loads_state(InMemorySagaStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn lists_expired_running_sagas() {
+        conformance::lists_expired_running_sagas(InMemorySagaStore::new()).await;
+    }
+}
@@ -0,0 +1,95 @@
+//! SQLite saga store
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+use crate::policies::saga_store::{SagaState, SagaStatus, SagaStore};
+
+const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS sagas (saga_type TEXT NOT NULL, correlation_id TEXT NOT NULL, running INTEGER NOT NULL, deadline INTEGER, state TEXT NOT NULL, PRIMARY KEY (saga_type, correlation_id))";
+
+/// Saga store persisted in SQLite
+#[derive(Clone)]
+pub struct SqliteSagaStore {
+    pool: SqlitePool,
+}
+
+impl SqliteSagaStore {
+    /// Open a private in-memory database (for tests)
+    pub async fn in_memory() -> Result<Self> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await?;
+        Self::with_pool(pool).await
+    }
+    /// Use an existing pool, such as the event store's, creating the table if needed
+    pub async fn with_pool(pool: SqlitePool) -> Result<Self> {
+        sqlx::query(SCHEMA).execute(&pool).await?;
+        Ok(Self { pool })
+    }
+}
+
+fn decode_states(states: Vec<String>) -> Result<Vec<SagaState>> {
+    states.iter().map(|state| Ok(serde_json::from_str(state)?)).collect()
+}
+
+#[async_trait]
+impl SagaStore for SqliteSagaStore {
+    async fn load(
+        &self,
+        saga_type: &str,
+        correlation_id: &str,
+    ) -> Result<Option<SagaState>> {
+        let state: Option<String> = sqlx::query_scalar(
+                "SELECT state FROM sagas WHERE saga_type = ? AND correlation_id = ?",
+            )
+            .bind(saga_type)
+            .bind(correlation_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(state.map(|state| serde_json::from_str(&state)).transpose()?)
+    }
+    async fn save(&self, state: &SagaState) -> Result<()> {
+        sqlx::query(
+                "INSERT INTO sagas (saga_type, correlation_id, running, deadline, state) VALUES (?, ?, ?, ?, ?) ON CONFLICT (saga_type, correlation_id) DO UPDATE SET running = excluded.running, deadline = excluded.deadline, state = excluded.state",
+            )
+            .bind(&state.saga_type)
+            .bind(&state.correlation_id)
+            .bind(state.status == SagaStatus::Running)
+            .bind(state.deadline.map(|deadline| deadline.timestamp_millis()))
+            .bind(serde_json::to_string(state)?)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+    async fn expired(&self, now: DateTime<Utc>) -> Result<Vec<SagaState>> {
+        let states = sqlx::query_scalar(
+                "SELECT state FROM sagas WHERE running = 1 AND deadline <= ? ORDER BY deadline",
+            )
+            .bind(now.timestamp_millis())
+            .fetch_all(&self.pool)
+            .await?;
+        decode_states(states)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policies::saga_store::conformance;
+
+    #[tokio::test]
+    async fn saves_and_loads_state() {
+        conformance::saves_and_loads_state(SqliteSagaStore::in_memory().await.unwrap())
+            .await;
+    }
+
+    #[tokio::test]
+    async fn lists_expired_running_sagas() {
+        conformance::lists_expired_running_sagas(
+                SqliteSagaStore::in_memory().await.unwrap(),
+            )
+            .await;
+    }
+}
@@ -0,0 +1,161 @@
+//! Read model projections
+
+pub mod order_summary;
+pub mod order_details;
+pub mod projector;
+#[cfg(feature = "sqlx")]
+pub mod sqlite_projection_store;
+
+pub use order_summary::*;
+pub use order_details::*;
+pub use projector::*;
+#[cfg(feature = "sqlx")]
+pub use sqlite_projection_store::*;
+use anyhow::Result;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode, response::Json, routing::get, Router,
+};
+use std::sync::Arc;
+use crate::api::ApiError;
+use crate::infrastructure::event_store::EventStore;
+
+/// Projections of this bounded context
+#[derive(Clone)]
+pub struct ReadModels {
+    pub order_summary: Arc<Projector<OrderSummaryProjection>>,
+    pub order_details: Arc<Projector<OrderDetailsProjection>>,
+}
+
+impl ReadModels {
+    /// Keep rows in memory; they are rebuilt from the event store on every start
+    pub fn in_memory(events: Arc<dyn EventStore>) -> Self {
+        Self {
+            order_summary: Arc::new(
+                Projector::new(events.clone(), Arc::new(InMemoryProjectionStore::new())),
+            ),
+            order_details: Arc::new(
+                Projector::new(events.clone(), Arc::new(InMemoryProjectionStore::new())),
+            ),
+        }
+    }
+    /// Keep rows and checkpoints in SQLite, next to the events
+    #[cfg(feature = "sqlx")]
+    pub async fn sqlite(
+        events: Arc<dyn EventStore>,
+        pool: sqlx::SqlitePool,
+    ) -> Result<Self> {
+        Ok(Self {
+            order_summary: Arc::new(
+                Projector::new(
+                    events.clone(),
+                    Arc::new(
+                        SqliteProjectionStore::with_pool(
+                                pool.clone(),
+                                OrderSummaryProjection::NAME,
+                            )
+                            .await?,
+                    ),
+                ),
+            ),
+            order_details: Arc::new(
+                Projector::new(
+                    events.clone(),
+                    Arc::new(
+                        SqliteProjectionStore::with_pool(
+                                pool.clone(),
+                                OrderDetailsProjection::NAME,
+                            )
+                            .await?,
+                    ),
+                ),
+            ),
+        })
+    }
+    /// Apply events appended since the last checkpoint to every projection
+    pub async fn catch_up(&self) -> Result<usize> {
+        let mut applied = 0;
+        applied += self.order_summary.catch_up().await?;
+        applied += self.order_details.catch_up().await?;
+        Ok(applied)
+    }
+    /// Drop all rows and replay every event into every projection
+    pub async fn rebuild(&self) -> Result<usize> {
+        let mut applied = 0;
+        applied += self.order_summary.rebuild().await?;
+        applied += self.order_details.rebuild().await?;
+        Ok(applied)
+    }
+}
+
+/// Query endpoints over the projections
+pub fn router(read_models: ReadModels) -> Router {
+    Router::new()
+        .route("/read-models/order-summary", get(list_order_summary))
+        .route("/read-models/order-summary/:id", get(get_order_summary))
+        .route("/read-models/order-details", get(list_order_details))
+        .route("/read-models/order-details/:id", get(get_order_details))
+        .with_state(read_models)
+}
+
+fn internal_error(error: anyhow::Error) -> (StatusCode, Json<ApiError>) {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiError {
+            code: "INTERNAL_ERROR".to_string(),
+            message: error.to_string(),
+        }),
+    )
+}
+
+/// List all `OrderSummary` rows
+async fn list_order_summary(
+    State(read_models): State<ReadModels>,
+) -> Result<Json<Vec<OrderSummary>>, (StatusCode, Json<ApiError>)> {
+    read_models.order_summary.list().await.map(Json).map_err(internal_error)
+}
+
+/// Get one `OrderSummary` row by aggregate ID
+async fn get_order_summary(
+    State(read_models): State<ReadModels>,
+    Path(id): Path<String>,
+) -> Result<Json<OrderSummary>, (StatusCode, Json<ApiError>)> {
+    match read_models.order_summary.get(&id).await.map_err(internal_error)? {
+        Some(row) => Ok(Json(row)),
+        None => {
+            Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiError {
+                    code: "NOT_FOUND".to_string(),
+                    message: format!("OrderSummary {} not found", id),
+                }),
+            ))
+        }
+    }
+}
+
+/// List all `OrderDetails` rows
+async fn list_order_details(
+    State(read_models): State<ReadModels>,
+) -> Result<Json<Vec<OrderDetails>>, (StatusCode, Json<ApiError>)> {
+    read_models.order_details.list().await.map(Json).map_err(internal_error)
+}
+
+/// Get one `OrderDetails` row by aggregate ID
+async fn get_order_details(
+    State(read_models): State<ReadModels>,
+    Path(id): Path<String>,
+) -> Result<Json<OrderDetails>, (StatusCode, Json<ApiError>)> {
+    match read_models.order_details.get(&id).await.map_err(internal_error)? {
+        Some(row) => Ok(Json(row)),
+        None => {
+            Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiError {
+                    code: "NOT_FOUND".to_string(),
+                    message: format!("OrderDetails {} not found", id),
+                }),
+            ))
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! OrderDetails projection
+//!
+//! Detailed view of a single order
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::domain::entities::*;
+use crate::domain::{expressions, state};
+use crate::projections::projector::{field, Projection};
+
+/// Detailed view of a single order
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderDetails {
+    pub order_id: Option<OrderId>,
+    pub customer: Option<serde_json::Value>,
+    pub items: Option<serde_json::Value>,
+    pub shipping_address: Option<Address>,
+    pub total_amount: Option<Money>,
+    pub status: Option<OrderStatus>,
+    pub timeline: Option<serde_json::Value>,
+}
+
+/// Events of the `Order` aggregate that update `OrderDetails`
+const UPDATED_BY: [&str; 5] = [
+    "OrderCreated",
+    "OrderPaid",
+    "OrderShipped",
+    "OrderDelivered",
+    "OrderCancelled",
+];
+
+/// Folds domain events into `OrderDetails` rows keyed by aggregate ID
+pub struct OrderDetailsProjection;
+
+impl Projection for OrderDetailsProjection {
+    type Event = Value;
+    type State = Value;
+    type Row = OrderDetails;
+    const NAME: &'static str = "OrderDetails";
+    fn row_id(event: &Value) -> Option<String> {
+        let event_type = event.get("type")?.as_str()?;
+        if !UPDATED_BY.contains(&event_type) {
+            return None;
+        }
+        Some(event.get("aggregate_id")?.as_str()?.to_string())
+    }
+    fn apply(state: &mut Value, event: &Value) {
+        if !state.is_object() {
+            *state = expressions::object([
+                ("order", state::new_order()),
+                ("events", Value::Array(Vec::new())),
+            ]);
+        }
+        state::apply_order(&mut state["order"], event);
+        if let Some(Value::Array(events)) = state.get_mut("events") {
+            events.push(event.clone());
+        }
+    }
+    fn joins(state: &Value) -> Vec<(&'static str, Option<String>)> {
+        let scope = state;
+        vec![
+            (
+                "Customer",
+                expressions::get(&expressions::get(scope, "order"), "customer_id")
+                    .as_str()
+                    .map(str::to_string),
+            ),
+        ]
+    }
+    fn row(state: &Value, joined: &[Option<Value>]) -> Option<OrderDetails> {
+        let scope = state;
+        let mut joined = joined.iter();
+        let scope = &expressions::with(scope, "customer", joined.next()?.as_ref()?);
+        Some(OrderDetails {
+            order_id: field(expressions::get(&expressions::get(scope, "order"), "id")),
+            customer: field(
+                expressions::object([
+                    ("id", expressions::get(&expressions::get(scope, "customer"), "id")),
+                    (
+                        "name",
+                        expressions::get(&expressions::get(scope, "customer"), "name"),
+                    ),
+                    (
+                        "email",
+                        expressions::get(&expressions::get(scope, "customer"), "email"),
+                    ),
+                ]),
+            ),
+            items: field(
+                expressions::map(
+                    &expressions::get(&expressions::get(scope, "order"), "items"),
+                    |item: &serde_json::Value| {
+                        let scope = &expressions::with(scope, "item", item);
+                        expressions::object([
+                            (
+                                "product_id",
+                                expressions::get(
+                                    &expressions::get(scope, "item"),
+                                    "product_id",
+                                ),
+                            ),
+                            (
+                                "product_name",
+                                expressions::get(
+                                    &expressions::get(scope, "item"),
+                                    "product_name",
+                                ),
+                            ),
+                            (
+                                "quantity",
+                                expressions::get(
+                                    &expressions::get(scope, "item"),
+                                    "quantity",
+                                ),
+                            ),
+                            (
+                                "subtotal",
+                                expressions::get(
+                                    &expressions::get(scope, "item"),
+                                    "subtotal",
+                                ),
+                            ),
+                        ])
+                    },
+                ),
+            ),
+            shipping_address: field(
+                expressions::get(&expressions::get(scope, "order"), "shipping_address"),
+            ),
+            total_amount: field(
+                expressions::get(&expressions::get(scope, "order"), "total_amount"),
+            ),
+            status: field(expressions::get(&expressions::get(scope, "order"), "status")),
+            timeline: field(
+                expressions::map(
+                    &expressions::get(scope, "events"),
+                    |event: &serde_json::Value| {
+                        let scope = &expressions::with(scope, "event", event);
+                        expressions::object([
+                            (
+                                "type",
+                                expressions::get(&expressions::get(scope, "event"), "type"),
+                            ),
+                            (
+                                "occurred_at",
+                                expressions::get(
+                                    &expressions::get(scope, "event"),
+                                    "occurred_at",
+                                ),
+                            ),
+                        ])
+                    },
+                ),
+            ),
+        })
+    }
+}
@@ -0,0 +1,100 @@
+//! OrderSummary projection
+//!
+//! Summary view of orders for listing
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::domain::entities::*;
+use crate::domain::{expressions, state};
+use crate::projections::projector::{field, Projection};
+
+/// Summary view of orders for listing
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderSummary {
+    pub order_id: Option<OrderId>,
+    pub order_number: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_email: Option<String>,
+    pub item_count: Option<i64>,
+    pub total_amount: Option<Money>,
+    pub status: Option<OrderStatus>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Events of the `Order` aggregate that update `OrderSummary`
+const UPDATED_BY: [&str; 4] = [
+    "OrderCreated",
+    "OrderPaid",
+    "OrderShipped",
+    "OrderCancelled",
+];
+
+/// Folds domain events into `OrderSummary` rows keyed by aggregate ID
+pub struct OrderSummaryProjection;
+
+impl Projection for OrderSummaryProjection {
+    type Event = Value;
+    type State = Value;
+    type Row = OrderSummary;
+    const NAME: &'static str = "OrderSummary";
+    fn row_id(event: &Value) -> Option<String> {
+        let event_type = event.get("type")?.as_str()?;
+        if !UPDATED_BY.contains(&event_type) {
+            return None;
+        }
+        Some(event.get("aggregate_id")?.as_str()?.to_string())
+    }
+    fn apply(state: &mut Value, event: &Value) {
+        if !state.is_object() {
+            *state = expressions::object([("order", state::new_order())]);
+        }
+        state::apply_order(&mut state["order"], event);
+    }
+    fn joins(state: &Value) -> Vec<(&'static str, Option<String>)> {
+        let scope = state;
+        vec![
+            (
+                "Customer",
+                expressions::get(&expressions::get(scope, "order"), "customer_id")
+                    .as_str()
+                    .map(str::to_string),
+            ),
+        ]
+    }
+    fn row(state: &Value, joined: &[Option<Value>]) -> Option<OrderSummary> {
+        let scope = state;
+        let mut joined = joined.iter();
+        let scope = &expressions::with(
+            scope,
+            "customer",
+            joined.next().and_then(Option::as_ref).unwrap_or(&Value::Null),
+        );
+        Some(OrderSummary {
+            order_id: field(expressions::get(&expressions::get(scope, "order"), "id")),
+            order_number: field(
+                expressions::add(
+                    &serde_json::Value::from("ORD-"),
+                    &expressions::get(&expressions::get(scope, "order"), "id"),
+                ),
+            ),
+            customer_name: field(
+                expressions::get(&expressions::get(scope, "customer"), "name"),
+            ),
+            customer_email: field(
+                expressions::get(&expressions::get(scope, "customer"), "email"),
+            ),
+            item_count: field(
+                expressions::len(
+                    &expressions::get(&expressions::get(scope, "order"), "items"),
+                ),
+            ),
+            total_amount: field(
+                expressions::get(&expressions::get(scope, "order"), "total_amount"),
+            ),
+            status: field(expressions::get(&expressions::get(scope, "order"), "status")),
+            created_at: field(
+                expressions::get(&expressions::get(scope, "order"), "created_at"),
+            ),
+        })
+    }
+}
@@ -0,0 +1,404 @@
+//! Projection runtime
+//!
+//! A `Projector` reads the events appended since its checkpoint, folds them
+//! into the state of their rows, and commits the changed states together
+//! with the new checkpoint. Rows are computed from the state when read,
+//! looking up joined entities at that time. Rebuilding drops all rows and
+//! replays the event store from the start.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use crate::domain::state;
+use crate::infrastructure::event_store::EventStore;
+
+/// Read model built by folding events into a state per row
+///
+/// The state is what the store persists; rows are computed from it,
+/// together with the joined entities, when they are read.
+pub trait Projection: Send + Sync + 'static {
+    type Event: DeserializeOwned + Send + Sync;
+    type State: Clone + Default + Serialize + DeserializeOwned + Send + Sync + 'static;
+    type Row: Send;
+    /// Read model name, used as the storage key of rows and checkpoints
+    const NAME: &'static str;
+    /// ID of the row an event updates, `None` when the event does not concern the read model
+    fn row_id(event: &Self::Event) -> Option<String>;
+    /// Apply an event to the state of its row
+    fn apply(state: &mut Self::State, event: &Self::Event);
+    /// Entities joined into a row, as aggregate type and ID, in join order,
+    /// then the looked up fields
+    fn joins(_state: &Self::State) -> Vec<(&'static str, Option<String>)> {
+        Vec::new()
+    }
+    /// Row of a state and its joined entities, `None` when an inner join found nothing
+    fn row(state: &Self::State, joined: &[Option<Value>]) -> Option<Self::Row>;
+}
+
+/// Finds the entities joined into read model rows
+///
+/// Fields with `custom` or `read_model` sources, which events cannot
+/// fill, are looked up too: as entity `ReadModel.field`, with the ID of
+/// their row. `EventStoreLookup` finds none, so wrap it to fill them.
+#[async_trait]
+pub trait EntityLookup: Send + Sync {
+    /// State of an entity, `None` when it does not exist
+    async fn find(&self, entity: &str, id: &str) -> Result<Option<Value>>;
+}
+
+/// Folds the events of joined aggregates of this context
+pub struct EventStoreLookup {
+    events: Arc<dyn EventStore>,
+}
+
+impl EventStoreLookup {
+    pub fn new(events: Arc<dyn EventStore>) -> Self {
+        Self { events }
+    }
+}
+
+#[async_trait]
+impl EntityLookup for EventStoreLookup {
+    async fn find(&self, entity: &str, id: &str) -> Result<Option<Value>> {
+        let events = self
+            .events
+            .load_events(id, entity)
+            .await?
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<serde_json::Result<Vec<_>>>()?;
+        if events.is_empty() {
+            return Ok(None);
+        }
+        Ok(state::load(entity, &events))
+    }
+}
+
+/// Typed row field from a computed value, `None` for `null` or a value of another shape
+pub fn field<T: DeserializeOwned>(value: Value) -> Option<T> {
+    if value.is_null() {
+        return None;
+    }
+    serde_json::from_value(value).ok()
+}
+
+/// Storage for the rows and checkpoint of one projection
+#[async_trait]
+pub trait ProjectionStore<R>: Send + Sync {
+    /// Load a row
+    async fn get(&self, id: &str) -> Result<Option<R>>;
+    /// All rows ordered by ID
+    async fn list(&self) -> Result<Vec<R>>;
+    /// Number of events applied so far
+    async fn checkpoint(&self) -> Result<i64>;
+    /// Save changed rows and the new checkpoint in one write
+    async fn commit(&self, rows: Vec<(String, R)>, checkpoint: i64) -> Result<()>;
+    /// Remove all rows and reset the checkpoint
+    async fn reset(&self) -> Result<()>;
+}
+
+struct InMemoryRows<R> {
+    rows: BTreeMap<String, R>,
+    checkpoint: i64,
+}
+
+/// In-memory projection store (for development/testing)
+pub struct InMemoryProjectionStore<R> {
+    state: RwLock<InMemoryRows<R>>,
+}
+
+impl<R> InMemoryProjectionStore<R> {
+    pub fn new() -> Self {
+        Self {
+            state: RwLock::new(InMemoryRows {
+                rows: BTreeMap::new(),
+                checkpoint: 0,
+            }),
+        }
+    }
+}
+
+impl<R> Default for InMemoryProjectionStore<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<R: Clone + Send + Sync> ProjectionStore<R> for InMemoryProjectionStore<R> {
+    async fn get(&self, id: &str) -> Result<Option<R>> {
+        let state = self.state.read().await;
+        Ok(state.rows.get(id).cloned())
+    }
+    async fn list(&self) -> Result<Vec<R>> {
+        let state = self.state.read().await;
+        Ok(state.rows.values().cloned().collect())
+    }
+    async fn checkpoint(&self) -> Result<i64> {
+        Ok(self.state.read().await.checkpoint)
+    }
+    async fn commit(&self, rows: Vec<(String, R)>, checkpoint: i64) -> Result<()> {
+        let mut state = self.state.write().await;
+        state.rows.extend(rows);
+        state.checkpoint = checkpoint;
+        Ok(())
+    }
+    async fn reset(&self) -> Result<()> {
+        let mut state = self.state.write().await;
+        state.rows.clear();
+        state.checkpoint = 0;
+        Ok(())
+    }
+}
+
+/// Keeps a projection up to date with the event store
+pub struct Projector<P: Projection> {
+    events: Arc<dyn EventStore>,
+    store: Arc<dyn ProjectionStore<P::State>>,
+    lookup: Arc<dyn EntityLookup>,
+    projection: PhantomData<P>,
+}
+
+impl<P: Projection> Projector<P> {
+    pub fn new(
+        events: Arc<dyn EventStore>,
+        store: Arc<dyn ProjectionStore<P::State>>,
+    ) -> Self {
+        Self {
+            lookup: Arc::new(EventStoreLookup::new(events.clone())),
+            events,
+            store,
+            projection: PhantomData,
+        }
+    }
+    /// Find joined entities with `lookup` instead of this context's event store
+    pub fn with_lookup(mut self, lookup: Arc<dyn EntityLookup>) -> Self {
+        self.lookup = lookup;
+        self
+    }
+    /// Load a row
+    pub async fn get(&self, id: &str) -> Result<Option<P::Row>> {
+        match self.store.get(id).await? {
+            Some(state) => self.row(&state).await,
+            None => Ok(None),
+        }
+    }
+    /// All rows ordered by ID
+    pub async fn list(&self) -> Result<Vec<P::Row>> {
+        let mut rows = Vec::new();
+        for state in self.store.list().await? {
+            rows.extend(self.row(&state).await?);
+        }
+        Ok(rows)
+    }
+    async fn row(&self, state: &P::State) -> Result<Option<P::Row>> {
+        let mut joined = Vec::new();
+        for (entity, id) in P::joins(state) {
+            joined
+                .push(
+                    match id {
+                        Some(id) => self.lookup.find(entity, &id).await?,
+                        None => None,
+                    },
+                );
+        }
+        Ok(P::row(state, &joined))
+    }
+    /// Apply the events appended since the checkpoint, returning how many were read
+    pub async fn catch_up(&self) -> Result<usize> {
+        let checkpoint = self.store.checkpoint().await?;
+        let payloads = self.events.read_all_from(checkpoint).await?;
+        if payloads.is_empty() {
+            return Ok(0);
+        }
+        let mut states: BTreeMap<String, P::State> = BTreeMap::new();
+        for payload in &payloads {
+            let event: P::Event = serde_json::from_value(payload.clone())?;
+            let Some(id) = P::row_id(&event) else {
+                continue;
+            };
+            let state = match states.entry(id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let state = self.store.get(entry.key()).await?.unwrap_or_default();
+                    entry.insert(state)
+                }
+            };
+            P::apply(state, &event);
+        }
+        let checkpoint = checkpoint + i64::try_from(payloads.len())?;
+        self.store.commit(states.into_iter().collect(), checkpoint).await?;
+        Ok(payloads.len())
+    }
+    /// Drop all rows and replay every event, returning how many were read
+    pub async fn rebuild(&self) -> Result<usize> {
+        self.store.reset().await?;
+        self.catch_up().await
+    }
+}
+
+#[cfg(test)]
+pub mod conformance {
+    use super::*;
+    use crate::infrastructure::event_bus::IntegrationMessage;
+    use crate::infrastructure::event_store::InMemoryEventStore;
+    use serde::Deserialize;
+    use uuid::Uuid;
+
+    #[derive(Deserialize)]
+    #[serde(tag = "type")]
+    pub enum AccountEvent {
+        Opened { aggregate_id: String, owner: String },
+        Renamed { aggregate_id: String, owner: String },
+        Audited,
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Account {
+        pub owner: Option<String>,
+        pub changes: i64,
+    }
+
+    pub struct Accounts;
+
+    impl Projection for Accounts {
+        type Event = AccountEvent;
+        type State = Account;
+        type Row = Account;
+        const NAME: &'static str = "Accounts";
+        fn row_id(event: &AccountEvent) -> Option<String> {
+            match event {
+                AccountEvent::Opened { aggregate_id, .. }
+                | AccountEvent::Renamed { aggregate_id, .. } => {
+                    Some(aggregate_id.clone())
+                }
+                AccountEvent::Audited => None,
+            }
+        }
+        fn apply(account: &mut Account, event: &AccountEvent) {
+            if let AccountEvent::Opened { owner, .. }
+            | AccountEvent::Renamed { owner, .. } = event {
+                account.owner = Some(owner.clone());
+                account.changes += 1;
+            }
+        }
+        fn row(account: &Account, _joined: &[Option<Value>]) -> Option<Account> {
+            Some(account.clone())
+        }
+    }
+
+    async fn append(events: &InMemoryEventStore, payload: serde_json::Value) {
+        let message = IntegrationMessage {
+            message_id: Uuid::new_v4(),
+            context: "Test".to_string(),
+            event_type: payload["type"].as_str().unwrap().to_string(),
+            payload,
+        };
+        let aggregate_id = message
+            .payload["aggregate_id"]
+            .as_str()
+            .unwrap_or("audit")
+            .to_string();
+        events
+            .append_messages(&aggregate_id, "Account", vec![message], None)
+            .await
+            .unwrap();
+    }
+
+    fn opened(id: &str, owner: &str) -> serde_json::Value {
+        serde_json::json!({ "type": "Opened", "aggregate_id": id, "owner": owner })
+    }
+
+    fn renamed(id: &str, owner: &str) -> serde_json::Value {
+        serde_json::json!({ "type": "Renamed", "aggregate_id": id, "owner": owner })
+    }
+
+    fn account(owner: &str, changes: i64) -> Account {
+        Account {
+            owner: Some(owner.to_string()),
+            changes,
+        }
+    }
+
+    pub async fn catch_up_applies_each_event_once<S: ProjectionStore<Account> + 'static>(
+        store: S,
+    ) {
+        let events = Arc::new(InMemoryEventStore::new());
+        let projector = Projector::<Accounts>::new(events.clone(), Arc::new(store));
+        append(&events, opened("acc-1", "Ada")).await;
+        append(&events, serde_json::json!({ "type": "Audited" })).await;
+        assert_eq!(projector.catch_up().await.unwrap(), 2);
+        assert_eq!(projector.catch_up().await.unwrap(), 0);
+        append(&events, renamed("acc-1", "Grace")).await;
+        append(&events, opened("acc-2", "Alan")).await;
+        assert_eq!(projector.catch_up().await.unwrap(), 2);
+        assert_eq!(projector.get("acc-1").await.unwrap(), Some(account("Grace", 2)));
+        assert_eq!(
+            projector.list().await.unwrap(),
+            vec![account("Grace", 2), account("Alan", 1)],
+        );
+        assert_eq!(projector.get("acc-3").await.unwrap(), None);
+    }
+
+    pub async fn checkpoint_survives_restart<S: ProjectionStore<Account> + 'static>(
+        store: S,
+    ) {
+        let events = Arc::new(InMemoryEventStore::new());
+        let store: Arc<dyn ProjectionStore<Account>> = Arc::new(store);
+        append(&events, opened("acc-1", "Ada")).await;
+        Projector::<Accounts>::new(events.clone(), store.clone())
+            .catch_up()
+            .await
+            .unwrap();
+        append(&events, renamed("acc-1", "Grace")).await;
+        let restarted = Projector::<Accounts>::new(events.clone(), store.clone());
+        assert_eq!(restarted.catch_up().await.unwrap(), 1);
+        assert_eq!(store.checkpoint().await.unwrap(), 2);
+        assert_eq!(restarted.get("acc-1").await.unwrap(), Some(account("Grace", 2)));
+    }
+
+    pub async fn rebuild_replays_from_scratch<S: ProjectionStore<Account> + 'static>(
+        store: S,
+    ) {
+        let events = Arc::new(InMemoryEventStore::new());
+        let store: Arc<dyn ProjectionStore<Account>> = Arc::new(store);
+        append(&events, opened("acc-1", "Ada")).await;
+        append(&events, renamed("acc-1", "Grace")).await;
+        let projector = Projector::<Accounts>::new(events.clone(), store.clone());
+        projector.catch_up().await.unwrap();
+        store
+            .commit(vec![("stale".to_string(), account("Nobody", 9))], 2)
+            .await
+            .unwrap();
+        assert_eq!(projector.rebuild().await.unwrap(), 2);
+        assert_eq!(projector.list().await.unwrap(), vec![account("Grace", 2)]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projections::projector::conformance;
+
+    #[tokio::test]
+    async fn catch_up_applies_each_event_once() {
+        conformance::catch_up_applies_each_event_once(InMemoryProjectionStore::new())
+            .await;
+    }
+
+    #[tokio::test]
+    async fn checkpoint_survives_restart() {
+        conformance::checkpoint_survives_restart(InMemoryProjectionStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn rebuild_replays_from_scratch() {
+        conformance::rebuild_replays_from_scratch(InMemoryProjectionStore::new()).await;
+    }
+}
@@ -0,0 +1,140 @@
+//! SQLite projection store
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+use std::marker::PhantomData;
+use crate::projections::projector::ProjectionStore;
+
+const SCHEMA: [&str; 2] = [
+    "CREATE TABLE IF NOT EXISTS read_models (projection TEXT NOT NULL, id TEXT NOT NULL, row TEXT NOT NULL, PRIMARY KEY (projection, id))",
+    "CREATE TABLE IF NOT EXISTS projection_checkpoints (projection TEXT PRIMARY KEY, position INTEGER NOT NULL)",
+];
+
+/// Projection store persisted in SQLite
+pub struct SqliteProjectionStore<R> {
+    pool: SqlitePool,
+    projection: String,
+    rows: PhantomData<fn() -> R>,
+}
+
+impl<R> SqliteProjectionStore<R> {
+    /// Open a private in-memory database (for tests)
+    pub async fn in_memory(projection: &str) -> Result<Self> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await?;
+        Self::with_pool(pool, projection).await
+    }
+    /// Use an existing pool, such as the event store's, creating the tables if needed
+    pub async fn with_pool(pool: SqlitePool, projection: &str) -> Result<Self> {
+        for statement in SCHEMA {
+            sqlx::query(statement).execute(&pool).await?;
+        }
+        Ok(Self {
+            pool,
+            projection: projection.to_string(),
+            rows: PhantomData,
+        })
+    }
+}
+
+#[async_trait]
+impl<R: Serialize + DeserializeOwned + Send + Sync> ProjectionStore<R>
+for SqliteProjectionStore<R> {
+    async fn get(&self, id: &str) -> Result<Option<R>> {
+        let row: Option<String> = sqlx::query_scalar(
+                "SELECT row FROM read_models WHERE projection = ? AND id = ?",
+            )
+            .bind(&self.projection)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.map(|row| serde_json::from_str(&row)).transpose()?)
+    }
+    async fn list(&self) -> Result<Vec<R>> {
+        let rows: Vec<String> = sqlx::query_scalar(
+                "SELECT row FROM read_models WHERE projection = ? ORDER BY id",
+            )
+            .bind(&self.projection)
+            .fetch_all(&self.pool)
+            .await?;
+        rows.iter().map(|row| Ok(serde_json::from_str(row)?)).collect()
+    }
+    async fn checkpoint(&self) -> Result<i64> {
+        let position: Option<i64> = sqlx::query_scalar(
+                "SELECT position FROM projection_checkpoints WHERE projection = ?",
+            )
+            .bind(&self.projection)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(position.unwrap_or(0))
+    }
+    async fn commit(&self, rows: Vec<(String, R)>, checkpoint: i64) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        for (id, row) in rows {
+            sqlx::query(
+                    "INSERT INTO read_models (projection, id, row) VALUES (?, ?, ?) ON CONFLICT (projection, id) DO UPDATE SET row = excluded.row",
+                )
+                .bind(&self.projection)
+                .bind(id)
+                .bind(serde_json::to_string(&row)?)
+                .execute(&mut *tx)
+                .await?;
+        }
+        sqlx::query(
+                "INSERT INTO projection_checkpoints (projection, position) VALUES (?, ?) ON CONFLICT (projection) DO UPDATE SET position = excluded.position",
+            )
+            .bind(&self.projection)
+            .bind(checkpoint)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+    async fn reset(&self) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM read_models WHERE projection = ?")
+            .bind(&self.projection)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM projection_checkpoints WHERE projection = ?")
+            .bind(&self.projection)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projections::projector::conformance;
+
+    #[tokio::test]
+    async fn catch_up_applies_each_event_once() {
+        conformance::catch_up_applies_each_event_once(
+                SqliteProjectionStore::in_memory("Accounts").await.unwrap(),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn checkpoint_survives_restart() {
+        conformance::checkpoint_survives_restart(
+                SqliteProjectionStore::in_memory("Accounts").await.unwrap(),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn rebuild_replays_from_scratch() {
+        conformance::rebuild_replays_from_scratch(
+                SqliteProjectionStore::in_memory("Accounts").await.unwrap(),
+            )
+            .await;
+    }
+}
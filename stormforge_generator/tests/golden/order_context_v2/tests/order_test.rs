@@ -0,0 +1,77 @@
+//! Starter tests of the `Order` aggregate derived from the IR
+
+mod support;
+
+use support::order::*;
+
+/// `CreateOrder` produces `OrderCreated`
+#[tokio::test]
+async fn create_order_produces_order_created() {
+    scenario().given(vec![]).await.when(create_order()).await.then(&["OrderCreated"]);
+}
+
+/// `ConfirmPayment` produces `OrderPaid`
+#[tokio::test]
+async fn confirm_payment_produces_order_paid() {
+    scenario()
+        .given(vec![order_created()])
+        .await
+        .when(confirm_payment())
+        .await
+        .then(&["OrderPaid"]);
+}
+
+/// `ConfirmPayment` is rejected unless `order.status == CREATED`
+#[tokio::test]
+async fn confirm_payment_fails_precondition() {
+    scenario()
+        .given(vec![order_created(), order_paid()])
+        .await
+        .when(confirm_payment())
+        .await
+        .then_error("Can only confirm payment for orders in CREATED status");
+}
+
+/// `ShipOrder` produces `OrderShipped`
+#[tokio::test]
+async fn ship_order_produces_order_shipped() {
+    scenario()
+        .given(vec![order_created(), order_paid()])
+        .await
+        .when(ship_order())
+        .await
+        .then(&["OrderShipped"]);
+}
+
+/// `ShipOrder` is rejected unless `order.status == PAID`
+#[tokio::test]
+async fn ship_order_fails_precondition() {
+    scenario()
+        .given(vec![order_created()])
+        .await
+        .when(ship_order())
+        .await
+        .then_error("Can only ship orders that have been paid");
+}
+
+/// `CancelOrder` produces `OrderCancelled`
+#[tokio::test]
+async fn cancel_order_produces_order_cancelled() {
+    scenario()
+        .given(vec![order_created()])
+        .await
+        .when(cancel_order())
+        .await
+        .then(&["OrderCancelled"]);
+}
+
+/// `CancelOrder` is rejected unless `order.status in [CREATED, PAID]`
+#[tokio::test]
+async fn cancel_order_fails_precondition() {
+    scenario()
+        .given(vec![order_created(), order_shipped()])
+        .await
+        .when(cancel_order())
+        .await
+        .then_error("Can only cancel orders that have not been shipped");
+}
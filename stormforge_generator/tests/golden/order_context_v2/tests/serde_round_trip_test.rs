@@ -0,0 +1,121 @@
+//! Serde round trips of the domain types with generated values
+
+mod support;
+
+use proptest::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use support::strategies::{self, check};
+use ::order::domain::events::DomainEvent;
+
+/// Decode a value's JSON and expect the result to encode to the same JSON
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> Result<(), TestCaseError> {
+    let json = serde_json::to_value(value)
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    let decoded: T = serde_json::from_value(json.clone())
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    let encoded = serde_json::to_value(&decoded)
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    prop_assert_eq!(encoded, json);
+    Ok(())
+}
+
+#[test]
+fn order_id_round_trips() {
+    check(strategies::order_id(), |value| round_trip(&value));
+}
+
+#[test]
+fn customer_id_round_trips() {
+    check(strategies::customer_id(), |value| round_trip(&value));
+}
+
+#[test]
+fn product_id_round_trips() {
+    check(strategies::product_id(), |value| round_trip(&value));
+}
+
+#[test]
+fn order_item_round_trips() {
+    check(strategies::order_item(), |value| round_trip(&value));
+}
+
+#[test]
+fn money_round_trips() {
+    check(strategies::money(), |value| round_trip(&value));
+}
+
+#[test]
+fn address_round_trips() {
+    check(strategies::address(), |value| round_trip(&value));
+}
+
+#[test]
+fn order_status_round_trips() {
+    check(strategies::order_status(), |value| round_trip(&value));
+}
+
+#[test]
+fn order_round_trips() {
+    check(strategies::order(), |value| round_trip(&value));
+}
+
+#[test]
+fn create_order_round_trips() {
+    check(strategies::create_order(), |value| round_trip(&value));
+}
+
+#[test]
+fn confirm_payment_round_trips() {
+    check(strategies::confirm_payment(), |value| round_trip(&value));
+}
+
+#[test]
+fn ship_order_round_trips() {
+    check(strategies::ship_order(), |value| round_trip(&value));
+}
+
+#[test]
+fn cancel_order_round_trips() {
+    check(strategies::cancel_order(), |value| round_trip(&value));
+}
+
+#[test]
+fn order_created_round_trips() {
+    check(
+        strategies::order_created(),
+        |event| { round_trip(&DomainEvent::OrderCreated(event)) },
+    );
+}
+
+#[test]
+fn order_paid_round_trips() {
+    check(
+        strategies::order_paid(),
+        |event| { round_trip(&DomainEvent::OrderPaid(event)) },
+    );
+}
+
+#[test]
+fn order_shipped_round_trips() {
+    check(
+        strategies::order_shipped(),
+        |event| { round_trip(&DomainEvent::OrderShipped(event)) },
+    );
+}
+
+#[test]
+fn order_delivered_round_trips() {
+    check(
+        strategies::order_delivered(),
+        |event| { round_trip(&DomainEvent::OrderDelivered(event)) },
+    );
+}
+
+#[test]
+fn order_cancelled_round_trips() {
+    check(
+        strategies::order_cancelled(),
+        |event| { round_trip(&DomainEvent::OrderCancelled(event)) },
+    );
+}
@@ -0,0 +1,161 @@
+//! Scenario and samples of the `Order` aggregate
+
+use super::Scenario;
+use ::order::domain::commands::*;
+use ::order::domain::entities::*;
+use ::order::domain::events::*;
+
+pub const AGGREGATE_TYPE: &str = "Order";
+
+/// ID of the aggregate in scenarios and sample events
+pub const AGGREGATE_ID: &str = "order-1";
+
+/// Scenario of the sample `Order`
+pub fn scenario() -> Scenario {
+    Scenario::new(AGGREGATE_TYPE, AGGREGATE_ID)
+}
+
+/// Sample `CreateOrder` command
+pub fn create_order() -> CreateOrder {
+    CreateOrder {
+        customer_id: CustomerId("customer-1".to_string()),
+        items: Vec::from([
+            OrderItem {
+                product_id: ProductId("product-1".to_string()),
+                product_name: "Sample 1".to_string(),
+                quantity: 1,
+                unit_price: Money {
+                    amount: "10.00".parse().unwrap(),
+                    currency: "AAA".to_string(),
+                },
+                subtotal: Money {
+                    amount: "10.00".parse().unwrap(),
+                    currency: "AAA".to_string(),
+                },
+            },
+        ]),
+        shipping_address: Address {
+            street: "Sample 1".to_string(),
+            city: "Sample 1".to_string(),
+            province: "Sample 1".to_string(),
+            postal_code: "000000".to_string(),
+            country: "sample".to_string(),
+        },
+    }
+}
+
+/// Sample `ConfirmPayment` command
+pub fn confirm_payment() -> ConfirmPayment {
+    ConfirmPayment {
+        order_id: OrderId("order-1".to_string()),
+        payment_id: "sample".to_string(),
+    }
+}
+
+/// Sample `ShipOrder` command
+pub fn ship_order() -> ShipOrder {
+    ShipOrder {
+        order_id: OrderId("order-1".to_string()),
+        tracking_number: "sample".to_string(),
+        carrier: "sample".to_string(),
+    }
+}
+
+/// Sample `CancelOrder` command
+pub fn cancel_order() -> CancelOrder {
+    CancelOrder {
+        order_id: OrderId("order-1".to_string()),
+        reason: "sample".to_string(),
+    }
+}
+
+/// Sample `OrderCreated` event
+pub fn order_created() -> DomainEvent {
+    DomainEvent::OrderCreated(
+        OrderCreated::new(
+            AGGREGATE_ID.to_string(),
+            OrderId("order-1".to_string()),
+            CustomerId("customer-1".to_string()),
+            Vec::from([
+                OrderItem {
+                    product_id: ProductId("product-1".to_string()),
+                    product_name: "Sample 1".to_string(),
+                    quantity: 1,
+                    unit_price: Money {
+                        amount: "10.00".parse().unwrap(),
+                        currency: "AAA".to_string(),
+                    },
+                    subtotal: Money {
+                        amount: "10.00".parse().unwrap(),
+                        currency: "AAA".to_string(),
+                    },
+                },
+            ]),
+            Address {
+                street: "Sample 1".to_string(),
+                city: "Sample 1".to_string(),
+                province: "Sample 1".to_string(),
+                postal_code: "000000".to_string(),
+                country: "sample".to_string(),
+            },
+            Money {
+                amount: "10.00".parse().unwrap(),
+                currency: "AAA".to_string(),
+            },
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `OrderPaid` event
+pub fn order_paid() -> DomainEvent {
+    DomainEvent::OrderPaid(
+        OrderPaid::new(
+            AGGREGATE_ID.to_string(),
+            OrderId("order-1".to_string()),
+            "sample".to_string(),
+            Money {
+                amount: "10.00".parse().unwrap(),
+                currency: "AAA".to_string(),
+            },
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `OrderShipped` event
+pub fn order_shipped() -> DomainEvent {
+    DomainEvent::OrderShipped(
+        OrderShipped::new(
+            AGGREGATE_ID.to_string(),
+            OrderId("order-1".to_string()),
+            "sample".to_string(),
+            "sample".to_string(),
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `OrderDelivered` event
+pub fn order_delivered() -> DomainEvent {
+    DomainEvent::OrderDelivered(
+        OrderDelivered::new(
+            AGGREGATE_ID.to_string(),
+            OrderId("order-1".to_string()),
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+            "sample".to_string(),
+        ),
+    )
+}
+
+/// Sample `OrderCancelled` event
+pub fn order_cancelled() -> DomainEvent {
+    DomainEvent::OrderCancelled(
+        OrderCancelled::new(
+            AGGREGATE_ID.to_string(),
+            OrderId("order-1".to_string()),
+            "sample".to_string(),
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
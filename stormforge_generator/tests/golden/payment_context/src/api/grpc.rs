@@ -0,0 +1,311 @@
+//! gRPC API serving the command and query handlers of the HTTP API
+//!
+//! Implements `PaymentService` from `proto/payment.proto`.
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use rust_decimal::Decimal;
+use std::net::SocketAddr;
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+use crate::api::routes::*;
+use crate::domain::{commands::*, entities::*};
+
+/// Types and service generated from `proto/payment.proto`
+pub mod proto {
+    tonic::include_proto!("acme.payment");
+}
+
+use proto::payment_service_server::{PaymentService, PaymentServiceServer};
+
+/// Serve the gRPC API on the given address, over the state shared with the HTTP API
+pub async fn serve(
+    addr: SocketAddr,
+    state: AppState,
+) -> Result<(), tonic::transport::Error> {
+    tonic::transport::Server::builder()
+        .add_service(PaymentServiceServer::new(GrpcApi { state }))
+        .serve(addr)
+        .await
+}
+
+/// gRPC service delegating to the API's command and query handlers
+pub struct GrpcApi {
+    pub state: AppState,
+}
+
+#[tonic::async_trait]
+impl PaymentService for GrpcApi {
+    /// Initiate a payment for an order
+    async fn initiate_payment(
+        &self,
+        request: Request<proto::InitiatePaymentRequest>,
+    ) -> Result<Response<proto::InitiatePaymentResponse>, Status> {
+        let command = InitiatePayment::from_proto(request.into_inner())?;
+        let data = execute_initiate_payment(&self.state, command).await?;
+        Ok(
+            Response::new(proto::InitiatePaymentResponse {
+                data,
+            }),
+        )
+    }
+    /// Mark payment as completed
+    async fn complete_payment(
+        &self,
+        request: Request<proto::CompletePaymentRequest>,
+    ) -> Result<Response<proto::CompletePaymentResponse>, Status> {
+        let command = CompletePayment::from_proto(request.into_inner())?;
+        let data = execute_complete_payment(&self.state, command).await?;
+        Ok(
+            Response::new(proto::CompletePaymentResponse {
+                data,
+            }),
+        )
+    }
+    /// Mark payment as failed
+    async fn fail_payment(
+        &self,
+        request: Request<proto::FailPaymentRequest>,
+    ) -> Result<Response<proto::FailPaymentResponse>, Status> {
+        let command = FailPayment::from_proto(request.into_inner())?;
+        let data = execute_fail_payment(&self.state, command).await?;
+        Ok(Response::new(proto::FailPaymentResponse { data }))
+    }
+    /// Refund a completed payment
+    async fn refund_payment(
+        &self,
+        request: Request<proto::RefundPaymentRequest>,
+    ) -> Result<Response<proto::RefundPaymentResponse>, Status> {
+        let command = RefundPayment::from_proto(request.into_inner())?;
+        let data = execute_refund_payment(&self.state, command).await?;
+        Ok(
+            Response::new(proto::RefundPaymentResponse {
+                data,
+            }),
+        )
+    }
+    /// Get payment by ID
+    async fn get_payment(
+        &self,
+        _request: Request<proto::GetPaymentRequest>,
+    ) -> Result<Response<proto::GetPaymentResponse>, Status> {
+        let data = execute_get_payment().await?;
+        Ok(Response::new(proto::GetPaymentResponse { data }))
+    }
+    /// Get payment for an order
+    async fn get_payment_by_order(
+        &self,
+        _request: Request<proto::GetPaymentByOrderRequest>,
+    ) -> Result<Response<proto::GetPaymentByOrderResponse>, Status> {
+        let data = execute_get_payment_by_order().await?;
+        Ok(
+            Response::new(proto::GetPaymentByOrderResponse {
+                data,
+            }),
+        )
+    }
+}
+
+impl From<ApiError> for Status {
+    fn from(error: ApiError) -> Self {
+        let code = match error.code.as_str() {
+            "VALIDATION_ERROR" => tonic::Code::InvalidArgument,
+            "NOT_FOUND" => tonic::Code::NotFound,
+            "PRECONDITION_FAILED" => tonic::Code::FailedPrecondition,
+            _ => tonic::Code::Internal,
+        };
+        Status::new(code, error.message)
+    }
+}
+
+/// Conversion of a decoded protobuf value into a domain value
+pub trait FromProto<T>: Sized {
+    fn from_proto(value: T) -> Result<Self, Status>;
+}
+
+/// Value of a message field the domain type requires
+fn required<T>(value: Option<T>, field: &str) -> Result<T, Status> {
+    value.ok_or_else(|| Status::invalid_argument(format!("Missing field: {}", field)))
+}
+
+fn invalid(field: &str, error: impl std::fmt::Display) -> Status {
+    Status::invalid_argument(format!("Invalid {}: {}", field, error))
+}
+
+impl FromProto<String> for String {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+impl FromProto<i64> for i64 {
+    fn from_proto(value: i64) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+impl FromProto<bool> for bool {
+    fn from_proto(value: bool) -> Result<Self, Status> {
+        Ok(value)
+    }
+}
+
+/// Decimals are strings to keep their precision
+impl FromProto<String> for Decimal {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("decimal", e))
+    }
+}
+
+impl FromProto<String> for Uuid {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("UUID", e))
+    }
+}
+
+/// Dates are `YYYY-MM-DD` strings
+impl FromProto<String> for NaiveDate {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("date", e))
+    }
+}
+
+impl FromProto<String> for NaiveTime {
+    fn from_proto(value: String) -> Result<Self, Status> {
+        value.parse().map_err(|e| invalid("time", e))
+    }
+}
+
+impl FromProto<prost_types::Timestamp> for DateTime<Utc> {
+    fn from_proto(value: prost_types::Timestamp) -> Result<Self, Status> {
+        u32::try_from(value.nanos)
+            .ok()
+            .and_then(|nanos| DateTime::from_timestamp(value.seconds, nanos))
+            .ok_or_else(|| invalid("timestamp", format!("{:?}", value)))
+    }
+}
+
+impl<T: FromProto<P>, P> FromProto<Vec<P>> for Vec<T> {
+    fn from_proto(value: Vec<P>) -> Result<Self, Status> {
+        value.into_iter().map(T::from_proto).collect()
+    }
+}
+
+impl<T: FromProto<P>, P> FromProto<Option<P>> for Option<T> {
+    fn from_proto(value: Option<P>) -> Result<Self, Status> {
+        value.map(T::from_proto).transpose()
+    }
+}
+
+impl FromProto<proto::PaymentId> for PaymentId {
+    fn from_proto(value: proto::PaymentId) -> Result<Self, Status> {
+        Ok(Self(FromProto::from_proto(value.value)?))
+    }
+}
+
+impl FromProto<proto::OrderId> for OrderId {
+    fn from_proto(value: proto::OrderId) -> Result<Self, Status> {
+        Ok(Self(FromProto::from_proto(value.value)?))
+    }
+}
+
+impl FromProto<proto::Money> for Money {
+    fn from_proto(value: proto::Money) -> Result<Self, Status> {
+        Ok(Self {
+            amount: FromProto::from_proto(value.amount)?,
+            currency: FromProto::from_proto(value.currency)?,
+        })
+    }
+}
+
+impl FromProto<i32> for PaymentMethod {
+    fn from_proto(value: i32) -> Result<Self, Status> {
+        match proto::PaymentMethod::try_from(value) {
+            Ok(proto::PaymentMethod::Alipay) => Ok(Self::Alipay),
+            Ok(proto::PaymentMethod::WechatPay) => Ok(Self::WechatPay),
+            Ok(proto::PaymentMethod::CreditCard) => Ok(Self::CreditCard),
+            Ok(proto::PaymentMethod::BankTransfer) => Ok(Self::BankTransfer),
+            _ => {
+                Err(
+                    Status::invalid_argument(format!("Invalid PaymentMethod: {}", value)),
+                )
+            }
+        }
+    }
+}
+
+impl FromProto<i32> for PaymentStatus {
+    fn from_proto(value: i32) -> Result<Self, Status> {
+        match proto::PaymentStatus::try_from(value) {
+            Ok(proto::PaymentStatus::Pending) => Ok(Self::Pending),
+            Ok(proto::PaymentStatus::Processing) => Ok(Self::Processing),
+            Ok(proto::PaymentStatus::Completed) => Ok(Self::Completed),
+            Ok(proto::PaymentStatus::Failed) => Ok(Self::Failed),
+            Ok(proto::PaymentStatus::Refunded) => Ok(Self::Refunded),
+            _ => {
+                Err(
+                    Status::invalid_argument(format!("Invalid PaymentStatus: {}", value)),
+                )
+            }
+        }
+    }
+}
+
+impl FromProto<proto::Payment> for Payment {
+    fn from_proto(value: proto::Payment) -> Result<Self, Status> {
+        Ok(Self {
+            id: FromProto::from_proto(required(value.id, "id")?)?,
+            order_id: FromProto::from_proto(required(value.order_id, "order_id")?)?,
+            amount: FromProto::from_proto(required(value.amount, "amount")?)?,
+            method: FromProto::from_proto(value.method)?,
+            status: FromProto::from_proto(value.status)?,
+            transaction_id: FromProto::from_proto(value.transaction_id)?,
+            created_at: FromProto::from_proto(
+                required(value.created_at, "created_at")?,
+            )?,
+            completed_at: FromProto::from_proto(value.completed_at)?,
+        })
+    }
+}
+
+impl FromProto<proto::InitiatePaymentRequest> for InitiatePayment {
+    fn from_proto(value: proto::InitiatePaymentRequest) -> Result<Self, Status> {
+        Ok(Self {
+            order_id: FromProto::from_proto(required(value.order_id, "order_id")?)?,
+            amount: FromProto::from_proto(required(value.amount, "amount")?)?,
+            method: FromProto::from_proto(value.method)?,
+        })
+    }
+}
+
+impl FromProto<proto::CompletePaymentRequest> for CompletePayment {
+    fn from_proto(value: proto::CompletePaymentRequest) -> Result<Self, Status> {
+        Ok(Self {
+            payment_id: FromProto::from_proto(
+                required(value.payment_id, "payment_id")?,
+            )?,
+            transaction_id: FromProto::from_proto(value.transaction_id)?,
+        })
+    }
+}
+
+impl FromProto<proto::FailPaymentRequest> for FailPayment {
+    fn from_proto(value: proto::FailPaymentRequest) -> Result<Self, Status> {
+        Ok(Self {
+            payment_id: FromProto::from_proto(
+                required(value.payment_id, "payment_id")?,
+            )?,
+            reason: FromProto::from_proto(value.reason)?,
+        })
+    }
+}
+
+impl FromProto<proto::RefundPaymentRequest> for RefundPayment {
+    fn from_proto(value: proto::RefundPaymentRequest) -> Result<Self, Status> {
+        Ok(Self {
+            payment_id: FromProto::from_proto(
+                required(value.payment_id, "payment_id")?,
+            )?,
+            refund_amount: FromProto::from_proto(value.refund_amount)?,
+        })
+    }
+}
@@ -0,0 +1,318 @@
+use axum::{
+    extract::State, http::{header, StatusCode},
+    response::{IntoResponse, Json},
+    routing::{get, post},
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::{ToSchema, OpenApi};
+use crate::domain::commands::*;
+use crate::domain::handlers::EventSourcedHandler;
+use crate::infrastructure::event_store::EventStore;
+
+/// API documentation
+#[derive(OpenApi)]
+#[openapi(
+    paths(),
+    components(schemas(ApiError, ApiResponseString)),
+    tags((name = "Payment", description = "Payment API endpoints"))
+)]
+pub struct ApiDoc;
+
+/// AsyncAPI document of the events published and consumed by this service
+pub const ASYNCAPI: &str = include_str!("../../asyncapi.json");
+
+/// OpenAPI document of the HTTP API
+///
+/// The component schemas of the AsyncAPI document are merged in, so
+/// both documents describe payloads with the same schemas.
+pub fn openapi() -> serde_json::Value {
+    let mut openapi = serde_json::to_value(ApiDoc::openapi())
+        .expect("OpenAPI document serializes to JSON");
+    let asyncapi: serde_json::Value = serde_json::from_str(ASYNCAPI)
+        .expect("AsyncAPI document is valid JSON");
+    if let (Some(schemas), Some(shared)) = (
+        openapi
+            .pointer_mut("/components/schemas")
+            .and_then(|schemas| schemas.as_object_mut()),
+        asyncapi.pointer("/components/schemas").and_then(|schemas| schemas.as_object()),
+    ) {
+        for (name, schema) in shared {
+            schemas.entry(name.clone()).or_insert_with(|| schema.clone());
+        }
+    }
+    openapi
+}
+
+/// Serve the AsyncAPI document
+pub async fn asyncapi() -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "application/json")], ASYNCAPI)
+}
+
+/// Standard API error response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiError {
+    pub code: String,
+    pub message: String,
+}
+
+impl ApiError {
+    /// HTTP status of the error code
+    pub fn status_code(&self) -> StatusCode {
+        match self.code.as_str() {
+            "VALIDATION_ERROR" => StatusCode::BAD_REQUEST,
+            "NOT_FOUND" => StatusCode::NOT_FOUND,
+            "PRECONDITION_FAILED" => StatusCode::CONFLICT,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<CommandError> for ApiError {
+    fn from(error: CommandError) -> Self {
+        let code = match &error {
+            CommandError::ValidationError(_) => "VALIDATION_ERROR",
+            CommandError::PreconditionFailed(_) => "PRECONDITION_FAILED",
+            CommandError::AggregateNotFound(_) => "NOT_FOUND",
+            CommandError::InternalError(_) => "INTERNAL_ERROR",
+        };
+        ApiError {
+            code: code.to_string(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Standard API success response (String variant)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiResponseString {
+    pub data: String,
+}
+
+/// State shared by the HTTP, GraphQL and gRPC APIs
+#[derive(Clone)]
+pub struct AppState {
+    /// Event store the commands append to
+    pub event_store: Arc<dyn EventStore>,
+    /// Handler of the commands
+    pub commands: Arc<dyn CommandHandler>,
+}
+
+impl AppState {
+    /// State handling commands with the event-sourced handler over `event_store`
+    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+        Self {
+            commands: Arc::new(EventSourcedHandler::new(event_store.clone())),
+            event_store,
+        }
+    }
+}
+
+/// Create the API router
+pub fn create_router(state: AppState) -> Router {
+    Router::new()
+        .route("/initiate-payment", post(initiate_payment))
+        .route("/complete-payment", post(complete_payment))
+        .route("/fail-payment", post(fail_payment))
+        .route("/refund-payment", post(refund_payment))
+        .route("/get-payment", get(get_payment))
+        .route("/get-payment-by-order", get(get_payment_by_order))
+        .with_state(state)
+}
+
+/// Initiate a payment for an order
+#[utoipa::path(
+    post,
+    path = "/initiate-payment",
+    request_body = InitiatePayment,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn initiate_payment(
+    State(state): State<AppState>,
+    Json(payload): Json<InitiatePayment>,
+) -> impl IntoResponse {
+    match execute_initiate_payment(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `InitiatePayment` command, shared by the HTTP and gRPC APIs
+pub async fn execute_initiate_payment(
+    state: &AppState,
+    command: InitiatePayment,
+) -> Result<String, ApiError> {
+    state.commands.handle_initiate_payment(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Mark payment as completed
+#[utoipa::path(
+    post,
+    path = "/complete-payment",
+    request_body = CompletePayment,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn complete_payment(
+    State(state): State<AppState>,
+    Json(payload): Json<CompletePayment>,
+) -> impl IntoResponse {
+    match execute_complete_payment(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `CompletePayment` command, shared by the HTTP and gRPC APIs
+pub async fn execute_complete_payment(
+    state: &AppState,
+    command: CompletePayment,
+) -> Result<String, ApiError> {
+    state.commands.handle_complete_payment(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Mark payment as failed
+#[utoipa::path(
+    post,
+    path = "/fail-payment",
+    request_body = FailPayment,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn fail_payment(
+    State(state): State<AppState>,
+    Json(payload): Json<FailPayment>,
+) -> impl IntoResponse {
+    match execute_fail_payment(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `FailPayment` command, shared by the HTTP and gRPC APIs
+pub async fn execute_fail_payment(
+    state: &AppState,
+    command: FailPayment,
+) -> Result<String, ApiError> {
+    state.commands.handle_fail_payment(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Refund a completed payment
+#[utoipa::path(
+    post,
+    path = "/refund-payment",
+    request_body = RefundPayment,
+    responses(
+        (
+            status = 200,
+            description = "Command executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 400, description = "Invalid request", body = ApiError),
+        (status = 404, description = "Aggregate not found", body = ApiError),
+        (status = 409, description = "Precondition failed", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn refund_payment(
+    State(state): State<AppState>,
+    Json(payload): Json<RefundPayment>,
+) -> impl IntoResponse {
+    match execute_refund_payment(&state, payload).await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `RefundPayment` command, shared by the HTTP and gRPC APIs
+pub async fn execute_refund_payment(
+    state: &AppState,
+    command: RefundPayment,
+) -> Result<String, ApiError> {
+    state.commands.handle_refund_payment(command).await?;
+    Ok("Command executed successfully".to_string())
+}
+
+/// Get payment by ID
+#[utoipa::path(
+    get,
+    path = "/get-payment",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn get_payment() -> impl IntoResponse {
+    match execute_get_payment().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `GetPayment` query, shared by the HTTP and gRPC APIs
+pub async fn execute_get_payment() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
+
+/// Get payment for an order
+#[utoipa::path(
+    get,
+    path = "/get-payment-by-order",
+    responses(
+        (
+            status = 200,
+            description = "Query executed successfully",
+            body = ApiResponseString
+        ),
+        (status = 404, description = "Not found", body = ApiError),
+        (status = 500, description = "Internal server error", body = ApiError)
+    )
+)]
+pub async fn get_payment_by_order() -> impl IntoResponse {
+    match execute_get_payment_by_order().await {
+        Ok(data) => (StatusCode::OK, Json(ApiResponseString { data })).into_response(),
+        Err(error) => (error.status_code(), Json(error)).into_response(),
+    }
+}
+
+/// Execute the `GetPaymentByOrder` query, shared by the HTTP and gRPC APIs
+pub async fn execute_get_payment_by_order() -> Result<String, ApiError> {
+    Ok("Query result".to_string())
+}
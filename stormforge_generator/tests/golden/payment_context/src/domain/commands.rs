@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+use crate::domain::entities::*;
+use crate::domain::events::*;
+
+pub type CommandResult<T> = Result<T, CommandError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CommandError {
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+    #[error("Aggregate not found: {0}")]
+    AggregateNotFound(String),
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+/// Initiate a payment for an order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "InitiatePaymentInput")
+)]
+pub struct InitiatePayment {
+    pub order_id: OrderId,
+    pub amount: Money,
+    pub method: PaymentMethod,
+}
+
+impl InitiatePayment {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Mark payment as completed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "CompletePaymentInput")
+)]
+pub struct CompletePayment {
+    pub payment_id: PaymentId,
+    pub transaction_id: String,
+}
+
+impl CompletePayment {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Mark payment as failed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "FailPaymentInput")
+)]
+pub struct FailPayment {
+    pub payment_id: PaymentId,
+    pub reason: String,
+}
+
+impl FailPayment {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Refund a completed payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::InputObject),
+    graphql(name = "RefundPaymentInput")
+)]
+pub struct RefundPayment {
+    pub payment_id: PaymentId,
+    /// If not provided, full refund
+    pub refund_amount: Option<Money>,
+}
+
+impl RefundPayment {
+    /// Validate the command
+    pub fn validate(&self) -> CommandResult<()> {
+        Ok(())
+    }
+}
+
+/// Command handler trait for processing commands
+#[async_trait]
+pub trait CommandHandler: Send + Sync {
+    /// Initiate a payment for an order
+    async fn handle_initiate_payment(
+        &self,
+        command: InitiatePayment,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Mark payment as completed
+    async fn handle_complete_payment(
+        &self,
+        command: CompletePayment,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Mark payment as failed
+    async fn handle_fail_payment(
+        &self,
+        command: FailPayment,
+    ) -> CommandResult<Vec<DomainEvent>>;
+    /// Refund a completed payment
+    async fn handle_refund_payment(
+        &self,
+        command: RefundPayment,
+    ) -> CommandResult<Vec<DomainEvent>>;
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "sqlx")]
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct PaymentId(pub String);
+
+impl From<String> for PaymentId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PaymentId> for String {
+    fn from(id: PaymentId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(PaymentId);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct OrderId(pub String);
+
+impl From<String> for OrderId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<OrderId> for String {
+    fn from(id: OrderId) -> Self {
+        id.0
+    }
+}
+
+#[cfg(feature = "graphql")]
+async_graphql::scalar!(OrderId);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::SimpleObject, async_graphql::InputObject),
+    graphql(input_name = "MoneyInput")
+)]
+pub struct Money {
+    pub amount: rust_decimal::Decimal,
+    pub currency: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum PaymentMethod {
+    /// Alipay payment
+    Alipay,
+    /// WeChat Pay
+    WechatPay,
+    /// Credit card payment
+    CreditCard,
+    /// Bank transfer
+    BankTransfer,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
+pub enum PaymentStatus {
+    /// Payment is pending
+    Pending,
+    /// Payment is being processed
+    Processing,
+    /// Payment completed successfully
+    Completed,
+    /// Payment failed
+    Failed,
+    /// Payment has been refunded
+    Refunded,
+}
+
+/// Payment aggregate handling payment lifecycle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(FromRow))]
+#[cfg_attr(
+    feature = "graphql",
+    derive(async_graphql::SimpleObject, async_graphql::InputObject),
+    graphql(input_name = "PaymentInput")
+)]
+pub struct Payment {
+    pub id: PaymentId,
+    pub order_id: OrderId,
+    pub amount: Money,
+    pub method: PaymentMethod,
+    pub status: PaymentStatus,
+    pub transaction_id: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
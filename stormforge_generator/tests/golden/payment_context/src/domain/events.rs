@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::entities::*;
+
+/// Domain event enum containing all events in this bounded context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Union))]
+#[serde(tag = "type")]
+pub enum DomainEvent {
+    PaymentInitiated(PaymentInitiated),
+    PaymentCompleted(PaymentCompleted),
+    PaymentFailed(PaymentFailed),
+    PaymentRefunded(PaymentRefunded),
+}
+
+/// Payment has been initiated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct PaymentInitiated {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub payment_id: PaymentId,
+    pub order_id: OrderId,
+    pub amount: Money,
+    pub method: PaymentMethod,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl PaymentInitiated {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        payment_id: PaymentId,
+        order_id: OrderId,
+        amount: Money,
+        method: PaymentMethod,
+        created_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            payment_id,
+            order_id,
+            amount,
+            method,
+            created_at,
+        }
+    }
+}
+
+/// Payment completed successfully
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct PaymentCompleted {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub payment_id: PaymentId,
+    pub order_id: OrderId,
+    pub transaction_id: String,
+    pub completed_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl PaymentCompleted {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        payment_id: PaymentId,
+        order_id: OrderId,
+        transaction_id: String,
+        completed_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            payment_id,
+            order_id,
+            transaction_id,
+            completed_at,
+        }
+    }
+}
+
+/// Payment has failed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct PaymentFailed {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub payment_id: PaymentId,
+    pub order_id: OrderId,
+    pub reason: String,
+    pub failed_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl PaymentFailed {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        payment_id: PaymentId,
+        order_id: OrderId,
+        reason: String,
+        failed_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            payment_id,
+            order_id,
+            reason,
+            failed_at,
+        }
+    }
+}
+
+/// Payment has been refunded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::SimpleObject))]
+pub struct PaymentRefunded {
+    /// Unique event identifier
+    pub event_id: Uuid,
+    /// Timestamp when the event occurred
+    pub occurred_at: DateTime<Utc>,
+    /// ID of the aggregate that produced this event
+    pub aggregate_id: String,
+    pub payment_id: PaymentId,
+    pub order_id: OrderId,
+    pub refund_amount: Money,
+    pub refunded_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl PaymentRefunded {
+    /// Create a new event instance
+    pub fn new(
+        aggregate_id: String,
+        payment_id: PaymentId,
+        order_id: OrderId,
+        refund_amount: Money,
+        refunded_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            aggregate_id,
+            payment_id,
+            order_id,
+            refund_amount,
+            refunded_at,
+        }
+    }
+}
+
+/// Event store metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventMetadata {
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub aggregate_type: String,
+    pub sequence_number: i64,
+    pub occurred_at: DateTime<Utc>,
+    pub stored_at: DateTime<Utc>,
+    pub correlation_id: Option<Uuid>,
+    pub causation_id: Option<Uuid>,
+}
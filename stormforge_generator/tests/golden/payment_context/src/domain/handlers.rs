@@ -0,0 +1,221 @@
+//! Command handler backed by the event store
+//!
+//! A command is validated, then checked against the state of its
+//! aggregate folded from the stored events. A command carrying the
+//! aggregate's identifier acts on that aggregate, any other command
+//! creates one. Each event takes a field from the `computed` expression
+//! of the event property, else from the command field, the aggregate ID
+//! or the aggregate state of the same name; `DateTime` fields the
+//! command does not give are the time it is handled. The events must
+//! keep the aggregate's invariants, and are appended with their outbox
+//! entries at the version the state was loaded at, so a concurrent
+//! command on the same aggregate fails rather than being lost.
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+use crate::domain::commands::*;
+use crate::domain::events::DomainEvent;
+use crate::domain::{expressions, state};
+use crate::infrastructure::event_store::EventStore;
+
+/// Command handler appending the events of commands to an event store
+pub struct EventSourcedHandler {
+    event_store: Arc<dyn EventStore>,
+}
+
+impl EventSourcedHandler {
+    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+        Self { event_store }
+    }
+    /// State of an aggregate folded from its events, and its version
+    pub async fn load(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+    ) -> CommandResult<(Value, i64)> {
+        let events = self
+            .event_store
+            .load_events(aggregate_id, aggregate_type)
+            .await
+            .map_err(internal)?
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(internal)?;
+        let version = i64::try_from(events.len()).map_err(internal)?;
+        let state = state::load(aggregate_type, &events).unwrap_or(Value::Null);
+        Ok((state, version))
+    }
+    /// Append the events of a command at the version its aggregate was loaded at
+    async fn append(
+        &self,
+        aggregate_type: &str,
+        aggregate_id: &str,
+        events: Vec<DomainEvent>,
+        version: i64,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        self.event_store
+            .append_events(aggregate_id, aggregate_type, events.clone(), Some(version))
+            .await
+            .map_err(internal)?;
+        Ok(events)
+    }
+}
+
+fn internal(error: impl std::fmt::Display) -> CommandError {
+    CommandError::InternalError(error.to_string())
+}
+
+/// Aggregate ID held by a command field
+fn id(value: &Value) -> String {
+    match value {
+        Value::String(id) => id.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Event of a type built from its payload fields
+fn event(
+    event_type: &str,
+    aggregate_id: &str,
+    mut payload: Value,
+) -> CommandResult<DomainEvent> {
+    if let Some(fields) = payload.as_object_mut() {
+        fields.insert("type".to_string(), Value::from(event_type));
+        fields.insert("event_id".to_string(), Value::from(Uuid::new_v4().to_string()));
+        fields.insert("occurred_at".to_string(), expressions::now());
+        fields.insert("aggregate_id".to_string(), Value::from(aggregate_id));
+    }
+    serde_json::from_value(payload)
+        .map_err(|e| {
+            CommandError::InternalError(format!("Cannot build {}: {}", event_type, e))
+        })
+}
+
+#[async_trait]
+impl CommandHandler for EventSourcedHandler {
+    async fn handle_initiate_payment(
+        &self,
+        command: InitiatePayment,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = Uuid::new_v4().to_string();
+        let version = 0;
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "PaymentInitiated",
+                &aggregate_id,
+                expressions::object([
+                    ("payment_id", Value::from(aggregate_id.as_str())),
+                    ("order_id", expressions::get(&command, "order_id")),
+                    ("amount", expressions::get(&command, "amount")),
+                    ("method", expressions::get(&command, "method")),
+                    ("created_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        self.append("Payment", &aggregate_id, events, version).await
+    }
+    async fn handle_complete_payment(
+        &self,
+        command: CompletePayment,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "payment_id"));
+        let (state, version) = self.load("Payment", &aggregate_id).await?;
+        if version == 0 {
+            return Err(CommandError::AggregateNotFound(aggregate_id));
+        }
+        let scope = &expressions::with(&command, "payment", &state);
+        if !expressions::contains(
+            &serde_json::Value::Array(
+                vec![
+                    serde_json::Value::from("Pending"),
+                    serde_json::Value::from("Processing"),
+                ],
+            ),
+            &expressions::get(&expressions::get(scope, "payment"), "status"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Can only complete pending or processing payments".to_string(),
+                ),
+            );
+        }
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "PaymentCompleted",
+                &aggregate_id,
+                expressions::object([
+                    ("payment_id", expressions::get(&command, "payment_id")),
+                    ("order_id", expressions::get(&state, "order_id")),
+                    ("transaction_id", expressions::get(&command, "transaction_id")),
+                    ("completed_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        self.append("Payment", &aggregate_id, events, version).await
+    }
+    async fn handle_fail_payment(
+        &self,
+        command: FailPayment,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "payment_id"));
+        let (state, version) = self.load("Payment", &aggregate_id).await?;
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "PaymentFailed",
+                &aggregate_id,
+                expressions::object([
+                    ("payment_id", expressions::get(&command, "payment_id")),
+                    ("order_id", expressions::get(&state, "order_id")),
+                    ("reason", expressions::get(&command, "reason")),
+                    ("failed_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        self.append("Payment", &aggregate_id, events, version).await
+    }
+    async fn handle_refund_payment(
+        &self,
+        command: RefundPayment,
+    ) -> CommandResult<Vec<DomainEvent>> {
+        command.validate()?;
+        let command = serde_json::to_value(&command).map_err(internal)?;
+        let aggregate_id = id(&expressions::get(&command, "payment_id"));
+        let (state, version) = self.load("Payment", &aggregate_id).await?;
+        if version == 0 {
+            return Err(CommandError::AggregateNotFound(aggregate_id));
+        }
+        let scope = &expressions::with(&command, "payment", &state);
+        if !expressions::eq(
+            &expressions::get(&expressions::get(scope, "payment"), "status"),
+            &serde_json::Value::from("Completed"),
+        ) {
+            return Err(
+                CommandError::PreconditionFailed(
+                    "Can only refund completed payments".to_string(),
+                ),
+            );
+        }
+        let events: Vec<DomainEvent> = vec![
+            event(
+                "PaymentRefunded",
+                &aggregate_id,
+                expressions::object([
+                    ("payment_id", expressions::get(&command, "payment_id")),
+                    ("order_id", expressions::get(&state, "order_id")),
+                    ("refund_amount", expressions::get(&command, "refund_amount")),
+                    ("refunded_at", expressions::now()),
+                ]),
+            )?,
+        ];
+        self.append("Payment", &aggregate_id, events, version).await
+    }
+}
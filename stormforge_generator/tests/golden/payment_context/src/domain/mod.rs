@@ -0,0 +1,10 @@
+pub mod entities;
+pub mod commands;
+pub mod events;
+pub mod expressions;
+pub mod handlers;
+pub mod state;
+
+pub use entities::*;
+pub use commands::*;
+pub use events::*;
@@ -0,0 +1,25 @@
+//! Anti-corruption layer
+//!
+//! Translates integration events from other bounded contexts into
+//! this context's domain model.
+
+use anyhow::Result;
+use crate::integration::contracts;
+
+/// Create pending payment when order is created
+///
+/// Translates `OrderCreated` from the Order context into this context's domain model.
+pub async fn handle_order_created(event: contracts::order::OrderCreated) -> Result<()> {
+    tracing::info!("Received OrderCreated from Order: {:?}", event);
+    Ok(())
+}
+
+/// Refund payment when order is cancelled
+///
+/// Translates `OrderCancelled` from the Order context into this context's domain model.
+pub async fn handle_order_cancelled(
+    event: contracts::order::OrderCancelled,
+) -> Result<()> {
+    tracing::info!("Received OrderCancelled from Order: {:?}", event);
+    Ok(())
+}
@@ -0,0 +1,23 @@
+//! Contracts of the integration events this context subscribes to
+//!
+//! The publishing contexts are not part of this build, so payloads
+//! are kept as untyped JSON objects.
+
+/// Events published by the Order context
+pub mod order {
+    use serde::{Deserialize, Serialize};
+
+    /// Published by the Order context, which is not part of this build
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct OrderCreated {
+        #[serde(flatten)]
+        pub data: serde_json::Map<String, serde_json::Value>,
+    }
+
+    /// Published by the Order context, which is not part of this build
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct OrderCancelled {
+        #[serde(flatten)]
+        pub data: serde_json::Map<String, serde_json::Value>,
+    }
+}
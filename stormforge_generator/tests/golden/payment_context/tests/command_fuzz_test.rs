@@ -0,0 +1,89 @@
+//! Command validation fuzzed with payloads outside the validation rules
+
+mod support;
+
+use proptest::prelude::*;
+use support::strategies::{self, check};
+use ::payment::domain::commands::*;
+
+/// `InitiatePayment` commands ignoring the validation rules
+fn any_initiate_payment() -> BoxedStrategy<InitiatePayment> {
+    (strategies::order_id(), strategies::money(), strategies::payment_method())
+        .prop_map(|(order_id, amount, method)| InitiatePayment {
+            order_id,
+            amount,
+            method,
+        })
+        .boxed()
+}
+
+/// `CompletePayment` commands ignoring the validation rules
+fn any_complete_payment() -> BoxedStrategy<CompletePayment> {
+    (strategies::payment_id(), proptest::string::string_regex("\\PC{0,32}").unwrap())
+        .prop_map(|(payment_id, transaction_id)| CompletePayment {
+            payment_id,
+            transaction_id,
+        })
+        .boxed()
+}
+
+/// `FailPayment` commands ignoring the validation rules
+fn any_fail_payment() -> BoxedStrategy<FailPayment> {
+    (strategies::payment_id(), proptest::string::string_regex("\\PC{0,32}").unwrap())
+        .prop_map(|(payment_id, reason)| FailPayment { payment_id, reason })
+        .boxed()
+}
+
+/// `RefundPayment` commands ignoring the validation rules
+fn any_refund_payment() -> BoxedStrategy<RefundPayment> {
+    (strategies::payment_id(), proptest::option::of(strategies::money()))
+        .prop_map(|(payment_id, refund_amount)| RefundPayment {
+            payment_id,
+            refund_amount,
+        })
+        .boxed()
+}
+
+#[test]
+fn initiate_payment_validation_never_panics() {
+    check(
+        any_initiate_payment(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn complete_payment_validation_never_panics() {
+    check(
+        any_complete_payment(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn fail_payment_validation_never_panics() {
+    check(
+        any_fail_payment(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn refund_payment_validation_never_panics() {
+    check(
+        any_refund_payment(),
+        |command| {
+            let _ = command.validate();
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,71 @@
+//! Starter tests of the `Payment` aggregate derived from the IR
+
+mod support;
+
+use support::payment::*;
+
+/// `InitiatePayment` produces `PaymentInitiated`
+#[tokio::test]
+async fn initiate_payment_produces_payment_initiated() {
+    scenario()
+        .given(vec![])
+        .await
+        .when(initiate_payment())
+        .await
+        .then(&["PaymentInitiated"]);
+}
+
+/// `CompletePayment` produces `PaymentCompleted`
+#[tokio::test]
+async fn complete_payment_produces_payment_completed() {
+    scenario()
+        .given(vec![payment_initiated()])
+        .await
+        .when(complete_payment())
+        .await
+        .then(&["PaymentCompleted"]);
+}
+
+/// `CompletePayment` is rejected unless `payment.status in [PENDING, PROCESSING]`
+#[tokio::test]
+async fn complete_payment_fails_precondition() {
+    scenario()
+        .given(vec![payment_initiated(), payment_completed()])
+        .await
+        .when(complete_payment())
+        .await
+        .then_error("Can only complete pending or processing payments");
+}
+
+/// `FailPayment` produces `PaymentFailed`
+#[tokio::test]
+async fn fail_payment_produces_payment_failed() {
+    scenario()
+        .given(vec![payment_initiated()])
+        .await
+        .when(fail_payment())
+        .await
+        .then(&["PaymentFailed"]);
+}
+
+/// `RefundPayment` produces `PaymentRefunded`
+#[tokio::test]
+async fn refund_payment_produces_payment_refunded() {
+    scenario()
+        .given(vec![payment_initiated(), payment_completed()])
+        .await
+        .when(refund_payment())
+        .await
+        .then(&["PaymentRefunded"]);
+}
+
+/// `RefundPayment` is rejected unless `payment.status == COMPLETED`
+#[tokio::test]
+async fn refund_payment_fails_precondition() {
+    scenario()
+        .given(vec![payment_initiated()])
+        .await
+        .when(refund_payment())
+        .await
+        .then_error("Can only refund completed payments");
+}
@@ -0,0 +1,103 @@
+//! Serde round trips of the domain types with generated values
+
+mod support;
+
+use proptest::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use support::strategies::{self, check};
+use ::payment::domain::events::DomainEvent;
+
+/// Decode a value's JSON and expect the result to encode to the same JSON
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> Result<(), TestCaseError> {
+    let json = serde_json::to_value(value)
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    let decoded: T = serde_json::from_value(json.clone())
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    let encoded = serde_json::to_value(&decoded)
+        .map_err(|error| TestCaseError::fail(error.to_string()))?;
+    prop_assert_eq!(encoded, json);
+    Ok(())
+}
+
+#[test]
+fn payment_id_round_trips() {
+    check(strategies::payment_id(), |value| round_trip(&value));
+}
+
+#[test]
+fn order_id_round_trips() {
+    check(strategies::order_id(), |value| round_trip(&value));
+}
+
+#[test]
+fn money_round_trips() {
+    check(strategies::money(), |value| round_trip(&value));
+}
+
+#[test]
+fn payment_method_round_trips() {
+    check(strategies::payment_method(), |value| round_trip(&value));
+}
+
+#[test]
+fn payment_status_round_trips() {
+    check(strategies::payment_status(), |value| round_trip(&value));
+}
+
+#[test]
+fn payment_round_trips() {
+    check(strategies::payment(), |value| round_trip(&value));
+}
+
+#[test]
+fn initiate_payment_round_trips() {
+    check(strategies::initiate_payment(), |value| round_trip(&value));
+}
+
+#[test]
+fn complete_payment_round_trips() {
+    check(strategies::complete_payment(), |value| round_trip(&value));
+}
+
+#[test]
+fn fail_payment_round_trips() {
+    check(strategies::fail_payment(), |value| round_trip(&value));
+}
+
+#[test]
+fn refund_payment_round_trips() {
+    check(strategies::refund_payment(), |value| round_trip(&value));
+}
+
+#[test]
+fn payment_initiated_round_trips() {
+    check(
+        strategies::payment_initiated(),
+        |event| { round_trip(&DomainEvent::PaymentInitiated(event)) },
+    );
+}
+
+#[test]
+fn payment_completed_round_trips() {
+    check(
+        strategies::payment_completed(),
+        |event| { round_trip(&DomainEvent::PaymentCompleted(event)) },
+    );
+}
+
+#[test]
+fn payment_failed_round_trips() {
+    check(
+        strategies::payment_failed(),
+        |event| { round_trip(&DomainEvent::PaymentFailed(event)) },
+    );
+}
+
+#[test]
+fn payment_refunded_round_trips() {
+    check(
+        strategies::payment_refunded(),
+        |event| { round_trip(&DomainEvent::PaymentRefunded(event)) },
+    );
+}
@@ -0,0 +1,114 @@
+//! Scenario and samples of the `Payment` aggregate
+
+use super::Scenario;
+use ::payment::domain::commands::*;
+use ::payment::domain::entities::*;
+use ::payment::domain::events::*;
+
+pub const AGGREGATE_TYPE: &str = "Payment";
+
+/// ID of the aggregate in scenarios and sample events
+pub const AGGREGATE_ID: &str = "payment-1";
+
+/// Scenario of the sample `Payment`
+pub fn scenario() -> Scenario {
+    Scenario::new(AGGREGATE_TYPE, AGGREGATE_ID)
+}
+
+/// Sample `InitiatePayment` command
+pub fn initiate_payment() -> InitiatePayment {
+    InitiatePayment {
+        order_id: OrderId("order-1".to_string()),
+        amount: Money {
+            amount: "10.00".parse().unwrap(),
+            currency: "sample".to_string(),
+        },
+        method: PaymentMethod::Alipay,
+    }
+}
+
+/// Sample `CompletePayment` command
+pub fn complete_payment() -> CompletePayment {
+    CompletePayment {
+        payment_id: PaymentId("payment-1".to_string()),
+        transaction_id: "sample".to_string(),
+    }
+}
+
+/// Sample `FailPayment` command
+pub fn fail_payment() -> FailPayment {
+    FailPayment {
+        payment_id: PaymentId("payment-1".to_string()),
+        reason: "sample".to_string(),
+    }
+}
+
+/// Sample `RefundPayment` command
+pub fn refund_payment() -> RefundPayment {
+    RefundPayment {
+        payment_id: PaymentId("payment-1".to_string()),
+        refund_amount: Some(Money {
+            amount: "10.00".parse().unwrap(),
+            currency: "sample".to_string(),
+        }),
+    }
+}
+
+/// Sample `PaymentInitiated` event
+pub fn payment_initiated() -> DomainEvent {
+    DomainEvent::PaymentInitiated(
+        PaymentInitiated::new(
+            AGGREGATE_ID.to_string(),
+            PaymentId("payment-1".to_string()),
+            OrderId("order-1".to_string()),
+            Money {
+                amount: "10.00".parse().unwrap(),
+                currency: "sample".to_string(),
+            },
+            PaymentMethod::Alipay,
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `PaymentCompleted` event
+pub fn payment_completed() -> DomainEvent {
+    DomainEvent::PaymentCompleted(
+        PaymentCompleted::new(
+            AGGREGATE_ID.to_string(),
+            PaymentId("payment-1".to_string()),
+            OrderId("order-1".to_string()),
+            "sample".to_string(),
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `PaymentFailed` event
+pub fn payment_failed() -> DomainEvent {
+    DomainEvent::PaymentFailed(
+        PaymentFailed::new(
+            AGGREGATE_ID.to_string(),
+            PaymentId("payment-1".to_string()),
+            OrderId("order-1".to_string()),
+            "sample".to_string(),
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
+
+/// Sample `PaymentRefunded` event
+pub fn payment_refunded() -> DomainEvent {
+    DomainEvent::PaymentRefunded(
+        PaymentRefunded::new(
+            AGGREGATE_ID.to_string(),
+            PaymentId("payment-1".to_string()),
+            OrderId("order-1".to_string()),
+            Money {
+                amount: "10.00".parse().unwrap(),
+                currency: "sample".to_string(),
+            },
+            "2099-01-01T09:00:00Z".parse().unwrap(),
+        ),
+    )
+}
@@ -0,0 +1,296 @@
+//! Proptest strategies for the domain types
+//!
+//! Values respect the IR's validation rules: string lengths and
+//! patterns, numeric ranges and decimal places.
+
+use proptest::prelude::*;
+use proptest::test_runner::TestRunner;
+use ::payment::domain::commands::*;
+use ::payment::domain::entities::*;
+use ::payment::domain::events::*;
+
+/// Run a property against values of a strategy
+///
+/// A failure panics with the smallest failing value proptest finds.
+pub fn check<S: Strategy>(
+    strategy: S,
+    test: impl Fn(S::Value) -> Result<(), TestCaseError>,
+) {
+    let mut runner = TestRunner::default();
+    if let Err(error) = runner.run(&strategy, test) {
+        panic!("{}", error);
+    }
+}
+
+/// `PaymentId` values
+pub fn payment_id() -> BoxedStrategy<PaymentId> {
+    proptest::string::string_regex("[a-z0-9-]{1,16}")
+        .unwrap()
+        .prop_map(PaymentId)
+        .boxed()
+}
+
+/// `OrderId` values
+pub fn order_id() -> BoxedStrategy<OrderId> {
+    proptest::string::string_regex("[a-z0-9-]{1,16}").unwrap().prop_map(OrderId).boxed()
+}
+
+/// `Money` values
+pub fn money() -> BoxedStrategy<Money> {
+    (
+        (0i64..=100000000).prop_map(|units| rust_decimal::Decimal::new(units, 2)),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+    )
+        .prop_map(|(amount, currency)| Money { amount, currency })
+        .boxed()
+}
+
+/// `PaymentMethod` values
+pub fn payment_method() -> BoxedStrategy<PaymentMethod> {
+    proptest::sample::select(
+            Vec::from([
+                PaymentMethod::Alipay,
+                PaymentMethod::WechatPay,
+                PaymentMethod::CreditCard,
+                PaymentMethod::BankTransfer,
+            ]),
+        )
+        .boxed()
+}
+
+/// `PaymentStatus` values
+pub fn payment_status() -> BoxedStrategy<PaymentStatus> {
+    proptest::sample::select(
+            Vec::from([
+                PaymentStatus::Pending,
+                PaymentStatus::Processing,
+                PaymentStatus::Completed,
+                PaymentStatus::Failed,
+                PaymentStatus::Refunded,
+            ]),
+        )
+        .boxed()
+}
+
+/// `Payment` values
+pub fn payment() -> BoxedStrategy<Payment> {
+    (
+        payment_id(),
+        order_id(),
+        money(),
+        payment_method(),
+        payment_status(),
+        proptest::option::of(proptest::string::string_regex("\\PC{0,32}").unwrap()),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::option::of(
+            (0i64..4_102_444_800)
+                .prop_map(|seconds| {
+                    chrono::DateTime::from_timestamp(seconds, 0).unwrap()
+                }),
+        ),
+    )
+        .prop_map(|
+            (
+                id,
+                order_id,
+                amount,
+                method,
+                status,
+                transaction_id,
+                created_at,
+                completed_at,
+            )|
+        Payment {
+            id,
+            order_id,
+            amount,
+            method,
+            status,
+            transaction_id,
+            created_at,
+            completed_at,
+        })
+        .boxed()
+}
+
+/// `InitiatePayment` values
+pub fn initiate_payment() -> BoxedStrategy<InitiatePayment> {
+    (order_id(), money(), payment_method())
+        .prop_map(|(order_id, amount, method)| InitiatePayment {
+            order_id,
+            amount,
+            method,
+        })
+        .boxed()
+}
+
+/// `CompletePayment` values
+pub fn complete_payment() -> BoxedStrategy<CompletePayment> {
+    (payment_id(), proptest::string::string_regex("\\PC{0,32}").unwrap())
+        .prop_map(|(payment_id, transaction_id)| CompletePayment {
+            payment_id,
+            transaction_id,
+        })
+        .boxed()
+}
+
+/// `FailPayment` values
+pub fn fail_payment() -> BoxedStrategy<FailPayment> {
+    (payment_id(), proptest::string::string_regex("\\PC{0,32}").unwrap())
+        .prop_map(|(payment_id, reason)| FailPayment { payment_id, reason })
+        .boxed()
+}
+
+/// `RefundPayment` values
+pub fn refund_payment() -> BoxedStrategy<RefundPayment> {
+    (payment_id(), proptest::option::of(money()))
+        .prop_map(|(payment_id, refund_amount)| RefundPayment {
+            payment_id,
+            refund_amount,
+        })
+        .boxed()
+}
+
+/// `PaymentInitiated` values
+pub fn payment_initiated() -> BoxedStrategy<PaymentInitiated> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        payment_id(),
+        order_id(),
+        money(),
+        payment_method(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                payment_id,
+                order_id,
+                amount,
+                method,
+                created_at,
+            )|
+        PaymentInitiated {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            payment_id,
+            order_id,
+            amount,
+            method,
+            created_at,
+        })
+        .boxed()
+}
+
+/// `PaymentCompleted` values
+pub fn payment_completed() -> BoxedStrategy<PaymentCompleted> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        payment_id(),
+        order_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                payment_id,
+                order_id,
+                transaction_id,
+                completed_at,
+            )|
+        PaymentCompleted {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            payment_id,
+            order_id,
+            transaction_id,
+            completed_at,
+        })
+        .boxed()
+}
+
+/// `PaymentFailed` values
+pub fn payment_failed() -> BoxedStrategy<PaymentFailed> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        payment_id(),
+        order_id(),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                payment_id,
+                order_id,
+                reason,
+                failed_at,
+            )|
+        PaymentFailed {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            payment_id,
+            order_id,
+            reason,
+            failed_at,
+        })
+        .boxed()
+}
+
+/// `PaymentRefunded` values
+pub fn payment_refunded() -> BoxedStrategy<PaymentRefunded> {
+    (
+        any::<u128>().prop_map(uuid::Uuid::from_u128),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+        proptest::string::string_regex("\\PC{0,32}").unwrap(),
+        payment_id(),
+        order_id(),
+        money(),
+        (0i64..4_102_444_800)
+            .prop_map(|seconds| chrono::DateTime::from_timestamp(seconds, 0).unwrap()),
+    )
+        .prop_map(|
+            (
+                event_id,
+                occurred_at,
+                aggregate_id,
+                payment_id,
+                order_id,
+                refund_amount,
+                refunded_at,
+            )|
+        PaymentRefunded {
+            event_id,
+            occurred_at,
+            aggregate_id,
+            payment_id,
+            order_id,
+            refund_amount,
+            refunded_at,
+        })
+        .boxed()
+}
@@ -0,0 +1 @@
+"""Inventory service, generated by StormForge Generator"""
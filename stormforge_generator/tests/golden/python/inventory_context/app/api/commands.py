@@ -0,0 +1,51 @@
+"""Command routes"""
+
+from fastapi import APIRouter, Depends
+
+from app.application.command_handlers import CommandHandlers
+from app.dependencies import get_command_handlers
+from app.domain.commands import AddStock, DeductStock, ReleaseStock, ReserveStock
+from app.schemas import ApiResponse
+
+
+router = APIRouter(tags=["commands"])
+
+
+@router.post(
+    "/add-stock", response_model=ApiResponse[str], summary="Add stock to inventory"
+)
+async def add_stock(
+    command: AddStock, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.add_stock(command))
+
+
+@router.post(
+    "/reserve-stock",
+    response_model=ApiResponse[str],
+    summary="Reserve stock for an order",
+)
+async def reserve_stock(
+    command: ReserveStock, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.reserve_stock(command))
+
+
+@router.post(
+    "/release-stock", response_model=ApiResponse[str], summary="Release reserved stock"
+)
+async def release_stock(
+    command: ReleaseStock, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.release_stock(command))
+
+
+@router.post(
+    "/deduct-stock",
+    response_model=ApiResponse[str],
+    summary="Deduct reserved stock (when order ships)",
+)
+async def deduct_stock(
+    command: DeductStock, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.deduct_stock(command))
@@ -0,0 +1,61 @@
+"""Query routes"""
+
+from fastapi import APIRouter, Depends
+
+from app.application.query_handlers import QueryHandlers
+from app.dependencies import get_query_handlers
+from app.domain.aggregates import Product
+from app.domain.value_objects import ProductId
+from app.schemas import ApiResponse
+
+
+router = APIRouter(tags=["queries"])
+
+
+@router.get(
+    "/get-product",
+    response_model=ApiResponse[Product | None],
+    summary="Get product by ID",
+)
+async def get_product(
+    *, product_id: ProductId, handlers: QueryHandlers = Depends(get_query_handlers)
+) -> ApiResponse[Product | None]:
+    return ApiResponse(data=await handlers.get_product(product_id=product_id))
+
+
+@router.get(
+    "/get-product-by-sku",
+    response_model=ApiResponse[Product | None],
+    summary="Get product by SKU",
+)
+async def get_product_by_sku(
+    *, sku: str, handlers: QueryHandlers = Depends(get_query_handlers)
+) -> ApiResponse[Product | None]:
+    return ApiResponse(data=await handlers.get_product_by_sku(sku=sku))
+
+
+@router.get(
+    "/check-availability",
+    response_model=ApiResponse[bool],
+    summary="Check if quantity is available",
+)
+async def check_availability(
+    *,
+    product_id: ProductId,
+    quantity: int,
+    handlers: QueryHandlers = Depends(get_query_handlers),
+) -> ApiResponse[bool]:
+    return ApiResponse(
+        data=await handlers.check_availability(product_id=product_id, quantity=quantity)
+    )
+
+
+@router.get(
+    "/get-low-stock-products",
+    response_model=ApiResponse[list[Product]],
+    summary="Get products below reorder level",
+)
+async def get_low_stock_products(
+    handlers: QueryHandlers = Depends(get_query_handlers)
+) -> ApiResponse[list[Product]]:
+    return ApiResponse(data=await handlers.get_low_stock_products())
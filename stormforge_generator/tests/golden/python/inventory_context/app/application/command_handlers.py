@@ -0,0 +1,200 @@
+"""Command handlers backed by the event store
+
+A command is validated by its model, then checked against the state of its
+aggregate folded from the stored events. A command carrying the aggregate's
+identifier acts on that aggregate, any other command creates one. Each event
+takes a field from the `computed` expression of the event property, else from
+the command field, the aggregate ID or the aggregate state of the same name;
+`DateTime` fields the command does not give are the time it is handled. The
+events must keep the aggregate's invariants, and are appended at the version
+the state was loaded at, so a concurrent command on the same aggregate fails
+rather than being lost.
+"""
+
+from typing import Any, TypeVar
+from uuid import uuid4
+
+from pydantic import BaseModel, ValidationError
+
+from app.domain import expressions
+from app.domain.commands import AddStock, DeductStock, ReleaseStock, ReserveStock
+from app.domain.events import (
+    DomainEvent,
+    LowStockAlert,
+    StockAdded,
+    StockDeducted,
+    StockReleased,
+    StockReserved,
+)
+from app.domain.state import State, apply_product, fold
+from app.errors import ApiError
+from app.infrastructure.event_store import EventStore
+
+
+E = TypeVar("E", bound=BaseModel)
+
+
+def event(event_type: type[E], aggregate_id: str, fields: dict[str, Any]) -> E:
+    """Event of a type built from its payload fields"""
+    try:
+        return event_type.model_validate({**fields, "aggregate_id": aggregate_id})
+    except ValidationError as error:
+        raise ApiError(
+            "INTERNAL_ERROR", f"Cannot build {event_type.__name__}: {error}"
+        ) from error
+
+
+def check_product(scope: State) -> None:
+    """Invariants of a `Product`
+
+    - `availableQuantity >= 0`: Available quantity cannot be negative
+    - `reservedQuantity >= 0`: Reserved quantity cannot be negative
+    """
+    if not expressions.ge(expressions.get(scope, "available_quantity"), 0):
+        raise ApiError("VALIDATION_ERROR", "Available quantity cannot be negative")
+    if not expressions.ge(expressions.get(scope, "reserved_quantity"), 0):
+        raise ApiError("VALIDATION_ERROR", "Reserved quantity cannot be negative")
+
+
+class CommandHandlers:
+    """Handlers of the Inventory commands
+
+    Each handler loads its aggregate from the event store, executes the
+    command and appends the events it produces.
+    """
+
+    def __init__(self, event_store: EventStore) -> None:
+        self.event_store = event_store
+
+    async def add_stock(self, command: AddStock) -> str:
+        """Add stock to inventory
+
+        Produces `StockAdded`.
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.product_id)
+        history = await self.event_store.load_events(aggregate_id, "Product")
+        state = fold(history, apply_product)
+        version = len(history)
+        scope = {**values, "product": state}
+        events: list[DomainEvent] = [
+            event(
+                StockAdded,
+                aggregate_id,
+                {
+                    "product_id": values["product_id"],
+                    "quantity": values["quantity"],
+                    "new_available_quantity": expressions.add(
+                        expressions.get(
+                            expressions.get(scope, "product"), "available_quantity"
+                        ),
+                        expressions.get(scope, "quantity"),
+                    ),
+                    "added_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_product, state)
+        check_product(state)
+        await self.event_store.append_events(aggregate_id, "Product", events, version)
+        return "Command executed successfully"
+
+    async def reserve_stock(self, command: ReserveStock) -> str:
+        """Reserve stock for an order
+
+        Produces `StockReserved`.
+
+        Preconditions:
+        - Insufficient stock available
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.product_id)
+        history = await self.event_store.load_events(aggregate_id, "Product")
+        state = fold(history, apply_product)
+        version = len(history)
+        if version == 0:
+            raise ApiError("NOT_FOUND", f"Product {aggregate_id} not found")
+        scope = {**values, "product": state}
+        if not expressions.ge(
+            expressions.get(expressions.get(scope, "product"), "available_quantity"),
+            expressions.get(scope, "quantity"),
+        ):
+            raise ApiError("PRECONDITION_FAILED", "Insufficient stock available")
+        events: list[DomainEvent] = [
+            event(
+                StockReserved,
+                aggregate_id,
+                {
+                    "product_id": values["product_id"],
+                    "order_id": values["order_id"],
+                    "reservation_id": expressions.get(state, "reservation_id"),
+                    "quantity": values["quantity"],
+                    "reserved_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_product, state)
+        check_product(state)
+        await self.event_store.append_events(aggregate_id, "Product", events, version)
+        return "Command executed successfully"
+
+    async def release_stock(self, command: ReleaseStock) -> str:
+        """Release reserved stock
+
+        Produces `StockReleased`.
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(uuid4())
+        state, version = None, 0
+        events: list[DomainEvent] = [
+            event(
+                StockReleased,
+                aggregate_id,
+                {
+                    "product_id": aggregate_id,
+                    "reservation_id": values["reservation_id"],
+                    "quantity": expressions.get(state, "quantity"),
+                    "released_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_product, state)
+        check_product(state)
+        await self.event_store.append_events(aggregate_id, "Product", events, version)
+        return "Command executed successfully"
+
+    async def deduct_stock(self, command: DeductStock) -> str:
+        """Deduct reserved stock (when order ships)
+
+        Produces `StockDeducted`, `LowStockAlert`.
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(uuid4())
+        state, version = None, 0
+        events: list[DomainEvent] = [
+            event(
+                StockDeducted,
+                aggregate_id,
+                {
+                    "product_id": aggregate_id,
+                    "reservation_id": values["reservation_id"],
+                    "quantity": expressions.get(state, "quantity"),
+                    "deducted_at": expressions.now(),
+                },
+            ),
+            event(
+                LowStockAlert,
+                aggregate_id,
+                {
+                    "product_id": aggregate_id,
+                    "sku": expressions.get(state, "sku"),
+                    "current_quantity": expressions.get(state, "current_quantity"),
+                    "reorder_level": expressions.get(state, "reorder_level"),
+                    "alerted_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_product, state)
+        check_product(state)
+        await self.event_store.append_events(aggregate_id, "Product", events, version)
+        return "Command executed successfully"
@@ -0,0 +1,33 @@
+"""Query handlers"""
+
+from app.domain.aggregates import Product
+from app.domain.value_objects import ProductId
+from app.errors import ApiError
+from app.infrastructure.event_store import EventStore
+
+
+class QueryHandlers:
+    """Handlers of the Inventory queries"""
+
+    def __init__(self, event_store: EventStore) -> None:
+        self.event_store = event_store
+
+    async def get_product(self, *, product_id: ProductId) -> Product | None:
+        """Get product by ID"""
+        # TODO: Read the result from a read model or by replaying events
+        return None
+
+    async def get_product_by_sku(self, *, sku: str) -> Product | None:
+        """Get product by SKU"""
+        # TODO: Read the result from a read model or by replaying events
+        return None
+
+    async def check_availability(self, *, product_id: ProductId, quantity: int) -> bool:
+        """Check if quantity is available"""
+        # TODO: Read the result from a read model or by replaying events
+        raise ApiError("NOT_FOUND", "bool not found")
+
+    async def get_low_stock_products(self) -> list[Product]:
+        """Get products below reorder level"""
+        # TODO: Read the result from a read model or by replaying events
+        return []
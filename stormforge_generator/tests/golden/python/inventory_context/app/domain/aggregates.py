@@ -0,0 +1,24 @@
+"""Aggregate state"""
+
+from datetime import datetime
+
+from pydantic import BaseModel
+
+from app.domain.value_objects import ProductId
+
+
+class Product(BaseModel):
+    """Product inventory aggregate
+
+    Invariants:
+    - Available quantity cannot be negative: `availableQuantity >= 0`
+    - Reserved quantity cannot be negative: `reservedQuantity >= 0`
+    """
+
+    id: ProductId
+    sku: str
+    name: str
+    available_quantity: int = 0
+    reserved_quantity: int = 0
+    reorder_level: int = 10
+    updated_at: datetime
@@ -0,0 +1,32 @@
+"""Commands"""
+
+from pydantic import BaseModel, Field
+
+from app.domain.value_objects import OrderId, ProductId, ReservationId
+
+
+class AddStock(BaseModel):
+    """Add stock to inventory"""
+
+    product_id: ProductId
+    quantity: int = Field(ge=1)
+
+
+class ReserveStock(BaseModel):
+    """Reserve stock for an order"""
+
+    product_id: ProductId
+    order_id: OrderId
+    quantity: int = Field(ge=1)
+
+
+class ReleaseStock(BaseModel):
+    """Release reserved stock"""
+
+    reservation_id: ReservationId
+
+
+class DeductStock(BaseModel):
+    """Deduct reserved stock (when order ships)"""
+
+    reservation_id: ReservationId
@@ -0,0 +1,108 @@
+"""Domain events"""
+
+from datetime import datetime, timezone
+from typing import Annotated, Literal, Union
+from uuid import UUID, uuid4
+
+from pydantic import BaseModel, Field
+
+from app.domain.value_objects import OrderId, ProductId, ReservationId
+
+
+def utc_now() -> datetime:
+    """Current time in UTC"""
+    return datetime.now(timezone.utc)
+
+
+class StockAdded(BaseModel):
+    """Stock has been added to inventory"""
+
+    type: Literal["StockAdded"] = "StockAdded"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    product_id: ProductId
+    quantity: int
+    new_available_quantity: int
+    added_at: datetime
+
+
+class StockReserved(BaseModel):
+    """Stock has been reserved for an order"""
+
+    type: Literal["StockReserved"] = "StockReserved"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    product_id: ProductId
+    order_id: OrderId
+    reservation_id: ReservationId
+    quantity: int
+    reserved_at: datetime
+
+
+class StockReleased(BaseModel):
+    """Reserved stock has been released"""
+
+    type: Literal["StockReleased"] = "StockReleased"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    product_id: ProductId
+    reservation_id: ReservationId
+    quantity: int
+    released_at: datetime
+
+
+class StockDeducted(BaseModel):
+    """Stock has been deducted (shipped)"""
+
+    type: Literal["StockDeducted"] = "StockDeducted"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    product_id: ProductId
+    reservation_id: ReservationId
+    quantity: int
+    deducted_at: datetime
+
+
+class LowStockAlert(BaseModel):
+    """Stock has fallen below reorder level"""
+
+    type: Literal["LowStockAlert"] = "LowStockAlert"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    product_id: ProductId
+    sku: str
+    current_quantity: int
+    reorder_level: int
+    alerted_at: datetime
+
+
+DomainEvent = Annotated[
+    Union[StockAdded, StockReserved, StockReleased, StockDeducted, LowStockAlert],
+    Field(discriminator="type"),
+]
+"""Domain event of the Inventory context, tagged by `type`"""
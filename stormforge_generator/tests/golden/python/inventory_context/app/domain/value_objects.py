@@ -0,0 +1,12 @@
+"""Value objects"""
+
+from typing import NewType
+
+
+ProductId = NewType("ProductId", str)
+
+
+OrderId = NewType("OrderId", str)
+
+
+ReservationId = NewType("ReservationId", str)
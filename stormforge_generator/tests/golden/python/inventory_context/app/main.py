@@ -0,0 +1,15 @@
+"""FastAPI application of the Inventory service"""
+
+from fastapi import FastAPI
+
+from app.api import commands, queries
+from app.errors import install_error_handlers
+
+app = FastAPI(
+    title="Inventory Service",
+    description="Inventory management bounded context",
+    version="0.1.0",
+)
+install_error_handlers(app)
+app.include_router(commands.router, prefix="/api")
+app.include_router(queries.router, prefix="/api")
@@ -0,0 +1,48 @@
+"""Example requests to the Inventory commands"""
+
+from fastapi.testclient import TestClient
+
+
+def test_add_stock_requires_product_id(client: TestClient) -> None:
+    response = client.post("/api/add-stock", json={"quantity": 1})
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_reserve_stock_needs_an_existing_product(client: TestClient) -> None:
+    response = client.post(
+        "/api/reserve-stock",
+        json={
+            "product_id": "prod_00000000-0000-4000-8000-000000000001",
+            "order_id": "ord_00000000-0000-4000-8000-000000000001",
+            "quantity": 1,
+        },
+    )
+
+    assert response.status_code == 404
+    assert response.json()["code"] == "NOT_FOUND"
+
+
+def test_reserve_stock_requires_product_id(client: TestClient) -> None:
+    response = client.post(
+        "/api/reserve-stock",
+        json={"order_id": "ord_00000000-0000-4000-8000-000000000001", "quantity": 1},
+    )
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_release_stock_requires_reservation_id(client: TestClient) -> None:
+    response = client.post("/api/release-stock", json={})
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_deduct_stock_requires_reservation_id(client: TestClient) -> None:
+    response = client.post("/api/deduct-stock", json={})
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
@@ -0,0 +1,36 @@
+"""Example requests to the Inventory queries"""
+
+from fastapi.testclient import TestClient
+
+
+def test_get_product_accepts_parameters(client: TestClient) -> None:
+    response = client.get(
+        "/api/get-product",
+        params={"product_id": "prod_00000000-0000-4000-8000-000000000001"},
+    )
+
+    assert response.status_code != 400
+
+
+def test_get_product_by_sku_accepts_parameters(client: TestClient) -> None:
+    response = client.get("/api/get-product-by-sku", params={"sku": "Sku 1"})
+
+    assert response.status_code != 400
+
+
+def test_check_availability_accepts_parameters(client: TestClient) -> None:
+    response = client.get(
+        "/api/check-availability",
+        params={
+            "product_id": "prod_00000000-0000-4000-8000-000000000001",
+            "quantity": 1,
+        },
+    )
+
+    assert response.status_code != 400
+
+
+def test_get_low_stock_products_accepts_parameters(client: TestClient) -> None:
+    response = client.get("/api/get-low-stock-products")
+
+    assert response.status_code != 400
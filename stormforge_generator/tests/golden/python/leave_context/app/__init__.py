@@ -0,0 +1 @@
+"""Leave service, generated by StormForge Generator"""
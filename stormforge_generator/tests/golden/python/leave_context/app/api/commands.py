@@ -0,0 +1,47 @@
+"""Command routes"""
+
+from fastapi import APIRouter, Depends
+
+from app.application.command_handlers import CommandHandlers
+from app.dependencies import get_command_handlers
+from app.domain.commands import ApproveLeave, CancelLeave, RejectLeave, RequestLeave
+from app.schemas import ApiResponse
+
+
+router = APIRouter(tags=["commands"])
+
+
+@router.post(
+    "/request-leave", response_model=ApiResponse[str], summary="Submit a leave request"
+)
+async def request_leave(
+    command: RequestLeave, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.request_leave(command))
+
+
+@router.post(
+    "/approve-leave", response_model=ApiResponse[str], summary="Approve a leave request"
+)
+async def approve_leave(
+    command: ApproveLeave, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.approve_leave(command))
+
+
+@router.post(
+    "/reject-leave", response_model=ApiResponse[str], summary="Reject a leave request"
+)
+async def reject_leave(
+    command: RejectLeave, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.reject_leave(command))
+
+
+@router.post(
+    "/cancel-leave", response_model=ApiResponse[str], summary="Cancel a leave request"
+)
+async def cancel_leave(
+    command: CancelLeave, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.cancel_leave(command))
@@ -0,0 +1,80 @@
+"""Query routes"""
+
+from typing import Any
+
+from fastapi import APIRouter, Depends
+
+from app.application.query_handlers import QueryHandlers
+from app.dependencies import get_query_handlers
+from app.domain.aggregates import LeaveRequest
+from app.domain.value_objects import EmployeeId, LeaveRequestId, LeaveStatus, LeaveType
+from app.schemas import ApiResponse
+
+
+router = APIRouter(tags=["queries"])
+
+
+@router.get(
+    "/get-leave-request",
+    response_model=ApiResponse[LeaveRequest | None],
+    summary="Get leave request by ID",
+)
+async def get_leave_request(
+    *,
+    leave_request_id: LeaveRequestId,
+    handlers: QueryHandlers = Depends(get_query_handlers),
+) -> ApiResponse[LeaveRequest | None]:
+    return ApiResponse(
+        data=await handlers.get_leave_request(leave_request_id=leave_request_id)
+    )
+
+
+@router.get(
+    "/get-employee-leaves",
+    response_model=ApiResponse[list[LeaveRequest]],
+    summary="Get all leave requests for an employee",
+)
+async def get_employee_leaves(
+    *,
+    employee_id: EmployeeId,
+    year: int | None = None,
+    status: LeaveStatus | None = None,
+    handlers: QueryHandlers = Depends(get_query_handlers),
+) -> ApiResponse[list[LeaveRequest]]:
+    return ApiResponse(
+        data=await handlers.get_employee_leaves(
+            employee_id=employee_id, year=year, status=status
+        ),
+    )
+
+
+@router.get(
+    "/get-pending-approvals",
+    response_model=ApiResponse[list[LeaveRequest]],
+    summary="Get pending leave requests for approval",
+)
+async def get_pending_approvals(
+    *, approver_id: EmployeeId, handlers: QueryHandlers = Depends(get_query_handlers)
+) -> ApiResponse[list[LeaveRequest]]:
+    return ApiResponse(
+        data=await handlers.get_pending_approvals(approver_id=approver_id)
+    )
+
+
+@router.get(
+    "/get-leave-balance",
+    response_model=ApiResponse[Any],
+    summary="Get remaining leave balance for an employee",
+)
+async def get_leave_balance(
+    *,
+    employee_id: EmployeeId,
+    leave_type: LeaveType,
+    year: int | None = None,
+    handlers: QueryHandlers = Depends(get_query_handlers),
+) -> ApiResponse[Any]:
+    return ApiResponse(
+        data=await handlers.get_leave_balance(
+            employee_id=employee_id, leave_type=leave_type, year=year
+        ),
+    )
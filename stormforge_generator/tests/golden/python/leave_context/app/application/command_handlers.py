@@ -0,0 +1,257 @@
+"""Command handlers backed by the event store
+
+A command is validated by its model, then checked against the state of its
+aggregate folded from the stored events. A command carrying the aggregate's
+identifier acts on that aggregate, any other command creates one. Each event
+takes a field from the `computed` expression of the event property, else from
+the command field, the aggregate ID or the aggregate state of the same name;
+`DateTime` fields the command does not give are the time it is handled. The
+events must keep the aggregate's invariants, and are appended at the version
+the state was loaded at, so a concurrent command on the same aggregate fails
+rather than being lost.
+"""
+
+from typing import Any, TypeVar
+from uuid import uuid4
+
+from pydantic import BaseModel, ValidationError
+
+from app.domain import expressions
+from app.domain.commands import ApproveLeave, CancelLeave, RejectLeave, RequestLeave
+from app.domain.events import (
+    DomainEvent,
+    LeaveApproved,
+    LeaveCancelled,
+    LeaveRejected,
+    LeaveRequested,
+)
+from app.domain.state import State, apply_leave_request, fold
+from app.errors import ApiError
+from app.infrastructure.event_store import EventStore
+
+
+E = TypeVar("E", bound=BaseModel)
+
+
+def event(event_type: type[E], aggregate_id: str, fields: dict[str, Any]) -> E:
+    """Event of a type built from its payload fields"""
+    try:
+        return event_type.model_validate({**fields, "aggregate_id": aggregate_id})
+    except ValidationError as error:
+        raise ApiError(
+            "INTERNAL_ERROR", f"Cannot build {event_type.__name__}: {error}"
+        ) from error
+
+
+def check_leave_request(scope: State) -> None:
+    """Invariants of a `LeaveRequest`
+
+    - `endDate >= startDate`: End date must be on or after start date
+    - `daysBetween(startDate, endDate) <= 30`: Leave cannot exceed 30 days
+    """
+    if not expressions.ge(
+        expressions.get(scope, "end_date"), expressions.get(scope, "start_date")
+    ):
+        raise ApiError("VALIDATION_ERROR", "End date must be on or after start date")
+    if not expressions.le(
+        expressions.days_between(
+            expressions.get(scope, "start_date"), expressions.get(scope, "end_date")
+        ),
+        30,
+    ):
+        raise ApiError("VALIDATION_ERROR", "Leave cannot exceed 30 days")
+
+
+class CommandHandlers:
+    """Handlers of the Leave commands
+
+    Each handler loads its aggregate from the event store, executes the
+    command and appends the events it produces.
+    """
+
+    def __init__(self, event_store: EventStore) -> None:
+        self.event_store = event_store
+
+    async def request_leave(self, command: RequestLeave) -> str:
+        """Submit a leave request
+
+        Produces `LeaveRequested`.
+
+        Validation:
+        - End date must be on or after start date
+        - Leave cannot start in the past
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(uuid4())
+        state, version = None, 0
+        events: list[DomainEvent] = [
+            event(
+                LeaveRequested,
+                aggregate_id,
+                {
+                    "leave_request_id": aggregate_id,
+                    "employee_id": values["employee_id"],
+                    "leave_type": values["leave_type"],
+                    "start_date": values["start_date"],
+                    "end_date": values["end_date"],
+                    "reason": values["reason"],
+                    "requested_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_leave_request, state)
+        check_leave_request(state)
+        await self.event_store.append_events(
+            aggregate_id, "LeaveRequest", events, version
+        )
+        return "Command executed successfully"
+
+    async def approve_leave(self, command: ApproveLeave) -> str:
+        """Approve a leave request
+
+        Produces `LeaveApproved`.
+
+        Preconditions:
+        - Can only approve pending leave requests
+        - Cannot approve own leave request
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.leave_request_id)
+        history = await self.event_store.load_events(aggregate_id, "LeaveRequest")
+        state = fold(history, apply_leave_request)
+        version = len(history)
+        if version == 0:
+            raise ApiError("NOT_FOUND", f"LeaveRequest {aggregate_id} not found")
+        scope = {**values, "leave_request": state}
+        if not expressions.eq(
+            expressions.get(expressions.get(scope, "leave_request"), "status"),
+            "Pending",
+        ):
+            raise ApiError(
+                "PRECONDITION_FAILED", "Can only approve pending leave requests"
+            )
+        if expressions.eq(
+            expressions.get(scope, "approver_id"),
+            expressions.get(expressions.get(scope, "leave_request"), "employee_id"),
+        ):
+            raise ApiError("PRECONDITION_FAILED", "Cannot approve own leave request")
+        events: list[DomainEvent] = [
+            event(
+                LeaveApproved,
+                aggregate_id,
+                {
+                    "leave_request_id": values["leave_request_id"],
+                    "employee_id": expressions.get(state, "employee_id"),
+                    "approver_id": values["approver_id"],
+                    "comment": values["comment"],
+                    "approved_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_leave_request, state)
+        check_leave_request(state)
+        await self.event_store.append_events(
+            aggregate_id, "LeaveRequest", events, version
+        )
+        return "Command executed successfully"
+
+    async def reject_leave(self, command: RejectLeave) -> str:
+        """Reject a leave request
+
+        Produces `LeaveRejected`.
+
+        Preconditions:
+        - Can only reject pending leave requests
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.leave_request_id)
+        history = await self.event_store.load_events(aggregate_id, "LeaveRequest")
+        state = fold(history, apply_leave_request)
+        version = len(history)
+        if version == 0:
+            raise ApiError("NOT_FOUND", f"LeaveRequest {aggregate_id} not found")
+        scope = {**values, "leave_request": state}
+        if not expressions.eq(
+            expressions.get(expressions.get(scope, "leave_request"), "status"),
+            "Pending",
+        ):
+            raise ApiError(
+                "PRECONDITION_FAILED", "Can only reject pending leave requests"
+            )
+        events: list[DomainEvent] = [
+            event(
+                LeaveRejected,
+                aggregate_id,
+                {
+                    "leave_request_id": values["leave_request_id"],
+                    "employee_id": expressions.get(state, "employee_id"),
+                    "approver_id": values["approver_id"],
+                    "reason": values["reason"],
+                    "rejected_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_leave_request, state)
+        check_leave_request(state)
+        await self.event_store.append_events(
+            aggregate_id, "LeaveRequest", events, version
+        )
+        return "Command executed successfully"
+
+    async def cancel_leave(self, command: CancelLeave) -> str:
+        """Cancel a leave request
+
+        Produces `LeaveCancelled`.
+
+        Preconditions:
+        - Can only cancel pending or approved leave requests
+        - Only the employee can cancel their own leave
+        - Cannot cancel leave that has already started
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.leave_request_id)
+        history = await self.event_store.load_events(aggregate_id, "LeaveRequest")
+        state = fold(history, apply_leave_request)
+        version = len(history)
+        if version == 0:
+            raise ApiError("NOT_FOUND", f"LeaveRequest {aggregate_id} not found")
+        scope = {**values, "leave_request": state}
+        if not expressions.contains(
+            ["Pending", "Approved"],
+            expressions.get(expressions.get(scope, "leave_request"), "status"),
+        ):
+            raise ApiError(
+                "PRECONDITION_FAILED",
+                "Can only cancel pending or approved leave requests",
+            )
+        if not expressions.eq(
+            expressions.get(expressions.get(scope, "leave_request"), "employee_id"),
+            expressions.get(scope, "employee_id"),
+        ):
+            raise ApiError(
+                "PRECONDITION_FAILED", "Only the employee can cancel their own leave"
+            )
+        if not expressions.gt(
+            expressions.get(expressions.get(scope, "leave_request"), "start_date"),
+            expressions.today(),
+        ):
+            raise ApiError(
+                "PRECONDITION_FAILED", "Cannot cancel leave that has already started"
+            )
+        events: list[DomainEvent] = [
+            event(
+                LeaveCancelled,
+                aggregate_id,
+                {
+                    "leave_request_id": values["leave_request_id"],
+                    "employee_id": values["employee_id"],
+                    "cancelled_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_leave_request, state)
+        check_leave_request(state)
+        await self.event_store.append_events(
+            aggregate_id, "LeaveRequest", events, version
+        )
+        return "Command executed successfully"
@@ -0,0 +1,43 @@
+"""Query handlers"""
+
+from typing import Any
+
+from app.domain.aggregates import LeaveRequest
+from app.domain.value_objects import EmployeeId, LeaveRequestId, LeaveStatus, LeaveType
+from app.errors import ApiError
+from app.infrastructure.event_store import EventStore
+
+
+class QueryHandlers:
+    """Handlers of the Leave queries"""
+
+    def __init__(self, event_store: EventStore) -> None:
+        self.event_store = event_store
+
+    async def get_leave_request(
+        self, *, leave_request_id: LeaveRequestId
+    ) -> LeaveRequest | None:
+        """Get leave request by ID"""
+        # TODO: Read the result from a read model or by replaying events
+        return None
+
+    async def get_employee_leaves(
+        self, *, employee_id: EmployeeId, year: int | None, status: LeaveStatus | None
+    ) -> list[LeaveRequest]:
+        """Get all leave requests for an employee"""
+        # TODO: Read the result from a read model or by replaying events
+        return []
+
+    async def get_pending_approvals(
+        self, *, approver_id: EmployeeId
+    ) -> list[LeaveRequest]:
+        """Get pending leave requests for approval"""
+        # TODO: Read the result from a read model or by replaying events
+        return []
+
+    async def get_leave_balance(
+        self, *, employee_id: EmployeeId, leave_type: LeaveType, year: int | None
+    ) -> Any:
+        """Get remaining leave balance for an employee"""
+        # TODO: Read the result from a read model or by replaying events
+        raise ApiError("NOT_FOUND", "LeaveBalance not found")
@@ -0,0 +1,28 @@
+"""Aggregate state"""
+
+from datetime import date, datetime
+
+from pydantic import BaseModel
+
+from app.domain.value_objects import EmployeeId, LeaveRequestId, LeaveStatus, LeaveType
+
+
+class LeaveRequest(BaseModel):
+    """Leave request aggregate
+
+    Invariants:
+    - End date must be on or after start date: `endDate >= startDate`
+    - Leave cannot exceed 30 days: `daysBetween(startDate, endDate) <= 30`
+    """
+
+    id: LeaveRequestId
+    employee_id: EmployeeId
+    leave_type: LeaveType
+    start_date: date
+    end_date: date
+    reason: str | None = None
+    status: LeaveStatus = LeaveStatus.PENDING
+    approver_id: EmployeeId | None = None
+    approval_comment: str | None = None
+    created_at: datetime
+    updated_at: datetime | None = None
@@ -0,0 +1,55 @@
+"""Commands"""
+
+from datetime import date
+
+from pydantic import BaseModel, model_validator
+
+from app.domain import expressions
+from app.domain.value_objects import EmployeeId, LeaveRequestId, LeaveType
+
+
+class RequestLeave(BaseModel):
+    """Submit a leave request"""
+
+    employee_id: EmployeeId
+    leave_type: LeaveType
+    start_date: date
+    end_date: date
+    reason: str | None = None
+
+    @model_validator(mode="after")
+    def check_rules(self) -> "RequestLeave":
+        """Validation rules of the command"""
+        scope = self.model_dump(mode="json", by_alias=True)
+        if not expressions.ge(
+            expressions.get(scope, "end_date"), expressions.get(scope, "start_date")
+        ):
+            raise ValueError("End date must be on or after start date")
+        if not expressions.ge(
+            expressions.get(scope, "start_date"), expressions.today()
+        ):
+            raise ValueError("Leave cannot start in the past")
+        return self
+
+
+class ApproveLeave(BaseModel):
+    """Approve a leave request"""
+
+    leave_request_id: LeaveRequestId
+    approver_id: EmployeeId
+    comment: str | None = None
+
+
+class RejectLeave(BaseModel):
+    """Reject a leave request"""
+
+    leave_request_id: LeaveRequestId
+    approver_id: EmployeeId
+    reason: str
+
+
+class CancelLeave(BaseModel):
+    """Cancel a leave request"""
+
+    leave_request_id: LeaveRequestId
+    employee_id: EmployeeId
@@ -0,0 +1,93 @@
+"""Domain events"""
+
+from datetime import date, datetime, timezone
+from typing import Annotated, Literal, Union
+from uuid import UUID, uuid4
+
+from pydantic import BaseModel, Field
+
+from app.domain.value_objects import EmployeeId, LeaveRequestId, LeaveType
+
+
+def utc_now() -> datetime:
+    """Current time in UTC"""
+    return datetime.now(timezone.utc)
+
+
+class LeaveRequested(BaseModel):
+    """A leave request has been submitted"""
+
+    type: Literal["LeaveRequested"] = "LeaveRequested"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    leave_request_id: LeaveRequestId
+    employee_id: EmployeeId
+    leave_type: LeaveType
+    start_date: date
+    end_date: date
+    reason: str
+    requested_at: datetime
+
+
+class LeaveApproved(BaseModel):
+    """Leave request has been approved"""
+
+    type: Literal["LeaveApproved"] = "LeaveApproved"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    leave_request_id: LeaveRequestId
+    employee_id: EmployeeId
+    approver_id: EmployeeId
+    comment: str
+    approved_at: datetime
+
+
+class LeaveRejected(BaseModel):
+    """Leave request has been rejected"""
+
+    type: Literal["LeaveRejected"] = "LeaveRejected"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    leave_request_id: LeaveRequestId
+    employee_id: EmployeeId
+    approver_id: EmployeeId
+    reason: str
+    rejected_at: datetime
+
+
+class LeaveCancelled(BaseModel):
+    """Leave request has been cancelled"""
+
+    type: Literal["LeaveCancelled"] = "LeaveCancelled"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    leave_request_id: LeaveRequestId
+    employee_id: EmployeeId
+    cancelled_at: datetime
+
+
+DomainEvent = Annotated[
+    Union[LeaveRequested, LeaveApproved, LeaveRejected, LeaveCancelled],
+    Field(discriminator="type"),
+]
+"""Domain event of the Leave context, tagged by `type`"""
@@ -0,0 +1,38 @@
+"""Value objects"""
+
+from enum import Enum
+from typing import NewType
+
+
+LeaveRequestId = NewType("LeaveRequestId", str)
+
+
+EmployeeId = NewType("EmployeeId", str)
+
+
+class LeaveType(str, Enum):
+    ANNUAL = "Annual"
+    """Annual leave"""
+    SICK = "Sick"
+    """Sick leave"""
+    PERSONAL = "Personal"
+    """Personal leave"""
+    MATERNITY = "Maternity"
+    """Maternity leave"""
+    PATERNITY = "Paternity"
+    """Paternity leave"""
+    BEREAVEMENT = "Bereavement"
+    """Bereavement leave"""
+    UNPAID = "Unpaid"
+    """Unpaid leave"""
+
+
+class LeaveStatus(str, Enum):
+    PENDING = "Pending"
+    """Awaiting approval"""
+    APPROVED = "Approved"
+    """Leave approved"""
+    REJECTED = "Rejected"
+    """Leave rejected"""
+    CANCELLED = "Cancelled"
+    """Leave cancelled by employee"""
@@ -0,0 +1,15 @@
+"""FastAPI application of the Leave service"""
+
+from fastapi import FastAPI
+
+from app.api import commands, queries
+from app.errors import install_error_handlers
+
+app = FastAPI(
+    title="Leave Service",
+    description="Employee leave management bounded context",
+    version="0.1.0",
+)
+install_error_handlers(app)
+app.include_router(commands.router, prefix="/api")
+app.include_router(queries.router, prefix="/api")
@@ -0,0 +1,111 @@
+"""Example requests to the Leave commands"""
+
+from fastapi.testclient import TestClient
+
+
+def test_request_leave_is_accepted(client: TestClient) -> None:
+    response = client.post(
+        "/api/request-leave",
+        json={
+            "employee_id": "emp_00000000-0000-4000-8000-000000000001",
+            "leave_type": "Annual",
+            "start_date": "2099-01-01",
+            "end_date": "2099-01-01",
+            "reason": "Reason 1",
+        },
+    )
+
+    assert response.status_code == 200
+    assert response.json() == {"data": "Command executed successfully"}
+
+
+def test_request_leave_requires_employee_id(client: TestClient) -> None:
+    response = client.post(
+        "/api/request-leave",
+        json={
+            "leave_type": "Annual",
+            "start_date": "2099-01-01",
+            "end_date": "2099-01-01",
+            "reason": "Reason 1",
+        },
+    )
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_approve_leave_needs_an_existing_leave_request(client: TestClient) -> None:
+    response = client.post(
+        "/api/approve-leave",
+        json={
+            "leave_request_id": "leave_00000000-0000-4000-8000-000000000001",
+            "approver_id": "emp_00000000-0000-4000-8000-000000000001",
+            "comment": "Comment 1",
+        },
+    )
+
+    assert response.status_code == 404
+    assert response.json()["code"] == "NOT_FOUND"
+
+
+def test_approve_leave_requires_leave_request_id(client: TestClient) -> None:
+    response = client.post(
+        "/api/approve-leave",
+        json={
+            "approver_id": "emp_00000000-0000-4000-8000-000000000001",
+            "comment": "Comment 1",
+        },
+    )
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_reject_leave_needs_an_existing_leave_request(client: TestClient) -> None:
+    response = client.post(
+        "/api/reject-leave",
+        json={
+            "leave_request_id": "leave_00000000-0000-4000-8000-000000000001",
+            "approver_id": "emp_00000000-0000-4000-8000-000000000001",
+            "reason": "Reason 1",
+        },
+    )
+
+    assert response.status_code == 404
+    assert response.json()["code"] == "NOT_FOUND"
+
+
+def test_reject_leave_requires_leave_request_id(client: TestClient) -> None:
+    response = client.post(
+        "/api/reject-leave",
+        json={
+            "approver_id": "emp_00000000-0000-4000-8000-000000000001",
+            "reason": "Reason 1",
+        },
+    )
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_cancel_leave_needs_an_existing_leave_request(client: TestClient) -> None:
+    response = client.post(
+        "/api/cancel-leave",
+        json={
+            "leave_request_id": "leave_00000000-0000-4000-8000-000000000001",
+            "employee_id": "emp_00000000-0000-4000-8000-000000000001",
+        },
+    )
+
+    assert response.status_code == 404
+    assert response.json()["code"] == "NOT_FOUND"
+
+
+def test_cancel_leave_requires_leave_request_id(client: TestClient) -> None:
+    response = client.post(
+        "/api/cancel-leave",
+        json={"employee_id": "emp_00000000-0000-4000-8000-000000000001"},
+    )
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
@@ -0,0 +1,47 @@
+"""Tests of the in-memory event store"""
+
+import pytest
+
+from app.domain.events import LeaveRequested
+from app.infrastructure.event_store import ConcurrencyError, InMemoryEventStore
+
+AGGREGATE_TYPE = "LeaveRequest"
+AGGREGATE_ID = "leave-request-1"
+
+
+def example_event() -> LeaveRequested:
+    return LeaveRequested(
+        aggregate_id=AGGREGATE_ID,
+        **{
+            "leave_request_id": "leave_00000000-0000-4000-8000-000000000001",
+            "employee_id": "emp_00000000-0000-4000-8000-000000000001",
+            "leave_type": "Annual",
+            "start_date": "2024-01-01",
+            "end_date": "2024-01-01",
+            "reason": "Reason 1",
+            "requested_at": "2024-01-01T09:00:00Z",
+        },
+    )
+
+
+async def test_events_load_in_append_order(event_store: InMemoryEventStore) -> None:
+    first, second = example_event(), example_event()
+    await event_store.append_events(AGGREGATE_ID, AGGREGATE_TYPE, [first])
+    await event_store.append_events(
+        AGGREGATE_ID, AGGREGATE_TYPE, [second], expected_version=1
+    )
+
+    events = await event_store.load_events(AGGREGATE_ID, AGGREGATE_TYPE)
+    assert events == [first, second]
+    assert await event_store.version(AGGREGATE_ID, AGGREGATE_TYPE) == 2
+    assert await event_store.load_events("other", AGGREGATE_TYPE) == []
+
+
+async def test_append_rejects_stale_version(event_store: InMemoryEventStore) -> None:
+    await event_store.append_events(AGGREGATE_ID, AGGREGATE_TYPE, [example_event()])
+
+    with pytest.raises(ConcurrencyError):
+        await event_store.append_events(
+            AGGREGATE_ID, AGGREGATE_TYPE, [example_event()], expected_version=0
+        )
+    assert await event_store.version(AGGREGATE_ID, AGGREGATE_TYPE) == 1
@@ -0,0 +1,42 @@
+"""Example requests to the Leave queries"""
+
+from fastapi.testclient import TestClient
+
+
+def test_get_leave_request_accepts_parameters(client: TestClient) -> None:
+    response = client.get(
+        "/api/get-leave-request",
+        params={"leave_request_id": "leave_00000000-0000-4000-8000-000000000001"},
+    )
+
+    assert response.status_code != 400
+
+
+def test_get_employee_leaves_accepts_parameters(client: TestClient) -> None:
+    response = client.get(
+        "/api/get-employee-leaves",
+        params={"employee_id": "emp_00000000-0000-4000-8000-000000000001"},
+    )
+
+    assert response.status_code != 400
+
+
+def test_get_pending_approvals_accepts_parameters(client: TestClient) -> None:
+    response = client.get(
+        "/api/get-pending-approvals",
+        params={"approver_id": "emp_00000000-0000-4000-8000-000000000001"},
+    )
+
+    assert response.status_code != 400
+
+
+def test_get_leave_balance_accepts_parameters(client: TestClient) -> None:
+    response = client.get(
+        "/api/get-leave-balance",
+        params={
+            "employee_id": "emp_00000000-0000-4000-8000-000000000001",
+            "leave_type": "Annual",
+        },
+    )
+
+    assert response.status_code != 400
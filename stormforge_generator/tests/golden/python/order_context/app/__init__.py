@@ -0,0 +1 @@
+"""Order service, generated by StormForge Generator"""
@@ -0,0 +1 @@
+"""HTTP routes, nested under `/api`"""
@@ -0,0 +1,62 @@
+"""Query routes"""
+
+from datetime import datetime
+from typing import Annotated
+
+from fastapi import APIRouter, Depends, Query
+
+from app.application.query_handlers import QueryHandlers
+from app.dependencies import get_query_handlers
+from app.domain.aggregates import Order
+from app.domain.value_objects import CustomerId, OrderId, OrderStatus
+from app.schemas import ApiResponse, PagedResult
+
+
+router = APIRouter(tags=["queries"])
+
+
+@router.get(
+    "/get-order", response_model=ApiResponse[Order | None], summary="Get order by ID"
+)
+async def get_order(
+    *, order_id: OrderId, handlers: QueryHandlers = Depends(get_query_handlers)
+) -> ApiResponse[Order | None]:
+    return ApiResponse(data=await handlers.get_order(order_id=order_id))
+
+
+@router.get(
+    "/list-orders",
+    response_model=ApiResponse[PagedResult[Order]],
+    summary="List orders with filters",
+)
+async def list_orders(
+    *,
+    customer_id: CustomerId | None = None,
+    status: OrderStatus | None = None,
+    from_date: datetime | None = None,
+    to_date: datetime | None = None,
+    page: Annotated[int, Query(ge=1)] = 1,
+    page_size: Annotated[int, Query(ge=1, le=100)] = 20,
+    handlers: QueryHandlers = Depends(get_query_handlers),
+) -> ApiResponse[PagedResult[Order]]:
+    return ApiResponse(
+        data=await handlers.list_orders(
+            customer_id=customer_id,
+            status=status,
+            from_date=from_date,
+            to_date=to_date,
+            page=page,
+            page_size=page_size,
+        ),
+    )
+
+
+@router.get(
+    "/get-orders-by-status",
+    response_model=ApiResponse[list[Order]],
+    summary="Get all orders with a specific status",
+)
+async def get_orders_by_status(
+    *, status: OrderStatus, handlers: QueryHandlers = Depends(get_query_handlers)
+) -> ApiResponse[list[Order]]:
+    return ApiResponse(data=await handlers.get_orders_by_status(status=status))
@@ -0,0 +1,269 @@
+"""Command handlers backed by the event store
+
+A command is validated by its model, then checked against the state of its
+aggregate folded from the stored events. A command carrying the aggregate's
+identifier acts on that aggregate, any other command creates one. Each event
+takes a field from the `computed` expression of the event property, else from
+the command field, the aggregate ID or the aggregate state of the same name;
+`DateTime` fields the command does not give are the time it is handled. The
+events must keep the aggregate's invariants, and are appended at the version
+the state was loaded at, so a concurrent command on the same aggregate fails
+rather than being lost.
+"""
+
+from typing import Any, TypeVar
+from uuid import uuid4
+
+from pydantic import BaseModel, ValidationError
+
+from app.domain import expressions
+from app.domain.commands import (
+    CancelOrder,
+    ConfirmPayment,
+    CreateOrder,
+    DeliverOrder,
+    ShipOrder,
+)
+from app.domain.events import (
+    DomainEvent,
+    OrderCancelled,
+    OrderCreated,
+    OrderDelivered,
+    OrderPaid,
+    OrderShipped,
+)
+from app.domain.state import State, apply_order, fold
+from app.errors import ApiError
+from app.infrastructure.event_store import EventStore
+
+
+E = TypeVar("E", bound=BaseModel)
+
+
+def event(event_type: type[E], aggregate_id: str, fields: dict[str, Any]) -> E:
+    """Event of a type built from its payload fields"""
+    try:
+        return event_type.model_validate({**fields, "aggregate_id": aggregate_id})
+    except ValidationError as error:
+        raise ApiError(
+            "INTERNAL_ERROR", f"Cannot build {event_type.__name__}: {error}"
+        ) from error
+
+
+def check_order(scope: State) -> None:
+    """Invariants of a `Order`
+
+    - `items.length > 0`: Order must have at least one item
+    - `totalAmount == sum(items.subtotal)`: Total must equal sum of item subtotals
+    """
+    if not expressions.gt(expressions.len_(expressions.get(scope, "items")), 0):
+        raise ApiError("VALIDATION_ERROR", "Order must have at least one item")
+    if not expressions.eq(
+        expressions.get(scope, "total_amount"),
+        expressions.sum_(expressions.get(expressions.get(scope, "items"), "subtotal")),
+    ):
+        raise ApiError("VALIDATION_ERROR", "Total must equal sum of item subtotals")
+
+
+class CommandHandlers:
+    """Handlers of the Order commands
+
+    Each handler loads its aggregate from the event store, executes the
+    command and appends the events it produces.
+    """
+
+    def __init__(self, event_store: EventStore) -> None:
+        self.event_store = event_store
+
+    async def create_order(self, command: CreateOrder) -> str:
+        """Create a new order
+
+        Produces `OrderCreated`.
+
+        Validation:
+        - Order must have at least one item
+        - Order cannot have more than 50 items
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(uuid4())
+        state, version = None, 0
+        scope = {**values, "order": state}
+        events: list[DomainEvent] = [
+            event(
+                OrderCreated,
+                aggregate_id,
+                {
+                    "order_id": aggregate_id,
+                    "customer_id": values["customer_id"],
+                    "items": values["items"],
+                    "shipping_address": values["shipping_address"],
+                    "total_amount": expressions.sum_(
+                        expressions.get(expressions.get(scope, "items"), "subtotal")
+                    ),
+                    "created_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_order, state)
+        check_order(state)
+        await self.event_store.append_events(aggregate_id, "Order", events, version)
+        return "Command executed successfully"
+
+    async def confirm_payment(self, command: ConfirmPayment) -> str:
+        """Confirm order payment
+
+        Produces `OrderPaid`.
+
+        Preconditions:
+        - Can only confirm payment for orders in CREATED status
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.order_id)
+        history = await self.event_store.load_events(aggregate_id, "Order")
+        state = fold(history, apply_order)
+        version = len(history)
+        if version == 0:
+            raise ApiError("NOT_FOUND", f"Order {aggregate_id} not found")
+        scope = {**values, "order": state}
+        if not expressions.eq(
+            expressions.get(expressions.get(scope, "order"), "status"), "Created"
+        ):
+            raise ApiError(
+                "PRECONDITION_FAILED",
+                "Can only confirm payment for orders in CREATED status",
+            )
+        events: list[DomainEvent] = [
+            event(
+                OrderPaid,
+                aggregate_id,
+                {
+                    "order_id": values["order_id"],
+                    "payment_id": values["payment_id"],
+                    "paid_amount": expressions.get(
+                        expressions.get(scope, "order"), "total_amount"
+                    ),
+                    "paid_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_order, state)
+        check_order(state)
+        await self.event_store.append_events(aggregate_id, "Order", events, version)
+        return "Command executed successfully"
+
+    async def ship_order(self, command: ShipOrder) -> str:
+        """Ship the order
+
+        Produces `OrderShipped`.
+
+        Preconditions:
+        - Can only ship orders that have been paid
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.order_id)
+        history = await self.event_store.load_events(aggregate_id, "Order")
+        state = fold(history, apply_order)
+        version = len(history)
+        if version == 0:
+            raise ApiError("NOT_FOUND", f"Order {aggregate_id} not found")
+        scope = {**values, "order": state}
+        if not expressions.eq(
+            expressions.get(expressions.get(scope, "order"), "status"), "Paid"
+        ):
+            raise ApiError(
+                "PRECONDITION_FAILED", "Can only ship orders that have been paid"
+            )
+        events: list[DomainEvent] = [
+            event(
+                OrderShipped,
+                aggregate_id,
+                {
+                    "order_id": values["order_id"],
+                    "tracking_number": values["tracking_number"],
+                    "carrier": values["carrier"],
+                    "shipped_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_order, state)
+        check_order(state)
+        await self.event_store.append_events(aggregate_id, "Order", events, version)
+        return "Command executed successfully"
+
+    async def deliver_order(self, command: DeliverOrder) -> str:
+        """Mark order as delivered
+
+        Produces `OrderDelivered`.
+
+        Preconditions:
+        - Can only deliver orders that have been shipped
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.order_id)
+        history = await self.event_store.load_events(aggregate_id, "Order")
+        state = fold(history, apply_order)
+        version = len(history)
+        if version == 0:
+            raise ApiError("NOT_FOUND", f"Order {aggregate_id} not found")
+        scope = {**values, "order": state}
+        if not expressions.eq(
+            expressions.get(expressions.get(scope, "order"), "status"), "Shipped"
+        ):
+            raise ApiError(
+                "PRECONDITION_FAILED", "Can only deliver orders that have been shipped"
+            )
+        events: list[DomainEvent] = [
+            event(
+                OrderDelivered,
+                aggregate_id,
+                {
+                    "order_id": values["order_id"],
+                    "delivered_at": expressions.now(),
+                    "signed_by": values["signed_by"],
+                },
+            ),
+        ]
+        state = fold(events, apply_order, state)
+        check_order(state)
+        await self.event_store.append_events(aggregate_id, "Order", events, version)
+        return "Command executed successfully"
+
+    async def cancel_order(self, command: CancelOrder) -> str:
+        """Cancel the order
+
+        Produces `OrderCancelled`.
+
+        Preconditions:
+        - Can only cancel orders that have not been shipped
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.order_id)
+        history = await self.event_store.load_events(aggregate_id, "Order")
+        state = fold(history, apply_order)
+        version = len(history)
+        if version == 0:
+            raise ApiError("NOT_FOUND", f"Order {aggregate_id} not found")
+        scope = {**values, "order": state}
+        if not expressions.contains(
+            ["Created", "Paid"],
+            expressions.get(expressions.get(scope, "order"), "status"),
+        ):
+            raise ApiError(
+                "PRECONDITION_FAILED",
+                "Can only cancel orders that have not been shipped",
+            )
+        events: list[DomainEvent] = [
+            event(
+                OrderCancelled,
+                aggregate_id,
+                {
+                    "order_id": values["order_id"],
+                    "reason": values["reason"],
+                    "cancelled_at": expressions.now(),
+                },
+            ),
+        ]
+        state = fold(events, apply_order, state)
+        check_order(state)
+        await self.event_store.append_events(aggregate_id, "Order", events, version)
+        return "Command executed successfully"
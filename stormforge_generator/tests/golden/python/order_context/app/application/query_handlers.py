@@ -0,0 +1,39 @@
+"""Query handlers"""
+
+from datetime import datetime
+
+from app.domain.aggregates import Order
+from app.domain.value_objects import CustomerId, OrderId, OrderStatus
+from app.infrastructure.event_store import EventStore
+from app.schemas import PagedResult
+
+
+class QueryHandlers:
+    """Handlers of the Order queries"""
+
+    def __init__(self, event_store: EventStore) -> None:
+        self.event_store = event_store
+
+    async def get_order(self, *, order_id: OrderId) -> Order | None:
+        """Get order by ID"""
+        # TODO: Read the result from a read model or by replaying events
+        return None
+
+    async def list_orders(
+        self,
+        *,
+        customer_id: CustomerId | None,
+        status: OrderStatus | None,
+        from_date: datetime | None,
+        to_date: datetime | None,
+        page: int,
+        page_size: int,
+    ) -> PagedResult[Order]:
+        """List orders with filters"""
+        # TODO: Read the result from a read model or by replaying events
+        return PagedResult(items=[], total=0, page=1, page_size=20)
+
+    async def get_orders_by_status(self, *, status: OrderStatus) -> list[Order]:
+        """Get all orders with a specific status"""
+        # TODO: Read the result from a read model or by replaying events
+        return []
@@ -0,0 +1,34 @@
+"""Aggregate state"""
+
+from datetime import datetime
+
+from pydantic import BaseModel, Field
+
+from app.domain.value_objects import (
+    Address,
+    CustomerId,
+    Money,
+    OrderId,
+    OrderItem,
+    OrderStatus,
+)
+
+
+class Order(BaseModel):
+    """Order aggregate root managing order lifecycle
+
+    Invariants:
+    - Order must have at least one item: `items.length > 0`
+    - Total must equal sum of item subtotals: `totalAmount == sum(items.subtotal)`
+    """
+
+    id: OrderId = Field(description="Unique order identifier")
+    customer_id: CustomerId = Field(description="Customer who placed the order")
+    items: list[OrderItem] = Field(description="Order line items")
+    status: OrderStatus = Field(
+        default=OrderStatus.CREATED, description="Current order status"
+    )
+    shipping_address: Address = Field(description="Delivery address")
+    total_amount: Money = Field(description="Order total")
+    created_at: datetime
+    updated_at: datetime | None = None
@@ -0,0 +1,53 @@
+"""Commands"""
+
+from pydantic import BaseModel, model_validator
+
+from app.domain import expressions
+from app.domain.value_objects import Address, CustomerId, OrderId, OrderItem
+
+
+class CreateOrder(BaseModel):
+    """Create a new order"""
+
+    customer_id: CustomerId
+    items: list[OrderItem]
+    shipping_address: Address
+
+    @model_validator(mode="after")
+    def check_rules(self) -> "CreateOrder":
+        """Validation rules of the command"""
+        scope = self.model_dump(mode="json", by_alias=True)
+        if not expressions.gt(expressions.len_(expressions.get(scope, "items")), 0):
+            raise ValueError("Order must have at least one item")
+        if not expressions.le(expressions.len_(expressions.get(scope, "items")), 50):
+            raise ValueError("Order cannot have more than 50 items")
+        return self
+
+
+class ConfirmPayment(BaseModel):
+    """Confirm order payment"""
+
+    order_id: OrderId
+    payment_id: str
+
+
+class ShipOrder(BaseModel):
+    """Ship the order"""
+
+    order_id: OrderId
+    tracking_number: str
+    carrier: str
+
+
+class DeliverOrder(BaseModel):
+    """Mark order as delivered"""
+
+    order_id: OrderId
+    signed_by: str | None = None
+
+
+class CancelOrder(BaseModel):
+    """Cancel the order"""
+
+    order_id: OrderId
+    reason: str
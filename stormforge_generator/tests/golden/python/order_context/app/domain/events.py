@@ -0,0 +1,106 @@
+"""Domain events"""
+
+from datetime import datetime, timezone
+from typing import Annotated, Literal, Union
+from uuid import UUID, uuid4
+
+from pydantic import BaseModel, Field
+
+from app.domain.value_objects import Address, CustomerId, Money, OrderId, OrderItem
+
+
+def utc_now() -> datetime:
+    """Current time in UTC"""
+    return datetime.now(timezone.utc)
+
+
+class OrderCreated(BaseModel):
+    """Emitted when a new order is created"""
+
+    type: Literal["OrderCreated"] = "OrderCreated"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    order_id: OrderId
+    customer_id: CustomerId
+    items: list[OrderItem]
+    shipping_address: Address
+    total_amount: Money
+    created_at: datetime
+
+
+class OrderPaid(BaseModel):
+    """Emitted when an order payment is confirmed"""
+
+    type: Literal["OrderPaid"] = "OrderPaid"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    order_id: OrderId
+    payment_id: str
+    paid_amount: Money
+    paid_at: datetime
+
+
+class OrderShipped(BaseModel):
+    """Emitted when an order is shipped"""
+
+    type: Literal["OrderShipped"] = "OrderShipped"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    order_id: OrderId
+    tracking_number: str
+    carrier: str
+    shipped_at: datetime
+
+
+class OrderDelivered(BaseModel):
+    """Emitted when an order is delivered"""
+
+    type: Literal["OrderDelivered"] = "OrderDelivered"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    order_id: OrderId
+    delivered_at: datetime
+    signed_by: str
+
+
+class OrderCancelled(BaseModel):
+    """Emitted when an order is cancelled"""
+
+    type: Literal["OrderCancelled"] = "OrderCancelled"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    order_id: OrderId
+    reason: str
+    cancelled_at: datetime
+
+
+DomainEvent = Annotated[
+    Union[OrderCreated, OrderPaid, OrderShipped, OrderDelivered, OrderCancelled],
+    Field(discriminator="type"),
+]
+"""Domain event of the Order context, tagged by `type`"""
@@ -0,0 +1,299 @@
+"""Runtime of the translated IR expressions
+
+Invariants, preconditions, validation rules and computed event fields are
+translated to Python over JSON values, so they read commands, events and
+aggregate state alike. A missing property reads as `None`, which is false in
+conditions and neither equal to, less nor greater than another value.
+Numbers, decimals (serialized as strings) and dates compare by value.
+Functions named like a builtin end with `_`.
+"""
+
+import json
+import operator
+from collections.abc import Callable
+from datetime import datetime, timedelta, timezone
+from decimal import Decimal, InvalidOperation
+from typing import Any
+
+
+def get(value: Any, key: str) -> Any:
+    """Property of an object; on a list, the property of each element"""
+    if isinstance(value, dict):
+        return value.get(key)
+    if isinstance(value, list):
+        return [get(item, key) for item in value]
+    return None
+
+
+def index(value: Any, position: Any) -> Any:
+    """Element of a list or property of an object"""
+    if isinstance(value, list) and _integer(position):
+        return value[position] if 0 <= position < len(value) else None
+    if isinstance(value, dict) and isinstance(position, str):
+        return value.get(position)
+    return None
+
+
+def len_(value: Any) -> Any:
+    """Number of elements of a list, characters of a string or properties of an object"""
+    if isinstance(value, (list, str, dict)):
+        return len(value)
+    return None
+
+
+def truthy(value: Any) -> bool:
+    """Whether a value holds in a condition"""
+    if isinstance(value, dict):
+        return True
+    return bool(value)
+
+
+def _integer(value: Any) -> bool:
+    return isinstance(value, int) and not isinstance(value, bool)
+
+
+def _decimal(value: Any) -> Decimal | None:
+    if isinstance(value, bool) or not isinstance(value, (int, float, str)):
+        return None
+    try:
+        number = Decimal(str(value))
+    except InvalidOperation:
+        return None
+    return number if number.is_finite() else None
+
+
+def _datetime(value: Any) -> datetime | None:
+    if not isinstance(value, str):
+        return None
+    try:
+        parsed = datetime.fromisoformat(value.replace("Z", "+00:00"))
+    except ValueError:
+        return None
+    if parsed.tzinfo is None:
+        return parsed.replace(tzinfo=timezone.utc)
+    return parsed.astimezone(timezone.utc)
+
+
+def _number(value: Decimal, operands: tuple[Any, Any]) -> Any:
+    """Number with the representation of its operands: decimals are
+    serialized as strings, integers and floats as numbers"""
+    if any(isinstance(operand, str) for operand in operands):
+        return format(value.normalize(), "f")
+    if value == value.to_integral_value():
+        return int(value)
+    return float(value)
+
+
+def _arithmetic(
+    left: Any, right: Any, op: Callable[[Decimal, Decimal], Decimal]
+) -> Any:
+    """Applies an operator to numbers, and to the numeric properties of
+    objects such as amounts of money"""
+    if isinstance(left, dict) and isinstance(right, dict):
+        return _fieldwise(
+            left,
+            lambda key, value: (
+                _arithmetic(value, right[key], op) if key in right else value
+            ),
+        )
+    if isinstance(left, dict):
+        return _fieldwise(left, lambda _, value: _arithmetic(value, right, op))
+    if isinstance(right, dict):
+        return _fieldwise(right, lambda _, value: _arithmetic(left, value, op))
+    lhs, rhs = _decimal(left), _decimal(right)
+    if lhs is None or rhs is None:
+        return None
+    try:
+        return _number(op(lhs, rhs), (left, right))
+    except ArithmeticError:
+        return None
+
+
+def _fieldwise(object_: dict, apply: Callable[[str, Any], Any]) -> dict:
+    """Object with its numeric properties transformed"""
+    return {
+        key: apply(key, value) if _decimal(value) is not None else value
+        for key, value in object_.items()
+    }
+
+
+def _text(value: Any) -> str:
+    if isinstance(value, str):
+        return value
+    if value is None:
+        return ""
+    return json.dumps(value)
+
+
+def add(left: Any, right: Any) -> Any:
+    """Sum of numbers, or concatenation when a string is not a number"""
+    if isinstance(left, str) or isinstance(right, str):
+        if _decimal(left) is None or _decimal(right) is None:
+            return _text(left) + _text(right)
+    return _arithmetic(left, right, operator.add)
+
+
+def sub(left: Any, right: Any) -> Any:
+    return _arithmetic(left, right, operator.sub)
+
+
+def mul(left: Any, right: Any) -> Any:
+    return _arithmetic(left, right, operator.mul)
+
+
+def div(left: Any, right: Any) -> Any:
+    return _arithmetic(left, right, operator.truediv)
+
+
+def rem(left: Any, right: Any) -> Any:
+    return _arithmetic(left, right, operator.mod)
+
+
+def neg(value: Any) -> Any:
+    return sub(0, value)
+
+
+def eq(left: Any, right: Any) -> bool:
+    """Equality of values, comparing numbers and decimals by value"""
+    if isinstance(left, list) and isinstance(right, list):
+        return len(left) == len(right) and all(map(eq, left, right))
+    if isinstance(left, dict) and isinstance(right, dict):
+        return len(left) == len(right) and all(
+            key in right and eq(value, right[key]) for key, value in left.items()
+        )
+    lhs, rhs = _decimal(left), _decimal(right)
+    if lhs is not None and rhs is not None:
+        return lhs == rhs
+    if isinstance(left, bool) != isinstance(right, bool):
+        return False
+    return left == right
+
+
+def _order(left: Any, right: Any) -> int:
+    return (left > right) - (left < right)
+
+
+def compare(left: Any, right: Any) -> int | None:
+    """Order of numbers, dates and strings, and of objects differing in one
+    numeric property such as amounts of money in one currency: -1, 0 or 1,
+    or `None` when the values do not compare"""
+    lhs, rhs = _decimal(left), _decimal(right)
+    if lhs is not None and rhs is not None:
+        return _order(lhs, rhs)
+    start, end = _datetime(left), _datetime(right)
+    if start is not None and end is not None:
+        return _order(start, end)
+    if isinstance(left, str) and isinstance(right, str):
+        return _order(left, right)
+    if isinstance(left, dict) and isinstance(right, dict) and len(left) == len(right):
+        order = None
+        for key, value in left.items():
+            if key not in right:
+                return None
+            other = right[key]
+            lhs, rhs = _decimal(value), _decimal(other)
+            if lhs is not None and rhs is not None and order is None:
+                order = _order(lhs, rhs)
+            elif not eq(value, other):
+                return None
+        return order
+    return None
+
+
+def lt(left: Any, right: Any) -> bool:
+    return compare(left, right) == -1
+
+
+def le(left: Any, right: Any) -> bool:
+    return compare(left, right) in (-1, 0)
+
+
+def gt(left: Any, right: Any) -> bool:
+    return compare(left, right) == 1
+
+
+def ge(left: Any, right: Any) -> bool:
+    return compare(left, right) in (1, 0)
+
+
+def contains(collection: Any, item: Any) -> bool:
+    """Whether a list holds an element, a string a substring or an object a property"""
+    if isinstance(collection, list):
+        return any(eq(element, item) for element in collection)
+    if isinstance(collection, (str, dict)) and isinstance(item, str):
+        return item in collection
+    return False
+
+
+def all_(items: Any, predicate: Callable[[Any], bool]) -> bool:
+    """Whether every element of a list matches; false when the value is not a list"""
+    return isinstance(items, list) and all(predicate(item) for item in items)
+
+
+def any_(items: Any, predicate: Callable[[Any], bool]) -> bool:
+    """Whether some element of a list matches"""
+    return isinstance(items, list) and any(predicate(item) for item in items)
+
+
+def filter_(items: Any, predicate: Callable[[Any], bool]) -> Any:
+    """Elements of a list that match"""
+    if not isinstance(items, list):
+        return None
+    return [item for item in items if predicate(item)]
+
+
+def map_(items: Any, transform: Callable[[Any], Any]) -> Any:
+    """Elements of a list transformed one by one"""
+    if not isinstance(items, list):
+        return None
+    return [transform(item) for item in items]
+
+
+def sum_(items: Any) -> Any:
+    """Sum of the elements of a list, property by property for objects"""
+    if not isinstance(items, list):
+        return None
+    if not items:
+        return 0
+    total = items[0]
+    for item in items[1:]:
+        total = add(total, item)
+    return total
+
+
+def min_(items: Any) -> Any:
+    """Smallest element of a list"""
+    return _extreme(items, -1)
+
+
+def max_(items: Any) -> Any:
+    """Largest element of a list"""
+    return _extreme(items, 1)
+
+
+def _extreme(items: Any, wanted: int) -> Any:
+    if not isinstance(items, list) or not items:
+        return None
+    best = items[0]
+    for item in items[1:]:
+        if compare(item, best) == wanted:
+            best = item
+    return best
+
+
+def today() -> str:
+    """Current date"""
+    return datetime.now(timezone.utc).date().isoformat()
+
+
+def now() -> str:
+    """Current time"""
+    return datetime.now(timezone.utc).isoformat().replace("+00:00", "Z")
+
+
+def days_between(start: Any, end: Any) -> Any:
+    """Whole days from one date to another"""
+    first, last = _datetime(start), _datetime(end)
+    if first is None or last is None:
+        return None
+    return int((last - first) / timedelta(days=1))
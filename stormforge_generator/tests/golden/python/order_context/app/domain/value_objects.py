@@ -0,0 +1,63 @@
+"""Value objects"""
+
+from decimal import Decimal
+from enum import Enum
+from typing import NewType
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+OrderId = NewType("OrderId", str)
+
+
+CustomerId = NewType("CustomerId", str)
+
+
+ProductId = NewType("ProductId", str)
+
+
+class Money(BaseModel):
+    """Monetary value with currency"""
+
+    model_config = ConfigDict(frozen=True)
+    amount: Decimal = Field(ge=0, decimal_places=2)
+    currency: str = Field(default="CNY", pattern="^[A-Z]{3}$")
+
+
+class OrderItem(BaseModel):
+    """A line item in an order"""
+
+    model_config = ConfigDict(frozen=True)
+    product_id: ProductId
+    product_name: str = Field(max_length=255)
+    quantity: int = Field(ge=1, le=999)
+    unit_price: Money
+    subtotal: Money
+
+
+class Address(BaseModel):
+    """Physical address"""
+
+    model_config = ConfigDict(frozen=True)
+    street: str = Field(max_length=255)
+    city: str = Field(max_length=100)
+    province: str = Field(max_length=100)
+    postal_code: str = Field(pattern="^[0-9]{6}$")
+    country: str = "CN"
+
+
+class OrderStatus(str, Enum):
+    CREATED = "Created"
+    """Order has been created, awaiting payment"""
+    PAID = "Paid"
+    """Payment has been received"""
+    PROCESSING = "Processing"
+    """Order is being prepared"""
+    SHIPPED = "Shipped"
+    """Order has been shipped"""
+    DELIVERED = "Delivered"
+    """Order has been delivered"""
+    CANCELLED = "Cancelled"
+    """Order has been cancelled"""
+    REFUNDED = "Refunded"
+    """Order has been refunded"""
@@ -0,0 +1,56 @@
+"""Error responses in the `{"code", "message"}` layout of the service API"""
+
+from typing import Any
+
+from fastapi import FastAPI, Request
+from fastapi.exceptions import RequestValidationError
+from fastapi.responses import JSONResponse
+
+STATUS_CODES = {
+    "VALIDATION_ERROR": 400,
+    "NOT_FOUND": 404,
+    "PRECONDITION_FAILED": 409,
+}
+
+
+class ApiError(Exception):
+    """Error answered with its code, such as `NOT_FOUND`, and message"""
+
+    def __init__(self, code: str, message: str) -> None:
+        super().__init__(message)
+        self.code = code
+        self.message = message
+
+    @property
+    def status_code(self) -> int:
+        return STATUS_CODES.get(self.code, 500)
+
+
+def describe(problem: dict[str, Any]) -> str:
+    """Problem of a request, prefixed with the field it is in, if any"""
+    field = ".".join(map(str, problem["loc"][1:]))
+    return "{}: {}".format(field, problem["msg"]) if field else problem["msg"]
+
+
+def install_error_handlers(app: FastAPI) -> None:
+    """Answer `ApiError`s and invalid requests with an error body"""
+
+    @app.exception_handler(ApiError)
+    async def api_error(request: Request, error: ApiError) -> JSONResponse:
+        return JSONResponse(
+            status_code=error.status_code,
+            content={"code": error.code, "message": error.message},
+        )
+
+    @app.exception_handler(RequestValidationError)
+    async def validation_error(
+        request: Request, error: RequestValidationError
+    ) -> JSONResponse:
+        problems = [describe(problem) for problem in error.errors()]
+        return JSONResponse(
+            status_code=400,
+            content={
+                "code": "VALIDATION_ERROR",
+                "message": "Validation error: {}".format("; ".join(problems)),
+            },
+        )
@@ -0,0 +1,83 @@
+"""Event store
+
+Events are kept per aggregate in append order. Appends check the version
+the writer expects, so concurrent writers to one aggregate are detected.
+"""
+
+import asyncio
+from abc import ABC, abstractmethod
+
+from app.domain.events import DomainEvent
+
+
+class ConcurrencyError(Exception):
+    """The aggregate is not at the version the writer expected"""
+
+
+class EventStore(ABC):
+    """Event store"""
+
+    @abstractmethod
+    async def append_events(
+        self,
+        aggregate_id: str,
+        aggregate_type: str,
+        events: list[DomainEvent],
+        expected_version: int | None = None,
+    ) -> None:
+        """Append events to an aggregate
+
+        Nothing is written when `expected_version` does not match the
+        number of events already stored for the aggregate.
+        """
+
+    @abstractmethod
+    async def version(self, aggregate_id: str, aggregate_type: str) -> int:
+        """Number of events stored for an aggregate"""
+
+    @abstractmethod
+    async def load_events(
+        self, aggregate_id: str, aggregate_type: str
+    ) -> list[DomainEvent]:
+        """Events of an aggregate in append order"""
+
+    @abstractmethod
+    async def get_all_events(self) -> list[DomainEvent]:
+        """Events of all aggregates in append order"""
+
+
+class InMemoryEventStore(EventStore):
+    """Event store keeping events in memory, for development and tests"""
+
+    def __init__(self) -> None:
+        self._streams: dict[tuple[str, str], list[DomainEvent]] = {}
+        self._log: list[DomainEvent] = []
+        self._lock = asyncio.Lock()
+
+    async def append_events(
+        self,
+        aggregate_id: str,
+        aggregate_type: str,
+        events: list[DomainEvent],
+        expected_version: int | None = None,
+    ) -> None:
+        async with self._lock:
+            stream = self._streams.setdefault((aggregate_type, aggregate_id), [])
+            if expected_version is not None and expected_version != len(stream):
+                raise ConcurrencyError(
+                    f"Concurrency conflict: expected version {expected_version}, "
+                    f"found {len(stream)}"
+                )
+            stream.extend(events)
+            self._log.extend(events)
+
+    async def version(self, aggregate_id: str, aggregate_type: str) -> int:
+        return len(self._streams.get((aggregate_type, aggregate_id), []))
+
+    async def load_events(
+        self, aggregate_id: str, aggregate_type: str
+    ) -> list[DomainEvent]:
+        return list(self._streams.get((aggregate_type, aggregate_id), []))
+
+    async def get_all_events(self) -> list[DomainEvent]:
+        return list(self._log)
@@ -0,0 +1,60 @@
+"""Tests of the in-memory event store"""
+
+import pytest
+
+from app.domain.events import OrderCreated
+from app.infrastructure.event_store import ConcurrencyError, InMemoryEventStore
+
+AGGREGATE_TYPE = "Order"
+AGGREGATE_ID = "order-1"
+
+
+def example_event() -> OrderCreated:
+    return OrderCreated(
+        aggregate_id=AGGREGATE_ID,
+        **{
+            "order_id": "ord_00000000-0000-4000-8000-000000000001",
+            "customer_id": "cust_00000000-0000-4000-8000-000000000001",
+            "items": [
+                {
+                    "product_id": "prod_00000000-0000-4000-8000-000000000001",
+                    "product_name": "Product name 1",
+                    "quantity": 1,
+                    "unit_price": {"amount": "10.00", "currency": "CNY"},
+                    "subtotal": {"amount": "10.00", "currency": "CNY"},
+                },
+            ],
+            "shipping_address": {
+                "street": "Street 1",
+                "city": "City 1",
+                "province": "Province 1",
+                "postal_code": "000000",
+                "country": "CN",
+            },
+            "total_amount": {"amount": "10.00", "currency": "CNY"},
+            "created_at": "2024-01-01T09:00:00Z",
+        },
+    )
+
+
+async def test_events_load_in_append_order(event_store: InMemoryEventStore) -> None:
+    first, second = example_event(), example_event()
+    await event_store.append_events(AGGREGATE_ID, AGGREGATE_TYPE, [first])
+    await event_store.append_events(
+        AGGREGATE_ID, AGGREGATE_TYPE, [second], expected_version=1
+    )
+
+    events = await event_store.load_events(AGGREGATE_ID, AGGREGATE_TYPE)
+    assert events == [first, second]
+    assert await event_store.version(AGGREGATE_ID, AGGREGATE_TYPE) == 2
+    assert await event_store.load_events("other", AGGREGATE_TYPE) == []
+
+
+async def test_append_rejects_stale_version(event_store: InMemoryEventStore) -> None:
+    await event_store.append_events(AGGREGATE_ID, AGGREGATE_TYPE, [example_event()])
+
+    with pytest.raises(ConcurrencyError):
+        await event_store.append_events(
+            AGGREGATE_ID, AGGREGATE_TYPE, [example_event()], expected_version=0
+        )
+    assert await event_store.version(AGGREGATE_ID, AGGREGATE_TYPE) == 1
@@ -0,0 +1,47 @@
+"""Tests of the expression runtime"""
+
+from app.domain import expressions
+
+
+def test_paths_read_missing_properties_as_none() -> None:
+    order = {"items": [{"qty": 1}, {"qty": 2}]}
+    assert len(expressions.get(order, "items")) == 2
+    assert expressions.get(expressions.get(order, "items"), "qty") == [1, 2]
+    assert expressions.get(order, "status") is None
+    assert not expressions.truthy(expressions.get(order, "status"))
+    assert not expressions.all_(expressions.get(order, "lines"), expressions.truthy)
+
+
+def test_numbers_compare_by_value() -> None:
+    assert expressions.eq("12.50", 12.5)
+    assert expressions.ge(3, "2.99")
+    assert not expressions.lt(None, 1)
+    assert not expressions.ge(None, 1)
+    assert not expressions.eq(True, 1)
+    assert expressions.lt(
+        {"amount": "5", "currency": "EUR"}, {"amount": "7.5", "currency": "EUR"}
+    )
+    assert (
+        expressions.compare(
+            {"amount": "5", "currency": "EUR"}, {"amount": "5", "currency": "USD"}
+        )
+        is None
+    )
+
+
+def test_sums_keep_the_shape_of_their_operands() -> None:
+    subtotals = [
+        {"amount": "10.25", "currency": "EUR"},
+        {"amount": "4.75", "currency": "EUR"},
+    ]
+    assert expressions.sum_(subtotals) == {"amount": "15", "currency": "EUR"}
+    assert expressions.sum_([1, 2, 3]) == 6
+    assert expressions.mul(3, {"amount": "2.5"}) == {"amount": "7.5"}
+    assert expressions.add("ORD-", 7) == "ORD-7"
+    assert expressions.div(1, 0) is None
+
+
+def test_dates() -> None:
+    assert expressions.days_between("2024-01-01", "2024-01-31") == 30
+    assert expressions.gt("2024-01-02T00:00:00Z", "2024-01-01")
+    assert expressions.le(expressions.today(), expressions.now())
@@ -0,0 +1,24 @@
+"""Example requests to the Order queries"""
+
+from fastapi.testclient import TestClient
+
+
+def test_get_order_accepts_parameters(client: TestClient) -> None:
+    response = client.get(
+        "/api/get-order",
+        params={"order_id": "ord_00000000-0000-4000-8000-000000000001"},
+    )
+
+    assert response.status_code != 400
+
+
+def test_list_orders_accepts_parameters(client: TestClient) -> None:
+    response = client.get("/api/list-orders")
+
+    assert response.status_code != 400
+
+
+def test_get_orders_by_status_accepts_parameters(client: TestClient) -> None:
+    response = client.get("/api/get-orders-by-status", params={"status": "Created"})
+
+    assert response.status_code != 400
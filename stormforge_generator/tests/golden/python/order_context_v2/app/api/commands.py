@@ -0,0 +1,45 @@
+"""Command routes"""
+
+from fastapi import APIRouter, Depends
+
+from app.application.command_handlers import CommandHandlers
+from app.dependencies import get_command_handlers
+from app.domain.commands import CancelOrder, ConfirmPayment, CreateOrder, ShipOrder
+from app.schemas import ApiResponse
+
+
+router = APIRouter(tags=["commands"])
+
+
+@router.post(
+    "/create-order", response_model=ApiResponse[str], summary="Create a new order"
+)
+async def create_order(
+    command: CreateOrder, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.create_order(command))
+
+
+@router.post(
+    "/confirm-payment", response_model=ApiResponse[str], summary="Confirm order payment"
+)
+async def confirm_payment(
+    command: ConfirmPayment, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.confirm_payment(command))
+
+
+@router.post("/ship-order", response_model=ApiResponse[str], summary="Ship the order")
+async def ship_order(
+    command: ShipOrder, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.ship_order(command))
+
+
+@router.post(
+    "/cancel-order", response_model=ApiResponse[str], summary="Cancel the order"
+)
+async def cancel_order(
+    command: CancelOrder, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.cancel_order(command))
@@ -0,0 +1,23 @@
+"""Wiring of the event store and handlers, overridable in tests"""
+
+from fastapi import Depends
+
+from app.application.command_handlers import CommandHandlers
+from app.application.query_handlers import QueryHandlers
+from app.infrastructure.event_store import EventStore, InMemoryEventStore
+
+event_store = InMemoryEventStore()
+
+
+def get_event_store() -> EventStore:
+    return event_store
+
+
+def get_command_handlers(
+    store: EventStore = Depends(get_event_store),
+) -> CommandHandlers:
+    return CommandHandlers(store)
+
+
+def get_query_handlers(store: EventStore = Depends(get_event_store)) -> QueryHandlers:
+    return QueryHandlers(store)
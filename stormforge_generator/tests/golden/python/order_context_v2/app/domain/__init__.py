@@ -0,0 +1 @@
+"""Domain model: value objects, aggregates, commands and events"""
@@ -0,0 +1,11 @@
+"""Aggregate state"""
+
+from pydantic import BaseModel
+
+from app.domain.value_objects import OrderId
+
+
+class Order(BaseModel):
+    """Order aggregate root managing order lifecycle"""
+
+    id: OrderId
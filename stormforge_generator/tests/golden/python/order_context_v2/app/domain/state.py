@@ -0,0 +1,64 @@
+"""Aggregate state folded from events
+
+The state of an aggregate is a JSON object of its root entity properties,
+starting from their defaults. Each event copies its payload fields into the
+state, and an event whose name ends with a value of the aggregate's status
+enum, such as `OrderPaid` for `PAID`, sets the status. The identifier is the
+aggregate ID of the first event, unless its payload carries the identifier
+field.
+"""
+
+from collections.abc import Callable, Iterable
+from typing import Any
+
+from pydantic import BaseModel
+
+
+State = dict[str, Any]
+"""JSON object of the root entity properties of an aggregate"""
+
+ENVELOPE_FIELDS = ("type", "event_id", "occurred_at", "aggregate_id")
+"""Event fields that describe the event rather than the aggregate"""
+
+
+def fold(
+    events: Iterable[BaseModel],
+    apply: Callable[[State | None, BaseModel], State],
+    state: State | None = None,
+) -> State | None:
+    """Fold events into a state, if any, with the apply function of an aggregate"""
+    for event in events:
+        state = apply(state, event)
+    return state
+
+
+def copy_payload(state: State, event: dict[str, Any]) -> None:
+    """Copy the payload fields of an event into a state"""
+    for key, value in event.items():
+        if key not in ENVELOPE_FIELDS:
+            state[key] = value
+
+
+def new_order() -> State:
+    """Initial state of a `Order`: the defaults of its root entity properties"""
+    return {}
+
+
+def apply_order(state: State | None, event: BaseModel) -> State:
+    """Fold an event into the state of a `Order`"""
+    if state is None:
+        state = new_order()
+    fields = event.model_dump(mode="json", by_alias=True)
+    status = {
+        "OrderCreated": "Created",
+        "OrderPaid": "Paid",
+        "OrderShipped": "Shipped",
+        "OrderDelivered": "Delivered",
+        "OrderCancelled": "Cancelled",
+    }.get(fields["type"])
+    if status is not None:
+        state["status"] = status
+    copy_payload(state, fields)
+    if "aggregate_id" in fields:
+        state.setdefault("id", fields["aggregate_id"])
+    return state
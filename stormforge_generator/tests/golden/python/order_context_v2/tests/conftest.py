@@ -0,0 +1,23 @@
+"""Fixtures giving each test its own event store"""
+
+from collections.abc import Iterator
+
+import pytest
+from fastapi.testclient import TestClient
+
+from app.dependencies import get_event_store
+from app.infrastructure.event_store import InMemoryEventStore
+from app.main import app
+
+
+@pytest.fixture
+def event_store() -> InMemoryEventStore:
+    return InMemoryEventStore()
+
+
+@pytest.fixture
+def client(event_store: InMemoryEventStore) -> Iterator[TestClient]:
+    app.dependency_overrides[get_event_store] = lambda: event_store
+    with TestClient(app) as client:
+        yield client
+    app.dependency_overrides.clear()
@@ -0,0 +1,122 @@
+"""Example requests to the Order commands"""
+
+from fastapi.testclient import TestClient
+
+
+def test_create_order_is_accepted(client: TestClient) -> None:
+    response = client.post(
+        "/api/create-order",
+        json={
+            "customer_id": "cust_00000000-0000-4000-8000-000000000001",
+            "items": [
+                {
+                    "product_id": "prod_00000000-0000-4000-8000-000000000001",
+                    "product_name": "Product name 1",
+                    "quantity": 1,
+                    "unit_price": {"amount": "10.00", "currency": "CNY"},
+                    "subtotal": {"amount": "10.00", "currency": "CNY"},
+                },
+            ],
+            "shipping_address": {
+                "street": "Street 1",
+                "city": "City 1",
+                "province": "Province 1",
+                "postal_code": "000000",
+                "country": "CN",
+            },
+        },
+    )
+
+    assert response.status_code == 200
+    assert response.json() == {"data": "Command executed successfully"}
+
+
+def test_create_order_requires_customer_id(client: TestClient) -> None:
+    response = client.post(
+        "/api/create-order",
+        json={
+            "items": [
+                {
+                    "product_id": "prod_00000000-0000-4000-8000-000000000001",
+                    "product_name": "Product name 1",
+                    "quantity": 1,
+                    "unit_price": {"amount": "10.00", "currency": "CNY"},
+                    "subtotal": {"amount": "10.00", "currency": "CNY"},
+                },
+            ],
+            "shipping_address": {
+                "street": "Street 1",
+                "city": "City 1",
+                "province": "Province 1",
+                "postal_code": "000000",
+                "country": "CN",
+            },
+        },
+    )
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_confirm_payment_needs_an_existing_order(client: TestClient) -> None:
+    response = client.post(
+        "/api/confirm-payment",
+        json={
+            "order_id": "ord_00000000-0000-4000-8000-000000000001",
+            "payment_id": "Payment id 1",
+        },
+    )
+
+    assert response.status_code == 404
+    assert response.json()["code"] == "NOT_FOUND"
+
+
+def test_confirm_payment_requires_order_id(client: TestClient) -> None:
+    response = client.post("/api/confirm-payment", json={"payment_id": "Payment id 1"})
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_ship_order_needs_an_existing_order(client: TestClient) -> None:
+    response = client.post(
+        "/api/ship-order",
+        json={
+            "order_id": "ord_00000000-0000-4000-8000-000000000001",
+            "tracking_number": "Tracking number 1",
+            "carrier": "Carrier 1",
+        },
+    )
+
+    assert response.status_code == 404
+    assert response.json()["code"] == "NOT_FOUND"
+
+
+def test_ship_order_requires_order_id(client: TestClient) -> None:
+    response = client.post(
+        "/api/ship-order",
+        json={"tracking_number": "Tracking number 1", "carrier": "Carrier 1"},
+    )
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_cancel_order_needs_an_existing_order(client: TestClient) -> None:
+    response = client.post(
+        "/api/cancel-order",
+        json={
+            "order_id": "ord_00000000-0000-4000-8000-000000000001",
+            "reason": "Reason 1",
+        },
+    )
+
+    assert response.status_code == 404
+    assert response.json()["code"] == "NOT_FOUND"
+
+
+def test_cancel_order_requires_order_id(client: TestClient) -> None:
+    response = client.post("/api/cancel-order", json={"reason": "Reason 1"})
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
@@ -0,0 +1 @@
+"""Payment service, generated by StormForge Generator"""
@@ -0,0 +1,58 @@
+"""Command routes"""
+
+from fastapi import APIRouter, Depends
+
+from app.application.command_handlers import CommandHandlers
+from app.dependencies import get_command_handlers
+from app.domain.commands import (
+    CompletePayment,
+    FailPayment,
+    InitiatePayment,
+    RefundPayment,
+)
+from app.schemas import ApiResponse
+
+
+router = APIRouter(tags=["commands"])
+
+
+@router.post(
+    "/initiate-payment",
+    response_model=ApiResponse[str],
+    summary="Initiate a payment for an order",
+)
+async def initiate_payment(
+    command: InitiatePayment, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.initiate_payment(command))
+
+
+@router.post(
+    "/complete-payment",
+    response_model=ApiResponse[str],
+    summary="Mark payment as completed",
+)
+async def complete_payment(
+    command: CompletePayment, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.complete_payment(command))
+
+
+@router.post(
+    "/fail-payment", response_model=ApiResponse[str], summary="Mark payment as failed"
+)
+async def fail_payment(
+    command: FailPayment, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.fail_payment(command))
+
+
+@router.post(
+    "/refund-payment",
+    response_model=ApiResponse[str],
+    summary="Refund a completed payment",
+)
+async def refund_payment(
+    command: RefundPayment, handlers: CommandHandlers = Depends(get_command_handlers)
+) -> ApiResponse[str]:
+    return ApiResponse(data=await handlers.refund_payment(command))
@@ -0,0 +1,34 @@
+"""Query routes"""
+
+from fastapi import APIRouter, Depends
+
+from app.application.query_handlers import QueryHandlers
+from app.dependencies import get_query_handlers
+from app.domain.aggregates import Payment
+from app.domain.value_objects import OrderId, PaymentId
+from app.schemas import ApiResponse
+
+
+router = APIRouter(tags=["queries"])
+
+
+@router.get(
+    "/get-payment",
+    response_model=ApiResponse[Payment | None],
+    summary="Get payment by ID",
+)
+async def get_payment(
+    *, payment_id: PaymentId, handlers: QueryHandlers = Depends(get_query_handlers)
+) -> ApiResponse[Payment | None]:
+    return ApiResponse(data=await handlers.get_payment(payment_id=payment_id))
+
+
+@router.get(
+    "/get-payment-by-order",
+    response_model=ApiResponse[Payment | None],
+    summary="Get payment for an order",
+)
+async def get_payment_by_order(
+    *, order_id: OrderId, handlers: QueryHandlers = Depends(get_query_handlers)
+) -> ApiResponse[Payment | None]:
+    return ApiResponse(data=await handlers.get_payment_by_order(order_id=order_id))
@@ -0,0 +1 @@
+"""Command and query handlers"""
@@ -0,0 +1,182 @@
+"""Command handlers backed by the event store
+
+A command is validated by its model, then checked against the state of its
+aggregate folded from the stored events. A command carrying the aggregate's
+identifier acts on that aggregate, any other command creates one. Each event
+takes a field from the `computed` expression of the event property, else from
+the command field, the aggregate ID or the aggregate state of the same name;
+`DateTime` fields the command does not give are the time it is handled. The
+events must keep the aggregate's invariants, and are appended at the version
+the state was loaded at, so a concurrent command on the same aggregate fails
+rather than being lost.
+"""
+
+from typing import Any, TypeVar
+from uuid import uuid4
+
+from pydantic import BaseModel, ValidationError
+
+from app.domain import expressions
+from app.domain.commands import (
+    CompletePayment,
+    FailPayment,
+    InitiatePayment,
+    RefundPayment,
+)
+from app.domain.events import (
+    DomainEvent,
+    PaymentCompleted,
+    PaymentFailed,
+    PaymentInitiated,
+    PaymentRefunded,
+)
+from app.domain.state import apply_payment, fold
+from app.errors import ApiError
+from app.infrastructure.event_store import EventStore
+
+
+E = TypeVar("E", bound=BaseModel)
+
+
+def event(event_type: type[E], aggregate_id: str, fields: dict[str, Any]) -> E:
+    """Event of a type built from its payload fields"""
+    try:
+        return event_type.model_validate({**fields, "aggregate_id": aggregate_id})
+    except ValidationError as error:
+        raise ApiError(
+            "INTERNAL_ERROR", f"Cannot build {event_type.__name__}: {error}"
+        ) from error
+
+
+class CommandHandlers:
+    """Handlers of the Payment commands
+
+    Each handler loads its aggregate from the event store, executes the
+    command and appends the events it produces.
+    """
+
+    def __init__(self, event_store: EventStore) -> None:
+        self.event_store = event_store
+
+    async def initiate_payment(self, command: InitiatePayment) -> str:
+        """Initiate a payment for an order
+
+        Produces `PaymentInitiated`.
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(uuid4())
+        version = 0
+        events: list[DomainEvent] = [
+            event(
+                PaymentInitiated,
+                aggregate_id,
+                {
+                    "payment_id": aggregate_id,
+                    "order_id": values["order_id"],
+                    "amount": values["amount"],
+                    "method": values["method"],
+                    "created_at": expressions.now(),
+                },
+            ),
+        ]
+        await self.event_store.append_events(aggregate_id, "Payment", events, version)
+        return "Command executed successfully"
+
+    async def complete_payment(self, command: CompletePayment) -> str:
+        """Mark payment as completed
+
+        Produces `PaymentCompleted`.
+
+        Preconditions:
+        - Can only complete pending or processing payments
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.payment_id)
+        history = await self.event_store.load_events(aggregate_id, "Payment")
+        state = fold(history, apply_payment)
+        version = len(history)
+        if version == 0:
+            raise ApiError("NOT_FOUND", f"Payment {aggregate_id} not found")
+        scope = {**values, "payment": state}
+        if not expressions.contains(
+            ["Pending", "Processing"],
+            expressions.get(expressions.get(scope, "payment"), "status"),
+        ):
+            raise ApiError(
+                "PRECONDITION_FAILED",
+                "Can only complete pending or processing payments",
+            )
+        events: list[DomainEvent] = [
+            event(
+                PaymentCompleted,
+                aggregate_id,
+                {
+                    "payment_id": values["payment_id"],
+                    "order_id": expressions.get(state, "order_id"),
+                    "transaction_id": values["transaction_id"],
+                    "completed_at": expressions.now(),
+                },
+            ),
+        ]
+        await self.event_store.append_events(aggregate_id, "Payment", events, version)
+        return "Command executed successfully"
+
+    async def fail_payment(self, command: FailPayment) -> str:
+        """Mark payment as failed
+
+        Produces `PaymentFailed`.
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.payment_id)
+        history = await self.event_store.load_events(aggregate_id, "Payment")
+        state = fold(history, apply_payment)
+        version = len(history)
+        events: list[DomainEvent] = [
+            event(
+                PaymentFailed,
+                aggregate_id,
+                {
+                    "payment_id": values["payment_id"],
+                    "order_id": expressions.get(state, "order_id"),
+                    "reason": values["reason"],
+                    "failed_at": expressions.now(),
+                },
+            ),
+        ]
+        await self.event_store.append_events(aggregate_id, "Payment", events, version)
+        return "Command executed successfully"
+
+    async def refund_payment(self, command: RefundPayment) -> str:
+        """Refund a completed payment
+
+        Produces `PaymentRefunded`.
+
+        Preconditions:
+        - Can only refund completed payments
+        """
+        values = command.model_dump(mode="json", by_alias=True)
+        aggregate_id = str(command.payment_id)
+        history = await self.event_store.load_events(aggregate_id, "Payment")
+        state = fold(history, apply_payment)
+        version = len(history)
+        if version == 0:
+            raise ApiError("NOT_FOUND", f"Payment {aggregate_id} not found")
+        scope = {**values, "payment": state}
+        if not expressions.eq(
+            expressions.get(expressions.get(scope, "payment"), "status"), "Completed"
+        ):
+            raise ApiError("PRECONDITION_FAILED", "Can only refund completed payments")
+        events: list[DomainEvent] = [
+            event(
+                PaymentRefunded,
+                aggregate_id,
+                {
+                    "payment_id": values["payment_id"],
+                    "order_id": expressions.get(state, "order_id"),
+                    "refund_amount": values["refund_amount"],
+                    "refunded_at": expressions.now(),
+                },
+            ),
+        ]
+        await self.event_store.append_events(aggregate_id, "Payment", events, version)
+        return "Command executed successfully"
@@ -0,0 +1,22 @@
+"""Query handlers"""
+
+from app.domain.aggregates import Payment
+from app.domain.value_objects import OrderId, PaymentId
+from app.infrastructure.event_store import EventStore
+
+
+class QueryHandlers:
+    """Handlers of the Payment queries"""
+
+    def __init__(self, event_store: EventStore) -> None:
+        self.event_store = event_store
+
+    async def get_payment(self, *, payment_id: PaymentId) -> Payment | None:
+        """Get payment by ID"""
+        # TODO: Read the result from a read model or by replaying events
+        return None
+
+    async def get_payment_by_order(self, *, order_id: OrderId) -> Payment | None:
+        """Get payment for an order"""
+        # TODO: Read the result from a read model or by replaying events
+        return None
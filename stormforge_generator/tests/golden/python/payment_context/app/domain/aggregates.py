@@ -0,0 +1,26 @@
+"""Aggregate state"""
+
+from datetime import datetime
+
+from pydantic import BaseModel
+
+from app.domain.value_objects import (
+    Money,
+    OrderId,
+    PaymentId,
+    PaymentMethod,
+    PaymentStatus,
+)
+
+
+class Payment(BaseModel):
+    """Payment aggregate handling payment lifecycle"""
+
+    id: PaymentId
+    order_id: OrderId
+    amount: Money
+    method: PaymentMethod
+    status: PaymentStatus = PaymentStatus.PENDING
+    transaction_id: str | None = None
+    created_at: datetime
+    completed_at: datetime | None = None
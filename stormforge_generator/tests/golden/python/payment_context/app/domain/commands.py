@@ -0,0 +1,36 @@
+"""Commands"""
+
+from pydantic import BaseModel, Field
+
+from app.domain.value_objects import Money, OrderId, PaymentId, PaymentMethod
+
+
+class InitiatePayment(BaseModel):
+    """Initiate a payment for an order"""
+
+    order_id: OrderId
+    amount: Money
+    method: PaymentMethod
+
+
+class CompletePayment(BaseModel):
+    """Mark payment as completed"""
+
+    payment_id: PaymentId
+    transaction_id: str
+
+
+class FailPayment(BaseModel):
+    """Mark payment as failed"""
+
+    payment_id: PaymentId
+    reason: str
+
+
+class RefundPayment(BaseModel):
+    """Refund a completed payment"""
+
+    payment_id: PaymentId
+    refund_amount: Money | None = Field(
+        default=None, description="If not provided, full refund"
+    )
@@ -0,0 +1,90 @@
+"""Domain events"""
+
+from datetime import datetime, timezone
+from typing import Annotated, Literal, Union
+from uuid import UUID, uuid4
+
+from pydantic import BaseModel, Field
+
+from app.domain.value_objects import Money, OrderId, PaymentId, PaymentMethod
+
+
+def utc_now() -> datetime:
+    """Current time in UTC"""
+    return datetime.now(timezone.utc)
+
+
+class PaymentInitiated(BaseModel):
+    """Payment has been initiated"""
+
+    type: Literal["PaymentInitiated"] = "PaymentInitiated"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    payment_id: PaymentId
+    order_id: OrderId
+    amount: Money
+    method: PaymentMethod
+    created_at: datetime
+
+
+class PaymentCompleted(BaseModel):
+    """Payment completed successfully"""
+
+    type: Literal["PaymentCompleted"] = "PaymentCompleted"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    payment_id: PaymentId
+    order_id: OrderId
+    transaction_id: str
+    completed_at: datetime
+
+
+class PaymentFailed(BaseModel):
+    """Payment has failed"""
+
+    type: Literal["PaymentFailed"] = "PaymentFailed"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    payment_id: PaymentId
+    order_id: OrderId
+    reason: str
+    failed_at: datetime
+
+
+class PaymentRefunded(BaseModel):
+    """Payment has been refunded"""
+
+    type: Literal["PaymentRefunded"] = "PaymentRefunded"
+    event_id: UUID = Field(default_factory=uuid4, description="Unique event identifier")
+    occurred_at: datetime = Field(
+        default_factory=utc_now, description="Timestamp when the event occurred"
+    )
+    aggregate_id: str = Field(
+        description="ID of the aggregate that produced this event"
+    )
+    payment_id: PaymentId
+    order_id: OrderId
+    refund_amount: Money
+    refunded_at: datetime
+
+
+DomainEvent = Annotated[
+    Union[PaymentInitiated, PaymentCompleted, PaymentFailed, PaymentRefunded],
+    Field(discriminator="type"),
+]
+"""Domain event of the Payment context, tagged by `type`"""
@@ -0,0 +1,43 @@
+"""Value objects"""
+
+from decimal import Decimal
+from enum import Enum
+from typing import NewType
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+PaymentId = NewType("PaymentId", str)
+
+
+OrderId = NewType("OrderId", str)
+
+
+class Money(BaseModel):
+    model_config = ConfigDict(frozen=True)
+    amount: Decimal = Field(ge=0)
+    currency: str = "CNY"
+
+
+class PaymentMethod(str, Enum):
+    ALIPAY = "Alipay"
+    """Alipay payment"""
+    WECHAT_PAY = "WechatPay"
+    """WeChat Pay"""
+    CREDIT_CARD = "CreditCard"
+    """Credit card payment"""
+    BANK_TRANSFER = "BankTransfer"
+    """Bank transfer"""
+
+
+class PaymentStatus(str, Enum):
+    PENDING = "Pending"
+    """Payment is pending"""
+    PROCESSING = "Processing"
+    """Payment is being processed"""
+    COMPLETED = "Completed"
+    """Payment completed successfully"""
+    FAILED = "Failed"
+    """Payment failed"""
+    REFUNDED = "Refunded"
+    """Payment has been refunded"""
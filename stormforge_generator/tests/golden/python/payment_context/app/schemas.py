@@ -0,0 +1,22 @@
+"""Response bodies shared by the routes"""
+
+from typing import Generic, TypeVar
+
+from pydantic import BaseModel
+
+T = TypeVar("T")
+
+
+class ApiResponse(BaseModel, Generic[T]):
+    """`{"data": ...}` envelope of command and query responses"""
+
+    data: T
+
+
+class PagedResult(BaseModel, Generic[T]):
+    """Page of query results"""
+
+    items: list[T]
+    total: int
+    page: int
+    page_size: int
@@ -0,0 +1,79 @@
+"""Example requests to the Payment commands"""
+
+from fastapi.testclient import TestClient
+
+
+def test_initiate_payment_is_accepted(client: TestClient) -> None:
+    response = client.post(
+        "/api/initiate-payment",
+        json={
+            "order_id": "ord_00000000-0000-4000-8000-000000000001",
+            "amount": {"amount": "10.00", "currency": "CNY"},
+            "method": "Alipay",
+        },
+    )
+
+    assert response.status_code == 200
+    assert response.json() == {"data": "Command executed successfully"}
+
+
+def test_initiate_payment_requires_order_id(client: TestClient) -> None:
+    response = client.post(
+        "/api/initiate-payment",
+        json={"amount": {"amount": "10.00", "currency": "CNY"}, "method": "Alipay"},
+    )
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_complete_payment_needs_an_existing_payment(client: TestClient) -> None:
+    response = client.post(
+        "/api/complete-payment",
+        json={
+            "payment_id": "pay_00000000-0000-4000-8000-000000000001",
+            "transaction_id": "Transaction id 1",
+        },
+    )
+
+    assert response.status_code == 404
+    assert response.json()["code"] == "NOT_FOUND"
+
+
+def test_complete_payment_requires_payment_id(client: TestClient) -> None:
+    response = client.post(
+        "/api/complete-payment", json={"transaction_id": "Transaction id 1"}
+    )
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_fail_payment_requires_payment_id(client: TestClient) -> None:
+    response = client.post("/api/fail-payment", json={"reason": "Reason 1"})
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
+
+
+def test_refund_payment_needs_an_existing_payment(client: TestClient) -> None:
+    response = client.post(
+        "/api/refund-payment",
+        json={
+            "payment_id": "pay_00000000-0000-4000-8000-000000000001",
+            "refund_amount": {"amount": "10.00", "currency": "CNY"},
+        },
+    )
+
+    assert response.status_code == 404
+    assert response.json()["code"] == "NOT_FOUND"
+
+
+def test_refund_payment_requires_payment_id(client: TestClient) -> None:
+    response = client.post(
+        "/api/refund-payment",
+        json={"refund_amount": {"amount": "10.00", "currency": "CNY"}},
+    )
+
+    assert response.status_code == 400
+    assert response.json()["code"] == "VALIDATION_ERROR"
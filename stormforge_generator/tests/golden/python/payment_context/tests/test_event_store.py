@@ -0,0 +1,45 @@
+"""Tests of the in-memory event store"""
+
+import pytest
+
+from app.domain.events import PaymentInitiated
+from app.infrastructure.event_store import ConcurrencyError, InMemoryEventStore
+
+AGGREGATE_TYPE = "Payment"
+AGGREGATE_ID = "payment-1"
+
+
+def example_event() -> PaymentInitiated:
+    return PaymentInitiated(
+        aggregate_id=AGGREGATE_ID,
+        **{
+            "payment_id": "pay_00000000-0000-4000-8000-000000000001",
+            "order_id": "ord_00000000-0000-4000-8000-000000000001",
+            "amount": {"amount": "10.00", "currency": "CNY"},
+            "method": "Alipay",
+            "created_at": "2024-01-01T09:00:00Z",
+        },
+    )
+
+
+async def test_events_load_in_append_order(event_store: InMemoryEventStore) -> None:
+    first, second = example_event(), example_event()
+    await event_store.append_events(AGGREGATE_ID, AGGREGATE_TYPE, [first])
+    await event_store.append_events(
+        AGGREGATE_ID, AGGREGATE_TYPE, [second], expected_version=1
+    )
+
+    events = await event_store.load_events(AGGREGATE_ID, AGGREGATE_TYPE)
+    assert events == [first, second]
+    assert await event_store.version(AGGREGATE_ID, AGGREGATE_TYPE) == 2
+    assert await event_store.load_events("other", AGGREGATE_TYPE) == []
+
+
+async def test_append_rejects_stale_version(event_store: InMemoryEventStore) -> None:
+    await event_store.append_events(AGGREGATE_ID, AGGREGATE_TYPE, [example_event()])
+
+    with pytest.raises(ConcurrencyError):
+        await event_store.append_events(
+            AGGREGATE_ID, AGGREGATE_TYPE, [example_event()], expected_version=0
+        )
+    assert await event_store.version(AGGREGATE_ID, AGGREGATE_TYPE) == 1
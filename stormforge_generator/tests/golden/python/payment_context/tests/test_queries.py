@@ -0,0 +1,21 @@
+"""Example requests to the Payment queries"""
+
+from fastapi.testclient import TestClient
+
+
+def test_get_payment_accepts_parameters(client: TestClient) -> None:
+    response = client.get(
+        "/api/get-payment",
+        params={"payment_id": "pay_00000000-0000-4000-8000-000000000001"},
+    )
+
+    assert response.status_code != 400
+
+
+def test_get_payment_by_order_accepts_parameters(client: TestClient) -> None:
+    response = client.get(
+        "/api/get-payment-by-order",
+        params={"order_id": "ord_00000000-0000-4000-8000-000000000001"},
+    )
+
+    assert response.status_code != 400
@@ -0,0 +1,121 @@
+import type { AddStock, ReserveStock, ReleaseStock, DeductStock } from "./commands";
+import type { GetProductParams, GetProductResult, GetProductBySkuParams, GetProductBySkuResult, CheckAvailabilityParams, CheckAvailabilityResult, GetLowStockProductsResult } from "./queries";
+
+/** Error response of the Inventory service */
+export class ApiError extends Error {
+  constructor(
+    /** HTTP status code */
+    readonly status: number,
+    /** Error code, such as `VALIDATION_ERROR` */
+    readonly code: string,
+    message: string,
+  ) {
+    super(message);
+    this.name = "ApiError";
+  }
+}
+
+/** Options of the Inventory client */
+export interface ClientOptions {
+  /** Base URL of the service, without the `/api` prefix */
+  baseUrl: string;
+  /** Headers sent with every request */
+  headers?: Record<string, string>;
+  /** Fetch implementation, the global `fetch` by default */
+  fetch?: typeof fetch;
+}
+
+/** `{"data": ...}` envelope of command and query responses */
+interface ApiResponse<T> {
+  data: T;
+}
+
+/** HTTP client for the Inventory service */
+export class InventoryClient {
+  private readonly baseUrl: string;
+  private readonly headers: Record<string, string>;
+  private readonly fetchImpl: typeof fetch;
+
+  constructor(options: ClientOptions) {
+    this.baseUrl = options.baseUrl.replace(/\/+$/, "");
+    this.headers = options.headers ?? {};
+    this.fetchImpl = options.fetch ?? globalThis.fetch.bind(globalThis);
+  }
+
+  /** Add stock to inventory */
+  async addStock(command: AddStock): Promise<string> {
+    return this.post<string>("/api/add-stock", command);
+  }
+
+  /** Reserve stock for an order */
+  async reserveStock(command: ReserveStock): Promise<string> {
+    return this.post<string>("/api/reserve-stock", command);
+  }
+
+  /** Release reserved stock */
+  async releaseStock(command: ReleaseStock): Promise<string> {
+    return this.post<string>("/api/release-stock", command);
+  }
+
+  /** Deduct reserved stock (when order ships) */
+  async deductStock(command: DeductStock): Promise<string> {
+    return this.post<string>("/api/deduct-stock", command);
+  }
+
+  /** Get product by ID */
+  async getProduct(params: GetProductParams): Promise<GetProductResult> {
+    return this.get<GetProductResult>("/api/get-product", params);
+  }
+
+  /** Get product by SKU */
+  async getProductBySku(params: GetProductBySkuParams): Promise<GetProductBySkuResult> {
+    return this.get<GetProductBySkuResult>("/api/get-product-by-sku", params);
+  }
+
+  /** Check if quantity is available */
+  async checkAvailability(params: CheckAvailabilityParams): Promise<CheckAvailabilityResult> {
+    return this.get<CheckAvailabilityResult>("/api/check-availability", params);
+  }
+
+  /** Get products below reorder level */
+  async getLowStockProducts(): Promise<GetLowStockProductsResult> {
+    return this.get<GetLowStockProductsResult>("/api/get-low-stock-products");
+  }
+
+  private async post<T>(path: string, body: unknown): Promise<T> {
+    const response = await this.request<ApiResponse<T>>("POST", path, body);
+    return response.data;
+  }
+
+  private async get<T>(path: string, params: object = {}): Promise<T> {
+    const query = new URLSearchParams();
+    for (const [key, value] of Object.entries(params)) {
+      if (value !== undefined && value !== null) {
+        query.append(key, String(value));
+      }
+    }
+    const search = query.toString();
+    const response = await this.request<ApiResponse<T>>("GET", search ? `${path}?${search}` : path);
+    return response.data;
+  }
+
+  private async request<T>(method: string, path: string, body?: unknown): Promise<T> {
+    const response = await this.fetchImpl(`${this.baseUrl}${path}`, {
+      method,
+      headers:
+        body === undefined ? this.headers : { ...this.headers, "content-type": "application/json" },
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    const text = await response.text();
+    const payload: unknown = text ? JSON.parse(text) : null;
+    if (!response.ok) {
+      const error = (payload ?? {}) as { code?: string; message?: string };
+      throw new ApiError(
+        response.status,
+        error.code ?? `HTTP_${response.status}`,
+        error.message ?? response.statusText,
+      );
+    }
+    return payload as T;
+  }
+}
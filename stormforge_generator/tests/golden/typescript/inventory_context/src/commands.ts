@@ -0,0 +1,24 @@
+import type { OrderId, ProductId, ReservationId } from "./types";
+
+/** Add stock to inventory */
+export interface AddStock {
+  product_id: ProductId;
+  quantity: number;
+}
+
+/** Reserve stock for an order */
+export interface ReserveStock {
+  product_id: ProductId;
+  order_id: OrderId;
+  quantity: number;
+}
+
+/** Release reserved stock */
+export interface ReleaseStock {
+  reservation_id: ReservationId;
+}
+
+/** Deduct reserved stock (when order ships) */
+export interface DeductStock {
+  reservation_id: ReservationId;
+}
@@ -0,0 +1,84 @@
+import type { OrderId, ProductId, ReservationId } from "./types";
+
+/** Domain event enum containing all events in this bounded context, tagged by `type` */
+export type DomainEvent =
+  | ({ type: "StockAdded" } & StockAdded)
+  | ({ type: "StockReserved" } & StockReserved)
+  | ({ type: "StockReleased" } & StockReleased)
+  | ({ type: "StockDeducted" } & StockDeducted)
+  | ({ type: "LowStockAlert" } & LowStockAlert);
+
+/** Name of a domain event */
+export type DomainEventType = DomainEvent["type"];
+
+/** Stock has been added to inventory */
+export interface StockAdded {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  product_id: ProductId;
+  quantity: number;
+  new_available_quantity: number;
+  added_at: string;
+}
+
+/** Stock has been reserved for an order */
+export interface StockReserved {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  product_id: ProductId;
+  order_id: OrderId;
+  reservation_id: ReservationId;
+  quantity: number;
+  reserved_at: string;
+}
+
+/** Reserved stock has been released */
+export interface StockReleased {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  product_id: ProductId;
+  reservation_id: ReservationId;
+  quantity: number;
+  released_at: string;
+}
+
+/** Stock has been deducted (shipped) */
+export interface StockDeducted {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  product_id: ProductId;
+  reservation_id: ReservationId;
+  quantity: number;
+  deducted_at: string;
+}
+
+/** Stock has fallen below reorder level */
+export interface LowStockAlert {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  product_id: ProductId;
+  sku: string;
+  current_quantity: number;
+  reorder_level: number;
+  alerted_at: string;
+}
@@ -0,0 +1,45 @@
+import type { ProductId } from "./types";
+
+/** Parameters of the `GetProduct` query */
+export interface GetProductParams {
+  product_id: ProductId;
+}
+
+/**
+ * Result of the `GetProduct` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `Product | null`, which the generated service does not return yet.
+ */
+export type GetProductResult = string;
+
+/** Parameters of the `GetProductBySku` query */
+export interface GetProductBySkuParams {
+  sku: string;
+}
+
+/**
+ * Result of the `GetProductBySku` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `Product | null`, which the generated service does not return yet.
+ */
+export type GetProductBySkuResult = string;
+
+/** Parameters of the `CheckAvailability` query */
+export interface CheckAvailabilityParams {
+  product_id: ProductId;
+  quantity: number;
+}
+
+/**
+ * Result of the `CheckAvailability` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `boolean`, which the generated service does not return yet.
+ */
+export type CheckAvailabilityResult = string;
+
+/**
+ * Result of the `GetLowStockProducts` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `Product[]`, which the generated service does not return yet.
+ */
+export type GetLowStockProductsResult = string;
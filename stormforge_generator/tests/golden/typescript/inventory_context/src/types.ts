@@ -0,0 +1,16 @@
+export type ProductId = string;
+
+export type OrderId = string;
+
+export type ReservationId = string;
+
+/** Product inventory aggregate */
+export interface Product {
+  id: ProductId;
+  sku: string;
+  name: string;
+  available_quantity: number;
+  reserved_quantity: number;
+  reorder_level: number;
+  updated_at: string;
+}
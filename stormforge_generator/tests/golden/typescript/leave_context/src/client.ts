@@ -0,0 +1,121 @@
+import type { RequestLeave, ApproveLeave, RejectLeave, CancelLeave } from "./commands";
+import type { GetLeaveRequestParams, GetLeaveRequestResult, GetEmployeeLeavesParams, GetEmployeeLeavesResult, GetPendingApprovalsParams, GetPendingApprovalsResult, GetLeaveBalanceParams, GetLeaveBalanceResult } from "./queries";
+
+/** Error response of the Leave service */
+export class ApiError extends Error {
+  constructor(
+    /** HTTP status code */
+    readonly status: number,
+    /** Error code, such as `VALIDATION_ERROR` */
+    readonly code: string,
+    message: string,
+  ) {
+    super(message);
+    this.name = "ApiError";
+  }
+}
+
+/** Options of the Leave client */
+export interface ClientOptions {
+  /** Base URL of the service, without the `/api` prefix */
+  baseUrl: string;
+  /** Headers sent with every request */
+  headers?: Record<string, string>;
+  /** Fetch implementation, the global `fetch` by default */
+  fetch?: typeof fetch;
+}
+
+/** `{"data": ...}` envelope of command and query responses */
+interface ApiResponse<T> {
+  data: T;
+}
+
+/** HTTP client for the Leave service */
+export class LeaveClient {
+  private readonly baseUrl: string;
+  private readonly headers: Record<string, string>;
+  private readonly fetchImpl: typeof fetch;
+
+  constructor(options: ClientOptions) {
+    this.baseUrl = options.baseUrl.replace(/\/+$/, "");
+    this.headers = options.headers ?? {};
+    this.fetchImpl = options.fetch ?? globalThis.fetch.bind(globalThis);
+  }
+
+  /** Submit a leave request */
+  async requestLeave(command: RequestLeave): Promise<string> {
+    return this.post<string>("/api/request-leave", command);
+  }
+
+  /** Approve a leave request */
+  async approveLeave(command: ApproveLeave): Promise<string> {
+    return this.post<string>("/api/approve-leave", command);
+  }
+
+  /** Reject a leave request */
+  async rejectLeave(command: RejectLeave): Promise<string> {
+    return this.post<string>("/api/reject-leave", command);
+  }
+
+  /** Cancel a leave request */
+  async cancelLeave(command: CancelLeave): Promise<string> {
+    return this.post<string>("/api/cancel-leave", command);
+  }
+
+  /** Get leave request by ID */
+  async getLeaveRequest(params: GetLeaveRequestParams): Promise<GetLeaveRequestResult> {
+    return this.get<GetLeaveRequestResult>("/api/get-leave-request", params);
+  }
+
+  /** Get all leave requests for an employee */
+  async getEmployeeLeaves(params: GetEmployeeLeavesParams): Promise<GetEmployeeLeavesResult> {
+    return this.get<GetEmployeeLeavesResult>("/api/get-employee-leaves", params);
+  }
+
+  /** Get pending leave requests for approval */
+  async getPendingApprovals(params: GetPendingApprovalsParams): Promise<GetPendingApprovalsResult> {
+    return this.get<GetPendingApprovalsResult>("/api/get-pending-approvals", params);
+  }
+
+  /** Get remaining leave balance for an employee */
+  async getLeaveBalance(params: GetLeaveBalanceParams): Promise<GetLeaveBalanceResult> {
+    return this.get<GetLeaveBalanceResult>("/api/get-leave-balance", params);
+  }
+
+  private async post<T>(path: string, body: unknown): Promise<T> {
+    const response = await this.request<ApiResponse<T>>("POST", path, body);
+    return response.data;
+  }
+
+  private async get<T>(path: string, params: object = {}): Promise<T> {
+    const query = new URLSearchParams();
+    for (const [key, value] of Object.entries(params)) {
+      if (value !== undefined && value !== null) {
+        query.append(key, String(value));
+      }
+    }
+    const search = query.toString();
+    const response = await this.request<ApiResponse<T>>("GET", search ? `${path}?${search}` : path);
+    return response.data;
+  }
+
+  private async request<T>(method: string, path: string, body?: unknown): Promise<T> {
+    const response = await this.fetchImpl(`${this.baseUrl}${path}`, {
+      method,
+      headers:
+        body === undefined ? this.headers : { ...this.headers, "content-type": "application/json" },
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    const text = await response.text();
+    const payload: unknown = text ? JSON.parse(text) : null;
+    if (!response.ok) {
+      const error = (payload ?? {}) as { code?: string; message?: string };
+      throw new ApiError(
+        response.status,
+        error.code ?? `HTTP_${response.status}`,
+        error.message ?? response.statusText,
+      );
+    }
+    return payload as T;
+  }
+}
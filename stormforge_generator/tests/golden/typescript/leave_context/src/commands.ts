@@ -0,0 +1,30 @@
+import type { EmployeeId, LeaveRequestId, LeaveType } from "./types";
+
+/** Submit a leave request */
+export interface RequestLeave {
+  employee_id: EmployeeId;
+  leave_type: LeaveType;
+  start_date: string;
+  end_date: string;
+  reason?: string | null;
+}
+
+/** Approve a leave request */
+export interface ApproveLeave {
+  leave_request_id: LeaveRequestId;
+  approver_id: EmployeeId;
+  comment?: string | null;
+}
+
+/** Reject a leave request */
+export interface RejectLeave {
+  leave_request_id: LeaveRequestId;
+  approver_id: EmployeeId;
+  reason: string;
+}
+
+/** Cancel a leave request */
+export interface CancelLeave {
+  leave_request_id: LeaveRequestId;
+  employee_id: EmployeeId;
+}
@@ -0,0 +1,71 @@
+import type { EmployeeId, LeaveRequestId, LeaveType } from "./types";
+
+/** Domain event enum containing all events in this bounded context, tagged by `type` */
+export type DomainEvent =
+  | ({ type: "LeaveRequested" } & LeaveRequested)
+  | ({ type: "LeaveApproved" } & LeaveApproved)
+  | ({ type: "LeaveRejected" } & LeaveRejected)
+  | ({ type: "LeaveCancelled" } & LeaveCancelled);
+
+/** Name of a domain event */
+export type DomainEventType = DomainEvent["type"];
+
+/** A leave request has been submitted */
+export interface LeaveRequested {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  leave_request_id: LeaveRequestId;
+  employee_id: EmployeeId;
+  leave_type: LeaveType;
+  start_date: string;
+  end_date: string;
+  reason: string;
+  requested_at: string;
+}
+
+/** Leave request has been approved */
+export interface LeaveApproved {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  leave_request_id: LeaveRequestId;
+  employee_id: EmployeeId;
+  approver_id: EmployeeId;
+  comment: string;
+  approved_at: string;
+}
+
+/** Leave request has been rejected */
+export interface LeaveRejected {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  leave_request_id: LeaveRequestId;
+  employee_id: EmployeeId;
+  approver_id: EmployeeId;
+  reason: string;
+  rejected_at: string;
+}
+
+/** Leave request has been cancelled */
+export interface LeaveCancelled {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  leave_request_id: LeaveRequestId;
+  employee_id: EmployeeId;
+  cancelled_at: string;
+}
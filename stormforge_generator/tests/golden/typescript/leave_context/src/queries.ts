@@ -0,0 +1,53 @@
+import type { EmployeeId, LeaveRequestId, LeaveStatus, LeaveType } from "./types";
+
+/** Parameters of the `GetLeaveRequest` query */
+export interface GetLeaveRequestParams {
+  leave_request_id: LeaveRequestId;
+}
+
+/**
+ * Result of the `GetLeaveRequest` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `LeaveRequest | null`, which the generated service does not return yet.
+ */
+export type GetLeaveRequestResult = string;
+
+/** Parameters of the `GetEmployeeLeaves` query */
+export interface GetEmployeeLeavesParams {
+  employee_id: EmployeeId;
+  year?: number;
+  status?: LeaveStatus;
+}
+
+/**
+ * Result of the `GetEmployeeLeaves` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `LeaveRequest[]`, which the generated service does not return yet.
+ */
+export type GetEmployeeLeavesResult = string;
+
+/** Parameters of the `GetPendingApprovals` query */
+export interface GetPendingApprovalsParams {
+  approver_id: EmployeeId;
+}
+
+/**
+ * Result of the `GetPendingApprovals` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `LeaveRequest[]`, which the generated service does not return yet.
+ */
+export type GetPendingApprovalsResult = string;
+
+/** Parameters of the `GetLeaveBalance` query */
+export interface GetLeaveBalanceParams {
+  employee_id: EmployeeId;
+  leave_type: LeaveType;
+  year?: number;
+}
+
+/**
+ * Result of the `GetLeaveBalance` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `unknown`, which the generated service does not return yet.
+ */
+export type GetLeaveBalanceResult = string;
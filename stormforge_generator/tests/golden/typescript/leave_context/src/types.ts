@@ -0,0 +1,52 @@
+export type LeaveRequestId = string;
+
+export type EmployeeId = string;
+
+/** Variants of `LeaveType` */
+export const LEAVE_TYPE_VALUES = [
+  /** Annual leave */
+  "Annual",
+  /** Sick leave */
+  "Sick",
+  /** Personal leave */
+  "Personal",
+  /** Maternity leave */
+  "Maternity",
+  /** Paternity leave */
+  "Paternity",
+  /** Bereavement leave */
+  "Bereavement",
+  /** Unpaid leave */
+  "Unpaid",
+] as const;
+
+export type LeaveType = (typeof LEAVE_TYPE_VALUES)[number];
+
+/** Variants of `LeaveStatus` */
+export const LEAVE_STATUS_VALUES = [
+  /** Awaiting approval */
+  "Pending",
+  /** Leave approved */
+  "Approved",
+  /** Leave rejected */
+  "Rejected",
+  /** Leave cancelled by employee */
+  "Cancelled",
+] as const;
+
+export type LeaveStatus = (typeof LEAVE_STATUS_VALUES)[number];
+
+/** Leave request aggregate */
+export interface LeaveRequest {
+  id: LeaveRequestId;
+  employee_id: EmployeeId;
+  leave_type: LeaveType;
+  start_date: string;
+  end_date: string;
+  reason: string | null;
+  status: LeaveStatus;
+  approver_id: EmployeeId | null;
+  approval_comment: string | null;
+  created_at: string;
+  updated_at: string | null;
+}
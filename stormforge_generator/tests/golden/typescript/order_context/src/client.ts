@@ -0,0 +1,121 @@
+import type { CreateOrder, ConfirmPayment, ShipOrder, DeliverOrder, CancelOrder } from "./commands";
+import type { GetOrderParams, GetOrderResult, ListOrdersParams, ListOrdersResult, GetOrdersByStatusParams, GetOrdersByStatusResult } from "./queries";
+
+/** Error response of the Order service */
+export class ApiError extends Error {
+  constructor(
+    /** HTTP status code */
+    readonly status: number,
+    /** Error code, such as `VALIDATION_ERROR` */
+    readonly code: string,
+    message: string,
+  ) {
+    super(message);
+    this.name = "ApiError";
+  }
+}
+
+/** Options of the Order client */
+export interface ClientOptions {
+  /** Base URL of the service, without the `/api` prefix */
+  baseUrl: string;
+  /** Headers sent with every request */
+  headers?: Record<string, string>;
+  /** Fetch implementation, the global `fetch` by default */
+  fetch?: typeof fetch;
+}
+
+/** `{"data": ...}` envelope of command and query responses */
+interface ApiResponse<T> {
+  data: T;
+}
+
+/** HTTP client for the Order service */
+export class OrderClient {
+  private readonly baseUrl: string;
+  private readonly headers: Record<string, string>;
+  private readonly fetchImpl: typeof fetch;
+
+  constructor(options: ClientOptions) {
+    this.baseUrl = options.baseUrl.replace(/\/+$/, "");
+    this.headers = options.headers ?? {};
+    this.fetchImpl = options.fetch ?? globalThis.fetch.bind(globalThis);
+  }
+
+  /** Create a new order */
+  async createOrder(command: CreateOrder): Promise<string> {
+    return this.post<string>("/api/create-order", command);
+  }
+
+  /** Confirm order payment */
+  async confirmPayment(command: ConfirmPayment): Promise<string> {
+    return this.post<string>("/api/confirm-payment", command);
+  }
+
+  /** Ship the order */
+  async shipOrder(command: ShipOrder): Promise<string> {
+    return this.post<string>("/api/ship-order", command);
+  }
+
+  /** Mark order as delivered */
+  async deliverOrder(command: DeliverOrder): Promise<string> {
+    return this.post<string>("/api/deliver-order", command);
+  }
+
+  /** Cancel the order */
+  async cancelOrder(command: CancelOrder): Promise<string> {
+    return this.post<string>("/api/cancel-order", command);
+  }
+
+  /** Get order by ID */
+  async getOrder(params: GetOrderParams): Promise<GetOrderResult> {
+    return this.get<GetOrderResult>("/api/get-order", params);
+  }
+
+  /** List orders with filters */
+  async listOrders(params: ListOrdersParams): Promise<ListOrdersResult> {
+    return this.get<ListOrdersResult>("/api/list-orders", params);
+  }
+
+  /** Get all orders with a specific status */
+  async getOrdersByStatus(params: GetOrdersByStatusParams): Promise<GetOrdersByStatusResult> {
+    return this.get<GetOrdersByStatusResult>("/api/get-orders-by-status", params);
+  }
+
+  private async post<T>(path: string, body: unknown): Promise<T> {
+    const response = await this.request<ApiResponse<T>>("POST", path, body);
+    return response.data;
+  }
+
+  private async get<T>(path: string, params: object = {}): Promise<T> {
+    const query = new URLSearchParams();
+    for (const [key, value] of Object.entries(params)) {
+      if (value !== undefined && value !== null) {
+        query.append(key, String(value));
+      }
+    }
+    const search = query.toString();
+    const response = await this.request<ApiResponse<T>>("GET", search ? `${path}?${search}` : path);
+    return response.data;
+  }
+
+  private async request<T>(method: string, path: string, body?: unknown): Promise<T> {
+    const response = await this.fetchImpl(`${this.baseUrl}${path}`, {
+      method,
+      headers:
+        body === undefined ? this.headers : { ...this.headers, "content-type": "application/json" },
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    const text = await response.text();
+    const payload: unknown = text ? JSON.parse(text) : null;
+    if (!response.ok) {
+      const error = (payload ?? {}) as { code?: string; message?: string };
+      throw new ApiError(
+        response.status,
+        error.code ?? `HTTP_${response.status}`,
+        error.message ?? response.statusText,
+      );
+    }
+    return payload as T;
+  }
+}
@@ -0,0 +1,33 @@
+import type { Address, CustomerId, OrderId, OrderItem } from "./types";
+
+/** Create a new order */
+export interface CreateOrder {
+  customer_id: CustomerId;
+  items: OrderItem[];
+  shipping_address: Address;
+}
+
+/** Confirm order payment */
+export interface ConfirmPayment {
+  order_id: OrderId;
+  payment_id: string;
+}
+
+/** Ship the order */
+export interface ShipOrder {
+  order_id: OrderId;
+  tracking_number: string;
+  carrier: string;
+}
+
+/** Mark order as delivered */
+export interface DeliverOrder {
+  order_id: OrderId;
+  signed_by?: string | null;
+}
+
+/** Cancel the order */
+export interface CancelOrder {
+  order_id: OrderId;
+  reason: string;
+}
@@ -0,0 +1,42 @@
+import type { CustomerId, OrderId, OrderStatus } from "./types";
+
+/** Parameters of the `GetOrder` query */
+export interface GetOrderParams {
+  order_id: OrderId;
+}
+
+/**
+ * Result of the `GetOrder` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `Order | null`, which the generated service does not return yet.
+ */
+export type GetOrderResult = string;
+
+/** Parameters of the `ListOrders` query */
+export interface ListOrdersParams {
+  customer_id?: CustomerId;
+  status?: OrderStatus;
+  from_date?: string;
+  to_date?: string;
+  page?: number;
+  page_size?: number;
+}
+
+/**
+ * Result of the `ListOrders` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `PagedResult<Order>`, which the generated service does not return yet.
+ */
+export type ListOrdersResult = string;
+
+/** Parameters of the `GetOrdersByStatus` query */
+export interface GetOrdersByStatusParams {
+  status: OrderStatus;
+}
+
+/**
+ * Result of the `GetOrdersByStatus` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `Order[]`, which the generated service does not return yet.
+ */
+export type GetOrdersByStatusResult = string;
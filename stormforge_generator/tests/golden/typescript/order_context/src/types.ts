@@ -0,0 +1,67 @@
+export type OrderId = string;
+
+export type CustomerId = string;
+
+export type ProductId = string;
+
+/** A line item in an order */
+export interface OrderItem {
+  product_id: ProductId;
+  product_name: string;
+  quantity: number;
+  unit_price: Money;
+  subtotal: Money;
+}
+
+/** Monetary value with currency */
+export interface Money {
+  amount: string;
+  currency: string;
+}
+
+/** Physical address */
+export interface Address {
+  street: string;
+  city: string;
+  province: string;
+  postal_code: string;
+  country: string;
+}
+
+/** Variants of `OrderStatus` */
+export const ORDER_STATUS_VALUES = [
+  /** Order has been created, awaiting payment */
+  "Created",
+  /** Payment has been received */
+  "Paid",
+  /** Order is being prepared */
+  "Processing",
+  /** Order has been shipped */
+  "Shipped",
+  /** Order has been delivered */
+  "Delivered",
+  /** Order has been cancelled */
+  "Cancelled",
+  /** Order has been refunded */
+  "Refunded",
+] as const;
+
+export type OrderStatus = (typeof ORDER_STATUS_VALUES)[number];
+
+/** Order aggregate root managing order lifecycle */
+export interface Order {
+  /** Unique order identifier */
+  id: OrderId;
+  /** Customer who placed the order */
+  customer_id: CustomerId;
+  /** Order line items */
+  items: OrderItem[];
+  /** Current order status */
+  status: OrderStatus;
+  /** Delivery address */
+  shipping_address: Address;
+  /** Order total */
+  total_amount: Money;
+  created_at: string;
+  updated_at: string | null;
+}
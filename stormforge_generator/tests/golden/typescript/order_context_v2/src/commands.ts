@@ -0,0 +1,30 @@
+import type { Address, CustomerId, OrderId, OrderItem } from "./types";
+
+/** Create a new order */
+export interface CreateOrder {
+  /** Customer placing the order */
+  customer_id: CustomerId;
+  /** Items to order */
+  items: OrderItem[];
+  /** Delivery address */
+  shipping_address: Address;
+}
+
+/** Confirm order payment */
+export interface ConfirmPayment {
+  order_id: OrderId;
+  payment_id: string;
+}
+
+/** Ship the order */
+export interface ShipOrder {
+  order_id: OrderId;
+  tracking_number: string;
+  carrier: string;
+}
+
+/** Cancel the order */
+export interface CancelOrder {
+  order_id: OrderId;
+  reason: string;
+}
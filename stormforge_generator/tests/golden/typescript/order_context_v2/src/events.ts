@@ -0,0 +1,82 @@
+import type { Address, CustomerId, Money, OrderId, OrderItem } from "./types";
+
+/** Domain event enum containing all events in this bounded context, tagged by `type` */
+export type DomainEvent =
+  | ({ type: "OrderCreated" } & OrderCreated)
+  | ({ type: "OrderPaid" } & OrderPaid)
+  | ({ type: "OrderShipped" } & OrderShipped)
+  | ({ type: "OrderDelivered" } & OrderDelivered)
+  | ({ type: "OrderCancelled" } & OrderCancelled);
+
+/** Name of a domain event */
+export type DomainEventType = DomainEvent["type"];
+
+/** Emitted when a new order is created */
+export interface OrderCreated {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  order_id: OrderId;
+  customer_id: CustomerId;
+  items: OrderItem[];
+  shipping_address: Address;
+  total_amount: Money;
+  created_at: string;
+}
+
+/** Emitted when an order payment is confirmed */
+export interface OrderPaid {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  order_id: OrderId;
+  payment_id: string;
+  paid_amount: Money;
+  paid_at: string;
+}
+
+/** Emitted when an order is shipped */
+export interface OrderShipped {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  order_id: OrderId;
+  tracking_number: string;
+  carrier: string;
+  shipped_at: string;
+}
+
+/** Emitted when an order is delivered */
+export interface OrderDelivered {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  order_id: OrderId;
+  delivered_at: string;
+  signed_by: string;
+}
+
+/** Emitted when an order is cancelled */
+export interface OrderCancelled {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  order_id: OrderId;
+  reason: string;
+  cancelled_at: string;
+}
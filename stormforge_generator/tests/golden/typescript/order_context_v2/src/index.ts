@@ -0,0 +1,5 @@
+export * from "./types";
+export * from "./commands";
+export * from "./events";
+export * from "./queries";
+export * from "./client";
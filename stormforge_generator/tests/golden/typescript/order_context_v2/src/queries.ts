@@ -0,0 +1,30 @@
+import type { CustomerId, OrderId, OrderStatus } from "./types";
+
+/** Parameters of the `GetOrder` query */
+export interface GetOrderParams {
+  order_id: OrderId;
+}
+
+/**
+ * Result of the `GetOrder` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `OrderDetails | null`, which the generated service does not return yet.
+ */
+export type GetOrderResult = string;
+
+/** Parameters of the `ListOrders` query */
+export interface ListOrdersParams {
+  customer_id?: CustomerId;
+  status?: OrderStatus;
+  from_date?: string;
+  to_date?: string;
+  page?: number;
+  page_size?: number;
+}
+
+/**
+ * Result of the `ListOrders` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `PagedResult<OrderSummary>`, which the generated service does not return yet.
+ */
+export type ListOrdersResult = string;
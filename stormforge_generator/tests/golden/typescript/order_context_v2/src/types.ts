@@ -0,0 +1,77 @@
+export type OrderId = string;
+
+export type CustomerId = string;
+
+export type ProductId = string;
+
+/** A line item in an order */
+export interface OrderItem {
+  product_id: ProductId;
+  product_name: string;
+  quantity: number;
+  unit_price: Money;
+  subtotal: Money;
+}
+
+/** Monetary value with currency */
+export interface Money {
+  amount: string;
+  currency: string;
+}
+
+/** Physical address */
+export interface Address {
+  street: string;
+  city: string;
+  province: string;
+  postal_code: string;
+  country: string;
+}
+
+/** Variants of `OrderStatus` */
+export const ORDER_STATUS_VALUES = [
+  /** Order has been created, awaiting payment */
+  "Created",
+  /** Payment has been received */
+  "Paid",
+  /** Order is being prepared */
+  "Processing",
+  /** Order has been shipped */
+  "Shipped",
+  /** Order has been delivered */
+  "Delivered",
+  /** Order has been cancelled */
+  "Cancelled",
+  /** Order has been refunded */
+  "Refunded",
+] as const;
+
+export type OrderStatus = (typeof ORDER_STATUS_VALUES)[number];
+
+/** Order aggregate root managing order lifecycle */
+export interface Order {
+  id: OrderId;
+}
+
+/** Summary view of orders for listing */
+export interface OrderSummary {
+  order_id: OrderId | null;
+  order_number: string | null;
+  customer_name: string | null;
+  customer_email: string | null;
+  item_count: number | null;
+  total_amount: Money | null;
+  status: OrderStatus | null;
+  created_at: string | null;
+}
+
+/** Detailed view of a single order */
+export interface OrderDetails {
+  order_id: OrderId | null;
+  customer: unknown;
+  items: unknown;
+  shipping_address: Address | null;
+  total_amount: Money | null;
+  status: OrderStatus | null;
+  timeline: unknown;
+}
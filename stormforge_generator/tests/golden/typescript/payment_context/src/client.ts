@@ -0,0 +1,111 @@
+import type { InitiatePayment, CompletePayment, FailPayment, RefundPayment } from "./commands";
+import type { GetPaymentParams, GetPaymentResult, GetPaymentByOrderParams, GetPaymentByOrderResult } from "./queries";
+
+/** Error response of the Payment service */
+export class ApiError extends Error {
+  constructor(
+    /** HTTP status code */
+    readonly status: number,
+    /** Error code, such as `VALIDATION_ERROR` */
+    readonly code: string,
+    message: string,
+  ) {
+    super(message);
+    this.name = "ApiError";
+  }
+}
+
+/** Options of the Payment client */
+export interface ClientOptions {
+  /** Base URL of the service, without the `/api` prefix */
+  baseUrl: string;
+  /** Headers sent with every request */
+  headers?: Record<string, string>;
+  /** Fetch implementation, the global `fetch` by default */
+  fetch?: typeof fetch;
+}
+
+/** `{"data": ...}` envelope of command and query responses */
+interface ApiResponse<T> {
+  data: T;
+}
+
+/** HTTP client for the Payment service */
+export class PaymentClient {
+  private readonly baseUrl: string;
+  private readonly headers: Record<string, string>;
+  private readonly fetchImpl: typeof fetch;
+
+  constructor(options: ClientOptions) {
+    this.baseUrl = options.baseUrl.replace(/\/+$/, "");
+    this.headers = options.headers ?? {};
+    this.fetchImpl = options.fetch ?? globalThis.fetch.bind(globalThis);
+  }
+
+  /** Initiate a payment for an order */
+  async initiatePayment(command: InitiatePayment): Promise<string> {
+    return this.post<string>("/api/initiate-payment", command);
+  }
+
+  /** Mark payment as completed */
+  async completePayment(command: CompletePayment): Promise<string> {
+    return this.post<string>("/api/complete-payment", command);
+  }
+
+  /** Mark payment as failed */
+  async failPayment(command: FailPayment): Promise<string> {
+    return this.post<string>("/api/fail-payment", command);
+  }
+
+  /** Refund a completed payment */
+  async refundPayment(command: RefundPayment): Promise<string> {
+    return this.post<string>("/api/refund-payment", command);
+  }
+
+  /** Get payment by ID */
+  async getPayment(params: GetPaymentParams): Promise<GetPaymentResult> {
+    return this.get<GetPaymentResult>("/api/get-payment", params);
+  }
+
+  /** Get payment for an order */
+  async getPaymentByOrder(params: GetPaymentByOrderParams): Promise<GetPaymentByOrderResult> {
+    return this.get<GetPaymentByOrderResult>("/api/get-payment-by-order", params);
+  }
+
+  private async post<T>(path: string, body: unknown): Promise<T> {
+    const response = await this.request<ApiResponse<T>>("POST", path, body);
+    return response.data;
+  }
+
+  private async get<T>(path: string, params: object = {}): Promise<T> {
+    const query = new URLSearchParams();
+    for (const [key, value] of Object.entries(params)) {
+      if (value !== undefined && value !== null) {
+        query.append(key, String(value));
+      }
+    }
+    const search = query.toString();
+    const response = await this.request<ApiResponse<T>>("GET", search ? `${path}?${search}` : path);
+    return response.data;
+  }
+
+  private async request<T>(method: string, path: string, body?: unknown): Promise<T> {
+    const response = await this.fetchImpl(`${this.baseUrl}${path}`, {
+      method,
+      headers:
+        body === undefined ? this.headers : { ...this.headers, "content-type": "application/json" },
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    const text = await response.text();
+    const payload: unknown = text ? JSON.parse(text) : null;
+    if (!response.ok) {
+      const error = (payload ?? {}) as { code?: string; message?: string };
+      throw new ApiError(
+        response.status,
+        error.code ?? `HTTP_${response.status}`,
+        error.message ?? response.statusText,
+      );
+    }
+    return payload as T;
+  }
+}
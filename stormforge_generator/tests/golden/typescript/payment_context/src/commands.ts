@@ -0,0 +1,27 @@
+import type { Money, OrderId, PaymentId, PaymentMethod } from "./types";
+
+/** Initiate a payment for an order */
+export interface InitiatePayment {
+  order_id: OrderId;
+  amount: Money;
+  method: PaymentMethod;
+}
+
+/** Mark payment as completed */
+export interface CompletePayment {
+  payment_id: PaymentId;
+  transaction_id: string;
+}
+
+/** Mark payment as failed */
+export interface FailPayment {
+  payment_id: PaymentId;
+  reason: string;
+}
+
+/** Refund a completed payment */
+export interface RefundPayment {
+  payment_id: PaymentId;
+  /** If not provided, full refund */
+  refund_amount?: Money | null;
+}
@@ -0,0 +1,68 @@
+import type { Money, OrderId, PaymentId, PaymentMethod } from "./types";
+
+/** Domain event enum containing all events in this bounded context, tagged by `type` */
+export type DomainEvent =
+  | ({ type: "PaymentInitiated" } & PaymentInitiated)
+  | ({ type: "PaymentCompleted" } & PaymentCompleted)
+  | ({ type: "PaymentFailed" } & PaymentFailed)
+  | ({ type: "PaymentRefunded" } & PaymentRefunded);
+
+/** Name of a domain event */
+export type DomainEventType = DomainEvent["type"];
+
+/** Payment has been initiated */
+export interface PaymentInitiated {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  payment_id: PaymentId;
+  order_id: OrderId;
+  amount: Money;
+  method: PaymentMethod;
+  created_at: string;
+}
+
+/** Payment completed successfully */
+export interface PaymentCompleted {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  payment_id: PaymentId;
+  order_id: OrderId;
+  transaction_id: string;
+  completed_at: string;
+}
+
+/** Payment has failed */
+export interface PaymentFailed {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  payment_id: PaymentId;
+  order_id: OrderId;
+  reason: string;
+  failed_at: string;
+}
+
+/** Payment has been refunded */
+export interface PaymentRefunded {
+  /** Unique event identifier */
+  event_id: string;
+  /** Timestamp when the event occurred, in RFC 3339 */
+  occurred_at: string;
+  /** ID of the aggregate that produced this event */
+  aggregate_id: string;
+  payment_id: PaymentId;
+  order_id: OrderId;
+  refund_amount: Money;
+  refunded_at: string;
+}
@@ -0,0 +1,25 @@
+import type { OrderId, PaymentId } from "./types";
+
+/** Parameters of the `GetPayment` query */
+export interface GetPaymentParams {
+  payment_id: PaymentId;
+}
+
+/**
+ * Result of the `GetPayment` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `Payment | null`, which the generated service does not return yet.
+ */
+export type GetPaymentResult = string;
+
+/** Parameters of the `GetPaymentByOrder` query */
+export interface GetPaymentByOrderParams {
+  order_id: OrderId;
+}
+
+/**
+ * Result of the `GetPaymentByOrder` query, the `data` of its `ApiResponseString` response
+ *
+ * The IR declares `Payment | null`, which the generated service does not return yet.
+ */
+export type GetPaymentByOrderResult = string;
@@ -0,0 +1,50 @@
+export type PaymentId = string;
+
+export type OrderId = string;
+
+export interface Money {
+  amount: string;
+  currency: string;
+}
+
+/** Variants of `PaymentMethod` */
+export const PAYMENT_METHOD_VALUES = [
+  /** Alipay payment */
+  "Alipay",
+  /** WeChat Pay */
+  "WechatPay",
+  /** Credit card payment */
+  "CreditCard",
+  /** Bank transfer */
+  "BankTransfer",
+] as const;
+
+export type PaymentMethod = (typeof PAYMENT_METHOD_VALUES)[number];
+
+/** Variants of `PaymentStatus` */
+export const PAYMENT_STATUS_VALUES = [
+  /** Payment is pending */
+  "Pending",
+  /** Payment is being processed */
+  "Processing",
+  /** Payment completed successfully */
+  "Completed",
+  /** Payment failed */
+  "Failed",
+  /** Payment has been refunded */
+  "Refunded",
+] as const;
+
+export type PaymentStatus = (typeof PAYMENT_STATUS_VALUES)[number];
+
+/** Payment aggregate handling payment lifecycle */
+export interface Payment {
+  id: PaymentId;
+  order_id: OrderId;
+  amount: Money;
+  method: PaymentMethod;
+  status: PaymentStatus;
+  transaction_id: string | null;
+  created_at: string;
+  completed_at: string | null;
+}
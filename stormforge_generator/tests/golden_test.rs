@@ -0,0 +1,215 @@
+//! Golden-file tests for the generated Rust crate, TypeScript SDK, Python
+//! and NestJS services and docs site
+//!
+//! Every example in `ir_schema/examples` is generated and compared file by
+//! file against `tests/golden/<example>/` for the Rust crate and
+//! `tests/golden/<target>/<example>/` for other targets. Targets covering
+//! several contexts are also generated from the e-commerce contexts together
+//! into `tests/golden/<target>/ecommerce/`. Set `UPDATE_GOLDEN=1`
+//! to rewrite the golden files after an intentional change to the generator
+//! output.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+use walkdir::WalkDir;
+
+const EXAMPLES_DIR: &str = "../ir_schema/examples";
+const GOLDEN_DIR: &str = "tests/golden";
+
+/// Relative paths of all files below `root`, sorted
+fn list_files(root: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = WalkDir::new(root)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.path().strip_prefix(root).unwrap().to_path_buf())
+        .collect();
+    files.sort();
+    files
+}
+
+fn example_files() -> Vec<PathBuf> {
+    let mut examples: Vec<PathBuf> = WalkDir::new(EXAMPLES_DIR)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "yaml"))
+        .map(|entry| entry.path().to_path_buf())
+        .collect();
+    examples.sort();
+    examples
+}
+
+/// Generate every example for `target` and compare it with the golden files
+fn assert_matches_golden(target: &str, golden_dir: &Path) {
+    let examples = example_files();
+    if examples.is_empty() {
+        println!("Skipping test: no example files found");
+        return;
+    }
+
+    build_generator();
+    for example in examples {
+        let name = example.file_stem().unwrap().to_str().unwrap();
+        assert_generated_matches(
+            target,
+            std::slice::from_ref(&example),
+            &golden_dir.join(name),
+            name,
+        );
+    }
+}
+
+/// Generate the contexts of the e-commerce example for a multi-context
+/// `target` and compare them with the golden files
+fn assert_project_matches_golden(target: &str, golden_dir: &Path) {
+    let inputs: Vec<PathBuf> = ["order_context", "inventory_context", "payment_context"]
+        .iter()
+        .map(|name| Path::new(EXAMPLES_DIR).join(format!("ecommerce/{}.yaml", name)))
+        .collect();
+    if !inputs.iter().all(|input| input.exists()) {
+        println!("Skipping test: e-commerce example files not found");
+        return;
+    }
+
+    build_generator();
+    assert_generated_matches(target, &inputs, &golden_dir.join("ecommerce"), "ecommerce");
+}
+
+fn build_generator() {
+    let build_status = Command::new("cargo")
+        .args(["build"])
+        .status()
+        .expect("Failed to build generator");
+
+    assert!(build_status.success(), "Generator build failed");
+}
+
+/// Generate `inputs` for `target` and compare the output with `golden_root`
+fn assert_generated_matches(target: &str, inputs: &[PathBuf], golden_root: &Path, name: &str) {
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+    let generate_status = Command::new("./target/debug/stormforge-generator")
+        .arg("generate")
+        .arg("--input")
+        .args(inputs)
+        .arg("--output")
+        .arg(temp_dir.path())
+        .args(["--target", target])
+        .stdout(std::process::Stdio::null())
+        .status()
+        .expect("Failed to run generator");
+
+    assert!(generate_status.success(), "Generator failed for {}", name);
+
+    let generated = list_files(temp_dir.path());
+
+    if update {
+        let _ = fs::remove_dir_all(golden_root);
+        for file in &generated {
+            let target = golden_root.join(file);
+            fs::create_dir_all(target.parent().unwrap()).unwrap();
+            fs::copy(temp_dir.path().join(file), target).unwrap();
+        }
+        return;
+    }
+
+    assert_eq!(
+        generated,
+        list_files(golden_root),
+        "Generated file set differs from golden files for {}",
+        name
+    );
+
+    for file in &generated {
+        let actual = fs::read_to_string(temp_dir.path().join(file)).unwrap();
+        let expected = fs::read_to_string(golden_root.join(file)).unwrap();
+        assert!(
+            actual == expected,
+            "{}/{} differs from golden file (rerun with UPDATE_GOLDEN=1 to accept)",
+            name,
+            file.display()
+        );
+    }
+}
+
+#[test]
+fn test_generated_crates_match_golden_files() {
+    assert_matches_golden("rust", Path::new(GOLDEN_DIR));
+}
+
+#[test]
+fn test_generated_typescript_sdks_match_golden_files() {
+    assert_matches_golden("typescript", &Path::new(GOLDEN_DIR).join("typescript"));
+}
+
+#[test]
+fn test_generated_python_services_match_golden_files() {
+    assert_matches_golden("python", &Path::new(GOLDEN_DIR).join("python"));
+}
+
+#[test]
+fn test_generated_nestjs_services_match_golden_files() {
+    assert_matches_golden("nestjs", &Path::new(GOLDEN_DIR).join("nestjs"));
+}
+
+#[test]
+fn test_generated_multi_context_nestjs_services_match_golden_files() {
+    assert_project_matches_golden("nestjs", &Path::new(GOLDEN_DIR).join("nestjs"));
+}
+
+#[test]
+fn test_generated_docs_match_golden_files() {
+    assert_matches_golden("docs", &Path::new(GOLDEN_DIR).join("docs"));
+}
+
+#[test]
+fn test_generated_multi_context_docs_match_golden_files() {
+    assert_project_matches_golden("docs", &Path::new(GOLDEN_DIR).join("docs"));
+}
+
+/// Token spacing prettyplease falls back to for macro bodies it cannot parse
+const TOKEN_ARTIFACTS: &[&str] = &[". await", ") .", ") ?", ":: <", "\" : ", "__macro_"];
+
+/// Whether `line` indexes or calls with a space before the bracket, as in
+/// `read_from(1). await .unwrap() [0]`
+fn has_spaced_index(line: &str) -> bool {
+    line.match_indices(" [").any(|(index, _)| {
+        let before = &line[..index];
+        let word = before
+            .rsplit(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .next()
+            .unwrap_or_default();
+        let lifetime = before[..before.len() - word.len()].ends_with('\'');
+        (before.ends_with(')') || before.ends_with(']') || !word.is_empty())
+            && !lifetime
+            && !matches!(word, "in" | "return" | "mut")
+    })
+}
+
+#[test]
+fn test_golden_rust_files_are_formatted() {
+    for file in list_files(Path::new(GOLDEN_DIR)) {
+        if file.extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+        let code = fs::read_to_string(Path::new(GOLDEN_DIR).join(&file)).unwrap();
+        for (number, line) in code.lines().enumerate() {
+            if line.trim_start().starts_with("//") {
+                continue;
+            }
+            assert!(
+                !TOKEN_ARTIFACTS
+                    .iter()
+                    .any(|artifact| line.contains(artifact))
+                    && !has_spaced_index(line),
+                "{}:{} is not formatted: {}",
+                file.display(),
+                number + 1,
+                line.trim()
+            );
+        }
+    }
+}
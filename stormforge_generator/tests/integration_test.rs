@@ -20,7 +20,7 @@ fn test_generator_order_context() {
 
     // Build the generator in debug mode
     let build_status = Command::new("cargo")
-        .args(&["build"])
+        .args(["build"])
         .status()
         .expect("Failed to build generator");
 
@@ -28,7 +28,7 @@ fn test_generator_order_context() {
 
     // Run the generator
     let generate_status = Command::new("./target/debug/stormforge-generator")
-        .args(&[
+        .args([
             "generate",
             "--input",
             input_path.to_str().unwrap(),
@@ -50,8 +50,19 @@ fn test_generator_order_context() {
         "src/domain/commands.rs",
         "src/domain/events.rs",
         "src/api/routes.rs",
+        "src/api/grpc.rs",
+        "proto/order.proto",
+        "build.rs",
+        "asyncapi.json",
+        "schema.graphql",
+        "src/api/graphql.rs",
         "src/repository/mod.rs",
         "src/infrastructure/event_store.rs",
+        "src/infrastructure/event_bus.rs",
+        "src/infrastructure/outbox.rs",
+        "src/infrastructure/sqlite_event_store.rs",
+        "src/integration/consumers.rs",
+        "src/integration/acl.rs",
     ];
 
     for file in generated_files {
@@ -59,16 +70,712 @@ fn test_generator_order_context() {
         assert!(file_path.exists(), "Expected file not found: {}", file);
     }
 
-    // Try to build the generated service
-    let build_generated_status = Command::new("cargo")
-        .args(&["build"])
+    std::fs::write(Path::new(output_path).join("tests/grpc.rs"), GRPC_TEST)
+        .expect("Failed to write gRPC test");
+
+    // Build the generated service and run its outbox tests against the
+    // in-memory and SQLite stores, and the gRPC test, compiling the GraphQL
+    // API as well
+    let test_generated_status = Command::new("cargo")
+        .args(["test", "--features", "sqlx,grpc,graphql"])
+        .current_dir(output_path)
+        .status()
+        .expect("Failed to test generated service");
+
+    assert!(
+        test_generated_status.success(),
+        "Generated service tests failed"
+    );
+}
+
+/// Creates an order over gRPC and pays it over HTTP, on one shared state
+const GRPC_TEST: &str = r#"
+#![cfg(feature = "grpc")]
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use order::api::grpc::proto::{self, order_service_server::OrderService};
+use order::api::grpc::GrpcApi;
+use order::api::*;
+use order::domain::commands::ConfirmPayment;
+use order::domain::entities::OrderId;
+use order::infrastructure::event_store::{EventStore, InMemoryEventStore};
+use std::sync::Arc;
+
+fn money(amount: &str) -> Option<proto::Money> {
+    Some(proto::Money { amount: amount.to_string(), currency: "EUR".to_string() })
+}
+
+#[tokio::test]
+async fn grpc_commands_append_to_the_shared_store() {
+    let store = Arc::new(InMemoryEventStore::new());
+    let state = AppState::new(store.clone());
+    let grpc = GrpcApi { state: state.clone() };
+
+    let request = proto::CreateOrderRequest {
+        customer_id: Some(proto::CustomerId { value: "customer-1".to_string() }),
+        items: vec![proto::OrderItem {
+            product_id: Some(proto::ProductId { value: "p-1".to_string() }),
+            product_name: "Pen".to_string(),
+            quantity: 2,
+            unit_price: money("1.50"),
+            subtotal: money("3.00"),
+        }],
+        shipping_address: Some(proto::Address {
+            street: "1 Main St".to_string(),
+            city: "Springfield".to_string(),
+            province: "SP".to_string(),
+            postal_code: "12345".to_string(),
+            country: "US".to_string(),
+        }),
+    };
+    grpc.create_order(tonic::Request::new(request)).await.unwrap();
+    let events = store.read_all_from(0).await.unwrap();
+    assert_eq!(events.len(), 1);
+    let order_id = events[0]["aggregate_id"].as_str().unwrap().to_string();
+
+    let command = ConfirmPayment { order_id: OrderId(order_id), payment_id: "payment-1".to_string() };
+    let response = confirm_payment(State(state), Json(command)).await.into_response();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(store.read_all_from(0).await.unwrap().len(), 2);
+}
+"#;
+
+#[test]
+fn test_generator_policies_and_sagas() {
+    let input_path = Path::new("../ir_schema/examples/ecommerce/order_context_v2.yaml");
+    if !input_path.exists() {
+        println!("Skipping test: example file not found");
+        return;
+    }
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let output_path = temp_dir
+        .path()
+        .to_str()
+        .expect("Output path contains invalid UTF-8 characters");
+
+    let build_status = Command::new("cargo")
+        .args(["build"])
+        .status()
+        .expect("Failed to build generator");
+
+    assert!(build_status.success(), "Generator build failed");
+
+    let generate_status = Command::new("./target/debug/stormforge-generator")
+        .args([
+            "generate",
+            "--input",
+            input_path.to_str().unwrap(),
+            "--output",
+            output_path,
+        ])
+        .status()
+        .expect("Failed to run generator");
+
+    assert!(generate_status.success(), "Generator execution failed");
+
+    let policies = std::fs::read_to_string(Path::new(output_path).join("src/policies/mod.rs"))
+        .expect("Policies module not generated");
+    assert!(policies.contains("name: \"AutoShipWhenPaid\""));
+    assert!(policies.contains("compensation: &[actions::cancel_order]"));
+
+    let projections =
+        std::fs::read_to_string(Path::new(output_path).join("src/projections/mod.rs"))
+            .expect("Projections module not generated");
+    assert!(projections.contains("\"/read-models/order-summary/:id\""));
+    assert!(Path::new(output_path)
+        .join("src/projections/order_details.rs")
+        .exists());
+
+    std::fs::write(
+        Path::new(output_path).join("tests/policy_conditions.rs"),
+        POLICY_CONDITIONS_TEST,
+    )
+    .expect("Failed to write policy conditions test");
+    std::fs::write(
+        Path::new(output_path).join("tests/read_models.rs"),
+        READ_MODELS_TEST,
+    )
+    .expect("Failed to write read models test");
+    std::fs::write(
+        Path::new(output_path).join("tests/commands.rs"),
+        COMMANDS_TEST,
+    )
+    .expect("Failed to write commands test");
+
+    // Runs the policy engine, saga store, projection and command tests,
+    // including the SQLite stores
+    let test_generated_status = Command::new("cargo")
+        .args(["test", "--features", "sqlx"])
         .current_dir(output_path)
         .status()
-        .expect("Failed to build generated service");
+        .expect("Failed to test generated service");
+
+    assert!(
+        test_generated_status.success(),
+        "Generated service tests failed"
+    );
+}
+
+/// Runs `AutoShipWhenPaid`, whose condition requires every item to be in stock
+const POLICY_CONDITIONS_TEST: &str = r#"
+use async_trait::async_trait;
+use order::infrastructure::event_bus::{
+    EventBus, InMemoryEventBus, IntegrationMessage, BOUNDED_CONTEXT,
+};
+use order::policies::*;
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct RecordingDispatcher {
+    commands: Mutex<Vec<PolicyCommand>>,
+}
+
+#[async_trait]
+impl CommandDispatcher<PolicyCommand> for RecordingDispatcher {
+    async fn dispatch(&self, command: PolicyCommand) -> anyhow::Result<()> {
+        self.commands.lock().unwrap().push(command);
+        Ok(())
+    }
+}
+
+async fn shipped_orders(in_stock: [bool; 2]) -> Vec<PolicyCommand> {
+    let payload = serde_json::json!({
+        "type": "OrderPaid",
+        "aggregate_id": "order-1",
+        "order_id": "order-1",
+        "tracking_number": "TRACK-1",
+        "carrier": "Post",
+        "items": [{ "in_stock": in_stock[0] }, { "in_stock": in_stock[1] }],
+    });
+    let bus = Arc::new(InMemoryEventBus::new());
+    let message = IntegrationMessage {
+        message_id: uuid::Uuid::new_v4(),
+        context: BOUNDED_CONTEXT.to_string(),
+        event_type: "OrderPaid".to_string(),
+        payload,
+    };
+    bus.publish(message).await.unwrap();
+    let dispatcher = Arc::new(RecordingDispatcher::default());
+    let mut engine = PolicyEngine::new(
+        bus,
+        dispatcher.clone(),
+        Arc::new(InMemorySagaStore::new()),
+        policies(),
+    );
+    engine.poll().await.unwrap();
+    let commands = dispatcher.commands.lock().unwrap();
+    commands.clone()
+}
+
+#[tokio::test]
+async fn false_condition_does_not_dispatch() {
+    assert!(shipped_orders([true, false]).await.is_empty());
+}
+
+#[tokio::test]
+async fn true_condition_dispatches() {
+    let commands = shipped_orders([true, true]).await;
+    assert!(matches!(commands.as_slice(), [PolicyCommand::ShipOrder(_)]));
+}
+"#;
+
+/// Folds order events into the `OrderSummary` and `OrderDetails` read models
+const READ_MODELS_TEST: &str = r#"
+use async_trait::async_trait;
+use order::domain::entities::*;
+use order::infrastructure::event_bus::{IntegrationMessage, BOUNDED_CONTEXT};
+use order::infrastructure::event_store::{EventStore, InMemoryEventStore};
+use order::projections::*;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+async fn append(events: &InMemoryEventStore, payload: Value) {
+    let message = IntegrationMessage {
+        message_id: uuid::Uuid::new_v4(),
+        context: BOUNDED_CONTEXT.to_string(),
+        event_type: payload["type"].as_str().unwrap().to_string(),
+        payload,
+    };
+    events
+        .append_messages("order-1", "Order", vec![message], None)
+        .await
+        .unwrap();
+}
+
+fn money(amount: &str) -> Value {
+    json!({ "amount": amount, "currency": "EUR" })
+}
+
+async fn paid_order() -> Arc<InMemoryEventStore> {
+    let events = Arc::new(InMemoryEventStore::new());
+    append(
+        &events,
+        json!({
+            "type": "OrderCreated",
+            "occurred_at": "2026-01-05T10:00:00Z",
+            "aggregate_id": "order-1",
+            "order_id": "order-1",
+            "customer_id": "customer-1",
+            "items": [
+                { "product_id": "p-1", "product_name": "Pen", "quantity": 2, "unit_price": money("1.50"), "subtotal": money("3.00") },
+                { "product_id": "p-2", "product_name": "Ink", "quantity": 1, "unit_price": money("4.00"), "subtotal": money("4.00") },
+            ],
+            "shipping_address": { "street": "1 Main St", "city": "Springfield", "province": "SP", "postal_code": "12345", "country": "US" },
+            "total_amount": money("7.00"),
+            "created_at": "2026-01-05T10:00:00Z",
+        }),
+    )
+    .await;
+    append(
+        &events,
+        json!({
+            "type": "OrderPaid",
+            "occurred_at": "2026-01-05T11:00:00Z",
+            "aggregate_id": "order-1",
+            "order_id": "order-1",
+            "payment_id": "payment-1",
+            "paid_amount": money("7.00"),
+            "paid_at": "2026-01-05T11:00:00Z",
+        }),
+    )
+    .await;
+    events
+}
+
+struct Customers;
+
+#[async_trait]
+impl EntityLookup for Customers {
+    async fn find(&self, entity: &str, id: &str) -> anyhow::Result<Option<Value>> {
+        Ok((entity == "Customer" && id == "customer-1")
+            .then(|| json!({ "id": "customer-1", "name": "Ada", "email": "ada@example.com" })))
+    }
+}
+
+#[tokio::test]
+async fn summary_rows_fold_order_events() {
+    let events = paid_order().await;
+    let summaries = Projector::<OrderSummaryProjection>::new(
+        events.clone(),
+        Arc::new(InMemoryProjectionStore::new()),
+    );
+    assert_eq!(summaries.catch_up().await.unwrap(), 2);
+
+    let summary = summaries.get("order-1").await.unwrap().unwrap();
+    assert_eq!(summary.order_id, Some(OrderId("order-1".to_string())));
+    assert_eq!(summary.order_number.as_deref(), Some("ORD-order-1"));
+    assert_eq!(summary.status, Some(OrderStatus::Paid));
+    assert_eq!(summary.item_count, Some(2));
+    assert_eq!(
+        summary.total_amount,
+        Some(Money {
+            amount: "7.00".parse().unwrap(),
+            currency: "EUR".to_string(),
+        })
+    );
+    // Left join: no customer events in this context, so no customer fields
+    assert_eq!(summary.customer_name, None);
+    assert_eq!(summaries.list().await.unwrap(), vec![summary]);
+}
+
+#[tokio::test]
+async fn details_rows_join_their_customer() {
+    let events = paid_order().await;
+    let store: Arc<dyn ProjectionStore<Value>> = Arc::new(InMemoryProjectionStore::new());
+    let details = Projector::<OrderDetailsProjection>::new(events.clone(), store.clone());
+    details.catch_up().await.unwrap();
+
+    // Inner join: without the customer there is no row
+    assert_eq!(details.get("order-1").await.unwrap(), None);
+    assert!(details.list().await.unwrap().is_empty());
+
+    let details = Projector::<OrderDetailsProjection>::new(events.clone(), store)
+        .with_lookup(Arc::new(Customers));
+    let row = details.get("order-1").await.unwrap().unwrap();
+    assert_eq!(
+        row.customer,
+        Some(json!({ "id": "customer-1", "name": "Ada", "email": "ada@example.com" }))
+    );
+    assert_eq!(row.status, Some(OrderStatus::Paid));
+    assert_eq!(
+        row.items,
+        Some(json!([
+            { "product_id": "p-1", "product_name": "Pen", "quantity": 2, "subtotal": money("3.00") },
+            { "product_id": "p-2", "product_name": "Ink", "quantity": 1, "subtotal": money("4.00") },
+        ]))
+    );
+    assert_eq!(
+        row.timeline,
+        Some(json!([
+            { "type": "OrderCreated", "occurred_at": "2026-01-05T10:00:00Z" },
+            { "type": "OrderPaid", "occurred_at": "2026-01-05T11:00:00Z" },
+        ]))
+    );
+    assert_eq!(row.shipping_address.map(|address| address.city), Some("Springfield".to_string()));
+}
+"#;
+
+/// Sends commands to the HTTP handlers and reads back the event store and outbox
+const COMMANDS_TEST: &str = r#"
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use order::api::*;
+use order::infrastructure::event_store::{EventStore, InMemoryEventStore};
+use order::infrastructure::outbox::OutboxStore;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+async fn body(response: impl IntoResponse) -> (StatusCode, Value) {
+    let response: Response = response.into_response();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+async fn post(state: &AppState, path: &str, payload: Value) -> (StatusCode, Value) {
+    let state = State(state.clone());
+    match path {
+        "/create-order" => body(create_order(state, Json(serde_json::from_value(payload).unwrap())).await).await,
+        "/confirm-payment" => body(confirm_payment(state, Json(serde_json::from_value(payload).unwrap())).await).await,
+        "/ship-order" => body(ship_order(state, Json(serde_json::from_value(payload).unwrap())).await).await,
+        _ => unreachable!("no route {}", path),
+    }
+}
+
+fn money(amount: &str) -> Value {
+    json!({ "amount": amount, "currency": "EUR" })
+}
+
+fn new_order(items: Value) -> Value {
+    json!({
+        "customer_id": "customer-1",
+        "items": items,
+        "shipping_address": { "street": "1 Main St", "city": "Springfield", "province": "SP", "postal_code": "12345", "country": "US" },
+    })
+}
+
+fn items() -> Value {
+    json!([
+        { "product_id": "p-1", "product_name": "Pen", "quantity": 2, "unit_price": money("1.50"), "subtotal": money("3.00") },
+        { "product_id": "p-2", "product_name": "Ink", "quantity": 1, "unit_price": money("4.00"), "subtotal": money("4.00") },
+    ])
+}
+
+fn amount(event: &Value, field: &str) -> f64 {
+    event[field]["amount"].as_str().unwrap().parse().unwrap()
+}
+
+#[tokio::test]
+async fn commands_append_events_and_outbox_entries() {
+    let store = Arc::new(InMemoryEventStore::new());
+    let state = AppState::new(store.clone());
+
+    let (status, body) = post(&state, "/create-order", new_order(items())).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let events = store.read_all_from(0).await.unwrap();
+    assert_eq!(events.len(), 1);
+    let order_id = events[0]["aggregate_id"].as_str().unwrap().to_string();
+    assert_eq!(events[0]["type"], "OrderCreated");
+    assert_eq!(events[0]["order_id"], order_id.as_str());
+    assert_eq!(amount(&events[0], "total_amount"), 7.0);
+
+    let (status, body) = post(
+        &state,
+        "/confirm-payment",
+        json!({ "order_id": order_id, "payment_id": "payment-1" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let events = store.read_all_from(0).await.unwrap();
+    assert_eq!(events[1]["type"], "OrderPaid");
+    assert_eq!(events[1]["aggregate_id"], order_id.as_str());
+    assert_eq!(amount(&events[1], "paid_amount"), 7.0);
+
+    let pending = store.pending(10).await.unwrap();
+    let types: Vec<&str> = pending.iter().map(|entry| entry.message.event_type.as_str()).collect();
+    assert_eq!(types, ["OrderCreated", "OrderPaid"]);
+}
+
+#[tokio::test]
+async fn rejected_commands_append_nothing() {
+    let store = Arc::new(InMemoryEventStore::new());
+    let state = AppState::new(store.clone());
+
+    let (status, body) = post(&state, "/create-order", new_order(json!([]))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "VALIDATION_ERROR");
+
+    let (status, body) = post(
+        &state,
+        "/ship-order",
+        json!({ "order_id": "order-9", "tracking_number": "T-1", "carrier": "UPS" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], "NOT_FOUND");
+    assert!(store.read_all_from(0).await.unwrap().is_empty());
+
+    post(&state, "/create-order", new_order(items())).await;
+    let order_id = store.read_all_from(0).await.unwrap()[0]["aggregate_id"].clone();
+    let (status, body) = post(
+        &state,
+        "/ship-order",
+        json!({ "order_id": order_id, "tracking_number": "T-1", "carrier": "UPS" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["message"], "Precondition failed: Can only ship orders that have been paid");
+    assert_eq!(store.read_all_from(0).await.unwrap().len(), 1);
+}
+"#;
+
+/// Context with state-stored aggregates; `{extra}` takes more `Product` properties
+const STATE_STORED_CONTEXT: &str = r#"
+version: "1.0"
+bounded_context:
+  name: "Catalog"
+  namespace: "test.catalog"
+aggregates:
+  Product:
+    name: "Product"
+    persistence: "state"
+    root_entity:
+      name: "Product"
+      properties:
+        - name: "id"
+          type: "ProductId"
+          identifier: true
+        - name: "code"
+          type: "Uuid"
+          identifier: true
+        - name: "order"
+          type: "Integer"
+        - name: "status"
+          type: "ProductStatus"
+          default: "ACTIVE"
+        - name: "price"
+          type: "Money"
+        - name: "discount"
+          type: "Money"
+          required: false
+        - name: "releasedOn"
+          type: "Date"
+          required: false
+        - name: "active"
+          type: "Boolean"
+        - name: "variants"
+          type: "List<Variant>"
+        - name: "tags"
+          type: "List<String>"
+        - name: "aliases"
+          type: "List<String>"
+          required: false
+        - name: "updatedAt"
+          type: "DateTime"
+{extra}
+  Category:
+    name: "Category"
+    persistence: "state"
+    root_entity:
+      name: "Category"
+      properties:
+        - name: "number"
+          type: "CategoryNumber"
+          identifier: true
+        - name: "label"
+          type: "String"
+value_objects:
+  ProductId:
+    name: "ProductId"
+    type: "identifier"
+    underlying_type: "String"
+  CategoryNumber:
+    name: "CategoryNumber"
+    type: "identifier"
+    underlying_type: "Integer"
+  ProductStatus:
+    name: "ProductStatus"
+    type: "enum"
+    values:
+      - name: "ACTIVE"
+      - name: "DISCONTINUED"
+  Money:
+    name: "Money"
+    properties:
+      - name: "amount"
+        type: "Decimal"
+      - name: "currency"
+        type: "String"
+        default: "EUR"
+  Variant:
+    name: "Variant"
+    properties:
+      - name: "sku"
+        type: "String"
+        identifier: true
+      - name: "price"
+        type: "Money"
+      - name: "status"
+        type: "ProductStatus"
+        required: false
+      - name: "restockAt"
+        type: "Time"
+        required: false
+commands:
+  RegisterCategory:
+    name: "RegisterCategory"
+    aggregate: "Category"
+    payload:
+      - name: "number"
+        type: "CategoryNumber"
+      - name: "label"
+        type: "String"
+    produces: ["CategoryRegistered"]
+  RenameCategory:
+    name: "RenameCategory"
+    aggregate: "Category"
+    payload:
+      - name: "number"
+        type: "CategoryNumber"
+      - name: "label"
+        type: "String"
+    produces: ["CategoryRenamed"]
+    preconditions:
+      - expression: "category.label != label"
+        message: "Category already has this label"
+events:
+  CategoryRegistered:
+    name: "CategoryRegistered"
+    aggregate: "Category"
+    payload:
+      - name: "number"
+        type: "CategoryNumber"
+      - name: "label"
+        type: "String"
+  CategoryRenamed:
+    name: "CategoryRenamed"
+    aggregate: "Category"
+    payload:
+      - name: "number"
+        type: "CategoryNumber"
+      - name: "label"
+        type: "String"
+"#;
+
+/// Runs commands on a state-stored aggregate and reads its row back
+const STATE_STORED_COMMANDS_TEST: &str = r#"
+#![cfg(feature = "sqlx")]
+
+use catalog::api::*;
+use catalog::domain::commands::*;
+use catalog::domain::entities::CategoryNumber;
+use catalog::infrastructure::event_store::EventStore;
+use catalog::infrastructure::SqliteEventStore;
+use catalog::repository::{self, Repositories};
+use std::sync::Arc;
+
+async fn label(store: &SqliteEventStore, number: i64) -> String {
+    sqlx::query_scalar("SELECT label FROM categories WHERE number = ?")
+        .bind(number)
+        .fetch_one(store.pool())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn commands_save_state_stored_aggregates() {
+    let store = Arc::new(SqliteEventStore::in_memory().await.unwrap());
+    repository::migrate(store.pool()).await.unwrap();
+    let state = AppState::with_repositories(store.clone(), Repositories::sqlite(store.pool().clone()));
+
+    let register = RegisterCategory { number: CategoryNumber(7), label: "Pens".to_string() };
+    execute_register_category(&state, register).await.unwrap();
+    assert_eq!(label(&store, 7).await, "Pens");
+
+    let rename = RenameCategory { number: CategoryNumber(7), label: "Ink".to_string() };
+    execute_rename_category(&state, rename).await.unwrap();
+    assert_eq!(label(&store, 7).await, "Ink");
+    assert_eq!(store.version("7", "Category").await.unwrap(), 2);
+
+    let rename = RenameCategory { number: CategoryNumber(7), label: "Ink".to_string() };
+    let error = execute_rename_category(&state, rename).await.unwrap_err();
+    assert_eq!(error.code, "PRECONDITION_FAILED");
+
+    let rename = RenameCategory { number: CategoryNumber(8), label: "Ink".to_string() };
+    let error = execute_rename_category(&state, rename).await.unwrap_err();
+    assert_eq!(error.code, "NOT_FOUND");
+}
+"#;
+
+#[test]
+fn test_generator_state_stored_aggregates() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let input_path = temp_dir.path().join("catalog.yaml");
+    let output_path = temp_dir.path().join("catalog");
+
+    let build_status = Command::new("cargo")
+        .args(["build"])
+        .status()
+        .expect("Failed to build generator");
+
+    assert!(build_status.success(), "Generator build failed");
+
+    // Generate twice into the same directory, the second time with a new
+    // optional property, which must add a migration next to the first
+    let extras = [
+        "",
+        "        - name: \"note\"\n          type: \"String\"\n          required: false",
+    ];
+    for extra in extras {
+        std::fs::write(&input_path, STATE_STORED_CONTEXT.replace("{extra}", extra))
+            .expect("Failed to write IR");
+        let generate_status = Command::new("./target/debug/stormforge-generator")
+            .args(["generate", "--input"])
+            .arg(&input_path)
+            .arg("--output")
+            .arg(&output_path)
+            .stdout(std::process::Stdio::null())
+            .status()
+            .expect("Failed to run generator");
+
+        assert!(generate_status.success(), "Generator execution failed");
+    }
+
+    let migrations = output_path.join("migrations");
+    let first = std::fs::read_to_string(migrations.join("0001_create_schema.sql"))
+        .expect("First migration not generated");
+    assert!(first.contains("CREATE TABLE product_variants ("));
+    assert!(first.contains("    UNIQUE (product_id, sku)"));
+    let second = std::fs::read_to_string(migrations.join("0002_alter_products.sql"))
+        .expect("Second migration not generated");
+    assert!(second.contains("ALTER TABLE products ADD COLUMN note TEXT;"));
+    assert!(output_path
+        .join("src/repository/sqlite_category_repository.rs")
+        .exists());
+
+    std::fs::write(
+        output_path.join("tests/state_stored_commands.rs"),
+        STATE_STORED_COMMANDS_TEST,
+    )
+    .expect("Failed to write state-stored commands test");
+
+    // Runs both migrations, the repository round trips and the commands on
+    // the state-stored aggregate
+    let test_generated_status = Command::new("cargo")
+        .args(["test", "--features", "sqlx"])
+        .current_dir(&output_path)
+        .status()
+        .expect("Failed to test generated service");
 
     assert!(
-        build_generated_status.success(),
-        "Generated service build failed"
+        test_generated_status.success(),
+        "Generated service tests failed"
     );
 }
 
@@ -82,7 +789,7 @@ fn test_validate_command() {
 
     // Build the generator
     let build_status = Command::new("cargo")
-        .args(&["build"])
+        .args(["build"])
         .status()
         .expect("Failed to build generator");
 
@@ -90,7 +797,7 @@ fn test_validate_command() {
 
     // Run validate command
     let validate_status = Command::new("./target/debug/stormforge-generator")
-        .args(&[
+        .args([
             "validate",
             "--input",
             input_path
@@ -102,3 +809,250 @@ fn test_validate_command() {
 
     assert!(validate_status.success(), "Validation failed");
 }
+
+/// Collect every generated file below `root` as (relative path, contents)
+fn read_tree(root: &Path) -> Vec<(String, Vec<u8>)> {
+    let mut files: Vec<(String, Vec<u8>)> = walkdir::WalkDir::new(root)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| {
+            let relative = entry
+                .path()
+                .strip_prefix(root)
+                .expect("Generated file outside output directory")
+                .to_string_lossy()
+                .to_string();
+            let content = std::fs::read(entry.path()).expect("Failed to read generated file");
+            (relative, content)
+        })
+        .collect();
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    files
+}
+
+#[test]
+fn test_generation_is_deterministic() {
+    let examples = [
+        "../ir_schema/examples/ecommerce/order_context.yaml",
+        "../ir_schema/examples/ecommerce/inventory_context.yaml",
+        "../ir_schema/examples/ecommerce/payment_context.yaml",
+        "../ir_schema/examples/hr/leave_context.yaml",
+    ];
+
+    let build_status = Command::new("cargo")
+        .args(["build"])
+        .status()
+        .expect("Failed to build generator");
+
+    assert!(build_status.success(), "Generator build failed");
+
+    for example in examples {
+        let input_path = Path::new(example);
+        if !input_path.exists() {
+            println!("Skipping {}: example file not found", example);
+            continue;
+        }
+
+        // Generate the same model several times and compare every file
+        let mut runs = Vec::new();
+        for _ in 0..3 {
+            let temp_dir = TempDir::new().expect("Failed to create temp dir");
+            let generate_status = Command::new("./target/debug/stormforge-generator")
+                .args([
+                    "generate",
+                    "--input",
+                    example,
+                    "--output",
+                    temp_dir.path().to_str().unwrap(),
+                ])
+                .stdout(std::process::Stdio::null())
+                .status()
+                .expect("Failed to run generator");
+
+            assert!(generate_status.success(), "Generator execution failed");
+            runs.push(read_tree(temp_dir.path()));
+        }
+
+        for run in &runs[1..] {
+            assert_eq!(run.len(), runs[0].len(), "File set differs for {}", example);
+            for ((path_a, content_a), (path_b, content_b)) in runs[0].iter().zip(run) {
+                assert_eq!(path_a, path_b, "File set differs for {}", example);
+                assert!(
+                    content_a == content_b,
+                    "Output of {} differs between runs for {}",
+                    path_a,
+                    example
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_generator_workspace() {
+    let inputs = [
+        "../ir_schema/examples/ecommerce/order_context.yaml",
+        "../ir_schema/examples/ecommerce/inventory_context.yaml",
+        "../ir_schema/examples/ecommerce/payment_context.yaml",
+    ];
+    if inputs.iter().any(|input| !Path::new(input).exists()) {
+        println!("Skipping test: example files not found");
+        return;
+    }
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let output_path = temp_dir
+        .path()
+        .to_str()
+        .expect("Output path contains invalid UTF-8 characters");
+
+    let build_status = Command::new("cargo")
+        .args(["build"])
+        .status()
+        .expect("Failed to build generator");
+
+    assert!(build_status.success(), "Generator build failed");
+
+    let mut args = vec!["workspace", "--output", output_path, "--input"];
+    args.extend(inputs);
+    let generate_status = Command::new("./target/debug/stormforge-generator")
+        .args(&args)
+        .status()
+        .expect("Failed to run generator");
+
+    assert!(generate_status.success(), "Workspace generation failed");
+
+    let generated_files = vec![
+        "Cargo.toml",
+        "shared-kernel/Cargo.toml",
+        "shared-kernel/src/lib.rs",
+        "integration-events/Cargo.toml",
+        "integration-events/src/lib.rs",
+        "order/Cargo.toml",
+        "order/src/domain/entities.rs",
+        "inventory/Cargo.toml",
+        "payment/Cargo.toml",
+    ];
+
+    for file in generated_files {
+        let file_path = Path::new(output_path).join(file);
+        assert!(file_path.exists(), "Expected file not found: {}", file);
+    }
+
+    let read = |file: &str| std::fs::read_to_string(Path::new(output_path).join(file)).unwrap();
+
+    // OrderId is identical in all contexts, Money differs between Order and Payment
+    let kernel = read("shared-kernel/src/lib.rs");
+    assert!(kernel.contains("pub struct OrderId("));
+    assert!(!kernel.contains("pub struct Money"));
+
+    let order_entities = read("order/src/domain/entities.rs");
+    assert!(order_entities.contains("pub use shared_kernel::*;"));
+    assert!(!order_entities.contains("pub struct OrderId("));
+    assert!(order_entities.contains("pub struct Money"));
+
+    let contracts = read("integration-events/src/lib.rs");
+    assert!(contracts.contains("pub mod payment"));
+    assert!(contracts.contains("pub struct PaymentCompleted"));
+    assert!(contracts.contains("pub struct StockReserved"));
+
+    let contracts = read("order/src/integration/contracts.rs");
+    assert!(contracts.contains("pub use integration_events::payment;"));
+
+    // Builds every crate and runs the generated event bus and consumer tests
+    let test_generated_status = Command::new("cargo")
+        .args(["test", "--workspace"])
+        .current_dir(output_path)
+        .status()
+        .expect("Failed to test generated workspace");
+
+    assert!(
+        test_generated_status.success(),
+        "Generated workspace tests failed"
+    );
+}
+
+#[test]
+fn test_cml_export_and_import() {
+    let inputs = [
+        "../ir_schema/examples/ecommerce/order_context.yaml",
+        "../ir_schema/examples/ecommerce/inventory_context.yaml",
+        "../ir_schema/examples/ecommerce/payment_context.yaml",
+    ];
+    if inputs.iter().any(|input| !Path::new(input).exists()) {
+        println!("Skipping test: example files not found");
+        return;
+    }
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let cml_path = temp_dir.path().join("shop.cml");
+    let ir_path = temp_dir.path().join("ir");
+
+    let build_status = Command::new("cargo")
+        .args(["build"])
+        .status()
+        .expect("Failed to build generator");
+
+    assert!(build_status.success(), "Generator build failed");
+
+    let mut args = vec![
+        "export",
+        "--to",
+        "cml",
+        "--output",
+        cml_path.to_str().unwrap(),
+        "--input",
+    ];
+    args.extend(inputs);
+    let export = Command::new("./target/debug/stormforge-generator")
+        .args(&args)
+        .output()
+        .expect("Failed to run export");
+
+    assert!(export.status.success(), "CML export failed");
+    let report = String::from_utf8_lossy(&export.stdout);
+    assert!(report.contains("could not be translated"));
+    assert!(report.contains("Order: queries.GetOrder: query has no CML counterpart"));
+
+    let cml = std::fs::read_to_string(&cml_path).unwrap();
+    assert!(cml.contains("contains Order, Inventory, Payment"));
+    assert!(cml.contains("Payment [U]->[D] Order"));
+
+    let import = Command::new("./target/debug/stormforge-generator")
+        .args([
+            "import",
+            "--from",
+            "cml",
+            "--input",
+            cml_path.to_str().unwrap(),
+            "--output",
+            ir_path.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Failed to run import");
+
+    assert!(import.status.success(), "CML import failed");
+    let report = String::from_utf8_lossy(&import.stdout);
+    assert!(report.contains("type `CreateOrderItem` is not declared"));
+
+    // Every imported context is valid IR
+    for file in [
+        "order_context.yaml",
+        "inventory_context.yaml",
+        "payment_context.yaml",
+    ] {
+        let file_path = ir_path.join(file);
+        assert!(file_path.exists(), "Expected file not found: {}", file);
+
+        let validate_status = Command::new("./target/debug/stormforge-generator")
+            .args(["validate", "--input", file_path.to_str().unwrap()])
+            .status()
+            .expect("Failed to run validator");
+
+        assert!(validate_status.success(), "Validation of {} failed", file);
+    }
+
+    let order = std::fs::read_to_string(ir_path.join("order_context.yaml")).unwrap();
+    assert!(order.contains("context: \"Payment\"\n    event: \"PaymentCompleted\""));
+}